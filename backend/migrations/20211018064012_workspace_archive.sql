@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE workspace_table ADD COLUMN archived BOOL NOT NULL DEFAULT false;
\ No newline at end of file
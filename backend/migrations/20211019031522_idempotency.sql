@@ -0,0 +1,8 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS idempotency_table(
+    user_id TEXT NOT NULL,
+    key TEXT NOT NULL,
+    PRIMARY KEY (user_id, key),
+    response BYTEA NOT NULL,
+    create_time timestamptz NOT NULL
+);
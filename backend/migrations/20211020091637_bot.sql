@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS bot_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    workspace_id TEXT NOT NULL,
+    owner_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    scope INTEGER NOT NULL,
+    create_time timestamptz NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,12 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS published_view_table(
+    view_id uuid NOT NULL,
+    PRIMARY KEY (view_id),
+    root_view_id uuid NOT NULL,
+    parent_view_id TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    html TEXT NOT NULL,
+    owner_id TEXT NOT NULL,
+    publish_time timestamptz NOT NULL,
+    update_time timestamptz NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS doc_revision_table(
+    doc_id uuid NOT NULL,
+    rev_id bigint NOT NULL,
+    PRIMARY KEY (doc_id, rev_id),
+    data TEXT NOT NULL DEFAULT '',
+    create_time timestamptz NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE published_view_table ADD COLUMN rev_id bigint NOT NULL DEFAULT 0;
+ALTER TABLE published_view_table ADD COLUMN is_pinned BOOL NOT NULL DEFAULT false;
\ No newline at end of file
@@ -0,0 +1,11 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS api_usage_table(
+    usage_date date NOT NULL,
+    user_id TEXT NOT NULL,
+    bot_id TEXT NOT NULL,
+    PRIMARY KEY (usage_date, user_id, bot_id),
+    workspace_id TEXT NOT NULL,
+    call_count bigint NOT NULL DEFAULT 0,
+    request_bytes bigint NOT NULL DEFAULT 0,
+    response_bytes bigint NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS doc_snapshot_table(
+    id BIGSERIAL PRIMARY KEY,
+    doc_id uuid NOT NULL,
+    name TEXT NOT NULL DEFAULT '',
+    rev_id bigint NOT NULL,
+    data TEXT NOT NULL DEFAULT '',
+    create_time timestamptz NOT NULL
+);
+CREATE INDEX IF NOT EXISTS doc_snapshot_doc_id_idx ON doc_snapshot_table(doc_id);
@@ -0,0 +1,9 @@
+-- Add migration script here
+ALTER TABLE doc_table ADD COLUMN IF NOT EXISTS text TEXT NOT NULL DEFAULT '';
+ALTER TABLE doc_table ADD COLUMN IF NOT EXISTS search_vector tsvector
+    GENERATED ALWAYS AS (to_tsvector('simple', text)) STORED;
+CREATE INDEX IF NOT EXISTS doc_search_vector_idx ON doc_table USING GIN(search_vector);
+
+ALTER TABLE view_table ADD COLUMN IF NOT EXISTS search_vector tsvector
+    GENERATED ALWAYS AS (to_tsvector('simple', name)) STORED;
+CREATE INDEX IF NOT EXISTS view_search_vector_idx ON view_table USING GIN(search_vector);
@@ -0,0 +1,8 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS published_view_access_table(
+    access_date date NOT NULL,
+    view_id uuid NOT NULL,
+    PRIMARY KEY (access_date, view_id),
+    owner_id TEXT NOT NULL,
+    read_count bigint NOT NULL DEFAULT 0
+);
\ No newline at end of file
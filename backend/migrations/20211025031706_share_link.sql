@@ -0,0 +1,11 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS share_link_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    view_id uuid NOT NULL,
+    workspace_id TEXT NOT NULL,
+    owner_id TEXT NOT NULL,
+    passcode_hash TEXT NOT NULL DEFAULT '',
+    expire_time timestamptz,
+    create_time timestamptz NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- Add migration script here
+-- The search is served by the tantivy index, which reads the text column of the doc table
+DROP INDEX IF EXISTS doc_search_vector_idx;
+ALTER TABLE doc_table DROP COLUMN IF EXISTS search_vector;
+
+DROP INDEX IF EXISTS view_search_vector_idx;
+ALTER TABLE view_table DROP COLUMN IF EXISTS search_vector;
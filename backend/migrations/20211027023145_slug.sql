@@ -0,0 +1,11 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS slug_table(
+    scope_id TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    PRIMARY KEY (scope_id, slug),
+    object_id TEXT NOT NULL,
+    is_current BOOL NOT NULL,
+    create_time timestamptz NOT NULL
+);
+CREATE INDEX IF NOT EXISTS slug_object_id_idx ON slug_table(object_id);
+ALTER TABLE published_view_table ADD COLUMN workspace_id TEXT NOT NULL DEFAULT '';
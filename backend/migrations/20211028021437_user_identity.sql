@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS user_identity_table(
+    provider TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    PRIMARY KEY (provider, subject),
+    user_id uuid NOT NULL,
+    email TEXT NOT NULL,
+    create_time timestamptz NOT NULL
+);
+CREATE INDEX IF NOT EXISTS user_identity_user_id_idx ON user_identity_table(user_id);
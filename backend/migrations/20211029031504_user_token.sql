@@ -0,0 +1,11 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS user_token_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    user_id uuid NOT NULL,
+    purpose TEXT NOT NULL,
+    is_used BOOL NOT NULL DEFAULT false,
+    create_time timestamptz NOT NULL,
+    expire_time timestamptz NOT NULL
+);
+CREATE INDEX IF NOT EXISTS user_token_user_id_idx ON user_token_table(user_id);
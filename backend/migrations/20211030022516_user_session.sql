@@ -0,0 +1,13 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS user_session_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    user_id uuid NOT NULL,
+    refresh_token_hash TEXT NOT NULL,
+    previous_token_hash TEXT NOT NULL DEFAULT '',
+    is_revoked BOOL NOT NULL DEFAULT false,
+    create_time timestamptz NOT NULL,
+    refresh_time timestamptz NOT NULL,
+    expire_time timestamptz NOT NULL
+);
+CREATE INDEX IF NOT EXISTS user_session_user_id_idx ON user_session_table(user_id);
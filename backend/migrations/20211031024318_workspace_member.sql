@@ -0,0 +1,9 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS workspace_member_table(
+    workspace_id uuid NOT NULL,
+    user_id TEXT NOT NULL,
+    PRIMARY KEY (workspace_id, user_id),
+    role INTEGER NOT NULL,
+    create_time timestamptz NOT NULL
+);
+CREATE INDEX IF NOT EXISTS workspace_member_user_id_idx ON workspace_member_table(user_id);
@@ -0,0 +1,15 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS attachment_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    doc_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    size BIGINT NOT NULL,
+    chunk_size BIGINT NOT NULL,
+    uploaded_chunks BIGINT NOT NULL DEFAULT 0,
+    is_orphaned BOOL NOT NULL DEFAULT false,
+    create_time timestamptz NOT NULL,
+    modified_time timestamptz NOT NULL
+);
+CREATE INDEX IF NOT EXISTS attachment_doc_id_idx ON attachment_table(doc_id);
@@ -0,0 +1,12 @@
+-- Add migration script here
+ALTER TABLE app_table ADD COLUMN rank TEXT COLLATE "C" NOT NULL DEFAULT '';
+ALTER TABLE view_table ADD COLUMN rank TEXT COLLATE "C" NOT NULL DEFAULT '';
+-- The existing apps and views keep the order of their create time
+UPDATE app_table SET rank = ranked.rank FROM (
+    SELECT id, LPAD((ROW_NUMBER() OVER (PARTITION BY workspace_id ORDER BY create_time))::TEXT, 8, '0') || 'V' AS rank
+    FROM app_table
+) AS ranked WHERE app_table.id = ranked.id;
+UPDATE view_table SET rank = ranked.rank FROM (
+    SELECT id, LPAD((ROW_NUMBER() OVER (PARTITION BY belong_to_id ORDER BY create_time))::TEXT, 8, '0') || 'V' AS rank
+    FROM view_table
+) AS ranked WHERE view_table.id = ranked.id;
@@ -0,0 +1,9 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS attachment_reference_table(
+    attachment_id uuid NOT NULL,
+    doc_id uuid NOT NULL,
+    PRIMARY KEY (attachment_id, doc_id),
+    ref_count BIGINT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS attachment_reference_doc_id_idx ON attachment_reference_table(doc_id);
+ALTER TABLE attachment_table ADD COLUMN IF NOT EXISTS unreferenced_time timestamptz;
@@ -0,0 +1,5 @@
+-- Add migration script here
+ALTER TABLE doc_revision_table ADD COLUMN IF NOT EXISTS device_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE doc_snapshot_table ADD COLUMN IF NOT EXISTS device_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE app_table ADD COLUMN IF NOT EXISTS modified_device_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE view_table ADD COLUMN IF NOT EXISTS modified_device_id TEXT NOT NULL DEFAULT '';
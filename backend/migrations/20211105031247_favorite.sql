@@ -0,0 +1,7 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS favorite_table(
+    user_id TEXT NOT NULL,
+    view_id uuid NOT NULL,
+    PRIMARY KEY (user_id, view_id),
+    create_time timestamptz NOT NULL
+);
@@ -0,0 +1,12 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS workspace_clone_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    user_id TEXT NOT NULL,
+    source_workspace_id TEXT NOT NULL,
+    workspace_id TEXT NOT NULL,
+    total BIGINT NOT NULL DEFAULT 0,
+    finished BIGINT NOT NULL DEFAULT 0,
+    state INTEGER NOT NULL DEFAULT 0,
+    create_time timestamptz NOT NULL
+);
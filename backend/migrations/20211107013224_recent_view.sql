@@ -0,0 +1,7 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS recent_view_table(
+    user_id TEXT NOT NULL,
+    view_id uuid NOT NULL,
+    PRIMARY KEY (user_id, view_id),
+    open_time timestamptz NOT NULL
+);
@@ -0,0 +1,12 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS view_webhook_table(
+    view_id uuid NOT NULL,
+    PRIMARY KEY (view_id),
+    owner_id TEXT NOT NULL,
+    url TEXT NOT NULL,
+    format INTEGER NOT NULL DEFAULT 0,
+    secret TEXT NOT NULL DEFAULT '',
+    create_time timestamptz NOT NULL,
+    last_delivery_time timestamptz,
+    last_status INTEGER NOT NULL DEFAULT 0
+);
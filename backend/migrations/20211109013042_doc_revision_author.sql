@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE doc_revision_table ADD COLUMN IF NOT EXISTS author_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE doc_revision_table ADD COLUMN IF NOT EXISTS is_bot BOOLEAN NOT NULL DEFAULT false;
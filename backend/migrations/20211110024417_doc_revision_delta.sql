@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE doc_revision_table ADD COLUMN IF NOT EXISTS is_checkpoint BOOLEAN NOT NULL DEFAULT true;
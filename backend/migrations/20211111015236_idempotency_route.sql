@@ -0,0 +1,5 @@
+-- Add migration script here
+ALTER TABLE idempotency_table ADD COLUMN IF NOT EXISTS route TEXT NOT NULL DEFAULT '';
+ALTER TABLE idempotency_table ADD COLUMN IF NOT EXISTS request_hash TEXT NOT NULL DEFAULT '';
+ALTER TABLE idempotency_table DROP CONSTRAINT IF EXISTS idempotency_table_pkey;
+ALTER TABLE idempotency_table ADD PRIMARY KEY (user_id, route, key);
@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE user_table ADD COLUMN IF NOT EXISTS email_verified BOOL NOT NULL DEFAULT false;
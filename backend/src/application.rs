@@ -1,4 +1,4 @@
-use std::{net::TcpListener, time::Duration};
+use std::{net::TcpListener, sync::Arc, time::Duration};
 
 use actix::Actor;
 use actix_identity::{CookieIdentityPolicy, IdentityService};
@@ -11,18 +11,39 @@ use crate::{
         env::{domain, secret, use_https},
         DatabaseSettings,
         Settings,
+        API_USAGE_FLUSH_INTERVAL,
+        ATTACHMENT_GC_INTERVAL,
+        METRICS_ROUTE,
+        PUBLISHED_API_ROUTE,
+        PUBLISHED_PAGE_ROUTE,
+        SHARED_PAGE_ROUTE,
     },
+    authenticator::build_authenticator,
     context::AppContext,
+    mailer::build_mailer,
+    rate_limit::build_rate_limiter,
     service::{
         app::router as app,
+        attachment::{collect_orphaned_attachments, router as attachment},
+        automation::router as automation,
+        bot::{load_authorized_bots, router as bot},
         doc::router as doc,
+        favorite::router as favorite,
+        metrics::{router as metrics, POOL_METRICS},
+        publish::router as publish,
+        recent::router as recent,
+        search::SearchIndex,
+        share::router as share,
         trash::router as trash,
+        usage::{flush_api_usage, router as usage},
         user::router as user,
         view::router as view,
+        webhook::router as webhook,
         workspace::router as workspace,
         ws,
         ws::WsServer,
     },
+    storage::{build_storage, ObjectStorage},
 };
 
 pub struct Application {
@@ -44,39 +65,85 @@ impl Application {
     pub fn port(&self) -> u16 { self.port }
 }
 
+// The default format of the Logger with the request id at the end
+const LOG_FORMAT: &str = r#"%a "%r" %s %b "%{Referer}i" "%{User-Agent}i" %T %{request-id}i"#;
+
 pub fn run(listener: TcpListener, app_ctx: AppContext) -> Result<Server, std::io::Error> {
     let domain = domain();
     let secret: String = secret();
     actix_rt::spawn(period_check(app_ctx.pg_pool.clone()));
+    actix_rt::spawn(attachment_gc(app_ctx.pg_pool.clone(), app_ctx.storage.clone()));
 
     let server = HttpServer::new(move || {
         App::new()
-            .wrap(middleware::Logger::default())
+            .wrap(middleware::Logger::new(LOG_FORMAT))
             .wrap(identify_service(&domain, &secret))
             .wrap(crate::middleware::default_cors())
             .wrap(crate::middleware::AuthenticationService)
+            .wrap(crate::middleware::RequestLimitService::new(app_ctx.rate_limiter.get_ref().clone()))
+            .wrap(crate::middleware::RequestIdService)
             .app_data(web::JsonConfig::default().limit(4096))
             .service(ws_scope())
             .service(user_scope())
+            .service(published_scope())
+            .service(published_api_scope())
+            .service(shared_scope())
+            .service(web::resource(METRICS_ROUTE).route(web::get().to(metrics::read_handler)))
             .app_data(app_ctx.ws_server.clone())
             .app_data(app_ctx.pg_pool.clone())
             .app_data(app_ctx.ws_bizs.clone())
             .app_data(app_ctx.doc_biz.clone())
+            .app_data(app_ctx.storage.clone())
+            .app_data(app_ctx.search.clone())
+            .app_data(app_ctx.oauth.clone())
+            .app_data(app_ctx.mailer.clone())
+            .app_data(app_ctx.authenticator.clone())
+            .app_data(app_ctx.metrics.clone())
+            .app_data(app_ctx.rate_limiter.clone())
     })
     .listen(listener)?
     .run();
     Ok(server)
 }
 
-async fn period_check(_pool: Data<PgPool>) {
-    let mut i = interval(Duration::from_secs(60));
+async fn period_check(pool: Data<PgPool>) {
+    let mut i = interval(API_USAGE_FLUSH_INTERVAL);
+    loop {
+        i.tick().await;
+        if let Err(e) = flush_api_usage(pool.get_ref()).await {
+            log::error!("Flush API usage failed: {:?}", e);
+        }
+    }
+}
+
+async fn attachment_gc(pool: Data<PgPool>, storage: Data<Arc<dyn ObjectStorage>>) {
+    let mut i = interval(ATTACHMENT_GC_INTERVAL);
     loop {
         i.tick().await;
+        match collect_orphaned_attachments(pool.get_ref(), storage.get_ref()).await {
+            Ok(count) => log::debug!("Collected {} orphaned attachments", count),
+            Err(e) => log::error!("Collect orphaned attachments failed: {:?}", e),
+        }
     }
 }
 
 fn ws_scope() -> Scope { web::scope("/ws").service(ws::router::establish_ws_connection) }
 
+fn published_scope() -> Scope {
+    let page = web::resource("/{slug:.*}").route(web::get().to(publish::read_page_handler));
+    web::scope(PUBLISHED_PAGE_ROUTE).service(page)
+}
+
+fn published_api_scope() -> Scope {
+    let content = web::resource("/{slug:.*}").route(web::get().to(publish::read_content_handler));
+    web::scope(PUBLISHED_API_ROUTE).service(content)
+}
+
+fn shared_scope() -> Scope {
+    let page = web::resource("/{slug:.*}").route(web::get().to(share::read_page_handler));
+    web::scope(SHARED_PAGE_ROUTE).service(page)
+}
+
 fn user_scope() -> Scope {
     // https://developer.mozilla.org/en-US/docs/Web/HTTP
     // TODO: replace GET body with query params
@@ -86,6 +153,24 @@ fn user_scope() -> Scope {
             .route(web::post().to(user::sign_in_handler))
             .route(web::delete().to(user::sign_out_handler))
         )
+        .service(web::resource("/auth/provider")
+            .route(web::post().to(user::sign_in_with_provider_handler))
+        )
+        .service(web::resource("/auth/refresh")
+            .route(web::post().to(user::refresh_token_handler))
+        )
+        .service(web::resource("/auth/password_reset")
+            .route(web::post().to(user::request_password_reset_handler))
+        )
+        .service(web::resource("/auth/password_reset/confirm")
+            .route(web::post().to(user::confirm_password_reset_handler))
+        )
+        .service(web::resource("/auth/email_verify")
+            .route(web::post().to(user::request_email_verify_handler))
+        )
+        .service(web::resource("/auth/email_verify/confirm")
+            .route(web::post().to(user::confirm_email_verify_handler))
+        )
         .service(web::resource("/user")
             .route(web::patch().to(user::set_user_profile_handler))
             .route(web::get().to(user::get_user_profile_handler))
@@ -99,6 +184,34 @@ fn user_scope() -> Scope {
             .route(web::get().to(workspace::read_handler))
             .route(web::patch().to(workspace::update_handler))
         )
+        .service(web::resource("/workspace/archive")
+            .route(web::post().to(workspace::archive_handler))
+        )
+        .service(web::resource("/workspace/search")
+            .route(web::get().to(workspace::search_handler))
+        )
+        .service(web::resource("/workspace/member")
+            .route(web::post().to(workspace::share_handler))
+            .route(web::get().to(workspace::read_members_handler))
+        )
+        .service(web::resource("/workspace/clone")
+            .route(web::post().to(workspace::clone_handler))
+            .route(web::get().to(workspace::read_clone_handler))
+        )
+        .service(web::resource("/workspace/backup/{workspace_id}")
+            .route(web::get().to(workspace::backup_handler))
+        )
+        .service(web::resource("/workspace/restore")
+            .route(web::post().to(workspace::restore_handler))
+        )
+        .service(web::resource("/workspace/bot")
+            .route(web::post().to(bot::create_handler))
+            .route(web::get().to(bot::read_handler))
+            .route(web::delete().to(bot::delete_handler))
+        )
+        .service(web::resource("/workspace/usage")
+            .route(web::get().to(usage::read_handler))
+        )
         .service(web::resource("/workspace_list/{user_id}")
             .route(web::get().to(workspace::workspace_list))
         )
@@ -108,17 +221,80 @@ fn user_scope() -> Scope {
             .route(web::delete().to(app::delete_handler))
             .route(web::patch().to(app::update_handler))
         )
+        .service(web::resource("/app/move")
+            .route(web::patch().to(app::move_handler))
+        )
+        .service(web::resource("/app/duplicate")
+            .route(web::post().to(app::duplicate_handler))
+        )
         .service(web::resource("/view")
             .route(web::post().to(view::create_handler))
             .route(web::delete().to(view::delete_handler))
             .route(web::get().to(view::read_handler))
             .route(web::patch().to(view::update_handler))
         )
+        .service(web::resource("/view/move")
+            .route(web::patch().to(view::move_handler))
+        )
+        .service(web::resource("/view/duplicate")
+            .route(web::post().to(view::duplicate_handler))
+        )
+        .service(web::resource("/view/publish")
+            .route(web::post().to(publish::publish_handler))
+            .route(web::delete().to(publish::unpublish_handler))
+        )
+        .service(web::resource("/view/publish/pin")
+            .route(web::post().to(publish::pin_handler))
+        )
+        .service(web::resource("/view/publish/latest")
+            .route(web::post().to(publish::publish_latest_handler))
+        )
+        .service(web::resource("/view/publish/access")
+            .route(web::get().to(publish::read_access_log_handler))
+        )
+        .service(web::resource("/view/share")
+            .route(web::post().to(share::create_handler))
+            .route(web::get().to(share::read_handler))
+            .route(web::delete().to(share::revoke_handler))
+        )
+        .service(web::resource("/view/webhook")
+            .route(web::post().to(webhook::set_handler))
+            .route(web::get().to(webhook::read_handler))
+            .route(web::delete().to(webhook::delete_handler))
+        )
+        .service(web::resource("/view/favorite")
+            .route(web::post().to(favorite::update_handler))
+            .route(web::get().to(favorite::read_handler))
+        )
+        .service(web::resource("/view/recent")
+            .route(web::post().to(recent::update_handler))
+            .route(web::get().to(recent::read_handler))
+        )
         .service(web::resource("/doc")
             .route(web::post().to(doc::create_handler))
             .route(web::get().to(doc::read_handler))
             .route(web::patch().to(doc::update_handler))
         )
+        .service(web::resource("/doc/reset")
+            .route(web::get().to(doc::reset_handler))
+        )
+        .service(web::resource("/doc/snapshot")
+            .route(web::get().to(doc::snapshot_read_handler))
+        )
+        .service(web::resource("/doc/snapshot/restore")
+            .route(web::post().to(doc::snapshot_restore_handler))
+        )
+        .service(web::resource("/attachment")
+            .route(web::post().to(attachment::create_handler))
+            .route(web::get().to(attachment::read_handler))
+        )
+        .service(web::resource("/attachment/chunk")
+            .route(web::post().to(attachment::upload_chunk_handler))
+            .route(web::get().to(attachment::read_chunk_handler))
+        )
+        .service(web::resource("/automation")
+            .route(web::post().to(automation::command_handler))
+        )
         .service(web::resource("/trash")
             .route(web::post().to(trash::create_handler))
             .route(web::delete().to(trash::delete_handler))
@@ -139,8 +315,28 @@ pub async fn init_app_context(configuration: &Settings) -> AppContext {
         configuration.database
     ));
 
+    if let Err(e) = load_authorized_bots(&pg_pool).await {
+        log::error!("Load the bots failed: {:?}", e);
+    }
+
+    let storage = build_storage(&configuration.storage).expect("Failed to build the object storage.");
+    let search_index = SearchIndex::open(&configuration.search.index_dir, configuration.search.language)
+        .expect("Failed to open the search index.");
+    let mailer = build_mailer(&configuration.mail);
+    let rate_limiter = build_rate_limiter(&configuration.rate_limit).expect("Failed to build the rate limiter.");
+    let authenticator = build_authenticator(&configuration.auth, pg_pool.clone());
     let ws_server = WsServer::new().start();
-    AppContext::new(ws_server, pg_pool)
+    AppContext::new(
+        ws_server,
+        pg_pool,
+        storage,
+        Arc::new(search_index),
+        configuration.oauth.clone(),
+        mailer,
+        authenticator,
+        configuration.metrics.clone(),
+        rate_limiter,
+    )
 }
 
 pub fn identify_service(domain: &str, secret: &str) -> IdentityService<CookieIdentityPolicy> {
@@ -155,8 +351,14 @@ pub fn identify_service(domain: &str, secret: &str) -> IdentityService<CookieIde
 }
 
 pub async fn get_connection_pool(configuration: &DatabaseSettings) -> Result<PgPool, sqlx::Error> {
+    let settings = &configuration.pool;
+    POOL_METRICS.configure(settings);
     PgPoolOptions::new()
-        .connect_timeout(std::time::Duration::from_secs(5))
+        .min_connections(settings.min_connections)
+        .max_connections(settings.max_connections())
+        .connect_timeout(Duration::from_secs(settings.acquire_timeout_secs))
+        .idle_timeout(Duration::from_secs(settings.idle_timeout_secs))
+        .max_lifetime(Duration::from_secs(settings.max_lifetime_secs))
         .connect_with(configuration.with_db())
         .await
 }
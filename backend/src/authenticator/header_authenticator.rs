@@ -0,0 +1,61 @@
+use crate::{
+    authenticator::{AuthFuture, AuthIdentity, AuthRequest, Authenticator},
+    config::HeaderAuthSettings,
+};
+use backend_service::errors::ServerError;
+use flowy_user_infra::parser::UserEmail;
+use std::net::IpAddr;
+
+// The headers that Authelia sets by default
+const DEFAULT_EMAIL_HEADER: &str = "Remote-Email";
+const DEFAULT_NAME_HEADER: &str = "Remote-Name";
+
+// Trusts the user that the reverse proxy put in the headers. The client can set any header, so the
+// request must come from one of the trusted proxies.
+pub struct HeaderAuthenticator {
+    trusted_proxies: Vec<IpAddr>,
+    email_header: String,
+    name_header: String,
+}
+
+impl HeaderAuthenticator {
+    pub fn new(settings: &HeaderAuthSettings) -> Self {
+        Self {
+            trusted_proxies: settings.trusted_proxies.clone(),
+            email_header: settings
+                .email_header
+                .clone()
+                .unwrap_or_else(|| DEFAULT_EMAIL_HEADER.to_owned()),
+            name_header: settings
+                .name_header
+                .clone()
+                .unwrap_or_else(|| DEFAULT_NAME_HEADER.to_owned()),
+        }
+    }
+
+    fn read_headers(&self, request: &AuthRequest) -> Result<AuthIdentity, ServerError> {
+        let is_trusted = request
+            .peer_ip
+            .map(|ip| self.trusted_proxies.contains(&ip))
+            .unwrap_or(false);
+        if !is_trusted {
+            return Err(ServerError::unauthorized().context(format!("{:?} is not a trusted proxy", request.peer_ip)));
+        }
+
+        let email = request
+            .header(&self.email_header)
+            .ok_or_else(|| ServerError::unauthorized().context(format!("Missing the {} header", self.email_header)))?;
+        let email = UserEmail::parse(email.to_owned()).map_err(|e| ServerError::unauthorized().context(e))?.0;
+        let name = match request.header(&self.name_header) {
+            Some(name) => name.to_owned(),
+            None => email.split('@').next().unwrap_or_default().to_owned(),
+        };
+        Ok(AuthIdentity::External { email, name })
+    }
+}
+
+impl Authenticator for HeaderAuthenticator {
+    fn authenticate<'a>(&'a self, request: &'a AuthRequest) -> AuthFuture<'a> {
+        Box::pin(async move { self.read_headers(request) })
+    }
+}
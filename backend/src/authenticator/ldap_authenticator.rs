@@ -0,0 +1,82 @@
+use crate::{
+    authenticator::{AuthFuture, AuthIdentity, AuthRequest, Authenticator},
+    config::LdapSettings,
+};
+use backend_service::errors::{internal_error, ServerError};
+use flowy_user_infra::parser::UserEmail;
+use ldap3::{dn_escape, LdapConnAsync, Scope, SearchEntry};
+
+const DEFAULT_EMAIL_ATTRIBUTE: &str = "mail";
+const DEFAULT_NAME_ATTRIBUTE: &str = "cn";
+
+// Binds as the user with the password, then reads the email and the name from the entry of the user
+pub struct LdapAuthenticator {
+    url: String,
+    user_dn: String,
+    email_attribute: String,
+    name_attribute: String,
+}
+
+impl LdapAuthenticator {
+    pub fn new(settings: &LdapSettings) -> Self {
+        Self {
+            url: settings.url.clone(),
+            user_dn: settings.user_dn.clone(),
+            email_attribute: settings
+                .email_attribute
+                .clone()
+                .unwrap_or_else(|| DEFAULT_EMAIL_ATTRIBUTE.to_owned()),
+            name_attribute: settings
+                .name_attribute
+                .clone()
+                .unwrap_or_else(|| DEFAULT_NAME_ATTRIBUTE.to_owned()),
+        }
+    }
+
+    async fn bind(&self, request: &AuthRequest) -> Result<AuthIdentity, ServerError> {
+        let username = request.email.trim();
+        // The bind with the empty password is anonymous, it always succeeds
+        if username.is_empty() || request.password.is_empty() {
+            return Err(ServerError::password_not_match());
+        }
+
+        let (conn, mut ldap) = LdapConnAsync::new(&self.url).await.map_err(internal_error)?;
+        ldap3::drive!(conn);
+
+        let dn = self.user_dn.replace("{username}", &dn_escape(username));
+        let _ = ldap
+            .simple_bind(&dn, &request.password)
+            .await
+            .map_err(internal_error)?
+            .success()
+            .map_err(|e| ServerError::password_not_match().context(e))?;
+
+        let attributes = vec![self.email_attribute.as_str(), self.name_attribute.as_str()];
+        let (entries, _) = ldap
+            .search(&dn, Scope::Base, "(objectClass=*)", attributes)
+            .await
+            .map_err(internal_error)?
+            .success()
+            .map_err(internal_error)?;
+        let _ = ldap.unbind().await;
+
+        let entry = entries
+            .into_iter()
+            .next()
+            .map(SearchEntry::construct)
+            .ok_or_else(|| ServerError::record_not_found().context(format!("Can't find the entry of {}", dn)))?;
+        let attribute = |name: &str| entry.attrs.get(name).and_then(|values| values.first()).cloned();
+
+        // The username is the email if the entry doesn't have one
+        let email = attribute(&self.email_attribute).unwrap_or_else(|| username.to_owned());
+        let email = UserEmail::parse(email).map_err(|e| ServerError::internal().context(e))?.0;
+        let name = attribute(&self.name_attribute).unwrap_or_else(|| username.to_owned());
+        Ok(AuthIdentity::External { email, name })
+    }
+}
+
+impl Authenticator for LdapAuthenticator {
+    fn authenticate<'a>(&'a self, request: &'a AuthRequest) -> AuthFuture<'a> {
+        Box::pin(async move { self.bind(request).await })
+    }
+}
@@ -0,0 +1,67 @@
+mod header_authenticator;
+mod ldap_authenticator;
+mod password_authenticator;
+
+pub use header_authenticator::*;
+pub use ldap_authenticator::*;
+pub use password_authenticator::*;
+
+use crate::{config::AuthSettings, entities::user::UserTable};
+use backend_service::errors::ServerError;
+use futures::future::BoxFuture;
+use sqlx::PgPool;
+use std::{collections::HashMap, net::IpAddr, sync::Arc};
+
+pub type AuthFuture<'a> = BoxFuture<'a, Result<AuthIdentity, ServerError>>;
+
+// The sign in request. The email is the username of the identity system, e.g. the uid of LDAP. The names
+// of the headers are in lowercase.
+#[derive(Debug, Clone, Default)]
+pub struct AuthRequest {
+    pub email: String,
+    pub password: String,
+    pub headers: HashMap<String, String>,
+    pub peer_ip: Option<IpAddr>,
+}
+
+impl AuthRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_lowercase())
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AuthIdentity {
+    // The user of the built-in password store
+    User(UserTable),
+    // The user verified by the identity system, it's created with the email when it signs in for the
+    // first time
+    External { email: String, name: String },
+}
+
+// Verifies the users that sign in. Only the built-in password store keeps the passwords, the users of
+// the other backends can't sign up or change their passwords here.
+pub trait Authenticator: Send + Sync {
+    fn authenticate<'a>(&'a self, request: &'a AuthRequest) -> AuthFuture<'a>;
+
+    fn manages_passwords(&self) -> bool { false }
+}
+
+pub fn build_authenticator(settings: &AuthSettings, pool: PgPool) -> Arc<dyn Authenticator> {
+    match settings {
+        AuthSettings::Password => Arc::new(PasswordAuthenticator::new(pool)),
+        AuthSettings::Ldap(settings) => Arc::new(LdapAuthenticator::new(settings)),
+        AuthSettings::Header(settings) => Arc::new(HeaderAuthenticator::new(settings)),
+    }
+}
+
+// Returns the permission_denied error if the users are managed by the identity system
+pub(crate) fn check_manages_passwords(authenticator: &Arc<dyn Authenticator>) -> Result<(), ServerError> {
+    if !authenticator.manages_passwords() {
+        return Err(ServerError::permission_denied().context("The users are managed by the identity system"));
+    }
+    Ok(())
+}
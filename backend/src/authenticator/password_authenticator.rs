@@ -0,0 +1,43 @@
+use crate::{
+    authenticator::{AuthFuture, AuthIdentity, AuthRequest, Authenticator},
+    service::user::check_user_password,
+};
+use anyhow::Context;
+use backend_service::errors::{invalid_params, ServerError};
+use flowy_user_infra::parser::{UserEmail, UserPassword};
+use sqlx::PgPool;
+
+// Checks the password with the hash in the user_table
+pub struct PasswordAuthenticator {
+    pool: PgPool,
+}
+
+impl PasswordAuthenticator {
+    pub fn new(pool: PgPool) -> Self { Self { pool } }
+
+    async fn check_password(&self, request: &AuthRequest) -> Result<AuthIdentity, ServerError> {
+        let email = UserEmail::parse(request.email.clone()).map_err(invalid_params)?;
+        let password = UserPassword::parse(request.password.clone()).map_err(invalid_params)?;
+        let mut transaction = self
+            .pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection to sign in")?;
+
+        let user = check_user_password(&mut transaction, email.as_ref(), password.as_ref()).await?;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to sign in.")?;
+
+        Ok(AuthIdentity::User(user))
+    }
+}
+
+impl Authenticator for PasswordAuthenticator {
+    fn authenticate<'a>(&'a self, request: &'a AuthRequest) -> AuthFuture<'a> {
+        Box::pin(async move { self.check_password(request).await })
+    }
+
+    fn manages_passwords(&self) -> bool { true }
+}
@@ -1,11 +1,28 @@
+use backend_service::oauth::OAuthClient;
+use flowy_user_infra::{entities, protobuf::AuthProvider};
 use serde_aux::field_attributes::deserialize_number_from_string;
 use sqlx::postgres::{PgConnectOptions, PgSslMode};
-use std::convert::{TryFrom, TryInto};
+use std::{
+    convert::{TryFrom, TryInto},
+    net::IpAddr,
+};
 
 #[derive(serde::Deserialize, Clone)]
 pub struct Settings {
     pub database: DatabaseSettings,
     pub application: ApplicationSettings,
+    pub storage: StorageSettings,
+    pub search: SearchSettings,
+    #[serde(default)]
+    pub oauth: OAuthSettings,
+    #[serde(default)]
+    pub mail: MailSettings,
+    #[serde(default)]
+    pub auth: AuthSettings,
+    #[serde(default)]
+    pub metrics: MetricsSettings,
+    #[serde(default)]
+    pub rate_limit: RateLimitSettings,
 }
 
 // We are using 127.0.0.1 as our host in address, we are instructing our
@@ -32,6 +49,8 @@ pub struct DatabaseSettings {
     pub host: String,
     pub database_name: String,
     pub require_ssl: bool,
+    #[serde(default)]
+    pub pool: PoolSettings,
 }
 
 impl DatabaseSettings {
@@ -52,6 +71,314 @@ impl DatabaseSettings {
     pub fn with_db(&self) -> PgConnectOptions { self.without_db().database(&self.database_name) }
 }
 
+// The pool opens the connections on demand up to the max_connections, and closes the ones that stay idle
+// for the idle_timeout_secs until there are min_connections left. The max_connections is sized from the
+// cpus of the server if it's not set, e.g.
+// database:
+//   pool:
+//     max_connections: 40
+//     acquire_timeout_secs: 10
+//     slow_transaction_ms: 200
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct PoolSettings {
+    #[serde(default = "default_min_connections")]
+    pub min_connections: u32,
+    #[serde(default)]
+    pub max_connections: Option<u32>,
+    // The request fails if it waits longer than this for a connection
+    #[serde(default = "default_acquire_timeout_secs")]
+    pub acquire_timeout_secs: u64,
+    #[serde(default = "default_idle_timeout_secs")]
+    pub idle_timeout_secs: u64,
+    // The connections are reopened after this, so the memory that Postgres keeps for them is released
+    #[serde(default = "default_max_lifetime_secs")]
+    pub max_lifetime_secs: u64,
+    // The transactions that are held longer than this are logged
+    #[serde(default = "default_slow_transaction_ms")]
+    pub slow_transaction_ms: u64,
+}
+
+impl std::default::Default for PoolSettings {
+    fn default() -> Self {
+        PoolSettings {
+            min_connections: default_min_connections(),
+            max_connections: None,
+            acquire_timeout_secs: default_acquire_timeout_secs(),
+            idle_timeout_secs: default_idle_timeout_secs(),
+            max_lifetime_secs: default_max_lifetime_secs(),
+            slow_transaction_ms: default_slow_transaction_ms(),
+        }
+    }
+}
+
+impl PoolSettings {
+    // The requests mostly wait for Postgres rather than the cpu, so a few connections per cpu keep the
+    // cpus busy without queueing too many queries in Postgres
+    pub fn max_connections(&self) -> u32 {
+        let max_connections = self
+            .max_connections
+            .unwrap_or_else(|| (num_cpus::get() as u32 * 4).max(10));
+        max_connections.max(self.min_connections)
+    }
+}
+
+fn default_min_connections() -> u32 { 1 }
+
+fn default_acquire_timeout_secs() -> u64 { 5 }
+
+fn default_idle_timeout_secs() -> u64 { 10 * 60 }
+
+fn default_max_lifetime_secs() -> u64 { 30 * 60 }
+
+fn default_slow_transaction_ms() -> u64 { 500 }
+
+// Selects where the objects are kept, e.g.
+// storage:
+//   backend: s3
+//   bucket: "flowy"
+//   region: "us-east-1"
+//   endpoint: "http://localhost:9000" # MinIO
+//   path_style: true
+//   access_key: "minioadmin"
+//   secret_key: "minioadmin"
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum StorageSettings {
+    Local { root: String },
+    S3(S3Settings),
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct S3Settings {
+    pub bucket: String,
+    pub region: String,
+    // The endpoint of the S3 compatible store, e.g. MinIO. AWS S3 is used if it's None.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    #[serde(default)]
+    pub path_style: bool,
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+// The search index is kept on the local disk. It's rebuilt from the database if the directory is
+// removed, so it doesn't need to be backed up.
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct SearchSettings {
+    pub index_dir: String,
+    #[serde(default)]
+    pub language: SearchLanguage,
+}
+
+// The language that the text is split into words with. The index is rebuilt when it's changed.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchLanguage {
+    // Splits the words at the whitespaces and the punctuations
+    Default,
+    Chinese,
+    Japanese,
+}
+
+impl std::default::Default for SearchLanguage {
+    fn default() -> Self { SearchLanguage::Default }
+}
+
+// The apps that are registered with the providers, the user can't sign in with the provider that isn't
+// configured, e.g.
+// oauth:
+//   github:
+//     client_id: "..."
+//     client_secret: "..."
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct OAuthSettings {
+    #[serde(default)]
+    pub google: Option<OAuthProviderSettings>,
+    #[serde(default)]
+    pub github: Option<OAuthProviderSettings>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct OAuthProviderSettings {
+    pub client_id: String,
+    pub client_secret: String,
+    // Overrides the endpoints of the provider, e.g. GitHub Enterprise
+    #[serde(default)]
+    pub token_url: Option<String>,
+    #[serde(default)]
+    pub user_info_url: Option<String>,
+    #[serde(default)]
+    pub emails_url: Option<String>,
+}
+
+impl OAuthSettings {
+    pub fn client(&self, provider: AuthProvider) -> Option<OAuthClient> {
+        let (settings, provider) = match provider {
+            AuthProvider::Google => (self.google.as_ref()?, entities::AuthProvider::Google),
+            AuthProvider::GitHub => (self.github.as_ref()?, entities::AuthProvider::GitHub),
+        };
+
+        let mut client = OAuthClient::new(provider, &settings.client_id, &settings.client_secret);
+        if let Some(token_url) = &settings.token_url {
+            client.token_url = token_url.clone();
+        }
+        if let Some(user_info_url) = &settings.user_info_url {
+            client.user_info_url = user_info_url.clone();
+        }
+        if settings.emails_url.is_some() {
+            client.emails_url = settings.emails_url.clone();
+        }
+        Some(client)
+    }
+}
+
+// Selects how the emails are sent, they are only logged by default, e.g.
+// mail:
+//   backend: http
+//   url: "https://api.mailgun.net/v3/example.com/messages"
+//   api_key: "..."
+//   sender: "AppFlowy <noreply@example.com>"
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum MailSettings {
+    Log,
+    Http(HttpMailSettings),
+}
+
+impl std::default::Default for MailSettings {
+    fn default() -> Self { MailSettings::Log }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct HttpMailSettings {
+    pub url: String,
+    pub api_key: String,
+    pub sender: String,
+}
+
+// Selects how the users sign in, the passwords are kept in the database by default, e.g.
+// auth:
+//   backend: ldap
+//   url: "ldaps://ldap.example.com"
+//   user_dn: "uid={username},ou=people,dc=example,dc=com"
+//
+// auth:
+//   backend: header
+//   trusted_proxies: ["127.0.0.1"]
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum AuthSettings {
+    Password,
+    Ldap(LdapSettings),
+    Header(HeaderAuthSettings),
+}
+
+impl std::default::Default for AuthSettings {
+    fn default() -> Self { AuthSettings::Password }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct LdapSettings {
+    pub url: String,
+    // The dn of the user that signs in, the {username} is replaced with the escaped username
+    pub user_dn: String,
+    #[serde(default)]
+    pub email_attribute: Option<String>,
+    #[serde(default)]
+    pub name_attribute: Option<String>,
+}
+
+// The reverse proxy, e.g. Authelia, authenticates the user and puts the user in the headers. The headers
+// of the requests that don't come from the trusted proxies are ignored.
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct HeaderAuthSettings {
+    pub trusted_proxies: Vec<IpAddr>,
+    #[serde(default)]
+    pub email_header: Option<String>,
+    #[serde(default)]
+    pub name_header: Option<String>,
+}
+
+// The metrics are served in the Prometheus text format at /metrics to the scrapers that send the token as
+// the bearer token. The endpoint is disabled if the token isn't set, e.g.
+// metrics:
+//   token: "..."
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct MetricsSettings {
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+// The requests are limited per signed-in user and per ip with the token buckets. Each bucket holds up to
+// the burst requests and is refilled with the requests_per_minute, the limit is disabled if it's null. The
+// buckets are kept in the memory of the server, or in Redis if the servers behind the load balancer share
+// them, e.g.
+// rate_limit:
+//   user:
+//     requests_per_minute: 300
+//     burst: 60
+//   ip: ~
+//   store:
+//     backend: redis
+//     url: "redis://127.0.0.1:6379"
+//   trusted_proxies: ["10.0.0.1"]
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct RateLimitSettings {
+    #[serde(default = "default_user_rate")]
+    pub user: Option<RateSettings>,
+    #[serde(default = "default_ip_rate")]
+    pub ip: Option<RateSettings>,
+    #[serde(default)]
+    pub store: RateLimitStoreSettings,
+    // The ip of the client is read from the Forwarded or the X-Forwarded-For header if the request comes
+    // from one of them, e.g. the CDN or the load balancer. Otherwise the ip of the peer is used.
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl std::default::Default for RateLimitSettings {
+    fn default() -> Self {
+        RateLimitSettings {
+            user: default_user_rate(),
+            ip: default_ip_rate(),
+            store: RateLimitStoreSettings::default(),
+            trusted_proxies: vec![],
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct RateSettings {
+    pub requests_per_minute: u32,
+    pub burst: u32,
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum RateLimitStoreSettings {
+    Memory,
+    Redis { url: String },
+}
+
+impl std::default::Default for RateLimitStoreSettings {
+    fn default() -> Self { RateLimitStoreSettings::Memory }
+}
+
+fn default_user_rate() -> Option<RateSettings> {
+    Some(RateSettings {
+        requests_per_minute: 600,
+        burst: 120,
+    })
+}
+
+// The users behind the same NAT share the ip, so it's allowed more requests than a user
+fn default_ip_rate() -> Option<RateSettings> {
+    Some(RateSettings {
+        requests_per_minute: 1200,
+        burst: 240,
+    })
+}
+
 pub fn get_configuration() -> Result<Settings, config::ConfigError> {
     let mut settings = config::Config::default();
     let base_path = std::env::current_dir().expect("Failed to determine the current directory");
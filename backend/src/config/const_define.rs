@@ -3,5 +3,61 @@ use std::time::Duration;
 pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(8);
 pub const PING_TIMEOUT: Duration = Duration::from_secs(60);
 pub const MAX_PAYLOAD_SIZE: usize = 262_144; // max payload size is 256k
+// The backup keeps the docs of the whole workspace, so it's allowed to be larger than the other payloads
+pub const MAX_BACKUP_SIZE: usize = 64 * 1024 * 1024;
+pub const IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
 
-pub const IGNORE_ROUTES: [&str; 3] = ["/api/register", "/api/auth", "/ws"];
+// The revisions of the doc are saved as deltas, with the full data every DOC_REVISION_CHECKPOINT_INTERVAL
+// revisions. The revisions before the oldest of the latest MAX_DOC_REVISION_CHECKPOINTS checkpoints are deleted.
+pub const DOC_REVISION_CHECKPOINT_INTERVAL: i64 = 100;
+pub const MAX_DOC_REVISION_CHECKPOINTS: i64 = 20;
+
+pub const IGNORE_ROUTES: [&str; 7] = [
+    "/api/register",
+    "/api/auth",
+    "/ws",
+    PUBLISHED_PAGE_ROUTE,
+    PUBLISHED_API_ROUTE,
+    SHARED_PAGE_ROUTE,
+    METRICS_ROUTE,
+];
+
+// The routes that can be requested with a bot token, see BotScope
+pub const BOT_ROUTES: [&str; 4] = ["/api/app", "/api/view", "/api/doc", "/api/automation"];
+pub const BOT_RATE_LIMIT: u32 = 120;
+pub const BOT_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+pub const MAX_API_USAGE_DAYS: i64 = 90;
+pub const API_USAGE_FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+pub const PUBLISHED_PAGE_ROUTE: &str = "/published";
+// The published and the shared pages are served from the api origin and have no scripts, styles or images
+pub const PUBLIC_PAGE_CSP: &str = "default-src 'none'; base-uri 'none'; form-action 'none'; frame-ancestors 'none'";
+pub const PUBLISH_RENDER_DELAY: Duration = Duration::from_secs(3);
+pub const MAX_ACCESS_LOG_DAYS: i64 = 90;
+pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// The published content is served as json to the external renderers without the token, so each client
+// is limited by its ip instead of the user
+pub const PUBLISHED_API_ROUTE: &str = "/public/published";
+pub const PUBLISHED_API_RATE_LIMIT: u32 = 60;
+pub const PUBLISHED_API_RATE_WINDOW: Duration = Duration::from_secs(60);
+// The CDN keeps the content for this long, the changes of the page show up after it expires
+pub const PUBLISHED_API_MAX_AGE: Duration = Duration::from_secs(60);
+
+pub const SHARED_PAGE_ROUTE: &str = "/shared";
+
+// The chunk and the protobuf fields around it must fit in the MAX_PAYLOAD_SIZE
+pub const ATTACHMENT_CHUNK_SIZE: i64 = 131_072;
+pub const ATTACHMENT_GC_INTERVAL: Duration = Duration::from_secs(10 * 60);
+// The attachment that isn't completed in time is collected as garbage
+pub const ATTACHMENT_UPLOAD_EXPIRE: Duration = Duration::from_secs(24 * 60 * 60);
+// The attachment that isn't referenced by any doc is collected as garbage after this, so the image
+// that was deleted by mistake can still be brought back by the undo or a snapshot
+pub const ATTACHMENT_UNREFERENCED_EXPIRE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+// The metrics are scraped with the token of the metrics settings instead of the token of a user
+pub const METRICS_ROUTE: &str = "/metrics";
+
+// The routes whose body can be larger than the MAX_PAYLOAD_SIZE, the body is read with their own limit
+pub const LARGE_PAYLOAD_ROUTES: [(&str, usize); 1] = [("/api/workspace/restore", MAX_BACKUP_SIZE)];
@@ -1,6 +1,14 @@
-use crate::service::{
-    doc::doc::DocBiz,
-    ws::{WsBizHandlers, WsServer},
+use crate::{
+    authenticator::Authenticator,
+    config::{MetricsSettings, OAuthSettings},
+    mailer::Mailer,
+    rate_limit::RateLimiter,
+    service::{
+        doc::doc::DocBiz,
+        search::{SearchIndex, SearchIndexHandle},
+        ws::{WsBizHandlers, WsServer},
+    },
+    storage::ObjectStorage,
 };
 use actix::Addr;
 use actix_web::web::Data;
@@ -14,15 +22,33 @@ pub struct AppContext {
     pub pg_pool: Data<PgPool>,
     pub ws_bizs: Data<WsBizHandlers>,
     pub doc_biz: Data<Arc<DocBiz>>,
+    pub storage: Data<Arc<dyn ObjectStorage>>,
+    pub search: Data<SearchIndexHandle>,
+    pub oauth: Data<OAuthSettings>,
+    pub mailer: Data<Arc<dyn Mailer>>,
+    pub authenticator: Data<Arc<dyn Authenticator>>,
+    pub metrics: Data<MetricsSettings>,
+    pub rate_limiter: Data<Arc<RateLimiter>>,
 }
 
 impl AppContext {
-    pub fn new(ws_server: Addr<WsServer>, db_pool: PgPool) -> Self {
+    pub fn new(
+        ws_server: Addr<WsServer>,
+        db_pool: PgPool,
+        storage: Arc<dyn ObjectStorage>,
+        search_index: Arc<SearchIndex>,
+        oauth: OAuthSettings,
+        mailer: Arc<dyn Mailer>,
+        authenticator: Arc<dyn Authenticator>,
+        metrics: MetricsSettings,
+        rate_limiter: Arc<RateLimiter>,
+    ) -> Self {
         let ws_server = Data::new(ws_server);
         let pg_pool = Data::new(db_pool);
+        let search = SearchIndexHandle::new(search_index, pg_pool.clone());
 
         let mut ws_bizs = WsBizHandlers::new();
-        let doc_biz = Arc::new(DocBiz::new(pg_pool.clone()));
+        let doc_biz = Arc::new(DocBiz::new(pg_pool.clone(), search.clone()));
         ws_bizs.register(WsModule::Doc, doc_biz.clone());
 
         AppContext {
@@ -30,6 +56,13 @@ impl AppContext {
             pg_pool,
             ws_bizs: Data::new(ws_bizs),
             doc_biz: Data::new(doc_biz),
+            storage: Data::new(storage),
+            search: Data::new(search),
+            oauth: Data::new(oauth),
+            mailer: Data::new(mailer),
+            authenticator: Data::new(authenticator),
+            metrics: Data::new(metrics),
+            rate_limiter: Data::new(rate_limiter),
         }
     }
 }
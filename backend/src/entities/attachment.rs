@@ -0,0 +1,52 @@
+use chrono::Utc;
+use flowy_document_infra::{entities::doc::chunk_count, protobuf::Attachment};
+
+pub(crate) const ATTACHMENT_TABLE: &'static str = "attachment_table";
+pub(crate) const ATTACHMENT_REFERENCE_TABLE: &'static str = "attachment_reference_table";
+
+// The attachment is orphaned when its doc is deleted, the chunks of the orphaned attachments are
+// removed from the storage by the garbage collection. The unreferenced_time is when the attachment
+// was created or lost its last reference in the docs, it's None while the attachment is referenced.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AttachmentTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) doc_id: String,
+    pub(crate) user_id: String,
+    pub(crate) name: String,
+    pub(crate) size: i64,
+    pub(crate) chunk_size: i64,
+    pub(crate) uploaded_chunks: i64,
+    pub(crate) is_orphaned: bool,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+    pub(crate) modified_time: chrono::DateTime<Utc>,
+    pub(crate) unreferenced_time: Option<chrono::DateTime<Utc>>,
+}
+
+impl AttachmentTable {
+    pub(crate) fn chunk_count(&self) -> i64 { chunk_count(self.size, self.chunk_size) }
+
+    pub(crate) fn is_completed(&self) -> bool { self.uploaded_chunks >= self.chunk_count() }
+
+    pub(crate) fn chunk_len(&self, index: i64) -> i64 {
+        (self.size - index * self.chunk_size).min(self.chunk_size).max(0)
+    }
+
+    // Each chunk is kept as an object, e.g. "attachments/<doc_id>/<attachment_id>/0"
+    pub(crate) fn chunk_key(&self, index: i64) -> String {
+        format!("attachments/{}/{}/{}", self.doc_id, self.id, index)
+    }
+}
+
+impl std::convert::Into<Attachment> for AttachmentTable {
+    fn into(self) -> Attachment {
+        let mut attachment = Attachment::default();
+        attachment.set_id(self.id.to_string());
+        attachment.set_doc_id(self.doc_id);
+        attachment.set_name(self.name);
+        attachment.set_size(self.size);
+        attachment.set_chunk_size(self.chunk_size);
+        attachment.set_uploaded_chunks(self.uploaded_chunks);
+        attachment.set_create_time(self.create_time.timestamp());
+        attachment
+    }
+}
@@ -0,0 +1,31 @@
+use chrono::Utc;
+use flowy_workspace_infra::protobuf::{Bot, BotScope};
+use protobuf::ProtobufEnum;
+
+pub(crate) const BOT_TABLE: &'static str = "bot_table";
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BotTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) workspace_id: String,
+    pub(crate) owner_id: String,
+    pub(crate) name: String,
+    pub(crate) scope: i32,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+}
+
+impl BotTable {
+    pub(crate) fn scope(&self) -> BotScope { BotScope::from_i32(self.scope).unwrap_or(BotScope::Read) }
+}
+
+impl std::convert::Into<Bot> for BotTable {
+    fn into(self) -> Bot {
+        let mut bot = Bot::default();
+        bot.set_scope(self.scope());
+        bot.set_id(self.id.to_string());
+        bot.set_workspace_id(self.workspace_id);
+        bot.set_name(self.name);
+        bot.set_create_time(self.create_time.timestamp());
+        bot
+    }
+}
@@ -1,4 +1,5 @@
-use flowy_document_infra::protobuf::Doc;
+use chrono::Utc;
+use flowy_document_infra::protobuf::{Doc, DocSnapshot};
 
 pub(crate) const DOC_TABLE: &'static str = "doc_table";
 
@@ -18,3 +19,45 @@ impl std::convert::Into<Doc> for DocTable {
         doc
     }
 }
+
+pub(crate) const DOC_REVISION_TABLE: &'static str = "doc_revision_table";
+
+// The data is the full doc right after the revision was applied if it's a checkpoint, otherwise it's the
+// delta from the previous revision. See read_doc_revision.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DocRevisionTable {
+    pub(crate) doc_id: uuid::Uuid,
+    pub(crate) rev_id: i64,
+    pub(crate) data: String,
+    pub(crate) is_checkpoint: bool,
+    pub(crate) device_id: String,
+    // The author_id is empty if the revision was made by the server, e.g. restoring a snapshot
+    pub(crate) author_id: String,
+    pub(crate) is_bot: bool,
+}
+
+pub(crate) const DOC_SNAPSHOT_TABLE: &'static str = "doc_snapshot_table";
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DocSnapshotTable {
+    pub(crate) id: i64,
+    pub(crate) doc_id: uuid::Uuid,
+    pub(crate) name: String,
+    pub(crate) rev_id: i64,
+    pub(crate) data: String,
+    pub(crate) device_id: String,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+}
+
+impl std::convert::Into<DocSnapshot> for DocSnapshotTable {
+    fn into(self) -> DocSnapshot {
+        let mut snapshot = DocSnapshot::new();
+        snapshot.set_id(self.id);
+        snapshot.set_doc_id(self.doc_id.to_string());
+        snapshot.set_name(self.name);
+        snapshot.set_rev_id(self.rev_id);
+        snapshot.set_create_time(self.create_time.timestamp());
+        snapshot.set_device_id(self.device_id);
+        snapshot
+    }
+}
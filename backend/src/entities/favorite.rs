@@ -0,0 +1,11 @@
+use chrono::Utc;
+
+pub(crate) const FAVORITE_TABLE: &'static str = "favorite_table";
+
+// Each user pins the views to the Favorites section of the sidebar for themselves
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct FavoriteTable {
+    pub(crate) user_id: String,
+    pub(crate) view_id: uuid::Uuid,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+}
@@ -0,0 +1,14 @@
+use chrono::Utc;
+
+pub(crate) const IDEMPOTENCY_TABLE: &'static str = "idempotency_table";
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct IdempotencyTable {
+    pub(crate) user_id: String,
+    pub(crate) route: String,
+    pub(crate) key: String,
+    // The md5 of the params of the request, the key can't be reused by the request with other params
+    pub(crate) request_hash: String,
+    pub(crate) response: Vec<u8>,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+}
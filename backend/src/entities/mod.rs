@@ -1,4 +1,15 @@
+pub mod attachment;
+pub mod bot;
 pub mod doc;
+pub mod favorite;
+pub mod idempotency;
+pub mod publish;
+pub mod recent;
+pub mod search;
+pub mod share;
+pub mod slug;
 pub mod token;
+pub mod usage;
 pub mod user;
+pub mod webhook;
 pub mod workspace;
@@ -0,0 +1,54 @@
+use chrono::{NaiveDate, Utc};
+use flowy_workspace_infra::protobuf::{PublishedView, ViewAccessLog};
+
+pub(crate) const PUBLISHED_VIEW_TABLE: &'static str = "published_view_table";
+pub(crate) const PUBLISHED_VIEW_ACCESS_TABLE: &'static str = "published_view_access_table";
+
+// Each published view has its own page. The parent_view_id of the root view is empty. The slug is the path
+// of the page, it's made of the slugs of the workspace and the views. The rev_id is the revision of the
+// doc that the html was rendered from, the pinned page keeps it when the doc changes.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PublishedViewTable {
+    pub(crate) view_id: uuid::Uuid,
+    pub(crate) root_view_id: uuid::Uuid,
+    pub(crate) parent_view_id: String,
+    pub(crate) workspace_id: String,
+    pub(crate) slug: String,
+    pub(crate) html: String,
+    pub(crate) owner_id: String,
+    pub(crate) publish_time: chrono::DateTime<Utc>,
+    pub(crate) update_time: chrono::DateTime<Utc>,
+    pub(crate) rev_id: i64,
+    pub(crate) is_pinned: bool,
+}
+
+impl std::convert::Into<PublishedView> for PublishedViewTable {
+    fn into(self) -> PublishedView {
+        let mut published_view = PublishedView::default();
+        published_view.set_view_id(self.view_id.to_string());
+        published_view.set_slug(self.slug);
+        published_view.set_publish_time(self.publish_time.timestamp());
+        published_view.set_rev_id(self.rev_id);
+        published_view.set_is_pinned(self.is_pinned);
+        published_view
+    }
+}
+
+// The number of reads of the published page in one day. The readers are anonymous, only the owner_id
+// of the page is kept so the log can be read after the view is unpublished.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PublishedViewAccessTable {
+    pub(crate) access_date: NaiveDate,
+    pub(crate) view_id: uuid::Uuid,
+    pub(crate) owner_id: String,
+    pub(crate) read_count: i64,
+}
+
+impl std::convert::Into<ViewAccessLog> for PublishedViewAccessTable {
+    fn into(self) -> ViewAccessLog {
+        let mut access_log = ViewAccessLog::default();
+        access_log.set_date(self.access_date.format("%Y-%m-%d").to_string());
+        access_log.set_read_count(self.read_count);
+        access_log
+    }
+}
@@ -0,0 +1,11 @@
+use chrono::Utc;
+
+pub(crate) const RECENT_VIEW_TABLE: &'static str = "recent_view_table";
+
+// The latest time that the user opened the view on any of the devices
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RecentViewTable {
+    pub(crate) user_id: String,
+    pub(crate) view_id: uuid::Uuid,
+    pub(crate) open_time: chrono::DateTime<Utc>,
+}
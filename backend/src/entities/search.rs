@@ -0,0 +1,16 @@
+// The view and the text of its doc, the text is empty if the view has no doc
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct IndexedViewTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) name: String,
+    pub(crate) text: String,
+    pub(crate) rev_id: i64,
+}
+
+// Compared with the indexed views to find out the ones that changed since they were indexed
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct IndexedRevTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) name: String,
+    pub(crate) rev_id: i64,
+}
@@ -0,0 +1,38 @@
+use chrono::Utc;
+use flowy_workspace_infra::protobuf::ShareLink;
+
+pub(crate) const SHARE_LINK_TABLE: &'static str = "share_link_table";
+
+// The passcode_hash is empty if the link doesn't require a passcode. The link never expires if the
+// expire_time is None.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ShareLinkTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) view_id: uuid::Uuid,
+    pub(crate) workspace_id: String,
+    pub(crate) owner_id: String,
+    pub(crate) passcode_hash: String,
+    pub(crate) expire_time: Option<chrono::DateTime<Utc>>,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+}
+
+impl ShareLinkTable {
+    pub(crate) fn is_expired(&self) -> bool {
+        match self.expire_time {
+            None => false,
+            Some(expire_time) => expire_time <= Utc::now(),
+        }
+    }
+}
+
+impl std::convert::Into<ShareLink> for ShareLinkTable {
+    fn into(self) -> ShareLink {
+        let mut share_link = ShareLink::default();
+        share_link.set_link_id(self.id.to_string());
+        share_link.set_view_id(self.view_id.to_string());
+        share_link.set_create_time(self.create_time.timestamp());
+        share_link.set_expire_time(self.expire_time.map(|time| time.timestamp()).unwrap_or(0));
+        share_link.set_has_passcode(!self.passcode_hash.is_empty());
+        share_link
+    }
+}
@@ -0,0 +1,15 @@
+use chrono::Utc;
+
+pub(crate) const SLUG_TABLE: &'static str = "slug_table";
+
+// The slugs of the workspaces are unique in the empty scope, the slugs of the views are unique in the
+// workspace that they belong to. The slugs that were replaced after renaming are kept with is_current
+// false, so the old urls can be redirected.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SlugTable {
+    pub(crate) scope_id: String,
+    pub(crate) slug: String,
+    pub(crate) object_id: String,
+    pub(crate) is_current: bool,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+}
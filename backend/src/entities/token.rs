@@ -6,6 +6,14 @@ use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header,
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_ALGORITHM: Algorithm = Algorithm::HS256;
+const BOT_SUBJECT: &str = "bot";
+const BOT_EXPIRED_DURATION_DAYS: i64 = 365;
+const PASSWORD_RESET_SUBJECT: &str = "password_reset";
+const EMAIL_VERIFY_SUBJECT: &str = "email_verify";
+// The access token is short-lived, the client exchanges the refresh token of the session for a new one
+const ACCESS_TOKEN_EXPIRED_DURATION_MINUTES: i64 = 15;
+pub(crate) const PASSWORD_RESET_EXPIRED_DURATION_MINUTES: i64 = 30;
+pub(crate) const EMAIL_VERIFY_EXPIRED_DURATION_HOURS: i64 = 24;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claim {
@@ -28,10 +36,49 @@ impl Claim {
             sub: "auth".to_string(),
             user_id: user_id.to_string(),
             iat: Local::now().timestamp(),
-            exp: (Local::now() + Duration::days(EXPIRED_DURATION_DAYS)).timestamp(),
+            exp: (Local::now() + Duration::minutes(ACCESS_TOKEN_EXPIRED_DURATION_MINUTES)).timestamp(),
         }
     }
 
+    // The bot token is long-lived, it's revoked by deleting the bot.
+    pub fn with_bot_id(bot_id: &str) -> Self {
+        Self {
+            iss: domain(),
+            sub: BOT_SUBJECT.to_string(),
+            user_id: bot_id.to_string(),
+            iat: Local::now().timestamp(),
+            exp: (Local::now() + Duration::days(BOT_EXPIRED_DURATION_DAYS)).timestamp(),
+        }
+    }
+
+    // The user_id is the id of the token in the user_token_table, it can't be used to sign in
+    pub fn with_password_reset_id(token_id: &str) -> Self {
+        Self {
+            iss: domain(),
+            sub: PASSWORD_RESET_SUBJECT.to_string(),
+            user_id: token_id.to_string(),
+            iat: Local::now().timestamp(),
+            exp: (Local::now() + Duration::minutes(PASSWORD_RESET_EXPIRED_DURATION_MINUTES)).timestamp(),
+        }
+    }
+
+    // Same as the password reset token, the user_id is the id of the token in the user_token_table
+    pub fn with_email_verify_id(token_id: &str) -> Self {
+        Self {
+            iss: domain(),
+            sub: EMAIL_VERIFY_SUBJECT.to_string(),
+            user_id: token_id.to_string(),
+            iat: Local::now().timestamp(),
+            exp: (Local::now() + Duration::hours(EMAIL_VERIFY_EXPIRED_DURATION_HOURS)).timestamp(),
+        }
+    }
+
+    pub fn is_bot(&self) -> bool { self.sub == BOT_SUBJECT }
+
+    pub fn is_password_reset(&self) -> bool { self.sub == PASSWORD_RESET_SUBJECT }
+
+    pub fn is_email_verify(&self) -> bool { self.sub == EMAIL_VERIFY_SUBJECT }
+
     pub fn user_id(self) -> String { self.user_id }
 }
 
@@ -44,6 +91,25 @@ pub struct Token(pub String);
 impl Token {
     pub fn create_token(user_id: &str) -> Result<Self, ServerError> {
         let claims = Claim::with_user_id(&user_id);
+        Self::encode_claim(claims)
+    }
+
+    pub fn create_bot_token(bot_id: &str) -> Result<Self, ServerError> {
+        let claims = Claim::with_bot_id(bot_id);
+        Self::encode_claim(claims)
+    }
+
+    pub fn create_password_reset_token(token_id: &str) -> Result<Self, ServerError> {
+        let claims = Claim::with_password_reset_id(token_id);
+        Self::encode_claim(claims)
+    }
+
+    pub fn create_email_verify_token(token_id: &str) -> Result<Self, ServerError> {
+        let claims = Claim::with_email_verify_id(token_id);
+        Self::encode_claim(claims)
+    }
+
+    fn encode_claim(claims: Claim) -> Result<Self, ServerError> {
         encode(
             &Header::new(DEFAULT_ALGORITHM),
             &claims,
@@ -74,7 +140,6 @@ impl Token {
     }
 }
 
-use crate::service::user::EXPIRED_DURATION_DAYS;
 use actix_web::{dev::Payload, FromRequest, HttpRequest};
 use backend_service::config::HEADER_TOKEN;
 use futures::future::{ready, Ready};
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use flowy_workspace_infra::protobuf::ApiUsage;
+
+pub(crate) const API_USAGE_TABLE: &'static str = "api_usage_table";
+
+// The bot_id is empty for the calls that were sent with the token of the user. The workspace_id column
+// is the workspace of the bot, it's only used to find the usage of the bots of a workspace.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ApiUsageTable {
+    pub(crate) usage_date: NaiveDate,
+    pub(crate) user_id: String,
+    pub(crate) bot_id: String,
+    pub(crate) call_count: i64,
+    pub(crate) request_bytes: i64,
+    pub(crate) response_bytes: i64,
+}
+
+impl std::convert::Into<ApiUsage> for ApiUsageTable {
+    fn into(self) -> ApiUsage {
+        let mut usage = ApiUsage::default();
+        usage.set_date(self.usage_date.format("%Y-%m-%d").to_string());
+        usage.set_user_id(self.user_id);
+        usage.set_bot_id(self.bot_id);
+        usage.set_call_count(self.call_count);
+        usage.set_request_bytes(self.request_bytes);
+        usage.set_response_bytes(self.response_bytes);
+        usage
+    }
+}
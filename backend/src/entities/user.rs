@@ -9,4 +9,50 @@ pub struct UserTable {
     pub(crate) name: String,
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) password: String,
+    // It's reset when the email is changed, the user verifies it again with the token that is sent to it
+    pub(crate) email_verified: bool,
+}
+
+pub(crate) const USER_IDENTITY_TABLE: &'static str = "user_identity_table";
+
+// The account of the third-party provider that the user signs in with, the subject is the id of the
+// account at the provider.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserIdentityTable {
+    pub(crate) provider: String,
+    pub(crate) subject: String,
+    pub(crate) user_id: uuid::Uuid,
+    pub(crate) email: String,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+}
+
+pub(crate) const USER_TOKEN_TABLE: &'static str = "user_token_table";
+
+// The single-use token that is sent to the user by email, e.g. to reset the password or verify the email. The
+// signed token carries the id, it's rejected after it's used or expired even though the signature is still valid.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserTokenTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) user_id: uuid::Uuid,
+    pub(crate) purpose: String,
+    pub(crate) is_used: bool,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+    pub(crate) expire_time: chrono::DateTime<Utc>,
+}
+
+pub(crate) const USER_SESSION_TABLE: &'static str = "user_session_table";
+
+// The session is started when the user signs in, the refresh token of the session is exchanged for the
+// short-lived access token. Only the hash of the refresh token is saved, it's replaced every time the
+// token is refreshed.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserSessionTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) user_id: uuid::Uuid,
+    pub(crate) refresh_token_hash: String,
+    pub(crate) previous_token_hash: String,
+    pub(crate) is_revoked: bool,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+    pub(crate) refresh_time: chrono::DateTime<Utc>,
+    pub(crate) expire_time: chrono::DateTime<Utc>,
 }
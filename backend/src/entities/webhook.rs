@@ -0,0 +1,32 @@
+use chrono::Utc;
+use flowy_workspace_infra::protobuf::{ViewWebhook, WebhookFormat};
+use protobuf::ProtobufEnum;
+
+pub(crate) const VIEW_WEBHOOK_TABLE: &'static str = "view_webhook_table";
+
+// Each view has at most one webhook. The last_delivery_time is None until the doc is delivered.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ViewWebhookTable {
+    pub(crate) view_id: uuid::Uuid,
+    pub(crate) owner_id: String,
+    pub(crate) url: String,
+    pub(crate) format: i32,
+    pub(crate) secret: String,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+    pub(crate) last_delivery_time: Option<chrono::DateTime<Utc>>,
+    pub(crate) last_status: i32,
+}
+
+impl std::convert::Into<ViewWebhook> for ViewWebhookTable {
+    fn into(self) -> ViewWebhook {
+        let mut webhook = ViewWebhook::default();
+        webhook.set_view_id(self.view_id.to_string());
+        webhook.set_url(self.url);
+        webhook.set_format(WebhookFormat::from_i32(self.format).unwrap_or(WebhookFormat::Markdown));
+        webhook.set_has_secret(!self.secret.is_empty());
+        webhook.set_create_time(self.create_time.timestamp());
+        webhook.set_last_delivery_time(self.last_delivery_time.map(|time| time.timestamp()).unwrap_or(0));
+        webhook.set_last_status(self.last_status);
+        webhook
+    }
+}
@@ -1,11 +1,23 @@
 use chrono::Utc;
-use flowy_workspace_infra::protobuf::{App, RepeatedView, Trash, TrashType, View, ViewType, Workspace};
+use flowy_workspace_infra::protobuf::{
+    App,
+    RepeatedView,
+    Trash,
+    TrashType,
+    View,
+    ViewType,
+    Workspace,
+    WorkspaceCloneProgress,
+    WorkspaceCloneState,
+};
 use protobuf::ProtobufEnum;
 
 pub(crate) const WORKSPACE_TABLE: &'static str = "workspace_table";
 pub(crate) const APP_TABLE: &'static str = "app_table";
 pub(crate) const VIEW_TABLE: &'static str = "view_table";
 pub(crate) const TRASH_TABLE: &'static str = "trash_table";
+pub(crate) const WORKSPACE_MEMBER_TABLE: &'static str = "workspace_member_table";
+pub(crate) const WORKSPACE_CLONE_TABLE: &'static str = "workspace_clone_table";
 
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct WorkspaceTable {
@@ -15,6 +27,7 @@ pub struct WorkspaceTable {
     pub(crate) modified_time: chrono::DateTime<Utc>,
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) user_id: String,
+    pub(crate) archived: bool,
 }
 
 impl std::convert::Into<Workspace> for WorkspaceTable {
@@ -25,10 +38,25 @@ impl std::convert::Into<Workspace> for WorkspaceTable {
         workspace.set_desc(self.description.clone());
         workspace.set_modified_time(self.modified_time.timestamp());
         workspace.set_create_time(self.create_time.timestamp());
+        workspace.set_archived(self.archived);
         workspace
     }
 }
 
+impl std::convert::Into<Trash> for WorkspaceTable {
+    fn into(self) -> Trash {
+        Trash {
+            id: self.id.to_string(),
+            name: self.name,
+            modified_time: self.modified_time.timestamp(),
+            create_time: self.create_time.timestamp(),
+            ty: TrashType::Workspace,
+            unknown_fields: Default::default(),
+            cached_size: Default::default(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct AppTable {
     pub(crate) id: uuid::Uuid,
@@ -40,6 +68,8 @@ pub struct AppTable {
     pub(crate) modified_time: chrono::DateTime<Utc>,
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) user_id: String,
+    pub(crate) rank: String,
+    pub(crate) modified_device_id: String,
 }
 
 impl std::convert::Into<App> for AppTable {
@@ -52,6 +82,8 @@ impl std::convert::Into<App> for AppTable {
         app.set_belongings(RepeatedView::default());
         app.set_modified_time(self.modified_time.timestamp());
         app.set_create_time(self.create_time.timestamp());
+        app.set_rank(self.rank);
+        app.set_modified_device_id(self.modified_device_id);
 
         app
     }
@@ -81,6 +113,8 @@ pub struct ViewTable {
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) thumbnail: String,
     pub(crate) view_type: i32,
+    pub(crate) rank: String,
+    pub(crate) modified_device_id: String,
 }
 
 impl std::convert::Into<View> for ViewTable {
@@ -96,6 +130,8 @@ impl std::convert::Into<View> for ViewTable {
         view.set_belongings(RepeatedView::default());
         view.set_create_time(self.create_time.timestamp());
         view.set_modified_time(self.modified_time.timestamp());
+        view.set_rank(self.rank);
+        view.set_modified_device_id(self.modified_device_id);
 
         view
     }
@@ -115,9 +151,119 @@ impl std::convert::Into<Trash> for ViewTable {
     }
 }
 
+// The row of the app joined with one of its views, the view columns are null if the app has no views.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AppViewRow {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) workspace_id: String,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) color_style: Vec<u8>,
+    pub(crate) last_view_id: String,
+    pub(crate) modified_time: chrono::DateTime<Utc>,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+    pub(crate) user_id: String,
+    pub(crate) rank: String,
+    pub(crate) modified_device_id: String,
+    pub(crate) in_trash: bool,
+    pub(crate) view_id: Option<uuid::Uuid>,
+    pub(crate) view_belong_to_id: Option<String>,
+    pub(crate) view_name: Option<String>,
+    pub(crate) view_description: Option<String>,
+    pub(crate) view_modified_time: Option<chrono::DateTime<Utc>>,
+    pub(crate) view_create_time: Option<chrono::DateTime<Utc>>,
+    pub(crate) view_thumbnail: Option<String>,
+    pub(crate) view_type: Option<i32>,
+    pub(crate) view_rank: Option<String>,
+    pub(crate) view_modified_device_id: Option<String>,
+}
+
+impl AppViewRow {
+    pub(crate) const VIEW_FIELDS: [&'static str; 10] = [
+        "view_table.id AS view_id",
+        "view_table.belong_to_id AS view_belong_to_id",
+        "view_table.name AS view_name",
+        "view_table.description AS view_description",
+        "view_table.modified_time AS view_modified_time",
+        "view_table.create_time AS view_create_time",
+        "view_table.thumbnail AS view_thumbnail",
+        "view_table.view_type AS view_type",
+        "view_table.rank AS view_rank",
+        "view_table.modified_device_id AS view_modified_device_id",
+    ];
+
+    pub(crate) fn view_table(&self) -> Option<ViewTable> {
+        Some(ViewTable {
+            id: self.view_id?,
+            belong_to_id: self.view_belong_to_id.clone()?,
+            name: self.view_name.clone()?,
+            description: self.view_description.clone()?,
+            modified_time: self.view_modified_time?,
+            create_time: self.view_create_time?,
+            thumbnail: self.view_thumbnail.clone()?,
+            view_type: self.view_type?,
+            rank: self.view_rank.clone()?,
+            modified_device_id: self.view_modified_device_id.clone()?,
+        })
+    }
+
+    pub(crate) fn app_table(self) -> AppTable {
+        AppTable {
+            id: self.id,
+            workspace_id: self.workspace_id,
+            name: self.name,
+            description: self.description,
+            color_style: self.color_style,
+            last_view_id: self.last_view_id,
+            modified_time: self.modified_time,
+            create_time: self.create_time,
+            user_id: self.user_id,
+            rank: self.rank,
+            modified_device_id: self.modified_device_id,
+        }
+    }
+}
+
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct TrashTable {
     pub(crate) id: uuid::Uuid,
     pub(crate) user_id: String,
     pub(crate) ty: i32,
 }
+
+// The member joined with the user that it refers to. The owner of the workspace isn't in the member
+// table, it's the user_id of the workspace table.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WorkspaceMemberRow {
+    pub(crate) user_id: String,
+    pub(crate) role: i32,
+    pub(crate) email: String,
+    pub(crate) name: String,
+}
+
+// The copy is created in the same transaction as its apps and views, so its workspace_id is empty until
+// the clone is completed.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WorkspaceCloneTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) user_id: String,
+    pub(crate) source_workspace_id: String,
+    pub(crate) workspace_id: String,
+    pub(crate) total: i64,
+    pub(crate) finished: i64,
+    pub(crate) state: i32,
+    pub(crate) create_time: chrono::DateTime<Utc>,
+}
+
+impl std::convert::Into<WorkspaceCloneProgress> for WorkspaceCloneTable {
+    fn into(self) -> WorkspaceCloneProgress {
+        let mut progress = WorkspaceCloneProgress::default();
+        progress.set_clone_id(self.id.to_string());
+        progress.set_source_workspace_id(self.source_workspace_id);
+        progress.set_workspace_id(self.workspace_id);
+        progress.set_total(self.total);
+        progress.set_finished(self.finished);
+        progress.set_state(WorkspaceCloneState::from_i32(self.state).unwrap_or(WorkspaceCloneState::Failed));
+        progress
+    }
+}
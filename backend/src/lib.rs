@@ -1,7 +1,11 @@
 pub mod application;
+pub mod authenticator;
 pub mod config;
 pub mod context;
 mod entities;
+pub mod mailer;
 mod middleware;
+pub mod rate_limit;
 pub mod service;
 mod sqlx_ext;
+pub mod storage;
@@ -0,0 +1,60 @@
+use crate::{
+    config::HttpMailSettings,
+    mailer::{Email, MailFuture, Mailer},
+};
+use backend_service::errors::ServerError;
+use reqwest::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    Client,
+};
+use std::time::Duration;
+
+// Sends the emails with the HTTP API of the mail service. The email is posted as the json with the
+// api key in the bearer token.
+pub struct HttpMailer {
+    client: Client,
+    url: String,
+    api_key: String,
+    sender: String,
+}
+
+impl HttpMailer {
+    pub fn new(settings: &HttpMailSettings) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_else(|_| Client::new());
+        Self {
+            client,
+            url: settings.url.clone(),
+            api_key: settings.api_key.clone(),
+            sender: settings.sender.clone(),
+        }
+    }
+}
+
+impl Mailer for HttpMailer {
+    fn send(&self, email: Email) -> MailFuture<'_> {
+        Box::pin(async move {
+            let body = serde_json::json!({
+                "from": self.sender,
+                "to": email.to,
+                "subject": email.subject,
+                "text": email.body,
+            });
+            let response = self
+                .client
+                .post(&self.url)
+                .header(AUTHORIZATION, format!("Bearer {}", self.api_key))
+                .header(CONTENT_TYPE, "application/json")
+                .body(body.to_string())
+                .send()
+                .await?;
+
+            if !response.status().is_success() {
+                return Err(ServerError::internal().context(format!("Send email failed: {}", response.status())));
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,11 @@
+use crate::mailer::{Email, MailFuture, Mailer};
+
+// Writes the emails to the log instead of sending them, it's used in the local development
+pub struct LogMailer {}
+
+impl Mailer for LogMailer {
+    fn send(&self, email: Email) -> MailFuture<'_> {
+        log::info!("Send email to {}: {}\n{}", email.to, email.subject, email.body);
+        Box::pin(async { Ok(()) })
+    }
+}
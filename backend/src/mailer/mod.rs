@@ -0,0 +1,31 @@
+mod http_mailer;
+mod log_mailer;
+
+pub use http_mailer::*;
+pub use log_mailer::*;
+
+use crate::config::MailSettings;
+use backend_service::errors::ServerError;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+pub type MailFuture<'a> = BoxFuture<'a, Result<(), ServerError>>;
+
+#[derive(Debug, Clone)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+// Sends the emails to the users, e.g. the password reset token. The body is the plain text.
+pub trait Mailer: Send + Sync {
+    fn send(&self, email: Email) -> MailFuture<'_>;
+}
+
+pub fn build_mailer(settings: &MailSettings) -> Arc<dyn Mailer> {
+    match settings {
+        MailSettings::Log => Arc::new(LogMailer {}),
+        MailSettings::Http(settings) => Arc::new(HttpMailer::new(settings)),
+    }
+}
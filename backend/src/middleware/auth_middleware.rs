@@ -1,14 +1,21 @@
-use crate::service::user::{LoggedUser, AUTHORIZED_USERS};
+use crate::service::{
+    bot::AUTHORIZED_BOTS,
+    usage::{ApiCaller, API_USAGE},
+    user::{LoggedUser, AUTHORIZED_USERS},
+};
 use actix_service::{Service, Transform};
 use actix_web::{
     dev::{ServiceRequest, ServiceResponse},
     Error,
-    HttpResponse,
     ResponseError,
 };
 
 use crate::config::IGNORE_ROUTES;
-use actix_web::{body::AnyBody, dev::MessageBody};
+use actix_web::{
+    body::{AnyBody, BodySize},
+    dev::MessageBody,
+    http::header::CONTENT_LENGTH,
+};
 use backend_service::{config::HEADER_TOKEN, errors::ServerError};
 use futures::future::{ok, LocalBoxFuture, Ready};
 use std::{
@@ -53,6 +60,8 @@ where
 
     fn call(&self, req: ServiceRequest) -> Self::Future {
         let mut authenticate_pass: bool = false;
+        let mut rejection = ServerError::unauthorized();
+        let mut caller: Option<ApiCaller> = None;
         for ignore_route in IGNORE_ROUTES.iter() {
             // tracing::info!("ignore: {}, path: {}", ignore_route, req.path());
             if req.path().starts_with(ignore_route) {
@@ -65,7 +74,19 @@ where
             if let Some(header) = req.headers().get(HEADER_TOKEN) {
                 let result: Result<LoggedUser, ServerError> = header.try_into();
                 match result {
+                    Ok(logged_user) if logged_user.is_bot => {
+                        // The bot is never stored in AUTHORIZED_USERS, its token is checked on its own
+                        let result = AUTHORIZED_BOTS.check(&logged_user.user_id, req.method(), req.path());
+                        match result {
+                            Ok(_) => {
+                                authenticate_pass = true;
+                                caller = AUTHORIZED_BOTS.caller(&logged_user.user_id);
+                            },
+                            Err(e) => rejection = e,
+                        }
+                    },
                     Ok(logged_user) => {
+                        let user_caller = ApiCaller::user(&logged_user.user_id);
                         if cfg!(feature = "ignore_auth") {
                             authenticate_pass = true;
                             AUTHORIZED_USERS.store_auth(logged_user, true);
@@ -75,6 +96,10 @@ where
                                 AUTHORIZED_USERS.store_auth(logged_user, true);
                             }
                         }
+
+                        if authenticate_pass {
+                            caller = Some(user_caller);
+                        }
                     },
                     Err(e) => log::error!("{:?}", e),
                 }
@@ -84,18 +109,27 @@ where
         }
 
         if authenticate_pass {
+            let request_bytes = req
+                .headers()
+                .get(CONTENT_LENGTH)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(0);
             let fut = self.service.call(req);
             return Box::pin(async move {
                 let res = fut.await?;
+                // The streaming response is counted as a call without the bytes
+                if let Some(caller) = caller {
+                    let response_bytes = match res.response().body().size() {
+                        BodySize::Sized(size) => size,
+                        _ => 0,
+                    };
+                    API_USAGE.record(caller, request_bytes, response_bytes);
+                }
                 Ok(res.map_body(|_, body| AnyBody::from_message(body)))
             });
         } else {
-            Box::pin(async move { Ok(req.into_response(unauthorized_response())) })
+            Box::pin(async move { Ok(req.into_response(rejection.error_response())) })
         }
     }
 }
-
-fn unauthorized_response() -> HttpResponse {
-    let error = ServerError::unauthorized();
-    error.error_response()
-}
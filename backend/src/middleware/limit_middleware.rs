@@ -0,0 +1,124 @@
+use crate::{
+    config::{LARGE_PAYLOAD_ROUTES, MAX_PAYLOAD_SIZE},
+    rate_limit::RateLimiter,
+    service::user::LoggedUser,
+};
+use actix_service::{Service, Transform};
+use actix_web::{
+    body::AnyBody,
+    dev::{MessageBody, ServiceRequest, ServiceResponse},
+    http::{
+        header::{CONTENT_LENGTH, RETRY_AFTER},
+        HeaderValue,
+    },
+    Error,
+    ResponseError,
+};
+use backend_service::{config::HEADER_TOKEN, errors::ServerError};
+use futures::future::{ok, LocalBoxFuture, Ready};
+use std::{
+    convert::TryInto,
+    error::Error as StdError,
+    rc::Rc,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+// Rejects the requests of the users and the ips that are over their rate limits, and the requests whose
+// body is larger than the payload limit of the route, before the body is read.
+pub struct RequestLimitService {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RequestLimitService {
+    pub fn new(limiter: Arc<RateLimiter>) -> Self { Self { limiter } }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequestLimitService
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Transform = RequestLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(RequestLimitMiddleware {
+            service: Rc::new(service),
+            limiter: self.limiter.clone(),
+        })
+    }
+}
+
+pub struct RequestLimitMiddleware<S> {
+    service: Rc<S>,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> { self.service.poll_ready(cx) }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let limiter = self.limiter.clone();
+        Box::pin(async move {
+            if let Err(e) = check_payload_size(&req) {
+                return Ok(req.into_response(e.error_response()));
+            }
+
+            // The token is only read to find the user, the AuthenticationService checks it
+            let user_id = req.headers().get(HEADER_TOKEN).and_then(|header| {
+                let result: Result<LoggedUser, ServerError> = header.try_into();
+                result.ok().map(|logged_user| logged_user.user_id)
+            });
+            let ip = limiter.client_ip(req.peer_addr(), &req.connection_info());
+            if let Some(retry_after) = limiter.check(user_id.as_deref(), &ip).await {
+                let mut response = ServerError::too_many_requests()
+                    .context(format!("Retry after {:?}", retry_after))
+                    .error_response();
+                // Rounded up, the client that retries right after the seconds is let through
+                let retry_after_secs = retry_after.as_secs() + 1;
+                response
+                    .headers_mut()
+                    .insert(RETRY_AFTER, HeaderValue::from(retry_after_secs));
+                return Ok(req.into_response(response));
+            }
+
+            let res = service.call(req).await?;
+            Ok(res.map_body(|_, body| AnyBody::from_message(body)))
+        })
+    }
+}
+
+// The body that's sent without the content length is still limited when it's read, see poll_payload
+fn check_payload_size(req: &ServiceRequest) -> Result<(), ServerError> {
+    let content_length = req
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let max_size = LARGE_PAYLOAD_ROUTES
+        .iter()
+        .find(|(route, _)| req.path() == *route)
+        .map_or(MAX_PAYLOAD_SIZE, |(_, max_size)| *max_size);
+    if content_length > max_size {
+        return Err(ServerError::payload_overflow().context(format!("The payload is larger than {}", max_size)));
+    }
+    Ok(())
+}
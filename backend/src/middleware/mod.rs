@@ -1,5 +1,9 @@
 mod auth_middleware;
 mod cors_middleware;
+mod limit_middleware;
+mod request_id_middleware;
 
 pub use auth_middleware::*;
 pub use cors_middleware::*;
+pub use limit_middleware::*;
+pub use request_id_middleware::*;
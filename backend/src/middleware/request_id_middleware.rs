@@ -0,0 +1,74 @@
+use actix_service::{Service, Transform};
+use actix_web::{
+    dev::{ServiceRequest, ServiceResponse},
+    http::{HeaderName, HeaderValue},
+    Error,
+};
+use backend_service::config::HEADER_REQUEST_ID;
+use futures::future::{ok, LocalBoxFuture, Ready};
+use std::task::{Context, Poll};
+use tracing::Instrument;
+
+// Uses the request id that the client sends, or generates one if it's missing. The id is logged with
+// every line of the request and returned in the request-id header of the response.
+pub struct RequestIdService;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdService
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestIdMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future { ok(RequestIdMiddleware { service }) }
+}
+
+pub struct RequestIdMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> { self.service.poll_ready(cx) }
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let request_id = req
+            .headers()
+            .get(HEADER_REQUEST_ID)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| !value.is_empty())
+            .map(|value| value.to_owned())
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        let header_value = HeaderValue::from_str(&request_id).ok();
+        if let Some(value) = header_value.clone() {
+            req.headers_mut().insert(HeaderName::from_static(HEADER_REQUEST_ID), value);
+        }
+
+        let span = tracing::info_span!("request", request_id = %request_id, method = %req.method(), path = %req.path());
+        let fut = self.service.call(req);
+        Box::pin(
+            async move {
+                let mut res = fut.await?;
+                if let Some(value) = header_value {
+                    res.headers_mut().insert(HeaderName::from_static(HEADER_REQUEST_ID), value);
+                }
+                Ok(res)
+            }
+            .instrument(span),
+        )
+    }
+}
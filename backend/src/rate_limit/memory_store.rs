@@ -0,0 +1,62 @@
+use crate::{
+    config::RateSettings,
+    rate_limit::{requests_per_second, RateLimitFuture, RateLimitStore},
+};
+use dashmap::DashMap;
+use futures::FutureExt;
+use std::time::{Duration, Instant};
+
+// The buckets that are full again are forgotten once this many buckets are kept
+const MAX_KEPT_BUCKETS: usize = 100_000;
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+    // The bucket is the same as the new one after this
+    full_at: Instant,
+}
+
+// The buckets are only seen by this server, so each server behind the load balancer has its own limits
+#[derive(Default)]
+pub struct MemoryStore {
+    buckets: DashMap<String, Bucket>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self { Self::default() }
+
+    fn take_token(&self, key: &str, rate: &RateSettings) -> Option<Duration> {
+        let now = Instant::now();
+        if self.buckets.len() > MAX_KEPT_BUCKETS {
+            self.buckets.retain(|_, bucket| bucket.full_at > now);
+        }
+
+        let burst = rate.burst as f64;
+        let per_second = requests_per_second(rate);
+        let mut bucket = self.buckets.entry(key.to_owned()).or_insert_with(|| Bucket {
+            tokens: burst,
+            updated: now,
+            full_at: now,
+        });
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * per_second).min(burst);
+        bucket.updated = now;
+
+        let retry_after = match bucket.tokens >= 1.0 {
+            true => {
+                bucket.tokens -= 1.0;
+                None
+            },
+            false => Some(Duration::from_secs_f64((1.0 - bucket.tokens) / per_second)),
+        };
+        bucket.full_at = now + Duration::from_secs_f64((burst - bucket.tokens).max(0.0) / per_second);
+        retry_after
+    }
+}
+
+impl RateLimitStore for MemoryStore {
+    fn take<'a>(&'a self, key: &'a str, rate: &'a RateSettings) -> RateLimitFuture<'a> {
+        let retry_after = self.take_token(key, rate);
+        async move { Ok(retry_after) }.boxed()
+    }
+}
@@ -0,0 +1,67 @@
+mod memory_store;
+mod redis_store;
+
+pub use memory_store::*;
+pub use redis_store::*;
+
+use crate::config::{RateLimitSettings, RateLimitStoreSettings, RateSettings};
+use backend_service::errors::ServerError;
+use futures::future::BoxFuture;
+use crate::service::util::client_ip;
+use actix_web::dev::ConnectionInfo;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+// Resolves to how long to wait before the next request if the bucket is empty
+pub type RateLimitFuture<'a> = BoxFuture<'a, Result<Option<Duration>, ServerError>>;
+
+// Keeps the token buckets, one per key. A request takes one token from the bucket of its key.
+pub trait RateLimitStore: Send + Sync {
+    fn take<'a>(&'a self, key: &'a str, rate: &'a RateSettings) -> RateLimitFuture<'a>;
+}
+
+pub struct RateLimiter {
+    settings: RateLimitSettings,
+    store: Arc<dyn RateLimitStore>,
+}
+
+impl RateLimiter {
+    // The ip that the requests are limited by, the headers are only read from the trusted proxies
+    pub fn client_ip(&self, peer_addr: Option<SocketAddr>, connection_info: &ConnectionInfo) -> String {
+        client_ip(peer_addr, connection_info, &self.settings.trusted_proxies)
+    }
+
+    // Returns how long to wait if the user or the ip is over its limit. The request is let through if the
+    // store fails, e.g. Redis is down, so the limits never take the server down with them.
+    pub async fn check(&self, user_id: Option<&str>, ip: &str) -> Option<Duration> {
+        let mut buckets = vec![];
+        if let (Some(user_id), Some(rate)) = (user_id, &self.settings.user) {
+            buckets.push((format!("user:{}", user_id), rate));
+        }
+        if let Some(rate) = &self.settings.ip {
+            buckets.push((format!("ip:{}", ip), rate));
+        }
+
+        for (key, rate) in buckets {
+            match self.store.take(&key, rate).await {
+                Ok(None) => {},
+                Ok(Some(retry_after)) => return Some(retry_after),
+                Err(e) => log::error!("Check the rate limit of {} failed: {:?}", key, e),
+            }
+        }
+        None
+    }
+}
+
+pub fn build_rate_limiter(settings: &RateLimitSettings) -> Result<Arc<RateLimiter>, ServerError> {
+    let store: Arc<dyn RateLimitStore> = match &settings.store {
+        RateLimitStoreSettings::Memory => Arc::new(MemoryStore::new()),
+        RateLimitStoreSettings::Redis { url } => Arc::new(RedisStore::new(url)?),
+    };
+    Ok(Arc::new(RateLimiter {
+        settings: settings.clone(),
+        store,
+    }))
+}
+
+// The bucket that's refilled with no requests would never let a request through again
+fn requests_per_second(rate: &RateSettings) -> f64 { rate.requests_per_minute.max(1) as f64 / 60.0 }
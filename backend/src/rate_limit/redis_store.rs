@@ -0,0 +1,78 @@
+use crate::{
+    config::RateSettings,
+    rate_limit::{RateLimitFuture, RateLimitStore},
+};
+use backend_service::errors::{internal_error, ServerError};
+use futures::FutureExt;
+use redis::{aio::ConnectionManager, Client, Script};
+use std::time::Duration;
+use tokio::sync::OnceCell;
+
+// The bucket is read and updated in one script, so the servers that share it never take the same token.
+// The time of Redis is used instead of the time of each server. The bucket expires once it's full again.
+// Redis before 5 only writes after reading the time if the commands are replicated instead of the script.
+const TAKE_TOKEN_SCRIPT: &str = r#"
+redis.replicate_commands()
+local burst = tonumber(ARGV[1])
+local per_ms = tonumber(ARGV[2]) / 60000
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'updated')
+local tokens = tonumber(bucket[1]) or burst
+local updated = tonumber(bucket[2]) or now
+tokens = math.min(burst, tokens + math.max(0, now - updated) * per_ms)
+local wait = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+else
+    wait = math.ceil((1 - tokens) / per_ms)
+end
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'updated', now)
+redis.call('PEXPIRE', KEYS[1], math.ceil((burst - tokens) / per_ms) + 1)
+return wait
+"#;
+
+// The buckets are shared by the servers behind the load balancer
+pub struct RedisStore {
+    client: Client,
+    // Connects on the first request, and reconnects by itself after Redis restarts
+    connection: OnceCell<ConnectionManager>,
+    script: Script,
+}
+
+impl RedisStore {
+    pub fn new(url: &str) -> Result<Self, ServerError> {
+        let client = Client::open(url).map_err(internal_error)?;
+        Ok(Self {
+            client,
+            connection: OnceCell::new(),
+            script: Script::new(TAKE_TOKEN_SCRIPT),
+        })
+    }
+}
+
+impl RateLimitStore for RedisStore {
+    fn take<'a>(&'a self, key: &'a str, rate: &'a RateSettings) -> RateLimitFuture<'a> {
+        async move {
+            let mut connection = self
+                .connection
+                .get_or_try_init(|| ConnectionManager::new(self.client.clone()))
+                .await
+                .map_err(internal_error)?
+                .clone();
+            let wait_millis: u64 = self
+                .script
+                .key(format!("rate_limit:{}", key))
+                .arg(rate.burst)
+                .arg(rate.requests_per_minute.max(1))
+                .invoke_async(&mut connection)
+                .await
+                .map_err(internal_error)?;
+            match wait_millis {
+                0 => Ok(None),
+                wait_millis => Ok(Some(Duration::from_millis(wait_millis))),
+            }
+        }
+        .boxed()
+    }
+}
@@ -1,20 +1,29 @@
 use crate::{
-    entities::workspace::{AppTable, APP_TABLE},
-    service::{app::sql_builder::*, user::LoggedUser, view::read_view_belong_to_id},
+    entities::workspace::{AppTable, AppViewRow, APP_TABLE, VIEW_TABLE},
+    service::{
+        app::sql_builder::*,
+        user::LoggedUser,
+        util::parse_from_bytes,
+        view::{copy_view_tree, read_child_view_tables, read_view_belong_to_id},
+        workspace::{check_workspace_editable, check_workspace_readable, check_workspace_writable},
+    },
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
 
-use crate::service::trash::read_trash_ids;
+use crate::service::trash::{read_trash_ids, read_trash_uuids};
 use backend_service::errors::{invalid_params, ServerError};
 use chrono::Utc;
 use flowy_workspace_infra::{
+    entities::workspace::ListFilter,
     parser::{
         app::{AppDesc, AppName},
+        rank::ItemRank,
         workspace::WorkspaceId,
     },
-    protobuf::{App, CreateAppParams, RepeatedView},
+    protobuf::{App, CreateAppParams, RepeatedView, View},
+    rank::rank_between,
 };
-use sqlx::{postgres::PgArguments, Postgres};
+use sqlx::{postgres::PgArguments, Postgres, Row};
 use uuid::Uuid;
 
 pub(crate) async fn create_app(
@@ -26,10 +35,14 @@ pub(crate) async fn create_app(
     let workspace_id = WorkspaceId::parse(params.take_workspace_id()).map_err(invalid_params)?;
     let user_id = logged_user.as_uuid()?.to_string();
     let desc = AppDesc::parse(params.take_desc()).map_err(invalid_params)?;
+    let _ = check_workspace_writable(transaction, workspace_id.as_ref()).await?;
+    let _ = check_workspace_editable(transaction, workspace_id.as_ref(), &logged_user).await?;
+    let last_rank = read_last_app_rank(transaction, workspace_id.as_ref()).await?;
 
     let (sql, args, app) = NewAppSqlBuilder::new(&user_id, workspace_id.as_ref())
         .name(name.as_ref())
         .desc(desc.as_ref())
+        .rank(&rank_between(&last_rank, None))
         .color_style(params.take_color_style())
         .build()?;
 
@@ -43,9 +56,14 @@ pub(crate) async fn create_app(
 pub(crate) async fn read_app(
     transaction: &mut DBTransaction<'_>,
     app_id: Uuid,
+    view_filter: Option<&ListFilter>,
     user: &LoggedUser,
 ) -> Result<App, ServerError> {
     let table = read_app_table(app_id, transaction).await?;
+    let _ = check_workspace_readable(transaction, &table.workspace_id, user).await?;
+    if view_filter.is_none() {
+        return read_app_with_views(transaction, app_id).await;
+    }
 
     let read_trash_ids = read_trash_ids(user, transaction).await?;
     if read_trash_ids.contains(&table.id.to_string()) {
@@ -54,9 +72,14 @@ pub(crate) async fn read_app(
 
     let mut views = RepeatedView::default();
     views.set_items(
-        read_view_belong_to_id(&table.id.to_string(), user, transaction as &mut DBTransaction<'_>)
-            .await?
-            .into(),
+        read_view_belong_to_id(
+            &table.id.to_string(),
+            view_filter,
+            user,
+            transaction as &mut DBTransaction<'_>,
+        )
+        .await?
+        .into(),
     );
 
     let mut app: App = table.into();
@@ -64,6 +87,49 @@ pub(crate) async fn read_app(
     Ok(app)
 }
 
+// Reads the app and all its views that are not in the trash with one query. The page of the views is
+// read separately, the limit and offset can't be applied to the joined rows. The caller must check that
+// the user can read the app.
+pub(crate) async fn read_app_with_views(transaction: &mut DBTransaction<'_>, app_id: Uuid) -> Result<App, ServerError> {
+    let builder = SqlBuilder::select(APP_TABLE)
+        .add_field("app_table.*")
+        .add_field("app_trash.id IS NOT NULL AS in_trash");
+    let (sql, args) = AppViewRow::VIEW_FIELDS
+        .iter()
+        .fold(builder, |builder, field| builder.add_field(field))
+        .left_join("trash_table AS app_trash", "app_trash.id = app_table.id")
+        .left_join(
+            VIEW_TABLE,
+            "view_table.belong_to_id = app_table.id::text AND view_table.id NOT IN (SELECT id FROM trash_table)",
+        )
+        .and_where_eq("app_table.id", app_id)
+        .order_by("view_table.rank", true)
+        .order_by("view_table.create_time", true)
+        .build()?;
+
+    let rows = sqlx::query_as_with::<Postgres, AppViewRow, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    // The app in the trash is treated as deleted, so it's not found until it's restored
+    let first_row = match rows.first() {
+        Some(row) if !row.in_trash => row.clone(),
+        _ => return Err(ServerError::record_not_found()),
+    };
+
+    let views = rows
+        .iter()
+        .flat_map(|row| row.view_table())
+        .map(|table| table.into())
+        .collect::<Vec<View>>();
+    let mut app: App = first_row.app_table().into();
+    let mut repeated_view = RepeatedView::default();
+    repeated_view.set_items(views.into());
+    app.set_belongings(repeated_view);
+    Ok(app)
+}
+
 pub(crate) async fn read_app_table(app_id: Uuid, transaction: &mut DBTransaction<'_>) -> Result<AppTable, ServerError> {
     let (sql, args) = SqlBuilder::select(APP_TABLE)
         .add_field("*")
@@ -84,12 +150,16 @@ pub(crate) async fn update_app(
     name: Option<String>,
     desc: Option<String>,
     color_style: Option<Vec<u8>>,
+    device_id: &str,
+    logged_user: &LoggedUser,
 ) -> Result<(), ServerError> {
+    let _ = check_app_writable(transaction, app_id, logged_user).await?;
     let (sql, args) = SqlBuilder::update(APP_TABLE)
         .add_some_arg("name", name)
         .add_some_arg("color_style", color_style)
         .add_some_arg("description", desc)
         .add_some_arg("modified_time", Some(Utc::now()))
+        .add_arg("modified_device_id", device_id)
         .and_where_eq("id", app_id)
         .build()?;
 
@@ -101,8 +171,13 @@ pub(crate) async fn update_app(
     Ok(())
 }
 
-#[tracing::instrument(skip(transaction), err)]
-pub(crate) async fn delete_app(transaction: &mut DBTransaction<'_>, app_id: Uuid) -> Result<(), ServerError> {
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn delete_app(
+    transaction: &mut DBTransaction<'_>,
+    app_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let _ = check_app_writable(transaction, app_id, logged_user).await?;
     let (sql, args) = SqlBuilder::delete(APP_TABLE).and_where_eq("id", app_id).build()?;
     let _ = sqlx::query_with(&sql, args)
         .execute(transaction)
@@ -111,3 +186,115 @@ pub(crate) async fn delete_app(transaction: &mut DBTransaction<'_>, app_id: Uuid
 
     Ok(())
 }
+
+// The rank is computed by the client from the apps around the position that the app is moved to
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn move_app(
+    transaction: &mut DBTransaction<'_>,
+    app_id: Uuid,
+    rank: String,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let rank = ItemRank::parse(rank).map_err(invalid_params)?;
+    let _ = check_app_writable(transaction, app_id, logged_user).await?;
+    let (sql, args) = SqlBuilder::update(APP_TABLE)
+        .add_arg("rank", rank.0)
+        .and_where_eq("id", app_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Copies the app with all its views and their docs. The copy is put right after the app with the
+// " (copy)" suffix and it belongs to the user who copies it.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn duplicate_app(
+    transaction: &mut DBTransaction<'_>,
+    app_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<App, ServerError> {
+    let table = read_app_table(app_id, transaction).await?;
+    let _ = check_workspace_writable(transaction, &table.workspace_id).await?;
+    let _ = check_workspace_editable(transaction, &table.workspace_id, logged_user).await?;
+    let next_rank = read_next_app_rank(transaction, &table.workspace_id, &table.rank).await?;
+
+    let user_id = logged_user.as_uuid()?.to_string();
+    let (sql, args, mut app) = NewAppSqlBuilder::new(&user_id, &table.workspace_id)
+        .name(&format!("{} (copy)", table.name))
+        .desc(&table.description)
+        .rank(&rank_between(&table.rank, next_rank.as_deref()))
+        .color_style(parse_from_bytes(&table.color_style)?)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let trash_ids = read_trash_uuids(logged_user, transaction).await?;
+    let view_tables = read_child_view_tables(transaction, &table.id.to_string(), &trash_ids).await?;
+    let mut views = vec![];
+    for view_table in view_tables {
+        let name = view_table.name.clone();
+        let rank = view_table.rank.clone();
+        views.push(copy_view_tree(transaction, view_table, app.get_id(), &name, &rank, logged_user).await?);
+    }
+    let mut repeated_view = RepeatedView::default();
+    repeated_view.set_items(views.into());
+    app.set_belongings(repeated_view);
+    Ok(app)
+}
+
+// The rank of the app that comes right after the rank in the workspace
+async fn read_next_app_rank(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &str,
+    rank: &str,
+) -> Result<Option<String>, ServerError> {
+    let (sql, args) = SqlBuilder::select(APP_TABLE)
+        .add_field("rank")
+        .and_where_eq("workspace_id", workspace_id)
+        .and_where_gt("rank", rank)
+        .order_by("rank", true)
+        .limit(1)
+        .build()?;
+
+    let rank = sqlx::query_with(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(|row| row.get::<String, _>("rank"));
+    Ok(rank)
+}
+
+// The new app is put after the other apps of the workspace, including the ones in the trash
+async fn read_last_app_rank(transaction: &mut DBTransaction<'_>, workspace_id: &str) -> Result<String, ServerError> {
+    let (sql, args) = SqlBuilder::select(APP_TABLE)
+        .add_field("rank")
+        .and_where_eq("workspace_id", workspace_id)
+        .order_by("rank", false)
+        .limit(1)
+        .build()?;
+
+    let rank = sqlx::query_with(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(|row| row.get::<String, _>("rank"))
+        .unwrap_or_default();
+    Ok(rank)
+}
+
+pub(crate) async fn check_app_writable(
+    transaction: &mut DBTransaction<'_>,
+    app_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let table = read_app_table(app_id, transaction).await?;
+    let _ = check_workspace_writable(transaction, &table.workspace_id).await?;
+    check_workspace_editable(transaction, &table.workspace_id, logged_user).await
+}
@@ -3,17 +3,21 @@ use actix_web::{
     HttpResponse,
 };
 use backend_service::errors::{invalid_params, ServerError};
-use flowy_workspace_infra::protobuf::{AppIdentifier, CreateAppParams, UpdateAppParams};
+use flowy_workspace_infra::protobuf::{AppIdentifier, CreateAppParams, MoveAppParams, UpdateAppParams};
 use protobuf::Message;
 use sqlx::PgPool;
 
 use crate::service::{
     app::{
-        app::{create_app, delete_app, read_app, update_app},
+        app::{create_app, delete_app, duplicate_app, move_app, read_app, update_app},
         sql_builder::check_app_id,
     },
+    idempotency::{read_idempotent_response, save_idempotent_response, IdempotencyKey},
+    search::SearchIndexHandle,
     user::LoggedUser,
     util::parse_from_payload,
+    view::router::nested_view_ids,
+    workspace::sql_builder::check_list_filter,
 };
 use anyhow::Context;
 use backend_service::response::FlowyResponse;
@@ -23,6 +27,7 @@ pub async fn create_handler(
     payload: Payload,
     pool: Data<PgPool>,
     logged_user: LoggedUser,
+    idempotency_key: IdempotencyKey,
 ) -> Result<HttpResponse, ServerError> {
     let params: CreateAppParams = parse_from_payload(payload).await?;
     let mut transaction = pool
@@ -30,25 +35,35 @@ pub async fn create_handler(
         .await
         .context("Failed to acquire a Postgres connection to create app")?;
 
-    let app = create_app(&mut transaction, params, logged_user).await?;
+    if let Some(data) = read_idempotent_response(&mut transaction, &idempotency_key, &logged_user, &params).await? {
+        return Ok(FlowyResponse::new(data, None).into());
+    }
+
+    let app = create_app(&mut transaction, params, logged_user.clone()).await?;
+    let resp = FlowyResponse::success().pb(app)?;
+    let _ = save_idempotent_response(&mut transaction, &idempotency_key, &logged_user, &resp.data).await?;
 
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to create app.")?;
 
-    Ok(FlowyResponse::success().pb(app)?.into())
+    Ok(resp.into())
 }
 
 pub async fn read_handler(payload: Payload, pool: Data<PgPool>, user: LoggedUser) -> Result<HttpResponse, ServerError> {
     let params: AppIdentifier = parse_from_payload(payload).await?;
+    let view_filter = match params.has_view_filter() {
+        false => None,
+        true => Some(check_list_filter(params.get_view_filter())?),
+    };
     let app_id = check_app_id(params.app_id)?;
 
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to read app")?;
-    let app = read_app(&mut transaction, app_id, &user).await?;
+    let app = read_app(&mut transaction, app_id, view_filter.as_ref(), &user).await?;
     transaction
         .commit()
         .await
@@ -57,7 +72,11 @@ pub async fn read_handler(payload: Payload, pool: Data<PgPool>, user: LoggedUser
     Ok(FlowyResponse::success().pb(app)?.into())
 }
 
-pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: UpdateAppParams = parse_from_payload(payload).await?;
     let app_id = check_app_id(params.get_app_id().to_string())?;
     let name = match params.has_name() {
@@ -83,7 +102,8 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         .await
         .context("Failed to acquire a Postgres connection to update app")?;
 
-    let _ = update_app(&mut transaction, app_id, name, desc, color_style).await?;
+    let device_id = params.get_device_id();
+    let _ = update_app(&mut transaction, app_id, name, desc, color_style, device_id, &logged_user).await?;
 
     transaction
         .commit()
@@ -92,7 +112,11 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
     Ok(FlowyResponse::success().into())
 }
 
-pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn delete_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: AppIdentifier = parse_from_payload(payload).await?;
     let app_id = check_app_id(params.app_id.to_owned())?;
     let mut transaction = pool
@@ -100,7 +124,7 @@ pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         .await
         .context("Failed to acquire a Postgres connection to delete app")?;
 
-    let _ = delete_app(&mut transaction, app_id).await?;
+    let _ = delete_app(&mut transaction, app_id, &logged_user).await?;
 
     transaction
         .commit()
@@ -109,3 +133,53 @@ pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
 
     Ok(FlowyResponse::success().into())
 }
+
+pub async fn move_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: MoveAppParams = parse_from_payload(payload).await?;
+    let app_id = check_app_id(params.app_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to move app")?;
+
+    let _ = move_app(&mut transaction, app_id, params.rank, &logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to move app.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn duplicate_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    search: Data<SearchIndexHandle>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: AppIdentifier = parse_from_payload(payload).await?;
+    let app_id = check_app_id(params.app_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to duplicate app")?;
+
+    let app = duplicate_app(&mut transaction, app_id, &logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to duplicate app.")?;
+
+    for view in app.get_belongings().get_items() {
+        for view_id in nested_view_ids(view) {
+            search.index_view(&view_id).await;
+        }
+    }
+    Ok(FlowyResponse::success().pb(app)?.into())
+}
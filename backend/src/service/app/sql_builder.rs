@@ -31,6 +31,8 @@ impl NewAppSqlBuilder {
             modified_time: time,
             create_time: time,
             user_id: user_id.to_string(),
+            rank: "".to_string(),
+            modified_device_id: "".to_string(),
         };
 
         Self { table }
@@ -51,6 +53,8 @@ impl NewAppSqlBuilder {
             modified_time,
             create_time,
             user_id: user_id.to_string(),
+            rank: app.rank,
+            modified_device_id: "".to_string(),
         };
 
         Ok(Self { table })
@@ -72,6 +76,11 @@ impl NewAppSqlBuilder {
         self
     }
 
+    pub fn rank(mut self, rank: &str) -> Self {
+        self.table.rank = rank.to_owned();
+        self
+    }
+
     pub fn color_style(mut self, color_style: ColorStyle) -> Self {
         self.table.color_style = color_style.write_to_bytes().unwrap_or(default_color_style());
         self
@@ -89,6 +98,7 @@ impl NewAppSqlBuilder {
             .add_arg("modified_time", self.table.modified_time)
             .add_arg("create_time", self.table.create_time)
             .add_arg("user_id", self.table.user_id)
+            .add_arg("rank", self.table.rank)
             .build()?;
 
         Ok((sql, args, app))
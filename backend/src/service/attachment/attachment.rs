@@ -0,0 +1,369 @@
+use crate::{
+    config::{ATTACHMENT_CHUNK_SIZE, ATTACHMENT_UNREFERENCED_EXPIRE, ATTACHMENT_UPLOAD_EXPIRE},
+    entities::attachment::{AttachmentTable, ATTACHMENT_REFERENCE_TABLE, ATTACHMENT_TABLE},
+    service::{user::LoggedUser, view::check_view_readable},
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+    storage::ObjectStorage,
+};
+use anyhow::Context;
+use backend_service::errors::{internal_error, invalid_params, ServerError};
+use chrono::Utc;
+use flowy_document_infra::{
+    entities::doc::{attachment_references, MAX_ATTACHMENT_SIZE},
+    protobuf::{Attachment, AttachmentChunk, CreateAttachmentParams},
+};
+use lib_ot::core::Delta;
+use sqlx::{postgres::PgArguments, PgPool, Postgres, Row};
+use std::{convert::TryFrom, sync::Arc};
+use uuid::Uuid;
+
+// The chunks are uploaded to the storage later, the attachment is collected as garbage if it's not
+// completed before the ATTACHMENT_UPLOAD_EXPIRE or not inserted into any doc before the
+// ATTACHMENT_UNREFERENCED_EXPIRE.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn create_attachment(
+    transaction: &mut DBTransaction<'_>,
+    params: CreateAttachmentParams,
+    logged_user: LoggedUser,
+) -> Result<Attachment, ServerError> {
+    if params.name.trim().is_empty() {
+        return Err(invalid_params("The name of the attachment should not be empty"));
+    }
+    if params.size < 0 {
+        return Err(invalid_params(format!("The size {} of the attachment is invalid", params.size)));
+    }
+    if params.size > MAX_ATTACHMENT_SIZE {
+        return Err(ServerError::payload_overflow()
+            .context(format!("The attachment is larger than {} bytes", MAX_ATTACHMENT_SIZE)));
+    }
+
+    let time = Utc::now();
+    let table = AttachmentTable {
+        id: Uuid::new_v4(),
+        doc_id: params.doc_id,
+        user_id: logged_user.user_id,
+        name: params.name,
+        size: params.size,
+        chunk_size: ATTACHMENT_CHUNK_SIZE,
+        uploaded_chunks: 0,
+        is_orphaned: false,
+        create_time: time,
+        modified_time: time,
+        unreferenced_time: Some(time),
+    };
+
+    let (sql, args) = SqlBuilder::create(ATTACHMENT_TABLE)
+        .add_arg("id", table.id)
+        .add_arg("doc_id", &table.doc_id)
+        .add_arg("user_id", &table.user_id)
+        .add_arg("name", &table.name)
+        .add_arg("size", table.size)
+        .add_arg("chunk_size", table.chunk_size)
+        .add_arg("uploaded_chunks", table.uploaded_chunks)
+        .add_arg("is_orphaned", table.is_orphaned)
+        .add_arg("create_time", table.create_time)
+        .add_arg("modified_time", table.modified_time)
+        .add_arg("unreferenced_time", table.unreferenced_time)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(table.into())
+}
+
+// The attachment can be read by the users who can read its doc
+pub(crate) async fn read_attachment(
+    transaction: &mut DBTransaction<'_>,
+    attachment_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<Attachment, ServerError> {
+    let table = read_attachment_table(transaction, attachment_id).await?;
+    let _ = check_view_readable(transaction, Uuid::parse_str(&table.doc_id)?, logged_user).await?;
+    Ok(table.into())
+}
+
+// The chunks must be uploaded in order, uploading the chunk that was uploaded before replaces it. Only
+// the user who created the attachment can upload its chunks.
+#[tracing::instrument(skip(pool, storage, chunk, logged_user), err)]
+pub(crate) async fn upload_attachment_chunk(
+    pool: &PgPool,
+    storage: &Arc<dyn ObjectStorage>,
+    chunk: AttachmentChunk,
+    logged_user: LoggedUser,
+) -> Result<Attachment, ServerError> {
+    let attachment_id = Uuid::parse_str(chunk.get_attachment_id())?;
+    let index = chunk.get_index();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to upload attachment chunk")?;
+
+    let table = read_attachment_table(&mut transaction, attachment_id).await?;
+    if table.user_id != logged_user.user_id {
+        return Err(ServerError::permission_denied().context("Only the uploader can upload the attachment"));
+    }
+    if index < 0 || index >= table.chunk_count() || index > table.uploaded_chunks {
+        return Err(invalid_params(format!(
+            "The chunk {} is out of order, {} chunks were uploaded",
+            index, table.uploaded_chunks
+        )));
+    }
+    let len = i64::try_from(chunk.get_data().len()).map_err(invalid_params)?;
+    if len != table.chunk_len(index) {
+        return Err(invalid_params(format!(
+            "The chunk {} should be {} bytes but it's {}",
+            index,
+            table.chunk_len(index),
+            len
+        )));
+    }
+
+    let mut reader = chunk.get_data();
+    let _ = storage.put_object(&table.chunk_key(index), &mut reader).await?;
+
+    let sql = format!(
+        r#"
+            UPDATE {0} SET uploaded_chunks = GREATEST(uploaded_chunks, $1), modified_time = $2
+            WHERE id = $3
+        "#,
+        ATTACHMENT_TABLE
+    );
+    let _ = sqlx::query(&sql)
+        .bind(index + 1)
+        .bind(Utc::now())
+        .bind(attachment_id)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let table = read_attachment_table(&mut transaction, attachment_id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to upload attachment chunk.")?;
+
+    Ok(table.into())
+}
+
+// The chunk can only be read after all the chunks of the attachment are uploaded
+#[tracing::instrument(skip(pool, storage, logged_user), err)]
+pub(crate) async fn read_attachment_chunk(
+    pool: &PgPool,
+    storage: &Arc<dyn ObjectStorage>,
+    attachment_id: Uuid,
+    index: i64,
+    logged_user: &LoggedUser,
+) -> Result<Vec<u8>, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read attachment chunk")?;
+
+    let table = read_attachment_table(&mut transaction, attachment_id).await?;
+    let _ = check_view_readable(&mut transaction, Uuid::parse_str(&table.doc_id)?, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read attachment chunk.")?;
+
+    if !table.is_completed() {
+        return Err(ServerError::record_not_found().context("The attachment is still being uploaded"));
+    }
+    if index < 0 || index >= table.chunk_count() {
+        return Err(invalid_params(format!("The chunk {} doesn't exist", index)));
+    }
+
+    let mut data = Vec::with_capacity(table.chunk_len(index) as usize);
+    let _ = storage.get_object(&table.chunk_key(index), &mut data).await?;
+    Ok(data)
+}
+
+// The attachments of the doc are kept until the garbage collection removes their chunks
+pub(crate) async fn mark_doc_attachments_orphaned(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::update(ATTACHMENT_TABLE)
+        .add_arg("is_orphaned", true)
+        .and_where_eq("doc_id", doc_id.to_string())
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Counts the references of the attachments in the data of the doc revision, the references of the doc are
+// replaced with the counted ones. The attachments that are no longer referenced by any doc start
+// waiting for the garbage collection.
+pub(crate) async fn update_doc_attachment_references(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    data: &str,
+) -> Result<(), ServerError> {
+    // The references are kept if the data isn't a valid delta, so the attachments aren't collected by
+    // mistake
+    let references = match Delta::from_json(data) {
+        Ok(delta) => attachment_references(&delta),
+        Err(e) => {
+            log::warn!("Count the attachment references of doc {} failed: {:?}", doc_id, e);
+            return Ok(());
+        },
+    };
+
+    let old_ids = delete_doc_attachment_references(transaction, doc_id).await?;
+    let mut new_ids = vec![];
+    for (attachment_id, ref_count) in references {
+        let attachment_id = match Uuid::parse_str(&attachment_id) {
+            Ok(attachment_id) => attachment_id,
+            Err(_) => continue,
+        };
+        let (sql, args) = SqlBuilder::create(ATTACHMENT_REFERENCE_TABLE)
+            .add_arg("attachment_id", attachment_id)
+            .add_arg("doc_id", doc_id)
+            .add_arg("ref_count", ref_count)
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+        new_ids.push(attachment_id);
+    }
+
+    if !new_ids.is_empty() {
+        let (sql, args) = SqlBuilder::update(ATTACHMENT_TABLE)
+            .add_arg("unreferenced_time", None::<chrono::DateTime<Utc>>)
+            .and_where_in("id", new_ids.clone())
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+
+    let removed_ids = old_ids
+        .into_iter()
+        .filter(|id| !new_ids.contains(id))
+        .collect::<Vec<Uuid>>();
+    mark_attachments_unreferenced(transaction, removed_ids).await
+}
+
+// Removes the references of the deleted doc, the attachments that it referenced may be collected later
+pub(crate) async fn remove_doc_attachment_references(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+) -> Result<(), ServerError> {
+    let ids = delete_doc_attachment_references(transaction, doc_id).await?;
+    mark_attachments_unreferenced(transaction, ids).await
+}
+
+// Returns the ids of the attachments that the doc referenced
+async fn delete_doc_attachment_references(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+) -> Result<Vec<Uuid>, ServerError> {
+    let sql = format!("DELETE FROM {} WHERE doc_id = $1 RETURNING attachment_id", ATTACHMENT_REFERENCE_TABLE);
+    let ids = sqlx::query(&sql)
+        .bind(doc_id)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .into_iter()
+        .map(|row| row.get("attachment_id"))
+        .collect::<Vec<Uuid>>();
+    Ok(ids)
+}
+
+// The attachment that is still referenced by another doc isn't marked
+async fn mark_attachments_unreferenced(
+    transaction: &mut DBTransaction<'_>,
+    attachment_ids: Vec<Uuid>,
+) -> Result<(), ServerError> {
+    if attachment_ids.is_empty() {
+        return Ok(());
+    }
+
+    let sql = format!(
+        r#"
+            UPDATE {0} SET unreferenced_time = $1
+            WHERE id = ANY($2)
+            AND NOT EXISTS (SELECT 1 FROM {1} WHERE {1}.attachment_id = {0}.id)
+        "#,
+        ATTACHMENT_TABLE, ATTACHMENT_REFERENCE_TABLE
+    );
+    let _ = sqlx::query(&sql)
+        .bind(Utc::now())
+        .bind(attachment_ids)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Removes the chunks and the records of the attachments whose doc was deleted, the attachments that
+// weren't completed in time and the attachments that haven't been referenced by any doc for the
+// ATTACHMENT_UNREFERENCED_EXPIRE. Returns the number of the removed attachments.
+pub async fn collect_orphaned_attachments(
+    pool: &PgPool,
+    storage: &Arc<dyn ObjectStorage>,
+) -> Result<usize, ServerError> {
+    let now = Utc::now();
+    let expire_time = now - chrono::Duration::from_std(ATTACHMENT_UPLOAD_EXPIRE).map_err(internal_error)?;
+    let unreferenced_expire_time =
+        now - chrono::Duration::from_std(ATTACHMENT_UNREFERENCED_EXPIRE).map_err(internal_error)?;
+    let sql = format!(
+        r#"
+            SELECT * FROM {0}
+            WHERE is_orphaned = true
+            OR (uploaded_chunks < (size + chunk_size - 1) / chunk_size AND modified_time < $1)
+            OR (
+                unreferenced_time < $2
+                AND NOT EXISTS (SELECT 1 FROM {1} WHERE {1}.attachment_id = {0}.id)
+            )
+        "#,
+        ATTACHMENT_TABLE, ATTACHMENT_REFERENCE_TABLE
+    );
+    let tables = sqlx::query_as::<Postgres, AttachmentTable>(&sql)
+        .bind(expire_time)
+        .bind(unreferenced_expire_time)
+        .fetch_all(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    for table in &tables {
+        // The chunk that was never uploaded doesn't exist, deleting it is not an error
+        for index in 0..table.chunk_count() {
+            let _ = storage.delete_object(&table.chunk_key(index)).await?;
+        }
+
+        let (sql, args) = SqlBuilder::delete(ATTACHMENT_TABLE)
+            .and_where_eq("id", table.id)
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(pool)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(tables.len())
+}
+
+async fn read_attachment_table(
+    transaction: &mut DBTransaction<'_>,
+    attachment_id: Uuid,
+) -> Result<AttachmentTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(ATTACHMENT_TABLE)
+        .add_field("*")
+        .and_where_eq("id", attachment_id)
+        .and_where_eq("is_orphaned", false)
+        .build()?;
+
+    sqlx::query_as_with::<Postgres, AttachmentTable, PgArguments>(&sql, args)
+        .fetch_one(transaction)
+        .await
+        .map_err(map_sqlx_error)
+}
@@ -0,0 +1,5 @@
+mod attachment;
+pub mod router;
+
+pub(crate) use attachment::*;
+pub use attachment::collect_orphaned_attachments;
@@ -0,0 +1,98 @@
+use crate::{
+    service::{
+        attachment::{create_attachment, read_attachment, read_attachment_chunk, upload_attachment_chunk},
+        doc::check_doc_writable,
+        user::LoggedUser,
+        util::parse_from_payload,
+    },
+    storage::ObjectStorage,
+};
+use actix_web::{
+    web::{Data, Payload},
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{
+    errors::{invalid_params, ServerError},
+    response::FlowyResponse,
+};
+use flowy_document_infra::protobuf::{
+    AttachmentChunk,
+    AttachmentChunkIdentifier,
+    AttachmentIdentifier,
+    CreateAttachmentParams,
+};
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub async fn create_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: CreateAttachmentParams = parse_from_payload(payload).await?;
+    let _ = check_doc_writable(pool.get_ref(), params.get_doc_id(), &logged_user).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to create attachment")?;
+
+    let attachment = create_attachment(&mut transaction, params, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to create attachment.")?;
+
+    Ok(FlowyResponse::success().pb(attachment)?.into())
+}
+
+pub async fn read_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: AttachmentIdentifier = parse_from_payload(payload).await?;
+    let attachment_id = Uuid::parse_str(params.get_attachment_id()).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read attachment")?;
+
+    let attachment = read_attachment(&mut transaction, attachment_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read attachment.")?;
+
+    Ok(FlowyResponse::success().pb(attachment)?.into())
+}
+
+pub async fn upload_chunk_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    storage: Data<Arc<dyn ObjectStorage>>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let chunk: AttachmentChunk = parse_from_payload(payload).await?;
+    let attachment = upload_attachment_chunk(pool.get_ref(), storage.get_ref(), chunk, logged_user).await?;
+    Ok(FlowyResponse::success().pb(attachment)?.into())
+}
+
+pub async fn read_chunk_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    storage: Data<Arc<dyn ObjectStorage>>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let mut params: AttachmentChunkIdentifier = parse_from_payload(payload).await?;
+    let attachment_id = Uuid::parse_str(params.get_attachment_id()).map_err(invalid_params)?;
+    let index = params.get_index();
+    let data = read_attachment_chunk(pool.get_ref(), storage.get_ref(), attachment_id, index, &logged_user).await?;
+
+    let mut chunk = AttachmentChunk::default();
+    chunk.set_attachment_id(params.take_attachment_id());
+    chunk.set_index(params.get_index());
+    chunk.set_data(data);
+    Ok(FlowyResponse::success().pb(chunk)?.into())
+}
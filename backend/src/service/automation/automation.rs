@@ -0,0 +1,87 @@
+use crate::service::{
+    doc::doc::DocBiz,
+    user::LoggedUser,
+    view::{check_view_writable, read_view_table},
+};
+use actix_web::web::Data;
+use anyhow::Context;
+use backend_service::errors::{invalid_params, ServerError};
+use flowy_document_infra::core::markdown_to_delta;
+use flowy_workspace_infra::protobuf::ViewType;
+use protobuf::ProtobufEnum;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+// The commands are sent as JSON instead of protobuf, so the no-code automation tools can build them
+// without the generated code. The command is chosen by the "command" field:
+// {"command": "append_markdown", "view_id": "...", "markdown": "..."}
+#[derive(Debug, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum AutomationCommand {
+    AppendMarkdown { view_id: String, markdown: String },
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutomationResult {
+    pub doc_id: String,
+    pub rev_id: i64,
+}
+
+pub fn parse_command(bytes: &[u8]) -> Result<AutomationCommand, ServerError> {
+    serde_json::from_slice(bytes).map_err(invalid_params)
+}
+
+// The command is run for the user or the bot that sends it, so it can only change the views that they
+// can edit
+#[tracing::instrument(level = "debug", skip(pool, doc_biz, logged_user), err)]
+pub(crate) async fn run_command(
+    command: AutomationCommand,
+    pool: Data<PgPool>,
+    doc_biz: &DocBiz,
+    logged_user: &LoggedUser,
+) -> Result<AutomationResult, ServerError> {
+    match command {
+        AutomationCommand::AppendMarkdown { view_id, markdown } => {
+            append_markdown(&view_id, &markdown, pool, doc_biz, logged_user).await
+        },
+    }
+}
+
+async fn append_markdown(
+    view_id: &str,
+    markdown: &str,
+    pool: Data<PgPool>,
+    doc_biz: &DocBiz,
+    logged_user: &LoggedUser,
+) -> Result<AutomationResult, ServerError> {
+    if markdown.trim().is_empty() {
+        return Err(invalid_params("The markdown is empty"));
+    }
+
+    let view_id = Uuid::parse_str(view_id).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to check view")?;
+
+    let table = read_view_table(view_id, &mut transaction).await?;
+    if ViewType::from_i32(table.view_type) != Some(ViewType::Doc) {
+        return Err(invalid_params("Markdown can only be appended to the doc view"));
+    }
+    let _ = check_view_writable(&mut transaction, view_id, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to check view.")?;
+
+    // The doc has the same id as the view. It's edited through the handle, so the users that are
+    // editing the doc receive the appended content right away.
+    let doc_id = view_id.to_string();
+    let handle = match doc_biz.manager.get(&doc_id, pool).await? {
+        None => return Err(ServerError::record_not_found().context(format!("The doc of {} is not found", doc_id))),
+        Some(handle) => handle,
+    };
+    let rev_id = handle.append_delta(markdown_to_delta(markdown)).await?;
+    Ok(AutomationResult { doc_id, rev_id })
+}
@@ -0,0 +1,4 @@
+mod automation;
+pub mod router;
+
+pub use automation::*;
@@ -0,0 +1,28 @@
+use crate::service::{
+    automation::{parse_command, run_command},
+    doc::doc::DocBiz,
+    user::LoggedUser,
+    util::poll_payload,
+};
+use actix_web::{
+    web::{Data, Payload},
+    HttpResponse,
+};
+use backend_service::{errors::ServerError, response::FlowyResponse};
+use bytes::Bytes;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+#[tracing::instrument(level = "debug", skip(payload, pool, doc_biz, logged_user), err)]
+pub async fn command_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    doc_biz: Data<Arc<DocBiz>>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let bytes = poll_payload(&mut payload.into_inner()).await?;
+    let command = parse_command(&bytes)?;
+    let result = run_command(command, pool, doc_biz.get_ref(), &logged_user).await?;
+    let response = FlowyResponse::new(Bytes::from(serde_json::to_vec(&result)?), None);
+    Ok(response.into())
+}
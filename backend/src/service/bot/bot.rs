@@ -0,0 +1,177 @@
+use crate::{
+    config::{BOT_RATE_LIMIT, BOT_RATE_WINDOW, BOT_ROUTES},
+    entities::bot::{BotTable, BOT_TABLE},
+    service::{usage::ApiCaller, user::LoggedUser, workspace::check_workspace_owner},
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use ::protobuf::ProtobufEnum;
+use actix_web::http::Method;
+use backend_service::errors::ServerError;
+use chrono::Utc;
+use dashmap::DashMap;
+use flowy_workspace_infra::protobuf::{Bot, BotScope, RepeatedBot};
+use lazy_static::lazy_static;
+use sqlx::{postgres::PgArguments, PgPool, Postgres};
+use std::time::Instant;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref AUTHORIZED_BOTS: AuthorizedBots = AuthorizedBots::new();
+}
+
+struct BotAuth {
+    scope: BotScope,
+    owner_id: String,
+    workspace_id: String,
+    window_start: Instant,
+    request_count: u32,
+}
+
+// The bots that are allowed to send requests. Unlike the users, the bots never sign in, so all the
+// bots are loaded when the server starts and the deleted bot is removed immediately.
+pub struct AuthorizedBots(DashMap<String, BotAuth>);
+impl AuthorizedBots {
+    pub fn new() -> Self { Self(DashMap::new()) }
+
+    pub fn check(&self, bot_id: &str, method: &Method, path: &str) -> Result<(), ServerError> {
+        let mut auth = match self.0.get_mut(bot_id) {
+            None => return Err(ServerError::unauthorized().context("The bot doesn't exist")),
+            Some(auth) => auth,
+        };
+
+        if !BOT_ROUTES.iter().any(|route| path.starts_with(route)) {
+            return Err(ServerError::unauthorized().context(format!("The bot is not allowed to access {}", path)));
+        }
+
+        if auth.scope == BotScope::Read && *method != Method::GET {
+            return Err(ServerError::unauthorized().context("The bot is read only"));
+        }
+
+        if auth.window_start.elapsed() > BOT_RATE_WINDOW {
+            auth.window_start = Instant::now();
+            auth.request_count = 0;
+        }
+        auth.request_count += 1;
+        if auth.request_count > BOT_RATE_LIMIT {
+            return Err(ServerError::too_many_requests());
+        }
+        Ok(())
+    }
+
+    pub fn caller(&self, bot_id: &str) -> Option<ApiCaller> {
+        self.0.get(bot_id).map(|auth| ApiCaller {
+            user_id: auth.owner_id.clone(),
+            bot_id: bot_id.to_owned(),
+            workspace_id: auth.workspace_id.clone(),
+        })
+    }
+
+    pub(crate) fn store(&self, table: &BotTable) {
+        let auth = BotAuth {
+            scope: table.scope(),
+            owner_id: table.owner_id.clone(),
+            workspace_id: table.workspace_id.clone(),
+            window_start: Instant::now(),
+            request_count: 0,
+        };
+        self.0.insert(table.id.to_string(), auth);
+    }
+
+    pub(crate) fn remove(&self, bot_id: &str) { self.0.remove(bot_id); }
+}
+
+pub async fn load_authorized_bots(pool: &PgPool) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::select(BOT_TABLE).add_field("*").build()?;
+    let tables = sqlx::query_as_with::<Postgres, BotTable, PgArguments>(&sql, args)
+        .fetch_all(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    tables.iter().for_each(|table| AUTHORIZED_BOTS.store(table));
+    Ok(())
+}
+
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn create_bot(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    name: &str,
+    scope: BotScope,
+    logged_user: LoggedUser,
+) -> Result<BotTable, ServerError> {
+    let _ = check_workspace_owner(transaction, workspace_id, &logged_user, "manage the bots of").await?;
+    let table = BotTable {
+        id: Uuid::new_v4(),
+        workspace_id: workspace_id.to_string(),
+        owner_id: logged_user.user_id.clone(),
+        name: name.to_owned(),
+        scope: scope.value(),
+        create_time: Utc::now(),
+    };
+
+    let (sql, args) = SqlBuilder::create(BOT_TABLE)
+        .add_arg("id", table.id)
+        .add_arg("workspace_id", &table.workspace_id)
+        .add_arg("owner_id", &table.owner_id)
+        .add_arg("name", &table.name)
+        .add_arg("scope", table.scope)
+        .add_arg("create_time", table.create_time)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(table)
+}
+
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn read_bots(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    logged_user: LoggedUser,
+) -> Result<RepeatedBot, ServerError> {
+    let _ = check_workspace_owner(transaction, workspace_id, &logged_user, "manage the bots of").await?;
+    let (sql, args) = SqlBuilder::select(BOT_TABLE)
+        .add_field("*")
+        .and_where_eq("workspace_id", workspace_id.to_string())
+        .build()?;
+
+    let tables = sqlx::query_as_with::<Postgres, BotTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let bots = tables.into_iter().map(|table| table.into()).collect::<Vec<Bot>>();
+    let mut repeated_bot = RepeatedBot::default();
+    repeated_bot.set_items(bots.into());
+    Ok(repeated_bot)
+}
+
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn delete_bot(
+    transaction: &mut DBTransaction<'_>,
+    bot_id: Uuid,
+    logged_user: LoggedUser,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::select(BOT_TABLE)
+        .add_field("*")
+        .and_where_eq("id", bot_id)
+        .build()?;
+    let table = sqlx::query_as_with::<Postgres, BotTable, PgArguments>(&sql, args)
+        .fetch_one(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let workspace_id = Uuid::parse_str(&table.workspace_id)?;
+    let _ = check_workspace_owner(transaction, workspace_id, &logged_user, "manage the bots of").await?;
+
+    let (sql, args) = SqlBuilder::delete(BOT_TABLE).and_where_eq("id", bot_id).build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
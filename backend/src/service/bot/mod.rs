@@ -0,0 +1,4 @@
+mod bot;
+pub mod router;
+
+pub use bot::*;
@@ -0,0 +1,94 @@
+use crate::{
+    entities::token::Token,
+    service::{
+        bot::{create_bot, delete_bot, read_bots, AUTHORIZED_BOTS},
+        user::LoggedUser,
+        util::parse_from_payload,
+        workspace::sql_builder::check_workspace_id,
+    },
+};
+use actix_web::{
+    web::{Data, Payload},
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{
+    errors::{invalid_params, ServerError},
+    response::FlowyResponse,
+};
+use flowy_workspace_infra::{
+    parser::workspace::BotName,
+    protobuf::{BotIdentifier, BotToken, CreateBotParams, WorkspaceIdentifier},
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn create_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: CreateBotParams = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let name = BotName::parse(params.get_name().to_owned()).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to create bot")?;
+
+    let table = create_bot(&mut transaction, workspace_id, name.as_ref(), params.get_scope(), logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to create bot.")?;
+
+    AUTHORIZED_BOTS.store(&table);
+    let mut bot_token = BotToken::default();
+    bot_token.set_token(Token::create_bot_token(&table.id.to_string())?.0);
+    bot_token.set_bot(table.into());
+    Ok(FlowyResponse::success().pb(bot_token)?.into())
+}
+
+pub async fn read_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: WorkspaceIdentifier = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read bots")?;
+
+    let repeated_bot = read_bots(&mut transaction, workspace_id, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read bots.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_bot)?.into())
+}
+
+pub async fn delete_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: BotIdentifier = parse_from_payload(payload).await?;
+    let bot_id = Uuid::parse_str(params.get_bot_id()).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to delete bot")?;
+
+    let _ = delete_bot(&mut transaction, bot_id, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to delete bot.")?;
+
+    // The token of the bot is rejected from now on
+    AUTHORIZED_BOTS.remove(&bot_id.to_string());
+    Ok(FlowyResponse::success().into())
+}
@@ -1,10 +1,23 @@
 use crate::{
-    entities::doc::{DocTable, DOC_TABLE},
+    config::{DOC_REVISION_CHECKPOINT_INTERVAL, MAX_DOC_REVISION_CHECKPOINTS},
+    entities::doc::{DocRevisionTable, DocTable, DOC_REVISION_TABLE, DOC_TABLE},
+    service::{
+        attachment::{mark_doc_attachments_orphaned, remove_doc_attachment_references, update_doc_attachment_references},
+        doc::{auto_save_doc_snapshot, delete_doc_snapshots},
+        metrics::begin_transaction,
+        user::LoggedUser,
+        view::{check_view_readable, check_view_writable},
+    },
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
 use anyhow::Context;
-use backend_service::errors::ServerError;
-use flowy_document_infra::protobuf::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams};
+use backend_service::errors::{internal_error, ServerError};
+use chrono::Utc;
+use flowy_document_infra::{
+    entities::doc::plain_text_from_delta,
+    protobuf::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams},
+};
+use lib_ot::core::{Delta, OperationTransformable};
 use sqlx::{postgres::PgArguments, PgPool, Postgres};
 use uuid::Uuid;
 
@@ -14,6 +27,8 @@ pub(crate) async fn create_doc(
     params: CreateDocParams,
 ) -> Result<(), ServerError> {
     let uuid = Uuid::parse_str(&params.id)?;
+    let _ = save_doc_revision(transaction, uuid, 0, &params.data, None, "", None).await?;
+    let _ = update_doc_attachment_references(transaction, uuid, &params.data).await?;
     let (sql, args) = NewDocSqlBuilder::new(uuid).data(params.data).build()?;
     let _ = sqlx::query_with(&sql, args)
         .execute(transaction)
@@ -26,20 +41,9 @@ pub(crate) async fn create_doc(
 #[tracing::instrument(level = "debug", skip(pool), err)]
 pub(crate) async fn read_doc(pool: &PgPool, params: DocIdentifier) -> Result<Doc, ServerError> {
     let doc_id = Uuid::parse_str(&params.doc_id)?;
-    let mut transaction = pool
-        .begin()
-        .await
-        .context("Failed to acquire a Postgres connection to read doc")?;
-
-    let builder = SqlBuilder::select(DOC_TABLE).add_field("*").and_where_eq("id", &doc_id);
+    let mut transaction = begin_transaction(pool, "read doc").await?;
 
-    let (sql, args) = builder.build()?;
-    // TODO: benchmark the speed of different documents with different size
-    let doc: Doc = sqlx::query_as_with::<Postgres, DocTable, PgArguments>(&sql, args)
-        .fetch_one(&mut transaction)
-        .await
-        .map_err(map_sqlx_error)?
-        .into();
+    let doc: Doc = read_doc_table(&mut transaction, doc_id).await?.into();
 
     transaction
         .commit()
@@ -49,33 +53,287 @@ pub(crate) async fn read_doc(pool: &PgPool, params: DocIdentifier) -> Result<Doc
     Ok(doc)
 }
 
-#[tracing::instrument(level = "debug", skip(pool, params), fields(delta), err)]
-pub async fn update_doc(pool: &PgPool, mut params: UpdateDocParams) -> Result<(), ServerError> {
+pub(crate) async fn read_doc_table(transaction: &mut DBTransaction<'_>, doc_id: Uuid) -> Result<DocTable, ServerError> {
+    let builder = SqlBuilder::select(DOC_TABLE).add_field("*").and_where_eq("id", &doc_id);
+
+    let (sql, args) = builder.build()?;
+    // TODO: benchmark the speed of different documents with different size
+    let table = sqlx::query_as_with::<Postgres, DocTable, PgArguments>(&sql, args)
+        .fetch_one(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(table)
+}
+
+// The delta is the change from the previous revision, it's None if the doc is overwritten, e.g. through
+// the http api. The author is None if the doc is updated by the server itself.
+#[tracing::instrument(level = "debug", skip(pool, params, delta, author), fields(delta), err)]
+pub async fn update_doc(
+    pool: &PgPool,
+    mut params: UpdateDocParams,
+    delta: Option<&Delta>,
+    author: Option<&LoggedUser>,
+) -> Result<(), ServerError> {
     let doc_id = Uuid::parse_str(&params.doc_id)?;
-    let mut transaction = pool
-        .begin()
+    let mut transaction = begin_transaction(pool, "update doc").await?;
+
+    let data = params.take_data();
+
+    tracing::Span::current().record("result", &data.as_str());
+    let _ = write_doc(&mut transaction, doc_id, params.rev_id, &data, delta, &params.device_id, author).await?;
+
+    transaction
+        .commit()
         .await
-        .context("Failed to acquire a Postgres connection to update doc")?;
+        .context("Failed to commit SQL transaction to update doc.")?;
 
-    let data = Some(params.take_data());
+    Ok(())
+}
 
-    tracing::Span::current().record("result", &data.as_ref().unwrap_or(&"".to_owned()).as_str());
+pub(crate) async fn write_doc(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    rev_id: i64,
+    data: &str,
+    delta: Option<&Delta>,
+    device_id: &str,
+    author: Option<&LoggedUser>,
+) -> Result<(), ServerError> {
+    let _ = save_doc_revision(transaction, doc_id, rev_id, data, delta, device_id, author).await?;
+    let _ = update_doc_attachment_references(transaction, doc_id, data).await?;
 
     let (sql, args) = SqlBuilder::update(DOC_TABLE)
-        .add_some_arg("data", data)
-        .add_arg("rev_id", params.rev_id)
+        .add_arg("data", data)
+        .add_arg("text", doc_text(data))
+        .add_arg("rev_id", rev_id)
         .and_where_eq("id", doc_id)
         .build()?;
 
     sqlx::query_with(&sql, args)
-        .execute(&mut transaction)
+        .execute(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
 
+    let _ = auto_save_doc_snapshot(transaction, doc_id, rev_id, data, device_id).await?;
+    Ok(())
+}
+
+// The text of the doc without the formats, it's read by the search index.
+// The data that isn't a valid delta is indexed as empty.
+fn doc_text(data: &str) -> String {
+    match Delta::from_json(data) {
+        Ok(delta) => plain_text_from_delta(&delta),
+        Err(e) => {
+            log::warn!("Index doc text failed: {:?}", e);
+            "".to_owned()
+        },
+    }
+}
+
+// The revision is saved as the delta from the previous one, and as the full data every
+// DOC_REVISION_CHECKPOINT_INTERVAL revisions. The full data is saved if the delta is unknown, or if the
+// revision isn't after the latest one, e.g. it's saved again by the reset, because the delta can't be
+// composed onto the previous revision then. The revision that is saved again replaces the old one. The
+// device_id is empty if the revision wasn't made on a device, e.g. by the server or a bot.
+async fn save_doc_revision(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    rev_id: i64,
+    data: &str,
+    delta: Option<&Delta>,
+    device_id: &str,
+    author: Option<&LoggedUser>,
+) -> Result<(), ServerError> {
+    let (author_id, is_bot) = match author {
+        None => ("", false),
+        Some(author) => (author.user_id.as_str(), author.is_bot),
+    };
+    let delta_data = match delta {
+        None => None,
+        Some(delta) => match is_checkpoint_due(transaction, doc_id, rev_id).await? {
+            true => None,
+            false => Some(delta.to_json()),
+        },
+    };
+    let is_checkpoint = delta_data.is_none();
+
+    let (sql, args) = SqlBuilder::delete(DOC_REVISION_TABLE)
+        .and_where_eq("doc_id", doc_id)
+        .and_where_eq("rev_id", rev_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let (sql, args) = SqlBuilder::create(DOC_REVISION_TABLE)
+        .add_arg("doc_id", doc_id)
+        .add_arg("rev_id", rev_id)
+        .add_arg("data", delta_data.as_deref().unwrap_or(data))
+        .add_arg("is_checkpoint", is_checkpoint)
+        .add_arg("device_id", device_id)
+        .add_arg("author_id", author_id)
+        .add_arg("is_bot", is_bot)
+        .add_arg("create_time", Utc::now())
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if is_checkpoint {
+        let _ = prune_doc_revisions(transaction, doc_id).await?;
+    }
+    Ok(())
+}
+
+async fn is_checkpoint_due(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    rev_id: i64,
+) -> Result<bool, ServerError> {
+    let (sql, args) = SqlBuilder::select(DOC_REVISION_TABLE)
+        .add_field("rev_id")
+        .add_field("is_checkpoint")
+        .and_where_eq("doc_id", doc_id)
+        .order_by("rev_id", false)
+        .limit(DOC_REVISION_CHECKPOINT_INTERVAL)
+        .build()?;
+    let revisions = sqlx::query_as_with::<Postgres, (i64, bool), PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    match revisions.first() {
+        Some((latest_rev_id, _)) if *latest_rev_id < rev_id => Ok(!revisions.iter().any(|(_, checkpoint)| *checkpoint)),
+        _ => Ok(true),
+    }
+}
+
+// Deletes the revisions before the oldest of the latest MAX_DOC_REVISION_CHECKPOINTS checkpoints, so the
+// revisions that are kept can still be read from their checkpoints
+async fn prune_doc_revisions(transaction: &mut DBTransaction<'_>, doc_id: Uuid) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::select(DOC_REVISION_TABLE)
+        .add_field("rev_id")
+        .and_where_eq("doc_id", doc_id)
+        .and_where_eq("is_checkpoint", true)
+        .order_by("rev_id", false)
+        .offset(MAX_DOC_REVISION_CHECKPOINTS - 1)
+        .limit(1)
+        .build()?;
+    let oldest_checkpoint = sqlx::query_as_with::<Postgres, (i64,), PgArguments>(&sql, args)
+        .fetch_optional(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if let Some((rev_id,)) = oldest_checkpoint {
+        let (sql, args) = SqlBuilder::delete(DOC_REVISION_TABLE)
+            .and_where_eq("doc_id", doc_id)
+            .and_where_lt("rev_id", rev_id)
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(pool), err)]
+pub async fn read_doc_at_revision(pool: &PgPool, doc_id: &str, rev_id: i64) -> Result<Doc, ServerError> {
+    let doc_id = Uuid::parse_str(doc_id)?;
+    let mut transaction = begin_transaction(pool, "read doc revision").await?;
+
+    let revision = read_doc_revision(&mut transaction, doc_id, rev_id).await?;
+
     transaction
         .commit()
         .await
-        .context("Failed to commit SQL transaction to update doc.")?;
+        .context("Failed to commit SQL transaction to read doc revision.")?;
+
+    let mut doc = Doc::new();
+    doc.set_id(revision.doc_id.to_string());
+    doc.set_data(revision.data);
+    doc.set_rev_id(revision.rev_id);
+    Ok(doc)
+}
+
+// Reads the doc at the revision by composing the deltas after the checkpoint before it. The data of the
+// returned revision is the full doc.
+pub(crate) async fn read_doc_revision(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    rev_id: i64,
+) -> Result<DocRevisionTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(DOC_REVISION_TABLE)
+        .add_field("*")
+        .and_where_eq("doc_id", doc_id)
+        .and_where_le("rev_id", rev_id)
+        .order_by("rev_id", false)
+        .limit(DOC_REVISION_CHECKPOINT_INTERVAL + 1)
+        .build()?;
+    let mut tables = sqlx::query_as_with::<Postgres, DocRevisionTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if tables.first().map(|table| table.rev_id) != Some(rev_id) {
+        return Err(ServerError::record_not_found().context(format!("The revision {} is not found", rev_id)));
+    }
+    let checkpoint_index = tables
+        .iter()
+        .position(|table| table.is_checkpoint)
+        .ok_or_else(|| ServerError::internal().context(format!("The checkpoint of the revision {} is lost", rev_id)))?;
+    if checkpoint_index == 0 {
+        return Ok(tables.remove(0));
+    }
+
+    let mut delta = Delta::from_json(&tables[checkpoint_index].data).map_err(internal_error)?;
+    for table in tables[..checkpoint_index].iter().rev() {
+        let revision_delta = Delta::from_json(&table.data).map_err(internal_error)?;
+        delta = delta.compose(&revision_delta).map_err(internal_error)?;
+    }
+    let mut table = tables.remove(0);
+    table.data = delta.to_json();
+    table.is_checkpoint = true;
+    Ok(table)
+}
+
+// The id of the doc is the same as the id of the view that it belongs to, so the doc can be written by
+// the users who can edit the view. A doc that doesn't belong to any view can't be accessed.
+#[tracing::instrument(level = "debug", skip(pool, logged_user), err)]
+pub(crate) async fn check_doc_writable(
+    pool: &PgPool,
+    doc_id: &str,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let view_id = Uuid::parse_str(doc_id)?;
+    let mut transaction = begin_transaction(pool, "check doc").await?;
+
+    let _ = check_view_writable(&mut transaction, view_id, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to check doc.")?;
+
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(pool, logged_user), err)]
+pub(crate) async fn check_doc_readable(
+    pool: &PgPool,
+    doc_id: &str,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let view_id = Uuid::parse_str(doc_id)?;
+    let mut transaction = begin_transaction(pool, "check doc").await?;
+
+    let _ = check_view_readable(&mut transaction, view_id, logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to check doc.")?;
 
     Ok(())
 }
@@ -85,10 +343,22 @@ pub(crate) async fn delete_doc(transaction: &mut DBTransaction<'_>, doc_id: Uuid
     let (sql, args) = SqlBuilder::delete(DOC_TABLE).and_where_eq("id", doc_id).build()?;
 
     let _ = sqlx::query_with(&sql, args)
-        .execute(transaction)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let (sql, args) = SqlBuilder::delete(DOC_REVISION_TABLE)
+        .and_where_eq("doc_id", doc_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
 
+    let _ = delete_doc_snapshots(transaction, doc_id).await?;
+    let _ = mark_doc_attachments_orphaned(transaction, doc_id).await?;
+    let _ = remove_doc_attachment_references(transaction, doc_id).await?;
     Ok(())
 }
 
@@ -112,8 +382,10 @@ impl NewDocSqlBuilder {
     }
 
     pub fn build(self) -> Result<(String, PgArguments), ServerError> {
+        let text = doc_text(&self.table.data);
         let (sql, args) = SqlBuilder::create(DOC_TABLE)
             .add_arg("id", self.table.id)
+            .add_arg("text", text)
             .add_arg("data", self.table.data)
             .add_arg("rev_id", self.table.rev_id)
             .build()?;
@@ -4,12 +4,13 @@ use crate::service::{
         read_doc,
         ws_actor::{DocWsActor, DocWsMsg},
     },
+    search::SearchIndexHandle,
     ws::{WsBizHandler, WsClientData},
 };
 use actix_web::web::Data;
 use backend_service::errors::{internal_error, ServerError};
 use dashmap::DashMap;
-use flowy_document_infra::protobuf::DocIdentifier;
+use flowy_document_infra::protobuf::{Doc, DocIdentifier};
 use sqlx::PgPool;
 use std::sync::Arc;
 use tokio::{
@@ -24,8 +25,8 @@ pub struct DocBiz {
 }
 
 impl DocBiz {
-    pub fn new(pg_pool: Data<PgPool>) -> Self {
-        let manager = Arc::new(DocManager::new());
+    pub fn new(pg_pool: Data<PgPool>, search: SearchIndexHandle) -> Self {
+        let manager = Arc::new(DocManager::new(search));
         let (tx, rx) = mpsc::channel(100);
         let actor = DocWsActor::new(rx, manager.clone());
         tokio::task::spawn(actor.run());
@@ -42,6 +43,7 @@ impl WsBizHandler for DocBiz {
         let (ret, rx) = oneshot::channel();
         let sender = self.sender.clone();
         let pool = self.pg_pool.clone();
+        let request_id = client_data.request_id.clone();
 
         actix_rt::spawn(async move {
             let msg = DocWsMsg::ClientData { client_data, ret, pool };
@@ -50,7 +52,8 @@ impl WsBizHandler for DocBiz {
                 Err(e) => log::error!("{}", e),
             }
             match rx.await {
-                Ok(_) => {},
+                Ok(Ok(_)) => {},
+                Ok(Err(e)) => log::error!("Handle ws message failed, request id: {}, {}", request_id, e),
                 Err(e) => log::error!("{:?}", e),
             };
         });
@@ -59,12 +62,14 @@ impl WsBizHandler for DocBiz {
 
 pub struct DocManager {
     docs_map: DashMap<String, Arc<DocHandle>>,
+    search: SearchIndexHandle,
 }
 
 impl DocManager {
-    pub fn new() -> Self {
+    pub fn new(search: SearchIndexHandle) -> Self {
         Self {
             docs_map: DashMap::new(),
+            search,
         }
     }
 
@@ -76,7 +81,8 @@ impl DocManager {
                     ..Default::default()
                 };
                 let doc = read_doc(pg_pool.get_ref(), params).await?;
-                let handle = spawn_blocking(|| DocHandle::new(doc, pg_pool))
+                let search = self.search.clone();
+                let handle = spawn_blocking(|| DocHandle::new(doc, pg_pool, search))
                     .await
                     .map_err(internal_error)?;
                 let handle = Arc::new(handle?);
@@ -86,4 +92,26 @@ impl DocManager {
             Some(ctx) => Ok(Some(ctx.clone())),
         }
     }
+
+    // The handle of an opened document holds the composed result of the revisions that were pushed
+    // by the clients, so it's preferred over the database when it exists.
+    pub async fn read_latest(&self, doc_id: &str, pg_pool: &PgPool) -> Result<Doc, ServerError> {
+        let handle = self.docs_map.get(doc_id).map(|ctx| ctx.clone());
+        match handle {
+            None => {
+                let params = DocIdentifier {
+                    doc_id: doc_id.to_string(),
+                    ..Default::default()
+                };
+                read_doc(pg_pool, params).await
+            },
+            Some(handle) => {
+                let mut doc = Doc::new();
+                doc.set_id(doc_id.to_owned());
+                doc.set_data(handle.document_json().await?);
+                doc.set_rev_id(handle.rev_id().await?);
+                Ok(doc)
+            },
+        }
+    }
 }
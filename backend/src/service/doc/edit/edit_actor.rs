@@ -1,12 +1,15 @@
 use crate::service::{
     doc::edit::ServerEditDoc,
+    search::SearchIndexHandle,
+    user::LoggedUser,
     ws::{entities::Socket, WsUser},
 };
 use actix_web::web::Data;
 use async_stream::stream;
 use backend_service::errors::{internal_error, Result as DocResult, ServerError};
-use flowy_document_infra::protobuf::{Doc, Revision};
+use flowy_document_infra::protobuf::{Doc, DocPresence, DocTyping, Revision};
 use futures::stream::StreamExt;
+use lib_ot::core::Delta;
 use sqlx::PgPool;
 use std::sync::{atomic::Ordering::SeqCst, Arc};
 use tokio::{
@@ -22,6 +25,8 @@ pub struct EditUser {
 
 impl EditUser {
     pub fn id(&self) -> String { self.user.id().to_string() }
+
+    pub fn logged_user(&self) -> &LoggedUser { self.user.logged_user() }
 }
 
 #[derive(Debug)]
@@ -32,6 +37,14 @@ pub enum EditMsg {
         revision: Revision,
         ret: oneshot::Sender<DocResult<()>>,
     },
+    AppendDelta {
+        delta: Delta,
+        ret: oneshot::Sender<DocResult<i64>>,
+    },
+    ResetDelta {
+        delta: Delta,
+        ret: oneshot::Sender<DocResult<i64>>,
+    },
     DocumentJson {
         ret: oneshot::Sender<DocResult<String>>,
     },
@@ -44,6 +57,16 @@ pub enum EditMsg {
         rev_id: i64,
         ret: oneshot::Sender<DocResult<()>>,
     },
+    Presence {
+        user: Arc<WsUser>,
+        socket: Socket,
+        presence: DocPresence,
+        ret: oneshot::Sender<DocResult<()>>,
+    },
+    Typing {
+        typing: DocTyping,
+        ret: oneshot::Sender<DocResult<()>>,
+    },
 }
 
 pub struct EditDocActor {
@@ -53,8 +76,13 @@ pub struct EditDocActor {
 }
 
 impl EditDocActor {
-    pub fn new(receiver: mpsc::Receiver<EditMsg>, doc: Doc, pg_pool: Data<PgPool>) -> Result<Self, ServerError> {
-        let edit_doc = Arc::new(ServerEditDoc::new(doc)?);
+    pub fn new(
+        receiver: mpsc::Receiver<EditMsg>,
+        doc: Doc,
+        pg_pool: Data<PgPool>,
+        search: SearchIndexHandle,
+    ) -> Result<Self, ServerError> {
+        let edit_doc = Arc::new(ServerEditDoc::new(doc, search)?);
         Ok(Self {
             receiver: Some(receiver),
             edit_doc,
@@ -93,6 +121,12 @@ impl EditDocActor {
                 };
                 let _ = ret.send(self.edit_doc.apply_revision(user, revision, self.pg_pool.clone()).await);
             },
+            EditMsg::AppendDelta { delta, ret } => {
+                let _ = ret.send(self.edit_doc.append_delta(delta, self.pg_pool.clone()).await);
+            },
+            EditMsg::ResetDelta { delta, ret } => {
+                let _ = ret.send(self.edit_doc.reset_delta(delta, self.pg_pool.clone()).await);
+            },
             EditMsg::DocumentJson { ret } => {
                 let edit_context = self.edit_doc.clone();
                 let json = spawn_blocking(move || edit_context.document_json())
@@ -116,6 +150,22 @@ impl EditDocActor {
                 };
                 let _ = ret.send(self.edit_doc.new_doc_user(user, rev_id).await);
             },
+            EditMsg::Presence {
+                user,
+                socket,
+                presence,
+                ret,
+            } => {
+                let user = EditUser {
+                    user: user.clone(),
+                    socket: socket.clone(),
+                };
+                let _ = ret.send(self.edit_doc.update_presence(user, presence));
+            },
+            EditMsg::Typing { typing, ret } => {
+                self.edit_doc.push_typing_to_other_users(typing);
+                let _ = ret.send(Ok(()));
+            },
         }
     }
 }
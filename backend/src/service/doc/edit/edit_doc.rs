@@ -1,5 +1,14 @@
 use crate::service::{
-    doc::{edit::edit_actor::EditUser, update_doc},
+    doc::{
+        edit::{
+            edit_actor::EditUser,
+            rev_history::{RevisionHistory, RevisionRecord},
+        },
+        update_doc,
+    },
+    publish::schedule_render,
+    search::SearchIndexHandle,
+    user::LoggedUser,
     util::md5,
     ws::{entities::Socket, WsMessageAdaptor},
 };
@@ -8,10 +17,13 @@ use backend_service::errors::{internal_error, ServerError};
 use dashmap::DashMap;
 use flowy_document_infra::{
     core::Document,
-    entities::ws::{WsDataType, WsDocumentData},
-    protobuf::{Doc, RevId, RevType, Revision, RevisionRange, UpdateDocParams},
+    entities::{
+        doc::PRESENCE_TIMEOUT,
+        ws::{WsDataType, WsDocumentData},
+    },
+    protobuf::{Doc, DocPresence, DocTyping, RevId, RevType, Revision, RevisionRange, UpdateDocParams},
 };
-use lib_ot::core::{Delta, OperationTransformable};
+use lib_ot::core::{plain_attributes, Delta, OperationTransformable};
 use parking_lot::RwLock;
 use protobuf::Message;
 use sqlx::PgPool;
@@ -21,7 +33,7 @@ use std::{
         atomic::{AtomicI64, Ordering::SeqCst},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 pub struct ServerEditDoc {
@@ -29,10 +41,14 @@ pub struct ServerEditDoc {
     pub rev_id: AtomicI64,
     document: Arc<RwLock<Document>>,
     users: DashMap<String, EditUser>,
+    // The latest presence of each user and the time it was received
+    presences: DashMap<String, (DocPresence, Instant)>,
+    history: RwLock<RevisionHistory>,
+    search: SearchIndexHandle,
 }
 
 impl ServerEditDoc {
-    pub fn new(doc: Doc) -> Result<Self, ServerError> {
+    pub fn new(doc: Doc, search: SearchIndexHandle) -> Result<Self, ServerError> {
         let delta = Delta::from_bytes(&doc.data).map_err(internal_error)?;
         let document = Arc::new(RwLock::new(Document::from_delta(delta)));
         let users = DashMap::new();
@@ -41,6 +57,9 @@ impl ServerEditDoc {
             rev_id: AtomicI64::new(doc.rev_id),
             document,
             users,
+            presences: DashMap::new(),
+            history: RwLock::new(RevisionHistory::default()),
+            search,
         })
     }
 
@@ -90,57 +109,232 @@ impl ServerEditDoc {
         pg_pool: Data<PgPool>,
     ) -> Result<(), ServerError> {
         self.users.insert(user.id(), user.clone());
+        if self.history.read().is_applied(&user.id(), &revision) {
+            // The revision was applied already but the client didn't receive the ack, e.g. it went
+            // offline. Ack it again, so the client stops syncing it.
+            return send_acked_msg(&user.socket, &revision);
+        }
+
         let cur_rev_id = self.rev_id.load(SeqCst);
         match cur_rev_id.cmp(&revision.rev_id) {
             Ordering::Less => {
                 let next_rev_id = next(cur_rev_id);
                 if cur_rev_id == revision.base_rev_id || next_rev_id == revision.base_rev_id {
                     // The rev is in the right order, just compose it.
-                    let _ = self.compose_revision(&revision, pg_pool).await?;
+                    let _ = self.compose_revision(&user, &revision, pg_pool).await?;
                     let _ = send_acked_msg(&user.socket, &revision)?;
                 } else {
                     // The server document is outdated, pull the missing revision from the client.
                     let _ = send_pull_message(&user.socket, &self.doc_id, next_rev_id, revision.rev_id)?;
                 }
             },
-            Ordering::Equal => {
-                // Do nothing
-                log::warn!("Applied revision rev_id is the same as cur_rev_id");
-            },
-            Ordering::Greater => {
-                // The client document is outdated. Transform the client revision delta and then
-                // send the prime delta to the client. Client should compose the this prime
-                // delta.
-                let cli_revision = self.transform_revision(&revision)?;
-                let _ = send_push_message(&user.socket, &self.doc_id, cli_revision)?;
+            Ordering::Equal | Ordering::Greater => {
+                // The revision was made concurrently with the ones that other users made after its
+                // base_rev_id. Transform it against them before composing.
+                let _ = self.transform_revision(&user, &revision, pg_pool).await?;
             },
         }
         Ok(())
     }
 
+    // Appends the delta to the end of the document on behalf of the server, e.g. the content that is
+    // pushed by the automations. The revision is pushed to all the users that opened the document.
+    #[tracing::instrument(level = "debug", skip(self, delta, pg_pool), err)]
+    pub async fn append_delta(&self, delta: Delta, pg_pool: Data<PgPool>) -> Result<i64, ServerError> {
+        let mut append_delta = Delta::new();
+        append_delta.retain(self.document.read().delta().target_len, plain_attributes());
+        append_delta.extend(delta);
+
+        let base_rev_id = self.rev_id.load(SeqCst);
+        let _ = self.compose_delta(append_delta.clone())?;
+        let _ = self.rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(next(base_rev_id)));
+        let revision = self.mk_revision(base_rev_id, append_delta.clone());
+        let _ = self.save_revision(&revision, &append_delta, None, pg_pool).await?;
+        self.history
+            .write()
+            .push(RevisionRecord::new(revision.rev_id, append_delta));
+
+        self.push_to_other_users(None, &revision);
+        Ok(revision.rev_id)
+    }
+
+    // Replaces the content of the document on behalf of the server, e.g. with the snapshot that is restored.
+    // The replacement is a revision like any other, so the users that opened the document receive it and
+    // their pending changes are transformed against it instead of overwriting it.
+    #[tracing::instrument(level = "debug", skip(self, delta, pg_pool), err)]
+    pub async fn reset_delta(&self, delta: Delta, pg_pool: Data<PgPool>) -> Result<i64, ServerError> {
+        let mut reset_delta = Delta::new();
+        reset_delta.delete(self.document.read().delta().target_len);
+        reset_delta.extend(delta);
+
+        let base_rev_id = self.rev_id.load(SeqCst);
+        let _ = self.compose_delta(reset_delta.clone())?;
+        let _ = self.rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(next(base_rev_id)));
+        let revision = self.mk_revision(base_rev_id, reset_delta.clone());
+        let _ = self.save_revision(&revision, &reset_delta, None, pg_pool).await?;
+        self.history.write().push(RevisionRecord::new(revision.rev_id, reset_delta));
+
+        self.push_to_other_users(None, &revision);
+        Ok(revision.rev_id)
+    }
+
+    // The presence is broadcast to the other users who opened the document, and the user who just opened
+    // it receives the presences of the others. The presence that isn't refreshed within the
+    // PRESENCE_TIMEOUT is closed, e.g. the user went offline without closing the document.
+    #[tracing::instrument(level = "debug", skip(self, user, presence), fields(user_id = %user.id()), err)]
+    pub fn update_presence(&self, user: EditUser, presence: DocPresence) -> Result<(), ServerError> {
+        self.expire_presences();
+        let user_id = user.id();
+        if presence.is_closed {
+            self.presences.remove(&user_id);
+            self.users.remove(&user_id);
+        } else {
+            self.users.insert(user_id.clone(), user.clone());
+            let old = self.presences.insert(user_id.clone(), (presence.clone(), Instant::now()));
+            if old.is_none() {
+                for other in self.presences.iter() {
+                    if other.key() != &user_id {
+                        let _ = send_presence_message(&user.socket, other.value().0.clone())?;
+                    }
+                }
+            }
+        }
+
+        self.push_presence_to_other_users(&user_id, presence);
+        Ok(())
+    }
+
+    fn expire_presences(&self) {
+        let expired_user_ids = self
+            .presences
+            .iter()
+            .filter(|presence| presence.value().1.elapsed() > PRESENCE_TIMEOUT)
+            .map(|presence| presence.key().clone())
+            .collect::<Vec<String>>();
+
+        for user_id in expired_user_ids {
+            self.presences.remove(&user_id);
+            let presence = DocPresence {
+                doc_id: self.doc_id.clone(),
+                user_id: user_id.clone(),
+                is_closed: true,
+                ..Default::default()
+            };
+            self.push_presence_to_other_users(&user_id, presence);
+        }
+    }
+
+    fn push_presence_to_other_users(&self, user_id: &str, presence: DocPresence) {
+        for user in self.users.iter() {
+            if user.key() == user_id {
+                continue;
+            }
+            if let Err(e) = send_presence_message(&user.socket, presence.clone()) {
+                log::error!("Push the presence of {} to {} failed: {:?}", user_id, user.id(), e);
+            }
+        }
+    }
+
+    pub fn push_typing_to_other_users(&self, typing: DocTyping) {
+        for user in self.users.iter() {
+            if user.key() == &typing.user_id {
+                continue;
+            }
+            if let Err(e) = send_typing_message(&user.socket, typing.clone()) {
+                log::error!("Push the typing of {} to {} failed: {:?}", typing.user_id, user.id(), e);
+            }
+        }
+    }
+
     pub fn document_json(&self) -> String { self.document.read().to_json() }
 
-    async fn compose_revision(&self, revision: &Revision, pg_pool: Data<PgPool>) -> Result<(), ServerError> {
+    async fn compose_revision(
+        &self,
+        user: &EditUser,
+        revision: &Revision,
+        pg_pool: Data<PgPool>,
+    ) -> Result<(), ServerError> {
         let delta = Delta::from_bytes(&revision.delta_data).map_err(internal_error)?;
-        let _ = self.compose_delta(delta)?;
+        let _ = self.compose_delta(delta.clone())?;
         let _ = self.rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(revision.rev_id));
-        let _ = self.save_revision(&revision, pg_pool).await?;
+        let _ = self.save_revision(&revision, &delta, Some(user.logged_user()), pg_pool).await?;
+        let record = RevisionRecord::new(revision.rev_id, delta.clone()).with_user(user.id(), revision);
+        self.history.write().push(record);
+
+        let mut pushed_revision = self.mk_revision(revision.base_rev_id, delta);
+        pushed_revision.set_device_id(revision.device_id.clone());
+        self.push_to_other_users(Some(&user.id()), &pushed_revision);
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, revision))]
-    fn transform_revision(&self, revision: &Revision) -> Result<Revision, ServerError> {
+    // The client delta is transformed against the changes that the client hasn't seen, and the result is
+    // composed as the next revision of the document. The transformed changes are pushed back to the
+    // client, so both sides end up with the same document. The other users receive the new revision.
+    #[tracing::instrument(level = "debug", skip(self, user, revision, pg_pool), err)]
+    async fn transform_revision(
+        &self,
+        user: &EditUser,
+        revision: &Revision,
+        pg_pool: Data<PgPool>,
+    ) -> Result<(), ServerError> {
+        let base_rev_id = self.rev_id.load(SeqCst);
+        let unseen_deltas = self.history.read().unseen_deltas(&user.id(), revision.base_rev_id);
+        let unseen_delta = match unseen_deltas {
+            Some(deltas) => compose_deltas(deltas)?,
+            None if revision.rev_id == base_rev_id => {
+                // The history was dropped after the revision was applied, e.g. the document was loaded
+                // again, but the client didn't receive the ack.
+                return send_acked_msg(&user.socket, revision);
+            },
+            None => {
+                let msg = format!(
+                    "The revision {} is based on {}, which is too old to be transformed",
+                    revision.rev_id, revision.base_rev_id
+                );
+                return Err(ServerError::internal().context(msg));
+            },
+        };
+
         let cli_delta = Delta::from_bytes(&revision.delta_data).map_err(internal_error)?;
-        let (cli_prime, server_prime) = self
-            .document
-            .read()
-            .delta()
-            .transform(&cli_delta)
-            .map_err(internal_error)?;
-
-        let _ = self.compose_delta(server_prime)?;
-        let cli_revision = self.mk_revision(revision.rev_id, cli_prime);
-        Ok(cli_revision)
+        let (cli_prime, server_prime) = match unseen_delta {
+            None => (cli_delta, None),
+            Some(unseen_delta) => {
+                let (server_prime, cli_prime) = unseen_delta.transform(&cli_delta).map_err(internal_error)?;
+                (cli_prime, Some(server_prime))
+            },
+        };
+
+        let _ = self.compose_delta(cli_prime.clone())?;
+        let _ = self.rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(next(base_rev_id)));
+        let mut new_revision = self.mk_revision(base_rev_id, cli_prime.clone());
+        new_revision.set_device_id(revision.device_id.clone());
+        let _ = self.save_revision(&new_revision, &cli_prime, Some(user.logged_user()), pg_pool).await?;
+
+        let mut record = RevisionRecord::new(new_revision.rev_id, cli_prime).with_user(user.id(), revision);
+        if let Some(server_prime) = &server_prime {
+            record = record.with_user_prime(server_prime.clone());
+        }
+        self.history.write().push(record);
+
+        let _ = send_acked_msg(&user.socket, revision)?;
+        if let Some(server_prime) = server_prime {
+            let cli_revision = self.mk_revision(revision.rev_id, server_prime);
+            let _ = send_push_message(&user.socket, &self.doc_id, cli_revision)?;
+        }
+        self.push_to_other_users(Some(&user.id()), &new_revision);
+        Ok(())
+    }
+
+    // Pushes the revision to the users that opened the document, except the one who made it.
+    fn push_to_other_users(&self, user_id: Option<&str>, revision: &Revision) {
+        for user in self.users.iter() {
+            if Some(user.key().as_str()) == user_id {
+                continue;
+            }
+            if let Err(e) = send_push_message(&user.socket, &self.doc_id, revision.clone()) {
+                log::error!("Push the revision {} to {} failed: {:?}", revision.rev_id, user.id(), e);
+            }
+        }
     }
 
     fn mk_revision(&self, base_rev_id: i64, delta: Delta) -> Revision {
@@ -183,14 +377,24 @@ impl ServerEditDoc {
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, revision, pg_pool), err)]
-    async fn save_revision(&self, revision: &Revision, pg_pool: Data<PgPool>) -> Result<(), ServerError> {
+    // The author is None if the revision is made by the server, e.g. appending the automation content
+    #[tracing::instrument(level = "debug", skip(self, revision, delta, author, pg_pool), err)]
+    async fn save_revision(
+        &self,
+        revision: &Revision,
+        delta: &Delta,
+        author: Option<&LoggedUser>,
+        pg_pool: Data<PgPool>,
+    ) -> Result<(), ServerError> {
         // Opti: save with multiple revisions
         let mut params = UpdateDocParams::new();
         params.set_doc_id(self.doc_id.clone());
         params.set_data(self.document.read().to_json());
         params.set_rev_id(revision.rev_id);
-        let _ = update_doc(pg_pool.get_ref(), params).await?;
+        params.set_device_id(revision.device_id.clone());
+        let _ = update_doc(pg_pool.get_ref(), params, Some(delta), author).await?;
+        schedule_render(&self.doc_id, pg_pool);
+        self.search.index_view(&self.doc_id).await;
         Ok(())
     }
 }
@@ -211,6 +415,26 @@ fn mk_push_message(doc_id: &str, revision: Revision) -> WsMessageAdaptor {
     data.into()
 }
 
+fn send_presence_message(socket: &Socket, presence: DocPresence) -> Result<(), ServerError> {
+    let data = WsDocumentData {
+        doc_id: presence.doc_id.clone(),
+        ty: WsDataType::Presence,
+        data: presence.write_to_bytes().map_err(internal_error)?,
+    };
+    let msg: WsMessageAdaptor = data.into();
+    socket.try_send(msg).map_err(internal_error)
+}
+
+fn send_typing_message(socket: &Socket, typing: DocTyping) -> Result<(), ServerError> {
+    let data = WsDocumentData {
+        doc_id: typing.doc_id.clone(),
+        ty: WsDataType::Typing,
+        data: typing.write_to_bytes().map_err(internal_error)?,
+    };
+    let msg: WsMessageAdaptor = data.into();
+    socket.try_send(msg).map_err(internal_error)
+}
+
 #[tracing::instrument(level = "debug", skip(socket, doc_id), err)]
 fn send_pull_message(socket: &Socket, doc_id: &str, from_rev_id: i64, to_rev_id: i64) -> Result<(), ServerError> {
     let msg = mk_pull_message(doc_id, from_rev_id, to_rev_id);
@@ -256,5 +480,17 @@ fn mk_acked_message(revision: &Revision) -> WsMessageAdaptor {
     data.into()
 }
 
+fn compose_deltas(deltas: Vec<Delta>) -> Result<Option<Delta>, ServerError> {
+    let mut deltas = deltas.into_iter();
+    let mut composed = match deltas.next() {
+        None => return Ok(None),
+        Some(delta) => delta,
+    };
+    for delta in deltas {
+        composed = composed.compose(&delta).map_err(internal_error)?;
+    }
+    Ok(Some(composed))
+}
+
 #[inline]
 fn next(rev_id: i64) -> i64 { rev_id + 1 }
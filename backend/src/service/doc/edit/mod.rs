@@ -1,6 +1,7 @@
 mod edit_actor;
 mod edit_doc;
 mod open_handle;
+mod rev_history;
 
 pub use edit_actor::*;
 pub use edit_doc::*;
@@ -1,10 +1,12 @@
 use crate::service::{
     doc::edit::edit_actor::{EditDocActor, EditMsg},
+    search::SearchIndexHandle,
     ws::{entities::Socket, WsUser},
 };
 use actix_web::web::Data;
 use backend_service::errors::{internal_error, Result as DocResult, ServerError};
-use flowy_document_infra::protobuf::{Doc, Revision};
+use flowy_document_infra::protobuf::{Doc, DocPresence, DocTyping, Revision};
+use lib_ot::core::Delta;
 use sqlx::PgPool;
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
@@ -14,9 +16,9 @@ pub struct DocHandle {
 }
 
 impl DocHandle {
-    pub fn new(doc: Doc, pg_pool: Data<PgPool>) -> Result<Self, ServerError> {
+    pub fn new(doc: Doc, pg_pool: Data<PgPool>, search: SearchIndexHandle) -> Result<Self, ServerError> {
         let (sender, receiver) = mpsc::channel(100);
-        let actor = EditDocActor::new(receiver, doc, pg_pool)?;
+        let actor = EditDocActor::new(receiver, doc, pg_pool, search)?;
         tokio::task::spawn(actor.run());
         Ok(Self { sender })
     }
@@ -50,6 +52,42 @@ impl DocHandle {
         Ok(())
     }
 
+    pub async fn update_presence(
+        &self,
+        user: Arc<WsUser>,
+        socket: Socket,
+        presence: DocPresence,
+    ) -> Result<(), ServerError> {
+        let (ret, rx) = oneshot::channel();
+        let msg = EditMsg::Presence {
+            user,
+            socket,
+            presence,
+            ret,
+        };
+        let _ = self.send(msg, rx).await?;
+        Ok(())
+    }
+
+    pub async fn push_typing(&self, typing: DocTyping) -> Result<(), ServerError> {
+        let (ret, rx) = oneshot::channel();
+        let msg = EditMsg::Typing { typing, ret };
+        let _ = self.send(msg, rx).await?;
+        Ok(())
+    }
+
+    pub async fn append_delta(&self, delta: Delta) -> DocResult<i64> {
+        let (ret, rx) = oneshot::channel();
+        let msg = EditMsg::AppendDelta { delta, ret };
+        self.send(msg, rx).await?
+    }
+
+    pub async fn reset_delta(&self, delta: Delta) -> DocResult<i64> {
+        let (ret, rx) = oneshot::channel();
+        let msg = EditMsg::ResetDelta { delta, ret };
+        self.send(msg, rx).await?
+    }
+
     pub async fn document_json(&self) -> DocResult<String> {
         let (ret, rx) = oneshot::channel();
         let msg = EditMsg::DocumentJson { ret };
@@ -0,0 +1,111 @@
+use flowy_document_infra::protobuf::Revision;
+use lib_ot::core::Delta;
+use std::collections::VecDeque;
+
+// The older revisions are dropped. The revision that is based on one of them can't be transformed anymore.
+const MAX_HISTORY_LEN: usize = 1000;
+
+pub(crate) struct RevisionRecord {
+    pub(crate) rev_id: i64,
+    // None if the revision was made by the server, e.g. the appended delta.
+    pub(crate) user_id: Option<String>,
+    // The rev_id and the md5 of the revision that the user sent, they are used to find out the revision
+    // that is sent again.
+    pub(crate) user_rev_id: i64,
+    pub(crate) user_md5: String,
+    // The delta that was composed into the document.
+    pub(crate) delta: Delta,
+    // The delta that was pushed back to the user if its revision was transformed. The document of the
+    // user doesn't contain the concurrent changes until it composes this delta.
+    pub(crate) user_prime: Option<Delta>,
+}
+
+impl RevisionRecord {
+    pub(crate) fn new(rev_id: i64, delta: Delta) -> Self {
+        Self {
+            rev_id,
+            user_id: None,
+            user_rev_id: 0,
+            user_md5: "".to_owned(),
+            delta,
+            user_prime: None,
+        }
+    }
+
+    pub(crate) fn with_user(mut self, user_id: String, revision: &Revision) -> Self {
+        self.user_id = Some(user_id);
+        self.user_rev_id = revision.rev_id;
+        self.user_md5 = revision.md5.clone();
+        self
+    }
+
+    pub(crate) fn with_user_prime(mut self, user_prime: Delta) -> Self {
+        self.user_prime = Some(user_prime);
+        self
+    }
+
+    fn is_made_by(&self, user_id: &str) -> bool { self.user_id.as_deref() == Some(user_id) }
+}
+
+// Keeps the revisions that were applied since the document was loaded, so the revision that was made
+// concurrently can be transformed against the changes that the user hasn't seen yet.
+#[derive(Default)]
+pub(crate) struct RevisionHistory {
+    records: VecDeque<RevisionRecord>,
+}
+
+impl RevisionHistory {
+    pub(crate) fn push(&mut self, record: RevisionRecord) {
+        if self.records.len() >= MAX_HISTORY_LEN {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    pub(crate) fn is_applied(&self, user_id: &str, revision: &Revision) -> bool {
+        self.records.iter().any(|record| {
+            record.is_made_by(user_id) && record.user_rev_id == revision.rev_id && record.user_md5 == revision.md5
+        })
+    }
+
+    // Returns the deltas that the document of the user at base_rev_id doesn't contain, in order. The
+    // user's own revisions are already in its document, but the concurrent changes that were pushed back
+    // to it are not. Returns None if the history doesn't reach back to the base_rev_id.
+    //
+    // The base_rev_id is the user's own rev_id if the revision is based on one that the user sent, e.g. it
+    // kept typing before receiving the ack. The transformed revision is stored with another rev_id, so it's
+    // found by the user_rev_id, and its user_prime already contains the changes that were made before it.
+    // Otherwise the base_rev_id is the one of the server that the user synced to.
+    pub(crate) fn unseen_deltas(&self, user_id: &str, base_rev_id: i64) -> Option<Vec<Delta>> {
+        let own_record = self
+            .records
+            .iter()
+            .rposition(|record| record.is_made_by(user_id) && record.user_rev_id == base_rev_id);
+        let mut deltas = vec![];
+        let start = match own_record {
+            Some(index) => {
+                deltas.extend(self.records[index].user_prime.clone());
+                index + 1
+            },
+            None => match self.records.front() {
+                Some(record) if record.rev_id <= base_rev_id + 1 => self
+                    .records
+                    .iter()
+                    .position(|record| record.rev_id > base_rev_id)
+                    .unwrap_or_else(|| self.records.len()),
+                _ => return None,
+            },
+        };
+
+        deltas.extend(
+            self.records
+                .iter()
+                .skip(start)
+                .flat_map(|record| match record.is_made_by(user_id) {
+                    true => record.user_prime.clone(),
+                    false => Some(record.delta.clone()),
+                }),
+        );
+        Some(deltas)
+    }
+}
@@ -1,8 +1,10 @@
 pub(crate) use crud::*;
 pub use router::*;
+pub(crate) use snapshot::*;
 
 pub mod crud;
 pub mod doc;
 mod edit;
 pub mod router;
+pub mod snapshot;
 mod ws_actor;
@@ -1,5 +1,18 @@
 use crate::service::{
-    doc::{create_doc, read_doc, update_doc},
+    doc::{
+        check_doc_readable,
+        check_doc_writable,
+        create_doc,
+        doc::DocBiz,
+        read_doc,
+        read_doc_snapshots,
+        restore_doc_snapshot,
+        update_doc,
+    },
+    metrics::begin_transaction,
+    publish::schedule_render,
+    search::SearchIndexHandle,
+    user::LoggedUser,
     util::parse_from_payload,
 };
 use actix_web::{
@@ -8,16 +21,20 @@ use actix_web::{
 };
 use anyhow::Context;
 use backend_service::{errors::ServerError, response::FlowyResponse};
-use flowy_document_infra::protobuf::{CreateDocParams, DocIdentifier, UpdateDocParams};
+use flowy_document_infra::protobuf::{CreateDocParams, DocIdentifier, DocSnapshotIdentifier, UpdateDocParams};
 use sqlx::PgPool;
+use std::sync::Arc;
 
-pub async fn create_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+// The doc is created along with its view, so it can only be created by the users who can edit the view
+pub async fn create_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: CreateDocParams = parse_from_payload(payload).await?;
+    let _ = check_doc_writable(pool.get_ref(), &params.id, &logged_user).await?;
 
-    let mut transaction = pool
-        .begin()
-        .await
-        .context("Failed to acquire a Postgres connection to create doc")?;
+    let mut transaction = begin_transaction(pool.get_ref(), "create doc").await?;
 
     let _ = create_doc(&mut transaction, params).await?;
 
@@ -29,16 +46,73 @@ pub async fn create_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
     Ok(FlowyResponse::success().into())
 }
 
-#[tracing::instrument(level = "debug", skip(payload, pool), err)]
-pub async fn read_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+#[tracing::instrument(level = "debug", skip(payload, pool, logged_user), err)]
+pub async fn read_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: DocIdentifier = parse_from_payload(payload).await?;
+    let _ = check_doc_readable(pool.get_ref(), &params.doc_id, &logged_user).await?;
     let doc = read_doc(pool.get_ref(), params).await?;
     let response = FlowyResponse::success().pb(doc)?;
     Ok(response.into())
 }
 
-pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+#[tracing::instrument(level = "debug", skip(payload, pool, doc_biz, logged_user), err)]
+pub async fn reset_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    doc_biz: Data<Arc<DocBiz>>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: DocIdentifier = parse_from_payload(payload).await?;
+    let _ = check_doc_readable(pool.get_ref(), &params.doc_id, &logged_user).await?;
+    let doc = doc_biz.manager.read_latest(&params.doc_id, pool.get_ref()).await?;
+    let response = FlowyResponse::success().pb(doc)?;
+    Ok(response.into())
+}
+
+// The bots write documents through this handler, so the writer is recorded with the revision to tell them apart
+#[tracing::instrument(level = "debug", skip(payload, pool, search), err)]
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    search: Data<SearchIndexHandle>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: UpdateDocParams = parse_from_payload(payload).await?;
-    let _ = update_doc(pool.get_ref(), params).await?;
+    let _ = check_doc_writable(pool.get_ref(), &params.doc_id, &logged_user).await?;
+    let doc_id = params.doc_id.clone();
+    let _ = update_doc(pool.get_ref(), params, None, Some(&logged_user)).await?;
+    search.index_view(&doc_id).await;
+    schedule_render(&doc_id, pool);
     Ok(FlowyResponse::success().into())
 }
+
+#[tracing::instrument(level = "debug", skip(payload, pool, logged_user), err)]
+pub async fn snapshot_read_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: DocIdentifier = parse_from_payload(payload).await?;
+    let _ = check_doc_readable(pool.get_ref(), &params.doc_id, &logged_user).await?;
+    let repeated_snapshot = read_doc_snapshots(pool.get_ref(), params).await?;
+    let response = FlowyResponse::success().pb(repeated_snapshot)?;
+    Ok(response.into())
+}
+
+#[tracing::instrument(level = "debug", skip(payload, pool, doc_biz, logged_user), err)]
+pub async fn snapshot_restore_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    doc_biz: Data<Arc<DocBiz>>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: DocSnapshotIdentifier = parse_from_payload(payload).await?;
+    let _ = check_doc_writable(pool.get_ref(), &params.doc_id, &logged_user).await?;
+    let doc = restore_doc_snapshot(pool, &doc_biz.manager, params).await?;
+    let response = FlowyResponse::success().pb(doc)?;
+    Ok(response.into())
+}
@@ -0,0 +1,184 @@
+use crate::{
+    entities::doc::{DocSnapshotTable, DOC_SNAPSHOT_TABLE},
+    service::{
+        doc::{doc::DocManager, read_doc_revision},
+        metrics::begin_transaction,
+    },
+    sqlx_ext::{map_sqlx_error, Aggregate, DBTransaction, SqlBuilder},
+};
+use actix_web::web::Data;
+use anyhow::Context;
+use backend_service::errors::{internal_error, ServerError};
+use chrono::Utc;
+use flowy_document_infra::protobuf::{Doc, DocIdentifier, DocSnapshotIdentifier, RepeatedDocSnapshot};
+use lib_ot::core::Delta;
+use sqlx::{postgres::PgArguments, PgPool, Postgres};
+use uuid::Uuid;
+
+// A snapshot is taken automatically once the doc is this many revisions ahead of its latest snapshot
+const AUTO_SNAPSHOT_INTERVAL: i64 = 50;
+
+// The oldest snapshots are removed when the doc has more than this
+const MAX_SNAPSHOT_COUNT: i64 = 20;
+
+pub(crate) async fn auto_save_doc_snapshot(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    rev_id: i64,
+    data: &str,
+    device_id: &str,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::select(DOC_SNAPSHOT_TABLE)
+        .add_aggregate(Aggregate::Max, "rev_id", "latest_rev_id")
+        .and_where_eq("doc_id", doc_id)
+        .build()?;
+    let (latest_rev_id,) = sqlx::query_as_with::<Postgres, (Option<i64>,), PgArguments>(&sql, args)
+        .fetch_one(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if rev_id - latest_rev_id.unwrap_or(0) >= AUTO_SNAPSHOT_INTERVAL {
+        let _ = save_doc_snapshot(transaction, doc_id, "Auto save", rev_id, data, device_id).await?;
+    }
+    Ok(())
+}
+
+async fn save_doc_snapshot(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    name: &str,
+    rev_id: i64,
+    data: &str,
+    device_id: &str,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::create(DOC_SNAPSHOT_TABLE)
+        .add_arg("doc_id", doc_id)
+        .add_arg("name", name)
+        .add_arg("rev_id", rev_id)
+        .add_arg("data", data)
+        .add_arg("device_id", device_id)
+        .add_arg("create_time", Utc::now())
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let (sql, args) = SqlBuilder::select(DOC_SNAPSHOT_TABLE)
+        .add_field("id")
+        .and_where_eq("doc_id", doc_id)
+        .order_by("id", false)
+        .offset(MAX_SNAPSHOT_COUNT)
+        .build()?;
+    let stale_ids = sqlx::query_as_with::<Postgres, (i64,), PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?
+        .into_iter()
+        .map(|(id,)| id)
+        .collect::<Vec<i64>>();
+
+    if !stale_ids.is_empty() {
+        let (sql, args) = SqlBuilder::delete(DOC_SNAPSHOT_TABLE)
+            .and_where_in("id", stale_ids)
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(pool), err)]
+pub async fn read_doc_snapshots(pool: &PgPool, params: DocIdentifier) -> Result<RepeatedDocSnapshot, ServerError> {
+    let doc_id = Uuid::parse_str(&params.doc_id)?;
+    let mut transaction = begin_transaction(pool, "read doc snapshots").await?;
+
+    let (sql, args) = SqlBuilder::select(DOC_SNAPSHOT_TABLE)
+        .add_field("*")
+        .and_where_eq("doc_id", doc_id)
+        .order_by("id", false)
+        .build()?;
+    let tables = sqlx::query_as_with::<Postgres, DocSnapshotTable, PgArguments>(&sql, args)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read doc snapshots.")?;
+
+    let mut repeated_snapshot = RepeatedDocSnapshot::default();
+    repeated_snapshot.set_items(tables.into_iter().map(|table| table.into()).collect());
+    Ok(repeated_snapshot)
+}
+
+// The data of the snapshot is saved as the next revision of the doc, so the restore is kept in the
+// history like any other change. The current data is saved as a snapshot first, the restore can be undone.
+// The revision is made by the opened doc, so the users who are editing it receive the restored content.
+#[tracing::instrument(level = "debug", skip(pool, doc_manager), err)]
+pub async fn restore_doc_snapshot(
+    pool: Data<PgPool>,
+    doc_manager: &DocManager,
+    params: DocSnapshotIdentifier,
+) -> Result<Doc, ServerError> {
+    let doc_id = Uuid::parse_str(&params.doc_id)?;
+    let mut transaction = begin_transaction(pool.get_ref(), "restore doc snapshot").await?;
+
+    let (sql, args) = SqlBuilder::select(DOC_SNAPSHOT_TABLE)
+        .add_field("*")
+        .and_where_eq("id", params.snapshot_id)
+        .and_where_eq("doc_id", doc_id)
+        .build()?;
+    let snapshot = sqlx::query_as_with::<Postgres, DocSnapshotTable, PgArguments>(&sql, args)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    let delta = Delta::from_json(&snapshot.data).map_err(internal_error)?;
+
+    // The current data was made by the device of its latest revision
+    let current = doc_manager.read_latest(&params.doc_id, pool.get_ref()).await?;
+    let current_device_id = read_doc_revision(&mut transaction, doc_id, current.rev_id)
+        .await
+        .map(|revision| revision.device_id)
+        .unwrap_or_default();
+    let _ = save_doc_snapshot(
+        &mut transaction,
+        doc_id,
+        "Before restore",
+        current.rev_id,
+        &current.data,
+        &current_device_id,
+    )
+    .await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to restore doc snapshot.")?;
+
+    let handle = doc_manager
+        .get(&params.doc_id, pool)
+        .await?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("The doc {} is not found", doc_id)))?;
+    let rev_id = handle.reset_delta(delta).await?;
+
+    let mut doc = Doc::new();
+    doc.set_id(doc_id.to_string());
+    doc.set_data(snapshot.data);
+    doc.set_rev_id(rev_id);
+    Ok(doc)
+}
+
+pub(crate) async fn delete_doc_snapshots(transaction: &mut DBTransaction<'_>, doc_id: Uuid) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(DOC_SNAPSHOT_TABLE)
+        .and_where_eq("doc_id", doc_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
@@ -1,14 +1,18 @@
 use crate::service::{
-    doc::{doc::DocManager, edit::DocHandle},
+    doc::{check_doc_readable, check_doc_writable, doc::DocManager, edit::DocHandle},
     util::{md5, parse_from_bytes},
-    ws::{entities::Socket, WsClientData, WsUser},
+    ws::{entities::Socket, WsClientData, WsMessageAdaptor, WsUser},
 };
 use actix_rt::task::spawn_blocking;
 use actix_web::web::Data;
 use async_stream::stream;
 use backend_service::errors::{internal_error, Result as DocResult, ServerError};
-use flowy_document_infra::protobuf::{NewDocUser, Revision, WsDataType, WsDocumentData};
+use flowy_document_infra::{
+    entities::ws::{WsDataType as WsDataTypeEntity, WsDocumentData as WsDocumentDataEntity},
+    protobuf::{Doc, DocIdentifiers, DocPresence, DocTyping, NewDocUser, Revision, WsDataType, WsDocumentData},
+};
 use futures::stream::StreamExt;
+use protobuf::Message;
 use sqlx::PgPool;
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
@@ -61,7 +65,7 @@ impl DocWsActor {
     }
 
     async fn handle_client_data(&self, client_data: WsClientData, pool: Data<PgPool>) -> DocResult<()> {
-        let WsClientData { user, socket, data } = client_data;
+        let WsClientData { user, socket, data, .. } = client_data;
         let document_data = spawn_blocking(move || {
             let document_data: WsDocumentData = parse_from_bytes(&data)?;
             DocResult::Ok(document_data)
@@ -77,6 +81,11 @@ impl DocWsActor {
             WsDataType::NewDocUser => self.handle_new_doc_user(user, socket, data, pool).await,
             WsDataType::PullRev => Ok(()),
             WsDataType::Conflict => Ok(()),
+            WsDataType::PullDocs => self.handle_pull_docs(user, socket, data, pool).await,
+            WsDataType::PushDoc => Ok(()),
+            WsDataType::PushDocFailed => Ok(()),
+            WsDataType::Presence => self.handle_presence(user, socket, data, pool).await,
+            WsDataType::Typing => self.handle_typing(user, data, pool).await,
         }
     }
 
@@ -93,6 +102,7 @@ impl DocWsActor {
         })
         .await
         .map_err(internal_error)??;
+        let _ = check_doc_readable(pool.get_ref(), &doc_user.doc_id, user.logged_user()).await?;
         if let Some(handle) = self.doc_handle(&doc_user.doc_id, pool).await {
             handle.handle_new_user(user, doc_user.rev_id, socket).await?;
         }
@@ -113,12 +123,94 @@ impl DocWsActor {
         })
         .await
         .map_err(internal_error)??;
+
+        // Reject the revision before it gets composed if the workspace of the doc is archived or the
+        // user can't edit it
+        let _ = check_doc_writable(pool.get_ref(), &revision.doc_id, user.logged_user()).await?;
         if let Some(handle) = self.doc_handle(&revision.doc_id, pool).await {
             handle.apply_revision(user, socket, revision).await?;
         }
         Ok(())
     }
 
+    // The user_id of the presence is taken from the connection, so the user can't pretend to be others
+    async fn handle_presence(
+        &self,
+        user: Arc<WsUser>,
+        socket: Socket,
+        data: Vec<u8>,
+        pool: Data<PgPool>,
+    ) -> DocResult<()> {
+        let mut presence = spawn_blocking(move || {
+            let presence: DocPresence = parse_from_bytes(&data)?;
+            DocResult::Ok(presence)
+        })
+        .await
+        .map_err(internal_error)??;
+        presence.user_id = user.id().to_owned();
+        let _ = check_doc_readable(pool.get_ref(), &presence.doc_id, user.logged_user()).await?;
+
+        if let Some(handle) = self.doc_handle(&presence.doc_id, pool).await {
+            handle.update_presence(user, socket, presence).await?;
+        }
+        Ok(())
+    }
+
+    // The typing signal is only relayed to the other users, it isn't kept like the presence
+    async fn handle_typing(&self, user: Arc<WsUser>, data: Vec<u8>, pool: Data<PgPool>) -> DocResult<()> {
+        let mut typing = spawn_blocking(move || {
+            let typing: DocTyping = parse_from_bytes(&data)?;
+            DocResult::Ok(typing)
+        })
+        .await
+        .map_err(internal_error)??;
+        typing.user_id = user.id().to_owned();
+        let _ = check_doc_readable(pool.get_ref(), &typing.doc_id, user.logged_user()).await?;
+
+        if let Some(handle) = self.doc_handle(&typing.doc_id, pool).await {
+            handle.push_typing(typing).await?;
+        }
+        Ok(())
+    }
+
+    // Sends the latest snapshot of each document back to the client one by one. The documents that
+    // can't be read are replied with PushDocFailed, so the client doesn't wait for them.
+    async fn handle_pull_docs(
+        &self,
+        user: Arc<WsUser>,
+        socket: Socket,
+        data: Vec<u8>,
+        pool: Data<PgPool>,
+    ) -> DocResult<()> {
+        let doc_identifiers = spawn_blocking(move || {
+            let doc_identifiers: DocIdentifiers = parse_from_bytes(&data)?;
+            DocResult::Ok(doc_identifiers)
+        })
+        .await
+        .map_err(internal_error)??;
+
+        for doc_id in doc_identifiers.get_doc_ids() {
+            let msg = match self.read_pulled_doc(&user, doc_id, &pool).await {
+                Ok(doc) => mk_push_doc_message(doc)?,
+                Err(e) => {
+                    log::error!("Read doc {} failed: {:?}", doc_id, e);
+                    mk_push_doc_failed_message(doc_id)
+                },
+            };
+
+            // The batch can be larger than the mailbox of the socket, so the capacity is ignored
+            if let Err(e) = socket.do_send(msg) {
+                log::error!("Push doc {} failed: {:?}", doc_id, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn read_pulled_doc(&self, user: &WsUser, doc_id: &str, pool: &Data<PgPool>) -> DocResult<Doc> {
+        let _ = check_doc_readable(pool.get_ref(), doc_id, user.logged_user()).await?;
+        self.doc_manager.read_latest(doc_id, pool.get_ref()).await
+    }
+
     async fn doc_handle(&self, doc_id: &str, pool: Data<PgPool>) -> Option<Arc<DocHandle>> {
         match self.doc_manager.get(doc_id, pool).await {
             Ok(Some(edit_doc)) => Some(edit_doc),
@@ -134,9 +226,27 @@ impl DocWsActor {
     }
 }
 
+fn mk_push_doc_message(doc: Doc) -> DocResult<WsMessageAdaptor> {
+    let data = WsDocumentDataEntity {
+        doc_id: doc.get_id().to_owned(),
+        ty: WsDataTypeEntity::PushDoc,
+        data: doc.write_to_bytes()?,
+    };
+    Ok(data.into())
+}
+
 fn verify_md5(revision: &Revision) -> DocResult<()> {
     if md5(&revision.delta_data) != revision.md5 {
         return Err(ServerError::internal().context("Revision md5 not match"));
     }
     Ok(())
 }
+
+fn mk_push_doc_failed_message(doc_id: &str) -> WsMessageAdaptor {
+    let data = WsDocumentDataEntity {
+        doc_id: doc_id.to_owned(),
+        ty: WsDataTypeEntity::PushDocFailed,
+        data: vec![],
+    };
+    data.into()
+}
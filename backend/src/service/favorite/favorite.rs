@@ -0,0 +1,107 @@
+use crate::{
+    entities::favorite::{FavoriteTable, FAVORITE_TABLE},
+    service::{
+        trash::read_trash_uuids,
+        user::LoggedUser,
+        view::{check_view_readable, read_view_table},
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::ServerError;
+use chrono::Utc;
+use flowy_workspace_infra::protobuf::{Favorite, RepeatedFavorite};
+use sqlx::{postgres::PgArguments, Postgres};
+use uuid::Uuid;
+
+// Pins the view to the favorites of the user or unpins it. Any view that the user can read can be pinned,
+// pinning it again doesn't change the time it was pinned.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn update_favorite(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    is_favorite: bool,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    if !is_favorite {
+        let (sql, args) = SqlBuilder::delete(FAVORITE_TABLE)
+            .and_where_eq("user_id", &logged_user.user_id)
+            .and_where_eq("view_id", view_id)
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+        return Ok(());
+    }
+
+    let _ = check_view_readable(transaction, view_id, logged_user).await?;
+    let sql = format!(
+        r#"
+            INSERT INTO {0} (user_id, view_id, create_time)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id, view_id) DO NOTHING
+        "#,
+        FAVORITE_TABLE
+    );
+
+    let _ = sqlx::query(&sql)
+        .bind(&logged_user.user_id)
+        .bind(view_id)
+        .bind(Utc::now())
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// The view that was pinned last comes first. The views in the trash and the views that the user can't
+// read anymore, e.g. the workspace is no longer shared with the user, are skipped.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn read_favorites(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: &LoggedUser,
+) -> Result<RepeatedFavorite, ServerError> {
+    let (sql, args) = SqlBuilder::select(FAVORITE_TABLE)
+        .add_field("*")
+        .and_where_eq("user_id", &logged_user.user_id)
+        .order_by("create_time", false)
+        .build()?;
+
+    let tables = sqlx::query_as_with::<Postgres, FavoriteTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let trash_ids = read_trash_uuids(logged_user, transaction).await?;
+    let mut favorites = vec![];
+    for table in tables.into_iter().filter(|table| !trash_ids.contains(&table.view_id)) {
+        match check_view_readable(transaction, table.view_id, logged_user).await {
+            Ok(_) => {},
+            Err(e) if e.is_record_not_found() || e.is_permission_denied() => continue,
+            Err(e) => return Err(e),
+        }
+
+        let view_table = read_view_table(table.view_id, transaction).await?;
+        let mut favorite = Favorite::default();
+        favorite.set_view(view_table.into());
+        favorite.set_favorite_time(table.create_time.timestamp());
+        favorites.push(favorite);
+    }
+
+    let mut repeated_favorite = RepeatedFavorite::default();
+    repeated_favorite.set_items(favorites.into());
+    Ok(repeated_favorite)
+}
+
+// The view is removed from the favorites of all the users
+pub(crate) async fn delete_favorites(transaction: &mut DBTransaction<'_>, view_id: Uuid) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(FAVORITE_TABLE)
+        .and_where_eq("view_id", view_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
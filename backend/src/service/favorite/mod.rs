@@ -0,0 +1,4 @@
+pub mod router;
+mod favorite;
+
+pub(crate) use favorite::*;
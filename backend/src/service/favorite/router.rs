@@ -0,0 +1,50 @@
+use crate::service::{
+    favorite::{read_favorites, update_favorite},
+    user::LoggedUser,
+    util::parse_from_payload,
+    view::sql_builder::check_view_ids,
+};
+use actix_web::{
+    web::{Data, Payload},
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{errors::ServerError, response::FlowyResponse};
+use flowy_workspace_infra::protobuf::ViewFavoriteState;
+use sqlx::PgPool;
+
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewFavoriteState = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id.clone()])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to update favorite")?;
+
+    let _ = update_favorite(&mut transaction, view_id, params.get_is_favorite(), &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to update favorite.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn read_handler(pool: Data<PgPool>, logged_user: LoggedUser) -> Result<HttpResponse, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read favorites")?;
+
+    let repeated_favorite = read_favorites(&mut transaction, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read favorites.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_favorite)?.into())
+}
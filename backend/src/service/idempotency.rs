@@ -0,0 +1,135 @@
+use crate::{
+    config::IDEMPOTENCY_WINDOW,
+    entities::idempotency::{IdempotencyTable, IDEMPOTENCY_TABLE},
+    service::{user::LoggedUser, util::md5},
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use backend_service::{
+    config::HEADER_IDEMPOTENCY_KEY,
+    errors::{internal_error, invalid_params, ServerError},
+};
+use bytes::Bytes;
+use chrono::Utc;
+use futures::future::{ready, Ready};
+use protobuf::Message;
+use sqlx::{postgres::PgArguments, Postgres};
+
+// The value of the Idempotency-Key header. The client generates the key once for each mutation and
+// sends it with every retry, the response of the first successful request is returned to the retries.
+// The key is scoped to the route of the request, so the same key can be used for different routes.
+#[derive(Debug, Clone)]
+pub struct IdempotencyKey {
+    pub key: Option<String>,
+    pub route: String,
+}
+
+impl FromRequest for IdempotencyKey {
+    type Error = ServerError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let route = request.path().to_owned();
+        match request.headers().get(HEADER_IDEMPOTENCY_KEY) {
+            None => ready(Ok(IdempotencyKey { key: None, route })),
+            Some(header) => match header.to_str() {
+                Ok(key) if !key.is_empty() => ready(Ok(IdempotencyKey {
+                    key: Some(key.to_owned()),
+                    route,
+                })),
+                _ => ready(Err(invalid_params(format!("Invalid {} header", HEADER_IDEMPOTENCY_KEY)))),
+            },
+        }
+    }
+}
+
+// Claims the key for the request, or returns the response of the request that was sent with the key
+// before. The claim is inserted within the transaction of the mutation and locks the record, so a
+// concurrent retry waits until the mutation is committed and then returns its response, instead of
+// failing on the primary key. The claim is dropped if the mutation is rolled back. The expired record
+// is claimed again as if it didn't exist.
+#[tracing::instrument(skip(transaction, params), err)]
+pub(crate) async fn read_idempotent_response<T: Message>(
+    transaction: &mut DBTransaction<'_>,
+    key: &IdempotencyKey,
+    user: &LoggedUser,
+    params: &T,
+) -> Result<Option<Bytes>, ServerError> {
+    let key_value = match &key.key {
+        None => return Ok(None),
+        Some(key) => key,
+    };
+    let request_hash = md5(params.write_to_bytes().map_err(internal_error)?);
+    let now = Utc::now();
+    let expire_time = now - chrono::Duration::from_std(IDEMPOTENCY_WINDOW).map_err(internal_error)?;
+
+    let sql = format!(
+        "INSERT INTO {table} (user_id, route, key, request_hash, response, create_time) \
+         VALUES ($1, $2, $3, $4, $5, $6) \
+         ON CONFLICT (user_id, route, key) DO UPDATE \
+         SET request_hash = EXCLUDED.request_hash, response = EXCLUDED.response, create_time = EXCLUDED.create_time \
+         WHERE {table}.create_time < $7",
+        table = IDEMPOTENCY_TABLE
+    );
+    let result = sqlx::query(&sql)
+        .bind(&user.user_id)
+        .bind(&key.route)
+        .bind(key_value)
+        .bind(&request_hash)
+        .bind(Vec::<u8>::new())
+        .bind(now)
+        .bind(expire_time)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+    if result.rows_affected() > 0 {
+        return Ok(None);
+    }
+
+    let (sql, args) = SqlBuilder::select(IDEMPOTENCY_TABLE)
+        .add_field("*")
+        .and_where_eq("user_id", &user.user_id)
+        .and_where_eq("route", &key.route)
+        .and_where_eq("key", key_value)
+        .build()?;
+    let table = sqlx::query_as_with::<Postgres, IdempotencyTable, PgArguments>(&sql, args)
+        .fetch_one(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if table.request_hash != request_hash {
+        return Err(invalid_params(format!(
+            "The {} {} was used by another request",
+            HEADER_IDEMPOTENCY_KEY, key_value
+        )));
+    }
+    Ok(Some(Bytes::from(table.response)))
+}
+
+// Must be called within the same transaction as the mutation, after the key is claimed by the
+// read_idempotent_response, so the response is recorded only if the mutation is committed.
+#[tracing::instrument(skip(transaction, response), err)]
+pub(crate) async fn save_idempotent_response(
+    transaction: &mut DBTransaction<'_>,
+    key: &IdempotencyKey,
+    user: &LoggedUser,
+    response: &Bytes,
+) -> Result<(), ServerError> {
+    let key_value = match &key.key {
+        None => return Ok(()),
+        Some(key) => key,
+    };
+
+    let (sql, args) = SqlBuilder::update(IDEMPOTENCY_TABLE)
+        .add_arg("response", response.to_vec())
+        .and_where_eq("user_id", &user.user_id)
+        .and_where_eq("route", &key.route)
+        .and_where_eq("key", key_value)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
@@ -0,0 +1,4 @@
+mod pool;
+pub mod router;
+
+pub use pool::*;
@@ -0,0 +1,158 @@
+use crate::{config::PoolSettings, sqlx_ext::DBTransaction};
+use backend_service::errors::{internal_error, ServerError};
+use lazy_static::lazy_static;
+use sqlx::PgPool;
+use std::{
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+lazy_static! {
+    pub static ref POOL_METRICS: PoolMetrics = PoolMetrics::default();
+}
+
+// The counters of the transactions that are begun with begin_transaction. The size of the pool is read
+// from the pool when the metrics are served.
+#[derive(Default)]
+pub struct PoolMetrics {
+    max_connections: AtomicU64,
+    slow_transaction_ms: AtomicU64,
+    acquire_count: AtomicU64,
+    acquire_timeout_count: AtomicU64,
+    acquire_wait_micros: AtomicU64,
+    max_acquire_wait_micros: AtomicU64,
+    slow_transaction_count: AtomicU64,
+}
+
+impl PoolMetrics {
+    pub fn configure(&self, settings: &PoolSettings) {
+        self.max_connections
+            .store(settings.max_connections() as u64, Ordering::Relaxed);
+        self.slow_transaction_ms
+            .store(settings.slow_transaction_ms, Ordering::Relaxed);
+    }
+
+    fn record_acquire(&self, wait: Duration, is_timeout: bool) {
+        let wait_micros = wait.as_micros() as u64;
+        self.acquire_count.fetch_add(1, Ordering::Relaxed);
+        self.acquire_wait_micros.fetch_add(wait_micros, Ordering::Relaxed);
+        self.max_acquire_wait_micros.fetch_max(wait_micros, Ordering::Relaxed);
+        if is_timeout {
+            self.acquire_timeout_count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn is_slow(&self, elapsed: Duration) -> bool {
+        let threshold = self.slow_transaction_ms.load(Ordering::Relaxed);
+        threshold > 0 && elapsed.as_millis() as u64 >= threshold
+    }
+
+    // The metrics in the Prometheus text format
+    pub fn render(&self, pool: &PgPool) -> String {
+        let micros_to_secs = |micros: &AtomicU64| micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+        let metrics = vec![
+            ("db_pool_connections", "gauge", "The open connections", pool.size() as f64),
+            ("db_pool_idle_connections", "gauge", "The idle connections", pool.num_idle() as f64),
+            (
+                "db_pool_max_connections",
+                "gauge",
+                "The max connections of the pool",
+                self.max_connections.load(Ordering::Relaxed) as f64,
+            ),
+            (
+                "db_pool_acquires_total",
+                "counter",
+                "The connections acquired for the transactions",
+                self.acquire_count.load(Ordering::Relaxed) as f64,
+            ),
+            (
+                "db_pool_acquire_timeouts_total",
+                "counter",
+                "The transactions that timed out waiting for a connection",
+                self.acquire_timeout_count.load(Ordering::Relaxed) as f64,
+            ),
+            (
+                "db_pool_acquire_wait_seconds_total",
+                "counter",
+                "The time spent waiting for the connections",
+                micros_to_secs(&self.acquire_wait_micros),
+            ),
+            (
+                "db_pool_acquire_wait_seconds_max",
+                "gauge",
+                "The longest wait for a connection since the server started",
+                micros_to_secs(&self.max_acquire_wait_micros),
+            ),
+            (
+                "db_slow_transactions_total",
+                "counter",
+                "The transactions that were held longer than the slow_transaction_ms",
+                self.slow_transaction_count.load(Ordering::Relaxed) as f64,
+            ),
+        ];
+
+        let mut text = String::new();
+        for (name, ty, help, value) in metrics {
+            text.push_str(&format!("# HELP {} {}\n# TYPE {} {}\n{} {}\n", name, help, name, ty, name, value));
+        }
+        text
+    }
+}
+
+// Begins the transaction and records how long it waited for the connection. The transaction that is
+// held longer than the slow_transaction_ms is logged with the name when it's committed or dropped.
+pub(crate) async fn begin_transaction<'a>(
+    pool: &'a PgPool,
+    name: &'static str,
+) -> Result<MeteredTransaction<'a>, ServerError> {
+    let start = Instant::now();
+    let result = pool.begin().await;
+    let is_timeout = matches!(result, Err(sqlx::Error::PoolTimedOut));
+    POOL_METRICS.record_acquire(start.elapsed(), is_timeout);
+
+    let transaction = result
+        .map_err(|e| internal_error(format!("Failed to acquire a Postgres connection to {}: {:?}", name, e)))?;
+    Ok(MeteredTransaction {
+        transaction: Some(transaction),
+        name,
+        start,
+    })
+}
+
+pub struct MeteredTransaction<'a> {
+    // It's None after the transaction is committed
+    transaction: Option<DBTransaction<'a>>,
+    name: &'static str,
+    start: Instant,
+}
+
+impl<'a> MeteredTransaction<'a> {
+    pub async fn commit(mut self) -> Result<(), sqlx::Error> {
+        match self.transaction.take() {
+            None => Ok(()),
+            Some(transaction) => transaction.commit().await,
+        }
+    }
+}
+
+impl<'a> Deref for MeteredTransaction<'a> {
+    type Target = DBTransaction<'a>;
+
+    fn deref(&self) -> &Self::Target { self.transaction.as_ref().expect("The transaction was committed") }
+}
+
+impl<'a> DerefMut for MeteredTransaction<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target { self.transaction.as_mut().expect("The transaction was committed") }
+}
+
+impl<'a> Drop for MeteredTransaction<'a> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        if POOL_METRICS.is_slow(elapsed) {
+            POOL_METRICS.slow_transaction_count.fetch_add(1, Ordering::Relaxed);
+            let is_committed = self.transaction.is_none();
+            log::warn!("Slow transaction to {} took {:?}, committed: {}", self.name, elapsed, is_committed);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{config::MetricsSettings, service::metrics::POOL_METRICS};
+use actix_web::{http::header::AUTHORIZATION, web::Data, HttpRequest, HttpResponse};
+use sqlx::PgPool;
+
+// The scrapers only look at the status, so the errors aren't sent as the FlowyResponse
+pub async fn read_handler(request: HttpRequest, pool: Data<PgPool>, settings: Data<MetricsSettings>) -> HttpResponse {
+    let token = match &settings.token {
+        None => return HttpResponse::NotFound().finish(),
+        Some(token) => token,
+    };
+    let bearer = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if bearer != Some(token.as_str()) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(POOL_METRICS.render(pool.get_ref()))
+}
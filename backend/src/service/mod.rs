@@ -1,9 +1,22 @@
 pub mod app;
+pub mod attachment;
+pub mod automation;
+pub mod bot;
 pub mod doc;
+pub mod favorite;
+pub mod idempotency;
 pub(crate) mod log;
+pub mod metrics;
+pub mod publish;
+pub mod recent;
+pub mod search;
+pub mod share;
+pub mod slug;
 pub mod trash;
+pub mod usage;
 pub mod user;
 pub(crate) mod util;
 pub mod view;
+pub mod webhook;
 pub mod workspace;
 pub mod ws;
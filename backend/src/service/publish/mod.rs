@@ -0,0 +1,6 @@
+mod public_api;
+mod publish;
+pub mod router;
+
+pub(crate) use public_api::*;
+pub(crate) use publish::*;
@@ -0,0 +1,125 @@
+use crate::{
+    config::{PUBLISHED_API_RATE_LIMIT, PUBLISHED_API_RATE_WINDOW},
+    entities::publish::{PublishedViewTable, PUBLISHED_VIEW_TABLE},
+    service::{
+        publish::{read_current_path, read_page_delta, read_sub_pages, record_page_read},
+        util::md5,
+        view::read_view_table,
+    },
+    sqlx_ext::{map_sqlx_error, SqlBuilder},
+};
+use anyhow::Context;
+use backend_service::errors::ServerError;
+use dashmap::DashMap;
+use flowy_document_infra::core::{delta_to_html, delta_to_outline};
+use lazy_static::lazy_static;
+use serde_json::Value;
+use sqlx::{postgres::PgArguments, PgPool, Postgres};
+use std::time::Instant;
+
+lazy_static! {
+    static ref PUBLISHED_API_CLIENTS: DashMap<String, (Instant, u32)> = DashMap::new();
+}
+
+// The clients whose windows are expired are forgotten once this many clients are counted
+const MAX_COUNTED_CLIENTS: usize = 10_000;
+
+// Like the bots, the window of the client starts with its first request and the requests after the
+// PUBLISHED_API_RATE_LIMIT within the window are rejected.
+pub(crate) fn check_published_api_rate(client: &str) -> Result<(), ServerError> {
+    if PUBLISHED_API_CLIENTS.len() > MAX_COUNTED_CLIENTS {
+        PUBLISHED_API_CLIENTS.retain(|_, (window_start, _)| window_start.elapsed() <= PUBLISHED_API_RATE_WINDOW);
+    }
+
+    let mut entry = PUBLISHED_API_CLIENTS
+        .entry(client.to_owned())
+        .or_insert_with(|| (Instant::now(), 0));
+    let (window_start, request_count) = entry.value_mut();
+    if window_start.elapsed() > PUBLISHED_API_RATE_WINDOW {
+        *window_start = Instant::now();
+        *request_count = 0;
+    }
+    *request_count += 1;
+    if *request_count > PUBLISHED_API_RATE_LIMIT {
+        return Err(ServerError::too_many_requests());
+    }
+    Ok(())
+}
+
+pub(crate) enum PublicContent {
+    // The etag is the hash of the body, so it changes with the names of the sub-pages too
+    Json { body: Value, etag: String },
+    // The page was moved to the path after the workspace or the view was renamed
+    Moved(String),
+}
+
+// The same page as read_published_page, but the content, the outline and the sub-pages are returned
+// separately, so the external renderers can lay them out. The unpinned page is read from the latest
+// revision of the doc and the pinned one from the revision it's pinned at.
+pub(crate) async fn read_published_content(pool: &PgPool, slug: &str) -> Result<PublicContent, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read published content")?;
+
+    let (sql, args) = SqlBuilder::select(PUBLISHED_VIEW_TABLE)
+        .add_field("*")
+        .and_where_eq("slug", slug)
+        .build()?;
+    let table = sqlx::query_as_with::<Postgres, PublishedViewTable, PgArguments>(&sql, args)
+        .fetch_optional(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let table = match table {
+        Some(table) => table,
+        None => {
+            let current_slug = read_current_path(&mut transaction, slug).await?;
+            transaction
+                .commit()
+                .await
+                .context("Failed to commit SQL transaction to read published content.")?;
+            return match current_slug {
+                Some(current_slug) if current_slug != slug => Ok(PublicContent::Moved(current_slug)),
+                _ => Err(ServerError::record_not_found()),
+            };
+        },
+    };
+
+    let view = read_view_table(table.view_id, &mut transaction).await?;
+    let pinned_rev_id = if table.is_pinned { Some(table.rev_id) } else { None };
+    let (delta, rev_id) = read_page_delta(&mut transaction, table.view_id, pinned_rev_id).await?;
+    let sub_pages = read_sub_pages(&mut transaction, table.view_id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read published content.")?;
+
+    if let Err(e) = record_page_read(pool, &table).await {
+        log::error!("Record the read of {} failed: {:?}", slug, e);
+    }
+
+    let outline = delta_to_outline(&delta)
+        .into_iter()
+        .map(|heading| serde_json::json!({ "level": heading.level, "text": heading.text }))
+        .collect::<Vec<Value>>();
+    let sub_pages = sub_pages
+        .into_iter()
+        .map(|(name, slug)| serde_json::json!({ "name": name, "slug": slug }))
+        .collect::<Vec<Value>>();
+    let body = serde_json::json!({
+        "view_id": table.view_id.to_string(),
+        "slug": table.slug,
+        "name": view.name,
+        "rev_id": rev_id,
+        "is_pinned": table.is_pinned,
+        "publish_time": table.publish_time.timestamp(),
+        "update_time": table.update_time.timestamp(),
+        "content": delta_to_html(&delta),
+        "outline": outline,
+        "sub_pages": sub_pages,
+    });
+
+    let etag = format!("\"{}\"", md5(body.to_string()));
+    Ok(PublicContent::Json { body, etag })
+}
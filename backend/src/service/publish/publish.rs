@@ -0,0 +1,594 @@
+use crate::{
+    config::{MAX_ACCESS_LOG_DAYS, PUBLISHED_PAGE_ROUTE, PUBLISH_RENDER_DELAY},
+    entities::publish::{
+        PublishedViewAccessTable,
+        PublishedViewTable,
+        PUBLISHED_VIEW_ACCESS_TABLE,
+        PUBLISHED_VIEW_TABLE,
+    },
+    service::{
+        doc::{read_doc_revision, read_doc_table},
+        slug::{read_current_slug, read_or_create_slug, resolve_slug, WORKSPACE_SLUG_SCOPE},
+        user::LoggedUser,
+        view::{check_view_writable, read_belong_to_workspace_id, read_view_belong_to_id, read_view_table},
+        webhook::{deliver_view_webhook, WebhookEvent},
+        workspace::{read_workspace_table, sql_builder::check_workspace_id},
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use actix_web::web::Data;
+use anyhow::Context;
+use backend_service::errors::{internal_error, invalid_params, ServerError};
+use chrono::{Duration, Utc};
+use dashmap::DashMap;
+use flowy_document_infra::core::{delta_to_html, escape_html};
+use flowy_workspace_infra::protobuf::{PublishedView, RepeatedViewAccessLog, ViewAccessLog, ViewType};
+use lazy_static::lazy_static;
+use lib_ot::core::Delta;
+use protobuf::ProtobufEnum;
+use sqlx::{postgres::PgArguments, PgPool, Postgres};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+lazy_static! {
+    static ref RENDER_GENERATIONS: DashMap<String, u64> = DashMap::new();
+}
+
+struct PendingPage {
+    view_id: Uuid,
+    parent_view_id: String,
+    name: String,
+    slug: String,
+}
+
+// Publishing the view that was published before replaces all of its pages, so the sub-views that
+// were added after the last publishing are picked up.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn publish_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    user: &LoggedUser,
+) -> Result<PublishedView, ServerError> {
+    let table = read_view_table(view_id, transaction).await?;
+    if ViewType::from_i32(table.view_type) != Some(ViewType::Doc) {
+        return Err(invalid_params("Only the doc view can be published"));
+    }
+    let _ = check_view_writable(transaction, view_id, user).await?;
+    let _ = delete_published_view(transaction, view_id).await?;
+    let workspace_id = match read_belong_to_workspace_id(transaction, &table.belong_to_id).await? {
+        None => return Err(invalid_params("The view doesn't belong to any workspace")),
+        Some(workspace_id) => workspace_id,
+    };
+
+    let workspace_slug = read_workspace_slug(transaction, &workspace_id).await?;
+    let view_slug = read_or_create_slug(transaction, &workspace_id, &view_id.to_string(), &table.name).await?;
+    let root_slug = format!("{}/{}", workspace_slug, view_slug);
+    let now = Utc::now();
+    let mut root_rev_id = 0;
+    let mut pending_pages = vec![PendingPage {
+        view_id,
+        parent_view_id: "".to_owned(),
+        name: table.name,
+        slug: root_slug.clone(),
+    }];
+
+    while let Some(page) = pending_pages.pop() {
+        let sub_views = read_view_belong_to_id(&page.view_id.to_string(), None, user, transaction).await?;
+        let mut sub_pages = vec![];
+        for sub_view in sub_views {
+            let sub_view_slug =
+                read_or_create_slug(transaction, &workspace_id, sub_view.get_id(), sub_view.get_name()).await?;
+            let sub_page = PendingPage {
+                view_id: Uuid::parse_str(sub_view.get_id())?,
+                parent_view_id: page.view_id.to_string(),
+                name: sub_view.get_name().to_owned(),
+                slug: format!("{}/{}", root_slug, sub_view_slug),
+            };
+            sub_pages.push((sub_page.name.clone(), sub_page.slug.clone()));
+            pending_pages.push(sub_page);
+        }
+
+        let (html, rev_id) = render_page(transaction, page.view_id, &page.name, &sub_pages, None).await?;
+        if page.view_id == view_id {
+            root_rev_id = rev_id;
+        }
+
+        let (sql, args) = SqlBuilder::create(PUBLISHED_VIEW_TABLE)
+            .add_arg("view_id", page.view_id)
+            .add_arg("root_view_id", view_id)
+            .add_arg("parent_view_id", page.parent_view_id)
+            .add_arg("workspace_id", &workspace_id)
+            .add_arg("slug", page.slug)
+            .add_arg("html", html)
+            .add_arg("owner_id", &user.user_id)
+            .add_arg("publish_time", now)
+            .add_arg("update_time", now)
+            .add_arg("rev_id", rev_id)
+            .add_arg("is_pinned", false)
+            .build()?;
+
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+
+    let mut published_view = PublishedView::default();
+    published_view.set_view_id(view_id.to_string());
+    published_view.set_slug(root_slug);
+    published_view.set_publish_time(now.timestamp());
+    published_view.set_rev_id(root_rev_id);
+    Ok(published_view)
+}
+
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn unpublish_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let _ = read_root_published_view_table(transaction, view_id, user).await?;
+    delete_published_view(transaction, view_id).await
+}
+
+// Renders the page of the view at the rev_id and stops following the changes of the doc, so the owner
+// can keep editing without publishing the changes. The sub-pages are pinned at the revisions they
+// were rendered from.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn pin_published_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    rev_id: i64,
+    user: &LoggedUser,
+) -> Result<PublishedView, ServerError> {
+    if rev_id < 0 {
+        return Err(invalid_params("The revision id can not be negative"));
+    }
+
+    let table = read_root_published_view_table(transaction, view_id, user).await?;
+    let (sql, args) = SqlBuilder::update(PUBLISHED_VIEW_TABLE)
+        .add_arg("is_pinned", true)
+        .and_where_eq("root_view_id", view_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let table = render_published_page(transaction, table, Some(rev_id)).await?;
+    Ok(table.into())
+}
+
+// Renders all the pages of the view at the latest revisions and unpins them. Unlike publishing the
+// view again, the slugs are kept.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn publish_latest_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    user: &LoggedUser,
+) -> Result<PublishedView, ServerError> {
+    let table = read_root_published_view_table(transaction, view_id, user).await?;
+    let (sql, args) = SqlBuilder::select(PUBLISHED_VIEW_TABLE)
+        .add_field("*")
+        .and_where_eq("root_view_id", view_id)
+        .build()?;
+    let tables = sqlx::query_as_with::<Postgres, PublishedViewTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    for sub_table in tables.into_iter().filter(|sub_table| sub_table.view_id != view_id) {
+        let _ = render_published_page(transaction, sub_table, None).await?;
+    }
+
+    let table = render_published_page(transaction, table, None).await?;
+    Ok(table.into())
+}
+
+// Removes the pages that were published from the view, including the page of the view itself when
+// it's a sub-view of another published view.
+pub(crate) async fn delete_published_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+) -> Result<(), ServerError> {
+    for field in vec!["root_view_id", "view_id"] {
+        let (sql, args) = SqlBuilder::delete(PUBLISHED_VIEW_TABLE)
+            .and_where_eq(field, view_id)
+            .build()?;
+
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(())
+}
+
+pub(crate) enum PublicPage {
+    Html(String),
+    // The page was moved to the path after the workspace or the view was renamed
+    Moved(String),
+}
+
+// Each read of the page is counted, the page is still returned if counting fails. The slug that was
+// replaced after renaming is redirected to the current one.
+pub(crate) async fn read_published_page(pool: &PgPool, slug: &str) -> Result<PublicPage, ServerError> {
+    let (sql, args) = SqlBuilder::select(PUBLISHED_VIEW_TABLE)
+        .add_field("*")
+        .and_where_eq("slug", slug)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, PublishedViewTable, PgArguments>(&sql, args)
+        .fetch_optional(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    match table {
+        Some(table) => {
+            if let Err(e) = record_page_read(pool, &table).await {
+                log::error!("Record the read of {} failed: {:?}", slug, e);
+            }
+            Ok(PublicPage::Html(table.html))
+        },
+        None => {
+            let mut transaction = pool
+                .begin()
+                .await
+                .context("Failed to acquire a Postgres connection to read published page")?;
+            let current_slug = read_current_path(&mut transaction, slug).await?;
+            transaction
+                .commit()
+                .await
+                .context("Failed to commit SQL transaction to read published page.")?;
+
+            match current_slug {
+                Some(current_slug) if current_slug != slug => Ok(PublicPage::Moved(current_slug)),
+                _ => Err(ServerError::record_not_found()),
+            }
+        },
+    }
+}
+
+// Resolves each slug of the path, the first one is the slug of the workspace and the others are the slugs
+// of the views in it. Returns None if any of them is not found.
+pub(crate) async fn read_current_path(
+    transaction: &mut DBTransaction<'_>,
+    path: &str,
+) -> Result<Option<String>, ServerError> {
+    let mut scope_id = WORKSPACE_SLUG_SCOPE.to_owned();
+    let mut current_slugs = vec![];
+    for slug in path.split('/') {
+        let object_id = match resolve_slug(transaction, &scope_id, slug).await? {
+            None => return Ok(None),
+            Some(table) => table.object_id,
+        };
+        match read_current_slug(transaction, &object_id).await? {
+            None => return Ok(None),
+            Some(table) => current_slugs.push(table.slug),
+        }
+        if scope_id == WORKSPACE_SLUG_SCOPE {
+            scope_id = object_id;
+        }
+    }
+    Ok(Some(current_slugs.join("/")))
+}
+
+// Called after the workspace or one of its views is renamed. The slugs of the published pages are
+// updated, and the pages that link to the moved ones are rendered again unless they are pinned. The
+// pinned pages keep the old links, which are redirected.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn update_published_slugs(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &str,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::select(PUBLISHED_VIEW_TABLE)
+        .add_field("*")
+        .and_where_eq("workspace_id", workspace_id)
+        .build()?;
+    let tables = sqlx::query_as_with::<Postgres, PublishedViewTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+    if tables.is_empty() {
+        return Ok(());
+    }
+
+    let workspace_slug = read_workspace_slug(transaction, workspace_id).await?;
+    let mut view_slugs = HashMap::new();
+    for table in &tables {
+        let view_id = table.view_id.to_string();
+        if let Some(slug) = read_current_slug(transaction, &view_id).await? {
+            view_slugs.insert(view_id, slug.slug);
+        }
+    }
+
+    // The parent page is always published with its sub-pages, so the path is built by walking up
+    let parents = tables
+        .iter()
+        .map(|table| (table.view_id.to_string(), table.parent_view_id.clone()))
+        .collect::<HashMap<String, String>>();
+    let mut moved_parents = vec![];
+    for table in &tables {
+        let mut slugs = vec![];
+        let mut view_id = table.view_id.to_string();
+        while !view_id.is_empty() {
+            slugs.push(view_slugs.get(&view_id).cloned().unwrap_or_else(|| view_id.clone()));
+            view_id = parents.get(&view_id).cloned().unwrap_or_default();
+        }
+        slugs.push(workspace_slug.clone());
+        slugs.reverse();
+
+        let slug = slugs.join("/");
+        if slug == table.slug {
+            continue;
+        }
+
+        let (sql, args) = SqlBuilder::update(PUBLISHED_VIEW_TABLE)
+            .add_arg("slug", &slug)
+            .and_where_eq("view_id", table.view_id)
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+        moved_parents.push(table.parent_view_id.clone());
+    }
+
+    for table in tables {
+        if !table.is_pinned && moved_parents.contains(&table.view_id.to_string()) {
+            let _ = render_published_page(transaction, table, None).await?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) async fn read_workspace_slug(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &str,
+) -> Result<String, ServerError> {
+    let workspace = read_workspace_table(transaction, check_workspace_id(workspace_id.to_owned())?).await?;
+    read_or_create_slug(transaction, WORKSPACE_SLUG_SCOPE, workspace_id, &workspace.name).await
+}
+
+pub(crate) async fn record_page_read(pool: &PgPool, table: &PublishedViewTable) -> Result<(), ServerError> {
+    let sql = format!(
+        r#"
+            INSERT INTO {0} (access_date, view_id, owner_id, read_count)
+            VALUES ($1, $2, $3, 1)
+            ON CONFLICT (access_date, view_id) DO UPDATE SET read_count = {0}.read_count + 1
+        "#,
+        PUBLISHED_VIEW_ACCESS_TABLE
+    );
+
+    let _ = sqlx::query(&sql)
+        .bind(Utc::today().naive_utc())
+        .bind(table.view_id)
+        .bind(&table.owner_id)
+        .execute(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Returns the daily reads of the page within the last MAX_ACCESS_LOG_DAYS. Unlike managing the published
+// view, the log of a sub-view can be read too.
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn read_access_log(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    user: &LoggedUser,
+) -> Result<RepeatedViewAccessLog, ServerError> {
+    let since = Utc::today().naive_utc() - Duration::days(MAX_ACCESS_LOG_DAYS - 1);
+    let sql = format!(
+        "SELECT * FROM {} WHERE view_id = $1 AND access_date >= $2 ORDER BY access_date DESC",
+        PUBLISHED_VIEW_ACCESS_TABLE
+    );
+    let tables = sqlx::query_as::<Postgres, PublishedViewAccessTable>(&sql)
+        .bind(view_id)
+        .bind(since)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if tables.iter().any(|table| table.owner_id != user.user_id) {
+        return Err(ServerError::unauthorized().context("Only the publisher can read the access log"));
+    }
+
+    let items = tables
+        .into_iter()
+        .map(|table| table.into())
+        .collect::<Vec<ViewAccessLog>>();
+    let mut repeated_log = RepeatedViewAccessLog::default();
+    repeated_log.set_items(items.into());
+    Ok(repeated_log)
+}
+
+// Called whenever the doc or the view changes. The page is rendered again and the doc is delivered to
+// the webhook once the doc stops changing for PUBLISH_RENDER_DELAY, so typing doesn't render the page
+// for each revision. The doc that isn't published or whose page is pinned is skipped at that time.
+pub(crate) fn schedule_render(view_id: &str, pg_pool: Data<PgPool>) {
+    let generation = {
+        let mut generation = RENDER_GENERATIONS.entry(view_id.to_owned()).or_insert(0);
+        *generation += 1;
+        *generation
+    };
+
+    let view_id = view_id.to_owned();
+    tokio::spawn(async move {
+        tokio::time::sleep(PUBLISH_RENDER_DELAY).await;
+        let is_latest = RENDER_GENERATIONS
+            .remove_if(&view_id, |_, latest| *latest == generation)
+            .is_some();
+        if !is_latest {
+            return;
+        }
+
+        if let Err(e) = render_published_view(pg_pool.get_ref(), &view_id).await {
+            log::error!("Render the published view {} failed: {:?}", view_id, e);
+        }
+        if let Err(e) = deliver_view_webhook(pg_pool.get_ref(), &view_id, WebhookEvent::Changed).await {
+            log::error!("Deliver the view {} to the webhook failed: {:?}", view_id, e);
+        }
+    });
+}
+
+#[tracing::instrument(skip(pool), err)]
+async fn render_published_view(pool: &PgPool, view_id: &str) -> Result<(), ServerError> {
+    let view_id = Uuid::parse_str(view_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to render published view")?;
+
+    match read_published_view_table(&mut transaction, view_id).await {
+        Ok(table) if table.is_pinned => {},
+        Ok(table) => {
+            let _ = render_published_page(&mut transaction, table, None).await?;
+        },
+        Err(e) if e.is_record_not_found() => {},
+        Err(e) => return Err(e),
+    }
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to render published view.")?;
+    Ok(())
+}
+
+// Renders the page at the rev_id, or at the latest revision if it's None. The page is pinned only if
+// the rev_id is specified.
+async fn render_published_page(
+    transaction: &mut DBTransaction<'_>,
+    mut table: PublishedViewTable,
+    pinned_rev_id: Option<i64>,
+) -> Result<PublishedViewTable, ServerError> {
+    let sub_pages = read_sub_pages(transaction, table.view_id).await?;
+    let view = read_view_table(table.view_id, transaction).await?;
+    let (html, rev_id) = render_page(transaction, table.view_id, &view.name, &sub_pages, pinned_rev_id).await?;
+    table.html = html;
+    table.rev_id = rev_id;
+    table.is_pinned = pinned_rev_id.is_some();
+    table.update_time = Utc::now();
+
+    let (sql, args) = SqlBuilder::update(PUBLISHED_VIEW_TABLE)
+        .add_arg("html", &table.html)
+        .add_arg("rev_id", table.rev_id)
+        .add_arg("is_pinned", table.is_pinned)
+        .add_arg("update_time", table.update_time)
+        .and_where_eq("view_id", table.view_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(table)
+}
+
+// The names and the slugs of the published sub-views of the page
+pub(crate) async fn read_sub_pages(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+) -> Result<Vec<(String, String)>, ServerError> {
+    let (sql, args) = SqlBuilder::select(PUBLISHED_VIEW_TABLE)
+        .add_field("*")
+        .and_where_eq("parent_view_id", view_id.to_string())
+        .build()?;
+    let sub_tables = sqlx::query_as_with::<Postgres, PublishedViewTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut sub_pages = vec![];
+    for sub_table in sub_tables {
+        let sub_view = read_view_table(sub_table.view_id, transaction).await?;
+        sub_pages.push((sub_view.name, sub_table.slug));
+    }
+    Ok(sub_pages)
+}
+
+async fn read_published_view_table(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+) -> Result<PublishedViewTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(PUBLISHED_VIEW_TABLE)
+        .add_field("*")
+        .and_where_eq("view_id", view_id)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, PublishedViewTable, PgArguments>(&sql, args)
+        .fetch_one(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(table)
+}
+
+// The published view can only be managed through the root view by the publisher
+async fn read_root_published_view_table(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    user: &LoggedUser,
+) -> Result<PublishedViewTable, ServerError> {
+    let table = read_published_view_table(transaction, view_id).await?;
+    if table.root_view_id != view_id {
+        return Err(invalid_params("The view is published as a part of another view"));
+    }
+
+    if table.owner_id != user.user_id {
+        return Err(ServerError::unauthorized().context("Only the publisher can manage the published view"));
+    }
+    Ok(table)
+}
+
+// The sub_pages are the names and the slugs of the published sub-views. Returns the html and the
+// revision of the doc that it was rendered from.
+pub(crate) async fn render_page(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    name: &str,
+    sub_pages: &[(String, String)],
+    rev_id: Option<i64>,
+) -> Result<(String, i64), ServerError> {
+    let (delta, rev_id) = read_page_delta(transaction, view_id, rev_id).await?;
+    let title = escape_html(name);
+
+    let mut html = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{0}</title></head><body><h1>{0}</h1>",
+        title
+    );
+    html.push_str(&format!("<article>{}</article>", delta_to_html(&delta)));
+    if !sub_pages.is_empty() {
+        html.push_str("<nav><ul>");
+        for (name, slug) in sub_pages {
+            let href = escape_html(&format!("{}/{}", PUBLISHED_PAGE_ROUTE, slug));
+            html.push_str(&format!("<li><a href=\"{}\">{}</a></li>", href, escape_html(name)));
+        }
+        html.push_str("</ul></nav>");
+    }
+    html.push_str("</body></html>");
+    Ok((html, rev_id))
+}
+
+// Reads the doc at the rev_id, or at the latest revision if it's None
+pub(crate) async fn read_page_delta(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    rev_id: Option<i64>,
+) -> Result<(Delta, i64), ServerError> {
+    let (data, rev_id) = match rev_id {
+        None => {
+            let doc = read_doc_table(transaction, view_id).await?;
+            (doc.data, doc.rev_id)
+        },
+        Some(rev_id) => match read_doc_revision(transaction, view_id, rev_id).await {
+            Ok(revision) => (revision.data, revision.rev_id),
+            Err(e) if e.is_record_not_found() => {
+                return Err(invalid_params(format!("The revision {} is not found", rev_id)));
+            },
+            Err(e) => return Err(e),
+        },
+    };
+    let delta = Delta::from_json(&data).map_err(internal_error)?;
+    Ok((delta, rev_id))
+}
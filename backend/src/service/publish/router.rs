@@ -0,0 +1,223 @@
+use crate::{
+    config::{
+        PUBLIC_PAGE_CSP,
+        PUBLISHED_API_MAX_AGE,
+        PUBLISHED_API_RATE_WINDOW,
+        PUBLISHED_API_ROUTE,
+        PUBLISHED_PAGE_ROUTE,
+    },
+    rate_limit::RateLimiter,
+    service::{
+        publish::{
+            check_published_api_rate,
+            pin_published_view,
+            publish_latest_view,
+            publish_view,
+            read_access_log,
+            read_published_content,
+            read_published_page,
+            unpublish_view,
+            PublicContent,
+            PublicPage,
+        },
+        user::LoggedUser,
+        util::parse_from_payload,
+        view::sql_builder::check_view_ids,
+        webhook::{spawn_webhook_delivery, WebhookEvent},
+    },
+};
+use actix_web::{
+    http::header::{CACHE_CONTROL, CONTENT_SECURITY_POLICY, ETAG, IF_NONE_MATCH, LOCATION, RETRY_AFTER},
+    web::{Data, Path, Payload},
+    HttpRequest,
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{
+    errors::{ErrorCode, ServerError},
+    response::FlowyResponse,
+};
+use flowy_workspace_infra::protobuf::{PinPublishedViewParams, ViewIdentifier};
+use sqlx::PgPool;
+use std::sync::Arc;
+
+pub async fn publish_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to publish view")?;
+
+    let published_view = publish_view(&mut transaction, view_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to publish view.")?;
+
+    spawn_webhook_delivery(&view_id.to_string(), pool, WebhookEvent::Published);
+    Ok(FlowyResponse::success().pb(published_view)?.into())
+}
+
+pub async fn unpublish_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to unpublish view")?;
+
+    let _ = unpublish_view(&mut transaction, view_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to unpublish view.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn pin_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: PinPublishedViewParams = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to pin published view")?;
+
+    let published_view = pin_published_view(&mut transaction, view_id, params.rev_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to pin published view.")?;
+
+    Ok(FlowyResponse::success().pb(published_view)?.into())
+}
+
+pub async fn publish_latest_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to publish latest view")?;
+
+    let published_view = publish_latest_view(&mut transaction, view_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to publish latest view.")?;
+
+    spawn_webhook_delivery(&view_id.to_string(), pool, WebhookEvent::Published);
+    Ok(FlowyResponse::success().pb(published_view)?.into())
+}
+
+pub async fn read_access_log_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read access log")?;
+
+    let repeated_log = read_access_log(&mut transaction, view_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read access log.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_log)?.into())
+}
+
+// The published pages are public, they don't require the token
+pub async fn read_page_handler(slug: Path<String>, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+    match read_published_page(pool.get_ref(), &slug.into_inner()).await? {
+        PublicPage::Html(html) => Ok(HttpResponse::Ok()
+            .content_type("text/html; charset=utf-8")
+            .insert_header((CONTENT_SECURITY_POLICY, PUBLIC_PAGE_CSP))
+            .body(html)),
+        PublicPage::Moved(slug) => Ok(HttpResponse::MovedPermanently()
+            .insert_header((LOCATION, format!("{}/{}", PUBLISHED_PAGE_ROUTE, slug)))
+            .finish()),
+    }
+}
+
+// The json of the published page for the external renderers. Unlike the other apis, the errors are
+// returned with the http status, so the CDN and the renderers can handle them without the token.
+pub async fn read_content_handler(
+    request: HttpRequest,
+    slug: Path<String>,
+    pool: Data<PgPool>,
+    limiter: Data<Arc<RateLimiter>>,
+) -> HttpResponse {
+    let client_ip = limiter.client_ip(request.peer_addr(), &request.connection_info());
+    match read_content(&request, &slug.into_inner(), &client_ip, pool.get_ref()).await {
+        Ok(response) => response,
+        Err(e) => public_error_response(e),
+    }
+}
+
+async fn read_content(
+    request: &HttpRequest,
+    slug: &str,
+    client_ip: &str,
+    pool: &PgPool,
+) -> Result<HttpResponse, ServerError> {
+    let _ = check_published_api_rate(client_ip)?;
+    match read_published_content(pool, slug).await? {
+        PublicContent::Moved(slug) => Ok(HttpResponse::MovedPermanently()
+            .insert_header((LOCATION, format!("{}/{}", PUBLISHED_API_ROUTE, slug)))
+            .finish()),
+        PublicContent::Json { body, etag } => {
+            let is_not_modified = request
+                .headers()
+                .get(IF_NONE_MATCH)
+                .and_then(|value| value.to_str().ok())
+                .map_or(false, |value| value.split(',').any(|tag| tag.trim() == etag));
+            let mut builder = match is_not_modified {
+                true => HttpResponse::NotModified(),
+                false => HttpResponse::Ok(),
+            };
+            builder
+                .insert_header((CACHE_CONTROL, format!("public, max-age={}", PUBLISHED_API_MAX_AGE.as_secs())))
+                .insert_header((ETAG, etag));
+            match is_not_modified {
+                true => Ok(builder.finish()),
+                false => Ok(builder.json(body)),
+            }
+        },
+    }
+}
+
+fn public_error_response(error: ServerError) -> HttpResponse {
+    let mut builder = match error.code {
+        ErrorCode::RecordNotFound => HttpResponse::NotFound(),
+        ErrorCode::ParamsInvalid => HttpResponse::BadRequest(),
+        ErrorCode::TooManyRequests => HttpResponse::TooManyRequests(),
+        _ => HttpResponse::InternalServerError(),
+    };
+    if error.code == ErrorCode::TooManyRequests {
+        builder.insert_header((RETRY_AFTER, PUBLISHED_API_RATE_WINDOW.as_secs().to_string()));
+    }
+    let response: FlowyResponse = (&error).into();
+    builder.insert_header((CACHE_CONTROL, "no-store")).json(response)
+}
@@ -0,0 +1,4 @@
+pub mod router;
+mod recent;
+
+pub(crate) use recent::*;
@@ -0,0 +1,125 @@
+use crate::{
+    entities::recent::{RecentViewTable, RECENT_VIEW_TABLE},
+    service::{
+        trash::read_trash_uuids,
+        user::LoggedUser,
+        view::{check_view_readable, read_belong_to_app_table, read_view_table},
+        workspace::read_workspace_table,
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::{TimeZone, Utc};
+use flowy_workspace_infra::{
+    entities::view::{DEFAULT_RECENT_LIMIT, MAX_RECENT_LIMIT},
+    errors::ErrorCode,
+    protobuf::{RecentView, RepeatedRecentView},
+};
+use sqlx::{postgres::PgArguments, Postgres};
+use uuid::Uuid;
+
+// Records that the user opened the view at the open_time. The devices of the user send the open times
+// after the views are opened, they may arrive out of order, so only the latest one is kept.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn update_recent_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    open_time: i64,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let open_time = Utc
+        .timestamp_opt(open_time, 0)
+        .single()
+        .ok_or_else(|| invalid_params("The open time of the view is invalid"))?;
+    let _ = check_view_readable(transaction, view_id, logged_user).await?;
+    let sql = format!(
+        r#"
+            INSERT INTO {0} (user_id, view_id, open_time)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id, view_id) DO UPDATE SET open_time = GREATEST({0}.open_time, EXCLUDED.open_time)
+        "#,
+        RECENT_VIEW_TABLE
+    );
+
+    let _ = sqlx::query(&sql)
+        .bind(&logged_user.user_id)
+        .bind(view_id)
+        .bind(open_time)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// The view that was opened last comes first, with the app and the workspace that it belongs to. The
+// views in the trash and the views that the user can't read anymore are skipped, they don't count
+// towards the limit. The limit of zero returns the DEFAULT_RECENT_LIMIT views.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn read_recent_views(
+    transaction: &mut DBTransaction<'_>,
+    limit: i64,
+    logged_user: &LoggedUser,
+) -> Result<RepeatedRecentView, ServerError> {
+    let limit = match limit {
+        0 => DEFAULT_RECENT_LIMIT,
+        limit if limit > 0 && limit <= MAX_RECENT_LIMIT => limit,
+        _ => return Err(invalid_params(ErrorCode::ListFilterInvalid)),
+    };
+
+    let (sql, args) = SqlBuilder::select(RECENT_VIEW_TABLE)
+        .add_field("*")
+        .and_where_eq("user_id", &logged_user.user_id)
+        .order_by("open_time", false)
+        .build()?;
+
+    let tables = sqlx::query_as_with::<Postgres, RecentViewTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let trash_ids = read_trash_uuids(logged_user, transaction).await?;
+    let mut recent_views = vec![];
+    for table in tables.into_iter().filter(|table| !trash_ids.contains(&table.view_id)) {
+        if recent_views.len() as i64 >= limit {
+            break;
+        }
+        match check_view_readable(transaction, table.view_id, logged_user).await {
+            Ok(_) => {},
+            Err(e) if e.is_record_not_found() || e.is_permission_denied() => continue,
+            Err(e) => return Err(e),
+        }
+
+        let view_table = read_view_table(table.view_id, transaction).await?;
+        let app_table = match read_belong_to_app_table(transaction, &view_table.belong_to_id).await? {
+            None => continue,
+            Some(app_table) => app_table,
+        };
+        let workspace_table = read_workspace_table(transaction, Uuid::parse_str(&app_table.workspace_id)?).await?;
+
+        let mut recent_view = RecentView::default();
+        recent_view.set_view(view_table.into());
+        recent_view.set_app_id(app_table.id.to_string());
+        recent_view.set_app_name(app_table.name);
+        recent_view.set_workspace_id(workspace_table.id.to_string());
+        recent_view.set_workspace_name(workspace_table.name);
+        recent_view.set_open_time(table.open_time.timestamp());
+        recent_views.push(recent_view);
+    }
+
+    let mut repeated_recent_view = RepeatedRecentView::default();
+    repeated_recent_view.set_items(recent_views.into());
+    Ok(repeated_recent_view)
+}
+
+// The view is removed from the recent views of all the users
+pub(crate) async fn delete_recent_views(transaction: &mut DBTransaction<'_>, view_id: Uuid) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(RECENT_VIEW_TABLE)
+        .and_where_eq("view_id", view_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::service::{
+    recent::{read_recent_views, update_recent_view},
+    user::LoggedUser,
+    util::parse_from_payload,
+    view::sql_builder::check_view_ids,
+};
+use actix_web::{
+    web::{Data, Payload},
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{errors::ServerError, response::FlowyResponse};
+use flowy_workspace_infra::protobuf::{QueryRecentViewsParams, RecentViewRecord};
+use sqlx::PgPool;
+
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: RecentViewRecord = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id.clone()])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to update recent view")?;
+
+    let _ = update_recent_view(&mut transaction, view_id, params.get_open_time(), &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to update recent view.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn read_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: QueryRecentViewsParams = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read recent views")?;
+
+    let repeated_recent_view = read_recent_views(&mut transaction, params.get_limit(), &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read recent views.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_recent_view)?.into())
+}
@@ -0,0 +1,211 @@
+use crate::{
+    entities::{
+        doc::DOC_TABLE,
+        search::{IndexedRevTable, IndexedViewTable},
+        workspace::VIEW_TABLE,
+    },
+    service::search::index::{IndexedView, SearchHit, SearchIndex},
+    sqlx_ext::map_sqlx_error,
+};
+use actix_web::web::Data;
+use backend_service::errors::{internal_error, ServerError};
+use sqlx::{PgPool, Postgres};
+use std::{collections::HashSet, sync::Arc, time::Duration};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::spawn_blocking,
+    time::interval,
+};
+use uuid::Uuid;
+
+// The views that changed are indexed in a batch, so typing doesn't commit the index for each revision
+const INDEX_COMMIT_INTERVAL: Duration = Duration::from_secs(2);
+const INDEX_BATCH_SIZE: usize = 100;
+
+#[derive(Debug)]
+pub enum SearchIndexMsg {
+    IndexView {
+        view_id: String,
+    },
+    Sync {
+        ret: oneshot::Sender<Result<(), ServerError>>,
+    },
+    Search {
+        view_ids: Vec<String>,
+        query: String,
+        limit: usize,
+        ret: oneshot::Sender<Result<Vec<SearchHit>, ServerError>>,
+    },
+}
+
+pub struct SearchIndexActor {
+    receiver: Option<mpsc::Receiver<SearchIndexMsg>>,
+    index: Arc<SearchIndex>,
+    pg_pool: Data<PgPool>,
+    pending_view_ids: HashSet<String>,
+}
+
+impl SearchIndexActor {
+    pub fn new(receiver: mpsc::Receiver<SearchIndexMsg>, index: Arc<SearchIndex>, pg_pool: Data<PgPool>) -> Self {
+        Self {
+            receiver: Some(receiver),
+            index,
+            pg_pool,
+            pending_view_ids: HashSet::new(),
+        }
+    }
+
+    pub async fn run(mut self) {
+        let mut receiver = self
+            .receiver
+            .take()
+            .expect("SearchIndexActor's receiver should only take one time");
+
+        // The index might be behind the database, e.g. the database was restored from a backup
+        if let Err(e) = self.sync().await {
+            log::error!("Sync the search index failed: {:?}", e);
+        }
+
+        let mut interval = interval(INDEX_COMMIT_INTERVAL);
+        loop {
+            tokio::select! {
+                msg = receiver.recv() => match msg {
+                    Some(msg) => self.handle_message(msg).await,
+                    None => break,
+                },
+                _ = interval.tick() => {
+                    if let Err(e) = self.flush().await {
+                        log::error!("Index the views failed: {:?}", e);
+                    }
+                },
+            }
+        }
+    }
+
+    async fn handle_message(&mut self, msg: SearchIndexMsg) {
+        match msg {
+            SearchIndexMsg::IndexView { view_id } => {
+                self.pending_view_ids.insert(view_id);
+            },
+            SearchIndexMsg::Sync { ret } => {
+                let _ = ret.send(self.sync().await);
+            },
+            SearchIndexMsg::Search {
+                view_ids,
+                query,
+                limit,
+                ret,
+            } => {
+                let _ = ret.send(self.search(view_ids, query, limit).await);
+            },
+        }
+    }
+
+    // The views that changed before the search are indexed first, so the search sees its own writes
+    async fn search(
+        &mut self,
+        view_ids: Vec<String>,
+        query: String,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>, ServerError> {
+        let _ = self.flush().await?;
+        let index = self.index.clone();
+        spawn_blocking(move || index.search(&view_ids, &query, limit))
+            .await
+            .map_err(internal_error)?
+    }
+
+    async fn flush(&mut self) -> Result<(), ServerError> {
+        if self.pending_view_ids.is_empty() {
+            return Ok(());
+        }
+
+        let view_ids = self.pending_view_ids.drain().collect::<Vec<String>>();
+        self.index_views(view_ids).await
+    }
+
+    // Indexes the views that changed since they were indexed and removes the ones that don't exist anymore.
+    // Only the name and the rev_id are compared, so the views that didn't change aren't read again.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    async fn sync(&mut self) -> Result<(), ServerError> {
+        let index = self.index.clone();
+        let mut indexed_views = spawn_blocking(move || index.indexed_views())
+            .await
+            .map_err(internal_error)??;
+
+        let sql = format!(
+            "SELECT v.id, v.name, COALESCE(d.rev_id, 0) AS rev_id FROM {view} v LEFT JOIN {doc} d ON d.id = v.id",
+            view = VIEW_TABLE,
+            doc = DOC_TABLE
+        );
+        let tables = sqlx::query_as::<Postgres, IndexedRevTable>(&sql)
+            .fetch_all(self.pg_pool.get_ref())
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let mut changed_view_ids = vec![];
+        for table in tables {
+            let view_id = table.id.to_string();
+            match indexed_views.remove(&view_id) {
+                Some((name, rev_id)) if name == table.name && rev_id == table.rev_id => {},
+                _ => changed_view_ids.push(view_id),
+            }
+        }
+
+        tracing::debug!(
+            "Sync the search index, changed: {}, removed: {}",
+            changed_view_ids.len(),
+            indexed_views.len()
+        );
+        for view_id in indexed_views.keys() {
+            self.index.remove(view_id);
+        }
+        changed_view_ids.extend(self.pending_view_ids.drain());
+        self.index_views(changed_view_ids).await
+    }
+
+    async fn index_views(&self, view_ids: Vec<String>) -> Result<(), ServerError> {
+        for chunk in view_ids.chunks(INDEX_BATCH_SIZE) {
+            let tables = read_indexed_views(self.pg_pool.get_ref(), chunk).await?;
+            let found_view_ids = tables.iter().map(|table| table.id.to_string()).collect::<HashSet<String>>();
+            for view_id in chunk.iter().filter(|view_id| !found_view_ids.contains(*view_id)) {
+                self.index.remove(view_id);
+            }
+
+            for table in tables {
+                self.index.put(IndexedView {
+                    view_id: table.id.to_string(),
+                    name: table.name,
+                    text: table.text,
+                    rev_id: table.rev_id,
+                });
+            }
+        }
+
+        let index = self.index.clone();
+        spawn_blocking(move || index.commit()).await.map_err(internal_error)?
+    }
+}
+
+async fn read_indexed_views(pool: &PgPool, view_ids: &[String]) -> Result<Vec<IndexedViewTable>, ServerError> {
+    let view_ids = view_ids
+        .iter()
+        .flat_map(|view_id| Uuid::parse_str(view_id).ok())
+        .collect::<Vec<Uuid>>();
+
+    let sql = format!(
+        r#"
+            SELECT v.id, v.name, COALESCE(d.text, '') AS text, COALESCE(d.rev_id, 0) AS rev_id
+            FROM {view} v LEFT JOIN {doc} d ON d.id = v.id
+            WHERE v.id = ANY($1)
+        "#,
+        view = VIEW_TABLE,
+        doc = DOC_TABLE
+    );
+    let tables = sqlx::query_as::<Postgres, IndexedViewTable>(&sql)
+        .bind(view_ids)
+        .fetch_all(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(tables)
+}
@@ -0,0 +1,240 @@
+use crate::{
+    config::SearchLanguage,
+    service::search::{
+        query::{parse_query, QueryClause},
+        snippet::highlight,
+        tokenizer::{text_analyzer, tokenizer_name},
+    },
+};
+use backend_service::errors::{internal_error, ServerError};
+use flowy_document_infra::core::escape_html;
+use flowy_workspace_infra::protobuf::SearchResult;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use tantivy::{
+    collector::TopDocs,
+    directory::MmapDirectory,
+    doc,
+    query::{BooleanQuery, BoostQuery, Occur, Query, TermQuery},
+    schema::{Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, STORED, STRING},
+    tokenizer::TextAnalyzer,
+    DocAddress,
+    Document,
+    Index,
+    IndexReader,
+    IndexWriter,
+    ReloadPolicy,
+    TantivyError,
+    Term,
+};
+
+const WRITER_HEAP_SIZE: usize = 15_000_000;
+// The matches in the view name weigh more than the ones in the text
+const NAME_BOOST: f32 = 10.0;
+
+pub struct IndexedView {
+    pub view_id: String,
+    pub name: String,
+    pub text: String,
+    pub rev_id: i64,
+}
+
+#[derive(Debug)]
+pub struct SearchHit {
+    pub view_id: String,
+    pub view_name: String,
+    pub snippet: String,
+}
+
+impl std::convert::Into<SearchResult> for SearchHit {
+    fn into(self) -> SearchResult {
+        let mut result = SearchResult::default();
+        result.set_view_id(self.view_id);
+        result.set_view_name(self.view_name);
+        result.set_snippet(self.snippet);
+        result
+    }
+}
+
+struct SearchFields {
+    view_id: Field,
+    name: Field,
+    text: Field,
+    rev_id: Field,
+}
+
+// The tantivy index of the view names and the doc texts. The writes are buffered until the commit, the
+// searches only see the committed views.
+pub struct SearchIndex {
+    reader: IndexReader,
+    writer: Mutex<IndexWriter>,
+    analyzer: TextAnalyzer,
+    fields: SearchFields,
+}
+
+impl SearchIndex {
+    pub fn open(index_dir: &str, language: SearchLanguage) -> Result<Self, ServerError> {
+        let (schema, fields) = build_schema(tokenizer_name(language));
+        let index = open_or_rebuild(index_dir, schema)?;
+        if let Some(analyzer) = text_analyzer(language)? {
+            index.tokenizers().register(tokenizer_name(language), analyzer);
+        }
+        let writer = index
+            .writer_with_num_threads(1, WRITER_HEAP_SIZE)
+            .map_err(internal_error)?;
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .map_err(internal_error)?;
+        let analyzer = index.tokenizer_for_field(fields.text).map_err(internal_error)?;
+
+        Ok(Self {
+            reader,
+            writer: Mutex::new(writer),
+            analyzer,
+            fields,
+        })
+    }
+
+    // Replaces the view if it's indexed already
+    pub fn put(&self, view: IndexedView) {
+        let writer = self.writer.lock();
+        writer.delete_term(Term::from_field_text(self.fields.view_id, &view.view_id));
+        let _ = writer.add_document(doc!(
+            self.fields.view_id => view.view_id,
+            self.fields.name => view.name,
+            self.fields.text => view.text,
+            self.fields.rev_id => view.rev_id,
+        ));
+    }
+
+    pub fn remove(&self, view_id: &str) {
+        let writer = self.writer.lock();
+        writer.delete_term(Term::from_field_text(self.fields.view_id, view_id));
+    }
+
+    pub fn commit(&self) -> Result<(), ServerError> {
+        let _ = self.writer.lock().commit().map_err(internal_error)?;
+        self.reader.reload().map_err(internal_error)
+    }
+
+    // Returns the name and the rev_id of each indexed view
+    pub fn indexed_views(&self) -> Result<HashMap<String, (String, i64)>, ServerError> {
+        let searcher = self.reader.searcher();
+        let mut views = HashMap::new();
+        for (segment_ord, segment_reader) in searcher.segment_readers().iter().enumerate() {
+            for doc_id in segment_reader.doc_ids_alive() {
+                let address = DocAddress::new(segment_ord as u32, doc_id);
+                let doc = searcher.doc(address).map_err(internal_error)?;
+                let rev_id = doc
+                    .get_first(self.fields.rev_id)
+                    .and_then(|value| value.i64_value())
+                    .unwrap_or(0);
+                let view_id = self.text_value(&doc, self.fields.view_id);
+                views.insert(view_id, (self.text_value(&doc, self.fields.name), rev_id));
+            }
+        }
+        Ok(views)
+    }
+
+    // Only the views in the view_ids are searched, e.g. the views of the workspace that aren't in the trash
+    pub fn search(&self, view_ids: &[String], query: &str, limit: usize) -> Result<Vec<SearchHit>, ServerError> {
+        let clauses = parse_query(query, &self.analyzer);
+        if clauses.is_empty() || view_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let searcher = self.reader.searcher();
+        let query = self.build_query(&clauses, view_ids);
+        let top_docs = searcher
+            .search(&query, &TopDocs::with_limit(limit))
+            .map_err(internal_error)?;
+
+        let mut hits = vec![];
+        for (_score, address) in top_docs {
+            let doc = searcher.doc(address).map_err(internal_error)?;
+            let view_name = self.text_value(&doc, self.fields.name);
+            let text = self.text_value(&doc, self.fields.text);
+            let snippet = highlight(&self.analyzer, &text, &clauses)
+                .or_else(|| highlight(&self.analyzer, &view_name, &clauses))
+                .unwrap_or_else(|| escape_html(&view_name));
+
+            hits.push(SearchHit {
+                view_id: self.text_value(&doc, self.fields.view_id),
+                view_name,
+                snippet,
+            });
+        }
+        Ok(hits)
+    }
+
+    // Each clause must match either the name or the text
+    fn build_query(&self, clauses: &[QueryClause], view_ids: &[String]) -> BooleanQuery {
+        let mut subqueries = clauses
+            .iter()
+            .map(|clause| {
+                let name_query = BoostQuery::new(clause.to_query(self.fields.name), NAME_BOOST);
+                let clause_query = BooleanQuery::new(vec![
+                    (Occur::Should, Box::new(name_query) as Box<dyn Query>),
+                    (Occur::Should, clause.to_query(self.fields.text)),
+                ]);
+                (Occur::Must, Box::new(clause_query) as Box<dyn Query>)
+            })
+            .collect::<Vec<(Occur, Box<dyn Query>)>>();
+
+        let view_id_queries = view_ids
+            .iter()
+            .map(|view_id| {
+                let term = Term::from_field_text(self.fields.view_id, view_id);
+                let query: Box<dyn Query> = Box::new(TermQuery::new(term, IndexRecordOption::Basic));
+                (Occur::Should, query)
+            })
+            .collect::<Vec<(Occur, Box<dyn Query>)>>();
+        // The filter doesn't affect the score
+        let filter = BoostQuery::new(Box::new(BooleanQuery::new(view_id_queries)), 0.0);
+        subqueries.push((Occur::Must, Box::new(filter)));
+
+        BooleanQuery::new(subqueries)
+    }
+
+    fn text_value(&self, doc: &Document, field: Field) -> String {
+        doc.get_first(field)
+            .and_then(|value| value.text())
+            .unwrap_or("")
+            .to_owned()
+    }
+}
+
+// The index is removed and created again if its schema changed, e.g. it was built with another language.
+// The views are indexed again by the sync after that.
+fn open_or_rebuild(index_dir: &str, schema: Schema) -> Result<Index, ServerError> {
+    let _ = std::fs::create_dir_all(index_dir).map_err(internal_error)?;
+    let directory = MmapDirectory::open(index_dir).map_err(internal_error)?;
+    match Index::open_or_create(directory, schema.clone()) {
+        Err(TantivyError::SchemaError(e)) => {
+            log::warn!("Rebuild the search index in {}: {}", index_dir, e);
+            let _ = std::fs::remove_dir_all(index_dir).map_err(internal_error)?;
+            let _ = std::fs::create_dir_all(index_dir).map_err(internal_error)?;
+            let directory = MmapDirectory::open(index_dir).map_err(internal_error)?;
+            Index::open_or_create(directory, schema).map_err(internal_error)
+        },
+        result => result.map_err(internal_error),
+    }
+}
+
+fn build_schema(tokenizer: &str) -> (Schema, SearchFields) {
+    let indexing = TextFieldIndexing::default()
+        .set_tokenizer(tokenizer)
+        .set_index_option(IndexRecordOption::WithFreqsAndPositions);
+    let text_options = TextOptions::default().set_indexing_options(indexing).set_stored();
+
+    let mut builder = Schema::builder();
+    let fields = SearchFields {
+        view_id: builder.add_text_field("view_id", STRING | STORED),
+        name: builder.add_text_field("name", text_options.clone()),
+        text: builder.add_text_field("text", text_options),
+        rev_id: builder.add_i64_field("rev_id", STORED),
+    };
+    (builder.build(), fields)
+}
@@ -0,0 +1,68 @@
+mod actor;
+mod index;
+mod query;
+mod snippet;
+mod tokenizer;
+
+pub use index::*;
+
+use crate::service::search::actor::{SearchIndexActor, SearchIndexMsg};
+use actix_web::web::Data;
+use backend_service::errors::{internal_error, ServerError};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot};
+
+// The views are indexed in the background after they change. Call index_view whenever the name of the
+// view or the text of its doc changes, or the view is deleted.
+#[derive(Clone)]
+pub struct SearchIndexHandle {
+    sender: mpsc::Sender<SearchIndexMsg>,
+}
+
+impl SearchIndexHandle {
+    pub fn new(index: Arc<SearchIndex>, pg_pool: Data<PgPool>) -> Self {
+        let (sender, receiver) = mpsc::channel(1000);
+        let actor = SearchIndexActor::new(receiver, index, pg_pool);
+        tokio::task::spawn(actor.run());
+        Self { sender }
+    }
+
+    pub async fn index_view(&self, view_id: &str) {
+        let msg = SearchIndexMsg::IndexView {
+            view_id: view_id.to_owned(),
+        };
+        if let Err(e) = self.sender.send(msg).await {
+            log::error!("Schedule the indexing of {} failed: {}", view_id, e);
+        }
+    }
+
+    // Indexes the views that changed since they were indexed, e.g. after the database was restored
+    pub async fn sync(&self) -> Result<(), ServerError> {
+        let (ret, rx) = oneshot::channel();
+        let msg = SearchIndexMsg::Sync { ret };
+        self.send(msg, rx).await?
+    }
+
+    pub async fn search(
+        &self,
+        view_ids: Vec<String>,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>, ServerError> {
+        let (ret, rx) = oneshot::channel();
+        let msg = SearchIndexMsg::Search {
+            view_ids,
+            query: query.to_owned(),
+            limit,
+            ret,
+        };
+        self.send(msg, rx).await?
+    }
+
+    async fn send<T>(&self, msg: SearchIndexMsg, rx: oneshot::Receiver<T>) -> Result<T, ServerError> {
+        let _ = self.sender.send(msg).await.map_err(internal_error)?;
+        let result = rx.await.map_err(internal_error)?;
+        Ok(result)
+    }
+}
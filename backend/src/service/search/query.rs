@@ -0,0 +1,141 @@
+use tantivy::{
+    query::{FuzzyTermQuery, PhraseQuery, Query, TermQuery},
+    schema::{Field, IndexRecordOption},
+    tokenizer::{TextAnalyzer, Token},
+    Term,
+};
+
+// The shorter words only match exactly, otherwise a typo in them would match too many words
+const FUZZY_MIN_LEN: usize = 5;
+
+pub(crate) enum QueryClause {
+    // Matches the words within the distance. The prefix word also matches the words that start with it.
+    Word { text: String, distance: u8, prefix: bool },
+    // The words must appear next to each other in the same order
+    Phrase(Vec<String>),
+}
+
+impl QueryClause {
+    fn word(text: String, is_quoted: bool) -> Self {
+        let distance = match !is_quoted && text.chars().count() >= FUZZY_MIN_LEN {
+            true => 1,
+            false => 0,
+        };
+        QueryClause::Word {
+            text,
+            distance,
+            prefix: false,
+        }
+    }
+
+    pub(crate) fn to_query(&self, field: Field) -> Box<dyn Query> {
+        match self {
+            QueryClause::Word { text, distance, prefix } => {
+                let term = Term::from_field_text(field, text);
+                match (*distance, *prefix) {
+                    (0, false) => Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs)),
+                    (distance, true) => Box::new(FuzzyTermQuery::new_prefix(term, distance, true)),
+                    (distance, false) => Box::new(FuzzyTermQuery::new(term, distance, true)),
+                }
+            },
+            QueryClause::Phrase(words) => {
+                let terms = words
+                    .iter()
+                    .map(|word| Term::from_field_text(field, word))
+                    .collect::<Vec<Term>>();
+                Box::new(PhraseQuery::new(terms))
+            },
+        }
+    }
+
+    // Tells whether the token of the text is matched by the clause, it's used to highlight the token
+    pub(crate) fn matches(&self, token: &str) -> bool {
+        match self {
+            QueryClause::Word { text, distance, prefix } => {
+                let distance = *distance as usize;
+                if token == text || (*prefix && token.starts_with(text.as_str())) {
+                    return true;
+                }
+
+                let token = token.chars().collect::<Vec<char>>();
+                let text = text.chars().collect::<Vec<char>>();
+                match *prefix {
+                    false => distance > 0 && edit_distance(&token, &text) <= distance,
+                    true => {
+                        let min_len = text.len().saturating_sub(distance);
+                        let max_len = std::cmp::min(text.len() + distance, token.len());
+                        (min_len..=max_len).any(|len| edit_distance(&token[..len], &text) <= distance)
+                    },
+                }
+            },
+            QueryClause::Phrase(words) => words.iter().any(|word| word == token),
+        }
+    }
+}
+
+// The text inside the double quotes is a phrase, the other words are matched fuzzily. The last word
+// matches as a prefix too, so the results show up while the query is being typed, e.g. "roadm" finds
+// the "roadmap".
+pub(crate) fn parse_query(query: &str, analyzer: &TextAnalyzer) -> Vec<QueryClause> {
+    let mut clauses = vec![];
+    let mut is_last_quoted = false;
+    for (i, part) in query.split('"').enumerate() {
+        let is_quoted = i % 2 == 1;
+        let mut words = tokenize(analyzer, part)
+            .into_iter()
+            .map(|token| token.text)
+            .collect::<Vec<String>>();
+
+        match (is_quoted, words.len()) {
+            (_, 0) => continue,
+            (true, 1) => clauses.push(QueryClause::word(words.remove(0), true)),
+            (true, _) => clauses.push(QueryClause::Phrase(words)),
+            (false, _) => {
+                for word in words {
+                    clauses.push(QueryClause::word(word, false));
+                }
+            },
+        }
+        is_last_quoted = is_quoted;
+    }
+
+    let is_typing = !query.ends_with(|c: char| c.is_whitespace() || c == '"');
+    if is_typing && !is_last_quoted {
+        if let Some(QueryClause::Word { prefix, .. }) = clauses.last_mut() {
+            *prefix = true;
+        }
+    }
+    clauses
+}
+
+pub(crate) fn tokenize(analyzer: &TextAnalyzer, text: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut stream = analyzer.token_stream(text);
+    stream.process(&mut |token| tokens.push(token.clone()));
+    tokens
+}
+
+// The number of the insertions, deletions, substitutions and transpositions of the adjacent chars that
+// turn one into the other, the same as the distance of the FuzzyTermQuery.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut distance = std::cmp::min(distances[i - 1][j] + 1, distances[i][j - 1] + 1);
+            distance = std::cmp::min(distance, distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = std::cmp::min(distance, distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
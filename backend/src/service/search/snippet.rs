@@ -0,0 +1,29 @@
+use crate::service::search::query::{tokenize, QueryClause};
+use flowy_document_infra::core::escape_html;
+use tantivy::tokenizer::TextAnalyzer;
+
+const SNIPPET_MAX_WORDS: usize = 16;
+// The words before the first match that are kept in the snippet
+const SNIPPET_LEADING_WORDS: usize = 4;
+
+// Returns the part of the text around the first match, with the matched words wrapped in <b></b>. The
+// rest of the text is escaped. Returns None if nothing in the text matches.
+pub(crate) fn highlight(analyzer: &TextAnalyzer, text: &str, clauses: &[QueryClause]) -> Option<String> {
+    let tokens = tokenize(analyzer, text);
+    let is_match = |token: &str| clauses.iter().any(|clause| clause.matches(token));
+    let first = tokens.iter().position(|token| is_match(&token.text))?;
+    let start = first.saturating_sub(SNIPPET_LEADING_WORDS);
+    let end = std::cmp::min(start + SNIPPET_MAX_WORDS, tokens.len());
+
+    let mut snippet = String::new();
+    let mut offset = tokens[start].offset_from;
+    for token in tokens[start..end].iter().filter(|token| is_match(&token.text)) {
+        snippet.push_str(&escape_html(&text[offset..token.offset_from]));
+        snippet.push_str("<b>");
+        snippet.push_str(&escape_html(&text[token.offset_from..token.offset_to]));
+        snippet.push_str("</b>");
+        offset = token.offset_to;
+    }
+    snippet.push_str(&escape_html(&text[offset..tokens[end - 1].offset_to]));
+    Some(snippet)
+}
@@ -0,0 +1,112 @@
+use crate::config::SearchLanguage;
+use backend_service::errors::{internal_error, ServerError};
+use jieba_rs::Jieba;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tantivy::tokenizer::{BoxTokenStream, LowerCaser, RemoveLongFilter, TextAnalyzer, Token, TokenStream, Tokenizer};
+
+// The same as the limit of the default tokenizer of tantivy
+const MAX_TOKEN_LEN: usize = 40;
+
+// The name that the tokenizer of the language is registered with. It's saved in the schema, so the index
+// that was built with another language is detected when it's opened.
+pub(crate) fn tokenizer_name(language: SearchLanguage) -> &'static str {
+    match language {
+        SearchLanguage::Default => "default",
+        SearchLanguage::Chinese => "jieba",
+        SearchLanguage::Japanese => "lindera",
+    }
+}
+
+// Returns None if the language uses the default tokenizer of tantivy
+pub(crate) fn text_analyzer(language: SearchLanguage) -> Result<Option<TextAnalyzer>, ServerError> {
+    let segmenter = match language {
+        SearchLanguage::Default => return Ok(None),
+        SearchLanguage::Chinese => Segmenter::Jieba(Arc::new(Jieba::new())),
+        SearchLanguage::Japanese => {
+            let tokenizer = lindera::tokenizer::Tokenizer::new().map_err(internal_error)?;
+            Segmenter::Lindera(Arc::new(Mutex::new(tokenizer)))
+        },
+    };
+
+    let analyzer = TextAnalyzer::from(SegmentTokenizer { segmenter })
+        .filter(RemoveLongFilter::limit(MAX_TOKEN_LEN))
+        .filter(LowerCaser);
+    Ok(Some(analyzer))
+}
+
+#[derive(Clone)]
+enum Segmenter {
+    Jieba(Arc<Jieba>),
+    Lindera(Arc<Mutex<lindera::tokenizer::Tokenizer>>),
+}
+
+impl Segmenter {
+    // The words are in the order they appear in the text, the whitespaces and the punctuations may be
+    // returned as the words too.
+    fn segment<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        match self {
+            Segmenter::Jieba(jieba) => jieba.cut(text, true),
+            Segmenter::Lindera(tokenizer) => match tokenizer.lock().tokenize(text) {
+                Ok(tokens) => tokens.into_iter().map(|token| token.text).collect(),
+                Err(e) => {
+                    log::error!("Tokenize the japanese text failed: {:?}", e);
+                    vec![text]
+                },
+            },
+        }
+    }
+}
+
+// Splits the text into the words of the dictionary, the Chinese or the Japanese sentence isn't separated
+// by the whitespaces.
+#[derive(Clone)]
+struct SegmentTokenizer {
+    segmenter: Segmenter,
+}
+
+impl Tokenizer for SegmentTokenizer {
+    fn token_stream<'a>(&self, text: &'a str) -> BoxTokenStream<'a> {
+        let mut tokens = vec![];
+        let mut offset = 0;
+        for word in self.segmenter.segment(text) {
+            let start = match text[offset..].find(word) {
+                None => continue,
+                Some(index) => offset + index,
+            };
+            offset = start + word.len();
+            if !word.chars().any(|c| c.is_alphanumeric()) {
+                continue;
+            }
+
+            tokens.push(Token {
+                offset_from: start,
+                offset_to: offset,
+                position: tokens.len(),
+                text: word.to_owned(),
+                position_length: 1,
+            });
+        }
+        BoxTokenStream::from(SegmentTokenStream { tokens, index: 0 })
+    }
+}
+
+struct SegmentTokenStream {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl TokenStream for SegmentTokenStream {
+    fn advance(&mut self) -> bool {
+        if self.index < self.tokens.len() {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn token(&self) -> &Token { &self.tokens[self.index - 1] }
+
+    fn token_mut(&mut self) -> &mut Token { &mut self.tokens[self.index - 1] }
+}
@@ -0,0 +1,4 @@
+pub mod router;
+mod share;
+
+pub(crate) use share::*;
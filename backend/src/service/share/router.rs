@@ -0,0 +1,117 @@
+use crate::{
+    config::{PUBLIC_PAGE_CSP, SHARED_PAGE_ROUTE},
+    service::{
+        publish::PublicPage,
+        share::{create_share_link, read_share_links, read_shared_page, revoke_share_link},
+        user::LoggedUser,
+        util::parse_from_payload,
+        view::sql_builder::check_view_ids,
+        workspace::sql_builder::check_workspace_id,
+    },
+};
+use actix_web::{
+    http::header::{CONTENT_SECURITY_POLICY, LOCATION},
+    web::{Data, Path, Payload, Query},
+    HttpRequest,
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{
+    errors::{invalid_params, ServerError},
+    response::FlowyResponse,
+};
+use flowy_workspace_infra::protobuf::{CreateShareLinkParams, ShareLinkIdentifier, WorkspaceIdentifier};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn create_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: CreateShareLinkParams = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id.clone()])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to create share link")?;
+
+    let share_link = create_share_link(&mut transaction, view_id, params, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to create share link.")?;
+
+    Ok(FlowyResponse::success().pb(share_link)?.into())
+}
+
+pub async fn read_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: WorkspaceIdentifier = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read share links")?;
+
+    let repeated_share_link = read_share_links(&mut transaction, workspace_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read share links.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_share_link)?.into())
+}
+
+pub async fn revoke_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ShareLinkIdentifier = parse_from_payload(payload).await?;
+    let link_id = Uuid::parse_str(params.get_link_id()).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to revoke share link")?;
+
+    let _ = revoke_share_link(&mut transaction, link_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to revoke share link.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+#[derive(serde::Deserialize)]
+pub struct SharedPageQuery {
+    passcode: Option<String>,
+}
+
+// The shared pages are public, the link and the passcode are all that the reader needs. The passcode
+// is kept in the query when the page is redirected.
+pub async fn read_page_handler(
+    request: HttpRequest,
+    slug: Path<String>,
+    query: Query<SharedPageQuery>,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
+    let passcode = query.into_inner().passcode.unwrap_or_default();
+    match read_shared_page(pool.get_ref(), &slug.into_inner(), &passcode).await? {
+        PublicPage::Html(html) => Ok(HttpResponse::Ok()
+            .content_type("text/html; charset=utf-8")
+            .insert_header((CONTENT_SECURITY_POLICY, PUBLIC_PAGE_CSP))
+            .body(html)),
+        PublicPage::Moved(slug) => {
+            let mut location = format!("{}/{}", SHARED_PAGE_ROUTE, slug);
+            if !request.query_string().is_empty() {
+                location = format!("{}?{}", location, request.query_string());
+            }
+            Ok(HttpResponse::MovedPermanently().insert_header((LOCATION, location)).finish())
+        },
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    entities::share::{ShareLinkTable, SHARE_LINK_TABLE},
+    service::{
+        publish::{read_workspace_slug, render_page, PublicPage},
+        slug::read_or_create_slug,
+        user::LoggedUser,
+        view::{check_view_writable, read_belong_to_workspace_id, read_view_table},
+        workspace::{check_workspace_owner, sql_builder::check_workspace_id},
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use anyhow::Context;
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use backend_service::errors::{internal_error, invalid_params, ServerError};
+use chrono::{TimeZone, Utc};
+use flowy_workspace_infra::protobuf::{CreateShareLinkParams, RepeatedShareLink, ShareLink};
+use rand_core::OsRng;
+use sqlx::{postgres::PgArguments, PgPool, Postgres};
+use uuid::Uuid;
+
+// Only the owner of the workspace can share its views. The passcode is hashed, so it can't be read
+// back after the link is created.
+#[tracing::instrument(skip(transaction, params, user), err)]
+pub(crate) async fn create_share_link(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    params: CreateShareLinkParams,
+    user: &LoggedUser,
+) -> Result<ShareLink, ServerError> {
+    let table = read_view_table(view_id, transaction).await?;
+    let _ = check_view_writable(transaction, view_id, user).await?;
+    let workspace_id = match read_belong_to_workspace_id(transaction, &table.belong_to_id).await? {
+        None => return Err(invalid_params("The view doesn't belong to any workspace")),
+        Some(workspace_id) => workspace_id,
+    };
+    let workspace_uuid = check_workspace_id(workspace_id.clone())?;
+    let _ = check_workspace_owner(transaction, workspace_uuid, user, "share the views of").await?;
+
+    let expire_time = match params.get_expire_time() {
+        0 => None,
+        timestamp => match Utc.timestamp_opt(timestamp, 0).single() {
+            Some(expire_time) if expire_time > Utc::now() => Some(expire_time),
+            _ => return Err(invalid_params("The expire time of the share link must be in the future")),
+        },
+    };
+
+    let passcode_hash = match params.get_passcode() {
+        "" => "".to_owned(),
+        passcode => hash_passcode(passcode)?,
+    };
+
+    let table = ShareLinkTable {
+        id: Uuid::new_v4(),
+        view_id,
+        workspace_id,
+        owner_id: user.user_id.clone(),
+        passcode_hash,
+        expire_time,
+        create_time: Utc::now(),
+    };
+
+    let (sql, args) = SqlBuilder::create(SHARE_LINK_TABLE)
+        .add_arg("id", table.id)
+        .add_arg("view_id", table.view_id)
+        .add_arg("workspace_id", &table.workspace_id)
+        .add_arg("owner_id", &table.owner_id)
+        .add_arg("passcode_hash", &table.passcode_hash)
+        .add_some_arg("expire_time", table.expire_time)
+        .add_arg("create_time", table.create_time)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let slug = read_share_link_slug(transaction, &table).await?;
+    let mut share_link: ShareLink = table.into();
+    share_link.set_slug(slug);
+    Ok(share_link)
+}
+
+// The expired links are kept in the table but never returned
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn read_share_links(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    user: &LoggedUser,
+) -> Result<RepeatedShareLink, ServerError> {
+    let _ = check_workspace_owner(transaction, workspace_id, user, "manage the share links of").await?;
+    let (sql, args) = SqlBuilder::select(SHARE_LINK_TABLE)
+        .add_field("*")
+        .and_where_eq("workspace_id", workspace_id.to_string())
+        .order_by("create_time", false)
+        .build()?;
+
+    let tables = sqlx::query_as_with::<Postgres, ShareLinkTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut share_links = vec![];
+    for table in tables.into_iter().filter(|table| !table.is_expired()) {
+        let slug = read_share_link_slug(transaction, &table).await?;
+        let mut share_link: ShareLink = table.into();
+        share_link.set_slug(slug);
+        share_links.push(share_link);
+    }
+    let mut repeated_share_link = RepeatedShareLink::default();
+    repeated_share_link.set_items(share_links.into());
+    Ok(repeated_share_link)
+}
+
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn revoke_share_link(
+    transaction: &mut DBTransaction<'_>,
+    link_id: Uuid,
+    user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let table = read_share_link_table(transaction, link_id).await?;
+    let workspace_id = check_workspace_id(table.workspace_id.clone())?;
+    let _ = check_workspace_owner(transaction, workspace_id, user, "manage the share links of").await?;
+
+    let (sql, args) = SqlBuilder::delete(SHARE_LINK_TABLE)
+        .and_where_eq("id", link_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
+
+pub(crate) async fn delete_share_links(transaction: &mut DBTransaction<'_>, view_id: Uuid) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(SHARE_LINK_TABLE)
+        .and_where_eq("view_id", view_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Unlike the published page, the shared page is rendered from the latest revision of the doc on each
+// read. The expired link is treated as if it doesn't exist. The link_id is the last part of the slug, the
+// slug that is out of date is redirected to the current one. The link_id alone still works.
+#[tracing::instrument(skip(pool, passcode), err)]
+pub(crate) async fn read_shared_page(pool: &PgPool, slug: &str, passcode: &str) -> Result<PublicPage, ServerError> {
+    let link_id = slug.rsplit('/').next().unwrap_or_default();
+    let link_id = Uuid::parse_str(link_id).map_err(|_| ServerError::record_not_found())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read shared page")?;
+    let table = read_share_link_table(&mut transaction, link_id).await?;
+    if table.is_expired() {
+        return Err(ServerError::record_not_found().context("The share link is expired"));
+    }
+
+    if slug.contains('/') {
+        let current_slug = read_share_link_slug(&mut transaction, &table).await?;
+        if current_slug != slug {
+            return Ok(PublicPage::Moved(current_slug));
+        }
+    }
+
+    if !table.passcode_hash.is_empty() && !verify_passcode(passcode, &table.passcode_hash) {
+        return Err(ServerError::unauthorized().context("The passcode of the share link doesn't match"));
+    }
+
+    let view = read_view_table(table.view_id, &mut transaction).await?;
+    let (html, _) = render_page(&mut transaction, table.view_id, &view.name, &[], None).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read shared page.")?;
+    Ok(PublicPage::Html(html))
+}
+
+async fn read_share_link_slug(
+    transaction: &mut DBTransaction<'_>,
+    table: &ShareLinkTable,
+) -> Result<String, ServerError> {
+    let view = read_view_table(table.view_id, transaction).await?;
+    let view_id = table.view_id.to_string();
+    let workspace_slug = read_workspace_slug(transaction, &table.workspace_id).await?;
+    let view_slug = read_or_create_slug(transaction, &table.workspace_id, &view_id, &view.name).await?;
+    Ok(format!("{}/{}/{}", workspace_slug, view_slug, table.id))
+}
+
+async fn read_share_link_table(
+    transaction: &mut DBTransaction<'_>,
+    link_id: Uuid,
+) -> Result<ShareLinkTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(SHARE_LINK_TABLE)
+        .add_field("*")
+        .and_where_eq("id", link_id)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, ShareLinkTable, PgArguments>(&sql, args)
+        .fetch_one(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(table)
+}
+
+fn hash_passcode(passcode: &str) -> Result<String, ServerError> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(passcode.as_bytes(), &salt)
+        .map_err(internal_error)?;
+    Ok(hash.to_string())
+}
+
+fn verify_passcode(passcode: &str, passcode_hash: &str) -> bool {
+    match PasswordHash::new(passcode_hash) {
+        Ok(hash) => Argon2::default().verify_password(passcode.as_bytes(), &hash).is_ok(),
+        Err(e) => {
+            log::error!("Parse the passcode hash failed: {:?}", e);
+            false
+        },
+    }
+}
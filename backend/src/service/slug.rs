@@ -0,0 +1,222 @@
+use crate::{
+    entities::slug::{SlugTable, SLUG_TABLE},
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::ServerError;
+use chrono::Utc;
+use sqlx::{postgres::PgArguments, Postgres};
+
+// The scope of the workspace slugs, the scope of the view slugs is the id of their workspace
+pub(crate) const WORKSPACE_SLUG_SCOPE: &str = "";
+
+const MAX_SLUG_WORDS_LEN: usize = 48;
+const DEFAULT_SLUG_WORDS: &str = "untitled";
+
+// Returns the current slug of the object, the slug is created from the name if the object doesn't have
+// one yet.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn read_or_create_slug(
+    transaction: &mut DBTransaction<'_>,
+    scope_id: &str,
+    object_id: &str,
+    name: &str,
+) -> Result<String, ServerError> {
+    if let Some(table) = read_current_slug(transaction, object_id).await? {
+        return Ok(table.slug);
+    }
+
+    let slug = unique_slug(transaction, scope_id, &slug_words(name)).await?;
+    let _ = insert_slug(transaction, scope_id, object_id, &slug).await?;
+    Ok(slug)
+}
+
+// The object gets the slug of the new name, the old slug is kept so it can be redirected. Returns None
+// if the object didn't have a slug or the slug of the name didn't change.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn rename_slug(
+    transaction: &mut DBTransaction<'_>,
+    object_id: &str,
+    name: &str,
+) -> Result<Option<SlugTable>, ServerError> {
+    let current = match read_current_slug(transaction, object_id).await? {
+        None => return Ok(None),
+        Some(current) => current,
+    };
+
+    let words = slug_words(name);
+    if is_slug_of(&current.slug, &words) {
+        return Ok(None);
+    }
+
+    let _ = set_current(transaction, &current.scope_id, &current.slug, false).await?;
+    // Renaming back to the old name takes the old slug back
+    let previous = read_object_slugs(transaction, object_id)
+        .await?
+        .into_iter()
+        .find(|table| is_slug_of(&table.slug, &words));
+    let slug = match previous {
+        Some(previous) => {
+            let _ = set_current(transaction, &previous.scope_id, &previous.slug, true).await?;
+            previous.slug
+        },
+        None => {
+            let slug = unique_slug(transaction, &current.scope_id, &words).await?;
+            let _ = insert_slug(transaction, &current.scope_id, object_id, &slug).await?;
+            slug
+        },
+    };
+
+    Ok(Some(SlugTable {
+        scope_id: current.scope_id,
+        slug,
+        object_id: object_id.to_owned(),
+        is_current: true,
+        create_time: Utc::now(),
+    }))
+}
+
+// Finds the slug in the scope, including the ones that were replaced after renaming
+pub(crate) async fn resolve_slug(
+    transaction: &mut DBTransaction<'_>,
+    scope_id: &str,
+    slug: &str,
+) -> Result<Option<SlugTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select(SLUG_TABLE)
+        .add_field("*")
+        .and_where_eq("scope_id", scope_id)
+        .and_where_eq("slug", slug)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, SlugTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(table)
+}
+
+pub(crate) async fn read_current_slug(
+    transaction: &mut DBTransaction<'_>,
+    object_id: &str,
+) -> Result<Option<SlugTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select(SLUG_TABLE)
+        .add_field("*")
+        .and_where_eq("object_id", object_id)
+        .and_where_eq("is_current", true)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, SlugTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(table)
+}
+
+async fn read_object_slugs(
+    transaction: &mut DBTransaction<'_>,
+    object_id: &str,
+) -> Result<Vec<SlugTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select(SLUG_TABLE)
+        .add_field("*")
+        .and_where_eq("object_id", object_id)
+        .build()?;
+
+    let tables = sqlx::query_as_with::<Postgres, SlugTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(tables)
+}
+
+async fn insert_slug(
+    transaction: &mut DBTransaction<'_>,
+    scope_id: &str,
+    object_id: &str,
+    slug: &str,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::create(SLUG_TABLE)
+        .add_arg("scope_id", scope_id)
+        .add_arg("slug", slug)
+        .add_arg("object_id", object_id)
+        .add_arg("is_current", true)
+        .add_arg("create_time", Utc::now())
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+async fn set_current(
+    transaction: &mut DBTransaction<'_>,
+    scope_id: &str,
+    slug: &str,
+    is_current: bool,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::update(SLUG_TABLE)
+        .add_arg("is_current", is_current)
+        .and_where_eq("scope_id", scope_id)
+        .and_where_eq("slug", slug)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// The words are suffixed with the smallest number that isn't taken in the scope, e.g. roadmap-2. The
+// slugs of the deleted objects are kept, so they are never taken by another object.
+async fn unique_slug(transaction: &mut DBTransaction<'_>, scope_id: &str, words: &str) -> Result<String, ServerError> {
+    let (sql, args) = SqlBuilder::select(SLUG_TABLE)
+        .add_field("*")
+        .and_where_eq("scope_id", scope_id)
+        .and_where_like("slug", &format!("{}%", words))
+        .build()?;
+
+    let taken_slugs = sqlx::query_as_with::<Postgres, SlugTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .into_iter()
+        .map(|table| table.slug)
+        .collect::<Vec<String>>();
+
+    let mut slug = words.to_owned();
+    let mut suffix = 1;
+    while taken_slugs.contains(&slug) {
+        suffix += 1;
+        slug = format!("{}-{}", words, suffix);
+    }
+    Ok(slug)
+}
+
+// The lowercase ascii words of the name that are joined by the dash
+fn slug_words(name: &str) -> String {
+    let mut words = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-");
+    words.truncate(MAX_SLUG_WORDS_LEN);
+
+    match words.trim_end_matches('-') {
+        "" => DEFAULT_SLUG_WORDS.to_owned(),
+        words => words.to_owned(),
+    }
+}
+
+// Tells whether the slug was made from the words, either as it is or with the number suffix
+fn is_slug_of(slug: &str, words: &str) -> bool {
+    match slug.strip_prefix(words) {
+        Some("") => true,
+        Some(suffix) => match suffix.strip_prefix('-') {
+            Some(number) => !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()),
+            None => false,
+        },
+        None => false,
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
     entities::workspace::{TrashTable, TRASH_TABLE},
     service::{
-        app::app::{delete_app, read_app_table},
+        app::app::{check_app_writable, delete_app, read_app_table},
         user::LoggedUser,
-        view::{delete_view, read_view_table},
+        view::{check_view_writable, delete_view, read_view_table},
+        workspace::{check_workspace_owner, check_workspace_writable, delete_workspace, read_workspace_table},
     },
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
@@ -20,6 +21,8 @@ pub(crate) async fn create_trash(
     user: LoggedUser,
 ) -> Result<(), ServerError> {
     for (trash_id, ty) in records {
+        let _ = check_trash_target_writable(transaction as &mut DBTransaction<'_>, trash_id, ty, &user).await?;
+
         let (sql, args) = SqlBuilder::create(TRASH_TABLE)
             .add_arg("id", trash_id)
             .add_arg("user_id", &user.user_id)
@@ -52,7 +55,10 @@ pub(crate) async fn delete_all_trash(
         .collect::<Vec<(Uuid, i32)>>();
     tracing::Span::current().record("delete_rows", &format!("{:?}", rows).as_str());
     let affected_row_count = rows.len();
-    let _ = delete_trash_targets(transaction as &mut DBTransaction<'_>, rows).await?;
+    for (id, ty) in &rows {
+        let _ = check_trash_target_writable(transaction as &mut DBTransaction<'_>, *id, *ty, user).await?;
+    }
+    let _ = delete_trash_targets(transaction as &mut DBTransaction<'_>, rows, user).await?;
 
     let (sql, args) = SqlBuilder::delete(TRASH_TABLE)
         .and_where_eq("user_id", &user.user_id)
@@ -67,16 +73,19 @@ pub(crate) async fn delete_all_trash(
     Ok(())
 }
 
-#[tracing::instrument(skip(transaction), err)]
+#[tracing::instrument(skip(transaction, user), err)]
 pub(crate) async fn delete_trash(
     transaction: &mut DBTransaction<'_>,
     records: Vec<(Uuid, i32)>,
+    user: &LoggedUser,
 ) -> Result<(), ServerError> {
     for (trash_id, _) in records {
-        // Read the trash_table and delete the original table according to the TrashType
+        // Read the trash_table and delete the original table according to the TrashType. The trash of
+        // other users can't be deleted even if its id is known.
         let (sql, args) = SqlBuilder::select(TRASH_TABLE)
             .add_field("*")
             .and_where_eq("id", trash_id)
+            .and_where_eq("user_id", &user.user_id)
             .build()?;
 
         let trash_table = sqlx::query_as_with::<Postgres, TrashTable, PgArguments>(&sql, args)
@@ -84,14 +93,25 @@ pub(crate) async fn delete_trash(
             .await
             .map_err(map_sqlx_error)?;
 
+        let _ = check_trash_target_writable(
+            transaction as &mut DBTransaction<'_>,
+            trash_table.id,
+            trash_table.ty,
+            user,
+        )
+        .await?;
         let _ = delete_trash_targets(
             transaction as &mut DBTransaction<'_>,
             vec![(trash_table.id.clone(), trash_table.ty)],
+            user,
         )
         .await?;
 
         // Delete the trash table
-        let (sql, args) = SqlBuilder::delete(TRASH_TABLE).and_where_eq("id", &trash_id).build()?;
+        let (sql, args) = SqlBuilder::delete(TRASH_TABLE)
+            .and_where_eq("id", &trash_id)
+            .and_where_eq("user_id", &user.user_id)
+            .build()?;
         let _ = sqlx::query_with(&sql, args)
             .execute(transaction as &mut DBTransaction<'_>)
             .await
@@ -100,10 +120,11 @@ pub(crate) async fn delete_trash(
     Ok(())
 }
 
-#[tracing::instrument(skip(transaction, targets), err)]
+#[tracing::instrument(skip(transaction, targets, user), err)]
 async fn delete_trash_targets(
     transaction: &mut DBTransaction<'_>,
     targets: Vec<(Uuid, i32)>,
+    user: &LoggedUser,
 ) -> Result<(), ServerError> {
     for (id, ty) in targets {
         match TrashType::from_i32(ty) {
@@ -111,10 +132,13 @@ async fn delete_trash_targets(
             Some(ty) => match ty {
                 TrashType::Unknown => {},
                 TrashType::View => {
-                    let _ = delete_view(transaction as &mut DBTransaction<'_>, vec![id]).await;
+                    let _ = delete_view(transaction as &mut DBTransaction<'_>, vec![id], user).await;
                 },
                 TrashType::App => {
-                    let _ = delete_app(transaction as &mut DBTransaction<'_>, id).await;
+                    let _ = delete_app(transaction as &mut DBTransaction<'_>, id, user).await;
+                },
+                TrashType::Workspace => {
+                    let _ = delete_workspace(transaction as &mut DBTransaction<'_>, id, user).await;
                 },
             },
         }
@@ -123,6 +147,23 @@ async fn delete_trash_targets(
     Ok(())
 }
 
+async fn check_trash_target_writable(
+    transaction: &mut DBTransaction<'_>,
+    id: Uuid,
+    ty: i32,
+    user: &LoggedUser,
+) -> Result<(), ServerError> {
+    match TrashType::from_i32(ty) {
+        Some(TrashType::View) => check_view_writable(transaction, id, user).await,
+        Some(TrashType::App) => check_app_writable(transaction, id, user).await,
+        Some(TrashType::Workspace) => {
+            let _ = check_workspace_owner(transaction, id, user, "delete").await?;
+            check_workspace_writable(transaction, &id.to_string()).await
+        },
+        _ => Ok(()),
+    }
+}
+
 pub(crate) async fn read_trash_ids(
     user: &LoggedUser,
     transaction: &mut DBTransaction<'_>,
@@ -136,6 +177,19 @@ pub(crate) async fn read_trash_ids(
     Ok(ids)
 }
 
+// The ids of the trash that can be passed to the sql filter
+pub(crate) async fn read_trash_uuids(
+    user: &LoggedUser,
+    transaction: &mut DBTransaction<'_>,
+) -> Result<Vec<Uuid>, ServerError> {
+    let ids = read_trash_ids(user, transaction)
+        .await?
+        .iter()
+        .flat_map(|id| Uuid::parse_str(id).ok())
+        .collect::<Vec<Uuid>>();
+    Ok(ids)
+}
+
 #[tracing::instrument(skip(transaction, user), err)]
 pub(crate) async fn read_trash(
     transaction: &mut DBTransaction<'_>,
@@ -163,6 +217,9 @@ pub(crate) async fn read_trash(
                 TrashType::App => {
                     trash.push(read_app_table(table.id, transaction).await?.into());
                 },
+                TrashType::Workspace => {
+                    trash.push(read_workspace_table(transaction, table.id).await?.into());
+                },
             },
         }
     }
@@ -0,0 +1,4 @@
+pub mod router;
+mod usage;
+
+pub use usage::*;
@@ -0,0 +1,50 @@
+use crate::{
+    config::MAX_API_USAGE_DAYS,
+    service::{
+        usage::{flush_api_usage, read_api_usage},
+        user::LoggedUser,
+        util::parse_from_payload,
+        workspace::sql_builder::check_workspace_id,
+    },
+};
+use actix_web::{
+    web::{Data, Payload},
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{
+    errors::{invalid_params, ServerError},
+    response::FlowyResponse,
+};
+use flowy_workspace_infra::protobuf::ReadApiUsageParams;
+use sqlx::PgPool;
+
+pub async fn read_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ReadApiUsageParams = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    if params.get_days() <= 0 || params.get_days() > MAX_API_USAGE_DAYS {
+        return Err(invalid_params(format!("The days must be 1 to {}", MAX_API_USAGE_DAYS)));
+    }
+
+    // Include the calls that are not flushed yet, e.g. the ones that were sent just now
+    if let Err(e) = flush_api_usage(pool.get_ref()).await {
+        log::error!("Flush API usage failed: {:?}", e);
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read API usage")?;
+
+    let repeated_usage = read_api_usage(&mut transaction, workspace_id, params.get_days(), logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read API usage.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_usage)?.into())
+}
@@ -0,0 +1,146 @@
+use crate::{
+    entities::usage::{ApiUsageTable, API_USAGE_TABLE},
+    service::{user::LoggedUser, workspace::read_workspace_table},
+    sqlx_ext::{map_sqlx_error, DBTransaction},
+};
+use backend_service::errors::ServerError;
+use chrono::{Duration, NaiveDate, Utc};
+use dashmap::DashMap;
+use flowy_workspace_infra::protobuf::{ApiUsage, RepeatedApiUsage};
+use lazy_static::lazy_static;
+use sqlx::{PgPool, Postgres};
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref API_USAGE: ApiUsageRecorder = ApiUsageRecorder::new();
+}
+
+// The user_id is the owner of the bot if the call is sent with a bot token. The workspace_id is the
+// workspace of the bot, the calls of the user don't belong to any workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiCaller {
+    pub user_id: String,
+    pub bot_id: String,
+    pub workspace_id: String,
+}
+
+impl ApiCaller {
+    pub fn user(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+            bot_id: "".to_owned(),
+            workspace_id: "".to_owned(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct ApiUsageCounter {
+    call_count: i64,
+    request_bytes: i64,
+    response_bytes: i64,
+}
+
+type ApiUsageKey = (NaiveDate, ApiCaller);
+
+// The calls are counted in memory and added to the daily usage in the database by flush_api_usage,
+// so recording a call never waits for the database.
+pub struct ApiUsageRecorder(DashMap<ApiUsageKey, ApiUsageCounter>);
+impl ApiUsageRecorder {
+    pub fn new() -> Self { Self(DashMap::new()) }
+
+    pub fn record(&self, caller: ApiCaller, request_bytes: u64, response_bytes: u64) {
+        let mut counter = self.0.entry((Utc::today().naive_utc(), caller)).or_default();
+        counter.call_count += 1;
+        counter.request_bytes += request_bytes as i64;
+        counter.response_bytes += response_bytes as i64;
+    }
+
+    fn take(&self) -> Vec<(ApiUsageKey, ApiUsageCounter)> {
+        let keys: Vec<ApiUsageKey> = self.0.iter().map(|item| item.key().clone()).collect();
+        keys.into_iter().flat_map(|key| self.0.remove(&key)).collect()
+    }
+
+    fn merge(&self, key: ApiUsageKey, counter: ApiUsageCounter) {
+        let mut merged = self.0.entry(key).or_default();
+        merged.call_count += counter.call_count;
+        merged.request_bytes += counter.request_bytes;
+        merged.response_bytes += counter.response_bytes;
+    }
+}
+
+// The counters that fail to be saved are kept in memory and saved by the next flush
+pub async fn flush_api_usage(pool: &PgPool) -> Result<(), ServerError> {
+    let mut result = Ok(());
+    for (key, counter) in API_USAGE.take() {
+        if let Err(e) = save_api_usage(pool, &key, &counter).await {
+            API_USAGE.merge(key, counter);
+            result = Err(e);
+        }
+    }
+    result
+}
+
+async fn save_api_usage(pool: &PgPool, key: &ApiUsageKey, counter: &ApiUsageCounter) -> Result<(), ServerError> {
+    let (usage_date, caller) = key;
+    let sql = format!(
+        r#"
+            INSERT INTO {0} (usage_date, user_id, bot_id, workspace_id, call_count, request_bytes, response_bytes)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (usage_date, user_id, bot_id) DO UPDATE SET
+                call_count = {0}.call_count + EXCLUDED.call_count,
+                request_bytes = {0}.request_bytes + EXCLUDED.request_bytes,
+                response_bytes = {0}.response_bytes + EXCLUDED.response_bytes
+        "#,
+        API_USAGE_TABLE
+    );
+
+    let _ = sqlx::query(&sql)
+        .bind(usage_date)
+        .bind(&caller.user_id)
+        .bind(&caller.bot_id)
+        .bind(&caller.workspace_id)
+        .bind(counter.call_count)
+        .bind(counter.request_bytes)
+        .bind(counter.response_bytes)
+        .execute(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Returns the usage of the owner and the bots of the workspace, the latest day comes first. The calls
+// of the owner are counted across all of the owner's workspaces.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn read_api_usage(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    days: i64,
+    logged_user: LoggedUser,
+) -> Result<RepeatedApiUsage, ServerError> {
+    let table = read_workspace_table(transaction, workspace_id).await?;
+    if table.user_id != logged_user.as_uuid()?.to_string() {
+        return Err(ServerError::unauthorized().context("Only the owner of the workspace can read its API usage"));
+    }
+
+    let since = Utc::today().naive_utc() - Duration::days(days - 1);
+    let sql = format!(
+        r#"
+            SELECT * FROM {} WHERE usage_date >= $1 AND ((user_id = $2 AND bot_id = '') OR workspace_id = $3)
+            ORDER BY usage_date DESC, bot_id
+        "#,
+        API_USAGE_TABLE
+    );
+    let tables = sqlx::query_as::<Postgres, ApiUsageTable>(&sql)
+        .bind(since)
+        .bind(&table.user_id)
+        .bind(workspace_id.to_string())
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let items = tables.into_iter().map(|table| table.into()).collect::<Vec<ApiUsage>>();
+    let mut repeated_usage = RepeatedApiUsage::default();
+    repeated_usage.set_items(items.into());
+    Ok(repeated_usage)
+}
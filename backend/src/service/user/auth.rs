@@ -1,6 +1,16 @@
 use crate::{
+    authenticator::{check_manages_passwords, AuthIdentity, AuthRequest, Authenticator},
+    config::OAuthSettings,
     entities::{token::Token, user::UserTable},
-    service::user::{hash_password, verify_password, LoggedUser},
+    service::user::{
+        hash_password,
+        identity::{link_identity, read_identity_user},
+        session::{create_session, revoke_user_sessions},
+        user_token::use_user_tokens,
+        verify_password,
+        LoggedUser,
+        EMAIL_VERIFY_PURPOSE,
+    },
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
 use anyhow::Context;
@@ -11,29 +21,47 @@ use backend_service::{
 use chrono::Utc;
 use flowy_user_infra::{
     parser::{UserEmail, UserName, UserPassword},
-    protobuf::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    protobuf::{
+        SignInResponse,
+        SignInWithProviderParams,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
 };
 use sqlx::{PgPool, Postgres};
+use std::sync::Arc;
 
 use super::AUTHORIZED_USERS;
 use crate::service::user::user_default::create_default_workspace;
 
-pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInResponse, ServerError> {
-    let email = UserEmail::parse(params.email).map_err(|e| ServerError::params_invalid().context(e))?;
-    let password = UserPassword::parse(params.password).map_err(|e| ServerError::params_invalid().context(e))?;
-
+// The user verified by the identity system is created when it signs in for the first time, or it's
+// linked to the user with the same email.
+pub async fn sign_in(
+    pool: &PgPool,
+    authenticator: &Arc<dyn Authenticator>,
+    request: AuthRequest,
+) -> Result<SignInResponse, ServerError> {
+    let identity = authenticator.authenticate(&request).await?;
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to sign in")?;
 
-    let user = check_user_password(&mut transaction, email.as_ref(), password.as_ref()).await?;
+    let user = match identity {
+        AuthIdentity::User(user) => user,
+        AuthIdentity::External { email, name } => match read_user_with_email(&mut transaction, &email).await? {
+            Some(user) => user,
+            None => insert_external_user(&mut transaction, &email, &name).await?,
+        },
+    };
+    let tokens = create_session(&mut transaction, &user.id).await?;
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to sign in.")?;
 
-    let token = Token::create_token(&user.id.to_string())?;
     let logged_user = LoggedUser::new(&user.id.to_string());
 
     AUTHORIZED_USERS.store_auth(logged_user, true);
@@ -41,17 +69,92 @@ pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInRespon
     response_data.set_user_id(user.id.to_string());
     response_data.set_name(user.name);
     response_data.set_email(user.email);
-    response_data.set_token(token.clone().into());
+    response_data.set_token(tokens.token);
+    response_data.set_refresh_token(tokens.refresh_token);
 
     Ok(response_data)
 }
 
-pub async fn sign_out(logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+// The account of the provider is linked to the user when it's used for the first time. It's linked to the
+// user with the same email if the provider verified the email, otherwise a new user is created.
+pub async fn sign_in_with_provider(
+    pool: &PgPool,
+    oauth: &OAuthSettings,
+    params: SignInWithProviderParams,
+) -> Result<SignInResponse, ServerError> {
+    let provider = params.get_provider();
+    let client = oauth
+        .client(provider)
+        .ok_or_else(|| ServerError::params_invalid().context(format!("Sign in with {:?} is not supported", provider)))?;
+    let identity = client
+        .sign_in(params.get_code(), params.get_code_verifier(), params.get_redirect_uri())
+        .await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to sign in with the provider")?;
+
+    let user = match read_identity_user(&mut transaction, provider, &identity.subject).await? {
+        Some(user) => user,
+        None => {
+            let user = match read_user_with_email(&mut transaction, &identity.email).await? {
+                Some(_) if !identity.email_verified => {
+                    return Err(ServerError {
+                        code: ErrorCode::EmailAlreadyExists,
+                        msg: format!("{} already exists, the provider didn't verify it", identity.email),
+                    });
+                },
+                Some(user) => user,
+                None => insert_external_user(&mut transaction, &identity.email, &identity.name).await?,
+            };
+            let _ = link_identity(&mut transaction, provider, &identity, &user.id).await?;
+            user
+        },
+    };
+
+    let tokens = create_session(&mut transaction, &user.id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to sign in with the provider.")?;
+
+    let logged_user = LoggedUser::new(&user.id.to_string());
+
+    AUTHORIZED_USERS.store_auth(logged_user, true);
+    let mut response_data = SignInResponse::default();
+    response_data.set_user_id(user.id.to_string());
+    response_data.set_name(user.name);
+    response_data.set_email(user.email);
+    response_data.set_token(tokens.token);
+    response_data.set_refresh_token(tokens.refresh_token);
+
+    Ok(response_data)
+}
+
+// The refresh tokens of the user are revoked too, the user signs out on all the devices
+pub async fn sign_out(pool: &PgPool, logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to sign out")?;
+
+    let _ = revoke_user_sessions(&mut transaction, &logged_user.as_uuid()?).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to sign out.")?;
+
     AUTHORIZED_USERS.store_auth(logged_user, false);
     Ok(FlowyResponse::success())
 }
 
-pub async fn register_user(pool: &PgPool, params: SignUpParams) -> Result<FlowyResponse, ServerError> {
+pub async fn register_user(
+    pool: &PgPool,
+    authenticator: &Arc<dyn Authenticator>,
+    params: SignUpParams,
+) -> Result<FlowyResponse, ServerError> {
+    let _ = check_manages_passwords(authenticator)?;
     let name = UserName::parse(params.name).map_err(|e| ServerError::params_invalid().context(e))?;
     let email = UserEmail::parse(params.email).map_err(|e| ServerError::params_invalid().context(e))?;
     let password = UserPassword::parse(params.password).map_err(|e| ServerError::params_invalid().context(e))?;
@@ -62,9 +165,12 @@ pub async fn register_user(pool: &PgPool, params: SignUpParams) -> Result<FlowyR
         .context("Failed to acquire a Postgres connection to register user")?;
 
     let _ = is_email_exist(&mut transaction, email.as_ref()).await?;
-    let response_data = insert_new_user(&mut transaction, name.as_ref(), email.as_ref(), password.as_ref())
+    let mut response_data = insert_new_user(&mut transaction, name.as_ref(), email.as_ref(), password.as_ref())
         .await
         .context("Failed to insert user")?;
+    let tokens = create_session(&mut transaction, &uuid::Uuid::parse_str(response_data.get_user_id())?).await?;
+    response_data.set_token(tokens.token);
+    response_data.set_refresh_token(tokens.refresh_token);
 
     let logged_user = LoggedUser::new(&response_data.user_id);
     AUTHORIZED_USERS.store_auth(logged_user, true);
@@ -108,14 +214,20 @@ pub(crate) async fn get_user_profile(
     user_profile.set_email(user_table.email);
     user_profile.set_name(user_table.name);
     user_profile.set_token(token.0);
+    user_profile.set_email_verified(user_table.email_verified);
     FlowyResponse::success().pb(user_profile)
 }
 
 pub(crate) async fn set_user_profile(
     pool: &PgPool,
+    authenticator: &Arc<dyn Authenticator>,
     logged_user: LoggedUser,
     params: UpdateUserParams,
 ) -> Result<FlowyResponse, ServerError> {
+    if params.has_password() {
+        let _ = check_manages_passwords(authenticator)?;
+    }
+
     let mut transaction = pool
         .begin()
         .await
@@ -144,11 +256,15 @@ pub(crate) async fn set_user_profile(
         },
     };
 
+    // The new email isn't verified yet, the tokens that were sent to the old one can't verify it
+    let user_id = logged_user.as_uuid()?;
+    let is_email_changed = email.is_some();
     let (sql, args) = SqlBuilder::update("user_table")
         .add_some_arg("name", name)
         .add_some_arg("email", email)
+        .add_arg_if(is_email_changed, "email_verified", false)
         .add_some_arg("password", password)
-        .and_where_eq("id", &logged_user.as_uuid()?)
+        .and_where_eq("id", &user_id)
         .build()?;
 
     sqlx::query_with(&sql, args)
@@ -156,6 +272,10 @@ pub(crate) async fn set_user_profile(
         .await
         .map_err(map_sqlx_error)?;
 
+    if is_email_changed {
+        let _ = use_user_tokens(&mut transaction, &user_id, EMAIL_VERIFY_PURPOSE).await?;
+    }
+
     transaction
         .commit()
         .await
@@ -180,7 +300,48 @@ async fn is_email_exist(transaction: &mut DBTransaction<'_>, email: &str) -> Res
     }
 }
 
-async fn check_user_password(
+async fn read_user_with_email(
+    transaction: &mut DBTransaction<'_>,
+    email: &str,
+) -> Result<Option<UserTable>, ServerError> {
+    let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE email = $1")
+        .bind(email)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(user)
+}
+
+// The user signs in with the provider or the identity system, the random password can be replaced after
+// setting the password in the user profile.
+async fn insert_external_user(
+    transaction: &mut DBTransaction<'_>,
+    email: &str,
+    name: &str,
+) -> Result<UserTable, ServerError> {
+    let email = UserEmail::parse(email.to_owned()).map_err(invalid_params)?;
+    let name = match UserName::parse(name.to_owned()) {
+        Ok(name) => name.0,
+        Err(_) => email.as_ref().split('@').next().unwrap_or_default().to_owned(),
+    };
+
+    let password = uuid::Uuid::new_v4().to_string();
+    let response = insert_new_user(transaction, &name, email.as_ref(), &password)
+        .await
+        .context("Failed to insert user")?;
+    let _ = create_default_workspace(transaction, response.get_user_id()).await?;
+
+    Ok(UserTable {
+        id: uuid::Uuid::parse_str(response.get_user_id())?,
+        email: response.email,
+        name: response.name,
+        create_time: Utc::now(),
+        password: String::new(),
+        email_verified: false,
+    })
+}
+
+pub(crate) async fn check_user_password(
     transaction: &mut DBTransaction<'_>,
     email: &str,
     password: &str,
@@ -204,7 +365,6 @@ async fn insert_new_user(
     password: &str,
 ) -> Result<SignUpResponse, ServerError> {
     let uuid = uuid::Uuid::new_v4();
-    let token = Token::create_token(&uuid.to_string())?;
     let password = hash_password(password)?;
     let _ = sqlx::query!(
         r#"
@@ -225,7 +385,6 @@ async fn insert_new_user(
     response.set_user_id(uuid.to_string());
     response.set_name(name.to_string());
     response.set_email(email.to_string());
-    response.set_token(token.into());
 
     Ok(response)
 }
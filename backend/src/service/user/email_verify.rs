@@ -0,0 +1,102 @@
+use crate::{
+    entities::{
+        token::{Token, EMAIL_VERIFY_EXPIRED_DURATION_HOURS},
+        user::UserTable,
+    },
+    mailer::{Email, Mailer},
+    service::user::{
+        user_token::{insert_user_token, is_token_valid, read_user_token_for_update, use_user_tokens},
+        LoggedUser,
+    },
+    sqlx_ext::{map_sqlx_error, SqlBuilder},
+};
+use anyhow::Context;
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::{Duration, Utc};
+use flowy_user_infra::{parser::EmailVerifyToken, protobuf::ConfirmEmailVerifyParams};
+use sqlx::{PgPool, Postgres};
+use std::sync::Arc;
+
+pub(crate) const EMAIL_VERIFY_PURPOSE: &str = "email_verify";
+
+// Sends the verify token to the email of the signed-in user. Nothing is sent if the email is verified
+// already.
+pub(crate) async fn request_email_verify(
+    pool: &PgPool,
+    mailer: &Arc<dyn Mailer>,
+    logged_user: LoggedUser,
+) -> Result<(), ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to request email verify")?;
+
+    let user_id = logged_user.as_uuid()?;
+    let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(&user_id)
+        .fetch_one(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    if user.email_verified {
+        log::info!("The email of the user {} is verified already", user.id);
+        return Ok(());
+    }
+
+    let token_id = uuid::Uuid::new_v4();
+    let token = Token::create_email_verify_token(&token_id.to_string())?;
+    let expire_time = Utc::now() + Duration::hours(EMAIL_VERIFY_EXPIRED_DURATION_HOURS);
+    let _ = insert_user_token(&mut transaction, &token_id, &user.id, EMAIL_VERIFY_PURPOSE, expire_time).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to request email verify.")?;
+
+    let email = Email {
+        to: user.email,
+        subject: "Verify your email".to_owned(),
+        body: format!(
+            "Use the token below to verify your email, it expires in {} hours.\n\n{}",
+            EMAIL_VERIFY_EXPIRED_DURATION_HOURS, token.0
+        ),
+    };
+    mailer.send(email).await
+}
+
+// The token can be used once. The tokens are revoked when the email is changed, so the token only
+// verifies the email that it was sent to.
+pub(crate) async fn confirm_email_verify(pool: &PgPool, params: ConfirmEmailVerifyParams) -> Result<(), ServerError> {
+    let token = EmailVerifyToken::parse(params.token).map_err(invalid_params)?;
+    let claim = Token::decode_token(&Token(token.0)).map_err(|_| ServerError::email_verify_token_invalid())?;
+    if !claim.is_email_verify() {
+        return Err(ServerError::email_verify_token_invalid());
+    }
+    let token_id = uuid::Uuid::parse_str(&claim.user_id()).map_err(|_| ServerError::email_verify_token_invalid())?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to confirm email verify")?;
+
+    let user_token = match read_user_token_for_update(&mut transaction, &token_id).await? {
+        Some(user_token) if is_token_valid(&user_token, EMAIL_VERIFY_PURPOSE) => user_token,
+        _ => return Err(ServerError::email_verify_token_invalid()),
+    };
+
+    let (sql, args) = SqlBuilder::update("user_table")
+        .add_arg("email_verified", true)
+        .and_where_eq("id", &user_token.user_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let _ = use_user_tokens(&mut transaction, &user_token.user_id, EMAIL_VERIFY_PURPOSE).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to confirm email verify.")?;
+    Ok(())
+}
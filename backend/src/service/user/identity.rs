@@ -0,0 +1,58 @@
+use crate::{
+    entities::user::{UserTable, USER_IDENTITY_TABLE},
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::{errors::ServerError, oauth::ProviderIdentity};
+use chrono::Utc;
+use flowy_user_infra::protobuf::AuthProvider;
+use sqlx::Postgres;
+
+// The name that the identities of the provider are saved with, it must not be changed
+pub(crate) fn provider_name(provider: AuthProvider) -> &'static str {
+    match provider {
+        AuthProvider::Google => "google",
+        AuthProvider::GitHub => "github",
+    }
+}
+
+// Returns the user that the account of the provider is linked to
+pub(crate) async fn read_identity_user(
+    transaction: &mut DBTransaction<'_>,
+    provider: AuthProvider,
+    subject: &str,
+) -> Result<Option<UserTable>, ServerError> {
+    let user = sqlx::query_as::<Postgres, UserTable>(
+        r#"
+            SELECT user_table.* FROM user_table
+            JOIN user_identity_table ON user_identity_table.user_id = user_table.id
+            WHERE user_identity_table.provider = $1 AND user_identity_table.subject = $2
+        "#,
+    )
+    .bind(provider_name(provider))
+    .bind(subject)
+    .fetch_optional(transaction)
+    .await
+    .map_err(map_sqlx_error)?;
+    Ok(user)
+}
+
+pub(crate) async fn link_identity(
+    transaction: &mut DBTransaction<'_>,
+    provider: AuthProvider,
+    identity: &ProviderIdentity,
+    user_id: &uuid::Uuid,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::create(USER_IDENTITY_TABLE)
+        .add_arg("provider", provider_name(provider))
+        .add_arg("subject", &identity.subject)
+        .add_arg("user_id", user_id)
+        .add_arg("email", &identity.email)
+        .add_arg("create_time", Utc::now())
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
@@ -12,22 +12,34 @@ lazy_static! {
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct LoggedUser {
     pub user_id: String,
+    // The user_id is the id of the bot if the request is sent with a bot token
+    pub is_bot: bool,
 }
 
 impl std::convert::From<Claim> for LoggedUser {
-    fn from(c: Claim) -> Self { Self { user_id: c.user_id() } }
+    fn from(c: Claim) -> Self {
+        let is_bot = c.is_bot();
+        Self {
+            user_id: c.user_id(),
+            is_bot,
+        }
+    }
 }
 
 impl LoggedUser {
     pub fn new(user_id: &str) -> Self {
         Self {
             user_id: user_id.to_owned(),
+            is_bot: false,
         }
     }
 
     pub fn from_token(token: String) -> Result<Self, ServerError> {
-        let user: LoggedUser = Token::decode_token(&token.into())?.into();
-        Ok(user)
+        let claim = Token::decode_token(&token.into())?;
+        if claim.is_password_reset() {
+            return Err(ServerError::unauthorized());
+        }
+        Ok(claim.into())
     }
 
     pub fn as_uuid(&self) -> Result<uuid::Uuid, ServerError> {
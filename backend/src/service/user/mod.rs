@@ -1,9 +1,17 @@
 pub use auth::*;
+pub(crate) use email_verify::*;
 pub use logged_user::*;
+pub(crate) use password_reset::*;
+pub(crate) use session::refresh_session;
 pub use utils::*;
 
 mod auth;
+mod email_verify;
+mod identity;
 mod logged_user;
+mod password_reset;
 pub mod router;
+mod session;
 pub mod user_default;
+mod user_token;
 mod utils;
@@ -0,0 +1,132 @@
+use crate::{
+    entities::{
+        token::{Token, PASSWORD_RESET_EXPIRED_DURATION_MINUTES},
+        user::UserTable,
+    },
+    mailer::{Email, Mailer},
+    service::user::{
+        hash_password,
+        session::revoke_user_sessions,
+        user_token::{insert_user_token, is_token_valid, read_user_token_for_update, use_user_tokens},
+        LoggedUser,
+        AUTHORIZED_USERS,
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use anyhow::Context;
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::{Duration, Utc};
+use flowy_user_infra::{
+    parser::{PasswordResetToken, UserEmail, UserPassword},
+    protobuf::{ConfirmPasswordResetParams, RequestPasswordResetParams},
+};
+use sqlx::{postgres::PgArguments, PgPool, Postgres};
+use std::sync::Arc;
+
+const PASSWORD_RESET_PURPOSE: &str = "password_reset";
+
+// Sends the reset token to the email. It's Ok if the email isn't registered, so the request can't be
+// used to find out the emails of the users.
+pub(crate) async fn request_password_reset(
+    pool: &PgPool,
+    mailer: &Arc<dyn Mailer>,
+    params: RequestPasswordResetParams,
+) -> Result<(), ServerError> {
+    let email = UserEmail::parse(params.email).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to request password reset")?;
+
+    let user = match read_user_with_email(&mut transaction, email.as_ref()).await? {
+        None => {
+            log::info!("Password reset is requested for the unknown email {}", email.as_ref());
+            return Ok(());
+        },
+        Some(user) => user,
+    };
+
+    let token_id = uuid::Uuid::new_v4();
+    let token = Token::create_password_reset_token(&token_id.to_string())?;
+    let expire_time = Utc::now() + Duration::minutes(PASSWORD_RESET_EXPIRED_DURATION_MINUTES);
+    let _ = insert_user_token(&mut transaction, &token_id, &user.id, PASSWORD_RESET_PURPOSE, expire_time).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to request password reset.")?;
+
+    let email = Email {
+        to: user.email,
+        subject: "Reset your password".to_owned(),
+        body: format!(
+            "Use the token below to reset your password, it expires in {} minutes.\n\n{}",
+            PASSWORD_RESET_EXPIRED_DURATION_MINUTES, token.0
+        ),
+    };
+    mailer.send(email).await
+}
+
+// The token can be used once, the other reset tokens of the user are revoked with it. The user is
+// signed out, so the sessions that were signed in with the old password can't be used anymore.
+pub(crate) async fn confirm_password_reset(
+    pool: &PgPool,
+    params: ConfirmPasswordResetParams,
+) -> Result<(), ServerError> {
+    let token = PasswordResetToken::parse(params.token).map_err(invalid_params)?;
+    let password = UserPassword::parse(params.new_password).map_err(invalid_params)?;
+
+    let claim = Token::decode_token(&Token(token.0)).map_err(|_| ServerError::password_reset_token_invalid())?;
+    if !claim.is_password_reset() {
+        return Err(ServerError::password_reset_token_invalid());
+    }
+    let token_id = uuid::Uuid::parse_str(&claim.user_id()).map_err(|_| ServerError::password_reset_token_invalid())?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to confirm password reset")?;
+
+    let user_token = match read_user_token_for_update(&mut transaction, &token_id).await? {
+        Some(user_token) if is_token_valid(&user_token, PASSWORD_RESET_PURPOSE) => user_token,
+        _ => return Err(ServerError::password_reset_token_invalid()),
+    };
+
+    let (sql, args) = SqlBuilder::update("user_table")
+        .add_arg("password", hash_password(password.as_ref())?)
+        .and_where_eq("id", &user_token.user_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let _ = use_user_tokens(&mut transaction, &user_token.user_id, PASSWORD_RESET_PURPOSE).await?;
+
+    // The sessions that were started with the old password are signed out
+    let _ = revoke_user_sessions(&mut transaction, &user_token.user_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to confirm password reset.")?;
+
+    AUTHORIZED_USERS.store_auth(LoggedUser::new(&user_token.user_id.to_string()), false);
+    Ok(())
+}
+
+async fn read_user_with_email(
+    transaction: &mut DBTransaction<'_>,
+    email: &str,
+) -> Result<Option<UserTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select("user_table")
+        .add_field("*")
+        .and_where_eq("email", email)
+        .build()?;
+
+    let user = sqlx::query_as_with::<Postgres, UserTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(user)
+}
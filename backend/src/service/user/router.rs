@@ -7,28 +7,100 @@ use actix_web::{
 use sqlx::PgPool;
 
 use backend_service::{errors::ServerError, response::FlowyResponse};
-use flowy_user_infra::protobuf::{SignInParams, SignUpParams, UpdateUserParams};
+use flowy_user_infra::protobuf::{
+    ConfirmEmailVerifyParams,
+    ConfirmPasswordResetParams,
+    RefreshTokenParams,
+    RequestPasswordResetParams,
+    SignInParams,
+    SignInWithProviderParams,
+    SignUpParams,
+    UpdateUserParams,
+};
+use std::sync::Arc;
 
 use crate::{
+    authenticator::{check_manages_passwords, AuthRequest, Authenticator},
+    config::OAuthSettings,
     entities::token::Token,
+    mailer::Mailer,
     service::{
-        user::{get_user_profile, register_user, set_user_profile, sign_in, sign_out, LoggedUser},
+        user::{
+            confirm_email_verify,
+            confirm_password_reset,
+            get_user_profile,
+            refresh_session,
+            register_user,
+            request_email_verify,
+            request_password_reset,
+            set_user_profile,
+            sign_in,
+            sign_in_with_provider,
+            sign_out,
+            LoggedUser,
+        },
         util::parse_from_payload,
     },
 };
 
-pub async fn sign_in_handler(payload: Payload, id: Identity, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
-    let params: SignInParams = parse_from_payload(payload).await?;
-    let data = sign_in(pool.get_ref(), params).await?;
+pub async fn sign_in_handler(
+    request: HttpRequest,
+    payload: Payload,
+    id: Identity,
+    pool: Data<PgPool>,
+    authenticator: Data<Arc<dyn Authenticator>>,
+) -> Result<HttpResponse, ServerError> {
+    let mut params: SignInParams = parse_from_payload(payload).await?;
+    let headers = request
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str().to_lowercase(), value.to_str().ok()?.to_owned())))
+        .collect();
+    let auth_request = AuthRequest {
+        email: params.take_email(),
+        password: params.take_password(),
+        headers,
+        peer_ip: request.peer_addr().map(|addr| addr.ip()),
+    };
+    let data = sign_in(pool.get_ref(), authenticator.get_ref(), auth_request).await?;
+    id.remember(data.token.clone());
+    let response = FlowyResponse::success().pb(data)?;
+    Ok(response.into())
+}
+
+pub async fn sign_in_with_provider_handler(
+    payload: Payload,
+    id: Identity,
+    pool: Data<PgPool>,
+    oauth: Data<OAuthSettings>,
+) -> Result<HttpResponse, ServerError> {
+    let params: SignInWithProviderParams = parse_from_payload(payload).await?;
+    let data = sign_in_with_provider(pool.get_ref(), oauth.get_ref(), params).await?;
     id.remember(data.token.clone());
     let response = FlowyResponse::success().pb(data)?;
     Ok(response.into())
 }
 
-pub async fn sign_out_handler(logged_user: LoggedUser, id: Identity) -> Result<HttpResponse, ServerError> {
+pub async fn sign_out_handler(
+    logged_user: LoggedUser,
+    id: Identity,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
     id.forget();
 
-    let response = sign_out(logged_user).await?;
+    let response = sign_out(pool.get_ref(), logged_user).await?;
+    Ok(response.into())
+}
+
+pub async fn refresh_token_handler(
+    payload: Payload,
+    id: Identity,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
+    let params: RefreshTokenParams = parse_from_payload(payload).await?;
+    let data = refresh_session(pool.get_ref(), params).await?;
+    id.remember(data.token.clone());
+    let response = FlowyResponse::success().pb(data)?;
     Ok(response.into())
 }
 
@@ -44,20 +116,63 @@ pub async fn get_user_profile_handler(
 pub async fn set_user_profile_handler(
     logged_user: LoggedUser,
     pool: Data<PgPool>,
+    authenticator: Data<Arc<dyn Authenticator>>,
     payload: Payload,
 ) -> Result<HttpResponse, ServerError> {
     let params: UpdateUserParams = parse_from_payload(payload).await?;
-    let response = set_user_profile(pool.get_ref(), logged_user, params).await?;
+    let response = set_user_profile(pool.get_ref(), authenticator.get_ref(), logged_user, params).await?;
     Ok(response.into())
 }
 
-pub async fn register_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn register_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    authenticator: Data<Arc<dyn Authenticator>>,
+) -> Result<HttpResponse, ServerError> {
     let params: SignUpParams = parse_from_payload(payload).await?;
-    let resp = register_user(pool.get_ref(), params).await?;
+    let resp = register_user(pool.get_ref(), authenticator.get_ref(), params).await?;
 
     Ok(resp.into())
 }
 
+pub async fn request_password_reset_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    mailer: Data<Arc<dyn Mailer>>,
+    authenticator: Data<Arc<dyn Authenticator>>,
+) -> Result<HttpResponse, ServerError> {
+    let _ = check_manages_passwords(authenticator.get_ref())?;
+    let params: RequestPasswordResetParams = parse_from_payload(payload).await?;
+    let _ = request_password_reset(pool.get_ref(), mailer.get_ref(), params).await?;
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn confirm_password_reset_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    authenticator: Data<Arc<dyn Authenticator>>,
+) -> Result<HttpResponse, ServerError> {
+    let _ = check_manages_passwords(authenticator.get_ref())?;
+    let params: ConfirmPasswordResetParams = parse_from_payload(payload).await?;
+    let _ = confirm_password_reset(pool.get_ref(), params).await?;
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn request_email_verify_handler(
+    logged_user: LoggedUser,
+    pool: Data<PgPool>,
+    mailer: Data<Arc<dyn Mailer>>,
+) -> Result<HttpResponse, ServerError> {
+    let _ = request_email_verify(pool.get_ref(), mailer.get_ref(), logged_user).await?;
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn confirm_email_verify_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+    let params: ConfirmEmailVerifyParams = parse_from_payload(payload).await?;
+    let _ = confirm_email_verify(pool.get_ref(), params).await?;
+    Ok(FlowyResponse::success().into())
+}
+
 pub async fn change_password(
     _request: HttpRequest,
     _payload: Payload,
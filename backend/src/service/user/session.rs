@@ -0,0 +1,169 @@
+use crate::{
+    entities::{
+        token::Token,
+        user::{UserSessionTable, USER_SESSION_TABLE},
+    },
+    service::user::{LoggedUser, AUTHORIZED_USERS, EXPIRED_DURATION_DAYS},
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use anyhow::Context;
+use backend_service::errors::ServerError;
+use chrono::{Duration, Utc};
+use flowy_user_infra::protobuf::{RefreshTokenParams, RefreshTokenResponse};
+use sha2::{Digest, Sha256};
+use sqlx::{PgPool, Postgres};
+
+// The refresh token that was just replaced is still accepted for a while, the response with the new one
+// may be lost, e.g. the connection dropped.
+const REFRESH_TOKEN_REUSE_SECONDS: i64 = 30;
+
+pub(crate) struct SessionTokens {
+    pub(crate) token: String,
+    pub(crate) refresh_token: String,
+}
+
+// Starts the session when the user signs in. The refresh token is the id of the session with the secret,
+// only the hash of the secret is saved.
+pub(crate) async fn create_session(
+    transaction: &mut DBTransaction<'_>,
+    user_id: &uuid::Uuid,
+) -> Result<SessionTokens, ServerError> {
+    let session_id = uuid::Uuid::new_v4();
+    let secret = new_secret();
+    let now = Utc::now();
+    let (sql, args) = SqlBuilder::create(USER_SESSION_TABLE)
+        .add_arg("id", session_id)
+        .add_arg("user_id", user_id)
+        .add_arg("refresh_token_hash", hash_secret(&secret))
+        .add_arg("create_time", now)
+        .add_arg("refresh_time", now)
+        .add_arg("expire_time", now + Duration::days(EXPIRED_DURATION_DAYS))
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(SessionTokens {
+        token: Token::create_token(&user_id.to_string())?.into(),
+        refresh_token: refresh_token(&session_id, &secret),
+    })
+}
+
+// The refresh token is replaced by the one in the response. Using the replaced token again revokes the
+// session, the token was probably stolen and the session can't tell which client is the legitimate one.
+pub async fn refresh_session(pool: &PgPool, params: RefreshTokenParams) -> Result<RefreshTokenResponse, ServerError> {
+    let (session_id, secret) = parse_refresh_token(params.get_refresh_token())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to refresh the token")?;
+
+    let now = Utc::now();
+    let session = match read_session_for_update(&mut transaction, &session_id).await? {
+        Some(session) if !session.is_revoked && session.expire_time > now => session,
+        _ => return Err(ServerError::unauthorized().context("The session is expired or revoked")),
+    };
+
+    let hash = hash_secret(&secret);
+    let is_just_replaced = hash == session.previous_token_hash
+        && now - session.refresh_time < Duration::seconds(REFRESH_TOKEN_REUSE_SECONDS);
+    if hash != session.refresh_token_hash && !is_just_replaced {
+        log::warn!("The replaced refresh token of the session {} is used, revoke it", session.id);
+        let _ = revoke_session(&mut transaction, &session.id).await?;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to revoke the session.")?;
+        return Err(ServerError::unauthorized().context("The refresh token was replaced"));
+    }
+
+    let next_secret = new_secret();
+    let (sql, args) = SqlBuilder::update(USER_SESSION_TABLE)
+        .add_arg("refresh_token_hash", hash_secret(&next_secret))
+        .add_arg("previous_token_hash", session.refresh_token_hash)
+        .add_arg("refresh_time", now)
+        .add_arg("expire_time", now + Duration::days(EXPIRED_DURATION_DAYS))
+        .and_where_eq("id", &session.id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to refresh the token.")?;
+
+    // The user is authorized again after the server was restarted
+    let user_id = session.user_id.to_string();
+    AUTHORIZED_USERS.store_auth(LoggedUser::new(&user_id), true);
+
+    let mut response = RefreshTokenResponse::default();
+    response.set_token(Token::create_token(&user_id)?.into());
+    response.set_refresh_token(refresh_token(&session.id, &next_secret));
+    Ok(response)
+}
+
+// Revokes all the sessions of the user, e.g. when the user signs out or resets the password
+pub(crate) async fn revoke_user_sessions(
+    transaction: &mut DBTransaction<'_>,
+    user_id: &uuid::Uuid,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::update(USER_SESSION_TABLE)
+        .add_arg("is_revoked", true)
+        .and_where_eq("user_id", user_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// The row is locked until the transaction ends, so the same token can't be exchanged twice at the same time
+async fn read_session_for_update(
+    transaction: &mut DBTransaction<'_>,
+    session_id: &uuid::Uuid,
+) -> Result<Option<UserSessionTable>, ServerError> {
+    let sql = format!("SELECT * FROM {} WHERE id = $1 FOR UPDATE", USER_SESSION_TABLE);
+    let session = sqlx::query_as::<Postgres, UserSessionTable>(&sql)
+        .bind(session_id)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(session)
+}
+
+async fn revoke_session(transaction: &mut DBTransaction<'_>, session_id: &uuid::Uuid) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::update(USER_SESSION_TABLE)
+        .add_arg("is_revoked", true)
+        .and_where_eq("id", session_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+fn refresh_token(session_id: &uuid::Uuid, secret: &str) -> String { format!("{}.{}", session_id, secret) }
+
+fn parse_refresh_token(refresh_token: &str) -> Result<(uuid::Uuid, String), ServerError> {
+    let invalid = || ServerError::unauthorized().context("Invalid refresh token");
+    let (session_id, secret) = refresh_token.split_once('.').ok_or_else(invalid)?;
+    let session_id = uuid::Uuid::parse_str(session_id).map_err(|_| invalid())?;
+    if secret.is_empty() {
+        return Err(invalid());
+    }
+    Ok((session_id, secret.to_owned()))
+}
+
+fn new_secret() -> String { format!("{}{}", uuid::Uuid::new_v4().to_simple(), uuid::Uuid::new_v4().to_simple()) }
+
+fn hash_secret(secret: &str) -> String { format!("{:x}", Sha256::digest(secret.as_bytes())) }
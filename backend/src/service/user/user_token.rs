@@ -0,0 +1,70 @@
+use crate::{
+    entities::user::{UserTokenTable, USER_TOKEN_TABLE},
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::ServerError;
+use chrono::{DateTime, Utc};
+use sqlx::Postgres;
+
+// The single-use tokens that are sent by email. The purpose keeps the tokens of the password reset and the
+// email verification apart, so one can't be used as the other.
+pub(crate) async fn insert_user_token(
+    transaction: &mut DBTransaction<'_>,
+    token_id: &uuid::Uuid,
+    user_id: &uuid::Uuid,
+    purpose: &str,
+    expire_time: DateTime<Utc>,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::create(USER_TOKEN_TABLE)
+        .add_arg("id", token_id)
+        .add_arg("user_id", user_id)
+        .add_arg("purpose", purpose)
+        .add_arg("create_time", Utc::now())
+        .add_arg("expire_time", expire_time)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// The token row is locked until the transaction is finished, so the token can't be used by two
+// concurrent confirmations.
+pub(crate) async fn read_user_token_for_update(
+    transaction: &mut DBTransaction<'_>,
+    token_id: &uuid::Uuid,
+) -> Result<Option<UserTokenTable>, ServerError> {
+    let sql = format!("SELECT * FROM {} WHERE id = $1 FOR UPDATE", USER_TOKEN_TABLE);
+    let user_token = sqlx::query_as::<Postgres, UserTokenTable>(&sql)
+        .bind(token_id)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(user_token)
+}
+
+pub(crate) fn is_token_valid(user_token: &UserTokenTable, purpose: &str) -> bool {
+    user_token.purpose == purpose && !user_token.is_used && user_token.expire_time > Utc::now()
+}
+
+// Marks all the tokens of the user with the purpose as used, including the ones that were sent before the
+// used one.
+pub(crate) async fn use_user_tokens(
+    transaction: &mut DBTransaction<'_>,
+    user_id: &uuid::Uuid,
+    purpose: &str,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::update(USER_TOKEN_TABLE)
+        .add_arg("is_used", true)
+        .and_where_eq("user_id", user_id)
+        .and_where_eq("purpose", purpose)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use crate::config::MAX_PAYLOAD_SIZE;
-use actix_web::web;
+use actix_web::{dev::ConnectionInfo, web};
 use backend_service::errors::{ErrorCode, ServerError};
 use futures::StreamExt;
 use protobuf::{Message, ProtobufResult};
+use std::net::{IpAddr, SocketAddr};
 
 pub async fn parse_from_payload<T: Message>(payload: web::Payload) -> Result<T, ServerError> {
     let bytes = poll_payload(&mut payload.into_inner()).await?;
@@ -30,11 +31,18 @@ pub fn parse_from_bytes<T: Message>(bytes: &[u8]) -> Result<T, ServerError> {
 }
 
 pub async fn poll_payload(payload: &mut actix_web::dev::Payload) -> Result<web::BytesMut, ServerError> {
+    poll_payload_with_limit(payload, MAX_PAYLOAD_SIZE).await
+}
+
+pub async fn poll_payload_with_limit(
+    payload: &mut actix_web::dev::Payload,
+    max_size: usize,
+) -> Result<web::BytesMut, ServerError> {
     let mut body = web::BytesMut::new();
     while let Some(chunk) = payload.next().await {
         let chunk = chunk.map_err(|err| ServerError::internal().context(err))?;
 
-        if (body.len() + chunk.len()) > MAX_PAYLOAD_SIZE {
+        if (body.len() + chunk.len()) > max_size {
             return Err(ServerError::new(
                 "Payload overflow".to_string(),
                 ErrorCode::PayloadOverflow,
@@ -44,3 +52,24 @@ pub async fn poll_payload(payload: &mut actix_web::dev::Payload) -> Result<web::
     }
     Ok(body)
 }
+
+// Behind the CDN or the load balancer, the ip of the client is read from the Forwarded or the
+// X-Forwarded-For header. The client can set any header, so they are only read if the peer is one of
+// the trusted proxies.
+pub(crate) fn client_ip(
+    peer_addr: Option<SocketAddr>,
+    connection_info: &ConnectionInfo,
+    trusted_proxies: &[IpAddr],
+) -> String {
+    let peer_ip = peer_addr.map(|addr| addr.ip());
+    let is_trusted = peer_ip.map(|ip| trusted_proxies.contains(&ip)).unwrap_or(false);
+    if !is_trusted {
+        return peer_ip.map(|ip| ip.to_string()).unwrap_or_default();
+    }
+
+    let addr = connection_info.realip_remote_addr().unwrap_or_default();
+    match addr.parse::<SocketAddr>() {
+        Ok(addr) => addr.ip().to_string(),
+        Err(_) => addr.to_owned(),
+    }
+}
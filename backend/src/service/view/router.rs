@@ -1,8 +1,19 @@
 use crate::service::{
     doc::doc::DocBiz,
+    idempotency::{read_idempotent_response, save_idempotent_response, IdempotencyKey},
+    publish::schedule_render,
+    search::SearchIndexHandle,
     user::LoggedUser,
     util::parse_from_payload,
-    view::{create_view, delete_view, read_view, sql_builder::check_view_ids, update_view},
+    view::{
+        create_view,
+        delete_view,
+        duplicate_view,
+        move_view,
+        read_view,
+        sql_builder::check_view_ids,
+        update_view,
+    },
 };
 use actix_web::{
     web::{Data, Payload},
@@ -15,7 +26,7 @@ use backend_service::{
 };
 use flowy_workspace_infra::{
     parser::view::{ViewDesc, ViewName, ViewThumbnail},
-    protobuf::{CreateViewParams, QueryViewRequest, UpdateViewParams, ViewIdentifier},
+    protobuf::{CreateViewParams, MoveViewParams, QueryViewRequest, UpdateViewParams, View, ViewIdentifier},
 };
 use sqlx::PgPool;
 use std::sync::Arc;
@@ -24,6 +35,9 @@ pub async fn create_handler(
     payload: Payload,
     pool: Data<PgPool>,
     _doc_biz: Data<Arc<DocBiz>>,
+    search: Data<SearchIndexHandle>,
+    logged_user: LoggedUser,
+    idempotency_key: IdempotencyKey,
 ) -> Result<HttpResponse, ServerError> {
     let params: CreateViewParams = parse_from_payload(payload).await?;
     let mut transaction = pool
@@ -31,13 +45,20 @@ pub async fn create_handler(
         .await
         .context("Failed to acquire a Postgres connection to create view")?;
 
-    let view = create_view(&mut transaction, params).await?;
+    if let Some(data) = read_idempotent_response(&mut transaction, &idempotency_key, &logged_user, &params).await? {
+        return Ok(FlowyResponse::new(data, None).into());
+    }
+
+    let view = create_view(&mut transaction, params, &logged_user).await?;
+    let resp = FlowyResponse::success().pb(view)?;
+    let _ = save_idempotent_response(&mut transaction, &idempotency_key, &logged_user, &resp.data).await?;
+
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to create view.")?;
 
-    let resp = FlowyResponse::success().pb(view)?;
+    search.index_view(view.get_id()).await;
     Ok(resp.into())
 }
 
@@ -58,7 +79,12 @@ pub async fn read_handler(payload: Payload, pool: Data<PgPool>, user: LoggedUser
     Ok(FlowyResponse::success().pb(view)?.into())
 }
 
-pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    search: Data<SearchIndexHandle>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: UpdateViewParams = parse_from_payload(payload).await?;
     let view_id = check_view_ids(vec![params.view_id.clone()])?.pop().unwrap();
     let name = match params.has_name() {
@@ -80,22 +106,33 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         ),
     };
 
+    let is_renamed = name.is_some();
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to update app")?;
 
-    let _ = update_view(&mut transaction, view_id, name, desc, thumbnail).await?;
+    let device_id = params.get_device_id();
+    let _ = update_view(&mut transaction, view_id, name, desc, thumbnail, device_id, &logged_user).await?;
 
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to update view.")?;
 
+    if is_renamed {
+        search.index_view(&view_id.to_string()).await;
+    }
+    schedule_render(&view_id.to_string(), pool);
     Ok(FlowyResponse::success().into())
 }
 
-pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn delete_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    search: Data<SearchIndexHandle>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: QueryViewRequest = parse_from_payload(payload).await?;
     let view_ids = check_view_ids(params.view_ids.to_vec())?;
     let mut transaction = pool
@@ -103,12 +140,73 @@ pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         .await
         .context("Failed to acquire a Postgres connection to delete view")?;
 
-    let _ = delete_view(&mut transaction, view_ids).await?;
+    let _ = delete_view(&mut transaction, view_ids.clone(), &logged_user).await?;
 
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to delete view.")?;
 
+    // The deleted views are removed from the index since they can't be read anymore
+    for view_id in view_ids {
+        search.index_view(&view_id.to_string()).await;
+    }
     Ok(FlowyResponse::success().into())
 }
+
+pub async fn move_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: MoveViewParams = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to move view")?;
+
+    let _ = move_view(&mut transaction, view_id, params.belong_to_id, params.rank, &logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to move view.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn duplicate_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    search: Data<SearchIndexHandle>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to duplicate view")?;
+
+    let view = duplicate_view(&mut transaction, view_id, &logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to duplicate view.")?;
+
+    for view_id in nested_view_ids(&view) {
+        search.index_view(&view_id).await;
+    }
+    Ok(FlowyResponse::success().pb(view)?.into())
+}
+
+// The id of the view and the ids of the views nested in it
+pub(crate) fn nested_view_ids(view: &View) -> Vec<String> {
+    let mut view_ids = vec![view.get_id().to_owned()];
+    for child in view.get_belongings().get_items() {
+        view_ids.extend(nested_view_ids(child));
+    }
+    view_ids
+}
@@ -30,6 +30,8 @@ impl NewViewSqlBuilder {
             create_time: time,
             thumbnail: "".to_string(),
             view_type: ViewType::Doc.value(),
+            rank: "".to_string(),
+            modified_device_id: "".to_string(),
         };
 
         Self { table }
@@ -50,6 +52,8 @@ impl NewViewSqlBuilder {
             create_time,
             thumbnail: "".to_string(),
             view_type: view.view_type.value(),
+            rank: view.rank,
+            modified_device_id: "".to_string(),
         };
         Ok(Self { table })
     }
@@ -69,6 +73,11 @@ impl NewViewSqlBuilder {
         self
     }
 
+    pub fn rank(mut self, rank: &str) -> Self {
+        self.table.rank = rank.to_owned();
+        self
+    }
+
     pub fn view_type(mut self, view_type: ViewType) -> Self {
         self.table.view_type = view_type.value();
         self
@@ -86,6 +95,7 @@ impl NewViewSqlBuilder {
             .add_arg("create_time", self.table.create_time)
             .add_arg("thumbnail", self.table.thumbnail)
             .add_arg("view_type", self.table.view_type)
+            .add_arg("rank", self.table.rank)
             .build()?;
 
         Ok((sql, args, view))
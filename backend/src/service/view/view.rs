@@ -1,10 +1,23 @@
 use crate::{
-    entities::workspace::{ViewTable, VIEW_TABLE},
+    entities::workspace::{AppTable, ViewTable, VIEW_TABLE},
     service::{
-        doc::{create_doc, delete_doc},
-        trash::read_trash_ids,
+        app::app::read_app_table,
+        doc::{create_doc, delete_doc, read_doc_table},
+        favorite::delete_favorites,
+        publish::{delete_published_view, update_published_slugs},
+        recent::delete_recent_views,
+        share::delete_share_links,
+        slug::rename_slug,
+        trash::{read_trash_ids, read_trash_uuids},
         user::LoggedUser,
         view::sql_builder::*,
+        webhook::delete_view_webhook,
+        workspace::{
+            check_workspace_editable,
+            check_workspace_readable,
+            check_workspace_writable,
+            sql_builder::build_list_filter,
+        },
     },
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
@@ -12,13 +25,18 @@ use backend_service::errors::{invalid_params, ServerError};
 use chrono::Utc;
 use flowy_document_infra::protobuf::CreateDocParams;
 use flowy_workspace_infra::{
+    entities::workspace::ListFilter,
+    errors::ErrorCode,
     parser::{
         app::AppId,
+        rank::ItemRank,
         view::{ViewDesc, ViewName, ViewThumbnail},
     },
     protobuf::{CreateViewParams, RepeatedView, View},
+    rank::rank_between,
 };
-use sqlx::{postgres::PgArguments, Postgres};
+use sqlx::{postgres::PgArguments, Postgres, Row};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 pub(crate) async fn update_view(
@@ -27,26 +45,42 @@ pub(crate) async fn update_view(
     name: Option<String>,
     desc: Option<String>,
     thumbnail: Option<String>,
+    device_id: &str,
+    logged_user: &LoggedUser,
 ) -> Result<(), ServerError> {
+    let _ = check_view_writable(transaction, view_id, logged_user).await?;
     let (sql, args) = SqlBuilder::update(VIEW_TABLE)
-        .add_some_arg("name", name)
+        .add_some_arg("name", name.clone())
         .add_some_arg("description", desc)
         .add_some_arg("thumbnail", thumbnail)
         .add_some_arg("modified_time", Some(Utc::now()))
+        .add_arg("modified_device_id", device_id)
         .and_where_eq("id", view_id)
         .build()?;
 
     sqlx::query_with(&sql, args)
-        .execute(transaction)
+        .execute(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
 
+    // The slug of the view is in the scope of its workspace
+    if let Some(name) = name {
+        if let Some(slug) = rename_slug(transaction, &view_id.to_string(), &name).await? {
+            let _ = update_published_slugs(transaction, &slug.scope_id).await?;
+        }
+    }
+
     Ok(())
 }
 
-#[tracing::instrument(skip(transaction), err)]
-pub(crate) async fn delete_view(transaction: &mut DBTransaction<'_>, view_ids: Vec<Uuid>) -> Result<(), ServerError> {
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn delete_view(
+    transaction: &mut DBTransaction<'_>,
+    view_ids: Vec<Uuid>,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
     for view_id in view_ids {
+        let _ = check_view_writable(transaction, view_id, logged_user).await?;
         let (sql, args) = SqlBuilder::delete(VIEW_TABLE).and_where_eq("id", &view_id).build()?;
         let _ = sqlx::query_with(&sql, args)
             .execute(transaction as &mut DBTransaction<'_>)
@@ -54,24 +88,33 @@ pub(crate) async fn delete_view(transaction: &mut DBTransaction<'_>, view_ids: V
             .map_err(map_sqlx_error)?;
 
         let _ = delete_doc(transaction, view_id).await?;
+        let _ = delete_published_view(transaction, view_id).await?;
+        let _ = delete_share_links(transaction, view_id).await?;
+        let _ = delete_favorites(transaction, view_id).await?;
+        let _ = delete_recent_views(transaction, view_id).await?;
+        let _ = delete_view_webhook(transaction, view_id).await?;
     }
     Ok(())
 }
 
-#[tracing::instrument(name = "create_view", level = "debug", skip(transaction), err)]
+#[tracing::instrument(name = "create_view", level = "debug", skip(transaction, logged_user), err)]
 pub(crate) async fn create_view(
     transaction: &mut DBTransaction<'_>,
     params: CreateViewParams,
+    logged_user: &LoggedUser,
 ) -> Result<View, ServerError> {
     let name = ViewName::parse(params.name).map_err(invalid_params)?;
     let belong_to_id = AppId::parse(params.belong_to_id).map_err(invalid_params)?;
     let thumbnail = ViewThumbnail::parse(params.thumbnail).map_err(invalid_params)?;
     let desc = ViewDesc::parse(params.desc).map_err(invalid_params)?;
+    let _ = check_belong_to_writable(transaction, belong_to_id.as_ref(), logged_user).await?;
+    let last_rank = read_last_view_rank(transaction, belong_to_id.as_ref()).await?;
 
     let (sql, args, view) = NewViewSqlBuilder::new(belong_to_id.as_ref())
         .name(name.as_ref())
         .desc(desc.as_ref())
         .thumbnail(thumbnail.as_ref())
+        .rank(&rank_between(&last_rank, None))
         .view_type(params.view_type)
         .build()?;
 
@@ -104,6 +147,7 @@ pub(crate) async fn read_view(
     transaction: &mut DBTransaction<'_>,
 ) -> Result<View, ServerError> {
     let table = read_view_table(view_id, transaction as &mut DBTransaction<'_>).await?;
+    let _ = check_belong_to_readable(transaction, &table.belong_to_id, user).await?;
 
     let read_trash_ids = read_trash_ids(user, transaction).await?;
     if read_trash_ids.contains(&table.id.to_string()) {
@@ -112,7 +156,7 @@ pub(crate) async fn read_view(
 
     let mut views = RepeatedView::default();
     views.set_items(
-        read_view_belong_to_id(&table.id.to_string(), &user, transaction)
+        read_view_belong_to_id(&table.id.to_string(), None, &user, transaction)
             .await?
             .into(),
     );
@@ -141,24 +185,287 @@ pub(crate) async fn read_view_table(
 // transaction must be commit from caller
 pub(crate) async fn read_view_belong_to_id<'c>(
     id: &str,
+    filter: Option<&ListFilter>,
     user: &LoggedUser,
     transaction: &mut DBTransaction<'_>,
 ) -> Result<Vec<View>, ServerError> {
+    let trash_ids = read_trash_uuids(user, transaction as &mut DBTransaction<'_>).await?;
     // TODO: add index for app_table
-    let (sql, args) = SqlBuilder::select(VIEW_TABLE)
+    let builder = SqlBuilder::select(VIEW_TABLE)
         .add_field("*")
         .and_where_eq("belong_to_id", id)
-        .build()?;
+        .and_where_not_in("id", trash_ids);
+    let (sql, args) = build_list_filter(builder, filter).build()?;
 
-    let mut tables = sqlx::query_as_with::<Postgres, ViewTable, PgArguments>(&sql, args)
+    let tables = sqlx::query_as_with::<Postgres, ViewTable, PgArguments>(&sql, args)
         .fetch_all(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
 
-    let read_trash_ids = read_trash_ids(user, transaction).await?;
-    tables.retain(|table| !read_trash_ids.contains(&table.id.to_string()));
-
     let views = tables.into_iter().map(|table| table.into()).collect::<Vec<View>>();
 
     Ok(views)
 }
+
+// The view can be moved under an app or a view of the same workspace, but not under itself or its
+// children. The rank is computed by the client from the views around the position that it is moved to.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn move_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    belong_to_id: String,
+    rank: String,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let belong_to_id = AppId::parse(belong_to_id).map_err(invalid_params)?;
+    let rank = ItemRank::parse(rank).map_err(invalid_params)?;
+    let table = read_view_table(view_id, transaction).await?;
+    let _ = check_belong_to_writable(transaction, &table.belong_to_id, logged_user).await?;
+    let workspace_id = read_belong_to_workspace_id(transaction, &table.belong_to_id).await?;
+    if read_belong_to_workspace_id(transaction, belong_to_id.as_ref()).await? != workspace_id {
+        return Err(invalid_params(ErrorCode::MoveTargetInvalid).context("Can't move the view to another workspace"));
+    }
+
+    if is_view_or_its_child(transaction, view_id, belong_to_id.as_ref()).await? {
+        return Err(invalid_params(ErrorCode::MoveTargetInvalid).context("Can't move the view under itself"));
+    }
+
+    let (sql, args) = SqlBuilder::update(VIEW_TABLE)
+        .add_arg("belong_to_id", belong_to_id.0)
+        .add_arg("rank", rank.0)
+        .and_where_eq("id", view_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Copies the view, the views nested in it and their docs. The copy is put right after the view with the
+// " (copy)" suffix, the nested views keep their names and order.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn duplicate_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<View, ServerError> {
+    let table = read_view_table(view_id, transaction).await?;
+    let _ = check_belong_to_writable(transaction, &table.belong_to_id, logged_user).await?;
+    let next_rank = read_next_view_rank(transaction, &table.belong_to_id, &table.rank).await?;
+    let rank = rank_between(&table.rank, next_rank.as_deref());
+    let name = format!("{} (copy)", table.name);
+    let belong_to_id = table.belong_to_id.clone();
+    copy_view_tree(transaction, table, &belong_to_id, &name, &rank, logged_user).await
+}
+
+// Copies the view under the belong_to_id with the name and the rank, the views nested in it are copied
+// too except the ones in the trash. Returns the copy with the nested copies as its belongings.
+pub(crate) async fn copy_view_tree(
+    transaction: &mut DBTransaction<'_>,
+    table: ViewTable,
+    belong_to_id: &str,
+    name: &str,
+    rank: &str,
+    logged_user: &LoggedUser,
+) -> Result<View, ServerError> {
+    // The parents always come before their children
+    let trash_ids = read_trash_uuids(logged_user, transaction).await?;
+    let mut tables = vec![table];
+    let mut index = 0;
+    while index < tables.len() {
+        let children = read_child_view_tables(transaction, &tables[index].id.to_string(), &trash_ids).await?;
+        tables.extend(children);
+        index += 1;
+    }
+
+    let new_ids = tables
+        .iter()
+        .map(|table| (table.id.to_string(), Uuid::new_v4().to_string()))
+        .collect::<HashMap<String, String>>();
+    let mut views: Vec<View> = vec![];
+    for (index, table) in tables.into_iter().enumerate() {
+        let data = read_doc_table(transaction, table.id).await?.data;
+        let thumbnail = table.thumbnail.clone();
+        let mut view: View = table.into();
+        let time = Utc::now().timestamp();
+        view.set_id(new_ids[view.get_id()].clone());
+        view.set_create_time(time);
+        view.set_modified_time(time);
+        if index == 0 {
+            view.set_belong_to_id(belong_to_id.to_owned());
+            view.set_name(name.to_owned());
+            view.set_rank(rank.to_owned());
+        } else {
+            view.set_belong_to_id(new_ids[view.get_belong_to_id()].clone());
+        }
+
+        let (sql, args, view) = NewViewSqlBuilder::from_view(view)?.thumbnail(&thumbnail).build()?;
+        views.push(create_view_with_args(transaction, sql, args, view, data).await?);
+    }
+
+    // Walk back from the last copy so that each one is added to its parent in order
+    while views.len() > 1 {
+        let view = views.pop().unwrap();
+        let parent = views
+            .iter_mut()
+            .find(|parent| parent.get_id() == view.get_belong_to_id())
+            .ok_or_else(|| ServerError::internal().context("The parent of the copied view is missing"))?;
+        parent.mut_belongings().mut_items().insert(0, view);
+    }
+    Ok(views.pop().unwrap())
+}
+
+// The views that belong to the app or the view and aren't in the trash, in their order
+pub(crate) async fn read_child_view_tables(
+    transaction: &mut DBTransaction<'_>,
+    belong_to_id: &str,
+    trash_ids: &[Uuid],
+) -> Result<Vec<ViewTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select(VIEW_TABLE)
+        .add_field("*")
+        .and_where_eq("belong_to_id", belong_to_id)
+        .and_where_not_in("id", trash_ids.to_vec())
+        .order_by("rank", true)
+        .build()?;
+
+    let tables = sqlx::query_as_with::<Postgres, ViewTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(tables)
+}
+
+// Tells whether the id is the view itself or one of its children by walking up from the id until reaching
+// the app
+async fn is_view_or_its_child(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    id: &str,
+) -> Result<bool, ServerError> {
+    let mut id = Uuid::parse_str(id)?;
+    loop {
+        if id == view_id {
+            return Ok(true);
+        }
+
+        match read_view_table(id, transaction).await {
+            Ok(table) => id = Uuid::parse_str(&table.belong_to_id)?,
+            Err(e) if e.is_record_not_found() => return Ok(false),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// The new view is put after the other views that belong to the same app or view
+async fn read_last_view_rank(transaction: &mut DBTransaction<'_>, belong_to_id: &str) -> Result<String, ServerError> {
+    let (sql, args) = SqlBuilder::select(VIEW_TABLE)
+        .add_field("rank")
+        .and_where_eq("belong_to_id", belong_to_id)
+        .order_by("rank", false)
+        .limit(1)
+        .build()?;
+
+    let rank = sqlx::query_with(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(|row| row.get::<String, _>("rank"))
+        .unwrap_or_default();
+    Ok(rank)
+}
+
+// The rank of the view that comes right after the rank among the views that belong to the same app or view
+async fn read_next_view_rank(
+    transaction: &mut DBTransaction<'_>,
+    belong_to_id: &str,
+    rank: &str,
+) -> Result<Option<String>, ServerError> {
+    let (sql, args) = SqlBuilder::select(VIEW_TABLE)
+        .add_field("rank")
+        .and_where_eq("belong_to_id", belong_to_id)
+        .and_where_gt("rank", rank)
+        .order_by("rank", true)
+        .limit(1)
+        .build()?;
+
+    let rank = sqlx::query_with(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(|row| row.get::<String, _>("rank"));
+    Ok(rank)
+}
+
+pub(crate) async fn check_view_writable(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let table = read_view_table(view_id, transaction).await?;
+    check_belong_to_writable(transaction, &table.belong_to_id, logged_user).await
+}
+
+pub(crate) async fn check_view_readable(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let table = read_view_table(view_id, transaction).await?;
+    check_belong_to_readable(transaction, &table.belong_to_id, logged_user).await
+}
+
+// The view that doesn't belong to any workspace, e.g. its app was deleted, can't be accessed by anyone
+async fn check_belong_to_writable(
+    transaction: &mut DBTransaction<'_>,
+    belong_to_id: &str,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let workspace_id = read_belong_to_workspace_id(transaction, belong_to_id)
+        .await?
+        .ok_or_else(|| ServerError::record_not_found().context("The view doesn't belong to any workspace"))?;
+    let _ = check_workspace_writable(transaction, &workspace_id).await?;
+    check_workspace_editable(transaction, &workspace_id, logged_user).await
+}
+
+async fn check_belong_to_readable(
+    transaction: &mut DBTransaction<'_>,
+    belong_to_id: &str,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let workspace_id = read_belong_to_workspace_id(transaction, belong_to_id)
+        .await?
+        .ok_or_else(|| ServerError::record_not_found().context("The view doesn't belong to any workspace"))?;
+    check_workspace_readable(transaction, &workspace_id, logged_user).await
+}
+
+// The view belongs to an app or to another view. Walk up until reaching the app, and then return the
+// workspace of the app. Returns None if the view doesn't belong to any app.
+pub(crate) async fn read_belong_to_workspace_id(
+    transaction: &mut DBTransaction<'_>,
+    belong_to_id: &str,
+) -> Result<Option<String>, ServerError> {
+    let app_table = read_belong_to_app_table(transaction, belong_to_id).await?;
+    Ok(app_table.map(|table| table.workspace_id))
+}
+
+// Returns the app of the top level view that the view is nested in, or None if the view doesn't belong
+// to any app.
+pub(crate) async fn read_belong_to_app_table(
+    transaction: &mut DBTransaction<'_>,
+    belong_to_id: &str,
+) -> Result<Option<AppTable>, ServerError> {
+    let mut belong_to_id = Uuid::parse_str(belong_to_id)?;
+    loop {
+        match read_app_table(belong_to_id, transaction).await {
+            Ok(table) => return Ok(Some(table)),
+            Err(e) if e.is_record_not_found() => match read_view_table(belong_to_id, transaction).await {
+                Ok(table) => belong_to_id = Uuid::parse_str(&table.belong_to_id)?,
+                Err(e) if e.is_record_not_found() => return Ok(None),
+                Err(e) => return Err(e),
+            },
+            Err(e) => return Err(e),
+        }
+    }
+}
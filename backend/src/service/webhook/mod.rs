@@ -0,0 +1,4 @@
+pub mod router;
+mod webhook;
+
+pub(crate) use webhook::*;
@@ -0,0 +1,77 @@
+use crate::service::{
+    user::LoggedUser,
+    util::parse_from_payload,
+    view::sql_builder::check_view_ids,
+    webhook::{read_view_webhook, remove_view_webhook, set_view_webhook},
+};
+use actix_web::{
+    web::{Data, Payload},
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{errors::ServerError, response::FlowyResponse};
+use flowy_workspace_infra::protobuf::{SetViewWebhookParams, ViewIdentifier};
+use sqlx::PgPool;
+
+pub async fn set_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: SetViewWebhookParams = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id.clone()])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to set view webhook")?;
+
+    let webhook = set_view_webhook(&mut transaction, view_id, params, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to set view webhook.")?;
+
+    Ok(FlowyResponse::success().pb(webhook)?.into())
+}
+
+pub async fn read_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read view webhook")?;
+
+    let webhook = read_view_webhook(&mut transaction, view_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read view webhook.")?;
+
+    Ok(FlowyResponse::success().pb(webhook)?.into())
+}
+
+pub async fn delete_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to delete view webhook")?;
+
+    let _ = remove_view_webhook(&mut transaction, view_id, &logged_user).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to delete view webhook.")?;
+
+    Ok(FlowyResponse::success().into())
+}
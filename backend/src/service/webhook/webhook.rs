@@ -0,0 +1,230 @@
+use crate::{
+    config::WEBHOOK_TIMEOUT,
+    entities::webhook::{ViewWebhookTable, VIEW_WEBHOOK_TABLE},
+    service::{
+        doc::read_doc_table,
+        user::LoggedUser,
+        view::{read_belong_to_workspace_id, read_view_table},
+        workspace::check_workspace_owner,
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use actix_web::web::Data;
+use anyhow::Context;
+use backend_service::errors::{internal_error, invalid_params, ServerError};
+use chrono::Utc;
+use flowy_document_infra::core::{delta_to_html, delta_to_markdown};
+use flowy_workspace_infra::{
+    parser::view::{WebhookSecret, WebhookUrl},
+    protobuf::{SetViewWebhookParams, ViewType, ViewWebhook, WebhookFormat},
+};
+use lazy_static::lazy_static;
+use lib_ot::core::Delta;
+use protobuf::ProtobufEnum;
+use reqwest::{header::AUTHORIZATION, Client};
+use sqlx::{postgres::PgArguments, PgPool, Postgres};
+use uuid::Uuid;
+
+lazy_static! {
+    static ref WEBHOOK_CLIENT: Client = Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .unwrap_or_else(|_| Client::new());
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum WebhookEvent {
+    Changed,
+    Published,
+}
+
+impl WebhookEvent {
+    fn name(&self) -> &'static str {
+        match self {
+            WebhookEvent::Changed => "changed",
+            WebhookEvent::Published => "published",
+        }
+    }
+}
+
+// Like the share links, the webhook sends the doc out of the workspace, so only the owner of the
+// workspace can manage it. Setting the webhook replaces the one that the view had.
+#[tracing::instrument(skip(transaction, params, user), err)]
+pub(crate) async fn set_view_webhook(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    params: SetViewWebhookParams,
+    user: &LoggedUser,
+) -> Result<ViewWebhook, ServerError> {
+    let url = WebhookUrl::parse(params.get_url().to_owned()).map_err(invalid_params)?;
+    let secret = WebhookSecret::parse(params.get_secret().to_owned()).map_err(invalid_params)?;
+    let view_table = read_view_table(view_id, transaction).await?;
+    if ViewType::from_i32(view_table.view_type) != Some(ViewType::Doc) {
+        return Err(invalid_params("Only the doc view can have the webhook"));
+    }
+    let _ = check_webhook_owner(transaction, &view_table.belong_to_id, user).await?;
+
+    let table = ViewWebhookTable {
+        view_id,
+        owner_id: user.user_id.clone(),
+        url: url.0,
+        format: params.get_format().value(),
+        secret: secret.0,
+        create_time: Utc::now(),
+        last_delivery_time: None,
+        last_status: 0,
+    };
+    let _ = delete_view_webhook(transaction, view_id).await?;
+    let (sql, args) = SqlBuilder::create(VIEW_WEBHOOK_TABLE)
+        .add_arg("view_id", table.view_id)
+        .add_arg("owner_id", &table.owner_id)
+        .add_arg("url", &table.url)
+        .add_arg("format", table.format)
+        .add_arg("secret", &table.secret)
+        .add_arg("create_time", table.create_time)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(table.into())
+}
+
+// Returns the webhook with the empty url if the view has no webhook
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn read_view_webhook(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    user: &LoggedUser,
+) -> Result<ViewWebhook, ServerError> {
+    let table = read_view_table(view_id, transaction).await?;
+    let _ = check_webhook_owner(transaction, &table.belong_to_id, user).await?;
+    match read_view_webhook_table(transaction, view_id).await? {
+        Some(table) => Ok(table.into()),
+        None => {
+            let mut webhook = ViewWebhook::default();
+            webhook.set_view_id(view_id.to_string());
+            Ok(webhook)
+        },
+    }
+}
+
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn remove_view_webhook(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let table = read_view_table(view_id, transaction).await?;
+    let _ = check_webhook_owner(transaction, &table.belong_to_id, user).await?;
+    delete_view_webhook(transaction, view_id).await
+}
+
+pub(crate) async fn delete_view_webhook(transaction: &mut DBTransaction<'_>, view_id: Uuid) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(VIEW_WEBHOOK_TABLE)
+        .and_where_eq("view_id", view_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+pub(crate) fn spawn_webhook_delivery(view_id: &str, pg_pool: Data<PgPool>, event: WebhookEvent) {
+    let view_id = view_id.to_owned();
+    tokio::spawn(async move {
+        if let Err(e) = deliver_view_webhook(pg_pool.get_ref(), &view_id, event).await {
+            log::error!("Deliver the view {} to the webhook failed: {:?}", view_id, e);
+        }
+    });
+}
+
+// Posts the latest revision of the doc to the webhook of the view, the view without the webhook is
+// skipped. The result of the delivery is saved, the failed delivery isn't retried until the doc changes
+// again.
+#[tracing::instrument(skip(pool), err)]
+pub(crate) async fn deliver_view_webhook(pool: &PgPool, view_id: &str, event: WebhookEvent) -> Result<(), ServerError> {
+    let view_id = Uuid::parse_str(view_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read view webhook")?;
+    let webhook = match read_view_webhook_table(&mut transaction, view_id).await? {
+        None => return Ok(()),
+        Some(webhook) => webhook,
+    };
+    let view = read_view_table(view_id, &mut transaction).await?;
+    let doc = read_doc_table(&mut transaction, view_id).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read view webhook.")?;
+
+    let delta = Delta::from_json(&doc.data).map_err(internal_error)?;
+    let format = WebhookFormat::from_i32(webhook.format).unwrap_or(WebhookFormat::Markdown);
+    let (format_name, content) = match format {
+        WebhookFormat::Markdown => ("markdown", delta_to_markdown(&delta)),
+        WebhookFormat::Html => ("html", delta_to_html(&delta)),
+    };
+    let body = serde_json::json!({
+        "event": event.name(),
+        "view_id": view_id.to_string(),
+        "name": view.name,
+        "rev_id": doc.rev_id,
+        "format": format_name,
+        "content": content,
+    });
+
+    let mut request = WEBHOOK_CLIENT.post(&webhook.url).json(&body);
+    if !webhook.secret.is_empty() {
+        request = request.header(AUTHORIZATION, format!("Bearer {}", webhook.secret));
+    }
+    let status = match request.send().await {
+        Ok(response) => response.status().as_u16() as i32,
+        Err(e) => {
+            log::warn!("Deliver the doc {} to the webhook failed: {:?}", view_id, e);
+            0
+        },
+    };
+
+    let (sql, args) = SqlBuilder::update(VIEW_WEBHOOK_TABLE)
+        .add_arg("last_delivery_time", Utc::now())
+        .add_arg("last_status", status)
+        .and_where_eq("view_id", view_id)
+        .build()?;
+    let _ = sqlx::query_with(&sql, args)
+        .execute(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+async fn read_view_webhook_table(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+) -> Result<Option<ViewWebhookTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select(VIEW_WEBHOOK_TABLE)
+        .add_field("*")
+        .and_where_eq("view_id", view_id)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, ViewWebhookTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(table)
+}
+
+async fn check_webhook_owner(
+    transaction: &mut DBTransaction<'_>,
+    belong_to_id: &str,
+    user: &LoggedUser,
+) -> Result<(), ServerError> {
+    let workspace_id = read_belong_to_workspace_id(transaction, belong_to_id)
+        .await?
+        .ok_or_else(|| invalid_params("The view doesn't belong to any workspace"))?;
+    check_workspace_owner(transaction, Uuid::parse_str(&workspace_id)?, user, "manage the webhooks of").await
+}
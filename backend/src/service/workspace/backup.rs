@@ -0,0 +1,351 @@
+use crate::{
+    entities::{
+        attachment::{AttachmentTable, ATTACHMENT_TABLE},
+        workspace::{AppTable, ViewTable, APP_TABLE},
+    },
+    service::{
+        app::sql_builder::NewAppSqlBuilder,
+        attachment::create_attachment,
+        doc::read_doc_table,
+        trash::read_trash_uuids,
+        user::LoggedUser,
+        util::parse_from_bytes,
+        view::{create_view_with_args, read_child_view_tables, sql_builder::NewViewSqlBuilder},
+        workspace::{check_workspace_readable, read_workspace_table, sql_builder::NewWorkspaceBuilder},
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::Utc;
+use flowy_document_infra::{core::replace_doc_references, protobuf::CreateAttachmentParams};
+use flowy_workspace_infra::{
+    parser::{
+        app::{AppColorStyle, AppDesc, AppName},
+        view::{ViewDesc, ViewName, ViewThumbnail},
+        workspace::{WorkspaceDesc, WorkspaceName},
+    },
+    protobuf::{ColorStyle, RestoredAttachment, View, ViewType, WorkspaceRestore},
+};
+use lib_ot::core::Delta;
+use protobuf::ProtobufEnum;
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgArguments, Postgres};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+// The version of the archive that is written, the archives of newer versions are rejected by the restore
+pub const BACKUP_VERSION: i64 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkspaceBackup {
+    pub version: i64,
+    pub create_time: i64,
+    pub name: String,
+    pub desc: String,
+    pub apps: Vec<AppBackup>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppBackup {
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    pub theme_color: String,
+    pub rank: String,
+    pub views: Vec<ViewBackup>,
+}
+
+// The data is the delta of the doc in json, the nested views are kept in the views
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ViewBackup {
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    pub view_type: i32,
+    pub thumbnail: String,
+    pub rank: String,
+    pub data: String,
+    pub attachments: Vec<AttachmentBackup>,
+    pub views: Vec<ViewBackup>,
+}
+
+// Only the manifest of the attachment is kept, its chunks stay in the storage of the server
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttachmentBackup {
+    pub id: String,
+    pub name: String,
+    pub size: i64,
+}
+
+// Writes the apps, the views, the docs and the manifest of the attachments of the workspace into the
+// backup. Any member can back up the workspace, the apps and the views in the trash are skipped.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn backup_workspace(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<WorkspaceBackup, ServerError> {
+    let _ = check_workspace_readable(transaction, &workspace_id.to_string(), logged_user).await?;
+    let workspace = read_workspace_table(transaction, workspace_id).await?;
+    let trash_ids = read_trash_uuids(logged_user, transaction).await?;
+    let (sql, args) = SqlBuilder::select(APP_TABLE)
+        .add_field("*")
+        .and_where_eq("workspace_id", workspace_id.to_string())
+        .and_where_not_in("id", trash_ids.clone())
+        .order_by("rank", true)
+        .build()?;
+
+    let app_tables = sqlx::query_as_with::<Postgres, AppTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut apps = vec![];
+    for app_table in app_tables {
+        let color_style: ColorStyle = parse_from_bytes(&app_table.color_style)?;
+        let mut app = AppBackup {
+            id: app_table.id.to_string(),
+            name: app_table.name,
+            desc: app_table.description,
+            theme_color: color_style.theme_color,
+            rank: app_table.rank,
+            views: vec![],
+        };
+        app.views = backup_view_tree(transaction, &app.id, &trash_ids).await?;
+        apps.push(app);
+    }
+
+    Ok(WorkspaceBackup {
+        version: BACKUP_VERSION,
+        create_time: Utc::now().timestamp(),
+        name: workspace.name,
+        desc: workspace.description,
+        apps,
+    })
+}
+
+// Reads the views under the app level by level, then nests each view into its parent from the deepest level
+async fn backup_view_tree(
+    transaction: &mut DBTransaction<'_>,
+    app_id: &str,
+    trash_ids: &[Uuid],
+) -> Result<Vec<ViewBackup>, ServerError> {
+    let mut tables: Vec<ViewTable> = read_child_view_tables(transaction, app_id, trash_ids).await?;
+    let mut index = 0;
+    while index < tables.len() {
+        let children = read_child_view_tables(transaction, &tables[index].id.to_string(), trash_ids).await?;
+        tables.extend(children);
+        index += 1;
+    }
+
+    let mut views = vec![];
+    for table in tables {
+        let data = read_doc_table(transaction, table.id).await?.data;
+        let attachments = read_doc_attachment_tables(transaction, &table.id.to_string())
+            .await?
+            .into_iter()
+            .map(|attachment| AttachmentBackup {
+                id: attachment.id.to_string(),
+                name: attachment.name,
+                size: attachment.size,
+            })
+            .collect();
+        let view = ViewBackup {
+            id: table.id.to_string(),
+            name: table.name,
+            desc: table.description,
+            view_type: table.view_type,
+            thumbnail: table.thumbnail,
+            rank: table.rank,
+            data,
+            attachments,
+            views: vec![],
+        };
+        views.push((table.belong_to_id, view));
+    }
+
+    // The parents always come before their children
+    while let Some((belong_to_id, view)) = views.pop() {
+        match views.iter_mut().find(|(_, parent)| parent.id == belong_to_id) {
+            Some((_, parent)) => parent.views.insert(0, view),
+            None => {
+                views.push((belong_to_id, view));
+                break;
+            },
+        }
+    }
+    Ok(views.into_iter().map(|(_, view)| view).collect())
+}
+
+async fn read_doc_attachment_tables(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: &str,
+) -> Result<Vec<AttachmentTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select(ATTACHMENT_TABLE)
+        .add_field("*")
+        .and_where_eq("doc_id", doc_id)
+        .and_where_eq("is_orphaned", false)
+        .order_by("create_time", true)
+        .build()?;
+
+    let tables = sqlx::query_as_with::<Postgres, AttachmentTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(tables)
+}
+
+// Creates a new workspace that is owned by the user from the backup. All the apps, views and attachments get
+// fresh ids, so the backup can be restored on the same server more than once. The links between the docs
+// and the attachments in the docs are pointed to the new ids. The chunks of the attachments aren't in the
+// backup, they must be uploaded again to the returned attachments.
+#[tracing::instrument(skip(transaction, backup, logged_user), err)]
+pub(crate) async fn restore_workspace(
+    transaction: &mut DBTransaction<'_>,
+    backup: WorkspaceBackup,
+    logged_user: &LoggedUser,
+) -> Result<WorkspaceRestore, ServerError> {
+    if logged_user.is_bot {
+        return Err(ServerError::permission_denied().context("The bot can't own the restored workspace"));
+    }
+    if backup.version < 1 || backup.version > BACKUP_VERSION {
+        return Err(invalid_params(format!("The backup version {} isn't supported", backup.version)));
+    }
+
+    let name = WorkspaceName::parse(backup.name).map_err(invalid_params)?;
+    let desc = WorkspaceDesc::parse(backup.desc).map_err(invalid_params)?;
+    let mut new_ids = HashMap::new();
+    let mut views = vec![];
+    for app in &backup.apps {
+        let mut index = views.len();
+        views.extend(app.views.iter());
+        while index < views.len() {
+            let view: &ViewBackup = views[index];
+            views.extend(view.views.iter());
+            index += 1;
+        }
+    }
+    for view in &views {
+        new_ids.insert(view.id.clone(), Uuid::new_v4().to_string());
+    }
+
+    // The attachments are created before the docs, so the docs reference the existing attachments
+    let mut attachments = vec![];
+    for view in &views {
+        for attachment in &view.attachments {
+            let mut params = CreateAttachmentParams::new();
+            params.set_doc_id(new_ids[&view.id].clone());
+            params.set_name(attachment.name.clone());
+            params.set_size(attachment.size);
+            let new_attachment = create_attachment(transaction, params, logged_user.clone()).await?;
+            new_ids.insert(attachment.id.clone(), new_attachment.id.clone());
+
+            let mut restored = RestoredAttachment::new();
+            restored.set_backup_id(attachment.id.clone());
+            restored.set_attachment_id(new_attachment.id);
+            restored.set_doc_id(new_attachment.doc_id);
+            attachments.push(restored);
+        }
+    }
+
+    let user_id = logged_user.as_uuid()?.to_string();
+    let (sql, args, mut workspace) = NewWorkspaceBuilder::new(&user_id)
+        .name(name.as_ref())
+        .desc(desc.as_ref())
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut created_views = HashMap::new();
+    for app_backup in &backup.apps {
+        let name = AppName::parse(app_backup.name.clone()).map_err(invalid_params)?;
+        let desc = AppDesc::parse(app_backup.desc.clone()).map_err(invalid_params)?;
+        let color = AppColorStyle::parse(app_backup.theme_color.clone()).map_err(invalid_params)?;
+        let mut color_style = ColorStyle::new();
+        color_style.set_theme_color(color.theme_color);
+        let (sql, args, mut app) = NewAppSqlBuilder::new(&user_id, workspace.get_id())
+            .name(name.as_ref())
+            .desc(desc.as_ref())
+            .rank(&app_backup.rank)
+            .color_style(color_style)
+            .build()?;
+
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let mut parents = app_backup
+            .views
+            .iter()
+            .map(|view| (app.get_id().to_owned(), view))
+            .collect::<Vec<(String, &ViewBackup)>>();
+        let mut index = 0;
+        while index < parents.len() {
+            let (belong_to_id, view_backup) = parents[index].clone();
+            let view = restore_view(transaction, view_backup, &belong_to_id, &new_ids).await?;
+            parents.extend(view_backup.views.iter().map(|child| (view.get_id().to_owned(), child)));
+            created_views.insert(view.get_id().to_owned(), view);
+            index += 1;
+        }
+
+        for view_backup in &app_backup.views {
+            let view = nest_restored_view(view_backup, &new_ids, &mut created_views);
+            app.mut_belongings().mut_items().push(view);
+        }
+        workspace.mut_apps().mut_items().push(app);
+    }
+
+    let mut restore = WorkspaceRestore::new();
+    restore.set_workspace(workspace);
+    restore.set_attachments(attachments.into());
+    Ok(restore)
+}
+
+async fn restore_view(
+    transaction: &mut DBTransaction<'_>,
+    backup: &ViewBackup,
+    belong_to_id: &str,
+    new_ids: &HashMap<String, String>,
+) -> Result<View, ServerError> {
+    let name = ViewName::parse(backup.name.clone()).map_err(invalid_params)?;
+    let desc = ViewDesc::parse(backup.desc.clone()).map_err(invalid_params)?;
+    let thumbnail = ViewThumbnail::parse(backup.thumbnail.clone()).map_err(invalid_params)?;
+    let view_type = ViewType::from_i32(backup.view_type)
+        .ok_or_else(|| invalid_params(format!("The view type {} is invalid", backup.view_type)))?;
+    let delta = Delta::from_json(&backup.data).map_err(invalid_params)?;
+    let data = replace_doc_references(&delta, new_ids).to_json();
+
+    let time = Utc::now().timestamp();
+    let mut view = View::new();
+    view.set_id(new_ids[&backup.id].clone());
+    view.set_belong_to_id(belong_to_id.to_owned());
+    view.set_name(name.as_ref().to_owned());
+    view.set_desc(desc.as_ref().to_owned());
+    view.set_view_type(view_type);
+    view.set_rank(backup.rank.clone());
+    view.set_create_time(time);
+    view.set_modified_time(time);
+
+    let (sql, args, view) = NewViewSqlBuilder::from_view(view)?
+        .thumbnail(thumbnail.as_ref())
+        .build()?;
+    create_view_with_args(transaction, sql, args, view, data).await
+}
+
+// Takes the created views out of the map and nests them as they were nested in the backup
+fn nest_restored_view(
+    backup: &ViewBackup,
+    new_ids: &HashMap<String, String>,
+    views: &mut HashMap<String, View>,
+) -> View {
+    let mut view = views.remove(&new_ids[&backup.id]).unwrap_or_default();
+    for child in &backup.views {
+        let child = nest_restored_view(child, new_ids, views);
+        view.mut_belongings().mut_items().push(child);
+    }
+    view
+}
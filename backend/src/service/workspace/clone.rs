@@ -0,0 +1,211 @@
+use crate::{
+    entities::workspace::{AppTable, WorkspaceCloneTable, APP_TABLE, WORKSPACE_CLONE_TABLE},
+    service::{
+        app::sql_builder::NewAppSqlBuilder,
+        metrics::begin_transaction,
+        trash::read_trash_uuids,
+        user::LoggedUser,
+        util::parse_from_bytes,
+        view::{copy_view_tree, read_child_view_tables},
+        workspace::{check_workspace_readable, read_workspace_table, sql_builder::NewWorkspaceBuilder},
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use ::protobuf::ProtobufEnum;
+use anyhow::Context;
+use backend_service::errors::ServerError;
+use chrono::Utc;
+use flowy_workspace_infra::protobuf::{WorkspaceCloneProgress, WorkspaceCloneState};
+use sqlx::{postgres::PgArguments, PgPool, Postgres};
+use uuid::Uuid;
+
+// Copies the apps, the views and the docs of the workspace into a new workspace that is owned by the user,
+// so the reorganizations can be tried out on the copy. Any member can clone the workspace. The clone runs in
+// the background, the returned progress is read again with its clone_id.
+#[tracing::instrument(skip(pool, logged_user), err)]
+pub(crate) async fn clone_workspace(
+    pool: &PgPool,
+    workspace_id: Uuid,
+    logged_user: LoggedUser,
+) -> Result<WorkspaceCloneProgress, ServerError> {
+    if logged_user.is_bot {
+        return Err(ServerError::permission_denied().context("The bot can't own the copy of the workspace"));
+    }
+
+    let mut transaction = begin_transaction(pool, "clone workspace").await?;
+    let _ = check_workspace_readable(&mut transaction, &workspace_id.to_string(), &logged_user).await?;
+
+    let table = WorkspaceCloneTable {
+        id: Uuid::new_v4(),
+        user_id: logged_user.user_id.clone(),
+        source_workspace_id: workspace_id.to_string(),
+        workspace_id: "".to_owned(),
+        total: 0,
+        finished: 0,
+        state: WorkspaceCloneState::Running.value(),
+        create_time: Utc::now(),
+    };
+    let (sql, args) = SqlBuilder::create(WORKSPACE_CLONE_TABLE)
+        .add_arg("id", table.id)
+        .add_arg("user_id", &table.user_id)
+        .add_arg("source_workspace_id", &table.source_workspace_id)
+        .add_arg("workspace_id", &table.workspace_id)
+        .add_arg("state", table.state)
+        .add_arg("create_time", table.create_time)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(&mut *transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to clone workspace.")?;
+
+    let clone_id = table.id;
+    let pool = pool.clone();
+    tokio::spawn(async move {
+        let (copy_id, state) = match copy_workspace(&pool, clone_id, workspace_id, &logged_user).await {
+            Ok(copy_id) => (copy_id, WorkspaceCloneState::Completed),
+            Err(e) => {
+                log::error!("Clone the workspace {} failed: {:?}", workspace_id, e);
+                ("".to_owned(), WorkspaceCloneState::Failed)
+            },
+        };
+
+        if let Err(e) = finish_clone(&pool, clone_id, &copy_id, state).await {
+            log::error!("Save the state of the workspace clone {} failed: {:?}", clone_id, e);
+        }
+    });
+
+    Ok(table.into())
+}
+
+// Only the user who started the clone can read its progress
+pub(crate) async fn read_clone_progress(
+    transaction: &mut DBTransaction<'_>,
+    clone_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<WorkspaceCloneProgress, ServerError> {
+    let (sql, args) = SqlBuilder::select(WORKSPACE_CLONE_TABLE)
+        .add_field("*")
+        .and_where_eq("id", clone_id)
+        .and_where_eq("user_id", &logged_user.user_id)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, WorkspaceCloneTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("No workspace clone {}", clone_id)))?;
+    Ok(table.into())
+}
+
+// The copy and all its apps and views are created in one transaction, so the half-done copy is never seen.
+// The progress is saved outside of the transaction each time an app or a view right under the app is
+// copied, the views nested in the view are copied with it. The apps and the views in the trash are skipped.
+async fn copy_workspace(
+    pool: &PgPool,
+    clone_id: Uuid,
+    workspace_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<String, ServerError> {
+    let mut transaction = begin_transaction(pool, "copy workspace").await?;
+    let source = read_workspace_table(&mut transaction, workspace_id).await?;
+    let trash_ids = read_trash_uuids(logged_user, &mut transaction).await?;
+    let (sql, args) = SqlBuilder::select(APP_TABLE)
+        .add_field("*")
+        .and_where_eq("workspace_id", workspace_id.to_string())
+        .and_where_not_in("id", trash_ids.clone())
+        .order_by("rank", true)
+        .build()?;
+
+    let app_tables = sqlx::query_as_with::<Postgres, AppTable, PgArguments>(&sql, args)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut apps = vec![];
+    for app_table in app_tables {
+        let view_tables = read_child_view_tables(&mut transaction, &app_table.id.to_string(), &trash_ids).await?;
+        apps.push((app_table, view_tables));
+    }
+    let total = apps.iter().map(|(_, view_tables)| 1 + view_tables.len() as i64).sum();
+    let _ = update_clone_progress(pool, clone_id, total, 0).await?;
+
+    let user_id = logged_user.as_uuid()?.to_string();
+    let (sql, args, workspace) = NewWorkspaceBuilder::new(&user_id)
+        .name(&format!("{} (copy)", source.name))
+        .desc(&source.description)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(&mut *transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut finished = 0;
+    for (app_table, view_tables) in apps {
+        let (sql, args, app) = NewAppSqlBuilder::new(&user_id, workspace.get_id())
+            .name(&app_table.name)
+            .desc(&app_table.description)
+            .rank(&app_table.rank)
+            .color_style(parse_from_bytes(&app_table.color_style)?)
+            .build()?;
+
+        let _ = sqlx::query_with(&sql, args)
+            .execute(&mut *transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+        finished += 1;
+        let _ = update_clone_progress(pool, clone_id, total, finished).await?;
+
+        for view_table in view_tables {
+            let name = view_table.name.clone();
+            let rank = view_table.rank.clone();
+            let _ = copy_view_tree(&mut transaction, view_table, app.get_id(), &name, &rank, logged_user).await?;
+            finished += 1;
+            let _ = update_clone_progress(pool, clone_id, total, finished).await?;
+        }
+    }
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to copy workspace.")?;
+    Ok(workspace.get_id().to_owned())
+}
+
+async fn update_clone_progress(pool: &PgPool, clone_id: Uuid, total: i64, finished: i64) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::update(WORKSPACE_CLONE_TABLE)
+        .add_arg("total", total)
+        .add_arg("finished", finished)
+        .and_where_eq("id", clone_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+async fn finish_clone(
+    pool: &PgPool,
+    clone_id: Uuid,
+    workspace_id: &str,
+    state: WorkspaceCloneState,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::update(WORKSPACE_CLONE_TABLE)
+        .add_arg("workspace_id", workspace_id)
+        .add_arg("state", state.value())
+        .and_where_eq("id", clone_id)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
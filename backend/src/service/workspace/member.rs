@@ -0,0 +1,232 @@
+use crate::{
+    entities::{
+        user::UserTable,
+        workspace::{WorkspaceMemberRow, WORKSPACE_MEMBER_TABLE},
+    },
+    service::{
+        bot::AUTHORIZED_BOTS,
+        user::LoggedUser,
+        workspace::{check_workspace_owner, read_workspace_table},
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
+};
+use ::protobuf::ProtobufEnum;
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::Utc;
+use flowy_workspace_infra::{
+    errors::ErrorCode,
+    parser::workspace::MemberEmail,
+    protobuf::{RepeatedWorkspaceMember, WorkspaceMember, WorkspaceRole},
+};
+use sqlx::{postgres::PgArguments, Postgres, Row};
+use uuid::Uuid;
+
+// Adds the user with the email to the workspace or changes the role of the member. Only the owner
+// can share the workspace and the owner can't be shared again.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn share_workspace(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    email: String,
+    role: WorkspaceRole,
+    logged_user: LoggedUser,
+) -> Result<(), ServerError> {
+    let email = MemberEmail::parse(email).map_err(invalid_params)?;
+    if role == WorkspaceRole::Owner {
+        return Err(invalid_params(ErrorCode::MemberRoleInvalid));
+    }
+    let _ = check_workspace_owner(transaction, workspace_id, &logged_user, "share").await?;
+
+    let user = read_user_with_email(transaction, email.as_ref())
+        .await?
+        .ok_or_else(|| ServerError::record_not_found().context(format!("No user signed up with {}", email.0)))?;
+    let user_id = user.id.to_string();
+    if user_id == logged_user.user_id {
+        return Err(invalid_params(ErrorCode::MemberRoleInvalid).context("The owner can't be shared"));
+    }
+
+    let sql = format!(
+        r#"
+            INSERT INTO {0} (workspace_id, user_id, role, create_time)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (workspace_id, user_id) DO UPDATE SET role = $3
+        "#,
+        WORKSPACE_MEMBER_TABLE
+    );
+
+    let _ = sqlx::query(&sql)
+        .bind(workspace_id)
+        .bind(user_id)
+        .bind(role.value())
+        .bind(Utc::now())
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(())
+}
+
+// Any member of the workspace can read the members. The owner comes first, then the members in the
+// order they were added.
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn read_members(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    logged_user: LoggedUser,
+) -> Result<RepeatedWorkspaceMember, ServerError> {
+    let _ = read_workspace_role(transaction, workspace_id, &logged_user)
+        .await?
+        .ok_or_else(|| ServerError::permission_denied().context("Only the members can read the members"))?;
+
+    let workspace = read_workspace_table(transaction, workspace_id).await?;
+    let owner = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(Uuid::parse_str(&workspace.user_id)?)
+        .fetch_one(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let (sql, args) = SqlBuilder::select(WORKSPACE_MEMBER_TABLE)
+        .add_field("workspace_member_table.user_id")
+        .add_field("workspace_member_table.role")
+        .add_field("user_table.email")
+        .add_field("user_table.name")
+        .join("user_table", "user_table.id::text = workspace_member_table.user_id")
+        .and_where_eq("workspace_member_table.workspace_id", workspace_id)
+        .order_by("workspace_member_table.create_time", true)
+        .build()?;
+
+    let rows = sqlx::query_as_with::<Postgres, WorkspaceMemberRow, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let workspace_id = workspace_id.to_string();
+    let owner_id = owner.id.to_string();
+    let mut members = vec![make_member(&workspace_id, owner_id, owner.email, owner.name, WorkspaceRole::Owner)];
+    for row in rows {
+        let role = WorkspaceRole::from_i32(row.role).unwrap_or(WorkspaceRole::Viewer);
+        members.push(make_member(&workspace_id, row.user_id, row.email, row.name, role));
+    }
+
+    let mut repeated_member = RepeatedWorkspaceMember::default();
+    repeated_member.set_items(members.into());
+    Ok(repeated_member)
+}
+
+// Returns None if the user is neither the owner nor a member of the workspace
+pub(crate) async fn read_workspace_role(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<Option<WorkspaceRole>, ServerError> {
+    let workspace = read_workspace_table(transaction, workspace_id).await?;
+    if workspace.user_id == logged_user.user_id {
+        return Ok(Some(WorkspaceRole::Owner));
+    }
+
+    let (sql, args) = SqlBuilder::select(WORKSPACE_MEMBER_TABLE)
+        .add_field("role")
+        .and_where_eq("workspace_id", workspace_id)
+        .and_where_eq("user_id", &logged_user.user_id)
+        .build()?;
+
+    let role = sqlx::query_with(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(|row| WorkspaceRole::from_i32(row.get("role")).unwrap_or(WorkspaceRole::Viewer));
+    Ok(role)
+}
+
+// The apps, views and docs of the workspace can be read by the owner and all the members, knowing their
+// ids isn't enough. The bot can read the workspace that it was created for.
+pub(crate) async fn check_workspace_readable(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &str,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    if logged_user.is_bot {
+        return match AUTHORIZED_BOTS.caller(&logged_user.user_id) {
+            Some(caller) if caller.workspace_id == workspace_id => Ok(()),
+            _ => Err(ServerError::permission_denied().context("The bot doesn't belong to the workspace")),
+        };
+    }
+
+    let workspace_id = Uuid::parse_str(workspace_id)?;
+    match read_workspace_role(transaction, workspace_id, logged_user).await? {
+        Some(_) => Ok(()),
+        None => Err(ServerError::permission_denied().context(format!("Can't read the workspace {}", workspace_id))),
+    }
+}
+
+// The apps of the workspace can only be changed by the owner and the editors. The bot acts for the
+// owner in the workspace that it was created for.
+pub(crate) async fn check_workspace_editable(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &str,
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    if logged_user.is_bot {
+        return match AUTHORIZED_BOTS.caller(&logged_user.user_id) {
+            Some(caller) if caller.workspace_id == workspace_id => Ok(()),
+            _ => Err(ServerError::permission_denied().context("The bot doesn't belong to the workspace")),
+        };
+    }
+
+    let workspace_id = Uuid::parse_str(workspace_id)?;
+    match read_workspace_role(transaction, workspace_id, logged_user).await? {
+        Some(WorkspaceRole::Owner) | Some(WorkspaceRole::Editor) => Ok(()),
+        _ => Err(ServerError::permission_denied().context(format!("Can't edit the workspace {}", workspace_id))),
+    }
+}
+
+// The ids of the workspaces that are shared with the user
+pub(crate) async fn read_member_workspace_ids(
+    transaction: &mut DBTransaction<'_>,
+    logged_user: &LoggedUser,
+) -> Result<Vec<Uuid>, ServerError> {
+    let (sql, args) = SqlBuilder::select(WORKSPACE_MEMBER_TABLE)
+        .add_field("workspace_id")
+        .and_where_eq("user_id", &logged_user.user_id)
+        .build()?;
+
+    let ids = sqlx::query_with(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?
+        .into_iter()
+        .map(|row| row.get("workspace_id"))
+        .collect::<Vec<Uuid>>();
+    Ok(ids)
+}
+
+async fn read_user_with_email(
+    transaction: &mut DBTransaction<'_>,
+    email: &str,
+) -> Result<Option<UserTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select("user_table")
+        .add_field("*")
+        .and_where_eq("email", email)
+        .build()?;
+
+    let user = sqlx::query_as_with::<Postgres, UserTable, PgArguments>(&sql, args)
+        .fetch_optional(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(user)
+}
+
+fn make_member(
+    workspace_id: &str,
+    user_id: String,
+    email: String,
+    name: String,
+    role: WorkspaceRole,
+) -> WorkspaceMember {
+    let mut member = WorkspaceMember::default();
+    member.set_workspace_id(workspace_id.to_owned());
+    member.set_user_id(user_id);
+    member.set_email(email);
+    member.set_name(name);
+    member.set_role(role);
+    member
+}
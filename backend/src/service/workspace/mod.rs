@@ -1,5 +1,13 @@
+mod backup;
+mod clone;
+mod member;
 pub mod router;
+mod search;
 pub mod sql_builder;
 mod workspace;
 
+pub(crate) use backup::*;
+pub(crate) use clone::*;
+pub(crate) use member::*;
+pub(crate) use search::*;
 pub use workspace::*;
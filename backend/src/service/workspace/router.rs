@@ -1,48 +1,79 @@
-use crate::service::{
-    user::LoggedUser,
-    util::parse_from_payload,
-    workspace::{
-        create_workspace,
-        delete_workspace,
-        read_workspaces,
-        sql_builder::check_workspace_id,
-        update_workspace,
+use crate::{
+    config::MAX_BACKUP_SIZE,
+    service::{
+        idempotency::{read_idempotent_response, save_idempotent_response, IdempotencyKey},
+        metrics::begin_transaction,
+        search::SearchIndexHandle,
+        user::LoggedUser,
+        util::{parse_from_payload, poll_payload_with_limit},
+        workspace::{
+            archive_workspace,
+            backup_workspace,
+            clone_workspace,
+            create_workspace,
+            delete_workspace,
+            read_clone_progress,
+            read_members,
+            read_workspaces,
+            restore_workspace,
+            search_workspace,
+            share_workspace,
+            sql_builder::{check_list_filter, check_search_params, check_workspace_id},
+            update_workspace,
+            WorkspaceBackup,
+        },
     },
 };
 use actix_web::{
-    web::{Data, Payload},
+    http::header::CONTENT_DISPOSITION,
+    web::{Data, Path, Payload},
     HttpResponse,
 };
 use anyhow::Context;
 use backend_service::{
-    errors::{invalid_params, ServerError},
+    errors::{internal_error, invalid_params, ServerError},
     response::FlowyResponse,
 };
 use flowy_workspace_infra::{
     parser::workspace::{WorkspaceDesc, WorkspaceName},
-    protobuf::{CreateWorkspaceParams, UpdateWorkspaceParams, WorkspaceIdentifier},
+    protobuf::{
+        ArchiveWorkspaceParams,
+        CreateWorkspaceParams,
+        SearchWorkspaceParams,
+        ShareWorkspaceParams,
+        UpdateWorkspaceParams,
+        WorkspaceCloneIdentifier,
+        WorkspaceIdentifier,
+    },
 };
 use sqlx::PgPool;
+use uuid::Uuid;
 
 pub async fn create_handler(
     payload: Payload,
     pool: Data<PgPool>,
     logged_user: LoggedUser,
+    idempotency_key: IdempotencyKey,
 ) -> Result<HttpResponse, ServerError> {
     let params: CreateWorkspaceParams = parse_from_payload(payload).await?;
     let name = WorkspaceName::parse(params.get_name().to_owned()).map_err(invalid_params)?;
     let desc = WorkspaceDesc::parse(params.get_desc().to_owned()).map_err(invalid_params)?;
-    let mut transaction = pool
-        .begin()
-        .await
-        .context("Failed to acquire a Postgres connection to create workspace")?;
-    let workspace = create_workspace(&mut transaction, name.as_ref(), desc.as_ref(), logged_user).await?;
+    let mut transaction = begin_transaction(pool.get_ref(), "create workspace").await?;
+
+    if let Some(data) = read_idempotent_response(&mut transaction, &idempotency_key, &logged_user, &params).await? {
+        return Ok(FlowyResponse::new(data, None).into());
+    }
+
+    let workspace = create_workspace(&mut transaction, name.as_ref(), desc.as_ref(), logged_user.clone()).await?;
+    let resp = FlowyResponse::success().pb(workspace)?;
+    let _ = save_idempotent_response(&mut transaction, &idempotency_key, &logged_user, &resp.data).await?;
+
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to create workspace.")?;
 
-    Ok(FlowyResponse::success().pb(workspace)?.into())
+    Ok(resp.into())
 }
 
 pub async fn read_handler(
@@ -51,17 +82,18 @@ pub async fn read_handler(
     logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: WorkspaceIdentifier = parse_from_payload(payload).await?;
-    let mut transaction = pool
-        .begin()
-        .await
-        .context("Failed to acquire a Postgres connection to read workspace")?;
+    let mut transaction = begin_transaction(pool.get_ref(), "read workspace").await?;
 
     let workspace_id = if params.has_workspace_id() {
         Some(params.get_workspace_id().to_owned())
     } else {
         None
     };
-    let repeated_workspace = read_workspaces(&mut transaction, workspace_id, logged_user).await?;
+    let app_filter = match params.has_app_filter() {
+        false => None,
+        true => Some(check_list_filter(params.get_app_filter())?),
+    };
+    let repeated_workspace = read_workspaces(&mut transaction, workspace_id, app_filter, logged_user).await?;
 
     transaction
         .commit()
@@ -74,16 +106,13 @@ pub async fn read_handler(
 pub async fn delete_handler(
     payload: Payload,
     pool: Data<PgPool>,
-    _logged_user: LoggedUser,
+    logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: WorkspaceIdentifier = parse_from_payload(payload).await?;
     let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
-    let mut transaction = pool
-        .begin()
-        .await
-        .context("Failed to acquire a Postgres connection to delete workspace")?;
+    let mut transaction = begin_transaction(pool.get_ref(), "delete workspace").await?;
 
-    let _ = delete_workspace(&mut transaction, workspace_id).await?;
+    let _ = delete_workspace(&mut transaction, workspace_id, &logged_user).await?;
     transaction
         .commit()
         .await
@@ -95,7 +124,7 @@ pub async fn delete_handler(
 pub async fn update_handler(
     payload: Payload,
     pool: Data<PgPool>,
-    _logged_user: LoggedUser,
+    logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: UpdateWorkspaceParams = parse_from_payload(payload).await?;
     let workspace_id = check_workspace_id(params.get_id().to_owned())?;
@@ -119,12 +148,9 @@ pub async fn update_handler(
         },
     };
 
-    let mut transaction = pool
-        .begin()
-        .await
-        .context("Failed to acquire a Postgres connection to update workspace")?;
+    let mut transaction = begin_transaction(pool.get_ref(), "update workspace").await?;
 
-    let _ = update_workspace(&mut transaction, workspace_id, name, desc).await?;
+    let _ = update_workspace(&mut transaction, workspace_id, name, desc, &logged_user).await?;
 
     transaction
         .commit()
@@ -134,13 +160,170 @@ pub async fn update_handler(
     Ok(FlowyResponse::success().into())
 }
 
-pub async fn workspace_list(pool: Data<PgPool>, logged_user: LoggedUser) -> Result<HttpResponse, ServerError> {
-    let mut transaction = pool
-        .begin()
+pub async fn archive_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ArchiveWorkspaceParams = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let mut transaction = begin_transaction(pool.get_ref(), "archive workspace").await?;
+
+    let _ = archive_workspace(&mut transaction, workspace_id, params.get_archived(), logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to archive workspace.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn share_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let mut params: ShareWorkspaceParams = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.take_workspace_id())?;
+    let mut transaction = begin_transaction(pool.get_ref(), "share workspace").await?;
+
+    let _ = share_workspace(
+        &mut transaction,
+        workspace_id,
+        params.take_email(),
+        params.get_role(),
+        logged_user,
+    )
+    .await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to share workspace.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn clone_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: WorkspaceIdentifier = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let progress = clone_workspace(pool.get_ref(), workspace_id, logged_user).await?;
+    Ok(FlowyResponse::success().pb(progress)?.into())
+}
+
+pub async fn read_clone_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: WorkspaceCloneIdentifier = parse_from_payload(payload).await?;
+    let clone_id = Uuid::parse_str(params.get_clone_id()).map_err(invalid_params)?;
+    let mut transaction = begin_transaction(pool.get_ref(), "read workspace clone").await?;
+
+    let progress = read_clone_progress(&mut transaction, clone_id, &logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read workspace clone.")?;
+
+    Ok(FlowyResponse::success().pb(progress)?.into())
+}
+
+// The backup is returned as the json file instead of the FlowyResponse, so it can be downloaded with curl
+// and restored on another server. The errors are still returned as the FlowyResponse.
+pub async fn backup_handler(
+    workspace_id: Path<String>,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let workspace_id = check_workspace_id(workspace_id.into_inner())?;
+    let mut transaction = begin_transaction(pool.get_ref(), "back up workspace").await?;
+
+    let backup = backup_workspace(&mut transaction, workspace_id, &logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to back up workspace.")?;
+
+    let body = serde_json::to_vec(&backup).map_err(internal_error)?;
+    let disposition = format!("attachment; filename=\"{}.json\"", workspace_id);
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .insert_header((CONTENT_DISPOSITION, disposition))
+        .body(body))
+}
+
+// The body is the json of the backup, it's read with the MAX_BACKUP_SIZE instead of the MAX_PAYLOAD_SIZE
+pub async fn restore_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let bytes = poll_payload_with_limit(&mut payload.into_inner(), MAX_BACKUP_SIZE).await?;
+    let backup: WorkspaceBackup = serde_json::from_slice(&bytes).map_err(invalid_params)?;
+    let mut transaction = begin_transaction(pool.get_ref(), "restore workspace").await?;
+
+    let restore = restore_workspace(&mut transaction, backup, &logged_user).await?;
+
+    transaction
+        .commit()
         .await
-        .context("Failed to acquire a Postgres connection to read workspaces")?;
+        .context("Failed to commit SQL transaction to restore workspace.")?;
+
+    Ok(FlowyResponse::success().pb(restore)?.into())
+}
+
+pub async fn read_members_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: WorkspaceIdentifier = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let mut transaction = begin_transaction(pool.get_ref(), "read workspace members").await?;
+
+    let repeated_member = read_members(&mut transaction, workspace_id, logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read workspace members.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_member)?.into())
+}
+
+pub async fn search_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    search: Data<SearchIndexHandle>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: SearchWorkspaceParams = parse_from_payload(payload).await?;
+    let params = check_search_params(&params)?;
+    let workspace_id = check_workspace_id(params.workspace_id.clone())?;
+    let mut transaction = begin_transaction(pool.get_ref(), "search workspace").await?;
+
+    let repeated_result = search_workspace(&mut transaction, &search, workspace_id, params, logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to search workspace.")?;
+
+    Ok(FlowyResponse::success().pb(repeated_result)?.into())
+}
+
+pub async fn workspace_list(pool: Data<PgPool>, logged_user: LoggedUser) -> Result<HttpResponse, ServerError> {
+    let mut transaction = begin_transaction(pool.get_ref(), "read workspaces").await?;
 
-    let repeated_workspace = read_workspaces(&mut transaction, None, logged_user).await?;
+    let repeated_workspace = read_workspaces(&mut transaction, None, None, logged_user).await?;
     transaction
         .commit()
         .await
@@ -0,0 +1,71 @@
+use crate::{
+    entities::workspace::{APP_TABLE, VIEW_TABLE},
+    service::{
+        search::SearchIndexHandle,
+        trash::read_trash_uuids,
+        user::LoggedUser,
+        workspace::check_workspace_owner,
+    },
+    sqlx_ext::{map_sqlx_error, DBTransaction},
+};
+use backend_service::errors::ServerError;
+use flowy_workspace_infra::{
+    entities::workspace::SearchWorkspaceParams,
+    protobuf::{RepeatedSearchResult, SearchResult},
+};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+// Searches the names of the views that belong to the workspace, including the nested ones, and the text
+// of their docs. The views in the trash and the views under them are skipped. See SearchIndex for the
+// query syntax.
+#[tracing::instrument(skip(transaction, search, logged_user), err)]
+pub(crate) async fn search_workspace(
+    transaction: &mut DBTransaction<'_>,
+    search: &SearchIndexHandle,
+    workspace_id: Uuid,
+    params: SearchWorkspaceParams,
+    logged_user: LoggedUser,
+) -> Result<RepeatedSearchResult, ServerError> {
+    let _ = check_workspace_owner(transaction, workspace_id, &logged_user, "search").await?;
+    let view_ids = read_workspace_view_ids(transaction, workspace_id, &logged_user).await?;
+    let hits = search.search(view_ids, &params.query, params.limit as usize).await?;
+
+    let items = hits
+        .into_iter()
+        .map(|hit| hit.into())
+        .collect::<Vec<SearchResult>>();
+    let mut repeated_result = RepeatedSearchResult::default();
+    repeated_result.set_items(items.into());
+    Ok(repeated_result)
+}
+
+async fn read_workspace_view_ids(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    logged_user: &LoggedUser,
+) -> Result<Vec<String>, ServerError> {
+    let trash_ids = read_trash_uuids(logged_user, transaction as &mut DBTransaction<'_>).await?;
+    let sql = format!(
+        r#"
+            WITH RECURSIVE workspace_view AS (
+                SELECT v.id FROM {view} v JOIN {app} a ON v.belong_to_id = a.id::text
+                WHERE a.workspace_id = $1 AND a.id <> ALL($2) AND v.id <> ALL($2)
+                UNION ALL
+                SELECT v.id FROM {view} v JOIN workspace_view w ON v.belong_to_id = w.id::text
+                WHERE v.id <> ALL($2)
+            )
+            SELECT id FROM workspace_view
+        "#,
+        view = VIEW_TABLE,
+        app = APP_TABLE
+    );
+
+    let view_ids = sqlx::query_as::<Postgres, (Uuid,)>(&sql)
+        .bind(workspace_id.to_string())
+        .bind(trash_ids)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(view_ids.into_iter().map(|(view_id,)| view_id.to_string()).collect())
+}
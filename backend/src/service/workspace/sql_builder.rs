@@ -4,8 +4,13 @@ use crate::{
 };
 use backend_service::errors::{invalid_params, ServerError};
 use chrono::{DateTime, NaiveDateTime, Utc};
-use flowy_workspace_infra::{parser::workspace::WorkspaceId, protobuf::Workspace};
+use flowy_workspace_infra::{
+    entities::workspace::{ListFilter, SearchWorkspaceParams, SearchWorkspaceRequest, LIST_SORT_FIELDS},
+    parser::workspace::WorkspaceId,
+    protobuf::Workspace,
+};
 use sqlx::postgres::PgArguments;
+use std::convert::TryInto;
 use uuid::Uuid;
 
 pub struct NewWorkspaceBuilder {
@@ -24,6 +29,7 @@ impl NewWorkspaceBuilder {
             modified_time: time,
             create_time: time,
             user_id: user_id.to_string(),
+            archived: false,
         };
         Self { table }
     }
@@ -40,6 +46,7 @@ impl NewWorkspaceBuilder {
             modified_time,
             create_time,
             user_id: user_id.to_string(),
+            archived: workspace.archived,
         };
 
         Ok(Self { table })
@@ -65,6 +72,7 @@ impl NewWorkspaceBuilder {
             .add_arg("modified_time", self.table.modified_time)
             .add_arg("create_time", self.table.create_time)
             .add_arg("user_id", self.table.user_id)
+            .add_arg("archived", self.table.archived)
             .build()?;
 
         Ok((sql, args, workspace))
@@ -76,3 +84,66 @@ pub(crate) fn check_workspace_id(id: String) -> Result<Uuid, ServerError> {
     let workspace_id = Uuid::parse_str(workspace_id.as_ref())?;
     Ok(workspace_id)
 }
+
+pub(crate) fn check_list_filter(
+    filter: &flowy_workspace_infra::protobuf::ListFilter,
+) -> Result<ListFilter, ServerError> {
+    let name = match filter.has_name() {
+        false => None,
+        true => Some(filter.get_name().to_owned()),
+    };
+    let after = match filter.has_after() {
+        false => None,
+        true => Some(filter.get_after().to_owned()),
+    };
+    let sort_by = match filter.has_sort_by() {
+        false => None,
+        true => Some(filter.get_sort_by().to_owned()),
+    };
+    let filter = ListFilter {
+        limit: filter.get_limit(),
+        offset: filter.get_offset(),
+        name,
+        after,
+        sort_by,
+    };
+    filter.check().map_err(invalid_params)
+}
+
+pub(crate) fn check_search_params(
+    params: &flowy_workspace_infra::protobuf::SearchWorkspaceParams,
+) -> Result<SearchWorkspaceParams, ServerError> {
+    let request = SearchWorkspaceRequest {
+        workspace_id: params.get_workspace_id().to_owned(),
+        query: params.get_query().to_owned(),
+        limit: params.get_limit(),
+    };
+    request.try_into().map_err(invalid_params)
+}
+
+// Reads the rows by the rank, or by the field that the client sorts by, and then in the order they were
+// created, so the pages are stable while the client scrolls. The rows in the trash must be excluded by the
+// caller before the limit is applied.
+pub(crate) fn build_list_filter(builder: SqlBuilder, filter: Option<&ListFilter>) -> SqlBuilder {
+    let mut builder = match filter.and_then(|filter| filter.sort_by.as_deref()) {
+        None => builder.order_by("rank", true).order_by("create_time", true),
+        Some("create_time") => builder.order_by("create_time", true),
+        Some(field) => builder.sort_by(field, true, &LIST_SORT_FIELDS).order_by("create_time", true),
+    };
+    if let Some(filter) = filter {
+        if let Some(name) = &filter.name {
+            builder = builder.and_where_like("name", name);
+        }
+
+        // The id that isn't a uuid can't be a row, so the page is empty like the one after a deleted row
+        if let Some(after) = &filter.after {
+            builder = builder.after("id", Uuid::parse_str(after).unwrap_or_else(|_| Uuid::nil()));
+        }
+
+        if filter.limit > 0 {
+            builder = builder.limit(filter.limit);
+        }
+        builder = builder.offset(filter.offset);
+    }
+    builder
+}
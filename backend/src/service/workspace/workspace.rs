@@ -1,12 +1,21 @@
 use super::sql_builder::NewWorkspaceBuilder;
 use crate::{
-    entities::workspace::{AppTable, WorkspaceTable, WORKSPACE_TABLE},
-    service::{app::app::read_app, user::LoggedUser, workspace::sql_builder::*},
+    entities::workspace::{AppTable, WorkspaceTable, APP_TABLE, WORKSPACE_TABLE},
+    service::{
+        app::app::read_app_with_views,
+        publish::update_published_slugs,
+        slug::rename_slug,
+        trash::{read_trash_ids, read_trash_uuids},
+        user::LoggedUser,
+        workspace::{read_member_workspace_ids, read_workspace_role, sql_builder::*},
+    },
     sqlx_ext::*,
 };
 use anyhow::Context;
 use backend_service::errors::{invalid_params, ServerError};
+use chrono::Utc;
 use flowy_workspace_infra::{
+    entities::workspace::ListFilter,
     parser::workspace::WorkspaceId,
     protobuf::{RepeatedApp, RepeatedWorkspace, Workspace},
 };
@@ -35,25 +44,38 @@ pub(crate) async fn update_workspace(
     workspace_id: Uuid,
     name: Option<String>,
     desc: Option<String>,
+    logged_user: &LoggedUser,
 ) -> Result<(), ServerError> {
+    let _ = check_workspace_owner(transaction, workspace_id, logged_user, "update").await?;
+    let _ = check_workspace_writable(transaction, &workspace_id.to_string()).await?;
     let (sql, args) = SqlBuilder::update(WORKSPACE_TABLE)
-        .add_some_arg("name", name)
+        .add_some_arg("name", name.clone())
         .add_some_arg("description", desc)
         .and_where_eq("id", workspace_id)
         .build()?;
 
     sqlx::query_with(&sql, args)
-        .execute(transaction)
+        .execute(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
 
+    if let Some(name) = name {
+        let workspace_id = workspace_id.to_string();
+        if rename_slug(transaction, &workspace_id, &name).await?.is_some() {
+            let _ = update_published_slugs(transaction, &workspace_id).await?;
+        }
+    }
+
     Ok(())
 }
 
 pub(crate) async fn delete_workspace(
     transaction: &mut DBTransaction<'_>,
     workspace_id: Uuid,
+    logged_user: &LoggedUser,
 ) -> Result<(), ServerError> {
+    let _ = check_workspace_owner(transaction, workspace_id, logged_user, "delete").await?;
+    let _ = check_workspace_writable(transaction, &workspace_id.to_string()).await?;
     let (sql, args) = SqlBuilder::delete(WORKSPACE_TABLE)
         .and_where_eq("id", workspace_id)
         .build()?;
@@ -66,43 +88,149 @@ pub(crate) async fn delete_workspace(
     Ok(())
 }
 
+#[tracing::instrument(skip(transaction, logged_user), err)]
+pub(crate) async fn archive_workspace(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    archived: bool,
+    logged_user: LoggedUser,
+) -> Result<(), ServerError> {
+    // Only the owner of the workspace is allowed to archive or unarchive it
+    let _ = check_workspace_owner(transaction, workspace_id, &logged_user, "archive").await?;
+
+    let (sql, args) = SqlBuilder::update(WORKSPACE_TABLE)
+        .add_arg("archived", archived)
+        .add_arg("modified_time", Utc::now())
+        .and_where_eq("id", workspace_id)
+        .build()?;
+
+    sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
+
+pub(crate) async fn read_workspace_table(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+) -> Result<WorkspaceTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(WORKSPACE_TABLE)
+        .add_field("*")
+        .and_where_eq("id", workspace_id)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
+        .fetch_one(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(table)
+}
+
+pub(crate) async fn check_workspace_owner(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    logged_user: &LoggedUser,
+    action: &str,
+) -> Result<(), ServerError> {
+    let table = read_workspace_table(transaction, workspace_id).await?;
+    if table.user_id != logged_user.as_uuid()?.to_string() {
+        return Err(ServerError::unauthorized().context(format!("Only the owner can {} the workspace", action)));
+    }
+    Ok(())
+}
+
+// Archived workspaces are read-only. Every mutation of the workspace or the apps, views and
+// documents that belong to it must pass this check first.
+pub(crate) async fn check_workspace_writable(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &str,
+) -> Result<(), ServerError> {
+    let workspace_id = check_workspace_id(workspace_id.to_owned())?;
+    let table = read_workspace_table(transaction, workspace_id).await?;
+    if table.archived {
+        return Err(ServerError::workspace_archived().context(format!("Workspace {} is archived", workspace_id)));
+    }
+    Ok(())
+}
+
 #[tracing::instrument(skip(transaction, logged_user), err)]
 pub async fn read_workspaces(
     transaction: &mut DBTransaction<'_>,
     workspace_id: Option<String>,
+    app_filter: Option<ListFilter>,
     logged_user: LoggedUser,
 ) -> Result<RepeatedWorkspace, ServerError> {
     let user_id = logged_user.as_uuid()?.to_string();
+    let member_workspace_ids = read_member_workspace_ids(transaction, &logged_user).await?;
 
+    // The workspaces that the user owns, followed by the workspaces that are shared with the user
     let mut builder = SqlBuilder::select(WORKSPACE_TABLE)
         .add_field("*")
         .and_where_eq("user_id", &user_id);
+    let mut member_builder = SqlBuilder::select(WORKSPACE_TABLE)
+        .add_field("*")
+        .and_where_in("id", member_workspace_ids.clone());
 
-    if let Some(workspace_id) = workspace_id {
-        let workspace_id = check_workspace_id(workspace_id)?;
-        builder = builder.and_where_eq("id", workspace_id);
+    // The archived workspaces and the workspaces in the trash are hidden unless they are requested by id
+    let mut trash_ids = vec![];
+    match workspace_id {
+        None => {
+            builder = builder.and_where_eq("archived", false);
+            member_builder = member_builder.and_where_eq("archived", false);
+            trash_ids = read_trash_ids(&logged_user, transaction as &mut DBTransaction<'_>).await?;
+        },
+        Some(workspace_id) => {
+            let workspace_id = check_workspace_id(workspace_id)?;
+            builder = builder.and_where_eq("id", workspace_id);
+            member_builder = member_builder.and_where_eq("id", workspace_id);
+        },
     }
 
     let (sql, args) = builder.build()?;
-    let tables = sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
+    let mut tables = sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
         .fetch_all(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
 
+    if !member_workspace_ids.is_empty() {
+        let (sql, args) = member_builder.build()?;
+        let member_tables = sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
+            .fetch_all(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+        tables.extend(member_tables);
+    }
+
     let mut repeated_workspace = RepeatedWorkspace::default();
     let mut workspaces = vec![];
     // Opti: combine the query
     for table in tables {
+        if trash_ids.contains(&table.id.to_string()) {
+            continue;
+        }
+
         let apps = read_workspace_apps(
             &logged_user,
             transaction as &mut DBTransaction<'_>,
             &table.id.to_string(),
+            app_filter.as_ref(),
         )
         .await
         .context("Get workspace app")
         .unwrap_or(RepeatedApp::default());
 
+        // The default role is the owner, only the role in the shared workspace is read
+        let role = match table.user_id == user_id {
+            true => None,
+            false => read_workspace_role(transaction as &mut DBTransaction<'_>, table.id, &logged_user).await?,
+        };
         let mut workspace: Workspace = table.into();
+        if let Some(role) = role {
+            workspace.set_role(role);
+        }
         workspace.set_apps(apps);
         workspaces.push(workspace);
     }
@@ -116,12 +244,15 @@ async fn read_workspace_apps<'c>(
     user: &LoggedUser,
     transaction: &mut DBTransaction<'_>,
     workspace_id: &str,
+    filter: Option<&ListFilter>,
 ) -> Result<RepeatedApp, ServerError> {
     let workspace_id = WorkspaceId::parse(workspace_id.to_owned()).map_err(invalid_params)?;
-    let (sql, args) = SqlBuilder::select("app_table")
+    let trash_ids = read_trash_uuids(user, transaction as &mut DBTransaction<'_>).await?;
+    let builder = SqlBuilder::select(APP_TABLE)
         .add_field("*")
         .and_where_eq("workspace_id", workspace_id.0)
-        .build()?;
+        .and_where_not_in("id", trash_ids);
+    let (sql, args) = build_list_filter(builder, filter).build()?;
 
     let app_tables = sqlx::query_as_with::<Postgres, AppTable, PgArguments>(&sql, args)
         .fetch_all(transaction as &mut DBTransaction<'_>)
@@ -131,7 +262,7 @@ async fn read_workspace_apps<'c>(
     tracing::Span::current().record("app_count", &app_tables.len());
     let mut apps = vec![];
     for table in app_tables {
-        let app = read_app(transaction, table.id, user).await?;
+        let app = read_app_with_views(transaction, table.id).await?;
         apps.push(app);
     }
 
@@ -20,6 +20,7 @@ impl std::convert::From<WsDocumentData> for WsMessageAdaptor {
         let msg = WsMessage {
             module: WsModule::Doc,
             data: bytes.to_vec(),
+            request_id: "".to_owned(),
         };
 
         let bytes: Bytes = msg.try_into().unwrap();
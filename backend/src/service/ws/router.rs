@@ -11,6 +11,7 @@ use actix_web::{
     HttpResponse,
 };
 use actix_web_actors::ws;
+use backend_service::errors::ServerError;
 
 #[get("/{token}")]
 pub async fn establish_ws_connection(
@@ -22,6 +23,8 @@ pub async fn establish_ws_connection(
 ) -> Result<HttpResponse, Error> {
     tracing::info!("establish_ws_connection");
     match LoggedUser::from_token(token.clone()) {
+        // The bots only have access to the http api
+        Ok(user) if user.is_bot => Ok(HttpResponse::Unauthorized().json(ServerError::unauthorized())),
         Ok(user) => {
             let ws_user = WsUser::new(user.clone());
             let client = WsClient::new(ws_user, server.get_ref().clone(), biz_handlers);
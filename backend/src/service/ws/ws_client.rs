@@ -26,12 +26,16 @@ impl WsUser {
     pub fn new(inner: LoggedUser) -> Self { Self { inner } }
 
     pub fn id(&self) -> &str { &self.inner.user_id }
+
+    pub fn logged_user(&self) -> &LoggedUser { &self.inner }
 }
 
 pub struct WsClientData {
     pub(crate) user: Arc<WsUser>,
     pub(crate) socket: Socket,
     pub(crate) data: Bytes,
+    // The id of the client event that sends the message, empty if the client doesn't send one
+    pub(crate) request_id: String,
 }
 
 pub struct WsClient {
@@ -69,13 +73,24 @@ impl WsClient {
         let message: WsMessage = WsMessage::try_from(bytes).unwrap();
         match self.biz_handlers.get(&message.module) {
             None => {
-                log::error!("Can't find the handler for {:?}", message.module);
+                log::error!(
+                    "Can't find the handler for {:?}, request id: {}",
+                    message.module,
+                    message.request_id
+                );
             },
             Some(handler) => {
+                tracing::debug!(
+                    "[{}]: receive {:?} message, request id: {}",
+                    self.user.id(),
+                    message.module,
+                    message.request_id
+                );
                 let client_data = WsClientData {
                     user: self.user.clone(),
                     socket,
                     data: Bytes::from(message.data),
+                    request_id: message.request_id,
                 };
                 handler.receive_data(client_data);
             },
@@ -9,12 +9,87 @@ enum BuilderType {
     Delete,
 }
 
+enum Filter {
+    Eq(String),
+    Gt(String),
+    Lt(String),
+    Le(String),
+    Like(String),
+    In(String),
+    NotIn(String),
+    // The rows after the one whose key equals the argument, in the order of the columns
+    After {
+        table: String,
+        columns: Vec<String>,
+        key: String,
+        asc: bool,
+    },
+}
+
+impl Filter {
+    fn condition(&self, index: usize) -> String {
+        match self {
+            Filter::Eq(field) => format!("{} = ${}", field, index),
+            Filter::Gt(field) => format!("{} > ${}", field, index),
+            Filter::Lt(field) => format!("{} < ${}", field, index),
+            Filter::Le(field) => format!("{} <= ${}", field, index),
+            Filter::Like(field) => format!("{} ILIKE ${}", field, index),
+            Filter::In(field) => format!("{} = ANY(${})", field, index),
+            Filter::NotIn(field) => format!("NOT ({} = ANY(${}))", field, index),
+            Filter::After {
+                table,
+                columns,
+                key,
+                asc,
+            } => {
+                let columns = columns.join(", ");
+                let operator = if *asc { ">" } else { "<" };
+                format!(
+                    "({}) {} (SELECT {} FROM {} WHERE {} = ${})",
+                    columns, operator, columns, table, key, index
+                )
+            },
+        }
+    }
+}
+
+struct Join {
+    table: String,
+    on: String,
+    left: bool,
+}
+
+pub enum Aggregate {
+    Count,
+    Max,
+    Min,
+    Sum,
+}
+
+impl Aggregate {
+    fn function(&self) -> &'static str {
+        match self {
+            Aggregate::Count => "COUNT",
+            Aggregate::Max => "MAX",
+            Aggregate::Min => "MIN",
+            Aggregate::Sum => "SUM",
+        }
+    }
+}
+
 pub struct SqlBuilder {
     table: String,
     fields: Vec<String>,
-    filters: Vec<String>,
+    filters: Vec<Filter>,
     fields_args: PgArguments,
+    joins: Vec<Join>,
+    group_by: Vec<String>,
+    order_by: Vec<(String, bool)>,
+    limit: Option<i64>,
+    offset: Option<i64>,
     ty: BuilderType,
+    // The error of the invalid sort or cursor, it's returned by the build
+    error: Option<ServerError>,
 }
 
 impl SqlBuilder {
@@ -24,7 +99,13 @@ impl SqlBuilder {
             fields: vec![],
             filters: vec![],
             fields_args: PgArguments::default(),
+            joins: vec![],
+            group_by: vec![],
+            order_by: vec![],
+            limit: None,
+            offset: None,
             ty: BuilderType::Select,
+            error: None,
         }
     }
 
@@ -93,12 +174,153 @@ impl SqlBuilder {
     where
         T: 'a + Send + Encode<'a, Postgres> + Type<Postgres>,
     {
-        self.filters.push(field.to_owned());
+        self.filters.push(Filter::Eq(field.to_owned()));
+        self.fields_args.add(arg);
+        self
+    }
+
+    pub fn and_where_gt<'a, T>(mut self, field: &str, arg: T) -> Self
+    where
+        T: 'a + Send + Encode<'a, Postgres> + Type<Postgres>,
+    {
+        self.filters.push(Filter::Gt(field.to_owned()));
+        self.fields_args.add(arg);
+        self
+    }
+
+    pub fn and_where_lt<'a, T>(mut self, field: &str, arg: T) -> Self
+    where
+        T: 'a + Send + Encode<'a, Postgres> + Type<Postgres>,
+    {
+        self.filters.push(Filter::Lt(field.to_owned()));
+        self.fields_args.add(arg);
+        self
+    }
+
+    pub fn and_where_le<'a, T>(mut self, field: &str, arg: T) -> Self
+    where
+        T: 'a + Send + Encode<'a, Postgres> + Type<Postgres>,
+    {
+        self.filters.push(Filter::Le(field.to_owned()));
         self.fields_args.add(arg);
         self
     }
 
+    // Matches the rows whose field contains the pattern, case insensitively. The wildcards in the
+    // pattern are escaped, so they are matched literally.
+    pub fn and_where_like(mut self, field: &str, pattern: &str) -> Self {
+        let escaped = pattern.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+        self.filters.push(Filter::Like(field.to_owned()));
+        self.fields_args.add(format!("%{}%", escaped));
+        self
+    }
+
+    // The values are bound as one array argument, so the list can be empty
+    pub fn and_where_in<'a, T>(mut self, field: &str, args: Vec<T>) -> Self
+    where
+        Vec<T>: 'a + Send + Encode<'a, Postgres> + Type<Postgres>,
+    {
+        self.filters.push(Filter::In(field.to_owned()));
+        self.fields_args.add(args);
+        self
+    }
+
+    pub fn and_where_not_in<'a, T>(mut self, field: &str, args: Vec<T>) -> Self
+    where
+        Vec<T>: 'a + Send + Encode<'a, Postgres> + Type<Postgres>,
+    {
+        self.filters.push(Filter::NotIn(field.to_owned()));
+        self.fields_args.add(args);
+        self
+    }
+
+    // The join, group, order, limit and offset only apply to the select. The on condition compares the
+    // columns of the tables, it can't bind the arguments.
+    pub fn join(mut self, table: &str, on: &str) -> Self {
+        self.joins.push(Join {
+            table: table.to_owned(),
+            on: on.to_owned(),
+            left: false,
+        });
+        self
+    }
+
+    pub fn left_join(mut self, table: &str, on: &str) -> Self {
+        self.joins.push(Join {
+            table: table.to_owned(),
+            on: on.to_owned(),
+            left: true,
+        });
+        self
+    }
+
+    pub fn add_aggregate(self, aggregate: Aggregate, field: &str, alias: &str) -> Self {
+        let field = format!("{}({}) AS {}", aggregate.function(), field, alias);
+        self.add_field(&field)
+    }
+
+    pub fn group_by(mut self, field: &str) -> Self {
+        self.group_by.push(field.to_owned());
+        self
+    }
+
+    pub fn order_by(mut self, field: &str, asc: bool) -> Self {
+        self.order_by.push((field.to_owned(), asc));
+        self
+    }
+
+    // The field that the client asks to sort by is put into the sql, so it must be one of the allowed fields
+    pub fn sort_by(mut self, field: &str, asc: bool, allowed: &[&str]) -> Self {
+        if !allowed.contains(&field) {
+            self.error = Some(ServerError::params_invalid().context(format!("Can't sort by {}", field)));
+            return self;
+        }
+        self.order_by(field, asc)
+    }
+
+    // Reads the page after the row whose column equals the value, e.g. the id of the last item of the
+    // previous page. Unlike the offset, the page doesn't shift when the rows before it are inserted or
+    // deleted. The rows are compared by all the ordered columns, so it must be called after the order_by,
+    // and the column is added to them to break the ties, so it should be unique. The page is empty if the
+    // row doesn't exist anymore.
+    pub fn after<'a, T>(mut self, column: &str, value: T) -> Self
+    where
+        T: 'a + Send + Encode<'a, Postgres> + Type<Postgres>,
+    {
+        let asc = self.order_by.first().map_or(true, |(_, asc)| *asc);
+        if self.order_by.iter().any(|(_, order_asc)| *order_asc != asc) {
+            self.error = Some(ServerError::internal().context("The cursor needs the columns in the same order"));
+            return self;
+        }
+
+        if !self.order_by.iter().any(|(field, _)| field == column) {
+            self.order_by.push((column.to_owned(), asc));
+        }
+        self.filters.push(Filter::After {
+            table: self.table.clone(),
+            columns: self.order_by.iter().map(|(field, _)| field.clone()).collect(),
+            key: column.to_owned(),
+            asc,
+        });
+        self.fields_args.add(value);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
     pub fn build(self) -> Result<(String, PgArguments), ServerError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
         match self.ty {
             BuilderType::Create => {
                 let mut inner = InnerBuilder::insert_into(&self.table);
@@ -124,10 +346,33 @@ impl SqlBuilder {
                     inner.field(field);
                 });
 
-                self.filters.into_iter().enumerate().for_each(|(index, filter)| {
-                    inner.and_where_eq(filter, format!("${}", index + 1));
+                self.joins.iter().for_each(|join| {
+                    if join.left {
+                        inner.left();
+                    }
+                    inner.join(&join.table).on(&join.on);
+                });
+
+                self.filters.iter().enumerate().for_each(|(index, filter)| {
+                    inner.and_where(filter.condition(index + 1));
+                });
+
+                self.group_by.iter().for_each(|field| {
+                    inner.group_by(field);
+                });
+
+                self.order_by.iter().for_each(|(field, asc)| {
+                    inner.order_by(field, !asc);
                 });
 
+                if let Some(limit) = self.limit {
+                    inner.limit(limit);
+                }
+
+                if let Some(offset) = self.offset {
+                    inner.offset(offset);
+                }
+
                 let sql = inner.sql()?;
                 Ok((sql, self.fields_args))
             },
@@ -138,9 +383,8 @@ impl SqlBuilder {
                     inner.set(&field, format!("${}", index + 1));
                 });
 
-                self.filters.into_iter().enumerate().for_each(|(index, filter)| {
-                    let index = index + field_len;
-                    inner.and_where_eq(filter, format!("${}", index + 1));
+                self.filters.iter().enumerate().for_each(|(index, filter)| {
+                    inner.and_where(filter.condition(index + field_len + 1));
                 });
 
                 let sql = inner.sql()?;
@@ -148,8 +392,8 @@ impl SqlBuilder {
             },
             BuilderType::Delete => {
                 let mut inner = InnerBuilder::delete_from(&self.table);
-                self.filters.into_iter().enumerate().for_each(|(index, filter)| {
-                    inner.and_where_eq(filter, format!("${}", index + 1));
+                self.filters.iter().enumerate().for_each(|(index, filter)| {
+                    inner.and_where(filter.condition(index + 1));
                 });
                 let sql = inner.sql()?;
                 Ok((sql, self.fields_args))
@@ -157,3 +401,116 @@ impl SqlBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The arguments are bound in the order that they're added, so the placeholders must follow the same order
+    fn assert_in_order(sql: &str, fragments: &[&str]) {
+        let mut start = 0;
+        for fragment in fragments {
+            match sql[start..].find(fragment) {
+                Some(index) => start += index + fragment.len(),
+                None => panic!("{} isn't found in order in {}", fragment, sql),
+            }
+        }
+    }
+
+    #[test]
+    fn select_with_in_filter() {
+        let (sql, _) = SqlBuilder::select("doc_table")
+            .add_field("id")
+            .and_where_eq("workspace_id", "workspace".to_owned())
+            .and_where_in("id", vec!["a".to_owned(), "b".to_owned()])
+            .and_where_not_in("id", Vec::<String>::new())
+            .build()
+            .unwrap();
+        assert_in_order(
+            &sql,
+            &["SELECT id FROM doc_table", "WHERE", "workspace_id = $1", "id = ANY($2)", "NOT (id = ANY($3))"],
+        );
+    }
+
+    #[test]
+    fn select_with_joins() {
+        let (sql, _) = SqlBuilder::select("app_table")
+            .add_field("app_table.*")
+            .join("workspace_table", "workspace_table.id = app_table.workspace_id")
+            .left_join("trash_table", "trash_table.id = app_table.id")
+            .and_where_eq("app_table.id", "app".to_owned())
+            .build()
+            .unwrap();
+        assert_in_order(
+            &sql,
+            &[
+                "FROM app_table",
+                "JOIN workspace_table ON workspace_table.id = app_table.workspace_id",
+                "LEFT JOIN trash_table ON trash_table.id = app_table.id",
+                "WHERE",
+                "app_table.id = $1",
+            ],
+        );
+        assert_eq!(sql.matches("LEFT JOIN").count(), 1);
+    }
+
+    #[test]
+    fn select_with_aggregate_and_group_by() {
+        let (sql, _) = SqlBuilder::select("doc_snapshot_table")
+            .add_field("doc_id")
+            .add_aggregate(Aggregate::Max, "rev_id", "latest_rev_id")
+            .add_aggregate(Aggregate::Count, "*", "count")
+            .and_where_in("doc_id", vec!["a".to_owned()])
+            .and_where_gt("rev_id", 1_i64)
+            .group_by("doc_id")
+            .group_by("owner")
+            .order_by("doc_id", false)
+            .build()
+            .unwrap();
+        assert_in_order(
+            &sql,
+            &[
+                "SELECT doc_id, MAX(rev_id) AS latest_rev_id, COUNT(*) AS count FROM doc_snapshot_table",
+                "WHERE",
+                "doc_id = ANY($1)",
+                "rev_id > $2",
+                "GROUP BY doc_id, owner",
+                "ORDER BY doc_id DESC",
+            ],
+        );
+    }
+
+    #[test]
+    fn update_filters_after_fields() {
+        let (sql, _) = SqlBuilder::update("app_table")
+            .add_arg("name", "name".to_owned())
+            .add_arg("description", "desc".to_owned())
+            .and_where_eq("workspace_id", "workspace".to_owned())
+            .and_where_in("id", vec!["a".to_owned()])
+            .build()
+            .unwrap();
+        assert_in_order(
+            &sql,
+            &["UPDATE app_table", "name = $1", "description = $2", "WHERE", "workspace_id = $3", "id = ANY($4)"],
+        );
+    }
+
+    #[test]
+    fn delete_with_in_filter() {
+        let (sql, _) = SqlBuilder::delete("trash_table")
+            .and_where_eq("user_id", "user".to_owned())
+            .and_where_in("id", vec!["a".to_owned()])
+            .build()
+            .unwrap();
+        assert_in_order(&sql, &["DELETE FROM trash_table", "WHERE", "user_id = $1", "id = ANY($2)"]);
+    }
+
+    #[test]
+    fn select_with_invalid_sort() {
+        let result = SqlBuilder::select("view_table")
+            .add_field("*")
+            .sort_by("password", true, &["name", "create_time"])
+            .build();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::storage::{check_object_key, ObjectStorage, StorageFuture};
+use backend_service::errors::{internal_error, ServerError};
+use futures::FutureExt;
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::{
+    fs,
+    io::{AsyncRead, AsyncWrite},
+};
+use uuid::Uuid;
+
+// Keeps each object as a file under the root directory
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    fn object_path(&self, key: &str) -> Result<PathBuf, ServerError> {
+        let _ = check_object_key(key)?;
+        Ok(self.root.join(key))
+    }
+}
+
+impl ObjectStorage for LocalStorage {
+    // The object is written to a temporary file first, so the reader never sees a partial object
+    fn put_object<'a>(&'a self, key: &'a str, reader: &'a mut (dyn AsyncRead + Send + Unpin)) -> StorageFuture<'a, ()> {
+        async move {
+            let path = self.object_path(key)?;
+            if let Some(dir) = path.parent() {
+                let _ = fs::create_dir_all(dir).await.map_err(internal_error)?;
+            }
+
+            let tmp_path = path.with_file_name(format!(".{}.tmp", Uuid::new_v4()));
+            let mut file = fs::File::create(&tmp_path).await.map_err(internal_error)?;
+            if let Err(e) = tokio::io::copy(reader, &mut file).await {
+                let _ = fs::remove_file(&tmp_path).await;
+                return Err(internal_error(e));
+            }
+            let _ = file.sync_all().await.map_err(internal_error)?;
+            let _ = fs::rename(&tmp_path, &path).await.map_err(internal_error)?;
+            Ok(())
+        }
+        .boxed()
+    }
+
+    fn get_object<'a>(
+        &'a self,
+        key: &'a str,
+        writer: &'a mut (dyn AsyncWrite + Send + Unpin),
+    ) -> StorageFuture<'a, ()> {
+        async move {
+            let path = self.object_path(key)?;
+            let mut file = match fs::File::open(&path).await {
+                Ok(file) => file,
+                Err(e) if e.kind() == ErrorKind::NotFound => {
+                    return Err(ServerError::record_not_found().context(format!("The object {} is not found", key)));
+                },
+                Err(e) => return Err(internal_error(e)),
+            };
+            let _ = tokio::io::copy(&mut file, writer).await.map_err(internal_error)?;
+            Ok(())
+        }
+        .boxed()
+    }
+
+    fn delete_object<'a>(&'a self, key: &'a str) -> StorageFuture<'a, ()> {
+        async move {
+            let path = self.object_path(key)?;
+            match fs::remove_file(&path).await {
+                Ok(_) => Ok(()),
+                Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+                Err(e) => Err(internal_error(e)),
+            }
+        }
+        .boxed()
+    }
+
+    fn signed_url(&self, key: &str, _expire_in: Duration) -> Result<Option<String>, ServerError> {
+        let _ = check_object_key(key)?;
+        Ok(None)
+    }
+}
@@ -0,0 +1,49 @@
+mod local_storage;
+mod s3_storage;
+
+pub use local_storage::*;
+pub use s3_storage::*;
+
+use crate::config::StorageSettings;
+use backend_service::errors::{invalid_params, ServerError};
+use futures::future::BoxFuture;
+use std::{sync::Arc, time::Duration};
+use tokio::io::{AsyncRead, AsyncWrite};
+
+pub type StorageFuture<'a, T> = BoxFuture<'a, Result<T, ServerError>>;
+
+// Keeps the objects, e.g. the assets, the exports and the backups, outside the database. The objects are
+// streamed, so a large object is never read into memory as a whole. The key is a relative path like
+// "exports/<workspace_id>/<name>".
+pub trait ObjectStorage: Send + Sync {
+    // Replaces the object if it exists
+    fn put_object<'a>(&'a self, key: &'a str, reader: &'a mut (dyn AsyncRead + Send + Unpin)) -> StorageFuture<'a, ()>;
+
+    // Returns the record_not_found error if the object doesn't exist
+    fn get_object<'a>(&'a self, key: &'a str, writer: &'a mut (dyn AsyncWrite + Send + Unpin)) -> StorageFuture<'a, ()>;
+
+    // Deleting the object that doesn't exist is not an error
+    fn delete_object<'a>(&'a self, key: &'a str) -> StorageFuture<'a, ()>;
+
+    // The url that lets anyone read the object without the token until it expires. Returns None if the
+    // storage can't sign urls, e.g. the local disk.
+    fn signed_url(&self, key: &str, expire_in: Duration) -> Result<Option<String>, ServerError>;
+}
+
+pub fn build_storage(settings: &StorageSettings) -> Result<Arc<dyn ObjectStorage>, ServerError> {
+    let storage: Arc<dyn ObjectStorage> = match settings {
+        StorageSettings::Local { root } => Arc::new(LocalStorage::new(root)),
+        StorageSettings::S3(settings) => Arc::new(S3Storage::new(settings)?),
+    };
+    Ok(storage)
+}
+
+fn check_object_key(key: &str) -> Result<(), ServerError> {
+    let is_valid = !key.is_empty()
+        && !key.starts_with('/')
+        && key.split('/').all(|part| !part.is_empty() && part != "." && part != "..");
+    if !is_valid {
+        return Err(invalid_params(format!("The object key {} is invalid", key)));
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::{
+    config::S3Settings,
+    storage::{check_object_key, ObjectStorage, StorageFuture},
+};
+use backend_service::errors::{internal_error, ServerError};
+use futures::FutureExt;
+use s3::{bucket::Bucket, creds::Credentials, region::Region};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite};
+
+// The signed url of S3 can't be valid for longer than 7 days
+const MAX_SIGNED_URL_EXPIRE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+// Works with any S3 compatible store. MinIO is used by setting the endpoint and the path_style.
+pub struct S3Storage {
+    bucket: Bucket,
+}
+
+impl S3Storage {
+    pub fn new(settings: &S3Settings) -> Result<Self, ServerError> {
+        let region = match &settings.endpoint {
+            None => settings.region.parse::<Region>().map_err(internal_error)?,
+            Some(endpoint) => Region::Custom {
+                region: settings.region.clone(),
+                endpoint: endpoint.clone(),
+            },
+        };
+        let credentials = Credentials::new(
+            Some(&settings.access_key),
+            Some(&settings.secret_key),
+            None,
+            None,
+            None,
+        )
+        .map_err(internal_error)?;
+
+        let bucket = match settings.path_style {
+            true => Bucket::new_with_path_style(&settings.bucket, region, credentials),
+            false => Bucket::new(&settings.bucket, region, credentials),
+        }
+        .map_err(internal_error)?;
+        Ok(Self { bucket })
+    }
+}
+
+impl ObjectStorage for S3Storage {
+    fn put_object<'a>(&'a self, key: &'a str, reader: &'a mut (dyn AsyncRead + Send + Unpin)) -> StorageFuture<'a, ()> {
+        async move {
+            let _ = check_object_key(key)?;
+            let mut reader = reader;
+            let status = self
+                .bucket
+                .put_object_stream(&mut reader, key)
+                .await
+                .map_err(internal_error)?;
+            check_status(key, status)
+        }
+        .boxed()
+    }
+
+    fn get_object<'a>(
+        &'a self,
+        key: &'a str,
+        writer: &'a mut (dyn AsyncWrite + Send + Unpin),
+    ) -> StorageFuture<'a, ()> {
+        async move {
+            let _ = check_object_key(key)?;
+            let mut writer = writer;
+            let status = self
+                .bucket
+                .get_object_stream(key, &mut writer)
+                .await
+                .map_err(internal_error)?;
+            check_status(key, status)
+        }
+        .boxed()
+    }
+
+    // S3 returns 204 whether or not the object exists
+    fn delete_object<'a>(&'a self, key: &'a str) -> StorageFuture<'a, ()> {
+        async move {
+            let _ = check_object_key(key)?;
+            let (_, status) = self.bucket.delete_object(key).await.map_err(internal_error)?;
+            match status {
+                404 => Ok(()),
+                status => check_status(key, status),
+            }
+        }
+        .boxed()
+    }
+
+    fn signed_url(&self, key: &str, expire_in: Duration) -> Result<Option<String>, ServerError> {
+        let _ = check_object_key(key)?;
+        let expire_secs = expire_in.min(MAX_SIGNED_URL_EXPIRE).as_secs().max(1) as u32;
+        let url = self.bucket.presign_get(key, expire_secs).map_err(internal_error)?;
+        Ok(Some(url))
+    }
+}
+
+fn check_status(key: &str, status: u16) -> Result<(), ServerError> {
+    match status {
+        200..=299 => Ok(()),
+        404 => Err(ServerError::record_not_found().context(format!("The object {} is not found", key))),
+        status => Err(ServerError::internal().context(format!("Access the object {} failed: {}", key, status))),
+    }
+}
@@ -0,0 +1,212 @@
+use crate::util::helper::{TestUserServer, ViewTest};
+use backend::service::attachment::collect_orphaned_attachments;
+use backend_service::errors::ErrorCode;
+use flowy_document_infra::entities::doc::{
+    Attachment,
+    AttachmentChunk,
+    AttachmentChunkIdentifier,
+    AttachmentIdentifier,
+    CreateAttachmentParams,
+    UpdateDocParams,
+    MAX_ATTACHMENT_SIZE,
+};
+use flowy_workspace_infra::entities::view::ViewIdentifiers;
+
+async fn create_attachment(server: &TestUserServer, doc_id: &str, size: i64) -> Attachment {
+    let params = CreateAttachmentParams {
+        doc_id: doc_id.to_owned(),
+        name: "image.png".to_owned(),
+        size,
+    };
+    server.try_create_attachment(params).await.unwrap()
+}
+
+fn attachment_data(size: i64) -> Vec<u8> { (0..size).map(|i| (i % 251) as u8).collect() }
+
+fn attachment_chunk(attachment: &Attachment, data: &[u8], index: i64) -> AttachmentChunk {
+    let start = (index * attachment.chunk_size) as usize;
+    let end = start + attachment.chunk_len(index) as usize;
+    AttachmentChunk {
+        attachment_id: attachment.id.clone(),
+        index,
+        data: data[start..end].to_vec(),
+    }
+}
+
+async fn upload_attachment(server: &TestUserServer, attachment: &Attachment, data: &[u8]) -> Attachment {
+    let mut uploaded = attachment.clone();
+    for index in 0..attachment.chunk_count() {
+        let chunk = attachment_chunk(attachment, data, index);
+        uploaded = server.try_upload_attachment_chunk(chunk).await.unwrap();
+    }
+    uploaded
+}
+
+// Pretends that the unreferenced attachments lost their references longer ago than the grace period
+async fn expire_unreferenced_attachments(server: &TestUserServer) {
+    let _ = sqlx::query("UPDATE attachment_table SET unreferenced_time = unreferenced_time - interval '8 days'")
+        .execute(&server.pg_pool)
+        .await
+        .unwrap();
+}
+
+async fn update_doc(server: &TestUserServer, doc_id: &str, data: &str, rev_id: i64) {
+    let params = UpdateDocParams {
+        doc_id: doc_id.to_owned(),
+        data: data.to_owned(),
+        rev_id,
+        ..Default::default()
+    };
+    server.update_doc(params).await;
+}
+
+#[actix_rt::test]
+async fn attachment_upload_then_download() {
+    let test = ViewTest::new().await;
+    let data = attachment_data(300 * 1024);
+    let attachment = create_attachment(&test.server, &test.view.id, data.len() as i64).await;
+    assert_eq!(attachment.chunk_count(), 3);
+    assert_eq!(attachment.uploaded_chunks, 0);
+
+    let uploaded = upload_attachment(&test.server, &attachment, &data).await;
+    assert_eq!(uploaded.is_completed(), true);
+
+    let mut downloaded = vec![];
+    for index in 0..attachment.chunk_count() {
+        let params = AttachmentChunkIdentifier {
+            attachment_id: attachment.id.clone(),
+            index,
+        };
+        let chunk = test.server.try_read_attachment_chunk(params).await.unwrap();
+        downloaded.extend(chunk.data);
+    }
+    assert_eq!(downloaded, data);
+}
+
+#[actix_rt::test]
+async fn attachment_upload_chunk_out_of_order() {
+    let test = ViewTest::new().await;
+    let data = attachment_data(300 * 1024);
+    let attachment = create_attachment(&test.server, &test.view.id, data.len() as i64).await;
+
+    let chunk = attachment_chunk(&attachment, &data, 1);
+    let error = test.server.try_upload_attachment_chunk(chunk).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+
+    // The chunk can't be read until the attachment is completed
+    let params = AttachmentChunkIdentifier {
+        attachment_id: attachment.id.clone(),
+        index: 0,
+    };
+    let error = test.server.try_read_attachment_chunk(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+}
+
+#[actix_rt::test]
+async fn attachment_too_large() {
+    let test = ViewTest::new().await;
+    let params = CreateAttachmentParams {
+        doc_id: test.view.id.clone(),
+        name: "video.mp4".to_owned(),
+        size: MAX_ATTACHMENT_SIZE + 1,
+    };
+    let error = test.server.try_create_attachment(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PayloadOverflow);
+}
+
+#[actix_rt::test]
+async fn attachment_collected_after_doc_deleted() {
+    let test = ViewTest::new().await;
+    let data = attachment_data(1024);
+    let attachment = create_attachment(&test.server, &test.view.id, data.len() as i64).await;
+    let _ = upload_attachment(&test.server, &attachment, &data).await;
+    let key = format!("attachments/{}/{}/0", test.view.id, attachment.id);
+
+    test.server
+        .delete_view(ViewIdentifiers {
+            view_ids: vec![test.view.id.clone()],
+        })
+        .await;
+    let error = test
+        .server
+        .try_read_attachment(AttachmentIdentifier::from(attachment.id.as_str()))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+
+    // The chunk is kept until the garbage collection runs
+    let mut chunk = vec![];
+    let _ = test.server.storage.get_object(&key, &mut chunk).await.unwrap();
+    assert_eq!(chunk, data);
+
+    let _ = collect_orphaned_attachments(&test.server.pg_pool, &test.server.storage)
+        .await
+        .unwrap();
+    let error = test.server.storage.get_object(&key, &mut Vec::<u8>::new()).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+}
+
+#[actix_rt::test]
+async fn attachment_collected_after_unreferenced() {
+    let test = ViewTest::new().await;
+    let data = attachment_data(1024);
+    let attachment = create_attachment(&test.server, &test.view.id, data.len() as i64).await;
+    let _ = upload_attachment(&test.server, &attachment, &data).await;
+    let key = format!("attachments/{}/{}/0", test.view.id, attachment.id);
+
+    // The referenced attachment is kept however long it has existed
+    let referenced = format!(
+        r#"[{{"insert":"image.png","attributes":{{"attachment":"{}"}}}},{{"insert":"\n"}}]"#,
+        attachment.id
+    );
+    update_doc(&test.server, &test.view.id, &referenced, 50).await;
+    expire_unreferenced_attachments(&test.server).await;
+    let _ = collect_orphaned_attachments(&test.server.pg_pool, &test.server.storage)
+        .await
+        .unwrap();
+    let mut chunk = vec![];
+    let _ = test.server.storage.get_object(&key, &mut chunk).await.unwrap();
+    assert_eq!(chunk, data);
+
+    // The attachment is kept for the grace period after its image is deleted from the doc
+    update_doc(&test.server, &test.view.id, r#"[{"insert":"\n"}]"#, 51).await;
+    let _ = collect_orphaned_attachments(&test.server.pg_pool, &test.server.storage)
+        .await
+        .unwrap();
+    let _ = test.server.storage.get_object(&key, &mut Vec::<u8>::new()).await.unwrap();
+
+    expire_unreferenced_attachments(&test.server).await;
+    let _ = collect_orphaned_attachments(&test.server.pg_pool, &test.server.storage)
+        .await
+        .unwrap();
+    let error = test.server.storage.get_object(&key, &mut Vec::<u8>::new()).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+}
+
+#[actix_rt::test]
+async fn attachment_referenced_again_within_grace_period() {
+    let test = ViewTest::new().await;
+    let data = attachment_data(1024);
+    let attachment = create_attachment(&test.server, &test.view.id, data.len() as i64).await;
+    let _ = upload_attachment(&test.server, &attachment, &data).await;
+
+    // Undoing the delete of the image references the attachment again
+    let referenced = format!(
+        r#"[{{"insert":"image.png","attributes":{{"attachment":"{}"}}}},{{"insert":"\n"}}]"#,
+        attachment.id
+    );
+    update_doc(&test.server, &test.view.id, &referenced, 50).await;
+    update_doc(&test.server, &test.view.id, r#"[{"insert":"\n"}]"#, 51).await;
+    update_doc(&test.server, &test.view.id, &referenced, 52).await;
+    expire_unreferenced_attachments(&test.server).await;
+    let _ = collect_orphaned_attachments(&test.server.pg_pool, &test.server.storage)
+        .await
+        .unwrap();
+
+    let read_attachment = test
+        .server
+        .try_read_attachment(AttachmentIdentifier::from(attachment.id.as_str()))
+        .await
+        .unwrap();
+    assert_eq!(read_attachment.id, attachment.id);
+}
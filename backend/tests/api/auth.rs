@@ -1,6 +1,18 @@
-use crate::util::helper::{spawn_user_server, TestUserServer};
-use backend_service::errors::ErrorCode;
-use flowy_user_infra::entities::{SignInParams, SignUpParams, SignUpResponse, UpdateUserParams};
+use crate::util::{
+    helper::{spawn_server_with_configuration, spawn_user_server, TestUserServer},
+    mock_mailer::{spawn_mock_mailer, MockMailbox},
+    mock_provider::{spawn_mock_github, MockGitHubAccount},
+};
+use backend::config::{AuthSettings, HeaderAuthSettings, MailSettings};
+use backend_service::{errors::ErrorCode, oauth::PkceChallenge};
+use flowy_user_infra::entities::{
+    AuthProvider,
+    SignInParams,
+    SignInWithProviderParams,
+    SignUpParams,
+    SignUpResponse,
+    UpdateUserParams,
+};
 
 #[actix_rt::test]
 async fn user_register() {
@@ -106,6 +118,438 @@ async fn user_update_email() {
     assert_eq!(user.email, email);
 }
 
+#[actix_rt::test]
+async fn user_sign_in_with_github() {
+    let pkce = PkceChallenge::new();
+    let server = spawn_github_user_server(github_account("annie@appflowy.io", true, &pkce)).await;
+    let response = server.sign_in_with_provider(github_params(&pkce)).await.unwrap();
+    assert_eq!(response.email, "annie@appflowy.io");
+    assert_eq!(response.name, "annie");
+
+    // The identity is linked to the user that was created the first time
+    let again = server.sign_in_with_provider(github_params(&pkce)).await.unwrap();
+    assert_eq!(again.user_id, response.user_id);
+}
+
+#[actix_rt::test]
+async fn user_sign_in_with_github_links_verified_email() {
+    let pkce = PkceChallenge::new();
+    let server = spawn_github_user_server(github_account("annie@appflowy.io", true, &pkce)).await;
+    let sign_up_resp = register_user(&server, "annie@appflowy.io", "HelloWorld123!").await;
+
+    let response = server.sign_in_with_provider(github_params(&pkce)).await.unwrap();
+    assert_eq!(response.user_id, sign_up_resp.user_id);
+}
+
+#[actix_rt::test]
+async fn user_sign_in_with_github_unverified_email_exists() {
+    let pkce = PkceChallenge::new();
+    let server = spawn_github_user_server(github_account("annie@appflowy.io", false, &pkce)).await;
+    let _ = register_user(&server, "annie@appflowy.io", "HelloWorld123!").await;
+
+    let error = server.sign_in_with_provider(github_params(&pkce)).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::EmailAlreadyExists);
+}
+
+#[actix_rt::test]
+async fn user_sign_in_with_github_invalid_code_verifier() {
+    let pkce = PkceChallenge::new();
+    let server = spawn_github_user_server(github_account("annie@appflowy.io", true, &pkce)).await;
+
+    let mut params = github_params(&pkce);
+    params.code_verifier = PkceChallenge::new().code_verifier;
+    let error = server.sign_in_with_provider(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn user_sign_in_with_unconfigured_provider() {
+    let pkce = PkceChallenge::new();
+    let server = spawn_github_user_server(github_account("annie@appflowy.io", true, &pkce)).await;
+
+    let mut params = github_params(&pkce);
+    params.provider = AuthProvider::Google;
+    let error = server.sign_in_with_provider(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn user_reset_password() {
+    let (server, mailbox) = spawn_mailer_user_server().await;
+    let email = "annie@appflowy.io";
+    let response = register_user(&server, email, "HelloWorld123!").await;
+
+    server.request_password_reset(email).await.unwrap();
+    let token = mailed_token(&mailbox, email);
+    server
+        .confirm_password_reset(&token, "HelloAppFlowy123!")
+        .await
+        .unwrap();
+
+    let error = server
+        .sign_in(sign_in_params(email, "HelloWorld123!"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordNotMatch);
+    let error = server.refresh_token(&response.refresh_token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+    let _ = server
+        .sign_in(sign_in_params(email, "HelloAppFlowy123!"))
+        .await
+        .unwrap();
+}
+
+#[actix_rt::test]
+async fn user_reset_password_token_is_used_once() {
+    let (server, mailbox) = spawn_mailer_user_server().await;
+    let email = "annie@appflowy.io";
+    let _ = register_user(&server, email, "HelloWorld123!").await;
+
+    server.request_password_reset(email).await.unwrap();
+    let token = mailed_token(&mailbox, email);
+    server
+        .confirm_password_reset(&token, "HelloAppFlowy123!")
+        .await
+        .unwrap();
+
+    let error = server
+        .confirm_password_reset(&token, "HelloAgain123!")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordResetTokenInvalid);
+}
+
+#[actix_rt::test]
+async fn user_reset_password_revokes_previous_token() {
+    let (server, mailbox) = spawn_mailer_user_server().await;
+    let email = "annie@appflowy.io";
+    let _ = register_user(&server, email, "HelloWorld123!").await;
+
+    server.request_password_reset(email).await.unwrap();
+    server.request_password_reset(email).await.unwrap();
+    let emails = mailbox.emails_to(email);
+    assert_eq!(emails.len(), 2);
+    let first_token = emails[0].text.lines().last().unwrap().to_owned();
+    let second_token = emails[1].text.lines().last().unwrap().to_owned();
+
+    server
+        .confirm_password_reset(&second_token, "HelloAppFlowy123!")
+        .await
+        .unwrap();
+    let error = server
+        .confirm_password_reset(&first_token, "HelloAgain123!")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordResetTokenInvalid);
+}
+
+#[actix_rt::test]
+async fn user_reset_password_with_unknown_email() {
+    let (server, mailbox) = spawn_mailer_user_server().await;
+    let email = "nobody@appflowy.io";
+
+    // The response doesn't tell whether the email is registered
+    server.request_password_reset(email).await.unwrap();
+    assert!(mailbox.emails_to(email).is_empty());
+}
+
+#[actix_rt::test]
+async fn user_reset_password_with_invalid_token() {
+    let (server, _) = spawn_mailer_user_server().await;
+    let error = server
+        .confirm_password_reset("invalid_token", "HelloAppFlowy123!")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordResetTokenInvalid);
+}
+
+#[actix_rt::test]
+async fn user_reset_password_with_sign_in_token() {
+    let (server, _) = spawn_mailer_user_server().await;
+    let response = register_user(&server, "annie@appflowy.io", "HelloWorld123!").await;
+    let error = server
+        .confirm_password_reset(&response.token, "HelloAppFlowy123!")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordResetTokenInvalid);
+}
+
+#[actix_rt::test]
+async fn user_verify_email() {
+    let email = "annie@appflowy.io";
+    let (server, mailbox) = spawn_mailer_signed_in_server(email).await;
+    assert!(!server.get_user_profile().await.email_verified);
+
+    server.request_email_verify().await.unwrap();
+    let token = mailed_token(&mailbox, email);
+    server.confirm_email_verify(&token).await.unwrap();
+    assert!(server.get_user_profile().await.email_verified);
+
+    // The verified email isn't sent another token
+    server.request_email_verify().await.unwrap();
+    assert_eq!(mailbox.emails_to(email).len(), 1);
+}
+
+#[actix_rt::test]
+async fn user_verify_email_token_is_used_once() {
+    let email = "annie@appflowy.io";
+    let (server, mailbox) = spawn_mailer_signed_in_server(email).await;
+    server.request_email_verify().await.unwrap();
+    server.request_email_verify().await.unwrap();
+    let emails = mailbox.emails_to(email);
+    assert_eq!(emails.len(), 2);
+    let first_token = emails[0].text.lines().last().unwrap().to_owned();
+    let second_token = emails[1].text.lines().last().unwrap().to_owned();
+
+    server.confirm_email_verify(&second_token).await.unwrap();
+    let error = server.confirm_email_verify(&second_token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::EmailVerifyTokenInvalid);
+    let error = server.confirm_email_verify(&first_token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::EmailVerifyTokenInvalid);
+}
+
+#[actix_rt::test]
+async fn user_verify_email_with_password_reset_token() {
+    let email = "annie@appflowy.io";
+    let (server, mailbox) = spawn_mailer_signed_in_server(email).await;
+    server.request_password_reset(email).await.unwrap();
+    let token = mailed_token(&mailbox, email);
+
+    let error = server.confirm_email_verify(&token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::EmailVerifyTokenInvalid);
+    assert!(!server.get_user_profile().await.email_verified);
+}
+
+#[actix_rt::test]
+async fn user_reset_password_with_email_verify_token() {
+    let email = "annie@appflowy.io";
+    let (server, mailbox) = spawn_mailer_signed_in_server(email).await;
+    server.request_email_verify().await.unwrap();
+    let token = mailed_token(&mailbox, email);
+
+    let error = server
+        .confirm_password_reset(&token, "HelloAgain123!")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordResetTokenInvalid);
+}
+
+#[actix_rt::test]
+async fn user_update_email_resets_email_verify() {
+    let email = "annie@appflowy.io";
+    let (server, mailbox) = spawn_mailer_signed_in_server(email).await;
+    server.request_email_verify().await.unwrap();
+    let token = mailed_token(&mailbox, email);
+    server.confirm_email_verify(&token).await.unwrap();
+
+    let new_email = "nathan@appflowy.io";
+    let params = UpdateUserParams::new(server.user_id()).email(new_email);
+    server.update_user_profile(params).await.unwrap();
+    assert!(!server.get_user_profile().await.email_verified);
+
+    server.request_email_verify().await.unwrap();
+    let token = mailed_token(&mailbox, new_email);
+    server.confirm_email_verify(&token).await.unwrap();
+    assert!(server.get_user_profile().await.email_verified);
+}
+
+#[actix_rt::test]
+async fn user_verify_email_with_old_email_token() {
+    let email = "annie@appflowy.io";
+    let (server, mailbox) = spawn_mailer_signed_in_server(email).await;
+    server.request_email_verify().await.unwrap();
+    let token = mailed_token(&mailbox, email);
+
+    let params = UpdateUserParams::new(server.user_id()).email("nathan@appflowy.io");
+    server.update_user_profile(params).await.unwrap();
+    let error = server.confirm_email_verify(&token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::EmailVerifyTokenInvalid);
+    assert!(!server.get_user_profile().await.email_verified);
+}
+
+#[actix_rt::test]
+async fn user_refresh_token() {
+    let mut server = spawn_user_server().await;
+    let response = register_user(&server, "annie@appflowy.io", "HelloWorld123!").await;
+    let refreshed = server.refresh_token(&response.refresh_token).await.unwrap();
+    assert_ne!(refreshed.refresh_token, response.refresh_token);
+
+    server.user_token = Some(refreshed.token);
+    let user_profile = server.get_user_profile().await;
+    assert_eq!(user_profile.id, response.user_id);
+}
+
+#[actix_rt::test]
+async fn user_refresh_token_after_sign_in() {
+    let server = spawn_user_server().await;
+    let email = "annie@appflowy.io";
+    let password = "HelloWorld123!";
+    let _ = register_user(&server, email, password).await;
+    let response = server.sign_in(sign_in_params(email, password)).await.unwrap();
+    let _ = server.refresh_token(&response.refresh_token).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn user_refresh_token_again_after_losing_response() {
+    let server = spawn_user_server().await;
+    let response = register_user(&server, "annie@appflowy.io", "HelloWorld123!").await;
+    let _ = server.refresh_token(&response.refresh_token).await.unwrap();
+
+    // The replaced token is still accepted for a while, the client may not receive the new one
+    let refreshed = server.refresh_token(&response.refresh_token).await.unwrap();
+    let _ = server.refresh_token(&refreshed.refresh_token).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn user_reuse_replaced_refresh_token() {
+    let server = spawn_user_server().await;
+    let response = register_user(&server, "annie@appflowy.io", "HelloWorld123!").await;
+    let first = server.refresh_token(&response.refresh_token).await.unwrap();
+    let second = server.refresh_token(&first.refresh_token).await.unwrap();
+
+    let error = server.refresh_token(&response.refresh_token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+
+    // The session is revoked, the latest token can't be used either
+    let error = server.refresh_token(&second.refresh_token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn user_refresh_token_after_sign_out() {
+    let mut server = spawn_user_server().await;
+    let response = register_user(&server, "annie@appflowy.io", "HelloWorld123!").await;
+    server.user_token = Some(response.token);
+    server.sign_out().await;
+
+    let error = server.refresh_token(&response.refresh_token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn user_refresh_token_with_invalid_token() {
+    let server = spawn_user_server().await;
+    let error = server.refresh_token("invalid_token").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+async fn spawn_mailer_user_server() -> (TestUserServer, MockMailbox) {
+    let (settings, mailbox) = spawn_mock_mailer();
+    let server: TestUserServer = spawn_server_with_configuration(|c| {
+        c.mail = MailSettings::Http(settings);
+    })
+    .await
+    .into();
+    (server, mailbox)
+}
+
+// The server sends the requests as the signed-in user of the email
+async fn spawn_mailer_signed_in_server(email: &str) -> (TestUserServer, MockMailbox) {
+    let (server, mailbox) = spawn_mailer_user_server().await;
+    let server = server.register_member(email).await;
+    (server, mailbox)
+}
+
+// The token is the last line of the email
+fn mailed_token(mailbox: &MockMailbox, email: &str) -> String {
+    let emails = mailbox.emails_to(email);
+    assert_eq!(emails.len(), 1);
+    emails[0].text.lines().last().unwrap().to_owned()
+}
+
+fn sign_in_params(email: &str, password: &str) -> SignInParams {
+    SignInParams {
+        email: email.to_owned(),
+        password: password.to_owned(),
+        name: "annie".to_owned(),
+    }
+}
+
+async fn spawn_github_user_server(account: MockGitHubAccount) -> TestUserServer {
+    let github = spawn_mock_github(account);
+    let server: TestUserServer = spawn_server_with_configuration(|c| {
+        c.oauth.github = Some(github);
+        c.oauth.google = None;
+    })
+    .await
+    .into();
+    server
+}
+
+fn github_account(email: &str, email_verified: bool, pkce: &PkceChallenge) -> MockGitHubAccount {
+    MockGitHubAccount {
+        id: 1024,
+        login: "annie".to_owned(),
+        email: email.to_owned(),
+        email_verified,
+        code: "github_code".to_owned(),
+        code_challenge: pkce.code_challenge.clone(),
+    }
+}
+
+fn github_params(pkce: &PkceChallenge) -> SignInWithProviderParams {
+    SignInWithProviderParams {
+        provider: AuthProvider::GitHub,
+        code: "github_code".to_owned(),
+        code_verifier: pkce.code_verifier.clone(),
+        redirect_uri: "http://localhost:8000/oauth/callback".to_owned(),
+    }
+}
+
+#[actix_rt::test]
+async fn user_sign_in_with_proxy_headers() {
+    let server = spawn_header_auth_server(vec!["127.0.0.1", "::1"]).await;
+    let response = server
+        .sign_in_with_proxy_headers("annie@appflowy.io", "annie")
+        .await
+        .unwrap();
+    assert_eq!(response.email, "annie@appflowy.io");
+    assert_eq!(response.name, "annie");
+
+    // The user is created at the first sign in
+    let again = server
+        .sign_in_with_proxy_headers("annie@appflowy.io", "annie")
+        .await
+        .unwrap();
+    assert_eq!(again.user_id, response.user_id);
+}
+
+#[actix_rt::test]
+async fn user_sign_in_with_proxy_headers_from_untrusted_proxy() {
+    let server = spawn_header_auth_server(vec!["10.0.0.1"]).await;
+    let error = server
+        .sign_in_with_proxy_headers("annie@appflowy.io", "annie")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn user_sign_up_with_proxy_headers_auth() {
+    let server = spawn_header_auth_server(vec!["127.0.0.1", "::1"]).await;
+    let params = SignUpParams {
+        email: "annie@appflowy.io".to_string(),
+        name: "annie".to_string(),
+        password: "HelloWorld123!".to_string(),
+    };
+    let error = server.try_register(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+async fn spawn_header_auth_server(trusted_proxies: Vec<&str>) -> TestUserServer {
+    let trusted_proxies = trusted_proxies.into_iter().map(|ip| ip.parse().unwrap()).collect();
+    let server: TestUserServer = spawn_server_with_configuration(|c| {
+        c.auth = AuthSettings::Header(HeaderAuthSettings {
+            trusted_proxies,
+            email_header: None,
+            name_header: None,
+        });
+    })
+    .await
+    .into();
+    server
+}
+
 #[allow(dead_code)]
 async fn sign_up_user(server: &TestUserServer) -> SignUpResponse {
     let email = "annie@appflowy.io";
@@ -1,6 +1,8 @@
 use crate::util::helper::ViewTest;
-use flowy_document_infra::entities::doc::DocIdentifier;
-use flowy_workspace_infra::entities::view::ViewIdentifiers;
+use backend_service::errors::ErrorCode;
+use flowy_document_infra::entities::doc::{DocIdentifier, DocSnapshotIdentifier, UpdateDocParams};
+use flowy_workspace_infra::entities::{view::ViewIdentifiers, workspace::BotScope};
+use serde_json::json;
 
 #[actix_rt::test]
 async fn doc_read() {
@@ -28,3 +30,148 @@ async fn doc_delete() {
     let doc = test.server.read_doc(params).await;
     assert_eq!(doc.is_none(), true);
 }
+
+#[actix_rt::test]
+async fn doc_append_markdown() {
+    let test = ViewTest::new().await;
+    let command = json!({
+        "command": "append_markdown",
+        "view_id": test.view.id,
+        "markdown": "# Meeting notes\n- [ ] send the summary",
+    });
+    test.server.try_run_automation(command).await.unwrap();
+
+    let params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+    let doc = test.server.read_doc(params).await.unwrap();
+    assert_eq!(doc.data.contains(r#"{"insert":"Meeting notes"}"#), true);
+    assert_eq!(doc.data.contains(r#"{"list":"unchecked"}"#), true);
+}
+
+#[actix_rt::test]
+async fn doc_append_markdown_with_unknown_command() {
+    let test = ViewTest::new().await;
+    let command = json!({
+        "command": "create_row",
+        "view_id": test.view.id,
+    });
+    let error = test.server.try_run_automation(command).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn doc_snapshot_restore() {
+    let test = ViewTest::new().await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"first draft\n"}]"#.to_owned(),
+        rev_id: 50,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+
+    let params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+    let snapshots = test.server.read_doc_snapshots(params.clone()).await;
+    assert_eq!(snapshots.items.len(), 1);
+    assert_eq!(snapshots.items[0].rev_id, 50);
+
+    let update_params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"second draft\n"}]"#.to_owned(),
+        rev_id: 51,
+        ..Default::default()
+    };
+    test.server.update_doc(update_params).await;
+
+    let restore_params = DocSnapshotIdentifier {
+        doc_id: test.view.id.clone(),
+        snapshot_id: snapshots.items[0].id,
+    };
+    let doc = test.server.restore_doc_snapshot(restore_params).await;
+    assert_eq!(doc.rev_id, 52);
+
+    let doc = test.server.read_doc(params.clone()).await.unwrap();
+    assert_eq!(doc.data.contains("first draft"), true);
+
+    let snapshots = test.server.read_doc_snapshots(params).await;
+    assert_eq!(snapshots.items[0].name, "Before restore");
+    assert_eq!(snapshots.items.len(), 2);
+}
+
+#[actix_rt::test]
+async fn doc_snapshot_records_device() {
+    let test = ViewTest::new().await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"first draft\n"}]"#.to_owned(),
+        rev_id: 50,
+        device_id: "device-a".to_owned(),
+    };
+    test.server.update_doc(params).await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"second draft\n"}]"#.to_owned(),
+        rev_id: 51,
+        device_id: "device-b".to_owned(),
+    };
+    test.server.update_doc(params).await;
+
+    let params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+    let snapshots = test.server.read_doc_snapshots(params.clone()).await;
+    assert_eq!(snapshots.items[0].device_id, "device-a");
+
+    // The data before the restore was written by the device of the latest revision
+    let restore_params = DocSnapshotIdentifier {
+        doc_id: test.view.id.clone(),
+        snapshot_id: snapshots.items[0].id,
+    };
+    test.server.restore_doc_snapshot(restore_params).await;
+    let snapshots = test.server.read_doc_snapshots(params).await;
+    assert_eq!(snapshots.items[0].name, "Before restore");
+    assert_eq!(snapshots.items[0].device_id, "device-b");
+}
+
+#[actix_rt::test]
+async fn doc_revision_records_author() {
+    let test = ViewTest::new().await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"written by the user\n"}]"#.to_owned(),
+        rev_id: 50,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+
+    let bot_token = test.server.create_bot(&test.workspace.id, BotScope::Write).await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"written by the bot\n"}]"#.to_owned(),
+        rev_id: 51,
+        ..Default::default()
+    };
+    test.server
+        .try_update_doc_with_token(&bot_token.token, params)
+        .await
+        .unwrap();
+
+    let doc_id = uuid::Uuid::parse_str(&test.view.id).unwrap();
+    let authors: Vec<(i64, String, bool)> = sqlx::query_as(
+        "SELECT rev_id, author_id, is_bot FROM doc_revision_table WHERE doc_id = $1 AND rev_id > 0 ORDER BY rev_id",
+    )
+    .bind(doc_id)
+    .fetch_all(&test.server.pg_pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        authors,
+        vec![
+            (50, test.server.user_id().to_owned(), false),
+            (51, bot_token.bot.id.clone(), true),
+        ]
+    );
+}
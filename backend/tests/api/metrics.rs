@@ -0,0 +1,67 @@
+use crate::util::helper::{spawn_server_with_configuration, spawn_user_server, TestUserServer};
+use flowy_workspace_infra::entities::workspace::CreateWorkspaceParams;
+use reqwest::StatusCode;
+
+#[actix_rt::test]
+async fn metrics_read_pool_health() {
+    let mut server = spawn_metrics_server("metrics token").await;
+    let response = server.register_user().await;
+    server.user_token = Some(response.token);
+    let _ = server
+        .create_workspace(CreateWorkspaceParams {
+            name: "my workspace".to_owned(),
+            desc: "".to_owned(),
+        })
+        .await;
+
+    let response = server.read_metrics(Some("metrics token")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let text = response.text().await.unwrap();
+    for name in &[
+        "db_pool_connections",
+        "db_pool_idle_connections",
+        "db_pool_max_connections",
+        "db_pool_acquire_timeouts_total",
+        "db_pool_acquire_wait_seconds_total",
+        "db_slow_transactions_total",
+    ] {
+        assert!(text.contains(&format!("# TYPE {} ", name)), "{} is missing", name);
+    }
+    // The workspace was created in a transaction that waited for a connection
+    let acquires = metric_value(&text, "db_pool_acquires_total");
+    assert!(acquires >= 1.0);
+}
+
+#[actix_rt::test]
+async fn metrics_read_with_invalid_token() {
+    let server = spawn_metrics_server("metrics token").await;
+    let response = server.read_metrics(Some("another token")).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = server.read_metrics(None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_rt::test]
+async fn metrics_disabled_without_token() {
+    let server = spawn_user_server().await;
+    let response = server.read_metrics(Some("metrics token")).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+async fn spawn_metrics_server(token: &str) -> TestUserServer {
+    let token = token.to_owned();
+    let server: TestUserServer = spawn_server_with_configuration(|c| {
+        c.metrics.token = Some(token);
+    })
+    .await
+    .into();
+    server
+}
+
+fn metric_value(text: &str, name: &str) -> f64 {
+    text.lines()
+        .find_map(|line| line.strip_prefix(&format!("{} ", name)))
+        .and_then(|value| value.parse::<f64>().ok())
+        .unwrap()
+}
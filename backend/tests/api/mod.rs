@@ -1,3 +1,9 @@
+mod attachment;
 mod auth;
 mod doc;
+mod metrics;
+mod rate_limit;
+mod search;
+mod storage;
+mod tenant;
 mod workspace;
@@ -0,0 +1,136 @@
+use crate::util::helper::{spawn_server_with_configuration, TestUserServer};
+use backend::config::{RateLimitSettings, RateSettings};
+use backend_service::{
+    errors::{ErrorCode, ServerError},
+    request::HttpRequestBuilder,
+};
+use bytes::Bytes;
+use flowy_user_infra::entities::{SignInParams, SignInResponse};
+use flowy_workspace_infra::entities::workspace::{CreateWorkspaceParams, UpdateWorkspaceParams};
+
+#[actix_rt::test]
+async fn rate_limit_user_requests() {
+    let mut server = spawn_rate_limited_server(Some(rate(5)), None).await;
+    let response = server.register_user().await;
+    server.user_token = Some(response.token);
+
+    let workspace = server
+        .create_workspace(CreateWorkspaceParams {
+            name: "my workspace".to_owned(),
+            desc: "".to_owned(),
+        })
+        .await;
+    let params = UpdateWorkspaceParams {
+        id: workspace.id.clone(),
+        name: Some("renamed workspace".to_owned()),
+        desc: None,
+    };
+    for _ in 0..4 {
+        server.try_update_workspace(params.clone()).await.unwrap();
+    }
+
+    let error = server.try_update_workspace(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::TooManyRequests);
+}
+
+#[actix_rt::test]
+async fn rate_limit_ip_requests() {
+    let server = spawn_rate_limited_server(None, Some(rate(2))).await;
+    let params = || SignInParams {
+        email: "annie@appflowy.io".to_owned(),
+        password: "HelloWorld!123".to_owned(),
+        name: "annie".to_owned(),
+    };
+    for _ in 0..2 {
+        let error = server.sign_in(params()).await.unwrap_err();
+        assert_ne!(error.code, ErrorCode::TooManyRequests);
+    }
+
+    let error = server.sign_in(params()).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::TooManyRequests);
+}
+
+// The client can't get another bucket by setting another ip in the X-Forwarded-For header, the header is
+// only read if the request comes from a trusted proxy
+#[actix_rt::test]
+async fn rate_limit_ip_of_untrusted_peer() {
+    for (trusted_proxies, is_limited) in vec![(vec![], true), (vec!["127.0.0.1", "::1"], false)] {
+        let trusted_proxies = trusted_proxies.into_iter().map(|ip| ip.parse().unwrap()).collect();
+        let server: TestUserServer = spawn_server_with_configuration(|c| {
+            c.rate_limit = RateLimitSettings {
+                user: None,
+                ip: Some(rate(2)),
+                trusted_proxies,
+                ..Default::default()
+            };
+        })
+        .await
+        .into();
+
+        for ip in &["10.0.1.1", "10.0.1.2"] {
+            let error = sign_in_from(&server, ip).await.unwrap_err();
+            assert_ne!(error.code, ErrorCode::TooManyRequests);
+        }
+        let error = sign_in_from(&server, "10.0.1.3").await.unwrap_err();
+        assert_eq!(error.code == ErrorCode::TooManyRequests, is_limited);
+    }
+}
+
+#[actix_rt::test]
+async fn payload_larger_than_limit() {
+    let server = TestUserServer::new().await;
+    let workspace = server
+        .create_workspace(CreateWorkspaceParams {
+            name: "my workspace".to_owned(),
+            desc: "".to_owned(),
+        })
+        .await;
+    let params = UpdateWorkspaceParams {
+        id: workspace.id.clone(),
+        name: None,
+        desc: Some("a".repeat(300 * 1024)),
+    };
+    let error = server.try_update_workspace(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PayloadOverflow);
+
+    // The backup is allowed to be larger, so it's read and rejected because it's not a backup
+    let error = server
+        .try_restore_workspace(Bytes::from(vec![b'a'; 300 * 1024]))
+        .await
+        .unwrap_err();
+    assert_ne!(error.code, ErrorCode::PayloadOverflow);
+}
+
+fn rate(burst: u32) -> RateSettings {
+    RateSettings {
+        requests_per_minute: 1,
+        burst,
+    }
+}
+
+async fn sign_in_from(server: &TestUserServer, ip: &str) -> Result<SignInResponse, ServerError> {
+    let params = SignInParams {
+        email: "annie@appflowy.io".to_owned(),
+        password: "HelloWorld!123".to_owned(),
+        name: "annie".to_owned(),
+    };
+    HttpRequestBuilder::new()
+        .post(&format!("{}/api/auth", server.http_addr()))
+        .header("X-Forwarded-For", ip)
+        .protobuf(params)?
+        .response()
+        .await
+}
+
+async fn spawn_rate_limited_server(user: Option<RateSettings>, ip: Option<RateSettings>) -> TestUserServer {
+    let server: TestUserServer = spawn_server_with_configuration(|c| {
+        c.rate_limit = RateLimitSettings {
+            user,
+            ip,
+            ..Default::default()
+        };
+    })
+    .await
+    .into();
+    server
+}
@@ -0,0 +1,113 @@
+use backend::{
+    config::SearchLanguage,
+    service::search::{IndexedView, SearchIndex},
+};
+use uuid::Uuid;
+
+fn index_dir() -> String { std::env::temp_dir().join(Uuid::new_v4().to_string()).to_string_lossy().to_string() }
+
+fn view(view_id: &str, name: &str, text: &str, rev_id: i64) -> IndexedView {
+    IndexedView {
+        view_id: view_id.to_owned(),
+        name: name.to_owned(),
+        text: text.to_owned(),
+        rev_id,
+    }
+}
+
+#[actix_rt::test]
+async fn search_index_put_then_search() {
+    let index = SearchIndex::open(&index_dir(), SearchLanguage::Default).unwrap();
+    index.put(view("1", "Roadmap", "Plans for the next quarter", 1));
+    index.put(view("2", "Notes", "The roadmap is discussed in the meeting", 1));
+    let _ = index.commit().unwrap();
+
+    let view_ids = vec!["1".to_owned(), "2".to_owned()];
+    let hits = index.search(&view_ids, "roadmap", 10).unwrap();
+    assert_eq!(hits.len(), 2);
+    // The match of the name is ranked higher than the match of the text
+    assert_eq!(hits[0].view_id, "1");
+    assert_eq!(hits[1].snippet.contains("<b>roadmap</b>"), true);
+
+    // Only the given views are searched
+    let hits = index.search(&["2".to_owned()], "roadmap", 10).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].view_id, "2");
+}
+
+#[actix_rt::test]
+async fn search_index_replace_and_remove() {
+    let index = SearchIndex::open(&index_dir(), SearchLanguage::Default).unwrap();
+    let view_ids = vec!["1".to_owned()];
+    index.put(view("1", "Draft", "The first version", 1));
+    let _ = index.commit().unwrap();
+
+    index.put(view("1", "Draft", "The second version", 2));
+    let _ = index.commit().unwrap();
+    assert_eq!(index.search(&view_ids, "first", 10).unwrap().is_empty(), true);
+    assert_eq!(index.search(&view_ids, "second", 10).unwrap().len(), 1);
+    assert_eq!(index.indexed_views().unwrap().get("1"), Some(&("Draft".to_owned(), 2)));
+
+    index.remove("1");
+    let _ = index.commit().unwrap();
+    assert_eq!(index.search(&view_ids, "second", 10).unwrap().is_empty(), true);
+    assert_eq!(index.indexed_views().unwrap().is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn search_index_escape_snippet() {
+    let index = SearchIndex::open(&index_dir(), SearchLanguage::Default).unwrap();
+    index.put(view("1", "Html", "Use <script> tags with care", 1));
+    let _ = index.commit().unwrap();
+
+    let hits = index.search(&["1".to_owned()], "script", 10).unwrap();
+    assert_eq!(hits[0].snippet.contains("&lt;<b>script</b>&gt;"), true);
+}
+
+#[actix_rt::test]
+async fn search_index_reopen() {
+    let index_dir = index_dir();
+    let index = SearchIndex::open(&index_dir, SearchLanguage::Default).unwrap();
+    index.put(view("1", "Roadmap", "Plans for the next quarter", 3));
+    let _ = index.commit().unwrap();
+    drop(index);
+
+    let index = SearchIndex::open(&index_dir, SearchLanguage::Default).unwrap();
+    assert_eq!(index.indexed_views().unwrap().get("1"), Some(&("Roadmap".to_owned(), 3)));
+    assert_eq!(index.search(&["1".to_owned()], "quarter", 10).unwrap().len(), 1);
+}
+
+#[actix_rt::test]
+async fn search_index_chinese() {
+    let index = SearchIndex::open(&index_dir(), SearchLanguage::Chinese).unwrap();
+    index.put(view("1", "笔记", "我们正在开发数据库管理系统", 1));
+    let _ = index.commit().unwrap();
+
+    let hits = index.search(&["1".to_owned()], "数据库", 10).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].snippet.contains("<b>数据库</b>"), true);
+}
+
+#[actix_rt::test]
+async fn search_index_japanese() {
+    let index = SearchIndex::open(&index_dir(), SearchLanguage::Japanese).unwrap();
+    index.put(view("1", "メモ", "東京都に住んでいます", 1));
+    let _ = index.commit().unwrap();
+
+    let hits = index.search(&["1".to_owned()], "東京", 10).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].snippet.contains("<b>東京</b>"), true);
+}
+
+#[actix_rt::test]
+async fn search_index_rebuild_after_language_changed() {
+    let index_dir = index_dir();
+    let index = SearchIndex::open(&index_dir, SearchLanguage::Default).unwrap();
+    index.put(view("1", "Roadmap", "Plans for the next quarter", 3));
+    let _ = index.commit().unwrap();
+    drop(index);
+
+    // The views are indexed again by the sync of the server
+    let index = SearchIndex::open(&index_dir, SearchLanguage::Chinese).unwrap();
+    assert_eq!(index.indexed_views().unwrap().is_empty(), true);
+}
@@ -0,0 +1,51 @@
+use backend::storage::{LocalStorage, ObjectStorage};
+use backend_service::errors::ErrorCode;
+use std::time::Duration;
+use uuid::Uuid;
+
+fn local_storage() -> LocalStorage { LocalStorage::new(std::env::temp_dir().join(Uuid::new_v4().to_string())) }
+
+#[actix_rt::test]
+async fn storage_local_put_then_get() {
+    let storage = local_storage();
+    let key = "exports/workspace/readme.md";
+    let _ = storage.put_object(key, &mut "# Read me".as_bytes()).await.unwrap();
+
+    let mut data = vec![];
+    let _ = storage.get_object(key, &mut data).await.unwrap();
+    assert_eq!(data, b"# Read me");
+
+    // The object is replaced
+    let _ = storage.put_object(key, &mut "# Release notes".as_bytes()).await.unwrap();
+    let mut data = vec![];
+    let _ = storage.get_object(key, &mut data).await.unwrap();
+    assert_eq!(data, b"# Release notes");
+
+    // The local disk can't sign urls
+    let url = storage.signed_url(key, Duration::from_secs(60)).unwrap();
+    assert_eq!(url, None);
+}
+
+#[actix_rt::test]
+async fn storage_local_delete() {
+    let storage = local_storage();
+    let key = "backups/flowy.sql";
+    let _ = storage.put_object(key, &mut "backup".as_bytes()).await.unwrap();
+    let _ = storage.delete_object(key).await.unwrap();
+
+    let mut data = vec![];
+    let error = storage.get_object(key, &mut data).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+
+    // Deleting again is not an error
+    let _ = storage.delete_object(key).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn storage_local_invalid_key() {
+    let storage = local_storage();
+    for key in vec!["", "/etc/passwd", "../secret", "assets//logo.png"] {
+        let error = storage.put_object(key, &mut "data".as_bytes()).await.unwrap_err();
+        assert_eq!(error.code, ErrorCode::ParamsInvalid);
+    }
+}
@@ -0,0 +1,337 @@
+use crate::util::helper::{create_test_app, create_test_view, create_test_workspace, TestUserServer, ViewTest};
+use backend_service::errors::ErrorCode;
+use flowy_document_infra::entities::doc::{
+    AttachmentChunkIdentifier,
+    AttachmentIdentifier,
+    CreateAttachmentParams,
+    DocIdentifier,
+    DocSnapshotIdentifier,
+    UpdateDocParams,
+};
+use flowy_workspace_infra::{
+    entities::{
+        app::AppIdentifier,
+        trash::{TrashIdentifier, TrashType},
+        view::{
+            CreateViewParams,
+            MoveViewParams,
+            UpdateViewParams,
+            ViewIdentifier,
+            ViewIdentifiers,
+            ViewType,
+            WebhookFormat,
+        },
+        workspace::{UpdateWorkspaceParams, WorkspaceIdentifier, WorkspaceRole},
+    },
+    rank::rank_between,
+};
+use serde_json::json;
+
+// The stranger signs up on the same server but isn't a member of the workspace of the test
+async fn register_stranger(test: &ViewTest) -> TestUserServer {
+    test.server.register_member("nathan@appflowy.io").await
+}
+
+fn doc_identifier(test: &ViewTest) -> DocIdentifier {
+    DocIdentifier {
+        doc_id: test.view.id.clone(),
+    }
+}
+
+#[actix_rt::test]
+async fn tenant_read_app_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let error = stranger.try_read_app(AppIdentifier::new(&test.app.id)).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_read_view_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let params: ViewIdentifier = test.view.id.clone().into();
+    let error = stranger.try_read_view(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_create_view_in_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let params = CreateViewParams::new(
+        test.app.id.clone(),
+        "Stranger's view".to_owned(),
+        "".to_owned(),
+        ViewType::Doc,
+        "".to_owned(),
+    );
+    let error = stranger.try_create_view(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_update_and_delete_view_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let params = UpdateViewParams::new(&test.view.id).name("renamed by stranger");
+    let error = stranger.try_update_view(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let params = ViewIdentifiers {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let error = stranger.try_delete_view(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let read_params: ViewIdentifier = test.view.id.clone().into();
+    let view = test.server.read_view(read_params).await.unwrap();
+    assert_eq!(view.name, test.view.name);
+}
+
+#[actix_rt::test]
+async fn tenant_move_view_to_own_app() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let workspace = create_test_workspace(&stranger).await;
+    let app = create_test_app(&stranger, &workspace.id).await;
+    let params = MoveViewParams {
+        view_id: test.view.id.clone(),
+        belong_to_id: app.id.clone(),
+        rank: rank_between("", None),
+    };
+    let error = stranger.try_move_view(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_update_and_delete_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let params = UpdateWorkspaceParams {
+        id: test.workspace.id.clone(),
+        name: Some("renamed by stranger".to_owned()),
+        desc: None,
+    };
+    let error = stranger.try_update_workspace(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+
+    let params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let error = stranger.try_delete_workspace(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn tenant_update_workspace_by_editor() {
+    let test = ViewTest::new().await;
+    let editor = register_stranger(&test).await;
+    let _ = test
+        .server
+        .share_workspace(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Editor)
+        .await
+        .unwrap();
+
+    // the editor can change the apps but not the workspace itself
+    let params = UpdateWorkspaceParams {
+        id: test.workspace.id.clone(),
+        name: Some("renamed by editor".to_owned()),
+        desc: None,
+    };
+    let error = editor.try_update_workspace(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn tenant_read_and_update_doc_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let error = stranger.try_read_doc(doc_identifier(&test)).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"written by stranger\n"}]"#.to_owned(),
+        rev_id: 50,
+        ..Default::default()
+    };
+    let error = stranger.try_update_doc(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let doc = test.server.read_doc(doc_identifier(&test)).await.unwrap();
+    assert_eq!(doc.data.contains("written by stranger"), false);
+}
+
+#[actix_rt::test]
+async fn tenant_read_and_restore_doc_snapshot_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"first draft\n"}]"#.to_owned(),
+        rev_id: 50,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+    let snapshots = test.server.read_doc_snapshots(doc_identifier(&test)).await;
+
+    let stranger = register_stranger(&test).await;
+    let error = stranger.try_read_doc_snapshots(doc_identifier(&test)).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let params = DocSnapshotIdentifier {
+        doc_id: test.view.id.clone(),
+        snapshot_id: snapshots.items[0].id,
+    };
+    let error = stranger.try_restore_doc_snapshot(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_read_doc_by_viewer() {
+    let test = ViewTest::new().await;
+    let viewer = register_stranger(&test).await;
+    let _ = test
+        .server
+        .share_workspace(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Viewer)
+        .await
+        .unwrap();
+
+    assert_eq!(viewer.try_read_doc(doc_identifier(&test)).await.unwrap().is_some(), true);
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"written by viewer\n"}]"#.to_owned(),
+        rev_id: 50,
+        ..Default::default()
+    };
+    let error = viewer.try_update_doc(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_trash_view_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let error = stranger.try_create_view_trash(&test.view.id).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_delete_trash_of_other_user() {
+    let test = ViewTest::new().await;
+    test.server.create_view_trash(&test.view.id).await;
+
+    // the trash is looked up among the stranger's own trash, so the view isn't deleted
+    let stranger = register_stranger(&test).await;
+    let identifier = TrashIdentifier {
+        id: test.view.id.clone(),
+        ty: TrashType::View,
+    };
+    let error = stranger.try_delete_view_trash(vec![identifier].into()).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+    assert_eq!(test.server.read_trash().await.len(), 1);
+}
+
+#[actix_rt::test]
+async fn tenant_run_automation_in_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let command = json!({
+        "command": "append_markdown",
+        "view_id": test.view.id,
+        "markdown": "# Written by stranger",
+    });
+    let error = stranger.try_run_automation(command).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_read_attachment_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let params = CreateAttachmentParams {
+        doc_id: test.view.id.clone(),
+        name: "image.png".to_owned(),
+        size: 1024,
+    };
+    let attachment = test.server.try_create_attachment(params).await.unwrap();
+
+    let stranger = register_stranger(&test).await;
+    let params = CreateAttachmentParams {
+        doc_id: test.view.id.clone(),
+        name: "stranger.png".to_owned(),
+        size: 1024,
+    };
+    let error = stranger.try_create_attachment(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let params = AttachmentIdentifier::from(attachment.id.as_str());
+    let error = stranger.try_read_attachment(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let params = AttachmentChunkIdentifier {
+        attachment_id: attachment.id.clone(),
+        index: 0,
+    };
+    let error = stranger.try_read_attachment_chunk(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_favorite_view_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let error = stranger.try_update_favorite(&test.view.id, true).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+    assert_eq!(stranger.read_favorites().await.items.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn tenant_record_recent_view_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let error = stranger.try_update_recent_view(&test.view.id, 1000).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+    assert_eq!(stranger.read_recent_views(0).await.items.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn tenant_clone_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let error = stranger.try_clone_workspace(&test.workspace.id).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_backup_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let error = stranger.try_backup_workspace(&test.workspace.id).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn tenant_set_webhook_of_other_workspace() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let error = stranger
+        .try_set_view_webhook(&test.view.id, "https://example.com/hook", WebhookFormat::Markdown, "")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+
+    let error = stranger.try_read_view_webhook(&test.view.id).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn tenant_own_workspace_still_accessible() {
+    let test = ViewTest::new().await;
+    let stranger = register_stranger(&test).await;
+    let workspace = create_test_workspace(&stranger).await;
+    let app = create_test_app(&stranger, &workspace.id).await;
+    let view = create_test_view(&stranger, &app.id).await;
+
+    let params: ViewIdentifier = view.id.clone().into();
+    assert_eq!(stranger.read_view(params.clone()).await.unwrap().id, view.id);
+    let error = test.server.try_read_view(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
@@ -1,10 +1,33 @@
-use crate::util::helper::*;
-use flowy_workspace_infra::entities::{
-    app::{AppIdentifier, UpdateAppParams},
-    trash::{TrashIdentifier, TrashIdentifiers, TrashType},
-    view::{UpdateViewParams, ViewIdentifier},
-    workspace::{CreateWorkspaceParams, UpdateWorkspaceParams, WorkspaceIdentifier},
+use crate::util::{helper::*, mock_webhook::spawn_mock_webhook};
+use backend_service::{
+    errors::ErrorCode,
+    workspace_request::{read_view_access_log_request, read_view_request, read_workspaces_request, update_view_request},
 };
+use bytes::Bytes;
+use flowy_document_infra::entities::doc::{CreateAttachmentParams, DocIdentifier, UpdateDocParams};
+use flowy_workspace_infra::{
+    entities::{
+        app::{AppIdentifier, ColorStyle, CreateAppParams, MoveAppParams, UpdateAppParams},
+        trash::{TrashIdentifier, TrashIdentifiers, TrashType},
+        view::{CreateViewParams, MoveViewParams, UpdateViewParams, ViewIdentifier, ViewType, WebhookFormat},
+        workspace::{
+            BotScope,
+            CreateWorkspaceParams,
+            ListFilter,
+            UpdateWorkspaceParams,
+            WorkspaceCloneState,
+            WorkspaceIdentifier,
+            WorkspaceRole,
+        },
+    },
+    rank::rank_between,
+};
+use reqwest::{
+    header::{CACHE_CONTROL, CONTENT_SECURITY_POLICY, ETAG, RETRY_AFTER},
+    StatusCode,
+};
+use serde_json::json;
+use std::time::Duration;
 
 #[actix_rt::test]
 async fn workspace_create() {
@@ -12,6 +35,73 @@ async fn workspace_create() {
     tracing::info!("{:?}", test.workspace);
 }
 
+#[actix_rt::test]
+async fn workspace_create_with_idempotency_key() {
+    let test = WorkspaceTest::new().await;
+    let params = || CreateWorkspaceParams {
+        name: "My second workspace".to_string(),
+        desc: "".to_string(),
+    };
+
+    let count = test.server.read_workspaces(WorkspaceIdentifier::new(None)).await.len();
+    let workspace = test.server.create_workspace_with_idempotency_key(params(), "create-workspace-1").await;
+    let retried_workspace = test.server.create_workspace_with_idempotency_key(params(), "create-workspace-1").await;
+    assert_eq!(workspace, retried_workspace);
+
+    let workspaces = test.server.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    assert_eq!(workspaces.len(), count + 1);
+}
+
+// The concurrent retries wait for the first request instead of failing on the key
+#[actix_rt::test]
+async fn workspace_create_with_idempotency_key_concurrently() {
+    let test = WorkspaceTest::new().await;
+    let params = || CreateWorkspaceParams {
+        name: "My second workspace".to_string(),
+        desc: "".to_string(),
+    };
+
+    let count = test.server.read_workspaces(WorkspaceIdentifier::new(None)).await.len();
+    let (workspace, retried_workspace) = futures::join!(
+        test.server.create_workspace_with_idempotency_key(params(), "create-workspace-1"),
+        test.server.create_workspace_with_idempotency_key(params(), "create-workspace-1"),
+    );
+    assert_eq!(workspace, retried_workspace);
+
+    let workspaces = test.server.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    assert_eq!(workspaces.len(), count + 1);
+}
+
+#[actix_rt::test]
+async fn workspace_create_with_idempotency_key_of_other_request() {
+    let test = WorkspaceTest::new().await;
+    let params = |name: &str| CreateWorkspaceParams {
+        name: name.to_string(),
+        desc: "".to_string(),
+    };
+
+    let _ = test
+        .server
+        .create_workspace_with_idempotency_key(params("My second workspace"), "create-workspace-1")
+        .await;
+    let error = test
+        .server
+        .try_create_workspace_with_idempotency_key(params("My third workspace"), "create-workspace-1")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+
+    // The key is scoped to the route
+    let app_params = CreateAppParams {
+        workspace_id: test.workspace.id.clone(),
+        name: "My app".to_string(),
+        desc: "".to_string(),
+        color_style: ColorStyle::default(),
+    };
+    let app = test.server.create_app_with_idempotency_key(app_params, "create-workspace-1").await;
+    assert_eq!(app.name, "My app");
+}
+
 #[actix_rt::test]
 async fn workspace_read() {
     let test = WorkspaceTest::new().await;
@@ -57,9 +147,7 @@ async fn workspace_update() {
 #[actix_rt::test]
 async fn workspace_delete() {
     let test = WorkspaceTest::new().await;
-    let delete_params = WorkspaceIdentifier {
-        workspace_id: Some(test.workspace.id.clone()),
-    };
+    let delete_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
 
     let _ = test.server.delete_workspace(delete_params).await;
     let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
@@ -67,12 +155,360 @@ async fn workspace_delete() {
     assert_eq!(repeated_workspace.len(), 0);
 }
 
+#[actix_rt::test]
+async fn workspace_trash() {
+    let test = WorkspaceTest::new().await;
+    test.server.create_workspace_trash(&test.workspace.id).await;
+
+    let workspaces = test.server.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    assert_eq!(workspaces.iter().any(|workspace| workspace.id == test.workspace.id), false);
+
+    let trash = test.server.read_trash().await;
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].ty, TrashType::Workspace);
+}
+
+#[actix_rt::test]
+async fn workspace_trash_delete() {
+    let test = WorkspaceTest::new().await;
+    test.server.create_workspace_trash(&test.workspace.id).await;
+
+    let identifier = TrashIdentifier {
+        id: test.workspace.id.clone(),
+        ty: TrashType::Workspace,
+    };
+    test.server.delete_view_trash(vec![identifier].into()).await;
+    assert_eq!(test.server.read_trash().await.is_empty(), true);
+
+    let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    assert_eq!(test.server.read_workspaces(read_params).await.len(), 0);
+}
+
+#[actix_rt::test]
+async fn workspace_archive() {
+    let test = WorkspaceTest::new().await;
+    test.server.archive_workspace(&test.workspace.id, true).await;
+
+    // the archived workspace is hidden from the workspace list
+    let workspaces = test.server.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    assert_eq!(workspaces.iter().any(|workspace| workspace.id == test.workspace.id), false);
+
+    let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let workspace = test.server.read_workspaces(read_params).await.pop().unwrap();
+    assert_eq!(workspace.archived, true);
+
+    test.server.archive_workspace(&test.workspace.id, false).await;
+    let workspaces = test.server.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    assert_eq!(workspaces.iter().any(|workspace| workspace.id == test.workspace.id), true);
+}
+
+#[actix_rt::test]
+async fn workspace_archive_read_only() {
+    let test = WorkspaceTest::new().await;
+    test.server.archive_workspace(&test.workspace.id, true).await;
+
+    let params = || CreateAppParams {
+        workspace_id: test.workspace.id.clone(),
+        name: "My first app".to_string(),
+        desc: "This is my first app".to_string(),
+        color_style: ColorStyle::default(),
+    };
+    let error = test.server.try_create_app(params()).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::WorkspaceArchived);
+
+    test.server.archive_workspace(&test.workspace.id, false).await;
+    assert_eq!(test.server.try_create_app(params()).await.is_ok(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_share() {
+    let test = WorkspaceTest::new().await;
+    let editor = test.server.register_member("nathan@appflowy.io").await;
+    let _ = test
+        .server
+        .share_workspace(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Editor)
+        .await
+        .unwrap();
+
+    let members = test.server.read_workspace_members(&test.workspace.id).await.unwrap().items;
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].role, WorkspaceRole::Owner);
+    assert_eq!(members[1].email, "nathan@appflowy.io");
+    assert_eq!(members[1].role, WorkspaceRole::Editor);
+
+    // the shared workspace is listed for the member and the member can change its apps
+    let workspaces = editor.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    let workspace = workspaces.iter().find(|workspace| workspace.id == test.workspace.id).unwrap();
+    assert_eq!(workspace.role, WorkspaceRole::Editor);
+    let workspaces = test.server.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    assert_eq!(workspaces.iter().all(|workspace| workspace.role == WorkspaceRole::Owner), true);
+    let app = create_test_app(&editor, &test.workspace.id).await;
+    editor.update_app(UpdateAppParams::new(&app.id).name("renamed by editor")).await;
+    editor.delete_app(AppIdentifier::new(&app.id)).await;
+}
+
+#[actix_rt::test]
+async fn workspace_share_viewer_read_only() {
+    let test = AppTest::new().await;
+    let viewer = test.server.register_member("nathan@appflowy.io").await;
+    let _ = test
+        .server
+        .share_workspace(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Viewer)
+        .await
+        .unwrap();
+
+    let read_params = AppIdentifier::new(&test.app.id);
+    assert_eq!(viewer.read_app(read_params).await.unwrap().id, test.app.id);
+
+    let params = CreateAppParams {
+        workspace_id: test.workspace.id.clone(),
+        name: "My first app".to_string(),
+        desc: "This is my first app".to_string(),
+        color_style: ColorStyle::default(),
+    };
+    let error = viewer.try_create_app(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let update_params = UpdateAppParams::new(&test.app.id).name("renamed by viewer");
+    let error = viewer.try_update_app(update_params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    // the viewer can be promoted to an editor
+    let _ = test
+        .server
+        .share_workspace(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Editor)
+        .await
+        .unwrap();
+    let update_params = UpdateAppParams::new(&test.app.id).name("renamed by editor");
+    assert_eq!(viewer.try_update_app(update_params).await.is_ok(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_share_by_member() {
+    let test = WorkspaceTest::new().await;
+    let editor = test.server.register_member("nathan@appflowy.io").await;
+    let _ = test
+        .server
+        .share_workspace(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Editor)
+        .await
+        .unwrap();
+
+    // only the owner can share the workspace
+    let error = editor
+        .share_workspace(&test.workspace.id, "annie@appflowy.io", WorkspaceRole::Viewer)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn workspace_share_with_unknown_email() {
+    let test = WorkspaceTest::new().await;
+    let error = test
+        .server
+        .share_workspace(&test.workspace.id, "nobody@appflowy.io", WorkspaceRole::Viewer)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+}
+
+#[actix_rt::test]
+async fn workspace_members_read_by_stranger() {
+    let test = WorkspaceTest::new().await;
+    let stranger = test.server.register_member("nathan@appflowy.io").await;
+    let error = stranger.read_workspace_members(&test.workspace.id).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[actix_rt::test]
+async fn workspace_clone() {
+    let test = ViewTest::new().await;
+    let child_view = create_test_view(&test.server, &test.view.id).await;
+    let trash_view = create_test_view(&test.server, &test.app.id).await;
+    test.server.create_view_trash(&trash_view.id).await;
+    let params = UpdateDocParams {
+        doc_id: child_view.id.clone(),
+        data: r#"[{"insert":"nested text\n"}]"#.to_owned(),
+        rev_id: 1,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+
+    let progress = test.server.clone_workspace(&test.workspace.id).await;
+    assert_eq!(progress.source_workspace_id, test.workspace.id);
+    let progress = test.server.wait_workspace_clone(&progress.clone_id).await;
+    assert_eq!(progress.state, WorkspaceCloneState::Completed);
+    // the app and the view right under it, the nested view is copied with its parent
+    assert_eq!(progress.total, 2);
+    assert_eq!(progress.finished, 2);
+
+    let read_params = WorkspaceIdentifier::new(Some(progress.workspace_id.clone()));
+    let workspace = test.server.read_workspaces(read_params).await.items.pop().unwrap();
+    assert_eq!(workspace.name, format!("{} (copy)", test.workspace.name));
+    assert_eq!(workspace.apps.len(), 1);
+    let app = &workspace.apps.items[0];
+    assert_ne!(app.id, test.app.id);
+    assert_eq!(app.name, test.app.name);
+    assert_eq!(app.belongings.len(), 1);
+    assert_eq!(app.belongings.items[0].name, test.view.name);
+
+    let view_copy = test
+        .server
+        .read_view(app.belongings.items[0].id.clone().into())
+        .await
+        .unwrap();
+    assert_eq!(view_copy.belongings.len(), 1);
+    let doc_identifier = DocIdentifier {
+        doc_id: view_copy.belongings.items[0].id.clone(),
+    };
+    let doc = test.server.read_doc(doc_identifier).await.unwrap();
+    assert_eq!(doc.data, r#"[{"insert":"nested text\n"}]"#);
+
+    // the source workspace is left as it was
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert_eq!(app.belongings.len(), 1);
+}
+
+#[actix_rt::test]
+async fn workspace_clone_by_viewer() {
+    let test = ViewTest::new().await;
+    let viewer = test.server.register_member("nathan@appflowy.io").await;
+    let _ = test
+        .server
+        .share_workspace(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Viewer)
+        .await
+        .unwrap();
+
+    // the viewer owns the copy, so the copy can be changed
+    let progress = viewer.clone_workspace(&test.workspace.id).await;
+    let progress = viewer.wait_workspace_clone(&progress.clone_id).await;
+    assert_eq!(progress.state, WorkspaceCloneState::Completed);
+    let app = create_test_app(&viewer, &progress.workspace_id).await;
+    assert_eq!(app.workspace_id, progress.workspace_id);
+
+    // the progress can only be read by the user who started the clone
+    let error = test.server.read_workspace_clone(&progress.clone_id).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+}
+
+#[actix_rt::test]
+async fn workspace_backup_and_restore() {
+    let test = ViewTest::new().await;
+    let child_view = create_test_view(&test.server, &test.view.id).await;
+    let trash_view = create_test_view(&test.server, &test.app.id).await;
+    test.server.create_view_trash(&trash_view.id).await;
+    let params = CreateAttachmentParams {
+        doc_id: child_view.id.clone(),
+        name: "image.png".to_owned(),
+        size: 1024,
+    };
+    let attachment = test.server.try_create_attachment(params).await.unwrap();
+    let data = format!(
+        r#"[{{"insert":"image.png","attributes":{{"attachment":"{}"}}}},{{"insert":"\n"}}]"#,
+        attachment.id
+    );
+    let params = UpdateDocParams {
+        doc_id: child_view.id.clone(),
+        data,
+        rev_id: 1,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+    let data = format!(
+        r#"[{{"insert":"child","attributes":{{"link":"{}"}}}},{{"insert":"\n"}}]"#,
+        child_view.id
+    );
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data,
+        rev_id: 1,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+
+    let backup = test.server.backup_workspace(&test.workspace.id).await;
+    let json: serde_json::Value = serde_json::from_slice(&backup).unwrap();
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["name"], test.workspace.name.as_str());
+
+    let restore = test.server.restore_workspace(backup).await;
+    let workspace = restore.workspace;
+    assert_ne!(workspace.id, test.workspace.id);
+    assert_eq!(workspace.name, test.workspace.name);
+    assert_eq!(workspace.apps.len(), 1);
+    let app = &workspace.apps.items[0];
+    assert_ne!(app.id, test.app.id);
+    assert_eq!(app.name, test.app.name);
+    // the view in the trash isn't in the backup
+    assert_eq!(app.belongings.len(), 1);
+    let view = &app.belongings.items[0];
+    assert_ne!(view.id, test.view.id);
+    assert_eq!(view.name, test.view.name);
+    assert_eq!(view.belongings.len(), 1);
+    let child_id = view.belongings.items[0].id.clone();
+    assert_ne!(child_id, child_view.id);
+
+    // the link points to the restored child and the attachment is created again under it
+    let doc = test.server.read_doc(DocIdentifier { doc_id: view.id.clone() }).await.unwrap();
+    assert!(doc.data.contains(&child_id));
+    assert_eq!(restore.attachments.len(), 1);
+    let restored = &restore.attachments[0];
+    assert_eq!(restored.backup_id, attachment.id);
+    assert_eq!(restored.doc_id, child_id);
+    let doc = test.server.read_doc(DocIdentifier { doc_id: child_id }).await.unwrap();
+    assert!(doc.data.contains(&restored.attachment_id));
+    assert_eq!(doc.data.contains(&attachment.id), false);
+
+    // the backup can be restored more than once
+    let backup = test.server.backup_workspace(&test.workspace.id).await;
+    let other = test.server.restore_workspace(backup).await;
+    assert_ne!(other.workspace.id, workspace.id);
+}
+
+#[actix_rt::test]
+async fn workspace_restore_invalid_backup() {
+    let test = WorkspaceTest::new().await;
+    let backup = test.server.backup_workspace(&test.workspace.id).await;
+    let mut json: serde_json::Value = serde_json::from_slice(&backup).unwrap();
+    json["version"] = json!(2);
+    let backup = Bytes::from(serde_json::to_vec(&json).unwrap());
+    let error = test.server.try_restore_workspace(backup).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+
+    let error = test
+        .server
+        .try_restore_workspace(Bytes::from_static(b"not a backup"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
 #[actix_rt::test]
 async fn app_create() {
     let test = AppTest::new().await;
     tracing::info!("{:?}", test.app);
 }
 
+#[actix_rt::test]
+async fn app_create_with_idempotency_key() {
+    let test = WorkspaceTest::new().await;
+    let params = || CreateAppParams {
+        workspace_id: test.workspace.id.clone(),
+        name: "My first app".to_string(),
+        desc: "This is my first app".to_string(),
+        color_style: ColorStyle::default(),
+    };
+
+    let app = test.server.create_app_with_idempotency_key(params(), "create-app-1").await;
+    let retried_app = test.server.create_app_with_idempotency_key(params(), "create-app-1").await;
+    assert_eq!(app, retried_app);
+
+    let _ = test.server.create_app_with_idempotency_key(params(), "create-app-2").await;
+    let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let workspaces = test.server.read_workspaces(read_params).await;
+    assert_eq!(workspaces.items.first().unwrap().apps.len(), 2);
+}
+
 #[actix_rt::test]
 async fn app_read() {
     let test = AppTest::new().await;
@@ -106,6 +542,61 @@ async fn app_read_with_belongs_in_trash() {
     assert_eq!(app.belongings.len(), 1);
 }
 
+#[actix_rt::test]
+async fn app_read_in_trash() {
+    let test = AppTest::new().await;
+    let _ = create_test_view(&test.server, &test.app.id).await;
+    test.server.create_app_trash(&test.app.id).await;
+
+    // The app in the trash is read as not found, like the deleted one
+    let read_params = AppIdentifier::new(&test.app.id);
+    assert_eq!(test.server.read_app(read_params).await.is_none(), true);
+
+    let trash = test.server.read_trash().await;
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].ty, TrashType::App);
+}
+
+#[actix_rt::test]
+async fn app_read_belongs_after_cursor() {
+    let test = AppTest::new().await;
+    let mut views = vec![];
+    for _ in 0..3 {
+        views.push(create_test_view(&test.server, &test.app.id).await);
+    }
+
+    // The views have the same name, the cursor reads each of them once
+    let mut after = None;
+    let mut pages = vec![];
+    loop {
+        let mut read_params = AppIdentifier::new(&test.app.id);
+        read_params.view_filter = Some(ListFilter {
+            after: after.clone(),
+            sort_by: Some("name".to_owned()),
+            ..ListFilter::new(2, 0)
+        });
+        let app = test.server.read_app(read_params).await.unwrap();
+        if app.belongings.is_empty() {
+            break;
+        }
+        after = app.belongings.items.last().map(|view| view.id.clone());
+        pages.push(app.belongings.len());
+    }
+    assert_eq!(pages, vec![2, 1]);
+}
+
+#[actix_rt::test]
+async fn app_read_belongs_with_invalid_sort() {
+    let test = AppTest::new().await;
+    let mut read_params = AppIdentifier::new(&test.app.id);
+    read_params.view_filter = Some(ListFilter {
+        sort_by: Some("rank; DROP TABLE view_table".to_owned()),
+        ..ListFilter::new(2, 0)
+    });
+    let error = test.server.try_read_app(read_params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
 #[actix_rt::test]
 async fn app_update() {
     let test = AppTest::new().await;
@@ -120,24 +611,89 @@ async fn app_update() {
     assert_eq!(&app.name, new_name);
 }
 
+#[actix_rt::test]
+async fn app_update_records_device() {
+    let test = AppTest::new().await;
+    let update_params = UpdateAppParams::new(&test.app.id).name("flowy").device_id("device-a");
+    test.server.update_app(update_params).await;
+
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert_eq!(app.modified_device_id, "device-a");
+}
+
 #[actix_rt::test]
 async fn app_delete() {
     let test = AppTest::new().await;
 
-    let delete_params = AppIdentifier {
-        app_id: test.app.id.clone(),
-    };
+    let delete_params = AppIdentifier::new(&test.app.id);
     test.server.delete_app(delete_params).await;
     let read_params = AppIdentifier::new(&test.app.id);
     assert_eq!(test.server.read_app(read_params).await.is_none(), true);
 }
 
+#[actix_rt::test]
+async fn app_move() {
+    let test = WorkspaceTest::new().await;
+    let first_app = test.create_app().await;
+    let second_app = test.create_app().await;
+    let third_app = test.create_app().await;
+    assert!(first_app.rank < second_app.rank && second_app.rank < third_app.rank);
+
+    let params = MoveAppParams {
+        app_id: third_app.id.clone(),
+        rank: rank_between(&first_app.rank, Some(second_app.rank.as_str())),
+    };
+    test.server.move_app(params).await;
+
+    let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let workspaces = test.server.read_workspaces(read_params).await;
+    let app_ids = workspaces.items[0]
+        .apps
+        .iter()
+        .map(|app| app.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(app_ids, vec![first_app.id, third_app.id, second_app.id]);
+}
+
+#[actix_rt::test]
+async fn app_move_with_invalid_rank() {
+    let test = AppTest::new().await;
+    let params = MoveAppParams {
+        app_id: test.app.id.clone(),
+        rank: "V0".to_owned(),
+    };
+    let error = test.server.try_move_app(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
 #[actix_rt::test]
 async fn view_create() {
     let test = ViewTest::new().await;
     tracing::info!("{:?}", test.view);
 }
 
+#[actix_rt::test]
+async fn view_create_with_idempotency_key() {
+    let test = AppTest::new().await;
+    let params = || {
+        CreateViewParams::new(
+            test.app.id.clone(),
+            "My first view".to_owned(),
+            "This is my first view".to_owned(),
+            ViewType::Doc,
+            "http://1.png".to_owned(),
+        )
+    };
+
+    let view = test.server.create_view_with_idempotency_key(params(), "create-view-1").await;
+    let retried_view = test.server.create_view_with_idempotency_key(params(), "create-view-1").await;
+    assert_eq!(view.id, retried_view.id);
+
+    let read_params = AppIdentifier::new(&test.app.id);
+    let app = test.server.read_app(read_params).await.unwrap();
+    assert_eq!(app.belongings.len(), 1);
+}
+
 #[actix_rt::test]
 async fn view_update() {
     let test = ViewTest::new().await;
@@ -153,6 +709,711 @@ async fn view_update() {
     assert_eq!(&view.name, new_name);
 }
 
+#[actix_rt::test]
+async fn view_update_records_device() {
+    let test = ViewTest::new().await;
+    let update_params = UpdateViewParams::new(&test.view.id).name("renamed").device_id("device-a");
+    test.server.update_view(update_params).await;
+
+    let read_params: ViewIdentifier = test.view.id.clone().into();
+    let view = test.server.read_view(read_params).await.unwrap();
+    assert_eq!(view.modified_device_id, "device-a");
+
+    // The view is read with its app too
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert_eq!(app.belongings.items[0].modified_device_id, "device-a");
+}
+
+#[actix_rt::test]
+async fn view_move_to_another_app() {
+    let test = ViewTest::new().await;
+    let other_app = create_test_app(&test.server, &test.workspace.id).await;
+    let other_view = create_test_view(&test.server, &other_app.id).await;
+
+    let params = MoveViewParams {
+        view_id: test.view.id.clone(),
+        belong_to_id: other_app.id.clone(),
+        rank: rank_between("", Some(other_view.rank.as_str())),
+    };
+    test.server.move_view(params).await;
+
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert!(app.belongings.is_empty());
+
+    let other_app = test.server.read_app(AppIdentifier::new(&other_app.id)).await.unwrap();
+    let view_ids = other_app
+        .belongings
+        .iter()
+        .map(|view| view.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(view_ids, vec![test.view.id.clone(), other_view.id]);
+}
+
+#[actix_rt::test]
+async fn view_move_under_its_child() {
+    let test = ViewTest::new().await;
+    let child_view = create_test_view(&test.server, &test.view.id).await;
+
+    let params = MoveViewParams {
+        view_id: test.view.id.clone(),
+        belong_to_id: child_view.id.clone(),
+        rank: rank_between("", None),
+    };
+    let error = test.server.try_move_view(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn view_move_to_another_workspace() {
+    let test = ViewTest::new().await;
+    let other_workspace = create_test_workspace(&test.server).await;
+    let other_app = create_test_app(&test.server, &other_workspace.id).await;
+
+    let params = MoveViewParams {
+        view_id: test.view.id.clone(),
+        belong_to_id: other_app.id.clone(),
+        rank: rank_between("", None),
+    };
+    let error = test.server.try_move_view(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn view_duplicate_with_nested_views() {
+    let test = ViewTest::new().await;
+    let next_view = create_test_view(&test.server, &test.app.id).await;
+    let child_view = create_test_view(&test.server, &test.view.id).await;
+    let params = UpdateDocParams {
+        doc_id: child_view.id.clone(),
+        data: r#"[{"insert":"nested text\n"}]"#.to_owned(),
+        rev_id: 1,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+
+    let copy = test.server.duplicate_view(test.view.id.clone().into()).await;
+    assert_ne!(copy.id, test.view.id);
+    assert_eq!(copy.name, format!("{} (copy)", test.view.name));
+    assert_eq!(copy.belongings.len(), 1);
+    let child_copy = &copy.belongings.items[0];
+    assert_ne!(child_copy.id, child_view.id);
+    assert_eq!(child_copy.belong_to_id, copy.id);
+    assert_eq!(child_copy.name, child_view.name);
+
+    // The copy comes right after the view and has its own doc
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    let view_ids = app.belongings.iter().map(|view| view.id.clone()).collect::<Vec<String>>();
+    assert_eq!(view_ids, vec![test.view.id.clone(), copy.id.clone(), next_view.id]);
+
+    let doc_identifier = DocIdentifier {
+        doc_id: child_copy.id.clone(),
+    };
+    let doc = test.server.read_doc(doc_identifier).await.unwrap();
+    assert_eq!(doc.data, r#"[{"insert":"nested text\n"}]"#);
+}
+
+#[actix_rt::test]
+async fn view_duplicate_skips_trash() {
+    let test = ViewTest::new().await;
+    let child_view = create_test_view(&test.server, &test.view.id).await;
+    test.server.create_view_trash(&child_view.id).await;
+
+    let copy = test.server.duplicate_view(test.view.id.clone().into()).await;
+    assert!(copy.belongings.is_empty());
+}
+
+#[actix_rt::test]
+async fn app_duplicate_with_views() {
+    let test = ViewTest::new().await;
+    let second_view = create_test_view(&test.server, &test.app.id).await;
+    let _ = create_test_view(&test.server, &second_view.id).await;
+    let next_app = create_test_app(&test.server, &test.workspace.id).await;
+
+    let copy = test.server.duplicate_app(AppIdentifier::new(&test.app.id)).await;
+    assert_eq!(copy.name, format!("{} (copy)", test.app.name));
+    assert_eq!(copy.belongings.len(), 2);
+    assert_eq!(copy.belongings.items[1].belongings.len(), 1);
+    assert!(copy.belongings.iter().all(|view| view.belong_to_id == copy.id));
+
+    let read_copy = test.server.read_app(AppIdentifier::new(&copy.id)).await.unwrap();
+    assert_eq!(read_copy.belongings.len(), 2);
+
+    let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let workspaces = test.server.read_workspaces(read_params).await;
+    let app_ids = workspaces.items[0]
+        .apps
+        .iter()
+        .map(|app| app.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(app_ids, vec![test.app.id.clone(), copy.id, next_app.id]);
+}
+
+#[actix_rt::test]
+async fn view_duplicate_by_viewer() {
+    let test = ViewTest::new().await;
+    let viewer = test.server.register_member("nathan@appflowy.io").await;
+    let _ = test
+        .server
+        .share_workspace(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Viewer)
+        .await
+        .unwrap();
+
+    let error = viewer.try_duplicate_view(test.view.id.clone().into()).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+    let error = viewer.try_duplicate_app(AppIdentifier::new(&test.app.id)).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert_eq!(app.belongings.len(), 1);
+}
+
+#[actix_rt::test]
+async fn bot_read_scope() {
+    let test = ViewTest::new().await;
+    let bot_token = test.server.create_bot(&test.workspace.id, BotScope::Read).await;
+    let url = format!("{}/api/view", test.server.http_addr());
+
+    let read_params: ViewIdentifier = test.view.id.clone().into();
+    let view = read_view_request(&bot_token.token, read_params, &url).await.unwrap();
+    assert_eq!(view.unwrap().id, test.view.id);
+
+    let update_params = UpdateViewParams::new(&test.view.id).name("renamed by bot");
+    let error = update_view_request(&bot_token.token, update_params, &url).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn bot_write_scope() {
+    let test = ViewTest::new().await;
+    let bot_token = test.server.create_bot(&test.workspace.id, BotScope::Write).await;
+    let url = format!("{}/api/view", test.server.http_addr());
+
+    let update_params = UpdateViewParams::new(&test.view.id).name("renamed by bot");
+    let _ = update_view_request(&bot_token.token, update_params, &url).await.unwrap();
+
+    // The bot can't manage the workspace
+    let url = format!("{}/api/workspace", test.server.http_addr());
+    let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let error = read_workspaces_request(&bot_token.token, read_params, &url).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn bot_delete() {
+    let test = ViewTest::new().await;
+    let bot_token = test.server.create_bot(&test.workspace.id, BotScope::Read).await;
+    assert_eq!(test.server.read_bots(&test.workspace.id).await.items, vec![bot_token.bot.clone()]);
+
+    test.server.delete_bot(&bot_token.bot.id).await;
+    assert_eq!(test.server.read_bots(&test.workspace.id).await.items.is_empty(), true);
+
+    let url = format!("{}/api/view", test.server.http_addr());
+    let read_params: ViewIdentifier = test.view.id.clone().into();
+    let error = read_view_request(&bot_token.token, read_params, &url).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn api_usage_read() {
+    let test = ViewTest::new().await;
+    let bot_token = test.server.create_bot(&test.workspace.id, BotScope::Read).await;
+    let url = format!("{}/api/view", test.server.http_addr());
+    let read_params: ViewIdentifier = test.view.id.clone().into();
+    let _ = read_view_request(&bot_token.token, read_params, &url).await.unwrap();
+
+    let usage = test.server.read_api_usage(&test.workspace.id, 1).await.unwrap().items;
+    let user_usage = usage.iter().find(|usage| usage.bot_id.is_empty()).unwrap();
+    assert_eq!(user_usage.user_id, test.server.user_id());
+    assert_eq!(user_usage.call_count > 0, true);
+
+    let bot_usage = usage.iter().find(|usage| usage.bot_id == bot_token.bot.id).unwrap();
+    assert_eq!(bot_usage.call_count, 1);
+    assert_eq!(bot_usage.request_bytes > 0, true);
+    assert_eq!(bot_usage.response_bytes > 0, true);
+}
+
+#[actix_rt::test]
+async fn api_usage_read_with_invalid_days() {
+    let test = WorkspaceTest::new().await;
+    let error = test.server.read_api_usage(&test.workspace.id, 0).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn view_publish() {
+    let test = ViewTest::new().await;
+    let _ = create_test_view(&test.server, &test.view.id).await;
+    let published_view = test.server.publish_view(&test.view.id).await;
+    assert_eq!(published_view.slug.starts_with("my-first-workspace"), true);
+    assert_eq!(published_view.slug.ends_with("/my-first-view"), true);
+
+    let html = test.server.read_published_page(&published_view.slug).await.unwrap();
+    assert_eq!(html.contains("<title>My first view</title>"), true);
+
+    // The sub-view has the same name, its slug is made unique within the workspace
+    let sub_slug = format!("{}/my-first-view-2", published_view.slug);
+    assert_eq!(html.contains(&format!("/published/{}", sub_slug)), true);
+    let _ = test.server.read_published_page(&sub_slug).await.unwrap();
+
+    // Publishing again keeps the slugs
+    test.server.unpublish_view(&test.view.id).await;
+    let republished_view = test.server.publish_view(&test.view.id).await;
+    assert_eq!(republished_view.slug, published_view.slug);
+}
+
+// The page is public and served from the api origin, so the links that could run scripts are dropped and
+// the browser is told not to run any
+#[actix_rt::test]
+async fn view_publish_drop_script_links() {
+    let test = ViewTest::new().await;
+    let data = json!([
+        {"insert": "safe", "attributes": {"link": "https://appflowy.io"}},
+        {"insert": " "},
+        {"insert": "evil", "attributes": {"link": "javascript:alert(document.cookie)"}},
+        {"insert": "\n"}
+    ]);
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: data.to_string(),
+        rev_id: 1,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+    let published_view = test.server.publish_view(&test.view.id).await;
+
+    let url = format!("{}/published/{}", test.server.http_addr(), published_view.slug);
+    let response = reqwest::get(&url).await.unwrap();
+    let csp = response.headers().get(CONTENT_SECURITY_POLICY).unwrap().to_str().unwrap();
+    assert_eq!(csp.contains("default-src 'none'"), true);
+    let html = response.text().await.unwrap();
+    assert_eq!(html.contains("<a href=\"https://appflowy.io\">safe</a>"), true);
+    assert_eq!(html.contains("javascript:"), false);
+}
+
+#[actix_rt::test]
+async fn view_publish_read_content() {
+    let test = ViewTest::new().await;
+    let _ = create_test_view(&test.server, &test.view.id).await;
+    let command = json!({
+        "command": "append_markdown",
+        "view_id": test.view.id,
+        "markdown": "# Roadmap\n## Q1\nShip the editor",
+    });
+    test.server.try_run_automation(command).await.unwrap();
+    let slug = test.server.publish_view(&test.view.id).await.slug;
+
+    let response = test.server.read_published_content(&slug, "10.0.0.1", None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CACHE_CONTROL], "public, max-age=60");
+    let etag = response.headers()[ETAG].to_str().unwrap().to_owned();
+
+    let content = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(content["name"], "My first view");
+    assert_eq!(content["content"].as_str().unwrap().contains("<h1>Roadmap</h1>"), true);
+    let outline = content["outline"].as_array().unwrap();
+    assert_eq!(outline.contains(&json!({ "level": 1, "text": "Roadmap" })), true);
+    assert_eq!(outline.contains(&json!({ "level": 2, "text": "Q1" })), true);
+    let sub_slug = format!("{}/my-first-view-2", slug);
+    assert_eq!(content["sub_pages"][0]["slug"], sub_slug);
+
+    // The CDN revalidates the cached content with the etag
+    let response = test.server.read_published_content(&slug, "10.0.0.1", Some(&etag)).await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[actix_rt::test]
+async fn view_publish_read_content_not_found() {
+    let test = ViewTest::new().await;
+    let slug = test.server.publish_view(&test.view.id).await.slug;
+    test.server.unpublish_view(&test.view.id).await;
+
+    let response = test.server.read_published_content(&slug, "10.0.0.2", None).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()[CACHE_CONTROL], "no-store");
+}
+
+#[actix_rt::test]
+async fn view_publish_read_content_rate_limited() {
+    let test = ViewTest::new().await;
+    let slug = test.server.publish_view(&test.view.id).await.slug;
+    for _ in 0..60 {
+        let response = test.server.read_published_content(&slug, "10.0.0.3", None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = test.server.read_published_content(&slug, "10.0.0.3", None).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()[RETRY_AFTER], "60");
+
+    // The other clients are limited separately
+    let response = test.server.read_published_content(&slug, "10.0.0.4", None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[actix_rt::test]
+async fn view_publish_redirect_after_view_renamed() {
+    let test = ViewTest::new().await;
+    let sub_view = create_test_view(&test.server, &test.view.id).await;
+    let published_view = test.server.publish_view(&test.view.id).await;
+    let old_sub_slug = format!("{}/my-first-view-2", published_view.slug);
+
+    let update_params = UpdateViewParams::new(&sub_view.id).name("Roadmap");
+    test.server.update_view(update_params).await;
+
+    let sub_slug = format!("{}/roadmap", published_view.slug);
+    let html = test.server.read_published_page(&published_view.slug).await.unwrap();
+    assert_eq!(html.contains(&format!("/published/{}", sub_slug)), true);
+    let html = test.server.read_published_page(&sub_slug).await.unwrap();
+    assert_eq!(html.contains("<title>Roadmap</title>"), true);
+
+    // The old slug is redirected to the new one
+    let html = test.server.read_published_page(&old_sub_slug).await.unwrap();
+    assert_eq!(html.contains("<title>Roadmap</title>"), true);
+}
+
+#[actix_rt::test]
+async fn view_publish_redirect_after_workspace_renamed() {
+    let test = ViewTest::new().await;
+    let published_view = test.server.publish_view(&test.view.id).await;
+    let update_params = UpdateWorkspaceParams {
+        id: test.workspace.id.clone(),
+        name: Some("Team wiki".to_string()),
+        desc: None,
+    };
+    test.server.update_workspace(update_params).await;
+
+    let html = test.server.read_published_page(&published_view.slug).await.unwrap();
+    assert_eq!(html.contains("<title>My first view</title>"), true);
+
+    let view_slug = published_view.slug.split('/').last().unwrap().to_owned();
+    let error = test
+        .server
+        .read_published_page(&format!("unknown-workspace/{}", view_slug))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+}
+
+#[actix_rt::test]
+async fn view_publish_access_log() {
+    let test = ViewTest::new().await;
+    let published_view = test.server.publish_view(&test.view.id).await;
+    for _ in 0..2 {
+        let _ = test.server.read_published_page(&published_view.slug).await.unwrap();
+    }
+
+    let access_logs = test.server.read_view_access_log(&test.view.id).await.unwrap().items;
+    assert_eq!(access_logs.len(), 1);
+    assert_eq!(access_logs[0].read_count, 2);
+
+    // Only the publisher can read the access log
+    let bot_token = test.server.create_bot(&test.workspace.id, BotScope::Read).await;
+    let url = format!("{}/api/view/publish/access", test.server.http_addr());
+    let params: ViewIdentifier = test.view.id.clone().into();
+    let error = read_view_access_log_request(&bot_token.token, params, &url).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn view_publish_render_after_update() {
+    let test = ViewTest::new().await;
+    let published_view = test.server.publish_view(&test.view.id).await;
+    test.server
+        .update_view(UpdateViewParams::new(&test.view.id).name("Release notes"))
+        .await;
+
+    // The page is rendered again after the view stops changing
+    actix_rt::time::sleep(Duration::from_secs(4)).await;
+    let html = test.server.read_published_page(&published_view.slug).await.unwrap();
+    assert_eq!(html.contains("<title>Release notes</title>"), true);
+}
+
+#[actix_rt::test]
+async fn view_unpublish() {
+    let test = ViewTest::new().await;
+    let published_view = test.server.publish_view(&test.view.id).await;
+    test.server.unpublish_view(&test.view.id).await;
+
+    let error = test.server.read_published_page(&published_view.slug).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+}
+
+#[actix_rt::test]
+async fn view_publish_pin() {
+    let test = ViewTest::new().await;
+    let published_view = test.server.publish_view(&test.view.id).await;
+    let pinned_view = test.server.pin_published_view(&test.view.id, published_view.rev_id).await;
+    assert_eq!(pinned_view.is_pinned, true);
+
+    let command = json!({
+        "command": "append_markdown",
+        "view_id": test.view.id,
+        "markdown": "Private draft",
+    });
+    test.server.try_run_automation(command).await.unwrap();
+
+    // The pinned page ignores the changes until the latest revision is published explicitly
+    actix_rt::time::sleep(Duration::from_secs(4)).await;
+    let html = test.server.read_published_page(&published_view.slug).await.unwrap();
+    assert_eq!(html.contains("Private draft"), false);
+
+    let latest_view = test.server.publish_latest_view(&test.view.id).await;
+    assert_eq!(latest_view.slug, published_view.slug);
+    assert_eq!(latest_view.is_pinned, false);
+    assert_eq!(latest_view.rev_id > published_view.rev_id, true);
+    let html = test.server.read_published_page(&published_view.slug).await.unwrap();
+    assert_eq!(html.contains("Private draft"), true);
+}
+
+#[actix_rt::test]
+async fn view_publish_pin_with_unknown_revision() {
+    let test = ViewTest::new().await;
+    let _ = test.server.publish_view(&test.view.id).await;
+    let error = test.server.try_pin_published_view(&test.view.id, 100).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn view_share_link() {
+    let test = ViewTest::new().await;
+    let share_link = test.server.create_share_link(&test.view.id, "").await;
+    assert_eq!(share_link.has_passcode, false);
+    assert_eq!(share_link.expire_time, 0);
+
+    assert_eq!(share_link.slug.ends_with(&format!("/my-first-view/{}", share_link.link_id)), true);
+    let html = test.server.read_shared_page(&share_link.link_id, "").await.unwrap();
+    assert_eq!(html.contains("<title>My first view</title>"), true);
+}
+
+#[actix_rt::test]
+async fn view_share_link_with_passcode() {
+    let test = ViewTest::new().await;
+    let share_link = test.server.create_share_link(&test.view.id, "123456").await;
+    assert_eq!(share_link.has_passcode, true);
+
+    let link_id = share_link.link_id;
+    let error = test.server.read_shared_page(&link_id, "").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+    let error = test.server.read_shared_page(&link_id, "654321").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+
+    let html = test.server.read_shared_page(&link_id, "123456").await.unwrap();
+    assert_eq!(html.contains("<title>My first view</title>"), true);
+
+    // The passcode is kept when the old slug is redirected
+    let update_params = UpdateViewParams::new(&test.view.id).name("Roadmap");
+    test.server.update_view(update_params).await;
+    let html = test.server.read_shared_page(&share_link.slug, "123456").await.unwrap();
+    assert_eq!(html.contains("<title>Roadmap</title>"), true);
+}
+
+#[actix_rt::test]
+async fn view_share_link_expire() {
+    let test = ViewTest::new().await;
+    let expire_time = chrono::Utc::now().timestamp() + 2;
+    let share_link = test
+        .server
+        .try_create_share_link(&test.view.id, expire_time, "")
+        .await
+        .unwrap();
+    assert_eq!(share_link.expire_time, expire_time);
+    let _ = test.server.read_shared_page(&share_link.link_id, "").await.unwrap();
+
+    // The expired link is neither readable nor listed
+    actix_rt::time::sleep(Duration::from_secs(3)).await;
+    let error = test.server.read_shared_page(&share_link.link_id, "").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+    assert_eq!(test.server.read_share_links(&test.workspace.id).await.items.is_empty(), true);
+
+    let error = test
+        .server
+        .try_create_share_link(&test.view.id, expire_time, "")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn view_share_link_revoke() {
+    let test = ViewTest::new().await;
+    let first_link = test.server.create_share_link(&test.view.id, "").await;
+    let second_link = test.server.create_share_link(&test.view.id, "123456").await;
+
+    let share_links = test.server.read_share_links(&test.workspace.id).await.items;
+    assert_eq!(share_links, vec![second_link.clone(), first_link.clone()]);
+
+    test.server.revoke_share_link(&first_link.link_id).await;
+    let share_links = test.server.read_share_links(&test.workspace.id).await.items;
+    assert_eq!(share_links, vec![second_link]);
+
+    let error = test.server.read_shared_page(&first_link.link_id, "").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound);
+}
+
+#[actix_rt::test]
+async fn view_webhook_deliver_after_update() {
+    let test = ViewTest::new().await;
+    let (url, receiver) = spawn_mock_webhook();
+    let webhook = test
+        .server
+        .set_view_webhook(&test.view.id, &url, WebhookFormat::Markdown, "webhook-secret")
+        .await;
+    assert_eq!(webhook.url, url);
+    assert_eq!(webhook.has_secret, true);
+
+    let command = json!({
+        "command": "append_markdown",
+        "view_id": test.view.id,
+        "markdown": "# Changelog",
+    });
+    test.server.try_run_automation(command).await.unwrap();
+
+    // The doc is delivered once after it stops changing
+    actix_rt::time::sleep(Duration::from_secs(4)).await;
+    let deliveries = receiver.deliveries();
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].event, "changed");
+    assert_eq!(deliveries[0].view_id, test.view.id);
+    assert_eq!(deliveries[0].format, "markdown");
+    assert_eq!(deliveries[0].content.contains("# Changelog"), true);
+    assert_eq!(deliveries[0].authorization.as_deref(), Some("Bearer webhook-secret"));
+
+    let webhook = test.server.read_view_webhook(&test.view.id).await;
+    assert_eq!(webhook.last_status, 200);
+    assert_eq!(webhook.last_delivery_time > 0, true);
+}
+
+#[actix_rt::test]
+async fn view_webhook_deliver_after_publish() {
+    let test = ViewTest::new().await;
+    let (url, receiver) = spawn_mock_webhook();
+    let _ = test
+        .server
+        .set_view_webhook(&test.view.id, &url, WebhookFormat::Html, "")
+        .await;
+    let _ = test.server.publish_view(&test.view.id).await;
+
+    actix_rt::time::sleep(Duration::from_secs(1)).await;
+    let deliveries = receiver.deliveries();
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].event, "published");
+    assert_eq!(deliveries[0].format, "html");
+    assert_eq!(deliveries[0].authorization, None);
+}
+
+#[actix_rt::test]
+async fn view_webhook_with_invalid_url() {
+    let test = ViewTest::new().await;
+    let error = test
+        .server
+        .try_set_view_webhook(&test.view.id, "ftp://127.0.0.1/hook", WebhookFormat::Markdown, "")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn view_webhook_delete() {
+    let test = ViewTest::new().await;
+    let (url, receiver) = spawn_mock_webhook();
+    let _ = test
+        .server
+        .set_view_webhook(&test.view.id, &url, WebhookFormat::Markdown, "")
+        .await;
+    test.server.delete_view_webhook(&test.view.id).await;
+    assert_eq!(test.server.read_view_webhook(&test.view.id).await.url.is_empty(), true);
+
+    let _ = test.server.publish_view(&test.view.id).await;
+    actix_rt::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(receiver.deliveries().is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn view_favorite() {
+    let test = ViewTest::new().await;
+    let view = create_test_view(&test.server, &test.app.id).await;
+    test.server.update_favorite(&test.view.id, true).await;
+    actix_rt::time::sleep(Duration::from_secs(1)).await;
+    test.server.update_favorite(&view.id, true).await;
+    // pinning the view again doesn't move it
+    test.server.update_favorite(&test.view.id, true).await;
+
+    let view_ids = test
+        .server
+        .read_favorites()
+        .await
+        .items
+        .into_iter()
+        .map(|favorite| favorite.view.id)
+        .collect::<Vec<String>>();
+    assert_eq!(view_ids, vec![view.id.clone(), test.view.id.clone()]);
+
+    test.server.update_favorite(&view.id, false).await;
+    let favorites = test.server.read_favorites().await.items;
+    assert_eq!(favorites.len(), 1);
+    assert_eq!(favorites[0].view.id, test.view.id);
+}
+
+#[actix_rt::test]
+async fn view_favorite_skips_trash() {
+    let test = ViewTest::new().await;
+    test.server.update_favorite(&test.view.id, true).await;
+    test.server.create_view_trash(&test.view.id).await;
+    assert_eq!(test.server.read_favorites().await.items.is_empty(), true);
+
+    let identifier = TrashIdentifier {
+        id: test.view.id.clone(),
+        ty: TrashType::View,
+    };
+    test.server.delete_view_trash(vec![identifier].into()).await;
+    test.server.update_favorite(&test.view.id, false).await;
+    assert_eq!(test.server.read_favorites().await.items.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn view_recent_views() {
+    let test = ViewTest::new().await;
+    let view = create_test_view(&test.server, &test.app.id).await;
+    test.server.update_recent_view(&test.view.id, 1000).await;
+    test.server.update_recent_view(&view.id, 2000).await;
+    // the open time that arrives late doesn't move the view back
+    test.server.update_recent_view(&view.id, 1500).await;
+
+    let recent_views = test.server.read_recent_views(0).await.items;
+    let view_ids = recent_views
+        .iter()
+        .map(|recent_view| recent_view.view.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(view_ids, vec![view.id.clone(), test.view.id.clone()]);
+    assert_eq!(recent_views[0].open_time, 2000);
+    assert_eq!(recent_views[0].app_id, test.app.id);
+    assert_eq!(recent_views[0].app_name, test.app.name);
+    assert_eq!(recent_views[0].workspace_id, test.workspace.id);
+    assert_eq!(recent_views[0].workspace_name, test.workspace.name);
+
+    let recent_views = test.server.read_recent_views(1).await.items;
+    assert_eq!(recent_views.len(), 1);
+    assert_eq!(recent_views[0].view.id, view.id);
+
+    let error = test.server.try_read_recent_views(1000).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn view_recent_views_skips_trash() {
+    let test = ViewTest::new().await;
+    test.server.update_recent_view(&test.view.id, 1000).await;
+    test.server.create_view_trash(&test.view.id).await;
+    assert_eq!(test.server.read_recent_views(0).await.items.is_empty(), true);
+
+    let identifier = TrashIdentifier {
+        id: test.view.id.clone(),
+        ty: TrashType::View,
+    };
+    test.server.delete_view_trash(vec![identifier].into()).await;
+    assert_eq!(test.server.read_recent_views(0).await.items.is_empty(), true);
+}
+
 #[actix_rt::test]
 async fn view_delete() {
     let test = ViewTest::new().await;
@@ -214,3 +1475,68 @@ async fn workspace_list_read() {
     let workspaces = server.read_workspaces(read_params).await;
     assert_eq!(workspaces.len(), 4);
 }
+
+#[actix_rt::test]
+async fn workspace_search() {
+    let test = ViewTest::new().await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"Meeting notes about the quarterly roadmap\n"}]"#.to_owned(),
+        rev_id: 1,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+
+    let results = test.server.search_workspace(&test.workspace.id, "roadm").await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].view_id, test.view.id);
+    assert_eq!(results[0].snippet.contains("<b>roadmap</b>"), true);
+
+    let results = test.server.search_workspace(&test.workspace.id, "first view").await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].view_name, test.view.name);
+
+    // The views in the trash are not searchable
+    test.server.create_view_trash(&test.view.id).await;
+    assert_eq!(test.server.search_workspace(&test.workspace.id, "roadmap").await.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_search_phrase() {
+    let test = ViewTest::new().await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"The quick brown fox jumps over the lazy dog\n"}]"#.to_owned(),
+        rev_id: 1,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+
+    let results = test.server.search_workspace(&test.workspace.id, r#""brown fox""#).await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].snippet.contains("<b>brown</b> <b>fox</b>"), true);
+
+    // The words of the phrase must be next to each other in the same order
+    let results = test.server.search_workspace(&test.workspace.id, r#""fox brown""#).await;
+    assert_eq!(results.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_search_fuzzy() {
+    let test = ViewTest::new().await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: r#"[{"insert":"Meeting notes about the quarterly roadmap\n"}]"#.to_owned(),
+        rev_id: 1,
+        ..Default::default()
+    };
+    test.server.update_doc(params).await;
+
+    // The typo is tolerated in the long words
+    let results = test.server.search_workspace(&test.workspace.id, "quartelry meeting").await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].snippet.contains("<b>quarterly</b>"), true);
+
+    let results = test.server.search_workspace(&test.workspace.id, "qurterly roadmap").await;
+    assert_eq!(results.len(), 1);
+}
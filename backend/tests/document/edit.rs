@@ -201,3 +201,222 @@ async fn delta_sync_while_local_rev_greater_than_server_rev() {
     ])
     .await;
 }
+
+#[actix_rt::test]
+async fn delta_reset_with_server_document() {
+    let test = DocumentTest::new().await;
+    let mut document = Document::new::<FlowyDoc>();
+    document.insert(0, "123").unwrap();
+    let json = document.to_json();
+
+    test.run_scripts(vec![
+        DocScript::ClientOpenDoc,
+        DocScript::ServerSaveDocument(json, 1),
+        DocScript::ClientResetDoc,
+        DocScript::AssertClient(r#"[{"insert":"123\n"}]"#),
+    ])
+    .await;
+}
+
+#[actix_rt::test]
+async fn delta_reset_rebase_pending_changes() {
+    let test = DocumentTest::new().await;
+    let mut document = Document::new::<FlowyDoc>();
+    document.insert(0, "123").unwrap();
+    let json = document.to_json();
+
+    test.run_scripts(vec![
+        DocScript::ServerSaveDocument(json, 1),
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(3, "abc"),
+        DocScript::ClientResetDoc,
+        DocScript::AssertClient(r#"[{"insert":"123abc\n"}]"#),
+    ])
+    .await;
+}
+
+// The server document was changed while the client was editing offline, the pending changes of the client
+// are rebased on the server document instead of being discarded.
+#[actix_rt::test]
+async fn delta_reset_rebase_concurrent_pending_changes() {
+    let test = DocumentTest::new().await;
+    let mut document = Document::new::<FlowyDoc>();
+    document.insert(0, "123").unwrap();
+    let json = document.to_json();
+    document.insert(3, "45").unwrap();
+    let server_json = document.to_json();
+
+    test.run_scripts(vec![
+        DocScript::ServerSaveDocument(json, 1),
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(3, "abc"),
+        DocScript::ServerSaveDocument(server_json, 2),
+        DocScript::ClientResetDoc,
+        DocScript::AssertClient(r#"[{"insert":"12345abc\n"}]"#),
+    ])
+    .await;
+}
+
+// The changes that were made offline are synced once the websocket connects, even if the document was
+// closed before.
+#[actix_rt::test]
+async fn delta_sync_offline_changes_after_connect() {
+    let test = DocumentTest::new().await;
+    test.run_scripts(vec![
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(0, "abc"),
+        DocScript::ClientInsertText(3, "123"),
+        DocScript::Wait(500),
+        DocScript::ClientCloseDoc,
+        DocScript::ClientConnectWs,
+        DocScript::Wait(500),
+        DocScript::AssertServer(r#"[{"insert":"abc123\n"}]"#, 2),
+    ])
+    .await;
+}
+
+// Another user inserts "123" at the same time as the client inserts "abc". The server transforms the
+// later revision against the earlier one, and both users end up with the same document.
+#[actix_rt::test]
+async fn delta_sync_concurrent_revisions_of_two_users() {
+    let test = DocumentTest::new().await;
+    test.run_scripts(vec![
+        DocScript::ClientConnectWs,
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(0, "abc"),
+        DocScript::AssertServer(r#"[{"insert":"abc\n"}]"#, 1),
+        DocScript::OtherUserSendRevision(r#"[{"insert":"123"},{"retain":1}]"#, 0),
+        DocScript::AssertServer(r#"[{"insert":"abc123\n"}]"#, 2),
+        DocScript::AssertClient(r#"[{"insert":"abc123\n"}]"#),
+    ])
+    .await;
+}
+
+// The other user keeps typing before its first revision is pushed back. Its second revision is based on its
+// own rev_id 1, which the server stored as 3 after transforming it against the client's "abc" and "def", so
+// those changes must not be applied to it twice.
+#[actix_rt::test]
+async fn delta_sync_concurrent_revisions_while_typing() {
+    let test = DocumentTest::new().await;
+    test.run_scripts(vec![
+        DocScript::ClientConnectWs,
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(0, "abc"),
+        DocScript::AssertServer(r#"[{"insert":"abc\n"}]"#, 1),
+        DocScript::ClientInsertText(3, "def"),
+        DocScript::AssertServer(r#"[{"insert":"abcdef\n"}]"#, 2),
+        DocScript::OtherUserSendRevision(r#"[{"insert":"1"},{"retain":1}]"#, 0),
+        DocScript::OtherUserSendRevision(r#"[{"retain":1},{"insert":"2"},{"retain":1}]"#, 1),
+        DocScript::AssertServer(r#"[{"insert":"abcdef12\n"}]"#, 4),
+        DocScript::ClientInsertText(8, "g"),
+        DocScript::AssertServer(r#"[{"insert":"abcdef12g\n"}]"#, 5),
+        DocScript::AssertClient(r#"[{"insert":"abcdef12g\n"}]"#),
+    ])
+    .await;
+}
+
+// The snapshot is restored while the client is editing the document, the client receives the restored
+// content instead of overwriting it with its own.
+#[actix_rt::test]
+async fn doc_restore_snapshot_while_editing() {
+    let test = DocumentTest::new().await;
+    let mut document = Document::new::<FlowyDoc>();
+    document.insert(0, "123").unwrap();
+    let json = document.to_json();
+
+    test.run_scripts(vec![
+        DocScript::ServerSaveDocument(json, 50),
+        DocScript::ClientOpenDoc,
+        DocScript::ClientConnectWs,
+        DocScript::ClientInsertText(3, "abc"),
+        DocScript::AssertServer(r#"[{"insert":"123abc\n"}]"#, 51),
+        DocScript::ServerRestoreLatestSnapshot,
+        DocScript::AssertServer(r#"[{"insert":"123\n"}]"#, 52),
+        DocScript::AssertClient(r#"[{"insert":"123\n"}]"#),
+        DocScript::ClientInsertText(3, "d"),
+        DocScript::AssertServer(r#"[{"insert":"123d\n"}]"#, 53),
+    ])
+    .await;
+}
+
+// Another user moves the cursor in the same document, the client receives its selection. The cursor is
+// removed after the other user closes the document.
+#[actix_rt::test]
+async fn doc_presence_of_other_user() {
+    let test = DocumentTest::new().await;
+    test.run_scripts(vec![
+        DocScript::ClientConnectWs,
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(0, "abc"),
+        DocScript::OtherUserSendPresence(1, 2, false),
+        DocScript::AssertClientPresences(vec![(1, 2)]),
+        DocScript::OtherUserSendPresence(3, 3, false),
+        DocScript::AssertClientPresences(vec![(3, 3)]),
+        DocScript::OtherUserSendPresence(0, 0, true),
+        DocScript::AssertClientPresences(vec![]),
+    ])
+    .await;
+}
+
+// The typing indicator of the other user is shown until it stops typing, or until the signal expires if
+// it isn't refreshed.
+#[actix_rt::test]
+async fn doc_typing_of_other_user() {
+    let test = DocumentTest::new().await;
+    test.run_scripts(vec![
+        DocScript::ClientConnectWs,
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(0, "abc"),
+        DocScript::OtherUserSendTyping(true),
+        DocScript::AssertClientTypingUsers(vec!["other_user"]),
+        DocScript::OtherUserSendTyping(false),
+        DocScript::AssertClientTypingUsers(vec![]),
+        DocScript::OtherUserSendTyping(true),
+        DocScript::AssertClientTypingUsers(vec!["other_user"]),
+        DocScript::Wait(6500),
+        DocScript::AssertClientTypingUsers(vec![]),
+    ])
+    .await;
+}
+
+// The changes that were made offline before restarting are synced once the websocket connects, without
+// opening the document again.
+#[actix_rt::test]
+async fn delta_sync_offline_changes_after_restart() {
+    let test = DocumentTest::new().await;
+    test.run_scripts(vec![
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(0, "abc"),
+        DocScript::ClientInsertText(3, "123"),
+        DocScript::Wait(500),
+        DocScript::ClientRestart,
+        DocScript::Wait(500),
+        DocScript::ClientConnectWs,
+        DocScript::Wait(500),
+        DocScript::AssertServer(r#"[{"insert":"abc123\n"}]"#, 2),
+    ])
+    .await;
+}
+
+// The revisions after the saved document are stored as deltas, the document at each of them is composed
+// from the saved document.
+#[actix_rt::test]
+async fn doc_read_revision_composed_from_deltas() {
+    let test = DocumentTest::new().await;
+    let mut document = Document::new::<FlowyDoc>();
+    document.insert(0, "123").unwrap();
+    let json = document.to_json();
+
+    test.run_scripts(vec![
+        DocScript::ServerSaveDocument(json, 50),
+        DocScript::OtherUserSendRevision(r#"[{"retain":3},{"insert":"4"},{"retain":1}]"#, 50),
+        DocScript::OtherUserSendRevision(r#"[{"retain":4},{"insert":"5"},{"retain":1}]"#, 51),
+        DocScript::OtherUserSendRevision(r#"[{"delete":1},{"retain":5}]"#, 52),
+        DocScript::AssertServer(r#"[{"insert":"2345\n"}]"#, 53),
+        DocScript::AssertServerRevision(r#"[{"insert":"123\n"}]"#, 50),
+        DocScript::AssertServerRevision(r#"[{"insert":"1234\n"}]"#, 51),
+        DocScript::AssertServerRevision(r#"[{"insert":"12345\n"}]"#, 52),
+        DocScript::AssertServerRevision(r#"[{"insert":"2345\n"}]"#, 53),
+    ])
+    .await;
+}
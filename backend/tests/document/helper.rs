@@ -1,5 +1,14 @@
+use actix::{Actor, Context, Handler};
 use actix_web::web::Data;
-use backend::service::doc::{crud::update_doc, doc::DocManager};
+use backend::service::{
+    doc::{
+        crud::{read_doc_at_revision, update_doc},
+        doc::DocManager,
+        snapshot::{read_doc_snapshots, restore_doc_snapshot},
+    },
+    user::LoggedUser,
+    ws::{WsMessageAdaptor, WsUser},
+};
 use backend_service::config::ServerConfig;
 use flowy_document::services::doc::ClientEditDoc as ClientEditDocContext;
 use flowy_test::{workspace::ViewTest, FlowyTest};
@@ -10,7 +19,18 @@ use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 // use crate::helper::*;
 use crate::util::helper::{spawn_server, TestServer};
-use flowy_document_infra::{entities::doc::DocIdentifier, protobuf::UpdateDocParams};
+use flowy_document_infra::{
+    entities::doc::DocIdentifier,
+    protobuf::{
+        DocIdentifier as DocIdentifierPB,
+        DocPresence,
+        DocSnapshotIdentifier,
+        DocTyping,
+        RevType,
+        Revision,
+        UpdateDocParams,
+    },
+};
 use lib_ot::core::{Attribute, Delta, Interval};
 use parking_lot::RwLock;
 
@@ -24,9 +44,20 @@ pub enum DocScript {
     ClientInsertText(usize, &'static str),
     ClientFormatText(Interval, Attribute),
     ClientOpenDoc,
+    ClientCloseDoc,
+    ClientResetDoc,
+    ClientRestart,
     AssertClient(&'static str),
     AssertServer(&'static str, i64),
-    ServerSaveDocument(String, i64), // delta_json, rev_id
+    AssertServerRevision(&'static str, i64), // delta_json, rev_id
+    ServerSaveDocument(String, i64),          // delta_json, rev_id
+    ServerRestoreLatestSnapshot,
+    OtherUserSendRevision(&'static str, i64), // delta_json, base_rev_id
+    OtherUserSendPresence(i64, i64, bool),    // selection_start, selection_end, is_closed
+    AssertClientPresences(Vec<(i64, i64)>),   // the selections of the other users
+    OtherUserSendTyping(bool),                // is_typing
+    AssertClientTypingUsers(Vec<&'static str>),
+    Wait(u64),                                // milliseconds
 }
 
 impl DocumentTest {
@@ -50,6 +81,8 @@ impl DocumentTest {
 struct ScriptContext {
     client_edit_context: Option<Arc<ClientEditDocContext>>,
     flowy_test: FlowyTest,
+    // The SDKs before the restarts, they are kept alive until the end of the test
+    previous_tests: Vec<FlowyTest>,
     client_user_session: Arc<UserSession>,
     server_doc_manager: Arc<DocManager>,
     server_pg_pool: Data<PgPool>,
@@ -64,6 +97,7 @@ impl ScriptContext {
         Self {
             client_edit_context: None,
             flowy_test,
+            previous_tests: vec![],
             client_user_session: user_session,
             server_doc_manager: server.app_ctx.doc_biz.manager.clone(),
             server_pg_pool: Data::new(server.pg_pool.clone()),
@@ -79,6 +113,15 @@ impl ScriptContext {
         self.client_edit_context = Some(edit_context);
     }
 
+    // The opened document isn't closed, it's dropped with the SDK like the app that is killed
+    fn restart(&mut self) {
+        let flowy_test = self.flowy_test.restart();
+        self.client_user_session = flowy_test.sdk.user_session.clone();
+        self.client_edit_context = None;
+        let previous_test = std::mem::replace(&mut self.flowy_test, flowy_test);
+        self.previous_tests.push(previous_test);
+    }
+
     fn client_edit_context(&self) -> Arc<ClientEditDocContext> { self.client_edit_context.as_ref().unwrap().clone() }
 }
 
@@ -104,6 +147,17 @@ async fn run_scripts(context: Arc<RwLock<ScriptContext>>, scripts: Vec<DocScript
                 DocScript::ClientOpenDoc => {
                     context.write().open_doc().await;
                 },
+                DocScript::ClientCloseDoc => {
+                    let flowy_document = context.read().flowy_test.sdk.flowy_document.clone();
+                    let _ = flowy_document.close(DocIdentifier { doc_id }).await.unwrap();
+                },
+                DocScript::ClientResetDoc => {
+                    let flowy_document = context.read().flowy_test.sdk.flowy_document.clone();
+                    let _ = flowy_document.reset(DocIdentifier { doc_id }).await.unwrap();
+                },
+                DocScript::ClientRestart => {
+                    context.write().restart();
+                },
                 DocScript::ClientInsertText(index, s) => {
                     context.read().client_edit_context().insert(index, s).await.unwrap();
                 },
@@ -129,10 +183,81 @@ async fn run_scripts(context: Arc<RwLock<ScriptContext>>, scripts: Vec<DocScript
                     let json = edit_doc.document_json().await.unwrap();
                     assert_eq(s, &json);
                 },
+                DocScript::AssertServerRevision(s, rev_id) => {
+                    sleep(Duration::from_millis(100)).await;
+                    let pg_pool = context.read().server_pg_pool.clone();
+                    let doc = read_doc_at_revision(pg_pool.get_ref(), &doc_id, rev_id).await.unwrap();
+                    assert_eq(s, &doc.data);
+                },
                 DocScript::ServerSaveDocument(json, rev_id) => {
                     let pg_pool = context.read().server_pg_pool.clone();
                     save_doc(&doc_id, json, rev_id, pg_pool).await;
                 },
+                DocScript::ServerRestoreLatestSnapshot => {
+                    let pg_pool = context.read().server_pg_pool.clone();
+                    let doc_manager = context.read().server_doc_manager.clone();
+                    let params = DocIdentifierPB {
+                        doc_id: doc_id.clone(),
+                        ..Default::default()
+                    };
+                    let snapshots = read_doc_snapshots(pg_pool.get_ref(), params).await.unwrap();
+                    let params = DocSnapshotIdentifier {
+                        doc_id: doc_id.clone(),
+                        snapshot_id: snapshots.items[0].id,
+                        ..Default::default()
+                    };
+                    let _ = restore_doc_snapshot(pg_pool, &doc_manager, params).await.unwrap();
+                },
+                DocScript::OtherUserSendRevision(delta_json, base_rev_id) => {
+                    let pg_pool = context.read().server_pg_pool.clone();
+                    let doc_manager = context.read().server_doc_manager.clone();
+                    let edit_doc = doc_manager.get(&doc_id, pg_pool).await.unwrap().unwrap();
+                    let user = Arc::new(WsUser::new(LoggedUser::new("other_user")));
+                    let socket = OtherUserSocket.start().recipient();
+                    let revision = mk_revision(&doc_id, delta_json, base_rev_id);
+                    edit_doc.apply_revision(user, socket, revision).await.unwrap();
+                },
+                DocScript::OtherUserSendPresence(selection_start, selection_end, is_closed) => {
+                    let pg_pool = context.read().server_pg_pool.clone();
+                    let doc_manager = context.read().server_doc_manager.clone();
+                    let edit_doc = doc_manager.get(&doc_id, pg_pool).await.unwrap().unwrap();
+                    let user = Arc::new(WsUser::new(LoggedUser::new("other_user")));
+                    let socket = OtherUserSocket.start().recipient();
+                    let mut presence = DocPresence::new();
+                    presence.set_doc_id(doc_id.clone());
+                    presence.set_user_id("other_user".to_owned());
+                    presence.set_selection_start(selection_start);
+                    presence.set_selection_end(selection_end);
+                    presence.set_is_closed(is_closed);
+                    edit_doc.update_presence(user, socket, presence).await.unwrap();
+                },
+                DocScript::AssertClientPresences(selections) => {
+                    sleep(Duration::from_millis(100)).await;
+                    let presences = context.read().client_edit_context().remote_presences();
+                    let received = presences
+                        .into_iter()
+                        .map(|presence| (presence.selection_start, presence.selection_end))
+                        .collect::<Vec<(i64, i64)>>();
+                    assert_eq!(received, selections);
+                },
+                DocScript::OtherUserSendTyping(is_typing) => {
+                    let pg_pool = context.read().server_pg_pool.clone();
+                    let doc_manager = context.read().server_doc_manager.clone();
+                    let edit_doc = doc_manager.get(&doc_id, pg_pool).await.unwrap().unwrap();
+                    let mut typing = DocTyping::new();
+                    typing.set_doc_id(doc_id.clone());
+                    typing.set_user_id("other_user".to_owned());
+                    typing.set_is_typing(is_typing);
+                    edit_doc.push_typing(typing).await.unwrap();
+                },
+                DocScript::AssertClientTypingUsers(user_ids) => {
+                    sleep(Duration::from_millis(100)).await;
+                    let typing_users = context.read().client_edit_context().typing_users();
+                    assert_eq!(typing_users, user_ids);
+                },
+                DocScript::Wait(millis) => {
+                    sleep(Duration::from_millis(millis)).await;
+                },
             }
         };
         fut_scripts.push(fut);
@@ -168,5 +293,31 @@ async fn save_doc(doc_id: &str, json: String, rev_id: i64, pool: Data<PgPool>) {
     params.set_doc_id(doc_id.to_owned());
     params.set_data(json);
     params.set_rev_id(rev_id);
-    let _ = update_doc(pool.get_ref(), params).await.unwrap();
+    let _ = update_doc(pool.get_ref(), params, None, None).await.unwrap();
+}
+
+fn mk_revision(doc_id: &str, delta_json: &str, base_rev_id: i64) -> Revision {
+    let delta_data = Delta::from_json(delta_json).unwrap().to_bytes().to_vec();
+    let mut revision = Revision::new();
+    revision.set_doc_id(doc_id.to_owned());
+    revision.set_base_rev_id(base_rev_id);
+    revision.set_rev_id(base_rev_id + 1);
+    revision.set_md5(format!("{:x}", md5::compute(&delta_data)));
+    revision.set_delta_data(delta_data);
+    revision.set_ty(RevType::Local);
+    revision
+}
+
+// Stands for the websocket of another user who edits the same document. The messages that the server
+// sends to it are dropped.
+struct OtherUserSocket;
+
+impl Actor for OtherUserSocket {
+    type Context = Context<Self>;
+}
+
+impl Handler<WsMessageAdaptor> for OtherUserSocket {
+    type Result = ();
+
+    fn handle(&mut self, _msg: WsMessageAdaptor, _ctx: &mut Self::Context) {}
 }
@@ -1,20 +1,45 @@
 use backend::{
     application::{get_connection_pool, init_app_context, Application},
-    config::{get_configuration, DatabaseSettings},
+    config::{get_configuration, DatabaseSettings, Settings},
     context::AppContext,
+    storage::ObjectStorage,
+};
+use backend_service::{
+    config::{HEADER_IDEMPOTENCY_KEY, HEADER_TOKEN},
+    errors::ServerError,
+    request::HttpRequestBuilder,
+    user_request::*,
+    workspace_request::*,
+};
+use flowy_document_infra::entities::doc::{
+    Attachment,
+    AttachmentChunk,
+    AttachmentChunkIdentifier,
+    AttachmentIdentifier,
+    CreateAttachmentParams,
+    Doc,
+    DocIdentifier,
+    DocSnapshotIdentifier,
+    RepeatedDocSnapshot,
+    UpdateDocParams,
 };
-use backend_service::{errors::ServerError, user_request::*, workspace_request::*};
-use flowy_document::services::server::read_doc_request;
-use flowy_document_infra::entities::doc::{Doc, DocIdentifier};
 use flowy_user_infra::entities::*;
 use flowy_workspace_infra::entities::prelude::*;
+use bytes::Bytes;
+use protobuf::ProtobufError;
 use sqlx::{Connection, Executor, PgConnection, PgPool};
+use std::{
+    convert::{TryFrom, TryInto},
+    sync::Arc,
+    time::Duration,
+};
 use uuid::Uuid;
 
 pub struct TestUserServer {
     pub host: String,
     pub port: u16,
     pub pg_pool: PgPool,
+    pub storage: Arc<dyn ObjectStorage>,
     pub user_token: Option<String>,
     pub user_id: Option<String>,
 }
@@ -34,11 +59,63 @@ impl TestUserServer {
         Ok(resp)
     }
 
+    pub async fn sign_in_with_provider(&self, params: SignInWithProviderParams) -> Result<SignInResponse, ServerError> {
+        let url = format!("{}/api/auth/provider", self.http_addr());
+        let resp = sign_in_with_provider_request(params, &url).await?;
+        Ok(resp)
+    }
+
+    // Signs in through the reverse proxy that puts the user in the headers
+    pub async fn sign_in_with_proxy_headers(&self, email: &str, name: &str) -> Result<SignInResponse, ServerError> {
+        let url = format!("{}/api/auth", self.http_addr());
+        HttpRequestBuilder::new()
+            .post(&url)
+            .header("Remote-Email", email)
+            .header("Remote-Name", name)
+            .protobuf(SignInParams::default())?
+            .response()
+            .await
+    }
+
     pub async fn sign_out(&self) {
         let url = format!("{}/api/auth", self.http_addr());
         let _ = user_sign_out_request(self.user_token(), &url).await.unwrap();
     }
 
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<RefreshTokenResponse, ServerError> {
+        let url = format!("{}/api/auth/refresh", self.http_addr());
+        let params = RefreshTokenParams {
+            refresh_token: refresh_token.to_owned(),
+        };
+        refresh_token_request(params, &url).await
+    }
+
+    pub async fn request_password_reset(&self, email: &str) -> Result<(), ServerError> {
+        let url = format!("{}/api/auth/password_reset", self.http_addr());
+        let params = RequestPasswordResetParams { email: email.to_owned() };
+        request_password_reset_request(params, &url).await
+    }
+
+    pub async fn confirm_password_reset(&self, token: &str, new_password: &str) -> Result<(), ServerError> {
+        let url = format!("{}/api/auth/password_reset/confirm", self.http_addr());
+        let params = ConfirmPasswordResetParams {
+            token: token.to_owned(),
+            new_password: new_password.to_owned(),
+        };
+        confirm_password_reset_request(params, &url).await
+    }
+
+    pub async fn request_email_verify(&self) -> Result<(), ServerError> {
+        let url = format!("{}/api/auth/email_verify", self.http_addr());
+        request_email_verify_request(self.user_token(), &url).await
+    }
+
+    pub async fn confirm_email_verify(&self, token: &str) -> Result<(), ServerError> {
+        let url = format!("{}/api/auth/email_verify/confirm", self.http_addr());
+        let params = ConfirmEmailVerifyParams { token: token.to_owned() };
+        confirm_email_verify_request(params, &url).await
+    }
+
     pub fn user_token(&self) -> &str { self.user_token.as_ref().expect("must call register_user first ") }
 
     pub fn user_id(&self) -> &str { self.user_id.as_ref().expect("must call register_user first ") }
@@ -61,6 +138,19 @@ impl TestUserServer {
         workspace
     }
 
+    pub async fn create_workspace_with_idempotency_key(&self, params: CreateWorkspaceParams, key: &str) -> Workspace {
+        self.try_create_workspace_with_idempotency_key(params, key).await.unwrap()
+    }
+
+    pub async fn try_create_workspace_with_idempotency_key(
+        &self,
+        params: CreateWorkspaceParams,
+        key: &str,
+    ) -> Result<Workspace, ServerError> {
+        let url = format!("{}/api/workspace", self.http_addr());
+        self.idempotent_post(params, key, &url).await
+    }
+
     pub async fn read_workspaces(&self, params: WorkspaceIdentifier) -> RepeatedWorkspace {
         let url = format!("{}/api/workspace", self.http_addr());
         let workspaces = read_workspaces_request(self.user_token(), params, &url).await.unwrap();
@@ -68,30 +158,181 @@ impl TestUserServer {
     }
 
     pub async fn update_workspace(&self, params: UpdateWorkspaceParams) {
+        self.try_update_workspace(params).await.unwrap()
+    }
+
+    pub async fn try_update_workspace(&self, params: UpdateWorkspaceParams) -> Result<(), ServerError> {
         let url = format!("{}/api/workspace", self.http_addr());
-        update_workspace_request(self.user_token(), params, &url).await.unwrap();
+        update_workspace_request(self.user_token(), params, &url).await
     }
 
     pub async fn delete_workspace(&self, params: WorkspaceIdentifier) {
+        self.try_delete_workspace(params).await.unwrap()
+    }
+
+    pub async fn try_delete_workspace(&self, params: WorkspaceIdentifier) -> Result<(), ServerError> {
         let url = format!("{}/api/workspace", self.http_addr());
-        delete_workspace_request(self.user_token(), params, &url).await.unwrap();
+        delete_workspace_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn archive_workspace(&self, workspace_id: &str, archived: bool) {
+        let url = format!("{}/api/workspace/archive", self.http_addr());
+        let params = ArchiveWorkspaceParams {
+            workspace_id: workspace_id.to_owned(),
+            archived,
+        };
+        archive_workspace_request(self.user_token(), params, &url).await.unwrap();
+    }
+
+    pub async fn share_workspace(
+        &self,
+        workspace_id: &str,
+        email: &str,
+        role: WorkspaceRole,
+    ) -> Result<(), ServerError> {
+        let url = format!("{}/api/workspace/member", self.http_addr());
+        let params = ShareWorkspaceParams {
+            workspace_id: workspace_id.to_owned(),
+            email: email.to_owned(),
+            role,
+        };
+        share_workspace_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn read_workspace_members(&self, workspace_id: &str) -> Result<RepeatedWorkspaceMember, ServerError> {
+        let url = format!("{}/api/workspace/member", self.http_addr());
+        let params = WorkspaceIdentifier::new(Some(workspace_id.to_owned()));
+        read_workspace_members_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn clone_workspace(&self, workspace_id: &str) -> WorkspaceCloneProgress {
+        self.try_clone_workspace(workspace_id).await.unwrap()
+    }
+
+    pub async fn try_clone_workspace(&self, workspace_id: &str) -> Result<WorkspaceCloneProgress, ServerError> {
+        let url = format!("{}/api/workspace/clone", self.http_addr());
+        let params = WorkspaceIdentifier::new(Some(workspace_id.to_owned()));
+        clone_workspace_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn read_workspace_clone(&self, clone_id: &str) -> Result<WorkspaceCloneProgress, ServerError> {
+        let url = format!("{}/api/workspace/clone", self.http_addr());
+        let params = WorkspaceCloneIdentifier {
+            clone_id: clone_id.to_owned(),
+        };
+        read_workspace_clone_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn backup_workspace(&self, workspace_id: &str) -> Bytes {
+        self.try_backup_workspace(workspace_id).await.unwrap()
+    }
+
+    pub async fn try_backup_workspace(&self, workspace_id: &str) -> Result<Bytes, ServerError> {
+        let url = format!("{}/api/workspace/backup/{}", self.http_addr(), workspace_id);
+        backup_workspace_request(self.user_token(), &url).await
+    }
+
+    pub async fn restore_workspace(&self, backup: Bytes) -> WorkspaceRestore {
+        self.try_restore_workspace(backup).await.unwrap()
     }
 
-    pub async fn create_app(&self, params: CreateAppParams) -> App {
+    pub async fn try_restore_workspace(&self, backup: Bytes) -> Result<WorkspaceRestore, ServerError> {
+        let url = format!("{}/api/workspace/restore", self.http_addr());
+        restore_workspace_request(self.user_token(), backup, &url).await
+    }
+
+    // Reads the progress of the clone until it's no longer running
+    pub async fn wait_workspace_clone(&self, clone_id: &str) -> WorkspaceCloneProgress {
+        loop {
+            let progress = self.read_workspace_clone(clone_id).await.unwrap();
+            if progress.state != WorkspaceCloneState::Running {
+                return progress;
+            }
+            actix_rt::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    pub async fn create_bot(&self, workspace_id: &str, scope: BotScope) -> BotToken {
+        let url = format!("{}/api/workspace/bot", self.http_addr());
+        let params = CreateBotParams {
+            workspace_id: workspace_id.to_owned(),
+            name: "meeting notes bot".to_owned(),
+            scope,
+        };
+        create_bot_request(self.user_token(), params, &url).await.unwrap()
+    }
+
+    pub async fn read_bots(&self, workspace_id: &str) -> RepeatedBot {
+        let url = format!("{}/api/workspace/bot", self.http_addr());
+        let params = WorkspaceIdentifier::new(Some(workspace_id.to_owned()));
+        read_bots_request(self.user_token(), params, &url).await.unwrap()
+    }
+
+    pub async fn read_api_usage(&self, workspace_id: &str, days: i64) -> Result<RepeatedApiUsage, ServerError> {
+        let url = format!("{}/api/workspace/usage", self.http_addr());
+        let params = ReadApiUsageParams {
+            workspace_id: workspace_id.to_owned(),
+            days,
+        };
+        read_api_usage_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn search_workspace(&self, workspace_id: &str, query: &str) -> RepeatedSearchResult {
+        let url = format!("{}/api/workspace/search", self.http_addr());
+        let params = SearchWorkspaceParams {
+            workspace_id: workspace_id.to_owned(),
+            query: query.to_owned(),
+            limit: 20,
+        };
+        search_workspace_request(self.user_token(), params, &url).await.unwrap()
+    }
+
+    pub async fn delete_bot(&self, bot_id: &str) {
+        let url = format!("{}/api/workspace/bot", self.http_addr());
+        let params = BotIdentifier {
+            bot_id: bot_id.to_owned(),
+        };
+        delete_bot_request(self.user_token(), params, &url).await.unwrap();
+    }
+
+    pub async fn create_app(&self, params: CreateAppParams) -> App { self.try_create_app(params).await.unwrap() }
+
+    pub async fn try_create_app(&self, params: CreateAppParams) -> Result<App, ServerError> {
         let url = format!("{}/api/app", self.http_addr());
-        let app = create_app_request(self.user_token(), params, &url).await.unwrap();
-        app
+        create_app_request(self.user_token(), params, &url).await
     }
 
-    pub async fn read_app(&self, params: AppIdentifier) -> Option<App> {
+    pub async fn create_app_with_idempotency_key(&self, params: CreateAppParams, key: &str) -> App {
         let url = format!("{}/api/app", self.http_addr());
-        let app = read_app_request(self.user_token(), params, &url).await.unwrap();
-        app
+        self.idempotent_post(params, key, &url).await.unwrap()
     }
 
-    pub async fn update_app(&self, params: UpdateAppParams) {
+    pub async fn read_app(&self, params: AppIdentifier) -> Option<App> { self.try_read_app(params).await.unwrap() }
+
+    pub async fn try_read_app(&self, params: AppIdentifier) -> Result<Option<App>, ServerError> {
+        let url = format!("{}/api/app", self.http_addr());
+        read_app_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn update_app(&self, params: UpdateAppParams) { self.try_update_app(params).await.unwrap() }
+
+    pub async fn try_update_app(&self, params: UpdateAppParams) -> Result<(), ServerError> {
         let url = format!("{}/api/app", self.http_addr());
-        update_app_request(self.user_token(), params, &url).await.unwrap();
+        update_app_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn move_app(&self, params: MoveAppParams) { self.try_move_app(params).await.unwrap() }
+
+    pub async fn try_move_app(&self, params: MoveAppParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/app/move", self.http_addr());
+        move_app_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn duplicate_app(&self, params: AppIdentifier) -> App { self.try_duplicate_app(params).await.unwrap() }
+
+    pub async fn try_duplicate_app(&self, params: AppIdentifier) -> Result<App, ServerError> {
+        let url = format!("{}/api/app/duplicate", self.http_addr());
+        duplicate_app_request(self.user_token(), params, &url).await
     }
 
     pub async fn delete_app(&self, params: AppIdentifier) {
@@ -99,56 +340,395 @@ impl TestUserServer {
         delete_app_request(self.user_token(), params, &url).await.unwrap();
     }
 
-    pub async fn create_view(&self, params: CreateViewParams) -> View {
+    pub async fn create_view(&self, params: CreateViewParams) -> View { self.try_create_view(params).await.unwrap() }
+
+    pub async fn try_create_view(&self, params: CreateViewParams) -> Result<View, ServerError> {
         let url = format!("{}/api/view", self.http_addr());
-        let view = create_view_request(self.user_token(), params, &url).await.unwrap();
-        view
+        create_view_request(self.user_token(), params, &url).await
     }
 
-    pub async fn read_view(&self, params: ViewIdentifier) -> Option<View> {
+    pub async fn create_view_with_idempotency_key(&self, params: CreateViewParams, key: &str) -> View {
         let url = format!("{}/api/view", self.http_addr());
-        let view = read_view_request(self.user_token(), params, &url).await.unwrap();
-        view
+        self.idempotent_post(params, key, &url).await.unwrap()
     }
 
-    pub async fn update_view(&self, params: UpdateViewParams) {
+    pub async fn read_view(&self, params: ViewIdentifier) -> Option<View> { self.try_read_view(params).await.unwrap() }
+
+    pub async fn try_read_view(&self, params: ViewIdentifier) -> Result<Option<View>, ServerError> {
         let url = format!("{}/api/view", self.http_addr());
-        update_view_request(self.user_token(), params, &url).await.unwrap();
+        read_view_request(self.user_token(), params, &url).await
     }
 
-    pub async fn delete_view(&self, params: ViewIdentifiers) {
+    pub async fn update_view(&self, params: UpdateViewParams) { self.try_update_view(params).await.unwrap() }
+
+    pub async fn try_update_view(&self, params: UpdateViewParams) -> Result<(), ServerError> {
         let url = format!("{}/api/view", self.http_addr());
-        delete_view_request(self.user_token(), params, &url).await.unwrap();
+        update_view_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn move_view(&self, params: MoveViewParams) { self.try_move_view(params).await.unwrap() }
+
+    pub async fn try_move_view(&self, params: MoveViewParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/move", self.http_addr());
+        move_view_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn duplicate_view(&self, params: ViewIdentifier) -> View {
+        self.try_duplicate_view(params).await.unwrap()
+    }
+
+    pub async fn try_duplicate_view(&self, params: ViewIdentifier) -> Result<View, ServerError> {
+        let url = format!("{}/api/view/duplicate", self.http_addr());
+        duplicate_view_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn publish_view(&self, view_id: &str) -> PublishedView {
+        let url = format!("{}/api/view/publish", self.http_addr());
+        let params: ViewIdentifier = view_id.to_owned().into();
+        publish_view_request(self.user_token(), params, &url).await.unwrap()
+    }
+
+    pub async fn unpublish_view(&self, view_id: &str) {
+        let url = format!("{}/api/view/publish", self.http_addr());
+        let params: ViewIdentifier = view_id.to_owned().into();
+        unpublish_view_request(self.user_token(), params, &url).await.unwrap();
+    }
+
+    pub async fn pin_published_view(&self, view_id: &str, rev_id: i64) -> PublishedView {
+        self.try_pin_published_view(view_id, rev_id).await.unwrap()
+    }
+
+    pub async fn try_pin_published_view(&self, view_id: &str, rev_id: i64) -> Result<PublishedView, ServerError> {
+        let url = format!("{}/api/view/publish/pin", self.http_addr());
+        let params = PinPublishedViewParams {
+            view_id: view_id.to_owned(),
+            rev_id,
+        };
+        pin_published_view_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn publish_latest_view(&self, view_id: &str) -> PublishedView {
+        let url = format!("{}/api/view/publish/latest", self.http_addr());
+        let params: ViewIdentifier = view_id.to_owned().into();
+        publish_latest_view_request(self.user_token(), params, &url).await.unwrap()
+    }
+
+    pub async fn read_view_access_log(&self, view_id: &str) -> Result<RepeatedViewAccessLog, ServerError> {
+        let url = format!("{}/api/view/publish/access", self.http_addr());
+        let params: ViewIdentifier = view_id.to_owned().into();
+        read_view_access_log_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn read_published_page(&self, slug: &str) -> Result<String, ServerError> {
+        let url = format!("{}/published/{}", self.http_addr(), slug);
+        read_published_page_request(&url).await
+    }
+
+    // The client_ip is sent as the X-Forwarded-For header, so each test is rate limited on its own
+    pub async fn read_published_content(&self, slug: &str, client_ip: &str, etag: Option<&str>) -> reqwest::Response {
+        let url = format!("{}/public/published/{}", self.http_addr(), slug);
+        let mut request = reqwest::Client::new().get(&url).header("X-Forwarded-For", client_ip);
+        if let Some(etag) = etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        request.send().await.unwrap()
     }
 
-    pub async fn create_view_trash(&self, view_id: &str) {
+    pub async fn read_metrics(&self, token: Option<&str>) -> reqwest::Response {
+        let url = format!("{}/metrics", self.http_addr());
+        let mut request = reqwest::Client::new().get(&url);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        request.send().await.unwrap()
+    }
+
+    pub async fn create_share_link(&self, view_id: &str, passcode: &str) -> ShareLink {
+        self.try_create_share_link(view_id, 0, passcode).await.unwrap()
+    }
+
+    pub async fn try_create_share_link(
+        &self,
+        view_id: &str,
+        expire_time: i64,
+        passcode: &str,
+    ) -> Result<ShareLink, ServerError> {
+        let url = format!("{}/api/view/share", self.http_addr());
+        let params = CreateShareLinkParams {
+            view_id: view_id.to_owned(),
+            expire_time,
+            passcode: passcode.to_owned(),
+        };
+        create_share_link_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn read_share_links(&self, workspace_id: &str) -> RepeatedShareLink {
+        let url = format!("{}/api/view/share", self.http_addr());
+        let params = WorkspaceIdentifier::new(Some(workspace_id.to_owned()));
+        read_share_links_request(self.user_token(), params, &url).await.unwrap()
+    }
+
+    pub async fn revoke_share_link(&self, link_id: &str) {
+        let url = format!("{}/api/view/share", self.http_addr());
+        let params = ShareLinkIdentifier {
+            link_id: link_id.to_owned(),
+        };
+        revoke_share_link_request(self.user_token(), params, &url).await.unwrap();
+    }
+
+    pub async fn read_shared_page(&self, link_id: &str, passcode: &str) -> Result<String, ServerError> {
+        let url = format!("{}/shared/{}", self.http_addr(), link_id);
+        read_shared_page_request(&url, passcode).await
+    }
+
+    pub async fn update_favorite(&self, view_id: &str, is_favorite: bool) {
+        self.try_update_favorite(view_id, is_favorite).await.unwrap()
+    }
+
+    pub async fn try_update_favorite(&self, view_id: &str, is_favorite: bool) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/favorite", self.http_addr());
+        let params = ViewFavoriteState {
+            view_id: view_id.to_owned(),
+            is_favorite,
+        };
+        update_favorite_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn read_favorites(&self) -> RepeatedFavorite {
+        let url = format!("{}/api/view/favorite", self.http_addr());
+        read_favorites_request(self.user_token(), &url).await.unwrap()
+    }
+
+    pub async fn update_recent_view(&self, view_id: &str, open_time: i64) {
+        self.try_update_recent_view(view_id, open_time).await.unwrap()
+    }
+
+    pub async fn try_update_recent_view(&self, view_id: &str, open_time: i64) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/recent", self.http_addr());
+        let params = RecentViewRecord {
+            view_id: view_id.to_owned(),
+            open_time,
+        };
+        update_recent_view_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn read_recent_views(&self, limit: i64) -> RepeatedRecentView {
+        self.try_read_recent_views(limit).await.unwrap()
+    }
+
+    pub async fn try_read_recent_views(&self, limit: i64) -> Result<RepeatedRecentView, ServerError> {
+        let url = format!("{}/api/view/recent", self.http_addr());
+        let params = QueryRecentViewsParams { limit };
+        read_recent_views_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn set_view_webhook(&self, view_id: &str, url: &str, format: WebhookFormat, secret: &str) -> ViewWebhook {
+        self.try_set_view_webhook(view_id, url, format, secret).await.unwrap()
+    }
+
+    pub async fn try_set_view_webhook(
+        &self,
+        view_id: &str,
+        url: &str,
+        format: WebhookFormat,
+        secret: &str,
+    ) -> Result<ViewWebhook, ServerError> {
+        let api_url = format!("{}/api/view/webhook", self.http_addr());
+        let params = SetViewWebhookParams {
+            view_id: view_id.to_owned(),
+            url: url.to_owned(),
+            format,
+            secret: secret.to_owned(),
+        };
+        set_view_webhook_request(self.user_token(), params, &api_url).await
+    }
+
+    pub async fn read_view_webhook(&self, view_id: &str) -> ViewWebhook {
+        self.try_read_view_webhook(view_id).await.unwrap()
+    }
+
+    pub async fn try_read_view_webhook(&self, view_id: &str) -> Result<ViewWebhook, ServerError> {
+        let url = format!("{}/api/view/webhook", self.http_addr());
+        read_view_webhook_request(self.user_token(), view_id.to_owned().into(), &url).await
+    }
+
+    pub async fn delete_view_webhook(&self, view_id: &str) {
+        let url = format!("{}/api/view/webhook", self.http_addr());
+        delete_view_webhook_request(self.user_token(), view_id.to_owned().into(), &url)
+            .await
+            .unwrap();
+    }
+
+    pub async fn delete_view(&self, params: ViewIdentifiers) { self.try_delete_view(params).await.unwrap() }
+
+    pub async fn try_delete_view(&self, params: ViewIdentifiers) -> Result<(), ServerError> {
+        let url = format!("{}/api/view", self.http_addr());
+        delete_view_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn create_view_trash(&self, view_id: &str) { self.try_create_view_trash(view_id).await.unwrap() }
+
+    pub async fn try_create_view_trash(&self, view_id: &str) -> Result<(), ServerError> {
         let identifier = TrashIdentifier {
             id: view_id.to_string(),
             ty: TrashType::View,
         };
         let url = format!("{}/api/trash", self.http_addr());
+        create_trash_request(self.user_token(), vec![identifier].into(), &url).await
+    }
+
+    pub async fn create_app_trash(&self, app_id: &str) {
+        let identifier = TrashIdentifier {
+            id: app_id.to_string(),
+            ty: TrashType::App,
+        };
+        let url = format!("{}/api/trash", self.http_addr());
         create_trash_request(self.user_token(), vec![identifier].into(), &url)
             .await
             .unwrap();
     }
 
-    pub async fn delete_view_trash(&self, trash_identifiers: TrashIdentifiers) {
+    pub async fn create_workspace_trash(&self, workspace_id: &str) {
+        let identifier = TrashIdentifier {
+            id: workspace_id.to_string(),
+            ty: TrashType::Workspace,
+        };
         let url = format!("{}/api/trash", self.http_addr());
-
-        delete_trash_request(self.user_token(), trash_identifiers, &url)
+        create_trash_request(self.user_token(), vec![identifier].into(), &url)
             .await
             .unwrap();
     }
 
+    pub async fn delete_view_trash(&self, trash_identifiers: TrashIdentifiers) {
+        self.try_delete_view_trash(trash_identifiers).await.unwrap()
+    }
+
+    pub async fn try_delete_view_trash(&self, trash_identifiers: TrashIdentifiers) -> Result<(), ServerError> {
+        let url = format!("{}/api/trash", self.http_addr());
+        delete_trash_request(self.user_token(), trash_identifiers, &url).await
+    }
+
     pub async fn read_trash(&self) -> RepeatedTrash {
         let url = format!("{}/api/trash", self.http_addr());
         read_trash_request(self.user_token(), &url).await.unwrap()
     }
 
-    pub async fn read_doc(&self, params: DocIdentifier) -> Option<Doc> {
+    pub async fn read_doc(&self, params: DocIdentifier) -> Option<Doc> { self.try_read_doc(params).await.unwrap() }
+
+    pub async fn try_read_doc(&self, params: DocIdentifier) -> Result<Option<Doc>, ServerError> {
+        let url = format!("{}/api/doc", self.http_addr());
+        HttpRequestBuilder::new()
+            .get(&url)
+            .header(HEADER_TOKEN, self.user_token())
+            .protobuf(params)?
+            .option_response()
+            .await
+    }
+
+    pub async fn update_doc(&self, params: UpdateDocParams) { self.try_update_doc(params).await.unwrap() }
+
+    pub async fn try_update_doc(&self, params: UpdateDocParams) -> Result<(), ServerError> {
+        self.try_update_doc_with_token(self.user_token(), params).await
+    }
+
+    pub async fn try_update_doc_with_token(&self, token: &str, params: UpdateDocParams) -> Result<(), ServerError> {
         let url = format!("{}/api/doc", self.http_addr());
-        let doc = read_doc_request(self.user_token(), params, &url).await.unwrap();
-        doc
+        HttpRequestBuilder::new()
+            .patch(&url)
+            .header(HEADER_TOKEN, token)
+            .protobuf(params)?
+            .send()
+            .await
+    }
+
+    pub async fn read_doc_snapshots(&self, params: DocIdentifier) -> RepeatedDocSnapshot {
+        self.try_read_doc_snapshots(params).await.unwrap()
+    }
+
+    pub async fn try_read_doc_snapshots(&self, params: DocIdentifier) -> Result<RepeatedDocSnapshot, ServerError> {
+        let url = format!("{}/api/doc/snapshot", self.http_addr());
+        HttpRequestBuilder::new()
+            .get(&url)
+            .header(HEADER_TOKEN, self.user_token())
+            .protobuf(params)?
+            .response()
+            .await
+    }
+
+    pub async fn restore_doc_snapshot(&self, params: DocSnapshotIdentifier) -> Doc {
+        self.try_restore_doc_snapshot(params).await.unwrap()
+    }
+
+    pub async fn try_restore_doc_snapshot(&self, params: DocSnapshotIdentifier) -> Result<Doc, ServerError> {
+        let url = format!("{}/api/doc/snapshot/restore", self.http_addr());
+        HttpRequestBuilder::new()
+            .post(&url)
+            .header(HEADER_TOKEN, self.user_token())
+            .protobuf(params)?
+            .response()
+            .await
+    }
+
+    pub async fn try_run_automation(&self, command: serde_json::Value) -> Result<(), ServerError> {
+        let url = format!("{}/api/automation", self.http_addr());
+        automation_request(self.user_token(), &command, &url).await
+    }
+
+    pub async fn try_create_attachment(&self, params: CreateAttachmentParams) -> Result<Attachment, ServerError> {
+        let url = format!("{}/api/attachment", self.http_addr());
+        HttpRequestBuilder::new()
+            .post(&url)
+            .header(HEADER_TOKEN, self.user_token())
+            .protobuf(params)?
+            .response()
+            .await
+    }
+
+    pub async fn try_read_attachment(&self, params: AttachmentIdentifier) -> Result<Attachment, ServerError> {
+        let url = format!("{}/api/attachment", self.http_addr());
+        HttpRequestBuilder::new()
+            .get(&url)
+            .header(HEADER_TOKEN, self.user_token())
+            .protobuf(params)?
+            .response()
+            .await
+    }
+
+    pub async fn try_upload_attachment_chunk(&self, chunk: AttachmentChunk) -> Result<Attachment, ServerError> {
+        let url = format!("{}/api/attachment/chunk", self.http_addr());
+        HttpRequestBuilder::new()
+            .post(&url)
+            .header(HEADER_TOKEN, self.user_token())
+            .protobuf(chunk)?
+            .response()
+            .await
+    }
+
+    pub async fn try_read_attachment_chunk(
+        &self,
+        params: AttachmentChunkIdentifier,
+    ) -> Result<AttachmentChunk, ServerError> {
+        let url = format!("{}/api/attachment/chunk", self.http_addr());
+        HttpRequestBuilder::new()
+            .get(&url)
+            .header(HEADER_TOKEN, self.user_token())
+            .protobuf(params)?
+            .response()
+            .await
+    }
+
+    async fn idempotent_post<T1, T2>(&self, params: T1, key: &str, url: &str) -> Result<T2, ServerError>
+    where
+        T1: TryInto<Bytes, Error = ProtobufError>,
+        T2: TryFrom<Bytes, Error = ProtobufError>,
+    {
+        HttpRequestBuilder::new()
+            .post(url)
+            .header(HEADER_TOKEN, self.user_token())
+            .header(HEADER_IDEMPOTENCY_KEY, key)
+            .protobuf(params)?
+            .response()
+            .await
     }
 
     pub async fn register_user(&self) -> SignUpResponse {
@@ -161,10 +741,29 @@ impl TestUserServer {
         self.register(params).await
     }
 
-    pub async fn register(&self, params: SignUpParams) -> SignUpResponse {
+    // Signs up another user on the same server, the returned server sends the requests as that user
+    pub async fn register_member(&self, email: &str) -> TestUserServer {
+        let params = SignUpParams {
+            email: email.to_string(),
+            name: "nathan".to_string(),
+            password: "HelloAppFlowy123!".to_string(),
+        };
+        let response = self.register(params).await;
+        TestUserServer {
+            host: self.host.clone(),
+            port: self.port,
+            pg_pool: self.pg_pool.clone(),
+            storage: self.storage.clone(),
+            user_token: Some(response.token),
+            user_id: Some(response.user_id),
+        }
+    }
+
+    pub async fn register(&self, params: SignUpParams) -> SignUpResponse { self.try_register(params).await.unwrap() }
+
+    pub async fn try_register(&self, params: SignUpParams) -> Result<SignUpResponse, ServerError> {
         let url = format!("{}/api/register", self.http_addr());
-        let response = user_sign_up_request(params, &url).await.unwrap();
-        response
+        user_sign_up_request(params, &url).await
     }
 
     pub fn http_addr(&self) -> String { format!("http://{}", self.host) }
@@ -178,6 +777,7 @@ impl std::convert::From<TestServer> for TestUserServer {
             host: server.host,
             port: server.port,
             pg_pool: server.pg_pool,
+            storage: server.app_ctx.storage.get_ref().clone(),
             user_token: None,
             user_id: None,
         }
@@ -196,13 +796,27 @@ pub struct TestServer {
     pub app_ctx: AppContext,
 }
 
-pub async fn spawn_server() -> TestServer {
+pub async fn spawn_server() -> TestServer { spawn_server_with_configuration(|_| {}).await }
+
+// The configuration can be changed before the server starts, e.g. to sign in with the mock provider
+pub async fn spawn_server_with_configuration<F>(configure: F) -> TestServer
+where
+    F: FnOnce(&mut Settings),
+{
     let database_name = format!("{}", Uuid::new_v4().to_string());
     let configuration = {
         let mut c = get_configuration().expect("Failed to read configuration.");
+        // The tests send the ip of the client in the X-Forwarded-For header
+        c.rate_limit.trusted_proxies = vec!["127.0.0.1".parse().unwrap(), "::1".parse().unwrap()];
+        configure(&mut c);
         c.database.database_name = database_name.clone();
         // Use a random OS port
         c.application.port = 0;
+        // Each server has its own index, the index can't be opened by two servers at the same time
+        c.search.index_dir = std::env::temp_dir()
+            .join(&database_name)
+            .to_string_lossy()
+            .to_string();
         c
     };
 
@@ -0,0 +1,75 @@
+use actix_web::{
+    http::header::AUTHORIZATION,
+    web::{self, Data, Json},
+    App,
+    HttpRequest,
+    HttpResponse,
+    HttpServer,
+};
+use backend::config::HttpMailSettings;
+use parking_lot::Mutex;
+use std::{net::TcpListener, sync::Arc};
+
+const MOCK_API_KEY: &str = "mock_api_key";
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MockEmail {
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+    pub text: String,
+}
+
+// Keeps the emails that the server sent to the mock mail API
+#[derive(Clone, Default)]
+pub struct MockMailbox {
+    emails: Arc<Mutex<Vec<MockEmail>>>,
+}
+
+impl MockMailbox {
+    pub fn emails_to(&self, to: &str) -> Vec<MockEmail> {
+        self.emails
+            .lock()
+            .iter()
+            .filter(|email| email.to == to)
+            .cloned()
+            .collect()
+    }
+}
+
+// Starts the mock of the mail API, returns the settings that point the server to it
+pub fn spawn_mock_mailer() -> (HttpMailSettings, MockMailbox) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind the mock mailer");
+    let addr = format!("http://127.0.0.1:{}", listener.local_addr().unwrap().port());
+    let mailbox = MockMailbox::default();
+    let data = Data::new(mailbox.clone());
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(data.clone())
+            .route("/messages", web::post().to(messages_handler))
+    })
+    .listen(listener)
+    .expect("Failed to listen the mock mailer")
+    .run();
+    actix_rt::spawn(server);
+
+    let settings = HttpMailSettings {
+        url: format!("{}/messages", addr),
+        api_key: MOCK_API_KEY.to_owned(),
+        sender: "noreply@appflowy.io".to_owned(),
+    };
+    (settings, mailbox)
+}
+
+async fn messages_handler(request: HttpRequest, email: Json<MockEmail>, mailbox: Data<MockMailbox>) -> HttpResponse {
+    let is_authorized = match request.headers().get(AUTHORIZATION).and_then(|value| value.to_str().ok()) {
+        Some(value) => value == format!("Bearer {}", MOCK_API_KEY),
+        None => false,
+    };
+    if !is_authorized {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    mailbox.emails.lock().push(email.into_inner());
+    HttpResponse::Ok().finish()
+}
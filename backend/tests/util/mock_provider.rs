@@ -0,0 +1,104 @@
+use actix_web::{
+    http::header::AUTHORIZATION,
+    web::{self, Data, Form},
+    App,
+    HttpRequest,
+    HttpResponse,
+    HttpServer,
+};
+use backend::config::OAuthProviderSettings;
+use backend_service::oauth::code_challenge;
+use std::{collections::HashMap, net::TcpListener};
+
+const MOCK_ACCESS_TOKEN: &str = "mock_access_token";
+
+// The GitHub account that the mock provider signs in with. The code is only exchanged for the token if
+// the verifier matches the challenge.
+#[derive(Clone)]
+pub struct MockGitHubAccount {
+    pub id: i64,
+    pub login: String,
+    pub email: String,
+    pub email_verified: bool,
+    pub code: String,
+    pub code_challenge: String,
+}
+
+// Starts the mock of the GitHub endpoints, returns the settings that point the server to it
+pub fn spawn_mock_github(account: MockGitHubAccount) -> OAuthProviderSettings {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind the mock provider");
+    let addr = format!("http://127.0.0.1:{}", listener.local_addr().unwrap().port());
+    let account = Data::new(account);
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(account.clone())
+            .route("/login/oauth/access_token", web::post().to(access_token_handler))
+            .route("/user", web::get().to(user_handler))
+            .route("/user/emails", web::get().to(user_emails_handler))
+    })
+    .listen(listener)
+    .expect("Failed to listen the mock provider")
+    .run();
+    actix_rt::spawn(server);
+
+    OAuthProviderSettings {
+        client_id: "mock_client_id".to_owned(),
+        client_secret: "mock_client_secret".to_owned(),
+        token_url: Some(format!("{}/login/oauth/access_token", addr)),
+        user_info_url: Some(format!("{}/user", addr)),
+        emails_url: Some(format!("{}/user/emails", addr)),
+    }
+}
+
+async fn access_token_handler(form: Form<HashMap<String, String>>, account: Data<MockGitHubAccount>) -> HttpResponse {
+    let is_code_valid = form.get("code") == Some(&account.code);
+    let is_verifier_valid = form
+        .get("code_verifier")
+        .map(|verifier| code_challenge(verifier) == account.code_challenge)
+        .unwrap_or(false);
+
+    // GitHub responds the error with the 200 status
+    if !is_code_valid || !is_verifier_valid {
+        return HttpResponse::Ok().json(serde_json::json!({
+            "error": "bad_verification_code",
+            "error_description": "The code passed is incorrect or expired.",
+        }));
+    }
+    HttpResponse::Ok().json(serde_json::json!({
+        "access_token": MOCK_ACCESS_TOKEN,
+        "token_type": "bearer",
+    }))
+}
+
+async fn user_handler(request: HttpRequest, account: Data<MockGitHubAccount>) -> HttpResponse {
+    if !is_authorized(&request) {
+        return HttpResponse::Unauthorized().finish();
+    }
+    // The email is private, it's only returned by the emails endpoint
+    HttpResponse::Ok().json(serde_json::json!({
+        "id": account.id,
+        "login": account.login,
+        "name": null,
+        "email": null,
+    }))
+}
+
+async fn user_emails_handler(request: HttpRequest, account: Data<MockGitHubAccount>) -> HttpResponse {
+    if !is_authorized(&request) {
+        return HttpResponse::Unauthorized().finish();
+    }
+    HttpResponse::Ok().json(serde_json::json!([
+        {
+            "email": account.email,
+            "primary": true,
+            "verified": account.email_verified,
+        },
+    ]))
+}
+
+fn is_authorized(request: &HttpRequest) -> bool {
+    match request.headers().get(AUTHORIZATION).and_then(|value| value.to_str().ok()) {
+        Some(value) => value == format!("Bearer {}", MOCK_ACCESS_TOKEN),
+        None => false,
+    }
+}
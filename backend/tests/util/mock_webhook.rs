@@ -0,0 +1,65 @@
+use actix_web::{
+    http::header::AUTHORIZATION,
+    web::{self, Data, Json},
+    App,
+    HttpRequest,
+    HttpResponse,
+    HttpServer,
+};
+use parking_lot::Mutex;
+use std::{net::TcpListener, sync::Arc};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MockDelivery {
+    pub event: String,
+    pub view_id: String,
+    pub name: String,
+    pub rev_id: i64,
+    pub format: String,
+    pub content: String,
+    #[serde(skip)]
+    pub authorization: Option<String>,
+}
+
+// Keeps the docs that the server delivered to the mock webhook
+#[derive(Clone, Default)]
+pub struct MockWebhookReceiver {
+    deliveries: Arc<Mutex<Vec<MockDelivery>>>,
+}
+
+impl MockWebhookReceiver {
+    pub fn deliveries(&self) -> Vec<MockDelivery> { self.deliveries.lock().clone() }
+}
+
+// Starts the mock of the webhook, returns the url that receives the docs
+pub fn spawn_mock_webhook() -> (String, MockWebhookReceiver) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind the mock webhook");
+    let url = format!("http://127.0.0.1:{}/hook", listener.local_addr().unwrap().port());
+    let receiver = MockWebhookReceiver::default();
+    let data = Data::new(receiver.clone());
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(data.clone())
+            .route("/hook", web::post().to(hook_handler))
+    })
+    .listen(listener)
+    .expect("Failed to listen the mock webhook")
+    .run();
+    actix_rt::spawn(server);
+    (url, receiver)
+}
+
+async fn hook_handler(
+    request: HttpRequest,
+    delivery: Json<MockDelivery>,
+    receiver: Data<MockWebhookReceiver>,
+) -> HttpResponse {
+    let mut delivery = delivery.into_inner();
+    delivery.authorization = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_owned());
+    receiver.deliveries.lock().push(delivery);
+    HttpResponse::Ok().finish()
+}
@@ -1 +1,4 @@
 pub mod helper;
+pub mod mock_mailer;
+pub mod mock_provider;
+pub mod mock_webhook;
@@ -5,9 +5,11 @@ mod util;
 
 use crate::{
     c::{extend_front_four_bytes_into_bytes, forget_rust},
-    model::{FFIRequest, FFIResponse},
+    model::{FFIBatchRequest, FFIBatchResponse, FFIRequest, FFIResponse, FFIStreamChunk},
 };
+use dart_notify::dart::{IsolateSink, NotificationRegistry, SubscriptionFilter};
 use flowy_sdk::*;
+use futures_util::StreamExt;
 use lazy_static::lazy_static;
 use lib_dispatch::prelude::*;
 use parking_lot::RwLock;
@@ -26,6 +28,8 @@ pub extern "C" fn init_sdk(path: *mut c_char) -> i64 {
 
     let server_config = ServerConfig::default();
     let config = FlowySDKConfig::new(path, server_config, "appflowy").log_filter("debug");
+    #[cfg(feature = "sqlcipher")]
+    let config = config.secret_store(Arc::new(KeychainSecretStore::new("appflowy")));
     *FLOWY_SDK.write() = Some(Arc::new(FlowySDK::new(config)));
 
     return 1;
@@ -47,6 +51,44 @@ pub extern "C" fn async_command(port: i64, input: *const u8, len: usize) {
     });
 }
 
+// The chunks of the response are posted to the port one by one, see FFIStreamChunk
+#[no_mangle]
+pub extern "C" fn stream_command(port: i64, input: *const u8, len: usize) {
+    let request: ModuleRequest = FFIRequest::from_u8_pointer(input, len).into();
+    log::trace!(
+        "[FFI]: {} Stream Event: {:?} with {} port",
+        &request.id,
+        &request.event,
+        port
+    );
+
+    let _ = EventDispatch::async_send_with_callback(dispatch(), request, move |resp: EventResponse| {
+        log::trace!("[FFI]: Post stream to dart through {} port", port);
+        Box::pin(post_stream_to_flutter(resp, port))
+    });
+}
+
+// The events of the batch are dispatched in one call, e.g. the default views of a new workspace. The
+// FFIBatchResponse that has the responses of the events is posted to the port.
+#[no_mangle]
+pub extern "C" fn batch_command(port: i64, input: *const u8, len: usize) {
+    let batch: BatchRequest = FFIBatchRequest::from_u8_pointer(input, len).into();
+    log::trace!(
+        "[FFI]: {} Batch Event: {} events with {} port",
+        &batch.id,
+        batch.requests.len(),
+        port
+    );
+
+    let dispatch = dispatch();
+    let response = EventDispatch::async_send_batch(dispatch.clone(), batch);
+    dispatch.spawn(async move {
+        let response = response.await;
+        log::trace!("[FFI]: Post batch to dart through {} port", port);
+        post_batch_to_flutter(response, port);
+    });
+}
+
 #[no_mangle]
 pub extern "C" fn sync_command(input: *const u8, len: usize) -> *const u8 {
     let request: ModuleRequest = FFIRequest::from_u8_pointer(input, len).into();
@@ -61,10 +103,35 @@ pub extern "C" fn sync_command(input: *const u8, len: usize) -> *const u8 {
 
 #[no_mangle]
 pub extern "C" fn set_stream_port(port: i64) -> i32 {
+    // Dart sets the port again after restarting, the subscriptions of the previous isolate are stale
+    dart_notify::dart::NotificationRegistry::clear();
     dart_notify::dart::DartStreamSender::set_port(port);
     return 0;
 }
 
+// The notifications of the source are posted to the port, only the ones of the object if the id isn't
+// empty. Returns the id of the subscription. The subscription is removed when the port is closed.
+#[no_mangle]
+pub extern "C" fn subscribe_notification(port: i64, source: *const c_char, id: *const c_char) -> i64 {
+    let source = unsafe { CStr::from_ptr(source) }.to_str().unwrap_or_default();
+    let id = unsafe { CStr::from_ptr(id) }.to_str().unwrap_or_default();
+    let mut filter = SubscriptionFilter::new(source);
+    if !id.is_empty() {
+        filter = filter.id(id);
+    }
+
+    log::trace!("[FFI]: Subscribe {:?} with {} port", filter, port);
+    NotificationRegistry::subscribe(filter, Arc::new(IsolateSink::new(port)))
+}
+
+#[no_mangle]
+pub extern "C" fn unsubscribe_notification(subscription_id: i64) -> i32 {
+    match NotificationRegistry::unsubscribe(subscription_id) {
+        true => 0,
+        false => -1,
+    }
+}
+
 #[inline(never)]
 #[no_mangle]
 pub extern "C" fn link_me_please() {}
@@ -94,3 +161,52 @@ async fn post_to_flutter(response: EventResponse, port: i64) {
         },
     }
 }
+
+async fn post_stream_to_flutter(response: EventResponse, port: i64) {
+    let isolate = allo_isolate::Isolate::new(port);
+    let stream_id = response.request_id.clone();
+    let mut stream = match response.take_stream() {
+        None => {
+            post_chunk(&isolate, FFIStreamChunk::end(&stream_id, response));
+            return;
+        },
+        Some(stream) => stream,
+    };
+
+    while let Some(item) = stream.next().await {
+        let chunk = match item {
+            Ok(bytes) => FFIStreamChunk::data(&stream_id, bytes),
+            Err(e) => {
+                post_chunk(&isolate, FFIStreamChunk::end(&stream_id, e.into()));
+                return;
+            },
+        };
+        // Stop reading the stream if dart closed the port
+        if !post_chunk(&isolate, chunk) {
+            return;
+        }
+    }
+    post_chunk(&isolate, FFIStreamChunk::end(&stream_id, ResponseBuilder::Ok().build()));
+}
+
+fn post_batch_to_flutter(response: BatchResponse, port: i64) {
+    let isolate = allo_isolate::Isolate::new(port);
+    match FFIBatchResponse::from(response).into_bytes() {
+        Ok(bytes) => {
+            if !isolate.post(bytes.to_vec()) {
+                log::error!("[FFI]: Post batch to dart failed");
+            }
+        },
+        Err(e) => log::error!("[FFI]: Serialize the batch response failed: {:?}", e),
+    }
+}
+
+fn post_chunk(isolate: &allo_isolate::Isolate, chunk: FFIStreamChunk) -> bool {
+    match chunk.into_bytes() {
+        Ok(bytes) => isolate.post(bytes.to_vec()),
+        Err(e) => {
+            log::error!("[FFI]: Serialize the stream chunk failed: {:?}", e);
+            false
+        },
+    }
+}
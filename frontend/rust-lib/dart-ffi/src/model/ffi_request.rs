@@ -1,6 +1,6 @@
 use bytes::Bytes;
-use flowy_derive::ProtoBuf;
-use lib_dispatch::prelude::ModuleRequest;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use lib_dispatch::prelude::{BatchRequest, ModuleRequest};
 use std::convert::TryFrom;
 
 #[derive(Default, ProtoBuf)]
@@ -24,3 +24,80 @@ impl FFIRequest {
 impl std::convert::Into<ModuleRequest> for FFIRequest {
     fn into(self) -> ModuleRequest { ModuleRequest::new(self.event).payload(self.payload) }
 }
+
+// Transactional is the zero value, so the batch that doesn't set the mode is transactional like the
+// default BatchRequest.
+#[derive(ProtoBuf_Enum, Clone, Copy, PartialEq, Debug)]
+pub enum FFIBatchMode {
+    Transactional   = 0,
+    ContinueOnError = 1,
+}
+
+impl std::default::Default for FFIBatchMode {
+    fn default() -> FFIBatchMode { FFIBatchMode::Transactional }
+}
+
+// The events are executed in order, see BatchRequest
+#[derive(Default, ProtoBuf)]
+pub struct FFIBatchRequest {
+    #[pb(index = 1)]
+    pub(crate) requests: Vec<FFIRequest>,
+
+    #[pb(index = 2)]
+    pub(crate) mode: FFIBatchMode,
+}
+
+impl FFIBatchRequest {
+    pub fn from_u8_pointer(pointer: *const u8, len: usize) -> Self {
+        let buffer = unsafe { std::slice::from_raw_parts(pointer, len) }.to_vec();
+        let bytes = Bytes::from(buffer);
+        let request: FFIBatchRequest = FFIBatchRequest::try_from(bytes).unwrap();
+        request
+    }
+}
+
+impl std::convert::Into<BatchRequest> for FFIBatchRequest {
+    fn into(self) -> BatchRequest {
+        let requests = self
+            .requests
+            .into_iter()
+            .map(|request| request.into())
+            .collect::<Vec<ModuleRequest>>();
+        let transactional = self.mode == FFIBatchMode::Transactional;
+        BatchRequest::from(requests).transactional(transactional)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+
+    fn batch_from_bytes(request: FFIBatchRequest) -> BatchRequest {
+        let bytes: Bytes = request.try_into().unwrap();
+        FFIBatchRequest::from_u8_pointer(bytes.as_ptr(), bytes.len()).into()
+    }
+
+    #[test]
+    fn batch_mode_unset_is_transactional() {
+        let request = FFIBatchRequest {
+            requests: vec![FFIRequest {
+                event: "increase".to_owned(),
+                payload: vec![],
+            }],
+            ..FFIBatchRequest::default()
+        };
+        let batch = batch_from_bytes(request);
+        assert!(batch.transactional);
+        assert_eq!(batch.requests.len(), 1);
+    }
+
+    #[test]
+    fn batch_mode_continue_on_error() {
+        let request = FFIBatchRequest {
+            requests: vec![],
+            mode: FFIBatchMode::ContinueOnError,
+        };
+        assert!(!batch_from_bytes(request).transactional);
+    }
+}
@@ -1,11 +1,13 @@
+use bytes::Bytes;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use lib_dispatch::prelude::{EventResponse, Payload, StatusCode};
+use lib_dispatch::prelude::{BatchResponse, EventResponse, Payload, StatusCode};
 
 #[derive(ProtoBuf_Enum, Clone, Copy)]
 pub enum FFIStatusCode {
     Ok       = 0,
     Err      = 1,
     Internal = 2,
+    Timeout  = 3,
 }
 
 impl std::default::Default for FFIStatusCode {
@@ -19,26 +21,104 @@ pub struct FFIResponse {
 
     #[pb(index = 2)]
     code: FFIStatusCode,
+
+    #[pb(index = 3)]
+    request_id: String,
 }
 
 impl std::convert::From<EventResponse> for FFIResponse {
     fn from(resp: EventResponse) -> Self {
-        let payload = match resp.payload {
-            Payload::Bytes(bytes) => bytes.to_vec(),
-            Payload::None => vec![],
-        };
-
-        let code = match resp.status_code {
-            StatusCode::Ok => FFIStatusCode::Ok,
-            StatusCode::Err => FFIStatusCode::Err,
-            StatusCode::Internal => FFIStatusCode::Internal,
-        };
+        let payload = payload_to_vec(resp.payload);
+        let code = resp.status_code.into();
 
         // let msg = match resp.error {
         //     None => "".to_owned(),
         //     Some(e) => format!("{:?}", e),
         // };
 
-        FFIResponse { payload, code }
+        FFIResponse {
+            payload,
+            code,
+            request_id: resp.request_id,
+        }
+    }
+}
+
+// Posted to dart for each chunk of the streamed response, the stream_id is the id of the request. The
+// last chunk is marked with is_end, it's either empty or the error that ended the stream. The response
+// that isn't streamed is posted as a single chunk.
+#[derive(ProtoBuf, Default)]
+pub struct FFIStreamChunk {
+    #[pb(index = 1)]
+    pub(crate) stream_id: String,
+
+    #[pb(index = 2)]
+    pub(crate) payload: Vec<u8>,
+
+    #[pb(index = 3)]
+    pub(crate) code: FFIStatusCode,
+
+    #[pb(index = 4)]
+    pub(crate) is_end: bool,
+}
+
+impl FFIStreamChunk {
+    pub(crate) fn data(stream_id: &str, bytes: Bytes) -> Self {
+        FFIStreamChunk {
+            stream_id: stream_id.to_owned(),
+            payload: bytes.to_vec(),
+            code: FFIStatusCode::Ok,
+            is_end: false,
+        }
+    }
+
+    pub(crate) fn end(stream_id: &str, resp: EventResponse) -> Self {
+        FFIStreamChunk {
+            stream_id: stream_id.to_owned(),
+            payload: payload_to_vec(resp.payload),
+            code: resp.status_code.into(),
+            is_end: true,
+        }
+    }
+}
+
+// The responses are in the order of the requests of the batch, the code is the one of the first failed event
+#[derive(ProtoBuf, Default)]
+pub struct FFIBatchResponse {
+    #[pb(index = 1)]
+    pub(crate) responses: Vec<FFIResponse>,
+
+    #[pb(index = 2)]
+    pub(crate) code: FFIStatusCode,
+
+    #[pb(index = 3)]
+    pub(crate) rolled_back: bool,
+}
+
+impl std::convert::From<BatchResponse> for FFIBatchResponse {
+    fn from(resp: BatchResponse) -> Self {
+        FFIBatchResponse {
+            responses: resp.responses.into_iter().map(FFIResponse::from).collect(),
+            code: resp.status_code.into(),
+            rolled_back: resp.rolled_back,
+        }
+    }
+}
+
+impl std::convert::From<StatusCode> for FFIStatusCode {
+    fn from(status_code: StatusCode) -> Self {
+        match status_code {
+            StatusCode::Ok => FFIStatusCode::Ok,
+            StatusCode::Err => FFIStatusCode::Err,
+            StatusCode::Internal => FFIStatusCode::Internal,
+            StatusCode::Timeout => FFIStatusCode::Timeout,
+        }
+    }
+}
+
+fn payload_to_vec(payload: Payload) -> Vec<u8> {
+    match payload {
+        Payload::Bytes(bytes) => bytes.to_vec(),
+        Payload::None => vec![],
     }
 }
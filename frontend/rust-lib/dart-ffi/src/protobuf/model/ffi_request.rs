@@ -224,17 +224,280 @@ impl ::protobuf::reflect::ProtobufValue for FFIRequest {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct FFIBatchRequest {
+    // message fields
+    pub requests: ::protobuf::RepeatedField<FFIRequest>,
+    pub mode: FFIBatchMode,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FFIBatchRequest {
+    fn default() -> &'a FFIBatchRequest {
+        <FFIBatchRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FFIBatchRequest {
+    pub fn new() -> FFIBatchRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .FFIRequest requests = 1;
+
+
+    pub fn get_requests(&self) -> &[FFIRequest] {
+        &self.requests
+    }
+    pub fn clear_requests(&mut self) {
+        self.requests.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requests(&mut self, v: ::protobuf::RepeatedField<FFIRequest>) {
+        self.requests = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requests(&mut self) -> &mut ::protobuf::RepeatedField<FFIRequest> {
+        &mut self.requests
+    }
+
+    // Take field
+    pub fn take_requests(&mut self) -> ::protobuf::RepeatedField<FFIRequest> {
+        ::std::mem::replace(&mut self.requests, ::protobuf::RepeatedField::new())
+    }
+
+    // .FFIBatchMode mode = 2;
+
+
+    pub fn get_mode(&self) -> FFIBatchMode {
+        self.mode
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = FFIBatchMode::Transactional;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: FFIBatchMode) {
+        self.mode = v;
+    }
+}
+
+impl ::protobuf::Message for FFIBatchRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.requests {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.requests)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.mode, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.requests {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.mode != FFIBatchMode::Transactional {
+            my_size += ::protobuf::rt::enum_size(2, self.mode);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.requests {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.mode != FFIBatchMode::Transactional {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.mode))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FFIBatchRequest {
+        FFIBatchRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FFIRequest>>(
+                "requests",
+                |m: &FFIBatchRequest| { &m.requests },
+                |m: &mut FFIBatchRequest| { &mut m.requests },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FFIBatchMode>>(
+                "mode",
+                |m: &FFIBatchRequest| { &m.mode },
+                |m: &mut FFIBatchRequest| { &mut m.mode },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FFIBatchRequest>(
+                "FFIBatchRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FFIBatchRequest {
+        static instance: ::protobuf::rt::LazyV2<FFIBatchRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FFIBatchRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for FFIBatchRequest {
+    fn clear(&mut self) {
+        self.requests.clear();
+        self.mode = FFIBatchMode::Transactional;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FFIBatchRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FFIBatchRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FFIBatchMode {
+    Transactional = 0,
+    ContinueOnError = 1,
+}
+
+impl ::protobuf::ProtobufEnum for FFIBatchMode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FFIBatchMode> {
+        match value {
+            0 => ::std::option::Option::Some(FFIBatchMode::Transactional),
+            1 => ::std::option::Option::Some(FFIBatchMode::ContinueOnError),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FFIBatchMode] = &[
+            FFIBatchMode::Transactional,
+            FFIBatchMode::ContinueOnError,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<FFIBatchMode>("FFIBatchMode", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for FFIBatchMode {
+}
+
+impl ::std::default::Default for FFIBatchMode {
+    fn default() -> Self {
+        FFIBatchMode::Transactional
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FFIBatchMode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x11ffi_request.proto\"<\n\nFFIRequest\x12\x14\n\x05event\x18\x01\x20\
-    \x01(\tR\x05event\x12\x18\n\x07payload\x18\x02\x20\x01(\x0cR\x07payloadJ\
-    \x98\x01\n\x06\x12\x04\0\0\x05\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x12\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x10\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x13\x14\n\x0b\n\x04\x04\0\x02\x01\x12\
-    \x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\
-    \x05\x04\0\x02\x01\x01\x12\x03\x04\n\x11\n\x0c\n\x05\x04\0\x02\x01\x03\
-    \x12\x03\x04\x14\x15b\x06proto3\
+    \x01(\tR\x05event\x12\x18\n\x07payload\x18\x02\x20\x01(\x0cR\x07payload\
+    \"]\n\x0fFFIBatchRequest\x12'\n\x08requests\x18\x01\x20\x03(\x0b2\x0b.FF\
+    IRequestR\x08requests\x12!\n\x04mode\x18\x02\x20\x01(\x0e2\r.FFIBatchMod\
+    eR\x04mode*6\n\x0cFFIBatchMode\x12\x11\n\rTransactional\x10\0\x12\x13\n\
+    \x0fContinueOnError\x10\x01J\x96\x03\n\x06\x12\x04\0\0\r\x01\n\x08\n\x01\
+    \x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\
+    \0\x01\x12\x03\x02\x08\x12\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x15\n\
+    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x13\x14\n\
+    \x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\n\x11\n\
+    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x14\x15\n\n\n\x02\x04\x01\x12\
+    \x04\x06\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x17\n\x0b\n\x04\
+    \x04\x01\x02\0\x12\x03\x07\x04%\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\
+    \x07\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x07\r\x17\n\x0c\n\x05\
+    \x04\x01\x02\0\x01\x12\x03\x07\x18\x20\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
+    \x03\x07#$\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x08\x04\x1a\n\x0c\n\x05\
+    \x04\x01\x02\x01\x06\x12\x03\x08\x04\x10\n\x0c\n\x05\x04\x01\x02\x01\x01\
+    \x12\x03\x08\x11\x15\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x08\x18\x19\
+    \n\n\n\x02\x05\0\x12\x04\n\0\r\x01\n\n\n\x03\x05\0\x01\x12\x03\n\x05\x11\
+    \n\x0b\n\x04\x05\0\x02\0\x12\x03\x0b\x04\x16\n\x0c\n\x05\x05\0\x02\0\x01\
+    \x12\x03\x0b\x04\x11\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x0b\x14\x15\n\
+    \x0b\n\x04\x05\0\x02\x01\x12\x03\x0c\x04\x18\n\x0c\n\x05\x05\0\x02\x01\
+    \x01\x12\x03\x0c\x04\x13\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x0c\x16\
+    \x17b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -28,6 +28,7 @@ pub struct FFIResponse {
     // message fields
     pub payload: ::std::vec::Vec<u8>,
     pub code: FFIStatusCode,
+    pub request_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -84,6 +85,32 @@ impl FFIResponse {
     pub fn set_code(&mut self, v: FFIStatusCode) {
         self.code = v;
     }
+
+    // string request_id = 3;
+
+
+    pub fn get_request_id(&self) -> &str {
+        &self.request_id
+    }
+    pub fn clear_request_id(&mut self) {
+        self.request_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_request_id(&mut self, v: ::std::string::String) {
+        self.request_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_request_id(&mut self) -> &mut ::std::string::String {
+        &mut self.request_id
+    }
+
+    // Take field
+    pub fn take_request_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.request_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for FFIResponse {
@@ -101,6 +128,9 @@ impl ::protobuf::Message for FFIResponse {
                 2 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.code, 2, &mut self.unknown_fields)?
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.request_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -119,6 +149,9 @@ impl ::protobuf::Message for FFIResponse {
         if self.code != FFIStatusCode::Ok {
             my_size += ::protobuf::rt::enum_size(2, self.code);
         }
+        if !self.request_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.request_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -131,6 +164,9 @@ impl ::protobuf::Message for FFIResponse {
         if self.code != FFIStatusCode::Ok {
             os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.code))?;
         }
+        if !self.request_id.is_empty() {
+            os.write_string(3, &self.request_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -179,6 +215,11 @@ impl ::protobuf::Message for FFIResponse {
                 |m: &FFIResponse| { &m.code },
                 |m: &mut FFIResponse| { &mut m.code },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "request_id",
+                |m: &FFIResponse| { &m.request_id },
+                |m: &mut FFIResponse| { &mut m.request_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<FFIResponse>(
                 "FFIResponse",
                 fields,
@@ -197,6 +238,7 @@ impl ::protobuf::Clear for FFIResponse {
     fn clear(&mut self) {
         self.payload.clear();
         self.code = FFIStatusCode::Ok;
+        self.request_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -213,11 +255,511 @@ impl ::protobuf::reflect::ProtobufValue for FFIResponse {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct FFIStreamChunk {
+    // message fields
+    pub stream_id: ::std::string::String,
+    pub payload: ::std::vec::Vec<u8>,
+    pub code: FFIStatusCode,
+    pub is_end: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FFIStreamChunk {
+    fn default() -> &'a FFIStreamChunk {
+        <FFIStreamChunk as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FFIStreamChunk {
+    pub fn new() -> FFIStreamChunk {
+        ::std::default::Default::default()
+    }
+
+    // string stream_id = 1;
+
+
+    pub fn get_stream_id(&self) -> &str {
+        &self.stream_id
+    }
+    pub fn clear_stream_id(&mut self) {
+        self.stream_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stream_id(&mut self, v: ::std::string::String) {
+        self.stream_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stream_id(&mut self) -> &mut ::std::string::String {
+        &mut self.stream_id
+    }
+
+    // Take field
+    pub fn take_stream_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.stream_id, ::std::string::String::new())
+    }
+
+    // bytes payload = 2;
+
+
+    pub fn get_payload(&self) -> &[u8] {
+        &self.payload
+    }
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
+        self.payload = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.payload
+    }
+
+    // Take field
+    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.payload, ::std::vec::Vec::new())
+    }
+
+    // .FFIStatusCode code = 3;
+
+
+    pub fn get_code(&self) -> FFIStatusCode {
+        self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code = FFIStatusCode::Ok;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: FFIStatusCode) {
+        self.code = v;
+    }
+
+    // bool is_end = 4;
+
+
+    pub fn get_is_end(&self) -> bool {
+        self.is_end
+    }
+    pub fn clear_is_end(&mut self) {
+        self.is_end = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_end(&mut self, v: bool) {
+        self.is_end = v;
+    }
+}
+
+impl ::protobuf::Message for FFIStreamChunk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.stream_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.payload)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.code, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_end = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.stream_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.stream_id);
+        }
+        if !self.payload.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.payload);
+        }
+        if self.code != FFIStatusCode::Ok {
+            my_size += ::protobuf::rt::enum_size(3, self.code);
+        }
+        if self.is_end != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.stream_id.is_empty() {
+            os.write_string(1, &self.stream_id)?;
+        }
+        if !self.payload.is_empty() {
+            os.write_bytes(2, &self.payload)?;
+        }
+        if self.code != FFIStatusCode::Ok {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.code))?;
+        }
+        if self.is_end != false {
+            os.write_bool(4, self.is_end)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FFIStreamChunk {
+        FFIStreamChunk::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "stream_id",
+                |m: &FFIStreamChunk| { &m.stream_id },
+                |m: &mut FFIStreamChunk| { &mut m.stream_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "payload",
+                |m: &FFIStreamChunk| { &m.payload },
+                |m: &mut FFIStreamChunk| { &mut m.payload },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FFIStatusCode>>(
+                "code",
+                |m: &FFIStreamChunk| { &m.code },
+                |m: &mut FFIStreamChunk| { &mut m.code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_end",
+                |m: &FFIStreamChunk| { &m.is_end },
+                |m: &mut FFIStreamChunk| { &mut m.is_end },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FFIStreamChunk>(
+                "FFIStreamChunk",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FFIStreamChunk {
+        static instance: ::protobuf::rt::LazyV2<FFIStreamChunk> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FFIStreamChunk::new)
+    }
+}
+
+impl ::protobuf::Clear for FFIStreamChunk {
+    fn clear(&mut self) {
+        self.stream_id.clear();
+        self.payload.clear();
+        self.code = FFIStatusCode::Ok;
+        self.is_end = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FFIStreamChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FFIStreamChunk {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FFIBatchResponse {
+    // message fields
+    pub responses: ::protobuf::RepeatedField<FFIResponse>,
+    pub code: FFIStatusCode,
+    pub rolled_back: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FFIBatchResponse {
+    fn default() -> &'a FFIBatchResponse {
+        <FFIBatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FFIBatchResponse {
+    pub fn new() -> FFIBatchResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .FFIResponse responses = 1;
+
+
+    pub fn get_responses(&self) -> &[FFIResponse] {
+        &self.responses
+    }
+    pub fn clear_responses(&mut self) {
+        self.responses.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_responses(&mut self, v: ::protobuf::RepeatedField<FFIResponse>) {
+        self.responses = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_responses(&mut self) -> &mut ::protobuf::RepeatedField<FFIResponse> {
+        &mut self.responses
+    }
+
+    // Take field
+    pub fn take_responses(&mut self) -> ::protobuf::RepeatedField<FFIResponse> {
+        ::std::mem::replace(&mut self.responses, ::protobuf::RepeatedField::new())
+    }
+
+    // .FFIStatusCode code = 2;
+
+
+    pub fn get_code(&self) -> FFIStatusCode {
+        self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code = FFIStatusCode::Ok;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: FFIStatusCode) {
+        self.code = v;
+    }
+
+    // bool rolled_back = 3;
+
+
+    pub fn get_rolled_back(&self) -> bool {
+        self.rolled_back
+    }
+    pub fn clear_rolled_back(&mut self) {
+        self.rolled_back = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rolled_back(&mut self, v: bool) {
+        self.rolled_back = v;
+    }
+}
+
+impl ::protobuf::Message for FFIBatchResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.responses {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.responses)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.code, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.rolled_back = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.responses {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.code != FFIStatusCode::Ok {
+            my_size += ::protobuf::rt::enum_size(2, self.code);
+        }
+        if self.rolled_back != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.responses {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.code != FFIStatusCode::Ok {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.code))?;
+        }
+        if self.rolled_back != false {
+            os.write_bool(3, self.rolled_back)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FFIBatchResponse {
+        FFIBatchResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FFIResponse>>(
+                "responses",
+                |m: &FFIBatchResponse| { &m.responses },
+                |m: &mut FFIBatchResponse| { &mut m.responses },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FFIStatusCode>>(
+                "code",
+                |m: &FFIBatchResponse| { &m.code },
+                |m: &mut FFIBatchResponse| { &mut m.code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "rolled_back",
+                |m: &FFIBatchResponse| { &m.rolled_back },
+                |m: &mut FFIBatchResponse| { &mut m.rolled_back },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FFIBatchResponse>(
+                "FFIBatchResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FFIBatchResponse {
+        static instance: ::protobuf::rt::LazyV2<FFIBatchResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FFIBatchResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for FFIBatchResponse {
+    fn clear(&mut self) {
+        self.responses.clear();
+        self.code = FFIStatusCode::Ok;
+        self.rolled_back = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FFIBatchResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FFIBatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum FFIStatusCode {
     Ok = 0,
     Err = 1,
     Internal = 2,
+    Timeout = 3,
 }
 
 impl ::protobuf::ProtobufEnum for FFIStatusCode {
@@ -230,6 +772,7 @@ impl ::protobuf::ProtobufEnum for FFIStatusCode {
             0 => ::std::option::Option::Some(FFIStatusCode::Ok),
             1 => ::std::option::Option::Some(FFIStatusCode::Err),
             2 => ::std::option::Option::Some(FFIStatusCode::Internal),
+            3 => ::std::option::Option::Some(FFIStatusCode::Timeout),
             _ => ::std::option::Option::None
         }
     }
@@ -239,6 +782,7 @@ impl ::protobuf::ProtobufEnum for FFIStatusCode {
             FFIStatusCode::Ok,
             FFIStatusCode::Err,
             FFIStatusCode::Internal,
+            FFIStatusCode::Timeout,
         ];
         values
     }
@@ -267,24 +811,59 @@ impl ::protobuf::reflect::ProtobufValue for FFIStatusCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x12ffi_response.proto\"K\n\x0bFFIResponse\x12\x18\n\x07payload\x18\
+    \n\x12ffi_response.proto\"j\n\x0bFFIResponse\x12\x18\n\x07payload\x18\
     \x01\x20\x01(\x0cR\x07payload\x12\"\n\x04code\x18\x02\x20\x01(\x0e2\x0e.\
-    FFIStatusCodeR\x04code*.\n\rFFIStatusCode\x12\x06\n\x02Ok\x10\0\x12\x07\
-    \n\x03Err\x10\x01\x12\x0c\n\x08Internal\x10\x02J\xab\x02\n\x06\x12\x04\0\
-    \0\n\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\
-    \x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x13\n\x0b\n\x04\x04\0\x02\0\
-    \x12\x03\x03\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\
-    \n\x05\x04\0\x02\0\x01\x12\x03\x03\n\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\
-    \x03\x03\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x1b\n\x0c\n\
-    \x05\x04\0\x02\x01\x06\x12\x03\x04\x04\x11\n\x0c\n\x05\x04\0\x02\x01\x01\
-    \x12\x03\x04\x12\x16\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x19\x1a\n\
-    \n\n\x02\x05\0\x12\x04\x06\0\n\x01\n\n\n\x03\x05\0\x01\x12\x03\x06\x05\
-    \x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x07\x04\x0b\n\x0c\n\x05\x05\0\x02\0\
-    \x01\x12\x03\x07\x04\x06\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x07\t\n\n\
-    \x0b\n\x04\x05\0\x02\x01\x12\x03\x08\x04\x0c\n\x0c\n\x05\x05\0\x02\x01\
-    \x01\x12\x03\x08\x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x08\n\x0b\
-    \n\x0b\n\x04\x05\0\x02\x02\x12\x03\t\x04\x11\n\x0c\n\x05\x05\0\x02\x02\
-    \x01\x12\x03\t\x04\x0c\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\t\x0f\x10b\
+    FFIStatusCodeR\x04code\x12\x1d\n\nrequest_id\x18\x03\x20\x01(\tR\treques\
+    tId\"\x82\x01\n\x0eFFIStreamChunk\x12\x1b\n\tstream_id\x18\x01\x20\x01(\
+    \tR\x08streamId\x12\x18\n\x07payload\x18\x02\x20\x01(\x0cR\x07payload\
+    \x12\"\n\x04code\x18\x03\x20\x01(\x0e2\x0e.FFIStatusCodeR\x04code\x12\
+    \x15\n\x06is_end\x18\x04\x20\x01(\x08R\x05isEnd\"\x83\x01\n\x10FFIBatchR\
+    esponse\x12*\n\tresponses\x18\x01\x20\x03(\x0b2\x0c.FFIResponseR\trespon\
+    ses\x12\"\n\x04code\x18\x02\x20\x01(\x0e2\x0e.FFIStatusCodeR\x04code\x12\
+    \x1f\n\x0brolled_back\x18\x03\x20\x01(\x08R\nrolledBack*;\n\rFFIStatusCo\
+    de\x12\x06\n\x02Ok\x10\0\x12\x07\n\x03Err\x10\x01\x12\x0c\n\x08Internal\
+    \x10\x02\x12\x0b\n\x07Timeout\x10\x03J\xca\x06\n\x06\x12\x04\0\0\x17\x01\
+    \n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x06\x01\n\
+    \n\n\x03\x04\0\x01\x12\x03\x02\x08\x13\n\x0b\n\x04\x04\0\x02\0\x12\x03\
+    \x03\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\x05\
+    \x04\0\x02\0\x01\x12\x03\x03\n\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\
+    \x03\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x1b\n\x0c\n\x05\
+    \x04\0\x02\x01\x06\x12\x03\x04\x04\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
+    \x03\x04\x12\x16\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x19\x1a\n\x0b\
+    \n\x04\x04\0\x02\x02\x12\x03\x05\x04\x1a\n\x0c\n\x05\x04\0\x02\x02\x05\
+    \x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x15\n\
+    \x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x18\x19\n\n\n\x02\x04\x01\x12\
+    \x04\x07\0\x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x16\n\x0b\n\x04\
+    \x04\x01\x02\0\x12\x03\x08\x04\x19\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\
+    \x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x0b\x14\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\x08\x17\x18\n\x0b\n\x04\x04\x01\x02\x01\x12\
+    \x03\t\x04\x16\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\t\x04\t\n\x0c\n\
+    \x05\x04\x01\x02\x01\x01\x12\x03\t\n\x11\n\x0c\n\x05\x04\x01\x02\x01\x03\
+    \x12\x03\t\x14\x15\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\n\x04\x1b\n\x0c\n\
+    \x05\x04\x01\x02\x02\x06\x12\x03\n\x04\x11\n\x0c\n\x05\x04\x01\x02\x02\
+    \x01\x12\x03\n\x12\x16\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\n\x19\x1a\
+    \n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x0b\x04\x14\n\x0c\n\x05\x04\x01\x02\
+    \x03\x05\x12\x03\x0b\x04\x08\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0b\
+    \t\x0f\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0b\x12\x13\n\n\n\x02\x04\
+    \x02\x12\x04\r\0\x11\x01\n\n\n\x03\x04\x02\x01\x12\x03\r\x08\x18\n\x0b\n\
+    \x04\x04\x02\x02\0\x12\x03\x0e\x04'\n\x0c\n\x05\x04\x02\x02\0\x04\x12\
+    \x03\x0e\x04\x0c\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x0e\r\x18\n\x0c\n\
+    \x05\x04\x02\x02\0\x01\x12\x03\x0e\x19\"\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03\x0e%&\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\x04\x1b\n\x0c\n\
+    \x05\x04\x02\x02\x01\x06\x12\x03\x0f\x04\x11\n\x0c\n\x05\x04\x02\x02\x01\
+    \x01\x12\x03\x0f\x12\x16\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f\x19\
+    \x1a\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x10\x04\x19\n\x0c\n\x05\x04\x02\
+    \x02\x02\x05\x12\x03\x10\x04\x08\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\
+    \x10\t\x14\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x10\x17\x18\n\n\n\x02\
+    \x05\0\x12\x04\x12\0\x17\x01\n\n\n\x03\x05\0\x01\x12\x03\x12\x05\x12\n\
+    \x0b\n\x04\x05\0\x02\0\x12\x03\x13\x04\x0b\n\x0c\n\x05\x05\0\x02\0\x01\
+    \x12\x03\x13\x04\x06\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x13\t\n\n\x0b\n\
+    \x04\x05\0\x02\x01\x12\x03\x14\x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\
+    \x03\x14\x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x14\n\x0b\n\x0b\n\
+    \x04\x05\0\x02\x02\x12\x03\x15\x04\x11\n\x0c\n\x05\x05\0\x02\x02\x01\x12\
+    \x03\x15\x04\x0c\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x15\x0f\x10\n\x0b\
+    \n\x04\x05\0\x02\x03\x12\x03\x16\x04\x10\n\x0c\n\x05\x05\0\x02\x03\x01\
+    \x12\x03\x16\x04\x0b\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x16\x0e\x0fb\
     \x06proto3\
 ";
 
@@ -1,3 +1,5 @@
 mod stream_sender;
+mod subscription;
 
 pub use stream_sender::*;
+pub use subscription::*;
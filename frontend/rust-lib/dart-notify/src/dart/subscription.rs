@@ -0,0 +1,152 @@
+use crate::entities::SubscribeObject;
+use bytes::Bytes;
+use lazy_static::lazy_static;
+use std::{
+    convert::TryInto,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+        RwLock,
+    },
+};
+
+lazy_static! {
+    static ref SUBSCRIPTIONS: RwLock<Vec<Subscription>> = RwLock::new(vec![]);
+}
+
+static NEXT_SUBSCRIPTION_ID: AtomicI64 = AtomicI64::new(1);
+
+// Receives the notifications that match the filter of the subscription. Returns false if the receiver
+// is gone, e.g. the port was closed by dart, the subscription is removed then.
+pub trait NotificationSink: Send + Sync {
+    fn post(&self, bytes: Vec<u8>) -> bool;
+}
+
+pub struct IsolateSink {
+    isolate: allo_isolate::Isolate,
+}
+
+impl IsolateSink {
+    pub fn new(port: i64) -> Self {
+        Self {
+            isolate: allo_isolate::Isolate::new(port),
+        }
+    }
+}
+
+impl NotificationSink for IsolateSink {
+    fn post(&self, bytes: Vec<u8>) -> bool { self.isolate.post(bytes) }
+}
+
+// Matches the notifications of the source, e.g. "Workspace". The id narrows it down to the notifications
+// of one object, e.g. the view that the widget shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionFilter {
+    source: String,
+    id: Option<String>,
+}
+
+impl SubscriptionFilter {
+    pub fn new(source: &str) -> Self {
+        Self {
+            source: source.to_owned(),
+            id: None,
+        }
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_owned());
+        self
+    }
+
+    fn matches(&self, subject: &SubscribeObject) -> bool {
+        if self.source != subject.source {
+            return false;
+        }
+
+        match &self.id {
+            None => true,
+            Some(id) => id == &subject.id,
+        }
+    }
+}
+
+struct Subscription {
+    id: i64,
+    filter: SubscriptionFilter,
+    sink: Arc<dyn NotificationSink>,
+}
+
+pub struct NotificationRegistry();
+
+impl NotificationRegistry {
+    // Returns the id of the subscription that is used to unsubscribe
+    pub fn subscribe(filter: SubscriptionFilter, sink: Arc<dyn NotificationSink>) -> i64 {
+        let id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::SeqCst);
+        match SUBSCRIPTIONS.write() {
+            Ok(mut subscriptions) => subscriptions.push(Subscription { id, filter, sink }),
+            Err(e) => log::error!("Get the notification subscriptions lock fail. {:?}", e),
+        }
+        id
+    }
+
+    // Returns false if the subscription doesn't exist
+    pub fn unsubscribe(subscription_id: i64) -> bool { remove_subscriptions(&[subscription_id]) > 0 }
+
+    // Removes all the subscriptions, e.g. dart restarted and the ports of the old isolate are gone
+    pub fn clear() {
+        match SUBSCRIPTIONS.write() {
+            Ok(mut subscriptions) => subscriptions.clear(),
+            Err(e) => log::error!("Get the notification subscriptions lock fail. {:?}", e),
+        }
+    }
+
+    pub(crate) fn post(subject: &SubscribeObject) {
+        let mut closed_ids = vec![];
+        match SUBSCRIPTIONS.read() {
+            Ok(subscriptions) => {
+                let matched = subscriptions
+                    .iter()
+                    .filter(|subscription| subscription.filter.matches(subject))
+                    .collect::<Vec<&Subscription>>();
+                if matched.is_empty() {
+                    return;
+                }
+
+                // The subject is serialized once for all the subscriptions that match it
+                let bytes: Bytes = match subject.clone().try_into() {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        log::error!("Serialize the notification failed: {:?}", e);
+                        return;
+                    },
+                };
+                for subscription in matched {
+                    if !subscription.sink.post(bytes.to_vec()) {
+                        closed_ids.push(subscription.id);
+                    }
+                }
+            },
+            Err(e) => log::error!("Get the notification subscriptions lock fail. {:?}", e),
+        }
+
+        if !closed_ids.is_empty() {
+            log::debug!("Remove the closed notification subscriptions: {:?}", closed_ids);
+            remove_subscriptions(&closed_ids);
+        }
+    }
+}
+
+fn remove_subscriptions(subscription_ids: &[i64]) -> usize {
+    match SUBSCRIPTIONS.write() {
+        Ok(mut subscriptions) => {
+            let len = subscriptions.len();
+            subscriptions.retain(|subscription| !subscription_ids.contains(&subscription.id));
+            len - subscriptions.len()
+        },
+        Err(e) => {
+            log::error!("Get the notification subscriptions lock fail. {:?}", e);
+            0
+        },
+    }
+}
@@ -4,7 +4,10 @@ pub mod dart;
 pub mod entities;
 mod protobuf;
 
-use crate::{dart::DartStreamSender, entities::SubscribeObject};
+use crate::{
+    dart::{DartStreamSender, NotificationRegistry},
+    entities::SubscribeObject,
+};
 use lib_dispatch::prelude::ToBytes;
 
 pub struct DartNotifyBuilder {
@@ -72,6 +75,7 @@ impl DartNotifyBuilder {
             error,
         };
 
+        NotificationRegistry::post(&subject);
         match DartStreamSender::post(subject) {
             Ok(_) => {},
             Err(error) => log::error!("Send observable subject failed: {}", error),
@@ -0,0 +1,86 @@
+use dart_notify::{
+    dart::{NotificationRegistry, NotificationSink, SubscriptionFilter},
+    entities::SubscribeObject,
+    DartNotifyBuilder,
+};
+use std::{
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+        Mutex,
+    },
+};
+
+#[derive(Default)]
+struct MockSink {
+    subjects: Mutex<Vec<SubscribeObject>>,
+    is_closed: AtomicBool,
+}
+
+impl MockSink {
+    fn ids(&self) -> Vec<String> {
+        self.subjects
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|subject| subject.id.clone())
+            .collect()
+    }
+}
+
+impl NotificationSink for MockSink {
+    fn post(&self, bytes: Vec<u8>) -> bool {
+        if self.is_closed.load(Ordering::SeqCst) {
+            return false;
+        }
+        let subject = SubscribeObject::try_from(bytes::Bytes::from(bytes)).unwrap();
+        self.subjects.lock().unwrap().push(subject);
+        true
+    }
+}
+
+// Each test uses its own source, the registry is shared by the tests that run in parallel
+fn notify(source: &str, id: &str) { DartNotifyBuilder::new(id, 0, source).send(); }
+
+#[test]
+fn subscribe_source() {
+    let sink = Arc::new(MockSink::default());
+    let _ = NotificationRegistry::subscribe(SubscriptionFilter::new("source_a"), sink.clone());
+    notify("source_a", "1");
+    notify("other_source_a", "2");
+    notify("source_a", "3");
+    assert_eq!(sink.ids(), vec!["1", "3"]);
+}
+
+#[test]
+fn subscribe_object() {
+    let view_sink = Arc::new(MockSink::default());
+    let other_sink = Arc::new(MockSink::default());
+    let _ = NotificationRegistry::subscribe(SubscriptionFilter::new("source_b").id("view"), view_sink.clone());
+    let _ = NotificationRegistry::subscribe(SubscriptionFilter::new("source_b").id("other"), other_sink.clone());
+    notify("source_b", "view");
+    notify("source_b", "another_view");
+    assert_eq!(view_sink.ids(), vec!["view"]);
+    assert_eq!(other_sink.ids().is_empty(), true);
+}
+
+#[test]
+fn unsubscribe() {
+    let sink = Arc::new(MockSink::default());
+    let subscription_id = NotificationRegistry::subscribe(SubscriptionFilter::new("source_c"), sink.clone());
+    notify("source_c", "1");
+    assert_eq!(NotificationRegistry::unsubscribe(subscription_id), true);
+    assert_eq!(NotificationRegistry::unsubscribe(subscription_id), false);
+    notify("source_c", "2");
+    assert_eq!(sink.ids(), vec!["1"]);
+}
+
+#[test]
+fn closed_sink_is_removed() {
+    let sink = Arc::new(MockSink::default());
+    let subscription_id = NotificationRegistry::subscribe(SubscriptionFilter::new("source_d"), sink.clone());
+    sink.is_closed.store(true, Ordering::SeqCst);
+    notify("source_d", "1");
+    assert_eq!(NotificationRegistry::unsubscribe(subscription_id), false);
+}
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE workspace_table ADD COLUMN archived Boolean NOT NULL DEFAULT false;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE VIRTUAL TABLE search_index USING fts5(id UNINDEXED, title, body);
\ No newline at end of file
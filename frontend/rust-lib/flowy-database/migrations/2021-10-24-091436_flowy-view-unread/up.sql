@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE view_unread_table (
+    view_id TEXT NOT NULL PRIMARY KEY,
+    changed_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
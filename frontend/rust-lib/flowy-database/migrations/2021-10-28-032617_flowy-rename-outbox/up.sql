@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE rename_outbox_table (
+    object_id TEXT NOT NULL PRIMARY KEY,
+    ty INTEGER NOT NULL DEFAULT 0,
+    base_name TEXT NOT NULL DEFAULT '',
+    base_modified_time BIGINT NOT NULL DEFAULT 0,
+    name TEXT NOT NULL DEFAULT '',
+    renamed_time BIGINT NOT NULL DEFAULT 0
+);
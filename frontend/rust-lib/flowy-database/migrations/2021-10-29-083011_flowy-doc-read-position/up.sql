@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE doc_read_position_table (
+    doc_id TEXT NOT NULL PRIMARY KEY,
+    read_offset BIGINT NOT NULL DEFAULT 0,
+    modified_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1 @@
+-- This file should undo anything in `up.sql`
\ No newline at end of file
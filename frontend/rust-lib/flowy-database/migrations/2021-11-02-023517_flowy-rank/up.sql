@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE app_table ADD COLUMN rank TEXT NOT NULL DEFAULT '';
+ALTER TABLE view_table ADD COLUMN rank TEXT NOT NULL DEFAULT '';
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE recent_view_table (
+    view_id TEXT NOT NULL PRIMARY KEY,
+    open_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE workspace_table ADD COLUMN role INTEGER NOT NULL DEFAULT 0;
+CREATE TABLE workspace_setting_table (
+    workspace_id TEXT NOT NULL PRIMARY KEY,
+    latest_view_id TEXT NOT NULL DEFAULT '',
+    last_open_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE doc_checkpoint_table (
+    doc_id TEXT NOT NULL PRIMARY KEY,
+    base_rev_id BIGINT NOT NULL DEFAULT 0,
+    rev_id BIGINT NOT NULL DEFAULT 0,
+    data BLOB NOT NULL DEFAULT (x''),
+    modified_time BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE doc_task_table (
+    doc_id TEXT NOT NULL PRIMARY KEY,
+    rev_id BIGINT NOT NULL DEFAULT 0,
+    data TEXT NOT NULL DEFAULT ''
+);
\ No newline at end of file
@@ -11,14 +11,24 @@ pub use diesel::*;
 extern crate diesel_derives;
 pub use diesel_derives::*;
 
-#[macro_use]
-extern crate diesel_migrations;
-
-pub use lib_sqlite::{ConnectionPool, DBConnection, Database};
+pub use lib_sqlite::{
+    is_encrypted,
+    set_connection_observer,
+    ConnectionPool,
+    DBConnection,
+    Database,
+    FtsColumn,
+    FtsLanguage,
+    FtsMatch,
+    FtsTable,
+    Migration,
+    MigrationMode,
+    MigrationReport,
+    MigrationRunner,
+};
 pub type Error = diesel::result::Error;
 
-use diesel_migrations::*;
-use lib_sqlite::PoolConfig;
+use lib_sqlite::{embed_migration, PoolConfig};
 use std::{fmt::Debug, io, path::Path};
 
 pub mod prelude {
@@ -26,20 +36,85 @@ pub mod prelude {
     pub use diesel::{query_dsl::*, BelongingToDsl, ExpressionMethods, RunQueryDsl};
 }
 
-embed_migrations!("../flowy-database/migrations/");
 pub const DB_NAME: &str = "flowy-database.db";
 
+lazy_static::lazy_static! {
+    // Indexes the names of the views and the text of the documents, both are keyed by the view id
+    pub static ref SEARCH_INDEX: FtsTable = FtsTable::new("search_index");
+}
+
+// The new migration is appended to the list, the applied ones must not be edited
+pub fn migrations() -> Vec<Migration> {
+    vec![
+        embed_migration!("../migrations", "2021-07-09-063045_flowy-user"),
+        embed_migration!("../migrations", "2021-07-14-022241_flowy-user"),
+        embed_migration!("../migrations", "2021-07-22-234458_flowy-editor"),
+        embed_migration!("../migrations", "2021-09-22-074638_flowy-doc-op"),
+        embed_migration!("../migrations", "2021-10-18-064012_flowy-workspace"),
+        embed_migration!("../migrations", "2021-10-20-032437_flowy-doc-snapshot"),
+        embed_migration!("../migrations", "2021-10-23-031512_flowy-search"),
+        embed_migration!("../migrations", "2021-10-24-091436_flowy-view-unread"),
+        embed_migration!("../migrations", "2021-10-28-032617_flowy-rename-outbox"),
+        embed_migration!("../migrations", "2021-10-29-083011_flowy-doc-read-position"),
+        embed_migration!("../migrations", "2021-11-02-023517_flowy-rank"),
+        embed_migration!("../migrations", "2021-11-05-031247_flowy-favorite"),
+        embed_migration!("../migrations", "2021-11-07-013224_flowy-recent-view"),
+        embed_migration!("../migrations", "2021-11-08-052114_flowy-workspace-setting"),
+    ]
+}
+
 pub fn init(storage_path: &str) -> Result<Database, io::Error> {
-    if !Path::new(storage_path).exists() {
-        std::fs::create_dir_all(storage_path)?;
-    }
+    let database = open(storage_path)?;
+    let conn = database.get_connection().map_err(as_io_error)?;
+    let _ = run_migrations(&*conn, MigrationMode::Apply)?;
+    Ok(database)
+}
+
+// The plaintext database that the older versions created is encrypted once, before it's opened with the
+// key. The migrations run after that, on the encrypted database.
+#[cfg(feature = "sqlcipher")]
+pub fn init_encrypted(storage_path: &str, key: &str) -> Result<Database, io::Error> {
+    let _ = create_dir(storage_path)?;
+    let db_path = lib_sqlite::db_file_uri(storage_path, DB_NAME);
+    let _ = lib_sqlite::encrypt_database(&db_path, key).map_err(as_io_error)?;
     let pool_config = PoolConfig::default();
-    let database = Database::new(storage_path, DB_NAME, pool_config).map_err(as_io_error)?;
+    let database = Database::new_encrypted(storage_path, DB_NAME, key, pool_config).map_err(as_io_error)?;
     let conn = database.get_connection().map_err(as_io_error)?;
-    let _ = embedded_migrations::run(&*conn).map_err(as_io_error)?;
+    let _ = run_migrations(&*conn, MigrationMode::Apply)?;
     Ok(database)
 }
 
+// Reports the migrations that the database at the storage_path would run, without changing it
+pub fn validate(storage_path: &str) -> Result<MigrationReport, io::Error> {
+    let database = open(storage_path)?;
+    let conn = database.get_connection().map_err(as_io_error)?;
+    run_migrations(&*conn, MigrationMode::DryRun)
+}
+
+pub fn run_migrations(conn: &SqliteConnection, mode: MigrationMode) -> Result<MigrationReport, io::Error> {
+    let runner = MigrationRunner::new(migrations()).map_err(as_io_error)?;
+    runner.run(conn, mode).map_err(as_io_error)
+}
+
+fn open(storage_path: &str) -> Result<Database, io::Error> {
+    let _ = create_dir(storage_path)?;
+    // Without the key the encrypted database looks like a broken file, the error tells what's wrong instead
+    let db_path = lib_sqlite::db_file_uri(storage_path, DB_NAME);
+    if lib_sqlite::is_encrypted(&db_path).map_err(as_io_error)? {
+        let msg = format!("{} is encrypted, it can only be opened with the key", db_path);
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, msg));
+    }
+    let pool_config = PoolConfig::default();
+    Database::new(storage_path, DB_NAME, pool_config).map_err(as_io_error)
+}
+
+fn create_dir(storage_path: &str) -> Result<(), io::Error> {
+    if !Path::new(storage_path).exists() {
+        std::fs::create_dir_all(storage_path)?;
+    }
+    Ok(())
+}
+
 fn as_io_error<E>(e: E) -> io::Error
 where
     E: Into<lib_sqlite::Error> + Debug,
@@ -10,6 +10,36 @@ table! {
         create_time -> BigInt,
         version -> BigInt,
         is_trash -> Bool,
+        rank -> Text,
+    }
+}
+
+table! {
+    doc_checkpoint_table (doc_id) {
+        doc_id -> Text,
+        base_rev_id -> BigInt,
+        rev_id -> BigInt,
+        data -> Binary,
+        modified_time -> BigInt,
+    }
+}
+
+table! {
+    doc_read_position_table (doc_id) {
+        doc_id -> Text,
+        read_offset -> BigInt,
+        modified_time -> BigInt,
+    }
+}
+
+table! {
+    doc_snapshot_table (id) {
+        id -> Integer,
+        doc_id -> Text,
+        name -> Text,
+        rev_id -> BigInt,
+        data -> Text,
+        create_time -> BigInt,
     }
 }
 
@@ -21,6 +51,39 @@ table! {
     }
 }
 
+table! {
+    doc_task_table (doc_id) {
+        doc_id -> Text,
+        rev_id -> BigInt,
+        data -> Text,
+    }
+}
+
+table! {
+    favorite_table (view_id) {
+        view_id -> Text,
+        create_time -> BigInt,
+    }
+}
+
+table! {
+    recent_view_table (view_id) {
+        view_id -> Text,
+        open_time -> BigInt,
+    }
+}
+
+table! {
+    rename_outbox_table (object_id) {
+        object_id -> Text,
+        ty -> Integer,
+        base_name -> Text,
+        base_modified_time -> BigInt,
+        name -> Text,
+        renamed_time -> BigInt,
+    }
+}
+
 table! {
     rev_table (id) {
         id -> Integer,
@@ -66,6 +129,22 @@ table! {
         view_type -> Integer,
         version -> BigInt,
         is_trash -> Bool,
+        rank -> Text,
+    }
+}
+
+table! {
+    view_unread_table (view_id) {
+        view_id -> Text,
+        changed_time -> BigInt,
+    }
+}
+
+table! {
+    workspace_setting_table (workspace_id) {
+        workspace_id -> Text,
+        latest_view_id -> Text,
+        last_open_time -> BigInt,
     }
 }
 
@@ -78,15 +157,26 @@ table! {
         create_time -> BigInt,
         user_id -> Text,
         version -> BigInt,
+        archived -> Bool,
+        role -> Integer,
     }
 }
 
 allow_tables_to_appear_in_same_query!(
     app_table,
+    doc_checkpoint_table,
+    doc_read_position_table,
+    doc_snapshot_table,
     doc_table,
+    doc_task_table,
+    favorite_table,
+    recent_view_table,
+    rename_outbox_table,
     rev_table,
     trash_table,
     user_table,
     view_table,
+    view_unread_table,
+    workspace_setting_table,
     workspace_table,
 );
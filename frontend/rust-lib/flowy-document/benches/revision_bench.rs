@@ -0,0 +1,102 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use flowy_database::{
+    insert_into,
+    prelude::*,
+    schema::{doc_checkpoint_table, rev_table},
+    ConnectionPool,
+};
+use flowy_document::{
+    errors::DocError,
+    services::doc::{RevisionServer, RevisionStore},
+};
+use flowy_document_infra::entities::doc::{compose_revisions, Doc, Revision};
+use flowy_test::fixture::typing_revisions;
+use lib_dispatch::prelude::BlockingPool;
+use lib_infra::future::ResultFuture;
+use lib_ot::core::Delta;
+use std::sync::Arc;
+use tokio::{runtime::Runtime, sync::mpsc};
+
+const DOC_ID: &str = "bench_doc";
+const REVISION_COUNT: usize = 10_000;
+// Same as the KEPT_REVISION_COUNT of the revision store
+const KEPT_REVISION_COUNT: usize = 100;
+// The value of RevState::Acked and RevTableType::Local
+const ACKED_STATE: i32 = 1;
+const LOCAL_TYPE: i32 = 0;
+
+struct LocalOnlyServer;
+
+impl RevisionServer for LocalOnlyServer {
+    fn fetch_document_from_remote(&self, doc_id: &str) -> ResultFuture<Doc, DocError> {
+        let msg = format!("{} isn't on the server", doc_id);
+        ResultFuture::new(async move { Err(DocError::record_not_found().context(msg)) })
+    }
+}
+
+// Creates the database that stores the acked revisions of the document. The ones before the kept revisions are
+// composed into the checkpoint if it's compacted, like the revision store does after the document is opened.
+fn open_database(name: &str, revisions: &[Revision], compacted: bool) -> Arc<ConnectionPool> {
+    let dir = std::env::temp_dir().join(format!("flowy-document-bench-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let database = flowy_database::init(dir.to_str().unwrap()).unwrap();
+    let pool = database.get_pool();
+
+    let (compacted_revisions, tail) = match compacted {
+        true => revisions.split_at(revisions.len() - KEPT_REVISION_COUNT),
+        false => revisions.split_at(0),
+    };
+    let conn = &*pool.get().unwrap();
+    conn.immediate_transaction::<_, flowy_database::Error, _>(|| {
+        if let Some(last) = compacted_revisions.last() {
+            let checkpoint = compose_revisions(Delta::new(), compacted_revisions).unwrap();
+            let _ = insert_into(doc_checkpoint_table::table)
+                .values((
+                    doc_checkpoint_table::doc_id.eq(DOC_ID),
+                    doc_checkpoint_table::base_rev_id.eq(last.base_rev_id),
+                    doc_checkpoint_table::rev_id.eq(last.rev_id),
+                    doc_checkpoint_table::data.eq(checkpoint.to_bytes().to_vec()),
+                    doc_checkpoint_table::modified_time.eq(0_i64),
+                ))
+                .execute(conn)?;
+        }
+        for revision in tail {
+            let _ = insert_into(rev_table::table)
+                .values((
+                    rev_table::doc_id.eq(DOC_ID),
+                    rev_table::base_rev_id.eq(revision.base_rev_id),
+                    rev_table::rev_id.eq(revision.rev_id),
+                    rev_table::data.eq(revision.delta_data.clone()),
+                    rev_table::state.eq(ACKED_STATE),
+                    rev_table::ty.eq(LOCAL_TYPE),
+                ))
+                .execute(conn)?;
+        }
+        Ok(())
+    })
+    .unwrap();
+    pool
+}
+
+// Opening the document replays the whole history without the checkpoint, and the checkpoint plus the kept
+// revisions with it.
+fn fetch_document_bench(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let revisions = typing_revisions(DOC_ID, REVISION_COUNT);
+    let mut group = c.benchmark_group("fetch_document");
+    group.sample_size(10);
+    for (name, compacted) in [("replay_history", false), ("checkpoint_and_tail", true)].iter() {
+        let pool = open_database(name, &revisions, *compacted);
+        let store = runtime.block_on(async {
+            let (ws_sender, _ws_receiver) = mpsc::unbounded_channel();
+            RevisionStore::new(DOC_ID, pool, BlockingPool::new(1), Arc::new(LocalOnlyServer), ws_sender)
+        });
+        group.bench_function(BenchmarkId::new(*name, REVISION_COUNT), |b| {
+            b.iter(|| runtime.block_on(store.fetch_document()).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, fetch_document_bench);
+criterion_main!(benches);
@@ -48,6 +48,7 @@ impl DocError {
     static_doc_error!(unauthorized, ErrorCode::UserUnauthorized);
     static_doc_error!(record_not_found, ErrorCode::DocNotfound);
     static_doc_error!(duplicate_rev, ErrorCode::DuplicateRevision);
+    static_doc_error!(replace_conflict, ErrorCode::ReplaceConflict);
 }
 
 pub fn internal_error<T>(e: T) -> DocError
@@ -68,9 +69,18 @@ pub enum ErrorCode {
     #[display(fmt = "Duplicate revision")]
     DuplicateRevision = 2,
 
+    #[display(fmt = "The text to replace has been changed")]
+    ReplaceConflict   = 3,
+
     #[display(fmt = "UserUnauthorized")]
     UserUnauthorized  = 10,
 
+    #[display(fmt = "Too many requests, try again later")]
+    TooManyRequests   = 11,
+
+    #[display(fmt = "The request is too large")]
+    PayloadTooLarge   = 12,
+
     #[display(fmt = "InternalError")]
     InternalError     = 1000,
 }
@@ -122,6 +132,8 @@ fn server_error_to_doc_error(code: ServerErrorCode) -> ErrorCode {
     match code {
         ServerErrorCode::UserUnauthorized => ErrorCode::UserUnauthorized,
         ServerErrorCode::RecordNotFound => ErrorCode::DocNotfound,
+        ServerErrorCode::TooManyRequests => ErrorCode::TooManyRequests,
+        ServerErrorCode::PayloadOverflow => ErrorCode::PayloadTooLarge,
         _ => ErrorCode::InternalError,
     }
 }
@@ -1,14 +1,43 @@
 use crate::{
-    errors::DocError,
+    errors::{internal_error, DocError},
     services::{
-        doc::{doc_controller::DocController, ClientEditDoc},
+        doc::{
+            doc_controller::DocController,
+            merge_read_positions,
+            read_all_positions,
+            read_input_rule_setting,
+            read_position,
+            save_input_rule_setting,
+            save_read_position,
+            ClientEditDoc,
+        },
         server::construct_doc_server,
         ws::WsDocumentManager,
     },
 };
 use backend_service::config::ServerConfig;
 use flowy_database::ConnectionPool;
-use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use lib_dispatch::prelude::BlockingPool;
+use lib_infra::bus::EventBus;
+use lib_ot::core::Delta;
+use flowy_document_infra::entities::doc::{
+    AttachmentFile,
+    AttachmentUploadProgress,
+    DocDelta,
+    DocIdentifier,
+    DocPrefetchProgress,
+    DocReadPosition,
+    DocSelection,
+    DocSnapshotIdentifier,
+    DocTextMatch,
+    DocTextReplacement,
+    DocUndoState,
+    InputRuleSetting,
+    RepeatedDocSnapshot,
+    RepeatedDocTask,
+    RepeatedDocTextRun,
+};
+use parking_lot::Mutex;
 use std::sync::Arc;
 
 pub trait DocumentUser: Send + Sync {
@@ -19,8 +48,9 @@ pub trait DocumentUser: Send + Sync {
 }
 
 pub struct FlowyDocument {
-    doc_ctrl: Arc<DocController>,
+    controller: Arc<DocController>,
     user: Arc<dyn DocumentUser>,
+    initialized: Mutex<bool>,
 }
 
 impl FlowyDocument {
@@ -28,38 +58,208 @@ impl FlowyDocument {
         user: Arc<dyn DocumentUser>,
         ws_manager: Arc<WsDocumentManager>,
         server_config: &ServerConfig,
+        bus: EventBus,
+        blocking_pool: BlockingPool,
     ) -> FlowyDocument {
         let server = construct_doc_server(server_config);
-        let doc_ctrl = Arc::new(DocController::new(server.clone(), user.clone(), ws_manager.clone()));
-        Self { doc_ctrl, user }
+        let controller = Arc::new(DocController::new(
+            server.clone(),
+            user.clone(),
+            ws_manager.clone(),
+            bus,
+            blocking_pool,
+        ));
+        Self {
+            controller,
+            user,
+            initialized: Mutex::new(false),
+        }
     }
 
+    // Listens to the websocket, so the prefetched documents are received and the unsynced revisions are pushed
+    // when it's connected, even if no document is opened. It's called by FlowySDK::new.
+    pub fn listen(&self) { self.controller.listen(); }
+
+    // The document engine is initialized by the first call that needs it instead of at startup. The call
+    // fails if the init fails and the next call tries again.
     pub fn init(&self) -> Result<(), DocError> {
-        let _ = self.doc_ctrl.init()?;
+        let mut initialized = self.initialized.lock();
+        if !*initialized {
+            let instant = std::time::Instant::now();
+            let _ = self.controller.init()?;
+            tracing::debug!("Document engine initialized in {:?}", instant.elapsed());
+            *initialized = true;
+        }
         Ok(())
     }
 
+    fn doc_ctrl(&self) -> Result<&Arc<DocController>, DocError> {
+        let _ = self.init()?;
+        Ok(&self.controller)
+    }
+
     pub fn delete(&self, params: DocIdentifier) -> Result<(), DocError> {
-        let _ = self.doc_ctrl.delete(params)?;
+        let _ = self.doc_ctrl()?.delete(params)?;
         Ok(())
     }
 
     pub async fn open(&self, params: DocIdentifier) -> Result<Arc<ClientEditDoc>, DocError> {
-        let edit_context = self.doc_ctrl.open(params, self.user.db_pool()?).await?;
+        let edit_context = self.doc_ctrl()?.open(params, self.user.db_pool()?).await?;
         Ok(edit_context)
     }
 
     pub async fn close(&self, params: DocIdentifier) -> Result<(), DocError> {
-        let _ = self.doc_ctrl.close(&params.doc_id)?;
+        let _ = self.doc_ctrl()?.close(&params.doc_id)?;
         Ok(())
     }
 
+    pub async fn reset(&self, params: DocIdentifier) -> Result<DocDelta, DocError> {
+        let delta = self.doc_ctrl()?.reset(params, self.user.db_pool()?).await?;
+        Ok(delta)
+    }
+
+    // Downloads the documents so they are available offline, the progress is notified with the id.
+    pub fn prefetch(&self, id: &str, doc_ids: Vec<String>) -> Result<DocPrefetchProgress, DocError> {
+        self.doc_ctrl()?.prefetch(id, doc_ids)
+    }
+
+    // Uploads the file to embed it in the document, the chunks are uploaded in the background and the
+    // progress is notified with the attachment_id.
+    pub async fn upload_attachment(&self, doc_id: &str, path: &str) -> Result<AttachmentUploadProgress, DocError> {
+        self.doc_ctrl()?.upload_attachment(doc_id, path).await
+    }
+
+    // Returns the file of the attachment, the attachment that isn't in the local cache is downloaded first
+    pub async fn read_attachment(&self, attachment_id: &str) -> Result<AttachmentFile, DocError> {
+        self.doc_ctrl()?.read_attachment(attachment_id).await
+    }
+
+    // Reads the unchecked todo items of the documents that are stored locally.
+    pub async fn read_tasks(&self, doc_ids: Vec<String>) -> Result<RepeatedDocTask, DocError> {
+        let items = self.doc_ctrl()?.read_tasks(doc_ids).await?;
+        Ok(RepeatedDocTask { items })
+    }
+
+    // Indexes the text of the documents that are stored locally for the full-text search, see SEARCH_INDEX.
+    pub async fn index_docs(&self, doc_ids: Vec<String>) -> Result<(), DocError> {
+        self.doc_ctrl()?.index_docs(doc_ids).await
+    }
+
+    // Finds the words of the document that match the query, so the search results can show where the
+    // document matches without opening it.
+    pub async fn find_text_matches(
+        &self,
+        doc_id: &str,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<DocTextMatch>, DocError> {
+        self.doc_ctrl()?.find_text_matches(doc_id, query, limit).await
+    }
+
+    // Exposes the text of the document to the spell checkers, see DocTextRun.
+    pub async fn read_text_runs(&self, params: DocIdentifier) -> Result<RepeatedDocTextRun, DocError> {
+        self.doc_ctrl()?.read_text_runs(params, self.user.db_pool()?).await
+    }
+
+    pub async fn replace_text(&self, replacement: DocTextReplacement) -> Result<DocDelta, DocError> {
+        self.doc_ctrl()?.replace_text(replacement, self.user.db_pool()?).await
+    }
+
+    // The selection is broadcast to the other users who opened the document, they receive it with the
+    // DocPresenceChanged notification.
+    pub async fn update_selection(&self, selection: DocSelection) -> Result<(), DocError> {
+        self.doc_ctrl()?.update_selection(selection, self.user.db_pool()?).await
+    }
+
+    // The undo and the redo are synced with the server as new revisions, the history is kept in memory
+    // until the document is closed.
+    pub async fn undo(&self, params: DocIdentifier) -> Result<DocDelta, DocError> {
+        self.doc_ctrl()?.undo(params, self.user.db_pool()?).await
+    }
+
+    pub async fn redo(&self, params: DocIdentifier) -> Result<DocDelta, DocError> {
+        self.doc_ctrl()?.redo(params, self.user.db_pool()?).await
+    }
+
+    pub async fn read_undo_state(&self, params: DocIdentifier) -> Result<DocUndoState, DocError> {
+        self.doc_ctrl()?.read_undo_state(params, self.user.db_pool()?).await
+    }
+
+    // The snapshots are taken automatically before the document is rewritten, e.g. by reset.
+    pub fn read_snapshots(&self, params: DocIdentifier) -> Result<RepeatedDocSnapshot, DocError> {
+        let items = self.doc_ctrl()?.read_snapshots(&params.doc_id)?;
+        Ok(RepeatedDocSnapshot { items })
+    }
+
+    pub async fn revert_to_snapshot(&self, params: DocSnapshotIdentifier) -> Result<DocDelta, DocError> {
+        self.doc_ctrl()?.revert_to_snapshot(params, self.user.db_pool()?).await
+    }
+
+    pub async fn query_history(&self, params: DocIdentifier) -> Result<RepeatedDocSnapshot, DocError> {
+        self.doc_ctrl()?.query_history(params).await
+    }
+
+    pub async fn restore_version(&self, params: DocSnapshotIdentifier) -> Result<DocDelta, DocError> {
+        self.doc_ctrl()?.restore_version(params, self.user.db_pool()?).await
+    }
+
+    // Replaces the content of the document with the markdown. The document is snapshotted first, so the
+    // import can be reverted.
+    pub async fn import_markdown(&self, params: DocIdentifier, markdown: &str) -> Result<DocDelta, DocError> {
+        self
+            .doc_ctrl()?
+            .import_markdown(params, markdown, self.user.db_pool()?)
+            .await
+    }
+
+    // Replaces the content of the document, e.g. with the delta that is converted from an imported file
+    pub async fn replace_document(&self, params: DocIdentifier, delta: Delta) -> Result<DocDelta, DocError> {
+        self.doc_ctrl()?.replace_document(params, delta, self.user.db_pool()?).await
+    }
+
+    pub async fn export_markdown(&self, params: DocIdentifier) -> Result<String, DocError> {
+        self.doc_ctrl()?.export_markdown(params, self.user.db_pool()?).await
+    }
+
+    pub fn read_input_rules(&self) -> Result<InputRuleSetting, DocError> {
+        let setting = read_input_rule_setting(&self.user.user_id()?);
+        Ok(setting)
+    }
+
+    pub fn update_input_rules(&self, setting: InputRuleSetting) -> Result<(), DocError> {
+        save_input_rule_setting(&self.user.user_id()?, &setting);
+        Ok(())
+    }
+
+    // Returns None if the document was never read on any device
+    pub fn read_position(&self, delta: &DocDelta) -> Result<Option<DocReadPosition>, DocError> {
+        let doc_len = Delta::from_json(&delta.data)?.target_len;
+        let conn = &*self.user.db_pool()?.get().map_err(internal_error)?;
+        read_position(&delta.doc_id, doc_len, conn)
+    }
+
+    pub fn update_read_position(&self, position: DocReadPosition) -> Result<(), DocError> {
+        let conn = &*self.user.db_pool()?.get().map_err(internal_error)?;
+        save_read_position(position, conn)
+    }
+
+    pub fn read_all_positions(&self) -> Result<Vec<DocReadPosition>, DocError> {
+        let conn = &*self.user.db_pool()?.get().map_err(internal_error)?;
+        read_all_positions(conn)
+    }
+
+    // The positions that were synced from another device, the later one is kept for each document
+    pub fn merge_read_positions(&self, positions: Vec<DocReadPosition>) -> Result<(), DocError> {
+        let conn = &*self.user.db_pool()?.get().map_err(internal_error)?;
+        merge_read_positions(positions, conn)
+    }
+
     pub async fn read_document_data(
         &self,
         params: DocIdentifier,
         pool: Arc<ConnectionPool>,
     ) -> Result<DocDelta, DocError> {
-        let edit_context = self.doc_ctrl.open(params, pool).await?;
+        let edit_context = self.doc_ctrl()?.open(params, pool).await?;
         let delta = edit_context.delta().await?;
         Ok(delta)
     }
@@ -68,7 +268,7 @@ impl FlowyDocument {
         // workaround: compare the rust's delta with flutter's delta. Will be removed
         // very soon
         let doc = self
-            .doc_ctrl
+            .doc_ctrl()?
             .apply_local_delta(params.clone(), self.user.db_pool()?)
             .await?;
         Ok(doc)
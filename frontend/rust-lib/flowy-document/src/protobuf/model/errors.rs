@@ -218,7 +218,10 @@ pub enum ErrorCode {
     WsConnectError = 0,
     DocNotfound = 1,
     DuplicateRevision = 2,
+    ReplaceConflict = 3,
     UserUnauthorized = 10,
+    TooManyRequests = 11,
+    PayloadTooLarge = 12,
     InternalError = 1000,
 }
 
@@ -232,7 +235,10 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             0 => ::std::option::Option::Some(ErrorCode::WsConnectError),
             1 => ::std::option::Option::Some(ErrorCode::DocNotfound),
             2 => ::std::option::Option::Some(ErrorCode::DuplicateRevision),
+            3 => ::std::option::Option::Some(ErrorCode::ReplaceConflict),
             10 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
+            11 => ::std::option::Option::Some(ErrorCode::TooManyRequests),
+            12 => ::std::option::Option::Some(ErrorCode::PayloadTooLarge),
             1000 => ::std::option::Option::Some(ErrorCode::InternalError),
             _ => ::std::option::Option::None
         }
@@ -243,7 +249,10 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::WsConnectError,
             ErrorCode::DocNotfound,
             ErrorCode::DuplicateRevision,
+            ErrorCode::ReplaceConflict,
             ErrorCode::UserUnauthorized,
+            ErrorCode::TooManyRequests,
+            ErrorCode::PayloadTooLarge,
             ErrorCode::InternalError,
         ];
         values
@@ -274,29 +283,37 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0cerrors.proto\"<\n\x08DocError\x12\x1e\n\x04code\x18\x01\x20\x01(\
-    \x0e2\n.ErrorCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg*q\
-    \n\tErrorCode\x12\x12\n\x0eWsConnectError\x10\0\x12\x0f\n\x0bDocNotfound\
-    \x10\x01\x12\x15\n\x11DuplicateRevision\x10\x02\x12\x14\n\x10UserUnautho\
-    rized\x10\n\x12\x12\n\rInternalError\x10\xe8\x07J\xfd\x02\n\x06\x12\x04\
-    \0\0\x0c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\
-    \0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\
-    \0\x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\r\n\
-    \x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0e\x12\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x13\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\x04\x0b\x0e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x11\
-    \x12\n\n\n\x02\x05\0\x12\x04\x06\0\x0c\x01\n\n\n\x03\x05\0\x01\x12\x03\
-    \x06\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x07\x04\x17\n\x0c\n\x05\x05\
-    \0\x02\0\x01\x12\x03\x07\x04\x12\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x07\
-    \x15\x16\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x08\x04\x14\n\x0c\n\x05\x05\0\
-    \x02\x01\x01\x12\x03\x08\x04\x0f\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x08\x12\x13\n\x0b\n\x04\x05\0\x02\x02\x12\x03\t\x04\x1a\n\x0c\n\x05\x05\
-    \0\x02\x02\x01\x12\x03\t\x04\x15\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\t\
-    \x18\x19\n\x0b\n\x04\x05\0\x02\x03\x12\x03\n\x04\x1a\n\x0c\n\x05\x05\0\
-    \x02\x03\x01\x12\x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\n\
-    \x17\x19\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x0b\x04\x19\n\x0c\n\x05\x05\0\
-    \x02\x04\x01\x12\x03\x0b\x04\x11\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\
-    \x0b\x14\x18b\x06proto3\
+    \x0e2\n.ErrorCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg*\
+    \xb0\x01\n\tErrorCode\x12\x12\n\x0eWsConnectError\x10\0\x12\x0f\n\x0bDoc\
+    Notfound\x10\x01\x12\x15\n\x11DuplicateRevision\x10\x02\x12\x13\n\x0fRep\
+    laceConflict\x10\x03\x12\x14\n\x10UserUnauthorized\x10\n\x12\x13\n\x0fTo\
+    oManyRequests\x10\x0b\x12\x13\n\x0fPayloadTooLarge\x10\x0c\x12\x12\n\rIn\
+    ternalError\x10\xe8\x07J\xf8\x03\n\x06\x12\x04\0\0\x0f\x01\n\x08\n\x01\
+    \x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\
+    \0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x17\n\
+    \x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\r\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x03\x0e\x12\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x15\x16\n\
+    \x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x13\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0e\
+    \n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x11\x12\n\n\n\x02\x05\0\x12\
+    \x04\x06\0\x0f\x01\n\n\n\x03\x05\0\x01\x12\x03\x06\x05\x0e\n\x0b\n\x04\
+    \x05\0\x02\0\x12\x03\x07\x04\x17\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x07\
+    \x04\x12\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x07\x15\x16\n\x0b\n\x04\x05\
+    \0\x02\x01\x12\x03\x08\x04\x14\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x08\
+    \x04\x0f\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x08\x12\x13\n\x0b\n\x04\
+    \x05\0\x02\x02\x12\x03\t\x04\x1a\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\t\
+    \x04\x15\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\t\x18\x19\n\x0b\n\x04\x05\
+    \0\x02\x03\x12\x03\n\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\n\x04\
+    \x13\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\n\x16\x17\n\x0b\n\x04\x05\0\
+    \x02\x04\x12\x03\x0b\x04\x1a\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x0b\
+    \x04\x14\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x0b\x17\x19\n\x0b\n\x04\
+    \x05\0\x02\x05\x12\x03\x0c\x04\x19\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\
+    \x0c\x04\x13\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x0c\x16\x18\n\x0b\n\
+    \x04\x05\0\x02\x06\x12\x03\r\x04\x19\n\x0c\n\x05\x05\0\x02\x06\x01\x12\
+    \x03\r\x04\x13\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\r\x16\x18\n\x0b\n\
+    \x04\x05\0\x02\x07\x12\x03\x0e\x04\x19\n\x0c\n\x05\x05\0\x02\x07\x01\x12\
+    \x03\x0e\x04\x11\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\x0e\x14\x18b\x06p\
+    roto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
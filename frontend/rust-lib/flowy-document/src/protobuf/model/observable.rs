@@ -26,6 +26,10 @@
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum DocObservable {
     UserCreateDoc = 0,
+    DocPrefetchProgress = 1,
+    DocPresenceChanged = 2,
+    DocTypingChanged = 3,
+    AttachmentUploadProgress = 4,
 }
 
 impl ::protobuf::ProtobufEnum for DocObservable {
@@ -36,6 +40,10 @@ impl ::protobuf::ProtobufEnum for DocObservable {
     fn from_i32(value: i32) -> ::std::option::Option<DocObservable> {
         match value {
             0 => ::std::option::Option::Some(DocObservable::UserCreateDoc),
+            1 => ::std::option::Option::Some(DocObservable::DocPrefetchProgress),
+            2 => ::std::option::Option::Some(DocObservable::DocPresenceChanged),
+            3 => ::std::option::Option::Some(DocObservable::DocTypingChanged),
+            4 => ::std::option::Option::Some(DocObservable::AttachmentUploadProgress),
             _ => ::std::option::Option::None
         }
     }
@@ -43,6 +51,10 @@ impl ::protobuf::ProtobufEnum for DocObservable {
     fn values() -> &'static [Self] {
         static values: &'static [DocObservable] = &[
             DocObservable::UserCreateDoc,
+            DocObservable::DocPrefetchProgress,
+            DocObservable::DocPresenceChanged,
+            DocObservable::DocTypingChanged,
+            DocObservable::AttachmentUploadProgress,
         ];
         values
     }
@@ -71,12 +83,22 @@ impl ::protobuf::reflect::ProtobufValue for DocObservable {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*\"\n\rDocObservable\x12\x11\n\rUserCreateDoc\x10\
-    \0JS\n\x06\x12\x04\0\0\x04\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x05\0\x12\x04\x02\0\x04\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x12\n\
-    \x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03\x03\x04\x11\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x14\x15b\
-    \x06proto3\
+    \n\x10observable.proto*\x87\x01\n\rDocObservable\x12\x11\n\rUserCreateDo\
+    c\x10\0\x12\x17\n\x13DocPrefetchProgress\x10\x01\x12\x16\n\x12DocPresenc\
+    eChanged\x10\x02\x12\x14\n\x10DocTypingChanged\x10\x03\x12\x1c\n\x18Atta\
+    chmentUploadProgress\x10\x04J\xf7\x01\n\x06\x12\x04\0\0\x08\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x08\x01\n\n\n\x03\
+    \x05\0\x01\x12\x03\x02\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\
+    \x16\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x11\n\x0c\n\x05\x05\0\
+    \x02\0\x02\x12\x03\x03\x14\x15\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\
+    \x1c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x17\n\x0c\n\x05\x05\0\
+    \x02\x01\x02\x12\x03\x04\x1a\x1b\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\
+    \x04\x1b\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x16\n\x0c\n\x05\
+    \x05\0\x02\x02\x02\x12\x03\x05\x19\x1a\n\x0b\n\x04\x05\0\x02\x03\x12\x03\
+    \x06\x04\x19\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x14\n\x0c\n\
+    \x05\x05\0\x02\x03\x02\x12\x03\x06\x17\x18\n\x0b\n\x04\x05\0\x02\x04\x12\
+    \x03\x07\x04!\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x1c\n\x0c\n\
+    \x05\x05\0\x02\x04\x02\x12\x03\x07\x1f\x20b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
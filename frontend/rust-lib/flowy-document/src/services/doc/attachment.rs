@@ -0,0 +1,136 @@
+use crate::{
+    errors::{DocError, DocResult},
+    module::DocumentUser,
+    notify::{dart_notify, DocObservable},
+    services::{doc::AttachmentCache, server::Server},
+};
+use flowy_document_infra::entities::doc::{
+    Attachment,
+    AttachmentChunk,
+    AttachmentChunkIdentifier,
+    AttachmentFile,
+    AttachmentIdentifier,
+    AttachmentUploadProgress,
+    CreateAttachmentParams,
+};
+use std::{path::Path, sync::Arc};
+
+// Uploads the files that are embedded in the documents. The chunks are uploaded in order in the
+// background, the progress is sent to the dart side with the attachment_id after each chunk. The file
+// is cached, so it doesn't need to be downloaded again.
+pub(crate) struct AttachmentUploader {
+    server: Server,
+    user: Arc<dyn DocumentUser>,
+    cache: Arc<AttachmentCache>,
+}
+
+impl AttachmentUploader {
+    pub(crate) fn new(server: Server, user: Arc<dyn DocumentUser>, cache: Arc<AttachmentCache>) -> Self {
+        Self { server, user, cache }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn upload(&self, doc_id: &str, path: &str) -> DocResult<AttachmentUploadProgress> {
+        let data = std::fs::read(path)?;
+        let name = Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let params = CreateAttachmentParams {
+            doc_id: doc_id.to_owned(),
+            name,
+            size: data.len() as i64,
+        };
+        let token = self.user.token()?;
+        let attachment = self.server.create_attachment(&token, params).await?;
+        let progress = AttachmentUploadProgress::from(&attachment);
+        if let Err(e) = self.cache.write(&attachment.id, &data) {
+            log::error!("Cache the attachment {} failed: {:?}", attachment.id, e);
+        }
+
+        let server = self.server.clone();
+        let _ = tokio::spawn(async move {
+            let attachment_id = attachment.id.clone();
+            if let Err(e) = upload_chunks(server, &token, attachment, data).await {
+                log::error!("Upload the attachment {} failed: {:?}", attachment_id, e);
+                dart_notify(&attachment_id, DocObservable::AttachmentUploadProgress)
+                    .error(e)
+                    .send();
+            }
+        });
+        Ok(progress)
+    }
+}
+
+// Downloads the attachments that aren't in the cache, the chunks are read in order and the file is
+// cached once all of them are read.
+pub(crate) struct AttachmentDownloader {
+    server: Server,
+    user: Arc<dyn DocumentUser>,
+    cache: Arc<AttachmentCache>,
+}
+
+impl AttachmentDownloader {
+    pub(crate) fn new(server: Server, user: Arc<dyn DocumentUser>, cache: Arc<AttachmentCache>) -> Self {
+        Self { server, user, cache }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read(&self, attachment_id: &str) -> DocResult<AttachmentFile> {
+        let path = match self.cache.read(attachment_id)? {
+            Some(path) => path,
+            None => {
+                let data = self.download(attachment_id).await?;
+                self.cache.write(attachment_id, &data)?
+            },
+        };
+
+        Ok(AttachmentFile {
+            attachment_id: attachment_id.to_owned(),
+            path: path.to_string_lossy().to_string(),
+        })
+    }
+
+    async fn download(&self, attachment_id: &str) -> DocResult<Vec<u8>> {
+        let token = self.user.token()?;
+        let attachment = self
+            .server
+            .read_attachment(&token, AttachmentIdentifier::from(attachment_id))
+            .await?;
+        if !attachment.is_completed() {
+            return Err(DocError::record_not_found().context("The attachment is still being uploaded"));
+        }
+
+        let mut data = Vec::with_capacity(attachment.size as usize);
+        for index in 0..attachment.chunk_count() {
+            let params = AttachmentChunkIdentifier {
+                attachment_id: attachment.id.clone(),
+                index,
+            };
+            let chunk = self.server.read_attachment_chunk(&token, params).await?;
+            data.extend(chunk.data);
+        }
+        Ok(data)
+    }
+}
+
+async fn upload_chunks(server: Server, token: &str, mut attachment: Attachment, data: Vec<u8>) -> DocResult<()> {
+    if attachment.chunk_size <= 0 {
+        return Err(DocError::internal().context(format!("Invalid chunk size: {}", attachment.chunk_size)));
+    }
+
+    let chunk_size = attachment.chunk_size as usize;
+    for (index, bytes) in data.chunks(chunk_size).enumerate() {
+        let chunk = AttachmentChunk {
+            attachment_id: attachment.id.clone(),
+            index: index as i64,
+            data: bytes.to_vec(),
+        };
+        let uploaded = server.upload_attachment_chunk(token, chunk).await?;
+        attachment.uploaded_chunks = uploaded.uploaded_chunks;
+        dart_notify(&attachment.id, DocObservable::AttachmentUploadProgress)
+            .payload(AttachmentUploadProgress::from(&attachment))
+            .send();
+    }
+    Ok(())
+}
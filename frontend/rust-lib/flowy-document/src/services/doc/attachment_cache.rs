@@ -0,0 +1,94 @@
+use crate::{
+    errors::{internal_error, DocResult},
+    module::DocumentUser,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+// The cache is shrunk below this size after each file is added
+pub(crate) const MAX_ATTACHMENT_CACHE_SIZE: u64 = 200 * 1024 * 1024;
+
+// Keeps the data of the uploaded and downloaded attachments in the user dir, one file per attachment.
+// When the cache grows larger than the max_size, the files that were cached first are removed until
+// it fits again. The removed attachments are downloaded again when they are read.
+pub(crate) struct AttachmentCache {
+    user: Arc<dyn DocumentUser>,
+    max_size: u64,
+}
+
+impl AttachmentCache {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>, max_size: u64) -> Self { Self { user, max_size } }
+
+    pub(crate) fn read(&self, attachment_id: &str) -> DocResult<Option<PathBuf>> {
+        let path = self.path(attachment_id)?;
+        if path.is_file() {
+            Ok(Some(path))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn write(&self, attachment_id: &str, data: &[u8]) -> DocResult<PathBuf> {
+        let path = self.path(attachment_id)?;
+        let _ = fs::write(&path, data)?;
+        match self.evict(&path) {
+            Ok(count) if count > 0 => tracing::debug!("Evicted {} attachments from the cache", count),
+            Ok(_) => {},
+            Err(e) => log::error!("Evict the attachment cache failed: {:?}", e),
+        }
+        Ok(path)
+    }
+
+    // Removes the oldest files until the cache fits in the max_size, the file that was just written is
+    // kept even if it's larger than the max_size. Returns the number of the removed files.
+    fn evict(&self, keep: &Path) -> DocResult<usize> {
+        let mut files = vec![];
+        let mut total_size = 0;
+        for entry in fs::read_dir(self.dir()?)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            total_size += metadata.len();
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push((entry.path(), metadata.len(), modified));
+        }
+
+        files.sort_by_key(|(_, _, modified)| *modified);
+        let mut count = 0;
+        for (path, size, _) in files {
+            if total_size <= self.max_size {
+                break;
+            }
+            if path == keep {
+                continue;
+            }
+            let _ = fs::remove_file(&path)?;
+            total_size -= size;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    fn path(&self, attachment_id: &str) -> DocResult<PathBuf> {
+        // The id becomes the file name, so it can't point outside the cache dir
+        let name = Path::new(attachment_id)
+            .file_name()
+            .filter(|name| *name == attachment_id)
+            .ok_or_else(|| internal_error(format!("Invalid attachment id: {}", attachment_id)))?;
+        Ok(self.dir()?.join(name))
+    }
+
+    fn dir(&self) -> DocResult<PathBuf> {
+        let dir = Path::new(&self.user.user_dir()?).join("attachments");
+        if !dir.exists() {
+            let _ = fs::create_dir_all(&dir)?;
+        }
+        Ok(dir)
+    }
+}
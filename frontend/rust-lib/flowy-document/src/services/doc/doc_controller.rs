@@ -5,7 +5,16 @@ use crate::{
         cache::DocCache,
         doc::{
             edit::{ClientEditDoc, EditDocWsHandler},
-            revision::RevisionServer,
+            revision::{Persistence, RevisionServer},
+            AttachmentCache,
+            AttachmentDownloader,
+            AttachmentUploader,
+            DocPrefetchWsHandler,
+            DocPrefetcher,
+            DocSearchIndexer,
+            DocTaskIndexer,
+            SnapshotPersistence,
+            MAX_ATTACHMENT_CACHE_SIZE,
         },
         server::Server,
         ws::WsDocumentManager,
@@ -13,32 +22,103 @@ use crate::{
 };
 use bytes::Bytes;
 use flowy_database::ConnectionPool;
-use flowy_document_infra::entities::doc::{Doc, DocDelta, DocIdentifier};
-use lib_infra::future::{wrap_future, FnFuture, ResultFuture};
+use flowy_document_infra::{
+    core::{delta_to_markdown, markdown_to_delta},
+    entities::doc::{
+        AttachmentFile,
+        AttachmentUploadProgress,
+        Doc,
+        DocDelta,
+        DocIdentifier,
+        DocPrefetchProgress,
+        DocSelection,
+        DocSnapshot,
+        DocSnapshotIdentifier,
+        DocTask,
+        DocTextMatch,
+        DocTextReplacement,
+        DocUndoState,
+        RepeatedDocSnapshot,
+        RepeatedDocTextRun,
+    },
+};
+use lib_dispatch::prelude::BlockingPool;
+use lib_infra::{
+    bus::EventBus,
+    future::{wrap_future, FnFuture, ResultFuture},
+};
+use lib_ot::core::Delta;
+use lib_ws::WsState;
 use std::sync::Arc;
-use tokio::time::{interval, Duration};
+use tokio::{
+    sync::broadcast::error::RecvError,
+    time::{interval, Duration},
+};
 
 pub(crate) struct DocController {
     server: Server,
     ws_manager: Arc<WsDocumentManager>,
     cache: Arc<DocCache>,
     user: Arc<dyn DocumentUser>,
+    prefetcher: Arc<DocPrefetcher>,
+    task_indexer: Arc<DocTaskIndexer>,
+    search_indexer: Arc<DocSearchIndexer>,
+    attachment_uploader: Arc<AttachmentUploader>,
+    attachment_downloader: Arc<AttachmentDownloader>,
+    bus: EventBus,
+    blocking_pool: BlockingPool,
 }
 
 impl DocController {
-    pub(crate) fn new(server: Server, user: Arc<dyn DocumentUser>, ws: Arc<WsDocumentManager>) -> Self {
+    pub(crate) fn new(
+        server: Server,
+        user: Arc<dyn DocumentUser>,
+        ws: Arc<WsDocumentManager>,
+        bus: EventBus,
+        blocking_pool: BlockingPool,
+    ) -> Self {
         let cache = Arc::new(DocCache::new());
+        let prefetcher = Arc::new(DocPrefetcher::new(
+            ws.ws(),
+            user.clone(),
+            cache.clone(),
+            blocking_pool.clone(),
+        ));
+        let task_indexer = Arc::new(DocTaskIndexer::new(user.clone(), cache.clone(), blocking_pool.clone()));
+        let search_indexer = Arc::new(DocSearchIndexer::new(user.clone(), cache.clone()));
+        let attachment_cache = Arc::new(AttachmentCache::new(user.clone(), MAX_ATTACHMENT_CACHE_SIZE));
+        let attachment_uploader = Arc::new(AttachmentUploader::new(
+            server.clone(),
+            user.clone(),
+            attachment_cache.clone(),
+        ));
+        let attachment_downloader = Arc::new(AttachmentDownloader::new(server.clone(), user.clone(), attachment_cache));
         let controller = Self {
             server,
             user,
             ws_manager: ws,
             cache: cache.clone(),
+            prefetcher,
+            task_indexer,
+            search_indexer,
+            attachment_uploader,
+            attachment_downloader,
+            bus,
+            blocking_pool,
         };
         controller
     }
 
-    pub(crate) fn init(&self) -> DocResult<()> {
+    // Spawns the listeners of the websocket, it must be called within the runtime and only once
+    pub(crate) fn listen(self: &Arc<Self>) {
         self.ws_manager.init();
+        self.ws_manager.register_prefetch_handler(Arc::new(DocPrefetchWsHandler(self.prefetcher.clone())));
+        self.listen_ws_reconnected();
+    }
+
+    // The documents are stored in the database of the signed in user, so it fails before signing in
+    pub(crate) fn init(&self) -> DocResult<()> {
+        let _ = self.user.db_pool()?;
         Ok(())
     }
 
@@ -57,6 +137,10 @@ impl DocController {
     }
 
     pub(crate) fn close(&self, doc_id: &str) -> Result<(), DocError> {
+        if let Ok(edit_doc) = self.cache.get(doc_id) {
+            edit_doc.close();
+            self.task_indexer.save_on_close(edit_doc);
+        }
         self.cache.remove(doc_id);
         self.ws_manager.remove_handler(doc_id);
         Ok(())
@@ -67,9 +151,170 @@ impl DocController {
         let doc_id = &params.doc_id;
         self.cache.remove(doc_id);
         self.ws_manager.remove_handler(doc_id);
+        let _ = self.task_indexer.remove(doc_id)?;
+        self.search_indexer.remove(doc_id);
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip(self, pool), err)]
+    pub(crate) async fn reset(&self, params: DocIdentifier, pool: Arc<ConnectionPool>) -> Result<DocDelta, DocError> {
+        let token = self.user.token()?;
+        let doc = self.server.reset_doc(&token, params.clone()).await?;
+        let edit_doc_ctx = self.open(params.clone(), pool).await?;
+        let delta = edit_doc_ctx.reset(doc).await?;
+        // The rev_id of the reset document might be the same as the one that was indexed
+        let _ = self.task_indexer.remove(&params.doc_id)?;
+        self.search_indexer.remove(&params.doc_id);
+        Ok(delta)
+    }
+
+    // The versions that the server keeps, unlike the snapshots that are taken on this device
+    pub(crate) async fn query_history(&self, params: DocIdentifier) -> Result<RepeatedDocSnapshot, DocError> {
+        let token = self.user.token()?;
+        self.server.read_doc_snapshots(&token, params).await
+    }
+
+    // The version is restored on the server first, so the other users receive it too. Then the local
+    // document is reset to the restored one.
+    #[tracing::instrument(level = "debug", skip(self, pool), err)]
+    pub(crate) async fn restore_version(
+        &self,
+        params: DocSnapshotIdentifier,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<DocDelta, DocError> {
+        let token = self.user.token()?;
+        let doc = self.server.restore_doc_snapshot(&token, params.clone()).await?;
+        let edit_doc_ctx = self.open(params.doc_id.clone().into(), pool).await?;
+        let delta = edit_doc_ctx.reset(doc).await?;
+        let _ = self.task_indexer.remove(&params.doc_id)?;
+        self.search_indexer.remove(&params.doc_id);
+        Ok(delta)
+    }
+
+    pub(crate) fn prefetch(&self, id: &str, doc_ids: Vec<String>) -> Result<DocPrefetchProgress, DocError> {
+        self.prefetcher.prefetch(id, doc_ids)
+    }
+
+    pub(crate) async fn upload_attachment(
+        &self,
+        doc_id: &str,
+        path: &str,
+    ) -> Result<AttachmentUploadProgress, DocError> {
+        self.attachment_uploader.upload(doc_id, path).await
+    }
+
+    pub(crate) async fn read_attachment(&self, attachment_id: &str) -> Result<AttachmentFile, DocError> {
+        self.attachment_downloader.read(attachment_id).await
+    }
+
+    pub(crate) async fn read_tasks(&self, doc_ids: Vec<String>) -> Result<Vec<DocTask>, DocError> {
+        self.task_indexer.read_tasks(doc_ids).await
+    }
+
+    pub(crate) async fn index_docs(&self, doc_ids: Vec<String>) -> Result<(), DocError> {
+        self.search_indexer.index_docs(doc_ids).await
+    }
+
+    pub(crate) async fn find_text_matches(
+        &self,
+        doc_id: &str,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<DocTextMatch>, DocError> {
+        self.search_indexer.find_matches(doc_id, query, limit).await
+    }
+
+    pub(crate) async fn read_text_runs(
+        &self,
+        params: DocIdentifier,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<RepeatedDocTextRun, DocError> {
+        let edit_doc_ctx = self.open(params, pool).await?;
+        edit_doc_ctx.text_runs().await
+    }
+
+    pub(crate) async fn replace_text(
+        &self,
+        replacement: DocTextReplacement,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<DocDelta, DocError> {
+        let edit_doc_ctx = self.open(replacement.doc_id.clone().into(), pool).await?;
+        edit_doc_ctx.replace_text(replacement).await
+    }
+
+    pub(crate) async fn update_selection(
+        &self,
+        selection: DocSelection,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<(), DocError> {
+        let edit_doc_ctx = self.open(selection.doc_id.clone().into(), pool).await?;
+        edit_doc_ctx.update_selection(selection)
+    }
+
+    pub(crate) async fn undo(&self, params: DocIdentifier, pool: Arc<ConnectionPool>) -> Result<DocDelta, DocError> {
+        let edit_doc_ctx = self.open(params, pool).await?;
+        edit_doc_ctx.undo().await
+    }
+
+    pub(crate) async fn redo(&self, params: DocIdentifier, pool: Arc<ConnectionPool>) -> Result<DocDelta, DocError> {
+        let edit_doc_ctx = self.open(params, pool).await?;
+        edit_doc_ctx.redo().await
+    }
+
+    pub(crate) async fn read_undo_state(
+        &self,
+        params: DocIdentifier,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<DocUndoState, DocError> {
+        let edit_doc_ctx = self.open(params, pool).await?;
+        Ok(edit_doc_ctx.undo_state().await)
+    }
+
+    pub(crate) fn read_snapshots(&self, doc_id: &str) -> Result<Vec<DocSnapshot>, DocError> {
+        match self.cache.contains(doc_id) {
+            true => self.cache.get(doc_id)?.read_snapshots(),
+            false => SnapshotPersistence::new(self.user.db_pool()?).read_snapshots(doc_id),
+        }
+    }
+
+    pub(crate) async fn revert_to_snapshot(
+        &self,
+        params: DocSnapshotIdentifier,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<DocDelta, DocError> {
+        let edit_doc_ctx = self.open(params.doc_id.clone().into(), pool).await?;
+        edit_doc_ctx.revert_to_snapshot(params.snapshot_id).await
+    }
+
+    pub(crate) async fn import_markdown(
+        &self,
+        params: DocIdentifier,
+        markdown: &str,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<DocDelta, DocError> {
+        self.replace_document(params, markdown_to_delta(markdown), pool).await
+    }
+
+    pub(crate) async fn replace_document(
+        &self,
+        params: DocIdentifier,
+        delta: Delta,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<DocDelta, DocError> {
+        let edit_doc_ctx = self.open(params, pool).await?;
+        edit_doc_ctx.replace_document(delta).await
+    }
+
+    pub(crate) async fn export_markdown(
+        &self,
+        params: DocIdentifier,
+        pool: Arc<ConnectionPool>,
+    ) -> Result<String, DocError> {
+        let edit_doc_ctx = self.open(params, pool).await?;
+        let delta = Delta::from_json(&edit_doc_ctx.delta().await?.data)?;
+        Ok(delta_to_markdown(&delta))
+    }
+
     // the delta's data that contains attributes with null value will be considered
     // as None e.g.
     // json : {"retain":7,"attributes":{"bold":null}}
@@ -86,12 +331,49 @@ impl DocController {
         }
 
         let edit_doc_ctx = self.cache.get(&delta.doc_id)?;
-        let _ = edit_doc_ctx.composing_local_delta(Bytes::from(delta.data)).await?;
+        let _ = edit_doc_ctx.composing_input_delta(Bytes::from(delta.data)).await?;
         Ok(edit_doc_ctx.delta().await?)
     }
 }
 
 impl DocController {
+    // The opened documents sync their changes when the websocket is connected, see EditDocWsHandler. The
+    // closed documents that have unsynced changes, e.g. the ones that were edited offline before
+    // restarting, are opened to sync them too.
+    fn listen_ws_reconnected(self: &Arc<Self>) {
+        let mut notify = self.ws_manager.ws().state_notify();
+        let controller = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match notify.recv().await {
+                    Ok(WsState::Connected(_)) => {
+                        if let Err(e) = controller.resync_closed_docs().await {
+                            log::error!("Resync the closed documents failed: {:?}", e);
+                        }
+                    },
+                    Ok(_) => {},
+                    Err(RecvError::Lagged(count)) => log::warn!("Missed {} websocket state changes", count),
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    async fn resync_closed_docs(&self) -> DocResult<()> {
+        let pool = self.user.db_pool()?;
+        let doc_ids = Persistence::new(pool.clone()).read_unsynced_doc_ids()?;
+        for doc_id in doc_ids {
+            if self.cache.contains(&doc_id) {
+                continue;
+            }
+            if let Err(e) = self.make_edit_context(&doc_id, pool.clone()).await {
+                log::error!("Resync {} failed: {:?}", doc_id, e);
+            }
+        }
+        Ok(())
+    }
+
     async fn make_edit_context(&self, doc_id: &str, pool: Arc<ConnectionPool>) -> Result<Arc<ClientEditDoc>, DocError> {
         // Opti: require upgradable_read lock and then upgrade to write lock using
         // RwLockUpgradableReadGuard::upgrade(xx) of ws
@@ -104,7 +386,9 @@ impl DocController {
             server: self.server.clone(),
         });
 
-        let edit_ctx = Arc::new(ClientEditDoc::new(doc_id, pool, ws, server, user).await?);
+        let blocking_pool = self.blocking_pool.clone();
+        let bus = self.bus.clone();
+        let edit_ctx = Arc::new(ClientEditDoc::new(doc_id, pool, blocking_pool, ws, server, user, bus).await?);
         let ws_handler = Arc::new(EditDocWsHandler(edit_ctx.clone()));
         self.ws_manager.register_handler(doc_id, ws_handler);
         self.cache.set(edit_ctx.clone());
@@ -1,8 +1,9 @@
+use crate::services::doc::apply_input_rules;
 use async_stream::stream;
 use bytes::Bytes;
 use flowy_document_infra::{
-    core::{history::UndoResult, Document},
-    entities::doc::{RevId, Revision},
+    core::Document,
+    entities::doc::{DocTaskIndex, InputRuleSetting, RevId, Revision},
     errors::DocumentError,
 };
 use futures::stream::StreamExt;
@@ -13,6 +14,8 @@ use tokio::sync::{mpsc, oneshot, RwLock};
 pub struct DocumentActor {
     doc_id: String,
     document: Arc<RwLock<Document>>,
+    // Built from the document the first time it's read, then updated with the deltas applied to the document
+    task_index: RwLock<Option<DocTaskIndex>>,
     receiver: Option<mpsc::UnboundedReceiver<DocumentMsg>>,
 }
 
@@ -22,6 +25,7 @@ impl DocumentActor {
         Self {
             doc_id: doc_id.to_owned(),
             document,
+            task_index: RwLock::new(None),
             receiver: Some(receiver),
         }
     }
@@ -49,7 +53,16 @@ impl DocumentActor {
     async fn handle_message(&self, msg: DocumentMsg) -> Result<(), DocumentError> {
         match msg {
             DocumentMsg::Delta { delta, ret } => {
-                let result = self.composed_delta(delta).await;
+                let result = self.composed_delta(delta.clone()).await;
+                if result.is_ok() {
+                    self.update_task_index(&delta).await;
+                }
+                let _ = ret.send(result);
+            },
+            DocumentMsg::Chunks { deltas, group_id, ret } => {
+                let result = self.composed_chunks(deltas, &group_id).await;
+                // The chunks of a large paste change most of the lines, so the index is built again
+                *self.task_index.write().await = None;
                 let _ = ret.send(result);
             },
             DocumentMsg::RemoteRevision { bytes, ret } => {
@@ -65,11 +78,13 @@ impl DocumentActor {
                 let _ = ret.send(Ok(transform_delta));
             },
             DocumentMsg::Insert { index, data, ret } => {
-                let delta = self.document.write().await.insert(index, data);
-                let _ = ret.send(delta);
+                let result = self.document.write().await.insert(index, data);
+                self.update_task_index_with(&result).await;
+                let _ = ret.send(result);
             },
             DocumentMsg::Delete { interval, ret } => {
                 let result = self.document.write().await.delete(interval);
+                self.update_task_index_with(&result).await;
                 let _ = ret.send(result);
             },
             DocumentMsg::Format {
@@ -78,10 +93,38 @@ impl DocumentActor {
                 ret,
             } => {
                 let result = self.document.write().await.format(interval, attribute);
+                self.update_task_index_with(&result).await;
                 let _ = ret.send(result);
             },
             DocumentMsg::Replace { interval, data, ret } => {
                 let result = self.document.write().await.replace(interval, data);
+                self.update_task_index_with(&result).await;
+                let _ = ret.send(result);
+            },
+            DocumentMsg::ReplaceText {
+                interval,
+                old_text,
+                new_text,
+                ret,
+            } => {
+                let mut document = self.document.write().await;
+                let text = document.to_plain_string().encode_utf16().collect::<Vec<u16>>();
+                let old_text = old_text.encode_utf16().collect::<Vec<u16>>();
+                let result = match text.get(interval.start..interval.end) {
+                    Some(s) if s == old_text.as_slice() => document.replace(interval, new_text).map(Some),
+                    _ => Ok(None),
+                };
+                drop(document);
+                if let Ok(Some(delta)) = &result {
+                    self.update_task_index(delta).await;
+                }
+                let _ = ret.send(result);
+            },
+            DocumentMsg::InputRule { setting, input, ret } => {
+                let result = apply_input_rules(&setting, &mut *self.document.write().await, &input);
+                if let Ok(Some(delta)) = &result {
+                    self.update_task_index(delta).await;
+                }
                 let _ = ret.send(result);
             },
             DocumentMsg::CanUndo { ret } => {
@@ -92,16 +135,30 @@ impl DocumentActor {
             },
             DocumentMsg::Undo { ret } => {
                 let result = self.document.write().await.undo();
+                self.update_task_index_with(&result).await;
                 let _ = ret.send(result);
             },
             DocumentMsg::Redo { ret } => {
                 let result = self.document.write().await.redo();
+                self.update_task_index_with(&result).await;
                 let _ = ret.send(result);
             },
             DocumentMsg::Doc { ret } => {
                 let data = self.document.read().await.to_json();
                 let _ = ret.send(Ok(data));
             },
+            DocumentMsg::TaskIndex { ret } => {
+                let mut task_index = self.task_index.write().await;
+                let document = self.document.read().await;
+                let task_index =
+                    task_index.get_or_insert_with(|| DocTaskIndex::from_delta(&self.doc_id, document.delta()));
+                let _ = ret.send(Ok(task_index.clone()));
+            },
+            DocumentMsg::Reset { delta, ret } => {
+                *self.document.write().await = Document::from_delta(delta);
+                *self.task_index.write().await = None;
+                let _ = ret.send(Ok(()));
+            },
         }
         Ok(())
     }
@@ -120,6 +177,31 @@ impl DocumentActor {
 
         result
     }
+
+    // The index is only updated after it's built
+    async fn update_task_index(&self, delta: &Delta) {
+        let mut task_index = self.task_index.write().await;
+        if let Some(task_index) = task_index.as_mut() {
+            task_index.apply_delta(&self.doc_id, delta, self.document.read().await.delta());
+        }
+    }
+
+    async fn update_task_index_with(&self, result: &Result<Delta, DocumentError>) {
+        if let Ok(delta) = result {
+            self.update_task_index(delta).await;
+        }
+    }
+
+    // The document is locked until all the chunks are composed, so nothing is composed between them
+    async fn composed_chunks(&self, deltas: Vec<Delta>, group_id: &str) -> Result<(), DocumentError> {
+        let mut document = self.document.write().await;
+        for delta in deltas {
+            let _ = document.compose_delta_in_group(delta, group_id)?;
+            // Lets the other tasks run between the chunks of a large paste
+            tokio::task::yield_now().await;
+        }
+        Ok(())
+    }
 }
 
 pub type Ret<T> = oneshot::Sender<Result<T, DocumentError>>;
@@ -128,6 +210,12 @@ pub enum DocumentMsg {
         delta: Delta,
         ret: Ret<()>,
     },
+    // The chunks of a large delta that are composed one after another and undone together
+    Chunks {
+        deltas: Vec<Delta>,
+        group_id: String,
+        ret: Ret<()>,
+    },
     RemoteRevision {
         bytes: Bytes,
         ret: Ret<TransformDeltas>,
@@ -152,6 +240,18 @@ pub enum DocumentMsg {
         data: String,
         ret: Ret<Delta>,
     },
+    // Returns None if the text in the interval is not the old_text
+    ReplaceText {
+        interval: Interval,
+        old_text: String,
+        new_text: String,
+        ret: Ret<Option<Delta>>,
+    },
+    InputRule {
+        setting: InputRuleSetting,
+        input: Delta,
+        ret: Ret<Option<Delta>>,
+    },
     CanUndo {
         ret: oneshot::Sender<bool>,
     },
@@ -159,14 +259,21 @@ pub enum DocumentMsg {
         ret: oneshot::Sender<bool>,
     },
     Undo {
-        ret: Ret<UndoResult>,
+        ret: Ret<Delta>,
     },
     Redo {
-        ret: Ret<UndoResult>,
+        ret: Ret<Delta>,
     },
     Doc {
         ret: Ret<String>,
     },
+    TaskIndex {
+        ret: Ret<DocTaskIndex>,
+    },
+    Reset {
+        delta: Delta,
+        ret: Ret<()>,
+    },
 }
 
 pub struct TransformDeltas {
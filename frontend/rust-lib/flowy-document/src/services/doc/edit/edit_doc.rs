@@ -2,58 +2,109 @@ use crate::{
     errors::{internal_error, DocError, DocResult},
     module::DocumentUser,
     services::{
-        doc::{DocumentActor, DocumentMsg, OpenDocAction, RevisionManager, RevisionServer, TransformDeltas},
+        doc::{
+            read_input_rule_setting,
+            DocPresenceManager,
+            DocTypingManager,
+            DocumentActor,
+            DocumentMsg,
+            OpenDocAction,
+            RevisionManager,
+            RevisionServer,
+            SnapshotPersistence,
+            TransformDeltas,
+        },
         ws::{DocumentWebSocket, WsDocumentHandler},
     },
 };
 use bytes::Bytes;
 use flowy_database::ConnectionPool;
 use flowy_document_infra::{
-    core::history::UndoResult,
+    core::split_delta,
     entities::{
-        doc::{DocDelta, RevId, RevType, Revision, RevisionRange},
+        doc::{
+            revert_delta,
+            text_runs_from_delta,
+            transform_interval,
+            Doc,
+            DocDelta,
+            DocPresence,
+            DocSelection,
+            DocSnapshot,
+            DocTaskIndex,
+            DocTextReplacement,
+            DocTyping,
+            DocUndoState,
+            RepeatedDocTextRun,
+            RevId,
+            RevType,
+            Revision,
+            RevisionRange,
+        },
         ws::{WsDataType, WsDocumentData},
     },
     errors::DocumentResult,
 };
-use lib_infra::retry::{ExponentialBackoff, Retry};
-use lib_ot::core::{Attribute, Delta, Interval};
+use lib_dispatch::prelude::BlockingPool;
+use lib_infra::{
+    bus::EventBus,
+    device_id,
+    retry::{ExponentialBackoff, Retry},
+    uuid,
+};
+use lib_ot::core::{plain_attributes, Attribute, Delta, DeltaIter, Interval, OperationTransformable};
 use lib_ws::WsState;
 use std::{convert::TryFrom, sync::Arc};
-use tokio::sync::{mpsc, mpsc::UnboundedSender, oneshot};
+use tokio::sync::{mpsc, mpsc::UnboundedSender, oneshot, Mutex};
 
 pub type DocId = String;
 
+// A snapshot of the local document is taken every this many local revisions
+const AUTO_SNAPSHOT_INTERVAL: i64 = 50;
+
+// The local delta that inserts more than this many utf16 code units is saved as several revisions
+const MAX_REVISION_INSERT_LEN: usize = 16 * 1024;
+
 pub struct ClientEditDoc {
     pub doc_id: DocId,
     rev_manager: Arc<RevisionManager>,
     document: UnboundedSender<DocumentMsg>,
     ws: Arc<dyn DocumentWebSocket>,
     user: Arc<dyn DocumentUser>,
+    presence: Arc<DocPresenceManager>,
+    typing: Arc<DocTypingManager>,
+    large_delta_lock: Mutex<()>,
 }
 
 impl ClientEditDoc {
     pub(crate) async fn new(
         doc_id: &str,
         pool: Arc<ConnectionPool>,
+        blocking_pool: BlockingPool,
         ws: Arc<dyn DocumentWebSocket>,
         server: Arc<dyn RevisionServer>,
         user: Arc<dyn DocumentUser>,
+        bus: EventBus,
     ) -> DocResult<Self> {
         let (sender, receiver) = mpsc::unbounded_channel();
-        let mut rev_manager = RevisionManager::new(doc_id, pool.clone(), server.clone(), sender);
+        let mut rev_manager = RevisionManager::new(doc_id, pool.clone(), blocking_pool, server.clone(), sender, bus);
         spawn_rev_receiver(receiver, ws.clone());
 
         let delta = rev_manager.load_document().await?;
         let document = spawn_doc_edit_actor(doc_id, delta, pool.clone());
         let doc_id = doc_id.to_string();
         let rev_manager = Arc::new(rev_manager);
+        let presence = DocPresenceManager::new(&doc_id, user.clone(), ws.clone());
+        let typing = DocTypingManager::new(&doc_id, user.clone(), ws.clone());
         let edit_doc = Self {
             doc_id,
             rev_manager,
             document,
             ws,
             user,
+            presence,
+            typing,
+            large_delta_lock: Mutex::new(()),
         };
         edit_doc.notify_open_doc();
         Ok(edit_doc)
@@ -68,7 +119,9 @@ impl ClientEditDoc {
         };
         let _ = self.document.send(msg);
         let delta = rx.await.map_err(internal_error)??;
-        let _ = self.save_local_delta(delta).await?;
+        let _ = self.save_local_deltas(split_delta(&delta, MAX_REVISION_INSERT_LEN)).await?;
+        let _ = self.apply_input_rules(delta).await?;
+        self.notify_typing();
         Ok(())
     }
 
@@ -78,6 +131,7 @@ impl ClientEditDoc {
         let _ = self.document.send(msg);
         let delta = rx.await.map_err(internal_error)??;
         let _ = self.save_local_delta(delta).await?;
+        self.notify_typing();
         Ok(())
     }
 
@@ -104,6 +158,7 @@ impl ClientEditDoc {
         let _ = self.document.send(msg);
         let delta = rx.await.map_err(internal_error)??;
         let _ = self.save_local_delta(delta).await?;
+        self.notify_typing();
         Ok(())
     }
 
@@ -121,20 +176,31 @@ impl ClientEditDoc {
         rx.await.unwrap_or(false)
     }
 
-    pub async fn undo(&self) -> Result<UndoResult, DocError> {
-        let (ret, rx) = oneshot::channel::<DocumentResult<UndoResult>>();
+    // The undo is saved as a new revision, so it's synced with the server like the other changes
+    pub async fn undo(&self) -> Result<DocDelta, DocError> {
+        let (ret, rx) = oneshot::channel::<DocumentResult<Delta>>();
         let msg = DocumentMsg::Undo { ret };
         let _ = self.document.send(msg);
-        let r = rx.await.map_err(internal_error)??;
-        Ok(r)
+        let delta = rx.await.map_err(internal_error)??;
+        let _ = self.save_local_delta(delta).await?;
+        self.delta().await
     }
 
-    pub async fn redo(&self) -> Result<UndoResult, DocError> {
-        let (ret, rx) = oneshot::channel::<DocumentResult<UndoResult>>();
+    pub async fn redo(&self) -> Result<DocDelta, DocError> {
+        let (ret, rx) = oneshot::channel::<DocumentResult<Delta>>();
         let msg = DocumentMsg::Redo { ret };
         let _ = self.document.send(msg);
-        let r = rx.await.map_err(internal_error)??;
-        Ok(r)
+        let delta = rx.await.map_err(internal_error)??;
+        let _ = self.save_local_delta(delta).await?;
+        self.delta().await
+    }
+
+    pub(crate) async fn undo_state(&self) -> DocUndoState {
+        DocUndoState {
+            doc_id: self.doc_id.clone(),
+            can_undo: self.can_undo().await,
+            can_redo: self.can_redo().await,
+        }
     }
 
     pub async fn delta(&self) -> DocResult<DocDelta> {
@@ -146,21 +212,174 @@ impl ClientEditDoc {
         Ok(DocDelta {
             doc_id: self.doc_id.clone(),
             data,
+            read_position: None,
+        })
+    }
+
+    pub(crate) fn rev_id(&self) -> i64 { self.rev_manager.rev_id() }
+
+    pub(crate) async fn task_index(&self) -> DocResult<DocTaskIndex> {
+        let (ret, rx) = oneshot::channel::<DocumentResult<DocTaskIndex>>();
+        let msg = DocumentMsg::TaskIndex { ret };
+        let _ = self.document.send(msg);
+        let task_index = rx.await.map_err(internal_error)??;
+        Ok(task_index)
+    }
+
+    pub(crate) async fn text_runs(&self) -> DocResult<RepeatedDocTextRun> {
+        let rev_id = self.rev_manager.rev_id();
+        let delta = Delta::from_json(&self.delta().await?.data)?;
+        Ok(RepeatedDocTextRun {
+            doc_id: self.doc_id.clone(),
+            rev_id,
+            items: text_runs_from_delta(&delta),
         })
     }
 
+    // The replacement is rejected if the text it's going to replace was edited after the rev_id, or if the
+    // revisions after the rev_id were compacted.
+    #[tracing::instrument(level = "debug", skip(self, replacement), fields(rev_id = %replacement.rev_id), err)]
+    pub(crate) async fn replace_text(&self, replacement: DocTextReplacement) -> DocResult<DocDelta> {
+        let mut interval = replacement.interval();
+        match self.rev_manager.delta_after(replacement.rev_id).await {
+            Ok(None) => {},
+            Ok(Some(delta)) => interval = transform_interval(&delta, interval),
+            Err(e) if e.is_record_not_found() => return Err(DocError::replace_conflict().context(e)),
+            Err(e) => return Err(e),
+        }
+
+        let (ret, rx) = oneshot::channel::<DocumentResult<Option<Delta>>>();
+        let msg = DocumentMsg::ReplaceText {
+            interval,
+            old_text: replacement.old_text,
+            new_text: replacement.new_text,
+            ret,
+        };
+        let _ = self.document.send(msg);
+        match rx.await.map_err(internal_error)?? {
+            None => Err(DocError::replace_conflict()),
+            Some(delta) => {
+                let _ = self.save_local_delta(delta).await?;
+                self.delta().await
+            },
+        }
+    }
+
+    // Replaces the local document with the authoritative one from the server. A snapshot of the local
+    // document is taken before its revisions are discarded. The changes that weren't acked yet are
+    // rebased on the new document and synced with the server like any other local change.
+    #[tracing::instrument(level = "debug", skip(self, doc), fields(rev_id = %doc.rev_id), err)]
+    pub(crate) async fn reset(&self, doc: Doc) -> DocResult<DocDelta> {
+        let server_delta = doc.delta()?;
+        let _ = self.snapshot("Before reset").await?;
+        let pending_delta = self.rev_manager.reset(doc).await?;
+
+        let (ret, rx) = oneshot::channel::<DocumentResult<()>>();
+        let msg = DocumentMsg::Reset {
+            delta: server_delta.clone(),
+            ret,
+        };
+        let _ = self.document.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+
+        if let Some(pending_delta) = pending_delta {
+            let pending_prime = rebase_pending_delta(&server_delta, &pending_delta)?;
+            let _ = self.composing_local_delta(pending_prime.to_bytes()).await?;
+        }
+
+        self.notify_open_doc();
+        self.delta().await
+    }
+
+    pub(crate) fn update_selection(&self, selection: DocSelection) -> DocResult<()> {
+        self.presence.update_selection(selection.start, selection.end)
+    }
+
+    // Tells the other users that the document is closed, so they stop showing the cursor of the user.
+    pub(crate) fn close(&self) {
+        if let Err(e) = self.presence.send_presence(true) {
+            log::error!("Send the closed presence of {} failed: {:?}", self.doc_id, e);
+        }
+        if let Err(e) = self.typing.stop() {
+            log::error!("Send the typing stop of {} failed: {:?}", self.doc_id, e);
+        }
+    }
+
+    // The typing signal is only sent for the edits of the user, e.g. not the undo or the import
+    fn notify_typing(&self) {
+        if let Err(e) = self.typing.typing() {
+            log::error!("Send the typing of {} failed: {:?}", self.doc_id, e);
+        }
+    }
+
+    pub(crate) fn read_snapshots(&self) -> DocResult<Vec<DocSnapshot>> {
+        let persistence = SnapshotPersistence::new(self.user.db_pool()?);
+        persistence.read_snapshots(&self.doc_id)
+    }
+
+    // The document is reverted by a local change, so it's synced with the server like any other edit.
+    // The current document is snapshotted first, so the revert itself can be reverted.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn revert_to_snapshot(&self, snapshot_id: i64) -> DocResult<DocDelta> {
+        let persistence = SnapshotPersistence::new(self.user.db_pool()?);
+        let snapshot = persistence.read_snapshot_delta(&self.doc_id, snapshot_id)?;
+        let _ = self.snapshot("Before revert").await?;
+
+        let current = Delta::from_json(&self.delta().await?.data)?;
+        let delta = revert_delta(&current, &snapshot);
+        let _ = self.composing_local_delta(delta.to_bytes()).await?;
+        self.delta().await
+    }
+
+    // Replaces the whole document with the delta, e.g. the imported markdown. Like the revert, it's a local
+    // change and the replaced document is snapshotted first.
+    #[tracing::instrument(level = "debug", skip(self, delta), err)]
+    pub(crate) async fn replace_document(&self, delta: Delta) -> DocResult<DocDelta> {
+        let _ = self.snapshot("Before import").await?;
+        let current = Delta::from_json(&self.delta().await?.data)?;
+        let delta = revert_delta(&current, &delta);
+        let _ = self.composing_local_delta(delta.to_bytes()).await?;
+        self.delta().await
+    }
+
+    // Call it before the operations that rewrite the whole document.
+    async fn snapshot(&self, name: &str) -> DocResult<()> {
+        let local = self.delta().await?;
+        let persistence = SnapshotPersistence::new(self.user.db_pool()?);
+        persistence.create_snapshot(&self.doc_id, name, self.rev_manager.rev_id(), local.data)
+    }
+
     async fn save_local_delta(&self, delta: Delta) -> Result<RevId, DocError> {
         let delta_data = delta.to_bytes();
         let (base_rev_id, rev_id) = self.rev_manager.next_rev_id();
         let delta_data = delta_data.to_vec();
         let revision = Revision::new(base_rev_id, rev_id, delta_data, &self.doc_id, RevType::Local);
         let _ = self.rev_manager.add_revision(&revision).await?;
+        if rev_id % AUTO_SNAPSHOT_INTERVAL == 0 {
+            if let Err(e) = self.snapshot("Auto save").await {
+                log::error!("Auto save snapshot of {} failed: {:?}", self.doc_id, e);
+            }
+        }
         Ok(rev_id.into())
     }
 
+    // The large delta, e.g. a paste, is split into the chunks that are saved as bounded revisions
+    async fn save_local_deltas(&self, deltas: Vec<Delta>) -> DocResult<()> {
+        for delta in deltas {
+            let _ = self.save_local_delta(delta).await?;
+            tokio::task::yield_now().await;
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self, data), err)]
     pub(crate) async fn composing_local_delta(&self, data: Bytes) -> Result<(), DocError> {
         let delta = Delta::from_bytes(&data)?;
+        let chunks = split_delta(&delta, MAX_REVISION_INSERT_LEN);
+        if chunks.len() > 1 {
+            return self.composing_chunks(chunks).await;
+        }
+
         let (ret, rx) = oneshot::channel::<DocumentResult<()>>();
         let msg = DocumentMsg::Delta {
             delta: delta.clone(),
@@ -173,6 +392,41 @@ impl ClientEditDoc {
         Ok(())
     }
 
+    // The chunks are undone together. One large delta is composed at a time, the next one waits until the
+    // revisions of the previous one are saved, so the large pastes don't pile up in the revision queue.
+    async fn composing_chunks(&self, chunks: Vec<Delta>) -> DocResult<()> {
+        let _guard = self.large_delta_lock.lock().await;
+        let (ret, rx) = oneshot::channel::<DocumentResult<()>>();
+        let msg = DocumentMsg::Chunks {
+            deltas: chunks.clone(),
+            group_id: uuid(),
+            ret,
+        };
+        let _ = self.document.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+        self.save_local_deltas(chunks).await
+    }
+
+    // Same as composing_local_delta, but the delta is typed by the user so it goes through the input rules.
+    pub(crate) async fn composing_input_delta(&self, data: Bytes) -> Result<(), DocError> {
+        let delta = Delta::from_bytes(&data)?;
+        let _ = self.composing_local_delta(data).await?;
+        let _ = self.apply_input_rules(delta).await?;
+        self.notify_typing();
+        Ok(())
+    }
+
+    async fn apply_input_rules(&self, input: Delta) -> DocResult<()> {
+        let setting = read_input_rule_setting(&self.user.user_id()?);
+        let (ret, rx) = oneshot::channel::<DocumentResult<Option<Delta>>>();
+        let msg = DocumentMsg::InputRule { setting, input, ret };
+        let _ = self.document.send(msg);
+        if let Some(delta) = rx.await.map_err(internal_error)?? {
+            let _ = self.save_local_delta(delta).await?;
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "flowy_test")]
     pub async fn doc_json(&self) -> DocResult<String> {
         let (ret, rx) = oneshot::channel::<DocumentResult<String>>();
@@ -182,6 +436,12 @@ impl ClientEditDoc {
         Ok(s)
     }
 
+    #[cfg(feature = "flowy_test")]
+    pub fn remote_presences(&self) -> Vec<DocPresence> { self.presence.remotes() }
+
+    #[cfg(feature = "flowy_test")]
+    pub fn typing_users(&self) -> Vec<String> { self.typing.typing_users() }
+
     #[tracing::instrument(level = "debug", skip(self))]
     fn notify_open_doc(&self) {
         let rev_id: RevId = self.rev_manager.rev_id().into();
@@ -197,10 +457,28 @@ impl ClientEditDoc {
                 }
             });
         }
+
+        if let Err(e) = self.presence.send_presence(false) {
+            log::error!("Send the presence of {} failed: {:?}", self.doc_id, e);
+        }
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
     async fn handle_push_rev(&self, bytes: Bytes) -> DocResult<()> {
+        let revision = Revision::try_from(bytes.clone())?;
+        if self.rev_manager.rev_id() >= revision.rev_id {
+            // Ignore this push revision if local_rev_id >= server_rev_id
+            return Ok(());
+        }
+
+        // The revision was made on top of the local document, e.g. another user's change or the concurrent
+        // changes that the server transformed against the local revision. Compose it as it is.
+        let delta = Delta::from_bytes(&revision.delta_data)?;
+        let local_delta = Delta::from_json(&self.delta().await?.data)?;
+        if revision.base_rev_id == self.rev_manager.rev_id() && delta.base_len == local_delta.target_len {
+            return self.compose_remote_revision(revision, delta).await;
+        }
+
         // Transform the revision
         let (ret, rx) = oneshot::channel::<DocumentResult<TransformDeltas>>();
         let _ = self.document.send(DocumentMsg::RemoteRevision { bytes, ret });
@@ -210,11 +488,6 @@ impl ClientEditDoc {
             server_rev_id,
         } = rx.await.map_err(internal_error)??;
 
-        if self.rev_manager.rev_id() >= server_rev_id.value {
-            // Ignore this push revision if local_rev_id >= server_rev_id
-            return Ok(());
-        }
-
         // compose delta
         let (ret, rx) = oneshot::channel::<DocumentResult<()>>();
         let msg = DocumentMsg::Delta {
@@ -247,10 +520,27 @@ impl ClientEditDoc {
             &self.doc_id,
             RevType::Remote,
         );
-        let _ = self.ws.send(revision.into());
+        let _ = self.ws.send(stamp_device(revision).into());
         Ok(())
     }
 
+    async fn compose_remote_revision(&self, revision: Revision, delta: Delta) -> DocResult<()> {
+        let (ret, rx) = oneshot::channel::<DocumentResult<()>>();
+        let msg = DocumentMsg::Delta { delta, ret };
+        let _ = self.document.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+
+        self.rev_manager.update_rev_id_counter_value(revision.rev_id);
+        let revision = Revision::new(
+            revision.base_rev_id,
+            revision.rev_id,
+            revision.delta_data,
+            &self.doc_id,
+            RevType::Remote,
+        );
+        self.rev_manager.add_revision(&revision).await
+    }
+
     async fn handle_ws_message(&self, doc_data: WsDocumentData) -> DocResult<()> {
         let bytes = Bytes::from(doc_data.data);
         match doc_data.ty {
@@ -260,7 +550,7 @@ impl ClientEditDoc {
             WsDataType::PullRev => {
                 let range = RevisionRange::try_from(bytes)?;
                 let revision = self.rev_manager.mk_revisions(range).await?;
-                let _ = self.ws.send(revision.into());
+                let _ = self.ws.send(stamp_device(revision).into());
             },
             WsDataType::NewDocUser => {},
             WsDataType::Acked => {
@@ -268,6 +558,17 @@ impl ClientEditDoc {
                 let _ = self.rev_manager.ack_revision(rev_id).await?;
             },
             WsDataType::Conflict => {},
+            WsDataType::PullDocs => {},
+            WsDataType::PushDoc => {},
+            WsDataType::PushDocFailed => {},
+            WsDataType::Presence => {
+                let presence = DocPresence::try_from(bytes)?;
+                let _ = self.presence.receive(presence)?;
+            },
+            WsDataType::Typing => {
+                let typing = DocTyping::try_from(bytes)?;
+                let _ = self.typing.receive(typing)?;
+            },
         }
         Ok(())
     }
@@ -289,17 +590,28 @@ impl WsDocumentHandler for EditDocWsHandler {
         match state {
             WsState::Init => {},
             WsState::Connected(_) => self.0.notify_open_doc(),
-            WsState::Disconnected(_e) => {},
+            WsState::Disconnected(_) | WsState::Offline(_) => {
+                self.0.presence.clear_remotes();
+                self.0.typing.clear_remotes();
+            },
+            WsState::Reconnecting(_) => {},
         }
     }
 }
 
+// The revisions sent to the server carry the id of this installation, so the history can tell which
+// device made them
+fn stamp_device(mut revision: Revision) -> Revision {
+    revision.device_id = device_id();
+    revision
+}
+
 fn spawn_rev_receiver(mut receiver: mpsc::UnboundedReceiver<Revision>, ws: Arc<dyn DocumentWebSocket>) {
     tokio::spawn(async move {
         loop {
             while let Some(revision) = receiver.recv().await {
                 // tracing::debug!("Send revision:{} to server", revision.rev_id);
-                match ws.send(revision.into()) {
+                match ws.send(stamp_device(revision).into()) {
                     Ok(_) => {},
                     Err(e) => log::error!("Send revision failed: {:?}", e),
                 };
@@ -314,3 +626,27 @@ fn spawn_doc_edit_actor(doc_id: &str, delta: Delta, _pool: Arc<ConnectionPool>)
     tokio::spawn(actor.run());
     sender
 }
+
+// The pending delta was made on the local document that is replaced by the server document. The server
+// document is regarded as the change of another user that replaced the local content, and the pending
+// delta is transformed against it. The local content is only regarded as unchanged if the lengths are
+// the same. Both documents end with the newline, so it's kept and the rebased text stays before it.
+fn rebase_pending_delta(server_delta: &Delta, pending_delta: &Delta) -> DocResult<Delta> {
+    let local_len = pending_delta.base_len;
+    let server_len = server_delta.target_len;
+    let mut server_change = Delta::new();
+    if local_len == server_len {
+        server_change.retain(local_len, plain_attributes());
+    } else {
+        let kept_len = if local_len > 0 && server_len > 0 { 1 } else { 0 };
+        DeltaIter::from_interval(server_delta, Interval::new(0, server_len - kept_len))
+            .ops()
+            .into_iter()
+            .for_each(|op| server_change.add(op));
+        server_change.delete(local_len - kept_len);
+        server_change.retain(kept_len, plain_attributes());
+    }
+
+    let (_, pending_prime) = server_change.transform(pending_delta)?;
+    Ok(pending_prime)
+}
@@ -0,0 +1,132 @@
+use flowy_document_infra::{core::Document, entities::doc::InputRuleSetting, errors::DocumentResult};
+use lib_infra::kv::KV;
+use lib_ot::core::{Attribute, Delta, Interval, Operation, OperationTransformable, NEW_LINE, WHITESPACE};
+
+const EM_DASH_RULE: &str = "em_dash";
+const HEADING_RULE: &str = "heading";
+const CHECKBOX_RULE: &str = "checkbox";
+
+pub(crate) fn read_input_rule_setting(user_id: &str) -> InputRuleSetting {
+    let default = InputRuleSetting::default();
+    let read = |rule: &str, default: bool| KV::get_bool(&rule_key(user_id, rule)).unwrap_or(default);
+    InputRuleSetting {
+        em_dash: read(EM_DASH_RULE, default.em_dash),
+        heading: read(HEADING_RULE, default.heading),
+        checkbox: read(CHECKBOX_RULE, default.checkbox),
+    }
+}
+
+pub(crate) fn save_input_rule_setting(user_id: &str, setting: &InputRuleSetting) {
+    KV::set_bool(&rule_key(user_id, EM_DASH_RULE), setting.em_dash);
+    KV::set_bool(&rule_key(user_id, HEADING_RULE), setting.heading);
+    KV::set_bool(&rule_key(user_id, CHECKBOX_RULE), setting.checkbox);
+}
+
+fn rule_key(user_id: &str, rule: &str) -> String { format!("{}_input_rule_{}", user_id, rule) }
+
+// Rewrites the character that was just typed and the text before it if they match one of the enabled
+// rules. The document must have composed the input already. Returns the follow-up delta, it's not
+// merged into the input so it can be reverted on its own.
+pub(crate) fn apply_input_rules(
+    setting: &InputRuleSetting,
+    document: &mut Document,
+    input: &Delta,
+) -> DocumentResult<Option<Delta>> {
+    let (index, s) = match single_char_insert(input) {
+        None => return Ok(None),
+        Some(insert) => insert,
+    };
+
+    let text = document.to_plain_string().encode_utf16().collect::<Vec<u16>>();
+    if index >= text.len() {
+        return Ok(None);
+    }
+
+    let newline = NEW_LINE.encode_utf16().next().unwrap();
+    let line_start = match text[..index].iter().rposition(|c| c == &newline) {
+        None => 0,
+        Some(position) => position + 1,
+    };
+    let prefix = String::from_utf16_lossy(&text[line_start..index]);
+
+    if setting.em_dash && s == "-" && prefix.ends_with('-') && !prefix.ends_with("--") {
+        let delta = document.replace(Interval::new(index - 1, index + 1), "—")?;
+        return Ok(Some(delta));
+    }
+
+    if s != WHITESPACE {
+        return Ok(None);
+    }
+
+    let attribute = match prefix.as_str() {
+        "#" | "##" | "###" if setting.heading => Attribute::Header(prefix.len()),
+        "[]" if setting.checkbox => Attribute::UnChecked(true),
+        "[x]" if setting.checkbox => Attribute::Checked(true),
+        _ => return Ok(None),
+    };
+    let delete = document.delete(Interval::new(line_start, index + 1))?;
+    let format = document.format(Interval::new(line_start, line_start), attribute)?;
+    Ok(Some(delete.compose(&format)?))
+}
+
+// Returns the index and the character if the delta only inserts one character
+fn single_char_insert(delta: &Delta) -> Option<(usize, &str)> {
+    let mut index = 0;
+    let mut inserted = None;
+    for op in &delta.ops {
+        match op {
+            Operation::Retain(retain) if inserted.is_none() => index += retain.n,
+            Operation::Retain(_) => {},
+            Operation::Insert(insert) if inserted.is_none() => inserted = Some(insert.s.as_str()),
+            _ => return None,
+        }
+    }
+
+    inserted.filter(|s| s.chars().count() == 1).map(|s| (index, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_input_rules;
+    use flowy_document_infra::{
+        core::{Document, FlowyDoc},
+        entities::doc::InputRuleSetting,
+    };
+
+    fn type_text(setting: &InputRuleSetting, s: &str) -> String {
+        let mut document = Document::new::<FlowyDoc>();
+        for (index, c) in s.chars().enumerate() {
+            let input = document.insert(index, c).unwrap();
+            let _ = apply_input_rules(setting, &mut document, &input).unwrap();
+        }
+        document.to_json()
+    }
+
+    #[test]
+    fn input_rule_em_dash() {
+        let json = type_text(&InputRuleSetting::default(), "a--");
+        assert_eq!(json, r#"[{"insert":"a—\n"}]"#);
+    }
+
+    #[test]
+    fn input_rule_heading() {
+        let json = type_text(&InputRuleSetting::default(), "## ");
+        assert_eq!(json, r#"[{"insert":"\n","attributes":{"header":2}}]"#);
+    }
+
+    #[test]
+    fn input_rule_checkbox() {
+        let json = type_text(&InputRuleSetting::default(), "[] ");
+        assert_eq!(json, r#"[{"insert":"\n","attributes":{"list":"unchecked"}}]"#);
+    }
+
+    #[test]
+    fn input_rule_disabled() {
+        let setting = InputRuleSetting {
+            heading: false,
+            ..InputRuleSetting::default()
+        };
+        let json = type_text(&setting, "# ");
+        assert_eq!(json, r#"[{"insert":"# \n"}]"#);
+    }
+}
@@ -1,7 +1,13 @@
 mod doc_actor;
 mod edit_doc;
+mod input_rule;
 mod model;
+mod presence;
+mod typing;
 
 pub(crate) use doc_actor::*;
 pub use edit_doc::*;
+pub(crate) use input_rule::*;
 pub(crate) use model::*;
+pub(crate) use presence::*;
+pub(crate) use typing::*;
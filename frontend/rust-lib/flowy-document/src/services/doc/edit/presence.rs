@@ -0,0 +1,107 @@
+use crate::{
+    errors::DocResult,
+    module::DocumentUser,
+    notify::{dart_notify, DocObservable},
+    services::ws::DocumentWebSocket,
+};
+use dashmap::DashMap;
+use flowy_document_infra::entities::doc::{DocPresence, RepeatedDocPresence, PRESENCE_HEARTBEAT_INTERVAL};
+use parking_lot::RwLock;
+use std::sync::{Arc, Weak};
+use tokio::time::interval;
+
+// Keeps the selection of the local user and the presences of the other users who opened the same
+// document. The remote presences are only kept while the ws is connected.
+pub(crate) struct DocPresenceManager {
+    doc_id: String,
+    user: Arc<dyn DocumentUser>,
+    ws: Arc<dyn DocumentWebSocket>,
+    selection: RwLock<(i64, i64)>,
+    remotes: DashMap<String, DocPresence>,
+}
+
+impl DocPresenceManager {
+    pub(crate) fn new(doc_id: &str, user: Arc<dyn DocumentUser>, ws: Arc<dyn DocumentWebSocket>) -> Arc<Self> {
+        let manager = Arc::new(Self {
+            doc_id: doc_id.to_owned(),
+            user,
+            ws,
+            selection: RwLock::new((0, 0)),
+            remotes: DashMap::new(),
+        });
+        spawn_heartbeat(Arc::downgrade(&manager));
+        manager
+    }
+
+    pub(crate) fn update_selection(&self, start: i64, end: i64) -> DocResult<()> {
+        *self.selection.write() = (start, end);
+        self.send_presence(false)
+    }
+
+    pub(crate) fn send_presence(&self, is_closed: bool) -> DocResult<()> {
+        let (selection_start, selection_end) = *self.selection.read();
+        let presence = DocPresence {
+            doc_id: self.doc_id.clone(),
+            user_id: self.user.user_id()?,
+            selection_start,
+            selection_end,
+            is_closed,
+        };
+        self.ws.send(presence.into())
+    }
+
+    pub(crate) fn receive(&self, presence: DocPresence) -> DocResult<()> {
+        if presence.user_id == self.user.user_id()? {
+            return Ok(());
+        }
+
+        match presence.is_closed {
+            true => self.remotes.remove(&presence.user_id),
+            false => self.remotes.insert(presence.user_id.clone(), presence),
+        };
+        self.notify_presence_changed();
+        Ok(())
+    }
+
+    pub(crate) fn clear_remotes(&self) {
+        if !self.remotes.is_empty() {
+            self.remotes.clear();
+            self.notify_presence_changed();
+        }
+    }
+
+    pub(crate) fn remotes(&self) -> Vec<DocPresence> {
+        self.remotes
+            .iter()
+            .map(|presence| presence.value().clone())
+            .collect::<Vec<DocPresence>>()
+    }
+
+    fn notify_presence_changed(&self) {
+        let items = self.remotes();
+        dart_notify(&self.doc_id, DocObservable::DocPresenceChanged)
+            .payload(RepeatedDocPresence { items })
+            .send();
+    }
+}
+
+// The server drops the presence that isn't refreshed in time, so it's sent periodically even if the
+// selection doesn't change. The task stops after the document is closed.
+fn spawn_heartbeat(manager: Weak<DocPresenceManager>) {
+    tokio::spawn(async move {
+        let mut interval = interval(PRESENCE_HEARTBEAT_INTERVAL);
+        // The first tick completes immediately, the presence is sent when the doc is opened
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            match manager.upgrade() {
+                None => break,
+                Some(manager) => {
+                    if let Err(e) = manager.send_presence(false) {
+                        log::error!("Send the presence of {} failed: {:?}", manager.doc_id, e);
+                    }
+                },
+            }
+        }
+    });
+}
@@ -0,0 +1,137 @@
+use crate::{
+    errors::DocResult,
+    module::DocumentUser,
+    notify::{dart_notify, DocObservable},
+    services::ws::DocumentWebSocket,
+};
+use dashmap::DashMap;
+use flowy_document_infra::entities::doc::{DocTyping, DocTypingUsers, TYPING_THROTTLE_INTERVAL, TYPING_TIMEOUT};
+use parking_lot::Mutex;
+use std::{
+    sync::{Arc, Weak},
+    time::{Duration, Instant},
+};
+use tokio::time::interval;
+
+const TYPING_EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+// Sends the typing signal of the local user and keeps the other users who are typing in the same
+// document. The signal of the other user is dropped if it isn't refreshed within the TYPING_TIMEOUT, so
+// the user who stopped typing doesn't need to tell it.
+pub(crate) struct DocTypingManager {
+    doc_id: String,
+    user: Arc<dyn DocumentUser>,
+    ws: Arc<dyn DocumentWebSocket>,
+    last_sent: Mutex<Option<Instant>>,
+    remotes: DashMap<String, Instant>,
+}
+
+impl DocTypingManager {
+    pub(crate) fn new(doc_id: &str, user: Arc<dyn DocumentUser>, ws: Arc<dyn DocumentWebSocket>) -> Arc<Self> {
+        let manager = Arc::new(Self {
+            doc_id: doc_id.to_owned(),
+            user,
+            ws,
+            last_sent: Mutex::new(None),
+            remotes: DashMap::new(),
+        });
+        spawn_expiry(Arc::downgrade(&manager));
+        manager
+    }
+
+    // Called on every edit of the local user, the signal is sent once within the TYPING_THROTTLE_INTERVAL
+    pub(crate) fn typing(&self) -> DocResult<()> {
+        {
+            let mut last_sent = self.last_sent.lock();
+            if let Some(time) = *last_sent {
+                if time.elapsed() < TYPING_THROTTLE_INTERVAL {
+                    return Ok(());
+                }
+            }
+            *last_sent = Some(Instant::now());
+        }
+        self.send_typing(true)
+    }
+
+    // Nothing is sent if the last signal has expired already
+    pub(crate) fn stop(&self) -> DocResult<()> {
+        let last_sent = self.last_sent.lock().take();
+        match last_sent {
+            Some(time) if time.elapsed() < TYPING_TIMEOUT => self.send_typing(false),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn receive(&self, typing: DocTyping) -> DocResult<()> {
+        if typing.user_id == self.user.user_id()? {
+            return Ok(());
+        }
+
+        let is_changed = match typing.is_typing {
+            true => self.remotes.insert(typing.user_id, Instant::now()).is_none(),
+            false => self.remotes.remove(&typing.user_id).is_some(),
+        };
+        if is_changed {
+            self.notify_typing_changed();
+        }
+        Ok(())
+    }
+
+    pub(crate) fn clear_remotes(&self) {
+        if !self.remotes.is_empty() {
+            self.remotes.clear();
+            self.notify_typing_changed();
+        }
+    }
+
+    pub(crate) fn typing_users(&self) -> Vec<String> {
+        let mut user_ids = self
+            .remotes
+            .iter()
+            .map(|remote| remote.key().clone())
+            .collect::<Vec<String>>();
+        user_ids.sort();
+        user_ids
+    }
+
+    fn send_typing(&self, is_typing: bool) -> DocResult<()> {
+        let typing = DocTyping {
+            doc_id: self.doc_id.clone(),
+            user_id: self.user.user_id()?,
+            is_typing,
+        };
+        self.ws.send(typing.into())
+    }
+
+    fn remove_expired(&self) {
+        let len = self.remotes.len();
+        self.remotes.retain(|_, time| time.elapsed() < TYPING_TIMEOUT);
+        if self.remotes.len() != len {
+            self.notify_typing_changed();
+        }
+    }
+
+    fn notify_typing_changed(&self) {
+        let user_ids = self.typing_users();
+        dart_notify(&self.doc_id, DocObservable::DocTypingChanged)
+            .payload(DocTypingUsers {
+                doc_id: self.doc_id.clone(),
+                user_ids,
+            })
+            .send();
+    }
+}
+
+// The task stops after the document is closed
+fn spawn_expiry(manager: Weak<DocTypingManager>) {
+    tokio::spawn(async move {
+        let mut interval = interval(TYPING_EXPIRY_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            match manager.upgrade() {
+                None => break,
+                Some(manager) => manager.remove_expired(),
+            }
+        }
+    });
+}
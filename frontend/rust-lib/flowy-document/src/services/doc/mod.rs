@@ -1,6 +1,20 @@
+mod attachment;
+mod attachment_cache;
 mod edit;
+mod prefetch;
+mod read_position;
 mod revision;
+mod search;
+mod snapshot;
+mod task;
 
 pub(crate) mod doc_controller;
+pub(crate) use attachment::*;
+pub(crate) use attachment_cache::*;
 pub use edit::*;
-pub(crate) use revision::*;
+pub(crate) use prefetch::*;
+pub(crate) use read_position::*;
+pub use revision::*;
+pub(crate) use search::*;
+pub(crate) use snapshot::*;
+pub(crate) use task::*;
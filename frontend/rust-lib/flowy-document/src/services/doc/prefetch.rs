@@ -0,0 +1,204 @@
+use crate::{
+    errors::{internal_error, DocError, DocResult},
+    module::DocumentUser,
+    notify::{dart_notify, DocObservable},
+    services::{
+        cache::DocCache,
+        doc::revision::Persistence,
+        ws::{DocumentWebSocket, WsDocumentHandler},
+    },
+};
+use bytes::Bytes;
+use flowy_document_infra::entities::{
+    doc::{revision_from_doc, Doc, DocIdentifiers, DocPrefetchProgress, RevType},
+    ws::{WsDataType, WsDocumentData},
+};
+use lib_dispatch::prelude::BlockingPool;
+use lib_ws::WsState;
+use parking_lot::RwLock;
+use std::{convert::TryFrom, sync::Arc};
+
+struct PrefetchTask {
+    id: String,
+    pending_doc_ids: Vec<String>,
+    total: i64,
+    size: i64,
+    failed: i64,
+}
+
+impl PrefetchTask {
+    fn new(id: &str, doc_ids: Vec<String>) -> Self {
+        let total = doc_ids.len() as i64;
+        Self {
+            id: id.to_owned(),
+            pending_doc_ids: doc_ids,
+            total,
+            size: 0,
+            failed: 0,
+        }
+    }
+
+    // Returns true if the doc belongs to this task
+    fn finish(&mut self, doc_id: &str, size: i64) -> bool {
+        let len = self.pending_doc_ids.len();
+        self.pending_doc_ids.retain(|pending_doc_id| pending_doc_id != doc_id);
+        if len == self.pending_doc_ids.len() {
+            return false;
+        }
+        self.size += size;
+        true
+    }
+
+    // The doc can't be read by the server, it's finished without being stored
+    fn fail(&mut self, doc_id: &str) -> bool {
+        if !self.finish(doc_id, 0) {
+            return false;
+        }
+        self.failed += 1;
+        true
+    }
+
+    fn is_completed(&self) -> bool { self.pending_doc_ids.is_empty() }
+
+    fn progress(&self) -> DocPrefetchProgress {
+        DocPrefetchProgress {
+            id: self.id.clone(),
+            total: self.total,
+            finished: self.total - self.pending_doc_ids.len() as i64,
+            size: self.size,
+            failed: self.failed,
+        }
+    }
+}
+
+// Downloads the snapshots of the documents in batch, so they can be opened without network. The
+// progress of each prefetch is sent to the dart side with the id of the prefetch.
+pub(crate) struct DocPrefetcher {
+    ws: Arc<dyn DocumentWebSocket>,
+    user: Arc<dyn DocumentUser>,
+    cache: Arc<DocCache>,
+    tasks: RwLock<Vec<PrefetchTask>>,
+    blocking_pool: BlockingPool,
+}
+
+impl DocPrefetcher {
+    pub(crate) fn new(
+        ws: Arc<dyn DocumentWebSocket>,
+        user: Arc<dyn DocumentUser>,
+        cache: Arc<DocCache>,
+        blocking_pool: BlockingPool,
+    ) -> Self {
+        Self {
+            ws,
+            user,
+            cache,
+            tasks: RwLock::new(vec![]),
+            blocking_pool,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, doc_ids), fields(count = %doc_ids.len()), err)]
+    pub(crate) fn prefetch(&self, id: &str, doc_ids: Vec<String>) -> DocResult<DocPrefetchProgress> {
+        let task = PrefetchTask::new(id, doc_ids.clone());
+        let progress = task.progress();
+        if task.is_completed() {
+            return Ok(progress);
+        }
+
+        {
+            let mut tasks = self.tasks.write();
+            tasks.retain(|task| task.id != id);
+            tasks.push(task);
+        }
+
+        let _ = self.ws.send(DocIdentifiers::from(doc_ids).into())?;
+        Ok(progress)
+    }
+
+    fn receive_doc(&self, doc: Doc) -> DocResult<()> {
+        let doc_id = doc.id.clone();
+        let size = doc.data.len() as i64;
+
+        // The opened document is kept in sync by its own ws handler
+        if !self.cache.contains(&doc_id) {
+            let persistence = Persistence::new(self.user.db_pool()?);
+            let revision = revision_from_doc(doc, RevType::Remote);
+            if !persistence.save_snapshot(revision)? {
+                tracing::debug!("Ignore the snapshot of {}, it has local changes", doc_id);
+            }
+        }
+
+        self.update_tasks(|task| task.finish(&doc_id, size));
+        Ok(())
+    }
+
+    fn receive_failed_doc(&self, doc_id: &str) {
+        log::warn!("Prefetch the doc {} failed", doc_id);
+        self.update_tasks(|task| task.fail(doc_id));
+    }
+
+    fn update_tasks<F>(&self, f: F)
+    where
+        F: Fn(&mut PrefetchTask) -> bool,
+    {
+        let mut tasks = self.tasks.write();
+        for task in tasks.iter_mut() {
+            if f(task) {
+                dart_notify(&task.id, DocObservable::DocPrefetchProgress)
+                    .payload(task.progress())
+                    .send();
+            }
+        }
+        tasks.retain(|task| !task.is_completed());
+    }
+}
+
+pub(crate) struct DocPrefetchWsHandler(pub Arc<DocPrefetcher>);
+
+impl WsDocumentHandler for DocPrefetchWsHandler {
+    fn receive(&self, doc_data: WsDocumentData) {
+        let prefetcher = self.0.clone();
+        if doc_data.ty == WsDataType::PushDocFailed {
+            prefetcher.receive_failed_doc(&doc_data.doc_id);
+            return;
+        }
+
+        let blocking_pool = prefetcher.blocking_pool.clone();
+        tokio::spawn(async move {
+            let result = blocking_pool
+                .spawn(move || {
+                    Doc::try_from(Bytes::from(doc_data.data))
+                        .map_err(DocError::from)
+                        .and_then(|doc| prefetcher.receive_doc(doc))
+                })
+                .await
+                .map_err(internal_error)
+                .and_then(|result| result);
+            if let Err(e) = result {
+                log::error!("Save the prefetched doc failed: {:?}", e);
+            }
+        });
+    }
+
+    fn state_changed(&self, _state: &WsState) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrefetchTask;
+
+    #[test]
+    fn prefetch_task_completed_with_failed_doc() {
+        let mut task = PrefetchTask::new("prefetch", vec!["a".to_owned(), "b".to_owned()]);
+        assert!(task.finish("a", 10));
+        assert!(!task.fail("c"));
+        assert!(!task.is_completed());
+
+        assert!(task.fail("b"));
+        assert!(task.is_completed());
+        let progress = task.progress();
+        assert_eq!(progress.finished, 2);
+        assert_eq!(progress.failed, 1);
+        assert_eq!(progress.size, 10);
+    }
+}
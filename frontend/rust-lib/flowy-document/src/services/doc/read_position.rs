@@ -0,0 +1,51 @@
+use crate::{
+    errors::DocError,
+    sql_tables::doc::{ReadPositionTable, ReadPositionTableSql},
+};
+use flowy_database::SqliteConnection;
+use flowy_document_infra::entities::doc::DocReadPosition;
+use lib_infra::timestamp;
+
+// The document may have been shortened on another device after the position was saved, so the offset is
+// clamped to the length of the document.
+pub(crate) fn read_position(
+    doc_id: &str,
+    doc_len: usize,
+    conn: &SqliteConnection,
+) -> Result<Option<DocReadPosition>, DocError> {
+    let position = ReadPositionTableSql::read_position(doc_id, conn)?.map(|table| {
+        let mut position: DocReadPosition = table.into();
+        position.offset = position.offset.max(0).min(doc_len as i64);
+        position
+    });
+    Ok(position)
+}
+
+pub(crate) fn save_read_position(mut position: DocReadPosition, conn: &SqliteConnection) -> Result<(), DocError> {
+    position.modified_time = timestamp();
+    ReadPositionTableSql::save_position(position.into(), conn)
+}
+
+pub(crate) fn read_all_positions(conn: &SqliteConnection) -> Result<Vec<DocReadPosition>, DocError> {
+    let positions = ReadPositionTableSql::read_all(conn)?
+        .into_iter()
+        .map(|table| table.into())
+        .collect::<Vec<DocReadPosition>>();
+    Ok(positions)
+}
+
+// The positions come from another device, each of them is only kept if it's later than the local one
+pub(crate) fn merge_read_positions(positions: Vec<DocReadPosition>, conn: &SqliteConnection) -> Result<(), DocError> {
+    conn.immediate_transaction::<_, DocError, _>(|| {
+        for position in positions {
+            let is_later = match ReadPositionTableSql::read_position(&position.doc_id, conn)? {
+                None => true,
+                Some(local) => position.modified_time > local.modified_time,
+            };
+            if is_later {
+                let _ = ReadPositionTableSql::save_position(ReadPositionTable::from(position), conn)?;
+            }
+        }
+        Ok(())
+    })
+}
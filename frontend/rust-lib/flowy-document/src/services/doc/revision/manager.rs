@@ -4,10 +4,14 @@ use crate::{
 };
 use flowy_database::ConnectionPool;
 use flowy_document_infra::{
-    entities::doc::{Doc, RevId, RevType, Revision, RevisionRange},
+    entities::doc::{revision_from_doc, Doc, RevId, RevType, Revision, RevisionRange},
     util::RevIdCounter,
 };
-use lib_infra::future::ResultFuture;
+use lib_dispatch::prelude::BlockingPool;
+use lib_infra::{
+    bus::{DocRevisionEvent, EventBus},
+    future::ResultFuture,
+};
 use lib_ot::core::{Delta, OperationTransformable};
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -20,32 +24,43 @@ pub struct RevisionManager {
     doc_id: String,
     rev_id_counter: RevIdCounter,
     rev_store: Arc<RevisionStore>,
+    bus: EventBus,
 }
 
 impl RevisionManager {
     pub fn new(
         doc_id: &str,
         pool: Arc<ConnectionPool>,
+        blocking_pool: BlockingPool,
         server: Arc<dyn RevisionServer>,
         pending_rev_sender: mpsc::UnboundedSender<Revision>,
+        bus: EventBus,
     ) -> Self {
-        let rev_store = RevisionStore::new(doc_id, pool, server, pending_rev_sender);
+        let rev_store = RevisionStore::new(doc_id, pool, blocking_pool, server, pending_rev_sender);
         let rev_id_counter = RevIdCounter::new(0);
         Self {
             doc_id: doc_id.to_string(),
             rev_id_counter,
             rev_store,
+            bus,
         }
     }
 
     pub async fn load_document(&mut self) -> DocResult<Delta> {
         let doc = self.rev_store.fetch_document().await?;
+        let _ = self.rev_store.load_pending_revs().await?;
+        self.rev_store.schedule_compaction().await;
         self.update_rev_id_counter_value(doc.rev_id);
         Ok(doc.delta()?)
     }
 
     pub async fn add_revision(&self, revision: &Revision) -> Result<(), DocError> {
         let _ = self.rev_store.add_revision(revision.clone()).await?;
+        self.bus.publish(DocRevisionEvent {
+            doc_id: revision.doc_id.clone(),
+            rev_id: revision.rev_id,
+            is_remote: !revision.ty.is_local(),
+        });
         Ok(())
     }
 
@@ -54,6 +69,39 @@ impl RevisionManager {
         Ok(())
     }
 
+    // Returns the composed delta of the revisions that were discarded, if there are any.
+    pub async fn reset(&self, doc: Doc) -> Result<Option<Delta>, DocError> {
+        let rev_id = doc.rev_id;
+        let revision = revision_from_doc(doc, RevType::Remote);
+        let pending_revs = self.rev_store.reset(revision).await?;
+        self.update_rev_id_counter_value(rev_id);
+
+        if pending_revs.is_empty() {
+            return Ok(None);
+        }
+
+        let mut pending_delta = Delta::new();
+        for revision in pending_revs {
+            let delta = Delta::from_bytes(revision.delta_data)?;
+            pending_delta = pending_delta.compose(&delta)?;
+        }
+        Ok(Some(pending_delta))
+    }
+
+    // Composes the revisions that were made after the rev_id, returns None if there isn't any.
+    pub async fn delta_after(&self, rev_id: i64) -> Result<Option<Delta>, DocError> {
+        let revisions = self.rev_store.revs_after(rev_id).await?;
+        if revisions.is_empty() {
+            return Ok(None);
+        }
+
+        let mut delta = Delta::new();
+        for revision in revisions {
+            delta = delta.compose(&Delta::from_bytes(revision.delta_data)?)?;
+        }
+        Ok(Some(delta))
+    }
+
     pub fn rev_id(&self) -> i64 { self.rev_id_counter.value() }
 
     pub fn next_rev_id(&self) -> (i64, i64) {
@@ -3,4 +3,5 @@ mod model;
 mod persistence;
 
 pub use manager::*;
+pub(crate) use model::Persistence;
 pub use persistence::*;
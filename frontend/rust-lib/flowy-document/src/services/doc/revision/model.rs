@@ -1,10 +1,11 @@
 use crate::{
     errors::{internal_error, DocError, DocResult},
-    sql_tables::{RevState, RevTableSql},
+    sql_tables::{CheckpointTable, CheckpointTableSql, RevState, RevTableSql},
 };
-use flowy_database::ConnectionPool;
-use flowy_document_infra::entities::doc::{Revision, RevisionRange};
+use flowy_database::{ConnectionPool, SqliteConnection};
+use flowy_document_infra::entities::doc::{compose_revisions, Revision, RevisionRange};
 use lib_infra::future::ResultFuture;
+use lib_ot::core::Delta;
 
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -44,15 +45,29 @@ impl PendingRevId {
     }
 }
 
+// The composed delta of the revisions that were compacted, the document is the checkpoint composed with the
+// revisions that are left in the rev_table.
+pub(crate) struct RevisionCheckpoint {
+    pub(crate) base_rev_id: i64,
+    pub(crate) rev_id: i64,
+    pub(crate) delta: Delta,
+}
+
 pub(crate) struct Persistence {
     pub(crate) rev_sql: Arc<RevTableSql>,
+    pub(crate) checkpoint_sql: Arc<CheckpointTableSql>,
     pub(crate) pool: Arc<ConnectionPool>,
 }
 
 impl Persistence {
     pub(crate) fn new(pool: Arc<ConnectionPool>) -> Self {
         let rev_sql = Arc::new(RevTableSql {});
-        Self { rev_sql, pool }
+        let checkpoint_sql = Arc::new(CheckpointTableSql {});
+        Self {
+            rev_sql,
+            checkpoint_sql,
+            pool,
+        }
     }
 
     pub(crate) fn create_revs(&self, revisions: Vec<(Revision, RevState)>) -> DocResult<()> {
@@ -63,12 +78,157 @@ impl Persistence {
         })
     }
 
+    // Replaces all the revisions of the document with the given one in a single transaction, so the
+    // document never ends up half reset.
+    pub(crate) fn reset_revs(&self, doc_id: &str, revision: Revision) -> DocResult<()> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            let _ = self.rev_sql.delete_rev_tables(doc_id, conn)?;
+            let _ = self.checkpoint_sql.delete_checkpoint(doc_id, conn)?;
+            let _ = self.rev_sql.create_rev_table(vec![(revision, RevState::Acked)], conn)?;
+            Ok(())
+        })
+    }
+
+    // The snapshot is ignored if the document has local changes that the server hasn't acked yet,
+    // they will be synced when the document gets opened. Returns true if the snapshot was saved.
+    pub(crate) fn save_snapshot(&self, revision: Revision) -> DocResult<bool> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            if self.rev_sql.contains_local_revs(&revision.doc_id, conn)? {
+                return Ok(false);
+            }
+            let _ = self.rev_sql.delete_rev_tables(&revision.doc_id, conn)?;
+            let _ = self.checkpoint_sql.delete_checkpoint(&revision.doc_id, conn)?;
+            let _ = self.rev_sql.create_rev_table(vec![(revision, RevState::Acked)], conn)?;
+            Ok(true)
+        })
+    }
+
+    // Writes a batch of the revisions and marks the saved ones up to the acked_rev_id as acked, in one transaction
+    pub(crate) fn write_revs(
+        &self,
+        doc_id: &str,
+        revisions: Vec<(Revision, RevState)>,
+        acked_rev_id: Option<i64>,
+    ) -> DocResult<()> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            if !revisions.is_empty() {
+                let _ = self.rev_sql.create_rev_table(revisions, conn)?;
+            }
+            if let Some(rev_id) = acked_rev_id {
+                let _ = self.rev_sql.ack_rev_tables(doc_id, rev_id, conn)?;
+            }
+            Ok(())
+        })
+    }
+
+    // Composes the acked revisions, except the latest keep ones, into the checkpoint of the document and removes
+    // them. The revisions that the server hasn't acked are never compacted because they are synced one by one.
+    // Returns how many revisions were compacted.
+    pub(crate) fn compact_revs(&self, doc_id: &str, keep: usize) -> DocResult<usize> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            let records = self.rev_sql.read_rev_records(doc_id, conn)?;
+            let count = records.len().saturating_sub(keep);
+            let revisions = records
+                .into_iter()
+                .take(count)
+                .take_while(|(_, state)| state == &RevState::Acked)
+                .map(|(revision, _)| revision)
+                .collect::<Vec<Revision>>();
+            let last = match revisions.last() {
+                None => return Ok(0),
+                Some(revision) => revision,
+            };
+
+            let base = match self.read_checkpoint(doc_id, conn)? {
+                None => Delta::new(),
+                Some(checkpoint) => checkpoint.delta,
+            };
+            let delta = compose_revisions(base, &revisions)?;
+            let table = CheckpointTable {
+                doc_id: doc_id.to_owned(),
+                base_rev_id: last.base_rev_id,
+                rev_id: last.rev_id,
+                data: delta.to_bytes().to_vec(),
+                modified_time: chrono::Utc::now().timestamp(),
+            };
+            let _ = self.checkpoint_sql.save_checkpoint(table, conn)?;
+            let _ = self.rev_sql.delete_rev_tables_to(doc_id, last.rev_id, conn)?;
+            tracing::debug!("Compact {} revisions of {} up to {}", revisions.len(), doc_id, last.rev_id);
+            Ok(revisions.len())
+        })
+    }
+
+    pub(crate) fn read_checkpoint(
+        &self,
+        doc_id: &str,
+        conn: &SqliteConnection,
+    ) -> DocResult<Option<RevisionCheckpoint>> {
+        match self.checkpoint_sql.read_checkpoint(doc_id, conn)? {
+            None => Ok(None),
+            Some(table) => Ok(Some(RevisionCheckpoint {
+                base_rev_id: table.base_rev_id,
+                rev_id: table.rev_id,
+                delta: Delta::from_bytes(table.data)?,
+            })),
+        }
+    }
+
+    // The revisions up to the rev_id were compacted, the ones after it are still in the rev_table
+    pub(crate) fn read_checkpoint_rev_id(&self, doc_id: &str) -> DocResult<Option<i64>> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        self.checkpoint_sql.read_checkpoint_rev_id(doc_id, conn)
+    }
+
+    pub(crate) fn read_local_revs(&self, doc_id: &str) -> DocResult<Vec<Revision>> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        self.rev_sql.read_local_rev_tables(doc_id, conn)
+    }
+
+    // The documents that have local changes that the server hasn't acked yet
+    pub(crate) fn read_unsynced_doc_ids(&self) -> DocResult<Vec<String>> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        self.rev_sql.read_doc_ids_with_local_revs(conn)
+    }
+
+    pub(crate) fn read_latest_rev_id(&self, doc_id: &str) -> DocResult<Option<i64>> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let rev_id = self.rev_sql.read_latest_rev_id(doc_id, conn)?;
+        Ok(rev_id)
+    }
+
+    // Composes the checkpoint and the local revisions of the document, returns None if the document isn't stored
+    // locally.
+    pub(crate) fn read_delta(&self, doc_id: &str) -> DocResult<Option<Delta>> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let checkpoint = self.read_checkpoint(doc_id, conn)?;
+        let revisions = self.rev_sql.read_rev_tables(doc_id, conn)?;
+        if checkpoint.is_none() && revisions.is_empty() {
+            return Ok(None);
+        }
+
+        let base = checkpoint.map_or_else(Delta::new, |checkpoint| checkpoint.delta);
+        Ok(Some(compose_revisions(base, &revisions)?))
+    }
+
     pub(crate) fn read_rev_with_range(&self, doc_id: &str, range: RevisionRange) -> DocResult<Vec<Revision>> {
         let conn = &*self.pool.get().map_err(internal_error).unwrap();
         let revisions = self.rev_sql.read_rev_tables_with_range(doc_id, range, conn)?;
         Ok(revisions)
     }
 
+    pub(crate) fn read_revs_after(&self, doc_id: &str, rev_id: i64) -> DocResult<Vec<Revision>> {
+        let range = RevisionRange {
+            doc_id: doc_id.to_owned(),
+            start: rev_id + 1,
+            end: i64::MAX,
+        };
+        self.read_rev_with_range(doc_id, range)
+    }
+
     pub(crate) fn read_rev(&self, doc_id: &str, rev_id: &i64) -> DocResult<Option<Revision>> {
         let conn = self.pool.get().map_err(internal_error)?;
         let some = self.rev_sql.read_rev_table(&doc_id, rev_id, &*conn)?;
@@ -8,28 +8,56 @@ use dashmap::DashMap;
 use flowy_database::{ConnectionPool, SqliteConnection};
 use flowy_document_infra::entities::doc::{revision_from_doc, Doc, RevId, RevType, Revision, RevisionRange};
 use futures::stream::StreamExt;
+use lib_dispatch::prelude::BlockingPool;
 use lib_infra::future::ResultFuture;
 use lib_ot::core::{Delta, Operation, OperationTransformable};
-use std::{collections::VecDeque, sync::Arc, time::Duration};
+use parking_lot::Mutex;
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::{
     sync::{broadcast, mpsc, RwLock},
-    task::{spawn_blocking, JoinHandle},
+    task::JoinHandle,
 };
 
+// The revisions and the acks are written at most once per window, the ones that are made while the user types
+// fast are written in one transaction instead of one per keystroke.
+const REVISION_WRITE_WINDOW: Duration = Duration::from_millis(300);
+
+// The acked revisions, except the latest ones, are composed into the checkpoint of the document after it's opened
+// and after this many revisions were written. The document is opened from the checkpoint and the short tail of
+// revisions instead of replaying its whole history.
+const COMPACTION_INTERVAL: usize = 200;
+// The latest revisions are kept, so the replacements that are made on a recent rev_id can still be transformed
+const KEPT_REVISION_COUNT: usize = 100;
+
 pub struct RevisionStore {
     doc_id: String,
     persistence: Arc<Persistence>,
     revs_map: Arc<DashMap<i64, RevisionRecord>>,
+    // The latest rev_id that the server acked. The saved revisions up to it are marked as acked in the next batch.
+    acked_rev_id: Arc<Mutex<Option<i64>>>,
+    is_saving: Arc<AtomicBool>,
+    // The revisions that were written since the last compaction
+    written_count: Arc<AtomicUsize>,
     pending_tx: PendingSender,
     pending_revs: Arc<RwLock<VecDeque<PendingRevId>>>,
     defer_save: RwLock<Option<JoinHandle<()>>>,
     server: Arc<dyn RevisionServer>,
+    // Runs the sqlite queries and the compaction off the async executor
+    blocking_pool: BlockingPool,
 }
 
 impl RevisionStore {
     pub fn new(
         doc_id: &str,
         pool: Arc<ConnectionPool>,
+        blocking_pool: BlockingPool,
         server: Arc<dyn RevisionServer>,
         ws_revision_sender: mpsc::UnboundedSender<Revision>,
     ) -> Arc<RevisionStore> {
@@ -43,10 +71,14 @@ impl RevisionStore {
             doc_id,
             persistence,
             revs_map,
+            acked_rev_id: Arc::new(Mutex::new(None)),
+            is_saving: Arc::new(AtomicBool::new(false)),
+            written_count: Arc::new(AtomicUsize::new(0)),
             pending_revs,
             pending_tx,
             defer_save: RwLock::new(None),
             server,
+            blocking_pool,
         });
 
         tokio::spawn(RevisionStream::new(store.clone(), pending_rx, ws_revision_sender).run());
@@ -60,19 +92,16 @@ impl RevisionStore {
             return Err(DocError::duplicate_rev().context(format!("Duplicate revision id: {}", revision.rev_id)));
         }
 
-        let (sender, receiver) = broadcast::channel(2);
-        let revs_map = self.revs_map.clone();
-        let mut rx = sender.subscribe();
-        tokio::spawn(async move {
-            match rx.recv().await {
-                Ok(rev_id) => match revs_map.get_mut(&rev_id) {
-                    None => {},
-                    Some(mut rev) => rev.value_mut().state = RevState::Acked,
-                },
-                Err(_) => {},
-            }
-        });
+        // The remote revision is on the server already, so it's saved as acked instead of being synced.
+        if !revision.ty.is_local() {
+            let mut record = RevisionRecord::new(revision);
+            record.state = RevState::Acked;
+            self.revs_map.insert(record.revision.rev_id, record);
+            self.save_revisions().await;
+            return Ok(());
+        }
 
+        let (sender, receiver) = broadcast::channel(2);
         let pending_rev = PendingRevId::new(revision.rev_id, sender);
         self.pending_revs.write().await.push_back(pending_rev);
         self.revs_map.insert(revision.rev_id, RevisionRecord::new(revision));
@@ -82,6 +111,8 @@ impl RevisionStore {
         Ok(())
     }
 
+    // The acked revisions are removed from the pending queue. The ones that were saved already are marked
+    // as acked in the database with the next batch, so they won't be synced again after restarting.
     #[tracing::instrument(level = "debug", skip(self, rev_id), fields(rev_id = %rev_id.as_ref()))]
     pub async fn ack_revision(&self, rev_id: RevId) {
         let rev_id = rev_id.value;
@@ -90,40 +121,81 @@ impl RevisionStore {
             .await
             .retain(|pending| !pending.finish(rev_id));
 
+        self.revs_map
+            .iter_mut()
+            .filter(|kv| kv.key() <= &rev_id)
+            .for_each(|mut kv| kv.value_mut().state = RevState::Acked);
+
+        {
+            let mut acked_rev_id = self.acked_rev_id.lock();
+            *acked_rev_id = Some(acked_rev_id.map_or(rev_id, |acked_rev_id| acked_rev_id.max(rev_id)));
+        }
         self.save_revisions().await;
     }
 
+    // Restores the revisions that weren't acked before the document was closed, e.g. the ones that were
+    // made offline. They are synced in order when the document is opened on the server, which pulls the
+    // revisions it's missing, see WsDataType::PullRev.
+    pub async fn load_pending_revs(&self) -> DocResult<()> {
+        let doc_id = self.doc_id.clone();
+        let persistence = self.persistence.clone();
+        let revisions = self
+            .blocking_pool
+            .spawn(move || persistence.read_local_revs(&doc_id))
+            .await
+            .map_err(internal_error)??;
+
+        let mut pending_revs = self.pending_revs.write().await;
+        for revision in revisions {
+            if pending_revs.iter().any(|pending| pending.rev_id == revision.rev_id) {
+                continue;
+            }
+            let (sender, _) = broadcast::channel(2);
+            pending_revs.push_back(PendingRevId::new(revision.rev_id, sender));
+        }
+        tracing::debug!("{} has {} pending revisions", self.doc_id, pending_revs.len());
+        Ok(())
+    }
+
+    // Schedules the batch that writes the revisions after the window. The revisions that are added before the
+    // batch is written are written with it.
     async fn save_revisions(&self) {
-        if let Some(handler) = self.defer_save.write().await.take() {
-            handler.abort();
+        if self
+            .is_saving
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return;
         }
 
-        if self.revs_map.is_empty() {
+        *self.defer_save.write().await = Some(tokio::spawn(self.batch().run()));
+    }
+
+    // Compacts the revisions in the background after the document is opened, so the open doesn't wait for it.
+    // The compaction runs like a batch, so it never races with the writes. It runs after the batch that is
+    // being written instead if there is one.
+    pub async fn schedule_compaction(&self) {
+        if self
+            .is_saving
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            self.written_count.store(COMPACTION_INTERVAL, Ordering::SeqCst);
             return;
         }
 
-        let revs_map = self.revs_map.clone();
-        let persistence = self.persistence.clone();
+        *self.defer_save.write().await = Some(tokio::spawn(self.batch().compact_then_run()));
+    }
 
-        *self.defer_save.write().await = Some(tokio::spawn(async move {
-            tokio::time::sleep(Duration::from_millis(300)).await;
-            let ids = revs_map.iter().map(|kv| kv.key().clone()).collect::<Vec<i64>>();
-            let revisions_state = revs_map
-                .iter()
-                .map(|kv| (kv.revision.clone(), kv.state))
-                .collect::<Vec<(Revision, RevState)>>();
-
-            match persistence.create_revs(revisions_state.clone()) {
-                Ok(_) => {
-                    tracing::debug!(
-                        "Revision State Changed: {:?}",
-                        revisions_state.iter().map(|s| (s.0.rev_id, s.1)).collect::<Vec<_>>()
-                    );
-                    revs_map.retain(|k, _| !ids.contains(k));
-                },
-                Err(e) => log::error!("Save revision failed: {:?}", e),
-            }
-        }));
+    fn batch(&self) -> RevisionBatch {
+        RevisionBatch {
+            doc_id: self.doc_id.clone(),
+            persistence: self.persistence.clone(),
+            revs_map: self.revs_map.clone(),
+            acked_rev_id: self.acked_rev_id.clone(),
+            is_saving: self.is_saving.clone(),
+            written_count: self.written_count.clone(),
+        }
     }
 
     pub async fn revs_in_range(&self, range: RevisionRange) -> DocResult<Vec<Revision>> {
@@ -140,26 +212,206 @@ impl RevisionStore {
         } else {
             let doc_id = self.doc_id.clone();
             let persistence = self.persistence.clone();
-            let result = spawn_blocking(move || persistence.read_rev_with_range(&doc_id, range))
+            let result = self
+                .blocking_pool
+                .spawn(move || persistence.read_rev_with_range(&doc_id, range))
                 .await
                 .map_err(internal_error)?;
             result
         }
     }
 
+    // Returns the revisions that were made after the rev_id, ordered by rev_id. The ones that are not
+    // saved yet are read from the memory. It fails if the revisions after the rev_id were compacted.
+    pub async fn revs_after(&self, rev_id: i64) -> DocResult<Vec<Revision>> {
+        let doc_id = self.doc_id.clone();
+        let persistence = self.persistence.clone();
+        let mut revisions = self
+            .blocking_pool
+            .spawn(move || match persistence.read_checkpoint_rev_id(&doc_id)? {
+                Some(checkpoint_rev_id) if checkpoint_rev_id > rev_id => Err(DocError::record_not_found()
+                    .context(format!("The revisions of {} up to {} were compacted", doc_id, checkpoint_rev_id))),
+                _ => persistence.read_revs_after(&doc_id, rev_id),
+            })
+            .await
+            .map_err(internal_error)??;
+
+        for kv in self.revs_map.iter() {
+            let is_saved = revisions.iter().any(|revision| &revision.rev_id == kv.key());
+            if kv.key() > &rev_id && !is_saved {
+                revisions.push(kv.revision.clone());
+            }
+        }
+        revisions.sort_by_key(|revision| revision.rev_id);
+        Ok(revisions)
+    }
+
+    // Drops the revisions that haven't been acked by the server yet and replaces the local history
+    // with the given revision. The dropped revisions are returned, ordered by rev_id.
+    #[tracing::instrument(level = "debug", skip(self, revision), fields(rev_id = %revision.rev_id), err)]
+    pub async fn reset(&self, revision: Revision) -> DocResult<Vec<Revision>> {
+        if let Some(handler) = self.defer_save.write().await.take() {
+            handler.abort();
+        }
+        self.is_saving.store(false, Ordering::SeqCst);
+        *self.acked_rev_id.lock() = None;
+
+        let pending_rev_ids = self
+            .pending_revs
+            .write()
+            .await
+            .drain(..)
+            .map(|pending| pending.rev_id)
+            .collect::<Vec<i64>>();
+
+        let mut pending_revs = vec![];
+        for rev_id in pending_rev_ids {
+            match self.revs_map.get(&rev_id) {
+                None => {
+                    if let Some(revision) = self.persistence.read_rev(&self.doc_id, &rev_id)? {
+                        pending_revs.push(revision);
+                    }
+                },
+                Some(record) => pending_revs.push(record.revision.clone()),
+            }
+        }
+        self.revs_map.clear();
+
+        let doc_id = self.doc_id.clone();
+        let persistence = self.persistence.clone();
+        let _ = self
+            .blocking_pool
+            .spawn(move || persistence.reset_revs(&doc_id, revision))
+            .await
+            .map_err(internal_error)??;
+
+        Ok(pending_revs)
+    }
+
     pub async fn fetch_document(&self) -> DocResult<Doc> {
-        let result = fetch_from_local(&self.doc_id, self.persistence.clone()).await;
+        let result = fetch_from_local(&self.doc_id, self.persistence.clone(), &self.blocking_pool).await;
         if result.is_ok() {
             return result;
         }
 
         let doc = self.server.fetch_document_from_remote(&self.doc_id).await?;
         let revision = revision_from_doc(doc.clone(), RevType::Remote);
-        let _ = self.persistence.create_revs(vec![(revision, RevState::Acked)])?;
+        let persistence = self.persistence.clone();
+        let _ = self
+            .blocking_pool
+            .spawn(move || persistence.create_revs(vec![(revision, RevState::Acked)]))
+            .await
+            .map_err(internal_error)??;
         Ok(doc)
     }
 }
 
+struct RevisionBatch {
+    doc_id: String,
+    persistence: Arc<Persistence>,
+    revs_map: Arc<DashMap<i64, RevisionRecord>>,
+    acked_rev_id: Arc<Mutex<Option<i64>>>,
+    is_saving: Arc<AtomicBool>,
+    written_count: Arc<AtomicUsize>,
+}
+
+impl RevisionBatch {
+    // Keeps writing a batch per window until there is nothing left to write. The batch that failed is kept in
+    // the memory and written with the next revision.
+    async fn run(self) {
+        loop {
+            tokio::time::sleep(REVISION_WRITE_WINDOW).await;
+            if let Err(e) = self.write().await {
+                log::error!("Save the revisions of {} failed: {:?}", self.doc_id, e);
+                self.is_saving.store(false, Ordering::SeqCst);
+                return;
+            }
+
+            if !self.continue_saving() {
+                return;
+            }
+        }
+    }
+
+    // Compacts the revisions, then writes the ones that were added meanwhile
+    async fn compact_then_run(self) {
+        self.compact().await;
+        if self.continue_saving() {
+            self.run().await;
+        }
+    }
+
+    // Releases the saving flag, and takes it again if there are changes that weren't written
+    fn continue_saving(&self) -> bool {
+        self.is_saving.store(false, Ordering::SeqCst);
+        let has_changes = !self.revs_map.is_empty() || self.acked_rev_id.lock().is_some();
+        has_changes
+            && self
+                .is_saving
+                .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+    }
+
+    async fn write(&self) -> DocResult<()> {
+        let rev_ids = self.revs_map.iter().map(|kv| *kv.key()).collect::<Vec<i64>>();
+        let revisions = self
+            .revs_map
+            .iter()
+            .map(|kv| (kv.revision.clone(), kv.state))
+            .collect::<Vec<(Revision, RevState)>>();
+        let acked_rev_id = self.acked_rev_id.lock().take();
+
+        let doc_id = self.doc_id.clone();
+        let persistence = self.persistence.clone();
+        let result = self
+            .blocking_pool
+            .spawn(move || persistence.write_revs(&doc_id, revisions, acked_rev_id))
+            .await
+            .map_err(internal_error)
+            .and_then(|result| result);
+
+        match result {
+            Ok(_) => {
+                tracing::debug!("Save {} revisions of {}, acked: {:?}", rev_ids.len(), self.doc_id, acked_rev_id);
+                // The revisions that are acked while they are written are marked with the ack of the next batch
+                self.revs_map.retain(|rev_id, _| !rev_ids.contains(rev_id));
+                self.compact_if_needed(rev_ids.len()).await;
+                Ok(())
+            },
+            Err(e) => {
+                if let Some(rev_id) = acked_rev_id {
+                    let mut acked_rev_id = self.acked_rev_id.lock();
+                    *acked_rev_id = Some(acked_rev_id.map_or(rev_id, |acked_rev_id| acked_rev_id.max(rev_id)));
+                }
+                Err(e)
+            },
+        }
+    }
+
+    // It runs before the next batch is scheduled, so the compaction never races with the writes
+    async fn compact_if_needed(&self, written: usize) {
+        if self.written_count.fetch_add(written, Ordering::SeqCst) + written < COMPACTION_INTERVAL {
+            return;
+        }
+        self.compact().await;
+    }
+
+    async fn compact(&self) {
+        self.written_count.store(0, Ordering::SeqCst);
+        let doc_id = self.doc_id.clone();
+        let persistence = self.persistence.clone();
+        let result = self
+            .blocking_pool
+            .spawn(move || persistence.compact_revs(&doc_id, KEPT_REVISION_COUNT))
+            .await
+            .map_err(internal_error)
+            .and_then(|result| result);
+        if let Err(e) = result {
+            log::error!("Compact the revisions of {} failed: {:?}", self.doc_id, e);
+        }
+    }
+}
+
 impl RevisionIterator for RevisionStore {
     fn next(&self) -> ResultFuture<Option<Revision>, DocError> {
         let pending_revs = self.pending_revs.clone();
@@ -178,51 +430,63 @@ impl RevisionIterator for RevisionStore {
     }
 }
 
-async fn fetch_from_local(doc_id: &str, persistence: Arc<Persistence>) -> DocResult<Doc> {
+async fn fetch_from_local(
+    doc_id: &str,
+    persistence: Arc<Persistence>,
+    blocking_pool: &BlockingPool,
+) -> DocResult<Doc> {
     let doc_id = doc_id.to_owned();
-    spawn_blocking(move || {
-        let conn = &*persistence.pool.get().map_err(internal_error)?;
-        let revisions = persistence.rev_sql.read_rev_tables(&doc_id, conn)?;
-        if revisions.is_empty() {
-            return Err(DocError::record_not_found().context("Local doesn't have this document"));
-        }
-
-        let base_rev_id: RevId = revisions.last().unwrap().base_rev_id.into();
-        let rev_id: RevId = revisions.last().unwrap().rev_id.into();
-        let mut delta = Delta::new();
-        for (_, revision) in revisions.into_iter().enumerate() {
-            match Delta::from_bytes(revision.delta_data) {
-                Ok(local_delta) => {
-                    delta = delta.compose(&local_delta)?;
-                },
-                Err(e) => {
-                    log::error!("Deserialize delta from revision failed: {}", e);
-                },
+    blocking_pool
+        .spawn(move || {
+            let conn = &*persistence.pool.get().map_err(internal_error)?;
+            let checkpoint = persistence.read_checkpoint(&doc_id, conn)?;
+            let revisions = persistence.rev_sql.read_rev_tables(&doc_id, conn)?;
+            let (base_rev_id, rev_id, mut delta) = match (revisions.last(), checkpoint) {
+                (None, None) => return Err(DocError::record_not_found().context("Local doesn't have this document")),
+                (None, Some(checkpoint)) => (checkpoint.base_rev_id, checkpoint.rev_id, checkpoint.delta),
+                (Some(last), checkpoint) => (
+                    last.base_rev_id,
+                    last.rev_id,
+                    checkpoint.map_or_else(Delta::new, |checkpoint| checkpoint.delta),
+                ),
+            };
+            let base_rev_id: RevId = base_rev_id.into();
+            let rev_id: RevId = rev_id.into();
+
+            // Only the revisions after the checkpoint are replayed
+            for revision in revisions {
+                match Delta::from_bytes(revision.delta_data) {
+                    Ok(local_delta) => {
+                        delta = delta.compose(&local_delta)?;
+                    },
+                    Err(e) => {
+                        log::error!("Deserialize delta from revision failed: {}", e);
+                    },
+                }
             }
-        }
 
-        #[cfg(debug_assertions)]
-        validate_delta(&doc_id, persistence, conn, &delta);
+            #[cfg(debug_assertions)]
+            validate_delta(&doc_id, persistence, conn, &delta);
 
-        match delta.ops.last() {
-            None => {},
-            Some(op) => {
-                let data = op.get_data();
-                if !data.ends_with("\n") {
-                    delta.ops.push(Operation::Insert("\n".into()))
-                }
-            },
-        }
+            match delta.ops.last() {
+                None => {},
+                Some(op) => {
+                    let data = op.get_data();
+                    if !data.ends_with("\n") {
+                        delta.ops.push(Operation::Insert("\n".into()))
+                    }
+                },
+            }
 
-        Result::<Doc, DocError>::Ok(Doc {
-            id: doc_id,
-            data: delta.to_json(),
-            rev_id: rev_id.into(),
-            base_rev_id: base_rev_id.into(),
+            Result::<Doc, DocError>::Ok(Doc {
+                id: doc_id,
+                data: delta.to_json(),
+                rev_id: rev_id.into(),
+                base_rev_id: base_rev_id.into(),
+            })
         })
-    })
-    .await
-    .map_err(internal_error)?
+        .await
+        .map_err(internal_error)?
 }
 
 #[cfg(debug_assertions)]
@@ -370,3 +634,101 @@ impl RevisionStream {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flowy_document_infra::entities::doc::compose_revisions;
+    use flowy_test::fixture::typing_revisions;
+
+    const DOC_ID: &str = "doc";
+
+    struct LocalOnlyServer;
+
+    impl RevisionServer for LocalOnlyServer {
+        fn fetch_document_from_remote(&self, doc_id: &str) -> ResultFuture<Doc, DocError> {
+            let msg = format!("{} isn't on the server", doc_id);
+            ResultFuture::new(async move { Err(DocError::record_not_found().context(msg)) })
+        }
+    }
+
+    fn open_persistence(name: &str) -> Arc<Persistence> {
+        let dir = std::env::temp_dir().join(format!("flowy-document-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let database = flowy_database::init(dir.to_str().unwrap()).unwrap();
+        Arc::new(Persistence::new(database.get_pool()))
+    }
+
+    // The first acked_count revisions were acked by the server, the others are local
+    fn write_typing_revisions(persistence: &Persistence, count: usize, acked_count: usize) -> Vec<Revision> {
+        let revisions = typing_revisions(DOC_ID, count);
+        let records = revisions
+            .iter()
+            .enumerate()
+            .map(|(index, revision)| match index < acked_count {
+                true => (revision.clone(), RevState::Acked),
+                false => (revision.clone(), RevState::Local),
+            })
+            .collect::<Vec<(Revision, RevState)>>();
+        persistence.write_revs(DOC_ID, records, None).unwrap();
+        revisions
+    }
+
+    fn saved_rev_ids(persistence: &Persistence) -> Vec<i64> {
+        let conn = &*persistence.pool.get().unwrap();
+        let revisions = persistence.rev_sql.read_rev_tables(DOC_ID, conn).unwrap();
+        revisions.iter().map(|revision| revision.rev_id).collect()
+    }
+
+    #[test]
+    fn compact_revs_keeps_latest_revisions() {
+        let persistence = open_persistence("compact");
+        let _ = write_typing_revisions(&persistence, 250, 250);
+
+        assert_eq!(persistence.compact_revs(DOC_ID, KEPT_REVISION_COUNT).unwrap(), 150);
+        assert_eq!(saved_rev_ids(&persistence), (151..=250).collect::<Vec<i64>>());
+        assert_eq!(persistence.read_checkpoint_rev_id(DOC_ID).unwrap(), Some(150));
+        assert_eq!(persistence.compact_revs(DOC_ID, KEPT_REVISION_COUNT).unwrap(), 0);
+    }
+
+    #[test]
+    fn compact_revs_skips_unacked_revisions() {
+        let persistence = open_persistence("compact-unacked");
+        let _ = write_typing_revisions(&persistence, 250, 50);
+
+        assert_eq!(persistence.compact_revs(DOC_ID, KEPT_REVISION_COUNT).unwrap(), 50);
+        assert_eq!(saved_rev_ids(&persistence), (51..=250).collect::<Vec<i64>>());
+        assert_eq!(persistence.read_local_revs(DOC_ID).unwrap().len(), 200);
+    }
+
+    #[tokio::test]
+    async fn fetch_compacted_document_from_checkpoint_and_tail() {
+        let persistence = open_persistence("fetch-compacted");
+        let revisions = write_typing_revisions(&persistence, 250, 250);
+        let _ = persistence.compact_revs(DOC_ID, KEPT_REVISION_COUNT).unwrap();
+
+        let doc = fetch_from_local(DOC_ID, persistence.clone(), &BlockingPool::new(1)).await.unwrap();
+        let document = compose_revisions(Delta::new(), &revisions).unwrap();
+        assert_eq!(doc.data, document.to_json());
+        assert_eq!(doc.rev_id, 250);
+        assert_eq!(doc.base_rev_id, 249);
+    }
+
+    #[tokio::test]
+    async fn revs_after_compacted_revisions() {
+        let persistence = open_persistence("revs-after");
+        let _ = write_typing_revisions(&persistence, 250, 250);
+        let _ = persistence.compact_revs(DOC_ID, KEPT_REVISION_COUNT).unwrap();
+
+        let (ws_sender, _ws_receiver) = mpsc::unbounded_channel();
+        let store = RevisionStore::new(
+            DOC_ID,
+            persistence.pool.clone(),
+            BlockingPool::new(1),
+            Arc::new(LocalOnlyServer),
+            ws_sender,
+        );
+        assert!(store.revs_after(100).await.unwrap_err().is_record_not_found());
+        assert_eq!(store.revs_after(150).await.unwrap().len(), 100);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    errors::{internal_error, DocResult},
+    module::DocumentUser,
+    services::{cache::DocCache, doc::revision::Persistence},
+};
+use flowy_database::{FtsColumn, SEARCH_INDEX};
+use flowy_document_infra::entities::doc::{find_text_matches, plain_text_from_delta, DocTextMatch};
+use lib_ot::core::Delta;
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+// Indexes the text of the documents for the full-text search. Same as the DocTaskIndexer, a document is
+// indexed again only if its latest revision changed since the last time it was indexed.
+pub(crate) struct DocSearchIndexer {
+    user: Arc<dyn DocumentUser>,
+    cache: Arc<DocCache>,
+    indexed_rev_ids: RwLock<HashMap<String, i64>>,
+}
+
+impl DocSearchIndexer {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>, cache: Arc<DocCache>) -> Self {
+        Self {
+            user,
+            cache,
+            indexed_rev_ids: RwLock::new(HashMap::new()),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, doc_ids), fields(count = %doc_ids.len()), err)]
+    pub(crate) async fn index_docs(&self, doc_ids: Vec<String>) -> DocResult<()> {
+        let pool = self.user.db_pool()?;
+        let persistence = Persistence::new(pool.clone());
+        for doc_id in doc_ids {
+            if let Some((rev_id, text)) = self.read_changed_text(&doc_id, &persistence).await? {
+                let conn = &*pool.get().map_err(internal_error)?;
+                let _ = SEARCH_INDEX.update(&doc_id, FtsColumn::Body, &text, conn)?;
+                self.indexed_rev_ids.write().insert(doc_id, rev_id);
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, doc_id: &str) { self.indexed_rev_ids.write().remove(doc_id); }
+
+    // The matches are found in the document that is stored locally, it isn't opened if it's not opened yet
+    pub(crate) async fn find_matches(&self, doc_id: &str, query: &str, limit: usize) -> DocResult<Vec<DocTextMatch>> {
+        let persistence = Persistence::new(self.user.db_pool()?);
+        let edit_doc = match self.cache.contains(doc_id) {
+            true => Some(self.cache.get(doc_id)?),
+            false => None,
+        };
+
+        let delta = match edit_doc {
+            Some(edit_doc) => Delta::from_json(&edit_doc.delta().await?.data)?,
+            None => match persistence.read_delta(doc_id)? {
+                None => return Ok(vec![]),
+                Some(delta) => delta,
+            },
+        };
+        Ok(find_text_matches(&delta, query, limit))
+    }
+
+    // Returns None if the document isn't stored locally or it wasn't changed since it was indexed
+    async fn read_changed_text(&self, doc_id: &str, persistence: &Persistence) -> DocResult<Option<(i64, String)>> {
+        // The revisions of the opened document might not be saved yet
+        let edit_doc = match self.cache.contains(doc_id) {
+            true => Some(self.cache.get(doc_id)?),
+            false => None,
+        };
+
+        let rev_id = match &edit_doc {
+            Some(edit_doc) => edit_doc.rev_id(),
+            None => match persistence.read_latest_rev_id(doc_id)? {
+                None => return Ok(None),
+                Some(rev_id) => rev_id,
+            },
+        };
+
+        if self.indexed_rev_ids.read().get(doc_id) == Some(&rev_id) {
+            return Ok(None);
+        }
+
+        let delta = match edit_doc {
+            Some(edit_doc) => Delta::from_json(&edit_doc.delta().await?.data)?,
+            None => match persistence.read_delta(doc_id)? {
+                None => return Ok(None),
+                Some(delta) => delta,
+            },
+        };
+        Ok(Some((rev_id, plain_text_from_delta(&delta))))
+    }
+}
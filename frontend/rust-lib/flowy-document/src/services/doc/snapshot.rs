@@ -0,0 +1,55 @@
+use crate::{
+    errors::{internal_error, DocError, DocResult},
+    sql_tables::SnapshotTableSql,
+};
+use flowy_database::ConnectionPool;
+use flowy_document_infra::entities::doc::DocSnapshot;
+use lib_ot::core::Delta;
+use std::sync::Arc;
+
+// The older snapshots of a document are removed when a new one is created
+const MAX_SNAPSHOT_COUNT: usize = 20;
+
+pub(crate) struct SnapshotPersistence {
+    snapshot_sql: Arc<SnapshotTableSql>,
+    pool: Arc<ConnectionPool>,
+}
+
+impl SnapshotPersistence {
+    pub(crate) fn new(pool: Arc<ConnectionPool>) -> Self {
+        let snapshot_sql = Arc::new(SnapshotTableSql {});
+        Self { snapshot_sql, pool }
+    }
+
+    pub(crate) fn create_snapshot(&self, doc_id: &str, name: &str, rev_id: i64, data: String) -> DocResult<()> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, DocError, _>(|| {
+            let _ = self
+                .snapshot_sql
+                .create_snapshot_table(doc_id, name, rev_id, data, conn)?;
+            let _ = self
+                .snapshot_sql
+                .delete_stale_snapshot_tables(doc_id, MAX_SNAPSHOT_COUNT, conn)?;
+            Ok(())
+        })
+    }
+
+    pub(crate) fn read_snapshots(&self, doc_id: &str) -> DocResult<Vec<DocSnapshot>> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let snapshots = self
+            .snapshot_sql
+            .read_snapshot_tables(doc_id, conn)?
+            .into_iter()
+            .map(|table| table.into())
+            .collect::<Vec<DocSnapshot>>();
+        Ok(snapshots)
+    }
+
+    pub(crate) fn read_snapshot_delta(&self, doc_id: &str, snapshot_id: i64) -> DocResult<Delta> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        match self.snapshot_sql.read_snapshot_table(doc_id, snapshot_id, conn)? {
+            None => Err(DocError::record_not_found().context(format!("Can't find the snapshot {}", snapshot_id))),
+            Some(table) => Ok(Delta::from_json(&table.data)?),
+        }
+    }
+}
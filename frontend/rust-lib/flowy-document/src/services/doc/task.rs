@@ -0,0 +1,139 @@
+use crate::{
+    errors::{internal_error, DocResult},
+    module::DocumentUser,
+    services::{
+        cache::DocCache,
+        doc::{revision::Persistence, ClientEditDoc},
+    },
+    sql_tables::doc::{TaskTable, TaskTableSql},
+};
+use flowy_database::ConnectionPool;
+use flowy_document_infra::entities::doc::{DocTask, DocTaskIndex};
+use lib_dispatch::prelude::BlockingPool;
+use std::sync::Arc;
+
+// Indexes the unchecked todo items of the documents. The index of the opened document is updated with the
+// deltas applied to it, and it's saved with the rev_id when it's read or the document is closed. The index
+// of a closed document is built again only if its latest revision changed since the index was saved.
+pub(crate) struct DocTaskIndexer {
+    user: Arc<dyn DocumentUser>,
+    cache: Arc<DocCache>,
+    blocking_pool: BlockingPool,
+}
+
+impl DocTaskIndexer {
+    pub(crate) fn new(user: Arc<dyn DocumentUser>, cache: Arc<DocCache>, blocking_pool: BlockingPool) -> Self {
+        Self {
+            user,
+            cache,
+            blocking_pool,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, doc_ids), fields(count = %doc_ids.len()), err)]
+    pub(crate) async fn read_tasks(&self, doc_ids: Vec<String>) -> DocResult<Vec<DocTask>> {
+        let pool = self.user.db_pool()?;
+        let mut tasks = vec![];
+        for doc_id in doc_ids {
+            if let Some(task_index) = self.read_task_index(doc_id, &pool).await? {
+                tasks.extend(task_index.tasks);
+            }
+        }
+        Ok(tasks)
+    }
+
+    // Saves the index of the document that is closed, the edit_doc is kept until it's saved
+    pub(crate) fn save_on_close(&self, edit_doc: Arc<ClientEditDoc>) {
+        let user = self.user.clone();
+        let blocking_pool = self.blocking_pool.clone();
+        tokio::spawn(async move {
+            let result = match user.db_pool() {
+                Ok(pool) => save_opened_doc_task_index(&edit_doc, &pool, &blocking_pool)
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                log::error!("Save the task index of {} failed: {:?}", edit_doc.doc_id, e);
+            }
+        });
+    }
+
+    pub(crate) fn remove(&self, doc_id: &str) -> DocResult<()> {
+        let conn = &*self.user.db_pool()?.get().map_err(internal_error)?;
+        TaskTableSql::delete_task_index(doc_id, conn)
+    }
+
+    // Returns None if the document isn't stored locally
+    async fn read_task_index(&self, doc_id: String, pool: &Arc<ConnectionPool>) -> DocResult<Option<DocTaskIndex>> {
+        if self.cache.contains(&doc_id) {
+            let edit_doc = self.cache.get(&doc_id)?;
+            let task_index = save_opened_doc_task_index(&edit_doc, pool, &self.blocking_pool).await?;
+            return Ok(Some(task_index));
+        }
+
+        let pool = pool.clone();
+        self.blocking_pool
+            .spawn(move || read_closed_doc_task_index(&doc_id, &pool))
+            .await
+            .map_err(internal_error)?
+    }
+}
+
+// The rev_id is read before the index, so the saved index is never older than its rev_id
+async fn save_opened_doc_task_index(
+    edit_doc: &ClientEditDoc,
+    pool: &Arc<ConnectionPool>,
+    blocking_pool: &BlockingPool,
+) -> DocResult<DocTaskIndex> {
+    let rev_id = edit_doc.rev_id();
+    let task_index = edit_doc.task_index().await?;
+    let doc_id = edit_doc.doc_id.clone();
+    let pool = pool.clone();
+    let cloned_task_index = task_index.clone();
+    let _ = blocking_pool
+        .spawn(move || match read_saved_task_index(&doc_id, rev_id, &pool)? {
+            Some(saved_task_index) if saved_task_index == cloned_task_index => Ok(()),
+            _ => save_task_index(&doc_id, rev_id, &cloned_task_index, &pool),
+        })
+        .await
+        .map_err(internal_error)??;
+    Ok(task_index)
+}
+
+fn read_closed_doc_task_index(doc_id: &str, pool: &Arc<ConnectionPool>) -> DocResult<Option<DocTaskIndex>> {
+    let persistence = Persistence::new(pool.clone());
+    let rev_id = match persistence.read_latest_rev_id(doc_id)? {
+        None => return Ok(None),
+        Some(rev_id) => rev_id,
+    };
+    if let Some(task_index) = read_saved_task_index(doc_id, rev_id, pool)? {
+        return Ok(Some(task_index));
+    }
+
+    let task_index = match persistence.read_delta(doc_id)? {
+        None => return Ok(None),
+        Some(delta) => DocTaskIndex::from_delta(doc_id, &delta),
+    };
+    let _ = save_task_index(doc_id, rev_id, &task_index, pool)?;
+    Ok(Some(task_index))
+}
+
+// Returns None if the index wasn't saved at the rev_id
+fn read_saved_task_index(doc_id: &str, rev_id: i64, pool: &Arc<ConnectionPool>) -> DocResult<Option<DocTaskIndex>> {
+    let conn = &*pool.get().map_err(internal_error)?;
+    match TaskTableSql::read_task_index(doc_id, conn)? {
+        Some(table) if table.rev_id == rev_id => Ok(Some(serde_json::from_str(&table.data)?)),
+        _ => Ok(None),
+    }
+}
+
+fn save_task_index(doc_id: &str, rev_id: i64, task_index: &DocTaskIndex, pool: &Arc<ConnectionPool>) -> DocResult<()> {
+    let conn = &*pool.get().map_err(internal_error)?;
+    let table = TaskTable {
+        doc_id: doc_id.to_owned(),
+        rev_id,
+        data: serde_json::to_string(task_index)?,
+    };
+    TaskTableSql::save_task_index(table, conn)
+}
@@ -6,7 +6,19 @@ pub use server_api::*;
 // TODO: ignore mock files in production
 use crate::errors::DocError;
 use backend_service::config::ServerConfig;
-use flowy_document_infra::entities::doc::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams};
+use flowy_document_infra::entities::doc::{
+    Attachment,
+    AttachmentChunk,
+    AttachmentChunkIdentifier,
+    AttachmentIdentifier,
+    CreateAttachmentParams,
+    CreateDocParams,
+    Doc,
+    DocIdentifier,
+    DocSnapshotIdentifier,
+    RepeatedDocSnapshot,
+    UpdateDocParams,
+};
 use lib_infra::future::ResultFuture;
 pub use server_api_mock::*;
 use std::sync::Arc;
@@ -18,6 +30,28 @@ pub trait DocumentServerAPI {
     fn read_doc(&self, token: &str, params: DocIdentifier) -> ResultFuture<Option<Doc>, DocError>;
 
     fn update_doc(&self, token: &str, params: UpdateDocParams) -> ResultFuture<(), DocError>;
+
+    // Returns the authoritative state of the document, used to recover a client that is out of sync.
+    fn reset_doc(&self, token: &str, params: DocIdentifier) -> ResultFuture<Doc, DocError>;
+
+    // The versions of the document that the server keeps, the latest one comes first
+    fn read_doc_snapshots(&self, token: &str, params: DocIdentifier) -> ResultFuture<RepeatedDocSnapshot, DocError>;
+
+    // Returns the document after the restore, the other users receive it as a revision
+    fn restore_doc_snapshot(&self, token: &str, params: DocSnapshotIdentifier) -> ResultFuture<Doc, DocError>;
+
+    fn create_attachment(&self, token: &str, params: CreateAttachmentParams) -> ResultFuture<Attachment, DocError>;
+
+    // Returns the attachment with the updated uploaded_chunks
+    fn upload_attachment_chunk(&self, token: &str, chunk: AttachmentChunk) -> ResultFuture<Attachment, DocError>;
+
+    fn read_attachment(&self, token: &str, params: AttachmentIdentifier) -> ResultFuture<Attachment, DocError>;
+
+    fn read_attachment_chunk(
+        &self,
+        token: &str,
+        params: AttachmentChunkIdentifier,
+    ) -> ResultFuture<AttachmentChunk, DocError>;
 }
 
 pub(crate) fn construct_doc_server(server_config: &ServerConfig) -> Arc<dyn DocumentServerAPI + Send + Sync> {
@@ -1,6 +1,18 @@
 use crate::{errors::DocError, services::server::DocumentServerAPI};
 use backend_service::{config::*, request::HttpRequestBuilder};
-use flowy_document_infra::entities::doc::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams};
+use flowy_document_infra::entities::doc::{
+    Attachment,
+    AttachmentChunk,
+    AttachmentChunkIdentifier,
+    AttachmentIdentifier,
+    CreateAttachmentParams,
+    CreateDocParams,
+    Doc,
+    DocIdentifier,
+    DocSnapshotIdentifier,
+    RepeatedDocSnapshot,
+    UpdateDocParams,
+};
 use lib_infra::future::ResultFuture;
 
 pub struct DocServer {
@@ -29,6 +41,52 @@ impl DocumentServerAPI for DocServer {
         let url = self.config.doc_url();
         ResultFuture::new(async move { update_doc_request(&token, params, &url).await })
     }
+
+    fn reset_doc(&self, token: &str, params: DocIdentifier) -> ResultFuture<Doc, DocError> {
+        let token = token.to_owned();
+        let url = self.config.doc_reset_url();
+        ResultFuture::new(async move { reset_doc_request(&token, params, &url).await })
+    }
+
+    fn read_doc_snapshots(&self, token: &str, params: DocIdentifier) -> ResultFuture<RepeatedDocSnapshot, DocError> {
+        let token = token.to_owned();
+        let url = self.config.doc_snapshot_url();
+        ResultFuture::new(async move { read_doc_snapshots_request(&token, params, &url).await })
+    }
+
+    fn restore_doc_snapshot(&self, token: &str, params: DocSnapshotIdentifier) -> ResultFuture<Doc, DocError> {
+        let token = token.to_owned();
+        let url = self.config.doc_snapshot_restore_url();
+        ResultFuture::new(async move { restore_doc_snapshot_request(&token, params, &url).await })
+    }
+
+    fn create_attachment(&self, token: &str, params: CreateAttachmentParams) -> ResultFuture<Attachment, DocError> {
+        let token = token.to_owned();
+        let url = self.config.attachment_url();
+        ResultFuture::new(async move { create_attachment_request(&token, params, &url).await })
+    }
+
+    fn upload_attachment_chunk(&self, token: &str, chunk: AttachmentChunk) -> ResultFuture<Attachment, DocError> {
+        let token = token.to_owned();
+        let url = self.config.attachment_chunk_url();
+        ResultFuture::new(async move { upload_attachment_chunk_request(&token, chunk, &url).await })
+    }
+
+    fn read_attachment(&self, token: &str, params: AttachmentIdentifier) -> ResultFuture<Attachment, DocError> {
+        let token = token.to_owned();
+        let url = self.config.attachment_url();
+        ResultFuture::new(async move { read_attachment_request(&token, params, &url).await })
+    }
+
+    fn read_attachment_chunk(
+        &self,
+        token: &str,
+        params: AttachmentChunkIdentifier,
+    ) -> ResultFuture<AttachmentChunk, DocError> {
+        let token = token.to_owned();
+        let url = self.config.attachment_chunk_url();
+        ResultFuture::new(async move { read_attachment_chunk_request(&token, params, &url).await })
+    }
 }
 
 pub(crate) fn request_builder() -> HttpRequestBuilder {
@@ -65,3 +123,104 @@ pub async fn update_doc_request(token: &str, params: UpdateDocParams, url: &str)
         .await?;
     Ok(())
 }
+
+pub async fn reset_doc_request(token: &str, params: DocIdentifier, url: &str) -> Result<Doc, DocError> {
+    let doc = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+
+    Ok(doc)
+}
+
+pub async fn read_doc_snapshots_request(
+    token: &str,
+    params: DocIdentifier,
+    url: &str,
+) -> Result<RepeatedDocSnapshot, DocError> {
+    let repeated_snapshot = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+
+    Ok(repeated_snapshot)
+}
+
+pub async fn restore_doc_snapshot_request(
+    token: &str,
+    params: DocSnapshotIdentifier,
+    url: &str,
+) -> Result<Doc, DocError> {
+    let doc = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+
+    Ok(doc)
+}
+
+pub async fn create_attachment_request(
+    token: &str,
+    params: CreateAttachmentParams,
+    url: &str,
+) -> Result<Attachment, DocError> {
+    let attachment = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+
+    Ok(attachment)
+}
+
+pub async fn read_attachment_request(
+    token: &str,
+    params: AttachmentIdentifier,
+    url: &str,
+) -> Result<Attachment, DocError> {
+    let attachment = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+
+    Ok(attachment)
+}
+
+pub async fn upload_attachment_chunk_request(
+    token: &str,
+    chunk: AttachmentChunk,
+    url: &str,
+) -> Result<Attachment, DocError> {
+    let attachment = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(chunk)?
+        .response()
+        .await?;
+
+    Ok(attachment)
+}
+
+pub async fn read_attachment_chunk_request(
+    token: &str,
+    params: AttachmentChunkIdentifier,
+    url: &str,
+) -> Result<AttachmentChunk, DocError> {
+    let chunk = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+
+    Ok(chunk)
+}
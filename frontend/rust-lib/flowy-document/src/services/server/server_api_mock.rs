@@ -1,10 +1,24 @@
 use crate::{errors::DocError, services::server::DocumentServerAPI};
 use flowy_document_infra::{
-    entities::doc::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams},
+    entities::doc::{
+        Attachment,
+        AttachmentChunk,
+        AttachmentChunkIdentifier,
+        AttachmentIdentifier,
+        CreateAttachmentParams,
+        CreateDocParams,
+        Doc,
+        DocIdentifier,
+        DocSnapshotIdentifier,
+        RepeatedDocSnapshot,
+        UpdateDocParams,
+    },
     user_default::doc_initial_string,
 };
 use lib_infra::future::ResultFuture;
 
+const MOCK_ATTACHMENT_CHUNK_SIZE: i64 = 128 * 1024;
+
 pub struct DocServerMock {}
 
 impl DocumentServerAPI for DocServerMock {
@@ -25,4 +39,64 @@ impl DocumentServerAPI for DocServerMock {
     fn update_doc(&self, _token: &str, _params: UpdateDocParams) -> ResultFuture<(), DocError> {
         ResultFuture::new(async { Ok(()) })
     }
+
+    fn reset_doc(&self, _token: &str, params: DocIdentifier) -> ResultFuture<Doc, DocError> {
+        let doc = Doc {
+            id: params.doc_id,
+            data: doc_initial_string(),
+            rev_id: 0,
+            base_rev_id: 0,
+        };
+        ResultFuture::new(async { Ok(doc) })
+    }
+
+    // The mock doesn't take the snapshots of the documents
+    fn read_doc_snapshots(&self, _token: &str, _params: DocIdentifier) -> ResultFuture<RepeatedDocSnapshot, DocError> {
+        ResultFuture::new(async { Ok(RepeatedDocSnapshot::default()) })
+    }
+
+    fn restore_doc_snapshot(&self, _token: &str, params: DocSnapshotIdentifier) -> ResultFuture<Doc, DocError> {
+        let error = DocError::record_not_found().context(format!("Snapshot {} not found", params.snapshot_id));
+        ResultFuture::new(async { Err(error) })
+    }
+
+    fn create_attachment(&self, _token: &str, params: CreateAttachmentParams) -> ResultFuture<Attachment, DocError> {
+        let attachment = Attachment {
+            id: lib_infra::uuid(),
+            doc_id: params.doc_id,
+            name: params.name,
+            size: params.size,
+            chunk_size: MOCK_ATTACHMENT_CHUNK_SIZE,
+            uploaded_chunks: 0,
+            create_time: chrono::Utc::now().timestamp(),
+        };
+        ResultFuture::new(async { Ok(attachment) })
+    }
+
+    // The mock doesn't keep the attachments, the uploaded_chunks of the returned attachment is the
+    // number of the chunks before this one plus one.
+    fn upload_attachment_chunk(&self, _token: &str, chunk: AttachmentChunk) -> ResultFuture<Attachment, DocError> {
+        let attachment = Attachment {
+            id: chunk.attachment_id,
+            uploaded_chunks: chunk.index + 1,
+            chunk_size: MOCK_ATTACHMENT_CHUNK_SIZE,
+            ..Attachment::default()
+        };
+        ResultFuture::new(async { Ok(attachment) })
+    }
+
+    // The mock doesn't keep the attachments, only the cached ones can be read
+    fn read_attachment(&self, _token: &str, params: AttachmentIdentifier) -> ResultFuture<Attachment, DocError> {
+        let error = DocError::record_not_found().context(format!("Attachment {} not found", params.attachment_id));
+        ResultFuture::new(async { Err(error) })
+    }
+
+    fn read_attachment_chunk(
+        &self,
+        _token: &str,
+        params: AttachmentChunkIdentifier,
+    ) -> ResultFuture<AttachmentChunk, DocError> {
+        let error = DocError::record_not_found().context(format!("Attachment {} not found", params.attachment_id));
+        ResultFuture::new(async { Err(error) })
+    }
 }
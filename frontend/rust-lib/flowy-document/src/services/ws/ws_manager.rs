@@ -1,8 +1,9 @@
 use crate::errors::DocError;
 use bytes::Bytes;
 use dashmap::DashMap;
-use flowy_document_infra::entities::ws::WsDocumentData;
+use flowy_document_infra::entities::ws::{WsDataType, WsDocumentData};
 use lib_ws::WsState;
+use parking_lot::RwLock;
 use std::{convert::TryInto, sync::Arc};
 
 pub(crate) trait WsDocumentHandler: Send + Sync {
@@ -20,12 +21,18 @@ pub struct WsDocumentManager {
     ws: Arc<dyn DocumentWebSocket>,
     // key: the document id
     handlers: Arc<DashMap<String, Arc<dyn WsDocumentHandler>>>,
+    // receives the documents that were pulled in batch, they don't have to be opened
+    prefetch_handler: RwLock<Option<Arc<dyn WsDocumentHandler>>>,
 }
 
 impl WsDocumentManager {
     pub fn new(ws: Arc<dyn DocumentWebSocket>) -> Self {
         let handlers: Arc<DashMap<String, Arc<dyn WsDocumentHandler>>> = Arc::new(DashMap::new());
-        Self { ws, handlers }
+        Self {
+            ws,
+            handlers,
+            prefetch_handler: RwLock::new(None),
+        }
     }
 
     pub(crate) fn init(&self) { listen_ws_state_changed(self.ws.clone(), self.handlers.clone()); }
@@ -39,8 +46,20 @@ impl WsDocumentManager {
 
     pub(crate) fn remove_handler(&self, id: &str) { self.handlers.remove(id); }
 
+    pub(crate) fn register_prefetch_handler(&self, handler: Arc<dyn WsDocumentHandler>) {
+        *self.prefetch_handler.write() = Some(handler);
+    }
+
     pub fn handle_ws_data(&self, data: Bytes) {
         let data: WsDocumentData = data.try_into().unwrap();
+        if data.ty == WsDataType::PushDoc || data.ty == WsDataType::PushDocFailed {
+            match &*self.prefetch_handler.read() {
+                None => log::error!("Can't find the prefetch handler for {:?}", data.doc_id),
+                Some(handler) => handler.receive(data),
+            }
+            return;
+        }
+
         match self.handlers.get(&data.doc_id) {
             None => {
                 log::error!("Can't find any source handler for {:?}", data.doc_id);
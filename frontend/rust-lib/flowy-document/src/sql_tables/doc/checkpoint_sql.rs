@@ -0,0 +1,45 @@
+use crate::{errors::DocError, sql_tables::doc::CheckpointTable};
+use diesel::OptionalExtension;
+use flowy_database::{prelude::*, schema::doc_checkpoint_table::dsl, SqliteConnection};
+
+pub struct CheckpointTableSql {}
+
+impl CheckpointTableSql {
+    pub(crate) fn save_checkpoint(&self, table: CheckpointTable, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::replace_into(dsl::doc_checkpoint_table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_checkpoint(
+        &self,
+        doc_id_s: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<CheckpointTable>, DocError> {
+        let table = dsl::doc_checkpoint_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .first::<CheckpointTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn read_checkpoint_rev_id(
+        &self,
+        doc_id_s: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<i64>, DocError> {
+        let rev_id = dsl::doc_checkpoint_table
+            .select(dsl::rev_id)
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .first::<i64>(conn)
+            .optional()?;
+        Ok(rev_id)
+    }
+
+    pub(crate) fn delete_checkpoint(&self, doc_id_s: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let filter = dsl::doc_checkpoint_table.filter(dsl::doc_id.eq(doc_id_s));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
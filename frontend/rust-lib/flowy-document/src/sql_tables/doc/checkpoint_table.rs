@@ -0,0 +1,13 @@
+use flowy_database::schema::doc_checkpoint_table;
+
+// The composed delta of the revisions up to the rev_id, they were removed from the rev_table
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "doc_checkpoint_table"]
+#[primary_key(doc_id)]
+pub(crate) struct CheckpointTable {
+    pub(crate) doc_id: String,
+    pub(crate) base_rev_id: i64,
+    pub(crate) rev_id: i64,
+    pub(crate) data: Vec<u8>,
+    pub(crate) modified_time: i64,
+}
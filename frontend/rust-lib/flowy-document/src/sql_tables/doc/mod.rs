@@ -1,5 +1,21 @@
+mod checkpoint_sql;
+mod checkpoint_table;
+mod read_position_sql;
+mod read_position_table;
 mod rev_sql;
 mod rev_table;
+mod snapshot_sql;
+mod snapshot_table;
+mod task_sql;
+mod task_table;
 
+pub(crate) use checkpoint_sql::*;
+pub(crate) use checkpoint_table::*;
+pub(crate) use read_position_sql::*;
+pub(crate) use read_position_table::*;
 pub(crate) use rev_sql::*;
 pub(crate) use rev_table::*;
+pub(crate) use snapshot_sql::*;
+pub(crate) use snapshot_table::*;
+pub(crate) use task_sql::*;
+pub(crate) use task_table::*;
@@ -0,0 +1,30 @@
+use crate::{errors::DocError, sql_tables::doc::ReadPositionTable};
+use diesel::OptionalExtension;
+use flowy_database::{prelude::*, schema::doc_read_position_table::dsl, SqliteConnection};
+
+pub struct ReadPositionTableSql {}
+
+impl ReadPositionTableSql {
+    pub(crate) fn save_position(table: ReadPositionTable, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::replace_into(dsl::doc_read_position_table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_position(
+        doc_id_s: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<ReadPositionTable>, DocError> {
+        let table = dsl::doc_read_position_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .first::<ReadPositionTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn read_all(conn: &SqliteConnection) -> Result<Vec<ReadPositionTable>, DocError> {
+        let tables = dsl::doc_read_position_table.load::<ReadPositionTable>(conn)?;
+        Ok(tables)
+    }
+}
@@ -0,0 +1,31 @@
+use flowy_database::schema::doc_read_position_table;
+use flowy_document_infra::entities::doc::DocReadPosition;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "doc_read_position_table"]
+#[primary_key(doc_id)]
+pub(crate) struct ReadPositionTable {
+    pub(crate) doc_id: String,
+    pub(crate) read_offset: i64,
+    pub(crate) modified_time: i64,
+}
+
+impl std::convert::From<DocReadPosition> for ReadPositionTable {
+    fn from(position: DocReadPosition) -> Self {
+        ReadPositionTable {
+            doc_id: position.doc_id,
+            read_offset: position.offset,
+            modified_time: position.modified_time,
+        }
+    }
+}
+
+impl std::convert::Into<DocReadPosition> for ReadPositionTable {
+    fn into(self) -> DocReadPosition {
+        DocReadPosition {
+            doc_id: self.doc_id,
+            offset: self.read_offset,
+            modified_time: self.modified_time,
+        }
+    }
+}
@@ -57,6 +57,26 @@ impl RevTableSql {
         Ok(revisions)
     }
 
+    // The revisions with their states, ordered by rev_id
+    pub(crate) fn read_rev_records(
+        &self,
+        doc_id_s: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<(Revision, RevState)>, DocError> {
+        let rev_tables = dsl::rev_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .order(dsl::rev_id.asc())
+            .load::<RevTable>(conn)?;
+        let records = rev_tables
+            .into_iter()
+            .map(|table| {
+                let state = table.state;
+                (table.into(), state)
+            })
+            .collect::<Vec<(Revision, RevState)>>();
+        Ok(records)
+    }
+
     pub(crate) fn read_rev_table(
         &self,
         doc_id: &str,
@@ -75,6 +95,73 @@ impl RevTableSql {
         }
     }
 
+    pub(crate) fn read_latest_rev_id(&self, doc_id_s: &str, conn: &SqliteConnection) -> Result<Option<i64>, DocError> {
+        let result = dsl::rev_table
+            .select(dsl::rev_id)
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .order(dsl::rev_id.desc())
+            .first::<i64>(conn);
+
+        if Err(diesel::NotFound) == result {
+            Ok(None)
+        } else {
+            Ok(Some(result?))
+        }
+    }
+
+    // The server acks the revisions in order, so the revisions before the rev_id are acked too
+    pub(crate) fn ack_rev_tables(
+        &self,
+        doc_id_s: &str,
+        rev_id_s: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let filter = dsl::rev_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .filter(dsl::rev_id.le(rev_id_s))
+            .filter(dsl::state.eq(RevState::Local));
+        let affected_row = update(filter).set(dsl::state.eq(RevState::Acked)).execute(conn)?;
+        tracing::debug!("Ack {} revisions of {}", affected_row, doc_id_s);
+        Ok(())
+    }
+
+    // The revisions that haven't been acked by the server, ordered by rev_id
+    pub(crate) fn read_local_rev_tables(
+        &self,
+        doc_id_s: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<Revision>, DocError> {
+        let rev_tables = dsl::rev_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .filter(dsl::state.eq(RevState::Local))
+            .order(dsl::rev_id.asc())
+            .load::<RevTable>(conn)?;
+
+        let revisions = rev_tables
+            .into_iter()
+            .map(|table| table.into())
+            .collect::<Vec<Revision>>();
+        Ok(revisions)
+    }
+
+    pub(crate) fn read_doc_ids_with_local_revs(&self, conn: &SqliteConnection) -> Result<Vec<String>, DocError> {
+        let doc_ids = dsl::rev_table
+            .select(dsl::doc_id)
+            .filter(dsl::state.eq(RevState::Local))
+            .distinct()
+            .load::<String>(conn)?;
+        Ok(doc_ids)
+    }
+
+    pub(crate) fn contains_local_revs(&self, doc_id_s: &str, conn: &SqliteConnection) -> Result<bool, DocError> {
+        let count = dsl::rev_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .filter(dsl::state.eq(RevState::Local))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count > 0)
+    }
+
     pub(crate) fn read_rev_tables_with_range(
         &self,
         doc_id_s: &str,
@@ -95,6 +182,28 @@ impl RevTableSql {
         Ok(revisions)
     }
 
+    pub(crate) fn delete_rev_tables(&self, doc_id_s: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let filter = dsl::rev_table.filter(dsl::doc_id.eq(doc_id_s));
+        let affected_row = diesel::delete(filter).execute(conn)?;
+        tracing::debug!("Delete {} revisions of {}", affected_row, doc_id_s);
+        Ok(())
+    }
+
+    // Removes the revisions up to the rev_id, including it
+    pub(crate) fn delete_rev_tables_to(
+        &self,
+        doc_id_s: &str,
+        rev_id_s: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let filter = dsl::rev_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .filter(dsl::rev_id.le(rev_id_s));
+        let affected_row = diesel::delete(filter).execute(conn)?;
+        tracing::debug!("Delete {} revisions of {} up to {}", affected_row, doc_id_s, rev_id_s);
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub(crate) fn delete_rev_table(
         &self,
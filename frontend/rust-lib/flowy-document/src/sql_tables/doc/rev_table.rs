@@ -56,6 +56,7 @@ impl std::convert::Into<Revision> for RevTable {
             md5,
             doc_id: self.doc_id,
             ty: self.ty.into(),
+            device_id: "".to_owned(),
         }
     }
 }
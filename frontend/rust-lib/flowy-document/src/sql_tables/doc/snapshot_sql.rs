@@ -0,0 +1,79 @@
+use crate::{errors::DocError, sql_tables::doc::SnapshotTable};
+use flowy_database::{prelude::*, schema::doc_snapshot_table::dsl, SqliteConnection};
+
+pub struct SnapshotTableSql {}
+
+impl SnapshotTableSql {
+    pub(crate) fn create_snapshot_table(
+        &self,
+        doc_id_s: &str,
+        name_s: &str,
+        rev_id_s: i64,
+        data_s: String,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let record = (
+            dsl::doc_id.eq(doc_id_s),
+            dsl::name.eq(name_s),
+            dsl::rev_id.eq(rev_id_s),
+            dsl::data.eq(data_s),
+            dsl::create_time.eq(chrono::Utc::now().timestamp()),
+        );
+        let _ = diesel::insert_into(dsl::doc_snapshot_table).values(record).execute(conn)?;
+        Ok(())
+    }
+
+    // The latest snapshot comes first
+    pub(crate) fn read_snapshot_tables(
+        &self,
+        doc_id_s: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<SnapshotTable>, DocError> {
+        let snapshot_tables = dsl::doc_snapshot_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .order(dsl::id.desc())
+            .load::<SnapshotTable>(conn)?;
+        Ok(snapshot_tables)
+    }
+
+    pub(crate) fn read_snapshot_table(
+        &self,
+        doc_id_s: &str,
+        snapshot_id: i64,
+        conn: &SqliteConnection,
+    ) -> Result<Option<SnapshotTable>, DocError> {
+        let result = dsl::doc_snapshot_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .filter(dsl::id.eq(snapshot_id as i32))
+            .first::<SnapshotTable>(conn);
+
+        if Err(diesel::NotFound) == result {
+            Ok(None)
+        } else {
+            Ok(Some(result?))
+        }
+    }
+
+    // Removes the older snapshots of the document, only the latest count snapshots are kept.
+    pub(crate) fn delete_stale_snapshot_tables(
+        &self,
+        doc_id_s: &str,
+        count: usize,
+        conn: &SqliteConnection,
+    ) -> Result<(), DocError> {
+        let ids = dsl::doc_snapshot_table
+            .select(dsl::id)
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .order(dsl::id.desc())
+            .load::<i32>(conn)?;
+        let stale_ids = ids.into_iter().skip(count).collect::<Vec<i32>>();
+        if stale_ids.is_empty() {
+            return Ok(());
+        }
+
+        let filter = dsl::doc_snapshot_table.filter(dsl::id.eq_any(stale_ids));
+        let affected_row = diesel::delete(filter).execute(conn)?;
+        tracing::debug!("Delete {} snapshots of {}", affected_row, doc_id_s);
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use flowy_database::schema::doc_snapshot_table;
+use flowy_document_infra::entities::doc::DocSnapshot;
+use lib_infra::device_id;
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable)]
+#[table_name = "doc_snapshot_table"]
+pub(crate) struct SnapshotTable {
+    pub(crate) id: i32,
+    pub(crate) doc_id: String,
+    pub(crate) name: String,
+    pub(crate) rev_id: i64,
+    pub(crate) data: String,
+    pub(crate) create_time: i64,
+}
+
+impl std::convert::Into<DocSnapshot> for SnapshotTable {
+    fn into(self) -> DocSnapshot {
+        DocSnapshot {
+            id: self.id as i64,
+            doc_id: self.doc_id,
+            name: self.name,
+            rev_id: self.rev_id,
+            create_time: self.create_time,
+            // The local snapshots are always taken on this device
+            device_id: device_id(),
+        }
+    }
+}
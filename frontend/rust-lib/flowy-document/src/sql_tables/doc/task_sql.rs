@@ -0,0 +1,26 @@
+use crate::{errors::DocError, sql_tables::doc::TaskTable};
+use diesel::OptionalExtension;
+use flowy_database::{prelude::*, schema::doc_task_table::dsl, SqliteConnection};
+
+pub struct TaskTableSql {}
+
+impl TaskTableSql {
+    pub(crate) fn save_task_index(table: TaskTable, conn: &SqliteConnection) -> Result<(), DocError> {
+        let _ = diesel::replace_into(dsl::doc_task_table).values(&table).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_task_index(doc_id_s: &str, conn: &SqliteConnection) -> Result<Option<TaskTable>, DocError> {
+        let table = dsl::doc_task_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .first::<TaskTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    pub(crate) fn delete_task_index(doc_id_s: &str, conn: &SqliteConnection) -> Result<(), DocError> {
+        let filter = dsl::doc_task_table.filter(dsl::doc_id.eq(doc_id_s));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use flowy_database::schema::doc_task_table;
+
+// The json of the DocTaskIndex of the document at the rev_id
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "doc_task_table"]
+#[primary_key(doc_id)]
+pub(crate) struct TaskTable {
+    pub(crate) doc_id: String,
+    pub(crate) rev_id: i64,
+    pub(crate) data: String,
+}
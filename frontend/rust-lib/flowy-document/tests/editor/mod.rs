@@ -1,6 +1,11 @@
 mod attribute_test;
 mod op_test;
+mod revision_test;
 mod serde_test;
+mod snapshot_test;
+mod task_test;
+mod text_match_test;
+mod text_run_test;
 mod undo_redo_test;
 
 use derive_more::Display;
@@ -0,0 +1,29 @@
+use flowy_document_infra::entities::doc::{compose_revisions, RevType, Revision};
+use flowy_test::fixture::typing_revisions;
+use lib_ot::core::Delta;
+
+#[test]
+fn revision_compose_checkpoint_and_tail() {
+    let revisions = typing_revisions("doc", 50);
+    let document = compose_revisions(Delta::new(), &revisions).unwrap();
+    assert_eq!(document.target_len, 50);
+
+    let (compacted, tail) = revisions.split_at(40);
+    let checkpoint = compose_revisions(Delta::new(), compacted).unwrap();
+    let checkpoint = Delta::from_bytes(checkpoint.to_bytes()).unwrap();
+    assert_eq!(compose_revisions(checkpoint, tail).unwrap(), document);
+}
+
+#[test]
+fn revision_compose_checkpoint_without_tail() {
+    let revisions = typing_revisions("doc", 10);
+    let checkpoint = compose_revisions(Delta::new(), &revisions).unwrap();
+    assert_eq!(compose_revisions(checkpoint.clone(), &[]).unwrap(), checkpoint);
+}
+
+#[test]
+fn revision_compose_invalid_delta() {
+    let mut revisions = typing_revisions("doc", 3);
+    revisions.push(Revision::new(3, 4, "not a delta".as_bytes(), "doc", RevType::Local));
+    assert!(compose_revisions(Delta::new(), &revisions).is_err());
+}
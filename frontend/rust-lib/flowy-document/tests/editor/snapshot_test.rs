@@ -0,0 +1,20 @@
+use flowy_document_infra::entities::doc::revert_delta;
+use lib_ot::core::{Delta, OperationTransformable};
+
+#[test]
+fn snapshot_revert_delta() {
+    let json = r#"[{"insert":"abc"},{"insert":"123","attributes":{"bold":"true"}},{"insert":"\n"}]"#;
+    let current = Delta::from_json(json).unwrap();
+    let snapshot = Delta::from_json(r#"[{"insert":"hello","attributes":{"italic":"true"}},{"insert":"\n"}]"#).unwrap();
+
+    let delta = revert_delta(&current, &snapshot);
+    assert_eq!(delta.base_len, current.target_len);
+    assert_eq!(current.compose(&delta).unwrap(), snapshot);
+}
+
+#[test]
+fn snapshot_revert_delta_to_same_document() {
+    let current = Delta::from_json(r#"[{"insert":"abc\n"}]"#).unwrap();
+    let delta = revert_delta(&current, &current);
+    assert_eq!(current.compose(&delta).unwrap(), current);
+}
@@ -0,0 +1,102 @@
+use flowy_document_infra::{
+    core::Document,
+    entities::doc::{unchecked_tasks_from_delta, DocTaskIndex},
+};
+use lib_ot::core::{Attribute, Delta, Interval};
+
+#[test]
+fn task_extract_unchecked_items() {
+    let json = r#"[
+        {"insert":"Plan"},{"insert":"\n","attributes":{"header":1}},
+        {"insert":"buy milk"},{"insert":"\n","attributes":{"list":"unchecked"}},
+        {"insert":"call mom"},{"insert":"\n","attributes":{"list":"checked"}},
+        {"insert":"😁 write "},{"insert":"docs","attributes":{"bold":"true"}},{"insert":"\n","attributes":{"list":"unchecked"}}
+    ]"#;
+    let delta = Delta::from_json(json).unwrap();
+    let tasks = unchecked_tasks_from_delta("doc", &delta);
+
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].text, "buy milk");
+    assert_eq!(tasks[0].index, 5);
+    assert_eq!(tasks[0].line, 1);
+    assert_eq!(tasks[1].text, "😁 write docs");
+    assert_eq!(tasks[1].index, 23);
+    assert_eq!(tasks[1].line, 3);
+}
+
+#[test]
+fn task_ignore_empty_unchecked_item() {
+    let json = r#"[{"insert":"\n","attributes":{"list":"unchecked"}},{"insert":"123\n"}]"#;
+    let delta = Delta::from_json(json).unwrap();
+    assert!(unchecked_tasks_from_delta("doc", &delta).is_empty());
+}
+
+const TASK_DOC: &str = r#"[
+    {"insert":"Plan"},{"insert":"\n","attributes":{"header":1}},
+    {"insert":"buy milk"},{"insert":"\n","attributes":{"list":"unchecked"}},
+    {"insert":"call mom"},{"insert":"\n","attributes":{"list":"checked"}},
+    {"insert":"😁 write docs"},{"insert":"\n","attributes":{"list":"unchecked"}},
+    {"insert":"done\n"}
+]"#;
+
+// Applies the deltas of the edits to the index one after another, the index must be the same as the one built
+// from the edited document.
+fn assert_task_index_updated(edits: &[fn(&mut Document) -> Delta]) {
+    let mut document = Document::from_delta(Delta::from_json(TASK_DOC).unwrap());
+    let mut task_index = DocTaskIndex::from_delta("doc", document.delta());
+    for edit in edits {
+        let delta = edit(&mut document);
+        task_index.apply_delta("doc", &delta, document.delta());
+        assert_eq!(task_index, DocTaskIndex::from_delta("doc", document.delta()));
+    }
+}
+
+#[test]
+fn task_index_insert_text() {
+    assert_task_index_updated(&[
+        |document| document.insert(5, "go ").unwrap(),
+        |document| document.insert(0, "My ").unwrap(),
+        |document| document.insert(26, "🥛").unwrap(),
+        |document| document.insert(document.delta().target_len - 1, "!").unwrap(),
+    ]);
+}
+
+#[test]
+fn task_index_insert_newlines() {
+    assert_task_index_updated(&[
+        |document| document.insert(8, "\n").unwrap(),
+        |document| document.insert(0, "\n\n").unwrap(),
+        |document| document.insert(12, "a\nb\nc").unwrap(),
+    ]);
+}
+
+#[test]
+fn task_index_format_lines() {
+    assert_task_index_updated(&[
+        |document| document.format(Interval::new(0, 2), Attribute::UnChecked(true)).unwrap(),
+        |document| document.format(Interval::new(6, 8), Attribute::Checked(true)).unwrap(),
+        |document| document.format(Interval::new(16, 30), Attribute::UnChecked(true)).unwrap(),
+        |document| document.format(Interval::new(16, 20), Attribute::Bold(true)).unwrap(),
+    ]);
+}
+
+#[test]
+fn task_index_delete_text() {
+    assert_task_index_updated(&[
+        |document| document.delete(Interval::new(5, 9)).unwrap(),
+        |document| document.delete(Interval::new(3, 12)).unwrap(),
+        |document| document.replace(Interval::new(0, 4), "tasks\n\n").unwrap(),
+        |document| document.undo().unwrap(),
+        |document| document.redo().unwrap(),
+    ]);
+}
+
+#[test]
+fn task_index_rebuilt_from_other_document() {
+    let mut task_index = DocTaskIndex::from_delta("doc", &Delta::from_json(r#"[{"insert":"\n"}]"#).unwrap());
+    let mut document = Document::from_delta(Delta::from_json(TASK_DOC).unwrap());
+    let delta = document.insert(5, "go ").unwrap();
+    task_index.apply_delta("doc", &delta, document.delta());
+    assert_eq!(task_index, DocTaskIndex::from_delta("doc", document.delta()));
+    assert_eq!(task_index.tasks.len(), 2);
+}
@@ -0,0 +1,62 @@
+use flowy_document_infra::entities::doc::{find_text_matches, DocTextMatch};
+use lib_ot::core::Delta;
+
+fn text_match(start: usize, context: &str, highlight_start: usize, highlight_end: usize) -> DocTextMatch {
+    DocTextMatch {
+        start,
+        end: start + highlight_end - highlight_start,
+        context: context.to_owned(),
+        highlight_start,
+        highlight_end,
+    }
+}
+
+#[test]
+fn text_match_prefix_of_last_word() {
+    let json = r#"[
+        {"insert":"Notes about the "},{"insert":"Roadmap","attributes":{"bold":"true"}},{"insert":" for 2022\n"},
+        {"insert":"roadmaps are fun\n"}
+    ]"#;
+    let delta = Delta::from_json(json).unwrap();
+    assert_eq!(
+        find_text_matches(&delta, "road", 10),
+        vec![
+            text_match(16, "Notes about the Roadmap for 2022", 16, 23),
+            text_match(33, "roadmaps are fun", 0, 8),
+        ]
+    );
+
+    // Only the last word of the query matches as a prefix
+    assert_eq!(
+        find_text_matches(&delta, "roadmap fu", 10),
+        vec![
+            text_match(16, "Notes about the Roadmap for 2022", 16, 23),
+            text_match(46, "roadmaps are fun", 13, 16),
+        ]
+    );
+
+    assert_eq!(find_text_matches(&delta, "road", 1).len(), 1);
+    assert_eq!(find_text_matches(&delta, " !! ", 10).is_empty(), true);
+}
+
+#[test]
+fn text_match_utf16_offsets() {
+    let delta = Delta::from_json(r#"[{"insert":"😀 hello\n"}]"#).unwrap();
+    assert_eq!(
+        find_text_matches(&delta, "hello", 10),
+        vec![text_match(3, "😀 hello", 3, 8)]
+    );
+}
+
+#[test]
+fn text_match_context_of_long_line() {
+    let line = format!("{}target{}", "word ".repeat(20), " tail".repeat(20));
+    let delta = Delta::from_json(&format!(r#"[{{"insert":"{}\n"}}]"#, line)).unwrap();
+
+    // The context doesn't cut the words around the match
+    let context = format!("{}target{}", "word ".repeat(6), " tail".repeat(6));
+    assert_eq!(
+        find_text_matches(&delta, "target", 10),
+        vec![text_match(100, &context, 30, 36)]
+    );
+}
@@ -0,0 +1,49 @@
+use flowy_document_infra::entities::doc::{text_runs_from_delta, transform_interval};
+use lib_ot::core::{Delta, Interval};
+
+#[test]
+fn text_run_skip_code_and_link() {
+    let json = r#"[
+        {"insert":"helo "},{"insert":"wrold","attributes":{"bold":"true"}},{"insert":"\n"},
+        {"insert":"let a = 1;"},{"insert":"\n","attributes":{"code_block":"true"}},
+        {"insert":"see "},{"insert":"appflowy","attributes":{"link":"https://appflowy.io"}},{"insert":" docs\n"}
+    ]"#;
+    let delta = Delta::from_json(json).unwrap();
+    let runs = text_runs_from_delta(&delta)
+        .into_iter()
+        .map(|run| (run.index, run.text))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        runs,
+        vec![
+            (0, "helo wrold".to_owned()),
+            (22, "see ".to_owned()),
+            (34, " docs".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn text_run_transform_interval_after_insert_before() {
+    let delta = Delta::from_json(r#"[{"insert":"abc"},{"retain":10}]"#).unwrap();
+    assert_eq!(transform_interval(&delta, Interval::new(2, 5)), Interval::new(5, 8));
+}
+
+#[test]
+fn text_run_transform_interval_after_delete_before() {
+    let delta = Delta::from_json(r#"[{"retain":1},{"delete":2}]"#).unwrap();
+    assert_eq!(transform_interval(&delta, Interval::new(4, 6)), Interval::new(2, 4));
+}
+
+#[test]
+fn text_run_transform_interval_extended_by_insert_inside() {
+    let delta = Delta::from_json(r#"[{"retain":5},{"insert":"x"}]"#).unwrap();
+    assert_eq!(transform_interval(&delta, Interval::new(2, 5)), Interval::new(2, 6));
+}
+
+#[test]
+fn text_run_transform_interval_ignore_change_after() {
+    let delta = Delta::from_json(r#"[{"retain":6},{"insert":"x"},{"delete":2}]"#).unwrap();
+    assert_eq!(transform_interval(&delta, Interval::new(2, 5)), Interval::new(2, 5));
+}
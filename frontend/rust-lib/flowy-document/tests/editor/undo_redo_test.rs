@@ -1,6 +1,7 @@
 use crate::editor::{TestBuilder, TestOp::*};
-use flowy_document_infra::core::{FlowyDoc, PlainDoc, RECORD_THRESHOLD};
-use lib_ot::core::{Interval, NEW_LINE, WHITESPACE};
+use flowy_document_infra::core::{split_delta, Document, FlowyDoc, PlainDoc, RECORD_THRESHOLD};
+use lib_ot::core::{Delta, DeltaBuilder, Interval, OperationTransformable, UndoManager, NEW_LINE, WHITESPACE};
+use std::time::{Duration, Instant};
 
 #[test]
 fn history_insert_undo() {
@@ -370,3 +371,149 @@ fn history_undo_attribute_on_merge_between_line() {
 
     TestBuilder::new().run_script::<FlowyDoc>(ops);
 }
+
+fn apply_change(manager: &mut UndoManager, document: &mut Delta, change: Delta, now: Instant) {
+    manager.record_at(&change, document, now).unwrap();
+    *document = document.compose(&change).unwrap();
+}
+
+fn undo_change(manager: &mut UndoManager, document: &mut Delta) {
+    let change = manager.undo(document).unwrap();
+    *document = document.compose(&change).unwrap();
+}
+
+fn redo_change(manager: &mut UndoManager, document: &mut Delta) {
+    let change = manager.redo(document).unwrap();
+    *document = document.compose(&change).unwrap();
+}
+
+fn insert_at(index: usize, s: &str, document: &Delta) -> Delta {
+    DeltaBuilder::new()
+        .retain(index)
+        .insert(s)
+        .retain(document.target_len - index)
+        .build()
+}
+
+#[test]
+fn undo_manager_merge_rapid_changes() {
+    let mut manager = UndoManager::new(10, Duration::from_millis(400));
+    let mut document = DeltaBuilder::new().insert(NEW_LINE).build();
+    let now = Instant::now();
+    apply_change(&mut manager, &mut document, insert_at(0, "a", &document), now);
+    apply_change(
+        &mut manager,
+        &mut document,
+        insert_at(1, "b", &document),
+        now + Duration::from_millis(100),
+    );
+    apply_change(
+        &mut manager,
+        &mut document,
+        insert_at(2, "c", &document),
+        now + Duration::from_millis(500),
+    );
+    assert_eq!(document.apply("").unwrap(), "abc\n");
+
+    undo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "ab\n");
+    undo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "\n");
+    assert_eq!(manager.can_undo(), false);
+
+    redo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "ab\n");
+    redo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "abc\n");
+    assert_eq!(manager.can_redo(), false);
+}
+
+#[test]
+fn undo_manager_cutoff() {
+    let mut manager = UndoManager::new(10, Duration::from_millis(400));
+    let mut document = DeltaBuilder::new().insert(NEW_LINE).build();
+    let now = Instant::now();
+    apply_change(&mut manager, &mut document, insert_at(0, "a", &document), now);
+    manager.cutoff();
+    apply_change(&mut manager, &mut document, insert_at(1, "b", &document), now);
+
+    undo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "a\n");
+}
+
+#[test]
+fn undo_manager_max_depth() {
+    let mut manager = UndoManager::new(2, Duration::from_millis(0));
+    let mut document = DeltaBuilder::new().insert(NEW_LINE).build();
+    let now = Instant::now();
+    for (index, s) in ["a", "b", "c"].iter().enumerate() {
+        apply_change(&mut manager, &mut document, insert_at(index, s, &document), now);
+    }
+
+    // The oldest change is dropped
+    undo_change(&mut manager, &mut document);
+    undo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "a\n");
+    assert_eq!(manager.can_undo(), false);
+    assert_eq!(manager.undo(&document), None);
+
+    redo_change(&mut manager, &mut document);
+    manager.set_max_depth(0);
+    assert_eq!(manager.can_undo(), false);
+    assert_eq!(manager.can_redo(), false);
+}
+
+#[test]
+fn undo_manager_clear_redo_after_change() {
+    let mut manager = UndoManager::default();
+    let mut document = DeltaBuilder::new().insert(NEW_LINE).build();
+    let now = Instant::now();
+    apply_change(&mut manager, &mut document, insert_at(0, "a", &document), now);
+    undo_change(&mut manager, &mut document);
+    assert_eq!(manager.can_redo(), true);
+
+    apply_change(&mut manager, &mut document, insert_at(0, "b", &document), now);
+    assert_eq!(manager.can_redo(), false);
+    assert_eq!(manager.can_undo(), true);
+}
+
+#[test]
+fn undo_manager_record_in_group() {
+    let mut manager = UndoManager::new(10, Duration::from_millis(400));
+    let mut document = DeltaBuilder::new().insert(NEW_LINE).build();
+    let now = Instant::now();
+    apply_change(&mut manager, &mut document, insert_at(0, "a", &document), now);
+
+    // The changes of the group are undone together however long they take, and they aren't merged into
+    // the change that was recorded just before them
+    for s in vec!["b", "c"] {
+        let change = insert_at(document.target_len - 1, s, &document);
+        manager.record_in_group(&change, &document, "paste").unwrap();
+        document = document.compose(&change).unwrap();
+        std::thread::sleep(Duration::from_millis(RECORD_THRESHOLD as u64));
+    }
+    apply_change(&mut manager, &mut document, insert_at(3, "d", &document), Instant::now());
+    assert_eq!(document.apply("").unwrap(), "abcd\n");
+
+    undo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "abc\n");
+    undo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "a\n");
+    undo_change(&mut manager, &mut document);
+    assert_eq!(document.apply("").unwrap(), "\n");
+}
+
+#[test]
+fn history_compose_chunks_undo() {
+    let mut document = Document::new::<FlowyDoc>();
+    let delta = DeltaBuilder::new().insert(&"0123456789".repeat(30)).retain(1).build();
+    let chunks = split_delta(&delta, 100);
+    assert_eq!(chunks.len(), 3);
+    for chunk in chunks {
+        document.compose_delta_in_group(chunk, "paste").unwrap();
+    }
+    assert_eq!(document.to_plain_string(), format!("{}\n", "0123456789".repeat(30)));
+
+    document.undo().unwrap();
+    assert_eq!(document.to_json(), r#"[{"insert":"\n"}]"#);
+}
@@ -6,6 +6,7 @@ use flowy_document::{
     services::ws::{DocumentWebSocket, WsDocumentManager, WsStateReceiver},
 };
 use flowy_document_infra::entities::ws::WsDocumentData;
+use lib_dispatch::prelude::current_request_id;
 use flowy_user::{
     errors::{ErrorCode, UserError},
     services::user::UserSession,
@@ -79,6 +80,7 @@ impl DocumentWebSocket for WsSenderImpl {
             let msg = WsMessage {
                 module: WsModule::Doc,
                 data: bytes.to_vec(),
+                request_id: current_request_id().unwrap_or_default(),
             };
             let sender = self.user.ws_controller.sender().map_err(internal_error)?;
             sender.send_msg(msg).map_err(internal_error)?;
@@ -1,5 +1,9 @@
 mod document_deps;
+mod server_deps;
+mod setting_deps;
 mod workspace_deps;
 
 pub use document_deps::*;
+pub use server_deps::*;
+pub use setting_deps::*;
 pub use workspace_deps::*;
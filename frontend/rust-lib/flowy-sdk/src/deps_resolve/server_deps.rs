@@ -0,0 +1,28 @@
+use backend_service::{
+    errors::ServerError,
+    request::{RefreshTokenFuture, TokenRefresher},
+};
+use flowy_user::services::user::UserSession;
+use std::sync::Arc;
+
+// The http requests of all the modules are sent again with the refreshed token of the session
+pub struct UserTokenRefresher {
+    user_session: Arc<UserSession>,
+}
+
+impl UserTokenRefresher {
+    pub fn new(user_session: Arc<UserSession>) -> Self { Self { user_session } }
+}
+
+impl TokenRefresher for UserTokenRefresher {
+    fn refresh_token(&self, token: &str) -> RefreshTokenFuture {
+        let user_session = self.user_session.clone();
+        let token = token.to_owned();
+        Box::pin(async move {
+            user_session
+                .refresh_token(&token)
+                .await
+                .map_err(|e| ServerError::unauthorized().context(e))
+        })
+    }
+}
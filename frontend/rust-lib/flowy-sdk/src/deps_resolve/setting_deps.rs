@@ -0,0 +1,100 @@
+use flowy_document::module::FlowyDocument;
+use flowy_document_infra::entities::doc::{DocReadPosition, InputRuleSetting};
+use flowy_user::{errors::UserError, services::user::UserSettingProvider};
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
+
+pub struct InputRuleSettingProvider {
+    document: Arc<FlowyDocument>,
+}
+
+impl InputRuleSettingProvider {
+    pub fn new(document: Arc<FlowyDocument>) -> Self { Self { document } }
+}
+
+impl UserSettingProvider for InputRuleSettingProvider {
+    fn name(&self) -> &'static str { "input_rules" }
+
+    fn export(&self) -> Result<Value, UserError> {
+        let setting = self
+            .document
+            .read_input_rules()
+            .map_err(|e| UserError::internal().context(e))?;
+        Ok(json!({
+            "em_dash": setting.em_dash,
+            "heading": setting.heading,
+            "checkbox": setting.checkbox,
+        }))
+    }
+
+    fn import(&self, value: Value) -> Result<(), UserError> {
+        let current = self
+            .document
+            .read_input_rules()
+            .map_err(|e| UserError::internal().context(e))?;
+        let read = |rule: &str, current: bool| value.get(rule).and_then(|v| v.as_bool()).unwrap_or(current);
+        let setting = InputRuleSetting {
+            em_dash: read("em_dash", current.em_dash),
+            heading: read("heading", current.heading),
+            checkbox: read("checkbox", current.checkbox),
+        };
+        self.document
+            .update_input_rules(setting)
+            .map_err(|e| UserError::internal().context(e))
+    }
+}
+
+// The read positions of the documents, keyed by the doc id. The profile is imported on the other device
+// to resume reading at the same place.
+pub struct ReadPositionSettingProvider {
+    document: Arc<FlowyDocument>,
+}
+
+impl ReadPositionSettingProvider {
+    pub fn new(document: Arc<FlowyDocument>) -> Self { Self { document } }
+}
+
+impl UserSettingProvider for ReadPositionSettingProvider {
+    fn name(&self) -> &'static str { "read_positions" }
+
+    fn export(&self) -> Result<Value, UserError> {
+        let positions = self
+            .document
+            .read_all_positions()
+            .map_err(|e| UserError::internal().context(e))?;
+        let mut map = Map::new();
+        for position in positions {
+            map.insert(
+                position.doc_id,
+                json!({
+                    "offset": position.offset,
+                    "modified_time": position.modified_time,
+                }),
+            );
+        }
+        Ok(Value::Object(map))
+    }
+
+    // The position that is malformed is skipped, the local one is kept if it's later
+    fn import(&self, value: Value) -> Result<(), UserError> {
+        let map = match value {
+            Value::Object(map) => map,
+            _ => return Ok(()),
+        };
+        let positions = map
+            .into_iter()
+            .filter_map(|(doc_id, value)| {
+                let offset = value.get("offset")?.as_i64()?;
+                let modified_time = value.get("modified_time")?.as_i64()?;
+                Some(DocReadPosition {
+                    doc_id,
+                    offset,
+                    modified_time,
+                })
+            })
+            .collect::<Vec<DocReadPosition>>();
+        self.document
+            .merge_read_positions(positions)
+            .map_err(|e| UserError::internal().context(e))
+    }
+}
@@ -1,7 +1,7 @@
 use flowy_database::ConnectionPool;
 use flowy_user::services::user::UserSession;
 use flowy_workspace::{
-    errors::WorkspaceError,
+    errors::{ErrorCode, WorkspaceError},
     module::{WorkspaceDatabase, WorkspaceUser},
 };
 use std::sync::Arc;
@@ -34,6 +34,18 @@ impl WorkspaceDatabase for Resolver {
             .db_pool()
             .map_err(|e| WorkspaceError::internal().context(e))
     }
+
+    fn local_user_db_pool(&self, user_id: &str) -> Result<Arc<ConnectionPool>, WorkspaceError> {
+        self.user_session
+            .local_user_db_pool(user_id)
+            .map_err(|e| WorkspaceError::from(ErrorCode::MergeSourceInvalid).context(e))
+    }
+
+    fn close_local_user_db(&self, user_id: &str) {
+        if let Err(e) = self.user_session.close_local_user_db(user_id) {
+            log::error!("Close the database of {} failed: {:?}", user_id, e);
+        }
+    }
 }
 
 impl WorkspaceUser for Resolver {
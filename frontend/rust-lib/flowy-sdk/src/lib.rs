@@ -1,18 +1,32 @@
 mod deps_resolve;
 // mod flowy_server;
+mod metrics;
 pub mod module;
+mod profile;
+#[cfg(feature = "scripting")]
+pub mod script;
 
-use crate::deps_resolve::WorkspaceDepsResolver;
-use backend_service::config::ServerConfig;
+use crate::deps_resolve::{UserTokenRefresher, WorkspaceDepsResolver};
+use backend_service::{config::ServerConfig, request::TokenRefresher};
+use flowy_database::{FtsLanguage, SEARCH_INDEX};
 use flowy_document::module::FlowyDocument;
 use flowy_user::services::user::{UserSession, UserSessionBuilder, UserStatus};
+#[cfg(feature = "sqlcipher")]
+pub use flowy_user::services::user::KeychainSecretStore;
+pub use flowy_user::services::user::SecretStore;
 use flowy_workspace::{errors::WorkspaceError, prelude::WorkspaceController};
 use lib_dispatch::prelude::*;
+use lib_infra::bus::EventBus;
+pub use lib_log::{LogFormat, Rotation};
 use module::mk_modules;
 pub use module::*;
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+pub use profile::*;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
 use tokio::sync::broadcast;
 
@@ -23,7 +37,15 @@ pub struct FlowySDKConfig {
     name: String,
     root: String,
     log_filter: String,
+    log_format: LogFormat,
+    log_rotation: Rotation,
     server_config: ServerConfig,
+    dispatch_config: DispatchConfig,
+    search_language: FtsLanguage,
+    secret_store: Option<Arc<dyn SecretStore>>,
+    enable_metrics: bool,
+    #[cfg(feature = "scripting")]
+    script_limits: Option<script::ScriptLimits>,
 }
 
 impl FlowySDKConfig {
@@ -32,7 +54,15 @@ impl FlowySDKConfig {
             name: name.to_owned(),
             root: root.to_owned(),
             log_filter: crate_log_filter(None),
+            log_format: LogFormat::Text,
+            log_rotation: Rotation::Daily,
             server_config,
+            dispatch_config: DispatchConfig::default(),
+            search_language: FtsLanguage::default(),
+            secret_store: None,
+            enable_metrics: false,
+            #[cfg(feature = "scripting")]
+            script_limits: None,
         }
     }
 
@@ -40,6 +70,58 @@ impl FlowySDKConfig {
         self.log_filter = crate_log_filter(Some(filter.to_owned()));
         self
     }
+
+    // The json logs can be searched by the fields, e.g. the user_id, after they are uploaded
+    pub fn log_format(mut self, format: LogFormat) -> Self {
+        self.log_format = format;
+        self
+    }
+
+    pub fn log_rotation(mut self, rotation: Rotation) -> Self {
+        self.log_rotation = rotation;
+        self
+    }
+
+    // The max number of the sqlite or compression tasks that run at the same time
+    pub fn blocking_pool_size(mut self, size: usize) -> Self {
+        self.dispatch_config.blocking_pool_size = size;
+        self
+    }
+
+    // The events that don't finish within the timeout return the StatusCode::Timeout response
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.dispatch_config.request_timeout = Some(timeout);
+        self
+    }
+
+    // The language that the full-text search splits the words with, e.g. the Chinese text is split into
+    // words by the dictionary instead of the whitespaces. It's shared by the SDKs in the same process.
+    pub fn search_language(mut self, language: FtsLanguage) -> Self {
+        self.search_language = language;
+        self
+    }
+
+    // The user databases are encrypted at rest with the passphrases that are kept in the store, e.g. the
+    // KeychainSecretStore. It needs the sqlcipher feature, the databases aren't encrypted without it.
+    pub fn secret_store(mut self, secret_store: Arc<dyn SecretStore>) -> Self {
+        self.secret_store = Some(secret_store);
+        self
+    }
+
+    // Records the counters and the latencies of the events, the websocket messages and the sqlite connections.
+    // They are read with the QueryMetricsSnapshot event and counted for all the SDKs in the same process.
+    pub fn metrics(mut self, enabled: bool) -> Self {
+        self.enable_metrics = enabled;
+        self
+    }
+
+    // Runs the scripts in the scripts dir of the root when the notifications they subscribe to are sent.
+    // The scripts can only create the views and append the text to the documents, within the limits.
+    #[cfg(feature = "scripting")]
+    pub fn scripting(mut self, limits: script::ScriptLimits) -> Self {
+        self.script_limits = Some(limits);
+        self
+    }
 }
 
 fn crate_log_filter(level: Option<String>) -> String {
@@ -64,25 +146,77 @@ pub struct FlowySDK {
     pub flowy_document: Arc<FlowyDocument>,
     pub workspace: Arc<WorkspaceController>,
     pub dispatch: Arc<EventDispatch>,
+    pub bus: EventBus,
+    pub startup_profile: StartupProfile,
+    #[cfg(feature = "scripting")]
+    pub scripts: Option<Arc<script::ScriptHooks>>,
+    // The registry of backend_service only keeps the weak reference
+    #[allow(dead_code)]
+    token_refresher: Arc<dyn TokenRefresher>,
 }
 
 impl FlowySDK {
     pub fn new(config: FlowySDKConfig) -> Self {
-        init_log(&config);
-        init_kv(&config.root);
+        let mut profile = StartupProfile::default();
+        profile.measure("log", || init_log(&config));
+        profile.measure("kv", || init_kv(&config.root));
+        metrics::init_metrics(config.enable_metrics);
+        SEARCH_INDEX.set_language(config.search_language);
         tracing::debug!("🔥 {:?}", config);
 
+        // The modules of the SDK publish and subscribe to the events on their own bus, so the SDKs of the
+        // users that run in the same process don't receive the events of each other.
+        let bus = EventBus::new();
+        // The document module runs the writes of the revisions on the same pool as the handlers of the dispatch
+        let blocking_pool = BlockingPool::new(config.dispatch_config.blocking_pool_size);
         let session_cache_key = format!("{}_session_cache", &config.name);
-        let user_session = Arc::new(
-            UserSessionBuilder::new()
-                .root_dir(&config.root, &config.server_config, &session_cache_key)
-                .build(),
-        );
-        let flowy_document = mk_document_module(user_session.clone(), &config.server_config);
-        let workspace = mk_workspace(user_session.clone(), flowy_document.clone(), &config.server_config);
-        let modules = mk_modules(workspace.clone(), user_session.clone());
-        let dispatch = Arc::new(EventDispatch::construct(|| modules));
-        _init(&dispatch, user_session.clone(), workspace.clone());
+        let user_session = profile.measure("user", || {
+            Arc::new(
+                UserSessionBuilder::new()
+                    .root_dir(&config.root, &config.server_config, &session_cache_key)
+                    .secret_store(config.secret_store.clone())
+                    .bus(bus.clone())
+                    .build(),
+            )
+        });
+        profile.measure("migration", || migrate_user_db(&user_session));
+        let flowy_document = profile.measure("document", || {
+            mk_document_module(
+                user_session.clone(),
+                &config.server_config,
+                bus.clone(),
+                blocking_pool.clone(),
+            )
+        });
+        let workspace = profile.measure("workspace", || {
+            mk_workspace(user_session.clone(), flowy_document.clone(), &config.server_config, bus.clone())
+        });
+        let token_refresher: Arc<dyn TokenRefresher> = Arc::new(UserTokenRefresher::new(user_session.clone()));
+        let dispatch = profile.measure("dispatch", || {
+            let modules = mk_modules(workspace.clone(), user_session.clone());
+            let scope_provider = mk_scope_provider(user_session.clone());
+            backend_service::request::set_request_id_provider(current_request_id);
+            backend_service::request::register_token_refresher(&token_refresher);
+            let dispatch_config = config.dispatch_config.clone();
+            let dispatch = EventDispatch::construct_with_pool(dispatch_config, blocking_pool, || modules);
+            Arc::new(dispatch.scope_provider(scope_provider))
+        });
+        profile.measure("listen", || {
+            _init(&dispatch, user_session.clone(), workspace.clone(), flowy_document.clone())
+        });
+        #[cfg(feature = "scripting")]
+        let scripts = config.script_limits.clone().map(|limits| {
+            profile.measure("script", || {
+                script::mk_script_hooks(
+                    &config.root,
+                    limits,
+                    dispatch.clone(),
+                    flowy_document.clone(),
+                    user_session.clone(),
+                )
+            })
+        });
+        tracing::info!("FlowySDK started, {}", profile);
 
         Self {
             config,
@@ -90,14 +224,30 @@ impl FlowySDK {
             flowy_document,
             workspace,
             dispatch,
+            bus,
+            startup_profile: profile,
+            #[cfg(feature = "scripting")]
+            scripts,
+            token_refresher,
         }
     }
 
     pub fn dispatch(&self) -> Arc<EventDispatch> { self.dispatch.clone() }
+
+    pub fn config(&self) -> &FlowySDKConfig { &self.config }
 }
 
-fn _init(dispatch: &EventDispatch, user_session: Arc<UserSession>, workspace_controller: Arc<WorkspaceController>) {
+fn _init(
+    dispatch: &EventDispatch,
+    user_session: Arc<UserSession>,
+    workspace_controller: Arc<WorkspaceController>,
+    flowy_document: Arc<FlowyDocument>,
+) {
     let subscribe = user_session.status_subscribe();
+    let session_events = workspace_controller.subscribe_bus_events();
+    dispatch.spawn(async move { flowy_document.listen() });
+    let cloned_workspace_controller = workspace_controller.clone();
+    dispatch.spawn(async move { cloned_workspace_controller.listen_bus_events(session_events).await });
     dispatch.spawn(async move {
         user_session.init();
         _listen_user_status(subscribe, workspace_controller).await;
@@ -116,9 +266,6 @@ async fn _listen_user_status(
                         UserStatus::Login { token } => {
                             let _ = workspace_controller.user_did_sign_in(&token).await?;
                         },
-                        UserStatus::Logout { .. } => {
-                            workspace_controller.user_did_logout().await;
-                        },
                         UserStatus::Expired { .. } => {
                             workspace_controller.user_session_expired().await;
                         },
@@ -140,6 +287,18 @@ async fn _listen_user_status(
     }
 }
 
+// Opening the database of the signed in user runs its pending migrations, so the old database is upgraded
+// before the modules read it.
+fn migrate_user_db(user_session: &UserSession) {
+    if user_session.user_id().is_err() {
+        return;
+    }
+    match user_session.db_pool() {
+        Ok(_) => {},
+        Err(e) => tracing::error!("Migrate the user database failed: {:?}", e),
+    }
+}
+
 fn init_kv(root: &str) {
     match lib_infra::kv::KV::init(root) {
         Ok(_) => {},
@@ -153,6 +312,8 @@ fn init_log(config: &FlowySDKConfig) {
 
         let _ = lib_log::Builder::new("flowy-client", &config.root)
             .env_filter(&config.log_filter)
+            .format(config.log_format)
+            .rotation(config.log_rotation)
             .build();
     }
 }
@@ -161,9 +322,11 @@ fn mk_workspace(
     user_session: Arc<UserSession>,
     flowy_document: Arc<FlowyDocument>,
     server_config: &ServerConfig,
+    bus: EventBus,
 ) -> Arc<WorkspaceController> {
     let workspace_deps = WorkspaceDepsResolver::new(user_session.clone());
     let (user, database) = workspace_deps.split_into();
-    let workspace_controller = flowy_workspace::module::mk_workspace(user, database, flowy_document, server_config);
+    let workspace_controller =
+        flowy_workspace::module::mk_workspace(user, database, flowy_document, server_config, bus);
     workspace_controller
 }
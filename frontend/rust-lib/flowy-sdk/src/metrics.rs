@@ -0,0 +1,28 @@
+use lib_dispatch::prelude::{DispatchRequest, EventResponse, Next};
+use lib_infra::metrics;
+use std::time::Instant;
+
+// Counts the events of the modules by their status, and records how long their handlers took
+pub(crate) async fn event_metrics(request: DispatchRequest, next: Next) -> EventResponse {
+    if !metrics::is_metrics_enabled() {
+        return next.run(request).await;
+    }
+
+    let event = request.event.name().to_owned();
+    let start = Instant::now();
+    let response = next.run(request).await;
+    let status = format!("{:?}", response.status_code);
+    let labels = [("event", event.as_str()), ("status", status.as_str())];
+    metrics::increment_counter("dispatch_events_total", &labels, 1);
+    metrics::observe_latency("dispatch_event_duration_seconds", &labels[..1], start.elapsed());
+    response
+}
+
+pub(crate) fn init_metrics(enabled: bool) {
+    metrics::enable_metrics(enabled);
+    if enabled {
+        flowy_database::set_connection_observer(Box::new(|duration| {
+            metrics::observe_latency("sqlite_connection_seconds", &[], duration)
+        }));
+    }
+}
@@ -1,13 +1,17 @@
-use crate::deps_resolve::DocumentDepsResolver;
+use crate::deps_resolve::{DocumentDepsResolver, InputRuleSettingProvider, ReadPositionSettingProvider};
 use backend_service::config::ServerConfig;
 use flowy_document::module::FlowyDocument;
 use flowy_user::services::user::UserSession;
 use flowy_workspace::prelude::WorkspaceController;
-use lib_dispatch::prelude::Module;
+use lib_dispatch::prelude::{BlockingPool, Module, ScopeProvider};
+use lib_infra::bus::EventBus;
 use std::sync::Arc;
 
 pub fn mk_modules(workspace_controller: Arc<WorkspaceController>, user_session: Arc<UserSession>) -> Vec<Module> {
     vec![mk_user_module(user_session), mk_workspace_module(workspace_controller)]
+        .into_iter()
+        .map(|module| module.middleware(crate::metrics::event_metrics))
+        .collect()
 }
 
 fn mk_user_module(user_session: Arc<UserSession>) -> Module { flowy_user::module::create(user_session.clone()) }
@@ -16,9 +20,36 @@ fn mk_workspace_module(workspace_controller: Arc<WorkspaceController>) -> Module
     flowy_workspace::module::create(workspace_controller)
 }
 
-pub fn mk_document_module(user_session: Arc<UserSession>, server_config: &ServerConfig) -> Arc<FlowyDocument> {
+// The events are annotated with the scopes in their event.rs
+const SIGNED_IN_SCOPE: &str = "signed_in";
+
+pub fn mk_scope_provider(user_session: Arc<UserSession>) -> Arc<dyn ScopeProvider> {
+    Arc::new(UserScopeProvider { user_session })
+}
+
+struct UserScopeProvider {
+    user_session: Arc<UserSession>,
+}
+
+impl ScopeProvider for UserScopeProvider {
+    fn has_scope(&self, scope: &str) -> bool {
+        match scope {
+            SIGNED_IN_SCOPE => self.user_session.user_id().is_ok(),
+            _ => false,
+        }
+    }
+}
+
+pub fn mk_document_module(
+    user_session: Arc<UserSession>,
+    server_config: &ServerConfig,
+    bus: EventBus,
+    blocking_pool: BlockingPool,
+) -> Arc<FlowyDocument> {
     let document_deps = DocumentDepsResolver::new(user_session.clone());
     let (user, ws_manager) = document_deps.split_into();
-    let document = Arc::new(FlowyDocument::new(user, ws_manager, server_config));
+    let document = Arc::new(FlowyDocument::new(user, ws_manager, server_config, bus, blocking_pool));
+    user_session.register_setting_provider(Arc::new(InputRuleSettingProvider::new(document.clone())));
+    user_session.register_setting_provider(Arc::new(ReadPositionSettingProvider::new(document.clone())));
     document
 }
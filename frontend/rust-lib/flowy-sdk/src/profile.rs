@@ -0,0 +1,36 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+// The time spent by each step of FlowySDK::new. The sdk is created before the Flutter splash screen is
+// dismissed, so the slow step shows up here first.
+#[derive(Debug, Clone, Default)]
+pub struct StartupProfile {
+    pub steps: Vec<(&'static str, Duration)>,
+}
+
+impl StartupProfile {
+    pub(crate) fn measure<T, F>(&mut self, name: &'static str, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let instant = Instant::now();
+        let output = f();
+        self.steps.push((name, instant.elapsed()));
+        output
+    }
+
+    pub fn total(&self) -> Duration { self.steps.iter().map(|(_, duration)| *duration).sum() }
+}
+
+impl fmt::Display for StartupProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let steps = self
+            .steps
+            .iter()
+            .map(|(name, duration)| format!("{}: {:?}", name, duration))
+            .collect::<Vec<_>>();
+        write!(f, "total: {:?} ({})", self.total(), steps.join(", "))
+    }
+}
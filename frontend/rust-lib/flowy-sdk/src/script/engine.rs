@@ -0,0 +1,301 @@
+use parking_lot::Mutex;
+use rhai::{Dynamic, Engine, EvalAltResult, Map, Scope, AST};
+use std::{fmt, sync::Arc};
+
+// The limits are checked by the engine while the script runs, the script is stopped with an error once
+// it goes over one of them.
+#[derive(Debug, Clone)]
+pub struct ScriptLimits {
+    // The operations that loading the script or one run of its handler can take, it stops the endless loops
+    pub max_operations: u64,
+    pub max_call_levels: usize,
+    pub max_string_size: usize,
+    pub max_array_size: usize,
+    pub max_map_size: usize,
+    // The events that one run of a handler can dispatch
+    pub max_actions_per_run: usize,
+    // The events that a script can dispatch in a minute. The events that it dispatches may trigger it
+    // again, the budget breaks the loop.
+    pub max_actions_per_minute: usize,
+}
+
+impl std::default::Default for ScriptLimits {
+    fn default() -> Self {
+        ScriptLimits {
+            max_operations: 100_000,
+            max_call_levels: 32,
+            max_string_size: 64 * 1024,
+            max_array_size: 1024,
+            max_map_size: 1024,
+            max_actions_per_run: 8,
+            max_actions_per_minute: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ScriptError {
+    Load(String),
+    Run(String),
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::Load(msg) => write!(f, "Load the script failed: {}", msg),
+            ScriptError::Run(msg) => write!(f, "Run the script failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+// The notification that the handlers of the scripts receive, e.g. the ViewUpdated of the Workspace with
+// the id of the view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptEvent {
+    pub source: String,
+    pub name: String,
+    pub id: String,
+}
+
+impl ScriptEvent {
+    fn to_map(&self) -> Map {
+        let mut map = Map::new();
+        map.insert("source".into(), self.source.clone().into());
+        map.insert("name".into(), self.name.clone().into());
+        map.insert("id".into(), self.id.clone().into());
+        map
+    }
+}
+
+// The only events that the scripts can dispatch
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptAction {
+    CreateView { belong_to_id: String, name: String },
+    AppendText { doc_id: String, text: String },
+}
+
+#[derive(Debug, Clone)]
+struct ScriptHandler {
+    source: String,
+    name: String,
+    function: String,
+}
+
+#[derive(Default)]
+struct ScriptState {
+    handlers: Vec<ScriptHandler>,
+    actions: Vec<ScriptAction>,
+}
+
+// A script subscribes to the notifications when it's loaded and names the function that handles them:
+//
+//  on("Workspace", "AppViewsChanged", "add_index");
+//
+//  fn add_index(event) {
+//      append_text(event.id, "Index\n");
+//  }
+//
+// The handlers queue the actions with create_view(belong_to_id, name) and append_text(doc_id, text), they
+// are dispatched after the handler returns. The scripts can't reach the files or the network.
+pub(crate) struct Script {
+    name: String,
+    engine: Engine,
+    ast: AST,
+    state: Arc<Mutex<ScriptState>>,
+}
+
+impl Script {
+    pub(crate) fn load(name: &str, source: &str, limits: &ScriptLimits) -> Result<Self, ScriptError> {
+        let state = Arc::new(Mutex::new(ScriptState::default()));
+        let engine = mk_engine(name, limits, state.clone());
+        let ast = engine.compile(source).map_err(|e| ScriptError::Load(e.to_string()))?;
+        let _ = engine.run_ast(&ast).map_err(|e| ScriptError::Load(e.to_string()))?;
+
+        // The actions that the script queued while loading are dropped, only the handlers dispatch events
+        let mut state_guard = state.lock();
+        state_guard.actions.clear();
+        for handler in &state_guard.handlers {
+            let is_defined = ast
+                .iter_functions()
+                .any(|function| function.name == handler.function && function.params.len() == 1);
+            if !is_defined {
+                return Err(ScriptError::Load(format!(
+                    "The handler {}(event) of {} isn't defined",
+                    handler.function, handler.name
+                )));
+            }
+        }
+        drop(state_guard);
+
+        Ok(Self {
+            name: name.to_owned(),
+            engine,
+            ast,
+            state,
+        })
+    }
+
+    pub(crate) fn name(&self) -> &str { &self.name }
+
+    pub(crate) fn is_subscribed(&self, event: &ScriptEvent) -> bool {
+        self.state
+            .lock()
+            .handlers
+            .iter()
+            .any(|handler| handler.source == event.source && handler.name == event.name)
+    }
+
+    // Runs the handlers of the event and returns the actions they queued. Nothing is dispatched if one of
+    // them fails.
+    pub(crate) fn handle(&self, event: &ScriptEvent) -> Result<Vec<ScriptAction>, ScriptError> {
+        let functions = self
+            .state
+            .lock()
+            .handlers
+            .iter()
+            .filter(|handler| handler.source == event.source && handler.name == event.name)
+            .map(|handler| handler.function.clone())
+            .collect::<Vec<String>>();
+
+        for function in functions {
+            let mut scope = Scope::new();
+            let mut args = [Dynamic::from(event.to_map())];
+            // The statements of the script only run when it's loaded, so the handlers don't subscribe again
+            let result = self
+                .engine
+                .call_fn_dynamic(&mut scope, &self.ast, false, &function, None, &mut args);
+            if let Err(e) = result {
+                self.state.lock().actions.clear();
+                return Err(ScriptError::Run(format!("{}: {}", function, e)));
+            }
+        }
+        Ok(std::mem::take(&mut self.state.lock().actions))
+    }
+}
+
+fn mk_engine(name: &str, limits: &ScriptLimits, state: Arc<Mutex<ScriptState>>) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(limits.max_operations);
+    engine.set_max_call_levels(limits.max_call_levels);
+    engine.set_max_string_size(limits.max_string_size);
+    engine.set_max_array_size(limits.max_array_size);
+    engine.set_max_map_size(limits.max_map_size);
+    engine.disable_symbol("eval");
+
+    let script_name = name.to_owned();
+    engine.on_print(move |s| tracing::info!("[script {}] {}", script_name, s));
+    let script_name = name.to_owned();
+    engine.on_debug(move |s, _, _| tracing::debug!("[script {}] {}", script_name, s));
+
+    let cloned_state = state.clone();
+    engine.register_fn("on", move |source: &str, name: &str, function: &str| {
+        cloned_state.lock().handlers.push(ScriptHandler {
+            source: source.to_owned(),
+            name: name.to_owned(),
+            function: function.to_owned(),
+        });
+    });
+
+    let cloned_state = state.clone();
+    let max_actions = limits.max_actions_per_run;
+    engine.register_result_fn("create_view", move |belong_to_id: &str, name: &str| {
+        queue_action(
+            &cloned_state,
+            max_actions,
+            ScriptAction::CreateView {
+                belong_to_id: belong_to_id.to_owned(),
+                name: name.to_owned(),
+            },
+        )
+    });
+
+    let max_actions = limits.max_actions_per_run;
+    engine.register_result_fn("append_text", move |doc_id: &str, text: &str| {
+        queue_action(
+            &state,
+            max_actions,
+            ScriptAction::AppendText {
+                doc_id: doc_id.to_owned(),
+                text: text.to_owned(),
+            },
+        )
+    });
+    engine
+}
+
+fn queue_action(
+    state: &Mutex<ScriptState>,
+    max_actions: usize,
+    action: ScriptAction,
+) -> Result<(), Box<EvalAltResult>> {
+    let mut state = state.lock();
+    if state.actions.len() >= max_actions {
+        return Err(format!("The handler can't dispatch more than {} events", max_actions).into());
+    }
+    state.actions.push(action);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(name: &str, id: &str) -> ScriptEvent {
+        ScriptEvent {
+            source: "Workspace".to_owned(),
+            name: name.to_owned(),
+            id: id.to_owned(),
+        }
+    }
+
+    #[test]
+    fn script_handle_event() {
+        let source = r#"
+            on("Workspace", "AppViewsChanged", "add_index");
+            fn add_index(event) {
+                create_view(event.id, "Index");
+                append_text(event.id, "Hello");
+            }
+        "#;
+        let script = Script::load("index", source, &ScriptLimits::default()).unwrap();
+        assert!(!script.is_subscribed(&event("ViewUpdated", "app")));
+
+        let actions = script.handle(&event("AppViewsChanged", "app")).unwrap();
+        assert_eq!(
+            actions,
+            vec![
+                ScriptAction::CreateView {
+                    belong_to_id: "app".to_owned(),
+                    name: "Index".to_owned(),
+                },
+                ScriptAction::AppendText {
+                    doc_id: "app".to_owned(),
+                    text: "Hello".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn script_undefined_handler() {
+        let source = r#"on("Workspace", "ViewUpdated", "missing");"#;
+        assert!(Script::load("missing", source, &ScriptLimits::default()).is_err());
+    }
+
+    #[test]
+    fn script_over_limits() {
+        let source = r#"
+            on("Workspace", "ViewUpdated", "spin");
+            on("Workspace", "AppUpdated", "flood");
+            fn spin(event) { loop {} }
+            fn flood(event) {
+                for i in range(0, 100) { append_text(event.id, "x"); }
+            }
+        "#;
+        let script = Script::load("limits", source, &ScriptLimits::default()).unwrap();
+        assert!(script.handle(&event("ViewUpdated", "view")).is_err());
+        assert!(script.handle(&event("AppUpdated", "app")).is_err());
+    }
+}
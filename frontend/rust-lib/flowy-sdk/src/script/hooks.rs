@@ -0,0 +1,303 @@
+use crate::script::engine::{Script, ScriptAction, ScriptError, ScriptEvent, ScriptLimits};
+use bytes::Bytes;
+use dart_notify::{
+    dart::{NotificationRegistry, NotificationSink, SubscriptionFilter},
+    entities::SubscribeObject,
+};
+use flowy_document::module::FlowyDocument;
+use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_user::services::user::UserSession;
+use flowy_workspace::{
+    entities::view::{CreateViewRequest, View, ViewType},
+    errors::WorkspaceError,
+    event::WorkspaceEvent,
+};
+use lib_dispatch::prelude::*;
+use lib_ot::core::{Delta, DeltaBuilder};
+use parking_lot::{Mutex, RwLock};
+use protobuf::ProtobufEnum;
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::TryFrom,
+    sync::{Arc, Weak},
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+
+const NOTIFICATION_SOURCES: [&str; 3] = ["Workspace", "Doc", "User"];
+const ACTION_BUDGET_WINDOW: Duration = Duration::from_secs(60);
+
+// Runs the scripts of the power users when the notifications they subscribe to are sent. The scripts
+// are run one at a time in the order of the notifications, and the events they dispatch go through the
+// dispatcher like the ones from dart.
+//
+// The notification registry is shared by the process, so the scripts see the notifications of all the
+// sdks in it.
+pub struct ScriptHooks {
+    limits: ScriptLimits,
+    scripts: RwLock<HashMap<String, Arc<Script>>>,
+    // The time of the events that each script dispatched in the last minute
+    dispatched: Mutex<HashMap<String, VecDeque<Instant>>>,
+    subscription_ids: Mutex<Vec<i64>>,
+}
+
+impl ScriptHooks {
+    pub(crate) fn new(limits: ScriptLimits) -> Self {
+        Self {
+            limits,
+            scripts: RwLock::new(HashMap::new()),
+            dispatched: Mutex::new(HashMap::new()),
+            subscription_ids: Mutex::new(vec![]),
+        }
+    }
+
+    // Replaces the script with the same name
+    pub fn load(&self, name: &str, source: &str) -> Result<(), ScriptError> {
+        let script = Script::load(name, source, &self.limits)?;
+        tracing::info!("Load the script {}", name);
+        self.scripts.write().insert(name.to_owned(), Arc::new(script));
+        Ok(())
+    }
+
+    // Loads the *.rhai files of the dir, they are named after the file. The scripts that fail to load are
+    // skipped.
+    pub fn load_dir(&self, dir: &str) {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("rhai") {
+                continue;
+            }
+            let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+                Some(name) => name.to_owned(),
+                None => continue,
+            };
+            let result = std::fs::read_to_string(&path)
+                .map_err(|e| ScriptError::Load(e.to_string()))
+                .and_then(|source| self.load(&name, &source));
+            if let Err(e) = result {
+                tracing::error!("{:?}: {}", path, e);
+            }
+        }
+    }
+
+    pub fn unload(&self, name: &str) -> bool {
+        self.dispatched.lock().remove(name);
+        self.scripts.write().remove(name).is_some()
+    }
+
+    pub fn script_names(&self) -> Vec<String> {
+        let mut names = self.scripts.read().keys().cloned().collect::<Vec<String>>();
+        names.sort();
+        names
+    }
+
+    pub(crate) fn listen(
+        self: &Arc<Self>,
+        dispatch: Arc<EventDispatch>,
+        document: Arc<FlowyDocument>,
+        user_session: Arc<UserSession>,
+    ) {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let sink = Arc::new(ScriptSink { sender });
+        let mut subscription_ids = self.subscription_ids.lock();
+        for source in NOTIFICATION_SOURCES.iter() {
+            subscription_ids.push(NotificationRegistry::subscribe(SubscriptionFilter::new(source), sink.clone()));
+        }
+
+        // The runner stops once the hooks are dropped, they unsubscribe the sink then
+        let hooks: Weak<ScriptHooks> = Arc::downgrade(self);
+        let runner = ActionRunner {
+            dispatch: dispatch.clone(),
+            document,
+            user_session,
+        };
+        dispatch.spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                match hooks.upgrade() {
+                    None => break,
+                    Some(hooks) => hooks.run(&event, &runner).await,
+                }
+            }
+        });
+    }
+
+    async fn run(&self, event: &ScriptEvent, runner: &ActionRunner) {
+        let scripts = self
+            .scripts
+            .read()
+            .values()
+            .filter(|script| script.is_subscribed(event))
+            .cloned()
+            .collect::<Vec<Arc<Script>>>();
+
+        for script in scripts {
+            let actions = match script.handle(event) {
+                Ok(actions) => actions,
+                Err(e) => {
+                    tracing::error!("[script {}] {}", script.name(), e);
+                    continue;
+                },
+            };
+
+            let allowed = self.take_budget(script.name(), actions.len());
+            if allowed < actions.len() {
+                tracing::warn!(
+                    "[script {}] Dropped {} events, it dispatched more than {} events in a minute",
+                    script.name(),
+                    actions.len() - allowed,
+                    self.limits.max_actions_per_minute
+                );
+            }
+            for action in actions.into_iter().take(allowed) {
+                if let Err(e) = runner.run(action).await {
+                    tracing::error!("[script {}] {}", script.name(), e);
+                }
+            }
+        }
+    }
+
+    // Returns how many of the events the script can still dispatch in the window
+    fn take_budget(&self, name: &str, count: usize) -> usize {
+        let now = Instant::now();
+        let mut dispatched = self.dispatched.lock();
+        let times = dispatched.entry(name.to_owned()).or_insert_with(VecDeque::new);
+        while let Some(time) = times.front() {
+            if now.duration_since(*time) < ACTION_BUDGET_WINDOW {
+                break;
+            }
+            times.pop_front();
+        }
+
+        let allowed = count.min(self.limits.max_actions_per_minute.saturating_sub(times.len()));
+        times.extend(std::iter::repeat(now).take(allowed));
+        allowed
+    }
+}
+
+impl Drop for ScriptHooks {
+    fn drop(&mut self) {
+        for subscription_id in self.subscription_ids.lock().drain(..) {
+            let _ = NotificationRegistry::unsubscribe(subscription_id);
+        }
+    }
+}
+
+struct ScriptSink {
+    sender: mpsc::UnboundedSender<ScriptEvent>,
+}
+
+impl NotificationSink for ScriptSink {
+    // The notifications are sent in the transactions of the controllers, so they are handled on the runner
+    // instead of here.
+    fn post(&self, bytes: Vec<u8>) -> bool {
+        let subject = match SubscribeObject::try_from(Bytes::from(bytes)) {
+            Ok(subject) => subject,
+            Err(e) => {
+                tracing::error!("Parse the notification for the scripts failed: {:?}", e);
+                return true;
+            },
+        };
+
+        match notification_name(&subject.source, subject.ty) {
+            None => true,
+            Some(name) => {
+                let event = ScriptEvent {
+                    source: subject.source,
+                    name,
+                    id: subject.id,
+                };
+                self.sender.send(event).is_ok()
+            },
+        }
+    }
+}
+
+fn notification_name(source: &str, ty: i32) -> Option<String> {
+    match source {
+        "Workspace" => flowy_workspace::protobuf::WorkspaceNotification::from_i32(ty).map(|ty| format!("{:?}", ty)),
+        "Doc" => flowy_document::protobuf::DocObservable::from_i32(ty).map(|ty| format!("{:?}", ty)),
+        "User" => flowy_user::protobuf::UserNotification::from_i32(ty).map(|ty| format!("{:?}", ty)),
+        _ => None,
+    }
+}
+
+struct ActionRunner {
+    dispatch: Arc<EventDispatch>,
+    document: Arc<FlowyDocument>,
+    user_session: Arc<UserSession>,
+}
+
+impl ActionRunner {
+    async fn run(&self, action: ScriptAction) -> Result<(), String> {
+        match action {
+            ScriptAction::CreateView { belong_to_id, name } => {
+                let request = CreateViewRequest {
+                    belong_to_id,
+                    name,
+                    desc: "".to_owned(),
+                    thumbnail: None,
+                    view_type: ViewType::Doc,
+                };
+                let _ = self.send::<_, View>(WorkspaceEvent::CreateView, request).await?;
+            },
+            ScriptAction::AppendText { doc_id, text } => {
+                // The text is inserted before the trailing newline of the document
+                let pool = self.user_session.db_pool().map_err(|e| format!("{:?}", e))?;
+                let doc = self
+                    .document
+                    .read_document_data(DocIdentifier { doc_id: doc_id.clone() }, pool)
+                    .await
+                    .map_err(|e| format!("{:?}", e))?;
+                let len = Delta::from_json(&doc.data)
+                    .map_err(|e| format!("{:?}", e))?
+                    .target_len;
+                let delta = DeltaBuilder::new().retain(len.saturating_sub(1)).insert(&text).build();
+                let request = DocDelta {
+                    doc_id,
+                    data: delta.to_json(),
+                    read_position: None,
+                };
+                let _ = self.send::<_, DocDelta>(WorkspaceEvent::ApplyDocDelta, request).await?;
+            },
+        }
+        Ok(())
+    }
+
+    async fn send<P, T>(&self, event: WorkspaceEvent, payload: P) -> Result<T, String>
+    where
+        P: ToBytes,
+        T: FromBytes,
+    {
+        let bytes = payload.into_bytes().map_err(|e| format!("{:?}", e))?;
+        let request = ModuleRequest::new(event).payload(bytes);
+        let response = EventDispatch::async_send(self.dispatch.clone(), request).await;
+        match response.parse::<T, WorkspaceError>() {
+            Ok(Ok(data)) => Ok(data),
+            Ok(Err(e)) => Err(format!("{:?} failed: {:?}", event, e)),
+            Err(e) => Err(format!("{:?} failed: {:?}", event, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_action_budget() {
+        let limits = ScriptLimits {
+            max_actions_per_minute: 3,
+            ..ScriptLimits::default()
+        };
+        let hooks = ScriptHooks::new(limits);
+        assert_eq!(hooks.take_budget("a", 2), 2);
+        assert_eq!(hooks.take_budget("a", 2), 1);
+        assert_eq!(hooks.take_budget("a", 1), 0);
+        assert_eq!(hooks.take_budget("b", 1), 1);
+    }
+}
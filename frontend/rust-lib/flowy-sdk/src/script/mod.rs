@@ -0,0 +1,24 @@
+mod engine;
+mod hooks;
+
+pub use engine::{ScriptError, ScriptLimits};
+pub use hooks::*;
+
+use flowy_document::module::FlowyDocument;
+use flowy_user::services::user::UserSession;
+use lib_dispatch::prelude::EventDispatch;
+use std::sync::Arc;
+
+// The scripts are kept in the scripts dir of the root, e.g. root/scripts/daily_note.rhai
+pub(crate) fn mk_script_hooks(
+    root: &str,
+    limits: ScriptLimits,
+    dispatch: Arc<EventDispatch>,
+    document: Arc<FlowyDocument>,
+    user_session: Arc<UserSession>,
+) -> Arc<ScriptHooks> {
+    let hooks = Arc::new(ScriptHooks::new(limits));
+    hooks.load_dir(&format!("{}/scripts", root));
+    hooks.listen(dispatch, document, user_session);
+    hooks
+}
@@ -0,0 +1,82 @@
+use crate::{
+    builder::{FlowyWorkspaceTest, UserTest},
+    helper::*,
+    FlowyTest,
+    FlowyTestSDK,
+};
+use backend_service::config::ServerConfig;
+use flowy_user::entities::UserProfile;
+use lib_infra::uuid;
+use std::{collections::HashMap, path::Path};
+
+// The SDKs of the users that use the app at the same time, e.g. the owner and the member of the shared
+// workspace. Each one has its own root dir, database, session, event bus and token refresher, so the users
+// are signed in and out on their own and the events of one user aren't received by the other. The rest is
+// process-wide by design, they are the settings of the app rather than of the user:
+//  - the language of the SEARCH_INDEX, the SDKs of the context are set up with the same one.
+//  - the metrics, they are counted for all the SDKs together.
+//  - the request-id provider of backend_service, it's the same function for all the SDKs and it reads the
+//    id of the event that the request is sent for.
+//  - the kv store, it's stored in the root dir of the last SDK and the keys of the users are prefixed with
+//    the name of the SDK or the user id.
+//
+//  let context = FlowyTestContext::setup(&["owner", "member"]);
+//  let owner = context.sign_up("owner").await;
+//  let workspaces = context.workspace_test("member").event(ReadWorkspaces).async_send().await;
+pub struct FlowyTestContext {
+    server_config: ServerConfig,
+    tests: HashMap<String, FlowyTest>,
+}
+
+impl FlowyTestContext {
+    pub fn setup(names: &[&str]) -> Self { Self::setup_with(names, ServerConfig::default()) }
+
+    pub fn setup_with(names: &[&str], server_config: ServerConfig) -> Self {
+        let mut context = Self {
+            server_config,
+            tests: HashMap::new(),
+        };
+        for name in names {
+            context.add(name);
+        }
+        context
+    }
+
+    // The root dir is unique for each run, so the databases of the previous runs aren't opened again
+    pub fn add(&mut self, name: &str) -> &FlowyTest {
+        let root = Path::new(&root_dir()).join(format!("{}_{}", name, uuid()));
+        let root = root.to_str().unwrap().to_owned();
+        std::fs::create_dir_all(&root).unwrap();
+
+        let test = FlowyTest::setup_in(&root, self.server_config.clone());
+        self.tests.insert(name.to_owned(), test);
+        self.test(name)
+    }
+
+    pub fn test(&self, name: &str) -> &FlowyTest {
+        self.tests
+            .get(name)
+            .unwrap_or_else(|| panic!("The context {} isn't set up", name))
+    }
+
+    pub fn sdk(&self, name: &str) -> FlowyTestSDK { self.test(name).sdk() }
+
+    pub async fn sign_up(&self, name: &str) -> SignUpContext { self.test(name).sign_up().await }
+
+    pub async fn sign_in(&self, name: &str, email: &str, password: &str) -> UserProfile {
+        async_sign_in(self.sdk(name).dispatch(), email, password).await
+    }
+
+    pub fn user_test(&self, name: &str) -> UserTest { UserTest::new(self.sdk(name)) }
+
+    pub fn workspace_test(&self, name: &str) -> FlowyWorkspaceTest { FlowyWorkspaceTest::new(self.sdk(name)) }
+}
+
+// The runtime of each dispatcher can't be dropped within the runtime of the test, so the SDKs are dropped on
+// another thread.
+impl Drop for FlowyTestContext {
+    fn drop(&mut self) {
+        let tests = std::mem::take(&mut self.tests);
+        let _ = std::thread::spawn(move || drop(tests)).join();
+    }
+}
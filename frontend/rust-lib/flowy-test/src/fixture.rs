@@ -0,0 +1,103 @@
+use crate::{workspace::*, FlowyTestSDK};
+use flowy_document_infra::entities::doc::{RevType, Revision};
+use flowy_workspace::entities::{
+    app::App,
+    view::{CreateViewRequest, View, ViewType},
+    workspace::Workspace,
+};
+use lib_ot::core::DeltaBuilder;
+
+// Creates the workspace, its apps and their views in one call. The workspace is opened, so the events
+// that read the current workspace see it.
+//
+//  let tree = WorkspaceFixture::new("Workspace")
+//      .app("App A", &["View A", "View B"])
+//      .app("App B", &[])
+//      .build(&test.sdk)
+//      .await;
+//  let view = tree.view("App A", "View B");
+pub struct WorkspaceFixture {
+    name: String,
+    apps: Vec<(String, Vec<String>)>,
+}
+
+impl WorkspaceFixture {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            apps: vec![],
+        }
+    }
+
+    // The views are created in the order of the names
+    pub fn app(mut self, name: &str, view_names: &[&str]) -> Self {
+        let view_names = view_names.iter().map(|name| name.to_string()).collect();
+        self.apps.push((name.to_owned(), view_names));
+        self
+    }
+
+    pub async fn build(self, sdk: &FlowyTestSDK) -> WorkspaceTree {
+        let workspace = create_workspace(sdk, &self.name, "").await;
+        open_workspace(sdk, &workspace.id).await;
+
+        let mut apps = vec![];
+        for (app_name, view_names) in self.apps {
+            let mut app = create_app(sdk, &app_name, "", &workspace.id).await;
+            for view_name in view_names {
+                let request = CreateViewRequest {
+                    belong_to_id: app.id.clone(),
+                    name: view_name,
+                    desc: "".to_owned(),
+                    thumbnail: None,
+                    view_type: ViewType::Doc,
+                };
+                let view = create_view_with_request(sdk, request).await;
+                app.belongings.items.push(view);
+            }
+            apps.push(app);
+        }
+
+        WorkspaceTree {
+            sdk: sdk.clone(),
+            workspace,
+            apps,
+        }
+    }
+}
+
+// The apps keep the views that were created in them as their belongings
+pub struct WorkspaceTree {
+    pub sdk: FlowyTestSDK,
+    pub workspace: Workspace,
+    pub apps: Vec<App>,
+}
+
+impl WorkspaceTree {
+    pub fn app(&self, name: &str) -> &App {
+        self.apps
+            .iter()
+            .find(|app| app.name == name)
+            .unwrap_or_else(|| panic!("The app {} isn't in the fixture", name))
+    }
+
+    pub fn view(&self, app_name: &str, view_name: &str) -> &View {
+        self.app(app_name)
+            .belongings
+            .iter()
+            .find(|view| view.name == view_name)
+            .unwrap_or_else(|| panic!("The view {} isn't in the app {}", view_name, app_name))
+    }
+}
+
+// Types one character per revision before the trailing newline, like the revisions of a long editing session.
+// The document of the revisions is count characters long, including the newline.
+pub fn typing_revisions(doc_id: &str, count: usize) -> Vec<Revision> {
+    let initial = DeltaBuilder::new().insert("\n").build();
+    let mut revisions = vec![Revision::new(0, 1, initial.to_bytes(), doc_id, RevType::Local)];
+    for i in 1..count {
+        let delta = DeltaBuilder::new().retain(i - 1).insert("a").retain(1).build();
+        let rev_id = i as i64 + 1;
+        revisions.push(Revision::new(rev_id - 1, rev_id, delta.to_bytes(), doc_id, RevType::Local));
+    }
+    revisions
+}
@@ -61,11 +61,9 @@ pub(crate) fn create_default_workspace_if_need(dispatch: Arc<EventDispatch>, use
         .map_err(|e| UserError::internal().context(e))?;
 
     let workspace = result.map_err(|e| UserError::internal().context(e))?;
-    let query: Bytes = QueryWorkspaceRequest {
-        workspace_id: Some(workspace.id.clone()),
-    }
-    .into_bytes()
-    .unwrap();
+    let query: Bytes = QueryWorkspaceRequest::new(Some(workspace.id.clone()))
+        .into_bytes()
+        .unwrap();
 
     let request = ModuleRequest::new(OpenWorkspace).payload(query);
     let _result = EventDispatch::sync_send(dispatch.clone(), request)
@@ -121,6 +119,23 @@ pub async fn async_sign_up(dispatch: Arc<EventDispatch>) -> SignUpContext {
     SignUpContext { user_profile, password }
 }
 
+pub async fn async_sign_in(dispatch: Arc<EventDispatch>, email: &str, password: &str) -> UserProfile {
+    let payload = SignInRequest {
+        email: email.to_owned(),
+        password: password.to_owned(),
+        name: "app flowy".to_owned(),
+    }
+    .into_bytes()
+    .unwrap();
+
+    let request = ModuleRequest::new(SignIn).payload(payload);
+    EventDispatch::async_send(dispatch, request)
+        .await
+        .parse::<UserProfile, UserError>()
+        .unwrap()
+        .unwrap()
+}
+
 #[allow(dead_code)]
 fn sign_in(dispatch: Arc<EventDispatch>) -> UserProfile {
     let payload = SignInRequest {
@@ -1,5 +1,9 @@
 pub mod builder;
+pub mod context;
+pub mod fixture;
 mod helper;
+pub mod migration;
+pub mod notification;
 pub mod workspace;
 
 use crate::helper::*;
@@ -9,7 +13,7 @@ use flowy_user::entities::UserProfile;
 use lib_infra::uuid;
 
 pub mod prelude {
-    pub use crate::{builder::*, helper::*, *};
+    pub use crate::{builder::*, context::*, fixture::*, helper::*, notification::*, *};
     pub use lib_dispatch::prelude::*;
 }
 
@@ -38,11 +42,21 @@ impl FlowyTest {
         context.user_profile
     }
 
-    pub fn setup_with(server_config: ServerConfig) -> Self {
-        let config = FlowySDKConfig::new(&root_dir(), server_config, &uuid().to_string()).log_filter("debug");
+    pub fn setup_with(server_config: ServerConfig) -> Self { Self::setup_in(&root_dir(), server_config) }
+
+    pub fn setup_in(root: &str, server_config: ServerConfig) -> Self {
+        let config = FlowySDKConfig::new(root, server_config, &uuid().to_string()).log_filter("debug");
         let sdk = FlowySDK::new(config);
         Self { sdk }
     }
 
     pub fn sdk(&self) -> FlowyTestSDK { self.sdk.clone() }
+
+    // Starts another SDK with the same root dir and name, like the app that is started again. The signed in
+    // user is restored from the session cache. This SDK must be kept alive, its runtime can't be dropped
+    // within the test.
+    pub fn restart(&self) -> Self {
+        let sdk = FlowySDK::new(self.sdk.config().clone());
+        Self { sdk }
+    }
 }
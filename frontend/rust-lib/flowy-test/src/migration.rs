@@ -0,0 +1,47 @@
+use crate::helper::root_dir;
+use flowy_database::{
+    connection::SimpleConnection,
+    migrations,
+    sql_query,
+    sql_types::Text,
+    Connection,
+    MigrationReport,
+    RunQueryDsl,
+    SqliteConnection,
+    DB_NAME,
+};
+use lib_infra::uuid;
+
+const CREATE_DIESEL_MIGRATION_TABLE: &str = "CREATE TABLE IF NOT EXISTS __diesel_schema_migrations (
+    version VARCHAR(50) PRIMARY KEY NOT NULL,
+    run_on TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+)";
+
+// Creates the database that the app created before the migration runner, the first `count` migrations
+// are applied by diesel_migrations. Returns the dir of the database.
+pub fn create_legacy_db(count: usize) -> String {
+    let dir = format!("{}/legacy_db/{}", root_dir(), uuid());
+    std::fs::create_dir_all(&dir).unwrap();
+    let conn = legacy_connection(&dir);
+    let _ = conn.batch_execute(CREATE_DIESEL_MIGRATION_TABLE).unwrap();
+    for migration in migrations().into_iter().take(count) {
+        let _ = conn.batch_execute(migration.up_sql).unwrap();
+        record_legacy_version(&dir, &migration.version);
+    }
+    dir
+}
+
+// Records the version as applied by diesel_migrations, the version doesn't need to be known
+pub fn record_legacy_version(dir: &str, version: &str) {
+    let conn = legacy_connection(dir);
+    let _ = sql_query("INSERT INTO __diesel_schema_migrations (version) VALUES (?)")
+        .bind::<Text, _>(version)
+        .execute(&conn)
+        .unwrap();
+}
+
+pub fn validate_migrations(dir: &str) -> MigrationReport { flowy_database::validate(dir).unwrap() }
+
+fn legacy_connection(dir: &str) -> SqliteConnection {
+    SqliteConnection::establish(&format!("{}/{}", dir, DB_NAME)).unwrap()
+}
@@ -0,0 +1,90 @@
+use bytes::Bytes;
+use dart_notify::{
+    dart::{NotificationRegistry, NotificationSink, SubscriptionFilter},
+    entities::SubscribeObject,
+};
+use lib_dispatch::prelude::FromBytes;
+use std::{
+    convert::TryFrom,
+    sync::{Arc, Mutex},
+};
+
+// Records the notifications that are sent to dart, so the tests can check the ones that the events emitted.
+// The registry is shared by all the sdks of the process and the tests that run in parallel, so the
+// notifications are matched by the id of the object, e.g. the app that was created by the test.
+//
+//  let recorder = NotificationRecorder::with_id("Workspace", &app.id);
+//  update_app(&sdk, request).await;
+//  let app: App = recorder.assert_notified(WorkspaceNotification::AppUpdated as i32);
+pub struct NotificationRecorder {
+    subscription_id: i64,
+    sink: Arc<RecorderSink>,
+}
+
+impl NotificationRecorder {
+    // Records all the notifications of the source, e.g. "Workspace", "Doc" or "User"
+    pub fn new(source: &str) -> Self { Self::subscribe(SubscriptionFilter::new(source)) }
+
+    pub fn with_id(source: &str, id: &str) -> Self { Self::subscribe(SubscriptionFilter::new(source).id(id)) }
+
+    fn subscribe(filter: SubscriptionFilter) -> Self {
+        let sink = Arc::new(RecorderSink::default());
+        let subscription_id = NotificationRegistry::subscribe(filter, sink.clone());
+        Self { subscription_id, sink }
+    }
+
+    // The notifications in the order they were sent
+    pub fn notifications(&self) -> Vec<SubscribeObject> { self.sink.subjects.lock().unwrap().clone() }
+
+    pub fn find(&self, ty: i32) -> Vec<SubscribeObject> {
+        self.notifications()
+            .into_iter()
+            .filter(|subject| subject.ty == ty)
+            .collect()
+    }
+
+    // The payload of the latest notification of the type
+    pub fn payload<P: FromBytes>(&self, ty: i32) -> Option<P> {
+        let subject = self.find(ty).pop()?;
+        let bytes = subject.payload?;
+        match P::parse_from_bytes(Bytes::from(bytes)) {
+            Ok(payload) => Some(payload),
+            Err(e) => panic!("Parse the payload of the notification {} failed: {:?}", ty, e),
+        }
+    }
+
+    pub fn assert_notified<P: FromBytes>(&self, ty: i32) -> P {
+        match self.payload(ty) {
+            Some(payload) => payload,
+            None => panic!(
+                "The notification {} wasn't sent, the recorded ones are {:?}",
+                ty,
+                self.notifications()
+                    .iter()
+                    .map(|subject| subject.ty)
+                    .collect::<Vec<i32>>()
+            ),
+        }
+    }
+
+    pub fn clear(&self) { self.sink.subjects.lock().unwrap().clear(); }
+}
+
+impl Drop for NotificationRecorder {
+    fn drop(&mut self) { let _ = NotificationRegistry::unsubscribe(self.subscription_id); }
+}
+
+#[derive(Default)]
+struct RecorderSink {
+    subjects: Mutex<Vec<SubscribeObject>>,
+}
+
+impl NotificationSink for RecorderSink {
+    fn post(&self, bytes: Vec<u8>) -> bool {
+        match SubscribeObject::try_from(Bytes::from(bytes)) {
+            Ok(subject) => self.subjects.lock().unwrap().push(subject),
+            Err(e) => log::error!("Parse the recorded notification failed: {:?}", e),
+        }
+        true
+    }
+}
@@ -1,8 +1,17 @@
 use crate::prelude::*;
-use flowy_document_infra::entities::doc::Doc;
+use flowy_document_infra::entities::doc::{
+    AttachmentFile,
+    AttachmentIdentifier,
+    AttachmentUploadProgress,
+    DocDelta,
+    DocImportPreview,
+    DocReadPosition,
+    RepeatedDocSnapshot,
+};
 use flowy_workspace::{
     entities::{
         app::*,
+        share::*,
         trash::{RepeatedTrash, TrashIdentifier},
         view::*,
         workspace::*,
@@ -121,10 +130,8 @@ pub async fn create_workspace(sdk: &FlowyTestSDK, name: &str, desc: &str) -> Wor
     workspace
 }
 
-async fn open_workspace(sdk: &FlowyTestSDK, workspace_id: &str) {
-    let request = QueryWorkspaceRequest {
-        workspace_id: Some(workspace_id.to_owned()),
-    };
+pub(crate) async fn open_workspace(sdk: &FlowyTestSDK, workspace_id: &str) {
+    let request = QueryWorkspaceRequest::new(Some(workspace_id.to_owned()));
     let _ = FlowyWorkspaceTest::new(sdk.clone())
         .event(OpenWorkspace)
         .request(request)
@@ -155,6 +162,33 @@ pub async fn read_workspace(sdk: &FlowyTestSDK, request: QueryWorkspaceRequest)
     workspaces
 }
 
+pub async fn archive_workspace(sdk: &FlowyTestSDK, workspace_id: &str, archived: bool) {
+    let request = ArchiveWorkspaceRequest {
+        workspace_id: workspace_id.to_owned(),
+        archived,
+    };
+    let _ = FlowyWorkspaceTest::new(sdk.clone())
+        .event(ArchiveWorkspace)
+        .request(request)
+        .async_send()
+        .await
+        .assert_success();
+}
+
+pub async fn search_workspace(sdk: &FlowyTestSDK, workspace_id: &str, query: &str) -> RepeatedSearchResult {
+    let request = SearchWorkspaceRequest {
+        workspace_id: workspace_id.to_owned(),
+        query: query.to_owned(),
+        limit: 0,
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(SearchWorkspace)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedSearchResult>()
+}
+
 pub async fn create_app(sdk: &FlowyTestSDK, name: &str, desc: &str, workspace_id: &str) -> App {
     let create_app_request = CreateAppRequest {
         workspace_id: workspace_id.to_owned(),
@@ -173,9 +207,7 @@ pub async fn create_app(sdk: &FlowyTestSDK, name: &str, desc: &str, workspace_id
 }
 
 pub async fn delete_app(sdk: &FlowyTestSDK, app_id: &str) {
-    let delete_app_request = AppIdentifier {
-        app_id: app_id.to_string(),
-    };
+    let delete_app_request = AppIdentifier::new(app_id);
 
     FlowyWorkspaceTest::new(sdk.clone())
         .event(DeleteApp)
@@ -192,6 +224,18 @@ pub async fn update_app(sdk: &FlowyTestSDK, request: UpdateAppRequest) {
         .await;
 }
 
+pub async fn move_app(sdk: &FlowyTestSDK, app_id: &str, index: i32) {
+    let request = MoveAppRequest {
+        app_id: app_id.to_owned(),
+        index,
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(MoveApp)
+        .request(request)
+        .async_send()
+        .await;
+}
+
 pub async fn read_app(sdk: &FlowyTestSDK, request: QueryAppRequest) -> App {
     let app = FlowyWorkspaceTest::new(sdk.clone())
         .event(ReadApp)
@@ -233,6 +277,43 @@ pub async fn update_view(sdk: &FlowyTestSDK, request: UpdateViewRequest) {
         .await;
 }
 
+pub async fn move_view(sdk: &FlowyTestSDK, request: MoveViewRequest) {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(MoveView)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+pub async fn toggle_favorite(sdk: &FlowyTestSDK, view_id: &str) -> ViewFavoriteState {
+    let request = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ToggleFavorite)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ViewFavoriteState>()
+}
+
+pub async fn read_favorites(sdk: &FlowyTestSDK) -> RepeatedFavorite {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(QueryFavorites)
+        .async_send()
+        .await
+        .parse::<RepeatedFavorite>()
+}
+
+pub async fn read_recent_views(sdk: &FlowyTestSDK, limit: i64) -> RepeatedRecentView {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(QueryRecentViews)
+        .request(QueryRecentViewsRequest { limit })
+        .async_send()
+        .await
+        .parse::<RepeatedRecentView>()
+}
+
 pub async fn read_view(sdk: &FlowyTestSDK, request: QueryViewRequest) -> View {
     FlowyWorkspaceTest::new(sdk.clone())
         .event(ReadView)
@@ -266,11 +347,193 @@ pub async fn putback_trash(sdk: &FlowyTestSDK, id: TrashIdentifier) {
         .await;
 }
 
-pub async fn open_view(sdk: &FlowyTestSDK, request: QueryViewRequest) -> Doc {
+pub async fn read_current_workspace(sdk: &FlowyTestSDK) -> CurrentWorkspaceSetting {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ReadCurWorkspace)
+        .async_send()
+        .await
+        .parse::<CurrentWorkspaceSetting>()
+}
+
+pub async fn query_workspaces(sdk: &FlowyTestSDK) -> RepeatedWorkspaceItem {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(QueryWorkspaces)
+        .async_send()
+        .await
+        .parse::<RepeatedWorkspaceItem>()
+}
+
+pub async fn switch_workspace(sdk: &FlowyTestSDK, workspace_id: &str) -> CurrentWorkspaceSetting {
+    let request = QueryWorkspaceRequest::new(Some(workspace_id.to_owned()));
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(SwitchWorkspace)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<CurrentWorkspaceSetting>()
+}
+
+pub async fn open_view(sdk: &FlowyTestSDK, request: QueryViewRequest) -> DocDelta {
     FlowyWorkspaceTest::new(sdk.clone())
         .event(OpenView)
         .request(request)
         .async_send()
         .await
-        .parse::<Doc>()
+        .parse::<DocDelta>()
+}
+
+pub async fn update_read_position(sdk: &FlowyTestSDK, doc_id: &str, offset: i64) {
+    let request = DocReadPosition {
+        doc_id: doc_id.to_owned(),
+        offset,
+        modified_time: 0,
+    };
+    let _ = FlowyWorkspaceTest::new(sdk.clone())
+        .event(UpdateReadPosition)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+pub async fn import_markdown(sdk: &FlowyTestSDK, doc_id: &str, markdown: &str) -> DocDelta {
+    let request = ImportRequest {
+        doc_id: doc_id.to_owned(),
+        data: markdown.to_owned(),
+        import_type: ImportType::Markdown,
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ImportDocument)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<DocDelta>()
+}
+
+pub async fn preview_import_markdown(sdk: &FlowyTestSDK, doc_id: &str, markdown: &str) -> DocImportPreview {
+    let request = ImportRequest {
+        doc_id: doc_id.to_owned(),
+        data: markdown.to_owned(),
+        import_type: ImportType::Markdown,
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(PreviewImport)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<DocImportPreview>()
+}
+
+pub async fn export_markdown(sdk: &FlowyTestSDK, doc_id: &str) -> ExportData {
+    let request = ExportRequest {
+        doc_id: doc_id.to_owned(),
+        export_type: ExportType::Markdown,
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ExportDocument)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ExportData>()
+}
+
+pub async fn export_app(sdk: &FlowyTestSDK, app_id: &str, export_type: AppExportType, path: &str) -> ExportAppProgress {
+    let request = ExportAppRequest {
+        app_id: app_id.to_owned(),
+        export_type,
+        path: path.to_owned(),
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ExportApp)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ExportAppProgress>()
+}
+
+pub async fn import_folder(sdk: &FlowyTestSDK, workspace_id: &str, path: &str) -> ImportFolderProgress {
+    let request = ImportFolderRequest {
+        workspace_id: workspace_id.to_owned(),
+        path: path.to_owned(),
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ImportFolder)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ImportFolderProgress>()
+}
+
+pub async fn upload_attachment(sdk: &FlowyTestSDK, doc_id: &str, path: &str) -> AttachmentUploadProgress {
+    let request = UploadAttachmentRequest {
+        doc_id: doc_id.to_owned(),
+        path: path.to_owned(),
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(UploadAttachment)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<AttachmentUploadProgress>()
+}
+
+pub async fn read_attachment(sdk: &FlowyTestSDK, attachment_id: &str) -> AttachmentFile {
+    let request = AttachmentIdentifier::from(attachment_id);
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ReadAttachment)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<AttachmentFile>()
+}
+
+pub async fn query_doc_history(sdk: &FlowyTestSDK, view_id: &str) -> RepeatedDocSnapshot {
+    let request = QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(QueryDocHistory)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedDocSnapshot>()
+}
+
+pub async fn read_rename_merge_policy(sdk: &FlowyTestSDK) -> RenameMergePolicySetting {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(ReadRenameMergePolicy)
+        .async_send()
+        .await
+        .parse::<RenameMergePolicySetting>()
+}
+
+pub async fn update_rename_merge_policy(sdk: &FlowyTestSDK, policy: RenameMergePolicy) {
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(UpdateRenameMergePolicy)
+        .request(RenameMergePolicySetting { policy })
+        .async_send()
+        .await;
+}
+
+pub async fn preview_account_merge(sdk: &FlowyTestSDK, source_user_id: &str) -> AccountMergeReport {
+    let request = MergeAccountRequest {
+        source_user_id: source_user_id.to_owned(),
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(PreviewAccountMerge)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<AccountMergeReport>()
+}
+
+pub async fn merge_account(sdk: &FlowyTestSDK, source_user_id: &str) -> AccountMergeReport {
+    let request = MergeAccountRequest {
+        source_user_id: source_user_id.to_owned(),
+    };
+    FlowyWorkspaceTest::new(sdk.clone())
+        .event(MergeAccount)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<AccountMergeReport>()
 }
@@ -104,7 +104,11 @@ fn server_error_to_user_error(error: backend_service::errors::ServerError) -> (E
     let code = match error.code {
         ServerErrorCode::UserUnauthorized => ErrorCode::UserUnauthorized,
         ServerErrorCode::PasswordNotMatch => ErrorCode::PasswordNotMatch,
+        ServerErrorCode::PasswordResetTokenInvalid => ErrorCode::PasswordResetTokenInvalid,
+        ServerErrorCode::EmailVerifyTokenInvalid => ErrorCode::EmailVerifyTokenInvalid,
         ServerErrorCode::RecordNotFound => ErrorCode::UserNotExist,
+        ServerErrorCode::TooManyRequests => ErrorCode::TooManyRequests,
+        ServerErrorCode::PayloadOverflow => ErrorCode::PayloadTooLarge,
         ServerErrorCode::ConnectRefused | ServerErrorCode::ConnectTimeout | ServerErrorCode::ConnectClose => {
             ErrorCode::ServerError
         },
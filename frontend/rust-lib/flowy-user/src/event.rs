@@ -3,25 +3,53 @@ use strum_macros::Display;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
 #[event_err = "UserError"]
+#[event_scope = "signed_in"]
 pub enum UserEvent {
-    #[event()]
-    InitUser       = 0,
+    #[event(public)]
+    InitUser             = 0,
 
-    #[event(input = "SignInRequest", output = "UserProfile")]
-    SignIn         = 1,
+    #[event(input = "SignInRequest", output = "UserProfile", public)]
+    SignIn               = 1,
 
-    #[event(input = "SignUpRequest", output = "UserProfile")]
-    SignUp         = 2,
+    #[event(input = "SignUpRequest", output = "UserProfile", public)]
+    SignUp               = 2,
 
     #[event(passthrough)]
-    SignOut        = 3,
+    SignOut              = 3,
 
     #[event(input = "UpdateUserRequest")]
-    UpdateUser     = 4,
+    UpdateUser           = 4,
 
     #[event(output = "UserProfile")]
-    GetUserProfile = 5,
+    GetUserProfile       = 5,
 
-    #[event(output = "UserProfile")]
-    CheckUser      = 6,
+    #[event(output = "UserProfile", public)]
+    CheckUser            = 6,
+
+    #[event(output = "UserSettingsProfile")]
+    ExportSettings       = 7,
+
+    #[event(input = "UserSettingsProfile")]
+    ImportSettings       = 8,
+
+    #[event(input = "SignInWithProviderRequest", output = "UserProfile", public)]
+    SignInWithProvider   = 9,
+
+    #[event(input = "RequestPasswordResetRequest", public)]
+    RequestPasswordReset = 10,
+
+    #[event(input = "ConfirmPasswordResetRequest", public)]
+    ConfirmPasswordReset = 11,
+
+    #[event(input = "SetLogLevelRequest", public)]
+    SetLogLevel          = 12,
+
+    #[event(output = "MetricsSnapshot", public)]
+    QueryMetricsSnapshot = 13,
+
+    #[event()]
+    RequestEmailVerify   = 14,
+
+    #[event(input = "ConfirmEmailVerifyRequest", public)]
+    ConfirmEmailVerify   = 15,
 }
@@ -10,6 +10,52 @@ pub async fn sign_in(data: Data<SignInRequest>, session: Unit<Arc<UserSession>>)
     data_result(user_profile)
 }
 
+#[tracing::instrument(name = "sign_in_with_provider", skip(data, session), fields(provider = ?data.provider), err)]
+pub async fn sign_in_with_provider(
+    data: Data<SignInWithProviderRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> DataResult<UserProfile, UserError> {
+    let params: SignInWithProviderParams = data.into_inner().try_into()?;
+    let user_profile = session.sign_in_with_provider(params).await?;
+    data_result(user_profile)
+}
+
+#[tracing::instrument(name = "request_password_reset", skip(data, session), fields(email = %data.email), err)]
+pub async fn request_password_reset(
+    data: Data<RequestPasswordResetRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let params: RequestPasswordResetParams = data.into_inner().try_into()?;
+    let _ = session.request_password_reset(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "confirm_password_reset", skip(data, session), err)]
+pub async fn confirm_password_reset(
+    data: Data<ConfirmPasswordResetRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let params: ConfirmPasswordResetParams = data.into_inner().try_into()?;
+    let _ = session.confirm_password_reset(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "request_email_verify", skip(session), err)]
+pub async fn request_email_verify(session: Unit<Arc<UserSession>>) -> Result<(), UserError> {
+    let _ = session.request_email_verify().await?;
+    Ok(())
+}
+
+#[tracing::instrument(name = "confirm_email_verify", skip(data, session), err)]
+pub async fn confirm_email_verify(
+    data: Data<ConfirmEmailVerifyRequest>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let params: ConfirmEmailVerifyParams = data.into_inner().try_into()?;
+    let _ = session.confirm_email_verify(params).await?;
+    Ok(())
+}
+
 #[tracing::instrument(
     name = "sign_up",
     skip(data, session),
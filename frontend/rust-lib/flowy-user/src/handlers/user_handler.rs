@@ -36,3 +36,69 @@ pub async fn update_user_handler(
     session.update_user(params).await?;
     Ok(())
 }
+
+#[tracing::instrument(skip(session))]
+pub async fn export_settings_handler(session: Unit<Arc<UserSession>>) -> DataResult<UserSettingsProfile, UserError> {
+    let profile = session.export_settings()?;
+    data_result(profile)
+}
+
+#[tracing::instrument(skip(data, session))]
+pub async fn import_settings_handler(
+    data: Data<UserSettingsProfile>,
+    session: Unit<Arc<UserSession>>,
+) -> Result<(), UserError> {
+    let _ = session.import_settings(data.into_inner())?;
+    Ok(())
+}
+
+// Support asks the user to turn on the debug logs when the issue can't be reproduced, and back to info after
+#[tracing::instrument(skip(data))]
+pub async fn set_log_level_handler(data: Data<SetLogLevelRequest>) -> Result<(), UserError> {
+    let level = data.into_inner().level;
+    let _ = lib_log::set_log_level(level.as_str()).map_err(|e| UserError::internal().context(e))?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(pool))]
+pub async fn query_metrics_snapshot_handler(pool: Unit<BlockingPool>) -> DataResult<MetricsSnapshot, UserError> {
+    let pool_metrics = pool.metrics();
+    let blocking_pool = BlockingPoolMetric {
+        size: pool_metrics.size as i64,
+        waiting: pool_metrics.waiting as i64,
+        running: pool_metrics.running as i64,
+        completed: pool_metrics.completed as i64,
+        starved: pool_metrics.starved as i64,
+        max_wait_ms: pool_metrics.max_wait.as_millis() as i64,
+    };
+    let snapshot = lib_infra::metrics::metrics_snapshot();
+    let counters = snapshot
+        .counters
+        .iter()
+        .map(|counter| CounterMetric {
+            name: counter.name.clone(),
+            labels: counter.labels.clone(),
+            value: counter.value as i64,
+        })
+        .collect();
+    let latencies = snapshot
+        .latencies
+        .iter()
+        .map(|latency| LatencyMetric {
+            name: latency.name.clone(),
+            labels: latency.labels.clone(),
+            count: latency.count as i64,
+            sum_ms: latency.sum.as_millis() as i64,
+            p50_ms: latency.percentile(0.5).as_millis() as i64,
+            p95_ms: latency.percentile(0.95).as_millis() as i64,
+            max_ms: latency.max.as_millis() as i64,
+        })
+        .collect();
+
+    data_result(MetricsSnapshot {
+        counters,
+        latencies,
+        prometheus: snapshot.to_prometheus(),
+        blocking_pool,
+    })
+}
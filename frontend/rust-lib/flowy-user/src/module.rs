@@ -9,9 +9,19 @@ pub fn create(user_session: Arc<UserSession>) -> Module {
         .data(user_session)
         .event(UserEvent::SignIn, sign_in)
         .event(UserEvent::SignUp, sign_up)
+        .event(UserEvent::SignInWithProvider, sign_in_with_provider)
+        .event(UserEvent::RequestPasswordReset, request_password_reset)
+        .event(UserEvent::ConfirmPasswordReset, confirm_password_reset)
+        .event(UserEvent::RequestEmailVerify, request_email_verify)
+        .event(UserEvent::ConfirmEmailVerify, confirm_email_verify)
         .event(UserEvent::InitUser, init_user_handler)
         .event(UserEvent::GetUserProfile, get_user_profile_handler)
         .event(UserEvent::SignOut, sign_out)
         .event(UserEvent::UpdateUser, update_user_handler)
         .event(UserEvent::CheckUser, check_user_handler)
+        .event(UserEvent::ExportSettings, export_settings_handler)
+        .event(UserEvent::ImportSettings, import_settings_handler)
+        .event(UserEvent::SetLogLevel, set_log_level_handler)
+        .event(UserEvent::QueryMetricsSnapshot, query_metrics_snapshot_handler)
+        .guard_table(UserEvent::guard_table())
 }
@@ -6,10 +6,11 @@ const OBSERVABLE_CATEGORY: &'static str = "User";
 
 #[derive(ProtoBuf_Enum, Debug)]
 pub(crate) enum UserNotification {
-    Unknown            = 0,
-    UserAuthChanged    = 1,
-    UserProfileUpdated = 2,
-    UserUnauthorized   = 3,
+    Unknown             = 0,
+    UserAuthChanged     = 1,
+    UserProfileUpdated  = 2,
+    UserUnauthorized    = 3,
+    NetworkStateChanged = 4,
 }
 
 impl std::default::Default for UserNotification {
@@ -32,6 +32,15 @@ pub enum UserEvent {
     UpdateUser = 4,
     GetUserProfile = 5,
     CheckUser = 6,
+    ExportSettings = 7,
+    ImportSettings = 8,
+    SignInWithProvider = 9,
+    RequestPasswordReset = 10,
+    ConfirmPasswordReset = 11,
+    SetLogLevel = 12,
+    QueryMetricsSnapshot = 13,
+    RequestEmailVerify = 14,
+    ConfirmEmailVerify = 15,
 }
 
 impl ::protobuf::ProtobufEnum for UserEvent {
@@ -48,6 +57,15 @@ impl ::protobuf::ProtobufEnum for UserEvent {
             4 => ::std::option::Option::Some(UserEvent::UpdateUser),
             5 => ::std::option::Option::Some(UserEvent::GetUserProfile),
             6 => ::std::option::Option::Some(UserEvent::CheckUser),
+            7 => ::std::option::Option::Some(UserEvent::ExportSettings),
+            8 => ::std::option::Option::Some(UserEvent::ImportSettings),
+            9 => ::std::option::Option::Some(UserEvent::SignInWithProvider),
+            10 => ::std::option::Option::Some(UserEvent::RequestPasswordReset),
+            11 => ::std::option::Option::Some(UserEvent::ConfirmPasswordReset),
+            12 => ::std::option::Option::Some(UserEvent::SetLogLevel),
+            13 => ::std::option::Option::Some(UserEvent::QueryMetricsSnapshot),
+            14 => ::std::option::Option::Some(UserEvent::RequestEmailVerify),
+            15 => ::std::option::Option::Some(UserEvent::ConfirmEmailVerify),
             _ => ::std::option::Option::None
         }
     }
@@ -61,6 +79,15 @@ impl ::protobuf::ProtobufEnum for UserEvent {
             UserEvent::UpdateUser,
             UserEvent::GetUserProfile,
             UserEvent::CheckUser,
+            UserEvent::ExportSettings,
+            UserEvent::ImportSettings,
+            UserEvent::SignInWithProvider,
+            UserEvent::RequestPasswordReset,
+            UserEvent::ConfirmPasswordReset,
+            UserEvent::SetLogLevel,
+            UserEvent::QueryMetricsSnapshot,
+            UserEvent::RequestEmailVerify,
+            UserEvent::ConfirmEmailVerify,
         ];
         values
     }
@@ -89,26 +116,49 @@ impl ::protobuf::reflect::ProtobufValue for UserEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*q\n\tUserEvent\x12\x0c\n\x08InitUser\x10\0\x12\n\n\x06\
-    SignIn\x10\x01\x12\n\n\x06SignUp\x10\x02\x12\x0b\n\x07SignOut\x10\x03\
-    \x12\x0e\n\nUpdateUser\x10\x04\x12\x12\n\x0eGetUserProfile\x10\x05\x12\r\
-    \n\tCheckUser\x10\x06J\xc9\x02\n\x06\x12\x04\0\0\n\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\n\x01\n\n\n\x03\x05\0\x01\
-    \x12\x03\x02\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\
-    \x03\x03\x0f\x10\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\x01\x01\x12\x03\x04\x04\n\n\x0c\n\x05\x05\0\x02\x01\x02\
-    \x12\x03\x04\r\x0e\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\x02\x01\x12\x03\x05\x04\n\n\x0c\n\x05\x05\0\x02\x02\x02\
-    \x12\x03\x05\r\x0e\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x04\x10\n\x0c\n\
-    \x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x0b\n\x0c\n\x05\x05\0\x02\x03\x02\
-    \x12\x03\x06\x0e\x0f\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x04\x13\n\x0c\
-    \n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x0e\n\x0c\n\x05\x05\0\x02\x04\
-    \x02\x12\x03\x07\x11\x12\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x17\n\
-    \x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x12\n\x0c\n\x05\x05\0\x02\
-    \x05\x02\x12\x03\x08\x15\x16\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x12\
-    \n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\r\n\x0c\n\x05\x05\0\x02\x06\
-    \x02\x12\x03\t\x10\x11b\x06proto3\
+    \n\x0bevent.proto*\xc0\x02\n\tUserEvent\x12\x0c\n\x08InitUser\x10\0\x12\
+    \n\n\x06SignIn\x10\x01\x12\n\n\x06SignUp\x10\x02\x12\x0b\n\x07SignOut\
+    \x10\x03\x12\x0e\n\nUpdateUser\x10\x04\x12\x12\n\x0eGetUserProfile\x10\
+    \x05\x12\r\n\tCheckUser\x10\x06\x12\x12\n\x0eExportSettings\x10\x07\x12\
+    \x12\n\x0eImportSettings\x10\x08\x12\x16\n\x12SignInWithProvider\x10\t\
+    \x12\x18\n\x14RequestPasswordReset\x10\n\x12\x18\n\x14ConfirmPasswordRes\
+    et\x10\x0b\x12\x0f\n\x0bSetLogLevel\x10\x0c\x12\x18\n\x14QueryMetricsSna\
+    pshot\x10\r\x12\x16\n\x12RequestEmailVerify\x10\x0e\x12\x16\n\x12Confirm\
+    EmailVerify\x10\x0fJ\xba\x05\n\x06\x12\x04\0\0\x13\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x13\x01\n\n\n\x03\x05\0\
+    \x01\x12\x03\x02\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x11\n\
+    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0c\n\x0c\n\x05\x05\0\x02\0\
+    \x02\x12\x03\x03\x0f\x10\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x0f\n\
+    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\n\n\x0c\n\x05\x05\0\x02\x01\
+    \x02\x12\x03\x04\r\x0e\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\x0f\n\
+    \x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\n\n\x0c\n\x05\x05\0\x02\x02\
+    \x02\x12\x03\x05\r\x0e\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x04\x10\n\
+    \x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x0b\n\x0c\n\x05\x05\0\x02\
+    \x03\x02\x12\x03\x06\x0e\x0f\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x04\
+    \x13\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x0e\n\x0c\n\x05\x05\0\
+    \x02\x04\x02\x12\x03\x07\x11\x12\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x08\
+    \x04\x17\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x12\n\x0c\n\x05\
+    \x05\0\x02\x05\x02\x12\x03\x08\x15\x16\n\x0b\n\x04\x05\0\x02\x06\x12\x03\
+    \t\x04\x12\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\r\n\x0c\n\x05\x05\
+    \0\x02\x06\x02\x12\x03\t\x10\x11\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\
+    \x17\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\x12\n\x0c\n\x05\x05\0\
+    \x02\x07\x02\x12\x03\n\x15\x16\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\
+    \x17\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x12\n\x0c\n\x05\x05\0\
+    \x02\x08\x02\x12\x03\x0b\x15\x16\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\
+    \x1b\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\x16\n\x0c\n\x05\x05\0\
+    \x02\t\x02\x12\x03\x0c\x19\x1a\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x1e\
+    \n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x18\n\x0c\n\x05\x05\0\x02\n\
+    \x02\x12\x03\r\x1b\x1d\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x1e\n\
+    \x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x18\n\x0c\n\x05\x05\0\x02\
+    \x0b\x02\x12\x03\x0e\x1b\x1d\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\
+    \x15\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x0f\n\x0c\n\x05\x05\0\
+    \x02\x0c\x02\x12\x03\x0f\x12\x14\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\
+    \x1e\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x18\n\x0c\n\x05\x05\0\
+    \x02\r\x02\x12\x03\x10\x1b\x1d\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\
+    \x1c\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x16\n\x0c\n\x05\x05\0\
+    \x02\x0e\x02\x12\x03\x11\x19\x1b\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\
+    \x04\x1c\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x16\n\x0c\n\x05\
+    \x05\0\x02\x0f\x02\x12\x03\x12\x19\x1bb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -29,6 +29,7 @@ pub enum UserNotification {
     UserAuthChanged = 1,
     UserProfileUpdated = 2,
     UserUnauthorized = 3,
+    NetworkStateChanged = 4,
 }
 
 impl ::protobuf::ProtobufEnum for UserNotification {
@@ -42,6 +43,7 @@ impl ::protobuf::ProtobufEnum for UserNotification {
             1 => ::std::option::Option::Some(UserNotification::UserAuthChanged),
             2 => ::std::option::Option::Some(UserNotification::UserProfileUpdated),
             3 => ::std::option::Option::Some(UserNotification::UserUnauthorized),
+            4 => ::std::option::Option::Some(UserNotification::NetworkStateChanged),
             _ => ::std::option::Option::None
         }
     }
@@ -52,6 +54,7 @@ impl ::protobuf::ProtobufEnum for UserNotification {
             UserNotification::UserAuthChanged,
             UserNotification::UserProfileUpdated,
             UserNotification::UserUnauthorized,
+            UserNotification::NetworkStateChanged,
         ];
         values
     }
@@ -80,19 +83,22 @@ impl ::protobuf::reflect::ProtobufValue for UserNotification {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*b\n\x10UserNotification\x12\x0b\n\x07Unknown\x10\
+    \n\x10observable.proto*{\n\x10UserNotification\x12\x0b\n\x07Unknown\x10\
     \0\x12\x13\n\x0fUserAuthChanged\x10\x01\x12\x16\n\x12UserProfileUpdated\
-    \x10\x02\x12\x14\n\x10UserUnauthorized\x10\x03J\xce\x01\n\x06\x12\x04\0\
-    \0\x07\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\
-    \x07\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x15\n\x0b\n\x04\x05\0\x02\0\
-    \x12\x03\x03\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\
-    \x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\
-    \x12\x03\x04\x04\x18\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x13\n\
-    \x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x16\x17\n\x0b\n\x04\x05\0\x02\
-    \x02\x12\x03\x05\x04\x1b\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\
-    \x16\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x19\x1a\n\x0b\n\x04\x05\0\
-    \x02\x03\x12\x03\x06\x04\x19\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\
-    \x04\x14\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x17\x18b\x06proto3\
+    \x10\x02\x12\x14\n\x10UserUnauthorized\x10\x03\x12\x17\n\x13NetworkState\
+    Changed\x10\x04J\xf7\x01\n\x06\x12\x04\0\0\x08\x01\n\x08\n\x01\x0c\x12\
+    \x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x08\x01\n\n\n\x03\x05\0\x01\
+    \x12\x03\x02\x05\x15\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x10\n\x0c\n\
+    \x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\
+    \x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x18\n\x0c\n\
+    \x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x13\n\x0c\n\x05\x05\0\x02\x01\x02\
+    \x12\x03\x04\x16\x17\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\x1b\n\x0c\
+    \n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x16\n\x0c\n\x05\x05\0\x02\x02\
+    \x02\x12\x03\x05\x19\x1a\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x04\x19\n\
+    \x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x14\n\x0c\n\x05\x05\0\x02\
+    \x03\x02\x12\x03\x06\x17\x18\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x04\
+    \x1c\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x17\n\x0c\n\x05\x05\0\
+    \x02\x04\x02\x12\x03\x07\x1a\x1bb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
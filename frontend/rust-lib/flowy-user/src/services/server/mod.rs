@@ -6,7 +6,20 @@ pub use server_api_mock::*;
 use std::sync::Arc;
 pub(crate) type Server = Arc<dyn UserServerAPI + Send + Sync>;
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ConfirmEmailVerifyParams,
+        ConfirmPasswordResetParams,
+        RefreshTokenParams,
+        RefreshTokenResponse,
+        RequestPasswordResetParams,
+        SignInParams,
+        SignInResponse,
+        SignInWithProviderParams,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::UserError,
 };
 use backend_service::config::ServerConfig;
@@ -15,7 +28,13 @@ use lib_infra::future::ResultFuture;
 pub trait UserServerAPI {
     fn sign_up(&self, params: SignUpParams) -> ResultFuture<SignUpResponse, UserError>;
     fn sign_in(&self, params: SignInParams) -> ResultFuture<SignInResponse, UserError>;
+    fn sign_in_with_provider(&self, params: SignInWithProviderParams) -> ResultFuture<SignInResponse, UserError>;
     fn sign_out(&self, token: &str) -> ResultFuture<(), UserError>;
+    fn refresh_token(&self, params: RefreshTokenParams) -> ResultFuture<RefreshTokenResponse, UserError>;
+    fn request_password_reset(&self, params: RequestPasswordResetParams) -> ResultFuture<(), UserError>;
+    fn confirm_password_reset(&self, params: ConfirmPasswordResetParams) -> ResultFuture<(), UserError>;
+    fn request_email_verify(&self, token: &str) -> ResultFuture<(), UserError>;
+    fn confirm_email_verify(&self, params: ConfirmEmailVerifyParams) -> ResultFuture<(), UserError>;
     fn update_user(&self, token: &str, params: UpdateUserParams) -> ResultFuture<(), UserError>;
     fn get_user(&self, token: &str) -> ResultFuture<UserProfile, UserError>;
     fn ws_addr(&self) -> String;
@@ -1,5 +1,18 @@
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ConfirmEmailVerifyParams,
+        ConfirmPasswordResetParams,
+        RefreshTokenParams,
+        RefreshTokenResponse,
+        RequestPasswordResetParams,
+        SignInParams,
+        SignInResponse,
+        SignInWithProviderParams,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::UserError,
     services::server::UserServerAPI,
 };
@@ -30,6 +43,47 @@ impl UserServerAPI for UserServer {
         })
     }
 
+    fn sign_in_with_provider(&self, params: SignInWithProviderParams) -> ResultFuture<SignInResponse, UserError> {
+        let url = self.config.sign_in_with_provider_url();
+        ResultFuture::new(async move {
+            let resp = sign_in_with_provider_request(params, &url).await?;
+            Ok(resp)
+        })
+    }
+
+    fn request_password_reset(&self, params: RequestPasswordResetParams) -> ResultFuture<(), UserError> {
+        let url = self.config.password_reset_url();
+        ResultFuture::new(async move {
+            let _ = request_password_reset_request(params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn confirm_password_reset(&self, params: ConfirmPasswordResetParams) -> ResultFuture<(), UserError> {
+        let url = self.config.confirm_password_reset_url();
+        ResultFuture::new(async move {
+            let _ = confirm_password_reset_request(params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn request_email_verify(&self, token: &str) -> ResultFuture<(), UserError> {
+        let token = token.to_owned();
+        let url = self.config.email_verify_url();
+        ResultFuture::new(async move {
+            let _ = request_email_verify_request(&token, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn confirm_email_verify(&self, params: ConfirmEmailVerifyParams) -> ResultFuture<(), UserError> {
+        let url = self.config.confirm_email_verify_url();
+        ResultFuture::new(async move {
+            let _ = confirm_email_verify_request(params, &url).await?;
+            Ok(())
+        })
+    }
+
     fn sign_out(&self, token: &str) -> ResultFuture<(), UserError> {
         let token = token.to_owned();
         let url = self.config.sign_out_url();
@@ -39,6 +93,14 @@ impl UserServerAPI for UserServer {
         })
     }
 
+    fn refresh_token(&self, params: RefreshTokenParams) -> ResultFuture<RefreshTokenResponse, UserError> {
+        let url = self.config.refresh_token_url();
+        ResultFuture::new(async move {
+            let resp = refresh_token_request(params, &url).await?;
+            Ok(resp)
+        })
+    }
+
     fn update_user(&self, token: &str, params: UpdateUserParams) -> ResultFuture<(), UserError> {
         let token = token.to_owned();
         let url = self.config.user_profile_url();
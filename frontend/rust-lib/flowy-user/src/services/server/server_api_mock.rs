@@ -1,5 +1,18 @@
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ConfirmEmailVerifyParams,
+        ConfirmPasswordResetParams,
+        RefreshTokenParams,
+        RefreshTokenResponse,
+        RequestPasswordResetParams,
+        SignInParams,
+        SignInResponse,
+        SignInWithProviderParams,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::UserError,
 };
 
@@ -19,6 +32,7 @@ impl UserServerAPI for UserServerMock {
                 name: params.name,
                 email: params.email,
                 token: uid,
+                refresh_token: uuid(),
             })
         })
     }
@@ -31,12 +45,50 @@ impl UserServerAPI for UserServerMock {
                 name: params.name,
                 email: params.email,
                 token: user_id,
+                refresh_token: uuid(),
             })
         })
     }
 
+    fn sign_in_with_provider(&self, params: SignInWithProviderParams) -> ResultFuture<SignInResponse, UserError> {
+        let user_id = uuid();
+        ResultFuture::new(async move {
+            Ok(SignInResponse {
+                user_id: user_id.clone(),
+                name: format!("{:?} user", params.provider),
+                email: format!("{}@appflowy.io", user_id),
+                token: user_id,
+                refresh_token: uuid(),
+            })
+        })
+    }
+
+    fn request_password_reset(&self, _params: RequestPasswordResetParams) -> ResultFuture<(), UserError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    fn confirm_password_reset(&self, _params: ConfirmPasswordResetParams) -> ResultFuture<(), UserError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    fn request_email_verify(&self, _token: &str) -> ResultFuture<(), UserError> { ResultFuture::new(async { Ok(()) }) }
+
+    fn confirm_email_verify(&self, _params: ConfirmEmailVerifyParams) -> ResultFuture<(), UserError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
     fn sign_out(&self, _token: &str) -> ResultFuture<(), UserError> { ResultFuture::new(async { Ok(()) }) }
 
+    fn refresh_token(&self, _params: RefreshTokenParams) -> ResultFuture<RefreshTokenResponse, UserError> {
+        let token = uuid();
+        ResultFuture::new(async move {
+            Ok(RefreshTokenResponse {
+                token,
+                refresh_token: uuid(),
+            })
+        })
+    }
+
     fn update_user(&self, _token: &str, _params: UpdateUserParams) -> ResultFuture<(), UserError> {
         ResultFuture::new(async { Ok(()) })
     }
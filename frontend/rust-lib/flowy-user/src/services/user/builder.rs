@@ -1,20 +1,48 @@
-use crate::services::user::{UserSession, UserSessionConfig};
+use crate::services::user::{SecretStore, UserSession, UserSessionConfig};
 use backend_service::config::ServerConfig;
+use lib_infra::bus::EventBus;
+use std::sync::Arc;
 
 pub struct UserSessionBuilder {
     config: Option<UserSessionConfig>,
+    secret_store: Option<Arc<dyn SecretStore>>,
+    bus: Option<EventBus>,
 }
 
 impl UserSessionBuilder {
-    pub fn new() -> Self { Self { config: None } }
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            secret_store: None,
+            bus: None,
+        }
+    }
 
     pub fn root_dir(mut self, dir: &str, server_config: &ServerConfig, session_cache_key: &str) -> Self {
         self.config = Some(UserSessionConfig::new(dir, server_config, session_cache_key));
         self
     }
 
+    // The user databases are encrypted with the passphrases that are kept in the store
+    pub fn secret_store(mut self, secret_store: Option<Arc<dyn SecretStore>>) -> Self {
+        self.secret_store = secret_store;
+        self
+    }
+
+    // The bus that the other modules of the SDK subscribe to the session events with
+    pub fn bus(mut self, bus: EventBus) -> Self {
+        self.bus = Some(bus);
+        self
+    }
+
     pub fn build(mut self) -> UserSession {
-        let config = self.config.take().unwrap();
+        let mut config = self.config.take().unwrap();
+        if let Some(secret_store) = self.secret_store.take() {
+            config = config.secret_store(secret_store);
+        }
+        if let Some(bus) = self.bus.take() {
+            config = config.bus(bus);
+        }
         UserSession::new(config)
     }
 }
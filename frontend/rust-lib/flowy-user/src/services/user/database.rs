@@ -1,4 +1,4 @@
-use crate::errors::UserError;
+use crate::{errors::UserError, services::user::SecretStore};
 use flowy_database::{DBConnection, Database};
 use lazy_static::lazy_static;
 use lib_sqlite::ConnectionPool;
@@ -11,12 +11,15 @@ lazy_static! {
 
 pub(crate) struct UserDB {
     db_dir: String,
+    // The databases are encrypted with the passphrases in the store if it's set
+    secret_store: Option<Arc<dyn SecretStore>>,
 }
 
 impl UserDB {
-    pub(crate) fn new(db_dir: &str) -> Self {
+    pub(crate) fn new(db_dir: &str, secret_store: Option<Arc<dyn SecretStore>>) -> Self {
         Self {
             db_dir: db_dir.to_owned(),
+            secret_store,
         }
     }
 
@@ -27,9 +30,9 @@ impl UserDB {
 
         tracing::info!("open user db {}", user_id);
         let dir = format!("{}/{}", self.db_dir, user_id);
-        let db = flowy_database::init(&dir).map_err(|e| {
+        let db = self.init_db(&dir, user_id).map_err(|e| {
             log::error!("init user db failed, {:?}, user_id: {}", e, user_id);
-            UserError::internal().context(e)
+            e
         })?;
 
         match DB_MAP.try_write_for(Duration::from_millis(300)) {
@@ -41,6 +44,23 @@ impl UserDB {
         }
     }
 
+    fn init_db(&self, dir: &str, user_id: &str) -> Result<Database, UserError> {
+        let db = match &self.secret_store {
+            #[cfg(feature = "sqlcipher")]
+            Some(store) => {
+                let passphrase = super::secret_store::read_db_passphrase(store.as_ref(), user_id)?;
+                flowy_database::init_encrypted(dir, &passphrase)
+            },
+            #[cfg(not(feature = "sqlcipher"))]
+            Some(_) => {
+                log::warn!("The database of {} isn't encrypted without the sqlcipher feature", user_id);
+                flowy_database::init(dir)
+            },
+            None => flowy_database::init(dir),
+        };
+        db.map_err(|e| UserError::internal().context(e))
+    }
+
     pub(crate) fn close_user_db(&self, user_id: &str) -> Result<(), UserError> {
         match DB_MAP.try_write_for(Duration::from_millis(300)) {
             None => Err(UserError::internal().context(format!("Acquire write lock to close user db failed"))),
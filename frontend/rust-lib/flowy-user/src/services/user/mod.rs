@@ -1,6 +1,10 @@
 pub use builder::*;
+pub use secret_store::*;
 pub use user_session::*;
+pub use user_setting::{UserSettingProvider, SETTINGS_PROFILE_VERSION};
 
 mod builder;
 pub mod database;
+mod secret_store;
 mod user_session;
+mod user_setting;
@@ -0,0 +1,59 @@
+use crate::errors::UserError;
+use std::fmt::Debug;
+
+// Keeps the secrets out of the files of the app, e.g. the passphrase that the user database is encrypted
+// with. The app plugs in the store of the platform, like the Keychain on macOS or the Credential Manager
+// on Windows.
+pub trait SecretStore: Send + Sync + Debug {
+    // Returns None if the secret was never written
+    fn read_secret(&self, name: &str) -> Result<Option<String>, UserError>;
+
+    fn write_secret(&self, name: &str, secret: &str) -> Result<(), UserError>;
+}
+
+// The secrets are kept in the keychain of the OS under the service, one entry per name
+#[cfg(feature = "sqlcipher")]
+#[derive(Debug)]
+pub struct KeychainSecretStore {
+    service: String,
+}
+
+#[cfg(feature = "sqlcipher")]
+impl KeychainSecretStore {
+    pub fn new(service: &str) -> Self {
+        Self {
+            service: service.to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "sqlcipher")]
+impl SecretStore for KeychainSecretStore {
+    fn read_secret(&self, name: &str) -> Result<Option<String>, UserError> {
+        match keyring::Entry::new(&self.service, name).get_password() {
+            Ok(secret) => Ok(Some(secret)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(UserError::internal().context(e)),
+        }
+    }
+
+    fn write_secret(&self, name: &str, secret: &str) -> Result<(), UserError> {
+        keyring::Entry::new(&self.service, name)
+            .set_password(secret)
+            .map_err(|e| UserError::internal().context(e))
+    }
+}
+
+// The passphrase is created the first time the database of the user is opened with the store. SQLCipher
+// derives the key from it, so it's only as strong as its randomness: two v4 uuids give 244 random bits.
+#[cfg(feature = "sqlcipher")]
+pub(crate) fn read_db_passphrase(store: &dyn SecretStore, user_id: &str) -> Result<String, UserError> {
+    let name = format!("{}_database", user_id);
+    if let Some(passphrase) = store.read_secret(&name)? {
+        return Ok(passphrase);
+    }
+
+    let passphrase = format!("{}{}", lib_infra::uuid(), lib_infra::uuid()).replace('-', "");
+    let _ = store.write_secret(&name, &passphrase)?;
+    Ok(passphrase)
+}
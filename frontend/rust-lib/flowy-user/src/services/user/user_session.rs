@@ -1,7 +1,25 @@
 use crate::{
-    entities::{SignInParams, SignUpParams, UpdateUserParams, UserProfile},
+    entities::{
+        ConfirmEmailVerifyParams,
+        ConfirmPasswordResetParams,
+        NetworkState,
+        NetworkStatus,
+        RefreshTokenParams,
+        RequestPasswordResetParams,
+        SignInParams,
+        SignInResponse,
+        SignInWithProviderParams,
+        SignUpParams,
+        UpdateUserParams,
+        UserProfile,
+        UserSettingsProfile,
+    },
     errors::{ErrorCode, UserError},
-    services::user::database::UserDB,
+    services::user::{
+        database::UserDB,
+        user_setting::{export_settings, import_settings, UserSettingProvider},
+        SecretStore,
+    },
     sql_tables::{UserTable, UserTableChangeset},
 };
 
@@ -9,7 +27,7 @@ use crate::{
     notify::*,
     services::server::{construct_user_server, Server},
 };
-use backend_service::config::ServerConfig;
+use backend_service::{config::ServerConfig, request::refresh_token};
 use flowy_database::{
     query_dsl::*,
     schema::{user_table, user_table::dsl},
@@ -17,22 +35,22 @@ use flowy_database::{
     ExpressionMethods,
     UserDatabaseConnection,
 };
-use lib_infra::kv::KV;
+use lib_infra::{
+    bus::{EventBus, UserSessionEvent, WsConnectionEvent},
+    kv::KV,
+};
 use lib_sqlite::ConnectionPool;
 use lib_ws::{WsController, WsMessageHandler, WsState};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tokio::sync::{broadcast, mpsc};
+use std::{path::Path, sync::Arc};
+use tokio::sync::{broadcast, mpsc, Mutex};
 
 #[derive(Clone)]
 pub enum UserStatus {
     Login {
         token: String,
     },
-    Logout {
-        token: String,
-    },
     Expired {
         token: String,
     },
@@ -46,6 +64,8 @@ pub struct UserSessionConfig {
     root_dir: String,
     server_config: ServerConfig,
     session_cache_key: String,
+    secret_store: Option<Arc<dyn SecretStore>>,
+    bus: EventBus,
 }
 
 impl UserSessionConfig {
@@ -54,8 +74,21 @@ impl UserSessionConfig {
             root_dir: root_dir.to_owned(),
             server_config: server_config.clone(),
             session_cache_key: session_cache_key.to_owned(),
+            secret_store: None,
+            bus: EventBus::new(),
         }
     }
+
+    pub fn secret_store(mut self, secret_store: Arc<dyn SecretStore>) -> Self {
+        self.secret_store = Some(secret_store);
+        self
+    }
+
+    // The sign in, sign up and sign out events are published on the bus of the SDK
+    pub fn bus(mut self, bus: EventBus) -> Self {
+        self.bus = bus;
+        self
+    }
 }
 
 pub struct UserSession {
@@ -66,11 +99,14 @@ pub struct UserSession {
     session: RwLock<Option<Session>>,
     pub ws_controller: Arc<WsController>,
     status_notifier: broadcast::Sender<UserStatus>,
+    setting_providers: RwLock<Vec<Arc<dyn UserSettingProvider>>>,
+    // The token that was replaced by the last refresh, the lock makes the refreshes run one by one
+    replaced_token: Mutex<Option<String>>,
 }
 
 impl UserSession {
     pub fn new(config: UserSessionConfig) -> Self {
-        let db = UserDB::new(&config.root_dir);
+        let db = UserDB::new(&config.root_dir, config.secret_store.clone());
         let server = construct_user_server(&config.server_config);
         let ws_controller = Arc::new(WsController::new());
         let (status_notifier, _) = broadcast::channel(10);
@@ -81,6 +117,8 @@ impl UserSession {
             session: RwLock::new(None),
             ws_controller,
             status_notifier,
+            setting_providers: RwLock::new(vec![]),
+            replaced_token: Mutex::new(None),
         };
         user_session
     }
@@ -88,12 +126,30 @@ impl UserSession {
     pub fn init(&self) {
         match self.get_session() {
             Ok(session) => {
+                self.config.bus.publish(UserSessionEvent::SignIn {
+                    user_id: session.user_id,
+                    token: session.token.clone(),
+                });
                 let _ = self.status_notifier.send(UserStatus::Login { token: session.token });
             },
             Err(_) => {},
         }
     }
 
+    pub fn register_setting_provider(&self, provider: Arc<dyn UserSettingProvider>) {
+        self.setting_providers.write().push(provider);
+    }
+
+    pub fn export_settings(&self) -> Result<UserSettingsProfile, UserError> {
+        let _ = self.get_session()?;
+        export_settings(&self.setting_providers.read())
+    }
+
+    pub fn import_settings(&self, profile: UserSettingsProfile) -> Result<(), UserError> {
+        let _ = self.get_session()?;
+        import_settings(&self.setting_providers.read(), profile)
+    }
+
     pub fn status_subscribe(&self) -> broadcast::Receiver<UserStatus> { self.status_notifier.subscribe() }
 
     pub fn db_connection(&self) -> Result<DBConnection, UserError> {
@@ -112,33 +168,88 @@ impl UserSession {
         self.database.get_pool(&user_id)
     }
 
+    // The database of another user that signed in on this device, e.g. the guest whose data is merged into
+    // the current user. It's not created if the user never signed in here.
+    pub fn local_user_db_pool(&self, user_id: &str) -> Result<Arc<ConnectionPool>, UserError> {
+        let session = self.get_session()?;
+        let db_path = format!("{}/{}/{}", self.config.root_dir, user_id, flowy_database::DB_NAME);
+        if user_id.is_empty() || user_id == session.user_id || !Path::new(&db_path).exists() {
+            return Err(UserError::user_not_exist());
+        }
+        self.database.get_pool(user_id)
+    }
+
+    pub fn close_local_user_db(&self, user_id: &str) -> Result<(), UserError> {
+        if user_id == self.get_session()?.user_id {
+            return Ok(());
+        }
+        self.database.close_user_db(user_id)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn sign_in(&self, params: SignInParams) -> Result<UserProfile, UserError> {
         if self.is_login(&params.email) {
             self.user_profile().await
         } else {
             let resp = self.server.sign_in(params).await?;
-            let session = Session::new(&resp.user_id, &resp.token, &resp.email);
-            let _ = self.set_session(Some(session))?;
-            let user_table = self.save_user(resp.into()).await?;
-            let user_profile: UserProfile = user_table.into();
-            let _ = self.status_notifier.send(UserStatus::Login {
-                token: user_profile.token.clone(),
-            });
-            Ok(user_profile)
+            self.save_signed_in_user(resp).await
         }
     }
 
+    // The email of the user isn't known until the provider account is resolved by the server
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn sign_in_with_provider(&self, params: SignInWithProviderParams) -> Result<UserProfile, UserError> {
+        let resp = self.server.sign_in_with_provider(params).await?;
+        if self.is_login(&resp.email) {
+            self.user_profile().await
+        } else {
+            self.save_signed_in_user(resp).await
+        }
+    }
+
+    // The reset token is sent to the email, the user doesn't need to be signed in
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn request_password_reset(&self, params: RequestPasswordResetParams) -> Result<(), UserError> {
+        let _ = self.server.request_password_reset(params).await?;
+        Ok(())
+    }
+
+    // The server signs out the user after the password is reset, so the user signs in with the new password
+    #[tracing::instrument(level = "debug", skip(self, params))]
+    pub async fn confirm_password_reset(&self, params: ConfirmPasswordResetParams) -> Result<(), UserError> {
+        let _ = self.server.confirm_password_reset(params).await?;
+        Ok(())
+    }
+
+    // The verify token is sent to the email of the signed-in user
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn request_email_verify(&self) -> Result<(), UserError> {
+        let session = self.get_session()?;
+        let _ = self.server.request_email_verify(&session.token).await?;
+        Ok(())
+    }
+
+    // The token is enough to verify the email, so the link in the email can be opened on another device
+    #[tracing::instrument(level = "debug", skip(self, params))]
+    pub async fn confirm_email_verify(&self, params: ConfirmEmailVerifyParams) -> Result<(), UserError> {
+        let _ = self.server.confirm_email_verify(params).await?;
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn sign_up(&self, params: SignUpParams) -> Result<UserProfile, UserError> {
         if self.is_login(&params.email) {
             self.user_profile().await
         } else {
             let resp = self.server.sign_up(params).await?;
-            let session = Session::new(&resp.user_id, &resp.token, &resp.email);
+            let session = Session::new(&resp.user_id, &resp.token, &resp.refresh_token, &resp.email);
             let _ = self.set_session(Some(session))?;
             let user_table = self.save_user(resp.into()).await?;
             let user_profile: UserProfile = user_table.into();
+            self.config.bus.publish(UserSessionEvent::SignUp {
+                user_id: user_profile.id.clone(),
+                token: user_profile.token.clone(),
+            });
             let (ret, mut tx) = mpsc::channel(1);
             let _ = self.status_notifier.send(UserStatus::SignUp {
                 profile: user_profile.clone(),
@@ -153,14 +264,16 @@ impl UserSession {
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn sign_out(&self) -> Result<(), UserError> {
         let session = self.get_session()?;
+        // The session is still needed if the token has to be refreshed before signing out
+        let _ = self.sign_out_on_server(&session.token).await?;
         let _ =
             diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*(self.db_connection()?))?;
         let _ = self.database.close_user_db(&session.user_id)?;
         let _ = self.set_session(None)?;
-        let _ = self.status_notifier.send(UserStatus::Logout {
+        self.config.bus.publish(UserSessionEvent::SignOut {
+            user_id: session.user_id.clone(),
             token: session.token.clone(),
         });
-        let _ = self.sign_out_on_server(&session.token).await?;
 
         Ok(())
     }
@@ -212,6 +325,39 @@ impl UserSession {
 
     pub fn token(&self) -> Result<String, UserError> { Ok(self.get_session()?.token) }
 
+    // Exchanges the refresh token for a new pair when the server rejects the token. The requests that are
+    // rejected at the same time wait for the first refresh and get the token that it returned.
+    #[tracing::instrument(level = "debug", skip(self, token), err)]
+    pub async fn refresh_token(&self, token: &str) -> Result<String, UserError> {
+        let mut replaced_token = self.replaced_token.lock().await;
+        let mut session = self.get_session()?;
+        if session.token != token {
+            return match replaced_token.as_deref() == Some(token) {
+                true => Ok(session.token),
+                false => Err(UserError::unauthorized()),
+            };
+        }
+
+        // The session that was saved before the refresh token was introduced has to sign in again
+        if session.refresh_token.is_empty() {
+            return Err(UserError::unauthorized());
+        }
+
+        let params = RefreshTokenParams {
+            refresh_token: session.refresh_token.clone(),
+        };
+        let resp = self.server.refresh_token(params).await?;
+        session.token = resp.token;
+        session.refresh_token = resp.refresh_token;
+        let _ = self.set_session(Some(session.clone()))?;
+        let _ = diesel::update(dsl::user_table.filter(dsl::id.eq(&session.user_id)))
+            .set(dsl::token.eq(&session.token))
+            .execute(&*(self.db_connection()?))?;
+
+        *replaced_token = Some(token.to_owned());
+        Ok(session.token)
+    }
+
     pub fn add_ws_handler(&self, handler: Arc<dyn WsMessageHandler>) {
         let _ = self.ws_controller.add_handler(handler);
     }
@@ -267,6 +413,21 @@ impl UserSession {
         Ok(())
     }
 
+    async fn save_signed_in_user(&self, resp: SignInResponse) -> Result<UserProfile, UserError> {
+        let session = Session::new(&resp.user_id, &resp.token, &resp.refresh_token, &resp.email);
+        let _ = self.set_session(Some(session))?;
+        let user_table = self.save_user(resp.into()).await?;
+        let user_profile: UserProfile = user_table.into();
+        self.config.bus.publish(UserSessionEvent::SignIn {
+            user_id: user_profile.id.clone(),
+            token: user_profile.token.clone(),
+        });
+        let _ = self.status_notifier.send(UserStatus::Login {
+            token: user_profile.token.clone(),
+        });
+        Ok(user_profile)
+    }
+
     async fn save_user(&self, user: UserTable) -> Result<UserTable, UserError> {
         let conn = self.db_connection()?;
         let _ = diesel::insert_into(user_table::table)
@@ -283,6 +444,7 @@ impl UserSession {
             },
             Some(session) => KV::set_str(&self.config.session_cache_key, session.clone().into()),
         }
+        lib_log::set_log_user(session.as_ref().map(|session| session.user_id.as_str()));
         *self.session.write() = session;
         Ok(())
     }
@@ -316,16 +478,22 @@ impl UserSession {
     pub async fn start_ws_connection(&self, token: &str) -> Result<(), UserError> {
         if cfg!(feature = "http_server") {
             let addr = format!("{}/{}", self.server.ws_addr(), token);
-            self.listen_on_websocket();
+            self.listen_on_websocket(token);
             let _ = self.ws_controller.start_connect(addr).await?;
         }
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
-    fn listen_on_websocket(&self) {
+    // The UI is told about each change of the connection with the NetworkStateChanged notification. The
+    // lost connection is made again with the ReconnectPolicy of the controller, the open docs send their
+    // revisions again once it's Connected.
+    #[tracing::instrument(level = "debug", skip(self, token))]
+    fn listen_on_websocket(&self, token: &str) {
         let mut notify = self.ws_controller.state_subscribe();
         let ws_controller = self.ws_controller.clone();
+        let ws_addr = self.server.ws_addr();
+        let bus = self.config.bus.clone();
+        let mut token = token.to_owned();
         let _ = tokio::spawn(async move {
             loop {
                 match notify.recv().await {
@@ -333,15 +501,40 @@ impl UserSession {
                         tracing::info!("Websocket state changed: {}", state);
                         match state {
                             WsState::Init => {},
-                            WsState::Connected(_) => {},
-                            WsState::Disconnected(_) => match ws_controller.retry().await {
-                                Ok(_) => {},
-                                Err(e) => {
-                                    log::error!("Retry websocket connect failed: {:?}", e);
-                                },
+                            WsState::Connected(_) => {
+                                notify_network_state(&token, NetworkState::new(NetworkStatus::Online, 0));
+                                bus.publish(WsConnectionEvent::Connected);
+                            },
+                            WsState::Reconnecting(attempt) => {
+                                let state = NetworkState::new(NetworkStatus::Connecting, attempt as i64);
+                                notify_network_state(&token, state);
+                            },
+                            WsState::Offline(_) => {
+                                notify_network_state(&token, NetworkState::new(NetworkStatus::Offline, 0));
+                            },
+                            WsState::Disconnected(_) => {
+                                notify_network_state(&token, NetworkState::new(NetworkStatus::Connecting, 0));
+                                bus.publish(WsConnectionEvent::Disconnected);
+                                // The token is checked when connecting, it may have expired since the last
+                                // connection. It's refreshed with the refresher of the http requests.
+                                match refresh_token(&token).await {
+                                    Ok(new_token) => token = new_token,
+                                    Err(e) => log::debug!("Refresh the token of websocket failed: {:?}", e),
+                                }
+                                // The retry runs in its own task, so the states it sends are received here
+                                let addr = format!("{}/{}", ws_addr, token);
+                                let ws_controller = ws_controller.clone();
+                                tokio::spawn(async move {
+                                    if let Err(e) = ws_controller.retry_with_addr(addr).await {
+                                        log::error!("Retry websocket connect failed: {:?}", e);
+                                    }
+                                });
                             },
                         }
                     },
+                    Err(broadcast::error::RecvError::Lagged(count)) => {
+                        log::debug!("Skip {} websocket states", count);
+                    },
                     Err(e) => {
                         log::error!("Websocket state notify error: {:?}", e);
                         break;
@@ -352,6 +545,12 @@ impl UserSession {
     }
 }
 
+fn notify_network_state(token: &str, state: NetworkState) {
+    dart_notify(token, UserNotification::NetworkStateChanged)
+        .payload(state)
+        .send();
+}
+
 pub async fn update_user(
     _server: Server,
     pool: Arc<ConnectionPool>,
@@ -371,14 +570,17 @@ impl UserDatabaseConnection for UserSession {
 struct Session {
     user_id: String,
     token: String,
+    #[serde(default)]
+    refresh_token: String,
     email: String,
 }
 
 impl Session {
-    pub fn new(user_id: &str, token: &str, email: &str) -> Self {
+    pub fn new(user_id: &str, token: &str, refresh_token: &str, email: &str) -> Self {
         Self {
             user_id: user_id.to_owned(),
             token: token.to_owned(),
+            refresh_token: refresh_token.to_owned(),
             email: email.to_owned(),
         }
     }
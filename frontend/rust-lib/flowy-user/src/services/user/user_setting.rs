@@ -0,0 +1,71 @@
+use crate::{
+    entities::UserSettingsProfile,
+    errors::{ErrorCode, UserError},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+// Bumped when the format of the profile changes in the way that the older version can't import
+pub const SETTINGS_PROFILE_VERSION: u32 = 1;
+
+// The crate that owns some user-level settings registers the provider, so the settings are included in
+// the exported profile. Each provider reads and writes the settings of the current user.
+pub trait UserSettingProvider: Send + Sync {
+    // The key of the settings in the profile, it must not be changed after the profile was exported
+    fn name(&self) -> &'static str;
+
+    fn export(&self) -> Result<Value, UserError>;
+
+    // The value may come from the profile that was exported by another version, the missing fields keep
+    // their current values.
+    fn import(&self, value: Value) -> Result<(), UserError>;
+}
+
+#[derive(Serialize, Deserialize)]
+struct SettingsProfile {
+    version: u32,
+    settings: Map<String, Value>,
+}
+
+pub(crate) fn export_settings(providers: &[Arc<dyn UserSettingProvider>]) -> Result<UserSettingsProfile, UserError> {
+    let mut settings = Map::new();
+    for provider in providers {
+        settings.insert(provider.name().to_owned(), provider.export()?);
+    }
+
+    let profile = SettingsProfile {
+        version: SETTINGS_PROFILE_VERSION,
+        settings,
+    };
+    let json = serde_json::to_string_pretty(&profile).map_err(|e| UserError::internal().context(e))?;
+    Ok(UserSettingsProfile { json })
+}
+
+// The whole profile is checked before any settings are changed. The sections that no provider owns are
+// skipped, e.g. the ones that were exported by the newer version.
+pub(crate) fn import_settings(
+    providers: &[Arc<dyn UserSettingProvider>],
+    profile: UserSettingsProfile,
+) -> Result<(), UserError> {
+    let profile: SettingsProfile = serde_json::from_str(&profile.json)
+        .map_err(|e| UserError::from(ErrorCode::SettingsProfileInvalid).context(e))?;
+    if profile.version > SETTINGS_PROFILE_VERSION {
+        let msg = format!("The profile version {} is not supported", profile.version);
+        return Err(UserError::from(ErrorCode::SettingsProfileInvalid).context(msg));
+    }
+
+    let mut settings = profile.settings;
+    for name in settings.keys() {
+        if !providers.iter().any(|provider| provider.name() == name) {
+            log::warn!("Skip the unknown settings: {}", name);
+        }
+    }
+
+    for provider in providers {
+        if let Some(value) = settings.remove(provider.name()) {
+            let _ = provider.import(value)?;
+        }
+    }
+    Ok(())
+}
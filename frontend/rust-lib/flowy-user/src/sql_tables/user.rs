@@ -44,6 +44,8 @@ impl std::convert::Into<UserProfile> for UserTable {
             email: self.email,
             name: self.name,
             token: self.token,
+            // Only the server knows it, the profile of the server is sent with the UserProfileUpdated
+            email_verified: false,
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::helper::*;
 use flowy_test::{builder::UserTest, FlowyTest};
 use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
 
 #[tokio::test]
 async fn sign_up_with_invalid_email() {
@@ -106,3 +107,174 @@ async fn sign_in_with_invalid_password() {
             .assert_error();
     }
 }
+
+#[tokio::test]
+async fn sign_in_with_provider_success() {
+    let test = FlowyTest::setup();
+    let _ = UserTest::new(test.sdk()).event(SignOut).sync_send();
+    let request = SignInWithProviderRequest {
+        provider: AuthProvider::GitHub,
+        code: "github_code".to_string(),
+        code_verifier: valid_code_verifier(),
+        redirect_uri: "http://localhost:8000/oauth/callback".to_string(),
+    };
+
+    let user_profile = UserTest::new(test.sdk())
+        .event(SignInWithProvider)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert!(!user_profile.token.is_empty());
+
+    let check_profile = UserTest::new(test.sdk())
+        .event(CheckUser)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(check_profile.id, user_profile.id);
+}
+
+#[tokio::test]
+async fn sign_in_with_provider_empty_code() {
+    let test = FlowyTest::setup();
+    let request = SignInWithProviderRequest {
+        provider: AuthProvider::Google,
+        code: " ".to_string(),
+        code_verifier: valid_code_verifier(),
+        redirect_uri: "http://localhost:8000/oauth/callback".to_string(),
+    };
+
+    assert_eq!(
+        UserTest::new(test.sdk)
+            .event(SignInWithProvider)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::AuthCodeIsEmpty.value()
+    );
+}
+
+#[tokio::test]
+async fn sign_in_with_provider_invalid_code_verifier() {
+    for code_verifier in invalid_code_verifier_test_case() {
+        let test = FlowyTest::setup();
+        let request = SignInWithProviderRequest {
+            provider: AuthProvider::Google,
+            code: "google_code".to_string(),
+            code_verifier,
+            redirect_uri: "http://localhost:8000/oauth/callback".to_string(),
+        };
+
+        assert_eq!(
+            UserTest::new(test.sdk)
+                .event(SignInWithProvider)
+                .request(request)
+                .async_send()
+                .await
+                .error()
+                .code,
+            ErrorCode::AuthCodeVerifierInvalid.value()
+        );
+    }
+}
+
+#[tokio::test]
+async fn request_password_reset_success() {
+    let test = FlowyTest::setup();
+    let request = RequestPasswordResetRequest { email: random_email() };
+
+    let _ = UserTest::new(test.sdk)
+        .event(RequestPasswordReset)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+#[tokio::test]
+async fn request_password_reset_with_invalid_email() {
+    for email in invalid_email_test_case() {
+        let test = FlowyTest::setup();
+        let request = RequestPasswordResetRequest { email };
+
+        assert_eq!(
+            UserTest::new(test.sdk)
+                .event(RequestPasswordReset)
+                .request(request)
+                .async_send()
+                .await
+                .error()
+                .code,
+            ErrorCode::EmailFormatInvalid.value()
+        );
+    }
+}
+
+#[tokio::test]
+async fn confirm_password_reset_empty_token() {
+    let test = FlowyTest::setup();
+    let request = ConfirmPasswordResetRequest {
+        token: " ".to_string(),
+        new_password: login_password(),
+    };
+
+    assert_eq!(
+        UserTest::new(test.sdk)
+            .event(ConfirmPasswordReset)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::PasswordResetTokenIsEmpty.value()
+    );
+}
+
+#[tokio::test]
+async fn confirm_password_reset_with_invalid_password() {
+    for new_password in invalid_password_test_case() {
+        let test = FlowyTest::setup();
+        let request = ConfirmPasswordResetRequest {
+            token: "reset_token".to_string(),
+            new_password,
+        };
+
+        UserTest::new(test.sdk)
+            .event(ConfirmPasswordReset)
+            .request(request)
+            .async_send()
+            .await
+            .assert_error();
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn request_email_verify_success() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let _ = UserTest::new(test.sdk)
+        .event(RequestEmailVerify)
+        .async_send()
+        .await
+        .assert_success();
+}
+
+#[tokio::test]
+async fn confirm_email_verify_empty_token() {
+    let test = FlowyTest::setup();
+    let request = ConfirmEmailVerifyRequest { token: " ".to_string() };
+
+    assert_eq!(
+        UserTest::new(test.sdk)
+            .event(ConfirmEmailVerify)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::EmailVerifyTokenIsEmpty.value()
+    );
+}
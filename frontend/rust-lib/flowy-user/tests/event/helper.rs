@@ -38,3 +38,17 @@ pub(crate) fn invalid_password_test_case() -> Vec<String> {
 }
 
 pub(crate) fn valid_name() -> String { "AppFlowy".to_string() }
+
+pub(crate) fn valid_code_verifier() -> String { "Ab3-._~".repeat(8) }
+
+pub(crate) fn invalid_code_verifier_test_case() -> Vec<String> {
+    vec![
+        "too_short",
+        "a".repeat(129).as_str(),
+        "with space".repeat(5).as_str(),
+        "非ascii".repeat(10).as_str(),
+    ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>()
+}
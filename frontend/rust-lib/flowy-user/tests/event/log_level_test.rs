@@ -0,0 +1,18 @@
+use crate::helper::*;
+use flowy_test::FlowyTest;
+use flowy_user::{event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+#[tokio::test]
+#[serial]
+async fn user_set_log_level_without_sign_in() {
+    let test = FlowyTest::setup();
+    for level in vec![LogLevel::Debug, LogLevel::Info] {
+        let request = SetLogLevelRequest { level };
+        let _ = UserTest::new(test.sdk())
+            .event(SetLogLevel)
+            .request(request)
+            .sync_send()
+            .assert_success();
+    }
+}
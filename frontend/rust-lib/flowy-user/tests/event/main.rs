@@ -1,3 +1,8 @@
 mod auth_test;
 mod helper;
+mod log_level_test;
+mod metrics_test;
+mod migration_test;
+mod multi_user_test;
 mod user_profile_test;
+mod user_setting_test;
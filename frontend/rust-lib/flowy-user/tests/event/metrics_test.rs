@@ -0,0 +1,36 @@
+use crate::helper::*;
+use flowy_test::FlowyTest;
+use flowy_user::{event::UserEvent::*, prelude::*};
+use lib_dispatch::prelude::DEFAULT_BLOCKING_POOL_SIZE;
+use serial_test::*;
+
+#[tokio::test]
+#[serial]
+async fn user_query_metrics_snapshot() {
+    let test = FlowyTest::setup();
+    lib_infra::metrics::enable_metrics(true);
+    let _ = UserTest::new(test.sdk())
+        .event(SetLogLevel)
+        .request(SetLogLevelRequest { level: LogLevel::Info })
+        .sync_send()
+        .assert_success();
+
+    let snapshot = UserTest::new(test.sdk())
+        .event(QueryMetricsSnapshot)
+        .sync_send()
+        .parse::<MetricsSnapshot>();
+    lib_infra::metrics::enable_metrics(false);
+
+    let counter = snapshot
+        .counters
+        .iter()
+        .find(|counter| counter.name == "dispatch_events_total" && counter.labels.contains("SetLogLevel"))
+        .unwrap();
+    assert!(counter.value >= 1);
+    assert!(snapshot
+        .latencies
+        .iter()
+        .any(|latency| latency.name == "dispatch_event_duration_seconds"));
+    assert!(snapshot.prometheus.contains("# TYPE dispatch_events_total counter"));
+    assert_eq!(snapshot.blocking_pool.size, DEFAULT_BLOCKING_POOL_SIZE as i64);
+}
@@ -0,0 +1,87 @@
+use flowy_database::{
+    migrations,
+    Connection,
+    Migration,
+    MigrationMode,
+    MigrationRunner,
+    SqliteConnection,
+};
+use flowy_test::migration::*;
+
+#[test]
+fn migration_upgrade_legacy_db() {
+    let dir = create_legacy_db(5);
+    let report = validate_migrations(&dir);
+    assert_eq!(report.applied.len(), 5);
+    assert_eq!(report.pending.len(), migrations().len() - 5);
+
+    let _ = flowy_database::init(&dir).unwrap();
+    let report = validate_migrations(&dir);
+    assert_eq!(report.applied.len(), migrations().len());
+    assert!(report.pending.is_empty());
+}
+
+#[test]
+fn migration_dry_run_keeps_db() {
+    let dir = create_legacy_db(2);
+    let first = validate_migrations(&dir);
+    let second = validate_migrations(&dir);
+    assert_eq!(first.applied, second.applied);
+    assert_eq!(first.pending, second.pending);
+    assert_eq!(second.applied.len(), 2);
+}
+
+#[test]
+fn migration_fresh_db() {
+    let dir = create_legacy_db(0);
+    let _ = flowy_database::init(&dir).unwrap();
+    let report = validate_migrations(&dir);
+    let versions = migrations()
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect::<Vec<String>>();
+    assert_eq!(report.applied, versions);
+    assert!(report.pending.is_empty());
+}
+
+#[test]
+fn migration_unknown_version() {
+    let dir = create_legacy_db(3);
+    record_legacy_version(&dir, "29991231000000");
+    assert!(flowy_database::validate(&dir).is_err());
+    assert!(flowy_database::init(&dir).is_err());
+}
+
+#[test]
+fn migration_changed_script() {
+    let conn = SqliteConnection::establish(":memory:").unwrap();
+    let migration = Migration::new("2021-01-01-000000_test", "CREATE TABLE test (id TEXT NOT NULL PRIMARY KEY);");
+    let runner = MigrationRunner::new(vec![migration]).unwrap();
+    let _ = runner.run(&conn, MigrationMode::Apply).unwrap();
+
+    let migration = Migration::new("2021-01-01-000000_test", "CREATE TABLE test (id TEXT NOT NULL);");
+    let runner = MigrationRunner::new(vec![migration]).unwrap();
+    assert!(runner.run(&conn, MigrationMode::Apply).is_err());
+}
+
+#[test]
+fn migration_invalid_order() {
+    let migrations = vec![
+        Migration::new("2021-02-01-000000_second", ""),
+        Migration::new("2021-01-01-000000_first", ""),
+    ];
+    assert!(MigrationRunner::new(migrations).is_err());
+}
+
+#[test]
+fn migration_encrypted_db_without_key() {
+    let dir = create_legacy_db(0);
+    // The encrypted file starts with the random salt instead of the sqlite header
+    let db_path = format!("{}/{}", dir, flowy_database::DB_NAME);
+    let bytes = (0..4096).map(|i| (i * 7 + 13) as u8).collect::<Vec<u8>>();
+    std::fs::write(&db_path, bytes).unwrap();
+    assert!(flowy_database::is_encrypted(&db_path).unwrap());
+
+    let error = flowy_database::init(&dir).err().unwrap();
+    assert_eq!(error.kind(), std::io::ErrorKind::PermissionDenied);
+}
@@ -0,0 +1,44 @@
+use flowy_test::context::FlowyTestContext;
+use flowy_user::{event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+#[tokio::test]
+#[serial]
+async fn user_profiles_of_the_contexts_are_isolated() {
+    let context = FlowyTestContext::setup(&["owner", "member"]);
+    let owner = context.sign_up("owner").await.user_profile;
+    let member = context.sign_up("member").await.user_profile;
+    assert_ne!(owner.id, member.id);
+
+    let user_profile = context
+        .user_test("owner")
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, owner);
+
+    let user_profile = context
+        .user_test("member")
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, member);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_sign_out_of_one_context() {
+    let context = FlowyTestContext::setup(&["owner", "member"]);
+    let _ = context.sign_up("owner").await;
+    let member = context.sign_up("member").await.user_profile;
+
+    let _ = context.user_test("owner").event(SignOut).sync_send();
+    let _ = context.user_test("owner").event(GetUserProfile).sync_send().error();
+
+    let user_profile = context
+        .user_test("member")
+        .event(GetUserProfile)
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user_profile, member);
+}
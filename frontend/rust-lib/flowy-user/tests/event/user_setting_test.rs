@@ -0,0 +1,97 @@
+use crate::helper::*;
+use flowy_test::FlowyTest;
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use serial_test::*;
+
+fn export_settings(test: &FlowyTest) -> serde_json::Value {
+    let profile = UserTest::new(test.sdk())
+        .event(ExportSettings)
+        .sync_send()
+        .parse::<UserSettingsProfile>();
+    serde_json::from_str(&profile.json).unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn user_settings_export() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let profile = export_settings(&test);
+    assert_eq!(profile["version"], 1);
+    assert_eq!(profile["settings"]["input_rules"]["em_dash"], true);
+    assert_eq!(profile["settings"]["read_positions"], serde_json::json!({}));
+}
+
+#[tokio::test]
+#[serial]
+async fn user_settings_import() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let mut profile = export_settings(&test);
+    profile["settings"]["input_rules"]["em_dash"] = false.into();
+    // The sections that no one owns are skipped
+    profile["settings"]["unknown"] = serde_json::json!({ "key": "value" });
+
+    let request = UserSettingsProfile::new(&profile.to_string());
+    let _ = UserTest::new(test.sdk()).event(ImportSettings).request(request).sync_send();
+
+    let profile = export_settings(&test);
+    assert_eq!(profile["settings"]["input_rules"]["em_dash"], false);
+    assert_eq!(profile["settings"]["input_rules"]["heading"], true);
+    assert_eq!(profile["settings"].get("unknown").is_none(), true);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_settings_import_keeps_later_read_position() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let mut profile = export_settings(&test);
+    profile["settings"]["read_positions"] = serde_json::json!({
+        "doc_1": { "offset": 10, "modified_time": 200 },
+        "doc_2": { "offset": "malformed" },
+    });
+    let request = UserSettingsProfile::new(&profile.to_string());
+    let _ = UserTest::new(test.sdk()).event(ImportSettings).request(request).sync_send();
+
+    // The position that is older than the local one is skipped
+    profile["settings"]["read_positions"] = serde_json::json!({
+        "doc_1": { "offset": 20, "modified_time": 100 },
+    });
+    let request = UserSettingsProfile::new(&profile.to_string());
+    let _ = UserTest::new(test.sdk()).event(ImportSettings).request(request).sync_send();
+
+    let profile = export_settings(&test);
+    assert_eq!(
+        profile["settings"]["read_positions"],
+        serde_json::json!({
+            "doc_1": { "offset": 10, "modified_time": 200 },
+        })
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn user_settings_import_invalid_profile() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    for json in vec!["not json", r#"{"version": 100, "settings": {}}"#] {
+        let request = UserSettingsProfile::new(json);
+        let error = UserTest::new(test.sdk())
+            .event(ImportSettings)
+            .request(request)
+            .sync_send()
+            .error();
+        assert_eq!(error.code, ErrorCode::SettingsProfileInvalid.value());
+    }
+}
+
+#[tokio::test]
+async fn user_settings_export_without_user() {
+    let test = FlowyTest::setup();
+    let _ = UserTest::new(test.sdk())
+        .event(ExportSettings)
+        .assert_error()
+        .async_send()
+        .await;
+}
@@ -37,6 +37,7 @@ impl WorkspaceError {
     static_workspace_error!(workspace_id, ErrorCode::WorkspaceIdInvalid);
     static_workspace_error!(color_style, ErrorCode::AppColorStyleInvalid);
     static_workspace_error!(workspace_desc, ErrorCode::WorkspaceDescTooLong);
+    static_workspace_error!(workspace_archived, ErrorCode::WorkspaceArchived);
     static_workspace_error!(app_name, ErrorCode::AppNameInvalid);
     static_workspace_error!(invalid_app_id, ErrorCode::AppIdInvalid);
     static_workspace_error!(view_name, ErrorCode::ViewNameInvalid);
@@ -101,6 +102,10 @@ fn server_error_to_workspace_error(code: ServerErrorCode) -> ErrorCode {
     match code {
         ServerErrorCode::UserUnauthorized => ErrorCode::UserUnauthorized,
         ServerErrorCode::RecordNotFound => ErrorCode::RecordNotFound,
+        ServerErrorCode::WorkspaceArchived => ErrorCode::WorkspaceArchived,
+        ServerErrorCode::PermissionDenied => ErrorCode::PermissionDenied,
+        ServerErrorCode::TooManyRequests => ErrorCode::TooManyRequests,
+        ServerErrorCode::PayloadOverflow => ErrorCode::PayloadTooLarge,
         _ => ErrorCode::InternalError,
     }
 }
@@ -3,79 +3,249 @@ use strum_macros::Display;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
 #[event_err = "WorkspaceError"]
+#[event_scope = "signed_in"]
 pub enum WorkspaceEvent {
     #[event(input = "CreateWorkspaceRequest", output = "Workspace")]
-    CreateWorkspace   = 0,
+    CreateWorkspace    = 0,
 
     #[event(output = "CurrentWorkspaceSetting")]
-    ReadCurWorkspace  = 1,
+    ReadCurWorkspace   = 1,
 
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedWorkspace")]
-    ReadWorkspaces    = 2,
+    ReadWorkspaces     = 2,
 
     #[event(input = "QueryWorkspaceRequest")]
-    DeleteWorkspace   = 3,
+    DeleteWorkspace    = 3,
 
     #[event(input = "QueryWorkspaceRequest", output = "Workspace")]
-    OpenWorkspace     = 4,
+    OpenWorkspace      = 4,
 
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedApp")]
-    ReadWorkspaceApps = 5,
+    ReadWorkspaceApps  = 5,
+
+    #[event(input = "ArchiveWorkspaceRequest")]
+    ArchiveWorkspace   = 6,
+
+    #[event(output = "RepeatedDocTask")]
+    ReadMyTasks        = 7,
+
+    #[event(input = "SearchWorkspaceRequest", output = "RepeatedSearchResult")]
+    SearchWorkspace    = 8,
+
+    #[event(input = "QueryWorkspaceRequest", output = "RepeatedShareLink")]
+    ReadShareLinks     = 9,
+
+    #[event(output = "RenameMergePolicySetting")]
+    ReadRenameMergePolicy = 10,
+
+    #[event(input = "RenameMergePolicySetting")]
+    UpdateRenameMergePolicy = 11,
+
+    #[event(input = "ShareWorkspaceRequest")]
+    ShareWorkspace     = 12,
+
+    #[event(input = "QueryWorkspaceRequest", output = "RepeatedWorkspaceMember")]
+    QueryMembers       = 13,
+
+    #[event(output = "RepeatedWorkspaceItem")]
+    QueryWorkspaces    = 14,
+
+    #[event(input = "QueryWorkspaceRequest", output = "CurrentWorkspaceSetting")]
+    SwitchWorkspace    = 15,
 
     #[event(input = "CreateAppRequest", output = "App")]
-    CreateApp         = 101,
+    CreateApp          = 101,
 
     #[event(input = "QueryAppRequest")]
-    DeleteApp         = 102,
+    DeleteApp          = 102,
 
     #[event(input = "QueryAppRequest", output = "App")]
-    ReadApp           = 103,
+    ReadApp            = 103,
 
     #[event(input = "UpdateAppRequest")]
-    UpdateApp         = 104,
+    UpdateApp          = 104,
+
+    #[event(input = "QueryAppRequest", output = "DocPrefetchProgress")]
+    PrefetchApp        = 105,
+
+    // The index is the position of the app in the apps of the workspace without the app itself
+    #[event(input = "MoveAppRequest")]
+    MoveApp            = 106,
+
+    // Copies the app with all its views and their docs, the copy is put right after the app
+    #[event(input = "QueryAppRequest", output = "App")]
+    DuplicateApp       = 107,
 
     #[event(input = "CreateViewRequest", output = "View")]
-    CreateView        = 201,
+    CreateView         = 201,
 
     #[event(input = "QueryViewRequest", output = "View")]
-    ReadView          = 202,
+    ReadView           = 202,
 
     #[event(input = "UpdateViewRequest", output = "View")]
-    UpdateView        = 203,
+    UpdateView         = 203,
 
     #[event(input = "QueryViewRequest")]
-    DeleteView        = 204,
+    DeleteView         = 204,
 
-    #[event(input = "QueryViewRequest")]
-    DuplicateView     = 205,
+    // The views nested in the view are copied too, the copy is put right after the view
+    #[event(input = "QueryViewRequest", output = "View")]
+    DuplicateView      = 205,
 
     #[event()]
-    CopyLink          = 206,
+    CopyLink           = 206,
 
     #[event(input = "QueryViewRequest", output = "DocDelta")]
-    OpenView          = 207,
+    OpenView           = 207,
+
+    #[event(input = "QueryViewRequest")]
+    CloseView          = 208,
+
+    #[event(input = "QueryViewRequest", output = "PublishedView")]
+    PublishView        = 209,
 
     #[event(input = "QueryViewRequest")]
-    CloseView         = 208,
+    UnpublishView      = 210,
+
+    #[event(input = "PinPublishedViewRequest", output = "PublishedView")]
+    PinPublishedView   = 211,
+
+    #[event(input = "QueryViewRequest", output = "PublishedView")]
+    PublishLatestView  = 212,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedViewAccessLog")]
+    ReadAccessLog      = 213,
+
+    #[event(input = "CreateShareLinkRequest", output = "ShareLink")]
+    CreateShareLink    = 214,
+
+    #[event(input = "ShareLinkIdentifier")]
+    RevokeShareLink    = 215,
+
+    // Moves the view to the index of the views that belong to the app or the view
+    #[event(input = "MoveViewRequest")]
+    MoveView           = 216,
+
+    // Pins the view to the Favorites section of the sidebar or unpins it
+    #[event(input = "QueryViewRequest", output = "ViewFavoriteState")]
+    ToggleFavorite     = 217,
+
+    #[event(output = "RepeatedFavorite")]
+    QueryFavorites     = 218,
+
+    // The views that were opened last on any of the devices of the user, with their apps and workspaces
+    #[event(input = "QueryRecentViewsRequest", output = "RepeatedRecentView")]
+    QueryRecentViews   = 219,
+
+    // Only the owner of the workspace can manage the webhook, the rendered doc is posted to the url after
+    // the doc is published or stops changing.
+    #[event(input = "SetViewWebhookRequest", output = "ViewWebhook")]
+    SetViewWebhook     = 220,
+
+    // The url of the webhook is empty if the view has no webhook
+    #[event(input = "QueryViewRequest", output = "ViewWebhook")]
+    ReadViewWebhook    = 221,
+
+    #[event(input = "QueryViewRequest")]
+    DeleteViewWebhook  = 222,
 
     #[event(output = "RepeatedTrash")]
-    ReadTrash         = 300,
+    ReadTrash          = 300,
 
     #[event(input = "TrashIdentifier")]
-    PutbackTrash      = 301,
+    PutbackTrash       = 301,
 
     #[event(input = "TrashIdentifiers")]
-    DeleteTrash       = 302,
+    DeleteTrash        = 302,
 
     #[event()]
-    RestoreAll        = 303,
+    RestoreAll         = 303,
 
     #[event()]
-    DeleteAll         = 304,
+    DeleteAll          = 304,
 
     #[event(input = "DocDelta", output = "DocDelta")]
-    ApplyDocDelta     = 400,
+    ApplyDocDelta      = 400,
+
+    #[event(input = "QueryViewRequest", output = "DocDelta")]
+    ResetDocument      = 401,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedDocTextRun")]
+    ReadDocTextRuns    = 402,
+
+    #[event(input = "DocTextReplacement", output = "DocDelta")]
+    ReplaceDocText     = 403,
+
+    #[event(output = "InputRuleSetting")]
+    ReadInputRules     = 404,
+
+    #[event(input = "InputRuleSetting")]
+    UpdateInputRules   = 405,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedDocSnapshot")]
+    ReadDocSnapshots   = 406,
+
+    #[event(input = "DocSnapshotIdentifier", output = "DocDelta")]
+    RevertToSnapshot   = 407,
+
+    #[event(input = "DocSelection")]
+    UpdateSelection    = 408,
+
+    #[event(input = "QueryViewRequest", output = "DocDelta")]
+    UndoDocument       = 409,
+
+    #[event(input = "QueryViewRequest", output = "DocDelta")]
+    RedoDocument       = 410,
+
+    #[event(input = "QueryViewRequest", output = "DocUndoState")]
+    ReadUndoState      = 411,
+
+    // The position is returned with the delta when the view is opened
+    #[event(input = "DocReadPosition")]
+    UpdateReadPosition = 412,
+
+    // The chunks are uploaded in the background, the progress is sent with the AttachmentUploadProgress
+    // notification of flowy-document.
+    #[event(input = "UploadAttachmentRequest", output = "AttachmentUploadProgress")]
+    UploadAttachment   = 413,
+
+    // Returns the file of the attachment in the local cache, it's downloaded first if it isn't cached
+    #[event(input = "AttachmentIdentifier", output = "AttachmentFile")]
+    ReadAttachment     = 414,
+
+    // The versions of the document that the server keeps, unlike ReadDocSnapshots that reads the local ones
+    #[event(input = "QueryViewRequest", output = "RepeatedDocSnapshot")]
+    QueryDocHistory    = 415,
+
+    // The version is restored on the server, so the other users who are editing the document receive it
+    #[event(input = "DocSnapshotIdentifier", output = "DocDelta")]
+    RestoreDocVersion  = 416,
 
     #[event(input = "ExportRequest", output = "ExportData")]
-    ExportDocument    = 500,
+    ExportDocument     = 500,
+
+    #[event(input = "ImportRequest", output = "DocDelta")]
+    ImportDocument     = 501,
+
+    // The progress is sent with the AppExportProgress notification until the file is written
+    #[event(input = "ExportAppRequest", output = "ExportAppProgress")]
+    ExportApp          = 502,
+
+    // Returns the blocks that ImportDocument would create and the syntax that it can't import, the doc
+    // isn't changed
+    #[event(input = "ImportRequest", output = "DocImportPreview")]
+    PreviewImport      = 503,
+
+    // Creates an app for the folder and imports its markdown files in the background, the progress is sent
+    // with the AppImportProgress notification.
+    #[event(input = "ImportFolderRequest", output = "ImportFolderProgress")]
+    ImportFolder       = 504,
+
+    // The source account is another account that signed in on this device, nothing is changed
+    #[event(input = "MergeAccountRequest", output = "AccountMergeReport")]
+    PreviewAccountMerge = 505,
+
+    // Copies the workspaces, the favorites and the settings of the source account to the current user
+    #[event(input = "MergeAccountRequest", output = "AccountMergeReport")]
+    MergeAccount       = 506,
 }
@@ -3,17 +3,21 @@ use crate::{
         app::{
             App,
             AppIdentifier,
+            AppPosition,
             CreateAppParams,
             CreateAppRequest,
+            MoveAppRequest,
             QueryAppRequest,
             UpdateAppParams,
             UpdateAppRequest,
         },
+        share::{ImportFolderParams, ImportFolderProgress, ImportFolderRequest},
         trash::Trash,
     },
     errors::WorkspaceError,
-    services::{AppController, TrashCan, ViewController},
+    services::{read_import_folder, spawn_folder_import, AppController, TrashCan, ViewController},
 };
+use flowy_document_infra::entities::doc::DocPrefetchProgress;
 use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::{convert::TryInto, sync::Arc};
 
@@ -53,6 +57,29 @@ pub(crate) async fn update_app_handler(
     Ok(())
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_app_handler(
+    data: Data<MoveAppRequest>,
+    controller: Unit<Arc<AppController>>,
+) -> Result<(), WorkspaceError> {
+    let position: AppPosition = data.into_inner().try_into()?;
+    let _ = controller.move_app(position).await?;
+    Ok(())
+}
+
+// The copied views are saved after the app, so the app is shown before its views are
+#[tracing::instrument(skip(data, app_controller, view_controller), err)]
+pub(crate) async fn duplicate_app_handler(
+    data: Data<QueryAppRequest>,
+    app_controller: Unit<Arc<AppController>>,
+    view_controller: Unit<Arc<ViewController>>,
+) -> DataResult<App, WorkspaceError> {
+    let params: AppIdentifier = data.into_inner().try_into()?;
+    let app = app_controller.duplicate_app(params).await?;
+    let _ = view_controller.save_view_copies(app.belongings.items.clone())?;
+    data_result(app)
+}
+
 #[tracing::instrument(skip(data, app_controller, view_controller))]
 pub(crate) async fn read_app_handler(
     data: Data<QueryAppRequest>,
@@ -62,6 +89,41 @@ pub(crate) async fn read_app_handler(
     let params: AppIdentifier = data.into_inner().try_into()?;
     let mut app = app_controller.read_app(params.clone()).await?;
     app.belongings = view_controller.read_views_belong_to(&params.app_id).await?;
+    if let Some(filter) = &params.view_filter {
+        let views = std::mem::take(&mut app.belongings.items);
+        app.belongings.items = filter.apply(views, |view| (&view.id, &view.name));
+    }
 
     data_result(app)
 }
+
+#[tracing::instrument(skip(data, controller))]
+pub(crate) async fn prefetch_app_handler(
+    data: Data<QueryAppRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocPrefetchProgress, WorkspaceError> {
+    let params: AppIdentifier = data.into_inner().try_into()?;
+    let progress = controller.prefetch_views(&params.app_id).await?;
+    data_result(progress)
+}
+
+// The app is created before the files are imported, so the views of the files are shown in the app while
+// they are imported.
+#[tracing::instrument(skip(data, app_controller, view_controller), err)]
+pub(crate) async fn import_folder_handler(
+    data: Data<ImportFolderRequest>,
+    app_controller: Unit<Arc<AppController>>,
+    view_controller: Unit<Arc<ViewController>>,
+) -> DataResult<ImportFolderProgress, WorkspaceError> {
+    let params: ImportFolderParams = data.into_inner().try_into()?;
+    let folder = read_import_folder(&params.path)?;
+    let params = CreateAppParams {
+        workspace_id: params.workspace_id,
+        name: folder.name.clone(),
+        desc: "".to_owned(),
+        color_style: Default::default(),
+    };
+    let app = app_controller.create_app_from_params(params).await?;
+    let progress = spawn_folder_import(app.id, folder, view_controller.get_ref().clone());
+    data_result(progress)
+}
@@ -0,0 +1,124 @@
+use crate::{
+    entities::{
+        app::{App, QueryAppRequest, UpdateAppRequest},
+        trash::{TrashIdentifier, TrashIdentifiers, TrashType},
+        view::{QueryViewRequest, UpdateViewRequest, View},
+        workspace::{QueryWorkspaceRequest, Workspace},
+    },
+    errors::WorkspaceError,
+    event::WorkspaceEvent,
+};
+use lib_dispatch::prelude::*;
+
+// The created workspace, app or view is moved to the trash and then deleted from the trash, so the batch
+// that fails leaves nothing behind, neither in the sidebar nor in the trash bin.
+
+pub(crate) fn undo_create_workspace(
+    _request: &ModuleRequest,
+    response: &EventResponse,
+) -> Result<Vec<ModuleRequest>, DispatchError> {
+    let workspace = parse_response::<Workspace>(response)?;
+    let query = QueryWorkspaceRequest::new(Some(workspace.id.clone())).into_bytes()?;
+    Ok(vec![
+        ModuleRequest::new(WorkspaceEvent::DeleteWorkspace).payload(query),
+        delete_trash_request(workspace.id, TrashType::Workspace)?,
+    ])
+}
+
+pub(crate) fn undo_create_app(
+    _request: &ModuleRequest,
+    response: &EventResponse,
+) -> Result<Vec<ModuleRequest>, DispatchError> {
+    let app = parse_response::<App>(response)?;
+    let query = QueryAppRequest::new(&app.id).into_bytes()?;
+    Ok(vec![
+        ModuleRequest::new(WorkspaceEvent::DeleteApp).payload(query),
+        delete_trash_request(app.id, TrashType::App)?,
+    ])
+}
+
+pub(crate) fn undo_create_view(
+    _request: &ModuleRequest,
+    response: &EventResponse,
+) -> Result<Vec<ModuleRequest>, DispatchError> {
+    let view = parse_response::<View>(response)?;
+    let query = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    }
+    .into_bytes()?;
+    Ok(vec![
+        ModuleRequest::new(WorkspaceEvent::DeleteView).payload(query),
+        delete_trash_request(view.id, TrashType::View)?,
+    ])
+}
+
+// The updated app or view is read before the update and its old name and desc are written back. The
+// color_style, is_trash and thumbnail aren't kept in the App or the View, so the updates of them can't be undone.
+
+pub(crate) fn read_app_state(request: &ModuleRequest) -> Result<ModuleRequest, DispatchError> {
+    let params = request.parse_payload::<UpdateAppRequest>()?;
+    let query = QueryAppRequest::new(&params.app_id).into_bytes()?;
+    Ok(ModuleRequest::new(WorkspaceEvent::ReadApp).payload(query))
+}
+
+pub(crate) fn undo_update_app(
+    request: &ModuleRequest,
+    state: &EventResponse,
+) -> Result<Vec<ModuleRequest>, DispatchError> {
+    let params = request.parse_payload::<UpdateAppRequest>()?;
+    if params.color_style.is_some() || params.is_trash.is_some() {
+        let msg = format!("The update of the color_style or is_trash of app {} can't be undone", params.app_id);
+        return Err(msg.into());
+    }
+    let app = parse_response::<App>(state)?;
+    let undo = UpdateAppRequest {
+        app_id: app.id,
+        name: params.name.map(|_| app.name),
+        desc: params.desc.map(|_| app.desc),
+        color_style: None,
+        is_trash: None,
+    }
+    .into_bytes()?;
+    Ok(vec![ModuleRequest::new(WorkspaceEvent::UpdateApp).payload(undo)])
+}
+
+pub(crate) fn read_view_state(request: &ModuleRequest) -> Result<ModuleRequest, DispatchError> {
+    let params = request.parse_payload::<UpdateViewRequest>()?;
+    let query = QueryViewRequest {
+        view_ids: vec![params.view_id],
+    }
+    .into_bytes()?;
+    Ok(ModuleRequest::new(WorkspaceEvent::ReadView).payload(query))
+}
+
+pub(crate) fn undo_update_view(
+    request: &ModuleRequest,
+    state: &EventResponse,
+) -> Result<Vec<ModuleRequest>, DispatchError> {
+    let params = request.parse_payload::<UpdateViewRequest>()?;
+    if params.thumbnail.is_some() {
+        return Err(format!("The update of the thumbnail of view {} can't be undone", params.view_id).into());
+    }
+    let view = parse_response::<View>(state)?;
+    let undo = UpdateViewRequest {
+        view_id: view.id,
+        name: params.name.map(|_| view.name),
+        desc: params.desc.map(|_| view.desc),
+        thumbnail: None,
+    }
+    .into_bytes()?;
+    Ok(vec![ModuleRequest::new(WorkspaceEvent::UpdateView).payload(undo)])
+}
+
+fn delete_trash_request(id: String, ty: TrashType) -> Result<ModuleRequest, DispatchError> {
+    let identifiers: TrashIdentifiers = vec![TrashIdentifier { id, ty }].into();
+    let payload = identifiers.into_bytes()?;
+    Ok(ModuleRequest::new(WorkspaceEvent::DeleteTrash).payload(payload))
+}
+
+fn parse_response<T: FromBytes>(response: &EventResponse) -> Result<T, DispatchError> {
+    match response.clone().parse::<T, WorkspaceError>()? {
+        Ok(data) => Ok(data),
+        Err(e) => Err(format!("{:?}", e).into()),
+    }
+}
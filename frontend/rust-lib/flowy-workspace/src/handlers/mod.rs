@@ -1,9 +1,11 @@
 mod app_handler;
+mod compensation;
 mod trash_handler;
 mod view_handler;
 mod workspace_handler;
 
 pub(crate) use app_handler::*;
+pub(crate) use compensation::*;
 pub(crate) use trash_handler::*;
 pub(crate) use view_handler::*;
 pub(crate) use workspace_handler::*;
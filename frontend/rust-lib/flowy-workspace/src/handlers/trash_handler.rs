@@ -1,15 +1,24 @@
 use crate::{
     entities::trash::{RepeatedTrash, TrashIdentifier, TrashIdentifiers},
-    errors::WorkspaceError,
+    errors::{internal_error, WorkspaceError},
     services::TrashCan,
 };
-use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
+use lib_dispatch::prelude::{data_result, BlockingPool, Data, DataResult, Unit};
 use std::sync::Arc;
 
-#[tracing::instrument(skip(controller), err)]
-pub(crate) async fn read_trash_handler(controller: Unit<Arc<TrashCan>>) -> DataResult<RepeatedTrash, WorkspaceError> {
-    let conn = controller.database.db_connection()?;
-    let repeated_trash = controller.read_trash(&conn)?;
+#[tracing::instrument(skip(controller, pool), err)]
+pub(crate) async fn read_trash_handler(
+    controller: Unit<Arc<TrashCan>>,
+    pool: Unit<BlockingPool>,
+) -> DataResult<RepeatedTrash, WorkspaceError> {
+    let controller = controller.get_ref().clone();
+    let repeated_trash = pool
+        .spawn(move || {
+            let conn = controller.database.db_connection()?;
+            controller.read_trash(&conn)
+        })
+        .await
+        .map_err(internal_error)??;
     data_result(repeated_trash)
 }
 
@@ -2,21 +2,64 @@ use crate::{
     entities::{
         trash::Trash,
         view::{
+            CreateShareLinkParams,
+            CreateShareLinkRequest,
             CreateViewParams,
             CreateViewRequest,
+            MoveViewRequest,
+            PinPublishedViewParams,
+            PinPublishedViewRequest,
+            PublishedView,
+            QueryRecentViewsParams,
+            QueryRecentViewsRequest,
             QueryViewRequest,
+            RepeatedFavorite,
+            RepeatedRecentView,
+            RepeatedViewAccessLog,
+            SetViewWebhookParams,
+            SetViewWebhookRequest,
+            ShareLink,
+            ShareLinkIdentifier,
             UpdateViewParams,
             UpdateViewRequest,
             View,
+            ViewFavoriteState,
             ViewIdentifier,
             ViewIdentifiers,
+            ViewPosition,
+            ViewWebhook,
         },
     },
     errors::WorkspaceError,
     services::{TrashCan, ViewController},
 };
-use flowy_document_infra::entities::doc::DocDelta;
-use flowy_workspace_infra::entities::share::{ExportData, ExportParams, ExportRequest};
+use flowy_document_infra::entities::doc::{
+    AttachmentFile,
+    AttachmentIdentifier,
+    AttachmentUploadProgress,
+    DocDelta,
+    DocImportPreview,
+    DocReadPosition,
+    DocSelection,
+    DocSnapshotIdentifier,
+    DocTextReplacement,
+    DocUndoState,
+    InputRuleSetting,
+    RepeatedDocSnapshot,
+    RepeatedDocTextRun,
+};
+use flowy_workspace_infra::entities::share::{
+    ExportAppParams,
+    ExportAppProgress,
+    ExportAppRequest,
+    ExportData,
+    ExportParams,
+    ExportRequest,
+    ImportParams,
+    ImportRequest,
+    UploadAttachmentParams,
+    UploadAttachmentRequest,
+};
 use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::{convert::TryInto, sync::Arc};
 
@@ -29,6 +72,16 @@ pub(crate) async fn create_view_handler(
     data_result(view)
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn move_view_handler(
+    data: Data<MoveViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), WorkspaceError> {
+    let position: ViewPosition = data.into_inner().try_into()?;
+    let _ = controller.move_view(position).await?;
+    Ok(())
+}
+
 pub(crate) async fn read_view_handler(
     data: Data<QueryViewRequest>,
     controller: Unit<Arc<ViewController>>,
@@ -60,6 +113,157 @@ pub(crate) async fn apply_doc_delta_handler(
     data_result(doc)
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn reset_document_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocDelta, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let doc = controller.reset_document(params.into()).await?;
+    data_result(doc)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_doc_text_runs_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedDocTextRun, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let repeated_run = controller.read_doc_text_runs(params.into()).await?;
+    data_result(repeated_run)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn replace_doc_text_handler(
+    data: Data<DocTextReplacement>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocDelta, WorkspaceError> {
+    let doc = controller.replace_doc_text(data.into_inner()).await?;
+    data_result(doc)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_selection_handler(
+    data: Data<DocSelection>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.update_doc_selection(data.into_inner()).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn undo_document_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocDelta, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let doc = controller.undo_document(params.into()).await?;
+    data_result(doc)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn redo_document_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocDelta, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let doc = controller.redo_document(params.into()).await?;
+    data_result(doc)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_undo_state_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocUndoState, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let state = controller.read_doc_undo_state(params.into()).await?;
+    data_result(state)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_input_rules_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<InputRuleSetting, WorkspaceError> {
+    let setting = controller.read_input_rules()?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_input_rules_handler(
+    data: Data<InputRuleSetting>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.update_input_rules(data.into_inner())?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn update_read_position_handler(
+    data: Data<DocReadPosition>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.update_read_position(data.into_inner())?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_doc_snapshots_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedDocSnapshot, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let repeated_snapshot = controller.read_doc_snapshots(params.into())?;
+    data_result(repeated_snapshot)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn revert_to_snapshot_handler(
+    data: Data<DocSnapshotIdentifier>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocDelta, WorkspaceError> {
+    let doc = controller.revert_to_snapshot(data.into_inner()).await?;
+    data_result(doc)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn query_doc_history_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedDocSnapshot, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let repeated_snapshot = controller.query_doc_history(params.into()).await?;
+    data_result(repeated_snapshot)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn restore_doc_version_handler(
+    data: Data<DocSnapshotIdentifier>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocDelta, WorkspaceError> {
+    let doc = controller.restore_doc_version(data.into_inner()).await?;
+    data_result(doc)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn upload_attachment_handler(
+    data: Data<UploadAttachmentRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<AttachmentUploadProgress, WorkspaceError> {
+    let params: UploadAttachmentParams = data.into_inner().try_into()?;
+    let progress = controller.upload_attachment(params).await?;
+    data_result(progress)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_attachment_handler(
+    data: Data<AttachmentIdentifier>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<AttachmentFile, WorkspaceError> {
+    let file = controller.read_attachment(data.into_inner()).await?;
+    data_result(file)
+}
+
 pub(crate) async fn delete_view_handler(
     data: Data<QueryViewRequest>,
     controller: Unit<Arc<ViewController>>,
@@ -99,15 +303,142 @@ pub(crate) async fn close_view_handler(
 }
 
 #[tracing::instrument(skip(data, controller), err)]
-pub(crate) async fn duplicate_view_handler(
+pub(crate) async fn publish_view_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<PublishedView, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let published_view = controller.publish_view(params).await?;
+    data_result(published_view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn unpublish_view_handler(
     data: Data<QueryViewRequest>,
     controller: Unit<Arc<ViewController>>,
 ) -> Result<(), WorkspaceError> {
     let params: ViewIdentifier = data.into_inner().try_into()?;
-    let _ = controller.duplicate_view(params.into()).await?;
+    let _ = controller.unpublish_view(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn pin_published_view_handler(
+    data: Data<PinPublishedViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<PublishedView, WorkspaceError> {
+    let params: PinPublishedViewParams = data.into_inner().try_into()?;
+    let published_view = controller.pin_published_view(params).await?;
+    data_result(published_view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn publish_latest_view_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<PublishedView, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let published_view = controller.publish_latest_view(params).await?;
+    data_result(published_view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_access_log_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedViewAccessLog, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let repeated_log = controller.read_access_log(params).await?;
+    data_result(repeated_log)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn create_share_link_handler(
+    data: Data<CreateShareLinkRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ShareLink, WorkspaceError> {
+    let params: CreateShareLinkParams = data.into_inner().try_into()?;
+    let share_link = controller.create_share_link(params).await?;
+    data_result(share_link)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn revoke_share_link_handler(
+    data: Data<ShareLinkIdentifier>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), WorkspaceError> {
+    let _ = controller.revoke_share_link(data.into_inner()).await?;
     Ok(())
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn set_view_webhook_handler(
+    data: Data<SetViewWebhookRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewWebhook, WorkspaceError> {
+    let params: SetViewWebhookParams = data.into_inner().try_into()?;
+    let webhook = controller.set_view_webhook(params).await?;
+    data_result(webhook)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_view_webhook_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewWebhook, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let webhook = controller.read_view_webhook(params).await?;
+    data_result(webhook)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn delete_view_webhook_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let _ = controller.delete_view_webhook(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn duplicate_view_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let view = controller.duplicate_view(params).await?;
+    data_result(view)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn toggle_favorite_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewFavoriteState, WorkspaceError> {
+    let params: ViewIdentifier = data.into_inner().try_into()?;
+    let state = controller.toggle_favorite(params).await?;
+    data_result(state)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn query_favorites_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedFavorite, WorkspaceError> {
+    let repeated_favorite = controller.read_favorites()?;
+    data_result(repeated_favorite)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn query_recent_views_handler(
+    data: Data<QueryRecentViewsRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedRecentView, WorkspaceError> {
+    let params: QueryRecentViewsParams = data.into_inner().try_into()?;
+    let repeated_recent_view = controller.read_recent_views(params)?;
+    data_result(repeated_recent_view)
+}
+
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn export_handler(
     data: Data<ExportRequest>,
@@ -117,3 +448,33 @@ pub(crate) async fn export_handler(
     let data = controller.export_doc(params.into()).await?;
     data_result(data)
 }
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn export_app_handler(
+    data: Data<ExportAppRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ExportAppProgress, WorkspaceError> {
+    let params: ExportAppParams = data.into_inner().try_into()?;
+    let progress = controller.export_app(params).await?;
+    data_result(progress)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn import_handler(
+    data: Data<ImportRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocDelta, WorkspaceError> {
+    let params: ImportParams = data.into_inner().try_into()?;
+    let doc = controller.import_doc(params).await?;
+    data_result(doc)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn preview_import_handler(
+    data: Data<ImportRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocImportPreview, WorkspaceError> {
+    let params: ImportParams = data.into_inner().try_into()?;
+    let preview = controller.preview_import(params)?;
+    data_result(preview)
+}
@@ -1,6 +1,11 @@
-use crate::{errors::WorkspaceError, services::WorkspaceController};
+use crate::{
+    entities::trash::Trash,
+    errors::WorkspaceError,
+    services::{TrashCan, WorkspaceController, WorkspaceSync},
+};
 
-use flowy_workspace_infra::entities::{app::RepeatedApp, workspace::*};
+use flowy_document_infra::entities::doc::RepeatedDocTask;
+use flowy_workspace_infra::entities::{app::RepeatedApp, view::RepeatedShareLink, workspace::*};
 use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::{convert::TryInto, sync::Arc};
 
@@ -31,6 +36,109 @@ pub(crate) async fn read_workspace_apps_handler(
     data_result(repeated_app)
 }
 
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn read_my_tasks_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedDocTask, WorkspaceError> {
+    let repeated_task = controller.read_current_workspace_tasks().await?;
+    data_result(repeated_task)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn search_workspace_handler(
+    data: Data<SearchWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedSearchResult, WorkspaceError> {
+    let params: SearchWorkspaceParams = data.into_inner().try_into()?;
+    let repeated_result = controller.search_workspace(params).await?;
+    data_result(repeated_result)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn read_share_links_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedShareLink, WorkspaceError> {
+    let params: WorkspaceIdentifier = data.into_inner().try_into()?;
+    let repeated_share_link = controller.read_share_links(params).await?;
+    data_result(repeated_share_link)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn share_workspace_handler(
+    data: Data<ShareWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let params: ShareWorkspaceParams = data.into_inner().try_into()?;
+    let _ = controller.share_workspace(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn query_members_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedWorkspaceMember, WorkspaceError> {
+    let params: WorkspaceIdentifier = data.into_inner().try_into()?;
+    let repeated_member = controller.read_members(params).await?;
+    data_result(repeated_member)
+}
+
+#[tracing::instrument(skip(controller), err)]
+pub(crate) async fn query_workspaces_handler(
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<RepeatedWorkspaceItem, WorkspaceError> {
+    let repeated_item = controller.query_workspaces().await?;
+    data_result(repeated_item)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn switch_workspace_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<CurrentWorkspaceSetting, WorkspaceError> {
+    let params: WorkspaceIdentifier = data.into_inner().try_into()?;
+    let setting = controller.switch_workspace(params).await?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn preview_account_merge_handler(
+    data: Data<MergeAccountRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<AccountMergeReport, WorkspaceError> {
+    let params: MergeAccountParams = data.into_inner().try_into()?;
+    let report = controller.preview_account_merge(params).await?;
+    data_result(report)
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn merge_account_handler(
+    data: Data<MergeAccountRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> DataResult<AccountMergeReport, WorkspaceError> {
+    let params: MergeAccountParams = data.into_inner().try_into()?;
+    let report = controller.merge_account(params).await?;
+    data_result(report)
+}
+
+#[tracing::instrument(skip(workspace_sync), err)]
+pub(crate) async fn read_rename_merge_policy_handler(
+    workspace_sync: Unit<Arc<WorkspaceSync>>,
+) -> DataResult<RenameMergePolicySetting, WorkspaceError> {
+    let setting = workspace_sync.read_rename_merge_policy()?;
+    data_result(setting)
+}
+
+#[tracing::instrument(skip(data, workspace_sync), err)]
+pub(crate) async fn update_rename_merge_policy_handler(
+    data: Data<RenameMergePolicySetting>,
+    workspace_sync: Unit<Arc<WorkspaceSync>>,
+) -> Result<(), WorkspaceError> {
+    let _ = workspace_sync.update_rename_merge_policy(data.into_inner())?;
+    Ok(())
+}
+
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn read_workspaces_handler(
     data: Data<QueryWorkspaceRequest>,
@@ -50,3 +158,34 @@ pub(crate) async fn open_workspace_handler(
     let workspaces = controller.open_workspace(params).await?;
     data_result(workspaces)
 }
+
+// The workspace is moved to the trash, it's deleted for good when the trash is deleted
+#[tracing::instrument(skip(data, controller, trash_can), err)]
+pub(crate) async fn delete_workspace_handler(
+    data: Data<QueryWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+    trash_can: Unit<Arc<TrashCan>>,
+) -> Result<(), WorkspaceError> {
+    let params: WorkspaceIdentifier = data.into_inner().try_into()?;
+    let workspace_id = params
+        .workspace_id
+        .ok_or_else(|| WorkspaceError::workspace_id().context("Deleted workspace id should not be empty"))?;
+    let trash = controller
+        .read_workspace_tables(vec![workspace_id])?
+        .into_iter()
+        .map(|workspace_table| workspace_table.into())
+        .collect::<Vec<Trash>>();
+
+    let _ = trash_can.add(trash).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn archive_workspace_handler(
+    data: Data<ArchiveWorkspaceRequest>,
+    controller: Unit<Arc<WorkspaceController>>,
+) -> Result<(), WorkspaceError> {
+    let params: ArchiveWorkspaceParams = data.into_inner().try_into()?;
+    let _ = controller.archive_workspace(params).await?;
+    Ok(())
+}
@@ -2,12 +2,21 @@ use crate::{
     errors::WorkspaceError,
     event::WorkspaceEvent,
     handlers::*,
-    services::{server::construct_workspace_server, AppController, TrashCan, ViewController, WorkspaceController},
+    services::{
+        server::construct_workspace_server,
+        AppController,
+        TrashCan,
+        ViewController,
+        WorkspaceCache,
+        WorkspaceController,
+        WorkspaceSync,
+    },
 };
 use backend_service::config::ServerConfig;
 use flowy_database::DBConnection;
 use flowy_document::module::FlowyDocument;
 use lib_dispatch::prelude::*;
+use lib_infra::bus::EventBus;
 use lib_sqlite::ConnectionPool;
 use std::sync::Arc;
 
@@ -26,6 +35,12 @@ pub trait WorkspaceDatabase: Send + Sync {
         let conn = pool.get().map_err(|e| WorkspaceError::internal().context(e))?;
         Ok(conn)
     }
+
+    // The database of another user that signed in on this device, e.g. the guest whose data is merged into
+    // the current user. It must be closed after use.
+    fn local_user_db_pool(&self, user_id: &str) -> Result<Arc<ConnectionPool>, WorkspaceError>;
+
+    fn close_local_user_db(&self, user_id: &str);
 }
 
 pub fn mk_workspace(
@@ -33,23 +48,37 @@ pub fn mk_workspace(
     database: Arc<dyn WorkspaceDatabase>,
     flowy_document: Arc<FlowyDocument>,
     server_config: &ServerConfig,
+    bus: EventBus,
 ) -> Arc<WorkspaceController> {
     let server = construct_workspace_server(server_config);
 
     let trash_can = Arc::new(TrashCan::new(database.clone(), server.clone(), user.clone()));
+    let cache = Arc::new(WorkspaceCache::new());
+    let workspace_sync = Arc::new(WorkspaceSync::new(
+        user.clone(),
+        database.clone(),
+        cache.clone(),
+        server.clone(),
+        bus.clone(),
+    ));
 
     let view_controller = Arc::new(ViewController::new(
         user.clone(),
         database.clone(),
         server.clone(),
         trash_can.clone(),
+        workspace_sync.clone(),
+        cache.clone(),
         flowy_document,
+        bus.clone(),
     ));
 
     let app_controller = Arc::new(AppController::new(
         user.clone(),
         database.clone(),
         trash_can.clone(),
+        workspace_sync.clone(),
+        cache.clone(),
         server.clone(),
     ));
 
@@ -59,7 +88,10 @@ pub fn mk_workspace(
         app_controller.clone(),
         view_controller.clone(),
         trash_can.clone(),
+        workspace_sync.clone(),
+        cache,
         server.clone(),
+        bus,
     ));
     workspace_controller
 }
@@ -70,20 +102,35 @@ pub fn create(workspace: Arc<WorkspaceController>) -> Module {
         .data(workspace.clone())
         .data(workspace.app_controller.clone())
         .data(workspace.view_controller.clone())
-        .data(workspace.trash_can.clone());
+        .data(workspace.trash_can.clone())
+        .data(workspace.workspace_sync.clone());
 
     module = module
         .event(WorkspaceEvent::CreateWorkspace, create_workspace_handler)
         .event(WorkspaceEvent::ReadCurWorkspace, read_cur_workspace_handler)
         .event(WorkspaceEvent::ReadWorkspaces, read_workspaces_handler)
         .event(WorkspaceEvent::OpenWorkspace, open_workspace_handler)
-        .event(WorkspaceEvent::ReadWorkspaceApps, read_workspace_apps_handler);
+        .event(WorkspaceEvent::DeleteWorkspace, delete_workspace_handler)
+        .event(WorkspaceEvent::ReadWorkspaceApps, read_workspace_apps_handler)
+        .event(WorkspaceEvent::ArchiveWorkspace, archive_workspace_handler)
+        .event(WorkspaceEvent::ReadMyTasks, read_my_tasks_handler)
+        .event(WorkspaceEvent::SearchWorkspace, search_workspace_handler)
+        .event(WorkspaceEvent::ReadShareLinks, read_share_links_handler)
+        .event(WorkspaceEvent::ReadRenameMergePolicy, read_rename_merge_policy_handler)
+        .event(WorkspaceEvent::UpdateRenameMergePolicy, update_rename_merge_policy_handler)
+        .event(WorkspaceEvent::ShareWorkspace, share_workspace_handler)
+        .event(WorkspaceEvent::QueryMembers, query_members_handler)
+        .event(WorkspaceEvent::QueryWorkspaces, query_workspaces_handler)
+        .event(WorkspaceEvent::SwitchWorkspace, switch_workspace_handler);
 
     module = module
         .event(WorkspaceEvent::CreateApp, create_app_handler)
         .event(WorkspaceEvent::ReadApp, read_app_handler)
         .event(WorkspaceEvent::UpdateApp, update_app_handler)
-        .event(WorkspaceEvent::DeleteApp, delete_app_handler);
+        .event(WorkspaceEvent::DeleteApp, delete_app_handler)
+        .event(WorkspaceEvent::PrefetchApp, prefetch_app_handler)
+        .event(WorkspaceEvent::MoveApp, move_app_handler)
+        .event(WorkspaceEvent::DuplicateApp, duplicate_app_handler);
 
     module = module
         .event(WorkspaceEvent::CreateView, create_view_handler)
@@ -93,7 +140,37 @@ pub fn create(workspace: Arc<WorkspaceController>) -> Module {
         .event(WorkspaceEvent::DuplicateView, duplicate_view_handler)
         .event(WorkspaceEvent::OpenView, open_view_handler)
         .event(WorkspaceEvent::CloseView, close_view_handler)
-        .event(WorkspaceEvent::ApplyDocDelta, apply_doc_delta_handler);
+        .event(WorkspaceEvent::PublishView, publish_view_handler)
+        .event(WorkspaceEvent::UnpublishView, unpublish_view_handler)
+        .event(WorkspaceEvent::PinPublishedView, pin_published_view_handler)
+        .event(WorkspaceEvent::PublishLatestView, publish_latest_view_handler)
+        .event(WorkspaceEvent::ReadAccessLog, read_access_log_handler)
+        .event(WorkspaceEvent::CreateShareLink, create_share_link_handler)
+        .event(WorkspaceEvent::RevokeShareLink, revoke_share_link_handler)
+        .event(WorkspaceEvent::MoveView, move_view_handler)
+        .event(WorkspaceEvent::ToggleFavorite, toggle_favorite_handler)
+        .event(WorkspaceEvent::QueryFavorites, query_favorites_handler)
+        .event(WorkspaceEvent::QueryRecentViews, query_recent_views_handler)
+        .event(WorkspaceEvent::SetViewWebhook, set_view_webhook_handler)
+        .event(WorkspaceEvent::ReadViewWebhook, read_view_webhook_handler)
+        .event(WorkspaceEvent::DeleteViewWebhook, delete_view_webhook_handler)
+        .event(WorkspaceEvent::ApplyDocDelta, apply_doc_delta_handler)
+        .event(WorkspaceEvent::ResetDocument, reset_document_handler)
+        .event(WorkspaceEvent::ReadDocTextRuns, read_doc_text_runs_handler)
+        .event(WorkspaceEvent::ReplaceDocText, replace_doc_text_handler)
+        .event(WorkspaceEvent::ReadInputRules, read_input_rules_handler)
+        .event(WorkspaceEvent::UpdateInputRules, update_input_rules_handler)
+        .event(WorkspaceEvent::ReadDocSnapshots, read_doc_snapshots_handler)
+        .event(WorkspaceEvent::RevertToSnapshot, revert_to_snapshot_handler)
+        .event(WorkspaceEvent::UpdateSelection, update_selection_handler)
+        .event(WorkspaceEvent::UndoDocument, undo_document_handler)
+        .event(WorkspaceEvent::RedoDocument, redo_document_handler)
+        .event(WorkspaceEvent::ReadUndoState, read_undo_state_handler)
+        .event(WorkspaceEvent::UpdateReadPosition, update_read_position_handler)
+        .event(WorkspaceEvent::UploadAttachment, upload_attachment_handler)
+        .event(WorkspaceEvent::ReadAttachment, read_attachment_handler)
+        .event(WorkspaceEvent::QueryDocHistory, query_doc_history_handler)
+        .event(WorkspaceEvent::RestoreDocVersion, restore_doc_version_handler);
 
     module = module
         .event(WorkspaceEvent::ReadTrash, read_trash_handler)
@@ -102,7 +179,48 @@ pub fn create(workspace: Arc<WorkspaceController>) -> Module {
         .event(WorkspaceEvent::RestoreAll, restore_all_handler)
         .event(WorkspaceEvent::DeleteAll, delete_all_handler);
 
-    module = module.event(WorkspaceEvent::ExportDocument, export_handler);
+    module = module
+        .event(WorkspaceEvent::ExportDocument, export_handler)
+        .event(WorkspaceEvent::ImportDocument, import_handler)
+        .event(WorkspaceEvent::PreviewImport, preview_import_handler)
+        .event(WorkspaceEvent::ExportApp, export_app_handler)
+        .event(WorkspaceEvent::ImportFolder, import_folder_handler)
+        .event(WorkspaceEvent::PreviewAccountMerge, preview_account_merge_handler)
+        .event(WorkspaceEvent::MergeAccount, merge_account_handler);
 
-    module
+    module = module
+        .compensate(WorkspaceEvent::CreateWorkspace, undo_create_workspace)
+        .compensate(WorkspaceEvent::CreateApp, undo_create_app)
+        .compensate(WorkspaceEvent::CreateView, undo_create_view)
+        .compensate_with_state(WorkspaceEvent::UpdateApp, read_app_state, undo_update_app)
+        .compensate_with_state(WorkspaceEvent::UpdateView, read_view_state, undo_update_view);
+
+    // The read events can be put in a transactional batch without being undone
+    module = module
+        .read_only(WorkspaceEvent::ReadCurWorkspace)
+        .read_only(WorkspaceEvent::ReadWorkspaces)
+        .read_only(WorkspaceEvent::ReadWorkspaceApps)
+        .read_only(WorkspaceEvent::ReadMyTasks)
+        .read_only(WorkspaceEvent::SearchWorkspace)
+        .read_only(WorkspaceEvent::ReadShareLinks)
+        .read_only(WorkspaceEvent::ReadRenameMergePolicy)
+        .read_only(WorkspaceEvent::QueryMembers)
+        .read_only(WorkspaceEvent::QueryWorkspaces)
+        .read_only(WorkspaceEvent::ReadApp)
+        .read_only(WorkspaceEvent::ReadView)
+        .read_only(WorkspaceEvent::ReadAccessLog)
+        .read_only(WorkspaceEvent::QueryFavorites)
+        .read_only(WorkspaceEvent::QueryRecentViews)
+        .read_only(WorkspaceEvent::ReadViewWebhook)
+        .read_only(WorkspaceEvent::ReadDocTextRuns)
+        .read_only(WorkspaceEvent::ReadInputRules)
+        .read_only(WorkspaceEvent::ReadDocSnapshots)
+        .read_only(WorkspaceEvent::ReadUndoState)
+        .read_only(WorkspaceEvent::ReadAttachment)
+        .read_only(WorkspaceEvent::QueryDocHistory)
+        .read_only(WorkspaceEvent::ReadTrash)
+        .read_only(WorkspaceEvent::PreviewImport)
+        .read_only(WorkspaceEvent::PreviewAccountMerge);
+
+    module.guard_table(WorkspaceEvent::guard_table())
 }
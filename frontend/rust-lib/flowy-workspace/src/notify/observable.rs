@@ -12,11 +12,18 @@ pub(crate) enum WorkspaceNotification {
     WorkspaceUpdated     = 12,
     WorkspaceListUpdated = 13,
     WorkspaceAppsChanged = 14,
+    RenameConflicted     = 15,
+    CurrentWorkspaceChanged = 16,
     AppUpdated           = 21,
     AppViewsChanged      = 24,
+    AppExportProgress    = 25,
+    AppImportProgress    = 26,
     ViewUpdated          = 31,
     ViewDeleted          = 32,
     ViewRestored         = 33,
+    ViewUnreadChanged    = 34,
+    FavoritesChanged     = 35,
+    RecentViewsChanged   = 36,
     UserUnauthorized     = 100,
     TrashUpdated         = 1000,
 }
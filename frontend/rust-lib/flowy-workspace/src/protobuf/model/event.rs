@@ -31,10 +31,23 @@ pub enum WorkspaceEvent {
     DeleteWorkspace = 3,
     OpenWorkspace = 4,
     ReadWorkspaceApps = 5,
+    ArchiveWorkspace = 6,
+    ReadMyTasks = 7,
+    SearchWorkspace = 8,
+    ReadShareLinks = 9,
+    ReadRenameMergePolicy = 10,
+    UpdateRenameMergePolicy = 11,
+    ShareWorkspace = 12,
+    QueryMembers = 13,
+    QueryWorkspaces = 14,
+    SwitchWorkspace = 15,
     CreateApp = 101,
     DeleteApp = 102,
     ReadApp = 103,
     UpdateApp = 104,
+    PrefetchApp = 105,
+    MoveApp = 106,
+    DuplicateApp = 107,
     CreateView = 201,
     ReadView = 202,
     UpdateView = 203,
@@ -43,13 +56,49 @@ pub enum WorkspaceEvent {
     CopyLink = 206,
     OpenView = 207,
     CloseView = 208,
+    PublishView = 209,
+    UnpublishView = 210,
+    PinPublishedView = 211,
+    PublishLatestView = 212,
+    ReadAccessLog = 213,
+    CreateShareLink = 214,
+    RevokeShareLink = 215,
+    MoveView = 216,
+    ToggleFavorite = 217,
+    QueryFavorites = 218,
+    QueryRecentViews = 219,
+    SetViewWebhook = 220,
+    ReadViewWebhook = 221,
+    DeleteViewWebhook = 222,
     ReadTrash = 300,
     PutbackTrash = 301,
     DeleteTrash = 302,
     RestoreAll = 303,
     DeleteAll = 304,
     ApplyDocDelta = 400,
+    ResetDocument = 401,
+    ReadDocTextRuns = 402,
+    ReplaceDocText = 403,
+    ReadInputRules = 404,
+    UpdateInputRules = 405,
+    ReadDocSnapshots = 406,
+    RevertToSnapshot = 407,
+    UpdateSelection = 408,
+    UndoDocument = 409,
+    RedoDocument = 410,
+    ReadUndoState = 411,
+    UpdateReadPosition = 412,
+    UploadAttachment = 413,
+    ReadAttachment = 414,
+    QueryDocHistory = 415,
+    RestoreDocVersion = 416,
     ExportDocument = 500,
+    ImportDocument = 501,
+    ExportApp = 502,
+    PreviewImport = 503,
+    ImportFolder = 504,
+    PreviewAccountMerge = 505,
+    MergeAccount = 506,
 }
 
 impl ::protobuf::ProtobufEnum for WorkspaceEvent {
@@ -65,10 +114,23 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             3 => ::std::option::Option::Some(WorkspaceEvent::DeleteWorkspace),
             4 => ::std::option::Option::Some(WorkspaceEvent::OpenWorkspace),
             5 => ::std::option::Option::Some(WorkspaceEvent::ReadWorkspaceApps),
+            6 => ::std::option::Option::Some(WorkspaceEvent::ArchiveWorkspace),
+            7 => ::std::option::Option::Some(WorkspaceEvent::ReadMyTasks),
+            8 => ::std::option::Option::Some(WorkspaceEvent::SearchWorkspace),
+            9 => ::std::option::Option::Some(WorkspaceEvent::ReadShareLinks),
+            10 => ::std::option::Option::Some(WorkspaceEvent::ReadRenameMergePolicy),
+            11 => ::std::option::Option::Some(WorkspaceEvent::UpdateRenameMergePolicy),
+            12 => ::std::option::Option::Some(WorkspaceEvent::ShareWorkspace),
+            13 => ::std::option::Option::Some(WorkspaceEvent::QueryMembers),
+            14 => ::std::option::Option::Some(WorkspaceEvent::QueryWorkspaces),
+            15 => ::std::option::Option::Some(WorkspaceEvent::SwitchWorkspace),
             101 => ::std::option::Option::Some(WorkspaceEvent::CreateApp),
             102 => ::std::option::Option::Some(WorkspaceEvent::DeleteApp),
             103 => ::std::option::Option::Some(WorkspaceEvent::ReadApp),
             104 => ::std::option::Option::Some(WorkspaceEvent::UpdateApp),
+            105 => ::std::option::Option::Some(WorkspaceEvent::PrefetchApp),
+            106 => ::std::option::Option::Some(WorkspaceEvent::MoveApp),
+            107 => ::std::option::Option::Some(WorkspaceEvent::DuplicateApp),
             201 => ::std::option::Option::Some(WorkspaceEvent::CreateView),
             202 => ::std::option::Option::Some(WorkspaceEvent::ReadView),
             203 => ::std::option::Option::Some(WorkspaceEvent::UpdateView),
@@ -77,13 +139,49 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             206 => ::std::option::Option::Some(WorkspaceEvent::CopyLink),
             207 => ::std::option::Option::Some(WorkspaceEvent::OpenView),
             208 => ::std::option::Option::Some(WorkspaceEvent::CloseView),
+            209 => ::std::option::Option::Some(WorkspaceEvent::PublishView),
+            210 => ::std::option::Option::Some(WorkspaceEvent::UnpublishView),
+            211 => ::std::option::Option::Some(WorkspaceEvent::PinPublishedView),
+            212 => ::std::option::Option::Some(WorkspaceEvent::PublishLatestView),
+            213 => ::std::option::Option::Some(WorkspaceEvent::ReadAccessLog),
+            214 => ::std::option::Option::Some(WorkspaceEvent::CreateShareLink),
+            215 => ::std::option::Option::Some(WorkspaceEvent::RevokeShareLink),
+            216 => ::std::option::Option::Some(WorkspaceEvent::MoveView),
+            217 => ::std::option::Option::Some(WorkspaceEvent::ToggleFavorite),
+            218 => ::std::option::Option::Some(WorkspaceEvent::QueryFavorites),
+            219 => ::std::option::Option::Some(WorkspaceEvent::QueryRecentViews),
+            220 => ::std::option::Option::Some(WorkspaceEvent::SetViewWebhook),
+            221 => ::std::option::Option::Some(WorkspaceEvent::ReadViewWebhook),
+            222 => ::std::option::Option::Some(WorkspaceEvent::DeleteViewWebhook),
             300 => ::std::option::Option::Some(WorkspaceEvent::ReadTrash),
             301 => ::std::option::Option::Some(WorkspaceEvent::PutbackTrash),
             302 => ::std::option::Option::Some(WorkspaceEvent::DeleteTrash),
             303 => ::std::option::Option::Some(WorkspaceEvent::RestoreAll),
             304 => ::std::option::Option::Some(WorkspaceEvent::DeleteAll),
             400 => ::std::option::Option::Some(WorkspaceEvent::ApplyDocDelta),
+            401 => ::std::option::Option::Some(WorkspaceEvent::ResetDocument),
+            402 => ::std::option::Option::Some(WorkspaceEvent::ReadDocTextRuns),
+            403 => ::std::option::Option::Some(WorkspaceEvent::ReplaceDocText),
+            404 => ::std::option::Option::Some(WorkspaceEvent::ReadInputRules),
+            405 => ::std::option::Option::Some(WorkspaceEvent::UpdateInputRules),
+            406 => ::std::option::Option::Some(WorkspaceEvent::ReadDocSnapshots),
+            407 => ::std::option::Option::Some(WorkspaceEvent::RevertToSnapshot),
+            408 => ::std::option::Option::Some(WorkspaceEvent::UpdateSelection),
+            409 => ::std::option::Option::Some(WorkspaceEvent::UndoDocument),
+            410 => ::std::option::Option::Some(WorkspaceEvent::RedoDocument),
+            411 => ::std::option::Option::Some(WorkspaceEvent::ReadUndoState),
+            412 => ::std::option::Option::Some(WorkspaceEvent::UpdateReadPosition),
+            413 => ::std::option::Option::Some(WorkspaceEvent::UploadAttachment),
+            414 => ::std::option::Option::Some(WorkspaceEvent::ReadAttachment),
+            415 => ::std::option::Option::Some(WorkspaceEvent::QueryDocHistory),
+            416 => ::std::option::Option::Some(WorkspaceEvent::RestoreDocVersion),
             500 => ::std::option::Option::Some(WorkspaceEvent::ExportDocument),
+            501 => ::std::option::Option::Some(WorkspaceEvent::ImportDocument),
+            502 => ::std::option::Option::Some(WorkspaceEvent::ExportApp),
+            503 => ::std::option::Option::Some(WorkspaceEvent::PreviewImport),
+            504 => ::std::option::Option::Some(WorkspaceEvent::ImportFolder),
+            505 => ::std::option::Option::Some(WorkspaceEvent::PreviewAccountMerge),
+            506 => ::std::option::Option::Some(WorkspaceEvent::MergeAccount),
             _ => ::std::option::Option::None
         }
     }
@@ -96,10 +194,23 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::DeleteWorkspace,
             WorkspaceEvent::OpenWorkspace,
             WorkspaceEvent::ReadWorkspaceApps,
+            WorkspaceEvent::ArchiveWorkspace,
+            WorkspaceEvent::ReadMyTasks,
+            WorkspaceEvent::SearchWorkspace,
+            WorkspaceEvent::ReadShareLinks,
+            WorkspaceEvent::ReadRenameMergePolicy,
+            WorkspaceEvent::UpdateRenameMergePolicy,
+            WorkspaceEvent::ShareWorkspace,
+            WorkspaceEvent::QueryMembers,
+            WorkspaceEvent::QueryWorkspaces,
+            WorkspaceEvent::SwitchWorkspace,
             WorkspaceEvent::CreateApp,
             WorkspaceEvent::DeleteApp,
             WorkspaceEvent::ReadApp,
             WorkspaceEvent::UpdateApp,
+            WorkspaceEvent::PrefetchApp,
+            WorkspaceEvent::MoveApp,
+            WorkspaceEvent::DuplicateApp,
             WorkspaceEvent::CreateView,
             WorkspaceEvent::ReadView,
             WorkspaceEvent::UpdateView,
@@ -108,13 +219,49 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::CopyLink,
             WorkspaceEvent::OpenView,
             WorkspaceEvent::CloseView,
+            WorkspaceEvent::PublishView,
+            WorkspaceEvent::UnpublishView,
+            WorkspaceEvent::PinPublishedView,
+            WorkspaceEvent::PublishLatestView,
+            WorkspaceEvent::ReadAccessLog,
+            WorkspaceEvent::CreateShareLink,
+            WorkspaceEvent::RevokeShareLink,
+            WorkspaceEvent::MoveView,
+            WorkspaceEvent::ToggleFavorite,
+            WorkspaceEvent::QueryFavorites,
+            WorkspaceEvent::QueryRecentViews,
+            WorkspaceEvent::SetViewWebhook,
+            WorkspaceEvent::ReadViewWebhook,
+            WorkspaceEvent::DeleteViewWebhook,
             WorkspaceEvent::ReadTrash,
             WorkspaceEvent::PutbackTrash,
             WorkspaceEvent::DeleteTrash,
             WorkspaceEvent::RestoreAll,
             WorkspaceEvent::DeleteAll,
             WorkspaceEvent::ApplyDocDelta,
+            WorkspaceEvent::ResetDocument,
+            WorkspaceEvent::ReadDocTextRuns,
+            WorkspaceEvent::ReplaceDocText,
+            WorkspaceEvent::ReadInputRules,
+            WorkspaceEvent::UpdateInputRules,
+            WorkspaceEvent::ReadDocSnapshots,
+            WorkspaceEvent::RevertToSnapshot,
+            WorkspaceEvent::UpdateSelection,
+            WorkspaceEvent::UndoDocument,
+            WorkspaceEvent::RedoDocument,
+            WorkspaceEvent::ReadUndoState,
+            WorkspaceEvent::UpdateReadPosition,
+            WorkspaceEvent::UploadAttachment,
+            WorkspaceEvent::ReadAttachment,
+            WorkspaceEvent::QueryDocHistory,
+            WorkspaceEvent::RestoreDocVersion,
             WorkspaceEvent::ExportDocument,
+            WorkspaceEvent::ImportDocument,
+            WorkspaceEvent::ExportApp,
+            WorkspaceEvent::PreviewImport,
+            WorkspaceEvent::ImportFolder,
+            WorkspaceEvent::PreviewAccountMerge,
+            WorkspaceEvent::MergeAccount,
         ];
         values
     }
@@ -143,72 +290,189 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*\xcb\x03\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorksp\
+    \n\x0bevent.proto*\xd0\x0b\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorksp\
     ace\x10\0\x12\x14\n\x10ReadCurWorkspace\x10\x01\x12\x12\n\x0eReadWorkspa\
     ces\x10\x02\x12\x13\n\x0fDeleteWorkspace\x10\x03\x12\x11\n\rOpenWorkspac\
-    e\x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\r\n\tCreateApp\x10e\
-    \x12\r\n\tDeleteApp\x10f\x12\x0b\n\x07ReadApp\x10g\x12\r\n\tUpdateApp\
-    \x10h\x12\x0f\n\nCreateView\x10\xc9\x01\x12\r\n\x08ReadView\x10\xca\x01\
-    \x12\x0f\n\nUpdateView\x10\xcb\x01\x12\x0f\n\nDeleteView\x10\xcc\x01\x12\
-    \x12\n\rDuplicateView\x10\xcd\x01\x12\r\n\x08CopyLink\x10\xce\x01\x12\r\
-    \n\x08OpenView\x10\xcf\x01\x12\x0e\n\tCloseView\x10\xd0\x01\x12\x0e\n\tR\
-    eadTrash\x10\xac\x02\x12\x11\n\x0cPutbackTrash\x10\xad\x02\x12\x10\n\x0b\
-    DeleteTrash\x10\xae\x02\x12\x0f\n\nRestoreAll\x10\xaf\x02\x12\x0e\n\tDel\
-    eteAll\x10\xb0\x02\x12\x12\n\rApplyDocDelta\x10\x90\x03\x12\x13\n\x0eExp\
-    ortDocument\x10\xf4\x03J\xab\x08\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x1c\x01\n\n\n\x03\x05\
-    \0\x01\x12\x03\x02\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x18\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x13\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x03\x16\x17\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x19\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x05\0\x02\
-    \x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\
-    \x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x12\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\
-    \x04\x18\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x13\n\x0c\n\x05\
-    \x05\0\x02\x03\x02\x12\x03\x06\x16\x17\n\x0b\n\x04\x05\0\x02\x04\x12\x03\
-    \x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\x04\x02\x12\x03\x07\x14\x15\n\x0b\n\x04\x05\0\x02\x05\x12\
-    \x03\x08\x04\x1a\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x15\n\x0c\
-    \n\x05\x05\0\x02\x05\x02\x12\x03\x08\x18\x19\n\x0b\n\x04\x05\0\x02\x06\
-    \x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\r\n\x0c\n\
-    \x05\x05\0\x02\x06\x02\x12\x03\t\x10\x13\n\x0b\n\x04\x05\0\x02\x07\x12\
-    \x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\r\n\x0c\n\x05\
-    \x05\0\x02\x07\x02\x12\x03\n\x10\x13\n\x0b\n\x04\x05\0\x02\x08\x12\x03\
-    \x0b\x04\x12\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x0b\n\x0c\n\
-    \x05\x05\0\x02\x08\x02\x12\x03\x0b\x0e\x11\n\x0b\n\x04\x05\0\x02\t\x12\
-    \x03\x0c\x04\x14\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\r\n\x0c\n\
-    \x05\x05\0\x02\t\x02\x12\x03\x0c\x10\x13\n\x0b\n\x04\x05\0\x02\n\x12\x03\
-    \r\x04\x15\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x0e\n\x0c\n\x05\x05\
-    \0\x02\n\x02\x12\x03\r\x11\x14\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\
-    \x13\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x0c\n\x0c\n\x05\x05\0\
-    \x02\x0b\x02\x12\x03\x0e\x0f\x12\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\
-    \x04\x15\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\x0c\x02\x12\x03\x0f\x11\x14\n\x0b\n\x04\x05\0\x02\r\x12\x03\
-    \x10\x04\x15\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\r\x02\x12\x03\x10\x11\x14\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\
-    \x11\x04\x18\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\x0e\x02\x12\x03\x11\x14\x17\n\x0b\n\x04\x05\0\x02\x0f\x12\
-    \x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x0c\n\x0c\
-    \n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x0f\x12\n\x0b\n\x04\x05\0\x02\x10\
-    \x12\x03\x13\x04\x13\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x0c\n\
-    \x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x0f\x12\n\x0b\n\x04\x05\0\x02\
-    \x11\x12\x03\x14\x04\x14\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\r\
-    \n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x10\x13\n\x0b\n\x04\x05\0\x02\
-    \x12\x12\x03\x15\x04\x14\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\r\
-    \n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\x10\x13\n\x0b\n\x04\x05\0\x02\
-    \x13\x12\x03\x16\x04\x17\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\
-    \x10\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x16\x13\x16\n\x0b\n\x04\x05\0\
-    \x02\x14\x12\x03\x17\x04\x16\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\
-    \x04\x0f\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x17\x12\x15\n\x0b\n\x04\
-    \x05\0\x02\x15\x12\x03\x18\x04\x15\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\
-    \x18\x04\x0e\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03\x18\x11\x14\n\x0b\n\
-    \x04\x05\0\x02\x16\x12\x03\x19\x04\x14\n\x0c\n\x05\x05\0\x02\x16\x01\x12\
-    \x03\x19\x04\r\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\x19\x10\x13\n\x0b\n\
-    \x04\x05\0\x02\x17\x12\x03\x1a\x04\x18\n\x0c\n\x05\x05\0\x02\x17\x01\x12\
-    \x03\x1a\x04\x11\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03\x1a\x14\x17\n\x0b\
-    \n\x04\x05\0\x02\x18\x12\x03\x1b\x04\x19\n\x0c\n\x05\x05\0\x02\x18\x01\
-    \x12\x03\x1b\x04\x12\n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03\x1b\x15\x18b\
-    \x06proto3\
+    e\x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\x14\n\x10ArchiveWor\
+    kspace\x10\x06\x12\x0f\n\x0bReadMyTasks\x10\x07\x12\x13\n\x0fSearchWorks\
+    pace\x10\x08\x12\x12\n\x0eReadShareLinks\x10\t\x12\x19\n\x15ReadRenameMe\
+    rgePolicy\x10\n\x12\x1b\n\x17UpdateRenameMergePolicy\x10\x0b\x12\x12\n\
+    \x0eShareWorkspace\x10\x0c\x12\x10\n\x0cQueryMembers\x10\r\x12\x13\n\x0f\
+    QueryWorkspaces\x10\x0e\x12\x13\n\x0fSwitchWorkspace\x10\x0f\x12\r\n\tCr\
+    eateApp\x10e\x12\r\n\tDeleteApp\x10f\x12\x0b\n\x07ReadApp\x10g\x12\r\n\t\
+    UpdateApp\x10h\x12\x0f\n\x0bPrefetchApp\x10i\x12\x0b\n\x07MoveApp\x10j\
+    \x12\x10\n\x0cDuplicateApp\x10k\x12\x0f\n\nCreateView\x10\xc9\x01\x12\r\
+    \n\x08ReadView\x10\xca\x01\x12\x0f\n\nUpdateView\x10\xcb\x01\x12\x0f\n\n\
+    DeleteView\x10\xcc\x01\x12\x12\n\rDuplicateView\x10\xcd\x01\x12\r\n\x08C\
+    opyLink\x10\xce\x01\x12\r\n\x08OpenView\x10\xcf\x01\x12\x0e\n\tCloseView\
+    \x10\xd0\x01\x12\x10\n\x0bPublishView\x10\xd1\x01\x12\x12\n\rUnpublishVi\
+    ew\x10\xd2\x01\x12\x15\n\x10PinPublishedView\x10\xd3\x01\x12\x16\n\x11Pu\
+    blishLatestView\x10\xd4\x01\x12\x12\n\rReadAccessLog\x10\xd5\x01\x12\x14\
+    \n\x0fCreateShareLink\x10\xd6\x01\x12\x14\n\x0fRevokeShareLink\x10\xd7\
+    \x01\x12\r\n\x08MoveView\x10\xd8\x01\x12\x13\n\x0eToggleFavorite\x10\xd9\
+    \x01\x12\x13\n\x0eQueryFavorites\x10\xda\x01\x12\x15\n\x10QueryRecentVie\
+    ws\x10\xdb\x01\x12\x13\n\x0eSetViewWebhook\x10\xdc\x01\x12\x14\n\x0fRead\
+    ViewWebhook\x10\xdd\x01\x12\x16\n\x11DeleteViewWebhook\x10\xde\x01\x12\
+    \x0e\n\tReadTrash\x10\xac\x02\x12\x11\n\x0cPutbackTrash\x10\xad\x02\x12\
+    \x10\n\x0bDeleteTrash\x10\xae\x02\x12\x0f\n\nRestoreAll\x10\xaf\x02\x12\
+    \x0e\n\tDeleteAll\x10\xb0\x02\x12\x12\n\rApplyDocDelta\x10\x90\x03\x12\
+    \x12\n\rResetDocument\x10\x91\x03\x12\x14\n\x0fReadDocTextRuns\x10\x92\
+    \x03\x12\x13\n\x0eReplaceDocText\x10\x93\x03\x12\x13\n\x0eReadInputRules\
+    \x10\x94\x03\x12\x15\n\x10UpdateInputRules\x10\x95\x03\x12\x15\n\x10Read\
+    DocSnapshots\x10\x96\x03\x12\x15\n\x10RevertToSnapshot\x10\x97\x03\x12\
+    \x14\n\x0fUpdateSelection\x10\x98\x03\x12\x11\n\x0cUndoDocument\x10\x99\
+    \x03\x12\x11\n\x0cRedoDocument\x10\x9a\x03\x12\x12\n\rReadUndoState\x10\
+    \x9b\x03\x12\x17\n\x12UpdateReadPosition\x10\x9c\x03\x12\x15\n\x10Upload\
+    Attachment\x10\x9d\x03\x12\x13\n\x0eReadAttachment\x10\x9e\x03\x12\x14\n\
+    \x0fQueryDocHistory\x10\x9f\x03\x12\x16\n\x11RestoreDocVersion\x10\xa0\
+    \x03\x12\x13\n\x0eExportDocument\x10\xf4\x03\x12\x13\n\x0eImportDocument\
+    \x10\xf5\x03\x12\x0e\n\tExportApp\x10\xf6\x03\x12\x12\n\rPreviewImport\
+    \x10\xf7\x03\x12\x11\n\x0cImportFolder\x10\xf8\x03\x12\x18\n\x13PreviewA\
+    ccountMerge\x10\xf9\x03\x12\x11\n\x0cMergeAccount\x10\xfa\x03J\x84\x18\n\
+    \x06\x12\x04\0\0M\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\
+    \x12\x04\x02\0M\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x13\n\x0b\n\x04\
+    \x05\0\x02\0\x12\x03\x03\x04\x18\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\
+    \x04\x13\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x16\x17\n\x0b\n\x04\x05\
+    \0\x02\x01\x12\x03\x04\x04\x19\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
+    \x04\x14\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\
+    \x05\0\x02\x02\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
+    \x05\x04\x12\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\
+    \x04\x05\0\x02\x03\x12\x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
+    \x03\x06\x04\x13\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x16\x17\n\x0b\
+    \n\x04\x05\0\x02\x04\x12\x03\x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x01\
+    \x12\x03\x07\x04\x11\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x14\x15\n\
+    \x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x1a\n\x0c\n\x05\x05\0\x02\x05\
+    \x01\x12\x03\x08\x04\x15\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x18\
+    \x19\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x19\n\x0c\n\x05\x05\0\x02\
+    \x06\x01\x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x17\
+    \x18\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\
+    \x07\x01\x12\x03\n\x04\x0f\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x12\
+    \x13\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x18\n\x0c\n\x05\x05\0\x02\
+    \x08\x01\x12\x03\x0b\x04\x13\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\
+    \x16\x17\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\x17\n\x0c\n\x05\x05\0\
+    \x02\t\x01\x12\x03\x0c\x04\x12\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\
+    \x15\x16\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x1f\n\x0c\n\x05\x05\0\x02\
+    \n\x01\x12\x03\r\x04\x19\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x1c\x1e\n\
+    \x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04!\n\x0c\n\x05\x05\0\x02\x0b\x01\
+    \x12\x03\x0e\x04\x1b\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x1e\x20\n\
+    \x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\x18\n\x0c\n\x05\x05\0\x02\x0c\
+    \x01\x12\x03\x0f\x04\x12\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\x15\
+    \x17\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x16\n\x0c\n\x05\x05\0\x02\r\
+    \x01\x12\x03\x10\x04\x10\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\x13\x15\
+    \n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x19\n\x0c\n\x05\x05\0\x02\x0e\
+    \x01\x12\x03\x11\x04\x13\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\x11\x16\
+    \x18\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x19\n\x0c\n\x05\x05\0\x02\
+    \x0f\x01\x12\x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\x03\x12\
+    \x16\x18\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04\x14\n\x0c\n\x05\x05\0\
+    \x02\x10\x01\x12\x03\x13\x04\r\n\x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\
+    \x10\x13\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x14\x04\x14\n\x0c\n\x05\x05\0\
+    \x02\x11\x01\x12\x03\x14\x04\r\n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\
+    \x10\x13\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x15\x04\x12\n\x0c\n\x05\x05\0\
+    \x02\x12\x01\x12\x03\x15\x04\x0b\n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\
+    \x15\x0e\x11\n\x0b\n\x04\x05\0\x02\x13\x12\x03\x16\x04\x14\n\x0c\n\x05\
+    \x05\0\x02\x13\x01\x12\x03\x16\x04\r\n\x0c\n\x05\x05\0\x02\x13\x02\x12\
+    \x03\x16\x10\x13\n\x0b\n\x04\x05\0\x02\x14\x12\x03\x17\x04\x16\n\x0c\n\
+    \x05\x05\0\x02\x14\x01\x12\x03\x17\x04\x0f\n\x0c\n\x05\x05\0\x02\x14\x02\
+    \x12\x03\x17\x12\x15\n\x0b\n\x04\x05\0\x02\x15\x12\x03\x18\x04\x12\n\x0c\
+    \n\x05\x05\0\x02\x15\x01\x12\x03\x18\x04\x0b\n\x0c\n\x05\x05\0\x02\x15\
+    \x02\x12\x03\x18\x0e\x11\n\x0b\n\x04\x05\0\x02\x16\x12\x03\x19\x04\x17\n\
+    \x0c\n\x05\x05\0\x02\x16\x01\x12\x03\x19\x04\x10\n\x0c\n\x05\x05\0\x02\
+    \x16\x02\x12\x03\x19\x13\x16\n\x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\x04\
+    \x15\n\x0c\n\x05\x05\0\x02\x17\x01\x12\x03\x1a\x04\x0e\n\x0c\n\x05\x05\0\
+    \x02\x17\x02\x12\x03\x1a\x11\x14\n\x0b\n\x04\x05\0\x02\x18\x12\x03\x1b\
+    \x04\x13\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03\x1b\x04\x0c\n\x0c\n\x05\
+    \x05\0\x02\x18\x02\x12\x03\x1b\x0f\x12\n\x0b\n\x04\x05\0\x02\x19\x12\x03\
+    \x1c\x04\x15\n\x0c\n\x05\x05\0\x02\x19\x01\x12\x03\x1c\x04\x0e\n\x0c\n\
+    \x05\x05\0\x02\x19\x02\x12\x03\x1c\x11\x14\n\x0b\n\x04\x05\0\x02\x1a\x12\
+    \x03\x1d\x04\x15\n\x0c\n\x05\x05\0\x02\x1a\x01\x12\x03\x1d\x04\x0e\n\x0c\
+    \n\x05\x05\0\x02\x1a\x02\x12\x03\x1d\x11\x14\n\x0b\n\x04\x05\0\x02\x1b\
+    \x12\x03\x1e\x04\x18\n\x0c\n\x05\x05\0\x02\x1b\x01\x12\x03\x1e\x04\x11\n\
+    \x0c\n\x05\x05\0\x02\x1b\x02\x12\x03\x1e\x14\x17\n\x0b\n\x04\x05\0\x02\
+    \x1c\x12\x03\x1f\x04\x13\n\x0c\n\x05\x05\0\x02\x1c\x01\x12\x03\x1f\x04\
+    \x0c\n\x0c\n\x05\x05\0\x02\x1c\x02\x12\x03\x1f\x0f\x12\n\x0b\n\x04\x05\0\
+    \x02\x1d\x12\x03\x20\x04\x13\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03\x20\
+    \x04\x0c\n\x0c\n\x05\x05\0\x02\x1d\x02\x12\x03\x20\x0f\x12\n\x0b\n\x04\
+    \x05\0\x02\x1e\x12\x03!\x04\x14\n\x0c\n\x05\x05\0\x02\x1e\x01\x12\x03!\
+    \x04\r\n\x0c\n\x05\x05\0\x02\x1e\x02\x12\x03!\x10\x13\n\x0b\n\x04\x05\0\
+    \x02\x1f\x12\x03\"\x04\x16\n\x0c\n\x05\x05\0\x02\x1f\x01\x12\x03\"\x04\
+    \x0f\n\x0c\n\x05\x05\0\x02\x1f\x02\x12\x03\"\x12\x15\n\x0b\n\x04\x05\0\
+    \x02\x20\x12\x03#\x04\x18\n\x0c\n\x05\x05\0\x02\x20\x01\x12\x03#\x04\x11\
+    \n\x0c\n\x05\x05\0\x02\x20\x02\x12\x03#\x14\x17\n\x0b\n\x04\x05\0\x02!\
+    \x12\x03$\x04\x1b\n\x0c\n\x05\x05\0\x02!\x01\x12\x03$\x04\x14\n\x0c\n\
+    \x05\x05\0\x02!\x02\x12\x03$\x17\x1a\n\x0b\n\x04\x05\0\x02\"\x12\x03%\
+    \x04\x1c\n\x0c\n\x05\x05\0\x02\"\x01\x12\x03%\x04\x15\n\x0c\n\x05\x05\0\
+    \x02\"\x02\x12\x03%\x18\x1b\n\x0b\n\x04\x05\0\x02#\x12\x03&\x04\x18\n\
+    \x0c\n\x05\x05\0\x02#\x01\x12\x03&\x04\x11\n\x0c\n\x05\x05\0\x02#\x02\
+    \x12\x03&\x14\x17\n\x0b\n\x04\x05\0\x02$\x12\x03'\x04\x1a\n\x0c\n\x05\
+    \x05\0\x02$\x01\x12\x03'\x04\x13\n\x0c\n\x05\x05\0\x02$\x02\x12\x03'\x16\
+    \x19\n\x0b\n\x04\x05\0\x02%\x12\x03(\x04\x1a\n\x0c\n\x05\x05\0\x02%\x01\
+    \x12\x03(\x04\x13\n\x0c\n\x05\x05\0\x02%\x02\x12\x03(\x16\x19\n\x0b\n\
+    \x04\x05\0\x02&\x12\x03)\x04\x13\n\x0c\n\x05\x05\0\x02&\x01\x12\x03)\x04\
+    \x0c\n\x0c\n\x05\x05\0\x02&\x02\x12\x03)\x0f\x12\n\x0b\n\x04\x05\0\x02'\
+    \x12\x03*\x04\x19\n\x0c\n\x05\x05\0\x02'\x01\x12\x03*\x04\x12\n\x0c\n\
+    \x05\x05\0\x02'\x02\x12\x03*\x15\x18\n\x0b\n\x04\x05\0\x02(\x12\x03+\x04\
+    \x19\n\x0c\n\x05\x05\0\x02(\x01\x12\x03+\x04\x12\n\x0c\n\x05\x05\0\x02(\
+    \x02\x12\x03+\x15\x18\n\x0b\n\x04\x05\0\x02)\x12\x03,\x04\x1b\n\x0c\n\
+    \x05\x05\0\x02)\x01\x12\x03,\x04\x14\n\x0c\n\x05\x05\0\x02)\x02\x12\x03,\
+    \x17\x1a\n\x0b\n\x04\x05\0\x02*\x12\x03-\x04\x19\n\x0c\n\x05\x05\0\x02*\
+    \x01\x12\x03-\x04\x12\n\x0c\n\x05\x05\0\x02*\x02\x12\x03-\x15\x18\n\x0b\
+    \n\x04\x05\0\x02+\x12\x03.\x04\x1a\n\x0c\n\x05\x05\0\x02+\x01\x12\x03.\
+    \x04\x13\n\x0c\n\x05\x05\0\x02+\x02\x12\x03.\x16\x19\n\x0b\n\x04\x05\0\
+    \x02,\x12\x03/\x04\x1c\n\x0c\n\x05\x05\0\x02,\x01\x12\x03/\x04\x15\n\x0c\
+    \n\x05\x05\0\x02,\x02\x12\x03/\x18\x1b\n\x0b\n\x04\x05\0\x02-\x12\x030\
+    \x04\x14\n\x0c\n\x05\x05\0\x02-\x01\x12\x030\x04\r\n\x0c\n\x05\x05\0\x02\
+    -\x02\x12\x030\x10\x13\n\x0b\n\x04\x05\0\x02.\x12\x031\x04\x17\n\x0c\n\
+    \x05\x05\0\x02.\x01\x12\x031\x04\x10\n\x0c\n\x05\x05\0\x02.\x02\x12\x031\
+    \x13\x16\n\x0b\n\x04\x05\0\x02/\x12\x032\x04\x16\n\x0c\n\x05\x05\0\x02/\
+    \x01\x12\x032\x04\x0f\n\x0c\n\x05\x05\0\x02/\x02\x12\x032\x12\x15\n\x0b\
+    \n\x04\x05\0\x020\x12\x033\x04\x15\n\x0c\n\x05\x05\0\x020\x01\x12\x033\
+    \x04\x0e\n\x0c\n\x05\x05\0\x020\x02\x12\x033\x11\x14\n\x0b\n\x04\x05\0\
+    \x021\x12\x034\x04\x14\n\x0c\n\x05\x05\0\x021\x01\x12\x034\x04\r\n\x0c\n\
+    \x05\x05\0\x021\x02\x12\x034\x10\x13\n\x0b\n\x04\x05\0\x022\x12\x035\x04\
+    \x18\n\x0c\n\x05\x05\0\x022\x01\x12\x035\x04\x11\n\x0c\n\x05\x05\0\x022\
+    \x02\x12\x035\x14\x17\n\x0b\n\x04\x05\0\x023\x12\x036\x04\x18\n\x0c\n\
+    \x05\x05\0\x023\x01\x12\x036\x04\x11\n\x0c\n\x05\x05\0\x023\x02\x12\x036\
+    \x14\x17\n\x0b\n\x04\x05\0\x024\x12\x037\x04\x1a\n\x0c\n\x05\x05\0\x024\
+    \x01\x12\x037\x04\x13\n\x0c\n\x05\x05\0\x024\x02\x12\x037\x16\x19\n\x0b\
+    \n\x04\x05\0\x025\x12\x038\x04\x19\n\x0c\n\x05\x05\0\x025\x01\x12\x038\
+    \x04\x12\n\x0c\n\x05\x05\0\x025\x02\x12\x038\x15\x18\n\x0b\n\x04\x05\0\
+    \x026\x12\x039\x04\x19\n\x0c\n\x05\x05\0\x026\x01\x12\x039\x04\x12\n\x0c\
+    \n\x05\x05\0\x026\x02\x12\x039\x15\x18\n\x0b\n\x04\x05\0\x027\x12\x03:\
+    \x04\x1b\n\x0c\n\x05\x05\0\x027\x01\x12\x03:\x04\x14\n\x0c\n\x05\x05\0\
+    \x027\x02\x12\x03:\x17\x1a\n\x0b\n\x04\x05\0\x028\x12\x03;\x04\x1b\n\x0c\
+    \n\x05\x05\0\x028\x01\x12\x03;\x04\x14\n\x0c\n\x05\x05\0\x028\x02\x12\
+    \x03;\x17\x1a\n\x0b\n\x04\x05\0\x029\x12\x03<\x04\x1b\n\x0c\n\x05\x05\0\
+    \x029\x01\x12\x03<\x04\x14\n\x0c\n\x05\x05\0\x029\x02\x12\x03<\x17\x1a\n\
+    \x0b\n\x04\x05\0\x02:\x12\x03=\x04\x1a\n\x0c\n\x05\x05\0\x02:\x01\x12\
+    \x03=\x04\x13\n\x0c\n\x05\x05\0\x02:\x02\x12\x03=\x16\x19\n\x0b\n\x04\
+    \x05\0\x02;\x12\x03>\x04\x17\n\x0c\n\x05\x05\0\x02;\x01\x12\x03>\x04\x10\
+    \n\x0c\n\x05\x05\0\x02;\x02\x12\x03>\x13\x16\n\x0b\n\x04\x05\0\x02<\x12\
+    \x03?\x04\x17\n\x0c\n\x05\x05\0\x02<\x01\x12\x03?\x04\x10\n\x0c\n\x05\
+    \x05\0\x02<\x02\x12\x03?\x13\x16\n\x0b\n\x04\x05\0\x02=\x12\x03@\x04\x18\
+    \n\x0c\n\x05\x05\0\x02=\x01\x12\x03@\x04\x11\n\x0c\n\x05\x05\0\x02=\x02\
+    \x12\x03@\x14\x17\n\x0b\n\x04\x05\0\x02>\x12\x03A\x04\x1d\n\x0c\n\x05\
+    \x05\0\x02>\x01\x12\x03A\x04\x16\n\x0c\n\x05\x05\0\x02>\x02\x12\x03A\x19\
+    \x1c\n\x0b\n\x04\x05\0\x02?\x12\x03B\x04\x1b\n\x0c\n\x05\x05\0\x02?\x01\
+    \x12\x03B\x04\x14\n\x0c\n\x05\x05\0\x02?\x02\x12\x03B\x17\x1a\n\x0b\n\
+    \x04\x05\0\x02@\x12\x03C\x04\x19\n\x0c\n\x05\x05\0\x02@\x01\x12\x03C\x04\
+    \x12\n\x0c\n\x05\x05\0\x02@\x02\x12\x03C\x15\x18\n\x0b\n\x04\x05\0\x02A\
+    \x12\x03D\x04\x1a\n\x0c\n\x05\x05\0\x02A\x01\x12\x03D\x04\x13\n\x0c\n\
+    \x05\x05\0\x02A\x02\x12\x03D\x16\x19\n\x0b\n\x04\x05\0\x02B\x12\x03E\x04\
+    \x1c\n\x0c\n\x05\x05\0\x02B\x01\x12\x03E\x04\x15\n\x0c\n\x05\x05\0\x02B\
+    \x02\x12\x03E\x18\x1b\n\x0b\n\x04\x05\0\x02C\x12\x03F\x04\x19\n\x0c\n\
+    \x05\x05\0\x02C\x01\x12\x03F\x04\x12\n\x0c\n\x05\x05\0\x02C\x02\x12\x03F\
+    \x15\x18\n\x0b\n\x04\x05\0\x02D\x12\x03G\x04\x19\n\x0c\n\x05\x05\0\x02D\
+    \x01\x12\x03G\x04\x12\n\x0c\n\x05\x05\0\x02D\x02\x12\x03G\x15\x18\n\x0b\
+    \n\x04\x05\0\x02E\x12\x03H\x04\x14\n\x0c\n\x05\x05\0\x02E\x01\x12\x03H\
+    \x04\r\n\x0c\n\x05\x05\0\x02E\x02\x12\x03H\x10\x13\n\x0b\n\x04\x05\0\x02\
+    F\x12\x03I\x04\x18\n\x0c\n\x05\x05\0\x02F\x01\x12\x03I\x04\x11\n\x0c\n\
+    \x05\x05\0\x02F\x02\x12\x03I\x14\x17\n\x0b\n\x04\x05\0\x02G\x12\x03J\x04\
+    \x17\n\x0c\n\x05\x05\0\x02G\x01\x12\x03J\x04\x10\n\x0c\n\x05\x05\0\x02G\
+    \x02\x12\x03J\x13\x16\n\x0b\n\x04\x05\0\x02H\x12\x03K\x04\x1e\n\x0c\n\
+    \x05\x05\0\x02H\x01\x12\x03K\x04\x17\n\x0c\n\x05\x05\0\x02H\x02\x12\x03K\
+    \x1a\x1d\n\x0b\n\x04\x05\0\x02I\x12\x03L\x04\x17\n\x0c\n\x05\x05\0\x02I\
+    \x01\x12\x03L\x04\x10\n\x0c\n\x05\x05\0\x02I\x02\x12\x03L\x13\x16b\x06pr\
+    oto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,276 @@
+use crate::{
+    entities::{
+        app::{ColorStyle, CreateAppParams},
+        view::{CreateViewParams, View},
+        workspace::{AccountMergeReport, CreateWorkspaceParams, WorkspaceMergeItem, WorkspaceRole},
+    },
+    errors::{internal_error, WorkspaceError},
+    services::WorkspaceController,
+    sql_tables::{
+        app::{AppTable, AppTableSql},
+        trash::TrashTableSql,
+        view::{ViewFavoriteTableSql, ViewTable, ViewTableSql, ViewTableType},
+        workspace::{WorkspaceSettingTableSql, WorkspaceTable, WorkspaceTableSql},
+    },
+};
+use flowy_database::{ConnectionPool, SqliteConnection};
+use lib_infra::kv::KV;
+use lib_ot::core::Delta;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
+
+// The workspace of the source account with the apps and the views nested in them
+struct MergeWorkspace {
+    table: WorkspaceTable,
+    // The workspace of the current user that has the same name, the apps are merged into it
+    target_id: Option<String>,
+    apps: Vec<MergeApp>,
+}
+
+struct MergeApp {
+    table: AppTable,
+    views: Vec<MergeView>,
+}
+
+struct MergeView {
+    table: ViewTable,
+    views: Vec<MergeView>,
+}
+
+pub(crate) struct AccountMergePlan {
+    pub(crate) report: AccountMergeReport,
+    workspaces: Vec<MergeWorkspace>,
+    // The ids of the views of the source account
+    favorite_ids: HashSet<String>,
+    latest_view_ids: HashMap<String, String>,
+    // The keys of the settings of the source account and the keys they are copied to
+    setting_keys: Vec<(String, String)>,
+}
+
+// Reads what the merge would do, nothing is changed. The workspaces that others shared with the source
+// account are skipped, the current user can be invited to them instead. The archived ones are skipped too.
+pub(crate) fn read_merge_plan(
+    source_user_id: &str,
+    source_conn: &SqliteConnection,
+    user_id: &str,
+    conn: &SqliteConnection,
+) -> Result<AccountMergePlan, WorkspaceError> {
+    let workspace_sql = WorkspaceTableSql {};
+    let trash_ids = TrashTableSql::read_all(source_conn)?
+        .into_inner()
+        .into_iter()
+        .map(|trash| trash.id)
+        .collect::<HashSet<String>>();
+    let targets = workspace_sql
+        .read_workspaces(None, user_id, conn)?
+        .into_iter()
+        .filter(|table| WorkspaceRole::from(table.role) == WorkspaceRole::Owner)
+        .map(|table| (table.name, table.id))
+        .collect::<HashMap<String, String>>();
+
+    let mut report = AccountMergeReport {
+        source_user_id: source_user_id.to_owned(),
+        skipped_count: trash_ids.len() as i64,
+        ..Default::default()
+    };
+    let mut workspaces = vec![];
+    let mut latest_view_ids = HashMap::new();
+    for table in workspace_sql.read_workspaces(None, source_user_id, source_conn)? {
+        if WorkspaceRole::from(table.role) != WorkspaceRole::Owner || trash_ids.contains(&table.id) {
+            report.skipped_count += 1;
+            continue;
+        }
+
+        let mut apps = vec![];
+        for app_table in AppTableSql::read_workspace_apps(&table.id, false, source_conn)? {
+            if trash_ids.contains(&app_table.id) {
+                continue;
+            }
+            let views = read_merge_views(&app_table.id, &trash_ids, source_conn)?;
+            apps.push(MergeApp { table: app_table, views });
+        }
+
+        let latest_view_id = WorkspaceSettingTableSql::read_latest_view_id(&table.id, source_conn)?;
+        if !latest_view_id.is_empty() {
+            latest_view_ids.insert(table.id.clone(), latest_view_id);
+        }
+
+        let target_id = targets.get(&table.name).cloned();
+        report.workspaces.push(WorkspaceMergeItem {
+            source_id: table.id.clone(),
+            name: table.name.clone(),
+            target_id: target_id.clone().unwrap_or_default(),
+            app_count: apps.len() as i64,
+            view_count: apps.iter().map(|app| count_views(&app.views)).sum::<usize>() as i64,
+        });
+        workspaces.push(MergeWorkspace { table, target_id, apps });
+    }
+
+    let mut view_ids = HashSet::new();
+    for workspace in &workspaces {
+        for app in &workspace.apps {
+            collect_view_ids(&app.views, &mut view_ids);
+        }
+    }
+    let favorite_ids = ViewFavoriteTableSql::read_all(source_conn)?
+        .into_iter()
+        .map(|(view_id, _)| view_id)
+        .filter(|view_id| view_ids.contains(view_id))
+        .collect::<HashSet<String>>();
+    report.favorite_count = favorite_ids.len() as i64;
+
+    let setting_keys = read_setting_keys(source_user_id, user_id)?;
+    report.settings = setting_keys
+        .iter()
+        .map(|(key, _)| key[source_user_id.len() + 1..].to_owned())
+        .collect();
+
+    Ok(AccountMergePlan {
+        report,
+        workspaces,
+        favorite_ids,
+        latest_view_ids,
+        setting_keys,
+    })
+}
+
+// The docs of the source account are read before anything is created, so the merge fails without any
+// change if one of them can't be read. The workspaces, the apps and the views are created on the server
+// like the ones that the user creates, so they get new ids and the merged content is uploaded. The local
+// revisions don't keep the user, the content of each doc is carried over as the delta it composes to.
+pub(crate) async fn apply_merge_plan(
+    plan: AccountMergePlan,
+    controller: &WorkspaceController,
+    source_pool: Arc<ConnectionPool>,
+) -> Result<AccountMergeReport, WorkspaceError> {
+    let view_controller = controller.view_controller.clone();
+    let mut deltas = HashMap::new();
+    for workspace in &plan.workspaces {
+        for app in &workspace.apps {
+            let mut views = app.views.iter().collect::<VecDeque<&MergeView>>();
+            while let Some(view) = views.pop_front() {
+                if view.table.view_type == ViewTableType::Docs {
+                    let delta = view_controller
+                        .read_local_doc(&view.table.id, source_pool.clone())
+                        .await?;
+                    deltas.insert(view.table.id.clone(), delta);
+                }
+                views.extend(view.views.iter());
+            }
+        }
+    }
+
+    let mut created_views = HashMap::new();
+    for workspace in plan.workspaces {
+        let is_created = workspace.target_id.is_none();
+        let workspace_id = match workspace.target_id {
+            Some(workspace_id) => workspace_id,
+            None => {
+                let params = CreateWorkspaceParams {
+                    name: workspace.table.name.clone(),
+                    desc: workspace.table.desc.clone(),
+                };
+                controller.create_workspace_from_params(params).await?.id
+            },
+        };
+
+        for app in workspace.apps {
+            let params = CreateAppParams {
+                workspace_id: workspace_id.clone(),
+                name: app.table.name,
+                desc: app.table.desc,
+                color_style: ColorStyle {
+                    theme_color: app.table.color_style.theme_color,
+                },
+            };
+            let app_id = controller.app_controller.create_app_from_params(params).await?.id;
+            let mut queue = VecDeque::from(vec![(app_id, app.views)]);
+            while let Some((belong_to_id, views)) = queue.pop_front() {
+                for view in views {
+                    let source_id = view.table.id.clone();
+                    let source_view: View = view.table.into();
+                    let params = CreateViewParams::new(
+                        belong_to_id.clone(),
+                        source_view.name,
+                        source_view.desc,
+                        source_view.view_type,
+                        source_view.thumbnail,
+                    );
+                    let created_view = view_controller.create_view_from_params(params).await?;
+                    if let Some(delta) = deltas.remove(&source_id) {
+                        let _ = view_controller.replace_doc(&created_view.id, delta).await?;
+                    }
+                    queue.push_back((created_view.id.clone(), view.views));
+                    created_views.insert(source_id, created_view);
+                }
+            }
+        }
+
+        // The workspace that the apps are merged into keeps the view that the current user opened last
+        let latest_view = plan
+            .latest_view_ids
+            .get(&workspace.table.id)
+            .and_then(|view_id| created_views.get(view_id));
+        if let (true, Some(view)) = (is_created, latest_view) {
+            let _ = view_controller.set_latest_view(&workspace_id, view)?;
+        }
+    }
+
+    for view_id in &plan.favorite_ids {
+        if let Some(view) = created_views.get(view_id) {
+            let _ = view_controller.toggle_favorite(view.id.clone().into()).await?;
+        }
+    }
+
+    for (key, target_key) in &plan.setting_keys {
+        let _ = KV::copy(key, target_key).map_err(internal_error)?;
+    }
+
+    let mut report = plan.report;
+    report.is_applied = true;
+    Ok(report)
+}
+
+fn read_merge_views(
+    belong_to_id: &str,
+    trash_ids: &HashSet<String>,
+    conn: &SqliteConnection,
+) -> Result<Vec<MergeView>, WorkspaceError> {
+    let mut views = vec![];
+    for table in ViewTableSql::read_views(belong_to_id, conn)? {
+        if trash_ids.contains(&table.id) {
+            continue;
+        }
+        let nested_views = read_merge_views(&table.id, trash_ids, conn)?;
+        views.push(MergeView {
+            table,
+            views: nested_views,
+        });
+    }
+    Ok(views)
+}
+
+fn count_views(views: &[MergeView]) -> usize { views.iter().map(|view| 1 + count_views(&view.views)).sum() }
+
+fn collect_view_ids(views: &[MergeView], view_ids: &mut HashSet<String>) {
+    for view in views {
+        view_ids.insert(view.table.id.clone());
+        collect_view_ids(&view.views, view_ids);
+    }
+}
+
+// The settings of the user are kept with the keys that start with the user id. The ones that the current
+// user has set are kept.
+fn read_setting_keys(source_user_id: &str, user_id: &str) -> Result<Vec<(String, String)>, WorkspaceError> {
+    let prefix = format!("{}_", source_user_id);
+    let mut setting_keys = vec![];
+    for key in KV::keys_with_prefix(&prefix).map_err(internal_error)? {
+        let target_key = format!("{}_{}", user_id, &key[prefix.len()..]);
+        if !KV::contains(&target_key) {
+            setting_keys.push((key, target_key));
+        }
+    }
+    Ok(setting_keys)
+}
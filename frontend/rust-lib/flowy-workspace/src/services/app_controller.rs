@@ -3,6 +3,8 @@ use std::{collections::HashSet, sync::Arc};
 use futures::{FutureExt, StreamExt};
 
 use flowy_database::SqliteConnection;
+use flowy_workspace_infra::rank::ranks_after_move;
+use lib_infra::device_id;
 
 use crate::{
     entities::{
@@ -12,14 +14,20 @@ use crate::{
     errors::*,
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::*,
-    services::{server::Server, TrashCan, TrashEvent},
-    sql_tables::app::{AppTable, AppTableChangeset, AppTableSql},
+    services::{keep_pending_name, server::Server, TrashCan, TrashEvent, WorkspaceCache, WorkspaceSync},
+    sql_tables::{
+        app::{AppTable, AppTableChangeset, AppTableSql},
+        outbox::SqlRenameObjectType,
+        workspace::WorkspaceTableSql,
+    },
 };
 
 pub(crate) struct AppController {
     user: Arc<dyn WorkspaceUser>,
     database: Arc<dyn WorkspaceDatabase>,
     trash_can: Arc<TrashCan>,
+    workspace_sync: Arc<WorkspaceSync>,
+    cache: Arc<WorkspaceCache>,
     server: Server,
 }
 
@@ -28,12 +36,16 @@ impl AppController {
         user: Arc<dyn WorkspaceUser>,
         database: Arc<dyn WorkspaceDatabase>,
         trash_can: Arc<TrashCan>,
+        workspace_sync: Arc<WorkspaceSync>,
+        cache: Arc<WorkspaceCache>,
         server: Server,
     ) -> Self {
         Self {
             user,
             database,
             trash_can,
+            workspace_sync,
+            cache,
             server,
         }
     }
@@ -45,23 +57,36 @@ impl AppController {
 
     #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name) err)]
     pub(crate) async fn create_app_from_params(&self, params: CreateAppParams) -> Result<App, WorkspaceError> {
+        let _ = self.check_workspace_not_archived(&params.workspace_id)?;
         let app = self.create_app_on_server(params).await?;
         self.create_app(app).await
     }
 
+    // The app is copied on the server with its views and their docs, only the copy of the app is saved
+    // here. The copied views are returned as the belongings of the app.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn duplicate_app(&self, params: AppIdentifier) -> Result<App, WorkspaceError> {
+        let _ = self.check_app_not_archived(&params.app_id)?;
+        let token = self.user.token()?;
+        let app = self.server.duplicate_app(&token, params).await?;
+        self.create_app(app).await
+    }
+
     pub(crate) async fn create_app(&self, app: App) -> Result<App, WorkspaceError> {
         let conn = &*self.database.db_connection()?;
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
             let _ = self.save_app(app.clone(), &*conn)?;
-            let _ = notify_apps_changed(&app.workspace_id, self.trash_can.clone(), conn)?;
+            let _ = notify_apps_changed(&app.workspace_id, self.trash_can.clone(), &self.cache, conn)?;
             Ok(())
         })?;
 
         Ok(app)
     }
 
-    pub(crate) fn save_app(&self, app: App, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
-        let app_table = AppTable::new(app.clone());
+    pub(crate) fn save_app(&self, mut app: App, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = keep_pending_name(&app.id, &mut app.name, conn)?;
+        self.cache.remove_apps(&app.workspace_id);
+        let app_table = AppTable::new(app);
         let _ = AppTableSql::create_app(app_table, &*conn)?;
         Ok(())
     }
@@ -84,8 +109,21 @@ impl AppController {
         let app_id = changeset.id.clone();
         let conn = &*self.database.db_connection()?;
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let app_table = AppTableSql::read_app(&app_id, conn)?;
+            let _ = WorkspaceTableSql {}.check_not_archived(&app_table.workspace_id, conn)?;
+            if let Some(name) = &params.name {
+                let _ = self.workspace_sync.record_rename(
+                    SqlRenameObjectType::App,
+                    &app_id,
+                    &app_table.name,
+                    app_table.modified_time,
+                    name,
+                    conn,
+                )?;
+            }
             let _ = AppTableSql::update_app(changeset, conn)?;
             let app: App = AppTableSql::read_app(&app_id, conn)?.into();
+            self.cache.remove_apps(&app.workspace_id);
             send_dart_notification(&app_id, WorkspaceNotification::AppUpdated)
                 .payload(app)
                 .send();
@@ -96,6 +134,52 @@ impl AppController {
         Ok(())
     }
 
+    // Moves the app to the index of the apps in its workspace, the index doesn't count the app itself
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn move_app(&self, position: AppPosition) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        let ranks = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let app_table = AppTableSql::read_app(&position.app_id, conn)?;
+            let _ = WorkspaceTableSql {}.check_not_archived(&app_table.workspace_id, conn)?;
+            let trash_can = self.trash_can.clone();
+            let mut apps = read_local_workspace_apps(&app_table.workspace_id, trash_can, &self.cache, conn)?.items;
+            apps.retain(|app| app.id != app_table.id);
+
+            let siblings = apps
+                .iter()
+                .map(|app| (app.id.as_str(), app.rank.as_str()))
+                .collect::<Vec<(&str, &str)>>();
+            let ranks = ranks_after_move(&app_table.id, &siblings, position.index);
+            for (id, rank) in &ranks {
+                let changeset = AppTableChangeset {
+                    id: id.clone(),
+                    rank: Some(rank.clone()),
+                    ..Default::default()
+                };
+                let _ = AppTableSql::update_app(changeset, conn)?;
+            }
+            let _ = notify_apps_changed(&app_table.workspace_id, self.trash_can.clone(), &self.cache, conn)?;
+            Ok(ranks)
+        })?;
+
+        let _ = self.move_app_on_server(ranks)?;
+        Ok(())
+    }
+
+    // The apps of the archived workspace are read-only
+    fn check_workspace_not_archived(&self, workspace_id: &str) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        WorkspaceTableSql {}.check_not_archived(workspace_id, conn)
+    }
+
+    fn check_app_not_archived(&self, app_id: &str) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        match AppTableSql::read_optional_app(app_id, conn)? {
+            None => Ok(()),
+            Some(app_table) => WorkspaceTableSql {}.check_not_archived(&app_table.workspace_id, conn),
+        }
+    }
+
     pub(crate) fn read_app_tables(&self, ids: Vec<String>) -> Result<Vec<AppTable>, WorkspaceError> {
         let conn = &*self.database.db_connection()?;
         let mut app_tables = vec![];
@@ -119,14 +203,24 @@ impl AppController {
     }
 
     #[tracing::instrument(level = "debug", skip(self), err)]
-    fn update_app_on_server(&self, params: UpdateAppParams) -> Result<(), WorkspaceError> {
+    fn update_app_on_server(&self, mut params: UpdateAppParams) -> Result<(), WorkspaceError> {
+        params.device_id = device_id();
         let token = self.user.token()?;
         let server = self.server.clone();
+        let workspace_sync = self.workspace_sync.clone();
         tokio::spawn(async move {
+            let app_id = params.app_id.clone();
+            let name = params.name.clone();
             match server.update_app(&token, params).await {
-                Ok(_) => {},
+                Ok(_) => {
+                    if let Some(name) = name {
+                        if let Err(e) = workspace_sync.did_send_rename(&app_id, &name) {
+                            log::error!("Remove the sent rename failed: {:?}", e);
+                        }
+                    }
+                },
                 Err(e) => {
-                    // TODO: retry?
+                    // The rename is sent again after reconnecting
                     log::error!("Update app failed: {:?}", e);
                 },
             }
@@ -134,20 +228,37 @@ impl AppController {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    fn move_app_on_server(&self, ranks: Vec<(String, String)>) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let server = self.server.clone();
+        tokio::spawn(async move {
+            for (app_id, rank) in ranks {
+                let params = MoveAppParams { app_id, rank };
+                if let Err(e) = server.move_app(&token, params).await {
+                    log::error!("Move app failed: {:?}", e);
+                }
+            }
+        });
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self), err)]
     fn read_app_on_server(&self, params: AppIdentifier) -> Result<(), WorkspaceError> {
         let token = self.user.token()?;
         let server = self.server.clone();
         let pool = self.database.db_pool()?;
+        let cache = self.cache.clone();
         tokio::spawn(async move {
             // Opti: retry?
             match server.read_app(&token, params).await {
-                Ok(Some(app)) => match pool.get() {
+                Ok(Some(mut app)) => match pool.get() {
                     Ok(conn) => {
-                        let app_table = AppTable::new(app.clone());
-                        let result = AppTableSql::create_app(app_table, &*conn);
+                        let result = keep_pending_name(&app.id, &mut app.name, &*conn)
+                            .and_then(|_| AppTableSql::create_app(AppTable::new(app.clone()), &*conn));
                         match result {
                             Ok(_) => {
+                                cache.remove_apps(&app.workspace_id);
                                 send_dart_notification(&app.id, WorkspaceNotification::AppUpdated)
                                     .payload(app)
                                     .send();
@@ -168,6 +279,7 @@ impl AppController {
         let mut rx = self.trash_can.subscribe();
         let database = self.database.clone();
         let trash_can = self.trash_can.clone();
+        let cache = self.cache.clone();
         let _ = tokio::spawn(async move {
             loop {
                 let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
@@ -177,7 +289,9 @@ impl AppController {
                     }
                 }));
                 match stream.next().await {
-                    Some(event) => handle_trash_event(database.clone(), trash_can.clone(), event).await,
+                    Some(event) => {
+                        handle_trash_event(database.clone(), trash_can.clone(), cache.clone(), event).await
+                    },
                     None => {},
                 }
             }
@@ -185,8 +299,13 @@ impl AppController {
     }
 }
 
-#[tracing::instrument(level = "trace", skip(database, trash_can))]
-async fn handle_trash_event(database: Arc<dyn WorkspaceDatabase>, trash_can: Arc<TrashCan>, event: TrashEvent) {
+#[tracing::instrument(level = "trace", skip(database, trash_can, cache))]
+async fn handle_trash_event(
+    database: Arc<dyn WorkspaceDatabase>,
+    trash_can: Arc<TrashCan>,
+    cache: Arc<WorkspaceCache>,
+    event: TrashEvent,
+) {
     let db_result = database.db_connection();
     match event {
         TrashEvent::NewTrash(identifiers, ret) | TrashEvent::Putback(identifiers, ret) => {
@@ -195,7 +314,7 @@ async fn handle_trash_event(database: Arc<dyn WorkspaceDatabase>, trash_can: Arc
                 let _ = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
                     for identifier in identifiers.items {
                         let app_table = AppTableSql::read_app(&identifier.id, conn)?;
-                        let _ = notify_apps_changed(&app_table.workspace_id, trash_can.clone(), conn)?;
+                        let _ = notify_apps_changed(&app_table.workspace_id, trash_can.clone(), &cache, conn)?;
                     }
                     Ok(())
                 })?;
@@ -215,7 +334,7 @@ async fn handle_trash_event(database: Arc<dyn WorkspaceDatabase>, trash_can: Arc
                     }
 
                     for notify_id in notify_ids {
-                        let _ = notify_apps_changed(&notify_id, trash_can.clone(), conn)?;
+                        let _ = notify_apps_changed(&notify_id, trash_can.clone(), &cache, conn)?;
                     }
                     Ok(())
                 })?;
@@ -226,25 +345,39 @@ async fn handle_trash_event(database: Arc<dyn WorkspaceDatabase>, trash_can: Arc
     }
 }
 
-#[tracing::instrument(skip(workspace_id, trash_can, conn), err)]
-fn notify_apps_changed(workspace_id: &str, trash_can: Arc<TrashCan>, conn: &SqliteConnection) -> WorkspaceResult<()> {
-    let repeated_app = read_local_workspace_apps(workspace_id, trash_can, conn)?;
+// The apps are read from the sqlite again, the cached list is replaced with them
+#[tracing::instrument(skip(workspace_id, trash_can, cache, conn), err)]
+fn notify_apps_changed(
+    workspace_id: &str,
+    trash_can: Arc<TrashCan>,
+    cache: &WorkspaceCache,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<()> {
+    cache.remove_apps(workspace_id);
+    let repeated_app = read_local_workspace_apps(workspace_id, trash_can, cache, conn)?;
     send_dart_notification(workspace_id, WorkspaceNotification::WorkspaceAppsChanged)
         .payload(repeated_app)
         .send();
     Ok(())
 }
 
-pub fn read_local_workspace_apps(
+pub(crate) fn read_local_workspace_apps(
     workspace_id: &str,
     trash_can: Arc<TrashCan>,
+    cache: &WorkspaceCache,
     conn: &SqliteConnection,
 ) -> Result<RepeatedApp, WorkspaceError> {
-    let mut app_tables = AppTableSql::read_workspace_apps(workspace_id, false, conn)?;
+    let mut apps = match cache.apps(workspace_id) {
+        Some(apps) => apps,
+        None => {
+            let app_tables = AppTableSql::read_workspace_apps(workspace_id, false, conn)?;
+            let apps = app_tables.into_iter().map(|table| table.into()).collect::<Vec<App>>();
+            cache.put_apps(workspace_id, apps.clone());
+            apps
+        },
+    };
     let trash_ids = trash_can.trash_ids(conn)?;
-    app_tables.retain(|app_table| !trash_ids.contains(&app_table.id));
-
-    let apps = app_tables.into_iter().map(|table| table.into()).collect::<Vec<App>>();
+    apps.retain(|app| !trash_ids.contains(&app.id));
     Ok(RepeatedApp { items: apps })
 }
 
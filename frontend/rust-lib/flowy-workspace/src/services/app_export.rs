@@ -0,0 +1,94 @@
+use crate::{
+    entities::{
+        share::{AppExportType, ExportAppParams, ExportAppProgress},
+        view::View,
+    },
+    errors::{internal_error, WorkspaceError},
+    notify::{send_dart_notification, WorkspaceNotification},
+};
+use flowy_database::ConnectionPool;
+use flowy_document::module::FlowyDocument;
+use flowy_document_infra::{
+    core::{sections_to_markdown, sections_to_pdf, ExportSection},
+    entities::doc::DocIdentifier,
+};
+use lib_ot::core::Delta;
+use std::sync::Arc;
+
+// The view and its depth in the app, the views of the app are at level 1
+pub(crate) struct ExportView {
+    pub(crate) view: View,
+    pub(crate) level: usize,
+}
+
+// Writes the docs of the views to one file in the background, the views are in the order of the
+// sidebar. The progress is notified with the app_id after each doc is read and when the file is
+// written, the error is notified if the export fails.
+pub(crate) fn spawn_app_export(
+    title: String,
+    views: Vec<ExportView>,
+    params: ExportAppParams,
+    document: Arc<FlowyDocument>,
+    pool: Arc<ConnectionPool>,
+) -> ExportAppProgress {
+    let progress = ExportAppProgress {
+        app_id: params.app_id.clone(),
+        path: params.path.clone(),
+        total: views.len() as i64,
+        finished: 0,
+        is_completed: false,
+    };
+
+    let task_progress = progress.clone();
+    let _ = tokio::spawn(async move {
+        let app_id = params.app_id.clone();
+        if let Err(e) = export_app(&title, views, params, document, pool, task_progress).await {
+            log::error!("Export the app {} failed: {:?}", app_id, e);
+            send_dart_notification(&app_id, WorkspaceNotification::AppExportProgress)
+                .error(e)
+                .send();
+        }
+    });
+    progress
+}
+
+async fn export_app(
+    title: &str,
+    views: Vec<ExportView>,
+    params: ExportAppParams,
+    document: Arc<FlowyDocument>,
+    pool: Arc<ConnectionPool>,
+    mut progress: ExportAppProgress,
+) -> Result<(), WorkspaceError> {
+    let mut sections = vec![];
+    for ExportView { view, level } in views {
+        let doc_identifier: DocIdentifier = view.id.clone().into();
+        let doc = document.read_document_data(doc_identifier, pool.clone()).await?;
+        let delta = Delta::from_json(&doc.data).map_err(internal_error)?;
+        sections.push(ExportSection {
+            id: view.id,
+            title: view.name,
+            level,
+            delta,
+        });
+
+        progress.finished += 1;
+        notify_progress(&progress);
+    }
+
+    let data = match params.export_type {
+        AppExportType::Markdown => sections_to_markdown(&sections).into_bytes(),
+        AppExportType::Pdf => sections_to_pdf(title, &sections),
+    };
+    let _ = std::fs::write(&params.path, data).map_err(internal_error)?;
+
+    progress.is_completed = true;
+    notify_progress(&progress);
+    Ok(())
+}
+
+fn notify_progress(progress: &ExportAppProgress) {
+    send_dart_notification(&progress.app_id, WorkspaceNotification::AppExportProgress)
+        .payload(progress.clone())
+        .send();
+}
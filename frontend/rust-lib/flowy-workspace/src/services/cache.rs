@@ -0,0 +1,78 @@
+use crate::entities::{app::App, view::View};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+
+// The apps of the workspaces and the views of the apps or views that were read from the sqlite. The local
+// writes replace the list that they changed with the one that is read again in the same transaction, so
+// the next read returns the change without waiting for the server. The lists that the server or the
+// reconciled renames may have changed are dropped and read from the sqlite again.
+//
+// The lists keep the objects in the trash, they are filtered when they are read because the trash is
+// also changed by the server.
+#[derive(Default)]
+pub(crate) struct WorkspaceCache {
+    apps: RwLock<HashMap<String, Vec<App>>>,
+    views: RwLock<HashMap<String, Vec<View>>>,
+}
+
+impl WorkspaceCache {
+    pub(crate) fn new() -> Self { Self::default() }
+
+    pub(crate) fn apps(&self, workspace_id: &str) -> Option<Vec<App>> { self.apps.read().get(workspace_id).cloned() }
+
+    pub(crate) fn put_apps(&self, workspace_id: &str, apps: Vec<App>) {
+        self.apps.write().insert(workspace_id.to_owned(), apps);
+    }
+
+    pub(crate) fn remove_apps(&self, workspace_id: &str) { self.apps.write().remove(workspace_id); }
+
+    pub(crate) fn views(&self, belong_to_id: &str) -> Option<Vec<View>> { self.views.read().get(belong_to_id).cloned() }
+
+    pub(crate) fn put_views(&self, belong_to_id: &str, views: Vec<View>) {
+        self.views.write().insert(belong_to_id.to_owned(), views);
+    }
+
+    pub(crate) fn remove_views(&self, belong_to_id: &str) { self.views.write().remove(belong_to_id); }
+
+    // Drops the lists that have the app or the view, and the views that belong to it. It's used when the
+    // parent of the object isn't known, e.g. the object may have been moved by others.
+    pub(crate) fn invalidate(&self, object_id: &str) {
+        self.apps
+            .write()
+            .retain(|_, apps| apps.iter().all(|app| app.id != object_id));
+        let mut views = self.views.write();
+        views.remove(object_id);
+        views.retain(|_, views| views.iter().all(|view| view.id != object_id));
+    }
+
+    pub(crate) fn clear(&self) {
+        self.apps.write().clear();
+        self.views.write().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(id: &str, belong_to_id: &str) -> View {
+        View {
+            id: id.to_owned(),
+            belong_to_id: belong_to_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn invalidate_lists_of_object_test() {
+        let cache = WorkspaceCache::new();
+        cache.put_views("app", vec![view("a", "app"), view("b", "app")]);
+        cache.put_views("a", vec![view("c", "a")]);
+        cache.put_views("other_app", vec![view("d", "other_app")]);
+
+        cache.invalidate("a");
+        assert!(cache.views("app").is_none());
+        assert!(cache.views("a").is_none());
+        assert_eq!(cache.views("other_app").unwrap().len(), 1);
+    }
+}
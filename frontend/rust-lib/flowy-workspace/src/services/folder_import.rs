@@ -0,0 +1,268 @@
+use crate::{
+    entities::{
+        share::{ImportFolderProgress, UploadAttachmentParams},
+        view::{CreateViewParams, ViewType},
+    },
+    errors::{internal_error, ErrorCode, WorkspaceError},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::ViewController,
+};
+use flowy_document_infra::core::markdown_to_delta_with_links;
+use lib_ot::core::{Attribute, Delta};
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+// The markdown file or the folder that contains markdown files, it becomes a view with the name
+pub(crate) struct ImportEntry {
+    pub(crate) name: String,
+    path: PathBuf,
+    entries: Vec<ImportEntry>,
+}
+
+impl ImportEntry {
+    fn count(&self) -> usize { self.entries.iter().map(|entry| 1 + entry.count()).sum() }
+}
+
+// Reads the tree of the folder before the app is created, so the invalid path is returned as the error
+// of the event. The path is canonicalized, so the links can be compared with the paths of the files.
+pub(crate) fn read_import_folder(path: &str) -> Result<ImportEntry, WorkspaceError> {
+    let path = fs::canonicalize(path).map_err(|_| ErrorCode::ImportPathInvalid)?;
+    if !path.is_dir() {
+        return Err(ErrorCode::ImportPathInvalid.into());
+    }
+
+    let name = file_name(&path);
+    let entries = read_entries(&path).map_err(internal_error)?;
+    Ok(ImportEntry { name, path, entries })
+}
+
+fn read_entries(dir: &Path) -> std::io::Result<Vec<ImportEntry>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<PathBuf>>>()?;
+    paths.sort();
+
+    let mut entries = vec![];
+    for path in paths {
+        // e.g. the .obsidian folder that keeps the settings of the vault
+        if file_name(&path).starts_with('.') {
+            continue;
+        }
+
+        if path.is_dir() {
+            let children = read_entries(&path)?;
+            if !children.is_empty() {
+                entries.push(ImportEntry {
+                    name: file_name(&path),
+                    path,
+                    entries: children,
+                });
+            }
+        } else if is_markdown(&path) {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+            entries.push(ImportEntry {
+                name,
+                path,
+                entries: vec![],
+            });
+        }
+    }
+    Ok(entries)
+}
+
+// Creates the views and imports the files in the background. The progress is notified with the app_id
+// after each view is created, the error is notified if the import fails.
+pub(crate) fn spawn_folder_import(
+    app_id: String,
+    folder: ImportEntry,
+    controller: Arc<ViewController>,
+) -> ImportFolderProgress {
+    let progress = ImportFolderProgress {
+        app_id: app_id.clone(),
+        path: folder.path.to_string_lossy().to_string(),
+        total: folder.count() as i64,
+        finished: 0,
+        is_completed: false,
+        warnings: vec![],
+    };
+
+    let task_progress = progress.clone();
+    let _ = tokio::spawn(async move {
+        if let Err(e) = import_folder(folder, controller, task_progress).await {
+            log::error!("Import the folder into the app {} failed: {:?}", app_id, e);
+            send_dart_notification(&app_id, WorkspaceNotification::AppImportProgress)
+                .error(e)
+                .send();
+        }
+    });
+    progress
+}
+
+async fn import_folder(
+    folder: ImportEntry,
+    controller: Arc<ViewController>,
+    mut progress: ImportFolderProgress,
+) -> Result<(), WorkspaceError> {
+    // All the views are created before the files are imported, so the links to the files that are
+    // imported later can be resolved.
+    let mut files = vec![];
+    let mut queue = VecDeque::from(vec![(progress.app_id.clone(), folder.entries)]);
+    while let Some((belong_to_id, entries)) = queue.pop_front() {
+        for entry in entries {
+            let params = CreateViewParams::new(
+                belong_to_id.clone(),
+                entry.name,
+                "".to_owned(),
+                ViewType::Doc,
+                "".to_owned(),
+            );
+            let view = controller.create_view_from_params(params).await?;
+            if entry.path.is_dir() {
+                progress.finished += 1;
+                notify_progress(&progress);
+                queue.push_back((view.id, entry.entries));
+            } else {
+                files.push((entry.path, view.id));
+            }
+        }
+    }
+
+    let view_ids = files.iter().cloned().collect::<HashMap<PathBuf, String>>();
+    for (path, view_id) in files {
+        let relative_path = path.strip_prefix(&folder.path).unwrap_or(&path).display().to_string();
+        match fs::read_to_string(&path) {
+            Ok(markdown) => {
+                let file = ImportFile {
+                    path: &path,
+                    relative_path: &relative_path,
+                    root: &folder.path,
+                    view_ids: &view_ids,
+                };
+                let delta = file.to_delta(&view_id, &markdown, &controller, &mut progress.warnings).await;
+                let _ = controller.replace_doc(&view_id, delta).await?;
+            },
+            Err(e) => {
+                let warning = format!("{}: the file can't be read, {}", relative_path, e);
+                progress.warnings.push(warning);
+            },
+        }
+
+        progress.finished += 1;
+        notify_progress(&progress);
+    }
+
+    progress.is_completed = true;
+    notify_progress(&progress);
+    Ok(())
+}
+
+struct ImportFile<'a> {
+    path: &'a Path,
+    relative_path: &'a str,
+    root: &'a Path,
+    view_ids: &'a HashMap<PathBuf, String>,
+}
+
+impl<'a> ImportFile<'a> {
+    // The links to the imported files become the links to their docs and the images are uploaded as the
+    // attachments of the doc. The other relative links are kept as they are and added to the warnings.
+    async fn to_delta(
+        &self,
+        view_id: &str,
+        markdown: &str,
+        controller: &ViewController,
+        warnings: &mut Vec<String>,
+    ) -> Delta {
+        let mut images = vec![];
+        let _ = markdown_to_delta_with_links(markdown, &mut |url, is_image| {
+            if is_image {
+                images.push(url.to_owned());
+            }
+            None
+        });
+
+        let mut attachment_ids = HashMap::new();
+        for url in images {
+            let path = match self.resolve(&url) {
+                Some(path) if !attachment_ids.contains_key(&url) => path,
+                _ => continue,
+            };
+            let params = UploadAttachmentParams {
+                doc_id: view_id.to_owned(),
+                path: path.to_string_lossy().to_string(),
+            };
+            match controller.upload_attachment(params).await {
+                Ok(progress) => {
+                    attachment_ids.insert(url, progress.attachment_id);
+                },
+                Err(e) => warnings.push(format!("{}: the image {} can't be uploaded, {}", self.relative_path, url, e)),
+            }
+        }
+
+        markdown_to_delta_with_links(markdown, &mut |url, is_image| {
+            if is_external(url) {
+                return None;
+            }
+
+            let attribute = if is_image {
+                attachment_ids.get(url).map(|id| Attribute::Attachment(id))
+            } else {
+                self.resolve(url)
+                    .and_then(|path| self.view_ids.get(&path))
+                    .map(|id| Attribute::Link(id))
+            };
+            if attribute.is_none() {
+                warnings.push(format!("{}: {} isn't imported", self.relative_path, url));
+            }
+            attribute
+        })
+    }
+
+    // The relative url is resolved like the markdown editors do, relative to the file first and then to
+    // the imported folder. The extension of the markdown file may be omitted.
+    fn resolve(&self, url: &str) -> Option<PathBuf> {
+        if is_external(url) {
+            return None;
+        }
+
+        let url = url.trim_start_matches('<').trim_end_matches('>');
+        let url = url.split('#').next().unwrap_or(url).replace("%20", " ");
+        if url.is_empty() {
+            return None;
+        }
+
+        let dirs = [self.path.parent()?, self.root];
+        dirs.iter().find_map(|dir| {
+            let path = dir.join(url.trim_start_matches('/'));
+            let mut candidates = vec![path.clone()];
+            if path.extension().is_none() {
+                candidates.push(path.with_extension("md"));
+            }
+            candidates
+                .into_iter()
+                .find_map(|path| fs::canonicalize(path).ok().filter(|path| path.is_file()))
+        })
+    }
+}
+
+// The urls with a scheme, e.g. https: or mailto:, and the anchors in the same file
+fn is_external(url: &str) -> bool { url.contains(':') || url.starts_with('#') }
+
+fn is_markdown(path: &Path) -> bool {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => extension.eq_ignore_ascii_case("md") || extension.eq_ignore_ascii_case("markdown"),
+        None => false,
+    }
+}
+
+fn file_name(path: &Path) -> String { path.file_name().unwrap_or_default().to_string_lossy().to_string() }
+
+fn notify_progress(progress: &ImportFolderProgress) {
+    send_dart_notification(&progress.app_id, WorkspaceNotification::AppImportProgress)
+        .payload(progress.clone())
+        .send();
+}
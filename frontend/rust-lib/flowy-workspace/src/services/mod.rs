@@ -1,11 +1,21 @@
+pub(crate) use account_merge::*;
 pub(crate) use app_controller::*;
+pub(crate) use app_export::*;
+pub(crate) use cache::*;
+pub(crate) use folder_import::*;
 pub(crate) use trash_can::*;
+pub(crate) use sync::*;
 pub(crate) use view_controller::*;
 pub use workspace_controller::*;
 
+mod account_merge;
 mod app_controller;
+mod app_export;
+mod cache;
 mod database;
+mod folder_import;
 pub(crate) mod server;
+mod sync;
 mod trash_can;
 mod view_controller;
 mod workspace_controller;
@@ -7,10 +7,40 @@ pub use server_api_mock::*;
 
 use crate::{
     entities::{
-        app::{App, AppIdentifier, CreateAppParams, UpdateAppParams},
+        app::{App, AppIdentifier, CreateAppParams, MoveAppParams, UpdateAppParams},
         trash::{RepeatedTrash, TrashIdentifiers},
-        view::{CreateViewParams, UpdateViewParams, View, ViewIdentifier, ViewIdentifiers},
-        workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceIdentifier},
+        view::{
+            CreateShareLinkParams,
+            CreateViewParams,
+            MoveViewParams,
+            PinPublishedViewParams,
+            PublishedView,
+            QueryRecentViewsParams,
+            RecentViewRecord,
+            RepeatedFavorite,
+            RepeatedRecentView,
+            RepeatedShareLink,
+            RepeatedViewAccessLog,
+            SetViewWebhookParams,
+            ShareLink,
+            ShareLinkIdentifier,
+            UpdateViewParams,
+            View,
+            ViewFavoriteState,
+            ViewIdentifier,
+            ViewIdentifiers,
+            ViewWebhook,
+        },
+        workspace::{
+            ArchiveWorkspaceParams,
+            CreateWorkspaceParams,
+            RepeatedWorkspace,
+            RepeatedWorkspaceMember,
+            ShareWorkspaceParams,
+            UpdateWorkspaceParams,
+            Workspace,
+            WorkspaceIdentifier,
+        },
     },
     errors::WorkspaceError,
 };
@@ -36,6 +66,16 @@ pub trait WorkspaceServerAPI {
 
     fn delete_workspace(&self, token: &str, params: WorkspaceIdentifier) -> ResultFuture<(), WorkspaceError>;
 
+    fn archive_workspace(&self, token: &str, params: ArchiveWorkspaceParams) -> ResultFuture<(), WorkspaceError>;
+
+    fn share_workspace(&self, token: &str, params: ShareWorkspaceParams) -> ResultFuture<(), WorkspaceError>;
+
+    fn read_workspace_members(
+        &self,
+        token: &str,
+        params: WorkspaceIdentifier,
+    ) -> ResultFuture<RepeatedWorkspaceMember, WorkspaceError>;
+
     // View
     fn create_view(&self, token: &str, params: CreateViewParams) -> ResultFuture<View, WorkspaceError>;
 
@@ -45,6 +85,57 @@ pub trait WorkspaceServerAPI {
 
     fn update_view(&self, token: &str, params: UpdateViewParams) -> ResultFuture<(), WorkspaceError>;
 
+    fn move_view(&self, token: &str, params: MoveViewParams) -> ResultFuture<(), WorkspaceError>;
+
+    // Copies the view with the views nested in it and their docs, returns the copy with the nested copies
+    fn duplicate_view(&self, token: &str, params: ViewIdentifier) -> ResultFuture<View, WorkspaceError>;
+
+    fn publish_view(&self, token: &str, params: ViewIdentifier) -> ResultFuture<PublishedView, WorkspaceError>;
+
+    fn unpublish_view(&self, token: &str, params: ViewIdentifier) -> ResultFuture<(), WorkspaceError>;
+
+    fn pin_published_view(
+        &self,
+        token: &str,
+        params: PinPublishedViewParams,
+    ) -> ResultFuture<PublishedView, WorkspaceError>;
+
+    fn publish_latest_view(&self, token: &str, params: ViewIdentifier) -> ResultFuture<PublishedView, WorkspaceError>;
+
+    fn read_view_access_log(
+        &self,
+        token: &str,
+        params: ViewIdentifier,
+    ) -> ResultFuture<RepeatedViewAccessLog, WorkspaceError>;
+
+    fn create_share_link(&self, token: &str, params: CreateShareLinkParams) -> ResultFuture<ShareLink, WorkspaceError>;
+
+    fn read_share_links(
+        &self,
+        token: &str,
+        params: WorkspaceIdentifier,
+    ) -> ResultFuture<RepeatedShareLink, WorkspaceError>;
+
+    fn revoke_share_link(&self, token: &str, params: ShareLinkIdentifier) -> ResultFuture<(), WorkspaceError>;
+
+    fn update_favorite(&self, token: &str, params: ViewFavoriteState) -> ResultFuture<(), WorkspaceError>;
+
+    fn read_favorites(&self, token: &str) -> ResultFuture<RepeatedFavorite, WorkspaceError>;
+
+    fn update_recent_view(&self, token: &str, params: RecentViewRecord) -> ResultFuture<(), WorkspaceError>;
+
+    fn read_recent_views(
+        &self,
+        token: &str,
+        params: QueryRecentViewsParams,
+    ) -> ResultFuture<RepeatedRecentView, WorkspaceError>;
+
+    fn set_view_webhook(&self, token: &str, params: SetViewWebhookParams) -> ResultFuture<ViewWebhook, WorkspaceError>;
+
+    fn read_view_webhook(&self, token: &str, params: ViewIdentifier) -> ResultFuture<ViewWebhook, WorkspaceError>;
+
+    fn delete_view_webhook(&self, token: &str, params: ViewIdentifier) -> ResultFuture<(), WorkspaceError>;
+
     // App
     fn create_app(&self, token: &str, params: CreateAppParams) -> ResultFuture<App, WorkspaceError>;
 
@@ -52,6 +143,11 @@ pub trait WorkspaceServerAPI {
 
     fn update_app(&self, token: &str, params: UpdateAppParams) -> ResultFuture<(), WorkspaceError>;
 
+    fn move_app(&self, token: &str, params: MoveAppParams) -> ResultFuture<(), WorkspaceError>;
+
+    // Copies the app with all its views and their docs, returns the copy with the copied views
+    fn duplicate_app(&self, token: &str, params: AppIdentifier) -> ResultFuture<App, WorkspaceError>;
+
     fn delete_app(&self, token: &str, params: AppIdentifier) -> ResultFuture<(), WorkspaceError>;
 
     // Trash
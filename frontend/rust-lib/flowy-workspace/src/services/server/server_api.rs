@@ -1,9 +1,39 @@
 use crate::{
     entities::{
-        app::{App, AppIdentifier, CreateAppParams, UpdateAppParams},
+        app::{App, AppIdentifier, CreateAppParams, MoveAppParams, UpdateAppParams},
         trash::{RepeatedTrash, TrashIdentifiers},
-        view::{CreateViewParams, UpdateViewParams, View, ViewIdentifier, ViewIdentifiers},
-        workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceIdentifier},
+        view::{
+            CreateShareLinkParams,
+            CreateViewParams,
+            MoveViewParams,
+            PinPublishedViewParams,
+            PublishedView,
+            QueryRecentViewsParams,
+            RecentViewRecord,
+            RepeatedFavorite,
+            RepeatedRecentView,
+            RepeatedShareLink,
+            RepeatedViewAccessLog,
+            SetViewWebhookParams,
+            ShareLink,
+            ShareLinkIdentifier,
+            UpdateViewParams,
+            View,
+            ViewFavoriteState,
+            ViewIdentifier,
+            ViewIdentifiers,
+            ViewWebhook,
+        },
+        workspace::{
+            ArchiveWorkspaceParams,
+            CreateWorkspaceParams,
+            RepeatedWorkspace,
+            RepeatedWorkspaceMember,
+            ShareWorkspaceParams,
+            UpdateWorkspaceParams,
+            Workspace,
+            WorkspaceIdentifier,
+        },
     },
     errors::WorkspaceError,
     notify::{send_dart_notification, WorkspaceNotification},
@@ -79,6 +109,37 @@ impl WorkspaceServerAPI for WorkspaceServer {
         })
     }
 
+    fn archive_workspace(&self, token: &str, params: ArchiveWorkspaceParams) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_archive_url();
+        ResultFuture::new(async move {
+            let _ = archive_workspace_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn share_workspace(&self, token: &str, params: ShareWorkspaceParams) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_member_url();
+        ResultFuture::new(async move {
+            let _ = share_workspace_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn read_workspace_members(
+        &self,
+        token: &str,
+        params: WorkspaceIdentifier,
+    ) -> ResultFuture<RepeatedWorkspaceMember, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.workspace_member_url();
+        ResultFuture::new(async move {
+            let repeated_member = read_workspace_members_request(&token, params, &url).await?;
+            Ok(repeated_member)
+        })
+    }
+
     fn create_view(&self, token: &str, params: CreateViewParams) -> ResultFuture<View, WorkspaceError> {
         let token = token.to_owned();
         let url = self.config.view_url();
@@ -115,6 +176,175 @@ impl WorkspaceServerAPI for WorkspaceServer {
         })
     }
 
+    fn move_view(&self, token: &str, params: MoveViewParams) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_move_url();
+        ResultFuture::new(async move {
+            let _ = move_view_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn duplicate_view(&self, token: &str, params: ViewIdentifier) -> ResultFuture<View, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_duplicate_url();
+        ResultFuture::new(async move {
+            let view = duplicate_view_request(&token, params, &url).await?;
+            Ok(view)
+        })
+    }
+
+    fn publish_view(&self, token: &str, params: ViewIdentifier) -> ResultFuture<PublishedView, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_publish_url();
+        ResultFuture::new(async move {
+            let published_view = publish_view_request(&token, params, &url).await?;
+            Ok(published_view)
+        })
+    }
+
+    fn unpublish_view(&self, token: &str, params: ViewIdentifier) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_publish_url();
+        ResultFuture::new(async move {
+            let _ = unpublish_view_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn pin_published_view(
+        &self,
+        token: &str,
+        params: PinPublishedViewParams,
+    ) -> ResultFuture<PublishedView, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_publish_pin_url();
+        ResultFuture::new(async move {
+            let published_view = pin_published_view_request(&token, params, &url).await?;
+            Ok(published_view)
+        })
+    }
+
+    fn publish_latest_view(&self, token: &str, params: ViewIdentifier) -> ResultFuture<PublishedView, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_publish_latest_url();
+        ResultFuture::new(async move {
+            let published_view = publish_latest_view_request(&token, params, &url).await?;
+            Ok(published_view)
+        })
+    }
+
+    fn read_view_access_log(
+        &self,
+        token: &str,
+        params: ViewIdentifier,
+    ) -> ResultFuture<RepeatedViewAccessLog, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_access_log_url();
+        ResultFuture::new(async move {
+            let repeated_log = read_view_access_log_request(&token, params, &url).await?;
+            Ok(repeated_log)
+        })
+    }
+
+    fn create_share_link(&self, token: &str, params: CreateShareLinkParams) -> ResultFuture<ShareLink, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.share_link_url();
+        ResultFuture::new(async move {
+            let share_link = create_share_link_request(&token, params, &url).await?;
+            Ok(share_link)
+        })
+    }
+
+    fn read_share_links(
+        &self,
+        token: &str,
+        params: WorkspaceIdentifier,
+    ) -> ResultFuture<RepeatedShareLink, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.share_link_url();
+        ResultFuture::new(async move {
+            let repeated_share_link = read_share_links_request(&token, params, &url).await?;
+            Ok(repeated_share_link)
+        })
+    }
+
+    fn revoke_share_link(&self, token: &str, params: ShareLinkIdentifier) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.share_link_url();
+        ResultFuture::new(async move {
+            let _ = revoke_share_link_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn update_favorite(&self, token: &str, params: ViewFavoriteState) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.favorite_url();
+        ResultFuture::new(async move {
+            let _ = update_favorite_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn read_favorites(&self, token: &str) -> ResultFuture<RepeatedFavorite, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.favorite_url();
+        ResultFuture::new(async move {
+            let repeated_favorite = read_favorites_request(&token, &url).await?;
+            Ok(repeated_favorite)
+        })
+    }
+
+    fn update_recent_view(&self, token: &str, params: RecentViewRecord) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.recent_view_url();
+        ResultFuture::new(async move {
+            let _ = update_recent_view_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn read_recent_views(
+        &self,
+        token: &str,
+        params: QueryRecentViewsParams,
+    ) -> ResultFuture<RepeatedRecentView, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.recent_view_url();
+        ResultFuture::new(async move {
+            let repeated_recent_view = read_recent_views_request(&token, params, &url).await?;
+            Ok(repeated_recent_view)
+        })
+    }
+
+    fn set_view_webhook(&self, token: &str, params: SetViewWebhookParams) -> ResultFuture<ViewWebhook, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_webhook_url();
+        ResultFuture::new(async move {
+            let webhook = set_view_webhook_request(&token, params, &url).await?;
+            Ok(webhook)
+        })
+    }
+
+    fn read_view_webhook(&self, token: &str, params: ViewIdentifier) -> ResultFuture<ViewWebhook, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_webhook_url();
+        ResultFuture::new(async move {
+            let webhook = read_view_webhook_request(&token, params, &url).await?;
+            Ok(webhook)
+        })
+    }
+
+    fn delete_view_webhook(&self, token: &str, params: ViewIdentifier) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.view_webhook_url();
+        ResultFuture::new(async move {
+            let _ = delete_view_webhook_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
     fn create_app(&self, token: &str, params: CreateAppParams) -> ResultFuture<App, WorkspaceError> {
         let token = token.to_owned();
         let url = self.config.app_url();
@@ -142,6 +372,24 @@ impl WorkspaceServerAPI for WorkspaceServer {
         })
     }
 
+    fn move_app(&self, token: &str, params: MoveAppParams) -> ResultFuture<(), WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.app_move_url();
+        ResultFuture::new(async move {
+            let _ = move_app_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn duplicate_app(&self, token: &str, params: AppIdentifier) -> ResultFuture<App, WorkspaceError> {
+        let token = token.to_owned();
+        let url = self.config.app_duplicate_url();
+        ResultFuture::new(async move {
+            let app = duplicate_app_request(&token, params, &url).await?;
+            Ok(app)
+        })
+    }
+
     fn delete_app(&self, token: &str, params: AppIdentifier) -> ResultFuture<(), WorkspaceError> {
         let token = token.to_owned();
         let url = self.config.app_url();
@@ -1,9 +1,41 @@
 use crate::{
     entities::{
-        app::{App, AppIdentifier, CreateAppParams, RepeatedApp, UpdateAppParams},
+        app::{App, AppIdentifier, CreateAppParams, MoveAppParams, RepeatedApp, UpdateAppParams},
         trash::{RepeatedTrash, TrashIdentifiers},
-        view::{CreateViewParams, RepeatedView, UpdateViewParams, View, ViewIdentifier, ViewIdentifiers},
-        workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceIdentifier},
+        view::{
+            CreateShareLinkParams,
+            CreateViewParams,
+            MoveViewParams,
+            PinPublishedViewParams,
+            PublishedView,
+            QueryRecentViewsParams,
+            RecentViewRecord,
+            RepeatedFavorite,
+            RepeatedRecentView,
+            RepeatedShareLink,
+            RepeatedViewAccessLog,
+            RepeatedView,
+            SetViewWebhookParams,
+            ShareLink,
+            ShareLinkIdentifier,
+            UpdateViewParams,
+            View,
+            ViewFavoriteState,
+            ViewIdentifier,
+            ViewIdentifiers,
+            ViewWebhook,
+        },
+        workspace::{
+            ArchiveWorkspaceParams,
+            CreateWorkspaceParams,
+            RepeatedWorkspace,
+            RepeatedWorkspaceMember,
+            ShareWorkspaceParams,
+            UpdateWorkspaceParams,
+            Workspace,
+            WorkspaceIdentifier,
+            WorkspaceRole,
+        },
     },
     errors::WorkspaceError,
     services::server::WorkspaceServerAPI,
@@ -24,6 +56,8 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
             apps: RepeatedApp::default(),
             modified_time: time,
             create_time: time,
+            archived: false,
+            role: WorkspaceRole::Owner,
         };
 
         ResultFuture::new(async { Ok(workspace) })
@@ -48,6 +82,22 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
         ResultFuture::new(async { Ok(()) })
     }
 
+    fn archive_workspace(&self, _token: &str, _params: ArchiveWorkspaceParams) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    fn share_workspace(&self, _token: &str, _params: ShareWorkspaceParams) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    fn read_workspace_members(
+        &self,
+        _token: &str,
+        _params: WorkspaceIdentifier,
+    ) -> ResultFuture<RepeatedWorkspaceMember, WorkspaceError> {
+        ResultFuture::new(async { Ok(RepeatedWorkspaceMember::default()) })
+    }
+
     fn create_view(&self, _token: &str, params: CreateViewParams) -> ResultFuture<View, WorkspaceError> {
         let time = timestamp();
         let view = View {
@@ -60,6 +110,9 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
             belongings: RepeatedView::default(),
             modified_time: time,
             create_time: time,
+            // The mock doesn't know the siblings, the views are ranked when one of them is moved
+            rank: "".to_owned(),
+            modified_device_id: "".to_owned(),
         };
         ResultFuture::new(async { Ok(view) })
     }
@@ -76,6 +129,142 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
         ResultFuture::new(async { Ok(()) })
     }
 
+    fn move_view(&self, _token: &str, _params: MoveViewParams) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    // The mock doesn't keep the views, so there is nothing to copy
+    fn duplicate_view(&self, _token: &str, _params: ViewIdentifier) -> ResultFuture<View, WorkspaceError> {
+        ResultFuture::new(async { Err(WorkspaceError::record_not_found()) })
+    }
+
+    fn publish_view(&self, _token: &str, params: ViewIdentifier) -> ResultFuture<PublishedView, WorkspaceError> {
+        let published_view = PublishedView {
+            slug: uuid(),
+            view_id: params.view_id,
+            publish_time: timestamp(),
+            ..Default::default()
+        };
+        ResultFuture::new(async { Ok(published_view) })
+    }
+
+    fn unpublish_view(&self, _token: &str, _params: ViewIdentifier) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    fn pin_published_view(
+        &self,
+        _token: &str,
+        params: PinPublishedViewParams,
+    ) -> ResultFuture<PublishedView, WorkspaceError> {
+        let published_view = PublishedView {
+            slug: uuid(),
+            view_id: params.view_id,
+            publish_time: timestamp(),
+            rev_id: params.rev_id,
+            is_pinned: true,
+        };
+        ResultFuture::new(async { Ok(published_view) })
+    }
+
+    fn publish_latest_view(&self, _token: &str, params: ViewIdentifier) -> ResultFuture<PublishedView, WorkspaceError> {
+        let published_view = PublishedView {
+            slug: uuid(),
+            view_id: params.view_id,
+            publish_time: timestamp(),
+            ..Default::default()
+        };
+        ResultFuture::new(async { Ok(published_view) })
+    }
+
+    fn read_view_access_log(
+        &self,
+        _token: &str,
+        _params: ViewIdentifier,
+    ) -> ResultFuture<RepeatedViewAccessLog, WorkspaceError> {
+        ResultFuture::new(async { Ok(RepeatedViewAccessLog::default()) })
+    }
+
+    fn create_share_link(
+        &self,
+        _token: &str,
+        params: CreateShareLinkParams,
+    ) -> ResultFuture<ShareLink, WorkspaceError> {
+        let link_id = uuid();
+        let share_link = ShareLink {
+            link_id: link_id.clone(),
+            view_id: params.view_id,
+            create_time: timestamp(),
+            expire_time: params.expire_time,
+            has_passcode: !params.passcode.is_empty(),
+            slug: link_id,
+        };
+        ResultFuture::new(async { Ok(share_link) })
+    }
+
+    fn read_share_links(
+        &self,
+        _token: &str,
+        _params: WorkspaceIdentifier,
+    ) -> ResultFuture<RepeatedShareLink, WorkspaceError> {
+        ResultFuture::new(async { Ok(RepeatedShareLink::default()) })
+    }
+
+    fn revoke_share_link(&self, _token: &str, _params: ShareLinkIdentifier) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    fn update_favorite(&self, _token: &str, _params: ViewFavoriteState) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    // The mock doesn't keep the favorites, so the local ones are kept
+    fn read_favorites(&self, _token: &str) -> ResultFuture<RepeatedFavorite, WorkspaceError> {
+        ResultFuture::new(async { Err(WorkspaceError::record_not_found()) })
+    }
+
+    fn update_recent_view(&self, _token: &str, _params: RecentViewRecord) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    // The mock doesn't keep the recent views, so only the local ones are read
+    fn read_recent_views(
+        &self,
+        _token: &str,
+        _params: QueryRecentViewsParams,
+    ) -> ResultFuture<RepeatedRecentView, WorkspaceError> {
+        ResultFuture::new(async { Err(WorkspaceError::record_not_found()) })
+    }
+
+    // The mock doesn't deliver the doc, the webhook is only echoed back
+    fn set_view_webhook(
+        &self,
+        _token: &str,
+        params: SetViewWebhookParams,
+    ) -> ResultFuture<ViewWebhook, WorkspaceError> {
+        let webhook = ViewWebhook {
+            view_id: params.view_id,
+            url: params.url,
+            format: params.format,
+            has_secret: !params.secret.is_empty(),
+            create_time: timestamp(),
+            ..Default::default()
+        };
+        ResultFuture::new(async { Ok(webhook) })
+    }
+
+    fn read_view_webhook(&self, _token: &str, params: ViewIdentifier) -> ResultFuture<ViewWebhook, WorkspaceError> {
+        let webhook = ViewWebhook {
+            view_id: params.view_id,
+            ..Default::default()
+        };
+        ResultFuture::new(async { Ok(webhook) })
+    }
+
+    fn delete_view_webhook(&self, _token: &str, _params: ViewIdentifier) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
     fn create_app(&self, _token: &str, params: CreateAppParams) -> ResultFuture<App, WorkspaceError> {
         let time = timestamp();
         let app = App {
@@ -87,6 +276,9 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
             version: 0,
             modified_time: time,
             create_time: time,
+            // The mock doesn't know the siblings, the apps are ranked when one of them is moved
+            rank: "".to_owned(),
+            modified_device_id: "".to_owned(),
         };
         ResultFuture::new(async { Ok(app) })
     }
@@ -99,6 +291,14 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
         ResultFuture::new(async { Ok(()) })
     }
 
+    fn move_app(&self, _token: &str, _params: MoveAppParams) -> ResultFuture<(), WorkspaceError> {
+        ResultFuture::new(async { Ok(()) })
+    }
+
+    fn duplicate_app(&self, _token: &str, _params: AppIdentifier) -> ResultFuture<App, WorkspaceError> {
+        ResultFuture::new(async { Err(WorkspaceError::record_not_found()) })
+    }
+
     fn delete_app(&self, _token: &str, _params: AppIdentifier) -> ResultFuture<(), WorkspaceError> {
         ResultFuture::new(async { Ok(()) })
     }
@@ -0,0 +1,374 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use flowy_database::SqliteConnection;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    entities::{
+        app::{App, AppIdentifier, UpdateAppParams},
+        view::{UpdateViewParams, View, ViewIdentifier},
+        workspace::{RenameConflict, RenameMergePolicy, RenameMergePolicySetting},
+    },
+    errors::WorkspaceError,
+    module::{WorkspaceDatabase, WorkspaceUser},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{server::Server, WorkspaceCache},
+    sql_tables::{
+        app::{AppTableChangeset, AppTableSql},
+        outbox::{RenameOutboxTable, RenameOutboxTableSql, SqlRenameObjectType},
+        view::{ViewTableChangeset, ViewTableSql},
+    },
+};
+use lib_infra::{
+    bus::{EventBus, WsConnectionEvent},
+    device_id,
+    kv::KV,
+    timestamp,
+};
+
+const RENAME_MERGE_POLICY: &str = "rename_merge_policy";
+
+// Reconciles the changes that were made offline with the server. The renames of the apps and the views
+// are kept in the outbox until the server accepts them, they are sent again after reconnecting and
+// merged with the renames that others made meanwhile.
+pub(crate) struct WorkspaceSync {
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    cache: Arc<WorkspaceCache>,
+    server: Server,
+    bus: EventBus,
+}
+
+impl WorkspaceSync {
+    pub(crate) fn new(
+        user: Arc<dyn WorkspaceUser>,
+        database: Arc<dyn WorkspaceDatabase>,
+        cache: Arc<WorkspaceCache>,
+        server: Server,
+        bus: EventBus,
+    ) -> Self {
+        Self {
+            user,
+            database,
+            cache,
+            server,
+            bus,
+        }
+    }
+
+    pub(crate) fn init(&self) { self.listen_ws_connection_event(); }
+
+    pub(crate) fn read_rename_merge_policy(&self) -> Result<RenameMergePolicySetting, WorkspaceError> {
+        let policy = read_rename_merge_policy(&self.user.user_id()?);
+        Ok(RenameMergePolicySetting { policy })
+    }
+
+    pub(crate) fn update_rename_merge_policy(&self, setting: RenameMergePolicySetting) -> Result<(), WorkspaceError> {
+        let key = rename_merge_policy_key(&self.user.user_id()?);
+        KV::set_int(&key, setting.policy as i64);
+        Ok(())
+    }
+
+    // Called in the transaction that renames the object locally, before the name is sent to the server
+    pub(crate) fn record_rename(
+        &self,
+        ty: SqlRenameObjectType,
+        object_id: &str,
+        base_name: &str,
+        base_modified_time: i64,
+        name: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let table = RenameOutboxTable {
+            object_id: object_id.to_owned(),
+            ty,
+            base_name: base_name.to_owned(),
+            base_modified_time,
+            name: name.to_owned(),
+            renamed_time: timestamp(),
+        };
+        RenameOutboxTableSql::save_rename(table, conn)
+    }
+
+    // The server accepted the rename, the lists that have the object are read from the sqlite again
+    pub(crate) fn did_send_rename(&self, object_id: &str, name: &str) -> Result<(), WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        let _ = RenameOutboxTableSql::delete_rename(object_id, name, &*conn)?;
+        self.cache.invalidate(object_id);
+        Ok(())
+    }
+
+    fn listen_ws_connection_event(&self) {
+        let mut rx = self.bus.subscribe::<WsConnectionEvent>();
+        let user = self.user.clone();
+        let database = self.database.clone();
+        let cache = self.cache.clone();
+        let server = self.server.clone();
+        let _ = tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(WsConnectionEvent::Connected) => {
+                        // The structure may have been changed by others while the connection was lost
+                        cache.clear();
+                        match reconcile_renames(user.clone(), database.clone(), cache.clone(), server.clone()).await {
+                            Ok(_) => {},
+                            Err(e) => log::error!("Reconcile renames failed: {:?}", e),
+                        }
+                    },
+                    Ok(WsConnectionEvent::Disconnected) => {},
+                    Err(RecvError::Lagged(count)) => log::warn!("Missed {} ws connection events", count),
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+}
+
+// The object that is read from the server keeps the local name until its rename is reconciled, otherwise
+// the rename that was made offline is overwritten before it's sent.
+pub(crate) fn keep_pending_name(
+    object_id: &str,
+    name: &mut String,
+    conn: &SqliteConnection,
+) -> Result<(), WorkspaceError> {
+    if let Some(rename) = RenameOutboxTableSql::read_rename(object_id, conn)? {
+        *name = rename.name;
+    }
+    Ok(())
+}
+
+// The name of the object on the server and the device that modified it last
+#[derive(Debug)]
+struct RemoteName {
+    name: String,
+    modified_time: i64,
+    device_id: String,
+}
+
+#[derive(Debug)]
+struct RenameMerge {
+    resolved_name: String,
+    // False if the name on the server is kept
+    is_sent: bool,
+    is_conflicted: bool,
+}
+
+// The rename conflicts if the object was renamed on the server after the base version that it was
+// renamed from locally.
+fn merge_rename(
+    rename: &RenameOutboxTable,
+    remote: &RemoteName,
+    local_device_id: &str,
+    policy: RenameMergePolicy,
+) -> RenameMerge {
+    let remote_name = remote.name.as_str();
+    let is_renamed_remotely = remote.modified_time > rename.base_modified_time && remote_name != rename.base_name;
+    if !is_renamed_remotely || remote_name == rename.name {
+        return RenameMerge {
+            resolved_name: rename.name.clone(),
+            is_sent: remote_name != rename.name,
+            is_conflicted: false,
+        };
+    }
+
+    match policy {
+        RenameMergePolicy::LatestWins => {
+            let is_local_latest = match rename.renamed_time.cmp(&remote.modified_time) {
+                Ordering::Greater => true,
+                Ordering::Less => false,
+                // The renames made within the same second are ordered by their devices, so every device
+                // keeps the same name. The remote rename without a device loses as before.
+                Ordering::Equal => remote.device_id.is_empty() || local_device_id >= remote.device_id.as_str(),
+            };
+            let resolved_name = if is_local_latest { &rename.name } else { remote_name };
+            RenameMerge {
+                resolved_name: resolved_name.to_owned(),
+                is_sent: is_local_latest,
+                is_conflicted: true,
+            }
+        },
+        RenameMergePolicy::AppendSuffix => RenameMerge {
+            resolved_name: format!("{} ({})", rename.name, remote_name),
+            is_sent: true,
+            is_conflicted: true,
+        },
+    }
+}
+
+#[tracing::instrument(level = "debug", skip(user, database, cache, server), err)]
+async fn reconcile_renames(
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    cache: Arc<WorkspaceCache>,
+    server: Server,
+) -> Result<(), WorkspaceError> {
+    let renames = RenameOutboxTableSql::read_all(&*database.db_connection()?)?;
+    if renames.is_empty() {
+        return Ok(());
+    }
+
+    let token = user.token()?;
+    let policy = read_rename_merge_policy(&user.user_id()?);
+    let local_device_id = device_id();
+    for rename in renames {
+        // The objects are read one by one, the rest are kept in the outbox if the connection is lost again
+        let remote = match rename.ty {
+            SqlRenameObjectType::App => server
+                .read_app(&token, AppIdentifier::new(&rename.object_id))
+                .await?
+                .map(|app| RemoteName {
+                    name: app.name,
+                    modified_time: app.modified_time,
+                    device_id: app.modified_device_id,
+                }),
+            SqlRenameObjectType::View => server
+                .read_view(&token, ViewIdentifier::from(rename.object_id.clone()))
+                .await?
+                .map(|view| RemoteName {
+                    name: view.name,
+                    modified_time: view.modified_time,
+                    device_id: view.modified_device_id,
+                }),
+            SqlRenameObjectType::Unknown => None,
+        };
+
+        let merge = match remote {
+            // The object isn't on the server, the rename is sent as it is
+            None => RenameMerge {
+                resolved_name: rename.name.clone(),
+                is_sent: true,
+                is_conflicted: false,
+            },
+            Some(remote) => {
+                let merge = merge_rename(&rename, &remote, &local_device_id, policy);
+                if merge.is_conflicted {
+                    let conflict = RenameConflict {
+                        object_id: rename.object_id.clone(),
+                        local_name: rename.name.clone(),
+                        remote_name: remote.name,
+                        resolved_name: merge.resolved_name.clone(),
+                        policy,
+                        remote_device_id: remote.device_id,
+                    };
+                    send_dart_notification(&rename.object_id, WorkspaceNotification::RenameConflicted)
+                        .payload(conflict)
+                        .send();
+                }
+                merge
+            },
+        };
+
+        if merge.is_sent {
+            match rename.ty {
+                SqlRenameObjectType::App => {
+                    let params = UpdateAppParams::new(&rename.object_id)
+                        .name(&merge.resolved_name)
+                        .device_id(&local_device_id);
+                    let _ = server.update_app(&token, params).await?;
+                },
+                SqlRenameObjectType::View => {
+                    let params = UpdateViewParams::new(&rename.object_id)
+                        .name(&merge.resolved_name)
+                        .device_id(&local_device_id);
+                    let _ = server.update_view(&token, params).await?;
+                },
+                SqlRenameObjectType::Unknown => {},
+            }
+        }
+
+        let conn = &*database.db_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            if merge.resolved_name != rename.name {
+                let _ = save_resolved_name(&rename, &merge.resolved_name, conn)?;
+            }
+            RenameOutboxTableSql::delete_rename(&rename.object_id, &rename.name, conn)
+        })?;
+        cache.invalidate(&rename.object_id);
+    }
+    Ok(())
+}
+
+fn save_resolved_name(rename: &RenameOutboxTable, name: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+    match rename.ty {
+        SqlRenameObjectType::App => {
+            let changeset = AppTableChangeset::new(UpdateAppParams::new(&rename.object_id).name(name));
+            let _ = AppTableSql::update_app(changeset, conn)?;
+            let app: App = AppTableSql::read_app(&rename.object_id, conn)?.into();
+            send_dart_notification(&rename.object_id, WorkspaceNotification::AppUpdated)
+                .payload(app)
+                .send();
+        },
+        SqlRenameObjectType::View => {
+            let changeset = ViewTableChangeset::new(UpdateViewParams::new(&rename.object_id).name(name));
+            let _ = ViewTableSql::update_view(changeset, conn)?;
+            let view: View = ViewTableSql::read_view(&rename.object_id, conn)?.into();
+            send_dart_notification(&rename.object_id, WorkspaceNotification::ViewUpdated)
+                .payload(view)
+                .send();
+        },
+        SqlRenameObjectType::Unknown => {},
+    }
+    Ok(())
+}
+
+fn read_rename_merge_policy(user_id: &str) -> RenameMergePolicy {
+    match KV::get_int(&rename_merge_policy_key(user_id)) {
+        None => RenameMergePolicy::default(),
+        Some(policy) => RenameMergePolicy::from(policy as i32),
+    }
+}
+
+fn rename_merge_policy_key(user_id: &str) -> String { format!("{}_{}", user_id, RENAME_MERGE_POLICY) }
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_rename, RemoteName};
+    use crate::{entities::workspace::RenameMergePolicy, sql_tables::outbox::*};
+
+    fn rename_at(renamed_time: i64) -> RenameOutboxTable {
+        RenameOutboxTable {
+            object_id: "view".to_owned(),
+            ty: SqlRenameObjectType::View,
+            base_name: "Plan".to_owned(),
+            base_modified_time: 100,
+            name: "Roadmap".to_owned(),
+            renamed_time,
+        }
+    }
+
+    fn remote_at(modified_time: i64, device_id: &str) -> RemoteName {
+        RemoteName {
+            name: "Schedule".to_owned(),
+            modified_time,
+            device_id: device_id.to_owned(),
+        }
+    }
+
+    #[test]
+    fn merge_rename_latest_wins() {
+        let merge = merge_rename(&rename_at(300), &remote_at(200, "b"), "a", RenameMergePolicy::LatestWins);
+        assert_eq!(merge.resolved_name, "Roadmap");
+        assert!(merge.is_sent && merge.is_conflicted);
+
+        let merge = merge_rename(&rename_at(200), &remote_at(300, "b"), "a", RenameMergePolicy::LatestWins);
+        assert_eq!(merge.resolved_name, "Schedule");
+        assert!(!merge.is_sent && merge.is_conflicted);
+    }
+
+    #[test]
+    fn merge_rename_at_same_time_by_device() {
+        // Both devices resolve the tie to the rename of the device "b"
+        let merge = merge_rename(&rename_at(200), &remote_at(200, "b"), "a", RenameMergePolicy::LatestWins);
+        assert_eq!(merge.resolved_name, "Schedule");
+
+        let mut rename = rename_at(200);
+        rename.name = "Schedule".to_owned();
+        let mut remote = remote_at(200, "a");
+        remote.name = "Roadmap".to_owned();
+        let merge = merge_rename(&rename, &remote, "b", RenameMergePolicy::LatestWins);
+        assert_eq!(merge.resolved_name, "Schedule");
+
+        let merge = merge_rename(&rename_at(200), &remote_at(200, ""), "a", RenameMergePolicy::LatestWins);
+        assert_eq!(merge.resolved_name, "Roadmap");
+    }
+}
@@ -1,31 +1,129 @@
-use flowy_database::SqliteConnection;
-use flowy_document_infra::entities::doc::{DocDelta, DocIdentifier};
+use flowy_database::{ConnectionPool, SqliteConnection, SEARCH_INDEX};
+use flowy_document_infra::{
+    core::preview_markdown,
+    entities::doc::{
+        AttachmentFile,
+        AttachmentIdentifier,
+        AttachmentUploadProgress,
+        DocDelta,
+        DocIdentifier,
+        DocImportPreview,
+        DocPrefetchProgress,
+        DocReadPosition,
+        DocSelection,
+        DocSnapshotIdentifier,
+        DocTextReplacement,
+        DocUndoState,
+        InputRuleSetting,
+        RepeatedDocSnapshot,
+        RepeatedDocTask,
+        RepeatedDocTextRun,
+        MAX_ATTACHMENT_SIZE,
+    },
+};
 use futures::{FutureExt, StreamExt};
+use parking_lot::RwLock;
 use std::{collections::HashSet, sync::Arc};
+use tokio::sync::broadcast::error::RecvError;
 
 use crate::{
     entities::{
         trash::{TrashIdentifiers, TrashType},
-        view::{CreateViewParams, RepeatedView, UpdateViewParams, View, ViewIdentifier},
+        view::{
+            CreateShareLinkParams,
+            CreateViewParams,
+            Favorite,
+            PinPublishedViewParams,
+            PublishedView,
+            QueryRecentViewsParams,
+            RecentView,
+            RecentViewRecord,
+            RepeatedFavorite,
+            RepeatedRecentView,
+            RepeatedShareLink,
+            RepeatedView,
+            RepeatedViewAccessLog,
+            SetViewWebhookParams,
+            ShareLink,
+            ShareLinkIdentifier,
+            MoveViewParams,
+            UpdateViewParams,
+            View,
+            ViewFavoriteState,
+            ViewIdentifier,
+            ViewPosition,
+            ViewUnreadState,
+            ViewWebhook,
+            DEFAULT_RECENT_LIMIT,
+        },
+        workspace::{
+            RepeatedSearchResult,
+            SearchMatch,
+            SearchResult,
+            SearchWorkspaceParams,
+            WorkspaceIdentifier,
+            MAX_SEARCH_MATCHES,
+        },
     },
-    errors::{internal_error, WorkspaceError, WorkspaceResult},
+    errors::{internal_error, ErrorCode, WorkspaceError, WorkspaceResult},
     module::{WorkspaceDatabase, WorkspaceUser},
-    notify::{send_dart_notification, WorkspaceNotification},
-    services::{server::Server, TrashCan, TrashEvent},
-    sql_tables::view::{ViewTable, ViewTableChangeset, ViewTableSql},
+    notify::{send_anonymous_dart_notification, send_dart_notification, WorkspaceNotification},
+    services::{
+        keep_pending_name,
+        server::Server,
+        spawn_app_export,
+        ExportView,
+        TrashCan,
+        TrashEvent,
+        WorkspaceCache,
+        WorkspaceSync,
+    },
+    sql_tables::{
+        app::{AppTable, AppTableSql},
+        outbox::SqlRenameObjectType,
+        view::{
+            ViewFavoriteTableSql,
+            ViewRecentTableSql,
+            ViewTable,
+            ViewTableChangeset,
+            ViewTableSql,
+            ViewUnreadTableSql,
+        },
+        workspace::{WorkspaceSettingTableSql, WorkspaceTable, WorkspaceTableSql},
+    },
 };
 use flowy_document::module::FlowyDocument;
-use flowy_workspace_infra::entities::share::{ExportData, ExportParams};
-use lib_infra::kv::KV;
-
-const LATEST_VIEW_ID: &str = "latest_view_id";
+use flowy_workspace_infra::{
+    entities::share::{
+        ExportAppParams,
+        ExportAppProgress,
+        ExportData,
+        ExportParams,
+        ExportType,
+        ImportParams,
+        ImportType,
+        UploadAttachmentParams,
+    },
+    rank::ranks_after_move,
+};
+use lib_infra::{
+    bus::{DocRevisionEvent, EventBus},
+    device_id,
+    timestamp,
+};
+use lib_ot::core::Delta;
 
 pub(crate) struct ViewController {
     user: Arc<dyn WorkspaceUser>,
     server: Server,
     database: Arc<dyn WorkspaceDatabase>,
     trash_can: Arc<TrashCan>,
+    workspace_sync: Arc<WorkspaceSync>,
+    cache: Arc<WorkspaceCache>,
     document: Arc<FlowyDocument>,
+    bus: EventBus,
+    // The views that are opened by the user are never marked as unread
+    opened_view_ids: Arc<RwLock<HashSet<String>>>,
 }
 
 impl ViewController {
@@ -34,25 +132,34 @@ impl ViewController {
         database: Arc<dyn WorkspaceDatabase>,
         server: Server,
         trash_can: Arc<TrashCan>,
+        workspace_sync: Arc<WorkspaceSync>,
+        cache: Arc<WorkspaceCache>,
         document: Arc<FlowyDocument>,
+        bus: EventBus,
     ) -> Self {
         Self {
             user,
             server,
             database,
             trash_can,
+            workspace_sync,
+            cache,
             document,
+            bus,
+            opened_view_ids: Arc::new(RwLock::new(HashSet::new())),
         }
     }
 
+    // The document engine is initialized lazily when the first document is opened
     pub(crate) fn init(&self) -> Result<(), WorkspaceError> {
-        let _ = self.document.init()?;
         self.listen_trash_can_event();
+        self.listen_doc_revision_event();
         Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name), err)]
     pub(crate) async fn create_view_from_params(&self, params: CreateViewParams) -> Result<View, WorkspaceError> {
+        let _ = self.check_not_archived(&params.belong_to_id)?;
         let view = self.create_view_on_server(params.clone()).await?;
         self.create_view(view).await
     }
@@ -63,7 +170,7 @@ impl ViewController {
 
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
             let _ = self.save_view(view.clone(), conn)?;
-            let _ = notify_views_changed(&view.belong_to_id, trash_can, &conn)?;
+            let _ = notify_views_changed(&view.belong_to_id, trash_can, &self.cache, &conn)?;
 
             Ok(())
         })?;
@@ -72,11 +179,18 @@ impl ViewController {
     }
 
     pub(crate) fn save_view(&self, view: View, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        self.cache.remove_views(&view.belong_to_id);
         let view_table = ViewTable::new(view);
         let _ = ViewTableSql::create_view(view_table, conn)?;
         Ok(())
     }
 
+    // Saves the view that is read from the server. It's marked as unread if it was changed by others.
+    pub(crate) fn save_remote_view(&self, view: View, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = save_remote_view(view, &self.opened_view_ids, &self.cache, conn)?;
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self, params), fields(view_id = %params.view_id), err)]
     pub(crate) async fn read_view(&self, params: ViewIdentifier) -> Result<View, WorkspaceError> {
         let conn = self.database.db_connection()?;
@@ -109,72 +223,335 @@ impl ViewController {
     pub(crate) async fn open_view(&self, params: DocIdentifier) -> Result<DocDelta, WorkspaceError> {
         let doc_id = params.doc_id.clone();
         let edit_context = self.document.open(params).await?;
+        self.opened_view_ids.write().insert(doc_id.clone());
+        let _ = self.mark_view_read(&doc_id)?;
+        let _ = self.record_recent_view(&doc_id)?;
 
-        KV::set_str(LATEST_VIEW_ID, doc_id);
-        Ok(edit_context.delta().await.map_err(internal_error)?)
+        if let Err(e) = self.update_latest_view(&doc_id) {
+            log::error!("Update the latest view of the workspace failed: {:?}", e);
+        }
+        let mut delta = edit_context.delta().await.map_err(internal_error)?;
+        delta.read_position = self.document.read_position(&delta)?;
+        Ok(delta)
     }
 
     #[tracing::instrument(level = "debug", skip(self,params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn close_view(&self, params: DocIdentifier) -> Result<(), WorkspaceError> {
+        self.opened_view_ids.write().remove(&params.doc_id);
         let _ = self.document.close(params).await?;
         Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip(self,params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn delete_view(&self, params: DocIdentifier) -> Result<(), WorkspaceError> {
-        if let Some(view_id) = KV::get_str(LATEST_VIEW_ID) {
-            if view_id == params.doc_id {
-                let _ = KV::remove(LATEST_VIEW_ID);
-            }
-        }
+        let conn = self.database.db_connection()?;
+        let _ = WorkspaceSettingTableSql::remove_latest_view(&params.doc_id, &*conn)?;
+        self.opened_view_ids.write().remove(&params.doc_id);
         let _ = self.document.close(params).await?;
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
-    pub(crate) async fn duplicate_view(&self, params: DocIdentifier) -> Result<(), WorkspaceError> {
-        let view: View = ViewTableSql::read_view(&params.doc_id, &*self.database.db_connection()?)?.into();
-        let delta_data = self
-            .document
-            .read_document_data(params, self.database.db_pool()?)
-            .await?;
-
-        let duplicate_params = CreateViewParams {
-            belong_to_id: view.belong_to_id.clone(),
-            name: format!("{} (copy)", &view.name),
-            desc: view.desc.clone(),
-            thumbnail: "".to_owned(),
-            view_type: view.view_type.clone(),
-            data: delta_data.data,
-        };
+    // The view is copied on the server with the views nested in it and their docs, the docs of the copies
+    // are read from the server when they are opened.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn duplicate_view(&self, params: ViewIdentifier) -> Result<View, WorkspaceError> {
+        let _ = self.check_not_archived(&params.view_id)?;
+        let token = self.user.token()?;
+        let view = self.server.duplicate_view(&token, params).await?;
+        let _ = self.save_view_copies(vec![view.clone()])?;
+        Ok(view)
+    }
 
-        let _ = self.create_view_from_params(duplicate_params).await?;
-        Ok(())
+    // Saves the copies that are returned by the server and the copies nested in them
+    pub(crate) fn save_view_copies(&self, views: Vec<View>) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let mut belong_to_ids = HashSet::new();
+            let mut views = views;
+            while let Some(mut view) = views.pop() {
+                views.extend(std::mem::take(&mut view.belongings.items));
+                belong_to_ids.insert(view.belong_to_id.clone());
+                let _ = self.save_view(view, conn)?;
+            }
+
+            for belong_to_id in belong_to_ids {
+                let _ = notify_views_changed(&belong_to_id, self.trash_can.clone(), &self.cache, conn)?;
+            }
+            Ok(())
+        })
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), err)]
     pub(crate) async fn export_doc(&self, params: ExportParams) -> Result<ExportData, WorkspaceError> {
         let doc_identifier: DocIdentifier = params.doc_id.into();
-        let doc = self
-            .document
-            .read_document_data(doc_identifier, self.database.db_pool()?)
-            .await?;
+        let data = match params.export_type {
+            ExportType::Markdown => self.document.export_markdown(doc_identifier).await?,
+            _ => {
+                self
+                    .document
+                    .read_document_data(doc_identifier, self.database.db_pool()?)
+                    .await?
+                    .data
+            },
+        };
 
         Ok(ExportData {
-            data: doc.data,
+            data,
             export_type: params.export_type,
         })
     }
 
+    // Exports the docs of all the views in the app, including the nested ones, to one file. The initial
+    // progress is returned and the rest is notified while the file is written in the background.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(app_id = %params.app_id), err)]
+    pub(crate) async fn export_app(&self, params: ExportAppParams) -> Result<ExportAppProgress, WorkspaceError> {
+        let (title, views) = {
+            let conn = &*self.database.db_connection()?;
+            let title = AppTableSql::read_app(&params.app_id, conn)?.name;
+            (title, self.read_export_views(&params.app_id, conn)?)
+        };
+        let progress = spawn_app_export(title, views, params, self.document.clone(), self.database.db_pool()?);
+        Ok(progress)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub(crate) async fn import_doc(&self, params: ImportParams) -> Result<DocDelta, WorkspaceError> {
+        let _ = self.check_not_archived(&params.doc_id)?;
+        let doc_identifier: DocIdentifier = params.doc_id.into();
+        let doc = match params.import_type {
+            ImportType::Markdown => self.document.import_markdown(doc_identifier, &params.data).await?,
+        };
+        Ok(doc)
+    }
+
+    // The content of the doc is replaced with the delta, e.g. the delta of the file in the imported folder
+    #[tracing::instrument(level = "debug", skip(self, delta), err)]
+    pub(crate) async fn replace_doc(&self, doc_id: &str, delta: Delta) -> Result<DocDelta, WorkspaceError> {
+        let _ = self.check_not_archived(doc_id)?;
+        let doc_identifier: DocIdentifier = doc_id.to_owned().into();
+        let doc = self.document.replace_document(doc_identifier, delta).await?;
+        Ok(doc)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub(crate) fn preview_import(&self, params: ImportParams) -> Result<DocImportPreview, WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        let _ = ViewTableSql::read_view(&params.doc_id, &*conn)?;
+        let preview = match params.import_type {
+            ImportType::Markdown => preview_markdown(&params.doc_id, &params.data),
+        };
+        Ok(preview)
+    }
+
     // belong_to_id will be the app_id or view_id.
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn read_views_belong_to(&self, belong_to_id: &str) -> Result<RepeatedView, WorkspaceError> {
         // TODO: read from server
         let conn = self.database.db_connection()?;
-        let repeated_view = read_local_belonging_view(belong_to_id, self.trash_can.clone(), &conn)?;
+        let repeated_view = read_local_belonging_view(belong_to_id, self.trash_can.clone(), &self.cache, &conn)?;
         Ok(repeated_view)
     }
 
+    // Downloads the documents of all the views belong to the app or view, including the nested ones.
+    // The progress is notified with the belong_to_id.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn prefetch_views(&self, belong_to_id: &str) -> Result<DocPrefetchProgress, WorkspaceError> {
+        let doc_ids = self.read_nested_view_ids(belong_to_id)?;
+        let progress = self.document.prefetch(belong_to_id, doc_ids)?;
+        Ok(progress)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, belong_to_ids), err)]
+    pub(crate) async fn read_tasks(&self, belong_to_ids: Vec<String>) -> Result<RepeatedDocTask, WorkspaceError> {
+        let mut doc_ids = vec![];
+        for belong_to_id in belong_to_ids {
+            doc_ids.extend(self.read_nested_view_ids(&belong_to_id)?);
+        }
+        let repeated_task = self.document.read_tasks(doc_ids).await?;
+        Ok(repeated_task)
+    }
+
+    // Searches the views that belong to the apps, including the nested ones. The documents are indexed
+    // before searching, the ones that weren't changed since the last search are skipped.
+    #[tracing::instrument(level = "debug", skip(self, belong_to_ids, params), fields(query = %params.query), err)]
+    pub(crate) async fn search_views(
+        &self,
+        belong_to_ids: Vec<String>,
+        params: SearchWorkspaceParams,
+    ) -> Result<RepeatedSearchResult, WorkspaceError> {
+        let mut view_ids = vec![];
+        for belong_to_id in belong_to_ids {
+            view_ids.extend(self.read_nested_view_ids(&belong_to_id)?);
+        }
+        let _ = self.document.index_docs(view_ids.clone()).await?;
+
+        // The index is shared by all the workspaces of the user, so the matches are read without the limit
+        // and the ones that don't belong to the workspace are skipped. The negative limit reads all of them.
+        let view_ids = view_ids.into_iter().collect::<HashSet<String>>();
+        let mut items = vec![];
+        {
+            let conn = &*self.database.db_connection()?;
+            for fts_match in SEARCH_INDEX.search(&params.query, -1, conn)? {
+                if items.len() as i64 >= params.limit {
+                    break;
+                }
+                if !view_ids.contains(&fts_match.id) {
+                    continue;
+                }
+
+                let view_table = ViewTableSql::read_view(&fts_match.id, conn)?;
+                items.push(SearchResult {
+                    view_id: view_table.id,
+                    view_name: view_table.name,
+                    snippet: fts_match.snippet,
+                    matches: vec![],
+                });
+            }
+        }
+
+        // The offsets of the matched words are read from the docs, so the UI can highlight them without
+        // opening every doc.
+        for item in items.iter_mut() {
+            item.matches = self
+                .document
+                .find_text_matches(&item.view_id, &params.query, MAX_SEARCH_MATCHES)
+                .await?
+                .into_iter()
+                .map(|text_match| SearchMatch {
+                    start: text_match.start as i64,
+                    end: text_match.end as i64,
+                    context: text_match.context,
+                    highlight_start: text_match.highlight_start as i64,
+                    highlight_end: text_match.highlight_end as i64,
+                })
+                .collect();
+        }
+        Ok(RepeatedSearchResult { items })
+    }
+
+    // The unread views that belong to the apps, including the nested ones. The latest changed view comes first.
+    pub(crate) fn read_unread_view_ids(&self, belong_to_ids: Vec<String>) -> Result<Vec<String>, WorkspaceError> {
+        let mut view_ids = HashSet::new();
+        for belong_to_id in belong_to_ids {
+            view_ids.extend(self.read_nested_view_ids(&belong_to_id)?);
+        }
+
+        let conn = self.database.db_connection()?;
+        let mut unread_view_ids = ViewUnreadTableSql::read_unread_view_ids(&*conn)?;
+        unread_view_ids.retain(|view_id| view_ids.contains(view_id));
+        Ok(unread_view_ids)
+    }
+
+    // Pins the view to the favorites or unpins it. The favorites are kept by the server too, so the Favorites
+    // section of the sidebar is the same on all the devices of the user.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn toggle_favorite(&self, params: ViewIdentifier) -> Result<ViewFavoriteState, WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        let state = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = ViewTableSql::read_view(&params.view_id, conn)?;
+            let is_favorite = !ViewFavoriteTableSql::remove(&params.view_id, conn)?;
+            if is_favorite {
+                let _ = ViewFavoriteTableSql::add(&params.view_id, conn)?;
+            }
+            Ok(ViewFavoriteState {
+                view_id: params.view_id.clone(),
+                is_favorite,
+            })
+        })?;
+
+        let _ = notify_favorites_changed(self.trash_can.clone(), conn)?;
+        let _ = self.update_favorite_on_server(state.clone())?;
+        Ok(state)
+    }
+
+    // Returns the local favorites, they are replaced by the ones read from the server later and the
+    // FavoritesChanged notification is sent.
+    pub(crate) fn read_favorites(&self) -> Result<RepeatedFavorite, WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        let repeated_favorite = read_local_favorites(self.trash_can.clone(), &*conn)?;
+        let _ = self.read_favorites_on_server()?;
+        Ok(repeated_favorite)
+    }
+
+    // Returns the local recent views, they are merged with the ones read from the server later and the
+    // RecentViewsChanged notification is sent.
+    pub(crate) fn read_recent_views(
+        &self,
+        params: QueryRecentViewsParams,
+    ) -> Result<RepeatedRecentView, WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        let repeated_recent_view = read_local_recent_views(params.limit, self.trash_can.clone(), &*conn)?;
+        let _ = self.read_recent_views_on_server(params)?;
+        Ok(repeated_recent_view)
+    }
+
+    // The open time is sent to the server too, so the recent views follow the user across the devices
+    fn record_recent_view(&self, view_id: &str) -> Result<(), WorkspaceError> {
+        let record = RecentViewRecord {
+            view_id: view_id.to_owned(),
+            open_time: timestamp(),
+        };
+        let conn = self.database.db_connection()?;
+        let _ = ViewRecentTableSql::record(&record.view_id, record.open_time, &*conn)?;
+        let _ = notify_recent_views_changed(self.trash_can.clone(), &*conn)?;
+        let _ = self.update_recent_view_on_server(record)?;
+        Ok(())
+    }
+
+    fn update_latest_view(&self, view_id: &str) -> Result<(), WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        let belong_to_id = match ViewTableSql::read_belong_to_id(view_id, &*conn)? {
+            None => return Ok(()),
+            Some(belong_to_id) => belong_to_id,
+        };
+        let (workspace_id, _) = read_path_to_app(&belong_to_id, &*conn)?;
+        WorkspaceSettingTableSql::update_latest_view(&workspace_id, view_id, &*conn)
+    }
+
+    fn mark_view_read(&self, view_id: &str) -> Result<(), WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        if ViewUnreadTableSql::mark_read(view_id, &*conn)? {
+            notify_unread_changed(view_id, false);
+        }
+        Ok(())
+    }
+
+    // The ids of the views that are not in the trash, including the nested ones.
+    fn read_nested_view_ids(&self, belong_to_id: &str) -> Result<Vec<String>, WorkspaceError> {
+        let conn = self.database.db_connection()?;
+        let mut view_ids = vec![];
+        let mut belong_to_ids = vec![belong_to_id.to_owned()];
+        while let Some(belong_to_id) = belong_to_ids.pop() {
+            let repeated_view = read_local_belonging_view(&belong_to_id, self.trash_can.clone(), &self.cache, &conn)?;
+            for view in repeated_view.items {
+                belong_to_ids.push(view.id.clone());
+                view_ids.push(view.id);
+            }
+        }
+        Ok(view_ids)
+    }
+
+    // The views are in the order of the sidebar and each view is followed by the views nested in it
+    fn read_export_views(&self, app_id: &str, conn: &SqliteConnection) -> Result<Vec<ExportView>, WorkspaceError> {
+        let nested_views = |belong_to_id: &str, level: usize| -> Result<Vec<ExportView>, WorkspaceError> {
+            let repeated_view = read_local_belonging_view(belong_to_id, self.trash_can.clone(), &self.cache, conn)?;
+            Ok(repeated_view
+                .items
+                .into_iter()
+                .rev()
+                .map(|view| ExportView { view, level })
+                .collect())
+        };
+
+        let mut export_views = vec![];
+        let mut pending_views = nested_views(app_id, 1)?;
+        while let Some(export_view) = pending_views.pop() {
+            pending_views.extend(nested_views(&export_view.view.id, export_view.level + 1)?);
+            export_views.push(export_view);
+        }
+        Ok(export_views)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, params), err)]
     pub(crate) async fn update_view(&self, params: UpdateViewParams) -> Result<View, WorkspaceError> {
         let conn = &*self.database.db_connection()?;
@@ -182,6 +559,18 @@ impl ViewController {
         let view_id = changeset.id.clone();
 
         let updated_view = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = check_workspace_not_archived(&view_id, conn)?;
+            if let Some(name) = &params.name {
+                let view_table = ViewTableSql::read_view(&view_id, conn)?;
+                let _ = self.workspace_sync.record_rename(
+                    SqlRenameObjectType::View,
+                    &view_id,
+                    &view_table.name,
+                    view_table.modified_time,
+                    name,
+                    conn,
+                )?;
+            }
             let _ = ViewTableSql::update_view(changeset, conn)?;
             let view: View = ViewTableSql::read_view(&view_id, conn)?.into();
             Ok(view)
@@ -191,29 +580,293 @@ impl ViewController {
             .send();
 
         //
-        let _ = notify_views_changed(&updated_view.belong_to_id, self.trash_can.clone(), conn)?;
+        let _ = notify_views_changed(&updated_view.belong_to_id, self.trash_can.clone(), &self.cache, conn)?;
 
         let _ = self.update_view_on_server(params);
         Ok(updated_view)
     }
 
+    // Moves the view to the index of the views that belong to the app or the view. The view can't be moved
+    // into itself, into the views nested in it or into another workspace.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn move_view(&self, position: ViewPosition) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        let (old_belong_to_id, ranks) = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let view_table = ViewTableSql::read_view(&position.view_id, conn)?;
+            let (workspace_id, _) = read_path_to_app(&view_table.belong_to_id, conn)?;
+            let (target_workspace_id, target_path) = read_path_to_app(&position.belong_to_id, conn)?;
+            if workspace_id != target_workspace_id || target_path.contains(&view_table.id) {
+                return Err(ErrorCode::MoveTargetInvalid.into());
+            }
+            let _ = WorkspaceTableSql {}.check_not_archived(&workspace_id, conn)?;
+
+            let trash_can = self.trash_can.clone();
+            let mut views = read_local_belonging_view(&position.belong_to_id, trash_can, &self.cache, conn)?.items;
+            views.retain(|view| view.id != view_table.id);
+            let siblings = views
+                .iter()
+                .map(|view| (view.id.as_str(), view.rank.as_str()))
+                .collect::<Vec<(&str, &str)>>();
+            let ranks = ranks_after_move(&view_table.id, &siblings, position.index);
+            for (id, rank) in &ranks {
+                let mut changeset = ViewTableChangeset::from_table(ViewTableSql::read_view(id, conn)?);
+                changeset.belong_to_id = Some(position.belong_to_id.clone());
+                changeset.rank = Some(rank.clone());
+                let _ = ViewTableSql::update_view(changeset, conn)?;
+            }
+            Ok((view_table.belong_to_id, ranks))
+        })?;
+
+        if old_belong_to_id != position.belong_to_id {
+            let _ = notify_views_changed(&old_belong_to_id, self.trash_can.clone(), &self.cache, conn)?;
+        }
+        let _ = notify_views_changed(&position.belong_to_id, self.trash_can.clone(), &self.cache, conn)?;
+
+        let _ = self.move_view_on_server(position.belong_to_id, ranks)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub(crate) async fn reset_document(&self, params: DocIdentifier) -> Result<DocDelta, WorkspaceError> {
+        let doc = self.document.reset(params).await?;
+        Ok(doc)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub(crate) async fn read_doc_text_runs(&self, params: DocIdentifier) -> Result<RepeatedDocTextRun, WorkspaceError> {
+        let repeated_run = self.document.read_text_runs(params).await?;
+        Ok(repeated_run)
+    }
+
+    pub(crate) async fn replace_doc_text(&self, replacement: DocTextReplacement) -> Result<DocDelta, WorkspaceError> {
+        let _ = self.check_not_archived(&replacement.doc_id)?;
+        let doc = self.document.replace_text(replacement).await?;
+        Ok(doc)
+    }
+
+    pub(crate) async fn update_doc_selection(&self, selection: DocSelection) -> Result<(), WorkspaceError> {
+        let _ = self.document.update_selection(selection).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub(crate) async fn undo_document(&self, params: DocIdentifier) -> Result<DocDelta, WorkspaceError> {
+        let _ = self.check_not_archived(&params.doc_id)?;
+        let doc = self.document.undo(params).await?;
+        Ok(doc)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub(crate) async fn redo_document(&self, params: DocIdentifier) -> Result<DocDelta, WorkspaceError> {
+        let _ = self.check_not_archived(&params.doc_id)?;
+        let doc = self.document.redo(params).await?;
+        Ok(doc)
+    }
+
+    pub(crate) async fn read_doc_undo_state(&self, params: DocIdentifier) -> Result<DocUndoState, WorkspaceError> {
+        let state = self.document.read_undo_state(params).await?;
+        Ok(state)
+    }
+
+    pub(crate) fn read_input_rules(&self) -> Result<InputRuleSetting, WorkspaceError> {
+        let setting = self.document.read_input_rules()?;
+        Ok(setting)
+    }
+
+    pub(crate) fn update_input_rules(&self, setting: InputRuleSetting) -> Result<(), WorkspaceError> {
+        let _ = self.document.update_input_rules(setting)?;
+        Ok(())
+    }
+
+    pub(crate) fn update_read_position(&self, position: DocReadPosition) -> Result<(), WorkspaceError> {
+        let _ = self.document.update_read_position(position)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_doc_snapshots(&self, params: DocIdentifier) -> Result<RepeatedDocSnapshot, WorkspaceError> {
+        let repeated_snapshot = self.document.read_snapshots(params)?;
+        Ok(repeated_snapshot)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn revert_to_snapshot(&self, params: DocSnapshotIdentifier) -> Result<DocDelta, WorkspaceError> {
+        let _ = self.check_not_archived(&params.doc_id)?;
+        let doc = self.document.revert_to_snapshot(params).await?;
+        Ok(doc)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn query_doc_history(&self, params: DocIdentifier) -> Result<RepeatedDocSnapshot, WorkspaceError> {
+        let repeated_snapshot = self.document.query_history(params).await?;
+        Ok(repeated_snapshot)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn restore_doc_version(&self, params: DocSnapshotIdentifier) -> Result<DocDelta, WorkspaceError> {
+        let _ = self.check_not_archived(&params.doc_id)?;
+        let doc = self.document.restore_version(params).await?;
+        Ok(doc)
+    }
+
+    // The file is checked before the upload starts, so the invalid path is returned as the error of the
+    // event instead of the notification.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn upload_attachment(
+        &self,
+        params: UploadAttachmentParams,
+    ) -> Result<AttachmentUploadProgress, WorkspaceError> {
+        let metadata = std::fs::metadata(&params.path).map_err(|_| ErrorCode::AttachmentPathInvalid)?;
+        if !metadata.is_file() {
+            return Err(ErrorCode::AttachmentPathInvalid.into());
+        }
+        if metadata.len() as i64 > MAX_ATTACHMENT_SIZE {
+            return Err(ErrorCode::AttachmentTooLarge.into());
+        }
+
+        let progress = self.document.upload_attachment(&params.doc_id, &params.path).await?;
+        Ok(progress)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_attachment(&self, params: AttachmentIdentifier) -> Result<AttachmentFile, WorkspaceError> {
+        let file = self.document.read_attachment(&params.attachment_id).await?;
+        Ok(file)
+    }
+
+    // The page is rendered by the server, so publishing requires the network unlike the other changes
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn publish_view(&self, params: ViewIdentifier) -> Result<PublishedView, WorkspaceError> {
+        let token = self.user.token()?;
+        let published_view = self.server.publish_view(&token, params).await?;
+        Ok(published_view)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn unpublish_view(&self, params: ViewIdentifier) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let _ = self.server.unpublish_view(&token, params).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn pin_published_view(
+        &self,
+        params: PinPublishedViewParams,
+    ) -> Result<PublishedView, WorkspaceError> {
+        let token = self.user.token()?;
+        let published_view = self.server.pin_published_view(&token, params).await?;
+        Ok(published_view)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn publish_latest_view(&self, params: ViewIdentifier) -> Result<PublishedView, WorkspaceError> {
+        let token = self.user.token()?;
+        let published_view = self.server.publish_latest_view(&token, params).await?;
+        Ok(published_view)
+    }
+
+    // Only the publisher can read how many times the published page was read
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_access_log(
+        &self,
+        params: ViewIdentifier,
+    ) -> Result<RepeatedViewAccessLog, WorkspaceError> {
+        let token = self.user.token()?;
+        let repeated_log = self.server.read_view_access_log(&token, params).await?;
+        Ok(repeated_log)
+    }
+
+    // Like publishing, the share links are managed by the server
+    #[tracing::instrument(level = "debug", skip(self, params), err)]
+    pub(crate) async fn create_share_link(&self, params: CreateShareLinkParams) -> Result<ShareLink, WorkspaceError> {
+        let token = self.user.token()?;
+        let share_link = self.server.create_share_link(&token, params).await?;
+        Ok(share_link)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_share_links(&self, workspace_id: &str) -> Result<RepeatedShareLink, WorkspaceError> {
+        let token = self.user.token()?;
+        let params = WorkspaceIdentifier::new(Some(workspace_id.to_owned()));
+        let repeated_share_link = self.server.read_share_links(&token, params).await?;
+        Ok(repeated_share_link)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn revoke_share_link(&self, params: ShareLinkIdentifier) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let _ = self.server.revoke_share_link(&token, params).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, params), err)]
+    pub(crate) async fn set_view_webhook(&self, params: SetViewWebhookParams) -> Result<ViewWebhook, WorkspaceError> {
+        let token = self.user.token()?;
+        let webhook = self.server.set_view_webhook(&token, params).await?;
+        Ok(webhook)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_view_webhook(&self, params: ViewIdentifier) -> Result<ViewWebhook, WorkspaceError> {
+        let token = self.user.token()?;
+        let webhook = self.server.read_view_webhook(&token, params).await?;
+        Ok(webhook)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn delete_view_webhook(&self, params: ViewIdentifier) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let _ = self.server.delete_view_webhook(&token, params).await?;
+        Ok(())
+    }
+
     pub(crate) async fn apply_doc_delta(&self, params: DocDelta) -> Result<DocDelta, WorkspaceError> {
+        let _ = self.check_not_archived(&params.doc_id)?;
         let doc = self.document.apply_doc_delta(params).await?;
         Ok(doc)
     }
 
-    pub(crate) fn latest_visit_view(&self) -> WorkspaceResult<Option<View>> {
-        match KV::get_str(LATEST_VIEW_ID) {
-            None => Ok(None),
-            Some(view_id) => {
-                let conn = self.database.db_connection()?;
-                let view_table = ViewTableSql::read_view(&view_id, &*conn)?;
-                Ok(Some(view_table.into()))
-            },
+    // The views and the docs of the archived workspace are read-only. The id is the view or the app that the
+    // view belongs to.
+    fn check_not_archived(&self, id: &str) -> Result<(), WorkspaceError> {
+        let conn = &*self.database.db_connection()?;
+        check_workspace_not_archived(id, conn)
+    }
+
+    // Each workspace keeps the view that was opened last in it
+    pub(crate) fn latest_visit_view(&self, workspace_id: &str) -> WorkspaceResult<Option<View>> {
+        let conn = self.database.db_connection()?;
+        let view_id = WorkspaceSettingTableSql::read_latest_view_id(workspace_id, &*conn)?;
+        if view_id.is_empty() {
+            return Ok(None);
         }
+
+        let view_table = ViewTableSql::read_view(&view_id, &*conn)?;
+        Ok(Some(view_table.into()))
+    }
+
+    pub(crate) fn set_latest_view(&self, workspace_id: &str, view: &View) -> WorkspaceResult<()> {
+        let conn = self.database.db_connection()?;
+        WorkspaceSettingTableSql::update_latest_view(workspace_id, &view.id, &*conn)
+    }
+
+    // The opened views are closed before switching to another workspace, so their docs stop syncing
+    pub(crate) async fn close_opened_views(&self) -> WorkspaceResult<()> {
+        let view_ids = self.opened_view_ids.write().drain().collect::<Vec<String>>();
+        for view_id in view_ids {
+            let _ = self.document.close(DocIdentifier { doc_id: view_id }).await?;
+        }
+        Ok(())
     }
 
-    pub(crate) fn set_latest_view(&self, view: &View) { KV::set_str(LATEST_VIEW_ID, view.id.clone()); }
+    // Reads the doc from the database of another user, e.g. the account that is merged. The doc is closed
+    // after reading, so it doesn't keep syncing with that database.
+    pub(crate) async fn read_local_doc(&self, doc_id: &str, pool: Arc<ConnectionPool>) -> WorkspaceResult<Delta> {
+        let doc_identifier: DocIdentifier = doc_id.to_owned().into();
+        let doc = self.document.read_document_data(doc_identifier.clone(), pool).await?;
+        let _ = self.document.close(doc_identifier).await?;
+        Delta::from_json(&doc.data).map_err(internal_error)
+    }
 }
 
 impl ViewController {
@@ -225,14 +878,24 @@ impl ViewController {
     }
 
     #[tracing::instrument(skip(self), err)]
-    fn update_view_on_server(&self, params: UpdateViewParams) -> Result<(), WorkspaceError> {
+    fn update_view_on_server(&self, mut params: UpdateViewParams) -> Result<(), WorkspaceError> {
+        params.device_id = device_id();
         let token = self.user.token()?;
         let server = self.server.clone();
+        let workspace_sync = self.workspace_sync.clone();
         tokio::spawn(async move {
+            let view_id = params.view_id.clone();
+            let name = params.name.clone();
             match server.update_view(&token, params).await {
-                Ok(_) => {},
+                Ok(_) => {
+                    if let Some(name) = name {
+                        if let Err(e) = workspace_sync.did_send_rename(&view_id, &name) {
+                            log::error!("Remove the sent rename failed: {:?}", e);
+                        }
+                    }
+                },
                 Err(e) => {
-                    // TODO: retry?
+                    // The rename is sent again after reconnecting
                     log::error!("Update view failed: {:?}", e);
                 },
             }
@@ -240,22 +903,132 @@ impl ViewController {
         Ok(())
     }
 
+    #[tracing::instrument(skip(self), err)]
+    fn move_view_on_server(&self, belong_to_id: String, ranks: Vec<(String, String)>) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let server = self.server.clone();
+        tokio::spawn(async move {
+            for (view_id, rank) in ranks {
+                let params = MoveViewParams {
+                    view_id,
+                    belong_to_id: belong_to_id.clone(),
+                    rank,
+                };
+                if let Err(e) = server.move_view(&token, params).await {
+                    log::error!("Move view failed: {:?}", e);
+                }
+            }
+        });
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    fn update_favorite_on_server(&self, state: ViewFavoriteState) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let server = self.server.clone();
+        tokio::spawn(async move {
+            if let Err(e) = server.update_favorite(&token, state).await {
+                log::error!("Update favorite failed: {:?}", e);
+            }
+        });
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    fn read_favorites_on_server(&self) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let server = self.server.clone();
+        let pool = self.database.db_pool()?;
+        let opened_view_ids = self.opened_view_ids.clone();
+        let trash_can = self.trash_can.clone();
+        let cache = self.cache.clone();
+        tokio::spawn(async move {
+            match server.read_favorites(&token).await {
+                Ok(repeated_favorite) => match pool.get() {
+                    Ok(conn) => {
+                        let result = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+                            let mut favorites = vec![];
+                            for favorite in repeated_favorite.items {
+                                favorites.push((favorite.view.id.clone(), favorite.favorite_time));
+                                let _ = save_remote_view(favorite.view, &opened_view_ids, &cache, &conn)?;
+                            }
+                            ViewFavoriteTableSql::replace_all(favorites, &conn)
+                        });
+                        match result.and_then(|_| notify_favorites_changed(trash_can, &conn)) {
+                            Ok(_) => {},
+                            Err(e) => log::error!("Save favorites failed: {:?}", e),
+                        }
+                    },
+                    Err(e) => log::error!("Require db connection failed: {:?}", e),
+                },
+                Err(e) => log::error!("Read favorites failed: {:?}", e),
+            }
+        });
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    fn update_recent_view_on_server(&self, record: RecentViewRecord) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let server = self.server.clone();
+        tokio::spawn(async move {
+            if let Err(e) = server.update_recent_view(&token, record).await {
+                log::error!("Update recent view failed: {:?}", e);
+            }
+        });
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    fn read_recent_views_on_server(&self, params: QueryRecentViewsParams) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        let server = self.server.clone();
+        let pool = self.database.db_pool()?;
+        let opened_view_ids = self.opened_view_ids.clone();
+        let trash_can = self.trash_can.clone();
+        let cache = self.cache.clone();
+        tokio::spawn(async move {
+            match server.read_recent_views(&token, params).await {
+                Ok(repeated_recent_view) => match pool.get() {
+                    Ok(conn) => {
+                        let result = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+                            let mut records = vec![];
+                            for recent_view in repeated_recent_view.items {
+                                records.push((recent_view.view.id.clone(), recent_view.open_time));
+                                let _ = save_remote_view(recent_view.view, &opened_view_ids, &cache, &conn)?;
+                            }
+                            ViewRecentTableSql::merge(records, &conn)
+                        });
+                        match result.and_then(|_| notify_recent_views_changed(trash_can, &conn)) {
+                            Ok(_) => {},
+                            Err(e) => log::error!("Save recent views failed: {:?}", e),
+                        }
+                    },
+                    Err(e) => log::error!("Require db connection failed: {:?}", e),
+                },
+                Err(e) => log::error!("Read recent views failed: {:?}", e),
+            }
+        });
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self), err)]
     fn read_view_on_server(&self, params: ViewIdentifier) -> Result<(), WorkspaceError> {
         let token = self.user.token()?;
         let server = self.server.clone();
         let pool = self.database.db_pool()?;
+        let opened_view_ids = self.opened_view_ids.clone();
+        let cache = self.cache.clone();
         // Opti: retry?
         tokio::spawn(async move {
             match server.read_view(&token, params).await {
                 Ok(Some(view)) => match pool.get() {
                     Ok(conn) => {
-                        let view_table = ViewTable::new(view.clone());
-                        let result = ViewTableSql::create_view(view_table, &conn);
+                        let result = save_remote_view(view, &opened_view_ids, &cache, &conn);
                         match result {
-                            Ok(_) => {
+                            Ok(view) => {
                                 send_dart_notification(&view.id, WorkspaceNotification::ViewUpdated)
-                                    .payload(view.clone())
+                                    .payload(view)
                                     .send();
                             },
                             Err(e) => log::error!("Save view failed: {:?}", e),
@@ -275,6 +1048,7 @@ impl ViewController {
         let database = self.database.clone();
         let document = self.document.clone();
         let trash_can = self.trash_can.clone();
+        let cache = self.cache.clone();
         let _ = tokio::spawn(async move {
             loop {
                 let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
@@ -285,20 +1059,46 @@ impl ViewController {
                 }));
                 match stream.next().await {
                     Some(event) => {
-                        handle_trash_event(database.clone(), document.clone(), trash_can.clone(), event).await
+                        let (document, trash_can, cache) = (document.clone(), trash_can.clone(), cache.clone());
+                        handle_trash_event(database.clone(), document, trash_can, cache, event).await
                     },
                     None => {},
                 }
             }
         });
     }
+
+    // The document that is changed by others is marked as unread unless it's opened
+    fn listen_doc_revision_event(&self) {
+        let mut rx = self.bus.subscribe::<DocRevisionEvent>();
+        let database = self.database.clone();
+        let opened_view_ids = self.opened_view_ids.clone();
+        let _ = tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => {
+                        if !event.is_remote || opened_view_ids.read().contains(&event.doc_id) {
+                            continue;
+                        }
+                        match mark_view_unread(&event.doc_id, database.clone()) {
+                            Ok(_) => {},
+                            Err(e) => log::error!("Mark view unread failed: {:?}", e),
+                        }
+                    },
+                    Err(RecvError::Lagged(count)) => log::warn!("Missed {} doc revision events", count),
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
 }
 
-#[tracing::instrument(level = "trace", skip(database, document, trash_can))]
+#[tracing::instrument(level = "trace", skip(database, document, trash_can, cache))]
 async fn handle_trash_event(
     database: Arc<dyn WorkspaceDatabase>,
     document: Arc<FlowyDocument>,
     trash_can: Arc<TrashCan>,
+    cache: Arc<WorkspaceCache>,
     event: TrashEvent,
 ) {
     let db_result = database.db_connection();
@@ -309,9 +1109,11 @@ async fn handle_trash_event(
                 let conn = &*db_result?;
                 let view_tables = get_view_table_from(identifiers, conn)?;
                 for view_table in view_tables {
-                    let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), conn)?;
+                    let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), &cache, conn)?;
                     notify_dart(view_table, WorkspaceNotification::ViewDeleted);
                 }
+                let _ = notify_favorites_changed(trash_can.clone(), conn)?;
+                let _ = notify_recent_views_changed(trash_can.clone(), conn)?;
                 Ok::<(), WorkspaceError>(())
             };
             let _ = ret.send(result()).await;
@@ -321,9 +1123,11 @@ async fn handle_trash_event(
                 let conn = &*db_result?;
                 let view_tables = get_view_table_from(identifiers, conn)?;
                 for view_table in view_tables {
-                    let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), conn)?;
+                    let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), &cache, conn)?;
                     notify_dart(view_table, WorkspaceNotification::ViewRestored);
                 }
+                let _ = notify_favorites_changed(trash_can.clone(), conn)?;
+                let _ = notify_recent_views_changed(trash_can.clone(), conn)?;
                 Ok::<(), WorkspaceError>(())
             };
             let _ = ret.send(result()).await;
@@ -336,13 +1140,17 @@ async fn handle_trash_event(
                     for identifier in identifiers.items {
                         let view_table = ViewTableSql::read_view(&identifier.id, conn)?;
                         let _ = ViewTableSql::delete_view(&identifier.id, conn)?;
+                        let _ = ViewFavoriteTableSql::remove(&identifier.id, conn)?;
+                        let _ = ViewRecentTableSql::remove(&identifier.id, conn)?;
                         let _ = document.delete(identifier.id.clone().into())?;
                         notify_ids.insert(view_table.belong_to_id);
                     }
 
                     for notify_id in notify_ids {
-                        let _ = notify_views_changed(&notify_id, trash_can.clone(), conn)?;
+                        let _ = notify_views_changed(&notify_id, trash_can.clone(), &cache, conn)?;
                     }
+                    let _ = notify_favorites_changed(trash_can.clone(), conn)?;
+                    let _ = notify_recent_views_changed(trash_can.clone(), conn)?;
 
                     Ok(())
                 })?;
@@ -368,14 +1176,149 @@ fn get_view_table_from(
     Ok(view_tables)
 }
 
+// Returns the view that is saved, it keeps the name that was renamed offline. The view may have been moved
+// by others, so the list that had it is dropped from the cache too.
+fn save_remote_view(
+    mut view: View,
+    opened_view_ids: &RwLock<HashSet<String>>,
+    cache: &WorkspaceCache,
+    conn: &SqliteConnection,
+) -> Result<View, WorkspaceError> {
+    let _ = keep_pending_name(&view.id, &mut view.name, conn)?;
+    cache.invalidate(&view.id);
+    cache.remove_views(&view.belong_to_id);
+    let is_changed = match ViewTableSql::read_view(&view.id, conn) {
+        Ok(view_table) => view_table.modified_time < view.modified_time,
+        Err(_) => false,
+    };
+
+    let _ = ViewTableSql::create_view(ViewTable::new(view.clone()), conn)?;
+    if is_changed && !opened_view_ids.read().contains(&view.id) {
+        let _ = ViewUnreadTableSql::mark_unread(&view.id, conn)?;
+        notify_unread_changed(&view.id, true);
+    }
+    Ok(view)
+}
+
+fn mark_view_unread(view_id: &str, database: Arc<dyn WorkspaceDatabase>) -> Result<(), WorkspaceError> {
+    let conn = database.db_connection()?;
+    let _ = ViewUnreadTableSql::mark_unread(view_id, &*conn)?;
+    notify_unread_changed(view_id, true);
+    Ok(())
+}
+
+fn notify_unread_changed(view_id: &str, unread: bool) {
+    let state = ViewUnreadState {
+        view_id: view_id.to_owned(),
+        unread,
+    };
+    send_dart_notification(view_id, WorkspaceNotification::ViewUnreadChanged)
+        .payload(state)
+        .send();
+}
+
+// The views in the trash and the views that aren't read from the server yet are skipped
+fn read_local_favorites(trash_can: Arc<TrashCan>, conn: &SqliteConnection) -> WorkspaceResult<RepeatedFavorite> {
+    let trash_ids = trash_can.trash_ids(conn)?;
+    let mut items = vec![];
+    for (view_id, favorite_time) in ViewFavoriteTableSql::read_all(conn)? {
+        if trash_ids.contains(&view_id) || ViewTableSql::read_belong_to_id(&view_id, conn)?.is_none() {
+            continue;
+        }
+        let view: View = ViewTableSql::read_view(&view_id, conn)?.into();
+        items.push(Favorite { view, favorite_time });
+    }
+    Ok(RepeatedFavorite { items })
+}
+
+fn notify_favorites_changed(trash_can: Arc<TrashCan>, conn: &SqliteConnection) -> WorkspaceResult<()> {
+    let repeated_favorite = read_local_favorites(trash_can, conn)?;
+    send_anonymous_dart_notification(WorkspaceNotification::FavoritesChanged)
+        .payload(repeated_favorite)
+        .send();
+    Ok(())
+}
+
+// The views in the trash and the views whose apps aren't saved locally, e.g. they were opened on another
+// device in a workspace that wasn't opened on this one, are skipped.
+fn read_local_recent_views(
+    limit: i64,
+    trash_can: Arc<TrashCan>,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<RepeatedRecentView> {
+    let trash_ids = trash_can.trash_ids(conn)?;
+    let mut items = vec![];
+    for (view_id, open_time) in ViewRecentTableSql::read_all(conn)? {
+        if items.len() as i64 >= limit {
+            break;
+        }
+        if trash_ids.contains(&view_id) {
+            continue;
+        }
+
+        let (app_table, workspace_table) = match read_view_context(&view_id, conn)? {
+            None => continue,
+            Some(context) => context,
+        };
+        let view: View = ViewTableSql::read_view(&view_id, conn)?.into();
+        items.push(RecentView {
+            view,
+            app_id: app_table.id,
+            app_name: app_table.name,
+            workspace_id: workspace_table.id,
+            workspace_name: workspace_table.name,
+            open_time,
+        });
+    }
+    Ok(RepeatedRecentView { items })
+}
+
+// Returns the app and the workspace that the view belongs to. The nested view belongs to another view, so
+// walk up until reaching the app.
+fn read_view_context(view_id: &str, conn: &SqliteConnection) -> WorkspaceResult<Option<(AppTable, WorkspaceTable)>> {
+    let mut belong_to_id = match ViewTableSql::read_belong_to_id(view_id, conn)? {
+        None => return Ok(None),
+        Some(belong_to_id) => belong_to_id,
+    };
+    while let Some(parent_id) = ViewTableSql::read_belong_to_id(&belong_to_id, conn)? {
+        belong_to_id = parent_id;
+    }
+
+    let app_table = match AppTableSql::read_optional_app(&belong_to_id, conn)? {
+        None => return Ok(None),
+        Some(app_table) => app_table,
+    };
+    let workspace_table = match WorkspaceTableSql {}.read_optional_workspace(&app_table.workspace_id, conn)? {
+        None => return Ok(None),
+        Some(workspace_table) => workspace_table,
+    };
+    Ok(Some((app_table, workspace_table)))
+}
+
+// The notification carries the DEFAULT_RECENT_LIMIT views that were opened last
+fn notify_recent_views_changed(trash_can: Arc<TrashCan>, conn: &SqliteConnection) -> WorkspaceResult<()> {
+    let repeated_recent_view = read_local_recent_views(DEFAULT_RECENT_LIMIT, trash_can, conn)?;
+    send_anonymous_dart_notification(WorkspaceNotification::RecentViewsChanged)
+        .payload(repeated_recent_view)
+        .send();
+    Ok(())
+}
+
 fn notify_dart(view_table: ViewTable, notification: WorkspaceNotification) {
     let view: View = view_table.into();
     send_dart_notification(&view.id, notification).payload(view).send();
 }
 
-#[tracing::instrument(skip(belong_to_id, trash_can, conn), fields(view_count), err)]
-fn notify_views_changed(belong_to_id: &str, trash_can: Arc<TrashCan>, conn: &SqliteConnection) -> WorkspaceResult<()> {
-    let repeated_view = read_local_belonging_view(belong_to_id, trash_can.clone(), conn)?;
+// The views are read from the sqlite again, the cached list is replaced with them
+#[tracing::instrument(skip(belong_to_id, trash_can, cache, conn), fields(view_count), err)]
+fn notify_views_changed(
+    belong_to_id: &str,
+    trash_can: Arc<TrashCan>,
+    cache: &WorkspaceCache,
+    conn: &SqliteConnection,
+) -> WorkspaceResult<()> {
+    cache.remove_views(belong_to_id);
+    let repeated_view = read_local_belonging_view(belong_to_id, trash_can.clone(), cache, conn)?;
     tracing::Span::current().record("view_count", &format!("{}", repeated_view.len()).as_str());
     send_dart_notification(&belong_to_id, WorkspaceNotification::AppViewsChanged)
         .payload(repeated_view)
@@ -383,19 +1326,51 @@ fn notify_views_changed(belong_to_id: &str, trash_can: Arc<TrashCan>, conn: &Sql
     Ok(())
 }
 
+// Returns the workspace id of the app that the id belongs to and the ids of the views on the way to the
+// app, starting with the id itself if it's a view.
+fn read_path_to_app(belong_to_id: &str, conn: &SqliteConnection) -> WorkspaceResult<(String, Vec<String>)> {
+    let mut view_ids = vec![];
+    let mut id = belong_to_id.to_owned();
+    while let Some(parent_id) = ViewTableSql::read_belong_to_id(&id, conn)? {
+        view_ids.push(id);
+        id = parent_id;
+    }
+
+    let app_table = AppTableSql::read_app(&id, conn)?;
+    Ok((app_table.workspace_id, view_ids))
+}
+
+// The view or the app that isn't saved locally isn't checked, the server rejects the changes to it instead
+fn check_workspace_not_archived(id: &str, conn: &SqliteConnection) -> WorkspaceResult<()> {
+    let mut id = id.to_owned();
+    while let Some(parent_id) = ViewTableSql::read_belong_to_id(&id, conn)? {
+        id = parent_id;
+    }
+    match AppTableSql::read_optional_app(&id, conn)? {
+        None => Ok(()),
+        Some(app_table) => WorkspaceTableSql {}.check_not_archived(&app_table.workspace_id, conn),
+    }
+}
+
 fn read_local_belonging_view(
     belong_to_id: &str,
     trash_can: Arc<TrashCan>,
+    cache: &WorkspaceCache,
     conn: &SqliteConnection,
 ) -> WorkspaceResult<RepeatedView> {
-    let mut view_tables = ViewTableSql::read_views(belong_to_id, conn)?;
+    let mut views = match cache.views(belong_to_id) {
+        Some(views) => views,
+        None => {
+            let views = ViewTableSql::read_views(belong_to_id, conn)?
+                .into_iter()
+                .map(|view_table| view_table.into())
+                .collect::<Vec<View>>();
+            cache.put_views(belong_to_id, views.clone());
+            views
+        },
+    };
     let trash_ids = trash_can.trash_ids(conn)?;
-    view_tables.retain(|view_table| !trash_ids.contains(&view_table.id));
-
-    let views = view_tables
-        .into_iter()
-        .map(|view_table| view_table.into())
-        .collect::<Vec<View>>();
+    views.retain(|view| !trash_ids.contains(&view.id));
 
     Ok(RepeatedView { items: views })
 }
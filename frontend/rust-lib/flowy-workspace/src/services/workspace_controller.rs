@@ -1,21 +1,47 @@
 use crate::{
+    entities::trash::TrashType,
     errors::*,
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::*,
-    services::{read_local_workspace_apps, server::Server, AppController, TrashCan, ViewController},
-    sql_tables::workspace::{WorkspaceTable, WorkspaceTableChangeset, WorkspaceTableSql},
+    services::{
+        apply_merge_plan,
+        read_local_workspace_apps,
+        read_merge_plan,
+        server::Server,
+        AppController,
+        TrashCan,
+        TrashEvent,
+        AccountMergePlan,
+        ViewController,
+        WorkspaceCache,
+        WorkspaceSync,
+    },
+    sql_tables::workspace::{WorkspaceSettingTableSql, WorkspaceTable, WorkspaceTableChangeset, WorkspaceTableSql},
 };
 use chrono::Utc;
-use flowy_database::SqliteConnection;
-use flowy_document_infra::{entities::doc::DocDelta, user_default::initial_read_me};
+use futures::{FutureExt, StreamExt};
+use flowy_database::{ConnectionPool, SqliteConnection};
+use flowy_document_infra::{
+    entities::doc::{DocDelta, RepeatedDocTask},
+    user_default::initial_read_me,
+};
 use flowy_workspace_infra::{
-    entities::{app::RepeatedApp, view::View, workspace::*},
+    entities::{
+        app::RepeatedApp,
+        view::{RepeatedShareLink, View},
+        workspace::*,
+    },
     user_default,
 };
 use lazy_static::lazy_static;
-use lib_infra::kv::KV;
+use lib_infra::{
+    bus::{EventBus, UserSessionEvent},
+    kv::KV,
+    timestamp,
+};
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
+use tokio::sync::broadcast::{self, error::RecvError};
 
 lazy_static! {
     static ref INIT_WORKSPACE: RwLock<HashMap<String, bool>> = RwLock::new(HashMap::new());
@@ -28,7 +54,10 @@ pub struct WorkspaceController {
     pub(crate) database: Arc<dyn WorkspaceDatabase>,
     pub(crate) app_controller: Arc<AppController>,
     pub(crate) trash_can: Arc<TrashCan>,
+    pub(crate) workspace_sync: Arc<WorkspaceSync>,
+    pub(crate) cache: Arc<WorkspaceCache>,
     server: Server,
+    bus: EventBus,
 }
 
 impl WorkspaceController {
@@ -38,7 +67,10 @@ impl WorkspaceController {
         app_controller: Arc<AppController>,
         view_controller: Arc<ViewController>,
         trash_can: Arc<TrashCan>,
+        workspace_sync: Arc<WorkspaceSync>,
+        cache: Arc<WorkspaceCache>,
         server: Server,
+        bus: EventBus,
     ) -> Self {
         if let Ok(token) = user.token() {
             INIT_WORKSPACE.write().insert(token, false);
@@ -52,7 +84,10 @@ impl WorkspaceController {
             app_controller,
             view_controller,
             trash_can,
+            workspace_sync,
+            cache,
             server,
+            bus,
         }
     }
 
@@ -69,6 +104,8 @@ impl WorkspaceController {
         let _ = self.trash_can.init()?;
         let _ = self.view_controller.init()?;
         let _ = self.app_controller.init()?;
+        self.workspace_sync.init();
+        self.listen_trash_can_event();
 
         Ok(())
     }
@@ -77,12 +114,34 @@ impl WorkspaceController {
         // TODO: (nathan) do something here
 
         log::debug!("workspace initialize after sign in");
+        self.cache.clear();
         let _ = self.init(token).await?;
         Ok(())
     }
 
-    pub async fn user_did_logout(&self) {
-        // TODO: (nathan) do something here
+    // The user's workspace is initialized again after signing in with the same token. The cached lists
+    // belong to the user, the next user reads them from its own database.
+    async fn user_did_logout(&self, token: &str) {
+        INIT_WORKSPACE.write().remove(token);
+        self.cache.clear();
+    }
+
+    // The events that are published before subscribing are dropped, so it's called before the other modules
+    // start publishing, e.g. before UserSession::init publishes the SignIn.
+    pub fn subscribe_bus_events(&self) -> broadcast::Receiver<UserSessionEvent> {
+        self.bus.subscribe::<UserSessionEvent>()
+    }
+
+    // Reacts to the events that the other modules publish on the bus. It runs until the bus is closed.
+    pub async fn listen_bus_events(&self, mut session_events: broadcast::Receiver<UserSessionEvent>) {
+        loop {
+            match session_events.recv().await {
+                Ok(UserSessionEvent::SignOut { token, .. }) => self.user_did_logout(&token).await,
+                Ok(_) => {},
+                Err(RecvError::Lagged(count)) => log::warn!("Missed {} user session events", count),
+                Err(RecvError::Closed) => break,
+            }
+        }
     }
 
     pub async fn user_session_expired(&self) {
@@ -106,10 +165,11 @@ impl WorkspaceController {
                     let doc_delta = DocDelta {
                         doc_id: view.id.clone(),
                         data: delta.to_json(),
+                        read_position: None,
                     };
                     let _ = self.view_controller.apply_doc_delta(doc_delta).await?;
 
-                    self.view_controller.set_latest_view(&view);
+                    let _ = self.view_controller.set_latest_view(&cloned_workspace.id, &view)?;
                 }
                 let _ = self.view_controller.create_view(view).await?;
             }
@@ -163,7 +223,7 @@ impl WorkspaceController {
             Ok(())
         })?;
 
-        set_current_workspace(&workspace.id);
+        set_current_workspace(&user_id, &workspace.id);
 
         Ok(workspace)
     }
@@ -174,6 +234,7 @@ impl WorkspaceController {
         let workspace_id = changeset.id.clone();
         let conn = &*self.database.db_connection()?;
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = self.workspace_sql.check_not_archived(&workspace_id, conn)?;
             let _ = self.workspace_sql.update_workspace(changeset, conn)?;
             let user_id = self.user.user_id()?;
             let workspace = self.read_local_workspace(workspace_id.clone(), &user_id, conn)?;
@@ -196,6 +257,7 @@ impl WorkspaceController {
         let conn = &*self.database.db_connection()?;
         conn.immediate_transaction::<_, WorkspaceError, _>(|| {
             let _ = self.workspace_sql.delete_workspace(workspace_id, conn)?;
+            let _ = WorkspaceSettingTableSql::remove(workspace_id, conn)?;
             let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
             send_dart_notification(&token, WorkspaceNotification::UserDeleteWorkspace)
                 .payload(repeated_workspace)
@@ -208,12 +270,38 @@ impl WorkspaceController {
         Ok(())
     }
 
+    // Only the owner can archive the workspace, so it's archived on the server first and the error of the
+    // server is returned instead of archiving it locally.
+    pub(crate) async fn archive_workspace(&self, params: ArchiveWorkspaceParams) -> Result<(), WorkspaceError> {
+        let _ = self.archive_workspace_on_server(params.clone()).await?;
+        let changeset = WorkspaceTableChangeset::from_archive_params(params);
+        let workspace_id = changeset.id.clone();
+        let user_id = self.user.user_id()?;
+        let token = self.user.token()?;
+        let conn = &*self.database.db_connection()?;
+        conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            let _ = self.workspace_sql.update_workspace(changeset, conn)?;
+            let workspace = self.read_local_workspace(workspace_id.clone(), &user_id, conn)?;
+            send_dart_notification(&workspace_id, WorkspaceNotification::WorkspaceUpdated)
+                .payload(workspace)
+                .send();
+
+            let repeated_workspace = self.read_local_workspaces(None, &user_id, conn)?;
+            send_dart_notification(&token, WorkspaceNotification::WorkspaceListUpdated)
+                .payload(repeated_workspace)
+                .send();
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     pub(crate) async fn open_workspace(&self, params: WorkspaceIdentifier) -> Result<Workspace, WorkspaceError> {
         let user_id = self.user.user_id()?;
         let conn = self.database.db_connection()?;
         if let Some(workspace_id) = params.workspace_id.clone() {
             let workspace = self.read_local_workspace(workspace_id, &user_id, &*conn)?;
-            set_current_workspace(&workspace.id);
+            set_current_workspace(&user_id, &workspace.id);
+            let _ = WorkspaceSettingTableSql::update_open_time(&workspace.id, timestamp(), &*conn)?;
             Ok(workspace)
         } else {
             return Err(WorkspaceError::workspace_id().context("Opened workspace id should not be empty"));
@@ -225,56 +313,210 @@ impl WorkspaceController {
         params: WorkspaceIdentifier,
     ) -> Result<RepeatedWorkspace, WorkspaceError> {
         let user_id = self.user.user_id()?;
-        let workspaces =
+        let mut workspaces =
             self.read_local_workspaces(params.workspace_id.clone(), &user_id, &*self.database.db_connection()?)?;
+        if let Some(filter) = &params.app_filter {
+            for workspace in workspaces.iter_mut() {
+                let apps = std::mem::take(&mut workspace.apps.items);
+                workspace.apps.items = filter.apply(apps, |app| (&app.id, &app.name));
+            }
+        }
         let _ = self.read_workspaces_on_server(user_id.clone(), params.clone());
         Ok(workspaces)
     }
 
     pub(crate) async fn read_current_workspace(&self) -> Result<CurrentWorkspaceSetting, WorkspaceError> {
-        let workspace_id = get_current_workspace()?;
         let user_id = self.user.user_id()?;
-        let params = WorkspaceIdentifier {
-            workspace_id: Some(workspace_id.clone()),
-        };
+        let workspace_id = get_current_workspace(&user_id)?;
+        let params = WorkspaceIdentifier::new(Some(workspace_id.clone()));
         let workspace = self.read_local_workspace(workspace_id, &user_id, &*self.database.db_connection()?)?;
 
         let mut latest_view: Option<View> = None;
-        match self.view_controller.latest_visit_view() {
+        match self.view_controller.latest_visit_view(&workspace.id) {
             Ok(view) => latest_view = view,
             Err(_) => {},
         }
-        let setting = CurrentWorkspaceSetting { workspace, latest_view };
+
+        let app_ids = workspace
+            .apps
+            .iter()
+            .map(|app| app.id.clone())
+            .collect::<Vec<String>>();
+        let unread_view_ids = self.view_controller.read_unread_view_ids(app_ids)?;
+        let setting = CurrentWorkspaceSetting {
+            workspace,
+            latest_view,
+            unread_view_ids,
+        };
         let _ = self.read_workspaces_on_server(user_id.clone(), params)?;
         Ok(setting)
     }
 
+    // Lists the workspaces that the user owns or that are shared with the user, the apps aren't included
+    pub(crate) async fn query_workspaces(&self) -> Result<RepeatedWorkspaceItem, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = self.database.db_connection()?;
+        let workspaces = self.read_local_workspaces(None, &user_id, &*conn)?.into_inner();
+        let open_times = WorkspaceSettingTableSql::read_open_times(&*conn)?
+            .into_iter()
+            .collect::<HashMap<String, i64>>();
+        let current_workspace_id = get_current_workspace(&user_id).unwrap_or_default();
+
+        let mut workspaces = workspaces
+            .into_iter()
+            .map(|workspace| WorkspaceItem {
+                is_current: workspace.id == current_workspace_id,
+                last_open_time: open_times.get(&workspace.id).cloned().unwrap_or(0),
+                id: workspace.id,
+                name: workspace.name,
+                role: workspace.role,
+            })
+            .collect::<Vec<WorkspaceItem>>();
+        // The local workspaces are read in the order they were created and the sort is stable
+        workspaces.sort_by(|a, b| b.last_open_time.cmp(&a.last_open_time));
+
+        let _ = self.read_workspaces_on_server(user_id, WorkspaceIdentifier::new(None))?;
+        Ok(RepeatedWorkspaceItem { items: workspaces })
+    }
+
+    // Switching closes the views that were opened in the previous workspace and returns the setting of
+    // the new one, including the view that was opened last in it.
+    pub(crate) async fn switch_workspace(
+        &self,
+        params: WorkspaceIdentifier,
+    ) -> Result<CurrentWorkspaceSetting, WorkspaceError> {
+        let workspace_id = match params.workspace_id {
+            None => return Err(WorkspaceError::workspace_id().context("Switched workspace id should not be empty")),
+            Some(workspace_id) => workspace_id,
+        };
+        let user_id = self.user.user_id()?;
+        let token = self.user.token()?;
+        let workspace = self.read_local_workspace(workspace_id, &user_id, &*self.database.db_connection()?)?;
+        if get_current_workspace(&user_id).ok().as_ref() != Some(&workspace.id) {
+            let _ = self.view_controller.close_opened_views().await?;
+            set_current_workspace(&user_id, &workspace.id);
+        }
+
+        let conn = self.database.db_connection()?;
+        let _ = WorkspaceSettingTableSql::update_open_time(&workspace.id, timestamp(), &*conn)?;
+        let setting = self.read_current_workspace().await?;
+        send_dart_notification(&token, WorkspaceNotification::CurrentWorkspaceChanged)
+            .payload(setting.clone())
+            .send();
+        Ok(setting)
+    }
+
+    // The source is another account that signed in on this device, e.g. the guest that the user was before
+    // signing up. Nothing is changed, the report is what merge_account would do.
+    pub(crate) async fn preview_account_merge(
+        &self,
+        params: MergeAccountParams,
+    ) -> Result<AccountMergeReport, WorkspaceError> {
+        let source_pool = self.database.local_user_db_pool(&params.source_user_id)?;
+        let plan = self.read_account_merge_plan(&params.source_user_id, &source_pool);
+        self.database.close_local_user_db(&params.source_user_id);
+        Ok(plan?.report)
+    }
+
+    // The data of the source account is copied to the current user, the source account is kept so the
+    // merge can be checked before it's removed. The current workspace doesn't change.
+    pub(crate) async fn merge_account(&self, params: MergeAccountParams) -> Result<AccountMergeReport, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let source_pool = self.database.local_user_db_pool(&params.source_user_id)?;
+        let current_workspace_id = get_current_workspace(&user_id).ok();
+        let result = match self.read_account_merge_plan(&params.source_user_id, &source_pool) {
+            Ok(plan) => apply_merge_plan(plan, self, source_pool).await,
+            Err(e) => Err(e),
+        };
+        if let Some(workspace_id) = current_workspace_id {
+            set_current_workspace(&user_id, &workspace_id);
+        }
+        self.database.close_local_user_db(&params.source_user_id);
+        result
+    }
+
     pub(crate) async fn read_current_workspace_apps(&self) -> Result<RepeatedApp, WorkspaceError> {
-        let workspace_id = get_current_workspace()?;
+        let workspace_id = get_current_workspace(&self.user.user_id()?)?;
         let conn = self.database.db_connection()?;
         let repeated_app = self.read_local_apps(&workspace_id, &*conn)?;
         // TODO: read from server
         Ok(repeated_app)
     }
 
-    #[tracing::instrument(level = "debug", skip(self, conn), err)]
+    // Aggregates the unchecked todo items of all the documents in the current workspace.
+    pub(crate) async fn read_current_workspace_tasks(&self) -> Result<RepeatedDocTask, WorkspaceError> {
+        let app_ids = self
+            .read_current_workspace_apps()
+            .await?
+            .into_inner()
+            .into_iter()
+            .map(|app| app.id)
+            .collect::<Vec<String>>();
+        self.view_controller.read_tasks(app_ids).await
+    }
+
+    pub(crate) async fn search_workspace(
+        &self,
+        params: SearchWorkspaceParams,
+    ) -> Result<RepeatedSearchResult, WorkspaceError> {
+        let app_ids = self
+            .read_local_apps(&params.workspace_id, &*self.database.db_connection()?)?
+            .into_inner()
+            .into_iter()
+            .map(|app| app.id)
+            .collect::<Vec<String>>();
+        self.view_controller.search_views(app_ids, params).await
+    }
+
+    // Reads the share links of the current workspace if the workspace_id is None
+    pub(crate) async fn read_share_links(
+        &self,
+        params: WorkspaceIdentifier,
+    ) -> Result<RepeatedShareLink, WorkspaceError> {
+        let workspace_id = match params.workspace_id {
+            None => get_current_workspace(&self.user.user_id()?)?,
+            Some(workspace_id) => workspace_id,
+        };
+        self.view_controller.read_share_links(&workspace_id).await
+    }
+
+    // Sharing needs the server to find the user with the email, so it fails if the server is unreachable
+    pub(crate) async fn share_workspace(&self, params: ShareWorkspaceParams) -> Result<(), WorkspaceError> {
+        let token = self.user.token()?;
+        self.server.share_workspace(&token, params).await
+    }
+
+    pub(crate) async fn read_members(
+        &self,
+        params: WorkspaceIdentifier,
+    ) -> Result<RepeatedWorkspaceMember, WorkspaceError> {
+        let workspace_id = match params.workspace_id {
+            None => get_current_workspace(&self.user.user_id()?)?,
+            Some(workspace_id) => workspace_id,
+        };
+        let token = self.user.token()?;
+        let params = WorkspaceIdentifier::new(Some(workspace_id));
+        self.server.read_workspace_members(&token, params).await
+    }
+
+    pub(crate) fn read_workspace_tables(&self, ids: Vec<String>) -> Result<Vec<WorkspaceTable>, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_connection()?;
+        let mut workspace_tables = vec![];
+        for workspace_id in ids {
+            let tables = self.workspace_sql.read_workspaces(Some(workspace_id), &user_id, conn)?;
+            workspace_tables.extend(tables);
+        }
+        Ok(workspace_tables)
+    }
+
     fn read_local_workspaces(
         &self,
         workspace_id: Option<String>,
         user_id: &str,
         conn: &SqliteConnection,
     ) -> Result<RepeatedWorkspace, WorkspaceError> {
-        let workspace_id = workspace_id.to_owned();
-        let workspace_tables = self.workspace_sql.read_workspaces(workspace_id, user_id, conn)?;
-
-        let mut workspaces = vec![];
-        for table in workspace_tables {
-            let apps = self.read_local_apps(&table.id, conn)?.into_inner();
-            let mut workspace: Workspace = table.into();
-            workspace.apps.items = apps;
-            workspaces.push(workspace);
-        }
-        Ok(RepeatedWorkspace { items: workspaces })
+        read_local_workspaces(workspace_id, user_id, self.trash_can.clone(), &self.cache, conn)
     }
 
     fn read_local_workspace(
@@ -296,12 +538,23 @@ impl WorkspaceController {
 
     #[tracing::instrument(level = "debug", skip(self, conn), err)]
     fn read_local_apps(&self, workspace_id: &str, conn: &SqliteConnection) -> Result<RepeatedApp, WorkspaceError> {
-        let repeated_app = read_local_workspace_apps(workspace_id, self.trash_can.clone(), conn)?;
+        let repeated_app = read_local_workspace_apps(workspace_id, self.trash_can.clone(), &self.cache, conn)?;
         Ok(repeated_app)
     }
 }
 
 impl WorkspaceController {
+    fn read_account_merge_plan(
+        &self,
+        source_user_id: &str,
+        source_pool: &ConnectionPool,
+    ) -> Result<AccountMergePlan, WorkspaceError> {
+        let user_id = self.user.user_id()?;
+        let source_conn = source_pool.get().map_err(internal_error)?;
+        let conn = self.database.db_connection()?;
+        read_merge_plan(source_user_id, &*source_conn, &user_id, &*conn)
+    }
+
     fn token_with_server(&self) -> Result<(String, Server), WorkspaceError> {
         let token = self.user.token()?;
         let server = self.server.clone();
@@ -332,9 +585,7 @@ impl WorkspaceController {
 
     #[tracing::instrument(level = "debug", skip(self), err)]
     fn delete_workspace_on_server(&self, workspace_id: &str) -> Result<(), WorkspaceError> {
-        let params = WorkspaceIdentifier {
-            workspace_id: Some(workspace_id.to_string()),
-        };
+        let params = WorkspaceIdentifier::new(Some(workspace_id.to_string()));
         let (token, server) = self.token_with_server()?;
         tokio::spawn(async move {
             match server.delete_workspace(&token, params).await {
@@ -348,6 +599,37 @@ impl WorkspaceController {
         Ok(())
     }
 
+    fn listen_trash_can_event(&self) {
+        let mut rx = self.trash_can.subscribe();
+        let user = self.user.clone();
+        let database = self.database.clone();
+        let trash_can = self.trash_can.clone();
+        let cache = self.cache.clone();
+        let _ = tokio::spawn(async move {
+            loop {
+                let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
+                    match result {
+                        Ok(event) => event.select(TrashType::Workspace),
+                        Err(_e) => None,
+                    }
+                }));
+                match stream.next().await {
+                    Some(event) => {
+                        let (database, trash_can, cache) = (database.clone(), trash_can.clone(), cache.clone());
+                        handle_trash_event(user.clone(), database, trash_can, cache, event).await
+                    },
+                    None => {},
+                }
+            }
+        });
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    async fn archive_workspace_on_server(&self, params: ArchiveWorkspaceParams) -> Result<(), WorkspaceError> {
+        let (token, server) = self.token_with_server()?;
+        server.archive_workspace(&token, params).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self), err)]
     fn read_workspaces_on_server(&self, user_id: String, params: WorkspaceIdentifier) -> Result<(), WorkspaceError> {
         let (token, server) = self.token_with_server()?;
@@ -376,7 +658,7 @@ impl WorkspaceController {
 
                         tracing::debug!("Save {} views", views.len());
                         for view in views {
-                            match view_ctrl.save_view(view, &*conn) {
+                            match view_ctrl.save_remote_view(view, &*conn) {
                                 Ok(_) => {},
                                 Err(e) => log::error!("create view failed: {:?}", e),
                             }
@@ -396,12 +678,82 @@ impl WorkspaceController {
     }
 }
 
+#[tracing::instrument(level = "trace", skip(user, database, trash_can, cache))]
+async fn handle_trash_event(
+    user: Arc<dyn WorkspaceUser>,
+    database: Arc<dyn WorkspaceDatabase>,
+    trash_can: Arc<TrashCan>,
+    cache: Arc<WorkspaceCache>,
+    event: TrashEvent,
+) {
+    let db_result = database.db_connection();
+    let (identifiers, ret, is_deleted) = match event {
+        TrashEvent::NewTrash(identifiers, ret) | TrashEvent::Putback(identifiers, ret) => (identifiers, ret, false),
+        TrashEvent::Delete(identifiers, ret) => (identifiers, ret, true),
+    };
+
+    let result = || {
+        let conn = &*db_result?;
+        let user_id = user.user_id()?;
+        let token = user.token()?;
+        let _ = conn.immediate_transaction::<_, WorkspaceError, _>(|| {
+            if is_deleted {
+                let workspace_sql = WorkspaceTableSql {};
+                for identifier in identifiers.items {
+                    let _ = workspace_sql.delete_workspace(&identifier.id, conn)?;
+                    let _ = WorkspaceSettingTableSql::remove(&identifier.id, conn)?;
+                    cache.remove_apps(&identifier.id);
+                }
+            }
+
+            let repeated_workspace = read_local_workspaces(None, &user_id, trash_can.clone(), &cache, conn)?;
+            send_dart_notification(&token, WorkspaceNotification::WorkspaceListUpdated)
+                .payload(repeated_workspace)
+                .send();
+            Ok(())
+        })?;
+        Ok::<(), WorkspaceError>(())
+    };
+    let _ = ret.send(result()).await;
+}
+
+// The workspaces in the trash are hidden unless they are requested by id, the trash bin shows them
+fn read_local_workspaces(
+    workspace_id: Option<String>,
+    user_id: &str,
+    trash_can: Arc<TrashCan>,
+    cache: &WorkspaceCache,
+    conn: &SqliteConnection,
+) -> Result<RepeatedWorkspace, WorkspaceError> {
+    let mut workspace_tables = WorkspaceTableSql {}.read_workspaces(workspace_id.clone(), user_id, conn)?;
+    if workspace_id.is_none() {
+        let trash_ids = trash_can.trash_ids(conn)?;
+        workspace_tables.retain(|table| !trash_ids.contains(&table.id));
+    }
+
+    let mut workspaces = vec![];
+    for table in workspace_tables {
+        let apps = read_local_workspace_apps(&table.id, trash_can.clone(), cache, conn)?.into_inner();
+        let mut workspace: Workspace = table.into();
+        workspace.apps.items = apps;
+        workspaces.push(workspace);
+    }
+    Ok(RepeatedWorkspace { items: workspaces })
+}
+
 const CURRENT_WORKSPACE_ID: &str = "current_workspace_id";
 
-fn set_current_workspace(workspace_id: &str) { KV::set_str(CURRENT_WORKSPACE_ID, workspace_id.to_owned()); }
+// The key is prefixed with the user id, so the users that are signed in on the same device at the same time
+// have their own current workspace. The key without the prefix is the one that was saved before.
+fn current_workspace_key(user_id: &str) -> String { format!("{}_{}", user_id, CURRENT_WORKSPACE_ID) }
+
+fn set_current_workspace(user_id: &str, workspace_id: &str) {
+    KV::set_str(&current_workspace_key(user_id), workspace_id.to_owned());
+}
 
-fn get_current_workspace() -> Result<String, WorkspaceError> {
-    match KV::get_str(CURRENT_WORKSPACE_ID) {
+fn get_current_workspace(user_id: &str) -> Result<String, WorkspaceError> {
+    let workspace_id = KV::get_str(&current_workspace_key(user_id)).or_else(|| KV::get_str(CURRENT_WORKSPACE_ID));
+    match workspace_id {
         None => Err(WorkspaceError::record_not_found()
             .context("Current workspace not found or should call open workspace first")),
         Some(workspace_id) => Ok(workspace_id),
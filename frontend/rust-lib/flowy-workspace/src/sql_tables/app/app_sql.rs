@@ -34,6 +34,18 @@ impl AppTableSql {
         Ok(app_table)
     }
 
+    // Returns None if the app isn't saved locally, e.g. it belongs to a workspace that wasn't opened yet
+    pub(crate) fn read_optional_app(
+        app_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<AppTable>, WorkspaceError> {
+        let app_table = dsl::app_table
+            .filter(app_table::id.eq(app_id))
+            .first::<AppTable>(conn)
+            .optional()?;
+        Ok(app_table)
+    }
+
     pub(crate) fn read_workspace_apps(
         workspace_id: &str,
         is_trash: bool,
@@ -42,7 +54,7 @@ impl AppTableSql {
         let app_table = dsl::app_table
             .filter(app_table::workspace_id.eq(workspace_id))
             .filter(app_table::is_trash.eq(is_trash))
-            .order(app_table::create_time.asc())
+            .order((app_table::rank.asc(), app_table::create_time.asc()))
             .load::<AppTable>(conn)?;
 
         Ok(app_table)
@@ -28,6 +28,7 @@ pub(crate) struct AppTable {
     pub create_time: i64,
     pub version: i64,
     pub is_trash: bool,
+    pub rank: String,
 }
 
 impl AppTable {
@@ -43,6 +44,7 @@ impl AppTable {
             create_time: app.create_time,
             version: 0,
             is_trash: false,
+            rank: app.rank,
         }
     }
 }
@@ -96,6 +98,7 @@ pub(crate) struct AppTableChangeset {
     pub name: Option<String>,
     pub desc: Option<String>,
     pub is_trash: Option<bool>,
+    pub rank: Option<String>,
 }
 
 impl AppTableChangeset {
@@ -105,6 +108,7 @@ impl AppTableChangeset {
             name: params.name,
             desc: params.desc,
             is_trash: params.is_trash,
+            rank: None,
         }
     }
 
@@ -114,6 +118,7 @@ impl AppTableChangeset {
             name: Some(table.name),
             desc: Some(table.desc),
             is_trash: Some(table.is_trash),
+            rank: Some(table.rank),
         }
     }
 }
@@ -129,6 +134,9 @@ impl std::convert::Into<App> for AppTable {
             version: self.version,
             modified_time: self.modified_time,
             create_time: self.create_time,
+            rank: self.rank,
+            // Only the server keeps the device that modified the app
+            modified_device_id: "".to_owned(),
         }
     }
 }
@@ -0,0 +1,5 @@
+mod rename_outbox_sql;
+mod rename_outbox_table;
+
+pub(crate) use rename_outbox_sql::*;
+pub(crate) use rename_outbox_table::*;
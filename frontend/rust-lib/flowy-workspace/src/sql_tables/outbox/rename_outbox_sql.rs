@@ -0,0 +1,53 @@
+use diesel::OptionalExtension;
+use flowy_database::{prelude::*, schema::rename_outbox_table::dsl, SqliteConnection};
+
+use crate::{errors::WorkspaceError, sql_tables::outbox::RenameOutboxTable};
+
+pub struct RenameOutboxTableSql {}
+
+impl RenameOutboxTableSql {
+    // The base of the first rename is kept if the object is renamed again before the server confirms it,
+    // so all the renames are merged against the name that the server had before them.
+    pub(crate) fn save_rename(table: RenameOutboxTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let table = match Self::read_rename(&table.object_id, conn)? {
+            None => table,
+            Some(pending) => RenameOutboxTable {
+                base_name: pending.base_name,
+                base_modified_time: pending.base_modified_time,
+                ..table
+            },
+        };
+        let _ = diesel::replace_into(dsl::rename_outbox_table)
+            .values(&table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_rename(
+        object_id_s: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<RenameOutboxTable>, WorkspaceError> {
+        let table = dsl::rename_outbox_table
+            .filter(dsl::object_id.eq(object_id_s))
+            .first::<RenameOutboxTable>(conn)
+            .optional()?;
+        Ok(table)
+    }
+
+    // The earliest rename comes first
+    pub(crate) fn read_all(conn: &SqliteConnection) -> Result<Vec<RenameOutboxTable>, WorkspaceError> {
+        let tables = dsl::rename_outbox_table
+            .order(dsl::renamed_time.asc())
+            .load::<RenameOutboxTable>(conn)?;
+        Ok(tables)
+    }
+
+    // The rename is only removed if the object isn't renamed again after the name was sent
+    pub(crate) fn delete_rename(object_id_s: &str, name_s: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let filter = dsl::rename_outbox_table
+            .filter(dsl::object_id.eq(object_id_s))
+            .filter(dsl::name.eq(name_s));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use diesel::sql_types::Integer;
+use flowy_database::schema::rename_outbox_table;
+
+// The rename of the app or the view that isn't confirmed by the server yet. The base is the name and the
+// modified time that the object had before it was renamed, they tell whether others renamed it meanwhile.
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "rename_outbox_table"]
+#[primary_key(object_id)]
+pub(crate) struct RenameOutboxTable {
+    pub object_id: String,
+    pub ty: SqlRenameObjectType,
+    pub base_name: String,
+    pub base_modified_time: i64,
+    pub name: String,
+    pub renamed_time: i64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub(crate) enum SqlRenameObjectType {
+    Unknown = 0,
+    App     = 1,
+    View    = 2,
+}
+
+impl std::convert::From<i32> for SqlRenameObjectType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => SqlRenameObjectType::App,
+            2 => SqlRenameObjectType::View,
+            _o => SqlRenameObjectType::Unknown,
+        }
+    }
+}
+
+impl_sql_integer_expression!(SqlRenameObjectType);
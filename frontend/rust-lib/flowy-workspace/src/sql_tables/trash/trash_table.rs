@@ -59,9 +59,10 @@ impl std::convert::From<TrashTable> for TrashTableChangeset {
 #[repr(i32)]
 #[sql_type = "Integer"]
 pub(crate) enum SqlTrashType {
-    Unknown = 0,
-    View    = 1,
-    App     = 2,
+    Unknown   = 0,
+    View      = 1,
+    App       = 2,
+    Workspace = 3,
 }
 
 impl std::convert::From<i32> for SqlTrashType {
@@ -70,6 +71,7 @@ impl std::convert::From<i32> for SqlTrashType {
             0 => SqlTrashType::Unknown,
             1 => SqlTrashType::View,
             2 => SqlTrashType::App,
+            3 => SqlTrashType::Workspace,
             _o => SqlTrashType::Unknown,
         }
     }
@@ -83,6 +85,7 @@ impl std::convert::Into<TrashType> for SqlTrashType {
             SqlTrashType::Unknown => TrashType::Unknown,
             SqlTrashType::View => TrashType::View,
             SqlTrashType::App => TrashType::App,
+            SqlTrashType::Workspace => TrashType::Workspace,
         }
     }
 }
@@ -93,6 +96,7 @@ impl std::convert::From<TrashType> for SqlTrashType {
             TrashType::Unknown => SqlTrashType::Unknown,
             TrashType::View => SqlTrashType::View,
             TrashType::App => SqlTrashType::App,
+            TrashType::Workspace => SqlTrashType::Workspace,
         }
     }
 }
@@ -1,5 +1,11 @@
+mod view_favorite_sql;
+mod view_recent_sql;
 mod view_sql;
 mod view_table;
+mod view_unread_sql;
 
+pub use view_favorite_sql::*;
+pub use view_recent_sql::*;
 pub use view_sql::*;
 pub use view_table::*;
+pub use view_unread_sql::*;
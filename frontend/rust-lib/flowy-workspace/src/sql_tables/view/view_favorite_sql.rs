@@ -0,0 +1,49 @@
+use flowy_database::{prelude::*, schema::favorite_table::dsl, SqliteConnection};
+use lib_infra::timestamp;
+
+use crate::errors::WorkspaceError;
+
+// The view is a favorite if it has a record. The create_time is the time the view was pinned.
+pub struct ViewFavoriteTableSql {}
+
+impl ViewFavoriteTableSql {
+    pub(crate) fn add(view_id_s: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let record = (dsl::view_id.eq(view_id_s), dsl::create_time.eq(timestamp()));
+        let _ = diesel::replace_into(dsl::favorite_table).values(record).execute(conn)?;
+        Ok(())
+    }
+
+    // Returns true if the view was a favorite
+    pub(crate) fn remove(view_id_s: &str, conn: &SqliteConnection) -> Result<bool, WorkspaceError> {
+        let filter = dsl::favorite_table.filter(dsl::view_id.eq(view_id_s));
+        let affected_row = diesel::delete(filter).execute(conn)?;
+        Ok(affected_row > 0)
+    }
+
+    pub(crate) fn is_favorite(view_id_s: &str, conn: &SqliteConnection) -> Result<bool, WorkspaceError> {
+        let count = dsl::favorite_table
+            .filter(dsl::view_id.eq(view_id_s))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count > 0)
+    }
+
+    // The ids and the create times of the favorites, the view that was pinned last comes first
+    pub(crate) fn read_all(conn: &SqliteConnection) -> Result<Vec<(String, i64)>, WorkspaceError> {
+        let favorites = dsl::favorite_table
+            .select((dsl::view_id, dsl::create_time))
+            .order(dsl::create_time.desc())
+            .load::<(String, i64)>(conn)?;
+        Ok(favorites)
+    }
+
+    // The favorites read from the server replace the local ones
+    pub(crate) fn replace_all(favorites: Vec<(String, i64)>, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::delete(dsl::favorite_table).execute(conn)?;
+        for (view_id_s, create_time) in favorites {
+            let record = (dsl::view_id.eq(view_id_s), dsl::create_time.eq(create_time));
+            let _ = diesel::insert_into(dsl::favorite_table).values(record).execute(conn)?;
+        }
+        Ok(())
+    }
+}
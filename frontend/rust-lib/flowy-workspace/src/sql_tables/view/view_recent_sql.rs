@@ -0,0 +1,45 @@
+use flowy_database::{prelude::*, schema::recent_view_table::dsl, SqliteConnection};
+
+use crate::errors::WorkspaceError;
+
+// The latest time that the view was opened on any of the devices of the user
+pub struct ViewRecentTableSql {}
+
+impl ViewRecentTableSql {
+    pub(crate) fn record(view_id_s: &str, open_time_s: i64, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let record = (dsl::view_id.eq(view_id_s), dsl::open_time.eq(open_time_s));
+        let _ = diesel::replace_into(dsl::recent_view_table).values(record).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn remove(view_id_s: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let filter = dsl::recent_view_table.filter(dsl::view_id.eq(view_id_s));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+
+    // The ids and the open times of the views, the view that was opened last comes first
+    pub(crate) fn read_all(conn: &SqliteConnection) -> Result<Vec<(String, i64)>, WorkspaceError> {
+        let records = dsl::recent_view_table
+            .select((dsl::view_id, dsl::open_time))
+            .order(dsl::open_time.desc())
+            .load::<(String, i64)>(conn)?;
+        Ok(records)
+    }
+
+    // The records read from the server are merged with the local ones, the later open time is kept. The
+    // views opened on this device may not be sent to the server yet.
+    pub(crate) fn merge(records: Vec<(String, i64)>, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        for (view_id_s, open_time_s) in records {
+            let local_open_time = dsl::recent_view_table
+                .filter(dsl::view_id.eq(&view_id_s))
+                .select(dsl::open_time)
+                .first::<i64>(conn)
+                .optional()?;
+            if local_open_time.map(|time| time < open_time_s).unwrap_or(true) {
+                let _ = Self::record(&view_id_s, open_time_s, conn)?;
+            }
+        }
+        Ok(())
+    }
+}
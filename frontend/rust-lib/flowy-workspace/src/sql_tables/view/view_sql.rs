@@ -1,18 +1,23 @@
+use diesel::OptionalExtension;
 use flowy_database::{
     prelude::*,
     schema::{view_table, view_table::dsl},
+    FtsColumn,
     SqliteConnection,
+    SEARCH_INDEX,
 };
 
 use crate::{
     errors::WorkspaceError,
-    sql_tables::view::{ViewTable, ViewTableChangeset},
+    sql_tables::view::{ViewTable, ViewTableChangeset, ViewUnreadTableSql},
 };
 
 pub struct ViewTableSql {}
 
 impl ViewTableSql {
+    // The name of the view is indexed for the full-text search along with the table
     pub(crate) fn create_view(view_table: ViewTable, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = SEARCH_INDEX.update(&view_table.id, FtsColumn::Title, &view_table.name, conn)?;
         match diesel_record_count!(view_table, &view_table.id, conn) {
             0 => diesel_insert_table!(view_table, &view_table, conn),
             _ => {
@@ -38,11 +43,21 @@ impl ViewTableSql {
         Ok(view_table)
     }
 
+    // Returns None if the id isn't a view, e.g. it's the id of the app
+    pub(crate) fn read_belong_to_id(view_id: &str, conn: &SqliteConnection) -> Result<Option<String>, WorkspaceError> {
+        let belong_to_id = dsl::view_table
+            .filter(view_table::id.eq(view_id))
+            .select(view_table::belong_to_id)
+            .first::<String>(conn)
+            .optional()?;
+        Ok(belong_to_id)
+    }
+
     // belong_to_id will be the app_id or view_id.
     pub(crate) fn read_views(belong_to_id: &str, conn: &SqliteConnection) -> Result<Vec<ViewTable>, WorkspaceError> {
         let view_tables = dsl::view_table
             .filter(view_table::belong_to_id.eq(belong_to_id))
-            .order(view_table::create_time.asc())
+            .order((view_table::rank.asc(), view_table::create_time.asc()))
             .into_boxed()
             .load::<ViewTable>(conn)?;
 
@@ -50,11 +65,16 @@ impl ViewTableSql {
     }
 
     pub(crate) fn update_view(changeset: ViewTableChangeset, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        if let Some(name) = &changeset.name {
+            let _ = SEARCH_INDEX.update(&changeset.id, FtsColumn::Title, name, conn)?;
+        }
         diesel_update_table!(view_table, changeset, conn);
         Ok(())
     }
 
     pub(crate) fn delete_view(view_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = SEARCH_INDEX.delete(view_id, conn)?;
+        let _ = ViewUnreadTableSql::mark_read(view_id, conn)?;
         diesel_delete_table!(view_table, view_id, conn);
         Ok(())
     }
@@ -25,6 +25,7 @@ pub(crate) struct ViewTable {
     pub view_type: ViewTableType,
     pub version: i64,
     pub is_trash: bool,
+    pub rank: String,
 }
 
 impl ViewTable {
@@ -46,6 +47,7 @@ impl ViewTable {
             view_type,
             version: 0,
             is_trash: false,
+            rank: view.rank,
         }
     }
 }
@@ -66,6 +68,9 @@ impl std::convert::Into<View> for ViewTable {
             modified_time: self.modified_time,
             version: self.version,
             create_time: self.create_time,
+            rank: self.rank,
+            // Only the server keeps the device that modified the view
+            modified_device_id: "".to_owned(),
         }
     }
 }
@@ -90,6 +95,8 @@ pub(crate) struct ViewTableChangeset {
     pub desc: Option<String>,
     pub thumbnail: Option<String>,
     pub modified_time: i64,
+    pub belong_to_id: Option<String>,
+    pub rank: Option<String>,
 }
 
 impl ViewTableChangeset {
@@ -100,6 +107,8 @@ impl ViewTableChangeset {
             desc: params.desc,
             thumbnail: params.thumbnail,
             modified_time: timestamp(),
+            belong_to_id: None,
+            rank: None,
         }
     }
 
@@ -110,6 +119,8 @@ impl ViewTableChangeset {
             desc: Some(table.desc),
             thumbnail: Some(table.thumbnail),
             modified_time: table.modified_time,
+            belong_to_id: Some(table.belong_to_id),
+            rank: Some(table.rank),
         }
     }
 }
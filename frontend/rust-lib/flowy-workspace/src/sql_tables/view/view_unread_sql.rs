@@ -0,0 +1,31 @@
+use flowy_database::{prelude::*, schema::view_unread_table::dsl, SqliteConnection};
+use lib_infra::timestamp;
+
+use crate::errors::WorkspaceError;
+
+// The view is unread if it has a record, the record is removed when the view is opened.
+pub struct ViewUnreadTableSql {}
+
+impl ViewUnreadTableSql {
+    pub(crate) fn mark_unread(view_id_s: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let record = (dsl::view_id.eq(view_id_s), dsl::changed_time.eq(timestamp()));
+        let _ = diesel::replace_into(dsl::view_unread_table).values(record).execute(conn)?;
+        Ok(())
+    }
+
+    // Returns true if the view was unread
+    pub(crate) fn mark_read(view_id_s: &str, conn: &SqliteConnection) -> Result<bool, WorkspaceError> {
+        let filter = dsl::view_unread_table.filter(dsl::view_id.eq(view_id_s));
+        let affected_row = diesel::delete(filter).execute(conn)?;
+        Ok(affected_row > 0)
+    }
+
+    // The latest changed view comes first
+    pub(crate) fn read_unread_view_ids(conn: &SqliteConnection) -> Result<Vec<String>, WorkspaceError> {
+        let view_ids = dsl::view_unread_table
+            .select(dsl::view_id)
+            .order(dsl::changed_time.desc())
+            .load::<String>(conn)?;
+        Ok(view_ids)
+    }
+}
@@ -1,5 +1,7 @@
+mod workspace_setting_sql;
 mod workspace_sql;
 mod workspace_table;
 
+pub(crate) use workspace_setting_sql::*;
 pub(crate) use workspace_sql::*;
 pub(crate) use workspace_table::*;
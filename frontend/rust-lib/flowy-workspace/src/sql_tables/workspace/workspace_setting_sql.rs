@@ -0,0 +1,73 @@
+use flowy_database::{prelude::*, schema::workspace_setting_table::dsl, SqliteConnection};
+
+use crate::errors::WorkspaceError;
+
+// The settings that each workspace keeps on this device, e.g. the view that was opened last in the
+// workspace is opened again after switching back to it.
+pub struct WorkspaceSettingTableSql {}
+
+impl WorkspaceSettingTableSql {
+    pub(crate) fn update_latest_view(
+        workspace_id_s: &str,
+        view_id_s: &str,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let _ = Self::create_if_needed(workspace_id_s, conn)?;
+        let filter = dsl::workspace_setting_table.filter(dsl::workspace_id.eq(workspace_id_s));
+        let _ = diesel::update(filter)
+            .set(dsl::latest_view_id.eq(view_id_s))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn update_open_time(
+        workspace_id_s: &str,
+        open_time_s: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(), WorkspaceError> {
+        let _ = Self::create_if_needed(workspace_id_s, conn)?;
+        let filter = dsl::workspace_setting_table.filter(dsl::workspace_id.eq(workspace_id_s));
+        let _ = diesel::update(filter)
+            .set(dsl::last_open_time.eq(open_time_s))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // Returns the empty id if the workspace has no latest view
+    pub(crate) fn read_latest_view_id(workspace_id_s: &str, conn: &SqliteConnection) -> Result<String, WorkspaceError> {
+        let view_id = dsl::workspace_setting_table
+            .filter(dsl::workspace_id.eq(workspace_id_s))
+            .select(dsl::latest_view_id)
+            .first::<String>(conn)
+            .optional()?;
+        Ok(view_id.unwrap_or_default())
+    }
+
+    // The ids of the workspaces and the times that they were opened last
+    pub(crate) fn read_open_times(conn: &SqliteConnection) -> Result<Vec<(String, i64)>, WorkspaceError> {
+        let open_times = dsl::workspace_setting_table
+            .select((dsl::workspace_id, dsl::last_open_time))
+            .load::<(String, i64)>(conn)?;
+        Ok(open_times)
+    }
+
+    // Called after the view is deleted, the workspace that opened it last has no latest view then
+    pub(crate) fn remove_latest_view(view_id_s: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let filter = dsl::workspace_setting_table.filter(dsl::latest_view_id.eq(view_id_s));
+        let _ = diesel::update(filter).set(dsl::latest_view_id.eq("")).execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn remove(workspace_id_s: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let filter = dsl::workspace_setting_table.filter(dsl::workspace_id.eq(workspace_id_s));
+        let _ = diesel::delete(filter).execute(conn)?;
+        Ok(())
+    }
+
+    fn create_if_needed(workspace_id_s: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        let _ = diesel::insert_or_ignore_into(dsl::workspace_setting_table)
+            .values(dsl::workspace_id.eq(workspace_id_s))
+            .execute(conn)?;
+        Ok(())
+    }
+}
@@ -39,8 +39,10 @@ impl WorkspaceTableSql {
             .order(workspace_table::create_time.asc())
             .into_boxed();
 
-        if let Some(workspace_id) = workspace_id {
-            filter = filter.filter(workspace_table::id.eq(workspace_id.to_owned()));
+        // The archived workspaces are hidden unless they are requested by id
+        match workspace_id {
+            None => filter = filter.filter(workspace_table::archived.eq(false)),
+            Some(workspace_id) => filter = filter.filter(workspace_table::id.eq(workspace_id.to_owned())),
         };
 
         let workspaces = filter.load::<WorkspaceTable>(conn)?;
@@ -48,6 +50,28 @@ impl WorkspaceTableSql {
         Ok(workspaces)
     }
 
+    // Returns None if the workspace isn't saved locally
+    pub(crate) fn read_optional_workspace(
+        &self,
+        workspace_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<WorkspaceTable>, WorkspaceError> {
+        let workspace = dsl::workspace_table
+            .filter(workspace_table::id.eq(workspace_id))
+            .first::<WorkspaceTable>(conn)
+            .optional()?;
+        Ok(workspace)
+    }
+
+    // The archived workspace is read-only until it's unarchived. The workspace that isn't saved locally isn't
+    // checked, the server rejects the changes to it instead.
+    pub(crate) fn check_not_archived(&self, workspace_id: &str, conn: &SqliteConnection) -> Result<(), WorkspaceError> {
+        match self.read_optional_workspace(workspace_id, conn)? {
+            Some(table) if table.archived => Err(WorkspaceError::workspace_archived().context(workspace_id)),
+            _ => Ok(()),
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn update_workspace(
         &self,
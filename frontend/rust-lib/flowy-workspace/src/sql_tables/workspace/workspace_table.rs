@@ -1,6 +1,7 @@
 use crate::entities::{
     app::RepeatedApp,
-    workspace::{UpdateWorkspaceParams, Workspace},
+    trash::{Trash, TrashType},
+    workspace::{ArchiveWorkspaceParams, UpdateWorkspaceParams, Workspace, WorkspaceRole},
 };
 use flowy_database::schema::workspace_table;
 
@@ -14,6 +15,8 @@ pub struct WorkspaceTable {
     pub create_time: i64,
     pub user_id: String,
     pub version: i64,
+    pub archived: bool,
+    pub role: i32,
 }
 
 impl WorkspaceTable {
@@ -27,6 +30,8 @@ impl WorkspaceTable {
             create_time: workspace.create_time,
             user_id: user_id.to_owned(),
             version: 0,
+            archived: workspace.archived,
+            role: workspace.role as i32,
         }
     }
 }
@@ -40,6 +45,20 @@ impl std::convert::Into<Workspace> for WorkspaceTable {
             apps: RepeatedApp::default(),
             modified_time: self.modified_time,
             create_time: self.create_time,
+            archived: self.archived,
+            role: WorkspaceRole::from(self.role),
+        }
+    }
+}
+
+impl std::convert::Into<Trash> for WorkspaceTable {
+    fn into(self) -> Trash {
+        Trash {
+            id: self.id,
+            name: self.name,
+            modified_time: self.modified_time,
+            create_time: self.create_time,
+            ty: TrashType::Workspace,
         }
     }
 }
@@ -50,6 +69,8 @@ pub struct WorkspaceTableChangeset {
     pub id: String,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub archived: Option<bool>,
+    pub role: Option<i32>,
 }
 
 impl WorkspaceTableChangeset {
@@ -58,6 +79,16 @@ impl WorkspaceTableChangeset {
             id: params.id,
             name: params.name,
             desc: params.desc,
+            archived: None,
+            role: None,
+        }
+    }
+
+    pub fn from_archive_params(params: ArchiveWorkspaceParams) -> Self {
+        WorkspaceTableChangeset {
+            id: params.workspace_id,
+            archived: Some(params.archived),
+            ..Default::default()
         }
     }
 
@@ -66,6 +97,8 @@ impl WorkspaceTableChangeset {
             id: table.id,
             name: Some(table.name),
             desc: Some(table.desc),
+            archived: Some(table.archived),
+            role: Some(table.role),
         }
     }
 }
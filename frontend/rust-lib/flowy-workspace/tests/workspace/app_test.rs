@@ -1,18 +1,25 @@
-use flowy_test::workspace::*;
-use flowy_workspace::entities::{
-    app::QueryAppRequest,
-    trash::{TrashIdentifier, TrashType},
-    view::*,
+use flowy_test::{builder::*, fixture::*, notification::*, workspace::*, FlowyTest, FlowyTestSDK};
+use flowy_workspace::{
+    entities::{
+        app::{App, MoveAppRequest, QueryAppRequest, UpdateAppRequest},
+        share::{AppExportType, ExportAppRequest, ImportFolderRequest},
+        trash::{TrashIdentifier, TrashType},
+        view::*,
+        workspace::{ListFilter, QueryWorkspaceRequest},
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
+    protobuf::WorkspaceNotification,
 };
+use lib_dispatch::prelude::{BatchRequest, EventDispatch, ModuleRequest, ToBytes};
+use std::time::Duration;
 
 #[tokio::test]
 #[should_panic]
 async fn app_delete() {
     let test = AppTest::new().await;
     delete_app(&test.sdk, &test.app.id).await;
-    let query = QueryAppRequest {
-        app_ids: vec![test.app.id.clone()],
-    };
+    let query = QueryAppRequest::new(&test.app.id);
     let _ = read_app(&test.sdk, query).await;
 }
 
@@ -29,9 +36,7 @@ async fn app_delete_then_putback() {
     )
     .await;
 
-    let query = QueryAppRequest {
-        app_ids: vec![test.app.id.clone()],
-    };
+    let query = QueryAppRequest::new(&test.app.id);
     let app = read_app(&test.sdk, query).await;
     assert_eq!(&app, &test.app);
 }
@@ -39,13 +44,131 @@ async fn app_delete_then_putback() {
 #[tokio::test]
 async fn app_read() {
     let test = AppTest::new().await;
-    let query = QueryAppRequest {
-        app_ids: vec![test.app.id.clone()],
-    };
+    let query = QueryAppRequest::new(&test.app.id);
     let app_from_db = read_app(&test.sdk, query).await;
     assert_eq!(app_from_db, test.app);
 }
 
+#[tokio::test]
+async fn app_rename_then_read() {
+    let test = AppTest::new().await;
+    let request = UpdateAppRequest {
+        app_id: test.app.id.clone(),
+        name: Some("Renamed App".to_owned()),
+        desc: None,
+        color_style: None,
+        is_trash: None,
+    };
+    update_app(&test.sdk, request).await;
+
+    let query = QueryAppRequest::new(&test.app.id);
+    let app_from_db = read_app(&test.sdk, query).await;
+    assert_eq!(app_from_db.name, "Renamed App");
+}
+
+#[tokio::test]
+async fn app_rename_then_read_workspace_apps() {
+    let test = AppTest::new().await;
+    let request = QueryWorkspaceRequest::new(Some(test.workspace.id.clone()));
+    let _ = read_workspace(&test.sdk, request.clone()).await;
+
+    let request_rename = UpdateAppRequest {
+        app_id: test.app.id.clone(),
+        name: Some("Renamed App".to_owned()),
+        desc: None,
+        color_style: None,
+        is_trash: None,
+    };
+    update_app(&test.sdk, request_rename).await;
+
+    let workspace = read_workspace(&test.sdk, request).await.pop().unwrap();
+    assert_eq!(workspace.apps.items[0].name, "Renamed App");
+}
+
+#[tokio::test]
+async fn app_rename_view_then_read_belongings() {
+    let test = AppTest::new().await;
+    let view = create_view(&test.sdk, &test.app.id).await;
+    let _ = read_app(&test.sdk, QueryAppRequest::new(&test.app.id)).await;
+
+    let request = UpdateViewRequest {
+        view_id: view.id.clone(),
+        name: Some("Renamed View".to_owned()),
+        desc: None,
+        thumbnail: None,
+    };
+    update_view(&test.sdk, request).await;
+
+    let app = read_app(&test.sdk, QueryAppRequest::new(&test.app.id)).await;
+    assert_eq!(app.belongings.items[0].name, "Renamed View");
+}
+
+#[tokio::test]
+async fn app_rename_notify_app_updated() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let tree = WorkspaceFixture::new("Workspace")
+        .app("App", &["View"])
+        .build(&test.sdk)
+        .await;
+    let app = tree.app("App");
+    let recorder = NotificationRecorder::with_id("Workspace", &app.id);
+
+    let request = UpdateAppRequest {
+        app_id: app.id.clone(),
+        name: Some("Renamed App".to_owned()),
+        desc: None,
+        color_style: None,
+        is_trash: None,
+    };
+    update_app(&tree.sdk, request).await;
+
+    let app: App = recorder.assert_notified(WorkspaceNotification::AppUpdated as i32);
+    assert_eq!(app.name, "Renamed App");
+}
+
+#[tokio::test]
+async fn app_rename_view_notify_view_updated() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let tree = WorkspaceFixture::new("Workspace")
+        .app("App", &["View A", "View B"])
+        .build(&test.sdk)
+        .await;
+    let view = tree.view("App", "View B");
+    let recorder = NotificationRecorder::with_id("Workspace", &view.id);
+
+    let request = UpdateViewRequest {
+        view_id: view.id.clone(),
+        name: Some("Renamed View".to_owned()),
+        desc: None,
+        thumbnail: None,
+    };
+    update_view(&tree.sdk, request).await;
+
+    let view: View = recorder.assert_notified(WorkspaceNotification::ViewUpdated as i32);
+    assert_eq!(view.name, "Renamed View");
+    assert_eq!(tree.app("App").belongings.len(), 2);
+}
+
+#[tokio::test]
+async fn app_move_to_first() {
+    let test = AppTest::new().await;
+    let app_b = create_app(&test.sdk, "App B", "", &test.workspace.id).await;
+    let app_c = create_app(&test.sdk, "App C", "", &test.workspace.id).await;
+    move_app(&test.sdk, &app_c.id, 0).await;
+
+    let request = QueryWorkspaceRequest::new(Some(test.workspace.id.clone()));
+    let workspace = read_workspace(&test.sdk, request).await.pop().unwrap();
+    let app_ids = workspace
+        .apps
+        .items
+        .iter()
+        .map(|app| app.id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(app_ids, vec![app_c.id.as_str(), test.app.id.as_str(), app_b.id.as_str()]);
+}
+
 #[tokio::test]
 async fn app_create_with_view() {
     let test = AppTest::new().await;
@@ -68,15 +191,212 @@ async fn app_create_with_view() {
     let view_a = create_view_with_request(&test.sdk, request_a).await;
     let view_b = create_view_with_request(&test.sdk, request_b).await;
 
-    let query = QueryAppRequest {
-        app_ids: vec![test.app.id.clone()],
-    };
+    let query = QueryAppRequest::new(&test.app.id);
     let view_from_db = read_app(&test.sdk, query).await;
 
     assert_eq!(view_from_db.belongings[0], view_a);
     assert_eq!(view_from_db.belongings[1], view_b);
 }
 
+#[tokio::test]
+async fn app_read_with_view_filter() {
+    let test = AppTest::new().await;
+    let mut views = vec![];
+    for name in vec!["Doc 1", "Grid", "doc 2", "DOC 3"] {
+        let request = CreateViewRequest {
+            belong_to_id: test.app.id.clone(),
+            name: name.to_string(),
+            desc: "".to_string(),
+            thumbnail: None,
+            view_type: ViewType::Doc,
+        };
+        views.push(create_view_with_request(&test.sdk, request).await);
+    }
+
+    let query = QueryAppRequest {
+        view_filter: Some(ListFilter {
+            name: Some("doc".to_owned()),
+            ..ListFilter::new(1, 1)
+        }),
+        ..QueryAppRequest::new(&test.app.id)
+    };
+    let app = read_app(&test.sdk, query).await;
+    assert_eq!(app.belongings.len(), 1);
+    assert_eq!(app.belongings[0], views[2]);
+}
+
+#[tokio::test]
+async fn app_export_markdown() {
+    let test = AppTest::new().await;
+    let guide = create_doc_view(&test.sdk, &test.app.id, "Guide").await;
+    let setup = create_doc_view(&test.sdk, &guide.id, "Setup").await;
+    let _ = import_markdown(&test.sdk, &guide.id, &format!("# Intro\nsee [setup]({})\n", setup.id)).await;
+    let _ = import_markdown(&test.sdk, &setup.id, "## Install\n").await;
+
+    let path = export_path(&test.app.id, "md");
+    let progress = export_app(&test.sdk, &test.app.id, AppExportType::Markdown, &path).await;
+    assert_eq!(progress.total, 2);
+    assert!(!progress.is_completed);
+
+    let markdown = String::from_utf8(read_exported_file(&path).await).unwrap();
+    assert_eq!(
+        markdown,
+        format!(
+            concat!(
+                "<a id=\"{0}\"></a>\n# Guide\n\n## Intro\nsee [setup](#{1})\n\n",
+                "<a id=\"{1}\"></a>\n## Setup\n\n#### Install\n"
+            ),
+            guide.id, setup.id
+        )
+    );
+}
+
+#[tokio::test]
+async fn app_export_pdf() {
+    let test = AppTest::new().await;
+    let view = create_doc_view(&test.sdk, &test.app.id, "Guide").await;
+    let _ = import_markdown(&test.sdk, &view.id, "# Intro\n- step\n").await;
+
+    let path = export_path(&test.app.id, "pdf");
+    let _ = export_app(&test.sdk, &test.app.id, AppExportType::Pdf, &path).await;
+    let pdf = String::from_utf8(read_exported_file(&path).await).unwrap();
+    assert!(pdf.starts_with("%PDF-1.4"));
+    assert!(pdf.contains("/Title (App)"));
+    assert!(pdf.contains("/Title (Guide)"));
+}
+
+#[tokio::test]
+async fn app_export_with_empty_path() {
+    let test = AppTest::new().await;
+    let request = ExportAppRequest {
+        app_id: test.app.id.clone(),
+        export_type: AppExportType::Markdown,
+        path: " ".to_owned(),
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(ExportApp)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::ExportPathInvalid.value()
+    )
+}
+
+#[tokio::test]
+async fn app_import_folder() {
+    let test = AppTest::new().await;
+    let dir = std::env::temp_dir().join(&test.app.id).join("Notes");
+    std::fs::create_dir_all(dir.join("Daily")).unwrap();
+    std::fs::create_dir_all(dir.join(".obsidian")).unwrap();
+    std::fs::write(dir.join(".obsidian").join("app.json"), "{}").unwrap();
+    std::fs::write(dir.join("logo.png"), vec![1u8; 1024]).unwrap();
+    std::fs::write(dir.join("Daily").join("Setup.md"), "## Setup\n").unwrap();
+    std::fs::write(
+        dir.join("Guide.md"),
+        "# Guide\nsee [setup](Daily/Setup.md) and [missing](Missing.md)\n![logo](logo.png)\n",
+    )
+    .unwrap();
+
+    let progress = import_folder(&test.sdk, &test.workspace.id, dir.to_str().unwrap()).await;
+    assert_eq!(progress.total, 3);
+    assert!(!progress.is_completed);
+
+    let views = read_imported_views(&test.sdk, &progress.app_id, 2).await;
+    let guide = views.iter().find(|view| view.name == "Guide").unwrap();
+    let daily = views.iter().find(|view| view.name == "Daily").unwrap();
+    let request = QueryViewRequest {
+        view_ids: vec![daily.id.clone()],
+    };
+    let setup = read_view(&test.sdk, request).await.belongings.items[0].clone();
+    assert_eq!(setup.name, "Setup");
+
+    // the link to the imported file refers to its doc and the image becomes an attachment
+    let markdown = read_imported_markdown(&test.sdk, &guide.id).await;
+    assert_eq!(
+        markdown,
+        format!("# Guide\nsee [setup]({}) and [missing](Missing.md)\nlogo\n", setup.id)
+    );
+    let _ = std::fs::remove_dir_all(std::env::temp_dir().join(&test.app.id));
+}
+
+#[tokio::test]
+async fn app_import_folder_with_invalid_path() {
+    let test = AppTest::new().await;
+    let path = std::env::temp_dir().join(format!("{}.md", test.app.id));
+    std::fs::write(&path, "# Guide\n").unwrap();
+
+    let request = ImportFolderRequest {
+        workspace_id: test.workspace.id.clone(),
+        path: path.to_str().unwrap().to_owned(),
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(ImportFolder)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::ImportPathInvalid.value()
+    )
+}
+
+async fn create_doc_view(sdk: &FlowyTestSDK, belong_to_id: &str, name: &str) -> View {
+    let request = CreateViewRequest {
+        belong_to_id: belong_to_id.to_owned(),
+        name: name.to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+    };
+    create_view_with_request(sdk, request).await
+}
+
+fn export_path(app_id: &str, extension: &str) -> String {
+    let path = std::env::temp_dir().join(format!("{}.{}", app_id, extension));
+    path.to_str().unwrap().to_owned()
+}
+
+// The file is written in the background after the export is started
+async fn read_exported_file(path: &str) -> Vec<u8> {
+    for _ in 0..50 {
+        if let Ok(data) = std::fs::read(path) {
+            if !data.is_empty() {
+                let _ = std::fs::remove_file(path);
+                return data;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("The app wasn't exported to {}", path);
+}
+
+// The views are created in the background after the app is created
+async fn read_imported_views(sdk: &FlowyTestSDK, app_id: &str, count: usize) -> Vec<View> {
+    for _ in 0..50 {
+        let app = read_app(sdk, QueryAppRequest::new(app_id)).await;
+        if app.belongings.items.len() == count {
+            return app.belongings.items;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("The views of the app {} weren't imported", app_id);
+}
+
+async fn read_imported_markdown(sdk: &FlowyTestSDK, doc_id: &str) -> String {
+    for _ in 0..50 {
+        let markdown = export_markdown(sdk, doc_id).await.data;
+        if !markdown.trim().is_empty() {
+            return markdown;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("The doc {} wasn't imported", doc_id);
+}
+
 // #[tokio::test]
 // async fn app_set_trash_flag() {
 //     let test = AppTest::new().await;
@@ -94,3 +414,66 @@ async fn app_create_with_view() {
 //     let query = QueryAppRequest::new(&test.app.id);
 //     let _ = read_app(&test.sdk, query);
 // }
+
+#[tokio::test]
+async fn app_read_views_after_cursor() {
+    let test = AppTest::new().await;
+    let mut views = vec![];
+    for name in vec!["View 1", "View 2", "View 3"] {
+        let request = CreateViewRequest {
+            belong_to_id: test.app.id.clone(),
+            name: name.to_string(),
+            desc: "".to_string(),
+            thumbnail: None,
+            view_type: ViewType::Doc,
+        };
+        views.push(create_view_with_request(&test.sdk, request).await);
+    }
+
+    let query = QueryAppRequest {
+        view_filter: Some(ListFilter {
+            after: Some(views[0].id.clone()),
+            ..ListFilter::new(1, 0)
+        }),
+        ..QueryAppRequest::new(&test.app.id)
+    };
+    let app = read_app(&test.sdk, query).await;
+    assert_eq!(app.belongings.len(), 1);
+    assert_eq!(app.belongings[0], views[1]);
+}
+
+#[tokio::test]
+async fn app_rename_rolled_back_in_failed_batch() {
+    let test = AppTest::new().await;
+    let request = UpdateAppRequest {
+        app_id: test.app.id.clone(),
+        name: Some("Renamed App".to_owned()),
+        desc: None,
+        color_style: None,
+        is_trash: None,
+    };
+    let unknown_app = QueryAppRequest::new("unknown app");
+    let batch = BatchRequest::new()
+        .request(ModuleRequest::new(UpdateApp).payload(request.into_bytes().unwrap()))
+        .request(ModuleRequest::new(ReadApp).payload(unknown_app.into_bytes().unwrap()));
+    let response = EventDispatch::async_send_batch(test.sdk.dispatch(), batch).await;
+    assert!(!response.is_ok());
+    assert!(response.rolled_back);
+
+    let query = QueryAppRequest::new(&test.app.id);
+    let app_from_db = read_app(&test.sdk, query).await;
+    assert_eq!(app_from_db.name, test.app.name);
+}
+
+#[tokio::test]
+async fn app_move_rejected_in_transactional_batch() {
+    let test = AppTest::new().await;
+    let request = MoveAppRequest {
+        app_id: test.app.id.clone(),
+        index: 0,
+    };
+    let batch = BatchRequest::new().request(ModuleRequest::new(MoveApp).payload(request.into_bytes().unwrap()));
+    let response = EventDispatch::async_send_batch(test.sdk.dispatch(), batch).await;
+    assert!(!response.is_ok());
+    assert!(!response.rolled_back);
+}
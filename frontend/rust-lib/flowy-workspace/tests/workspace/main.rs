@@ -1,4 +1,5 @@
 mod app_test;
+mod member_test;
 // mod helper;
 mod view_test;
 mod workspace_test;
@@ -0,0 +1,79 @@
+use flowy_test::{builder::*, context::FlowyTestContext, workspace::*};
+use flowy_workspace::{entities::workspace::QueryWorkspaceRequest, event::WorkspaceEvent::*, prelude::*};
+use lib_infra::bus::DocRevisionEvent;
+use serial_test::*;
+use std::time::Duration;
+
+#[tokio::test]
+#[serial]
+async fn workspace_share_with_member_of_another_context() {
+    let context = FlowyTestContext::setup(&["owner", "member"]);
+    let _ = context.sign_up("owner").await;
+    let member = context.sign_up("member").await.user_profile;
+    let owner_workspace = read_current_workspace(&context.sdk("owner")).await.workspace;
+    let member_workspace = read_current_workspace(&context.sdk("member")).await.workspace;
+    assert_ne!(owner_workspace.id, member_workspace.id);
+
+    for role in vec![WorkspaceRole::Editor, WorkspaceRole::Viewer] {
+        let request = ShareWorkspaceRequest {
+            workspace_id: owner_workspace.id.clone(),
+            email: member.email.clone(),
+            role,
+        };
+        let _ = context
+            .workspace_test("owner")
+            .event(ShareWorkspace)
+            .request(request)
+            .async_send()
+            .await
+            .assert_success();
+    }
+
+    // The workspace only has one owner
+    let request = ShareWorkspaceRequest {
+        workspace_id: owner_workspace.id.clone(),
+        email: member.email.clone(),
+        role: WorkspaceRole::Owner,
+    };
+    let error = context
+        .workspace_test("owner")
+        .event(ShareWorkspace)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::MemberRoleInvalid.value());
+
+    // The workspace of the owner isn't opened by the member until it's synced to the member's database
+    let _ = context
+        .workspace_test("member")
+        .event(OpenWorkspace)
+        .request(QueryWorkspaceRequest::new(Some(owner_workspace.id.clone())))
+        .async_send()
+        .await
+        .assert_error();
+    assert_eq!(read_current_workspace(&context.sdk("member")).await.workspace.id, member_workspace.id);
+    assert_eq!(read_current_workspace(&context.sdk("owner")).await.workspace.id, owner_workspace.id);
+}
+
+#[tokio::test]
+#[serial]
+async fn workspace_events_of_another_context_are_isolated() {
+    let context = FlowyTestContext::setup(&["owner", "member"]);
+    let _ = context.sign_up("owner").await;
+    let _ = context.sign_up("member").await;
+    let view = read_current_workspace(&context.sdk("owner")).await.latest_view.unwrap();
+
+    let event = DocRevisionEvent {
+        doc_id: view.id.clone(),
+        rev_id: 1,
+        is_remote: true,
+    };
+    context.sdk("member").bus.publish(event.clone());
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(read_current_workspace(&context.sdk("owner")).await.unread_view_ids.is_empty());
+
+    context.sdk("owner").bus.publish(event);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(read_current_workspace(&context.sdk("owner")).await.unread_view_ids, vec![view.id]);
+}
@@ -1,9 +1,19 @@
-use flowy_test::{workspace::*, FlowyTest};
-use flowy_workspace::entities::{
-    app::QueryAppRequest,
-    trash::{TrashIdentifier, TrashType},
-    view::*,
+use flowy_document_infra::entities::doc::{DocBlockType, DocSnapshotIdentifier};
+use flowy_test::{builder::*, workspace::*, FlowyTest};
+use flowy_workspace::{
+    entities::{
+        app::QueryAppRequest,
+        share::UploadAttachmentRequest,
+        trash::{TrashIdentifier, TrashType},
+        view::*,
+    },
+    errors::ErrorCode,
+    event::WorkspaceEvent::*,
 };
+use flowy_workspace_infra::parser::view::MAX_WEBHOOK_SECRET_LEN;
+use lib_infra::bus::DocRevisionEvent;
+use lib_ot::core::Delta;
+use std::time::Duration;
 
 #[tokio::test]
 #[should_panic]
@@ -53,9 +63,7 @@ async fn view_delete_all() {
     let view3 = create_view(&test.sdk, &test.app.id).await;
     let view_ids = vec![view1.id.clone(), view2.id.clone(), view3.id.clone()];
 
-    let query = QueryAppRequest {
-        app_ids: vec![test.app.id.clone()],
-    };
+    let query = QueryAppRequest::new(&test.app.id);
     let app = read_app(&test.sdk, query.clone()).await;
     assert_eq!(app.belongings.len(), view_ids.len());
     test.delete_views(view_ids.clone()).await;
@@ -76,9 +84,7 @@ async fn view_delete_all_permanent() {
     let view_ids = vec![view1.id.clone(), view2.id.clone()];
     test.delete_views_permanent(view_ids).await;
 
-    let query = QueryAppRequest {
-        app_ids: vec![test.app.id.clone()],
-    };
+    let query = QueryAppRequest::new(&test.app.id);
     assert_eq!(read_app(&test.sdk, query).await.belongings.len(), 0);
     assert_eq!(read_trash(&test.sdk).await.len(), 0);
 }
@@ -94,3 +100,460 @@ async fn view_open_doc() {
     };
     let _ = open_view(&test.sdk, request).await;
 }
+
+// The revisions of the document are read and written on the blocking pool of the dispatch
+#[tokio::test]
+async fn view_open_doc_on_blocking_pool() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let completed = test.sdk.dispatch.blocking_pool().metrics().completed;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    assert!(test.sdk.dispatch.blocking_pool().metrics().completed > completed);
+}
+
+#[tokio::test]
+async fn view_open_doc_without_read_position() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let delta = open_view(&test.sdk, request).await;
+    assert_eq!(delta.read_position, None);
+}
+
+#[tokio::test]
+async fn view_update_read_position_then_open() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let markdown = "# Title\nThe first paragraph\nThe second paragraph\n";
+    let _ = import_markdown(&test.sdk, &test.view.id, markdown).await;
+    update_read_position(&test.sdk, &test.view.id, 12).await;
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let position = open_view(&test.sdk, request).await.read_position.unwrap();
+    assert_eq!(position.doc_id, test.view.id);
+    assert_eq!(position.offset, 12);
+    assert!(position.modified_time > 0);
+}
+
+#[tokio::test]
+async fn view_read_position_is_clamped_to_doc_length() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let markdown = "# Title\n";
+    let _ = import_markdown(&test.sdk, &test.view.id, markdown).await;
+    update_read_position(&test.sdk, &test.view.id, 1000).await;
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let delta = open_view(&test.sdk, request).await;
+    let doc_len = Delta::from_json(&delta.data).unwrap().target_len as i64;
+    assert_eq!(delta.read_position.unwrap().offset, doc_len);
+}
+
+#[tokio::test]
+async fn view_import_then_export_markdown() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let markdown = "# Title\n- [ ] todo\nsee [AppFlowy](https://appflowy.io)\n";
+    let _ = import_markdown(&test.sdk, &test.view.id, markdown).await;
+    let data = export_markdown(&test.sdk, &test.view.id).await;
+    assert_eq!(data.data, markdown);
+}
+
+#[tokio::test]
+async fn view_preview_import_markdown() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let _ = import_markdown(&test.sdk, &test.view.id, "# Title\n").await;
+    let preview = preview_import_markdown(&test.sdk, &test.view.id, "## Install\n- [x] **done**\n| a | b |\n").await;
+    let blocks = preview
+        .blocks
+        .iter()
+        .map(|block| (block.block_type, block.text.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        blocks,
+        vec![
+            (DocBlockType::Heading, "Install"),
+            (DocBlockType::Checkbox, "done"),
+            (DocBlockType::Paragraph, "| a | b |"),
+        ]
+    );
+    assert_eq!(preview.blocks[0].level, 2);
+    assert_eq!(preview.warnings.len(), 1);
+    assert_eq!(preview.warnings[0].line, 3);
+
+    // the doc isn't changed by the preview
+    let data = export_markdown(&test.sdk, &test.view.id).await;
+    assert_eq!(data.data, "# Title\n");
+}
+
+#[tokio::test]
+async fn view_unread_after_remote_change_until_opened() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.sdk.bus.publish(DocRevisionEvent {
+        doc_id: test.view.id.clone(),
+        rev_id: 1,
+        is_remote: true,
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(read_current_workspace(&test.sdk).await.unread_view_ids, vec![test.view.id.clone()]);
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    assert!(read_current_workspace(&test.sdk).await.unread_view_ids.is_empty());
+}
+
+#[tokio::test]
+async fn view_move_to_another_app() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let app = create_app(&test.sdk, "App B", "", &test.workspace.id).await;
+    let view = create_view(&test.sdk, &app.id).await;
+    let request = MoveViewRequest {
+        view_id: test.view.id.clone(),
+        belong_to_id: app.id.clone(),
+        index: 0,
+    };
+    move_view(&test.sdk, request).await;
+
+    let app_from_db = read_app(&test.sdk, QueryAppRequest::new(&app.id)).await;
+    let view_ids = app_from_db
+        .belongings
+        .items
+        .iter()
+        .map(|view| view.id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(view_ids, vec![test.view.id.as_str(), view.id.as_str()]);
+
+    let old_app_from_db = read_app(&test.sdk, QueryAppRequest::new(&test.app.id)).await;
+    assert!(old_app_from_db.belongings.is_empty());
+}
+
+#[tokio::test]
+async fn view_move_under_its_child() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child = create_view(&test.sdk, &test.view.id).await;
+    let request = MoveViewRequest {
+        view_id: test.view.id.clone(),
+        belong_to_id: child.id.clone(),
+        index: 0,
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(MoveView)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::MoveTargetInvalid.value()
+    )
+}
+
+#[tokio::test]
+async fn view_share_link_with_long_passcode() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = CreateShareLinkRequest {
+        view_id: test.view.id.clone(),
+        expire_time: 0,
+        passcode: "1".repeat(MAX_SHARE_PASSCODE_LEN + 1),
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(CreateShareLink)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::SharePasscodeTooLong.value()
+    )
+}
+
+#[tokio::test]
+async fn view_upload_attachment() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let path = std::env::temp_dir().join(format!("{}.png", test.view.id));
+    std::fs::write(&path, vec![1u8; 300 * 1024]).unwrap();
+
+    let progress = upload_attachment(&test.sdk, &test.view.id, path.to_str().unwrap()).await;
+    assert_eq!(progress.doc_id, test.view.id);
+    assert_eq!(progress.total, 300 * 1024);
+    assert_eq!(progress.uploaded, 0);
+    assert!(!progress.is_completed);
+}
+
+#[tokio::test]
+async fn view_read_uploaded_attachment_from_cache() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let path = std::env::temp_dir().join(format!("{}.txt", test.view.id));
+    std::fs::write(&path, b"attachment data").unwrap();
+
+    // the mock server can't be downloaded from, so the file must come from the cache
+    let progress = upload_attachment(&test.sdk, &test.view.id, path.to_str().unwrap()).await;
+    let file = read_attachment(&test.sdk, &progress.attachment_id).await;
+    assert_eq!(file.attachment_id, progress.attachment_id);
+    assert_eq!(std::fs::read(&file.path).unwrap(), b"attachment data");
+}
+
+#[tokio::test]
+async fn view_upload_attachment_with_invalid_path() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = UploadAttachmentRequest {
+        doc_id: test.view.id.clone(),
+        path: std::env::temp_dir().to_str().unwrap().to_owned(),
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(UploadAttachment)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::AttachmentPathInvalid.value()
+    )
+}
+
+// The mock server doesn't take the snapshots, so there is no version to restore
+#[tokio::test]
+async fn view_query_doc_history_then_restore() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let history = query_doc_history(&test.sdk, &test.view.id).await;
+    assert!(history.items.is_empty());
+
+    let request = DocSnapshotIdentifier {
+        doc_id: test.view.id.clone(),
+        snapshot_id: 1,
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(RestoreDocVersion)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::InternalError.value()
+    )
+}
+
+#[tokio::test]
+async fn view_toggle_favorite() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view = create_view(&test.sdk, &test.app.id).await;
+    assert_eq!(toggle_favorite(&test.sdk, &test.view.id).await.is_favorite, true);
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(toggle_favorite(&test.sdk, &view.id).await.is_favorite, true);
+
+    // the view that was pinned last comes first
+    let favorites = read_favorites(&test.sdk).await;
+    let view_ids = favorites
+        .items
+        .iter()
+        .map(|favorite| favorite.view.id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(view_ids, vec![view.id.as_str(), test.view.id.as_str()]);
+
+    assert_eq!(toggle_favorite(&test.sdk, &test.view.id).await.is_favorite, false);
+    let favorites = read_favorites(&test.sdk).await;
+    assert_eq!(favorites.items.len(), 1);
+    assert_eq!(favorites.items[0].view.id, view.id);
+}
+
+#[tokio::test]
+async fn view_favorite_in_trash() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let _ = toggle_favorite(&test.sdk, &test.view.id).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+    assert!(read_favorites(&test.sdk).await.items.is_empty());
+
+    putback_trash(
+        &test.sdk,
+        TrashIdentifier {
+            id: test.view.id.clone(),
+            ty: TrashType::View,
+        },
+    )
+    .await;
+    assert_eq!(read_favorites(&test.sdk).await.items.len(), 1);
+}
+
+#[tokio::test]
+async fn view_webhook_with_invalid_url() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = SetViewWebhookRequest {
+        view_id: test.view.id.clone(),
+        url: "ftp://127.0.0.1/hook".to_owned(),
+        format: WebhookFormat::Markdown,
+        secret: "".to_owned(),
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(SetViewWebhook)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::WebhookUrlInvalid.value()
+    )
+}
+
+#[tokio::test]
+async fn view_webhook_with_long_secret() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = SetViewWebhookRequest {
+        view_id: test.view.id.clone(),
+        url: "https://example.com/hook".to_owned(),
+        format: WebhookFormat::Html,
+        secret: "1".repeat(MAX_WEBHOOK_SECRET_LEN + 1),
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(SetViewWebhook)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::WebhookSecretTooLong.value()
+    )
+}
+
+#[tokio::test]
+async fn view_recent_views() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view = create_view(&test.sdk, &test.app.id).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+
+    // the view that was opened last comes first
+    let recent_views = read_recent_views(&test.sdk, 0).await;
+    let view_ids = recent_views
+        .items
+        .iter()
+        .map(|recent_view| recent_view.view.id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(view_ids, vec![view.id.as_str(), test.view.id.as_str()]);
+    assert_eq!(recent_views.items[0].app_id, test.app.id);
+    assert_eq!(recent_views.items[0].app_name, test.app.name);
+    assert_eq!(recent_views.items[0].workspace_id, test.workspace.id);
+    assert_eq!(recent_views.items[0].workspace_name, test.workspace.name);
+
+    let recent_views = read_recent_views(&test.sdk, 1).await;
+    assert_eq!(recent_views.items.len(), 1);
+    assert_eq!(recent_views.items[0].view.id, view.id);
+}
+
+#[tokio::test]
+async fn view_recent_views_in_trash() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+    assert!(read_recent_views(&test.sdk, 0).await.items.is_empty());
+
+    putback_trash(
+        &test.sdk,
+        TrashIdentifier {
+            id: test.view.id.clone(),
+            ty: TrashType::View,
+        },
+    )
+    .await;
+    assert_eq!(read_recent_views(&test.sdk, 0).await.items.len(), 1);
+}
+
+#[tokio::test]
+async fn view_recent_views_with_invalid_limit() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let request = QueryRecentViewsRequest {
+        limit: MAX_RECENT_LIMIT + 1,
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(QueryRecentViews)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::ListFilterInvalid.value()
+    )
+}
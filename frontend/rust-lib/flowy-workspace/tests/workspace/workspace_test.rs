@@ -1,3 +1,4 @@
+use flowy_database::{FtsLanguage, SEARCH_INDEX};
 use flowy_test::{builder::*, workspace::*, FlowyTest};
 use flowy_workspace::{
     entities::workspace::{CreateWorkspaceRequest, QueryWorkspaceRequest},
@@ -81,4 +82,330 @@ async fn workspace_update_with_invalid_name() {
     }
 }
 
+#[tokio::test]
+async fn workspace_search_view_name() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let results = search_workspace(&test.sdk, &test.workspace.id, "vie").await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].view_id, test.view.id);
+    assert_eq!(results[0].snippet, "<b>View</b> A");
+
+    // The views in the trash are not searchable
+    test.delete_views(vec![test.view.id.clone()]).await;
+    assert!(search_workspace(&test.sdk, &test.workspace.id, "view").await.is_empty());
+}
+
+#[tokio::test]
+async fn workspace_search_doc_text_matches() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let markdown = "Plans for the **roadmap**\n\nThe roadmap is reviewed weekly";
+    let _ = import_markdown(&test.sdk, &test.view.id, markdown).await;
+    let results = search_workspace(&test.sdk, &test.workspace.id, "roadmap").await;
+    assert_eq!(results.len(), 1);
+
+    let matches = &results[0].matches;
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].context, "Plans for the roadmap");
+    assert_eq!((matches[0].highlight_start, matches[0].highlight_end), (14, 21));
+    assert_eq!((matches[1].start, matches[1].end), (27, 34));
+}
+
+#[tokio::test]
+async fn workspace_search_chinese_doc() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    // The text that isn't Chinese is indexed the same as before, so the other tests aren't affected
+    SEARCH_INDEX.set_language(FtsLanguage::Chinese);
+
+    let test = ViewTest::new(&test).await;
+    let _ = import_markdown(&test.sdk, &test.view.id, "我们正在开发数据库管理系统").await;
+    let results = search_workspace(&test.sdk, &test.workspace.id, "数据库").await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].snippet.contains("开发<b>数据库</b>"), true);
+}
+
+#[tokio::test]
+async fn workspace_search_with_empty_query() {
+    let test = WorkspaceTest::new().await;
+    let request = SearchWorkspaceRequest {
+        workspace_id: test.workspace.id.clone(),
+        query: "  ".to_owned(),
+        limit: 0,
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(SearchWorkspace)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::SearchQueryInvalid.value()
+    )
+}
+
+#[tokio::test]
+async fn workspace_query_all() {
+    let test = WorkspaceTest::new().await;
+    let items = query_workspaces(&test.sdk).await.items;
+    assert_eq!(items.len(), 2);
+    assert!(items.iter().all(|item| item.role == WorkspaceRole::Owner));
+
+    let current_ids = items
+        .iter()
+        .filter(|item| item.is_current)
+        .map(|item| item.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(current_ids, vec![test.workspace.id.clone()]);
+}
+
+#[tokio::test]
+async fn workspace_switch_with_latest_view() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    let other = query_workspaces(&test.sdk)
+        .await
+        .items
+        .into_iter()
+        .find(|item| !item.is_current)
+        .unwrap();
+
+    // The latest view is kept per workspace
+    let setting = switch_workspace(&test.sdk, &other.id).await;
+    assert_eq!(setting.workspace.id, other.id);
+    assert_ne!(setting.latest_view.map(|view| view.id), Some(test.view.id.clone()));
+    assert_eq!(read_current_workspace(&test.sdk).await.workspace.id, other.id);
+
+    let setting = switch_workspace(&test.sdk, &test.workspace.id).await;
+    assert_eq!(setting.latest_view.unwrap().id, test.view.id);
+    let items = query_workspaces(&test.sdk).await.items;
+    assert!(items.iter().any(|item| item.id == test.workspace.id && item.is_current));
+    assert!(items.iter().all(|item| item.last_open_time > 0));
+}
+
+#[tokio::test]
+async fn workspace_switch_with_invalid_id() {
+    let test = WorkspaceTest::new().await;
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(SwitchWorkspace)
+            .request(QueryWorkspaceRequest::new(None))
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::WorkspaceIdInvalid.value()
+    )
+}
+
 // TODO 1) delete workspace, but can't delete the last workspace
+
+#[tokio::test]
+async fn workspace_rename_merge_policy_default() {
+    let test = WorkspaceTest::new().await;
+    let setting = read_rename_merge_policy(&test.sdk).await;
+    assert_eq!(setting.policy, RenameMergePolicy::LatestWins);
+}
+
+#[tokio::test]
+async fn workspace_update_rename_merge_policy() {
+    let test = WorkspaceTest::new().await;
+    update_rename_merge_policy(&test.sdk, RenameMergePolicy::AppendSuffix).await;
+    let setting = read_rename_merge_policy(&test.sdk).await;
+    assert_eq!(setting.policy, RenameMergePolicy::AppendSuffix);
+}
+
+#[tokio::test]
+async fn workspace_share_with_invalid_email() {
+    let test = WorkspaceTest::new().await;
+    for email in vec!["", "annie", "@appflowy.io", "annie@"] {
+        let request = ShareWorkspaceRequest {
+            workspace_id: test.workspace.id.clone(),
+            email: email.to_owned(),
+            role: WorkspaceRole::Editor,
+        };
+        assert_eq!(
+            FlowyWorkspaceTest::new(test.sdk.clone())
+                .event(ShareWorkspace)
+                .request(request)
+                .async_send()
+                .await
+                .error()
+                .code,
+            ErrorCode::MemberEmailInvalid.value()
+        )
+    }
+}
+
+#[tokio::test]
+async fn workspace_share_with_owner_role() {
+    let test = WorkspaceTest::new().await;
+    let request = ShareWorkspaceRequest {
+        workspace_id: test.workspace.id.clone(),
+        email: "nathan@appflowy.io".to_owned(),
+        role: WorkspaceRole::Owner,
+    };
+    assert_eq!(
+        FlowyWorkspaceTest::new(test.sdk.clone())
+            .event(ShareWorkspace)
+            .request(request)
+            .async_send()
+            .await
+            .error()
+            .code,
+        ErrorCode::MemberRoleInvalid.value()
+    )
+}
+
+// The guest account that has a workspace, which the current user doesn't have, with a doc in it
+async fn guest_account() -> (FlowyTest, String, View) {
+    let guest = FlowyTest::setup();
+    let user = guest.init_user().await;
+    let workspace = create_workspace(&guest.sdk, "Guest notes", "").await;
+    open_workspace(&guest.sdk, &workspace.id).await;
+    let app = create_app(&guest.sdk, "Journal", "", &workspace.id).await;
+    let view = create_view(&guest.sdk, &app.id).await;
+    let _ = import_markdown(&guest.sdk, &view.id, "# Monday\nwrite the merge flow").await;
+    (guest, user.id, view)
+}
+
+#[tokio::test]
+async fn workspace_preview_account_merge() {
+    let (_guest, guest_user_id, _) = guest_account().await;
+    let test = WorkspaceTest::new().await;
+    let report = preview_account_merge(&test.sdk, &guest_user_id).await;
+    assert!(!report.is_applied);
+    assert_eq!(report.source_user_id, guest_user_id);
+
+    let item = report.workspaces.iter().find(|item| item.name == "Guest notes").unwrap();
+    assert!(item.target_id.is_empty());
+    assert_eq!(item.app_count, 1);
+    assert_eq!(item.view_count, 1);
+    assert!(report
+        .workspaces
+        .iter()
+        .filter(|item| item.name == "Workspace")
+        .all(|item| !item.target_id.is_empty()));
+
+    // Nothing is changed until the merge is applied
+    assert_eq!(query_workspaces(&test.sdk).await.items.len(), 2);
+}
+
+#[tokio::test]
+async fn workspace_merge_account() {
+    let (guest, guest_user_id, view) = guest_account().await;
+    let _ = toggle_favorite(&guest.sdk, &view.id).await;
+    update_rename_merge_policy(&guest.sdk, RenameMergePolicy::AppendSuffix).await;
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    let guest_doc = open_view(&guest.sdk, request).await;
+
+    let test = WorkspaceTest::new().await;
+    let report = merge_account(&test.sdk, &guest_user_id).await;
+    assert!(report.is_applied);
+    assert_eq!(report.favorite_count, 1);
+    assert!(report.settings.contains(&"rename_merge_policy".to_owned()));
+
+    // The workspace is created with new ids and the current workspace is kept
+    let items = query_workspaces(&test.sdk).await.items;
+    assert_eq!(items.len(), 3);
+    assert!(items.iter().any(|item| item.name == "Guest notes" && !item.is_current));
+    assert_eq!(read_current_workspace(&test.sdk).await.workspace.id, test.workspace.id);
+
+    let favorite = read_favorites(&test.sdk).await.items.pop().unwrap();
+    assert_eq!(favorite.view.name, view.name);
+    assert_ne!(favorite.view.id, view.id);
+    let request = QueryViewRequest {
+        view_ids: vec![favorite.view.id.clone()],
+    };
+    assert_eq!(open_view(&test.sdk, request).await.data, guest_doc.data);
+
+    let setting = read_rename_merge_policy(&test.sdk).await;
+    assert_eq!(setting.policy, RenameMergePolicy::AppendSuffix);
+}
+
+#[tokio::test]
+async fn workspace_merge_account_with_invalid_source() {
+    let test = FlowyTest::setup();
+    let user = test.init_user().await;
+    for source_user_id in vec!["".to_owned(), user.id, "not_signed_in_user".to_owned()] {
+        assert_eq!(
+            FlowyWorkspaceTest::new(test.sdk.clone())
+                .event(MergeAccount)
+                .request(MergeAccountRequest { source_user_id })
+                .async_send()
+                .await
+                .error()
+                .code,
+            ErrorCode::MergeSourceInvalid.value()
+        )
+    }
+}
+
+#[tokio::test]
+async fn workspace_archived_rejects_local_changes() {
+    let test = FlowyTest::setup();
+    let _ = test.init_user().await;
+    let test = ViewTest::new(&test).await;
+    archive_workspace(&test.sdk, &test.workspace.id, true).await;
+
+    let request = CreateAppRequest {
+        workspace_id: test.workspace.id.clone(),
+        name: "App B".to_owned(),
+        desc: "".to_owned(),
+        color_style: Default::default(),
+    };
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(CreateApp)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceArchived.value());
+
+    let request = UpdateViewRequest {
+        view_id: test.view.id.clone(),
+        name: Some("Renamed View".to_owned()),
+        desc: None,
+        thumbnail: None,
+    };
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(UpdateView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceArchived.value());
+
+    let request = CreateViewRequest {
+        belong_to_id: test.view.id.clone(),
+        name: "Nested View".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+    };
+    let error = FlowyWorkspaceTest::new(test.sdk.clone())
+        .event(CreateView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::WorkspaceArchived.value());
+
+    archive_workspace(&test.sdk, &test.workspace.id, false).await;
+    let app = create_app(&test.sdk, "App B", "", &test.workspace.id).await;
+    assert_eq!(app.workspace_id, test.workspace.id);
+}
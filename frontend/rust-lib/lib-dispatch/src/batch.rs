@@ -0,0 +1,203 @@
+use crate::{
+    dispatch::{DispatchContext, DispatchService},
+    errors::DispatchError,
+    module::{ModuleRequest, Undo},
+    response::{EventResponse, StatusCode},
+    service::Service,
+};
+
+// The events of the batch are executed one by one. If the batch is transactional, either all of them succeed
+// or the remaining ones are aborted after the first failure and the ones that succeeded are undone by their
+// compensating requests, in the reverse order. Otherwise all the events are executed whether they fail or not.
+//
+// The events are undone by the compensating requests instead of a sqlite savepoint, because each event runs on
+// its own connection of the pool and may have been synced to the server already. So every event of the
+// transactional batch must be compensated or marked as read only by its module, the batch that has the other
+// events is rejected before any of them runs.
+#[derive(Debug, Clone)]
+pub struct BatchRequest {
+    pub id: String,
+    pub requests: Vec<ModuleRequest>,
+    pub transactional: bool,
+}
+
+impl BatchRequest {
+    pub fn new() -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            requests: vec![],
+            transactional: true,
+        }
+    }
+
+    pub fn request(mut self, request: ModuleRequest) -> Self {
+        self.requests.push(request);
+        self
+    }
+
+    pub fn transactional(mut self, transactional: bool) -> Self {
+        self.transactional = transactional;
+        self
+    }
+}
+
+impl std::convert::From<Vec<ModuleRequest>> for BatchRequest {
+    fn from(requests: Vec<ModuleRequest>) -> Self {
+        Self {
+            requests,
+            ..BatchRequest::new()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchResponse {
+    // The responses of the executed events in the order of the requests. The last one is the failed event if
+    // the batch was rolled back.
+    pub responses: Vec<EventResponse>,
+    // The status of the first failed event, or Ok if all of them succeeded
+    pub status_code: StatusCode,
+    // True if the batch failed and all the executed events were undone
+    pub rolled_back: bool,
+}
+
+impl BatchResponse {
+    pub fn is_ok(&self) -> bool { self.status_code == StatusCode::Ok }
+}
+
+pub(crate) async fn run_batch(service: DispatchService, batch: BatchRequest) -> BatchResponse {
+    if !batch.transactional {
+        return run_all(service, batch).await;
+    }
+
+    let mut undos = vec![];
+    for request in &batch.requests {
+        match service.module_map.get(&request.event).and_then(|module| module.undo(&request.event)) {
+            Some(undo) => undos.push(undo),
+            None => {
+                let msg = format!("{:?} can't be undone, it can't be put in a transactional batch", request.event);
+                let response: EventResponse = DispatchError::from(msg).into();
+                return BatchResponse {
+                    status_code: response.status_code.clone(),
+                    responses: vec![response],
+                    rolled_back: false,
+                };
+            },
+        }
+    }
+
+    let mut responses = vec![];
+    let mut undo_stack: Vec<Vec<ModuleRequest>> = vec![];
+    let mut can_undo = true;
+    for (request, undo) in batch.requests.into_iter().zip(undos) {
+        let event = request.event.clone();
+        let result = match read_state(&service, &request, &undo).await {
+            Ok(state) => {
+                let response = call(&service, request.clone()).await;
+                match response.status_code {
+                    StatusCode::Ok => Ok((response, state)),
+                    _ => Err(response),
+                }
+            },
+            Err(response) => Err(response),
+        };
+
+        match result {
+            Ok((response, state)) => {
+                let compensation = match (&undo, &state) {
+                    (Undo::WithResponse(f), _) => f(&request, &response),
+                    (Undo::WithState(_, f), Some(state)) => f(&request, state),
+                    _ => Ok(vec![]),
+                };
+                match compensation {
+                    Ok(requests) => undo_stack.push(requests),
+                    Err(e) => {
+                        log::error!("Batch {} can't undo {:?}: {:?}", batch.id, event, e);
+                        can_undo = false;
+                    },
+                }
+                responses.push(response);
+            },
+            Err(response) => {
+                let status_code = response.status_code.clone();
+                tracing::debug!("Batch {} failed at {:?}, rolling back", batch.id, event);
+                responses.push(response);
+                let rolled_back = rollback(&service, undo_stack).await && can_undo;
+                return BatchResponse {
+                    responses,
+                    status_code,
+                    rolled_back,
+                };
+            },
+        }
+    }
+
+    BatchResponse {
+        responses,
+        status_code: StatusCode::Ok,
+        rolled_back: false,
+    }
+}
+
+// Returns the state that the event is going to change, or the failed response of the read request
+async fn read_state(
+    service: &DispatchService,
+    request: &ModuleRequest,
+    undo: &Undo,
+) -> Result<Option<EventResponse>, EventResponse> {
+    let read = match undo {
+        Undo::WithState(read, _) => read,
+        _ => return Ok(None),
+    };
+    let read_request = read(request).map_err(|e| e.into())?;
+    let response = call(service, read_request).await;
+    match response.status_code {
+        StatusCode::Ok => Ok(Some(response)),
+        _ => Err(response),
+    }
+}
+
+async fn run_all(service: DispatchService, batch: BatchRequest) -> BatchResponse {
+    let mut responses = vec![];
+    let mut status_code = StatusCode::Ok;
+    for request in batch.requests {
+        let event = request.event.clone();
+        let response = call(&service, request).await;
+        if response.status_code != StatusCode::Ok && status_code == StatusCode::Ok {
+            tracing::debug!("Batch {} failed at {:?}, continuing", batch.id, event);
+            status_code = response.status_code.clone();
+        }
+        responses.push(response);
+    }
+
+    BatchResponse {
+        responses,
+        status_code,
+        rolled_back: false,
+    }
+}
+
+// The compensating request that fails is logged and skipped, the rest are still executed. Returns false if
+// any of them failed.
+async fn rollback(service: &DispatchService, undo_stack: Vec<Vec<ModuleRequest>>) -> bool {
+    let mut is_ok = true;
+    for requests in undo_stack.into_iter().rev() {
+        for request in requests {
+            let event = request.event.clone();
+            let response = call(service, request).await;
+            if response.status_code != StatusCode::Ok {
+                log::error!("Compensating request {:?} failed: {}", event, response);
+                is_ok = false;
+            }
+        }
+    }
+    is_ok
+}
+
+async fn call(service: &DispatchService, request: ModuleRequest) -> EventResponse {
+    let ctx = DispatchContext {
+        request,
+        callback: None,
+    };
+    service.call(ctx).await.unwrap_or_else(|e| e.into())
+}
@@ -0,0 +1,102 @@
+use crate::errors::{DispatchError, InternalError};
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::Semaphore;
+
+pub const DEFAULT_BLOCKING_POOL_SIZE: usize = 4;
+
+// The task that waits longer than this for a free thread is counted as starved
+const STARVATION_THRESHOLD: Duration = Duration::from_millis(100);
+
+// Runs the blocking work, e.g. the sqlite queries or the compression, off the async executor. At most
+// `size` tasks run at the same time, the rest wait for a free thread, so the blocking work can't take up
+// all the threads of the runtime. Every module receives the pool as its data, see Unit<BlockingPool>. The
+// task runs on the blocking threads of the runtime that spawns it.
+#[derive(Clone)]
+pub struct BlockingPool {
+    permits: Arc<Semaphore>,
+    size: usize,
+    metrics: Arc<Metrics>,
+}
+
+#[derive(Default)]
+struct Metrics {
+    waiting: AtomicUsize,
+    running: AtomicUsize,
+    completed: AtomicU64,
+    starved: AtomicU64,
+    max_wait_micros: AtomicU64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlockingPoolMetrics {
+    pub size: usize,
+    pub waiting: usize,
+    pub running: usize,
+    pub completed: u64,
+    pub starved: u64,
+    pub max_wait: Duration,
+}
+
+impl BlockingPool {
+    pub fn new(size: usize) -> Self {
+        let size = size.max(1);
+        Self {
+            permits: Arc::new(Semaphore::new(size)),
+            size,
+            metrics: Arc::new(Metrics::default()),
+        }
+    }
+
+    pub async fn spawn<F, R>(&self, f: F) -> Result<R, DispatchError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let metrics = self.metrics.clone();
+        let instant = Instant::now();
+        metrics.waiting.fetch_add(1, Ordering::SeqCst);
+        let permit = self.permits.clone().acquire_owned().await;
+        metrics.waiting.fetch_sub(1, Ordering::SeqCst);
+        let permit = permit.map_err(|e| InternalError::Other(format!("{:?}", e)))?;
+
+        let wait = instant.elapsed();
+        metrics.max_wait_micros.fetch_max(wait.as_micros() as u64, Ordering::SeqCst);
+        if wait > STARVATION_THRESHOLD {
+            metrics.starved.fetch_add(1, Ordering::SeqCst);
+            tracing::warn!("Blocking task waited {:?} for a free thread", wait);
+        }
+
+        metrics.running.fetch_add(1, Ordering::SeqCst);
+        let cloned_metrics = metrics.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let output = f();
+            drop(permit);
+            cloned_metrics.running.fetch_sub(1, Ordering::SeqCst);
+            cloned_metrics.completed.fetch_add(1, Ordering::SeqCst);
+            output
+        })
+        .await;
+
+        result.map_err(|e| {
+            metrics.running.fetch_sub(1, Ordering::SeqCst);
+            InternalError::from(e).into()
+        })
+    }
+
+    pub fn metrics(&self) -> BlockingPoolMetrics {
+        BlockingPoolMetrics {
+            size: self.size,
+            waiting: self.metrics.waiting.load(Ordering::SeqCst),
+            running: self.metrics.running.load(Ordering::SeqCst),
+            completed: self.metrics.completed.load(Ordering::SeqCst),
+            starved: self.metrics.starved.load(Ordering::SeqCst),
+            max_wait: Duration::from_micros(self.metrics.max_wait_micros.load(Ordering::SeqCst)),
+        }
+    }
+}
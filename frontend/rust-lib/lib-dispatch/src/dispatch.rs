@@ -1,7 +1,10 @@
 use crate::{
+    batch::{run_batch, BatchRequest, BatchResponse},
+    blocking::{BlockingPool, DEFAULT_BLOCKING_POOL_SIZE},
     errors::{DispatchError, Error, InternalError},
-    module::{as_module_map, Module, ModuleMap, ModuleRequest},
-    response::EventResponse,
+    module::{as_module_map, Module, ModuleMap, ModuleRequest, ScopeProvider},
+    request::with_request_id,
+    response::{EventResponse, StatusCode},
     service::{Service, ServiceFactory},
     util::tokio_default_runtime,
 };
@@ -9,27 +12,84 @@ use derivative::*;
 use futures_core::future::BoxFuture;
 use futures_util::task::Context;
 use pin_project::pin_project;
-use std::{future::Future, sync::Arc};
+use std::{future::Future, sync::Arc, time::Duration};
+use tracing::Instrument;
 use tokio::macros::support::{Pin, Poll};
 pub struct EventDispatch {
     module_map: ModuleMap,
+    scope_provider: Option<Arc<dyn ScopeProvider>>,
+    blocking_pool: BlockingPool,
+    request_timeout: Option<Duration>,
     runtime: tokio::runtime::Runtime,
 }
 
+#[derive(Debug, Clone)]
+pub struct DispatchConfig {
+    pub blocking_pool_size: usize,
+    // The requests that don't finish within the timeout get the StatusCode::Timeout response. None
+    // waits for the handler forever, ModuleRequest::timeout overrides it.
+    pub request_timeout: Option<Duration>,
+}
+
+impl std::default::Default for DispatchConfig {
+    fn default() -> Self {
+        DispatchConfig {
+            blocking_pool_size: DEFAULT_BLOCKING_POOL_SIZE,
+            request_timeout: None,
+        }
+    }
+}
+
 impl EventDispatch {
     pub fn construct<F>(module_factory: F) -> EventDispatch
+    where
+        F: FnOnce() -> Vec<Module>,
+    {
+        EventDispatch::construct_with_config(DispatchConfig::default(), module_factory)
+    }
+
+    pub fn construct_with_config<F>(config: DispatchConfig, module_factory: F) -> EventDispatch
+    where
+        F: FnOnce() -> Vec<Module>,
+    {
+        let blocking_pool = BlockingPool::new(config.blocking_pool_size);
+        EventDispatch::construct_with_pool(config, blocking_pool, module_factory)
+    }
+
+    // The pool is shared with the blocking work that runs outside the handlers, e.g. the writes of the
+    // revisions. The blocking_pool_size of the config is ignored.
+    pub fn construct_with_pool<F>(
+        config: DispatchConfig,
+        blocking_pool: BlockingPool,
+        module_factory: F,
+    ) -> EventDispatch
     where
         F: FnOnce() -> Vec<Module>,
     {
         let runtime = tokio_default_runtime().unwrap();
-        let modules = module_factory();
+        let modules = module_factory()
+            .into_iter()
+            .map(|module| module.data(blocking_pool.clone()))
+            .collect::<Vec<Module>>();
         tracing::trace!("{}", module_info(&modules));
         let module_map = as_module_map(modules);
 
-        let dispatch = EventDispatch { module_map, runtime };
+        let dispatch = EventDispatch {
+            module_map,
+            scope_provider: None,
+            blocking_pool,
+            request_timeout: config.request_timeout,
+            runtime,
+        };
         dispatch
     }
 
+    // The scopes of the caller are checked against the guard tables of the modules
+    pub fn scope_provider(mut self, provider: Arc<dyn ScopeProvider>) -> Self {
+        self.scope_provider = Some(provider);
+        self
+    }
+
     pub fn async_send<Req>(dispatch: Arc<EventDispatch>, request: Req) -> DispatchFuture<EventResponse>
     where
         Req: std::convert::Into<ModuleRequest>,
@@ -48,7 +108,12 @@ impl EventDispatch {
     {
         let request: ModuleRequest = request.into();
         let module_map = dispatch.module_map.clone();
-        let service = Box::new(DispatchService { module_map });
+        let scope_provider = dispatch.scope_provider.clone();
+        let service = Box::new(DispatchService {
+            module_map,
+            scope_provider,
+            request_timeout: dispatch.request_timeout,
+        });
         tracing::trace!("Async event: {:?}", &request.event);
         let service_ctx = DispatchContext {
             request,
@@ -71,12 +136,41 @@ impl EventDispatch {
         }
     }
 
+    pub fn async_send_batch<Req>(dispatch: Arc<EventDispatch>, batch: Req) -> DispatchFuture<BatchResponse>
+    where
+        Req: std::convert::Into<BatchRequest>,
+    {
+        let batch: BatchRequest = batch.into();
+        let service = DispatchService {
+            module_map: dispatch.module_map.clone(),
+            scope_provider: dispatch.scope_provider.clone(),
+            request_timeout: dispatch.request_timeout,
+        };
+        tracing::trace!("Async batch: {} with {} events", &batch.id, batch.requests.len());
+        let join_handle = dispatch.runtime.spawn(run_batch(service, batch));
+
+        DispatchFuture {
+            fut: Box::pin(async move {
+                join_handle.await.unwrap_or_else(|e| {
+                    let error = InternalError::JoinError(format!("EVENT_DISPATCH join error: {:?}", e));
+                    BatchResponse {
+                        responses: vec![error.as_response()],
+                        status_code: StatusCode::Internal,
+                        rolled_back: false,
+                    }
+                })
+            }),
+        }
+    }
+
     pub fn sync_send(dispatch: Arc<EventDispatch>, request: ModuleRequest) -> EventResponse {
         futures::executor::block_on(async {
             EventDispatch::async_send_with_callback(dispatch, request, |_| Box::pin(async {})).await
         })
     }
 
+    pub fn blocking_pool(&self) -> BlockingPool { self.blocking_pool.clone() }
+
     pub fn spawn<F>(&self, f: F)
     where
         F: Future<Output = ()> + Send + 'static,
@@ -124,6 +218,8 @@ impl DispatchContext {
 
 pub(crate) struct DispatchService {
     pub(crate) module_map: ModuleMap,
+    pub(crate) scope_provider: Option<Arc<dyn ScopeProvider>>,
+    pub(crate) request_timeout: Option<Duration>,
 }
 
 impl Service<DispatchContext> for DispatchService {
@@ -137,16 +233,44 @@ impl Service<DispatchContext> for DispatchService {
     )]
     fn call(&self, ctx: DispatchContext) -> Self::Future {
         let module_map = self.module_map.clone();
+        let scope_provider = self.scope_provider.clone();
+        let request_timeout = self.request_timeout;
         let (request, callback) = ctx.into_parts();
+        let request_id = request.id.clone();
+        let span = tracing::debug_span!("event", request_id = %request_id, event = ?request.event);
 
-        Box::pin(async move {
+        let cloned_request_id = request_id.clone();
+        let fut = async move {
             let result = {
                 // print_module_map_info(&module_map);
                 match module_map.get(&request.event) {
-                    Some(module) => {
-                        let fut = module.new_service(());
-                        let service_fut = fut.await?.call(request);
-                        service_fut.await
+                    Some(module) => match module.check_scopes(&request.event, scope_provider.as_deref()) {
+                        Ok(_) => {
+                            let timeout = request.timeout.or(request_timeout);
+                            let cancellation_token = request.cancellation_token.clone();
+                            let event = request.event.clone();
+                            let service_fut = async {
+                                let service = module.new_service(()).await?;
+                                service.call(request).await
+                            };
+                            match timeout {
+                                None => service_fut.await,
+                                Some(timeout) => match tokio::time::timeout(timeout, service_fut).await {
+                                    Ok(result) => result,
+                                    Err(_) => {
+                                        // The handler future is dropped here
+                                        cancellation_token.cancel();
+                                        let msg = format!("{:?} timed out after {:?}", event, timeout);
+                                        log::warn!("{}", msg);
+                                        Err(InternalError::Timeout(msg).into())
+                                    },
+                                },
+                            }
+                        },
+                        Err(e) => {
+                            log::warn!("{}", e);
+                            Err(e)
+                        },
                     },
                     None => {
                         let msg = format!("Can not find the event handler. {:?}", request);
@@ -156,14 +280,17 @@ impl Service<DispatchContext> for DispatchService {
                 }
             };
 
-            let response = result.unwrap_or_else(|e| e.into());
+            let mut response = result.unwrap_or_else(|e| e.into());
+            response.request_id = cloned_request_id;
             tracing::trace!("Dispatch result: {:?}", response);
             if let Some(callback) = callback {
                 callback(response.clone()).await;
             }
 
             Ok(response)
-        })
+        };
+
+        Box::pin(with_request_id(request_id, fut.instrument(span)))
     }
 }
 
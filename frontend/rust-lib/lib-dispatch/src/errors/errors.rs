@@ -84,6 +84,8 @@ pub(crate) enum InternalError {
     JoinError(String),
     ServiceNotFound(String),
     HandleNotFound(String),
+    PermissionDenied(String),
+    Timeout(String),
     Other(String),
 }
 
@@ -96,6 +98,8 @@ impl fmt::Display for InternalError {
             InternalError::JoinError(s) => fmt::Display::fmt(&s, f),
             InternalError::ServiceNotFound(s) => fmt::Display::fmt(&s, f),
             InternalError::HandleNotFound(s) => fmt::Display::fmt(&s, f),
+            InternalError::PermissionDenied(s) => fmt::Display::fmt(&s, f),
+            InternalError::Timeout(s) => fmt::Display::fmt(&s, f),
             InternalError::Other(s) => fmt::Display::fmt(&s, f),
         }
     }
@@ -104,7 +108,10 @@ impl fmt::Display for InternalError {
 impl Error for InternalError {
     fn as_response(&self) -> EventResponse {
         let error = format!("{}", self).into_bytes();
-        ResponseBuilder::Internal().data(error).build()
+        match self {
+            InternalError::Timeout(_) => ResponseBuilder::Timeout().data(error).build(),
+            _ => ResponseBuilder::Internal().data(error).build(),
+        }
     }
 }
 
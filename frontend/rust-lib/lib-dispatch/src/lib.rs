@@ -5,6 +5,8 @@ mod response;
 mod service;
 mod util;
 
+mod batch;
+mod blocking;
 mod byte_trait;
 mod data;
 mod dispatch;
@@ -16,5 +18,5 @@ pub mod macros;
 pub use errors::Error;
 
 pub mod prelude {
-    pub use crate::{byte_trait::*, data::*, dispatch::*, errors::*, module::*, request::*, response::*};
+    pub use crate::{batch::*, blocking::*, byte_trait::*, data::*, dispatch::*, errors::*, module::*, request::*, response::*};
 }
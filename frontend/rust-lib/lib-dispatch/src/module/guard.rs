@@ -0,0 +1,56 @@
+use crate::{
+    errors::{DispatchError, InternalError},
+    module::Event,
+};
+use std::collections::HashMap;
+
+// The scopes that the caller needs for each event of the module. It's generated by Flowy_Event from the
+// annotations of the event enum, see the event_scope attribute.
+#[derive(Debug, Clone, Default)]
+pub struct EventGuardTable(HashMap<Event, Vec<&'static str>>);
+
+impl EventGuardTable {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn insert<E>(&mut self, event: E, scopes: &[&'static str])
+    where
+        E: Into<Event>,
+    {
+        self.0.insert(event.into(), scopes.to_vec());
+    }
+
+    pub fn required_scopes(&self, event: &Event) -> Option<&[&'static str]> {
+        self.0.get(event).map(|scopes| scopes.as_slice())
+    }
+}
+
+pub trait ScopeProvider: Send + Sync {
+    fn has_scope(&self, scope: &str) -> bool;
+}
+
+// The event that is registered without being in the table is rejected as well, so the handler that is
+// added later can't skip the check by accident.
+pub(crate) fn check_scopes(
+    table: &EventGuardTable,
+    event: &Event,
+    provider: Option<&dyn ScopeProvider>,
+) -> Result<(), DispatchError> {
+    let scopes = match table.required_scopes(event) {
+        None => {
+            let msg = format!("{:?} is not in the guard table", event);
+            return Err(InternalError::PermissionDenied(msg).into());
+        },
+        Some(scopes) => scopes,
+    };
+
+    match scopes
+        .iter()
+        .find(|scope| !provider.map_or(false, |provider| provider.has_scope(scope)))
+    {
+        None => Ok(()),
+        Some(scope) => {
+            let msg = format!("{:?} requires the {} scope", event, scope);
+            Err(InternalError::PermissionDenied(msg).into())
+        },
+    }
+}
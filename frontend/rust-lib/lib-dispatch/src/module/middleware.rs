@@ -0,0 +1,59 @@
+use crate::{
+    module::{call_handler, Event, EventServiceFactory, ModuleDataMap},
+    request::{CancellationToken, Payload},
+    response::EventResponse,
+};
+use futures_core::future::BoxFuture;
+use std::{collections::HashMap, future::Future, sync::Arc};
+
+pub(crate) type Middleware = Arc<dyn Fn(DispatchRequest, Next) -> BoxFuture<'static, EventResponse> + Send + Sync>;
+
+pub(crate) fn as_middleware<F, Fut>(f: F) -> Middleware
+where
+    F: Fn(DispatchRequest, Next) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = EventResponse> + Send + 'static,
+{
+    Arc::new(move |request, next| Box::pin(f(request, next)))
+}
+
+// The request that is passed through the middlewares of the module before it reaches the handler. The
+// middleware can read or replace the payload, e.g. to validate it.
+pub struct DispatchRequest {
+    pub id: String,
+    pub event: Event,
+    pub payload: Payload,
+    pub cancellation_token: CancellationToken,
+    pub(crate) module_data: Arc<ModuleDataMap>,
+}
+
+impl DispatchRequest {
+    pub fn module_data<T: 'static>(&self) -> Option<&T>
+    where
+        T: Send + Sync,
+    {
+        self.module_data.get::<T>()
+    }
+}
+
+// Calls the rest of the middlewares and then the handler. The middleware that doesn't call it stops the
+// request, the response it returns is sent back instead, e.g. when the auth check fails.
+pub struct Next {
+    pub(crate) middlewares: Arc<Vec<Middleware>>,
+    pub(crate) index: usize,
+    pub(crate) service_map: Arc<HashMap<Event, EventServiceFactory>>,
+}
+
+impl Next {
+    pub fn run(self, request: DispatchRequest) -> BoxFuture<'static, EventResponse> {
+        match self.middlewares.get(self.index).cloned() {
+            Some(middleware) => {
+                let next = Next {
+                    index: self.index + 1,
+                    ..self
+                };
+                middleware(request, next)
+            },
+            None => call_handler(&self.service_map, request),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 pub use container::*;
 pub use data::*;
+pub use guard::*;
+pub use middleware::*;
 pub use module::*;
 
 mod container;
 mod data;
+mod guard;
+mod middleware;
 mod module;
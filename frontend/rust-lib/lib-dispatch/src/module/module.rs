@@ -4,17 +4,19 @@ use std::{
     fmt::{Debug, Display},
     future::Future,
     hash::Hash,
-    pin::Pin,
-    task::{Context, Poll},
+    time::Duration,
 };
 
-use futures_core::ready;
-use pin_project::pin_project;
-
 use crate::{
-    errors::{DispatchError, InternalError},
-    module::{container::ModuleDataMap, Unit},
-    request::{payload::Payload, EventRequest, FromRequest},
+    byte_trait::FromBytes,
+    errors::{DispatchError, Error, InternalError},
+    module::{
+        container::ModuleDataMap,
+        guard::{check_scopes, EventGuardTable, ScopeProvider},
+        middleware::{as_middleware, DispatchRequest, Middleware, Next},
+        Unit,
+    },
+    request::{payload::Payload, CancellationToken, EventRequest, FromRequest},
     response::{EventResponse, Responder},
     service::{
         factory,
@@ -51,12 +53,38 @@ impl<T: Display + Eq + Hash + Debug + Clone> std::convert::From<T> for Event {
     fn from(t: T) -> Self { Event(format!("{}", t)) }
 }
 
+impl Event {
+    pub fn name(&self) -> &str { &self.0 }
+}
+
 pub type EventServiceFactory = BoxServiceFactory<(), ServiceRequest, ServiceResponse, DispatchError>;
 
+// Returns the requests that undo the event, it's called with the request and the successful response of
+// the event when the batch that contains the event fails. The compensation of compensate_with_state is called
+// with the response of the read request instead, i.e. the state before the event changed it.
+pub type Compensation =
+    Arc<dyn Fn(&ModuleRequest, &EventResponse) -> Result<Vec<ModuleRequest>, DispatchError> + Send + Sync>;
+
+// Returns the request that reads the state that the event is going to change
+pub type StateReader = Arc<dyn Fn(&ModuleRequest) -> Result<ModuleRequest, DispatchError> + Send + Sync>;
+
+// How the event is undone when the transactional batch that contains it fails. The events without it can't
+// be put in a transactional batch.
+#[derive(Clone)]
+pub(crate) enum Undo {
+    // The event doesn't change anything, e.g. the read events
+    Nothing,
+    WithResponse(Compensation),
+    WithState(StateReader, Compensation),
+}
+
 pub struct Module {
     pub name: String,
     module_data: Arc<ModuleDataMap>,
     service_map: Arc<HashMap<Event, EventServiceFactory>>,
+    guard_table: Option<EventGuardTable>,
+    compensations: HashMap<Event, Undo>,
+    middlewares: Arc<Vec<Middleware>>,
 }
 
 impl Module {
@@ -65,6 +93,9 @@ impl Module {
             name: "".to_owned(),
             module_data: Arc::new(ModuleDataMap::new()),
             service_map: Arc::new(HashMap::new()),
+            guard_table: None,
+            compensations: HashMap::new(),
+            middlewares: Arc::new(vec![]),
         }
     }
 
@@ -99,7 +130,61 @@ impl Module {
         self
     }
 
+    pub fn guard_table(mut self, table: EventGuardTable) -> Self {
+        self.guard_table = Some(table);
+        self
+    }
+
+    pub fn compensate<E, F>(mut self, event: E, f: F) -> Self
+    where
+        E: Into<Event>,
+        F: Fn(&ModuleRequest, &EventResponse) -> Result<Vec<ModuleRequest>, DispatchError> + Send + Sync + 'static,
+    {
+        self.compensations.insert(event.into(), Undo::WithResponse(Arc::new(f)));
+        self
+    }
+
+    // The read request runs before the event in the batch, e.g. to read the name of the app that the event
+    // renames, and its response is passed to the compensation. The event fails if the read request fails.
+    pub fn compensate_with_state<E, R, F>(mut self, event: E, read: R, f: F) -> Self
+    where
+        E: Into<Event>,
+        R: Fn(&ModuleRequest) -> Result<ModuleRequest, DispatchError> + Send + Sync + 'static,
+        F: Fn(&ModuleRequest, &EventResponse) -> Result<Vec<ModuleRequest>, DispatchError> + Send + Sync + 'static,
+    {
+        let undo = Undo::WithState(Arc::new(read), Arc::new(f));
+        self.compensations.insert(event.into(), undo);
+        self
+    }
+
+    // Marks the event that doesn't need to be undone, so it can be put in a transactional batch
+    pub fn read_only<E: Into<Event>>(mut self, event: E) -> Self {
+        self.compensations.insert(event.into(), Undo::Nothing);
+        self
+    }
+
+    // The middlewares run around the handlers of all the events of the module, in the order they are added.
+    // The first one sees the request first and the response last. They run after the scopes are checked.
+    pub fn middleware<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn(DispatchRequest, Next) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = EventResponse> + Send + 'static,
+    {
+        Arc::get_mut(&mut self.middlewares).unwrap().push(as_middleware(f));
+        self
+    }
+
     pub fn events(&self) -> Vec<Event> { self.service_map.keys().map(|key| key.clone()).collect::<Vec<_>>() }
+
+    // The module without the guard table lets every event through
+    pub(crate) fn check_scopes(&self, event: &Event, provider: Option<&dyn ScopeProvider>) -> Result<(), DispatchError> {
+        match &self.guard_table {
+            None => Ok(()),
+            Some(table) => check_scopes(table, event, provider),
+        }
+    }
+
+    pub(crate) fn undo(&self, event: &Event) -> Option<Undo> { self.compensations.get(event).cloned() }
 }
 
 #[derive(Debug, Clone)]
@@ -107,6 +192,8 @@ pub struct ModuleRequest {
     pub id: String,
     pub event: Event,
     pub(crate) payload: Payload,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) cancellation_token: CancellationToken,
 }
 
 impl ModuleRequest {
@@ -118,6 +205,8 @@ impl ModuleRequest {
             id: uuid::Uuid::new_v4().to_string(),
             event: event.into(),
             payload: Payload::None,
+            timeout: None,
+            cancellation_token: CancellationToken::new(),
         }
     }
 
@@ -128,6 +217,20 @@ impl ModuleRequest {
         self.payload = payload.into();
         self
     }
+
+    // Parses the payload, e.g. in the compensations that need to know what the event changed
+    pub fn parse_payload<T: FromBytes>(&self) -> Result<T, DispatchError> {
+        match &self.payload {
+            Payload::Bytes(bytes) => T::parse_from_bytes(bytes.clone()),
+            Payload::None => Err(InternalError::UnexpectedNone(format!("{:?} expected payload", self.event)).into()),
+        }
+    }
+
+    // Overrides the request_timeout of the DispatchConfig
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 }
 
 impl std::fmt::Display for ModuleRequest {
@@ -144,10 +247,12 @@ impl ServiceFactory<ModuleRequest> for Module {
     fn new_service(&self, _cfg: Self::Context) -> Self::Future {
         let service_map = self.service_map.clone();
         let module_data = self.module_data.clone();
+        let middlewares = self.middlewares.clone();
         Box::pin(async move {
             let service = ModuleService {
                 service_map,
                 module_data,
+                middlewares,
             };
             let module_service = Box::new(service) as Self::Service;
             Ok(module_service)
@@ -158,6 +263,7 @@ impl ServiceFactory<ModuleRequest> for Module {
 pub struct ModuleService {
     service_map: Arc<HashMap<Event, EventServiceFactory>>,
     module_data: Arc<ModuleDataMap>,
+    middlewares: Arc<Vec<Middleware>>,
 }
 
 impl Service<ModuleRequest> for ModuleService {
@@ -166,44 +272,67 @@ impl Service<ModuleRequest> for ModuleService {
     type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
 
     fn call(&self, request: ModuleRequest) -> Self::Future {
-        let ModuleRequest { id, event, payload } = request;
-        let module_data = self.module_data.clone();
-        let request = EventRequest::new(id.clone(), event, module_data);
-
-        match self.service_map.get(&request.event) {
-            Some(factory) => {
-                let service_fut = factory.new_service(());
-                let fut = ModuleServiceFuture {
-                    fut: Box::pin(async {
-                        let service = service_fut.await?;
-                        let service_req = ServiceRequest::new(request, payload);
-                        service.call(service_req).await
-                    }),
-                };
-                Box::pin(async move { Ok(fut.await.unwrap_or_else(|e| e.into())) })
-            },
-            None => {
-                let msg = format!("Can not find service factory for event: {:?}", request.event);
-                Box::pin(async { Err(InternalError::ServiceNotFound(msg).into()) })
-            },
-        }
+        let ModuleRequest {
+            id,
+            event,
+            payload,
+            cancellation_token,
+            ..
+        } = request;
+        let request = DispatchRequest {
+            id,
+            event,
+            payload,
+            cancellation_token,
+            module_data: self.module_data.clone(),
+        };
+        let next = Next {
+            middlewares: self.middlewares.clone(),
+            index: 0,
+            service_map: self.service_map.clone(),
+        };
+        let fut = next.run(request);
+        Box::pin(async move { Ok(fut.await) })
     }
 }
 
-#[pin_project]
-pub struct ModuleServiceFuture {
-    #[pin]
-    fut: BoxFuture<'static, Result<ServiceResponse, DispatchError>>,
-}
+// The last step of the middleware chain
+pub(crate) fn call_handler(
+    service_map: &HashMap<Event, EventServiceFactory>,
+    request: DispatchRequest,
+) -> BoxFuture<'static, EventResponse> {
+    let DispatchRequest {
+        id,
+        event,
+        payload,
+        cancellation_token,
+        module_data,
+    } = request;
+    let mut request = EventRequest::new(id, event, module_data);
+    request.cancellation_token = cancellation_token;
 
-impl Future for ModuleServiceFuture {
-    type Output = Result<EventResponse, DispatchError>;
+    match service_map.get(&request.event) {
+        Some(factory) => {
+            let service_fut = factory.new_service(());
+            Box::pin(async move {
+                let result: Result<ServiceResponse, DispatchError> = async {
+                    let service = service_fut.await?;
+                    let service_req = ServiceRequest::new(request, payload);
+                    service.call(service_req).await
+                }
+                .await;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        loop {
-            let (_, response) = ready!(self.as_mut().project().fut.poll(cx))?.into_parts();
-            return Poll::Ready(Ok(response));
-        }
+                match result {
+                    Ok(response) => response.into_parts().1,
+                    Err(e) => e.into(),
+                }
+            })
+        },
+        None => {
+            let msg = format!("Can not find service factory for event: {:?}", request.event);
+            let response = InternalError::ServiceNotFound(msg).as_response();
+            Box::pin(async { response })
+        },
     }
 }
 
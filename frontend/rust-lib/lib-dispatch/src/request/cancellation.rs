@@ -0,0 +1,64 @@
+use crate::{
+    errors::DispatchError,
+    request::{EventRequest, FromRequest, Payload},
+    util::ready::{ready, Ready},
+};
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::Notify;
+
+// It's cancelled when the request times out. The handler future is dropped at that point, but the tasks
+// that the handler spawned keep running, they can check the token to stop early.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancellationInner>,
+}
+
+#[derive(Default)]
+struct CancellationInner {
+    is_cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn cancel(&self) {
+        self.inner.is_cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool { self.inner.is_cancelled.load(Ordering::SeqCst) }
+
+    // Completes when the token is cancelled, e.g. select it with the work of the spawned task
+    pub async fn cancelled(&self) {
+        // The notified future is created before the check, so the cancel in between isn't missed
+        let notified = self.inner.notify.notified();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("is_cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl FromRequest for CancellationToken {
+    type Error = DispatchError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &EventRequest, _payload: &mut Payload) -> Self::Future {
+        ready(Ok(req.cancellation_token.clone()))
+    }
+}
@@ -1,5 +1,9 @@
+mod cancellation;
 pub mod payload;
 mod request;
+mod request_id;
 
+pub use cancellation::*;
 pub use payload::*;
 pub use request::*;
+pub use request_id::*;
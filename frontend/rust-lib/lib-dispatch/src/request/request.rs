@@ -3,7 +3,7 @@ use std::future::Future;
 use crate::{
     errors::{DispatchError, InternalError},
     module::{Event, ModuleDataMap},
-    request::payload::Payload,
+    request::{payload::Payload, CancellationToken},
     util::ready::{ready, Ready},
 };
 use derivative::*;
@@ -21,6 +21,7 @@ pub struct EventRequest {
     pub(crate) event: Event,
     #[derivative(Debug = "ignore")]
     pub(crate) module_data: Arc<ModuleDataMap>,
+    pub(crate) cancellation_token: CancellationToken,
 }
 
 impl EventRequest {
@@ -32,6 +33,7 @@ impl EventRequest {
             id,
             event: event.into(),
             module_data,
+            cancellation_token: CancellationToken::new(),
         }
     }
 
@@ -0,0 +1,16 @@
+use std::future::Future;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+// Returns the id of the event that is being dispatched. The futures that the handler awaits see the id,
+// but the tasks it spawns don't, pass the id to them if they need it.
+pub fn current_request_id() -> Option<String> { REQUEST_ID.try_with(|id| id.clone()).ok() }
+
+pub(crate) async fn with_request_id<F>(request_id: String, f: F) -> F::Output
+where
+    F: Future,
+{
+    REQUEST_ID.scope(request_id, f).await
+}
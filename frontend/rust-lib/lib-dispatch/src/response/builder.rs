@@ -32,10 +32,13 @@ impl ResponseBuilder {
         EventResponse {
             payload: self.payload,
             status_code: self.status,
+            request_id: "".to_owned(),
+            stream: None,
         }
     }
 
     static_response!(Ok, StatusCode::Ok);
     static_response!(Err, StatusCode::Err);
     static_response!(Internal, StatusCode::Internal);
+    static_response!(Timeout, StatusCode::Timeout);
 }
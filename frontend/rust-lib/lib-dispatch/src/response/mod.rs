@@ -1,7 +1,9 @@
 pub use builder::*;
 pub use responder::*;
 pub use response::*;
+pub use stream::*;
 
 mod builder;
 mod responder;
 mod response;
+mod stream;
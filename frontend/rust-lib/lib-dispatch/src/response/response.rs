@@ -3,7 +3,7 @@ use crate::{
     data::Data,
     errors::DispatchError,
     request::{EventRequest, Payload},
-    response::Responder,
+    response::{ChunkStream, Responder, ResponseStream},
 };
 use derivative::*;
 use std::{convert::TryFrom, fmt, fmt::Formatter};
@@ -13,6 +13,8 @@ pub enum StatusCode {
     Ok       = 0,
     Err      = 1,
     Internal = 2,
+    // The handler didn't finish within the timeout of the request
+    Timeout  = 3,
 }
 
 // serde user guide: https://serde.rs/field-attrs.html
@@ -21,6 +23,13 @@ pub struct EventResponse {
     #[derivative(Debug = "ignore")]
     pub payload: Payload,
     pub status_code: StatusCode,
+    // The id of the request that the response is for. It's the same id that is sent to the server in
+    // the request-id header, so the error can be matched to the server logs.
+    pub request_id: String,
+    // The chunks that follow the response if the handler returns the DataStream
+    #[serde(skip)]
+    #[derivative(Debug = "ignore")]
+    pub stream: Option<ResponseStream>,
 }
 
 impl EventResponse {
@@ -28,9 +37,14 @@ impl EventResponse {
         EventResponse {
             payload: Payload::None,
             status_code,
+            request_id: "".to_owned(),
+            stream: None,
         }
     }
 
+    // Returns None if the response isn't streamed or the stream was taken by another clone
+    pub fn take_stream(&self) -> Option<ChunkStream> { self.stream.as_ref().and_then(|stream| stream.take()) }
+
     pub fn parse<T, E>(self) -> Result<Result<T, E>, DispatchError>
     where
         T: FromBytes,
@@ -41,7 +55,7 @@ impl EventResponse {
                 let data = <Data<T>>::try_from(self.payload)?;
                 Ok(Ok(data.into_inner()))
             },
-            StatusCode::Err | StatusCode::Internal => {
+            StatusCode::Err | StatusCode::Internal | StatusCode::Timeout => {
                 let err = <Data<E>>::try_from(self.payload)?;
                 Ok(Err(err.into_inner()))
             },
@@ -52,6 +66,9 @@ impl EventResponse {
 impl std::fmt::Display for EventResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.write_fmt(format_args!("Status_Code: {:?}", self.status_code))?;
+        if !self.request_id.is_empty() {
+            f.write_fmt(format_args!(" Request_Id: {}", self.request_id))?;
+        }
 
         match &self.payload {
             Payload::Bytes(b) => f.write_fmt(format_args!("Data: {} bytes", b.len()))?,
@@ -0,0 +1,71 @@
+use crate::{
+    byte_trait::ToBytes,
+    errors::DispatchError,
+    request::EventRequest,
+    response::{EventResponse, Responder, ResponseBuilder},
+};
+use bytes::Bytes;
+use futures_core::{stream::BoxStream, Stream};
+use futures_util::StreamExt;
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+pub type ChunkStream = BoxStream<'static, Result<Bytes, DispatchError>>;
+
+// Returned by the handler to send the data in chunks, e.g. the large doc or the search results are
+// loaded progressively instead of being sent in one allocation. The stream ends after the first error.
+pub struct DataStream {
+    stream: ChunkStream,
+}
+
+impl DataStream {
+    pub fn new<S, T, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<T, E>> + Send + 'static,
+        T: ToBytes,
+        E: Into<DispatchError>,
+    {
+        let stream = stream.map(|item| item.map_err(|e| e.into()).and_then(|data| data.into_bytes()));
+        DataStream {
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl Responder for DataStream {
+    fn respond_to(self, _: &EventRequest) -> EventResponse {
+        let mut response = ResponseBuilder::Ok().build();
+        response.stream = Some(ResponseStream::new(self.stream));
+        response
+    }
+}
+
+// The clones of the response share the stream, only the first one that takes it can read the chunks
+#[derive(Clone)]
+pub struct ResponseStream {
+    inner: Arc<Mutex<Option<ChunkStream>>>,
+}
+
+impl ResponseStream {
+    fn new(stream: ChunkStream) -> Self {
+        ResponseStream {
+            inner: Arc::new(Mutex::new(Some(stream))),
+        }
+    }
+
+    pub fn take(&self) -> Option<ChunkStream> {
+        match self.inner.lock() {
+            Ok(mut stream) => stream.take(),
+            Err(e) => {
+                log::error!("Take the response stream failed: {:?}", e);
+                None
+            },
+        }
+    }
+}
+
+impl fmt::Debug for ResponseStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("ResponseStream") }
+}
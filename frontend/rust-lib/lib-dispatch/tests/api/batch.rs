@@ -0,0 +1,129 @@
+use lib_dispatch::prelude::*;
+use std::sync::{
+    atomic::{AtomicI32, Ordering},
+    Arc,
+};
+
+struct Counter(AtomicI32);
+
+async fn increase(counter: Unit<Counter>) { counter.0.fetch_add(1, Ordering::SeqCst); }
+
+async fn decrease(counter: Unit<Counter>) { counter.0.fetch_sub(1, Ordering::SeqCst); }
+
+async fn set(value: String, counter: Unit<Counter>) { counter.0.store(value.parse().unwrap(), Ordering::SeqCst); }
+
+async fn read(counter: Unit<Counter>) -> String { counter.0.load(Ordering::SeqCst).to_string() }
+
+async fn fail() -> Result<(), DispatchError> { Err("fail".to_owned().into()) }
+
+fn counter_dispatch() -> Arc<EventDispatch> {
+    let module = Module::new()
+        .data(Counter(AtomicI32::new(0)))
+        .event("increase", increase)
+        .event("decrease", decrease)
+        .event("set", set)
+        .event("read", read)
+        .event("fail", fail)
+        .compensate("increase", |_, _| Ok(vec![ModuleRequest::new("decrease")]))
+        .compensate_with_state("set", |_| Ok(ModuleRequest::new("read")), undo_set)
+        .read_only("read")
+        .read_only("fail");
+    Arc::new(EventDispatch::construct(|| vec![module]))
+}
+
+// Sets the counter back to the value that was read before the set event
+fn undo_set(_request: &ModuleRequest, state: &EventResponse) -> Result<Vec<ModuleRequest>, DispatchError> {
+    match &state.payload {
+        Payload::Bytes(bytes) => {
+            let value = String::from_utf8(bytes.to_vec()).unwrap();
+            Ok(vec![ModuleRequest::new("set").payload(value)])
+        },
+        Payload::None => Err("The counter wasn't read".to_owned().into()),
+    }
+}
+
+async fn read_counter(dispatch: Arc<EventDispatch>) -> String {
+    let response = EventDispatch::async_send(dispatch, ModuleRequest::new("read")).await;
+    match response.payload {
+        Payload::Bytes(bytes) => String::from_utf8(bytes.to_vec()).unwrap(),
+        Payload::None => panic!("Counter should not be empty"),
+    }
+}
+
+#[tokio::test]
+async fn batch_commit_all_events() {
+    let dispatch = counter_dispatch();
+    let batch = BatchRequest::new()
+        .request(ModuleRequest::new("increase"))
+        .request(ModuleRequest::new("increase"));
+    let response = EventDispatch::async_send_batch(dispatch.clone(), batch).await;
+    assert!(response.is_ok());
+    assert_eq!(response.responses.len(), 2);
+    assert_eq!(read_counter(dispatch.clone()).await, "2");
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn batch_rollback_after_failure() {
+    let dispatch = counter_dispatch();
+    let batch = BatchRequest::new()
+        .request(ModuleRequest::new("increase"))
+        .request(ModuleRequest::new("increase"))
+        .request(ModuleRequest::new("fail"))
+        .request(ModuleRequest::new("increase"));
+    let response = EventDispatch::async_send_batch(dispatch.clone(), batch).await;
+    assert!(!response.is_ok());
+    assert!(response.rolled_back);
+    assert_eq!(response.responses.len(), 3);
+    assert_eq!(read_counter(dispatch.clone()).await, "0");
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn batch_continue_after_failure() {
+    let dispatch = counter_dispatch();
+    let batch = BatchRequest::new()
+        .request(ModuleRequest::new("increase"))
+        .request(ModuleRequest::new("fail"))
+        .request(ModuleRequest::new("increase"))
+        .transactional(false);
+    let response = EventDispatch::async_send_batch(dispatch.clone(), batch).await;
+    assert_eq!(response.status_code, StatusCode::Internal);
+    assert!(!response.rolled_back);
+    let status_codes = response
+        .responses
+        .iter()
+        .map(|response| response.status_code.clone())
+        .collect::<Vec<StatusCode>>();
+    assert_eq!(status_codes, vec![StatusCode::Ok, StatusCode::Internal, StatusCode::Ok]);
+    assert_eq!(read_counter(dispatch.clone()).await, "2");
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn batch_rollback_update_after_failure() {
+    let dispatch = counter_dispatch();
+    let _ = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("set").payload("5")).await;
+    let batch = BatchRequest::new()
+        .request(ModuleRequest::new("set").payload("10"))
+        .request(ModuleRequest::new("increase"))
+        .request(ModuleRequest::new("fail"));
+    let response = EventDispatch::async_send_batch(dispatch.clone(), batch).await;
+    assert!(response.rolled_back);
+    assert_eq!(read_counter(dispatch.clone()).await, "5");
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn batch_reject_event_without_undo() {
+    let dispatch = counter_dispatch();
+    let batch = BatchRequest::new()
+        .request(ModuleRequest::new("increase"))
+        .request(ModuleRequest::new("decrease"));
+    let response = EventDispatch::async_send_batch(dispatch.clone(), batch).await;
+    assert!(!response.is_ok());
+    assert!(!response.rolled_back);
+    assert_eq!(response.responses.len(), 1);
+    assert_eq!(read_counter(dispatch.clone()).await, "0");
+    std::mem::forget(dispatch);
+}
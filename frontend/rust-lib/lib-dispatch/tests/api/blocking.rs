@@ -0,0 +1,54 @@
+use lib_dispatch::prelude::*;
+use std::{sync::Arc, time::Duration};
+
+async fn sum(pool: Unit<BlockingPool>) -> Result<String, DispatchError> {
+    let sum = pool.spawn(|| (1..=100).sum::<i32>()).await?;
+    Ok(sum.to_string())
+}
+
+#[tokio::test]
+async fn blocking_pool_run_task() {
+    let config = DispatchConfig {
+        blocking_pool_size: 2,
+        ..DispatchConfig::default()
+    };
+    let dispatch = Arc::new(EventDispatch::construct_with_config(config, || {
+        vec![Module::new().event("sum", sum)]
+    }));
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("sum")).await;
+    match response.payload {
+        Payload::Bytes(bytes) => assert_eq!(String::from_utf8(bytes.to_vec()).unwrap(), "5050"),
+        Payload::None => panic!("Sum should not be empty"),
+    }
+
+    let metrics = dispatch.blocking_pool().metrics();
+    assert_eq!(metrics.size, 2);
+    assert_eq!(metrics.completed, 1);
+    assert_eq!(metrics.running, 0);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn blocking_pool_starved_task() {
+    let pool = BlockingPool::new(1);
+    let cloned_pool = pool.clone();
+    let first = tokio::spawn(async move { cloned_pool.spawn(|| std::thread::sleep(Duration::from_millis(300))).await });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let cloned_pool = pool.clone();
+    let second = tokio::spawn(async move { cloned_pool.spawn(|| ()).await });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // The second task waits for the only thread of the pool
+    let metrics = pool.metrics();
+    assert_eq!(metrics.running, 1);
+    assert_eq!(metrics.waiting, 1);
+
+    first.await.unwrap().unwrap();
+    second.await.unwrap().unwrap();
+    let metrics = pool.metrics();
+    assert_eq!(metrics.size, 1);
+    assert_eq!(metrics.completed, 2);
+    assert_eq!(metrics.running, 0);
+    assert_eq!(metrics.starved, 1);
+    assert!(metrics.max_wait >= Duration::from_millis(200));
+}
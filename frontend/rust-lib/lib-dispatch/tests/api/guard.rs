@@ -0,0 +1,52 @@
+use lib_dispatch::prelude::*;
+use std::sync::Arc;
+
+pub async fn hello() -> String { "say hello".to_string() }
+
+struct SignedIn(bool);
+
+impl ScopeProvider for SignedIn {
+    fn has_scope(&self, scope: &str) -> bool { self.0 && scope == "signed_in" }
+}
+
+fn guarded_dispatch(is_signed_in: bool) -> Arc<EventDispatch> {
+    let mut table = EventGuardTable::new();
+    table.insert("public", &[]);
+    table.insert("private", &["signed_in"]);
+    let module = Module::new()
+        .event("public", hello)
+        .event("private", hello)
+        .event("unlisted", hello)
+        .guard_table(table);
+    let dispatch = EventDispatch::construct(|| vec![module]).scope_provider(Arc::new(SignedIn(is_signed_in)));
+    Arc::new(dispatch)
+}
+
+async fn send(dispatch: Arc<EventDispatch>, event: &str) -> StatusCode {
+    EventDispatch::async_send(dispatch, ModuleRequest::new(event))
+        .await
+        .status_code
+}
+
+#[tokio::test]
+async fn guard_allows_event_with_scopes() {
+    let dispatch = guarded_dispatch(true);
+    assert_eq!(send(dispatch.clone(), "public").await, StatusCode::Ok);
+    assert_eq!(send(dispatch.clone(), "private").await, StatusCode::Ok);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn guard_rejects_event_without_scopes() {
+    let dispatch = guarded_dispatch(false);
+    assert_eq!(send(dispatch.clone(), "public").await, StatusCode::Ok);
+    assert_eq!(send(dispatch.clone(), "private").await, StatusCode::Internal);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn guard_rejects_event_missing_from_table() {
+    let dispatch = guarded_dispatch(true);
+    assert_eq!(send(dispatch.clone(), "unlisted").await, StatusCode::Internal);
+    std::mem::forget(dispatch);
+}
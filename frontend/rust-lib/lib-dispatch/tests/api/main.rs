@@ -1 +1,8 @@
+mod batch;
+mod blocking;
+mod guard;
+mod middleware;
 mod module;
+mod request_id;
+mod stream;
+mod timeout;
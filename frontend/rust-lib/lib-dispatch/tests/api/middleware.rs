@@ -0,0 +1,102 @@
+use futures_util::future::BoxFuture;
+use lib_dispatch::prelude::*;
+use std::sync::{Arc, Mutex};
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+async fn echo(data: String) -> String { data }
+
+fn record(
+    log: &Log,
+    name: &'static str,
+) -> impl Fn(DispatchRequest, Next) -> BoxFuture<'static, EventResponse> + Send + Sync + 'static {
+    let log = log.clone();
+    move |request, next| {
+        let log = log.clone();
+        Box::pin(async move {
+            log.lock().unwrap().push(format!("{} before {:?}", name, request.event));
+            let response = next.run(request).await;
+            log.lock().unwrap().push(format!("{} after", name));
+            response
+        })
+    }
+}
+
+async fn send(dispatch: Arc<EventDispatch>, event: &str, payload: &str) -> EventResponse {
+    EventDispatch::async_send(dispatch, ModuleRequest::new(event).payload(payload)).await
+}
+
+fn payload_string(response: &EventResponse) -> String {
+    match &response.payload {
+        Payload::Bytes(bytes) => String::from_utf8(bytes.to_vec()).unwrap(),
+        Payload::None => "".to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn middleware_runs_in_order() {
+    let log = Log::default();
+    let module = Module::new()
+        .event("echo", echo)
+        .middleware(record(&log, "first"))
+        .middleware(record(&log, "second"));
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]));
+
+    let response = send(dispatch.clone(), "echo", "hello").await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    assert_eq!(payload_string(&response), "hello");
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            r#"first before Event("echo")"#.to_owned(),
+            r#"second before Event("echo")"#.to_owned(),
+            "second after".to_owned(),
+            "first after".to_owned(),
+        ]
+    );
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn middleware_stops_request() {
+    let log = Log::default();
+    let module = Module::new()
+        .event("echo", echo)
+        .middleware(|request: DispatchRequest, next: Next| async move {
+            match &request.payload {
+                Payload::Bytes(bytes) if !bytes.is_empty() => next.run(request).await,
+                _ => ResponseBuilder::Err().data("The payload is empty").build(),
+            }
+        })
+        .middleware(record(&log, "inner"));
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]));
+
+    let response = send(dispatch.clone(), "echo", "").await;
+    assert_eq!(response.status_code, StatusCode::Err);
+    assert_eq!(payload_string(&response), "The payload is empty");
+    assert_eq!(log.lock().unwrap().is_empty(), true);
+
+    let response = send(dispatch.clone(), "echo", "hello").await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    assert_eq!(log.lock().unwrap().len(), 2);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn middleware_reads_request_context() {
+    let module = Module::new()
+        .data("module data".to_owned())
+        .event("echo", echo)
+        .middleware(|mut request: DispatchRequest, next: Next| async move {
+            let data = request.module_data::<Unit<String>>().map(|data| data.to_string());
+            request.payload = format!("{} {}", request.id, data.unwrap_or_default()).into();
+            next.run(request).await
+        });
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![module]));
+
+    let request = ModuleRequest::new("echo").payload("hello");
+    let id = request.id.clone();
+    let response = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(payload_string(&response), format!("{} module data", id));
+    std::mem::forget(dispatch);
+}
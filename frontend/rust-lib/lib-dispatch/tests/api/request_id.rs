@@ -0,0 +1,30 @@
+use lib_dispatch::prelude::*;
+use std::sync::Arc;
+
+async fn request_id() -> String { current_request_id().unwrap_or_default() }
+
+#[tokio::test]
+async fn request_id_returned_in_response() {
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![Module::new().event("id", request_id)]));
+    let request = ModuleRequest::new("id");
+    let id = request.id.clone();
+    let response = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(response.request_id, id);
+    match response.payload {
+        Payload::Bytes(bytes) => assert_eq!(String::from_utf8(bytes.to_vec()).unwrap(), id),
+        Payload::None => panic!("Request id should not be empty"),
+    }
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn request_id_returned_in_error_response() {
+    let dispatch = Arc::new(EventDispatch::construct(|| vec![]));
+    let request = ModuleRequest::new("not_found");
+    let id = request.id.clone();
+    let response = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(response.status_code, StatusCode::Internal);
+    assert_eq!(response.request_id, id);
+    assert_eq!(current_request_id(), None);
+    std::mem::forget(dispatch);
+}
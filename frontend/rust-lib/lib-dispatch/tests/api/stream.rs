@@ -0,0 +1,73 @@
+use futures_util::{stream, StreamExt};
+use lib_dispatch::prelude::*;
+use std::sync::Arc;
+
+async fn chunks() -> DataStream {
+    let items = (0..3).map(|i| Ok::<_, DispatchError>(Data(format!("chunk {}", i))));
+    DataStream::new(stream::iter(items))
+}
+
+async fn broken_chunks() -> DataStream {
+    let items = vec![
+        Ok(Data("chunk 0".to_owned())),
+        Err(DispatchError::from("broken".to_owned())),
+        Ok(Data("chunk 2".to_owned())),
+    ];
+    DataStream::new(stream::iter(items))
+}
+
+fn stream_dispatch() -> Arc<EventDispatch> {
+    let dispatch = EventDispatch::construct(|| {
+        vec![Module::new()
+            .event("chunks", chunks)
+            .event("broken_chunks", broken_chunks)]
+    });
+    Arc::new(dispatch)
+}
+
+#[tokio::test]
+async fn stream_response() {
+    let dispatch = stream_dispatch();
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("chunks")).await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+
+    let chunks = response
+        .take_stream()
+        .unwrap()
+        .map(|item| String::from_utf8(item.unwrap().to_vec()).unwrap())
+        .collect::<Vec<String>>()
+        .await;
+    assert_eq!(chunks, vec!["chunk 0", "chunk 1", "chunk 2"]);
+
+    // The stream can only be taken once
+    assert_eq!(response.take_stream().is_none(), true);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn stream_response_ends_after_error() {
+    let dispatch = stream_dispatch();
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("broken_chunks")).await;
+    let items = response.take_stream().unwrap().collect::<Vec<_>>().await;
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].is_ok(), true);
+    assert_eq!(items[1].is_err(), true);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn stream_response_taken_by_callback() {
+    let dispatch = stream_dispatch();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let response = EventDispatch::async_send_with_callback(dispatch.clone(), ModuleRequest::new("chunks"), |resp| {
+        Box::pin(async move {
+            let count = resp.take_stream().unwrap().count().await;
+            let _ = tx.send(count);
+        })
+    })
+    .await;
+
+    assert_eq!(rx.await.unwrap(), 3);
+    assert_eq!(response.take_stream().is_none(), true);
+    std::mem::forget(dispatch);
+}
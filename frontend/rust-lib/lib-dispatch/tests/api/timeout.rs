@@ -0,0 +1,74 @@
+use lib_dispatch::prelude::*;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+async fn fast() -> String { "fast".to_owned() }
+
+async fn slow(token: CancellationToken, cancelled: Unit<Arc<AtomicBool>>) -> String {
+    tokio::spawn(async move {
+        token.cancelled().await;
+        cancelled.store(true, Ordering::SeqCst);
+    });
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    "slow".to_owned()
+}
+
+fn timeout_dispatch(request_timeout: Option<Duration>, cancelled: Arc<AtomicBool>) -> Arc<EventDispatch> {
+    let config = DispatchConfig {
+        request_timeout,
+        ..DispatchConfig::default()
+    };
+    let dispatch = EventDispatch::construct_with_config(config, || {
+        vec![Module::new().data(cancelled).event("fast", fast).event("slow", slow)]
+    });
+    Arc::new(dispatch)
+}
+
+#[tokio::test]
+async fn request_timeout() {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let dispatch = timeout_dispatch(None, cancelled.clone());
+    let request = ModuleRequest::new("slow").timeout(Duration::from_millis(50));
+    let response = EventDispatch::async_send(dispatch.clone(), request).await;
+    assert_eq!(response.status_code, StatusCode::Timeout);
+
+    // The task that the handler spawned is notified
+    let _ = tokio::time::timeout(Duration::from_secs(1), async {
+        while !cancelled.load(Ordering::SeqCst) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await;
+    assert_eq!(cancelled.load(Ordering::SeqCst), true);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn request_timeout_from_config() {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let dispatch = timeout_dispatch(Some(Duration::from_millis(50)), cancelled);
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("slow")).await;
+    assert_eq!(response.status_code, StatusCode::Timeout);
+    assert_eq!(response.request_id.is_empty(), false);
+
+    let response = EventDispatch::async_send(dispatch.clone(), ModuleRequest::new("fast")).await;
+    assert_eq!(response.status_code, StatusCode::Ok);
+    std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn request_timeout_overrides_config() {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let dispatch = timeout_dispatch(Some(Duration::from_secs(30)), cancelled);
+    let request = ModuleRequest::new("slow").timeout(Duration::from_millis(50));
+    let response = tokio::time::timeout(Duration::from_secs(5), EventDispatch::async_send(dispatch.clone(), request))
+        .await
+        .unwrap();
+    assert_eq!(response.status_code, StatusCode::Timeout);
+    std::mem::forget(dispatch);
+}
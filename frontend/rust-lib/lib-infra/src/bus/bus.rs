@@ -0,0 +1,99 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tokio::sync::broadcast;
+
+const BUS_CHANNEL_CAPACITY: usize = 100;
+
+// The modules publish their domain events on the bus instead of calling each other, so the module
+// that reacts to an event doesn't need to depend on the crate that publishes it. Each type of event
+// has its own broadcast channel, the channel is created by whichever of publish and subscribe runs
+// first. Each SDK has its own bus, so the events of one user aren't received by the modules of another
+// user that runs in the same process.
+#[derive(Clone, Default)]
+pub struct EventBus {
+    channels: Arc<RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn publish<T>(&self, event: T)
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        // The event is dropped if no one subscribes to it
+        let _ = self.sender::<T>().send(event);
+    }
+
+    pub fn subscribe<T>(&self) -> broadcast::Receiver<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.sender::<T>().subscribe()
+    }
+
+    fn sender<T>(&self) -> broadcast::Sender<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        if let Some(sender) = self.read_sender::<T>(&type_id) {
+            return sender;
+        }
+
+        let mut channels = self.channels.write().unwrap();
+        channels
+            .entry(type_id)
+            .or_insert_with(|| Box::new(broadcast::channel::<T>(BUS_CHANNEL_CAPACITY).0))
+            .downcast_ref::<broadcast::Sender<T>>()
+            .unwrap()
+            .clone()
+    }
+
+    fn read_sender<T: Send + 'static>(&self, type_id: &TypeId) -> Option<broadcast::Sender<T>> {
+        let channels = self.channels.read().unwrap();
+        channels
+            .get(type_id)
+            .and_then(|sender| sender.downcast_ref::<broadcast::Sender<T>>())
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventBus;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Ping(i64);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pong(i64);
+
+    #[test]
+    fn bus_delivers_events_by_type() {
+        let bus = EventBus::new();
+        let mut ping_rx = bus.subscribe::<Ping>();
+        let mut pong_rx = bus.subscribe::<Pong>();
+        bus.publish(Ping(1));
+        bus.publish(Pong(2));
+
+        assert_eq!(ping_rx.try_recv().unwrap(), Ping(1));
+        assert_eq!(pong_rx.try_recv().unwrap(), Pong(2));
+        assert!(ping_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn bus_only_delivers_events_to_its_own_subscribers() {
+        let bus = EventBus::new();
+        let other_bus = EventBus::new();
+        let mut rx = bus.subscribe::<Ping>();
+        let mut other_rx = other_bus.subscribe::<Ping>();
+        bus.clone().publish(Ping(1));
+
+        assert_eq!(rx.try_recv().unwrap(), Ping(1));
+        assert!(other_rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,26 @@
+// The domain events that are published across the modules. They are defined here, not in the crate
+// that publishes them, so the subscribers don't have to depend on that crate.
+
+#[derive(Clone, Debug)]
+pub enum UserSessionEvent {
+    SignIn { user_id: String, token: String },
+    SignUp { user_id: String, token: String },
+    SignOut { user_id: String, token: String },
+}
+
+// Published after the revision of the document is saved, either made locally or merged from the server
+#[derive(Clone, Debug)]
+pub struct DocRevisionEvent {
+    pub doc_id: String,
+    pub rev_id: i64,
+    // True if the revision is made by another client
+    pub is_remote: bool,
+}
+
+// Published when the websocket connection to the server is made or lost, e.g. the changes that were made
+// offline are sent after it's connected again.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WsConnectionEvent {
+    Connected,
+    Disconnected,
+}
@@ -0,0 +1,5 @@
+mod bus;
+mod event;
+
+pub use bus::*;
+pub use event::*;
@@ -1,5 +1,5 @@
 use crate::kv::schema::{kv_table, kv_table::dsl, KV_SQL};
-use ::diesel::{query_dsl::*, ExpressionMethods};
+use ::diesel::{query_dsl::*, ExpressionMethods, TextExpressionMethods};
 use diesel::{Connection, SqliteConnection};
 use flowy_derive::ProtoBuf;
 use lazy_static::lazy_static;
@@ -70,6 +70,33 @@ impl KV {
         Ok(())
     }
 
+    // The keys that start with the prefix, e.g. the settings of the user are prefixed with the user id
+    pub fn keys_with_prefix(prefix: &str) -> Result<Vec<String>, String> {
+        let conn = get_connection()?;
+        let keys = dsl::kv_table
+            .select(kv_table::key)
+            .filter(kv_table::key.like(format!("{}%", prefix)))
+            .load::<String>(&*conn)
+            .map_err(|e| format!("KV read keys error: {:?}", e))?;
+
+        // The underscore in the prefix matches any character in the LIKE pattern
+        Ok(keys.into_iter().filter(|key| key.starts_with(prefix)).collect())
+    }
+
+    pub fn contains(key: &str) -> bool { KV::get(key).is_ok() }
+
+    // Returns false if the key has no value
+    pub fn copy(from: &str, to: &str) -> Result<bool, String> {
+        match KV::get(from) {
+            Ok(mut value) => {
+                value.key = to.to_owned();
+                let _ = KV::set(value)?;
+                Ok(true)
+            },
+            Err(_) => Ok(false),
+        }
+    }
+
     pub fn init(root: &str) -> Result<(), String> {
         if !Path::new(root).exists() {
             return Err(format!("Init KVStore failed. {} not exists", root));
@@ -229,5 +256,13 @@ mod tests {
         assert_eq!(KV::get_bool("1").unwrap(), true);
 
         assert_eq!(KV::get_bool("2"), None);
+
+        KV::set_int("user_a_policy", 1);
+        KV::set_int("user_ab_policy", 2);
+        assert_eq!(KV::keys_with_prefix("user_a_").unwrap(), vec!["user_a_policy".to_owned()]);
+        assert_eq!(KV::copy("user_a_policy", "user_b_policy").unwrap(), true);
+        assert_eq!(KV::get_int("user_b_policy").unwrap(), 1);
+        assert_eq!(KV::copy("user_c_policy", "user_b_policy").unwrap(), false);
+        assert!(KV::contains("user_b_policy"));
     }
 }
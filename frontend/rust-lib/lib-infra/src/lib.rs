@@ -4,8 +4,10 @@ extern crate diesel;
 #[macro_use]
 extern crate diesel_derives;
 
+pub mod bus;
 pub mod future;
 pub mod kv;
+pub mod metrics;
 mod protobuf;
 pub mod retry;
 
@@ -14,3 +16,18 @@ pub fn uuid() -> String { uuid::Uuid::new_v4().to_string() }
 
 #[allow(dead_code)]
 pub fn timestamp() -> i64 { chrono::Utc::now().timestamp() }
+
+const DEVICE_ID_KEY: &str = "device_id";
+
+// The id of the installation, it's generated the first time it's read and kept in the kv store. It tells
+// apart the changes that were made by the same user on different devices.
+pub fn device_id() -> String {
+    match kv::KV::get_str(DEVICE_ID_KEY) {
+        Some(device_id) => device_id,
+        None => {
+            let device_id = uuid();
+            kv::KV::set_str(DEVICE_ID_KEY, device_id.clone());
+            device_id
+        },
+    }
+}
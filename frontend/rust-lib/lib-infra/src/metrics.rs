@@ -0,0 +1,273 @@
+use lazy_static::lazy_static;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+    time::Duration,
+};
+
+// The upper bounds of the latency buckets in milliseconds, the slower ones are only counted in the +Inf bucket
+const LATENCY_BUCKETS_MS: [u64; 10] = [1, 5, 10, 25, 50, 100, 250, 500, 1000, 5000];
+
+// Nothing is recorded until the metrics are enabled, so the apps that don't read them don't pay for the lock
+static METRICS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref METRICS: RwLock<Metrics> = RwLock::new(Metrics::default());
+}
+
+// The name of the metric and its rendered labels, e.g. dispatch_events_total and event="SignIn"
+type MetricKey = (&'static str, String);
+
+#[derive(Default)]
+struct Metrics {
+    counters: BTreeMap<MetricKey, u64>,
+    latencies: BTreeMap<MetricKey, Latency>,
+}
+
+#[derive(Default, Clone)]
+struct Latency {
+    buckets: [u64; LATENCY_BUCKETS_MS.len()],
+    count: u64,
+    sum: Duration,
+    max: Duration,
+}
+
+impl Latency {
+    fn observe(&mut self, duration: Duration) {
+        let millis = duration.as_millis() as u64;
+        if let Some(index) = LATENCY_BUCKETS_MS.iter().position(|bound| millis <= *bound) {
+            self.buckets[index] += 1;
+        }
+        self.count += 1;
+        self.sum += duration;
+        self.max = self.max.max(duration);
+    }
+}
+
+pub fn enable_metrics(enabled: bool) { METRICS_ENABLED.store(enabled, Ordering::SeqCst); }
+
+pub fn is_metrics_enabled() -> bool { METRICS_ENABLED.load(Ordering::Relaxed) }
+
+pub fn increment_counter(name: &'static str, labels: &[(&str, &str)], value: u64) {
+    if !is_metrics_enabled() {
+        return;
+    }
+    let mut metrics = METRICS.write().unwrap();
+    *metrics.counters.entry((name, render_labels(labels))).or_insert(0) += value;
+}
+
+pub fn observe_latency(name: &'static str, labels: &[(&str, &str)], duration: Duration) {
+    if !is_metrics_enabled() {
+        return;
+    }
+    let mut metrics = METRICS.write().unwrap();
+    metrics
+        .latencies
+        .entry((name, render_labels(labels)))
+        .or_default()
+        .observe(duration);
+}
+
+pub fn metrics_snapshot() -> MetricsSnapshot {
+    let metrics = METRICS.read().unwrap();
+    let counters = metrics
+        .counters
+        .iter()
+        .map(|((name, labels), value)| CounterSnapshot {
+            name: name.to_string(),
+            labels: labels.clone(),
+            value: *value,
+        })
+        .collect();
+
+    let latencies = metrics
+        .latencies
+        .iter()
+        .map(|((name, labels), latency)| {
+            let buckets = LATENCY_BUCKETS_MS
+                .iter()
+                .zip(latency.buckets.iter())
+                .scan(0, |count, (bound, bucket_count)| {
+                    *count += bucket_count;
+                    Some((*bound, *count))
+                })
+                .collect();
+            LatencySnapshot {
+                name: name.to_string(),
+                labels: labels.clone(),
+                count: latency.count,
+                sum: latency.sum,
+                max: latency.max,
+                buckets,
+            }
+        })
+        .collect();
+
+    MetricsSnapshot { counters, latencies }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounterSnapshot {
+    pub name: String,
+    pub labels: String,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencySnapshot {
+    pub name: String,
+    pub labels: String,
+    pub count: u64,
+    pub sum: Duration,
+    pub max: Duration,
+    // The upper bound of each bucket in milliseconds and the number of the observations up to it
+    pub buckets: Vec<(u64, u64)>,
+}
+
+impl LatencySnapshot {
+    // The upper bound of the bucket that the percentile falls into, e.g. 0.95 for the p95. It's the max
+    // latency if the percentile is slower than all the buckets.
+    pub fn percentile(&self, percentile: f64) -> Duration {
+        let rank = (self.count as f64 * percentile).ceil() as u64;
+        match self.buckets.iter().find(|(_, count)| *count >= rank.max(1)) {
+            Some((bound, _)) => Duration::from_millis(*bound).min(self.max),
+            None => self.max,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSnapshot {
+    pub counters: Vec<CounterSnapshot>,
+    pub latencies: Vec<LatencySnapshot>,
+}
+
+impl MetricsSnapshot {
+    // The Prometheus text format, the latencies are the histograms in seconds
+    pub fn to_prometheus(&self) -> String {
+        let mut text = String::new();
+        let mut last_name = "";
+        for counter in &self.counters {
+            if counter.name != last_name {
+                let _ = writeln!(text, "# TYPE {} counter", counter.name);
+                last_name = &counter.name;
+            }
+            let _ = writeln!(text, "{}{} {}", counter.name, wrap_labels(&counter.labels), counter.value);
+        }
+
+        last_name = "";
+        for latency in &self.latencies {
+            if latency.name != last_name {
+                let _ = writeln!(text, "# TYPE {} histogram", latency.name);
+                last_name = &latency.name;
+            }
+            for (bound, count) in &latency.buckets {
+                let labels = join_labels(&latency.labels, &format!("le=\"{}\"", *bound as f64 / 1000.0));
+                let _ = writeln!(text, "{}_bucket{{{}}} {}", latency.name, labels, count);
+            }
+            let labels = join_labels(&latency.labels, "le=\"+Inf\"");
+            let _ = writeln!(text, "{}_bucket{{{}}} {}", latency.name, labels, latency.count);
+            let labels = wrap_labels(&latency.labels);
+            let _ = writeln!(text, "{}_sum{} {}", latency.name, labels, latency.sum.as_secs_f64());
+            let _ = writeln!(text, "{}_count{} {}", latency.name, labels, latency.count);
+        }
+        text
+    }
+}
+
+fn render_labels(labels: &[(&str, &str)]) -> String {
+    labels
+        .iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, value.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn wrap_labels(labels: &str) -> String {
+    match labels.is_empty() {
+        true => "".to_owned(),
+        false => format!("{{{}}}", labels),
+    }
+}
+
+fn join_labels(labels: &str, label: &str) -> String {
+    match labels.is_empty() {
+        true => label.to_owned(),
+        false => format!("{},{}", labels, label),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_latency(snapshot: &MetricsSnapshot, name: &str) -> LatencySnapshot {
+        snapshot
+            .latencies
+            .iter()
+            .find(|latency| latency.name == name)
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn metrics_record_test() {
+        enable_metrics(true);
+        increment_counter("test_events_total", &[("event", "SignIn")], 1);
+        increment_counter("test_events_total", &[("event", "SignIn")], 2);
+        for millis in &[3, 7, 40, 2000] {
+            observe_latency("test_event_seconds", &[], Duration::from_millis(*millis));
+        }
+
+        let snapshot = metrics_snapshot();
+        let counter = snapshot
+            .counters
+            .iter()
+            .find(|counter| counter.name == "test_events_total")
+            .unwrap();
+        assert_eq!(counter.labels, "event=\"SignIn\"");
+        assert_eq!(counter.value, 3);
+
+        let latency = find_latency(&snapshot, "test_event_seconds");
+        assert_eq!(latency.count, 4);
+        assert_eq!(latency.percentile(0.5), Duration::from_millis(10));
+        assert_eq!(latency.percentile(0.95), Duration::from_millis(2000));
+        assert_eq!(latency.max, Duration::from_millis(2000));
+    }
+
+    #[test]
+    fn metrics_prometheus_test() {
+        let snapshot = MetricsSnapshot {
+            counters: vec![CounterSnapshot {
+                name: "ws_sent_bytes_total".to_owned(),
+                labels: "module=\"Doc\"".to_owned(),
+                value: 42,
+            }],
+            latencies: vec![LatencySnapshot {
+                name: "sqlite_connection_seconds".to_owned(),
+                labels: "".to_owned(),
+                count: 2,
+                sum: Duration::from_millis(1500),
+                max: Duration::from_millis(1000),
+                buckets: vec![(500, 1), (1000, 2)],
+            }],
+        };
+        assert_eq!(
+            snapshot.to_prometheus(),
+            concat!(
+                "# TYPE ws_sent_bytes_total counter\n",
+                "ws_sent_bytes_total{module=\"Doc\"} 42\n",
+                "# TYPE sqlite_connection_seconds histogram\n",
+                "sqlite_connection_seconds_bucket{le=\"0.5\"} 1\n",
+                "sqlite_connection_seconds_bucket{le=\"1\"} 2\n",
+                "sqlite_connection_seconds_bucket{le=\"+Inf\"} 2\n",
+                "sqlite_connection_seconds_sum 1.5\n",
+                "sqlite_connection_seconds_count 2\n",
+            )
+        );
+    }
+}
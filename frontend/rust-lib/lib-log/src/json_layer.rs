@@ -0,0 +1,140 @@
+use crate::log_user_id;
+use serde::ser::{SerializeMap, Serializer};
+use serde_json::Value;
+use std::io::Write;
+use tracing::{Event, Subscriber};
+use tracing_bunyan_formatter::JsonStorage;
+use tracing_subscriber::{fmt::MakeWriter, layer::Context, registry::LookupSpan, Layer};
+
+const TIME: &str = "time";
+const LEVEL: &str = "level";
+const MODULE: &str = "module";
+const EVENT: &str = "event";
+const USER_ID: &str = "user_id";
+const SPAN: &str = "span";
+
+const JSON_RESERVED_FIELDS: [&str; 6] = [TIME, LEVEL, MODULE, EVENT, USER_ID, SPAN];
+// The fields that the records of the log crate carry, the module is read from them
+const LOG_FIELDS: [&str; 4] = ["log.module_path", "log.target", "log.file", "log.line"];
+
+// Writes each event as one line of json that the log tools can search by the fields, e.g.
+// {"time":"2021-11-09T10:00:00.123+08:00","level":"INFO","module":"flowy_user::services::user","event":"...",
+// "user_id":"...","span":"sign_in"}
+// The fields of the event and of its span follow them. The spans aren't written on their own.
+pub struct JsonFormattingLayer<W: MakeWriter + 'static> {
+    make_writer: W,
+}
+
+impl<W: MakeWriter + 'static> JsonFormattingLayer<W> {
+    pub fn new(make_writer: W) -> Self { Self { make_writer } }
+
+    fn format<S>(&self, event: &Event<'_>, ctx: &Context<'_, S>) -> Result<Vec<u8>, std::io::Error>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let mut event_visitor = JsonStorage::default();
+        event.record(&mut event_visitor);
+        let values = event_visitor.values();
+
+        let metadata = event.metadata();
+        let module = values
+            .get("log.module_path")
+            .and_then(|value| value.as_str())
+            .or_else(|| metadata.module_path())
+            .unwrap_or_else(|| metadata.target());
+        let message = values.get("message").and_then(|value| value.as_str()).unwrap_or_default();
+
+        let mut buffer = Vec::new();
+        let mut serializer = serde_json::Serializer::new(&mut buffer);
+        let mut map_serializer = serializer.serialize_map(None)?;
+        map_serializer.serialize_entry(TIME, &chrono::Local::now().to_rfc3339())?;
+        map_serializer.serialize_entry(LEVEL, &metadata.level().to_string())?;
+        map_serializer.serialize_entry(MODULE, module)?;
+        map_serializer.serialize_entry(EVENT, message)?;
+        if let Some(user_id) = log_user_id() {
+            map_serializer.serialize_entry(USER_ID, &user_id)?;
+        }
+
+        for (key, value) in values.iter() {
+            if is_custom_field(key) {
+                map_serializer.serialize_entry(key, value)?;
+            }
+        }
+
+        if let Some(span) = ctx.lookup_current() {
+            map_serializer.serialize_entry(SPAN, span.metadata().name())?;
+            let extensions = span.extensions();
+            if let Some(visitor) = extensions.get::<JsonStorage>() {
+                for (key, value) in visitor.values() {
+                    if is_custom_field(key) && !values.contains_key(key) {
+                        map_serializer.serialize_entry(key, value)?;
+                    }
+                }
+            }
+        }
+        map_serializer.end()?;
+        buffer.write_all(b"\n")?;
+        Ok(buffer)
+    }
+}
+
+fn is_custom_field(key: &str) -> bool {
+    key != "message" && !JSON_RESERVED_FIELDS.contains(&key) && !LOG_FIELDS.contains(&key)
+}
+
+impl<S, W> Layer<S> for JsonFormattingLayer<W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: MakeWriter + 'static,
+{
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        if let Ok(buffer) = self.format(event, &ctx) {
+            let _ = self.make_writer.make_writer().write_all(&buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::set_log_user;
+    use std::sync::{Arc, Mutex};
+    use tracing_bunyan_formatter::JsonStorageLayer;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Clone, Default)]
+    struct TestWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for TestWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> { self.0.lock().unwrap().write(buf) }
+
+        fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+    }
+
+    #[test]
+    fn json_event_test() {
+        let writer = TestWriter::default();
+        let cloned_writer = writer.clone();
+        let subscriber = tracing_subscriber::registry()
+            .with(JsonStorageLayer)
+            .with(JsonFormattingLayer::new(move || cloned_writer.clone()));
+
+        set_log_user(Some("user_id"));
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("sign_in", device = "mac");
+            let _enter = span.enter();
+            tracing::info!(attempt = 2, "Sign in succeeded");
+        });
+        set_log_user(None);
+
+        let output = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+        let line: Value = serde_json::from_str(output.lines().next().unwrap()).unwrap();
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["module"], "lib_log::json_layer::tests");
+        assert_eq!(line["event"], "Sign in succeeded");
+        assert_eq!(line["user_id"], "user_id");
+        assert_eq!(line["span"], "sign_in");
+        assert_eq!(line["attempt"], 2);
+        assert_eq!(line["device"], "mac");
+    }
+}
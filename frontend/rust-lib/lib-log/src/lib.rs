@@ -1,37 +1,60 @@
+mod json_layer;
 mod layer;
+mod rotation;
 
 use log::LevelFilter;
 
 use tracing::subscriber::set_global_default;
 
-use crate::layer::*;
+use crate::{json_layer::JsonFormattingLayer, layer::*, rotation::RotatingFile};
 use lazy_static::lazy_static;
+pub use rotation::Rotation;
 use std::sync::RwLock;
-use tracing_appender::{non_blocking::WorkerGuard, rolling::RollingFileAppender};
+use tracing_appender::non_blocking::WorkerGuard;
 use tracing_bunyan_formatter::JsonStorageLayer;
 use tracing_log::LogTracer;
-use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
+use tracing_subscriber::{layer::SubscriberExt, reload, EnvFilter};
+
+const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
 
 lazy_static! {
     static ref LOG_GUARD: RwLock<Option<WorkerGuard>> = RwLock::new(None);
+    static ref LOG_FILTER: RwLock<Option<LogFilter>> = RwLock::new(None);
+    static ref LOG_USER_ID: RwLock<Option<String>> = RwLock::new(None);
+}
+
+// The filter that the subscriber was built with, its levels can be changed while the app is running
+struct LogFilter {
+    directives: String,
+    reload: Box<dyn Fn(EnvFilter) -> Result<(), String> + Send + Sync>,
+}
+
+// How the logs are written into the file. The stdout always gets the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    // One json object per line, with the time, level, module, event and user_id fields
+    Json,
 }
 
 pub struct Builder {
-    #[allow(dead_code)]
     name: String,
+    directory: String,
     env_filter: String,
-    file_appender: RollingFileAppender,
+    format: LogFormat,
+    rotation: Rotation,
+    max_files: usize,
 }
 
 impl Builder {
     pub fn new(name: &str, directory: &str) -> Self {
-        // let directory = directory.as_ref().to_str().unwrap().to_owned();
-        let local_file_name = format!("{}.log", name);
-
         Builder {
             name: name.to_owned(),
+            directory: directory.to_owned(),
             env_filter: "Info".to_owned(),
-            file_appender: tracing_appender::rolling::daily(directory, local_file_name),
+            format: LogFormat::Text,
+            rotation: Rotation::Daily,
+            max_files: 7,
         }
     }
 
@@ -40,10 +63,33 @@ impl Builder {
         self
     }
 
+    pub fn format(mut self, format: LogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    // The number of the closed log files that are kept, the older ones are removed
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
     pub fn build(self) -> std::result::Result<(), String> {
-        let env_filter = EnvFilter::new(self.env_filter);
+        let (env_filter, reload_handle) = reload::Layer::new(EnvFilter::new(&self.env_filter));
+
+        let local_file_name = format!("{}.log", self.name);
+        let file = RotatingFile::new(&self.directory, &local_file_name, self.rotation, self.max_files);
+        let (non_blocking, guard) = tracing_appender::non_blocking(file);
+        let (text_file_layer, json_file_layer) = match self.format {
+            LogFormat::Text => (Some(FlowyFormattingLayer::new(non_blocking)), None),
+            LogFormat::Json => (None, Some(JsonFormattingLayer::new(non_blocking))),
+        };
 
-        let (non_blocking, guard) = tracing_appender::non_blocking(self.file_appender);
         let subscriber = tracing_subscriber::fmt()
             // .with_span_events(FmtSpan::NEW | FmtSpan::CLOSE)
             .with_ansi(false)
@@ -60,7 +106,8 @@ impl Builder {
             .with(env_filter)
             .with(JsonStorageLayer)
             .with(FlowyFormattingLayer::new(std::io::stdout))
-            .with(FlowyFormattingLayer::new(non_blocking));
+            .with(text_file_layer)
+            .with(json_file_layer);
 
         // if cfg!(feature = "use_bunyan") {
         //     let formatting_layer = BunyanFormattingLayer::new(self.name.clone(),
@@ -78,10 +125,54 @@ impl Builder {
             .unwrap();
 
         *LOG_GUARD.write().unwrap() = Some(guard);
+        *LOG_FILTER.write().unwrap() = Some(LogFilter {
+            directives: self.env_filter,
+            reload: Box::new(move |filter| reload_handle.reload(filter).map_err(|e| format!("{:?}", e))),
+        });
         Ok(())
     }
 }
 
+// Changes the level of all the targets that the log was built with, e.g. to debug the issue that a user
+// reported without restarting the app.
+pub fn set_log_level(level: &str) -> std::result::Result<(), String> {
+    let level = level.to_lowercase();
+    if !LOG_LEVELS.contains(&level.as_str()) {
+        return Err(format!("Unknown log level: {}", level));
+    }
+
+    let mut log_filter = LOG_FILTER.write().map_err(|e| format!("{:?}", e))?;
+    match log_filter.as_mut() {
+        None => Err("The log isn't initialized".to_owned()),
+        Some(log_filter) => {
+            let directives = replace_level(&log_filter.directives, &level);
+            let _ = (log_filter.reload)(EnvFilter::new(&directives))?;
+            tracing::info!("Log level is changed to {}", level);
+            log_filter.directives = directives;
+            Ok(())
+        },
+    }
+}
+
+// The user_id is added to each json log, so the logs of a user can be found
+pub fn set_log_user(user_id: Option<&str>) { *LOG_USER_ID.write().unwrap() = user_id.map(|s| s.to_owned()); }
+
+pub(crate) fn log_user_id() -> Option<String> { LOG_USER_ID.read().ok().and_then(|user_id| user_id.clone()) }
+
+// "flowy_sdk=info,lib_ot=warn" becomes "flowy_sdk=debug,lib_ot=debug". The directive that has no target
+// is the level of all the targets, so it's replaced too.
+fn replace_level(directives: &str, level: &str) -> String {
+    directives
+        .split(',')
+        .filter(|directive| !directive.trim().is_empty())
+        .map(|directive| match directive.rsplit_once('=') {
+            None => level.to_owned(),
+            Some((target, _)) => format!("{}={}", target, level),
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +193,12 @@ mod tests {
         say("hello world");
     }
 
+    #[test]
+    fn replace_level_test() {
+        assert_eq!(replace_level("flowy_sdk=info,lib_ot=warn", "debug"), "flowy_sdk=debug,lib_ot=debug");
+        assert_eq!(replace_level("Info", "trace"), "trace");
+    }
+
     #[tracing::instrument(name = "say")]
     fn say(s: &str) {
         tracing::info!("{}", s);
@@ -0,0 +1,187 @@
+use chrono::{DateTime, Local, NaiveDate};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+// When the current log file is closed and a new one is started
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    // The file of each day is kept as <name>.log.<date>
+    Daily,
+    // The file is closed before it grows larger than the bytes, and kept as <name>.log.<time>
+    Size(u64),
+}
+
+// Writes the logs into <name>.log in the directory. The closed files are kept next to it, the oldest ones
+// are removed once there are more than the max_files.
+pub(crate) struct RotatingFile {
+    directory: PathBuf,
+    file_name: String,
+    rotation: Rotation,
+    max_files: usize,
+    file: Option<File>,
+    size: u64,
+    date: NaiveDate,
+}
+
+impl RotatingFile {
+    pub(crate) fn new(directory: &str, file_name: &str, rotation: Rotation, max_files: usize) -> Self {
+        Self {
+            directory: PathBuf::from(directory),
+            file_name: file_name.to_owned(),
+            rotation,
+            max_files,
+            file: None,
+            size: 0,
+            date: today(),
+        }
+    }
+
+    fn path(&self) -> PathBuf { self.directory.join(&self.file_name) }
+
+    // The file that was written before the app restarted is appended to, unless it's from another day
+    fn open(&mut self) -> io::Result<&mut File> {
+        if self.file.is_none() {
+            let _ = fs::create_dir_all(&self.directory)?;
+            let path = self.path();
+            if let Ok(metadata) = fs::metadata(&path) {
+                let modified: DateTime<Local> = metadata.modified()?.into();
+                self.size = metadata.len();
+                self.date = modified.naive_local().date();
+                if self.should_rotate(0) {
+                    let _ = self.rotate()?;
+                }
+            }
+            let file = OpenOptions::new().create(true).append(true).open(&path)?;
+            self.file = Some(file);
+        }
+        Ok(self.file.as_mut().unwrap())
+    }
+
+    fn should_rotate(&self, len: usize) -> bool {
+        match self.rotation {
+            Rotation::Daily => self.date != today(),
+            Rotation::Size(max_size) => self.size > 0 && self.size + len as u64 > max_size,
+        }
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        if let Some(mut file) = self.file.take() {
+            let _ = file.flush();
+        }
+
+        let suffix = match self.rotation {
+            Rotation::Daily => self.date.format("%Y-%m-%d").to_string(),
+            Rotation::Size(_) => Local::now().format("%Y-%m-%d_%H-%M-%S%.3f").to_string(),
+        };
+        let mut rotated_path = self.directory.join(format!("{}.{}", self.file_name, suffix));
+        let mut index = 1;
+        while rotated_path.exists() {
+            rotated_path = self.directory.join(format!("{}.{}.{}", self.file_name, suffix, index));
+            index += 1;
+        }
+
+        let _ = fs::rename(self.path(), &rotated_path)?;
+        self.size = 0;
+        self.date = today();
+        self.remove_expired_files()
+    }
+
+    // The suffixes of the closed files start with the time, so they are sorted from the oldest
+    fn remove_expired_files(&self) -> io::Result<()> {
+        let prefix = format!("{}.", self.file_name);
+        let mut paths = fs::read_dir(&self.directory)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| is_rotated_file(path, &prefix))
+            .collect::<Vec<PathBuf>>();
+        paths.sort();
+        let expired_count = paths.len().saturating_sub(self.max_files);
+        for path in paths.into_iter().take(expired_count) {
+            let _ = fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
+
+fn today() -> NaiveDate { Local::now().naive_local().date() }
+
+fn is_rotated_file(path: &Path, prefix: &str) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map_or(false, |name| name.starts_with(prefix))
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let _ = self.open()?;
+        if self.should_rotate(buf.len()) {
+            let _ = self.rotate()?;
+        }
+        let size = self.open()?.write(buf)?;
+        self.size += size as u64;
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.file.as_mut() {
+            None => Ok(()),
+            Some(file) => file.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!("lib-log-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.to_str().unwrap().to_owned()
+    }
+
+    fn rotated_files(dir: &str) -> Vec<String> {
+        let mut names = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_str().unwrap().to_owned())
+            .filter(|name| name != "flowy.log")
+            .collect::<Vec<String>>();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn rotate_by_size_test() {
+        let dir = temp_dir("size");
+        let mut file = RotatingFile::new(&dir, "flowy.log", Rotation::Size(10), 10);
+        file.write_all(b"12345678\n").unwrap();
+        file.write_all(b"abc\n").unwrap();
+        file.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(Path::new(&dir).join("flowy.log")).unwrap(), "abc\n");
+        let rotated = rotated_files(&dir);
+        assert_eq!(rotated.len(), 1);
+        assert_eq!(fs::read_to_string(Path::new(&dir).join(&rotated[0])).unwrap(), "12345678\n");
+    }
+
+    #[test]
+    fn remove_expired_files_test() {
+        let dir = temp_dir("retention");
+        let mut file = RotatingFile::new(&dir, "flowy.log", Rotation::Size(4), 2);
+        for line in &["aaa\n", "bbb\n", "ccc\n", "ddd\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+
+        // The current file and the two latest closed ones are kept
+        assert_eq!(fs::read_to_string(Path::new(&dir).join("flowy.log")).unwrap(), "ddd\n");
+        let rotated = rotated_files(&dir)
+            .iter()
+            .map(|name| fs::read_to_string(Path::new(&dir).join(name)).unwrap())
+            .collect::<Vec<String>>();
+        assert_eq!(rotated, vec!["bbb\n".to_owned(), "ccc\n".to_owned()]);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::errors::*;
+use std::{fs::File, io::Read, path::Path};
+#[cfg(feature = "sqlcipher")]
+use diesel::{connection::SimpleConnection, Connection, SqliteConnection};
+
+// The plaintext database file starts with the header, the encrypted one starts with the random salt
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+// The file that doesn't exist or is still empty isn't encrypted
+pub fn is_encrypted(path: &str) -> Result<bool> {
+    if !Path::new(path).exists() {
+        return Ok(false);
+    }
+    let mut header = Vec::with_capacity(SQLITE_HEADER.len());
+    let _ = File::open(path)?
+        .take(SQLITE_HEADER.len() as u64)
+        .read_to_end(&mut header)?;
+    Ok(header.len() == SQLITE_HEADER.len() && header != SQLITE_HEADER)
+}
+
+// SQLCipher derives the key of the database from the passphrase with PBKDF2 and the salt that's kept in
+// the file. The database is created encrypted if it doesn't exist.
+#[cfg(feature = "sqlcipher")]
+pub fn open_encrypted(path: &str, key: &str) -> Result<SqliteConnection> {
+    let conn = SqliteConnection::establish(path)?;
+    let _ = apply_key(&conn, key)?;
+    Ok(conn)
+}
+
+// The key must be set before anything else is read from the connection. SQLCipher doesn't check it until
+// the first page is read, so a wrong key fails here instead of in the first query of the caller.
+#[cfg(feature = "sqlcipher")]
+pub(crate) fn apply_key(conn: &SqliteConnection, key: &str) -> Result<()> {
+    let _ = conn.batch_execute(&format!("PRAGMA key = {};", quote(key)))?;
+    conn.batch_execute("SELECT count(*) FROM sqlite_master;")
+        .map_err(|_| ErrorKind::DatabaseKeyInvalid.into())
+}
+
+// Re-encrypts the plaintext database at the path with the key, the one that's already encrypted is kept.
+// The copy is encrypted next to the database and replaces it once it's complete, so the plaintext database
+// stays as it was if the copy fails. No connection to the database may be open.
+#[cfg(feature = "sqlcipher")]
+pub fn encrypt_database(path: &str, key: &str) -> Result<()> {
+    if !Path::new(path).exists() || is_encrypted(path)? {
+        return Ok(());
+    }
+
+    log::info!("Encrypt the database {}", path);
+    let encrypted_path = format!("{}.encrypted", path);
+    let _ = remove_file(&encrypted_path)?;
+    {
+        let conn = SqliteConnection::establish(path)?;
+        // The changes that are still in the WAL file are written into the database before it's exported
+        let _ = conn.batch_execute("PRAGMA wal_checkpoint(TRUNCATE);")?;
+        let _ = conn.batch_execute(&format!(
+            "ATTACH DATABASE {} AS encrypted KEY {}; SELECT sqlcipher_export('encrypted'); DETACH DATABASE encrypted;",
+            quote(&encrypted_path),
+            quote(key)
+        ))?;
+    }
+
+    let _ = std::fs::rename(&encrypted_path, path)?;
+    let _ = remove_file(&format!("{}-wal", path))?;
+    let _ = remove_file(&format!("{}-shm", path))?;
+    Ok(())
+}
+
+#[cfg(feature = "sqlcipher")]
+fn quote(value: &str) -> String { format!("'{}'", value.replace('\'', "''")) }
+
+#[cfg(feature = "sqlcipher")]
+fn remove_file(path: &str) -> Result<()> {
+    if Path::new(path).exists() {
+        let _ = std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sqlcipher"))]
+mod tests {
+    use super::*;
+
+    fn temp_db_path(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!("lib-sqlite-cipher-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name).to_str().unwrap().to_owned();
+        let _ = remove_file(&path);
+        path
+    }
+
+    fn read_names(conn: &SqliteConnection) -> Vec<String> {
+        use diesel::{sql_query, sql_types::Text, RunQueryDsl};
+        use diesel_derives::QueryableByName;
+        #[derive(QueryableByName)]
+        struct Name {
+            #[sql_type = "Text"]
+            name: String,
+        }
+        sql_query("SELECT name FROM names ORDER BY name")
+            .load::<Name>(conn)
+            .unwrap()
+            .into_iter()
+            .map(|row| row.name)
+            .collect()
+    }
+
+    #[test]
+    fn encrypt_plaintext_database_test() {
+        let path = temp_db_path("plaintext.db");
+        {
+            let conn = SqliteConnection::establish(&path).unwrap();
+            conn.batch_execute("CREATE TABLE names (name TEXT); INSERT INTO names VALUES ('annie'), ('nathan');")
+                .unwrap();
+        }
+        assert!(!is_encrypted(&path).unwrap());
+
+        encrypt_database(&path, "it's a passphrase").unwrap();
+        assert!(is_encrypted(&path).unwrap());
+        assert!(!Path::new(&format!("{}.encrypted", path)).exists());
+
+        let conn = open_encrypted(&path, "it's a passphrase").unwrap();
+        assert_eq!(read_names(&conn), vec!["annie".to_owned(), "nathan".to_owned()]);
+
+        // The database that's encrypted already is kept
+        drop(conn);
+        encrypt_database(&path, "another passphrase").unwrap();
+        assert!(open_encrypted(&path, "it's a passphrase").is_ok());
+    }
+
+    #[test]
+    fn open_encrypted_with_wrong_key_test() {
+        let path = temp_db_path("wrong_key.db");
+        {
+            let conn = open_encrypted(&path, "passphrase").unwrap();
+            conn.batch_execute("CREATE TABLE names (name TEXT);").unwrap();
+        }
+        assert!(is_encrypted(&path).unwrap());
+        match open_encrypted(&path, "wrong passphrase") {
+            Err(Error(ErrorKind::DatabaseKeyInvalid, _)) => {},
+            _ => panic!("The wrong key should be rejected"),
+        }
+    }
+}
@@ -27,6 +27,17 @@ impl Database {
         })
     }
 
+    // The database file is encrypted with the key, see open_encrypted
+    #[cfg(feature = "sqlcipher")]
+    pub fn new_encrypted(dir: &str, name: &str, key: &str, pool_config: PoolConfig) -> Result<Self> {
+        let uri = db_file_uri(dir, name);
+        let pool = ConnectionPool::new_encrypted(pool_config, &uri, key)?;
+        Ok(Self {
+            uri,
+            pool: Arc::new(pool),
+        })
+    }
+
     pub fn get_uri(&self) -> &str { &self.uri }
 
     pub fn get_connection(&self) -> Result<DBConnection> {
@@ -11,6 +11,15 @@ error_chain! {
         UnknownMigrationExists(v: String) {
              display("unknown migration version: '{}'", v),
         }
+        InvalidMigration(name: String) {
+             display("invalid migration version or order: '{}'", name),
+        }
+        MigrationChanged(name: String) {
+             display("applied migration was changed: '{}'", name),
+        }
+        DatabaseKeyInvalid {
+             display("the key can't decrypt the database"),
+        }
     }
     foreign_links {
         R2D2(::r2d2::Error);
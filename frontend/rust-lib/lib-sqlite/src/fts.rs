@@ -0,0 +1,146 @@
+use crate::segment::{remove_word_breaks, segment, FtsLanguage, WORD_BREAK};
+use diesel::{
+    sql_query,
+    sql_types::{BigInt, Double, Text},
+    QueryResult,
+    RunQueryDsl,
+    SqliteConnection,
+};
+use diesel_derives::QueryableByName;
+use std::sync::RwLock;
+
+// A full-text search table that is backed by the FTS5 extension of sqlite. The virtual table is created
+// by the migrations, e.g. CREATE VIRTUAL TABLE search_index USING fts5(id UNINDEXED, title, body).
+pub struct FtsTable {
+    name: &'static str,
+    language: RwLock<FtsLanguage>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum FtsColumn {
+    Title,
+    Body,
+}
+
+impl FtsColumn {
+    fn name(&self) -> &'static str {
+        match self {
+            FtsColumn::Title => "title",
+            FtsColumn::Body => "body",
+        }
+    }
+}
+
+#[derive(Debug, Clone, QueryableByName)]
+pub struct FtsMatch {
+    #[sql_type = "Text"]
+    pub id: String,
+
+    #[sql_type = "Text"]
+    pub title: String,
+
+    // The matched words are wrapped in <b></b>
+    #[sql_type = "Text"]
+    pub snippet: String,
+
+    // The higher the better
+    #[sql_type = "Double"]
+    pub rank: f64,
+}
+
+impl FtsTable {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            language: RwLock::new(FtsLanguage::default()),
+        }
+    }
+
+    pub fn language(&self) -> FtsLanguage { *self.language.read().unwrap() }
+
+    // The rows that were indexed with another language are segmented again when they are updated, until
+    // then only their whole sentences can be matched.
+    pub fn set_language(&self, language: FtsLanguage) { *self.language.write().unwrap() = language; }
+
+    // Replaces the text of the column, the row is created if the id wasn't indexed before.
+    pub fn update(&self, id: &str, column: FtsColumn, text: &str, conn: &SqliteConnection) -> QueryResult<()> {
+        let text = &segment(text, self.language());
+        let sql = format!("UPDATE {} SET {} = ? WHERE id = ?", self.name, column.name());
+        let count = sql_query(sql)
+            .bind::<Text, _>(text)
+            .bind::<Text, _>(id)
+            .execute(conn)?;
+
+        if count == 0 {
+            let (title, body) = match column {
+                FtsColumn::Title => (text, ""),
+                FtsColumn::Body => ("", text),
+            };
+            let sql = format!("INSERT INTO {}(id, title, body) VALUES (?, ?, ?)", self.name);
+            let _ = sql_query(sql)
+                .bind::<Text, _>(id)
+                .bind::<Text, _>(title)
+                .bind::<Text, _>(body)
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+
+    pub fn delete(&self, id: &str, conn: &SqliteConnection) -> QueryResult<()> {
+        let sql = format!("DELETE FROM {} WHERE id = ?", self.name);
+        let _ = sql_query(sql).bind::<Text, _>(id).execute(conn)?;
+        Ok(())
+    }
+
+    // Every word of the query must match, either in the title or in the body. The last word matches as
+    // a prefix, so the results show up while typing. The matches in the title weigh more.
+    pub fn search(&self, query: &str, limit: i64, conn: &SqliteConnection) -> QueryResult<Vec<FtsMatch>> {
+        let expression = match_expression(query, self.language());
+        if expression.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sql = format!(
+            "SELECT id, title, snippet({table}, -1, '<b>', '</b>', '...', 16) AS snippet, \
+             -bm25({table}, 0.0, 10.0, 1.0) AS rank FROM {table} WHERE {table} MATCH ? ORDER BY rank DESC LIMIT ?",
+            table = self.name
+        );
+        let matches = sql_query(sql)
+            .bind::<Text, _>(expression)
+            .bind::<BigInt, _>(limit)
+            .load::<FtsMatch>(conn)?
+            .into_iter()
+            .map(|fts_match| FtsMatch {
+                title: remove_word_breaks(&fts_match.title),
+                snippet: remove_word_breaks(&fts_match.snippet),
+                ..fts_match
+            })
+            .collect::<Vec<FtsMatch>>();
+        Ok(matches)
+    }
+}
+
+// The words are quoted, so the characters that have a meaning in the FTS5 query syntax are searched
+// as they are. The word is segmented the same way as the indexed text, the quoted segments are matched
+// as a phrase.
+fn match_expression(query: &str, language: FtsLanguage) -> String {
+    let words = query
+        .split_whitespace()
+        .map(|word| segment(&word.replace(&['"', WORD_BREAK][..], ""), language))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<String>>();
+
+    let count = words.len();
+    words
+        .into_iter()
+        .enumerate()
+        .map(|(index, word)| {
+            if index == count - 1 {
+                format!("\"{}\"*", word)
+            } else {
+                format!("\"{}\"", word)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
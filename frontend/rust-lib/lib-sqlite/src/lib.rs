@@ -1,11 +1,22 @@
+#[macro_use]
+extern crate diesel;
+
+mod cipher;
 mod conn_ext;
 mod database;
 #[allow(deprecated, clippy::large_enum_variant)]
 mod errors;
+mod fts;
+mod migration;
 mod pool;
 mod pragma;
+mod segment;
 
+pub use cipher::*;
 pub use database::*;
+pub use fts::*;
+pub use migration::*;
 pub use pool::*;
+pub use segment::FtsLanguage;
 
 pub use errors::{Error, ErrorKind, Result};
@@ -0,0 +1,210 @@
+use crate::errors::*;
+use diesel::{
+    connection::SimpleConnection,
+    insert_into,
+    insert_or_ignore_into,
+    sql_query,
+    sql_types::Text,
+    Connection,
+    ExpressionMethods,
+    QueryDsl,
+    RunQueryDsl,
+    SqliteConnection,
+};
+use diesel_derives::QueryableByName;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+table! {
+    __flowy_migrations (version) {
+        version -> Text,
+        name -> Text,
+        checksum -> Text,
+        run_on -> BigInt,
+    }
+}
+
+const CREATE_MIGRATION_TABLE: &str = "CREATE TABLE IF NOT EXISTS __flowy_migrations (
+    version TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL DEFAULT '',
+    checksum TEXT NOT NULL DEFAULT '',
+    run_on BIGINT NOT NULL DEFAULT 0
+)";
+
+// The table that diesel_migrations kept the applied versions in before this runner
+const DIESEL_MIGRATION_TABLE: &str = "__diesel_schema_migrations";
+
+// Embeds the up.sql of the migration folder into the binary. The dir is relative to the file that calls
+// the macro, the same as include_str!.
+#[macro_export]
+macro_rules! embed_migration {
+    ($dir:literal, $name:literal) => {
+        $crate::Migration::new($name, include_str!(concat!($dir, "/", $name, "/up.sql")))
+    };
+}
+
+#[derive(Debug, Clone)]
+pub struct Migration {
+    // The digits of the timestamp that the name of the folder starts with, e.g. 20211108052114 for
+    // 2021-11-08-052114_flowy-workspace-setting. It's the same version that diesel kept.
+    pub version: String,
+    pub name: &'static str,
+    pub up_sql: &'static str,
+}
+
+impl Migration {
+    pub fn new(name: &'static str, up_sql: &'static str) -> Self {
+        let version = name
+            .split('_')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect();
+        Self { version, name, up_sql }
+    }
+
+    // The applied migration must not be edited, the change would never run on the old databases. The
+    // FNV-1a hash is stable across the versions of Rust, unlike the DefaultHasher.
+    pub fn checksum(&self) -> String {
+        let hash = self.up_sql.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        format!("{:016x}", hash)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MigrationMode {
+    Apply,
+    // Runs the pending migrations in the transaction that is rolled back, so the database is unchanged
+    DryRun,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MigrationReport {
+    // The versions that were applied before the run
+    pub applied: Vec<String>,
+    // The versions that the run applied, or would apply in the DryRun mode
+    pub pending: Vec<String>,
+}
+
+pub struct MigrationRunner {
+    migrations: Vec<Migration>,
+}
+
+impl MigrationRunner {
+    // The migrations run in the order of the list, so their versions must be increasing
+    pub fn new(migrations: Vec<Migration>) -> Result<Self> {
+        let mut previous = String::new();
+        for migration in &migrations {
+            if migration.version.is_empty() || migration.version <= previous {
+                return Err(ErrorKind::InvalidMigration(migration.name.to_owned()).into());
+            }
+            previous = migration.version.clone();
+        }
+        Ok(Self { migrations })
+    }
+
+    // All the pending migrations run in one transaction, so the database stays at the version it was if
+    // any of them fails. The database that has the unknown or the edited migrations is rejected, e.g.
+    // the database that a newer version of the app created.
+    pub fn run(&self, conn: &SqliteConnection, mode: MigrationMode) -> Result<MigrationReport> {
+        let mut report = MigrationReport::default();
+        let result = conn.transaction::<_, Error, _>(|| {
+            let _ = conn.batch_execute(CREATE_MIGRATION_TABLE)?;
+            let _ = self.import_diesel_versions(conn)?;
+
+            let applied = __flowy_migrations::table
+                .select((__flowy_migrations::version, __flowy_migrations::checksum))
+                .order(__flowy_migrations::version.asc())
+                .load::<(String, String)>(conn)?;
+            for (version, checksum) in &applied {
+                match self.migrations.iter().find(|migration| &migration.version == version) {
+                    None => return Err(ErrorKind::UnknownMigrationExists(version.clone()).into()),
+                    Some(migration) if &migration.checksum() != checksum => {
+                        return Err(ErrorKind::MigrationChanged(migration.name.to_owned()).into());
+                    },
+                    Some(_) => {},
+                }
+            }
+            report.applied = applied.into_iter().map(|(version, _)| version).collect();
+
+            for migration in &self.migrations {
+                if report.applied.contains(&migration.version) {
+                    continue;
+                }
+                log::info!("Run the migration {}", migration.name);
+                let _ = conn.batch_execute(migration.up_sql)?;
+                let _ = insert_into(__flowy_migrations::table)
+                    .values((
+                        __flowy_migrations::version.eq(&migration.version),
+                        __flowy_migrations::name.eq(migration.name),
+                        __flowy_migrations::checksum.eq(migration.checksum()),
+                        __flowy_migrations::run_on.eq(timestamp()),
+                    ))
+                    .execute(conn)?;
+                report.pending.push(migration.version.clone());
+            }
+
+            match mode {
+                MigrationMode::Apply => Ok(()),
+                MigrationMode::DryRun => Err(ErrorKind::Diesel(diesel::result::Error::RollbackTransaction).into()),
+            }
+        });
+
+        match result {
+            Ok(_) => Ok(report),
+            Err(Error(ErrorKind::Diesel(diesel::result::Error::RollbackTransaction), _)) => Ok(report),
+            Err(e) => Err(e),
+        }
+    }
+
+    // The databases that diesel_migrations created keep their versions in its table, they are imported so
+    // those migrations don't run again. The table is kept for the older versions of the app.
+    fn import_diesel_versions(&self, conn: &SqliteConnection) -> Result<()> {
+        let tables = sql_query("SELECT name FROM sqlite_master WHERE type = 'table' AND name = ?")
+            .bind::<Text, _>(DIESEL_MIGRATION_TABLE)
+            .load::<TableName>(conn)?;
+        if tables.is_empty() {
+            return Ok(());
+        }
+
+        let versions = sql_query(format!("SELECT version FROM {}", DIESEL_MIGRATION_TABLE)).load::<Version>(conn)?;
+        for Version { version } in versions {
+            let migration = self
+                .migrations
+                .iter()
+                .find(|migration| migration.version == version)
+                .ok_or_else(|| Error::from(ErrorKind::UnknownMigrationExists(version.clone())))?;
+            let _ = insert_or_ignore_into(__flowy_migrations::table)
+                .values((
+                    __flowy_migrations::version.eq(&migration.version),
+                    __flowy_migrations::name.eq(migration.name),
+                    __flowy_migrations::checksum.eq(migration.checksum()),
+                    __flowy_migrations::run_on.eq(timestamp()),
+                ))
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(QueryableByName)]
+struct TableName {
+    #[sql_type = "Text"]
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[derive(QueryableByName)]
+struct Version {
+    #[sql_type = "Text"]
+    version: String,
+}
+
+fn timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default()
+}
@@ -1,15 +1,30 @@
 use crate::{errors::*, pragma::*};
 use diesel::{connection::Connection, SqliteConnection};
-use r2d2::{CustomizeConnection, ManageConnection, Pool};
+use r2d2::{
+    event::{CheckinEvent, HandleEvent},
+    CustomizeConnection,
+    ManageConnection,
+    Pool,
+};
 use scheduled_thread_pool::ScheduledThreadPool;
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 lazy_static::lazy_static! {
     static ref DB_POOL: Arc<ScheduledThreadPool> = Arc::new(
         ScheduledThreadPool::with_name("db-pool-{}:", 4)
     );
+    static ref CONNECTION_OBSERVER: RwLock<Option<ConnectionObserver>> = RwLock::new(None);
 }
 
+// Called with the time that each connection was checked out of the pools, i.e. the time of the queries or
+// the transaction that ran on it, e.g. to record the metrics.
+pub type ConnectionObserver = Box<dyn Fn(Duration) + Send + Sync>;
+
+pub fn set_connection_observer(observer: ConnectionObserver) { *CONNECTION_OBSERVER.write().unwrap() = Some(observer); }
+
 pub struct ConnectionPool {
     pub(crate) inner: Pool<ConnectionManager>,
 }
@@ -25,7 +40,21 @@ impl ConnectionPool {
     where
         T: Into<String>,
     {
-        let manager = ConnectionManager::new(uri);
+        Self::with_manager(config, ConnectionManager::new(uri))
+    }
+
+    // Every connection of the pool is opened with the key
+    #[cfg(feature = "sqlcipher")]
+    pub fn new_encrypted<T>(config: PoolConfig, uri: T, key: &str) -> Result<Self>
+    where
+        T: Into<String>,
+    {
+        let mut manager = ConnectionManager::new(uri);
+        manager.key = Some(key.to_owned());
+        Self::with_manager(config, manager)
+    }
+
+    fn with_manager(config: PoolConfig, manager: ConnectionManager) -> Result<Self> {
         let thread_pool = DB_POOL.clone();
         let config = Arc::new(config);
         let customizer_config = DatabaseCustomizerConfig::default();
@@ -34,6 +63,7 @@ impl ConnectionPool {
             .thread_pool(thread_pool)
             .min_idle(Some(config.min_idle))
             .connection_customizer(Box::new(DatabaseCustomizer::new(customizer_config)))
+            .event_handler(Box::new(ConnectionEventHandler))
             .max_size(config.max_size)
             .max_lifetime(None)
             .connection_timeout(config.connection_timeout)
@@ -79,13 +109,22 @@ impl PoolConfig {
 
 pub struct ConnectionManager {
     db_uri: String,
+    #[cfg(feature = "sqlcipher")]
+    key: Option<String>,
 }
 
 impl ManageConnection for ConnectionManager {
     type Connection = SqliteConnection;
     type Error = crate::Error;
 
-    fn connect(&self) -> Result<Self::Connection> { Ok(SqliteConnection::establish(&self.db_uri)?) }
+    fn connect(&self) -> Result<Self::Connection> {
+        let conn = SqliteConnection::establish(&self.db_uri)?;
+        #[cfg(feature = "sqlcipher")]
+        if let Some(key) = &self.key {
+            let _ = crate::cipher::apply_key(&conn, key)?;
+        }
+        Ok(conn)
+    }
 
     fn is_valid(&self, conn: &mut Self::Connection) -> Result<()> { Ok(conn.execute("SELECT 1").map(|_| ())?) }
 
@@ -93,7 +132,13 @@ impl ManageConnection for ConnectionManager {
 }
 
 impl ConnectionManager {
-    pub fn new<S: Into<String>>(uri: S) -> Self { ConnectionManager { db_uri: uri.into() } }
+    pub fn new<S: Into<String>>(uri: S) -> Self {
+        ConnectionManager {
+            db_uri: uri.into(),
+            #[cfg(feature = "sqlcipher")]
+            key: None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -140,3 +185,14 @@ impl CustomizeConnection<SqliteConnection, crate::Error> for DatabaseCustomizer
         Ok(())
     }
 }
+
+#[derive(Debug)]
+struct ConnectionEventHandler;
+
+impl HandleEvent for ConnectionEventHandler {
+    fn handle_checkin(&self, event: CheckinEvent) {
+        if let Some(observer) = CONNECTION_OBSERVER.read().unwrap().as_ref() {
+            observer(event.duration());
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use jieba_rs::Jieba;
+use lindera::tokenizer::Tokenizer;
+use std::sync::Mutex;
+
+// The unicode61 tokenizer of the FTS5 only splits the text at the separators, so the Chinese or the
+// Japanese sentence is indexed as a single word. The words of these languages are separated with the
+// zero width space before the text is indexed, it's a separator for the unicode61 tokenizer.
+pub(crate) const WORD_BREAK: char = '\u{200B}';
+
+lazy_static::lazy_static! {
+    static ref JIEBA: Jieba = Jieba::new();
+    static ref LINDERA: Option<Mutex<Tokenizer>> = match Tokenizer::new() {
+        Ok(tokenizer) => Some(Mutex::new(tokenizer)),
+        Err(e) => {
+            log::error!("Create the japanese tokenizer failed: {:?}", e);
+            None
+        },
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FtsLanguage {
+    // Splits the words at the whitespaces and the punctuations
+    Default,
+    Chinese,
+    Japanese,
+}
+
+impl std::default::Default for FtsLanguage {
+    fn default() -> Self { FtsLanguage::Default }
+}
+
+// Inserts the WORD_BREAK between the adjacent words of the text. The text is returned as it is if the
+// language is the default one.
+pub(crate) fn segment(text: &str, language: FtsLanguage) -> String {
+    let words = match language {
+        FtsLanguage::Default => return text.to_owned(),
+        FtsLanguage::Chinese => JIEBA.cut(text, true),
+        FtsLanguage::Japanese => match LINDERA.as_ref().and_then(|tokenizer| tokenizer.lock().ok()) {
+            None => return text.to_owned(),
+            Some(mut tokenizer) => match tokenizer.tokenize(text) {
+                Ok(tokens) => tokens.into_iter().map(|token| token.text).collect::<Vec<&str>>(),
+                Err(e) => {
+                    log::error!("Tokenize the japanese text failed: {:?}", e);
+                    return text.to_owned();
+                },
+            },
+        },
+    };
+
+    let mut segmented = String::with_capacity(text.len());
+    let mut offset = 0;
+    for word in words {
+        let start = match text[offset..].find(word) {
+            None => continue,
+            Some(index) => offset + index,
+        };
+        let is_adjacent = start == offset
+            && segmented.ends_with(|c: char| c.is_alphanumeric())
+            && word.starts_with(|c: char| c.is_alphanumeric());
+        if is_adjacent {
+            segmented.push(WORD_BREAK);
+        }
+        segmented.push_str(&text[offset..start]);
+        segmented.push_str(word);
+        offset = start + word.len();
+    }
+    segmented.push_str(&text[offset..]);
+    segmented
+}
+
+pub(crate) fn remove_word_breaks(text: &str) -> String { text.replace(WORD_BREAK, "") }
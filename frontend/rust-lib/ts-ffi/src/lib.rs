@@ -0,0 +1,40 @@
+mod response;
+
+use crate::response::TSResponse;
+use backend_service::config::ServerConfig;
+use flowy_sdk::*;
+use lazy_static::lazy_static;
+use lib_dispatch::prelude::*;
+use parking_lot::RwLock;
+use std::sync::Arc;
+use wasm_bindgen::prelude::*;
+
+// The entry points of the web frontend, they're exported with wasm-bindgen. The payloads are the same
+// protobuf bytes that dart sends, their typescript declarations are generated by the ts-event command
+// of flowy-tool.
+
+lazy_static! {
+    static ref FLOWY_SDK: RwLock<Option<Arc<FlowySDK>>> = RwLock::new(None);
+}
+
+fn dispatch() -> Option<Arc<EventDispatch>> { FLOWY_SDK.read().as_ref().map(|sdk| sdk.dispatch()) }
+
+#[wasm_bindgen]
+pub fn init_sdk(path: &str) -> i64 {
+    let server_config = ServerConfig::default();
+    let config = FlowySDKConfig::new(path, server_config, "appflowy").log_filter("debug");
+    *FLOWY_SDK.write() = Some(Arc::new(FlowySDK::new(config)));
+    1
+}
+
+// The event is the name of the event, e.g. "CreateWorkspace". The promise is resolved with the response
+// of the event even if the event failed, it's only rejected if the sdk isn't initialized.
+#[wasm_bindgen]
+pub async fn async_event(event: String, payload: Vec<u8>) -> Result<TSResponse, JsValue> {
+    let dispatch = dispatch().ok_or_else(|| JsValue::from_str("The sdk isn't initialized"))?;
+    let request = ModuleRequest::new(event).payload(payload);
+    log::trace!("[TS]: {} Async Event: {:?}", &request.id, &request.event);
+
+    let response = EventDispatch::async_send(dispatch, request).await;
+    Ok(TSResponse::from(response))
+}
@@ -0,0 +1,58 @@
+use lib_dispatch::prelude::{EventResponse, Payload, StatusCode};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TSStatusCode {
+    Ok       = 0,
+    Err      = 1,
+    Internal = 2,
+    Timeout  = 3,
+}
+
+impl std::convert::From<StatusCode> for TSStatusCode {
+    fn from(code: StatusCode) -> Self {
+        match code {
+            StatusCode::Ok => TSStatusCode::Ok,
+            StatusCode::Err => TSStatusCode::Err,
+            StatusCode::Internal => TSStatusCode::Internal,
+            StatusCode::Timeout => TSStatusCode::Timeout,
+        }
+    }
+}
+
+// The payload is the protobuf bytes of the output of the event if the code is Ok, otherwise the bytes of
+// its error.
+#[wasm_bindgen]
+pub struct TSResponse {
+    code: TSStatusCode,
+    payload: Vec<u8>,
+    request_id: String,
+}
+
+#[wasm_bindgen]
+impl TSResponse {
+    #[wasm_bindgen(getter)]
+    pub fn code(&self) -> TSStatusCode { self.code }
+
+    #[wasm_bindgen(getter)]
+    pub fn payload(&self) -> Vec<u8> { self.payload.clone() }
+
+    #[wasm_bindgen(getter)]
+    pub fn request_id(&self) -> String { self.request_id.clone() }
+}
+
+impl std::convert::From<EventResponse> for TSResponse {
+    fn from(resp: EventResponse) -> Self {
+        let payload = match resp.payload {
+            Payload::Bytes(bytes) => bytes.to_vec(),
+            Payload::None => vec![],
+        };
+
+        TSResponse {
+            code: resp.status_code.into(),
+            payload,
+            request_id: resp.request_id,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 mod config;
 mod dart_event;
 mod proto;
+mod ts_event;
 mod util;
 use clap::{App, Arg};
 
@@ -33,6 +34,21 @@ fn main() {
         };
         code_gen.gen();
     }
+
+    if let Some(ref matches) = matches.subcommand_matches("ts-event") {
+        let rust_sources = matches
+            .values_of("rust_source")
+            .unwrap()
+            .map(|source| source.to_string())
+            .collect::<Vec<String>>();
+        let output_file = matches.value_of("output").unwrap().to_string();
+
+        let code_gen = ts_event::TsEventCodeGen {
+            rust_sources,
+            output_file,
+        };
+        code_gen.gen();
+    }
 }
 
 pub fn app<'a, 'b>() -> App<'a, 'b> {
@@ -75,6 +91,23 @@ pub fn app<'a, 'b>() -> App<'a, 'b> {
                         .long("output")
                         .value_name("DIRECTORY"),
                 ),
+        )
+        .subcommand(
+            App::new("ts-event")
+                .about("Generate the typescript declarations of the events and their payloads")
+                .arg(
+                    Arg::with_name("rust_source")
+                        .long("rust_source")
+                        .value_name("DIRECTORY")
+                        .multiple(true)
+                        .number_of_values(1)
+                        .help("Directories of the cargo workspaces, e.g. rust-lib and shared-lib"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .value_name("FILE"),
+                ),
         );
 
     app
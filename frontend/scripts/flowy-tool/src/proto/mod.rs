@@ -4,5 +4,7 @@ mod proto_gen;
 mod proto_info;
 mod template;
 
+pub(crate) use ast::{get_ast_enums, get_ast_structs};
 pub use builder::*;
+pub(crate) use proto_info::parse_crate_info_from_path;
 pub use proto_gen::*;
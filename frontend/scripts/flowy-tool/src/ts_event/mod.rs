@@ -0,0 +1,4 @@
+mod ts_event;
+mod ts_template;
+
+pub use ts_event::*;
@@ -0,0 +1,97 @@
+use super::ts_template::*;
+use crate::dart_event::{parse_dart_event_files, parse_event_crate};
+use crate::proto::{get_ast_enums, get_ast_structs, parse_crate_info_from_path};
+use crate::util::*;
+use std::collections::BTreeMap;
+use walkdir::WalkDir;
+
+// Generates the typescript declarations of the protobuf types and the events, from the same ast that
+// the proto files and the dart events are generated from.
+pub struct TsEventCodeGen {
+    pub rust_sources: Vec<String>,
+    pub output_file: String,
+}
+
+impl TsEventCodeGen {
+    pub fn gen(&self) {
+        let mut enums: BTreeMap<String, TsEnum> = BTreeMap::new();
+        let mut interfaces: BTreeMap<String, TsInterface> = BTreeMap::new();
+        let mut event_enums: Vec<TsEventEnum> = vec![];
+        for rust_source in &self.rust_sources {
+            for proto_crate in parse_crate_info_from_path(rust_source) {
+                for proto_path in &proto_crate.proto_paths {
+                    parse_ts_types(proto_path, &mut enums, &mut interfaces);
+                }
+            }
+
+            let event_crates = parse_dart_event_files(rust_source);
+            for event_ast in event_crates.iter().flat_map(|c| parse_event_crate(c)) {
+                let event = TsEvent {
+                    event: event_ast.event.to_string(),
+                    input: path_to_ts_type(&event_ast.event_input),
+                    output: path_to_ts_type(&event_ast.event_output),
+                    error: event_ast.event_error.clone(),
+                };
+                let name = event_ast.event_ty.to_string();
+                match event_enums.iter_mut().find(|e| e.name == name) {
+                    Some(event_enum) => event_enum.events.push(event),
+                    None => event_enums.push(TsEventEnum {
+                        name,
+                        events: vec![event],
+                    }),
+                }
+            }
+        }
+
+        // The events are sent by their names, so the event enums are declared with the names instead
+        for event_enum in &event_enums {
+            enums.remove(&event_enum.name);
+        }
+
+        let enums = enums.into_iter().map(|(_, e)| e).collect::<Vec<_>>();
+        let interfaces = interfaces.into_iter().map(|(_, i)| i).collect::<Vec<_>>();
+        let mut template = TsTemplate::new();
+        if let Some(content) = template.render(&enums, &interfaces, &event_enums) {
+            save_content_to_file_with_diff_prompt(content.as_ref(), self.output_file.as_str(), true);
+        }
+    }
+}
+
+fn path_to_ts_type(path: &Option<syn::Path>) -> String {
+    match path {
+        Some(path) => path.get_ident().unwrap().to_string(),
+        None => "void".to_owned(),
+    }
+}
+
+// The types with the same name in different crates are declared once
+fn parse_ts_types(
+    proto_path: &str,
+    enums: &mut BTreeMap<String, TsEnum>,
+    interfaces: &mut BTreeMap<String, TsInterface>,
+) {
+    for entry in WalkDir::new(proto_path)
+        .into_iter()
+        .filter_entry(|e| !is_hidden(e))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_dir() == false)
+    {
+        let path = entry.path().to_str().unwrap().to_string();
+        if entry.path().file_stem().unwrap() == "mod" {
+            continue;
+        }
+
+        let ast = syn::parse_file(read_file(&path).unwrap().as_ref()).expect("Unable to parse file");
+        for s in get_ast_structs(&ast) {
+            interfaces
+                .entry(s.name.clone())
+                .or_insert_with(|| TsInterface::from_fields(&s.name, &s.fields));
+        }
+
+        for e in get_ast_enums(&ast) {
+            enums
+                .entry(e.name.clone())
+                .or_insert_with(|| TsEnum::from_variants(&e.name, &e.attrs));
+        }
+    }
+}
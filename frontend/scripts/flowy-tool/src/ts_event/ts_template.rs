@@ -0,0 +1,149 @@
+use crate::util::get_tera;
+use flowy_ast::*;
+use phf::phf_map;
+use serde::Serialize;
+use tera::Context;
+
+// The protobuf numbers are numbers in typescript, the 64-bit ones lose precision above 2^53
+static TS_TYPE_MAP: phf::Map<&'static str, &'static str> = phf_map! {
+    "String" => "string",
+    "bool" => "boolean",
+    "i64" => "number",
+    "i32" => "number",
+    "u64" => "number",
+    "u32" => "number",
+    "u8" => "number",
+    "f64" => "number",
+    "f32" => "number",
+};
+
+fn ts_type(ty: &str) -> String {
+    TS_TYPE_MAP.get(ty).unwrap_or(&ty).to_string()
+}
+
+#[derive(Serialize)]
+pub struct TsEnum {
+    pub name: String,
+    pub items: Vec<TsEnumItem>,
+}
+
+#[derive(Serialize)]
+pub struct TsEnumItem {
+    pub name: String,
+    pub value: String,
+}
+
+impl TsEnum {
+    pub fn from_variants(name: &str, variants: &Vec<ASTEnumVariant>) -> Self {
+        let items = variants
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| {
+                // The variants without the discriminant are numbered like the protobuf enums
+                let value = match variant.attrs.value.is_empty() {
+                    true => index.to_string(),
+                    false => variant.attrs.value.clone(),
+                };
+                TsEnumItem {
+                    name: variant.attrs.enum_item_name.clone(),
+                    value,
+                }
+            })
+            .collect();
+        TsEnum {
+            name: name.to_owned(),
+            items,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct TsInterface {
+    pub name: String,
+    pub fields: Vec<TsField>,
+}
+
+#[derive(Serialize)]
+pub struct TsField {
+    pub name: String,
+    pub ty: String,
+    pub optional: bool,
+}
+
+impl TsInterface {
+    pub fn from_fields(name: &str, fields: &Vec<ASTField>) -> Self {
+        let fields = fields
+            .iter()
+            .filter(|f| f.attrs.pb_index().is_some())
+            .flat_map(|f| ts_field(f))
+            .collect();
+        TsInterface {
+            name: name.to_owned(),
+            fields,
+        }
+    }
+}
+
+// Mirrors the field types of the StructTemplate of the proto files
+fn ts_field(field: &ASTField) -> Option<TsField> {
+    let name = field.name()?.to_string();
+    let ty = field.ty_as_str();
+    let (ty, optional) = match field.bracket_category.as_ref()? {
+        BracketCategory::Opt => match field.bracket_inner_ty.as_ref()?.to_string().as_str() {
+            "Vec" => ("Uint8Array".to_owned(), true),
+            _ => (ts_type(&ty), true),
+        },
+        BracketCategory::Map((k, v)) => (format!("{{ [key: {}]: {} }}", ts_type(k), ts_type(v)), false),
+        BracketCategory::Vec => match ty.as_str() {
+            "u8" => ("Uint8Array".to_owned(), false),
+            _ => (format!("{}[]", ts_type(&ty)), false),
+        },
+        BracketCategory::Other => (ts_type(&ty), false),
+    };
+    Some(TsField { name, ty, optional })
+}
+
+#[derive(Serialize)]
+pub struct TsEventEnum {
+    pub name: String,
+    pub events: Vec<TsEvent>,
+}
+
+#[derive(Serialize)]
+pub struct TsEvent {
+    pub event: String,
+    pub input: String,
+    pub output: String,
+    pub error: String,
+}
+
+pub struct TsTemplate {
+    context: Context,
+}
+
+impl TsTemplate {
+    pub fn new() -> Self {
+        TsTemplate {
+            context: Context::new(),
+        }
+    }
+
+    pub fn render(
+        &mut self,
+        enums: &Vec<TsEnum>,
+        interfaces: &Vec<TsInterface>,
+        event_enums: &Vec<TsEventEnum>,
+    ) -> Option<String> {
+        self.context.insert("enums", enums);
+        self.context.insert("interfaces", interfaces);
+        self.context.insert("event_enums", event_enums);
+        let tera = get_tera("ts_event");
+        match tera.render("ts_template.tera", &self.context) {
+            Ok(r) => Some(r),
+            Err(e) => {
+                log::error!("{:?}", e);
+                None
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@ pub const HOST: &'static str = "localhost:8000";
 pub const HTTP_SCHEMA: &'static str = "http";
 pub const WS_SCHEMA: &'static str = "ws";
 pub const HEADER_TOKEN: &'static str = "token";
+pub const HEADER_IDEMPOTENCY_KEY: &'static str = "Idempotency-Key";
+pub const HEADER_REQUEST_ID: &'static str = "request-id";
 
 #[derive(Debug, Clone)]
 pub struct ServerConfig {
@@ -35,18 +37,106 @@ impl ServerConfig {
 
     pub fn sign_in_url(&self) -> String { format!("{}{}/api/auth", self.scheme(), self.host) }
 
+    pub fn sign_in_with_provider_url(&self) -> String {
+        format!("{}{}/api/auth/provider", self.scheme(), self.host)
+    }
+
+    pub fn password_reset_url(&self) -> String {
+        format!("{}{}/api/auth/password_reset", self.scheme(), self.host)
+    }
+
+    pub fn confirm_password_reset_url(&self) -> String {
+        format!("{}{}/api/auth/password_reset/confirm", self.scheme(), self.host)
+    }
+
+    pub fn refresh_token_url(&self) -> String { format!("{}{}/api/auth/refresh", self.scheme(), self.host) }
+
+    pub fn email_verify_url(&self) -> String { format!("{}{}/api/auth/email_verify", self.scheme(), self.host) }
+
+    pub fn confirm_email_verify_url(&self) -> String {
+        format!("{}{}/api/auth/email_verify/confirm", self.scheme(), self.host)
+    }
+
     pub fn sign_out_url(&self) -> String { format!("{}{}/api/auth", self.scheme(), self.host) }
 
     pub fn user_profile_url(&self) -> String { format!("{}{}/api/user", self.scheme(), self.host) }
 
     pub fn workspace_url(&self) -> String { format!("{}{}/api/workspace", self.scheme(), self.host) }
 
+    pub fn workspace_archive_url(&self) -> String { format!("{}{}/api/workspace/archive", self.scheme(), self.host) }
+
+    pub fn workspace_member_url(&self) -> String { format!("{}{}/api/workspace/member", self.scheme(), self.host) }
+
+    pub fn workspace_clone_url(&self) -> String { format!("{}{}/api/workspace/clone", self.scheme(), self.host) }
+
+    pub fn workspace_backup_url(&self, workspace_id: &str) -> String {
+        format!("{}{}/api/workspace/backup/{}", self.scheme(), self.host, workspace_id)
+    }
+
+    pub fn workspace_restore_url(&self) -> String { format!("{}{}/api/workspace/restore", self.scheme(), self.host) }
+
+    pub fn bot_url(&self) -> String { format!("{}{}/api/workspace/bot", self.scheme(), self.host) }
+
+    pub fn workspace_usage_url(&self) -> String { format!("{}{}/api/workspace/usage", self.scheme(), self.host) }
+
+    pub fn workspace_search_url(&self) -> String { format!("{}{}/api/workspace/search", self.scheme(), self.host) }
+
     pub fn app_url(&self) -> String { format!("{}{}/api/app", self.scheme(), self.host) }
 
+    pub fn app_move_url(&self) -> String { format!("{}{}/api/app/move", self.scheme(), self.host) }
+
+    pub fn app_duplicate_url(&self) -> String { format!("{}{}/api/app/duplicate", self.scheme(), self.host) }
+
     pub fn view_url(&self) -> String { format!("{}{}/api/view", self.scheme(), self.host) }
 
+    pub fn view_move_url(&self) -> String { format!("{}{}/api/view/move", self.scheme(), self.host) }
+
+    pub fn view_duplicate_url(&self) -> String { format!("{}{}/api/view/duplicate", self.scheme(), self.host) }
+
+    pub fn view_publish_url(&self) -> String { format!("{}{}/api/view/publish", self.scheme(), self.host) }
+
+    pub fn view_publish_pin_url(&self) -> String { format!("{}{}/api/view/publish/pin", self.scheme(), self.host) }
+
+    pub fn view_publish_latest_url(&self) -> String {
+        format!("{}{}/api/view/publish/latest", self.scheme(), self.host)
+    }
+
+    pub fn view_access_log_url(&self) -> String {
+        format!("{}{}/api/view/publish/access", self.scheme(), self.host)
+    }
+
+    pub fn published_page_url(&self, slug: &str) -> String {
+        format!("{}{}/published/{}", self.scheme(), self.host, slug)
+    }
+
+    pub fn share_link_url(&self) -> String { format!("{}{}/api/view/share", self.scheme(), self.host) }
+
+    pub fn shared_page_url(&self, link_id: &str) -> String {
+        format!("{}{}/shared/{}", self.scheme(), self.host, link_id)
+    }
+
+    pub fn favorite_url(&self) -> String { format!("{}{}/api/view/favorite", self.scheme(), self.host) }
+
+    pub fn recent_view_url(&self) -> String { format!("{}{}/api/view/recent", self.scheme(), self.host) }
+
+    pub fn view_webhook_url(&self) -> String { format!("{}{}/api/view/webhook", self.scheme(), self.host) }
+
     pub fn doc_url(&self) -> String { format!("{}{}/api/doc", self.scheme(), self.host) }
 
+    pub fn doc_reset_url(&self) -> String { format!("{}{}/api/doc/reset", self.scheme(), self.host) }
+
+    pub fn doc_snapshot_url(&self) -> String { format!("{}{}/api/doc/snapshot", self.scheme(), self.host) }
+
+    pub fn doc_snapshot_restore_url(&self) -> String {
+        format!("{}{}/api/doc/snapshot/restore", self.scheme(), self.host)
+    }
+
+    pub fn attachment_url(&self) -> String { format!("{}{}/api/attachment", self.scheme(), self.host) }
+
+    pub fn attachment_chunk_url(&self) -> String { format!("{}{}/api/attachment/chunk", self.scheme(), self.host) }
+
+    pub fn automation_url(&self) -> String { format!("{}{}/api/automation", self.scheme(), self.host) }
+
     pub fn trash_url(&self) -> String { format!("{}{}/api/trash", self.scheme(), self.host) }
 
     pub fn ws_addr(&self) -> String { format!("{}://{}/ws", self.ws_schema, self.host) }
@@ -32,11 +32,17 @@ impl ServerError {
     static_error!(unauthorized, ErrorCode::UserUnauthorized);
     static_error!(password_not_match, ErrorCode::PasswordNotMatch);
     static_error!(params_invalid, ErrorCode::ParamsInvalid);
+    static_error!(payload_overflow, ErrorCode::PayloadOverflow);
     static_error!(connect_timeout, ErrorCode::ConnectTimeout);
     static_error!(connect_close, ErrorCode::ConnectClose);
     static_error!(connect_cancel, ErrorCode::ConnectCancel);
     static_error!(connect_refused, ErrorCode::ConnectRefused);
     static_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_error!(workspace_archived, ErrorCode::WorkspaceArchived);
+    static_error!(permission_denied, ErrorCode::PermissionDenied);
+    static_error!(too_many_requests, ErrorCode::TooManyRequests);
+    static_error!(password_reset_token_invalid, ErrorCode::PasswordResetTokenInvalid);
+    static_error!(email_verify_token_invalid, ErrorCode::EmailVerifyTokenInvalid);
 
     pub fn new(msg: String, code: ErrorCode) -> Self { Self { code, msg } }
 
@@ -48,6 +54,8 @@ impl ServerError {
     pub fn is_record_not_found(&self) -> bool { self.code == ErrorCode::RecordNotFound }
 
     pub fn is_unauthorized(&self) -> bool { self.code == ErrorCode::UserUnauthorized }
+
+    pub fn is_permission_denied(&self) -> bool { self.code == ErrorCode::PermissionDenied }
 }
 
 pub fn internal_error<T>(e: T) -> ServerError
@@ -88,6 +96,8 @@ pub enum ErrorCode {
     PayloadUnexpectedNone = 4,
     #[display(fmt = "Params is invalid")]
     ParamsInvalid      = 5,
+    #[display(fmt = "Too many requests")]
+    TooManyRequests    = 6,
 
     #[display(fmt = "Protobuf serde error")]
     ProtobufError      = 10,
@@ -99,6 +109,15 @@ pub enum ErrorCode {
 
     #[display(fmt = "Username and password do not match")]
     PasswordNotMatch   = 51,
+    #[display(fmt = "The password reset token is invalid or expired")]
+    PasswordResetTokenInvalid = 52,
+    #[display(fmt = "The email verify token is invalid or expired")]
+    EmailVerifyTokenInvalid = 53,
+
+    #[display(fmt = "Workspace is archived")]
+    WorkspaceArchived  = 60,
+    #[display(fmt = "The user doesn't have the permission")]
+    PermissionDenied   = 61,
 
     #[display(fmt = "Connect refused")]
     ConnectRefused     = 100,
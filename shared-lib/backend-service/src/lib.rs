@@ -1,6 +1,7 @@
 pub mod config;
 pub mod errors;
 pub mod middleware;
+pub mod oauth;
 pub mod request;
 pub mod response;
 pub mod user_request;
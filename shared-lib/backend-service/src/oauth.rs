@@ -0,0 +1,225 @@
+use crate::{errors::ServerError, request::default_client};
+use flowy_user_infra::entities::AuthProvider;
+use rand::{distributions::Alphanumeric, Rng};
+use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use serde::{de::DeserializeOwned, Deserialize};
+use sha2::{Digest, Sha256};
+
+// https://datatracker.ietf.org/doc/html/rfc7636#section-4.1
+const CODE_VERIFIER_LEN: usize = 64;
+// GitHub rejects the requests without the user agent
+const OAUTH_USER_AGENT: &str = "flowy-server";
+
+// The client keeps the verifier and sends the challenge with the authorization request. The verifier is
+// sent with the code when it's exchanged for the token, so the code that was intercepted is useless.
+#[derive(Debug, Clone)]
+pub struct PkceChallenge {
+    pub code_verifier: String,
+    pub code_challenge: String,
+}
+
+impl PkceChallenge {
+    pub fn new() -> Self {
+        let code_verifier = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(CODE_VERIFIER_LEN)
+            .map(char::from)
+            .collect::<String>();
+        let code_challenge = code_challenge(&code_verifier);
+        Self {
+            code_verifier,
+            code_challenge,
+        }
+    }
+}
+
+// The S256 method, the challenge is the base64url of the sha256 of the verifier
+pub fn code_challenge(code_verifier: &str) -> String {
+    let digest = Sha256::digest(code_verifier.as_bytes());
+    base64::encode_config(digest, base64::URL_SAFE_NO_PAD)
+}
+
+// The account of the user at the provider. The subject never changes, the email may be changed by the
+// user, so the identity is linked to the user by the subject.
+#[derive(Debug, Clone)]
+pub struct ProviderIdentity {
+    pub provider: AuthProvider,
+    pub subject: String,
+    pub email: String,
+    pub email_verified: bool,
+    pub name: String,
+}
+
+// The app that is registered with the provider. It's only used by the server, the secret must not be
+// shipped with the client.
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    pub provider: AuthProvider,
+    pub client_id: String,
+    pub client_secret: String,
+    pub token_url: String,
+    pub user_info_url: String,
+    // GitHub doesn't return the private email with the user info
+    pub emails_url: Option<String>,
+}
+
+impl OAuthClient {
+    pub fn new(provider: AuthProvider, client_id: &str, client_secret: &str) -> Self {
+        let (token_url, user_info_url, emails_url) = match provider {
+            AuthProvider::Google => (
+                "https://oauth2.googleapis.com/token",
+                "https://openidconnect.googleapis.com/v1/userinfo",
+                None,
+            ),
+            AuthProvider::GitHub => (
+                "https://github.com/login/oauth/access_token",
+                "https://api.github.com/user",
+                Some("https://api.github.com/user/emails".to_owned()),
+            ),
+        };
+
+        Self {
+            provider,
+            client_id: client_id.to_owned(),
+            client_secret: client_secret.to_owned(),
+            token_url: token_url.to_owned(),
+            user_info_url: user_info_url.to_owned(),
+            emails_url,
+        }
+    }
+
+    // Exchanges the code for the access token and then reads the account that the token belongs to
+    pub async fn sign_in(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        redirect_uri: &str,
+    ) -> Result<ProviderIdentity, ServerError> {
+        let access_token = self.exchange_code(code, code_verifier, redirect_uri).await?;
+        self.read_identity(&access_token).await
+    }
+
+    async fn exchange_code(&self, code: &str, code_verifier: &str, redirect_uri: &str) -> Result<String, ServerError> {
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("code_verifier", code_verifier),
+            ("redirect_uri", redirect_uri),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+        let response = default_client()
+            .post(&self.token_url)
+            .header(ACCEPT, "application/json")
+            .header(USER_AGENT, OAUTH_USER_AGENT)
+            .form(&form)
+            .send()
+            .await?;
+
+        // The provider may respond the error with the 200 status, e.g. GitHub
+        let bytes = response.bytes().await?;
+        match serde_json::from_slice::<TokenResponse>(&bytes)? {
+            TokenResponse::Token { access_token } => Ok(access_token),
+            TokenResponse::Error { error, error_description } => Err(ServerError::unauthorized().context(format!(
+                "{:?} exchange code failed: {} {}",
+                self.provider,
+                error,
+                error_description.unwrap_or_default()
+            ))),
+        }
+    }
+
+    async fn read_identity(&self, access_token: &str) -> Result<ProviderIdentity, ServerError> {
+        match self.provider {
+            AuthProvider::Google => {
+                let user: GoogleUser = self.get(&self.user_info_url, access_token).await?;
+                Ok(ProviderIdentity {
+                    provider: self.provider,
+                    subject: user.sub,
+                    email: user.email,
+                    email_verified: user.email_verified,
+                    name: user.name.unwrap_or_default(),
+                })
+            },
+            AuthProvider::GitHub => {
+                let user: GitHubUser = self.get(&self.user_info_url, access_token).await?;
+                let mut email = GitHubEmail {
+                    email: user.email.unwrap_or_default(),
+                    primary: true,
+                    verified: false,
+                };
+                if let Some(emails_url) = &self.emails_url {
+                    let emails: Vec<GitHubEmail> = self.get(emails_url, access_token).await?;
+                    if let Some(primary) = emails.into_iter().find(|email| email.primary) {
+                        email = primary;
+                    }
+                }
+
+                Ok(ProviderIdentity {
+                    provider: self.provider,
+                    subject: user.id.to_string(),
+                    email: email.email,
+                    email_verified: email.verified,
+                    name: user.name.unwrap_or(user.login),
+                })
+            },
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(&self, url: &str, access_token: &str) -> Result<T, ServerError> {
+        let response = default_client()
+            .get(url)
+            .header(ACCEPT, "application/json")
+            .header(USER_AGENT, OAUTH_USER_AGENT)
+            .header(AUTHORIZATION, format!("Bearer {}", access_token))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(ServerError::unauthorized().context(format!(
+                "{:?} rejected the access token: {}",
+                self.provider,
+                response.status()
+            )));
+        }
+        let bytes = response.bytes().await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TokenResponse {
+    Token {
+        access_token: String,
+    },
+    Error {
+        error: String,
+        error_description: Option<String>,
+    },
+}
+
+#[derive(Deserialize)]
+struct GoogleUser {
+    sub: String,
+    #[serde(default)]
+    email: String,
+    #[serde(default)]
+    email_verified: bool,
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GitHubUser {
+    id: i64,
+    login: String,
+    name: Option<String>,
+    email: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GitHubEmail {
+    email: String,
+    primary: bool,
+    verified: bool,
+}
@@ -1,3 +1,5 @@
 mod request;
+mod retry;
 
 pub use request::*;
+pub use retry::*;
@@ -1,15 +1,87 @@
-use crate::{config::HEADER_TOKEN, errors::ServerError, response::FlowyResponse};
+use crate::{
+    config::{HEADER_IDEMPOTENCY_KEY, HEADER_REQUEST_ID, HEADER_TOKEN},
+    errors::ServerError,
+    request::{current_retry_policy, RetryPolicy},
+    response::FlowyResponse,
+};
 use bytes::Bytes;
 use hyper::http;
+use lazy_static::lazy_static;
 use protobuf::ProtobufError;
-use reqwest::{header::HeaderMap, Client, Method, Response};
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Client,
+    Method,
+    Response,
+    StatusCode,
+};
 use std::{
     convert::{TryFrom, TryInto},
-    sync::Arc,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, RwLock, Weak},
     time::Duration,
 };
 use tokio::sync::oneshot;
 
+pub type RequestIdProvider = fn() -> Option<String>;
+
+pub type RefreshTokenFuture = Pin<Box<dyn Future<Output = Result<String, ServerError>> + Send>>;
+
+// Exchanges the token that the server rejected for a new one. The requests that fail at the same time
+// pass the same token, the refresher should only refresh it once. It returns the unauthorized error
+// without sending any request for the token that isn't its own.
+pub trait TokenRefresher: Send + Sync {
+    fn refresh_token(&self, token: &str) -> RefreshTokenFuture;
+}
+
+lazy_static! {
+    static ref REQUEST_ID_PROVIDER: RwLock<Option<RequestIdProvider>> = RwLock::new(None);
+    static ref TOKEN_REFRESHERS: RwLock<Vec<Weak<dyn TokenRefresher>>> = RwLock::new(vec![]);
+}
+
+// The provider returns the id of the event that the http request is sent for. The id is sent in the
+// request-id header, the server logs it with every line of the request.
+pub fn set_request_id_provider(provider: RequestIdProvider) {
+    match REQUEST_ID_PROVIDER.write() {
+        Ok(mut guard) => *guard = Some(provider),
+        Err(e) => log::error!("Set request id provider failed: {:?}", e),
+    }
+}
+
+fn current_request_id() -> Option<String> {
+    let provider = REQUEST_ID_PROVIDER.read().ok().and_then(|guard| *guard)?;
+    provider()
+}
+
+// The request that is rejected as unauthorized is sent again with the refreshed token, so the short-lived
+// token expiring doesn't sign the user out. Each SDK registers the refresher of its own user, the token is
+// refreshed by the one that it belongs to. The refresher is unregistered once it's dropped.
+pub fn register_token_refresher(refresher: &Arc<dyn TokenRefresher>) {
+    match TOKEN_REFRESHERS.write() {
+        Ok(mut guard) => {
+            guard.retain(|refresher| refresher.strong_count() > 0);
+            guard.push(Arc::downgrade(refresher));
+        },
+        Err(e) => log::error!("Register token refresher failed: {:?}", e),
+    }
+}
+
+pub async fn refresh_token(token: &str) -> Result<String, ServerError> {
+    let refreshers = match TOKEN_REFRESHERS.read() {
+        Ok(guard) => guard.iter().flat_map(|refresher| refresher.upgrade()).collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+    let mut result = Err(ServerError::unauthorized().context("The token refresher isn't set"));
+    for refresher in refreshers {
+        result = refresher.refresh_token(token).await;
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
 pub trait ResponseMiddleware {
     fn receive_response(&self, token: &Option<String>, response: &FlowyResponse);
 }
@@ -21,6 +93,8 @@ pub struct HttpRequestBuilder {
     headers: HeaderMap,
     method: Method,
     middleware: Vec<Arc<dyn ResponseMiddleware + Send + Sync>>,
+    retry_policy: Option<RetryPolicy>,
+    is_idempotent: bool,
 }
 
 impl HttpRequestBuilder {
@@ -32,9 +106,23 @@ impl HttpRequestBuilder {
             headers: HeaderMap::new(),
             method: Method::GET,
             middleware: Vec::new(),
+            retry_policy: None,
+            is_idempotent: false,
         }
     }
 
+    // Overrides the policy that's set with set_retry_policy for this request
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    // The server dedupes the request by its Idempotency-Key, so it's sent again like the GET requests
+    pub fn idempotent(mut self) -> Self {
+        self.is_idempotent = true;
+        self
+    }
+
     pub fn middleware<T>(mut self, middleware: Arc<T>) -> Self
     where
         T: 'static + ResponseMiddleware + Send + Sync,
@@ -128,10 +216,105 @@ impl HttpRequestBuilder {
         }
     }
 
+    fn request_id(&self) -> Option<String> {
+        match self.headers.get(HEADER_REQUEST_ID) {
+            None => None,
+            Some(header) => match header.to_str() {
+                Ok(val) => Some(val.to_owned()),
+                Err(_) => None,
+            },
+        }
+    }
+
     async fn inner_send(mut self) -> Result<Self, ServerError> {
+        if !self.headers.contains_key(HEADER_REQUEST_ID) {
+            if let Some(value) = current_request_id().and_then(|id| HeaderValue::from_str(&id).ok()) {
+                self.headers.insert(HEADER_REQUEST_ID, value);
+            }
+        }
+        // The key is generated once for the request, so the retries and the request that's sent again with
+        // the refreshed token carry the same key
+        if self.method != Method::GET && !self.headers.contains_key(HEADER_IDEMPOTENCY_KEY) {
+            let value = HeaderValue::from_str(&uuid::Uuid::new_v4().to_string()).unwrap();
+            self.headers.insert(HEADER_IDEMPOTENCY_KEY, value);
+        }
+
+        let body = self.body.take();
+        let mut flowy_response = self.send_request(body.clone()).await?;
+        // The token may have expired, the request is sent again once with the refreshed token
+        if let Some(token) = self.token() {
+            let is_unauthorized = matches!(&flowy_response.error, Some(error) if error.is_unauthorized());
+            if is_unauthorized {
+                match refresh_token(&token).await {
+                    Ok(new_token) => {
+                        let value = HeaderValue::from_str(&new_token).map_err(|e| ServerError::internal().context(e))?;
+                        self.headers.insert(HEADER_TOKEN, value);
+                        flowy_response = self.send_request(body).await?;
+                    },
+                    Err(e) => log::debug!("Refresh token failed: {}", e),
+                }
+            }
+        }
+
+        let token = self.token();
+        self.middleware.iter().for_each(|middleware| {
+            middleware.receive_response(&token, &flowy_response);
+        });
+        match flowy_response.error {
+            None => {
+                self.response = Some(flowy_response.data);
+                Ok(self)
+            },
+            Some(error) => {
+                log::debug!("{} {} failed, request id: {:?}, {}", self.method, self.url, self.request_id(), error);
+                Err(error)
+            },
+        }
+    }
+
+    // Sends the request again with the delays of the retry policy while it fails on the way to the server.
+    // The mutations that the server doesn't dedupe are only sent again if they never reached the server.
+    async fn send_request(&self, body: Option<Bytes>) -> Result<FlowyResponse, ServerError> {
+        let policy = self.retry_policy.clone().unwrap_or_else(current_retry_policy);
+        let can_resend = self.method == Method::GET || self.is_idempotent;
+        let mut attempt = 1;
+        loop {
+            let (result, is_retryable) = match self.send_http_request(body.clone()).await {
+                Ok(response) if can_resend && policy.is_retryable_status(response.status()) => {
+                    (Err(http_status_error(response.status())), true)
+                },
+                Ok(response) => {
+                    let result = flowy_response_from(response).await;
+                    let is_retryable = match &result {
+                        Ok(response) if can_resend => {
+                            response.error.as_ref().map_or(false, |e| policy.is_retryable_error(e))
+                        },
+                        _ => false,
+                    };
+                    (result, is_retryable)
+                },
+                Err(error) => {
+                    let is_retryable = match can_resend {
+                        true => policy.is_retryable_error(&error),
+                        false => policy.is_unsent_error(&error),
+                    };
+                    (Err(error), is_retryable)
+                },
+            };
+
+            if !is_retryable || attempt >= policy.max_attempts {
+                return result;
+            }
+            let delay = policy.delay(attempt);
+            log::debug!("{} {} failed, retry in {:?}, attempt: {}", self.method, self.url, delay, attempt);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn send_http_request(&self, body: Option<Bytes>) -> Result<Response, ServerError> {
         let (tx, rx) = oneshot::channel::<Result<Response, _>>();
         let url = self.url.clone();
-        let body = self.body.take();
         let method = self.method.clone();
         let headers = self.headers.clone();
 
@@ -149,21 +332,14 @@ impl HttpRequestBuilder {
 
         let response = rx.await??;
         tracing::trace!("Http Response: {:?}", response);
-        let flowy_response = flowy_response_from(response).await?;
-        let token = self.token();
-        self.middleware.iter().for_each(|middleware| {
-            middleware.receive_response(&token, &flowy_response);
-        });
-        match flowy_response.error {
-            None => {
-                self.response = Some(flowy_response.data);
-                Ok(self)
-            },
-            Some(error) => Err(error),
-        }
+        Ok(response)
     }
 }
 
+fn http_status_error(status: StatusCode) -> ServerError {
+    ServerError::http().context(format!("The server responded with {}", status))
+}
+
 fn unexpected_empty_payload(url: &str) -> ServerError {
     let msg = format!("Request: {} receives unexpected empty payload", url);
     ServerError::payload_none().context(msg)
@@ -189,7 +365,7 @@ async fn get_response_data(original: Response) -> Result<Bytes, ServerError> {
     }
 }
 
-fn default_client() -> Client {
+pub(crate) fn default_client() -> Client {
     let result = reqwest::Client::builder()
         .connect_timeout(Duration::from_millis(500))
         .timeout(Duration::from_secs(5))
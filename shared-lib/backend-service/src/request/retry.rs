@@ -0,0 +1,122 @@
+use crate::errors::{ErrorCode, ServerError};
+use lazy_static::lazy_static;
+use rand::Rng;
+use reqwest::StatusCode;
+use std::{sync::RwLock, time::Duration};
+
+lazy_static! {
+    static ref RETRY_POLICY: RwLock<RetryPolicy> = RwLock::new(RetryPolicy::default());
+}
+
+// How the requests that fail on the way to the server are sent again. The GET requests and the ones that
+// the server dedupes by the Idempotency-Key header are sent again with the policy. The other mutations
+// may have been applied by the server when they time out, so they're only sent again if the connection
+// was refused.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    // The status codes of the responses that are sent again, e.g. the proxy timed out
+    pub retryable_status_codes: Vec<u16>,
+    // The first request is counted, so 1 means the request isn't sent again
+    pub max_attempts: usize,
+    // The delay doubles after each attempt up to the max_delay, half of it is random
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl std::default::Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retryable_status_codes: vec![408, 429, 502, 503, 504],
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn no_retry() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.retryable_status_codes.contains(&status.as_u16())
+    }
+
+    // The request may not have reached the server, or the server asked to be called later
+    pub(crate) fn is_retryable_error(&self, error: &ServerError) -> bool {
+        matches!(
+            error.code,
+            ErrorCode::ConnectTimeout | ErrorCode::ConnectClose | ErrorCode::ConnectRefused | ErrorCode::TooManyRequests
+        )
+    }
+
+    // The request that fails with the error never reached the server
+    pub(crate) fn is_unsent_error(&self, error: &ServerError) -> bool {
+        matches!(error.code, ErrorCode::ConnectRefused)
+    }
+
+    // The delay before the attempt that follows the given one, the first attempt is 1
+    pub(crate) fn delay(&self, attempt: usize) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1) as u32);
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        let half_millis = delay.as_millis() as u64 / 2;
+        Duration::from_millis(half_millis + rand::thread_rng().gen_range(0..=half_millis))
+    }
+}
+
+// The policy of the requests that don't set their own with HttpRequestBuilder::retry_policy
+pub fn set_retry_policy(policy: RetryPolicy) {
+    match RETRY_POLICY.write() {
+        Ok(mut guard) => *guard = policy,
+        Err(e) => log::error!("Set retry policy failed: {:?}", e),
+    }
+}
+
+pub(crate) fn current_retry_policy() -> RetryPolicy {
+    match RETRY_POLICY.read() {
+        Ok(guard) => guard.clone(),
+        Err(_) => RetryPolicy::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_test() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+            ..Default::default()
+        };
+        // The delay is between the half of the backoff and the backoff: 100ms, 200ms, 400ms, 500ms
+        for (attempt, backoff) in vec![100, 200, 400, 500, 500].into_iter().enumerate() {
+            let delay = policy.delay(attempt + 1);
+            assert!(delay >= Duration::from_millis(backoff / 2));
+            assert!(delay <= Duration::from_millis(backoff));
+        }
+        assert!(policy.delay(100) <= policy.max_delay);
+    }
+
+    #[test]
+    fn retryable_test() {
+        let policy = RetryPolicy::default();
+        assert!(policy.is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(!policy.is_retryable_status(StatusCode::BAD_REQUEST));
+        assert!(policy.is_retryable_error(&ServerError::connect_timeout()));
+        assert!(policy.is_retryable_error(&ServerError::too_many_requests()));
+        assert!(!policy.is_retryable_error(&ServerError::unauthorized()));
+        assert!(!policy.is_retryable_error(&ServerError::record_not_found()));
+        assert!(policy.is_unsent_error(&ServerError::connect_refused()));
+        assert!(!policy.is_unsent_error(&ServerError::connect_timeout()));
+    }
+}
@@ -23,6 +23,63 @@ pub async fn user_sign_in_request(params: SignInParams, url: &str) -> Result<Sig
     Ok(response)
 }
 
+pub async fn sign_in_with_provider_request(
+    params: SignInWithProviderParams,
+    url: &str,
+) -> Result<SignInResponse, ServerError> {
+    let response = request_builder()
+        .post(&url.to_owned())
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(response)
+}
+
+pub async fn request_password_reset_request(params: RequestPasswordResetParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn confirm_password_reset_request(params: ConfirmPasswordResetParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn request_email_verify_request(token: &str, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn confirm_email_verify_request(params: ConfirmEmailVerifyParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn refresh_token_request(params: RefreshTokenParams, url: &str) -> Result<RefreshTokenResponse, ServerError> {
+    let response = request_builder()
+        .post(&url.to_owned())
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(response)
+}
+
 pub async fn user_sign_out_request(token: &str, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .delete(&url.to_owned())
@@ -1,5 +1,7 @@
-use crate::{config::HEADER_TOKEN, errors::ServerError, request::HttpRequestBuilder};
+use crate::{config::HEADER_TOKEN, errors::ServerError, request::HttpRequestBuilder, response::FlowyResponse};
+use bytes::Bytes;
 use flowy_workspace_infra::entities::prelude::*;
+use serde::Serialize;
 
 pub(crate) fn request_builder() -> HttpRequestBuilder {
     HttpRequestBuilder::new().middleware(crate::middleware::BACKEND_API_MIDDLEWARE.clone())
@@ -12,6 +14,7 @@ pub async fn create_workspace_request(
 ) -> Result<Workspace, ServerError> {
     let workspace = request_builder()
         .post(&url.to_owned())
+        .idempotent()
         .header(HEADER_TOKEN, token)
         .protobuf(params)?
         .response()
@@ -58,10 +61,166 @@ pub async fn delete_workspace_request(token: &str, params: WorkspaceIdentifier,
     Ok(())
 }
 
+pub async fn archive_workspace_request(
+    token: &str,
+    params: ArchiveWorkspaceParams,
+    url: &str,
+) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn share_workspace_request(token: &str, params: ShareWorkspaceParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn read_workspace_members_request(
+    token: &str,
+    params: WorkspaceIdentifier,
+    url: &str,
+) -> Result<RepeatedWorkspaceMember, ServerError> {
+    let repeated_member = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedWorkspaceMember>()
+        .await?;
+    Ok(repeated_member)
+}
+
+pub async fn clone_workspace_request(
+    token: &str,
+    params: WorkspaceIdentifier,
+    url: &str,
+) -> Result<WorkspaceCloneProgress, ServerError> {
+    let progress = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(progress)
+}
+
+// The backup is the json file instead of the FlowyResponse, the response without the Content-Disposition
+// header is the FlowyResponse of the error
+pub async fn backup_workspace_request(token: &str, url: &str) -> Result<Bytes, ServerError> {
+    let response = reqwest::Client::new().get(url).header(HEADER_TOKEN, token).send().await?;
+    let is_backup = response.headers().contains_key(reqwest::header::CONTENT_DISPOSITION);
+    let bytes = response.bytes().await?;
+    if is_backup {
+        return Ok(bytes);
+    }
+
+    let response: FlowyResponse = serde_json::from_slice(&bytes)?;
+    match response.error {
+        Some(error) => Err(error),
+        None => Err(ServerError::internal().context("The backup is missing in the response")),
+    }
+}
+
+pub async fn restore_workspace_request(token: &str, backup: Bytes, url: &str) -> Result<WorkspaceRestore, ServerError> {
+    let restore = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .bytes(backup)?
+        .response()
+        .await?;
+    Ok(restore)
+}
+
+pub async fn read_workspace_clone_request(
+    token: &str,
+    params: WorkspaceCloneIdentifier,
+    url: &str,
+) -> Result<WorkspaceCloneProgress, ServerError> {
+    let progress = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<WorkspaceCloneProgress>()
+        .await?;
+    Ok(progress)
+}
+
+pub async fn create_bot_request(token: &str, params: CreateBotParams, url: &str) -> Result<BotToken, ServerError> {
+    let bot_token = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(bot_token)
+}
+
+pub async fn read_bots_request(
+    token: &str,
+    params: WorkspaceIdentifier,
+    url: &str,
+) -> Result<RepeatedBot, ServerError> {
+    let repeated_bot = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedBot>()
+        .await?;
+    Ok(repeated_bot)
+}
+
+pub async fn delete_bot_request(token: &str, params: BotIdentifier, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(url)
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn read_api_usage_request(
+    token: &str,
+    params: ReadApiUsageParams,
+    url: &str,
+) -> Result<RepeatedApiUsage, ServerError> {
+    let repeated_usage = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedApiUsage>()
+        .await?;
+    Ok(repeated_usage)
+}
+
+pub async fn search_workspace_request(
+    token: &str,
+    params: SearchWorkspaceParams,
+    url: &str,
+) -> Result<RepeatedSearchResult, ServerError> {
+    let repeated_result = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedSearchResult>()
+        .await?;
+    Ok(repeated_result)
+}
+
 // App
 pub async fn create_app_request(token: &str, params: CreateAppParams, url: &str) -> Result<App, ServerError> {
     let app = request_builder()
         .post(&url.to_owned())
+        .idempotent()
         .header(HEADER_TOKEN, token)
         .protobuf(params)?
         .response()
@@ -90,6 +249,26 @@ pub async fn update_app_request(token: &str, params: UpdateAppParams, url: &str)
     Ok(())
 }
 
+pub async fn move_app_request(token: &str, params: MoveAppParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .patch(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn duplicate_app_request(token: &str, params: AppIdentifier, url: &str) -> Result<App, ServerError> {
+    let app = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(app)
+}
+
 pub async fn delete_app_request(token: &str, params: AppIdentifier, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .delete(&url.to_owned())
@@ -104,6 +283,7 @@ pub async fn delete_app_request(token: &str, params: AppIdentifier, url: &str) -
 pub async fn create_view_request(token: &str, params: CreateViewParams, url: &str) -> Result<View, ServerError> {
     let view = request_builder()
         .post(&url.to_owned())
+        .idempotent()
         .header(HEADER_TOKEN, token)
         .protobuf(params)?
         .response()
@@ -132,6 +312,26 @@ pub async fn update_view_request(token: &str, params: UpdateViewParams, url: &st
     Ok(())
 }
 
+pub async fn move_view_request(token: &str, params: MoveViewParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .patch(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn duplicate_view_request(token: &str, params: ViewIdentifier, url: &str) -> Result<View, ServerError> {
+    let view = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(view)
+}
+
 pub async fn delete_view_request(token: &str, params: ViewIdentifiers, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .delete(&url.to_owned())
@@ -142,6 +342,215 @@ pub async fn delete_view_request(token: &str, params: ViewIdentifiers, url: &str
     Ok(())
 }
 
+pub async fn publish_view_request(
+    token: &str,
+    params: ViewIdentifier,
+    url: &str,
+) -> Result<PublishedView, ServerError> {
+    let published_view = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<PublishedView>()
+        .await?;
+    Ok(published_view)
+}
+
+pub async fn unpublish_view_request(token: &str, params: ViewIdentifier, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn pin_published_view_request(
+    token: &str,
+    params: PinPublishedViewParams,
+    url: &str,
+) -> Result<PublishedView, ServerError> {
+    let published_view = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<PublishedView>()
+        .await?;
+    Ok(published_view)
+}
+
+pub async fn publish_latest_view_request(
+    token: &str,
+    params: ViewIdentifier,
+    url: &str,
+) -> Result<PublishedView, ServerError> {
+    let published_view = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<PublishedView>()
+        .await?;
+    Ok(published_view)
+}
+
+pub async fn read_view_access_log_request(
+    token: &str,
+    params: ViewIdentifier,
+    url: &str,
+) -> Result<RepeatedViewAccessLog, ServerError> {
+    let repeated_log = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedViewAccessLog>()
+        .await?;
+    Ok(repeated_log)
+}
+
+// The published page is plain html instead of the FlowyResponse
+pub async fn read_published_page_request(url: &str) -> Result<String, ServerError> {
+    let response = reqwest::get(url).await?;
+    if !response.status().is_success() {
+        return Err(ServerError::record_not_found().context(format!("The page {} is not found", url)));
+    }
+    let html = response.text().await?;
+    Ok(html)
+}
+
+pub async fn create_share_link_request(
+    token: &str,
+    params: CreateShareLinkParams,
+    url: &str,
+) -> Result<ShareLink, ServerError> {
+    let share_link = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(share_link)
+}
+
+pub async fn read_share_links_request(
+    token: &str,
+    params: WorkspaceIdentifier,
+    url: &str,
+) -> Result<RepeatedShareLink, ServerError> {
+    let repeated_share_link = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedShareLink>()
+        .await?;
+    Ok(repeated_share_link)
+}
+
+pub async fn revoke_share_link_request(token: &str, params: ShareLinkIdentifier, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(url)
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+// The shared page is plain html, but the error, e.g. the passcode doesn't match, is the FlowyResponse
+pub async fn read_shared_page_request(url: &str, passcode: &str) -> Result<String, ServerError> {
+    let response = reqwest::Client::new()
+        .get(url)
+        .query(&[("passcode", passcode)])
+        .send()
+        .await?;
+    let body = response.text().await?;
+    match serde_json::from_str::<FlowyResponse>(&body) {
+        Ok(FlowyResponse { error: Some(error), .. }) => Err(error),
+        _ => Ok(body),
+    }
+}
+
+pub async fn update_favorite_request(token: &str, params: ViewFavoriteState, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn read_favorites_request(token: &str, url: &str) -> Result<RepeatedFavorite, ServerError> {
+    let repeated_favorite = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .response::<RepeatedFavorite>()
+        .await?;
+    Ok(repeated_favorite)
+}
+
+pub async fn update_recent_view_request(token: &str, params: RecentViewRecord, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn read_recent_views_request(
+    token: &str,
+    params: QueryRecentViewsParams,
+    url: &str,
+) -> Result<RepeatedRecentView, ServerError> {
+    let repeated_recent_view = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<RepeatedRecentView>()
+        .await?;
+    Ok(repeated_recent_view)
+}
+
+pub async fn set_view_webhook_request(
+    token: &str,
+    params: SetViewWebhookParams,
+    url: &str,
+) -> Result<ViewWebhook, ServerError> {
+    let webhook = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(webhook)
+}
+
+pub async fn read_view_webhook_request(
+    token: &str,
+    params: ViewIdentifier,
+    url: &str,
+) -> Result<ViewWebhook, ServerError> {
+    let webhook = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<ViewWebhook>()
+        .await?;
+    Ok(webhook)
+}
+
+pub async fn delete_view_webhook_request(token: &str, params: ViewIdentifier, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(url)
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
 pub async fn create_trash_request(token: &str, params: TrashIdentifiers, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .post(&url.to_owned())
@@ -170,3 +579,15 @@ pub async fn read_trash_request(token: &str, url: &str) -> Result<RepeatedTrash,
         .await?;
     Ok(repeated_trash)
 }
+
+// Automation
+pub async fn automation_request<T: Serialize>(token: &str, command: &T, url: &str) -> Result<(), ServerError> {
+    let body = serde_json::to_vec(command)?;
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .bytes(Bytes::from(body))?
+        .send()
+        .await?;
+    Ok(())
+}
@@ -238,6 +238,7 @@ pub struct EventAttrs {
     output: Option<syn::Path>,
     error_ty: Option<String>,
     pub ignore: bool,
+    scopes: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -280,6 +281,15 @@ impl ASTEnumAttrVariant {
     pub fn event_output(&self) -> Option<syn::Path> { self.event_attrs.output.clone() }
 
     pub fn event_error(&self) -> String { self.event_attrs.error_ty.as_ref().unwrap().clone() }
+
+    pub fn event_scopes(&self) -> Vec<String> { self.event_attrs.scopes.clone() }
+}
+
+fn parse_scopes(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(|scope| scope.trim().to_owned())
+        .filter(|scope| !scope.is_empty())
+        .collect()
 }
 
 fn get_event_attrs_from(
@@ -292,6 +302,7 @@ fn get_event_attrs_from(
         output: None,
         error_ty: None,
         ignore: false,
+        scopes: vec![],
     };
 
     enum_attrs
@@ -309,6 +320,17 @@ fn get_event_attrs_from(
             }
         });
 
+    // The scopes of the enum apply to every event unless the event declares its own
+    enum_attrs
+        .iter()
+        .filter(|attr| attr.path == EVENT_DEFAULT_SCOPE)
+        .for_each(|attr| match attr.parse_meta() {
+            Ok(NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(s), ..
+            })) => event_attrs.scopes = parse_scopes(&s.value()),
+            _ => ctxt.error_spanned_by(attr, format!("expected #[{} = \"...\"]", EVENT_DEFAULT_SCOPE)),
+        });
+
     let mut extract_event_attr = |attr: &syn::Attribute, meta_item: &syn::NestedMeta| match &meta_item {
         Meta(NameValue(name_value)) => {
             if name_value.path == EVENT_INPUT {
@@ -332,11 +354,22 @@ fn get_event_attrs_from(
                     event_attrs.output = Some(output_type);
                 }
             }
+
+            if name_value.path == EVENT_SCOPE {
+                match &name_value.lit {
+                    syn::Lit::Str(s) => event_attrs.scopes = parse_scopes(&s.value()),
+                    lit => ctxt.error_spanned_by(lit, format!("expected {} to be a string", EVENT_SCOPE)),
+                }
+            }
         },
         Meta(Path(word)) => {
             if word == EVENT_IGNORE && attr.path == EVENT {
                 event_attrs.ignore = true;
             }
+
+            if word == EVENT_PUBLIC && attr.path == EVENT {
+                event_attrs.scopes.clear();
+            }
         },
         Lit(s) => ctxt.error_spanned_by(s, "unexpected attribute"),
         _ => ctxt.error_spanned_by(meta_item, "unexpected attribute"),
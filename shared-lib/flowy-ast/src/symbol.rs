@@ -19,6 +19,9 @@ pub const EVENT_OUTPUT: Symbol = Symbol("output");
 pub const EVENT_IGNORE: Symbol = Symbol("ignore");
 pub const EVENT: Symbol = Symbol("event");
 pub const EVENT_ERR: Symbol = Symbol("event_err");
+pub const EVENT_DEFAULT_SCOPE: Symbol = Symbol("event_scope"); //#[event_scope = "signed_in"]
+pub const EVENT_SCOPE: Symbol = Symbol("scope"); //#[event(scope = "a,b")]
+pub const EVENT_PUBLIC: Symbol = Symbol("public"); //#[event(public)]
 
 impl PartialEq<Symbol> for Ident {
     fn eq(&self, word: &Symbol) -> bool { self == word.0 }
@@ -24,9 +24,14 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "RepeatedApp"
         | "UpdateAppRequest"
         | "UpdateAppParams"
+        | "MoveAppRequest"
+        | "MoveAppParams"
         | "UpdateWorkspaceRequest"
         | "UpdateWorkspaceParams"
         | "CurrentWorkspaceSetting"
+        | "RenameMergePolicySetting"
+        | "RenameConflict"
+        | "ViewUnreadState"
         | "CreateWorkspaceRequest"
         | "CreateWorkspaceParams"
         | "Workspace"
@@ -44,10 +49,22 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "ViewIdentifiers"
         | "CreateViewRequest"
         | "CreateViewParams"
+        | "MoveViewRequest"
+        | "MoveViewParams"
         | "View"
         | "RepeatedView"
         | "ExportRequest"
         | "ExportData"
+        | "ExportAppRequest"
+        | "ExportAppProgress"
+        | "ImportFolderRequest"
+        | "ImportFolderProgress"
+        | "UploadAttachmentRequest"
+        | "ShareWorkspaceRequest"
+        | "ShareWorkspaceParams"
+        | "WorkspaceMember"
+        | "RepeatedWorkspaceMember"
+        | "ImportRequest"
         | "CreateDocParams"
         | "Doc"
         | "UpdateDocParams"
@@ -65,22 +82,123 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "SignInRequest"
         | "SignInParams"
         | "SignInResponse"
+        | "SignInWithProviderRequest"
+        | "SignInWithProviderParams"
+        | "RequestPasswordResetRequest"
+        | "RequestPasswordResetParams"
+        | "ConfirmPasswordResetRequest"
+        | "ConfirmPasswordResetParams"
+        | "ConfirmEmailVerifyRequest"
+        | "ConfirmEmailVerifyParams"
         | "SignUpRequest"
         | "SignUpParams"
         | "SignUpResponse"
+        | "RefreshTokenParams"
+        | "RefreshTokenResponse"
         | "UserToken"
         | "UserProfile"
+        | "UserSettingsProfile"
         | "UpdateUserRequest"
         | "UpdateUserParams"
         | "DocError"
         | "FFIRequest"
         | "FFIResponse"
+        | "FFIStreamChunk"
+        | "FFIBatchRequest"
+        | "FFIBatchResponse"
         | "SubscribeObject"
         | "UserError"
+        | "ArchiveWorkspaceRequest"
+        | "ArchiveWorkspaceParams"
+        | "DocIdentifiers"
+        | "DocPrefetchProgress"
+        | "DocTask"
+        | "RepeatedDocTask"
+        | "DocPresence"
+        | "DocReadPosition"
+        | "RepeatedDocPresence"
+        | "DocTyping"
+        | "DocTypingUsers"
+        | "DocSelection"
+        | "DocUndoState"
+        | "DocTextRun"
+        | "RepeatedDocTextRun"
+        | "DocImportBlock"
+        | "DocImportWarning"
+        | "DocImportPreview"
+        | "DocTextReplacement"
+        | "InputRuleSetting"
+        | "DocSnapshot"
+        | "RepeatedDocSnapshot"
+        | "DocSnapshotIdentifier"
+        | "Attachment"
+        | "CreateAttachmentParams"
+        | "AttachmentIdentifier"
+        | "AttachmentChunk"
+        | "AttachmentChunkIdentifier"
+        | "AttachmentUploadProgress"
+        | "AttachmentFile"
+        | "CreateBotRequest"
+        | "CreateBotParams"
+        | "Bot"
+        | "RepeatedBot"
+        | "BotToken"
+        | "BotIdentifier"
+        | "PublishedView"
+        | "ViewAccessLog"
+        | "RepeatedViewAccessLog"
+        | "PinPublishedViewRequest"
+        | "PinPublishedViewParams"
+        | "ApiUsage"
+        | "RepeatedApiUsage"
+        | "ReadApiUsageParams"
+        | "ListFilter"
+        | "SearchWorkspaceRequest"
+        | "SearchWorkspaceParams"
+        | "SearchResult"
+        | "SearchMatch"
+        | "RepeatedSearchResult"
+        | "ShareLink"
+        | "RepeatedShareLink"
+        | "CreateShareLinkRequest"
+        | "CreateShareLinkParams"
+        | "ShareLinkIdentifier"
+        | "ViewFavoriteState"
+        | "Favorite"
+        | "RepeatedFavorite"
+        | "QueryRecentViewsRequest"
+        | "QueryRecentViewsParams"
+        | "RecentViewRecord"
+        | "RecentView"
+        | "RepeatedRecentView"
+        | "ViewWebhook"
+        | "SetViewWebhookRequest"
+        | "SetViewWebhookParams"
+        | "WorkspaceItem"
+        | "RepeatedWorkspaceItem"
+        | "WorkspaceCloneProgress"
+        | "WorkspaceCloneIdentifier"
+        | "RestoredAttachment"
+        | "WorkspaceRestore"
+        | "MergeAccountRequest"
+        | "WorkspaceMergeItem"
+        | "AccountMergeReport"
+        | "NetworkState"
+        | "SetLogLevelRequest"
+        | "MetricsSnapshot"
+        | "CounterMetric"
+        | "LatencyMetric"
+        | "BlockingPoolMetric"
         => TypeCategory::Protobuf,
         "TrashType"
         | "ViewType"
         | "ExportType"
+        | "AppExportType"
+        | "WorkspaceRole"
+        | "WorkspaceCloneState"
+        | "WebhookFormat"
+        | "DocBlockType"
+        | "ImportType"
         | "ErrorCode"
         | "RevType"
         | "WsDataType"
@@ -89,8 +207,14 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "WsModule"
         | "DocObservable"
         | "FFIStatusCode"
+        | "FFIBatchMode"
         | "UserEvent"
         | "UserNotification"
+        | "BotScope"
+        | "RenameMergePolicy"
+        | "AuthProvider"
+        | "NetworkStatus"
+        | "LogLevel"
         => TypeCategory::Enum,
 
         "Option" => TypeCategory::Opt,
@@ -0,0 +1,161 @@
+use lib_ot::core::{plain_attributes, Attribute, Attributes, Delta, NEW_LINE};
+
+// The text of the line that has the same inline attributes, e.g. the bold word in a paragraph
+#[derive(Debug, Clone)]
+pub struct Span {
+    pub text: String,
+    pub attributes: Attributes,
+}
+
+impl Span {
+    pub fn new(text: &str, attributes: Vec<Attribute>) -> Self {
+        let mut span = Span::from(text);
+        for attribute in attributes {
+            span.attributes.add(attribute);
+        }
+        span
+    }
+
+    pub fn bold(text: &str) -> Self { Span::new(text, vec![Attribute::Bold(true)]) }
+
+    pub fn italic(text: &str) -> Self { Span::new(text, vec![Attribute::Italic(true)]) }
+
+    pub fn code(text: &str) -> Self { Span::new(text, vec![Attribute::InlineCode(true)]) }
+
+    // The url may be the id of another view, the editor opens the view instead
+    pub fn link(text: &str, url: &str) -> Self { Span::new(text, vec![Attribute::Link(url)]) }
+
+    // The text is replaced with the attachment in the editor, e.g. the image
+    pub fn attachment(name: &str, attachment_id: &str) -> Self {
+        Span::new(name, vec![Attribute::Attachment(attachment_id)])
+    }
+}
+
+impl std::convert::From<&str> for Span {
+    fn from(text: &str) -> Self {
+        Span {
+            text: text.to_owned(),
+            attributes: plain_attributes(),
+        }
+    }
+}
+
+// Builds the delta of a doc line by line, so the code that creates the docs doesn't need to know that the
+// block attributes are kept on the newline that ends each line. The text that has newlines is split into
+// lines of the same block, and the delta always ends with a newline like the one the editor creates.
+//
+//  let delta = DocumentBuilder::new()
+//      .heading(1, "Meeting notes")
+//      .checkbox("Send the agenda", true)
+//      .line(vec!["Read the ".into(), Span::bold("spec")], None)
+//      .build();
+#[derive(Default)]
+pub struct DocumentBuilder {
+    delta: Delta,
+}
+
+impl DocumentBuilder {
+    pub fn new() -> Self { Self::default() }
+
+    // The editor shows the levels from 1 to 3, the others are kept as they are
+    pub fn heading(self, level: usize, text: &str) -> Self { self.block(text, Some(Attribute::Header(level))) }
+
+    pub fn paragraph(self, text: &str) -> Self { self.block(text, None) }
+
+    pub fn bullet(self, text: &str) -> Self { self.block(text, Some(Attribute::Bullet(true))) }
+
+    pub fn ordered(self, text: &str) -> Self { self.block(text, Some(Attribute::Ordered(true))) }
+
+    pub fn checkbox(self, text: &str, is_checked: bool) -> Self {
+        let attribute = match is_checked {
+            true => Attribute::Checked(true),
+            false => Attribute::UnChecked(true),
+        };
+        self.block(text, Some(attribute))
+    }
+
+    pub fn quote(self, text: &str) -> Self { self.block(text, Some(Attribute::BlockQuote(true))) }
+
+    // Each line of the code is a line of the code block
+    pub fn code(self, code: &str) -> Self { self.block(code, Some(Attribute::CodeBlock(true))) }
+
+    // The line that has the inline attributes. The newlines in the spans are replaced with spaces, they
+    // would split the line.
+    pub fn line(mut self, spans: Vec<Span>, block: Option<Attribute>) -> Self {
+        for span in spans {
+            self.delta.insert(&span.text.replace(NEW_LINE, " "), span.attributes);
+        }
+        self.end_line(block)
+    }
+
+    pub fn build(self) -> Delta {
+        match self.delta.is_empty() {
+            true => self.paragraph("").delta,
+            false => self.delta,
+        }
+    }
+
+    fn block(mut self, text: &str, block: Option<Attribute>) -> Self {
+        for line in text.split(NEW_LINE) {
+            self.delta.insert(line, plain_attributes());
+            self = self.end_line(block.clone());
+        }
+        self
+    }
+
+    fn end_line(mut self, block: Option<Attribute>) -> Self {
+        match block {
+            None => self.delta.insert(NEW_LINE, plain_attributes()),
+            Some(attribute) => self.delta.insert(NEW_LINE, attribute.into()),
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_empty_doc_test() {
+        assert_eq!(DocumentBuilder::new().build().to_json(), r#"[{"insert":"\n"}]"#);
+    }
+
+    #[test]
+    fn build_blocks_test() {
+        let delta = DocumentBuilder::new()
+            .heading(2, "Title")
+            .paragraph("a\nb")
+            .checkbox("done", true)
+            .code("let a = 1;")
+            .build();
+        assert_eq!(
+            delta.to_json(),
+            concat!(
+                r#"[{"insert":"Title"},{"insert":"\n","attributes":{"header":2}},{"insert":"a\nb\ndone"},"#,
+                r#"{"insert":"\n","attributes":{"list":"checked"}},{"insert":"let a = 1;"},"#,
+                r#"{"insert":"\n","attributes":{"code_block":true}}]"#
+            )
+        );
+    }
+
+    #[test]
+    fn build_line_with_spans_test() {
+        let spans = vec![
+            "see ".into(),
+            Span::link("docs", "view_id"),
+            Span::bold("a\nb"),
+            Span::attachment("a.png", "attachment_id"),
+        ];
+        let delta = DocumentBuilder::new().line(spans, Some(Attribute::Bullet(true))).build();
+        assert_eq!(
+            delta.to_json(),
+            concat!(
+                r#"[{"insert":"see "},{"insert":"docs","attributes":{"link":"view_id"}},"#,
+                r#"{"insert":"a b","attributes":{"bold":true}},"#,
+                r#"{"insert":"a.png","attributes":{"attachment":"attachment_id"}},"#,
+                r#"{"insert":"\n","attributes":{"list":"bullet"}}]"#
+            )
+        );
+    }
+}
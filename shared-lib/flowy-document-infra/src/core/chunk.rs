@@ -0,0 +1,132 @@
+use lib_ot::core::{plain_attributes, Attributes, Delta, Operation};
+
+// Splits the delta into the deltas that compose into the same document one after another, each of them
+// inserts up to max_len utf16 code units, so a large paste is saved and synced as bounded revisions. The
+// delta that inserts less is returned as it is.
+pub fn split_delta(delta: &Delta, max_len: usize) -> Vec<Delta> {
+    let insert_len = delta
+        .ops
+        .iter()
+        .filter(|op| op.is_insert())
+        .map(|op| op.len())
+        .sum::<usize>();
+    if max_len == 0 || insert_len <= max_len {
+        return vec![delta.clone()];
+    }
+
+    let mut splitter = DeltaSplitter::new(delta.base_len);
+    for op in &delta.ops {
+        match op {
+            Operation::Insert(insert) => {
+                let mut text = String::new();
+                for c in insert.s.chars() {
+                    if splitter.insert_len + c.len_utf16() > max_len {
+                        splitter.insert(&text, &insert.attributes);
+                        splitter.finish_chunk();
+                        text.clear();
+                    }
+                    splitter.insert_len += c.len_utf16();
+                    text.push(c);
+                }
+                splitter.insert(&text, &insert.attributes);
+            },
+            _ => splitter.chunk.add(op.clone()),
+        }
+    }
+    splitter.finish_chunk();
+    splitter.chunks
+}
+
+struct DeltaSplitter {
+    base_len: usize,
+    chunks: Vec<Delta>,
+    // The operations of the chunk that's being split, they start where the previous chunks end
+    chunk: Delta,
+    insert_len: usize,
+    // The length of the document that the previous chunks produced, and the length of the base they consumed
+    target_offset: usize,
+    base_offset: usize,
+}
+
+impl DeltaSplitter {
+    fn new(base_len: usize) -> Self {
+        Self {
+            base_len,
+            chunks: vec![],
+            chunk: Delta::new(),
+            insert_len: 0,
+            target_offset: 0,
+            base_offset: 0,
+        }
+    }
+
+    fn insert(&mut self, text: &str, attributes: &Attributes) { self.chunk.insert(text, attributes.clone()); }
+
+    // The chunk retains what the previous chunks produced and the base that the next chunks consume
+    fn finish_chunk(&mut self) {
+        let chunk = std::mem::take(&mut self.chunk);
+        self.insert_len = 0;
+        if chunk.is_empty() {
+            return;
+        }
+
+        let mut delta = Delta::new();
+        delta.retain(self.target_offset, plain_attributes());
+        self.target_offset += chunk.target_len;
+        self.base_offset += chunk.base_len;
+        delta.extend(chunk);
+        delta.retain(self.base_len - self.base_offset, plain_attributes());
+        self.chunks.push(delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_ot::core::{Attribute, OperationTransformable};
+
+    fn compose_chunks(document: &Delta, chunks: &[Delta]) -> Delta {
+        chunks
+            .iter()
+            .fold(document.clone(), |document, chunk| document.compose(chunk).unwrap())
+    }
+
+    #[test]
+    fn split_small_delta_test() {
+        let mut delta = Delta::new();
+        delta.insert("abc", plain_attributes());
+        assert_eq!(split_delta(&delta, 3), vec![delta]);
+    }
+
+    #[test]
+    fn split_large_insert_test() {
+        let mut document = Delta::new();
+        document.insert("hello\n", plain_attributes());
+
+        let text = "0123456789".repeat(25);
+        let mut delta = Delta::new();
+        delta.retain(5, plain_attributes());
+        delta.insert(&text, Attribute::Bold(true).into());
+        delta.delete(1);
+
+        let chunks = split_delta(&delta, 100);
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|chunk| chunk.target_len - chunk.base_len <= 100));
+        assert_eq!(compose_chunks(&document, &chunks), document.compose(&delta).unwrap());
+    }
+
+    #[test]
+    fn split_insert_without_breaking_characters_test() {
+        let mut document = Delta::new();
+        document.insert("\n", plain_attributes());
+
+        let mut delta = Delta::new();
+        delta.insert(&"a😀".repeat(10), plain_attributes());
+        delta.retain(1, plain_attributes());
+
+        // Each emoji takes two utf16 code units, the chunk ends before the emoji that doesn't fit
+        let chunks = split_delta(&delta, 4);
+        assert_eq!(chunks.len(), 10);
+        assert_eq!(compose_chunks(&document, &chunks), document.compose(&delta).unwrap());
+    }
+}
@@ -1,14 +1,14 @@
 use crate::{
-    core::{
-        history::{History, UndoResult},
-        view::{View, RECORD_THRESHOLD},
-    },
+    core::view::{View, RECORD_THRESHOLD},
     errors::DocumentError,
     user_default::doc_initial_delta,
 };
 use lib_ot::core::*;
+use std::time::Duration;
 use tokio::sync::mpsc;
 
+const MAX_UNDOS: usize = 20;
+
 pub trait CustomDocument {
     fn init_delta() -> Delta;
 }
@@ -25,9 +25,8 @@ impl CustomDocument for FlowyDoc {
 
 pub struct Document {
     delta: Delta,
-    history: History,
+    history: UndoManager,
     view: View,
-    last_edit_time: usize,
     notify: Option<mpsc::UnboundedSender<()>>,
 }
 
@@ -37,9 +36,8 @@ impl Document {
     pub fn from_delta(delta: Delta) -> Self {
         Document {
             delta,
-            history: History::new(),
+            history: UndoManager::new(MAX_UNDOS, Duration::from_millis(RECORD_THRESHOLD as u64)),
             view: View::new(),
-            last_edit_time: 0,
             notify: None,
         }
     }
@@ -70,28 +68,21 @@ impl Document {
         }
     }
 
-    pub fn compose_delta(&mut self, mut delta: Delta) -> Result<(), DocumentError> {
+    pub fn compose_delta(&mut self, delta: Delta) -> Result<(), DocumentError> { self.compose(delta, None) }
+
+    // The deltas that are composed with the same group id are undone together, e.g. the chunks of a paste
+    pub fn compose_delta_in_group(&mut self, delta: Delta, group_id: &str) -> Result<(), DocumentError> {
+        self.compose(delta, Some(group_id))
+    }
+
+    fn compose(&mut self, mut delta: Delta, group_id: Option<&str>) -> Result<(), DocumentError> {
         trim(&mut delta);
         tracing::trace!("{} compose {}", &self.delta.to_json(), delta.to_json());
         let mut composed_delta = self.delta.compose(&delta)?;
-        let mut undo_delta = delta.invert(&self.delta);
-
-        let now = chrono::Utc::now().timestamp_millis() as usize;
-        if now - self.last_edit_time < RECORD_THRESHOLD {
-            if let Some(last_delta) = self.history.undo() {
-                tracing::trace!("compose previous change");
-                tracing::trace!("current = {}", undo_delta);
-                tracing::trace!("previous = {}", last_delta);
-                undo_delta = undo_delta.compose(&last_delta)?;
-            }
-        } else {
-            self.last_edit_time = now;
-        }
-
-        tracing::trace!("👉 receive change undo: {}", undo_delta);
-        if !undo_delta.is_empty() {
-            self.history.record(undo_delta);
-        }
+        let _ = match group_id {
+            None => self.history.record(&delta, &self.delta)?,
+            Some(group_id) => self.history.record_in_group(&delta, &self.delta, group_id)?,
+        };
 
         tracing::trace!("compose result: {}", composed_delta.to_json());
         trim(&mut composed_delta);
@@ -154,47 +145,32 @@ impl Document {
 
     pub fn can_redo(&self) -> bool { self.history.can_redo() }
 
-    pub fn undo(&mut self) -> Result<UndoResult, DocumentError> {
-        match self.history.undo() {
+    // Returns the delta that was composed into the document, so it can be saved as a revision.
+    pub fn undo(&mut self) -> Result<Delta, DocumentError> {
+        match self.history.undo(&self.delta) {
             None => Err(DocumentError::undo().context("Undo stack is empty")),
             Some(undo_delta) => {
-                let (new_delta, inverted_delta) = self.invert(&undo_delta)?;
-                let result = UndoResult::success(new_delta.target_len as usize);
+                tracing::trace!("👉 undo: {}", undo_delta);
+                let new_delta = self.delta.compose(&undo_delta)?;
                 self.set_delta(new_delta);
-                self.history.add_redo(inverted_delta);
-
-                Ok(result)
+                Ok(undo_delta)
             },
         }
     }
 
-    pub fn redo(&mut self) -> Result<UndoResult, DocumentError> {
-        match self.history.redo() {
-            None => Err(DocumentError::redo()),
+    pub fn redo(&mut self) -> Result<Delta, DocumentError> {
+        match self.history.redo(&self.delta) {
+            None => Err(DocumentError::redo().context("Redo stack is empty")),
             Some(redo_delta) => {
-                let (new_delta, inverted_delta) = self.invert(&redo_delta)?;
-                let result = UndoResult::success(new_delta.target_len as usize);
+                tracing::trace!("👉 redo: {}", redo_delta);
+                let new_delta = self.delta.compose(&redo_delta)?;
                 self.set_delta(new_delta);
-
-                self.history.add_undo(inverted_delta);
-                Ok(result)
+                Ok(redo_delta)
             },
         }
     }
 }
 
-impl Document {
-    fn invert(&self, delta: &Delta) -> Result<(Delta, Delta), DocumentError> {
-        // c = a.compose(b)
-        // d = b.invert(a)
-        // a = c.compose(d)
-        tracing::trace!("Invert {}", delta);
-        let new_delta = self.delta.compose(delta)?;
-        let inverted_delta = delta.invert(&self.delta);
-        Ok((new_delta, inverted_delta))
-    }
-}
-
 fn validate_interval(delta: &Delta, interval: &Interval) -> Result<(), DocumentError> {
     if delta.target_len < interval.end {
         log::error!("{:?} out of bounds. should 0..{}", interval, delta.target_len);
@@ -0,0 +1,168 @@
+use crate::core::delta_to_markdown;
+use lib_ot::core::{Attribute, AttributeKey, Attributes, Delta, NEW_LINE};
+use std::collections::{HashMap, HashSet};
+
+const MAX_HEADER_LEVEL: usize = 6;
+
+// One doc of the combined export. The level is the depth of its view in the app, the views of the app
+// are at level 1, so the title of the view becomes the heading of that level.
+#[derive(Debug, Clone)]
+pub struct ExportSection {
+    pub id: String,
+    pub title: String,
+    pub level: usize,
+    pub delta: Delta,
+}
+
+// Concatenates the docs into one markdown. Each doc is preceded by the anchor and the heading of its
+// title, the headings of the doc are nested under it and the links to the other docs of the export
+// point to their anchors.
+pub fn sections_to_markdown(sections: &[ExportSection]) -> String {
+    let ids = section_ids(sections);
+    let mut markdown = String::new();
+    for section in sections {
+        if !markdown.is_empty() {
+            markdown.push_str(NEW_LINE);
+        }
+        markdown.push_str(&format!("<a id=\"{}\"></a>\n", section.id));
+        markdown.push_str(&format!(
+            "{} {}\n\n",
+            "#".repeat(header_level(section.level)),
+            section.title
+        ));
+        markdown.push_str(&delta_to_markdown(&resolve_section(section, &ids)));
+    }
+    markdown
+}
+
+pub(crate) fn section_ids(sections: &[ExportSection]) -> HashSet<&str> {
+    sections.iter().map(|section| section.id.as_str()).collect()
+}
+
+pub(crate) fn header_level(level: usize) -> usize { level.max(1).min(MAX_HEADER_LEVEL) }
+
+// Shifts the headings of the doc below the level of its title and replaces the links to the other docs
+// of the export with the internal links, e.g. #<view_id>.
+pub(crate) fn resolve_section(section: &ExportSection, ids: &HashSet<&str>) -> Delta {
+    let mut delta = Delta::new();
+    for op in &section.delta.ops {
+        if !op.is_insert() {
+            continue;
+        }
+
+        let mut attributes = op.get_attributes();
+        let header = attribute_value(&attributes, AttributeKey::Header).and_then(|level| level.parse::<usize>().ok());
+        if let Some(level) = header {
+            attributes.add(Attribute::Header(header_level(section.level + level)));
+        }
+        let link = attribute_value(&attributes, AttributeKey::Link).and_then(|link| linked_section(&link, ids));
+        if let Some(id) = link {
+            attributes.add(Attribute::Link(&internal_link(id)));
+        }
+        delta.insert(op.get_data(), attributes);
+    }
+    delta
+}
+
+fn attribute_value(attributes: &Attributes, key: AttributeKey) -> Option<String> {
+    attributes.get(&key).and_then(|value| value.0.clone())
+}
+
+pub(crate) fn internal_link(id: &str) -> String { format!("#{}", id) }
+
+// The link to the doc ends with the id of its view, it's either the id itself or the url of the view
+fn linked_section<'a>(link: &str, ids: &HashSet<&'a str>) -> Option<&'a str> {
+    let id = link.trim_end_matches('/').rsplit('/').next()?;
+    ids.get(id).copied()
+}
+
+// Points the links to the docs and the attachments to their new ids after the docs are copied, e.g. when
+// the workspace is restored from the backup. The ids that aren't in the map are kept.
+pub fn replace_doc_references(delta: &Delta, new_ids: &HashMap<String, String>) -> Delta {
+    let mut new_delta = Delta::new();
+    for op in &delta.ops {
+        if !op.is_insert() {
+            continue;
+        }
+
+        let mut attributes = op.get_attributes();
+        if let Some(link) = attribute_value(&attributes, AttributeKey::Link) {
+            let trimmed = link.trim_end_matches('/');
+            let id = trimmed.rsplit('/').next().unwrap_or(trimmed);
+            if let Some(new_id) = new_ids.get(id) {
+                let prefix = &trimmed[..trimmed.len() - id.len()];
+                attributes.add(Attribute::Link(&format!("{}{}", prefix, new_id)));
+            }
+        }
+        let attachment = attribute_value(&attributes, AttributeKey::Attachment).and_then(|id| new_ids.get(&id));
+        if let Some(new_id) = attachment {
+            attributes.add(Attribute::Attachment(new_id));
+        }
+        new_delta.insert(op.get_data(), attributes);
+    }
+    new_delta
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{replace_doc_references, sections_to_markdown, ExportSection};
+    use crate::core::{delta_to_markdown, markdown_to_delta};
+    use lib_ot::core::{Attribute, AttributeKey, Attributes};
+    use std::collections::HashMap;
+
+    fn section(id: &str, title: &str, level: usize, markdown: &str) -> ExportSection {
+        ExportSection {
+            id: id.to_owned(),
+            title: title.to_owned(),
+            level,
+            delta: markdown_to_delta(markdown),
+        }
+    }
+
+    #[test]
+    fn export_nested_headings() {
+        let sections = vec![
+            section("a", "Guide", 1, "# Intro\ntext"),
+            section("b", "Setup", 2, "## Install"),
+        ];
+        assert_eq!(
+            sections_to_markdown(&sections),
+            "<a id=\"a\"></a>\n# Guide\n\n## Intro\ntext\n\n<a id=\"b\"></a>\n## Setup\n\n#### Install\n"
+        );
+    }
+
+    #[test]
+    fn export_internal_links() {
+        let sections = vec![
+            section("a", "Guide", 1, "see [setup](https://appflowy.io/view/b) and [site](https://appflowy.io)"),
+            section("b", "Setup", 1, "done"),
+        ];
+        let markdown = sections_to_markdown(&sections);
+        assert!(markdown.contains("[setup](#b)"));
+        assert!(markdown.contains("[site](https://appflowy.io)"));
+    }
+
+    #[test]
+    fn export_replace_doc_references() {
+        let mut delta = markdown_to_delta("see [a](a) and [b](https://appflowy.io/view/b/) or [c](c)");
+        let mut attributes = Attributes::new();
+        attributes.add(Attribute::Attachment("image"));
+        delta.insert("image.png", attributes);
+
+        let new_ids = vec![("a", "x"), ("b", "y"), ("image", "z")]
+            .into_iter()
+            .map(|(id, new_id)| (id.to_owned(), new_id.to_owned()))
+            .collect::<HashMap<String, String>>();
+        let new_delta = replace_doc_references(&delta, &new_ids);
+        let markdown = delta_to_markdown(&new_delta);
+        assert!(markdown.contains("[a](x)"));
+        assert!(markdown.contains("[b](https://appflowy.io/view/y)"));
+        assert!(markdown.contains("[c](c)"));
+
+        let attachment = new_delta
+            .ops
+            .iter()
+            .find_map(|op| op.get_attributes().get(&AttributeKey::Attachment).and_then(|value| value.0.clone()));
+        assert_eq!(attachment, Some("z".to_owned()));
+    }
+}
@@ -0,0 +1,267 @@
+use lib_ot::core::{AttributeKey, Attributes, Delta, NEW_LINE};
+use std::mem;
+
+pub(crate) struct Line {
+    pub(crate) spans: Vec<(String, Attributes)>,
+    pub(crate) attributes: Attributes,
+}
+
+#[derive(PartialEq)]
+pub(crate) enum Block {
+    Paragraph,
+    Header(usize),
+    Bullet,
+    Ordered,
+    Checkbox(bool),
+    Quote,
+    Code,
+}
+
+impl Block {
+    // The consecutive lines of the same list or code block share one container
+    fn container(&self) -> Option<&'static str> {
+        match self {
+            Block::Bullet | Block::Checkbox(_) => Some("ul"),
+            Block::Ordered => Some("ol"),
+            Block::Code => Some("pre"),
+            _ => None,
+        }
+    }
+}
+
+// Renders the delta to the html fragment that goes into the body of the page. The block attributes
+// are carried by the newline that ends each line, the inline attributes by the text itself.
+pub fn delta_to_html(delta: &Delta) -> String {
+    let mut html = String::new();
+    let mut container: Option<&'static str> = None;
+    for line in lines_from_delta(delta) {
+        let block = block_from_attributes(&line.attributes);
+        if block.container() != container {
+            if let Some(tag) = container {
+                html.push_str(&format!("</{}>", tag));
+            }
+            container = block.container();
+            if let Some(tag) = container {
+                html.push_str(&format!("<{}>", tag));
+            }
+        }
+
+        if block == Block::Code {
+            line.spans
+                .iter()
+                .for_each(|(text, _)| html.push_str(&escape_html(text)));
+            html.push_str(NEW_LINE);
+            continue;
+        }
+
+        let content = match line.spans.is_empty() {
+            true => "<br>".to_owned(),
+            false => line
+                .spans
+                .iter()
+                .map(|(text, attributes)| inline_html(text, attributes))
+                .collect(),
+        };
+        match block {
+            Block::Header(level) => html.push_str(&format!("<h{0}>{1}</h{0}>", level, content)),
+            Block::Bullet | Block::Ordered => html.push_str(&format!("<li>{}</li>", content)),
+            Block::Checkbox(is_checked) => {
+                let checked = if is_checked { " checked" } else { "" };
+                html.push_str(&format!(
+                    "<li><input type=\"checkbox\"{} disabled>{}</li>",
+                    checked, content
+                ));
+            },
+            Block::Quote => html.push_str(&format!("<blockquote>{}</blockquote>", content)),
+            _ => html.push_str(&format!("<p>{}</p>", content)),
+        }
+    }
+
+    if let Some(tag) = container {
+        html.push_str(&format!("</{}>", tag));
+    }
+    html
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineHeading {
+    pub level: usize,
+    pub text: String,
+}
+
+// The headers of the doc in the order they appear, the text is plain without the inline attributes
+pub fn delta_to_outline(delta: &Delta) -> Vec<OutlineHeading> {
+    lines_from_delta(delta)
+        .into_iter()
+        .filter_map(|line| match block_from_attributes(&line.attributes) {
+            Block::Header(level) => Some(OutlineHeading {
+                level,
+                text: line.spans.into_iter().map(|(text, _)| text).collect(),
+            }),
+            _ => None,
+        })
+        .filter(|heading| !heading.text.trim().is_empty())
+        .collect()
+}
+
+pub(crate) fn lines_from_delta(delta: &Delta) -> Vec<Line> {
+    let mut lines = vec![];
+    let mut spans = vec![];
+    for op in &delta.ops {
+        if !op.is_insert() {
+            continue;
+        }
+
+        let attributes = op.get_attributes();
+        for s in op.get_data().split_inclusive(NEW_LINE) {
+            match s.strip_suffix(NEW_LINE) {
+                None => spans.push((s.to_owned(), attributes.clone())),
+                Some(text) => {
+                    if !text.is_empty() {
+                        spans.push((text.to_owned(), attributes.clone()));
+                    }
+                    lines.push(Line {
+                        spans: mem::take(&mut spans),
+                        attributes: attributes.clone(),
+                    });
+                },
+            }
+        }
+    }
+
+    if !spans.is_empty() {
+        lines.push(Line {
+            spans,
+            attributes: Attributes::new(),
+        });
+    }
+    lines
+}
+
+pub(crate) fn block_from_attributes(attributes: &Attributes) -> Block {
+    let value = |key: AttributeKey| attributes.get(&key).and_then(|value| value.0.clone());
+    if let Some(level) = value(AttributeKey::Header).and_then(|level| level.parse::<usize>().ok()) {
+        return Block::Header(level.max(1).min(6));
+    }
+
+    if value(AttributeKey::CodeBlock).is_some() {
+        return Block::Code;
+    }
+
+    if value(AttributeKey::BlockQuote).is_some() {
+        return Block::Quote;
+    }
+
+    match value(AttributeKey::List).as_deref() {
+        Some("bullet") => Block::Bullet,
+        Some("ordered") => Block::Ordered,
+        Some("checked") => Block::Checkbox(true),
+        Some("unchecked") => Block::Checkbox(false),
+        _ => Block::Paragraph,
+    }
+}
+
+fn inline_html(text: &str, attributes: &Attributes) -> String {
+    let is_enabled = |key: AttributeKey| attributes.get(&key).map_or(false, |value| value.0.is_some());
+    let mut html = escape_html(text);
+    let tags = vec![
+        (AttributeKey::InlineCode, "code"),
+        (AttributeKey::StrikeThrough, "s"),
+        (AttributeKey::Underline, "u"),
+        (AttributeKey::Italic, "em"),
+        (AttributeKey::Bold, "strong"),
+    ];
+    for (key, tag) in tags {
+        if is_enabled(key) {
+            html = format!("<{0}>{1}</{0}>", tag, html);
+        }
+    }
+
+    if let Some(Some(link)) = attributes.get(&AttributeKey::Link).map(|value| value.0.as_ref()) {
+        if is_safe_link(link) {
+            html = format!("<a href=\"{}\">{}</a>", escape_html(link), html);
+        }
+    }
+    html
+}
+
+// The pages are public, so the links that could run scripts, e.g. javascript: or data:, are rendered as
+// plain text
+const SAFE_LINK_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+fn is_safe_link(link: &str) -> bool {
+    match link.trim().split_once(':') {
+        Some((scheme, _)) => SAFE_LINK_SCHEMES.iter().any(|safe| scheme.eq_ignore_ascii_case(safe)),
+        None => false,
+    }
+}
+
+// The page title and the links are escaped as well, so it's shared with the page template
+pub fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{delta_to_html, delta_to_outline, OutlineHeading};
+    use crate::core::markdown_to_delta;
+
+    #[test]
+    fn html_blocks() {
+        let delta = markdown_to_delta("# Title\n- a\n- b\nend");
+        assert_eq!(
+            delta_to_html(&delta),
+            "<h1>Title</h1><ul><li>a</li><li>b</li></ul><p>end</p>"
+        );
+    }
+
+    #[test]
+    fn html_inline() {
+        let delta = markdown_to_delta("**<b>** and `code`");
+        assert_eq!(
+            delta_to_html(&delta),
+            "<p><strong>&lt;b&gt;</strong> and <code>code</code></p>"
+        );
+    }
+
+    #[test]
+    fn html_links() {
+        let delta = markdown_to_delta("[site](https://appflowy.io) [x](javascript:alert`1`) [y](JavaScript:void)");
+        assert_eq!(
+            delta_to_html(&delta),
+            "<p><a href=\"https://appflowy.io\">site</a> x y</p>"
+        );
+    }
+
+    #[test]
+    fn outline_headers() {
+        let delta = markdown_to_delta("# Title
+intro
+## **Plans**
+#
+### Q1");
+        let outline = delta_to_outline(&delta)
+            .into_iter()
+            .map(|OutlineHeading { level, text }| (level, text))
+            .collect::<Vec<(usize, String)>>();
+        assert_eq!(
+            outline,
+            vec![
+                (1, "Title".to_owned()),
+                (2, "Plans".to_owned()),
+                (3, "Q1".to_owned())
+            ]
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use crate::{
+    core::{
+        builder::{DocumentBuilder, Span},
+        html::{block_from_attributes, lines_from_delta, Block},
+    },
+    entities::doc::{DocBlockType, DocImportBlock, DocImportPreview, DocImportWarning},
+};
+use lib_ot::core::{Attribute, AttributeKey, Attributes, Delta, NEW_LINE};
+
+const CODE_FENCE: &str = "```";
+
+pub type LinkResolver<'a> = dyn FnMut(&str, bool) -> Option<Attribute> + 'a;
+
+// Converts the markdown to a delta that ends with a newline. Only the syntax that the editor can show
+// is supported: headings, lists, checkboxes, quotes, code blocks, links and the bold, italic and inline
+// code marks. Everything else is kept as plain text.
+pub fn markdown_to_delta(markdown: &str) -> Delta { markdown_to_delta_with_links(markdown, &mut |_, _| None) }
+
+// Like markdown_to_delta, but the url of each link is passed to the resolve function first, the bool is
+// true if the link is an image, i.e. ![text](url). The returned attribute replaces the link attribute,
+// e.g. the link to another doc or the attachment. The link is kept as it is if None is returned, and
+// the image is kept as a link.
+pub fn markdown_to_delta_with_links(markdown: &str, resolve: &mut LinkResolver) -> Delta {
+    let mut builder = DocumentBuilder::new();
+    let mut is_code_block = false;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with(CODE_FENCE) {
+            is_code_block = !is_code_block;
+            continue;
+        }
+
+        if is_code_block {
+            builder = builder.code(line);
+            continue;
+        }
+
+        let (text, block_attribute) = parse_block(line);
+        builder = builder.line(inline_spans(text, resolve), block_attribute);
+    }
+    builder.build()
+}
+
+// Returns the lines of the doc that markdown_to_delta would create and the syntax that it would keep as
+// plain text, so the import can be previewed without changing the doc.
+pub fn preview_markdown(doc_id: &str, markdown: &str) -> DocImportPreview {
+    let mut blocks = vec![];
+    let mut warnings = vec![];
+    let mut code_block_start = None;
+    for (index, line) in markdown.lines().enumerate() {
+        let line_number = index as i64 + 1;
+        if line.trim_start().starts_with(CODE_FENCE) {
+            code_block_start = match code_block_start {
+                None => Some(line_number),
+                Some(_) => None,
+            };
+            continue;
+        }
+
+        if code_block_start.is_some() {
+            blocks.push(DocImportBlock {
+                block_type: DocBlockType::Code,
+                level: 0,
+                text: line.to_owned(),
+                line: line_number,
+            });
+            continue;
+        }
+
+        let (text, block_attribute) = parse_block(line);
+        let block = match block_attribute {
+            None => Block::Paragraph,
+            Some(attribute) => block_from_attributes(&attribute.into()),
+        };
+        if let Some(message) = unsupported_syntax(line, &block) {
+            warnings.push(DocImportWarning {
+                line: line_number,
+                message: message.to_owned(),
+            });
+        }
+
+        let spans = inline_spans(text, &mut |_, _| None);
+        let (block_type, level) = match block {
+            Block::Header(level) => (DocBlockType::Heading, level as i32),
+            Block::Bullet => (DocBlockType::Bullet, 0),
+            Block::Ordered => (DocBlockType::Ordered, 0),
+            Block::Checkbox(_) => (DocBlockType::Checkbox, 0),
+            Block::Quote => (DocBlockType::Quote, 0),
+            Block::Code => (DocBlockType::Code, 0),
+            Block::Paragraph => (DocBlockType::Paragraph, 0),
+        };
+        blocks.push(DocImportBlock {
+            block_type,
+            level,
+            text: spans.into_iter().map(|span| span.text).collect(),
+            line: line_number,
+        });
+    }
+
+    if let Some(line) = code_block_start {
+        warnings.push(DocImportWarning {
+            line,
+            message: "The code block isn't closed, the lines after it are imported as code".to_owned(),
+        });
+    }
+
+    DocImportPreview {
+        doc_id: doc_id.to_owned(),
+        blocks,
+        warnings,
+    }
+}
+
+// The common syntax that parse_block and insert_inline don't support. The nested lists are flattened
+// because the indent is trimmed.
+fn unsupported_syntax(line: &str, block: &Block) -> Option<&'static str> {
+    let trimmed = line.trim();
+    let is_list = matches!(block, Block::Bullet | Block::Ordered | Block::Checkbox(_));
+    if is_list && line.starts_with(|c: char| c.is_whitespace()) {
+        return Some("The nested list is imported as a top level list");
+    }
+    if trimmed.len() > 1 && trimmed.starts_with('|') && trimmed.ends_with('|') {
+        return Some("The table is imported as plain text");
+    }
+    if trimmed.starts_with('<') && trimmed.ends_with('>') {
+        return Some("The HTML is imported as plain text");
+    }
+    let is_divider = |c: char| trimmed.len() >= 3 && trimmed.chars().all(|ch| ch == c);
+    if is_divider('-') || is_divider('*') || is_divider('_') {
+        return Some("The divider is imported as plain text");
+    }
+    if trimmed.contains("![") {
+        return Some("The image is imported as plain text");
+    }
+    None
+}
+
+fn parse_block(line: &str) -> (&str, Option<Attribute>) {
+    let trimmed = line.trim_start();
+    let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&hashes) {
+        if let Some(text) = trimmed[hashes..].strip_prefix(' ') {
+            return (text, Some(Attribute::Header(hashes)));
+        }
+    }
+
+    let prefixes = vec![
+        ("- [ ] ", Attribute::UnChecked(true)),
+        ("- [x] ", Attribute::Checked(true)),
+        ("- [X] ", Attribute::Checked(true)),
+        ("- ", Attribute::Bullet(true)),
+        ("* ", Attribute::Bullet(true)),
+        ("> ", Attribute::BlockQuote(true)),
+    ];
+    for (prefix, attribute) in prefixes {
+        if let Some(text) = trimmed.strip_prefix(prefix) {
+            return (text, Some(attribute));
+        }
+    }
+
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        if let Some(text) = trimmed[digits..].strip_prefix(". ") {
+            return (text, Some(Attribute::Ordered(true)));
+        }
+    }
+    (line, None)
+}
+
+// The marks that are not closed on the same line are kept as plain text
+fn inline_spans(text: &str, resolve: &mut LinkResolver) -> Vec<Span> {
+    let mut spans = vec![];
+    let mut rest = text;
+    while let Some(start) = rest.find(|c| c == '*' || c == '`' || c == '[') {
+        if rest[start..].starts_with('[') {
+            let is_image = rest[..start].ends_with('!');
+            match parse_link(&rest[start..]) {
+                Some((link_text, url, len)) if !link_text.is_empty() || is_image => {
+                    match resolve(url, is_image) {
+                        Some(attribute) => {
+                            // The image without the text is named after its file
+                            let text_start = if is_image { start - 1 } else { start };
+                            let link_text = match link_text {
+                                "" => url.rsplit('/').next().unwrap_or(url),
+                                _ => link_text,
+                            };
+                            spans.push(Span::from(&rest[..text_start]));
+                            spans.push(Span::new(link_text, vec![attribute]));
+                        },
+                        None if link_text.is_empty() => spans.push(Span::from(&rest[..start + len])),
+                        None => {
+                            spans.push(Span::from(&rest[..start]));
+                            spans.push(Span::link(link_text, url));
+                        },
+                    }
+                    rest = &rest[start + len..];
+                },
+                _ => {
+                    spans.push(Span::from(&rest[..start + 1]));
+                    rest = &rest[start + 1..];
+                },
+            }
+            continue;
+        }
+
+        let (mark, attribute) = match &rest[start..] {
+            s if s.starts_with("**") => ("**", Attribute::Bold(true)),
+            s if s.starts_with('`') => ("`", Attribute::InlineCode(true)),
+            _ => ("*", Attribute::Italic(true)),
+        };
+
+        let marked = &rest[start + mark.len()..];
+        match marked.find(mark) {
+            Some(end) if end > 0 => {
+                spans.push(Span::from(&rest[..start]));
+                spans.push(Span::new(&marked[..end], vec![attribute]));
+                rest = &marked[end + mark.len()..];
+            },
+            _ => {
+                spans.push(Span::from(&rest[..start + mark.len()]));
+                rest = marked;
+            },
+        }
+    }
+    spans.push(Span::from(rest));
+    spans
+}
+
+// Parses the [text](url) at the start of s, returns the text, the url and the length of the link. The
+// text may be empty, e.g. the image without the alt text.
+fn parse_link(s: &str) -> Option<(&str, &str, usize)> {
+    let text_end = s.find("](")?;
+    let url_start = text_end + 2;
+    let url_end = url_start + s[url_start..].find(')')?;
+    let text = &s[1..text_end];
+    let url = &s[url_start..url_end];
+    if url.is_empty() {
+        return None;
+    }
+    Some((text, url, url_end + 1))
+}
+
+// Converts the delta to markdown, it's the reverse of markdown_to_delta. The attributes that markdown
+// can't express, e.g. the color or the underline, are dropped.
+pub fn delta_to_markdown(delta: &Delta) -> String {
+    let mut markdown = String::new();
+    let mut is_code_block = false;
+    let mut ordered_index = 0;
+    for line in lines_from_delta(delta) {
+        let block = block_from_attributes(&line.attributes);
+        if (block == Block::Code) != is_code_block {
+            is_code_block = !is_code_block;
+            markdown.push_str(CODE_FENCE);
+            markdown.push_str(NEW_LINE);
+        }
+
+        if is_code_block {
+            line.spans.iter().for_each(|(text, _)| markdown.push_str(text));
+            markdown.push_str(NEW_LINE);
+            continue;
+        }
+
+        ordered_index = match block {
+            Block::Ordered => ordered_index + 1,
+            _ => 0,
+        };
+        let prefix = match block {
+            Block::Header(level) => format!("{} ", "#".repeat(level)),
+            Block::Bullet => "- ".to_owned(),
+            Block::Ordered => format!("{}. ", ordered_index),
+            Block::Checkbox(true) => "- [x] ".to_owned(),
+            Block::Checkbox(false) => "- [ ] ".to_owned(),
+            Block::Quote => "> ".to_owned(),
+            Block::Paragraph | Block::Code => "".to_owned(),
+        };
+        markdown.push_str(&prefix);
+        line.spans
+            .iter()
+            .for_each(|(text, attributes)| markdown.push_str(&inline_markdown(text, attributes)));
+        markdown.push_str(NEW_LINE);
+    }
+
+    if is_code_block {
+        markdown.push_str(CODE_FENCE);
+        markdown.push_str(NEW_LINE);
+    }
+    markdown
+}
+
+fn inline_markdown(text: &str, attributes: &Attributes) -> String {
+    let is_enabled = |key: AttributeKey| attributes.get(&key).map_or(false, |value| value.0.is_some());
+    let mut markdown = text.to_owned();
+    let marks = vec![
+        (AttributeKey::InlineCode, "`"),
+        (AttributeKey::Italic, "*"),
+        (AttributeKey::Bold, "**"),
+    ];
+    for (key, mark) in marks {
+        if is_enabled(key) {
+            markdown = format!("{0}{1}{0}", mark, markdown);
+        }
+    }
+
+    if let Some(Some(link)) = attributes.get(&AttributeKey::Link).map(|value| value.0.as_ref()) {
+        markdown = format!("[{}]({})", markdown, link);
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{delta_to_markdown, markdown_to_delta, markdown_to_delta_with_links, preview_markdown};
+    use crate::entities::doc::DocBlockType;
+    use lib_ot::core::{Attribute, DeltaBuilder};
+
+    #[test]
+    fn markdown_block_attributes() {
+        let delta = markdown_to_delta("# Title\n- [ ] task\n1. first");
+        let expected = DeltaBuilder::new()
+            .insert("Title")
+            .insert_with_attributes("\n", Attribute::Header(1).into())
+            .insert("task")
+            .insert_with_attributes("\n", Attribute::UnChecked(true).into())
+            .insert("first")
+            .insert_with_attributes("\n", Attribute::Ordered(true).into())
+            .build();
+        assert_eq!(delta, expected);
+    }
+
+    #[test]
+    fn markdown_inline_attributes() {
+        let delta = markdown_to_delta("a **b** *c");
+        assert_eq!(
+            delta.to_json(),
+            r#"[{"insert":"a "},{"insert":"b","attributes":{"bold":true}},{"insert":" *c\n"}]"#
+        );
+    }
+
+    #[test]
+    fn markdown_code_block() {
+        let delta = markdown_to_delta("```\nlet a = 1;\n```");
+        assert_eq!(
+            delta.to_json(),
+            r#"[{"insert":"let a = 1;"},{"insert":"\n","attributes":{"code_block":true}}]"#
+        );
+    }
+
+    #[test]
+    fn markdown_link() {
+        let delta = markdown_to_delta("see [docs](https://appflowy.io) [x]");
+        assert_eq!(
+            delta.to_json(),
+            r#"[{"insert":"see "},{"insert":"docs","attributes":{"link":"https://appflowy.io"}},{"insert":" [x]\n"}]"#
+        );
+    }
+
+    #[test]
+    fn markdown_resolve_links() {
+        let markdown = "[page](page.md) ![](img/a.png) ![b](b.png) [web](https://appflowy.io)";
+        let delta = markdown_to_delta_with_links(markdown, &mut |url, is_image| match (url, is_image) {
+            ("page.md", false) => Some(Attribute::Link("page_id")),
+            ("img/a.png", true) => Some(Attribute::Attachment("attachment_id")),
+            _ => None,
+        });
+        assert_eq!(
+            delta.to_json(),
+            concat!(
+                r#"[{"insert":"page","attributes":{"link":"page_id"}},{"insert":" "},"#,
+                r#"{"insert":"a.png","attributes":{"attachment":"attachment_id"}},{"insert":" !"},"#,
+                r#"{"insert":"b","attributes":{"link":"b.png"}},{"insert":" "},"#,
+                r#"{"insert":"web","attributes":{"link":"https://appflowy.io"}},{"insert":"\n"}]"#
+            )
+        );
+    }
+
+    #[test]
+    fn markdown_round_trip() {
+        let markdown = concat!(
+            "#### Title\n- [x] done\n- [ ] todo\n1. first\n2. second\n> quote\n",
+            "```\nlet a = 1;\n```\n**bold** and [link](https://appflowy.io)\n"
+        );
+        let delta = markdown_to_delta(markdown);
+        assert_eq!(delta_to_markdown(&delta), markdown);
+    }
+
+    #[test]
+    fn markdown_preview() {
+        let markdown = "## Title\n- **bold** item\n  - nested\n| a | b |\n```\nlet a = 1;";
+        let preview = preview_markdown("doc", markdown);
+        let blocks = preview
+            .blocks
+            .iter()
+            .map(|block| (block.block_type, block.level, block.text.as_str(), block.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            blocks,
+            vec![
+                (DocBlockType::Heading, 2, "Title", 1),
+                (DocBlockType::Bullet, 0, "bold item", 2),
+                (DocBlockType::Bullet, 0, "nested", 3),
+                (DocBlockType::Paragraph, 0, "| a | b |", 4),
+                (DocBlockType::Code, 0, "let a = 1;", 6),
+            ]
+        );
+
+        let warning_lines = preview.warnings.iter().map(|warning| warning.line).collect::<Vec<i64>>();
+        assert_eq!(warning_lines, vec![3, 4, 5]);
+    }
+}
@@ -1,8 +1,19 @@
+mod builder;
+mod chunk;
 mod data;
 mod document;
+mod export;
 mod extensions;
-pub mod history;
+mod html;
+mod markdown;
+mod pdf;
 mod view;
 
+pub use builder::*;
+pub use chunk::*;
 pub use document::*;
+pub use export::*;
+pub use html::*;
+pub use markdown::*;
+pub use pdf::*;
 pub use view::RECORD_THRESHOLD;
@@ -0,0 +1,714 @@
+use crate::core::{
+    export::{header_level, resolve_section, section_ids, ExportSection},
+    html::{block_from_attributes, lines_from_delta, Block, Line},
+};
+use lib_ot::core::{AttributeKey, Attributes};
+use std::{collections::HashMap, mem};
+
+// A4 in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 56.0;
+const BODY_SIZE: f32 = 11.0;
+const CODE_SIZE: f32 = 10.0;
+const LINE_HEIGHT: f32 = 1.4;
+const BLOCK_SPACING: f32 = 4.0;
+const INDENT: f32 = 18.0;
+// The glyphs of Helvetica-Bold are a bit wider than the regular ones, it's close enough for wrapping
+const BOLD_WIDTH_RATIO: f32 = 1.08;
+
+const TEXT_COLOR: &str = "0 g";
+const QUOTE_COLOR: &str = "0.4 g";
+const LINK_COLOR: &str = "0.1 0.3 0.8 rg";
+
+// The widths of the ascii chars of Helvetica in the thousandths of the font size, from the space
+#[rustfmt::skip]
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+// Renders the docs to a PDF that only uses the standard fonts, so no font is embedded. The title of each
+// doc is added to the outline and the links to the other docs jump to their titles. The chars that the
+// WinAnsi encoding of the standard fonts doesn't have are rendered as '?'.
+pub fn sections_to_pdf(title: &str, sections: &[ExportSection]) -> Vec<u8> {
+    let ids = section_ids(sections);
+    let mut layout = Layout::new();
+    for section in sections {
+        layout.add_title(section);
+        layout.add_lines(lines_from_delta(&resolve_section(section, &ids)));
+    }
+    write_pdf(title, layout)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Font {
+    Regular,
+    Bold,
+    Italic,
+    BoldItalic,
+    Code,
+}
+
+const FONTS: [Font; 5] = [Font::Regular, Font::Bold, Font::Italic, Font::BoldItalic, Font::Code];
+
+impl Font {
+    fn from_attributes(attributes: &Attributes) -> Self {
+        let is_enabled = |key: AttributeKey| attributes.get(&key).map_or(false, |value| value.0.is_some());
+        if is_enabled(AttributeKey::InlineCode) {
+            return Font::Code;
+        }
+
+        match (is_enabled(AttributeKey::Bold), is_enabled(AttributeKey::Italic)) {
+            (true, true) => Font::BoldItalic,
+            (true, false) => Font::Bold,
+            (false, true) => Font::Italic,
+            (false, false) => Font::Regular,
+        }
+    }
+
+    fn bold(self) -> Self {
+        match self {
+            Font::Italic | Font::BoldItalic => Font::BoldItalic,
+            Font::Code => Font::Code,
+            _ => Font::Bold,
+        }
+    }
+
+    fn resource_name(&self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+            Font::Italic => "F3",
+            Font::BoldItalic => "F4",
+            Font::Code => "F5",
+        }
+    }
+
+    fn base_font(&self) -> &'static str {
+        match self {
+            Font::Regular => "Helvetica",
+            Font::Bold => "Helvetica-Bold",
+            Font::Italic => "Helvetica-Oblique",
+            Font::BoldItalic => "Helvetica-BoldOblique",
+            Font::Code => "Courier",
+        }
+    }
+
+    fn text_width(&self, text: &str, size: f32) -> f32 {
+        let width = text
+            .chars()
+            .map(|c| match self {
+                Font::Code => 600.0,
+                Font::Bold | Font::BoldItalic => helvetica_width(c) * BOLD_WIDTH_RATIO,
+                _ => helvetica_width(c),
+            })
+            .sum::<f32>();
+        width * size / 1000.0
+    }
+}
+
+fn helvetica_width(c: char) -> f32 {
+    match c {
+        ' '..='~' => HELVETICA_WIDTHS[c as usize - ' ' as usize] as f32,
+        '\u{2022}' => 350.0,
+        '\u{2014}' | '\u{2026}' => 1000.0,
+        _ => 556.0,
+    }
+}
+
+fn heading_size(level: usize) -> f32 {
+    match level {
+        1 => 22.0,
+        2 => 18.0,
+        3 => 15.0,
+        4 => 13.0,
+        _ => 12.0,
+    }
+}
+
+// The part of the line that is wrapped as a whole, e.g. the word with the spaces after it
+struct Piece {
+    text: String,
+    font: Font,
+    link: Option<String>,
+}
+
+// The text that is drawn in one font, x and y are the start of its baseline
+struct Run {
+    text: String,
+    font: Font,
+    size: f32,
+    x: f32,
+    y: f32,
+    color: &'static str,
+}
+
+struct LinkArea {
+    rect: [f32; 4],
+    target: String,
+}
+
+#[derive(Default)]
+struct Page {
+    runs: Vec<Run>,
+    links: Vec<LinkArea>,
+}
+
+struct Outline {
+    title: String,
+    level: usize,
+    page: usize,
+    y: f32,
+}
+
+struct Layout {
+    pages: Vec<Page>,
+    // The top of the next line, it goes down from the top margin of the page
+    y: f32,
+    outlines: Vec<Outline>,
+    // The page and the top of the title of each doc
+    destinations: HashMap<String, (usize, f32)>,
+}
+
+impl Layout {
+    fn new() -> Self {
+        Layout {
+            pages: vec![Page::default()],
+            y: PAGE_HEIGHT - MARGIN,
+            outlines: vec![],
+            destinations: HashMap::new(),
+        }
+    }
+
+    fn add_title(&mut self, section: &ExportSection) {
+        let size = heading_size(header_level(section.level));
+        self.skip(size);
+        // The title isn't left alone at the bottom of the page
+        self.ensure_space(size * LINE_HEIGHT + BODY_SIZE * LINE_HEIGHT);
+
+        let page = self.pages.len() - 1;
+        self.destinations.insert(section.id.clone(), (page, self.y));
+        self.outlines.push(Outline {
+            title: section.title.clone(),
+            level: section.level,
+            page,
+            y: self.y,
+        });
+
+        let piece = Piece {
+            text: section.title.clone(),
+            font: Font::Bold,
+            link: None,
+        };
+        self.add_paragraph(vec![piece], size, 0.0, None, TEXT_COLOR);
+        self.skip(BLOCK_SPACING * 2.0);
+    }
+
+    fn add_lines(&mut self, lines: Vec<Line>) {
+        let mut ordered_index = 0;
+        for line in lines {
+            let block = block_from_attributes(&line.attributes);
+            ordered_index = match block {
+                Block::Ordered => ordered_index + 1,
+                _ => 0,
+            };
+
+            if block == Block::Code {
+                // The code keeps its spaces, so it's wrapped at any char
+                let pieces = line
+                    .spans
+                    .iter()
+                    .flat_map(|(text, _)| text.chars())
+                    .map(|c| Piece {
+                        text: c.to_string(),
+                        font: Font::Code,
+                        link: None,
+                    })
+                    .collect();
+                self.add_paragraph(pieces, CODE_SIZE, INDENT / 2.0, None, TEXT_COLOR);
+                continue;
+            }
+
+            let mut pieces = pieces_from_spans(&line.spans);
+            let (size, indent, marker, color) = match block {
+                Block::Header(level) => {
+                    pieces.iter_mut().for_each(|piece| piece.font = piece.font.bold());
+                    let size = heading_size(level);
+                    self.skip(size / 2.0);
+                    (size, 0.0, None, TEXT_COLOR)
+                },
+                Block::Bullet => (BODY_SIZE, INDENT, Some("\u{2022}".to_owned()), TEXT_COLOR),
+                Block::Ordered => (BODY_SIZE, INDENT, Some(format!("{}.", ordered_index)), TEXT_COLOR),
+                Block::Checkbox(true) => (BODY_SIZE, INDENT, Some("[x]".to_owned()), TEXT_COLOR),
+                Block::Checkbox(false) => (BODY_SIZE, INDENT, Some("[ ]".to_owned()), TEXT_COLOR),
+                Block::Quote => (BODY_SIZE, INDENT, None, QUOTE_COLOR),
+                Block::Paragraph | Block::Code => (BODY_SIZE, 0.0, None, TEXT_COLOR),
+            };
+            self.add_paragraph(pieces, size, indent, marker, color);
+            self.skip(BLOCK_SPACING);
+        }
+    }
+
+    fn add_paragraph(
+        &mut self,
+        pieces: Vec<Piece>,
+        size: f32,
+        indent: f32,
+        marker: Option<String>,
+        color: &'static str,
+    ) {
+        let left = MARGIN + indent;
+        let height = size * LINE_HEIGHT;
+        for (index, line) in wrap_pieces(pieces, size, PAGE_WIDTH - MARGIN - left).into_iter().enumerate() {
+            self.ensure_space(height);
+            self.y -= height;
+            // The baseline leaves the room for the descenders below it
+            let y = self.y + size * (LINE_HEIGHT - 1.0);
+            let page = self.pages.last_mut().unwrap();
+            if let (0, Some(marker)) = (index, &marker) {
+                page.runs.push(Run {
+                    text: marker.clone(),
+                    font: Font::Regular,
+                    size,
+                    x: left - INDENT,
+                    y,
+                    color,
+                });
+            }
+
+            let mut x = left;
+            for piece in merge_pieces(line) {
+                let width = piece.font.text_width(&piece.text, size);
+                if let Some(target) = piece.link {
+                    page.links.push(LinkArea {
+                        rect: [x, y - size * 0.25, x + width, y + size * 0.9],
+                        target,
+                    });
+                    page.runs.push(Run {
+                        text: piece.text,
+                        font: piece.font,
+                        size,
+                        x,
+                        y,
+                        color: LINK_COLOR,
+                    });
+                } else {
+                    page.runs.push(Run {
+                        text: piece.text,
+                        font: piece.font,
+                        size,
+                        x,
+                        y,
+                        color,
+                    });
+                }
+                x += width;
+            }
+        }
+    }
+
+    // Starts the next page if the height doesn't fit in the rest of the current one
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.pages.push(Page::default());
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    // The space isn't added at the top of the page
+    fn skip(&mut self, space: f32) {
+        if self.y < PAGE_HEIGHT - MARGIN {
+            self.y = (self.y - space).max(MARGIN);
+        }
+    }
+}
+
+fn pieces_from_spans(spans: &[(String, Attributes)]) -> Vec<Piece> {
+    let mut pieces = vec![];
+    for (text, attributes) in spans {
+        let font = Font::from_attributes(attributes);
+        let link = attributes.get(&AttributeKey::Link).and_then(|value| value.0.clone());
+        for word in text.split_inclusive(' ') {
+            pieces.push(Piece {
+                text: word.to_owned(),
+                font,
+                link: link.clone(),
+            });
+        }
+    }
+    pieces
+}
+
+// Fills each line with as many pieces as fit in the width, the piece that is wider than the line is
+// broken at its chars. There is always one line, the empty one keeps the space of the empty paragraph.
+fn wrap_pieces(pieces: Vec<Piece>, size: f32, max_width: f32) -> Vec<Vec<Piece>> {
+    let mut lines = vec![];
+    let mut line = vec![];
+    let mut width = 0.0;
+    for piece in pieces {
+        let parts = match piece.font.text_width(piece.text.trim_end(), size) > max_width {
+            false => vec![piece],
+            true => piece
+                .text
+                .chars()
+                .map(|c| Piece {
+                    text: c.to_string(),
+                    font: piece.font,
+                    link: piece.link.clone(),
+                })
+                .collect(),
+        };
+
+        for piece in parts {
+            // The spaces at the end of the line may go past the width
+            if !line.is_empty() && width + piece.font.text_width(piece.text.trim_end(), size) > max_width {
+                lines.push(mem::take(&mut line));
+                width = 0.0;
+            }
+            width += piece.font.text_width(&piece.text, size);
+            line.push(piece);
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+// The consecutive pieces in the same font and link are drawn as one run
+fn merge_pieces(line: Vec<Piece>) -> Vec<Piece> {
+    let mut merged: Vec<Piece> = vec![];
+    for piece in line {
+        match merged.last_mut() {
+            Some(last) if last.font == piece.font && last.link == piece.link => last.text.push_str(&piece.text),
+            _ => merged.push(piece),
+        }
+    }
+    merged
+}
+
+// The objects are numbered from 1 in the order they are reserved
+#[derive(Default)]
+struct PdfWriter {
+    objects: Vec<String>,
+}
+
+impl PdfWriter {
+    fn reserve(&mut self) -> usize {
+        self.objects.push(String::new());
+        self.objects.len()
+    }
+
+    fn set(&mut self, id: usize, object: String) { self.objects[id - 1] = object; }
+
+    fn add(&mut self, object: String) -> usize {
+        let id = self.reserve();
+        self.set(id, object);
+        id
+    }
+
+    // The content is ascii, so its length is the number of bytes
+    fn add_stream(&mut self, content: &str) -> usize {
+        self.add(format!(
+            "<< /Length {} >>\nstream\n{}\nendstream",
+            content.len(),
+            content
+        ))
+    }
+
+    fn finish(self, catalog: usize, info: usize) -> Vec<u8> {
+        let mut pdf = "%PDF-1.4\n".to_owned();
+        let mut offsets = vec![];
+        for (index, object) in self.objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", index + 1, object));
+        }
+
+        let xref = pdf.len();
+        pdf.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", self.objects.len() + 1));
+        offsets
+            .iter()
+            .for_each(|offset| pdf.push_str(&format!("{:010} 00000 n \n", offset)));
+        pdf.push_str(&format!(
+            "trailer\n<< /Size {} /Root {} 0 R /Info {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+            self.objects.len() + 1,
+            catalog,
+            info,
+            xref
+        ));
+        pdf.into_bytes()
+    }
+}
+
+fn write_pdf(title: &str, layout: Layout) -> Vec<u8> {
+    let mut writer = PdfWriter::default();
+    let catalog = writer.reserve();
+    let pages = writer.reserve();
+    let info = writer.add(format!("<< /Title {} /Producer (AppFlowy) >>", text_string(title)));
+    let fonts = FONTS
+        .iter()
+        .map(|font| {
+            let id = writer.add(format!(
+                "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                font.base_font()
+            ));
+            format!("/{} {} 0 R", font.resource_name(), id)
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let page_ids = layout.pages.iter().map(|_| writer.reserve()).collect::<Vec<usize>>();
+    let destination = |page: usize, y: f32| format!("[{} 0 R /XYZ 0 {:.2} null]", page_ids[page], y);
+    for (index, page) in layout.pages.iter().enumerate() {
+        let content = writer.add_stream(&page_content(page));
+        let mut annots = vec![];
+        for link in &page.links {
+            let action = match link.target.strip_prefix('#') {
+                Some(id) => match layout.destinations.get(id) {
+                    None => continue,
+                    Some((page_index, y)) => format!("/Dest {}", destination(*page_index, *y)),
+                },
+                None => format!("/A << /S /URI /URI {} >>", literal_string(&ascii_uri(&link.target))),
+            };
+            let [left, bottom, right, top] = link.rect;
+            let id = writer.add(format!(
+                "<< /Type /Annot /Subtype /Link /Rect [{:.2} {:.2} {:.2} {:.2}] /Border [0 0 0] {} >>",
+                left, bottom, right, top, action
+            ));
+            annots.push(format!("{} 0 R", id));
+        }
+
+        writer.set(
+            page_ids[index],
+            format!(
+                "<< /Type /Page /Parent {} 0 R /MediaBox [0 0 {} {}] /Resources << /Font << {} >> >> {} >>",
+                pages,
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                fonts,
+                format!("/Contents {} 0 R /Annots [{}]", content, annots.join(" "))
+            ),
+        );
+    }
+
+    let kids = page_ids
+        .iter()
+        .map(|id| format!("{} 0 R", id))
+        .collect::<Vec<String>>()
+        .join(" ");
+    writer.set(
+        pages,
+        format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids, page_ids.len()),
+    );
+
+    let outlines = match write_outlines(&mut writer, &layout.outlines, &destination) {
+        None => "".to_owned(),
+        Some(id) => format!(" /Outlines {} 0 R /PageMode /UseOutlines", id),
+    };
+    writer.set(catalog, format!("<< /Type /Catalog /Pages {} 0 R{} >>", pages, outlines));
+    writer.finish(catalog, info)
+}
+
+fn page_content(page: &Page) -> String {
+    let mut content = String::new();
+    for run in &page.runs {
+        content.push_str(&format!(
+            "BT {} /{} {} Tf {:.2} {:.2} Td ({}) Tj ET\n",
+            run.color,
+            run.font.resource_name(),
+            run.size,
+            run.x,
+            run.y,
+            encode_text(&run.text)
+        ));
+    }
+    content
+}
+
+// Each item of the outline is nested under the closest item before it that has a lower level, all the
+// items are open.
+fn write_outlines(
+    writer: &mut PdfWriter,
+    outlines: &[Outline],
+    destination: &dyn Fn(usize, f32) -> String,
+) -> Option<usize> {
+    if outlines.is_empty() {
+        return None;
+    }
+
+    let root = writer.reserve();
+    let ids = outlines.iter().map(|_| writer.reserve()).collect::<Vec<usize>>();
+    let mut parents: Vec<Option<usize>> = vec![];
+    let mut ancestors: Vec<usize> = vec![];
+    for outline in outlines {
+        while let Some(&last) = ancestors.last() {
+            if outlines[last].level < outline.level {
+                break;
+            }
+            ancestors.pop();
+        }
+        parents.push(ancestors.last().copied());
+        ancestors.push(parents.len() - 1);
+    }
+
+    let children = |parent: Option<usize>| {
+        (0..outlines.len())
+            .filter(|index| parents[*index] == parent)
+            .collect::<Vec<usize>>()
+    };
+    for (index, outline) in outlines.iter().enumerate() {
+        let parent = parents[index].map_or(root, |parent| ids[parent]);
+        let mut object = format!(
+            "<< /Title {} /Parent {} 0 R /Dest {}",
+            text_string(&outline.title),
+            parent,
+            destination(outline.page, outline.y)
+        );
+
+        let siblings = children(parents[index]);
+        if let Some(prev) = siblings.iter().rev().find(|sibling| **sibling < index) {
+            object.push_str(&format!(" /Prev {} 0 R", ids[*prev]));
+        }
+        if let Some(next) = siblings.iter().find(|sibling| **sibling > index) {
+            object.push_str(&format!(" /Next {} 0 R", ids[*next]));
+        }
+
+        let kids = children(Some(index));
+        if let (Some(first), Some(last)) = (kids.first(), kids.last()) {
+            let descendants = outlines[index + 1..]
+                .iter()
+                .take_while(|descendant| descendant.level > outline.level)
+                .count();
+            object.push_str(&format!(
+                " /First {} 0 R /Last {} 0 R /Count {}",
+                ids[*first], ids[*last], descendants
+            ));
+        }
+        object.push_str(" >>");
+        writer.set(ids[index], object);
+    }
+
+    let top = children(None);
+    writer.set(
+        root,
+        format!(
+            "<< /Type /Outlines /First {} 0 R /Last {} 0 R /Count {} >>",
+            ids[top[0]],
+            ids[top[top.len() - 1]],
+            outlines.len()
+        ),
+    );
+    Some(root)
+}
+
+// The text of the content stream is in WinAnsi, the bytes out of ascii are written as the octal escapes
+// to keep the file ascii.
+fn encode_text(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                encoded.push('\\');
+                encoded.push(c);
+            },
+            '\t' => encoded.push(' '),
+            ' '..='~' => encoded.push(c),
+            _ => encoded.push_str(&format!("\\{:03o}", win_ansi_byte(c))),
+        }
+    }
+    encoded
+}
+
+fn win_ansi_byte(c: char) -> u8 {
+    match c {
+        '\u{a0}'..='\u{ff}' => c as u8,
+        '\u{20ac}' => 0x80,
+        '\u{201a}' => 0x82,
+        '\u{201e}' => 0x84,
+        '\u{2026}' => 0x85,
+        '\u{2018}' => 0x91,
+        '\u{2019}' => 0x92,
+        '\u{201c}' => 0x93,
+        '\u{201d}' => 0x94,
+        '\u{2022}' => 0x95,
+        '\u{2013}' => 0x96,
+        '\u{2014}' => 0x97,
+        '\u{2122}' => 0x99,
+        _ => b'?',
+    }
+}
+
+fn literal_string(s: &str) -> String {
+    let escaped = s.replace('\\', "\\\\").replace('(', "\\(").replace(')', "\\)");
+    format!("({})", escaped)
+}
+
+// The title and the outline are shown by the viewer, so the text out of ascii is kept in UTF-16
+fn text_string(s: &str) -> String {
+    if s.chars().all(|c| (' '..='~').contains(&c)) {
+        return literal_string(s);
+    }
+
+    let hex = s
+        .encode_utf16()
+        .map(|unit| format!("{:04X}", unit))
+        .collect::<String>();
+    format!("<FEFF{}>", hex)
+}
+
+fn ascii_uri(uri: &str) -> String {
+    uri.bytes()
+        .map(|byte| match byte.is_ascii_graphic() {
+            true => (byte as char).to_string(),
+            false => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_text, sections_to_pdf};
+    use crate::core::{markdown_to_delta, ExportSection};
+
+    fn section(id: &str, title: &str, level: usize, markdown: &str) -> ExportSection {
+        ExportSection {
+            id: id.to_owned(),
+            title: title.to_owned(),
+            level,
+            delta: markdown_to_delta(markdown),
+        }
+    }
+
+    #[test]
+    fn pdf_outline_and_links() {
+        let sections = vec![
+            section("a", "Guide", 1, "# Intro\nsee [setup](b) or [site](https://appflowy.io)"),
+            section("b", "Setup", 2, "- step"),
+        ];
+        let pdf = String::from_utf8(sections_to_pdf("Docs", &sections)).unwrap();
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.contains("/Title (Docs)"));
+        assert!(pdf.contains("/Type /Outlines"));
+        assert!(pdf.contains("/Title (Guide)"));
+        assert!(pdf.contains("/Title (Setup)"));
+        assert!(pdf.contains("/Subtype /Link"));
+        assert!(pdf.contains("/URI (https://appflowy.io)"));
+        assert_eq!(pdf.matches("/Dest [").count(), 3);
+    }
+
+    #[test]
+    fn pdf_breaks_pages() {
+        let markdown = vec!["line"; 200].join("\n");
+        let pdf = String::from_utf8(sections_to_pdf("Docs", &[section("a", "Guide", 1, &markdown)])).unwrap();
+        assert!(pdf.matches("/Type /Page ").count() > 1);
+    }
+
+    #[test]
+    fn pdf_text_encoding() {
+        assert_eq!(encode_text("a(b) \u{e9} \u{4e2d}"), "a\\(b\\) \\351 \\077");
+    }
+}
@@ -0,0 +1,162 @@
+use flowy_derive::ProtoBuf;
+use lib_ot::core::{AttributeKey, Delta};
+use std::collections::HashMap;
+
+// The file that is larger than this can't be attached to the document
+pub const MAX_ATTACHMENT_SIZE: i64 = 20 * 1024 * 1024;
+
+// The file that is embedded in the document, e.g. an image. It's uploaded and downloaded in chunks of
+// the chunk_size that the server decides, the last chunk might be smaller. The attachment can be read
+// once all the chunks are uploaded.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct Attachment {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub size: i64,
+
+    #[pb(index = 5)]
+    pub chunk_size: i64,
+
+    // The chunks are uploaded in order, so the upload is resumed from this index
+    #[pb(index = 6)]
+    pub uploaded_chunks: i64,
+
+    #[pb(index = 7)]
+    pub create_time: i64,
+}
+
+impl Attachment {
+    pub fn chunk_count(&self) -> i64 { chunk_count(self.size, self.chunk_size) }
+
+    pub fn is_completed(&self) -> bool { self.uploaded_chunks >= self.chunk_count() }
+
+    // The length of the chunk at the index, only the last chunk can be smaller than the chunk_size
+    pub fn chunk_len(&self, index: i64) -> i64 { (self.size - index * self.chunk_size).min(self.chunk_size).max(0) }
+}
+
+pub fn chunk_count(size: i64, chunk_size: i64) -> i64 {
+    if chunk_size <= 0 {
+        return 0;
+    }
+    (size + chunk_size - 1) / chunk_size
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CreateAttachmentParams {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub size: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AttachmentIdentifier {
+    #[pb(index = 1)]
+    pub attachment_id: String,
+}
+
+impl std::convert::From<&str> for AttachmentIdentifier {
+    fn from(attachment_id: &str) -> Self {
+        AttachmentIdentifier {
+            attachment_id: attachment_id.to_owned(),
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AttachmentChunk {
+    #[pb(index = 1)]
+    pub attachment_id: String,
+
+    #[pb(index = 2)]
+    pub index: i64,
+
+    #[pb(index = 3)]
+    pub data: Vec<u8>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AttachmentChunkIdentifier {
+    #[pb(index = 1)]
+    pub attachment_id: String,
+
+    #[pb(index = 2)]
+    pub index: i64,
+}
+
+// Sent to the dart side with the attachment_id after each chunk is uploaded
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct AttachmentUploadProgress {
+    #[pb(index = 1)]
+    pub attachment_id: String,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub total: i64,
+
+    #[pb(index = 4)]
+    pub uploaded: i64,
+
+    #[pb(index = 5)]
+    pub is_completed: bool,
+}
+
+impl std::convert::From<&Attachment> for AttachmentUploadProgress {
+    fn from(attachment: &Attachment) -> Self {
+        let uploaded = (0..attachment.uploaded_chunks.min(attachment.chunk_count()))
+            .map(|index| attachment.chunk_len(index))
+            .sum();
+        AttachmentUploadProgress {
+            attachment_id: attachment.id.clone(),
+            doc_id: attachment.doc_id.clone(),
+            total: attachment.size,
+            uploaded,
+            is_completed: attachment.is_completed(),
+        }
+    }
+}
+
+// The attachment that was downloaded to the local cache, the path is the file of its data
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct AttachmentFile {
+    #[pb(index = 1)]
+    pub attachment_id: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+}
+
+// Counts the references of each attachment in the document. The text that the attachment attribute is
+// applied to may be split into several operations, e.g. when part of it is bold, the adjacent operations
+// with the same attachment are counted once.
+pub fn attachment_references(delta: &Delta) -> HashMap<String, i64> {
+    let mut references = HashMap::new();
+    let mut prev_id: Option<String> = None;
+    for op in delta.ops.iter().filter(|op| op.is_insert()) {
+        let id = op
+            .get_attributes()
+            .get(&AttributeKey::Attachment)
+            .and_then(|value| value.0.clone());
+        if let Some(id) = &id {
+            if prev_id.as_ref() != Some(id) {
+                *references.entry(id.clone()).or_insert(0) += 1;
+            }
+        }
+        prev_id = id;
+    }
+    references
+}
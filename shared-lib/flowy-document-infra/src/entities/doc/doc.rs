@@ -1,3 +1,4 @@
+use crate::entities::doc::DocReadPosition;
 use flowy_derive::ProtoBuf;
 use lib_ot::{core::Delta, errors::OTError};
 
@@ -51,6 +52,9 @@ pub struct UpdateDocParams {
 
     #[pb(index = 3)]
     pub rev_id: i64,
+
+    #[pb(index = 4)]
+    pub device_id: String,
 }
 
 #[derive(ProtoBuf, Default, Debug, Clone)]
@@ -60,6 +64,23 @@ pub struct DocDelta {
 
     #[pb(index = 2)]
     pub data: String, // Delta
+
+    // Only set when the document is opened
+    #[pb(index = 3, one_of)]
+    pub read_position: Option<DocReadPosition>,
+}
+
+// The rapid changes, e.g. the keystrokes of a word, are undone together
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocUndoState {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub can_undo: bool,
+
+    #[pb(index = 3)]
+    pub can_redo: bool,
 }
 
 #[derive(ProtoBuf, Default, Debug, Clone)]
@@ -91,3 +112,33 @@ impl std::convert::From<&String> for DocIdentifier {
         }
     }
 }
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocIdentifiers {
+    #[pb(index = 1)]
+    pub doc_ids: Vec<String>,
+}
+
+impl std::convert::From<Vec<String>> for DocIdentifiers {
+    fn from(doc_ids: Vec<String>) -> Self { DocIdentifiers { doc_ids } }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocPrefetchProgress {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub total: i64,
+
+    #[pb(index = 3)]
+    pub finished: i64,
+
+    // The size in bytes of the documents that were stored locally
+    #[pb(index = 4)]
+    pub size: i64,
+
+    // The documents that the server couldn't read, e.g. they were deleted. They are counted as finished.
+    #[pb(index = 5)]
+    pub failed: i64,
+}
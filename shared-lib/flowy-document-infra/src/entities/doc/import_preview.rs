@@ -0,0 +1,76 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum DocBlockType {
+    Paragraph = 0,
+    Heading   = 1,
+    Bullet    = 2,
+    Ordered   = 3,
+    Checkbox  = 4,
+    Quote     = 5,
+    Code      = 6,
+}
+
+impl std::default::Default for DocBlockType {
+    fn default() -> Self { DocBlockType::Paragraph }
+}
+
+impl std::convert::From<i32> for DocBlockType {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => DocBlockType::Paragraph,
+            1 => DocBlockType::Heading,
+            2 => DocBlockType::Bullet,
+            3 => DocBlockType::Ordered,
+            4 => DocBlockType::Checkbox,
+            5 => DocBlockType::Quote,
+            6 => DocBlockType::Code,
+            _ => {
+                log::error!("Invalid doc block type: {}", val);
+                DocBlockType::Paragraph
+            },
+        }
+    }
+}
+
+// A line of the doc that the import would create. The text is the plain text of the line without the
+// markdown syntax, the level is the level of the heading and zero for the other blocks. The line is the
+// number of the line in the imported data, starting from one.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct DocImportBlock {
+    #[pb(index = 1)]
+    pub block_type: DocBlockType,
+
+    #[pb(index = 2)]
+    pub level: i32,
+
+    #[pb(index = 3)]
+    pub text: String,
+
+    #[pb(index = 4)]
+    pub line: i64,
+}
+
+// The syntax that the editor can't show, it's imported as plain text
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct DocImportWarning {
+    #[pb(index = 1)]
+    pub line: i64,
+
+    #[pb(index = 2)]
+    pub message: String,
+}
+
+// What the import would do to the doc without changing it, so the UI can show a preview before the
+// data is imported.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct DocImportPreview {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub blocks: Vec<DocImportBlock>,
+
+    #[pb(index = 3)]
+    pub warnings: Vec<DocImportWarning>,
+}
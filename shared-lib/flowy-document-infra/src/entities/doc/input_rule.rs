@@ -0,0 +1,27 @@
+use flowy_derive::ProtoBuf;
+
+// The rules that rewrite the text right after it's typed, each of them can be turned off by the user.
+#[derive(ProtoBuf, Debug, Clone, PartialEq)]
+pub struct InputRuleSetting {
+    // "--" to "—"
+    #[pb(index = 1)]
+    pub em_dash: bool,
+
+    // "# ", "## " or "### " at the beginning of the line to the heading
+    #[pb(index = 2)]
+    pub heading: bool,
+
+    // "[] " or "[x] " at the beginning of the line to the checkbox
+    #[pb(index = 3)]
+    pub checkbox: bool,
+}
+
+impl std::default::Default for InputRuleSetting {
+    fn default() -> Self {
+        Self {
+            em_dash: true,
+            heading: true,
+            checkbox: true,
+        }
+    }
+}
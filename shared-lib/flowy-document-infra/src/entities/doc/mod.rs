@@ -1,6 +1,24 @@
+mod attachment;
 mod doc;
+mod import_preview;
+mod input_rule;
 pub mod parser;
+mod presence;
+mod read_position;
 mod revision;
+mod snapshot;
+mod task;
+mod text_match;
+mod text_run;
 
+pub use attachment::*;
 pub use doc::*;
+pub use import_preview::*;
+pub use input_rule::*;
+pub use presence::*;
+pub use read_position::*;
 pub use revision::*;
+pub use snapshot::*;
+pub use task::*;
+pub use text_match::*;
+pub use text_run::*;
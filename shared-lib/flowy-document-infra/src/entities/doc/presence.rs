@@ -0,0 +1,79 @@
+use flowy_derive::ProtoBuf;
+use std::time::Duration;
+
+// The client sends its presence again after the interval even if the selection doesn't change. The
+// presence of the user is dropped if the server doesn't receive anything from it within the timeout.
+pub const PRESENCE_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+pub const PRESENCE_TIMEOUT: Duration = Duration::from_secs(30);
+
+// The client sends the typing signal at most once within the throttle interval while the user keeps
+// typing. The other clients drop the signal that isn't refreshed within the timeout.
+pub const TYPING_THROTTLE_INTERVAL: Duration = Duration::from_secs(2);
+pub const TYPING_TIMEOUT: Duration = Duration::from_secs(5);
+
+// The selection is in utf16 code units, the same as the index of the delta. The cursor is the selection
+// whose start equals to its end.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocPresence {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    // The server overwrites it with the user of the connection
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub selection_start: i64,
+
+    #[pb(index = 4)]
+    pub selection_end: i64,
+
+    // True if the user has closed the doc or has been offline for the PRESENCE_TIMEOUT
+    #[pb(index = 5)]
+    pub is_closed: bool,
+}
+
+// The other users who have the doc open, sent with the DocPresenceChanged notification
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocPresence {
+    #[pb(index = 1)]
+    pub items: Vec<DocPresence>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocSelection {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub start: i64,
+
+    #[pb(index = 3)]
+    pub end: i64,
+}
+
+// The "user is typing" signal. It's much smaller than the DocPresence and it isn't kept by the server, so
+// it can be used on the connections where the cursor presence costs too much.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocTyping {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    // The server overwrites it with the user of the connection
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    // False if the user stops typing before the timeout, e.g. the doc is closed
+    #[pb(index = 3)]
+    pub is_typing: bool,
+}
+
+// The other users who are typing in the doc, sent with the DocTypingChanged notification
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocTypingUsers {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub user_ids: Vec<String>,
+}
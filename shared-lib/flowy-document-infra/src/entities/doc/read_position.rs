@@ -0,0 +1,18 @@
+use flowy_derive::ProtoBuf;
+
+// Where the user stopped reading the document, so it's opened at the same place on the other devices.
+// The offset is in utf16 code units, the same as the index of the delta, the scroll offset in pixels
+// isn't kept because it differs between the screens.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocReadPosition {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub offset: i64,
+
+    // It's overwritten with the current time when the position is saved, the later position wins when
+    // the positions of two devices are merged.
+    #[pb(index = 3)]
+    pub modified_time: i64,
+}
@@ -1,6 +1,9 @@
 use crate::{entities::doc::Doc, util::md5};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use lib_ot::core::Delta;
+use lib_ot::{
+    core::{Delta, OperationTransformable},
+    errors::OTError,
+};
 use std::{fmt::Formatter, ops::RangeInclusive};
 
 #[derive(Debug, ProtoBuf_Enum, Clone, Eq, PartialEq)]
@@ -76,6 +79,10 @@ pub struct Revision {
 
     #[pb(index = 6)]
     pub ty: RevType,
+
+    // The installation that made the revision, it's empty for the revisions made by the server
+    #[pb(index = 7)]
+    pub device_id: String,
 }
 
 impl Revision {
@@ -123,6 +130,7 @@ impl Revision {
             md5,
             doc_id,
             ty,
+            device_id: "".to_owned(),
         }
     }
 }
@@ -139,6 +147,16 @@ pub fn revision_from_doc(doc: Doc, ty: RevType) -> Revision {
     revision
 }
 
+// Composes the deltas of the revisions onto the base in the order of the revisions, e.g. the tail revisions
+// onto the checkpoint of the document.
+pub fn compose_revisions(base: Delta, revisions: &[Revision]) -> Result<Delta, OTError> {
+    let mut delta = base;
+    for revision in revisions {
+        delta = delta.compose(&Delta::from_bytes(&revision.delta_data)?)?;
+    }
+    Ok(delta)
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct RevisionRange {
     #[pb(index = 1)]
@@ -0,0 +1,50 @@
+use flowy_derive::ProtoBuf;
+use lib_ot::core::Delta;
+
+// A copy of the local document that is taken automatically before the operations that rewrite the
+// whole document, e.g. reset. The data of the snapshot is not included, it might be large.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocSnapshot {
+    #[pb(index = 1)]
+    pub id: i64,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub rev_id: i64,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+
+    // The installation that made the revision of the snapshot
+    #[pb(index = 6)]
+    pub device_id: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocSnapshot {
+    #[pb(index = 1)]
+    pub items: Vec<DocSnapshot>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocSnapshotIdentifier {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub snapshot_id: i64,
+}
+
+// Returns the delta that turns the current document into the snapshot. It's applied as a normal local
+// change, so the revert is synced with the server and can be undone.
+pub fn revert_delta(current: &Delta, snapshot: &Delta) -> Delta {
+    let mut delta = Delta::new();
+    delta.delete(current.target_len);
+    snapshot.ops.iter().for_each(|op| delta.add(op.clone()));
+    delta
+}
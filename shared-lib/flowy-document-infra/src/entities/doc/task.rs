@@ -0,0 +1,225 @@
+use flowy_derive::ProtoBuf;
+use lib_ot::core::{Attribute, AttributeKey, Delta, DeltaIter, FlowyStr, Interval, Operation, NEW_LINE};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+// An unchecked item of the todo list in the document.
+#[derive(ProtoBuf, Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct DocTask {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub text: String,
+
+    // The offset of the line in utf16 code units, used to move the cursor to the task
+    #[pb(index = 3)]
+    pub index: i64,
+
+    #[pb(index = 4)]
+    pub line: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocTask {
+    #[pb(index = 1)]
+    pub items: Vec<DocTask>,
+}
+
+// The list attribute is a block attribute, so it's carried by the newline that ends the line.
+pub fn unchecked_tasks_from_delta(doc_id: &str, delta: &Delta) -> Vec<DocTask> { scan_lines(doc_id, delta, 0, 0).1 }
+
+// The unchecked tasks and the lengths of the lines of the document in utf16 code units. The last line is
+// the text after the last newline, it's empty if the document ends with the newline. The index is updated
+// with the deltas that are applied to the document, only the lines that a delta changed are scanned again.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct DocTaskIndex {
+    pub line_lens: Vec<usize>,
+    pub tasks: Vec<DocTask>,
+}
+
+impl DocTaskIndex {
+    pub fn from_delta(doc_id: &str, delta: &Delta) -> Self {
+        let (line_lens, tasks) = scan_lines(doc_id, delta, 0, 0);
+        Self { line_lens, tasks }
+    }
+
+    // The document is the one that the delta was applied to. The tasks of the unchanged lines are moved by the
+    // lines that were inserted or deleted before them. It's indexed again if the delta wasn't made on the
+    // indexed document.
+    pub fn apply_delta(&mut self, doc_id: &str, delta: &Delta, document: &Delta) {
+        if delta.ops.is_empty() {
+            return;
+        }
+        if delta.base_len > self.line_lens.iter().sum::<usize>() {
+            *self = Self::from_delta(doc_id, document);
+            return;
+        }
+
+        let (changed_lines, len_diffs) = self.changed_lines(delta);
+        match self.rescan_changed_lines(doc_id, &changed_lines, &len_diffs, document) {
+            Some(index) => *self = index,
+            None => *self = Self::from_delta(doc_id, document),
+        }
+    }
+
+    // Returns the lines that the delta changed and the length that each line gained or lost. A deleted
+    // newline merges the line with the next one, so the next one is changed too.
+    fn changed_lines(&self, delta: &Delta) -> (Vec<bool>, Vec<i64>) {
+        let mut changed_lines = vec![false; self.line_lens.len()];
+        let mut len_diffs = vec![0; self.line_lens.len()];
+        let mut cursor = LineCursor::default();
+        for op in &delta.ops {
+            match op {
+                Operation::Retain(retain) if retain.is_plain() => cursor = self.move_cursor(cursor, retain.n),
+                Operation::Retain(retain) => {
+                    let last = self.move_cursor(cursor, retain.n.saturating_sub(1)).line;
+                    mark_lines(&mut changed_lines, cursor.line, last);
+                    cursor = self.move_cursor(cursor, retain.n);
+                },
+                Operation::Insert(insert) => {
+                    let line = cursor.line.min(changed_lines.len() - 1);
+                    changed_lines[line] = true;
+                    len_diffs[line] += insert.count_of_code_units() as i64;
+                },
+                Operation::Delete(n) => {
+                    let last = self.move_cursor(cursor, *n).line;
+                    mark_lines(&mut changed_lines, cursor.line, last);
+                    len_diffs[cursor.line.min(len_diffs.len() - 1)] -= *n as i64;
+                    cursor = self.move_cursor(cursor, *n);
+                },
+            }
+        }
+        (changed_lines, len_diffs)
+    }
+
+    fn move_cursor(&self, mut cursor: LineCursor, mut n: usize) -> LineCursor {
+        while n > 0 && cursor.line < self.line_lens.len() {
+            let remaining = self.line_lens[cursor.line] - cursor.offset;
+            if n < remaining {
+                cursor.offset += n;
+                break;
+            }
+            n -= remaining;
+            cursor.line += 1;
+            cursor.offset = 0;
+        }
+        cursor
+    }
+
+    // Returns None if the document doesn't match the changed lines
+    fn rescan_changed_lines(
+        &self,
+        doc_id: &str,
+        changed_lines: &[bool],
+        len_diffs: &[i64],
+        document: &Delta,
+    ) -> Option<Self> {
+        let mut index = DocTaskIndex::default();
+        let mut tasks = self.tasks.iter().peekable();
+        let mut offset = 0;
+        let mut line = 0;
+        while line < self.line_lens.len() {
+            while tasks.peek().map(|task| (task.line as usize) < line).unwrap_or(false) {
+                tasks.next();
+            }
+
+            if !changed_lines[line] {
+                if let Some(task) = tasks.peek().filter(|task| task.line as usize == line) {
+                    index.tasks.push(DocTask {
+                        index: offset as i64,
+                        line: index.line_lens.len() as i64,
+                        ..(*task).clone()
+                    });
+                }
+                index.line_lens.push(self.line_lens[line]);
+                offset += self.line_lens[line];
+                line += 1;
+                continue;
+            }
+
+            let mut len = 0;
+            while line < self.line_lens.len() && changed_lines[line] {
+                len += self.line_lens[line] as i64 + len_diffs[line];
+                line += 1;
+            }
+            let end = offset + usize::try_from(len).ok()?;
+            let mut changed = Delta::new();
+            DeltaIter::from_interval(document, Interval::new(offset, end)).for_each(|op| changed.add(op));
+            if changed.target_len != end - offset {
+                return None;
+            }
+
+            let (mut line_lens, changed_tasks) = scan_lines(doc_id, &changed, offset, index.line_lens.len());
+            // The changed lines end with the newline, unless they are the last lines of the document
+            if line < self.line_lens.len() && line_lens.pop() != Some(0) {
+                return None;
+            }
+            index.line_lens.extend(line_lens);
+            index.tasks.extend(changed_tasks);
+            offset = end;
+        }
+
+        match offset == document.target_len {
+            true => Some(index),
+            false => None,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct LineCursor {
+    line: usize,
+    offset: usize,
+}
+
+fn mark_lines(changed_lines: &mut [bool], first: usize, last: usize) {
+    let last = last.min(changed_lines.len() - 1);
+    for changed in &mut changed_lines[first.min(last)..=last] {
+        *changed = true;
+    }
+}
+
+// Returns the lengths of the lines and the tasks. The offset and the line are the position of the delta in
+// the document.
+fn scan_lines(doc_id: &str, delta: &Delta, offset: usize, line: usize) -> (Vec<usize>, Vec<DocTask>) {
+    let unchecked = Attribute::UnChecked(true).value;
+    let mut line_lens = vec![];
+    let mut tasks = vec![];
+    let mut text = String::new();
+    let mut line_index = offset;
+    let mut line_len = 0;
+
+    for op in &delta.ops {
+        if !op.is_insert() {
+            continue;
+        }
+
+        let attributes = op.get_attributes();
+        let is_unchecked = attributes.get(&AttributeKey::List) == Some(&unchecked);
+        for s in op.get_data().split_inclusive(NEW_LINE) {
+            line_len += FlowyStr::from(s).count_utf16_code_units();
+            if !s.ends_with(NEW_LINE) {
+                text.push_str(s);
+                continue;
+            }
+
+            text.push_str(&s[..s.len() - NEW_LINE.len()]);
+            let trimmed = text.trim();
+            if is_unchecked && !trimmed.is_empty() {
+                tasks.push(DocTask {
+                    doc_id: doc_id.to_owned(),
+                    text: trimmed.to_owned(),
+                    index: line_index as i64,
+                    line: (line + line_lens.len()) as i64,
+                });
+            }
+            text.clear();
+            line_lens.push(line_len);
+            line_index += line_len;
+            line_len = 0;
+        }
+    }
+    line_lens.push(line_len);
+    (line_lens, tasks)
+}
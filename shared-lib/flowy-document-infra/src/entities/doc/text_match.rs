@@ -0,0 +1,114 @@
+use crate::entities::doc::plain_text_from_delta;
+use lib_ot::core::{Delta, NEW_LINE};
+use std::cmp::min;
+
+// The characters of the line that are kept before and after the matched word
+const CONTEXT_CHARS: usize = 32;
+
+// A word of the document that matches the search query. The start and the end are in utf16 code units
+// of the document, the same as the index of the delta. The context is the text of the line around the
+// word, the word is at the highlight_start..highlight_end of it, which are in utf16 code units too.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocTextMatch {
+    pub start: usize,
+    pub end: usize,
+    pub context: String,
+    pub highlight_start: usize,
+    pub highlight_end: usize,
+}
+
+// Same as the full-text search, the words are matched case-insensitively and the last word of the query
+// matches as a prefix. The matches are in the order they appear in the document.
+pub fn find_text_matches(delta: &Delta, query: &str, limit: usize) -> Vec<DocTextMatch> {
+    let query_words = words(&query.chars().collect::<Vec<char>>())
+        .into_iter()
+        .map(|word| word.text)
+        .collect::<Vec<String>>();
+    if query_words.is_empty() {
+        return vec![];
+    }
+
+    let is_match = |word: &str| {
+        query_words.iter().enumerate().any(|(index, query_word)| {
+            match index == query_words.len() - 1 {
+                true => word.starts_with(query_word.as_str()),
+                false => word == query_word,
+            }
+        })
+    };
+
+    let text = plain_text_from_delta(delta);
+    let mut matches = vec![];
+    let mut line_offset = 0;
+    for line in text.split(NEW_LINE) {
+        let chars = line.chars().collect::<Vec<char>>();
+        for word in words(&chars).into_iter().filter(|word| is_match(&word.text)) {
+            if matches.len() >= limit {
+                return matches;
+            }
+
+            let context_start = context_start(&chars, word.start);
+            let context_end = context_end(&chars, word.end);
+            let highlight_start = utf16_len(&chars[context_start..word.start]);
+            let highlight_end = highlight_start + utf16_len(&chars[word.start..word.end]);
+            let start = line_offset + utf16_len(&chars[..word.start]);
+            matches.push(DocTextMatch {
+                start,
+                end: start + highlight_end - highlight_start,
+                context: chars[context_start..context_end].iter().collect(),
+                highlight_start,
+                highlight_end,
+            });
+        }
+        line_offset += utf16_len(&chars) + NEW_LINE.len();
+    }
+    matches
+}
+
+struct Word {
+    // The indexes of the chars
+    start: usize,
+    end: usize,
+    // In lowercase
+    text: String,
+}
+
+fn words(chars: &[char]) -> Vec<Word> {
+    let mut words = vec![];
+    let mut start = None;
+    for index in 0..=chars.len() {
+        let is_word_char = index < chars.len() && chars[index].is_alphanumeric();
+        match (start, is_word_char) {
+            (None, true) => start = Some(index),
+            (Some(word_start), false) => {
+                words.push(Word {
+                    start: word_start,
+                    end: index,
+                    text: chars[word_start..index].iter().collect::<String>().to_lowercase(),
+                });
+                start = None;
+            },
+            _ => {},
+        }
+    }
+    words
+}
+
+// The context doesn't start or end in the middle of a word unless the word is longer than CONTEXT_CHARS
+fn context_start(chars: &[char], word_start: usize) -> usize {
+    let mut start = word_start.saturating_sub(CONTEXT_CHARS);
+    while start > 0 && start < word_start && !chars[start - 1].is_whitespace() {
+        start += 1;
+    }
+    start
+}
+
+fn context_end(chars: &[char], word_end: usize) -> usize {
+    let mut end = min(word_end + CONTEXT_CHARS, chars.len());
+    while end < chars.len() && end > word_end && !chars[end].is_whitespace() {
+        end -= 1;
+    }
+    end
+}
+
+fn utf16_len(chars: &[char]) -> usize { chars.iter().map(|c| c.len_utf16()).sum() }
@@ -0,0 +1,149 @@
+use flowy_derive::ProtoBuf;
+use lib_ot::core::{count_utf16_code_units, AttributeKey, Delta, Interval, Operation, NEW_LINE};
+use std::cmp::{max, min};
+
+// A piece of text that can be checked by the spell checker. The index is the offset in utf16 code
+// units of the document at the rev_id of the RepeatedDocTextRun.
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq)]
+pub struct DocTextRun {
+    #[pb(index = 1)]
+    pub index: i64,
+
+    #[pb(index = 2)]
+    pub text: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocTextRun {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub rev_id: i64,
+
+    #[pb(index = 3)]
+    pub items: Vec<DocTextRun>,
+}
+
+// Replaces the old_text at the index with the new_text. The index is relative to the document at the
+// rev_id, it's transformed by the changes that were made after that.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocTextReplacement {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub rev_id: i64,
+
+    #[pb(index = 3)]
+    pub index: i64,
+
+    #[pb(index = 4)]
+    pub old_text: String,
+
+    #[pb(index = 5)]
+    pub new_text: String,
+}
+
+impl DocTextReplacement {
+    pub fn interval(&self) -> Interval {
+        let start = self.index as usize;
+        Interval::new(start, start + count_utf16_code_units(&self.old_text))
+    }
+}
+
+// Code blocks, inline code and links are not natural language, so they are left out. The formatted
+// pieces of a line are merged into one run as long as they are next to each other.
+pub fn text_runs_from_delta(delta: &Delta) -> Vec<DocTextRun> {
+    let mut runs = vec![];
+    let mut line_runs = vec![];
+    let mut run: Option<DocTextRun> = None;
+    let mut offset = 0;
+
+    for op in &delta.ops {
+        if !op.is_insert() {
+            continue;
+        }
+
+        let attributes = op.get_attributes();
+        let is_skipped =
+            attributes.contains_key(&AttributeKey::InlineCode) || attributes.contains_key(&AttributeKey::Link);
+        for s in op.get_data().split_inclusive(NEW_LINE) {
+            let is_line_end = s.ends_with(NEW_LINE);
+            let text = match is_line_end {
+                true => &s[..s.len() - NEW_LINE.len()],
+                false => s,
+            };
+
+            if is_skipped {
+                line_runs.extend(run.take());
+            } else if !text.is_empty() {
+                run.get_or_insert_with(|| DocTextRun {
+                    index: offset as i64,
+                    text: "".to_owned(),
+                })
+                .text
+                .push_str(text);
+            }
+            offset += count_utf16_code_units(s);
+
+            if is_line_end {
+                line_runs.extend(run.take());
+                match attributes.contains_key(&AttributeKey::CodeBlock) {
+                    true => line_runs.clear(),
+                    false => runs.append(&mut line_runs),
+                }
+            }
+        }
+    }
+
+    runs.append(&mut line_runs);
+    runs.extend(run);
+    runs
+}
+
+// The text of the document without the formats, it's indexed by the full-text search
+pub fn plain_text_from_delta(delta: &Delta) -> String {
+    delta
+        .ops
+        .iter()
+        .filter(|op| op.is_insert())
+        .map(|op| op.get_data())
+        .collect::<String>()
+}
+
+// Maps the interval of the document before the delta was applied to the document after. The text that
+// was inserted inside or right next to the interval extends it.
+pub fn transform_interval(delta: &Delta, interval: Interval) -> Interval {
+    let (mut start, mut end) = interval.start_end();
+    let mut index = 0;
+
+    for op in &delta.ops {
+        if index > interval.end {
+            break;
+        }
+
+        match op {
+            Operation::Retain(retain) => index += retain.n,
+            Operation::Insert(insert) => {
+                let len = insert.count_of_code_units();
+                if index < interval.start {
+                    start += len;
+                }
+                end += len;
+            },
+            Operation::Delete(n) => {
+                let before = overlap(index, index + n, 0, interval.start);
+                let inside = overlap(index, index + n, interval.start, interval.end);
+                start -= before;
+                end -= before + inside;
+                index += n;
+            },
+        }
+    }
+    Interval::new(start, end)
+}
+
+fn overlap(start: usize, end: usize, other_start: usize, other_end: usize) -> usize {
+    min(end, other_end).saturating_sub(max(start, other_start))
+}
@@ -1,5 +1,5 @@
 use crate::{
-    entities::doc::{NewDocUser, Revision},
+    entities::doc::{Doc, DocIdentifiers, DocPresence, DocTyping, NewDocUser, Revision},
     errors::DocumentError,
 };
 use bytes::Bytes;
@@ -13,6 +13,11 @@ pub enum WsDataType {
     PullRev    = 2, // data should be Revision
     Conflict   = 3,
     NewDocUser = 4,
+    PullDocs   = 5, // data should be DocIdentifiers
+    PushDoc    = 6, // data should be Doc
+    Presence   = 7, // data should be DocPresence
+    Typing     = 8, // data should be DocTyping
+    PushDocFailed = 9, // the doc of the PullDocs can't be read, data is empty
 }
 
 impl WsDataType {
@@ -64,3 +69,50 @@ impl std::convert::From<NewDocUser> for WsDocumentData {
         }
     }
 }
+
+impl std::convert::From<DocIdentifiers> for WsDocumentData {
+    fn from(identifiers: DocIdentifiers) -> Self {
+        let bytes: Bytes = identifiers.try_into().unwrap();
+        Self {
+            doc_id: "".to_owned(),
+            ty: WsDataType::PullDocs,
+            data: bytes.to_vec(),
+        }
+    }
+}
+
+impl std::convert::From<Doc> for WsDocumentData {
+    fn from(doc: Doc) -> Self {
+        let doc_id = doc.id.clone();
+        let bytes: Bytes = doc.try_into().unwrap();
+        Self {
+            doc_id,
+            ty: WsDataType::PushDoc,
+            data: bytes.to_vec(),
+        }
+    }
+}
+
+impl std::convert::From<DocPresence> for WsDocumentData {
+    fn from(presence: DocPresence) -> Self {
+        let doc_id = presence.doc_id.clone();
+        let bytes: Bytes = presence.try_into().unwrap();
+        Self {
+            doc_id,
+            ty: WsDataType::Presence,
+            data: bytes.to_vec(),
+        }
+    }
+}
+
+impl std::convert::From<DocTyping> for WsDocumentData {
+    fn from(typing: DocTyping) -> Self {
+        let doc_id = typing.doc_id.clone();
+        let bytes: Bytes = typing.try_into().unwrap();
+        Self {
+            doc_id,
+            ty: WsDataType::Typing,
+            data: bytes.to_vec(),
+        }
+    }
+}
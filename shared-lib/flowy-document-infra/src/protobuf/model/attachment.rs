@@ -0,0 +1,1846 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `attachment.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Attachment {
+    // message fields
+    pub id: ::std::string::String,
+    pub doc_id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub size: i64,
+    pub chunk_size: i64,
+    pub uploaded_chunks: i64,
+    pub create_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Attachment {
+    fn default() -> &'a Attachment {
+        <Attachment as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Attachment {
+    pub fn new() -> Attachment {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string doc_id = 2;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // int64 size = 4;
+
+
+    pub fn get_size(&self) -> i64 {
+        self.size
+    }
+    pub fn clear_size(&mut self) {
+        self.size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: i64) {
+        self.size = v;
+    }
+
+    // int64 chunk_size = 5;
+
+
+    pub fn get_chunk_size(&self) -> i64 {
+        self.chunk_size
+    }
+    pub fn clear_chunk_size(&mut self) {
+        self.chunk_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chunk_size(&mut self, v: i64) {
+        self.chunk_size = v;
+    }
+
+    // int64 uploaded_chunks = 6;
+
+
+    pub fn get_uploaded_chunks(&self) -> i64 {
+        self.uploaded_chunks
+    }
+    pub fn clear_uploaded_chunks(&mut self) {
+        self.uploaded_chunks = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uploaded_chunks(&mut self, v: i64) {
+        self.uploaded_chunks = v;
+    }
+
+    // int64 create_time = 7;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+}
+
+impl ::protobuf::Message for Attachment {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.size = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.chunk_size = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.uploaded_chunks = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.doc_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.name);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.chunk_size != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.chunk_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.uploaded_chunks != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.uploaded_chunks, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.doc_id.is_empty() {
+            os.write_string(2, &self.doc_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(3, &self.name)?;
+        }
+        if self.size != 0 {
+            os.write_int64(4, self.size)?;
+        }
+        if self.chunk_size != 0 {
+            os.write_int64(5, self.chunk_size)?;
+        }
+        if self.uploaded_chunks != 0 {
+            os.write_int64(6, self.uploaded_chunks)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(7, self.create_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Attachment {
+        Attachment::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &Attachment| { &m.id },
+                |m: &mut Attachment| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &Attachment| { &m.doc_id },
+                |m: &mut Attachment| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Attachment| { &m.name },
+                |m: &mut Attachment| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "size",
+                |m: &Attachment| { &m.size },
+                |m: &mut Attachment| { &mut m.size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "chunk_size",
+                |m: &Attachment| { &m.chunk_size },
+                |m: &mut Attachment| { &mut m.chunk_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "uploaded_chunks",
+                |m: &Attachment| { &m.uploaded_chunks },
+                |m: &mut Attachment| { &mut m.uploaded_chunks },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &Attachment| { &m.create_time },
+                |m: &mut Attachment| { &mut m.create_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Attachment>(
+                "Attachment",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Attachment {
+        static instance: ::protobuf::rt::LazyV2<Attachment> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Attachment::new)
+    }
+}
+
+impl ::protobuf::Clear for Attachment {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.doc_id.clear();
+        self.name.clear();
+        self.size = 0;
+        self.chunk_size = 0;
+        self.uploaded_chunks = 0;
+        self.create_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Attachment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Attachment {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateAttachmentParams {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub size: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateAttachmentParams {
+    fn default() -> &'a CreateAttachmentParams {
+        <CreateAttachmentParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateAttachmentParams {
+    pub fn new() -> CreateAttachmentParams {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // int64 size = 3;
+
+
+    pub fn get_size(&self) -> i64 {
+        self.size
+    }
+    pub fn clear_size(&mut self) {
+        self.size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: i64) {
+        self.size = v;
+    }
+}
+
+impl ::protobuf::Message for CreateAttachmentParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.size = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.size != 0 {
+            os.write_int64(3, self.size)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateAttachmentParams {
+        CreateAttachmentParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &CreateAttachmentParams| { &m.doc_id },
+                |m: &mut CreateAttachmentParams| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &CreateAttachmentParams| { &m.name },
+                |m: &mut CreateAttachmentParams| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "size",
+                |m: &CreateAttachmentParams| { &m.size },
+                |m: &mut CreateAttachmentParams| { &mut m.size },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateAttachmentParams>(
+                "CreateAttachmentParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateAttachmentParams {
+        static instance: ::protobuf::rt::LazyV2<CreateAttachmentParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateAttachmentParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateAttachmentParams {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.name.clear();
+        self.size = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateAttachmentParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateAttachmentParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttachmentIdentifier {
+    // message fields
+    pub attachment_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttachmentIdentifier {
+    fn default() -> &'a AttachmentIdentifier {
+        <AttachmentIdentifier as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttachmentIdentifier {
+    pub fn new() -> AttachmentIdentifier {
+        ::std::default::Default::default()
+    }
+
+    // string attachment_id = 1;
+
+
+    pub fn get_attachment_id(&self) -> &str {
+        &self.attachment_id
+    }
+    pub fn clear_attachment_id(&mut self) {
+        self.attachment_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachment_id(&mut self, v: ::std::string::String) {
+        self.attachment_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_attachment_id(&mut self) -> &mut ::std::string::String {
+        &mut self.attachment_id
+    }
+
+    // Take field
+    pub fn take_attachment_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.attachment_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AttachmentIdentifier {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.attachment_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.attachment_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.attachment_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.attachment_id.is_empty() {
+            os.write_string(1, &self.attachment_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttachmentIdentifier {
+        AttachmentIdentifier::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "attachment_id",
+                |m: &AttachmentIdentifier| { &m.attachment_id },
+                |m: &mut AttachmentIdentifier| { &mut m.attachment_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttachmentIdentifier>(
+                "AttachmentIdentifier",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttachmentIdentifier {
+        static instance: ::protobuf::rt::LazyV2<AttachmentIdentifier> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttachmentIdentifier::new)
+    }
+}
+
+impl ::protobuf::Clear for AttachmentIdentifier {
+    fn clear(&mut self) {
+        self.attachment_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttachmentIdentifier {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttachmentIdentifier {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttachmentChunk {
+    // message fields
+    pub attachment_id: ::std::string::String,
+    pub index: i64,
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttachmentChunk {
+    fn default() -> &'a AttachmentChunk {
+        <AttachmentChunk as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttachmentChunk {
+    pub fn new() -> AttachmentChunk {
+        ::std::default::Default::default()
+    }
+
+    // string attachment_id = 1;
+
+
+    pub fn get_attachment_id(&self) -> &str {
+        &self.attachment_id
+    }
+    pub fn clear_attachment_id(&mut self) {
+        self.attachment_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachment_id(&mut self, v: ::std::string::String) {
+        self.attachment_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_attachment_id(&mut self) -> &mut ::std::string::String {
+        &mut self.attachment_id
+    }
+
+    // Take field
+    pub fn take_attachment_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.attachment_id, ::std::string::String::new())
+    }
+
+    // int64 index = 2;
+
+
+    pub fn get_index(&self) -> i64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i64) {
+        self.index = v;
+    }
+
+    // bytes data = 3;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for AttachmentChunk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.attachment_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.index = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.attachment_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.attachment_id);
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.attachment_id.is_empty() {
+            os.write_string(1, &self.attachment_id)?;
+        }
+        if self.index != 0 {
+            os.write_int64(2, self.index)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttachmentChunk {
+        AttachmentChunk::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "attachment_id",
+                |m: &AttachmentChunk| { &m.attachment_id },
+                |m: &mut AttachmentChunk| { &mut m.attachment_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "index",
+                |m: &AttachmentChunk| { &m.index },
+                |m: &mut AttachmentChunk| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "data",
+                |m: &AttachmentChunk| { &m.data },
+                |m: &mut AttachmentChunk| { &mut m.data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttachmentChunk>(
+                "AttachmentChunk",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttachmentChunk {
+        static instance: ::protobuf::rt::LazyV2<AttachmentChunk> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttachmentChunk::new)
+    }
+}
+
+impl ::protobuf::Clear for AttachmentChunk {
+    fn clear(&mut self) {
+        self.attachment_id.clear();
+        self.index = 0;
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttachmentChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttachmentChunk {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttachmentChunkIdentifier {
+    // message fields
+    pub attachment_id: ::std::string::String,
+    pub index: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttachmentChunkIdentifier {
+    fn default() -> &'a AttachmentChunkIdentifier {
+        <AttachmentChunkIdentifier as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttachmentChunkIdentifier {
+    pub fn new() -> AttachmentChunkIdentifier {
+        ::std::default::Default::default()
+    }
+
+    // string attachment_id = 1;
+
+
+    pub fn get_attachment_id(&self) -> &str {
+        &self.attachment_id
+    }
+    pub fn clear_attachment_id(&mut self) {
+        self.attachment_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachment_id(&mut self, v: ::std::string::String) {
+        self.attachment_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_attachment_id(&mut self) -> &mut ::std::string::String {
+        &mut self.attachment_id
+    }
+
+    // Take field
+    pub fn take_attachment_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.attachment_id, ::std::string::String::new())
+    }
+
+    // int64 index = 2;
+
+
+    pub fn get_index(&self) -> i64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i64) {
+        self.index = v;
+    }
+}
+
+impl ::protobuf::Message for AttachmentChunkIdentifier {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.attachment_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.attachment_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.attachment_id);
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.attachment_id.is_empty() {
+            os.write_string(1, &self.attachment_id)?;
+        }
+        if self.index != 0 {
+            os.write_int64(2, self.index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttachmentChunkIdentifier {
+        AttachmentChunkIdentifier::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "attachment_id",
+                |m: &AttachmentChunkIdentifier| { &m.attachment_id },
+                |m: &mut AttachmentChunkIdentifier| { &mut m.attachment_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "index",
+                |m: &AttachmentChunkIdentifier| { &m.index },
+                |m: &mut AttachmentChunkIdentifier| { &mut m.index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttachmentChunkIdentifier>(
+                "AttachmentChunkIdentifier",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttachmentChunkIdentifier {
+        static instance: ::protobuf::rt::LazyV2<AttachmentChunkIdentifier> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttachmentChunkIdentifier::new)
+    }
+}
+
+impl ::protobuf::Clear for AttachmentChunkIdentifier {
+    fn clear(&mut self) {
+        self.attachment_id.clear();
+        self.index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttachmentChunkIdentifier {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttachmentChunkIdentifier {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttachmentUploadProgress {
+    // message fields
+    pub attachment_id: ::std::string::String,
+    pub doc_id: ::std::string::String,
+    pub total: i64,
+    pub uploaded: i64,
+    pub is_completed: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttachmentUploadProgress {
+    fn default() -> &'a AttachmentUploadProgress {
+        <AttachmentUploadProgress as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttachmentUploadProgress {
+    pub fn new() -> AttachmentUploadProgress {
+        ::std::default::Default::default()
+    }
+
+    // string attachment_id = 1;
+
+
+    pub fn get_attachment_id(&self) -> &str {
+        &self.attachment_id
+    }
+    pub fn clear_attachment_id(&mut self) {
+        self.attachment_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachment_id(&mut self, v: ::std::string::String) {
+        self.attachment_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_attachment_id(&mut self) -> &mut ::std::string::String {
+        &mut self.attachment_id
+    }
+
+    // Take field
+    pub fn take_attachment_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.attachment_id, ::std::string::String::new())
+    }
+
+    // string doc_id = 2;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 total = 3;
+
+
+    pub fn get_total(&self) -> i64 {
+        self.total
+    }
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: i64) {
+        self.total = v;
+    }
+
+    // int64 uploaded = 4;
+
+
+    pub fn get_uploaded(&self) -> i64 {
+        self.uploaded
+    }
+    pub fn clear_uploaded(&mut self) {
+        self.uploaded = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uploaded(&mut self, v: i64) {
+        self.uploaded = v;
+    }
+
+    // bool is_completed = 5;
+
+
+    pub fn get_is_completed(&self) -> bool {
+        self.is_completed
+    }
+    pub fn clear_is_completed(&mut self) {
+        self.is_completed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_completed(&mut self, v: bool) {
+        self.is_completed = v;
+    }
+}
+
+impl ::protobuf::Message for AttachmentUploadProgress {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.attachment_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.uploaded = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_completed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.attachment_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.attachment_id);
+        }
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.doc_id);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.uploaded != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.uploaded, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_completed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.attachment_id.is_empty() {
+            os.write_string(1, &self.attachment_id)?;
+        }
+        if !self.doc_id.is_empty() {
+            os.write_string(2, &self.doc_id)?;
+        }
+        if self.total != 0 {
+            os.write_int64(3, self.total)?;
+        }
+        if self.uploaded != 0 {
+            os.write_int64(4, self.uploaded)?;
+        }
+        if self.is_completed != false {
+            os.write_bool(5, self.is_completed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttachmentUploadProgress {
+        AttachmentUploadProgress::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "attachment_id",
+                |m: &AttachmentUploadProgress| { &m.attachment_id },
+                |m: &mut AttachmentUploadProgress| { &mut m.attachment_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &AttachmentUploadProgress| { &m.doc_id },
+                |m: &mut AttachmentUploadProgress| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "total",
+                |m: &AttachmentUploadProgress| { &m.total },
+                |m: &mut AttachmentUploadProgress| { &mut m.total },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "uploaded",
+                |m: &AttachmentUploadProgress| { &m.uploaded },
+                |m: &mut AttachmentUploadProgress| { &mut m.uploaded },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_completed",
+                |m: &AttachmentUploadProgress| { &m.is_completed },
+                |m: &mut AttachmentUploadProgress| { &mut m.is_completed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttachmentUploadProgress>(
+                "AttachmentUploadProgress",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttachmentUploadProgress {
+        static instance: ::protobuf::rt::LazyV2<AttachmentUploadProgress> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttachmentUploadProgress::new)
+    }
+}
+
+impl ::protobuf::Clear for AttachmentUploadProgress {
+    fn clear(&mut self) {
+        self.attachment_id.clear();
+        self.doc_id.clear();
+        self.total = 0;
+        self.uploaded = 0;
+        self.is_completed = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttachmentUploadProgress {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttachmentUploadProgress {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttachmentFile {
+    // message fields
+    pub attachment_id: ::std::string::String,
+    pub path: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttachmentFile {
+    fn default() -> &'a AttachmentFile {
+        <AttachmentFile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttachmentFile {
+    pub fn new() -> AttachmentFile {
+        ::std::default::Default::default()
+    }
+
+    // string attachment_id = 1;
+
+
+    pub fn get_attachment_id(&self) -> &str {
+        &self.attachment_id
+    }
+    pub fn clear_attachment_id(&mut self) {
+        self.attachment_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachment_id(&mut self, v: ::std::string::String) {
+        self.attachment_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_attachment_id(&mut self) -> &mut ::std::string::String {
+        &mut self.attachment_id
+    }
+
+    // Take field
+    pub fn take_attachment_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.attachment_id, ::std::string::String::new())
+    }
+
+    // string path = 2;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AttachmentFile {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.attachment_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.attachment_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.attachment_id);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.attachment_id.is_empty() {
+            os.write_string(1, &self.attachment_id)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttachmentFile {
+        AttachmentFile::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "attachment_id",
+                |m: &AttachmentFile| { &m.attachment_id },
+                |m: &mut AttachmentFile| { &mut m.attachment_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &AttachmentFile| { &m.path },
+                |m: &mut AttachmentFile| { &mut m.path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttachmentFile>(
+                "AttachmentFile",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttachmentFile {
+        static instance: ::protobuf::rt::LazyV2<AttachmentFile> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttachmentFile::new)
+    }
+}
+
+impl ::protobuf::Clear for AttachmentFile {
+    fn clear(&mut self) {
+        self.attachment_id.clear();
+        self.path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttachmentFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttachmentFile {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10attachment.proto\"\xc4\x01\n\nAttachment\x12\x0e\n\x02id\x18\x01\
+    \x20\x01(\tR\x02id\x12\x15\n\x06doc_id\x18\x02\x20\x01(\tR\x05docId\x12\
+    \x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04size\x18\x04\x20\
+    \x01(\x03R\x04size\x12\x1d\n\nchunk_size\x18\x05\x20\x01(\x03R\tchunkSiz\
+    e\x12'\n\x0fuploaded_chunks\x18\x06\x20\x01(\x03R\x0euploadedChunks\x12\
+    \x1f\n\x0bcreate_time\x18\x07\x20\x01(\x03R\ncreateTime\"W\n\x16CreateAt\
+    tachmentParams\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x12\
+    \n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04size\x18\x03\x20\x01\
+    (\x03R\x04size\";\n\x14AttachmentIdentifier\x12#\n\rattachment_id\x18\
+    \x01\x20\x01(\tR\x0cattachmentId\"`\n\x0fAttachmentChunk\x12#\n\rattachm\
+    ent_id\x18\x01\x20\x01(\tR\x0cattachmentId\x12\x14\n\x05index\x18\x02\
+    \x20\x01(\x03R\x05index\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04data\
+    \"V\n\x19AttachmentChunkIdentifier\x12#\n\rattachment_id\x18\x01\x20\x01\
+    (\tR\x0cattachmentId\x12\x14\n\x05index\x18\x02\x20\x01(\x03R\x05index\"\
+    \xab\x01\n\x18AttachmentUploadProgress\x12#\n\rattachment_id\x18\x01\x20\
+    \x01(\tR\x0cattachmentId\x12\x15\n\x06doc_id\x18\x02\x20\x01(\tR\x05docI\
+    d\x12\x14\n\x05total\x18\x03\x20\x01(\x03R\x05total\x12\x1a\n\x08uploade\
+    d\x18\x04\x20\x01(\x03R\x08uploaded\x12!\n\x0cis_completed\x18\x05\x20\
+    \x01(\x08R\x0bisCompleted\"I\n\x0eAttachmentFile\x12#\n\rattachment_id\
+    \x18\x01\x20\x01(\tR\x0cattachmentId\x12\x12\n\x04path\x18\x02\x20\x01(\
+    \tR\x04pathJ\xab\x0b\n\x06\x12\x04\0\0&\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x12\n\n\n\x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\
+    \x08\x12\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x12\n\x0c\n\x05\x04\0\
+    \x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\
+    \r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\n\x0b\n\x04\x04\0\x02\
+    \x01\x12\x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\
+    \n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\
+    \x01\x03\x12\x03\x04\x14\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\
+    \x14\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\
+    \x02\x02\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\
+    \x05\x12\x13\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x13\n\x0c\n\x05\
+    \x04\0\x02\x03\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
+    \x03\x06\n\x0e\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x11\x12\n\x0b\n\
+    \x04\x04\0\x02\x04\x12\x03\x07\x04\x19\n\x0c\n\x05\x04\0\x02\x04\x05\x12\
+    \x03\x07\x04\t\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\n\x14\n\x0c\n\
+    \x05\x04\0\x02\x04\x03\x12\x03\x07\x17\x18\n\x0b\n\x04\x04\0\x02\x05\x12\
+    \x03\x08\x04\x1e\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x04\t\n\x0c\n\
+    \x05\x04\0\x02\x05\x01\x12\x03\x08\n\x19\n\x0c\n\x05\x04\0\x02\x05\x03\
+    \x12\x03\x08\x1c\x1d\n\x0b\n\x04\x04\0\x02\x06\x12\x03\t\x04\x1a\n\x0c\n\
+    \x05\x04\0\x02\x06\x05\x12\x03\t\x04\t\n\x0c\n\x05\x04\0\x02\x06\x01\x12\
+    \x03\t\n\x15\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\x18\x19\n\n\n\x02\
+    \x04\x01\x12\x04\x0b\0\x0f\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0b\x08\x1e\
+    \n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0c\x04\x16\n\x0c\n\x05\x04\x01\x02\0\
+    \x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0c\x0b\x11\
+    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0c\x14\x15\n\x0b\n\x04\x04\x01\
+    \x02\x01\x12\x03\r\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\r\x04\
+    \n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\r\x0b\x0f\n\x0c\n\x05\x04\x01\
+    \x02\x01\x03\x12\x03\r\x12\x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0e\
+    \x04\x13\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x0e\x04\t\n\x0c\n\x05\
+    \x04\x01\x02\x02\x01\x12\x03\x0e\n\x0e\n\x0c\n\x05\x04\x01\x02\x02\x03\
+    \x12\x03\x0e\x11\x12\n\n\n\x02\x04\x02\x12\x04\x10\0\x12\x01\n\n\n\x03\
+    \x04\x02\x01\x12\x03\x10\x08\x1c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x11\
+    \x04\x1d\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x11\x04\n\n\x0c\n\x05\x04\
+    \x02\x02\0\x01\x12\x03\x11\x0b\x18\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\
+    \x11\x1b\x1c\n\n\n\x02\x04\x03\x12\x04\x13\0\x17\x01\n\n\n\x03\x04\x03\
+    \x01\x12\x03\x13\x08\x17\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x14\x04\x1d\n\
+    \x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x03\x02\0\
+    \x01\x12\x03\x14\x0b\x18\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x1b\
+    \x1c\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x03\
+    \x02\x01\x05\x12\x03\x15\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\
+    \x15\n\x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x15\x12\x13\n\x0b\n\
+    \x04\x04\x03\x02\x02\x12\x03\x16\x04\x13\n\x0c\n\x05\x04\x03\x02\x02\x05\
+    \x12\x03\x16\x04\t\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x16\n\x0e\n\
+    \x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x16\x11\x12\n\n\n\x02\x04\x04\x12\
+    \x04\x18\0\x1b\x01\n\n\n\x03\x04\x04\x01\x12\x03\x18\x08!\n\x0b\n\x04\
+    \x04\x04\x02\0\x12\x03\x19\x04\x1d\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\
+    \x19\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x19\x0b\x18\n\x0c\n\x05\
+    \x04\x04\x02\0\x03\x12\x03\x19\x1b\x1c\n\x0b\n\x04\x04\x04\x02\x01\x12\
+    \x03\x1a\x04\x14\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1a\x04\t\n\x0c\
+    \n\x05\x04\x04\x02\x01\x01\x12\x03\x1a\n\x0f\n\x0c\n\x05\x04\x04\x02\x01\
+    \x03\x12\x03\x1a\x12\x13\n\n\n\x02\x04\x05\x12\x04\x1c\0\"\x01\n\n\n\x03\
+    \x04\x05\x01\x12\x03\x1c\x08\x20\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1d\
+    \x04\x1d\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1d\x04\n\n\x0c\n\x05\x04\
+    \x05\x02\0\x01\x12\x03\x1d\x0b\x18\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\
+    \x1d\x1b\x1c\n\x0b\n\x04\x04\x05\x02\x01\x12\x03\x1e\x04\x16\n\x0c\n\x05\
+    \x04\x05\x02\x01\x05\x12\x03\x1e\x04\n\n\x0c\n\x05\x04\x05\x02\x01\x01\
+    \x12\x03\x1e\x0b\x11\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03\x1e\x14\x15\
+    \n\x0b\n\x04\x04\x05\x02\x02\x12\x03\x1f\x04\x14\n\x0c\n\x05\x04\x05\x02\
+    \x02\x05\x12\x03\x1f\x04\t\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03\x1f\n\
+    \x0f\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03\x1f\x12\x13\n\x0b\n\x04\x04\
+    \x05\x02\x03\x12\x03\x20\x04\x17\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03\
+    \x20\x04\t\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03\x20\n\x12\n\x0c\n\x05\
+    \x04\x05\x02\x03\x03\x12\x03\x20\x15\x16\n\x0b\n\x04\x04\x05\x02\x04\x12\
+    \x03!\x04\x1a\n\x0c\n\x05\x04\x05\x02\x04\x05\x12\x03!\x04\x08\n\x0c\n\
+    \x05\x04\x05\x02\x04\x01\x12\x03!\t\x15\n\x0c\n\x05\x04\x05\x02\x04\x03\
+    \x12\x03!\x18\x19\n\n\n\x02\x04\x06\x12\x04#\0&\x01\n\n\n\x03\x04\x06\
+    \x01\x12\x03#\x08\x16\n\x0b\n\x04\x04\x06\x02\0\x12\x03$\x04\x1d\n\x0c\n\
+    \x05\x04\x06\x02\0\x05\x12\x03$\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\
+    \x03$\x0b\x18\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03$\x1b\x1c\n\x0b\n\x04\
+    \x04\x06\x02\x01\x12\x03%\x04\x14\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\
+    \x03%\x04\n\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03%\x0b\x0f\n\x0c\n\x05\
+    \x04\x06\x02\x01\x03\x12\x03%\x12\x13b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
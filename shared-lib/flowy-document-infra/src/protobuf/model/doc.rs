@@ -501,6 +501,7 @@ pub struct UpdateDocParams {
     pub doc_id: ::std::string::String,
     pub data: ::std::string::String,
     pub rev_id: i64,
+    pub device_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -583,6 +584,32 @@ impl UpdateDocParams {
     pub fn set_rev_id(&mut self, v: i64) {
         self.rev_id = v;
     }
+
+    // string device_id = 4;
+
+
+    pub fn get_device_id(&self) -> &str {
+        &self.device_id
+    }
+    pub fn clear_device_id(&mut self) {
+        self.device_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_id(&mut self, v: ::std::string::String) {
+        self.device_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_id(&mut self) -> &mut ::std::string::String {
+        &mut self.device_id
+    }
+
+    // Take field
+    pub fn take_device_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for UpdateDocParams {
@@ -607,6 +634,9 @@ impl ::protobuf::Message for UpdateDocParams {
                     let tmp = is.read_int64()?;
                     self.rev_id = tmp;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -628,6 +658,9 @@ impl ::protobuf::Message for UpdateDocParams {
         if self.rev_id != 0 {
             my_size += ::protobuf::rt::value_size(3, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.device_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.device_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -643,6 +676,9 @@ impl ::protobuf::Message for UpdateDocParams {
         if self.rev_id != 0 {
             os.write_int64(3, self.rev_id)?;
         }
+        if !self.device_id.is_empty() {
+            os.write_string(4, &self.device_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -696,6 +732,11 @@ impl ::protobuf::Message for UpdateDocParams {
                 |m: &UpdateDocParams| { &m.rev_id },
                 |m: &mut UpdateDocParams| { &mut m.rev_id },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_id",
+                |m: &UpdateDocParams| { &m.device_id },
+                |m: &mut UpdateDocParams| { &mut m.device_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateDocParams>(
                 "UpdateDocParams",
                 fields,
@@ -715,6 +756,7 @@ impl ::protobuf::Clear for UpdateDocParams {
         self.doc_id.clear();
         self.data.clear();
         self.rev_id = 0;
+        self.device_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -736,6 +778,8 @@ pub struct DocDelta {
     // message fields
     pub doc_id: ::std::string::String,
     pub data: ::std::string::String,
+    // message oneof groups
+    pub one_of_read_position: ::std::option::Option<DocDelta_oneof_one_of_read_position>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -747,6 +791,11 @@ impl<'a> ::std::default::Default for &'a DocDelta {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum DocDelta_oneof_one_of_read_position {
+    read_position(super::read_position::DocReadPosition),
+}
+
 impl DocDelta {
     pub fn new() -> DocDelta {
         ::std::default::Default::default()
@@ -803,10 +852,64 @@ impl DocDelta {
     pub fn take_data(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.data, ::std::string::String::new())
     }
+
+    // .DocReadPosition read_position = 3;
+
+
+    pub fn get_read_position(&self) -> &super::read_position::DocReadPosition {
+        match self.one_of_read_position {
+            ::std::option::Option::Some(DocDelta_oneof_one_of_read_position::read_position(ref v)) => v,
+            _ => <super::read_position::DocReadPosition as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_read_position(&mut self) {
+        self.one_of_read_position = ::std::option::Option::None;
+    }
+
+    pub fn has_read_position(&self) -> bool {
+        match self.one_of_read_position {
+            ::std::option::Option::Some(DocDelta_oneof_one_of_read_position::read_position(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_position(&mut self, v: super::read_position::DocReadPosition) {
+        self.one_of_read_position = ::std::option::Option::Some(DocDelta_oneof_one_of_read_position::read_position(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_read_position(&mut self) -> &mut super::read_position::DocReadPosition {
+        if let ::std::option::Option::Some(DocDelta_oneof_one_of_read_position::read_position(_)) = self.one_of_read_position {
+        } else {
+            self.one_of_read_position = ::std::option::Option::Some(DocDelta_oneof_one_of_read_position::read_position(super::read_position::DocReadPosition::new()));
+        }
+        match self.one_of_read_position {
+            ::std::option::Option::Some(DocDelta_oneof_one_of_read_position::read_position(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_read_position(&mut self) -> super::read_position::DocReadPosition {
+        if self.has_read_position() {
+            match self.one_of_read_position.take() {
+                ::std::option::Option::Some(DocDelta_oneof_one_of_read_position::read_position(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::read_position::DocReadPosition::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for DocDelta {
     fn is_initialized(&self) -> bool {
+        if let Some(DocDelta_oneof_one_of_read_position::read_position(ref v)) = self.one_of_read_position {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -820,6 +923,12 @@ impl ::protobuf::Message for DocDelta {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.data)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_read_position = ::std::option::Option::Some(DocDelta_oneof_one_of_read_position::read_position(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -838,6 +947,14 @@ impl ::protobuf::Message for DocDelta {
         if !self.data.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.data);
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_read_position {
+            match v {
+                &DocDelta_oneof_one_of_read_position::read_position(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -850,6 +967,15 @@ impl ::protobuf::Message for DocDelta {
         if !self.data.is_empty() {
             os.write_string(2, &self.data)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_read_position {
+            match v {
+                &DocDelta_oneof_one_of_read_position::read_position(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -898,6 +1024,11 @@ impl ::protobuf::Message for DocDelta {
                 |m: &DocDelta| { &m.data },
                 |m: &mut DocDelta| { &mut m.data },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::read_position::DocReadPosition>(
+                "read_position",
+                DocDelta::has_read_position,
+                DocDelta::get_read_position,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocDelta>(
                 "DocDelta",
                 fields,
@@ -916,6 +1047,7 @@ impl ::protobuf::Clear for DocDelta {
     fn clear(&mut self) {
         self.doc_id.clear();
         self.data.clear();
+        self.one_of_read_position = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -932,6 +1064,235 @@ impl ::protobuf::reflect::ProtobufValue for DocDelta {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct DocUndoState {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub can_undo: bool,
+    pub can_redo: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocUndoState {
+    fn default() -> &'a DocUndoState {
+        <DocUndoState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocUndoState {
+    pub fn new() -> DocUndoState {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // bool can_undo = 2;
+
+
+    pub fn get_can_undo(&self) -> bool {
+        self.can_undo
+    }
+    pub fn clear_can_undo(&mut self) {
+        self.can_undo = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_can_undo(&mut self, v: bool) {
+        self.can_undo = v;
+    }
+
+    // bool can_redo = 3;
+
+
+    pub fn get_can_redo(&self) -> bool {
+        self.can_redo
+    }
+    pub fn clear_can_redo(&mut self) {
+        self.can_redo = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_can_redo(&mut self, v: bool) {
+        self.can_redo = v;
+    }
+}
+
+impl ::protobuf::Message for DocUndoState {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.can_undo = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.can_redo = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.can_undo != false {
+            my_size += 2;
+        }
+        if self.can_redo != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.can_undo != false {
+            os.write_bool(2, self.can_undo)?;
+        }
+        if self.can_redo != false {
+            os.write_bool(3, self.can_redo)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocUndoState {
+        DocUndoState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocUndoState| { &m.doc_id },
+                |m: &mut DocUndoState| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "can_undo",
+                |m: &DocUndoState| { &m.can_undo },
+                |m: &mut DocUndoState| { &mut m.can_undo },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "can_redo",
+                |m: &DocUndoState| { &m.can_redo },
+                |m: &mut DocUndoState| { &mut m.can_redo },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocUndoState>(
+                "DocUndoState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocUndoState {
+        static instance: ::protobuf::rt::LazyV2<DocUndoState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocUndoState::new)
+    }
+}
+
+impl ::protobuf::Clear for DocUndoState {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.can_undo = false;
+        self.can_redo = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocUndoState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocUndoState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct NewDocUser {
     // message fields
@@ -1327,69 +1688,571 @@ impl ::protobuf::reflect::ProtobufValue for DocIdentifier {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct DocIdentifiers {
+    // message fields
+    pub doc_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocIdentifiers {
+    fn default() -> &'a DocIdentifiers {
+        <DocIdentifiers as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocIdentifiers {
+    pub fn new() -> DocIdentifiers {
+        ::std::default::Default::default()
+    }
+
+    // repeated string doc_ids = 1;
+
+
+    pub fn get_doc_ids(&self) -> &[::std::string::String] {
+        &self.doc_ids
+    }
+    pub fn clear_doc_ids(&mut self) {
+        self.doc_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.doc_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_doc_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.doc_ids
+    }
+
+    // Take field
+    pub fn take_doc_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.doc_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DocIdentifiers {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.doc_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.doc_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.doc_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocIdentifiers {
+        DocIdentifiers::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_ids",
+                |m: &DocIdentifiers| { &m.doc_ids },
+                |m: &mut DocIdentifiers| { &mut m.doc_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocIdentifiers>(
+                "DocIdentifiers",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocIdentifiers {
+        static instance: ::protobuf::rt::LazyV2<DocIdentifiers> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocIdentifiers::new)
+    }
+}
+
+impl ::protobuf::Clear for DocIdentifiers {
+    fn clear(&mut self) {
+        self.doc_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocIdentifiers {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocIdentifiers {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocPrefetchProgress {
+    // message fields
+    pub id: ::std::string::String,
+    pub total: i64,
+    pub finished: i64,
+    pub size: i64,
+    pub failed: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocPrefetchProgress {
+    fn default() -> &'a DocPrefetchProgress {
+        <DocPrefetchProgress as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocPrefetchProgress {
+    pub fn new() -> DocPrefetchProgress {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // int64 total = 2;
+
+
+    pub fn get_total(&self) -> i64 {
+        self.total
+    }
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: i64) {
+        self.total = v;
+    }
+
+    // int64 finished = 3;
+
+
+    pub fn get_finished(&self) -> i64 {
+        self.finished
+    }
+    pub fn clear_finished(&mut self) {
+        self.finished = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_finished(&mut self, v: i64) {
+        self.finished = v;
+    }
+
+    // int64 size = 4;
+
+
+    pub fn get_size(&self) -> i64 {
+        self.size
+    }
+    pub fn clear_size(&mut self) {
+        self.size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: i64) {
+        self.size = v;
+    }
+
+    // int64 failed = 5;
+
+
+    pub fn get_failed(&self) -> i64 {
+        self.failed
+    }
+    pub fn clear_failed(&mut self) {
+        self.failed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failed(&mut self, v: i64) {
+        self.failed = v;
+    }
+}
+
+impl ::protobuf::Message for DocPrefetchProgress {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.finished = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.size = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.failed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.finished != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.finished, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.failed != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.failed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.total != 0 {
+            os.write_int64(2, self.total)?;
+        }
+        if self.finished != 0 {
+            os.write_int64(3, self.finished)?;
+        }
+        if self.size != 0 {
+            os.write_int64(4, self.size)?;
+        }
+        if self.failed != 0 {
+            os.write_int64(5, self.failed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocPrefetchProgress {
+        DocPrefetchProgress::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &DocPrefetchProgress| { &m.id },
+                |m: &mut DocPrefetchProgress| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "total",
+                |m: &DocPrefetchProgress| { &m.total },
+                |m: &mut DocPrefetchProgress| { &mut m.total },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "finished",
+                |m: &DocPrefetchProgress| { &m.finished },
+                |m: &mut DocPrefetchProgress| { &mut m.finished },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "size",
+                |m: &DocPrefetchProgress| { &m.size },
+                |m: &mut DocPrefetchProgress| { &mut m.size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "failed",
+                |m: &DocPrefetchProgress| { &m.failed },
+                |m: &mut DocPrefetchProgress| { &mut m.failed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocPrefetchProgress>(
+                "DocPrefetchProgress",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocPrefetchProgress {
+        static instance: ::protobuf::rt::LazyV2<DocPrefetchProgress> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocPrefetchProgress::new)
+    }
+}
+
+impl ::protobuf::Clear for DocPrefetchProgress {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.total = 0;
+        self.finished = 0;
+        self.size = 0;
+        self.failed = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocPrefetchProgress {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocPrefetchProgress {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\tdoc.proto\"5\n\x0fCreateDocParams\x12\x0e\n\x02id\x18\x01\x20\x01(\t\
-    R\x02id\x12\x12\n\x04data\x18\x02\x20\x01(\tR\x04data\"`\n\x03Doc\x12\
-    \x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\x04data\x18\x02\x20\x01\
-    (\tR\x04data\x12\x15\n\x06rev_id\x18\x03\x20\x01(\x03R\x05revId\x12\x1e\
-    \n\x0bbase_rev_id\x18\x04\x20\x01(\x03R\tbaseRevId\"S\n\x0fUpdateDocPara\
-    ms\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x12\n\x04data\
-    \x18\x02\x20\x01(\tR\x04data\x12\x15\n\x06rev_id\x18\x03\x20\x01(\x03R\
-    \x05revId\"5\n\x08DocDelta\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05do\
-    cId\x12\x12\n\x04data\x18\x02\x20\x01(\tR\x04data\"S\n\nNewDocUser\x12\
-    \x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12\x15\n\x06rev_id\x18\
-    \x02\x20\x01(\x03R\x05revId\x12\x15\n\x06doc_id\x18\x03\x20\x01(\tR\x05d\
-    ocId\"&\n\rDocIdentifier\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docI\
-    dJ\xdb\x07\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\
-    \n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\
-    \x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x12\n\x0c\n\x05\x04\0\x02\0\
-    \x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\r\n\
-    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\n\x0b\n\x04\x04\0\x02\x01\
-    \x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\
-    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\
-    \x01\x03\x12\x03\x04\x12\x13\n\n\n\x02\x04\x01\x12\x04\x06\0\x0b\x01\n\n\
-    \n\x03\x04\x01\x01\x12\x03\x06\x08\x0b\n\x0b\n\x04\x04\x01\x02\0\x12\x03\
-    \x07\x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x07\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\x07\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\x07\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x08\x04\x14\n\x0c\n\
-    \x05\x04\x01\x02\x01\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x08\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x08\x12\
-    \x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\t\x04\x15\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\t\x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\t\n\
-    \x10\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\t\x13\x14\n\x0b\n\x04\x04\
-    \x01\x02\x03\x12\x03\n\x04\x1a\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\n\
-    \x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\n\n\x15\n\x0c\n\x05\x04\
-    \x01\x02\x03\x03\x12\x03\n\x18\x19\n\n\n\x02\x04\x02\x12\x04\x0c\0\x10\
-    \x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x17\n\x0b\n\x04\x04\x02\x02\0\
-    \x12\x03\r\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\
-    \x05\x04\x02\x02\0\x01\x12\x03\r\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x03\
-    \x12\x03\r\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x14\n\x0c\
-    \n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\
-    \x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x12\
-    \x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x04\x15\n\x0c\n\x05\x04\x02\
-    \x02\x02\x05\x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\
-    \x0f\n\x10\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x13\x14\n\n\n\x02\
-    \x04\x03\x12\x04\x11\0\x14\x01\n\n\n\x03\x04\x03\x01\x12\x03\x11\x08\x10\
-    \n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\x16\n\x0c\n\x05\x04\x03\x02\0\
-    \x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x12\x0b\x11\
-    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\x14\x15\n\x0b\n\x04\x04\x03\
-    \x02\x01\x12\x03\x13\x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x13\
-    \x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x13\x0b\x0f\n\x0c\n\x05\
-    \x04\x03\x02\x01\x03\x12\x03\x13\x12\x13\n\n\n\x02\x04\x04\x12\x04\x15\0\
-    \x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\x15\x08\x12\n\x0b\n\x04\x04\x04\
-    \x02\0\x12\x03\x16\x04\x17\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x16\x04\
-    \n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x16\x0b\x12\n\x0c\n\x05\x04\x04\
-    \x02\0\x03\x12\x03\x16\x15\x16\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x17\
-    \x04\x15\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x17\x04\t\n\x0c\n\x05\
-    \x04\x04\x02\x01\x01\x12\x03\x17\n\x10\n\x0c\n\x05\x04\x04\x02\x01\x03\
-    \x12\x03\x17\x13\x14\n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x18\x04\x16\n\
-    \x0c\n\x05\x04\x04\x02\x02\x05\x12\x03\x18\x04\n\n\x0c\n\x05\x04\x04\x02\
-    \x02\x01\x12\x03\x18\x0b\x11\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x18\
-    \x14\x15\n\n\n\x02\x04\x05\x12\x04\x1a\0\x1c\x01\n\n\n\x03\x04\x05\x01\
-    \x12\x03\x1a\x08\x15\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1b\x04\x16\n\x0c\
-    \n\x05\x04\x05\x02\0\x05\x12\x03\x1b\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\
-    \x12\x03\x1b\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1b\x14\x15b\
-    \x06proto3\
+    \n\tdoc.proto\x1a\x13read_position.proto\"5\n\x0fCreateDocParams\x12\x0e\
+    \n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\x04data\x18\x02\x20\x01(\tR\
+    \x04data\"`\n\x03Doc\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\
+    \x04data\x18\x02\x20\x01(\tR\x04data\x12\x15\n\x06rev_id\x18\x03\x20\x01\
+    (\x03R\x05revId\x12\x1e\n\x0bbase_rev_id\x18\x04\x20\x01(\x03R\tbaseRevI\
+    d\"p\n\x0fUpdateDocParams\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05doc\
+    Id\x12\x12\n\x04data\x18\x02\x20\x01(\tR\x04data\x12\x15\n\x06rev_id\x18\
+    \x03\x20\x01(\x03R\x05revId\x12\x1b\n\tdevice_id\x18\x04\x20\x01(\tR\x08\
+    deviceId\"\x86\x01\n\x08DocDelta\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\
+    \x05docId\x12\x12\n\x04data\x18\x02\x20\x01(\tR\x04data\x127\n\rread_pos\
+    ition\x18\x03\x20\x01(\x0b2\x10.DocReadPositionH\0R\x0creadPositionB\x16\
+    \n\x14one_of_read_position\"[\n\x0cDocUndoState\x12\x15\n\x06doc_id\x18\
+    \x01\x20\x01(\tR\x05docId\x12\x19\n\x08can_undo\x18\x02\x20\x01(\x08R\
+    \x07canUndo\x12\x19\n\x08can_redo\x18\x03\x20\x01(\x08R\x07canRedo\"S\n\
+    \nNewDocUser\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12\x15\
+    \n\x06rev_id\x18\x02\x20\x01(\x03R\x05revId\x12\x15\n\x06doc_id\x18\x03\
+    \x20\x01(\tR\x05docId\"&\n\rDocIdentifier\x12\x15\n\x06doc_id\x18\x01\
+    \x20\x01(\tR\x05docId\")\n\x0eDocIdentifiers\x12\x17\n\x07doc_ids\x18\
+    \x01\x20\x03(\tR\x06docIds\"\x83\x01\n\x13DocPrefetchProgress\x12\x0e\n\
+    \x02id\x18\x01\x20\x01(\tR\x02id\x12\x14\n\x05total\x18\x02\x20\x01(\x03\
+    R\x05total\x12\x1a\n\x08finished\x18\x03\x20\x01(\x03R\x08finished\x12\
+    \x12\n\x04size\x18\x04\x20\x01(\x03R\x04size\x12\x16\n\x06failed\x18\x05\
+    \x20\x01(\x03R\x06failedJ\xb4\r\n\x06\x12\x04\0\0.\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1d\n\n\n\x02\x04\0\x12\
+    \x04\x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x17\n\x0b\n\x04\
+    \x04\0\x02\0\x12\x03\x04\x04\x12\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\
+    \x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\r\n\x0c\n\x05\x04\0\
+    \x02\0\x03\x12\x03\x04\x10\x11\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\
+    \x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\
+    \x02\x01\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
+    \x05\x12\x13\n\n\n\x02\x04\x01\x12\x04\x07\0\x0c\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\x07\x08\x0b\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x12\n\
+    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x01\x12\x03\x08\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x10\x11\
+    \n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\x14\n\x0c\n\x05\x04\x01\x02\
+    \x01\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x0b\
+    \x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\x12\x13\n\x0b\n\x04\x04\
+    \x01\x02\x02\x12\x03\n\x04\x15\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\n\
+    \x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\n\n\x10\n\x0c\n\x05\x04\
+    \x01\x02\x02\x03\x12\x03\n\x13\x14\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\
+    \x0b\x04\x1a\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x0b\x04\t\n\x0c\n\
+    \x05\x04\x01\x02\x03\x01\x12\x03\x0b\n\x15\n\x0c\n\x05\x04\x01\x02\x03\
+    \x03\x12\x03\x0b\x18\x19\n\n\n\x02\x04\x02\x12\x04\r\0\x12\x01\n\n\n\x03\
+    \x04\x02\x01\x12\x03\r\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0e\x04\
+    \x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\
+    \x02\0\x01\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0e\
+    \x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\x04\x14\n\x0c\n\x05\x04\
+    \x02\x02\x01\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\
+    \x03\x0f\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f\x12\x13\n\
+    \x0b\n\x04\x04\x02\x02\x02\x12\x03\x10\x04\x15\n\x0c\n\x05\x04\x02\x02\
+    \x02\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x10\n\
+    \x10\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x10\x13\x14\n\x0b\n\x04\x04\
+    \x02\x02\x03\x12\x03\x11\x04\x19\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\
+    \x11\x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x11\x0b\x14\n\x0c\n\
+    \x05\x04\x02\x02\x03\x03\x12\x03\x11\x17\x18\n\n\n\x02\x04\x03\x12\x04\
+    \x13\0\x17\x01\n\n\n\x03\x04\x03\x01\x12\x03\x13\x08\x10\n\x0b\n\x04\x04\
+    \x03\x02\0\x12\x03\x14\x04\x16\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x14\
+    \x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x14\x0b\x11\n\x0c\n\x05\x04\
+    \x03\x02\0\x03\x12\x03\x14\x14\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\
+    \x15\x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x15\x04\n\n\x0c\n\
+    \x05\x04\x03\x02\x01\x01\x12\x03\x15\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x01\
+    \x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\x03\x08\0\x12\x03\x16\x04E\n\
+    \x0c\n\x05\x04\x03\x08\0\x01\x12\x03\x16\n\x1e\n\x0b\n\x04\x04\x03\x02\
+    \x02\x12\x03\x16!C\n\x0c\n\x05\x04\x03\x02\x02\x06\x12\x03\x16!0\n\x0c\n\
+    \x05\x04\x03\x02\x02\x01\x12\x03\x161>\n\x0c\n\x05\x04\x03\x02\x02\x03\
+    \x12\x03\x16AB\n\n\n\x02\x04\x04\x12\x04\x18\0\x1c\x01\n\n\n\x03\x04\x04\
+    \x01\x12\x03\x18\x08\x14\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x19\x04\x16\n\
+    \x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\x04\x02\0\
+    \x01\x12\x03\x19\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x19\x14\
+    \x15\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x1a\x04\x16\n\x0c\n\x05\x04\x04\
+    \x02\x01\x05\x12\x03\x1a\x04\x08\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\
+    \x1a\t\x11\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x1a\x14\x15\n\x0b\n\
+    \x04\x04\x04\x02\x02\x12\x03\x1b\x04\x16\n\x0c\n\x05\x04\x04\x02\x02\x05\
+    \x12\x03\x1b\x04\x08\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x1b\t\x11\n\
+    \x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x1b\x14\x15\n\n\n\x02\x04\x05\x12\
+    \x04\x1d\0!\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1d\x08\x12\n\x0b\n\x04\
+    \x04\x05\x02\0\x12\x03\x1e\x04\x17\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\
+    \x1e\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1e\x0b\x12\n\x0c\n\x05\
+    \x04\x05\x02\0\x03\x12\x03\x1e\x15\x16\n\x0b\n\x04\x04\x05\x02\x01\x12\
+    \x03\x1f\x04\x15\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03\x1f\x04\t\n\x0c\
+    \n\x05\x04\x05\x02\x01\x01\x12\x03\x1f\n\x10\n\x0c\n\x05\x04\x05\x02\x01\
+    \x03\x12\x03\x1f\x13\x14\n\x0b\n\x04\x04\x05\x02\x02\x12\x03\x20\x04\x16\
+    \n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03\x20\x04\n\n\x0c\n\x05\x04\x05\
+    \x02\x02\x01\x12\x03\x20\x0b\x11\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03\
+    \x20\x14\x15\n\n\n\x02\x04\x06\x12\x04\"\0$\x01\n\n\n\x03\x04\x06\x01\
+    \x12\x03\"\x08\x15\n\x0b\n\x04\x04\x06\x02\0\x12\x03#\x04\x16\n\x0c\n\
+    \x05\x04\x06\x02\0\x05\x12\x03#\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\
+    \x03#\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03#\x14\x15\n\n\n\x02\
+    \x04\x07\x12\x04%\0'\x01\n\n\n\x03\x04\x07\x01\x12\x03%\x08\x16\n\x0b\n\
+    \x04\x04\x07\x02\0\x12\x03&\x04\x20\n\x0c\n\x05\x04\x07\x02\0\x04\x12\
+    \x03&\x04\x0c\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03&\r\x13\n\x0c\n\x05\
+    \x04\x07\x02\0\x01\x12\x03&\x14\x1b\n\x0c\n\x05\x04\x07\x02\0\x03\x12\
+    \x03&\x1e\x1f\n\n\n\x02\x04\x08\x12\x04(\0.\x01\n\n\n\x03\x04\x08\x01\
+    \x12\x03(\x08\x1b\n\x0b\n\x04\x04\x08\x02\0\x12\x03)\x04\x12\n\x0c\n\x05\
+    \x04\x08\x02\0\x05\x12\x03)\x04\n\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03)\
+    \x0b\r\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03)\x10\x11\n\x0b\n\x04\x04\
+    \x08\x02\x01\x12\x03*\x04\x14\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03*\
+    \x04\t\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03*\n\x0f\n\x0c\n\x05\x04\
+    \x08\x02\x01\x03\x12\x03*\x12\x13\n\x0b\n\x04\x04\x08\x02\x02\x12\x03+\
+    \x04\x17\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03+\x04\t\n\x0c\n\x05\x04\
+    \x08\x02\x02\x01\x12\x03+\n\x12\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03+\
+    \x15\x16\n\x0b\n\x04\x04\x08\x02\x03\x12\x03,\x04\x13\n\x0c\n\x05\x04\
+    \x08\x02\x03\x05\x12\x03,\x04\t\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x03,\
+    \n\x0e\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\x03,\x11\x12\n\x0b\n\x04\x04\
+    \x08\x02\x04\x12\x03-\x04\x15\n\x0c\n\x05\x04\x08\x02\x04\x05\x12\x03-\
+    \x04\t\n\x0c\n\x05\x04\x08\x02\x04\x01\x12\x03-\n\x10\n\x0c\n\x05\x04\
+    \x08\x02\x04\x03\x12\x03-\x13\x14b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,872 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `import_preview.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocImportBlock {
+    // message fields
+    pub block_type: DocBlockType,
+    pub level: i32,
+    pub text: ::std::string::String,
+    pub line: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocImportBlock {
+    fn default() -> &'a DocImportBlock {
+        <DocImportBlock as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocImportBlock {
+    pub fn new() -> DocImportBlock {
+        ::std::default::Default::default()
+    }
+
+    // .DocBlockType block_type = 1;
+
+
+    pub fn get_block_type(&self) -> DocBlockType {
+        self.block_type
+    }
+    pub fn clear_block_type(&mut self) {
+        self.block_type = DocBlockType::Paragraph;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_type(&mut self, v: DocBlockType) {
+        self.block_type = v;
+    }
+
+    // int32 level = 2;
+
+
+    pub fn get_level(&self) -> i32 {
+        self.level
+    }
+    pub fn clear_level(&mut self) {
+        self.level = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_level(&mut self, v: i32) {
+        self.level = v;
+    }
+
+    // string text = 3;
+
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        &mut self.text
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text, ::std::string::String::new())
+    }
+
+    // int64 line = 4;
+
+
+    pub fn get_line(&self) -> i64 {
+        self.line
+    }
+    pub fn clear_line(&mut self) {
+        self.line = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_line(&mut self, v: i64) {
+        self.line = v;
+    }
+}
+
+impl ::protobuf::Message for DocImportBlock {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.block_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.level = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.line = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.block_type != DocBlockType::Paragraph {
+            my_size += ::protobuf::rt::enum_size(1, self.block_type);
+        }
+        if self.level != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.level, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.text);
+        }
+        if self.line != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.line, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.block_type != DocBlockType::Paragraph {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.block_type))?;
+        }
+        if self.level != 0 {
+            os.write_int32(2, self.level)?;
+        }
+        if !self.text.is_empty() {
+            os.write_string(3, &self.text)?;
+        }
+        if self.line != 0 {
+            os.write_int64(4, self.line)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocImportBlock {
+        DocImportBlock::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DocBlockType>>(
+                "block_type",
+                |m: &DocImportBlock| { &m.block_type },
+                |m: &mut DocImportBlock| { &mut m.block_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "level",
+                |m: &DocImportBlock| { &m.level },
+                |m: &mut DocImportBlock| { &mut m.level },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text",
+                |m: &DocImportBlock| { &m.text },
+                |m: &mut DocImportBlock| { &mut m.text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "line",
+                |m: &DocImportBlock| { &m.line },
+                |m: &mut DocImportBlock| { &mut m.line },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocImportBlock>(
+                "DocImportBlock",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocImportBlock {
+        static instance: ::protobuf::rt::LazyV2<DocImportBlock> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocImportBlock::new)
+    }
+}
+
+impl ::protobuf::Clear for DocImportBlock {
+    fn clear(&mut self) {
+        self.block_type = DocBlockType::Paragraph;
+        self.level = 0;
+        self.text.clear();
+        self.line = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocImportBlock {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocImportBlock {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocImportWarning {
+    // message fields
+    pub line: i64,
+    pub message: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocImportWarning {
+    fn default() -> &'a DocImportWarning {
+        <DocImportWarning as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocImportWarning {
+    pub fn new() -> DocImportWarning {
+        ::std::default::Default::default()
+    }
+
+    // int64 line = 1;
+
+
+    pub fn get_line(&self) -> i64 {
+        self.line
+    }
+    pub fn clear_line(&mut self) {
+        self.line = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_line(&mut self, v: i64) {
+        self.line = v;
+    }
+
+    // string message = 2;
+
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DocImportWarning {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.line = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.line != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.line, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.line != 0 {
+            os.write_int64(1, self.line)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(2, &self.message)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocImportWarning {
+        DocImportWarning::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "line",
+                |m: &DocImportWarning| { &m.line },
+                |m: &mut DocImportWarning| { &mut m.line },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "message",
+                |m: &DocImportWarning| { &m.message },
+                |m: &mut DocImportWarning| { &mut m.message },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocImportWarning>(
+                "DocImportWarning",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocImportWarning {
+        static instance: ::protobuf::rt::LazyV2<DocImportWarning> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocImportWarning::new)
+    }
+}
+
+impl ::protobuf::Clear for DocImportWarning {
+    fn clear(&mut self) {
+        self.line = 0;
+        self.message.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocImportWarning {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocImportWarning {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocImportPreview {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub blocks: ::protobuf::RepeatedField<DocImportBlock>,
+    pub warnings: ::protobuf::RepeatedField<DocImportWarning>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocImportPreview {
+    fn default() -> &'a DocImportPreview {
+        <DocImportPreview as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocImportPreview {
+    pub fn new() -> DocImportPreview {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // repeated .DocImportBlock blocks = 2;
+
+
+    pub fn get_blocks(&self) -> &[DocImportBlock] {
+        &self.blocks
+    }
+    pub fn clear_blocks(&mut self) {
+        self.blocks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blocks(&mut self, v: ::protobuf::RepeatedField<DocImportBlock>) {
+        self.blocks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_blocks(&mut self) -> &mut ::protobuf::RepeatedField<DocImportBlock> {
+        &mut self.blocks
+    }
+
+    // Take field
+    pub fn take_blocks(&mut self) -> ::protobuf::RepeatedField<DocImportBlock> {
+        ::std::mem::replace(&mut self.blocks, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .DocImportWarning warnings = 3;
+
+
+    pub fn get_warnings(&self) -> &[DocImportWarning] {
+        &self.warnings
+    }
+    pub fn clear_warnings(&mut self) {
+        self.warnings.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_warnings(&mut self, v: ::protobuf::RepeatedField<DocImportWarning>) {
+        self.warnings = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_warnings(&mut self) -> &mut ::protobuf::RepeatedField<DocImportWarning> {
+        &mut self.warnings
+    }
+
+    // Take field
+    pub fn take_warnings(&mut self) -> ::protobuf::RepeatedField<DocImportWarning> {
+        ::std::mem::replace(&mut self.warnings, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DocImportPreview {
+    fn is_initialized(&self) -> bool {
+        for v in &self.blocks {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.warnings {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.blocks)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.warnings)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        for value in &self.blocks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.warnings {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        for v in &self.blocks {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.warnings {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocImportPreview {
+        DocImportPreview::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocImportPreview| { &m.doc_id },
+                |m: &mut DocImportPreview| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocImportBlock>>(
+                "blocks",
+                |m: &DocImportPreview| { &m.blocks },
+                |m: &mut DocImportPreview| { &mut m.blocks },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocImportWarning>>(
+                "warnings",
+                |m: &DocImportPreview| { &m.warnings },
+                |m: &mut DocImportPreview| { &mut m.warnings },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocImportPreview>(
+                "DocImportPreview",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocImportPreview {
+        static instance: ::protobuf::rt::LazyV2<DocImportPreview> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocImportPreview::new)
+    }
+}
+
+impl ::protobuf::Clear for DocImportPreview {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.blocks.clear();
+        self.warnings.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocImportPreview {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocImportPreview {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DocBlockType {
+    Paragraph = 0,
+    Heading = 1,
+    Bullet = 2,
+    Ordered = 3,
+    Checkbox = 4,
+    Quote = 5,
+    Code = 6,
+}
+
+impl ::protobuf::ProtobufEnum for DocBlockType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DocBlockType> {
+        match value {
+            0 => ::std::option::Option::Some(DocBlockType::Paragraph),
+            1 => ::std::option::Option::Some(DocBlockType::Heading),
+            2 => ::std::option::Option::Some(DocBlockType::Bullet),
+            3 => ::std::option::Option::Some(DocBlockType::Ordered),
+            4 => ::std::option::Option::Some(DocBlockType::Checkbox),
+            5 => ::std::option::Option::Some(DocBlockType::Quote),
+            6 => ::std::option::Option::Some(DocBlockType::Code),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DocBlockType] = &[
+            DocBlockType::Paragraph,
+            DocBlockType::Heading,
+            DocBlockType::Bullet,
+            DocBlockType::Ordered,
+            DocBlockType::Checkbox,
+            DocBlockType::Quote,
+            DocBlockType::Code,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DocBlockType>("DocBlockType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DocBlockType {
+}
+
+impl ::std::default::Default for DocBlockType {
+    fn default() -> Self {
+        DocBlockType::Paragraph
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocBlockType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14import_preview.proto\"|\n\x0eDocImportBlock\x12,\n\nblock_type\x18\
+    \x01\x20\x01(\x0e2\r.DocBlockTypeR\tblockType\x12\x14\n\x05level\x18\x02\
+    \x20\x01(\x05R\x05level\x12\x12\n\x04text\x18\x03\x20\x01(\tR\x04text\
+    \x12\x12\n\x04line\x18\x04\x20\x01(\x03R\x04line\"@\n\x10DocImportWarnin\
+    g\x12\x12\n\x04line\x18\x01\x20\x01(\x03R\x04line\x12\x18\n\x07message\
+    \x18\x02\x20\x01(\tR\x07message\"\x81\x01\n\x10DocImportPreview\x12\x15\
+    \n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12'\n\x06blocks\x18\x02\x20\
+    \x03(\x0b2\x0f.DocImportBlockR\x06blocks\x12-\n\x08warnings\x18\x03\x20\
+    \x03(\x0b2\x11.DocImportWarningR\x08warnings*f\n\x0cDocBlockType\x12\r\n\
+    \tParagraph\x10\0\x12\x0b\n\x07Heading\x10\x01\x12\n\n\x06Bullet\x10\x02\
+    \x12\x0b\n\x07Ordered\x10\x03\x12\x0c\n\x08Checkbox\x10\x04\x12\t\n\x05Q\
+    uote\x10\x05\x12\x08\n\x04Code\x10\x06J\x9c\x07\n\x06\x12\x04\0\0\x19\
+    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x07\
+    \x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x16\n\x0b\n\x04\x04\0\x02\0\x12\
+    \x03\x03\x04\x20\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\x10\n\x0c\n\
+    \x05\x04\0\x02\0\x01\x12\x03\x03\x11\x1b\n\x0c\n\x05\x04\0\x02\0\x03\x12\
+    \x03\x03\x1e\x1f\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x14\n\x0c\n\
+    \x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\x04\n\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x12\x13\n\
+    \x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\x02\
+    \x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x0f\
+    \n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\x0b\n\x04\x04\0\x02\
+    \x03\x12\x03\x06\x04\x13\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\t\
+    \n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\n\x0e\n\x0c\n\x05\x04\0\x02\
+    \x03\x03\x12\x03\x06\x11\x12\n\n\n\x02\x04\x01\x12\x04\x08\0\x0b\x01\n\n\
+    \n\x03\x04\x01\x01\x12\x03\x08\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\
+    \t\x04\x13\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\t\x04\t\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03\t\n\x0e\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\
+    \x11\x12\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\n\x04\x17\n\x0c\n\x05\x04\
+    \x01\x02\x01\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\
+    \n\x0b\x12\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\n\x15\x16\n\n\n\x02\
+    \x04\x02\x12\x04\x0c\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x18\
+    \n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x16\n\x0c\n\x05\x04\x02\x02\0\
+    \x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\r\x0b\x11\n\
+    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x14\x15\n\x0b\n\x04\x04\x02\x02\
+    \x01\x12\x03\x0e\x04'\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x0e\x04\
+    \x0c\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x0e\r\x1b\n\x0c\n\x05\x04\
+    \x02\x02\x01\x01\x12\x03\x0e\x1c\"\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
+    \x03\x0e%&\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x04+\n\x0c\n\x05\x04\
+    \x02\x02\x02\x04\x12\x03\x0f\x04\x0c\n\x0c\n\x05\x04\x02\x02\x02\x06\x12\
+    \x03\x0f\r\x1d\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x1e&\n\x0c\n\
+    \x05\x04\x02\x02\x02\x03\x12\x03\x0f)*\n\n\n\x02\x05\0\x12\x04\x11\0\x19\
+    \x01\n\n\n\x03\x05\0\x01\x12\x03\x11\x05\x11\n\x0b\n\x04\x05\0\x02\0\x12\
+    \x03\x12\x04\x12\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x12\x04\r\n\x0c\n\
+    \x05\x05\0\x02\0\x02\x12\x03\x12\x10\x11\n\x0b\n\x04\x05\0\x02\x01\x12\
+    \x03\x13\x04\x10\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x13\x04\x0b\n\x0c\
+    \n\x05\x05\0\x02\x01\x02\x12\x03\x13\x0e\x0f\n\x0b\n\x04\x05\0\x02\x02\
+    \x12\x03\x14\x04\x0f\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x14\x04\n\n\
+    \x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x14\r\x0e\n\x0b\n\x04\x05\0\x02\x03\
+    \x12\x03\x15\x04\x10\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x15\x04\x0b\n\
+    \x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x15\x0e\x0f\n\x0b\n\x04\x05\0\x02\
+    \x04\x12\x03\x16\x04\x11\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x16\x04\
+    \x0c\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x16\x0f\x10\n\x0b\n\x04\x05\0\
+    \x02\x05\x12\x03\x17\x04\x0e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x17\
+    \x04\t\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x17\x0c\r\n\x0b\n\x04\x05\0\
+    \x02\x06\x12\x03\x18\x04\r\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\x18\x04\
+    \x08\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\x18\x0b\x0cb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
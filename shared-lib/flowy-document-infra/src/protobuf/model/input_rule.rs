@@ -0,0 +1,275 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `input_rule.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InputRuleSetting {
+    // message fields
+    pub em_dash: bool,
+    pub heading: bool,
+    pub checkbox: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InputRuleSetting {
+    fn default() -> &'a InputRuleSetting {
+        <InputRuleSetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InputRuleSetting {
+    pub fn new() -> InputRuleSetting {
+        ::std::default::Default::default()
+    }
+
+    // bool em_dash = 1;
+
+
+    pub fn get_em_dash(&self) -> bool {
+        self.em_dash
+    }
+    pub fn clear_em_dash(&mut self) {
+        self.em_dash = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_em_dash(&mut self, v: bool) {
+        self.em_dash = v;
+    }
+
+    // bool heading = 2;
+
+
+    pub fn get_heading(&self) -> bool {
+        self.heading
+    }
+    pub fn clear_heading(&mut self) {
+        self.heading = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_heading(&mut self, v: bool) {
+        self.heading = v;
+    }
+
+    // bool checkbox = 3;
+
+
+    pub fn get_checkbox(&self) -> bool {
+        self.checkbox
+    }
+    pub fn clear_checkbox(&mut self) {
+        self.checkbox = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checkbox(&mut self, v: bool) {
+        self.checkbox = v;
+    }
+}
+
+impl ::protobuf::Message for InputRuleSetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.em_dash = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.heading = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.checkbox = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.em_dash != false {
+            my_size += 2;
+        }
+        if self.heading != false {
+            my_size += 2;
+        }
+        if self.checkbox != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.em_dash != false {
+            os.write_bool(1, self.em_dash)?;
+        }
+        if self.heading != false {
+            os.write_bool(2, self.heading)?;
+        }
+        if self.checkbox != false {
+            os.write_bool(3, self.checkbox)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InputRuleSetting {
+        InputRuleSetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "em_dash",
+                |m: &InputRuleSetting| { &m.em_dash },
+                |m: &mut InputRuleSetting| { &mut m.em_dash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "heading",
+                |m: &InputRuleSetting| { &m.heading },
+                |m: &mut InputRuleSetting| { &mut m.heading },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "checkbox",
+                |m: &InputRuleSetting| { &m.checkbox },
+                |m: &mut InputRuleSetting| { &mut m.checkbox },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<InputRuleSetting>(
+                "InputRuleSetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static InputRuleSetting {
+        static instance: ::protobuf::rt::LazyV2<InputRuleSetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(InputRuleSetting::new)
+    }
+}
+
+impl ::protobuf::Clear for InputRuleSetting {
+    fn clear(&mut self) {
+        self.em_dash = false;
+        self.heading = false;
+        self.checkbox = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InputRuleSetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InputRuleSetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10input_rule.proto\"a\n\x10InputRuleSetting\x12\x17\n\x07em_dash\x18\
+    \x01\x20\x01(\x08R\x06emDash\x12\x18\n\x07heading\x18\x02\x20\x01(\x08R\
+    \x07heading\x12\x1a\n\x08checkbox\x18\x03\x20\x01(\x08R\x08checkboxJ\xcf\
+    \x01\n\x06\x12\x04\0\0\x06\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
+    \x04\0\x12\x04\x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x18\n\
+    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\
+    \x12\x03\x03\x04\x08\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\t\x10\n\x0c\
+    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x13\x14\n\x0b\n\x04\x04\0\x02\x01\x12\
+    \x03\x04\x04\x15\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\x08\n\x0c\
+    \n\x05\x04\0\x02\x01\x01\x12\x03\x04\t\x10\n\x0c\n\x05\x04\0\x02\x01\x03\
+    \x12\x03\x04\x13\x14\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x16\n\x0c\
+    \n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\x08\n\x0c\n\x05\x04\0\x02\x02\
+    \x01\x12\x03\x05\t\x11\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x14\x15\
+    b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -8,3 +8,27 @@ pub use revision::*;
 
 mod doc; 
 pub use doc::*; 
+
+mod task; 
+pub use task::*; 
+
+mod text_run; 
+pub use text_run::*; 
+
+mod input_rule; 
+pub use input_rule::*; 
+
+mod snapshot; 
+pub use snapshot::*; 
+
+mod presence; 
+pub use presence::*; 
+
+mod read_position; 
+pub use read_position::*; 
+
+mod attachment; 
+pub use attachment::*; 
+
+mod import_preview; 
+pub use import_preview::*; 
@@ -262,6 +262,11 @@ pub enum WsDataType {
     PullRev = 2,
     Conflict = 3,
     NewDocUser = 4,
+    PullDocs = 5,
+    PushDoc = 6,
+    Presence = 7,
+    Typing = 8,
+    PushDocFailed = 9,
 }
 
 impl ::protobuf::ProtobufEnum for WsDataType {
@@ -276,6 +281,11 @@ impl ::protobuf::ProtobufEnum for WsDataType {
             2 => ::std::option::Option::Some(WsDataType::PullRev),
             3 => ::std::option::Option::Some(WsDataType::Conflict),
             4 => ::std::option::Option::Some(WsDataType::NewDocUser),
+            5 => ::std::option::Option::Some(WsDataType::PullDocs),
+            6 => ::std::option::Option::Some(WsDataType::PushDoc),
+            7 => ::std::option::Option::Some(WsDataType::Presence),
+            8 => ::std::option::Option::Some(WsDataType::Typing),
+            9 => ::std::option::Option::Some(WsDataType::PushDocFailed),
             _ => ::std::option::Option::None
         }
     }
@@ -287,6 +297,11 @@ impl ::protobuf::ProtobufEnum for WsDataType {
             WsDataType::PullRev,
             WsDataType::Conflict,
             WsDataType::NewDocUser,
+            WsDataType::PullDocs,
+            WsDataType::PushDoc,
+            WsDataType::Presence,
+            WsDataType::Typing,
+            WsDataType::PushDocFailed,
         ];
         values
     }
@@ -317,31 +332,43 @@ impl ::protobuf::reflect::ProtobufValue for WsDataType {
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x08ws.proto\"X\n\x0eWsDocumentData\x12\x15\n\x06doc_id\x18\x01\x20\
     \x01(\tR\x05docId\x12\x1b\n\x02ty\x18\x02\x20\x01(\x0e2\x0b.WsDataTypeR\
-    \x02ty\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04data*O\n\nWsDataType\
-    \x12\t\n\x05Acked\x10\0\x12\x0b\n\x07PushRev\x10\x01\x12\x0b\n\x07PullRe\
-    v\x10\x02\x12\x0c\n\x08Conflict\x10\x03\x12\x0e\n\nNewDocUser\x10\x04J\
-    \xb4\x03\n\x06\x12\x04\0\0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x16\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x11\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\
-    \x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x04\x04\x0e\n\x0c\
-    \n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0f\x11\n\x0c\n\x05\x04\0\x02\x01\
-    \x03\x12\x03\x04\x14\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x13\n\
-    \x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\x02\x02\
-    \x01\x12\x03\x05\n\x0e\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x11\x12\
-    \n\n\n\x02\x05\0\x12\x04\x07\0\r\x01\n\n\n\x03\x05\0\x01\x12\x03\x07\x05\
-    \x0f\n\x0b\n\x04\x05\0\x02\0\x12\x03\x08\x04\x0e\n\x0c\n\x05\x05\0\x02\0\
-    \x01\x12\x03\x08\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x08\x0c\r\n\
-    \x0b\n\x04\x05\0\x02\x01\x12\x03\t\x04\x10\n\x0c\n\x05\x05\0\x02\x01\x01\
-    \x12\x03\t\x04\x0b\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\t\x0e\x0f\n\x0b\
-    \n\x04\x05\0\x02\x02\x12\x03\n\x04\x10\n\x0c\n\x05\x05\0\x02\x02\x01\x12\
-    \x03\n\x04\x0b\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\n\x0e\x0f\n\x0b\n\
-    \x04\x05\0\x02\x03\x12\x03\x0b\x04\x11\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
-    \x03\x0b\x04\x0c\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x0b\x0f\x10\n\x0b\
-    \n\x04\x05\0\x02\x04\x12\x03\x0c\x04\x13\n\x0c\n\x05\x05\0\x02\x04\x01\
-    \x12\x03\x0c\x04\x0e\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x0c\x11\x12b\
-    \x06proto3\
+    \x02ty\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04data*\x97\x01\n\nWsDat\
+    aType\x12\t\n\x05Acked\x10\0\x12\x0b\n\x07PushRev\x10\x01\x12\x0b\n\x07P\
+    ullRev\x10\x02\x12\x0c\n\x08Conflict\x10\x03\x12\x0e\n\nNewDocUser\x10\
+    \x04\x12\x0c\n\x08PullDocs\x10\x05\x12\x0b\n\x07PushDoc\x10\x06\x12\x0c\
+    \n\x08Presence\x10\x07\x12\n\n\x06Typing\x10\x08\x12\x11\n\rPushDocFaile\
+    d\x10\tJ\x81\x05\n\x06\x12\x04\0\0\x12\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x12\n\n\n\x02\x04\0\x12\x04\x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\
+    \x02\x08\x16\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x04\
+    \0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\
+    \x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x14\x15\n\x0b\n\x04\x04\
+    \0\x02\x01\x12\x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x04\
+    \x04\x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0f\x11\n\x0c\n\x05\
+    \x04\0\x02\x01\x03\x12\x03\x04\x14\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\
+    \x05\x04\x13\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\n\x0c\n\x05\
+    \x04\0\x02\x02\x01\x12\x03\x05\n\x0e\n\x0c\n\x05\x04\0\x02\x02\x03\x12\
+    \x03\x05\x11\x12\n\n\n\x02\x05\0\x12\x04\x07\0\x12\x01\n\n\n\x03\x05\0\
+    \x01\x12\x03\x07\x05\x0f\n\x0b\n\x04\x05\0\x02\0\x12\x03\x08\x04\x0e\n\
+    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x08\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\
+    \x12\x03\x08\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03\t\x04\x10\n\x0c\n\
+    \x05\x05\0\x02\x01\x01\x12\x03\t\x04\x0b\n\x0c\n\x05\x05\0\x02\x01\x02\
+    \x12\x03\t\x0e\x0f\n\x0b\n\x04\x05\0\x02\x02\x12\x03\n\x04\x10\n\x0c\n\
+    \x05\x05\0\x02\x02\x01\x12\x03\n\x04\x0b\n\x0c\n\x05\x05\0\x02\x02\x02\
+    \x12\x03\n\x0e\x0f\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x0b\x04\x11\n\x0c\n\
+    \x05\x05\0\x02\x03\x01\x12\x03\x0b\x04\x0c\n\x0c\n\x05\x05\0\x02\x03\x02\
+    \x12\x03\x0b\x0f\x10\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x0c\x04\x13\n\x0c\
+    \n\x05\x05\0\x02\x04\x01\x12\x03\x0c\x04\x0e\n\x0c\n\x05\x05\0\x02\x04\
+    \x02\x12\x03\x0c\x11\x12\n\x0b\n\x04\x05\0\x02\x05\x12\x03\r\x04\x11\n\
+    \x0c\n\x05\x05\0\x02\x05\x01\x12\x03\r\x04\x0c\n\x0c\n\x05\x05\0\x02\x05\
+    \x02\x12\x03\r\x0f\x10\n\x0b\n\x04\x05\0\x02\x06\x12\x03\x0e\x04\x10\n\
+    \x0c\n\x05\x05\0\x02\x06\x01\x12\x03\x0e\x04\x0b\n\x0c\n\x05\x05\0\x02\
+    \x06\x02\x12\x03\x0e\x0e\x0f\n\x0b\n\x04\x05\0\x02\x07\x12\x03\x0f\x04\
+    \x11\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\x0f\x04\x0c\n\x0c\n\x05\x05\0\
+    \x02\x07\x02\x12\x03\x0f\x0f\x10\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x10\
+    \x04\x0f\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x10\x04\n\n\x0c\n\x05\x05\
+    \0\x02\x08\x02\x12\x03\x10\r\x0e\n\x0b\n\x04\x05\0\x02\t\x12\x03\x11\x04\
+    \x16\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x11\x04\x11\n\x0c\n\x05\x05\0\
+    \x02\t\x02\x12\x03\x11\x14\x15b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -1,24 +1,90 @@
-use lib_ot::core::{Delta, DeltaBuilder};
+use crate::core::{DocumentBuilder, Span};
+use lib_ot::core::{Attribute, Delta};
 
 #[inline]
-pub fn doc_initial_delta() -> Delta { DeltaBuilder::new().insert("\n").build() }
+pub fn doc_initial_delta() -> Delta { DocumentBuilder::new().build() }
 
 #[inline]
 pub fn doc_initial_string() -> String { doc_initial_delta().to_json() }
 
-#[inline]
 pub fn initial_read_me() -> Delta {
-    let json = include_str!("READ_ME.json");
-    let delta = Delta::from_json(json).unwrap();
-    delta
+    let highlight = |text: &str, color: &str| Span::new(text, vec![Attribute::Background(color.to_owned())]);
+    let button = |text: &str| Span::new(text, vec![Attribute::Background("#defff1".to_owned()), Attribute::Bold(true)]);
+    let styles = vec![
+        highlight("Highlight", "#fff2cd"),
+        " any text, and use the menu at the bottom to ".into(),
+        Span::italic("style"),
+        " ".into(),
+        Span::bold("your"),
+        " ".into(),
+        Span::new("writing", vec![Attribute::Underline(true)]),
+        " ".into(),
+        Span::code("however"),
+        " ".into(),
+        Span::new("you", vec![Attribute::StrikeThrough(true)]),
+        " ".into(),
+        highlight("like", "#e8e0ff"),
+    ];
+    let new_page = vec![
+        "Click ".into(),
+        button("+ New Page"),
+        " button at the bottom of your sidebar to add a new page".into(),
+    ];
+    let new_sub_page = vec![
+        "Click the ".into(),
+        highlight("'", "#defff1"),
+        button("+'"),
+        "  next to any page title in the sidebar to quickly add a new subpage".into(),
+    ];
+    let help = vec![
+        "Click the ".into(),
+        button("'?'"),
+        " at the bottom right for help and support.".into(),
+    ];
+
+    let unchecked = Some(Attribute::UnChecked(true));
+    DocumentBuilder::new()
+        .paragraph("")
+        .heading(1, "👋 Welcome to AppFlowy!")
+        .paragraph("")
+        .heading(2, "Here are the basics")
+        .checkbox("Click anywhere and just start typing", false)
+        .line(styles, unchecked.clone())
+        .line(new_page, unchecked.clone())
+        .line(new_sub_page, unchecked)
+        .paragraph("")
+        .heading(2, "Have a question? ")
+        .line(help, None)
+        .paragraph("")
+        .heading(2, "Like AppFlowy? Follow us:")
+        .quote("Github: https://github.com/AppFlowy-IO/appflowy")
+        .quote("Twitter: https://twitter.com/appflowy")
+        .quote("Newsletter: https://www.appflowy.io/blog")
+        .build()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::user_default::initial_read_me;
+    use crate::{core::delta_to_outline, user_default::initial_read_me};
 
     #[test]
     fn load_read_me() {
-        println!("{}", initial_read_me().to_json());
+        let read_me = initial_read_me();
+        let outline = delta_to_outline(&read_me)
+            .into_iter()
+            .map(|heading| (heading.level, heading.text))
+            .collect::<Vec<(usize, String)>>();
+        assert_eq!(
+            outline,
+            vec![
+                (1, "👋 Welcome to AppFlowy!".to_owned()),
+                (2, "Here are the basics".to_owned()),
+                (2, "Have a question? ".to_owned()),
+                (2, "Like AppFlowy? Follow us:".to_owned()),
+            ]
+        );
+        assert!(read_me
+            .to_json()
+            .ends_with(r#"{"insert":"\n","attributes":{"blockquote":true}}]"#));
     }
 }
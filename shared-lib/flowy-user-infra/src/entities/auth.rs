@@ -1,6 +1,6 @@
 use std::convert::TryInto;
 
-use flowy_derive::ProtoBuf;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 
 use crate::{errors::*, parser::*};
 
@@ -41,6 +41,9 @@ pub struct SignInResponse {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub refresh_token: String,
 }
 
 impl TryInto<SignInParams> for SignInRequest {
@@ -110,4 +113,83 @@ pub struct SignUpResponse {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub refresh_token: String,
+}
+
+// The token is short-lived, the refresh token is exchanged for a new pair before it expires. The refresh
+// token can only be used once, it's replaced by the one in the response.
+#[derive(ProtoBuf, Default, Debug)]
+pub struct RefreshTokenParams {
+    #[pb(index = 1)]
+    pub refresh_token: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct RefreshTokenResponse {
+    #[pb(index = 1)]
+    pub token: String,
+
+    #[pb(index = 2)]
+    pub refresh_token: String,
+}
+
+// The third-party account that the user signs in with, see SignInWithProviderRequest
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum AuthProvider {
+    Google = 0,
+    GitHub = 1,
+}
+
+impl std::default::Default for AuthProvider {
+    fn default() -> Self { AuthProvider::Google }
+}
+
+// The code is returned to the redirect_uri after the user authorizes the app with the provider. The
+// code_verifier is the one that the code_challenge of the authorization request was made from.
+#[derive(ProtoBuf, Default)]
+pub struct SignInWithProviderRequest {
+    #[pb(index = 1)]
+    pub provider: AuthProvider,
+
+    #[pb(index = 2)]
+    pub code: String,
+
+    #[pb(index = 3)]
+    pub code_verifier: String,
+
+    #[pb(index = 4)]
+    pub redirect_uri: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct SignInWithProviderParams {
+    #[pb(index = 1)]
+    pub provider: AuthProvider,
+
+    #[pb(index = 2)]
+    pub code: String,
+
+    #[pb(index = 3)]
+    pub code_verifier: String,
+
+    #[pb(index = 4)]
+    pub redirect_uri: String,
+}
+
+impl TryInto<SignInWithProviderParams> for SignInWithProviderRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SignInWithProviderParams, Self::Error> {
+        let code = AuthCode::parse(self.code)?;
+        let code_verifier = AuthCodeVerifier::parse(self.code_verifier)?;
+
+        Ok(SignInWithProviderParams {
+            provider: self.provider,
+            code: code.0,
+            code_verifier: code_verifier.0,
+            redirect_uri: self.redirect_uri,
+        })
+    }
 }
@@ -0,0 +1,28 @@
+use std::convert::TryInto;
+
+use flowy_derive::ProtoBuf;
+
+use crate::{errors::*, parser::*};
+
+// The verify token is sent to the email of the signed-in user, the email is verified after the token is
+// confirmed.
+#[derive(ProtoBuf, Default)]
+pub struct ConfirmEmailVerifyRequest {
+    #[pb(index = 1)]
+    pub token: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct ConfirmEmailVerifyParams {
+    #[pb(index = 1)]
+    pub token: String,
+}
+
+impl TryInto<ConfirmEmailVerifyParams> for ConfirmEmailVerifyRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ConfirmEmailVerifyParams, Self::Error> {
+        let token = EmailVerifyToken::parse(self.token)?;
+        Ok(ConfirmEmailVerifyParams { token: token.0 })
+    }
+}
@@ -0,0 +1,33 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum LogLevel {
+    Error = 0,
+    Warn  = 1,
+    Info  = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+impl std::default::Default for LogLevel {
+    fn default() -> Self { LogLevel::Info }
+}
+
+impl LogLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+// The level applies to the logs of all the modules until the app restarts
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct SetLogLevelRequest {
+    #[pb(index = 1)]
+    pub level: LogLevel,
+}
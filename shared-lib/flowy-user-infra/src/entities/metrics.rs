@@ -0,0 +1,79 @@
+use flowy_derive::ProtoBuf;
+
+// The metrics that were recorded since the app started, they are empty unless the metrics are enabled
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct MetricsSnapshot {
+    #[pb(index = 1)]
+    pub counters: Vec<CounterMetric>,
+
+    #[pb(index = 2)]
+    pub latencies: Vec<LatencyMetric>,
+
+    // The same metrics in the Prometheus text format, e.g. to attach to the bug report
+    #[pb(index = 3)]
+    pub prometheus: String,
+
+    // The blocking pool of the SDK, it's recorded even if the metrics are disabled
+    #[pb(index = 4)]
+    pub blocking_pool: BlockingPoolMetric,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CounterMetric {
+    #[pb(index = 1)]
+    pub name: String,
+
+    // e.g. event="SignIn"
+    #[pb(index = 2)]
+    pub labels: String,
+
+    #[pb(index = 3)]
+    pub value: i64,
+}
+
+// The percentiles are the upper bounds of the buckets that they fall into
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct LatencyMetric {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub labels: String,
+
+    #[pb(index = 3)]
+    pub count: i64,
+
+    #[pb(index = 4)]
+    pub sum_ms: i64,
+
+    #[pb(index = 5)]
+    pub p50_ms: i64,
+
+    #[pb(index = 6)]
+    pub p95_ms: i64,
+
+    #[pb(index = 7)]
+    pub max_ms: i64,
+}
+
+// A task is starved if it waited more than 100ms for a free thread of the pool
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct BlockingPoolMetric {
+    #[pb(index = 1)]
+    pub size: i64,
+
+    #[pb(index = 2)]
+    pub waiting: i64,
+
+    #[pb(index = 3)]
+    pub running: i64,
+
+    #[pb(index = 4)]
+    pub completed: i64,
+
+    #[pb(index = 5)]
+    pub starved: i64,
+
+    #[pb(index = 6)]
+    pub max_wait_ms: i64,
+}
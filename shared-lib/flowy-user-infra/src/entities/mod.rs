@@ -1,9 +1,30 @@
 pub use auth::*;
+pub use email_verify::*;
+pub use log_level::*;
+pub use metrics::*;
+pub use network_state::*;
+pub use password_reset::*;
 pub use user_profile::*;
+pub use user_setting::*;
 
 pub mod auth;
+mod email_verify;
+mod log_level;
+mod metrics;
+mod network_state;
+mod password_reset;
 mod user_profile;
+mod user_setting;
 
 pub mod prelude {
-    pub use crate::entities::{auth::*, user_profile::*};
+    pub use crate::entities::{
+        auth::*,
+        email_verify::*,
+        log_level::*,
+        metrics::*,
+        network_state::*,
+        password_reset::*,
+        user_profile::*,
+        user_setting::*,
+    };
 }
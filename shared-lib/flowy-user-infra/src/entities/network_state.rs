@@ -0,0 +1,28 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum NetworkStatus {
+    // The reconnection gave up, the changes are kept locally until the app connects again
+    Offline = 0,
+    Connecting = 1,
+    Online = 2,
+}
+
+impl std::default::Default for NetworkStatus {
+    fn default() -> Self { NetworkStatus::Offline }
+}
+
+// Sent with the NetworkStateChanged notification when the websocket connection changes
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct NetworkState {
+    #[pb(index = 1)]
+    pub status: NetworkStatus,
+
+    // The number of the reconnect attempt, it's 0 unless the status is Connecting
+    #[pb(index = 2)]
+    pub attempt: i64,
+}
+
+impl NetworkState {
+    pub fn new(status: NetworkStatus, attempt: i64) -> Self { Self { status, attempt } }
+}
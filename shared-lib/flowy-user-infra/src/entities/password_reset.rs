@@ -0,0 +1,60 @@
+use std::convert::TryInto;
+
+use flowy_derive::ProtoBuf;
+
+use crate::{errors::*, parser::*};
+
+// The reset token is sent to the email if the user exists, the response is the same either way so the
+// request doesn't tell whether the email is registered.
+#[derive(ProtoBuf, Default)]
+pub struct RequestPasswordResetRequest {
+    #[pb(index = 1)]
+    pub email: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct RequestPasswordResetParams {
+    #[pb(index = 1)]
+    pub email: String,
+}
+
+impl TryInto<RequestPasswordResetParams> for RequestPasswordResetRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RequestPasswordResetParams, Self::Error> {
+        let email = UserEmail::parse(self.email)?;
+        Ok(RequestPasswordResetParams { email: email.0 })
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct ConfirmPasswordResetRequest {
+    #[pb(index = 1)]
+    pub token: String,
+
+    #[pb(index = 2)]
+    pub new_password: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct ConfirmPasswordResetParams {
+    #[pb(index = 1)]
+    pub token: String,
+
+    #[pb(index = 2)]
+    pub new_password: String,
+}
+
+impl TryInto<ConfirmPasswordResetParams> for ConfirmPasswordResetRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ConfirmPasswordResetParams, Self::Error> {
+        let token = PasswordResetToken::parse(self.token)?;
+        let new_password = UserPassword::parse(self.new_password)?;
+
+        Ok(ConfirmPasswordResetParams {
+            token: token.0,
+            new_password: new_password.0,
+        })
+    }
+}
@@ -25,6 +25,9 @@ pub struct UserProfile {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub email_verified: bool,
 }
 
 #[derive(ProtoBuf, Default)]
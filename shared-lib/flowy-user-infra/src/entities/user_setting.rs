@@ -0,0 +1,13 @@
+use flowy_derive::ProtoBuf;
+
+// The user-level settings in JSON, it's exported on one machine and imported on another. The json is
+// an object with the version and the settings of each section, e.g. the input rules of the editor.
+#[derive(ProtoBuf, Default, Debug, PartialEq, Eq, Clone)]
+pub struct UserSettingsProfile {
+    #[pb(index = 1)]
+    pub json: String,
+}
+
+impl UserSettingsProfile {
+    pub fn new(json: &str) -> Self { Self { json: json.to_owned() } }
+}
@@ -34,6 +34,26 @@ pub enum ErrorCode {
     UserUnauthorized   = 24,
     #[display(fmt = "User not exist")]
     UserNotExist       = 25,
+    #[display(fmt = "The settings profile is invalid")]
+    SettingsProfileInvalid = 30,
+    #[display(fmt = "The authorization code can not be empty or whitespace")]
+    AuthCodeIsEmpty    = 40,
+    #[display(fmt = "The code verifier should contain 43 to 128 unreserved characters")]
+    AuthCodeVerifierInvalid = 41,
+    #[display(fmt = "The password reset token can not be empty or whitespace")]
+    PasswordResetTokenIsEmpty = 50,
+    #[display(fmt = "The password reset token is invalid or expired")]
+    PasswordResetTokenInvalid = 51,
+    #[display(fmt = "The email verify token can not be empty or whitespace")]
+    EmailVerifyTokenIsEmpty = 52,
+    #[display(fmt = "The email verify token is invalid or expired")]
+    EmailVerifyTokenInvalid = 53,
+
+    #[display(fmt = "Too many requests, try again later")]
+    TooManyRequests    = 97,
+
+    #[display(fmt = "The request is too large")]
+    PayloadTooLarge    = 98,
 
     #[display(fmt = "Server error")]
     ServerError        = 99,
@@ -0,0 +1,41 @@
+use crate::errors::ErrorCode;
+
+// https://datatracker.ietf.org/doc/html/rfc7636#section-4.1
+const MIN_CODE_VERIFIER_LEN: usize = 43;
+const MAX_CODE_VERIFIER_LEN: usize = 128;
+
+#[derive(Debug)]
+pub struct AuthCode(pub String);
+
+impl AuthCode {
+    pub fn parse(s: String) -> Result<AuthCode, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::AuthCodeIsEmpty);
+        }
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for AuthCode {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+#[derive(Debug)]
+pub struct AuthCodeVerifier(pub String);
+
+impl AuthCodeVerifier {
+    pub fn parse(s: String) -> Result<AuthCodeVerifier, ErrorCode> {
+        let is_valid_len = s.len() >= MIN_CODE_VERIFIER_LEN && s.len() <= MAX_CODE_VERIFIER_LEN;
+        let is_unreserved = s
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~'));
+        if !is_valid_len || !is_unreserved {
+            return Err(ErrorCode::AuthCodeVerifierInvalid);
+        }
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for AuthCodeVerifier {
+    fn as_ref(&self) -> &str { &self.0 }
+}
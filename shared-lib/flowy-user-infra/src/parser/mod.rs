@@ -1,12 +1,18 @@
 // https://lexi-lambda.github.io/blog/2019/11/05/parse-don-t-validate/
+mod auth_code;
+mod reset_token;
 mod user_email;
 mod user_id;
 mod user_name;
 mod user_password;
 mod user_workspace;
+mod verify_token;
 
+pub use auth_code::*;
+pub use reset_token::*;
 pub use user_email::*;
 pub use user_id::*;
 pub use user_name::*;
 pub use user_password::*;
 pub use user_workspace::*;
+pub use verify_token::*;
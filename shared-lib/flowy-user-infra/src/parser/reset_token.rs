@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+// The token is signed by the server, it's only checked there
+#[derive(Debug)]
+pub struct PasswordResetToken(pub String);
+
+impl PasswordResetToken {
+    pub fn parse(s: String) -> Result<PasswordResetToken, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::PasswordResetTokenIsEmpty);
+        }
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for PasswordResetToken {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+
+// The token is signed by the server, it's only checked there
+#[derive(Debug)]
+pub struct EmailVerifyToken(pub String);
+
+impl EmailVerifyToken {
+    pub fn parse(s: String) -> Result<EmailVerifyToken, ErrorCode> {
+        if s.trim().is_empty() {
+            return Err(ErrorCode::EmailVerifyTokenIsEmpty);
+        }
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for EmailVerifyToken {
+    fn as_ref(&self) -> &str { &self.0 }
+}
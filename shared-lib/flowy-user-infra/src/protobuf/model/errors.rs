@@ -39,6 +39,15 @@ pub enum ErrorCode {
     UserIdInvalid = 23,
     UserUnauthorized = 24,
     UserNotExist = 25,
+    SettingsProfileInvalid = 30,
+    AuthCodeIsEmpty = 40,
+    AuthCodeVerifierInvalid = 41,
+    PasswordResetTokenIsEmpty = 50,
+    PasswordResetTokenInvalid = 51,
+    EmailVerifyTokenIsEmpty = 52,
+    EmailVerifyTokenInvalid = 53,
+    TooManyRequests = 97,
+    PayloadTooLarge = 98,
     ServerError = 99,
     InternalError = 100,
 }
@@ -64,6 +73,15 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             23 => ::std::option::Option::Some(ErrorCode::UserIdInvalid),
             24 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
             25 => ::std::option::Option::Some(ErrorCode::UserNotExist),
+            30 => ::std::option::Option::Some(ErrorCode::SettingsProfileInvalid),
+            40 => ::std::option::Option::Some(ErrorCode::AuthCodeIsEmpty),
+            41 => ::std::option::Option::Some(ErrorCode::AuthCodeVerifierInvalid),
+            50 => ::std::option::Option::Some(ErrorCode::PasswordResetTokenIsEmpty),
+            51 => ::std::option::Option::Some(ErrorCode::PasswordResetTokenInvalid),
+            52 => ::std::option::Option::Some(ErrorCode::EmailVerifyTokenIsEmpty),
+            53 => ::std::option::Option::Some(ErrorCode::EmailVerifyTokenInvalid),
+            97 => ::std::option::Option::Some(ErrorCode::TooManyRequests),
+            98 => ::std::option::Option::Some(ErrorCode::PayloadTooLarge),
             99 => ::std::option::Option::Some(ErrorCode::ServerError),
             100 => ::std::option::Option::Some(ErrorCode::InternalError),
             _ => ::std::option::Option::None
@@ -86,6 +104,15 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::UserIdInvalid,
             ErrorCode::UserUnauthorized,
             ErrorCode::UserNotExist,
+            ErrorCode::SettingsProfileInvalid,
+            ErrorCode::AuthCodeIsEmpty,
+            ErrorCode::AuthCodeVerifierInvalid,
+            ErrorCode::PasswordResetTokenIsEmpty,
+            ErrorCode::PasswordResetTokenInvalid,
+            ErrorCode::EmailVerifyTokenIsEmpty,
+            ErrorCode::EmailVerifyTokenInvalid,
+            ErrorCode::TooManyRequests,
+            ErrorCode::PayloadTooLarge,
             ErrorCode::ServerError,
             ErrorCode::InternalError,
         ];
@@ -116,7 +143,7 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cerrors.proto*\xff\x02\n\tErrorCode\x12\x10\n\x0cEmailIsEmpty\x10\0\
+    \n\x0cerrors.proto*\xef\x04\n\tErrorCode\x12\x10\n\x0cEmailIsEmpty\x10\0\
     \x12\x16\n\x12EmailFormatInvalid\x10\x01\x12\x16\n\x12EmailAlreadyExists\
     \x10\x02\x12\x13\n\x0fPasswordIsEmpty\x10\n\x12\x13\n\x0fPasswordTooLong\
     \x10\x0b\x12$\n\x20PasswordContainsForbidCharacters\x10\x0c\x12\x19\n\
@@ -124,9 +151,14 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x12\x13\n\x0fUserNameTooLong\x10\x14\x12&\n\"UserNameContainForbiddenCh\
     aracters\x10\x15\x12\x13\n\x0fUserNameIsEmpty\x10\x16\x12\x11\n\rUserIdI\
     nvalid\x10\x17\x12\x14\n\x10UserUnauthorized\x10\x18\x12\x10\n\x0cUserNo\
-    tExist\x10\x19\x12\x0f\n\x0bServerError\x10c\x12\x11\n\rInternalError\
-    \x10dJ\xba\x05\n\x06\x12\x04\0\0\x13\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
-    \n\n\n\x02\x05\0\x12\x04\x02\0\x13\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\
+    tExist\x10\x19\x12\x1a\n\x16SettingsProfileInvalid\x10\x1e\x12\x13\n\x0f\
+    AuthCodeIsEmpty\x10(\x12\x1b\n\x17AuthCodeVerifierInvalid\x10)\x12\x1d\n\
+    \x19PasswordResetTokenIsEmpty\x102\x12\x1d\n\x19PasswordResetTokenInvali\
+    d\x103\x12\x1b\n\x17EmailVerifyTokenIsEmpty\x104\x12\x1b\n\x17EmailVerif\
+    yTokenInvalid\x105\x12\x13\n\x0fTooManyRequests\x10a\x12\x13\n\x0fPayloa\
+    dTooLarge\x10b\x12\x0f\n\x0bServerError\x10c\x12\x11\n\rInternalError\
+    \x10dJ\xab\x08\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
+    \n\n\n\x02\x05\0\x12\x04\x02\0\x1c\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\
     \x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x15\n\x0c\n\x05\x05\0\
     \x02\0\x01\x12\x03\x03\x04\x10\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\
     \x13\x14\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x1b\n\x0c\n\x05\x05\0\
@@ -155,11 +187,29 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x02\x0c\x01\x12\x03\x0f\x04\x14\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\
     \x0f\x17\x19\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x16\n\x0c\n\x05\x05\
     \0\x02\r\x01\x12\x03\x10\x04\x10\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\
-    \x13\x15\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x15\n\x0c\n\x05\x05\0\
-    \x02\x0e\x01\x12\x03\x11\x04\x0f\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\
-    \x11\x12\x14\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x18\n\x0c\n\x05\
-    \x05\0\x02\x0f\x01\x12\x03\x12\x04\x11\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\
-    \x03\x12\x14\x17b\x06proto3\
+    \x13\x15\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x20\n\x0c\n\x05\x05\0\
+    \x02\x0e\x01\x12\x03\x11\x04\x1a\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\
+    \x11\x1d\x1f\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x19\n\x0c\n\x05\
+    \x05\0\x02\x0f\x01\x12\x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\
+    \x03\x12\x16\x18\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04!\n\x0c\n\x05\
+    \x05\0\x02\x10\x01\x12\x03\x13\x04\x1b\n\x0c\n\x05\x05\0\x02\x10\x02\x12\
+    \x03\x13\x1e\x20\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x14\x04#\n\x0c\n\x05\
+    \x05\0\x02\x11\x01\x12\x03\x14\x04\x1d\n\x0c\n\x05\x05\0\x02\x11\x02\x12\
+    \x03\x14\x20\"\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x15\x04#\n\x0c\n\x05\
+    \x05\0\x02\x12\x01\x12\x03\x15\x04\x1d\n\x0c\n\x05\x05\0\x02\x12\x02\x12\
+    \x03\x15\x20\"\n\x0b\n\x04\x05\0\x02\x13\x12\x03\x16\x04!\n\x0c\n\x05\
+    \x05\0\x02\x13\x01\x12\x03\x16\x04\x1b\n\x0c\n\x05\x05\0\x02\x13\x02\x12\
+    \x03\x16\x1e\x20\n\x0b\n\x04\x05\0\x02\x14\x12\x03\x17\x04!\n\x0c\n\x05\
+    \x05\0\x02\x14\x01\x12\x03\x17\x04\x1b\n\x0c\n\x05\x05\0\x02\x14\x02\x12\
+    \x03\x17\x1e\x20\n\x0b\n\x04\x05\0\x02\x15\x12\x03\x18\x04\x19\n\x0c\n\
+    \x05\x05\0\x02\x15\x01\x12\x03\x18\x04\x13\n\x0c\n\x05\x05\0\x02\x15\x02\
+    \x12\x03\x18\x16\x18\n\x0b\n\x04\x05\0\x02\x16\x12\x03\x19\x04\x19\n\x0c\
+    \n\x05\x05\0\x02\x16\x01\x12\x03\x19\x04\x13\n\x0c\n\x05\x05\0\x02\x16\
+    \x02\x12\x03\x19\x16\x18\n\x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\x04\x15\n\
+    \x0c\n\x05\x05\0\x02\x17\x01\x12\x03\x1a\x04\x0f\n\x0c\n\x05\x05\0\x02\
+    \x17\x02\x12\x03\x1a\x12\x14\n\x0b\n\x04\x05\0\x02\x18\x12\x03\x1b\x04\
+    \x18\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03\x1b\x04\x11\n\x0c\n\x05\x05\0\
+    \x02\x18\x02\x12\x03\x1b\x14\x17b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,266 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `log_level.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetLogLevelRequest {
+    // message fields
+    pub level: LogLevel,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetLogLevelRequest {
+    fn default() -> &'a SetLogLevelRequest {
+        <SetLogLevelRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetLogLevelRequest {
+    pub fn new() -> SetLogLevelRequest {
+        ::std::default::Default::default()
+    }
+
+    // .LogLevel level = 1;
+
+
+    pub fn get_level(&self) -> LogLevel {
+        self.level
+    }
+    pub fn clear_level(&mut self) {
+        self.level = LogLevel::Error;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_level(&mut self, v: LogLevel) {
+        self.level = v;
+    }
+}
+
+impl ::protobuf::Message for SetLogLevelRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.level, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.level != LogLevel::Error {
+            my_size += ::protobuf::rt::enum_size(1, self.level);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.level != LogLevel::Error {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.level))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetLogLevelRequest {
+        SetLogLevelRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<LogLevel>>(
+                "level",
+                |m: &SetLogLevelRequest| { &m.level },
+                |m: &mut SetLogLevelRequest| { &mut m.level },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetLogLevelRequest>(
+                "SetLogLevelRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetLogLevelRequest {
+        static instance: ::protobuf::rt::LazyV2<SetLogLevelRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetLogLevelRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetLogLevelRequest {
+    fn clear(&mut self) {
+        self.level = LogLevel::Error;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetLogLevelRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetLogLevelRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+impl ::protobuf::ProtobufEnum for LogLevel {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<LogLevel> {
+        match value {
+            0 => ::std::option::Option::Some(LogLevel::Error),
+            1 => ::std::option::Option::Some(LogLevel::Warn),
+            2 => ::std::option::Option::Some(LogLevel::Info),
+            3 => ::std::option::Option::Some(LogLevel::Debug),
+            4 => ::std::option::Option::Some(LogLevel::Trace),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [LogLevel] = &[
+            LogLevel::Error,
+            LogLevel::Warn,
+            LogLevel::Info,
+            LogLevel::Debug,
+            LogLevel::Trace,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<LogLevel>("LogLevel", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for LogLevel {
+}
+
+impl ::std::default::Default for LogLevel {
+    fn default() -> Self {
+        LogLevel::Error
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogLevel {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0flog_level.proto\"5\n\x12SetLogLevelRequest\x12\x1f\n\x05level\x18\
+    \x01\x20\x01(\x0e2\t.LogLevelR\x05level*?\n\x08LogLevel\x12\t\n\x05Error\
+    \x10\0\x12\x08\n\x04Warn\x10\x01\x12\x08\n\x04Info\x10\x02\x12\t\n\x05De\
+    bug\x10\x03\x12\t\n\x05Trace\x10\x04J\xc6\x02\n\x06\x12\x04\0\0\x0b\x01\
+    \n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\
+    \n\n\x03\x04\0\x01\x12\x03\x02\x08\x1a\n\x0b\n\x04\x04\0\x02\0\x12\x03\
+    \x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\x0c\n\x0c\n\x05\
+    \x04\0\x02\0\x01\x12\x03\x03\r\x12\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\
+    \x03\x15\x16\n\n\n\x02\x05\0\x12\x04\x05\0\x0b\x01\n\n\n\x03\x05\0\x01\
+    \x12\x03\x05\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x06\x04\x0e\n\x0c\n\
+    \x05\x05\0\x02\0\x01\x12\x03\x06\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\
+    \x03\x06\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x07\x04\r\n\x0c\n\x05\
+    \x05\0\x02\x01\x01\x12\x03\x07\x04\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\
+    \x03\x07\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x08\x04\r\n\x0c\n\x05\
+    \x05\0\x02\x02\x01\x12\x03\x08\x04\x08\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
+    \x03\x08\x0b\x0c\n\x0b\n\x04\x05\0\x02\x03\x12\x03\t\x04\x0e\n\x0c\n\x05\
+    \x05\0\x02\x03\x01\x12\x03\t\x04\t\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\
+    \t\x0c\r\n\x0b\n\x04\x05\0\x02\x04\x12\x03\n\x04\x0e\n\x0c\n\x05\x05\0\
+    \x02\x04\x01\x12\x03\n\x04\t\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\n\x0c\
+    \rb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
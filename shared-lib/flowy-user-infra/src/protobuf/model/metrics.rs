@@ -0,0 +1,1372 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `metrics.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MetricsSnapshot {
+    // message fields
+    pub counters: ::protobuf::RepeatedField<CounterMetric>,
+    pub latencies: ::protobuf::RepeatedField<LatencyMetric>,
+    pub prometheus: ::std::string::String,
+    pub blocking_pool: ::protobuf::SingularPtrField<BlockingPoolMetric>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MetricsSnapshot {
+    fn default() -> &'a MetricsSnapshot {
+        <MetricsSnapshot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MetricsSnapshot {
+    pub fn new() -> MetricsSnapshot {
+        ::std::default::Default::default()
+    }
+
+    // repeated .CounterMetric counters = 1;
+
+
+    pub fn get_counters(&self) -> &[CounterMetric] {
+        &self.counters
+    }
+    pub fn clear_counters(&mut self) {
+        self.counters.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counters(&mut self, v: ::protobuf::RepeatedField<CounterMetric>) {
+        self.counters = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_counters(&mut self) -> &mut ::protobuf::RepeatedField<CounterMetric> {
+        &mut self.counters
+    }
+
+    // Take field
+    pub fn take_counters(&mut self) -> ::protobuf::RepeatedField<CounterMetric> {
+        ::std::mem::replace(&mut self.counters, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .LatencyMetric latencies = 2;
+
+
+    pub fn get_latencies(&self) -> &[LatencyMetric] {
+        &self.latencies
+    }
+    pub fn clear_latencies(&mut self) {
+        self.latencies.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_latencies(&mut self, v: ::protobuf::RepeatedField<LatencyMetric>) {
+        self.latencies = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_latencies(&mut self) -> &mut ::protobuf::RepeatedField<LatencyMetric> {
+        &mut self.latencies
+    }
+
+    // Take field
+    pub fn take_latencies(&mut self) -> ::protobuf::RepeatedField<LatencyMetric> {
+        ::std::mem::replace(&mut self.latencies, ::protobuf::RepeatedField::new())
+    }
+
+    // string prometheus = 3;
+
+
+    pub fn get_prometheus(&self) -> &str {
+        &self.prometheus
+    }
+    pub fn clear_prometheus(&mut self) {
+        self.prometheus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prometheus(&mut self, v: ::std::string::String) {
+        self.prometheus = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prometheus(&mut self) -> &mut ::std::string::String {
+        &mut self.prometheus
+    }
+
+    // Take field
+    pub fn take_prometheus(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.prometheus, ::std::string::String::new())
+    }
+
+    // .BlockingPoolMetric blocking_pool = 4;
+
+
+    pub fn get_blocking_pool(&self) -> &BlockingPoolMetric {
+        self.blocking_pool.as_ref().unwrap_or_else(|| <BlockingPoolMetric as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_blocking_pool(&mut self) {
+        self.blocking_pool.clear();
+    }
+
+    pub fn has_blocking_pool(&self) -> bool {
+        self.blocking_pool.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blocking_pool(&mut self, v: BlockingPoolMetric) {
+        self.blocking_pool = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_blocking_pool(&mut self) -> &mut BlockingPoolMetric {
+        if self.blocking_pool.is_none() {
+            self.blocking_pool.set_default();
+        }
+        self.blocking_pool.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_blocking_pool(&mut self) -> BlockingPoolMetric {
+        self.blocking_pool.take().unwrap_or_else(|| BlockingPoolMetric::new())
+    }
+}
+
+impl ::protobuf::Message for MetricsSnapshot {
+    fn is_initialized(&self) -> bool {
+        for v in &self.counters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.latencies {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.blocking_pool {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.counters)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.latencies)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.prometheus)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.blocking_pool)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.counters {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.latencies {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.prometheus.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.prometheus);
+        }
+        if let Some(ref v) = self.blocking_pool.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.counters {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.latencies {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.prometheus.is_empty() {
+            os.write_string(3, &self.prometheus)?;
+        }
+        if let Some(ref v) = self.blocking_pool.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MetricsSnapshot {
+        MetricsSnapshot::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CounterMetric>>(
+                "counters",
+                |m: &MetricsSnapshot| { &m.counters },
+                |m: &mut MetricsSnapshot| { &mut m.counters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LatencyMetric>>(
+                "latencies",
+                |m: &MetricsSnapshot| { &m.latencies },
+                |m: &mut MetricsSnapshot| { &mut m.latencies },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "prometheus",
+                |m: &MetricsSnapshot| { &m.prometheus },
+                |m: &mut MetricsSnapshot| { &mut m.prometheus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BlockingPoolMetric>>(
+                "blocking_pool",
+                |m: &MetricsSnapshot| { &m.blocking_pool },
+                |m: &mut MetricsSnapshot| { &mut m.blocking_pool },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MetricsSnapshot>(
+                "MetricsSnapshot",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MetricsSnapshot {
+        static instance: ::protobuf::rt::LazyV2<MetricsSnapshot> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MetricsSnapshot::new)
+    }
+}
+
+impl ::protobuf::Clear for MetricsSnapshot {
+    fn clear(&mut self) {
+        self.counters.clear();
+        self.latencies.clear();
+        self.prometheus.clear();
+        self.blocking_pool.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MetricsSnapshot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MetricsSnapshot {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CounterMetric {
+    // message fields
+    pub name: ::std::string::String,
+    pub labels: ::std::string::String,
+    pub value: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CounterMetric {
+    fn default() -> &'a CounterMetric {
+        <CounterMetric as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CounterMetric {
+    pub fn new() -> CounterMetric {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string labels = 2;
+
+
+    pub fn get_labels(&self) -> &str {
+        &self.labels
+    }
+    pub fn clear_labels(&mut self) {
+        self.labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_labels(&mut self, v: ::std::string::String) {
+        self.labels = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_labels(&mut self) -> &mut ::std::string::String {
+        &mut self.labels
+    }
+
+    // Take field
+    pub fn take_labels(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.labels, ::std::string::String::new())
+    }
+
+    // int64 value = 3;
+
+
+    pub fn get_value(&self) -> i64 {
+        self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: i64) {
+        self.value = v;
+    }
+}
+
+impl ::protobuf::Message for CounterMetric {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.labels)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.value = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.labels.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.labels);
+        }
+        if self.value != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.value, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.labels.is_empty() {
+            os.write_string(2, &self.labels)?;
+        }
+        if self.value != 0 {
+            os.write_int64(3, self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CounterMetric {
+        CounterMetric::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &CounterMetric| { &m.name },
+                |m: &mut CounterMetric| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "labels",
+                |m: &CounterMetric| { &m.labels },
+                |m: &mut CounterMetric| { &mut m.labels },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "value",
+                |m: &CounterMetric| { &m.value },
+                |m: &mut CounterMetric| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CounterMetric>(
+                "CounterMetric",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CounterMetric {
+        static instance: ::protobuf::rt::LazyV2<CounterMetric> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CounterMetric::new)
+    }
+}
+
+impl ::protobuf::Clear for CounterMetric {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.labels.clear();
+        self.value = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CounterMetric {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CounterMetric {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LatencyMetric {
+    // message fields
+    pub name: ::std::string::String,
+    pub labels: ::std::string::String,
+    pub count: i64,
+    pub sum_ms: i64,
+    pub p50_ms: i64,
+    pub p95_ms: i64,
+    pub max_ms: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LatencyMetric {
+    fn default() -> &'a LatencyMetric {
+        <LatencyMetric as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LatencyMetric {
+    pub fn new() -> LatencyMetric {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string labels = 2;
+
+
+    pub fn get_labels(&self) -> &str {
+        &self.labels
+    }
+    pub fn clear_labels(&mut self) {
+        self.labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_labels(&mut self, v: ::std::string::String) {
+        self.labels = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_labels(&mut self) -> &mut ::std::string::String {
+        &mut self.labels
+    }
+
+    // Take field
+    pub fn take_labels(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.labels, ::std::string::String::new())
+    }
+
+    // int64 count = 3;
+
+
+    pub fn get_count(&self) -> i64 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i64) {
+        self.count = v;
+    }
+
+    // int64 sum_ms = 4;
+
+
+    pub fn get_sum_ms(&self) -> i64 {
+        self.sum_ms
+    }
+    pub fn clear_sum_ms(&mut self) {
+        self.sum_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sum_ms(&mut self, v: i64) {
+        self.sum_ms = v;
+    }
+
+    // int64 p50_ms = 5;
+
+
+    pub fn get_p50_ms(&self) -> i64 {
+        self.p50_ms
+    }
+    pub fn clear_p50_ms(&mut self) {
+        self.p50_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_p50_ms(&mut self, v: i64) {
+        self.p50_ms = v;
+    }
+
+    // int64 p95_ms = 6;
+
+
+    pub fn get_p95_ms(&self) -> i64 {
+        self.p95_ms
+    }
+    pub fn clear_p95_ms(&mut self) {
+        self.p95_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_p95_ms(&mut self, v: i64) {
+        self.p95_ms = v;
+    }
+
+    // int64 max_ms = 7;
+
+
+    pub fn get_max_ms(&self) -> i64 {
+        self.max_ms
+    }
+    pub fn clear_max_ms(&mut self) {
+        self.max_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_ms(&mut self, v: i64) {
+        self.max_ms = v;
+    }
+}
+
+impl ::protobuf::Message for LatencyMetric {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.labels)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.sum_ms = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.p50_ms = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.p95_ms = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.max_ms = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.labels.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.labels);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.sum_ms != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.sum_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.p50_ms != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.p50_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.p95_ms != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.p95_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_ms != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.max_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.labels.is_empty() {
+            os.write_string(2, &self.labels)?;
+        }
+        if self.count != 0 {
+            os.write_int64(3, self.count)?;
+        }
+        if self.sum_ms != 0 {
+            os.write_int64(4, self.sum_ms)?;
+        }
+        if self.p50_ms != 0 {
+            os.write_int64(5, self.p50_ms)?;
+        }
+        if self.p95_ms != 0 {
+            os.write_int64(6, self.p95_ms)?;
+        }
+        if self.max_ms != 0 {
+            os.write_int64(7, self.max_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LatencyMetric {
+        LatencyMetric::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &LatencyMetric| { &m.name },
+                |m: &mut LatencyMetric| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "labels",
+                |m: &LatencyMetric| { &m.labels },
+                |m: &mut LatencyMetric| { &mut m.labels },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "count",
+                |m: &LatencyMetric| { &m.count },
+                |m: &mut LatencyMetric| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "sum_ms",
+                |m: &LatencyMetric| { &m.sum_ms },
+                |m: &mut LatencyMetric| { &mut m.sum_ms },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "p50_ms",
+                |m: &LatencyMetric| { &m.p50_ms },
+                |m: &mut LatencyMetric| { &mut m.p50_ms },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "p95_ms",
+                |m: &LatencyMetric| { &m.p95_ms },
+                |m: &mut LatencyMetric| { &mut m.p95_ms },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "max_ms",
+                |m: &LatencyMetric| { &m.max_ms },
+                |m: &mut LatencyMetric| { &mut m.max_ms },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LatencyMetric>(
+                "LatencyMetric",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LatencyMetric {
+        static instance: ::protobuf::rt::LazyV2<LatencyMetric> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LatencyMetric::new)
+    }
+}
+
+impl ::protobuf::Clear for LatencyMetric {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.labels.clear();
+        self.count = 0;
+        self.sum_ms = 0;
+        self.p50_ms = 0;
+        self.p95_ms = 0;
+        self.max_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LatencyMetric {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LatencyMetric {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlockingPoolMetric {
+    // message fields
+    pub size: i64,
+    pub waiting: i64,
+    pub running: i64,
+    pub completed: i64,
+    pub starved: i64,
+    pub max_wait_ms: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlockingPoolMetric {
+    fn default() -> &'a BlockingPoolMetric {
+        <BlockingPoolMetric as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlockingPoolMetric {
+    pub fn new() -> BlockingPoolMetric {
+        ::std::default::Default::default()
+    }
+
+    // int64 size = 1;
+
+
+    pub fn get_size(&self) -> i64 {
+        self.size
+    }
+    pub fn clear_size(&mut self) {
+        self.size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: i64) {
+        self.size = v;
+    }
+
+    // int64 waiting = 2;
+
+
+    pub fn get_waiting(&self) -> i64 {
+        self.waiting
+    }
+    pub fn clear_waiting(&mut self) {
+        self.waiting = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_waiting(&mut self, v: i64) {
+        self.waiting = v;
+    }
+
+    // int64 running = 3;
+
+
+    pub fn get_running(&self) -> i64 {
+        self.running
+    }
+    pub fn clear_running(&mut self) {
+        self.running = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_running(&mut self, v: i64) {
+        self.running = v;
+    }
+
+    // int64 completed = 4;
+
+
+    pub fn get_completed(&self) -> i64 {
+        self.completed
+    }
+    pub fn clear_completed(&mut self) {
+        self.completed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_completed(&mut self, v: i64) {
+        self.completed = v;
+    }
+
+    // int64 starved = 5;
+
+
+    pub fn get_starved(&self) -> i64 {
+        self.starved
+    }
+    pub fn clear_starved(&mut self) {
+        self.starved = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_starved(&mut self, v: i64) {
+        self.starved = v;
+    }
+
+    // int64 max_wait_ms = 6;
+
+
+    pub fn get_max_wait_ms(&self) -> i64 {
+        self.max_wait_ms
+    }
+    pub fn clear_max_wait_ms(&mut self) {
+        self.max_wait_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_wait_ms(&mut self, v: i64) {
+        self.max_wait_ms = v;
+    }
+}
+
+impl ::protobuf::Message for BlockingPoolMetric {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.size = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.waiting = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.running = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.completed = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.starved = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.max_wait_ms = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.waiting != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.waiting, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.running != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.running, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.completed != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.completed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.starved != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.starved, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_wait_ms != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.max_wait_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.size != 0 {
+            os.write_int64(1, self.size)?;
+        }
+        if self.waiting != 0 {
+            os.write_int64(2, self.waiting)?;
+        }
+        if self.running != 0 {
+            os.write_int64(3, self.running)?;
+        }
+        if self.completed != 0 {
+            os.write_int64(4, self.completed)?;
+        }
+        if self.starved != 0 {
+            os.write_int64(5, self.starved)?;
+        }
+        if self.max_wait_ms != 0 {
+            os.write_int64(6, self.max_wait_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlockingPoolMetric {
+        BlockingPoolMetric::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "size",
+                |m: &BlockingPoolMetric| { &m.size },
+                |m: &mut BlockingPoolMetric| { &mut m.size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "waiting",
+                |m: &BlockingPoolMetric| { &m.waiting },
+                |m: &mut BlockingPoolMetric| { &mut m.waiting },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "running",
+                |m: &BlockingPoolMetric| { &m.running },
+                |m: &mut BlockingPoolMetric| { &mut m.running },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "completed",
+                |m: &BlockingPoolMetric| { &m.completed },
+                |m: &mut BlockingPoolMetric| { &mut m.completed },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "starved",
+                |m: &BlockingPoolMetric| { &m.starved },
+                |m: &mut BlockingPoolMetric| { &mut m.starved },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "max_wait_ms",
+                |m: &BlockingPoolMetric| { &m.max_wait_ms },
+                |m: &mut BlockingPoolMetric| { &mut m.max_wait_ms },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlockingPoolMetric>(
+                "BlockingPoolMetric",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlockingPoolMetric {
+        static instance: ::protobuf::rt::LazyV2<BlockingPoolMetric> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlockingPoolMetric::new)
+    }
+}
+
+impl ::protobuf::Clear for BlockingPoolMetric {
+    fn clear(&mut self) {
+        self.size = 0;
+        self.waiting = 0;
+        self.running = 0;
+        self.completed = 0;
+        self.starved = 0;
+        self.max_wait_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlockingPoolMetric {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlockingPoolMetric {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rmetrics.proto\"\xc5\x01\n\x0fMetricsSnapshot\x12*\n\x08counters\x18\
+    \x01\x20\x03(\x0b2\x0e.CounterMetricR\x08counters\x12,\n\tlatencies\x18\
+    \x02\x20\x03(\x0b2\x0e.LatencyMetricR\tlatencies\x12\x1e\n\nprometheus\
+    \x18\x03\x20\x01(\tR\nprometheus\x128\n\rblocking_pool\x18\x04\x20\x01(\
+    \x0b2\x13.BlockingPoolMetricR\x0cblockingPool\"Q\n\rCounterMetric\x12\
+    \x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06labels\x18\x02\
+    \x20\x01(\tR\x06labels\x12\x14\n\x05value\x18\x03\x20\x01(\x03R\x05value\
+    \"\xad\x01\n\rLatencyMetric\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04nam\
+    e\x12\x16\n\x06labels\x18\x02\x20\x01(\tR\x06labels\x12\x14\n\x05count\
+    \x18\x03\x20\x01(\x03R\x05count\x12\x15\n\x06sum_ms\x18\x04\x20\x01(\x03\
+    R\x05sumMs\x12\x15\n\x06p50_ms\x18\x05\x20\x01(\x03R\x05p50Ms\x12\x15\n\
+    \x06p95_ms\x18\x06\x20\x01(\x03R\x05p95Ms\x12\x15\n\x06max_ms\x18\x07\
+    \x20\x01(\x03R\x05maxMs\"\xb4\x01\n\x12BlockingPoolMetric\x12\x12\n\x04s\
+    ize\x18\x01\x20\x01(\x03R\x04size\x12\x18\n\x07waiting\x18\x02\x20\x01(\
+    \x03R\x07waiting\x12\x18\n\x07running\x18\x03\x20\x01(\x03R\x07running\
+    \x12\x1c\n\tcompleted\x18\x04\x20\x01(\x03R\tcompleted\x12\x18\n\x07star\
+    ved\x18\x05\x20\x01(\x03R\x07starved\x12\x1e\n\x0bmax_wait_ms\x18\x06\
+    \x20\x01(\x03R\tmaxWaitMsJ\xda\t\n\x06\x12\x04\0\0\x1d\x01\n\x08\n\x01\
+    \x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x07\x01\n\n\n\x03\x04\
+    \0\x01\x12\x03\x02\x08\x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04(\n\
+    \x0c\n\x05\x04\0\x02\0\x04\x12\x03\x03\x04\x0c\n\x0c\n\x05\x04\0\x02\0\
+    \x06\x12\x03\x03\r\x1a\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x1b#\n\
+    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03&'\n\x0b\n\x04\x04\0\x02\x01\x12\
+    \x03\x04\x04)\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x04\x04\x0c\n\x0c\n\
+    \x05\x04\0\x02\x01\x06\x12\x03\x04\r\x1a\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\x04\x1b$\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04'(\n\x0b\n\
+    \x04\x04\0\x02\x02\x12\x03\x05\x04\x1a\n\x0c\n\x05\x04\0\x02\x02\x05\x12\
+    \x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x15\n\x0c\n\
+    \x05\x04\0\x02\x02\x03\x12\x03\x05\x18\x19\n\x0b\n\x04\x04\0\x02\x03\x12\
+    \x03\x06\x04)\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03\x06\x04\x16\n\x0c\n\
+    \x05\x04\0\x02\x03\x01\x12\x03\x06\x17$\n\x0c\n\x05\x04\0\x02\x03\x03\
+    \x12\x03\x06'(\n\n\n\x02\x04\x01\x12\x04\x08\0\x0c\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\x08\x08\x15\n\x0b\n\x04\x04\x01\x02\0\x12\x03\t\x04\x14\n\
+    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x01\x12\x03\t\x0b\x0f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\x12\x13\n\
+    \x0b\n\x04\x04\x01\x02\x01\x12\x03\n\x04\x16\n\x0c\n\x05\x04\x01\x02\x01\
+    \x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\n\x0b\x11\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\n\x14\x15\n\x0b\n\x04\x04\x01\x02\
+    \x02\x12\x03\x0b\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x0b\x04\
+    \t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0b\n\x0f\n\x0c\n\x05\x04\x01\
+    \x02\x02\x03\x12\x03\x0b\x12\x13\n\n\n\x02\x04\x02\x12\x04\r\0\x15\x01\n\
+    \n\n\x03\x04\x02\x01\x12\x03\r\x08\x15\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
+    \x0e\x04\x14\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x0e\x12\x13\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\x04\x16\n\x0c\n\
+    \x05\x04\x02\x02\x01\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x01\
+    \x01\x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f\x14\
+    \x15\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x10\x04\x14\n\x0c\n\x05\x04\x02\
+    \x02\x02\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\
+    \x10\n\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x10\x12\x13\n\x0b\n\
+    \x04\x04\x02\x02\x03\x12\x03\x11\x04\x15\n\x0c\n\x05\x04\x02\x02\x03\x05\
+    \x12\x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x11\n\x10\n\
+    \x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x11\x13\x14\n\x0b\n\x04\x04\x02\
+    \x02\x04\x12\x03\x12\x04\x15\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03\x12\
+    \x04\t\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x12\n\x10\n\x0c\n\x05\x04\
+    \x02\x02\x04\x03\x12\x03\x12\x13\x14\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\
+    \x13\x04\x15\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x13\x04\t\n\x0c\n\
+    \x05\x04\x02\x02\x05\x01\x12\x03\x13\n\x10\n\x0c\n\x05\x04\x02\x02\x05\
+    \x03\x12\x03\x13\x13\x14\n\x0b\n\x04\x04\x02\x02\x06\x12\x03\x14\x04\x15\
+    \n\x0c\n\x05\x04\x02\x02\x06\x05\x12\x03\x14\x04\t\n\x0c\n\x05\x04\x02\
+    \x02\x06\x01\x12\x03\x14\n\x10\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\
+    \x14\x13\x14\n\n\n\x02\x04\x03\x12\x04\x16\0\x1d\x01\n\n\n\x03\x04\x03\
+    \x01\x12\x03\x16\x08\x1a\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x17\x04\x13\n\
+    \x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x17\x04\t\n\x0c\n\x05\x04\x03\x02\0\
+    \x01\x12\x03\x17\n\x0e\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x17\x11\x12\
+    \n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x18\x04\x16\n\x0c\n\x05\x04\x03\x02\
+    \x01\x05\x12\x03\x18\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x18\n\
+    \x11\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x18\x14\x15\n\x0b\n\x04\x04\
+    \x03\x02\x02\x12\x03\x19\x04\x16\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\
+    \x19\x04\t\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x19\n\x11\n\x0c\n\x05\
+    \x04\x03\x02\x02\x03\x12\x03\x19\x14\x15\n\x0b\n\x04\x04\x03\x02\x03\x12\
+    \x03\x1a\x04\x18\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03\x1a\x04\t\n\x0c\
+    \n\x05\x04\x03\x02\x03\x01\x12\x03\x1a\n\x13\n\x0c\n\x05\x04\x03\x02\x03\
+    \x03\x12\x03\x1a\x16\x17\n\x0b\n\x04\x04\x03\x02\x04\x12\x03\x1b\x04\x16\
+    \n\x0c\n\x05\x04\x03\x02\x04\x05\x12\x03\x1b\x04\t\n\x0c\n\x05\x04\x03\
+    \x02\x04\x01\x12\x03\x1b\n\x11\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\
+    \x1b\x14\x15\n\x0b\n\x04\x04\x03\x02\x05\x12\x03\x1c\x04\x1a\n\x0c\n\x05\
+    \x04\x03\x02\x05\x05\x12\x03\x1c\x04\t\n\x0c\n\x05\x04\x03\x02\x05\x01\
+    \x12\x03\x1c\n\x15\n\x0c\n\x05\x04\x03\x02\x05\x03\x12\x03\x1c\x18\x19b\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
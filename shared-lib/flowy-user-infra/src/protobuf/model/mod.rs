@@ -8,3 +8,21 @@ pub use user_profile::*;
 
 mod auth; 
 pub use auth::*; 
+
+mod user_setting; 
+pub use user_setting::*; 
+
+mod password_reset; 
+pub use password_reset::*; 
+
+mod email_verify; 
+pub use email_verify::*; 
+
+mod network_state; 
+pub use network_state::*; 
+
+mod log_level; 
+pub use log_level::*; 
+
+mod metrics; 
+pub use metrics::*; 
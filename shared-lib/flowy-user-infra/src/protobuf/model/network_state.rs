@@ -0,0 +1,294 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `network_state.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NetworkState {
+    // message fields
+    pub status: NetworkStatus,
+    pub attempt: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NetworkState {
+    fn default() -> &'a NetworkState {
+        <NetworkState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NetworkState {
+    pub fn new() -> NetworkState {
+        ::std::default::Default::default()
+    }
+
+    // .NetworkStatus status = 1;
+
+
+    pub fn get_status(&self) -> NetworkStatus {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = NetworkStatus::Offline;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: NetworkStatus) {
+        self.status = v;
+    }
+
+    // int64 attempt = 2;
+
+
+    pub fn get_attempt(&self) -> i64 {
+        self.attempt
+    }
+    pub fn clear_attempt(&mut self) {
+        self.attempt = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attempt(&mut self, v: i64) {
+        self.attempt = v;
+    }
+}
+
+impl ::protobuf::Message for NetworkState {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.attempt = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != NetworkStatus::Offline {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if self.attempt != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.attempt, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.status != NetworkStatus::Offline {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.status))?;
+        }
+        if self.attempt != 0 {
+            os.write_int64(2, self.attempt)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NetworkState {
+        NetworkState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<NetworkStatus>>(
+                "status",
+                |m: &NetworkState| { &m.status },
+                |m: &mut NetworkState| { &mut m.status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "attempt",
+                |m: &NetworkState| { &m.attempt },
+                |m: &mut NetworkState| { &mut m.attempt },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NetworkState>(
+                "NetworkState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NetworkState {
+        static instance: ::protobuf::rt::LazyV2<NetworkState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NetworkState::new)
+    }
+}
+
+impl ::protobuf::Clear for NetworkState {
+    fn clear(&mut self) {
+        self.status = NetworkStatus::Offline;
+        self.attempt = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NetworkState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NetworkState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum NetworkStatus {
+    Offline = 0,
+    Connecting = 1,
+    Online = 2,
+}
+
+impl ::protobuf::ProtobufEnum for NetworkStatus {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<NetworkStatus> {
+        match value {
+            0 => ::std::option::Option::Some(NetworkStatus::Offline),
+            1 => ::std::option::Option::Some(NetworkStatus::Connecting),
+            2 => ::std::option::Option::Some(NetworkStatus::Online),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [NetworkStatus] = &[
+            NetworkStatus::Offline,
+            NetworkStatus::Connecting,
+            NetworkStatus::Online,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<NetworkStatus>("NetworkStatus", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for NetworkStatus {
+}
+
+impl ::std::default::Default for NetworkStatus {
+    fn default() -> Self {
+        NetworkStatus::Offline
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NetworkStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x13network_state.proto\"P\n\x0cNetworkState\x12&\n\x06status\x18\x01\
+    \x20\x01(\x0e2\x0e.NetworkStatusR\x06status\x12\x18\n\x07attempt\x18\x02\
+    \x20\x01(\x03R\x07attempt*8\n\rNetworkStatus\x12\x0b\n\x07Offline\x10\0\
+    \x12\x0e\n\nConnecting\x10\x01\x12\n\n\x06Online\x10\x02J\xab\x02\n\x06\
+    \x12\x04\0\0\n\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\
+    \x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x14\n\x0b\n\x04\
+    \x04\0\x02\0\x12\x03\x03\x04\x1d\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\
+    \x04\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x12\x18\n\x0c\n\x05\x04\
+    \0\x02\0\x03\x12\x03\x03\x1b\x1c\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\
+    \x04\x16\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\
+    \0\x02\x01\x01\x12\x03\x04\n\x11\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
+    \x04\x14\x15\n\n\n\x02\x05\0\x12\x04\x06\0\n\x01\n\n\n\x03\x05\0\x01\x12\
+    \x03\x06\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x07\x04\x10\n\x0c\n\x05\
+    \x05\0\x02\0\x01\x12\x03\x07\x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\
+    \x07\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x08\x04\x13\n\x0c\n\x05\
+    \x05\0\x02\x01\x01\x12\x03\x08\x04\x0e\n\x0c\n\x05\x05\0\x02\x01\x02\x12\
+    \x03\x08\x11\x12\n\x0b\n\x04\x05\0\x02\x02\x12\x03\t\x04\x0f\n\x0c\n\x05\
+    \x05\0\x02\x02\x01\x12\x03\t\x04\n\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\
+    \t\r\x0eb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
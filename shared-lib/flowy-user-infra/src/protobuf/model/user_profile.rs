@@ -189,6 +189,7 @@ pub struct UserProfile {
     pub email: ::std::string::String,
     pub name: ::std::string::String,
     pub token: ::std::string::String,
+    pub email_verified: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -308,6 +309,21 @@ impl UserProfile {
     pub fn take_token(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.token, ::std::string::String::new())
     }
+
+    // bool email_verified = 5;
+
+
+    pub fn get_email_verified(&self) -> bool {
+        self.email_verified
+    }
+    pub fn clear_email_verified(&mut self) {
+        self.email_verified = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email_verified(&mut self, v: bool) {
+        self.email_verified = v;
+    }
 }
 
 impl ::protobuf::Message for UserProfile {
@@ -331,6 +347,13 @@ impl ::protobuf::Message for UserProfile {
                 4 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.email_verified = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -355,6 +378,9 @@ impl ::protobuf::Message for UserProfile {
         if !self.token.is_empty() {
             my_size += ::protobuf::rt::string_size(4, &self.token);
         }
+        if self.email_verified != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -373,6 +399,9 @@ impl ::protobuf::Message for UserProfile {
         if !self.token.is_empty() {
             os.write_string(4, &self.token)?;
         }
+        if self.email_verified != false {
+            os.write_bool(5, self.email_verified)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -431,6 +460,11 @@ impl ::protobuf::Message for UserProfile {
                 |m: &UserProfile| { &m.token },
                 |m: &mut UserProfile| { &mut m.token },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "email_verified",
+                |m: &UserProfile| { &m.email_verified },
+                |m: &mut UserProfile| { &mut m.email_verified },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserProfile>(
                 "UserProfile",
                 fields,
@@ -451,6 +485,7 @@ impl ::protobuf::Clear for UserProfile {
         self.email.clear();
         self.name.clear();
         self.token.clear();
+        self.email_verified = false;
         self.unknown_fields.clear();
     }
 }
@@ -1275,23 +1310,24 @@ impl ::protobuf::reflect::ProtobufValue for UpdateUserParams {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x12user_profile.proto\"!\n\tUserToken\x12\x14\n\x05token\x18\x01\x20\
-    \x01(\tR\x05token\"]\n\x0bUserProfile\x12\x0e\n\x02id\x18\x01\x20\x01(\t\
-    R\x02id\x12\x14\n\x05email\x18\x02\x20\x01(\tR\x05email\x12\x12\n\x04nam\
-    e\x18\x03\x20\x01(\tR\x04name\x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05\
-    token\"\xa1\x01\n\x11UpdateUserRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\
-    \tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x16\n\x05\
-    email\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\x08password\x18\x04\
-    \x20\x01(\tH\x02R\x08passwordB\r\n\x0bone_of_nameB\x0e\n\x0cone_of_email\
-    B\x11\n\x0fone_of_password\"\xa0\x01\n\x10UpdateUserParams\x12\x0e\n\x02\
-    id\x18\x01\x20\x01(\tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\
-    \x04name\x12\x16\n\x05email\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\
-    \x08password\x18\x04\x20\x01(\tH\x02R\x08passwordB\r\n\x0bone_of_nameB\
-    \x0e\n\x0cone_of_emailB\x11\n\x0fone_of_passwordJ\xdf\x07\n\x06\x12\x04\
-    \0\0\x16\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\
-    \0\x04\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x11\n\x0b\n\x04\x04\0\x02\
-    \0\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\
-    \x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x03\x13\x14\n\n\n\x02\x04\x01\x12\x04\x05\0\n\x01\n\n\n\x03\
+    \x01(\tR\x05token\"\x84\x01\n\x0bUserProfile\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\tR\x02id\x12\x14\n\x05email\x18\x02\x20\x01(\tR\x05email\x12\x12\n\
+    \x04name\x18\x03\x20\x01(\tR\x04name\x12\x14\n\x05token\x18\x04\x20\x01(\
+    \tR\x05token\x12%\n\x0eemail_verified\x18\x05\x20\x01(\x08R\remailVerifi\
+    ed\"\xa1\x01\n\x11UpdateUserRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\
+    \x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x16\n\x05ema\
+    il\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\x08password\x18\x04\x20\
+    \x01(\tH\x02R\x08passwordB\r\n\x0bone_of_nameB\x0e\n\x0cone_of_emailB\
+    \x11\n\x0fone_of_password\"\xa0\x01\n\x10UpdateUserParams\x12\x0e\n\x02i\
+    d\x18\x01\x20\x01(\tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04\
+    name\x12\x16\n\x05email\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\x08p\
+    assword\x18\x04\x20\x01(\tH\x02R\x08passwordB\r\n\x0bone_of_nameB\x0e\n\
+    \x0cone_of_emailB\x11\n\x0fone_of_passwordJ\x96\x08\n\x06\x12\x04\0\0\
+    \x17\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\
+    \x04\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x11\n\x0b\n\x04\x04\0\x02\0\
+    \x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\
+    \n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\x02\0\x03\
+    \x12\x03\x03\x13\x14\n\n\n\x02\x04\x01\x12\x04\x05\0\x0b\x01\n\n\n\x03\
     \x04\x01\x01\x12\x03\x05\x08\x13\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\
     \x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\
     \x01\x02\0\x01\x12\x03\x06\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\
@@ -1303,39 +1339,42 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x08\x12\x13\n\x0b\n\x04\
     \x04\x01\x02\x03\x12\x03\t\x04\x15\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\
     \x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\t\x0b\x10\n\x0c\n\
-    \x05\x04\x01\x02\x03\x03\x12\x03\t\x13\x14\n\n\n\x02\x04\x02\x12\x04\x0b\
-    \0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x19\n\x0b\n\x04\x04\x02\
-    \x02\0\x12\x03\x0c\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0c\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\r\n\x0c\n\x05\x04\x02\
-    \x02\0\x03\x12\x03\x0c\x10\x11\n\x0b\n\x04\x04\x02\x08\0\x12\x03\r\x04*\
-    \n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\r\n\x15\n\x0b\n\x04\x04\x02\x02\
-    \x01\x12\x03\r\x18(\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\r\x18\x1e\n\
-    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\r\x1f#\n\x0c\n\x05\x04\x02\x02\
-    \x01\x03\x12\x03\r&'\n\x0b\n\x04\x04\x02\x08\x01\x12\x03\x0e\x04,\n\x0c\
-    \n\x05\x04\x02\x08\x01\x01\x12\x03\x0e\n\x16\n\x0b\n\x04\x04\x02\x02\x02\
-    \x12\x03\x0e\x19*\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0e\x19\x1f\n\
-    \x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0e\x20%\n\x0c\n\x05\x04\x02\x02\
-    \x02\x03\x12\x03\x0e()\n\x0b\n\x04\x04\x02\x08\x02\x12\x03\x0f\x042\n\
-    \x0c\n\x05\x04\x02\x08\x02\x01\x12\x03\x0f\n\x19\n\x0b\n\x04\x04\x02\x02\
-    \x03\x12\x03\x0f\x1c0\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x0f\x1c\"\
-    \n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x0f#+\n\x0c\n\x05\x04\x02\x02\
-    \x03\x03\x12\x03\x0f./\n\n\n\x02\x04\x03\x12\x04\x11\0\x16\x01\n\n\n\x03\
-    \x04\x03\x01\x12\x03\x11\x08\x18\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\
-    \x04\x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\
-    \x03\x02\0\x01\x12\x03\x12\x0b\r\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\
-    \x12\x10\x11\n\x0b\n\x04\x04\x03\x08\0\x12\x03\x13\x04*\n\x0c\n\x05\x04\
-    \x03\x08\0\x01\x12\x03\x13\n\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x13\
-    \x18(\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x13\x18\x1e\n\x0c\n\x05\
-    \x04\x03\x02\x01\x01\x12\x03\x13\x1f#\n\x0c\n\x05\x04\x03\x02\x01\x03\
-    \x12\x03\x13&'\n\x0b\n\x04\x04\x03\x08\x01\x12\x03\x14\x04,\n\x0c\n\x05\
-    \x04\x03\x08\x01\x01\x12\x03\x14\n\x16\n\x0b\n\x04\x04\x03\x02\x02\x12\
-    \x03\x14\x19*\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x14\x19\x1f\n\x0c\
-    \n\x05\x04\x03\x02\x02\x01\x12\x03\x14\x20%\n\x0c\n\x05\x04\x03\x02\x02\
-    \x03\x12\x03\x14()\n\x0b\n\x04\x04\x03\x08\x02\x12\x03\x15\x042\n\x0c\n\
-    \x05\x04\x03\x08\x02\x01\x12\x03\x15\n\x19\n\x0b\n\x04\x04\x03\x02\x03\
-    \x12\x03\x15\x1c0\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03\x15\x1c\"\n\
-    \x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x15#+\n\x0c\n\x05\x04\x03\x02\x03\
-    \x03\x12\x03\x15./b\x06proto3\
+    \x05\x04\x01\x02\x03\x03\x12\x03\t\x13\x14\n\x0b\n\x04\x04\x01\x02\x04\
+    \x12\x03\n\x04\x1c\n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03\n\x04\x08\n\
+    \x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\n\t\x17\n\x0c\n\x05\x04\x01\x02\
+    \x04\x03\x12\x03\n\x1a\x1b\n\n\n\x02\x04\x02\x12\x04\x0c\0\x11\x01\n\n\n\
+    \x03\x04\x02\x01\x12\x03\x0c\x08\x19\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\
+    \x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\
+    \x02\x02\0\x01\x12\x03\r\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\
+    \x10\x11\n\x0b\n\x04\x04\x02\x08\0\x12\x03\x0e\x04*\n\x0c\n\x05\x04\x02\
+    \x08\0\x01\x12\x03\x0e\n\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x18\
+    (\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x18\x1e\n\x0c\n\x05\x04\
+    \x02\x02\x01\x01\x12\x03\x0e\x1f#\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
+    \x03\x0e&'\n\x0b\n\x04\x04\x02\x08\x01\x12\x03\x0f\x04,\n\x0c\n\x05\x04\
+    \x02\x08\x01\x01\x12\x03\x0f\n\x16\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\
+    \x0f\x19*\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0f\x19\x1f\n\x0c\n\
+    \x05\x04\x02\x02\x02\x01\x12\x03\x0f\x20%\n\x0c\n\x05\x04\x02\x02\x02\
+    \x03\x12\x03\x0f()\n\x0b\n\x04\x04\x02\x08\x02\x12\x03\x10\x042\n\x0c\n\
+    \x05\x04\x02\x08\x02\x01\x12\x03\x10\n\x19\n\x0b\n\x04\x04\x02\x02\x03\
+    \x12\x03\x10\x1c0\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x10\x1c\"\n\
+    \x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x10#+\n\x0c\n\x05\x04\x02\x02\x03\
+    \x03\x12\x03\x10./\n\n\n\x02\x04\x03\x12\x04\x12\0\x17\x01\n\n\n\x03\x04\
+    \x03\x01\x12\x03\x12\x08\x18\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x13\x04\
+    \x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x03\
+    \x02\0\x01\x12\x03\x13\x0b\r\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x13\
+    \x10\x11\n\x0b\n\x04\x04\x03\x08\0\x12\x03\x14\x04*\n\x0c\n\x05\x04\x03\
+    \x08\0\x01\x12\x03\x14\n\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x14\x18\
+    (\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x14\x18\x1e\n\x0c\n\x05\x04\
+    \x03\x02\x01\x01\x12\x03\x14\x1f#\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\
+    \x03\x14&'\n\x0b\n\x04\x04\x03\x08\x01\x12\x03\x15\x04,\n\x0c\n\x05\x04\
+    \x03\x08\x01\x01\x12\x03\x15\n\x16\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\
+    \x15\x19*\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x15\x19\x1f\n\x0c\n\
+    \x05\x04\x03\x02\x02\x01\x12\x03\x15\x20%\n\x0c\n\x05\x04\x03\x02\x02\
+    \x03\x12\x03\x15()\n\x0b\n\x04\x04\x03\x08\x02\x12\x03\x16\x042\n\x0c\n\
+    \x05\x04\x03\x08\x02\x01\x12\x03\x16\n\x19\n\x0b\n\x04\x04\x03\x02\x03\
+    \x12\x03\x16\x1c0\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03\x16\x1c\"\n\
+    \x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x16#+\n\x0c\n\x05\x04\x03\x02\x03\
+    \x03\x12\x03\x16./b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -96,6 +96,14 @@ pub struct App {
 
     #[pb(index = 8)]
     pub create_time: i64,
+
+    // The apps of the workspace are sorted by the rank
+    #[pb(index = 9)]
+    pub rank: String,
+
+    // The device that made the latest update, it's empty if the app wasn't updated from a device
+    #[pb(index = 10)]
+    pub modified_device_id: String,
 }
 
 impl App {
@@ -0,0 +1,46 @@
+use crate::{errors::ErrorCode, parser::app::AppId};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// Moves the app to the index of the apps in its workspace, the index is the position after the app is
+// removed from its current position.
+#[derive(ProtoBuf, Default)]
+pub struct MoveAppRequest {
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    #[pb(index = 2)]
+    pub index: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct AppPosition {
+    pub app_id: String,
+    pub index: usize,
+}
+
+impl TryInto<AppPosition> for MoveAppRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AppPosition, Self::Error> {
+        let app_id = AppId::parse(self.app_id)?.0;
+        if self.index < 0 {
+            return Err(ErrorCode::MoveIndexInvalid);
+        }
+
+        Ok(AppPosition {
+            app_id,
+            index: self.index as usize,
+        })
+    }
+}
+
+// The rank is computed by the client from the ranks of the apps around the index
+#[derive(ProtoBuf, Default, Clone, Debug)]
+pub struct MoveAppParams {
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    #[pb(index = 2)]
+    pub rank: String,
+}
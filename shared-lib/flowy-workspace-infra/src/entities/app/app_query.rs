@@ -1,4 +1,4 @@
-use crate::{errors::ErrorCode, parser::app::AppId};
+use crate::{entities::workspace::ListFilter, errors::ErrorCode, parser::app::AppId};
 use flowy_derive::ProtoBuf;
 use std::convert::TryInto;
 
@@ -6,18 +6,35 @@ use std::convert::TryInto;
 pub struct QueryAppRequest {
     #[pb(index = 1)]
     pub app_ids: Vec<String>,
+
+    // Limits the views of the app
+    #[pb(index = 2, one_of)]
+    pub view_filter: Option<ListFilter>,
+}
+
+impl QueryAppRequest {
+    pub fn new(app_id: &str) -> Self {
+        Self {
+            app_ids: vec![app_id.to_string()],
+            view_filter: None,
+        }
+    }
 }
 
 #[derive(ProtoBuf, Default, Clone, Debug)]
 pub struct AppIdentifier {
     #[pb(index = 1)]
     pub app_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub view_filter: Option<ListFilter>,
 }
 
 impl AppIdentifier {
     pub fn new(app_id: &str) -> Self {
         Self {
             app_id: app_id.to_string(),
+            view_filter: None,
         }
     }
 }
@@ -34,6 +51,10 @@ impl TryInto<AppIdentifier> for QueryAppRequest {
 
         let app_id = self.app_ids.first().unwrap().clone();
         let app_id = AppId::parse(app_id)?.0;
-        Ok(AppIdentifier { app_id })
+        let view_filter = match self.view_filter {
+            None => None,
+            Some(filter) => Some(filter.check()?),
+        };
+        Ok(AppIdentifier { app_id, view_filter })
     }
 }
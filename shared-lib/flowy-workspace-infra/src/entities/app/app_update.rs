@@ -40,6 +40,10 @@ pub struct UpdateAppParams {
 
     #[pb(index = 5, one_of)]
     pub is_trash: Option<bool>,
+
+    // The device that the update was made on
+    #[pb(index = 6)]
+    pub device_id: String,
 }
 
 impl UpdateAppParams {
@@ -64,6 +68,11 @@ impl UpdateAppParams {
         self.is_trash = Some(true);
         self
     }
+
+    pub fn device_id(mut self, device_id: &str) -> Self {
+        self.device_id = device_id.to_owned();
+        self
+    }
 }
 
 impl TryInto<UpdateAppParams> for UpdateAppRequest {
@@ -88,6 +97,7 @@ impl TryInto<UpdateAppParams> for UpdateAppRequest {
             desc: self.desc,
             color_style,
             is_trash: self.is_trash,
+            device_id: "".to_owned(),
         })
     }
 }
@@ -1,6 +1,8 @@
 mod app_create;
+mod app_move;
 mod app_query;
 mod app_update;
 pub use app_create::*;
+pub use app_move::*;
 pub use app_query::*;
 pub use app_update::*;
@@ -0,0 +1,82 @@
+use crate::{errors::ErrorCode, parser::app::AppId};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum AppExportType {
+    Markdown = 0,
+    Pdf      = 1,
+}
+
+impl std::default::Default for AppExportType {
+    fn default() -> Self { AppExportType::Markdown }
+}
+
+impl std::convert::From<i32> for AppExportType {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => AppExportType::Markdown,
+            1 => AppExportType::Pdf,
+            _ => {
+                log::error!("Invalid app export type: {}", val);
+                AppExportType::Markdown
+            },
+        }
+    }
+}
+
+// Exports the docs of all the views in the app to one file at the path, in the order of the sidebar
+#[derive(Default, ProtoBuf)]
+pub struct ExportAppRequest {
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    #[pb(index = 2)]
+    pub export_type: AppExportType,
+
+    #[pb(index = 3)]
+    pub path: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ExportAppParams {
+    pub app_id: String,
+    pub export_type: AppExportType,
+    pub path: String,
+}
+
+impl TryInto<ExportAppParams> for ExportAppRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ExportAppParams, Self::Error> {
+        let app_id = AppId::parse(self.app_id)?.0;
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::ExportPathInvalid);
+        }
+
+        Ok(ExportAppParams {
+            app_id,
+            export_type: self.export_type,
+            path: self.path,
+        })
+    }
+}
+
+// The export runs in the background, it's returned when the export starts and then sent with the
+// AppExportProgress notification after each doc is read.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ExportAppProgress {
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+
+    #[pb(index = 3)]
+    pub total: i64,
+
+    #[pb(index = 4)]
+    pub finished: i64,
+
+    #[pb(index = 5)]
+    pub is_completed: bool,
+}
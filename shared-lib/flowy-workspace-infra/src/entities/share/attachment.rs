@@ -0,0 +1,32 @@
+use crate::{errors::ErrorCode, parser::view::ViewId};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// Uploads the file at the path as an attachment of the doc. The upload runs in the background, the
+// progress is returned when the upload starts.
+#[derive(Default, ProtoBuf)]
+pub struct UploadAttachmentRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+}
+
+#[derive(Default, Debug)]
+pub struct UploadAttachmentParams {
+    pub doc_id: String,
+    pub path: String,
+}
+
+impl TryInto<UploadAttachmentParams> for UploadAttachmentRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<UploadAttachmentParams, Self::Error> {
+        let doc_id = ViewId::parse(self.doc_id)?.0;
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::AttachmentPathInvalid);
+        }
+
+        Ok(UploadAttachmentParams { doc_id, path: self.path })
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{errors::ErrorCode, parser::workspace::WorkspaceId};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// Imports the folder of markdown files at the path into a new app of the workspace that is named after
+// the folder. Each markdown file becomes a doc and each sub folder becomes a view that contains the
+// files of the folder, the hidden files and the folders without markdown files are skipped.
+#[derive(Default, ProtoBuf)]
+pub struct ImportFolderRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+}
+
+#[derive(Default, Debug)]
+pub struct ImportFolderParams {
+    pub workspace_id: String,
+    pub path: String,
+}
+
+impl TryInto<ImportFolderParams> for ImportFolderRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ImportFolderParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?.0;
+        if self.path.trim().is_empty() {
+            return Err(ErrorCode::ImportPathInvalid);
+        }
+
+        Ok(ImportFolderParams {
+            workspace_id,
+            path: self.path,
+        })
+    }
+}
+
+// The import runs in the background after the app is created, it's returned when the import starts and
+// then sent with the AppImportProgress notification after each file is imported. The warnings are the
+// relative links and images that don't refer to an imported file, they are kept as they are.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ImportFolderProgress {
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    #[pb(index = 2)]
+    pub path: String,
+
+    #[pb(index = 3)]
+    pub total: i64,
+
+    #[pb(index = 4)]
+    pub finished: i64,
+
+    #[pb(index = 5)]
+    pub is_completed: bool,
+
+    #[pb(index = 6)]
+    pub warnings: Vec<String>,
+}
@@ -0,0 +1,56 @@
+use crate::{errors::ErrorCode, parser::view::ViewId};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum ImportType {
+    Markdown = 0,
+}
+
+impl std::default::Default for ImportType {
+    fn default() -> Self { ImportType::Markdown }
+}
+
+impl std::convert::From<i32> for ImportType {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => ImportType::Markdown,
+            _ => {
+                log::error!("Invalid import type: {}", val);
+                ImportType::Markdown
+            },
+        }
+    }
+}
+
+// The imported data replaces the content of the doc
+#[derive(Default, ProtoBuf)]
+pub struct ImportRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub data: String,
+
+    #[pb(index = 3)]
+    pub import_type: ImportType,
+}
+
+#[derive(Default, Debug)]
+pub struct ImportParams {
+    pub doc_id: String,
+    pub data: String,
+    pub import_type: ImportType,
+}
+
+impl TryInto<ImportParams> for ImportRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<ImportParams, Self::Error> {
+        let doc_id = ViewId::parse(self.doc_id)?.0;
+        Ok(ImportParams {
+            doc_id,
+            data: self.data,
+            import_type: self.import_type,
+        })
+    }
+}
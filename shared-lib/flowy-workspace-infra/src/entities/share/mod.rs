@@ -1,3 +1,11 @@
+mod app_export;
+mod attachment;
 mod export;
+mod folder_import;
+mod import;
 
+pub use app_export::*;
+pub use attachment::*;
 pub use export::*;
+pub use folder_import::*;
+pub use import::*;
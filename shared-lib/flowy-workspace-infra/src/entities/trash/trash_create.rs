@@ -4,9 +4,10 @@ use std::fmt::Formatter;
 
 #[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
 pub enum TrashType {
-    Unknown = 0,
-    View    = 1,
-    App     = 2,
+    Unknown   = 0,
+    View      = 1,
+    App       = 2,
+    Workspace = 3,
 }
 
 impl std::convert::TryFrom<i32> for TrashType {
@@ -17,6 +18,7 @@ impl std::convert::TryFrom<i32> for TrashType {
             0 => Ok(TrashType::Unknown),
             1 => Ok(TrashType::View),
             2 => Ok(TrashType::App),
+            3 => Ok(TrashType::Workspace),
             _ => Err(format!("Invalid trash type: {}", value)),
         }
     }
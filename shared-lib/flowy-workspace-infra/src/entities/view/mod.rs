@@ -1,7 +1,21 @@
 pub use view_create::*;
+pub use view_favorite::*;
+pub use view_move::*;
+pub use view_publish::*;
 pub use view_query::*;
+pub use view_recent::*;
+pub use view_share::*;
+pub use view_unread::*;
 pub use view_update::*;
+pub use view_webhook::*;
 
 mod view_create;
+mod view_favorite;
+mod view_move;
+mod view_publish;
 mod view_query;
+mod view_recent;
+mod view_share;
+mod view_unread;
 mod view_update;
+mod view_webhook;
@@ -136,6 +136,14 @@ pub struct View {
 
     #[pb(index = 9)]
     pub create_time: i64,
+
+    // The views that belong to the same app or view are sorted by the rank
+    #[pb(index = 10)]
+    pub rank: String,
+
+    // The device that made the latest update, it's empty if the view wasn't updated from a device
+    #[pb(index = 11)]
+    pub modified_device_id: String,
 }
 
 #[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
@@ -0,0 +1,29 @@
+use crate::entities::view::View;
+use flowy_derive::ProtoBuf;
+
+// The view is a favorite if the user pinned it to the Favorites section of the sidebar. Returned by
+// ToggleFavorite and sent to the server after the view is pinned or unpinned.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ViewFavoriteState {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub is_favorite: bool,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct Favorite {
+    #[pb(index = 1)]
+    pub view: View,
+
+    #[pb(index = 2)]
+    pub favorite_time: i64,
+}
+
+// The view that was pinned last comes first. Sent with the FavoritesChanged notification.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedFavorite {
+    #[pb(index = 1)]
+    pub items: Vec<Favorite>,
+}
@@ -0,0 +1,58 @@
+use crate::{
+    errors::ErrorCode,
+    parser::{app::AppId, view::ViewId},
+};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// Moves the view to the index of the views that belong to the app or the view of the same workspace. The
+// index is the position after the view is removed from its current position.
+#[derive(ProtoBuf, Default)]
+pub struct MoveViewRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub belong_to_id: String,
+
+    #[pb(index = 3)]
+    pub index: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ViewPosition {
+    pub view_id: String,
+    pub belong_to_id: String,
+    pub index: usize,
+}
+
+impl TryInto<ViewPosition> for MoveViewRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewPosition, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+        let belong_to_id = AppId::parse(self.belong_to_id)?.0;
+        if self.index < 0 {
+            return Err(ErrorCode::MoveIndexInvalid);
+        }
+
+        Ok(ViewPosition {
+            view_id,
+            belong_to_id,
+            index: self.index as usize,
+        })
+    }
+}
+
+// The rank is computed by the client from the ranks of the views around the index
+#[derive(ProtoBuf, Default, Clone, Debug)]
+pub struct MoveViewParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub belong_to_id: String,
+
+    #[pb(index = 3)]
+    pub rank: String,
+}
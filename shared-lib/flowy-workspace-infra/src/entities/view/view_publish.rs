@@ -0,0 +1,79 @@
+use crate::{errors::ErrorCode, impl_def_and_def_mut, parser::view::ViewId};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// The published view is rendered to static html by the server. The page can be read by anyone
+// with the slug, the sub-views are published along with it under the same slug.
+//
+// The page follows the latest revision of the doc unless it's pinned. The rev_id is the revision
+// that the page was rendered from.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct PublishedView {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub slug: String,
+
+    #[pb(index = 3)]
+    pub publish_time: i64,
+
+    #[pb(index = 4)]
+    pub rev_id: i64,
+
+    #[pb(index = 5)]
+    pub is_pinned: bool,
+}
+
+// The anonymous reads of the published page in one day (yyyy-mm-dd in UTC)
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ViewAccessLog {
+    #[pb(index = 1)]
+    pub date: String,
+
+    #[pb(index = 2)]
+    pub read_count: i64,
+}
+
+// The latest day comes first, the days without any read are omitted
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedViewAccessLog {
+    #[pb(index = 1)]
+    pub items: Vec<ViewAccessLog>,
+}
+
+impl_def_and_def_mut!(RepeatedViewAccessLog, ViewAccessLog);
+
+#[derive(Default, ProtoBuf)]
+pub struct PinPublishedViewRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub rev_id: i64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct PinPublishedViewParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub rev_id: i64,
+}
+
+impl TryInto<PinPublishedViewParams> for PinPublishedViewRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<PinPublishedViewParams, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+        if self.rev_id < 0 {
+            return Err(ErrorCode::ViewRevIdInvalid);
+        }
+
+        Ok(PinPublishedViewParams {
+            view_id,
+            rev_id: self.rev_id,
+        })
+    }
+}
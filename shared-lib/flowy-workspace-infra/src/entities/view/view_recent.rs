@@ -0,0 +1,75 @@
+use crate::{entities::view::View, errors::ErrorCode};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+pub const DEFAULT_RECENT_LIMIT: i64 = 20;
+pub const MAX_RECENT_LIMIT: i64 = 100;
+
+// Reads the views that the user opened last. The limit of zero returns the DEFAULT_RECENT_LIMIT views.
+#[derive(Default, ProtoBuf)]
+pub struct QueryRecentViewsRequest {
+    #[pb(index = 1)]
+    pub limit: i64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct QueryRecentViewsParams {
+    #[pb(index = 1)]
+    pub limit: i64,
+}
+
+impl TryInto<QueryRecentViewsParams> for QueryRecentViewsRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<QueryRecentViewsParams, Self::Error> {
+        if self.limit < 0 || self.limit > MAX_RECENT_LIMIT {
+            return Err(ErrorCode::ListFilterInvalid);
+        }
+
+        let limit = match self.limit {
+            0 => DEFAULT_RECENT_LIMIT,
+            limit => limit,
+        };
+        Ok(QueryRecentViewsParams { limit })
+    }
+}
+
+// Sent to the server after the view is opened, the server keeps the latest open time of each view
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RecentViewRecord {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub open_time: i64,
+}
+
+// The view with the app and the workspace that it belongs to, the app of the nested view is the app of
+// its top level view.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RecentView {
+    #[pb(index = 1)]
+    pub view: View,
+
+    #[pb(index = 2)]
+    pub app_id: String,
+
+    #[pb(index = 3)]
+    pub app_name: String,
+
+    #[pb(index = 4)]
+    pub workspace_id: String,
+
+    #[pb(index = 5)]
+    pub workspace_name: String,
+
+    #[pb(index = 6)]
+    pub open_time: i64,
+}
+
+// The view that was opened last comes first. Sent with the RecentViewsChanged notification.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedRecentView {
+    #[pb(index = 1)]
+    pub items: Vec<RecentView>,
+}
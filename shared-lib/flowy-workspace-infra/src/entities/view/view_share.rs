@@ -0,0 +1,91 @@
+use crate::{errors::ErrorCode, impl_def_and_def_mut, parser::view::ViewId};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+pub const MAX_SHARE_PASSCODE_LEN: usize = 64;
+
+// The share link lets anyone who has it read the view without publishing it. The link stops working
+// when it's revoked or after the expire_time, which is a unix timestamp in seconds. Zero means the link
+// never expires. The reader has to enter the passcode if the link has one.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ShareLink {
+    #[pb(index = 1)]
+    pub link_id: String,
+
+    #[pb(index = 2)]
+    pub view_id: String,
+
+    #[pb(index = 3)]
+    pub create_time: i64,
+
+    #[pb(index = 4)]
+    pub expire_time: i64,
+
+    #[pb(index = 5)]
+    pub has_passcode: bool,
+
+    // The path of the shared page, e.g. my-workspace/my-view/{link_id}
+    #[pb(index = 6)]
+    pub slug: String,
+}
+
+// The latest link comes first
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedShareLink {
+    #[pb(index = 1)]
+    pub items: Vec<ShareLink>,
+}
+
+impl_def_and_def_mut!(RepeatedShareLink, ShareLink);
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateShareLinkRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub expire_time: i64,
+
+    // The link doesn't require a passcode if it's empty
+    #[pb(index = 3)]
+    pub passcode: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct CreateShareLinkParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub expire_time: i64,
+
+    #[pb(index = 3)]
+    pub passcode: String,
+}
+
+impl TryInto<CreateShareLinkParams> for CreateShareLinkRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateShareLinkParams, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+        if self.expire_time < 0 {
+            return Err(ErrorCode::ShareLinkExpireTimeInvalid);
+        }
+
+        if self.passcode.len() > MAX_SHARE_PASSCODE_LEN {
+            return Err(ErrorCode::SharePasscodeTooLong);
+        }
+
+        Ok(CreateShareLinkParams {
+            view_id,
+            expire_time: self.expire_time,
+            passcode: self.passcode,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ShareLinkIdentifier {
+    #[pb(index = 1)]
+    pub link_id: String,
+}
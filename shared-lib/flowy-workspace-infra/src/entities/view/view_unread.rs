@@ -0,0 +1,12 @@
+use flowy_derive::ProtoBuf;
+
+// Sent with the ViewUnreadChanged notification. The view is unread if it's changed by others since it was
+// last opened.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ViewUnreadState {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub unread: bool,
+}
@@ -33,6 +33,10 @@ pub struct UpdateViewParams {
 
     #[pb(index = 4, one_of)]
     pub thumbnail: Option<String>,
+
+    // The device that the update was made on
+    #[pb(index = 5)]
+    pub device_id: String,
 }
 
 impl UpdateViewParams {
@@ -52,6 +56,11 @@ impl UpdateViewParams {
         self.desc = Some(desc.to_owned());
         self
     }
+
+    pub fn device_id(mut self, device_id: &str) -> Self {
+        self.device_id = device_id.to_owned();
+        self
+    }
 }
 
 impl TryInto<UpdateViewParams> for UpdateViewRequest {
@@ -80,6 +89,7 @@ impl TryInto<UpdateViewParams> for UpdateViewRequest {
             name,
             desc,
             thumbnail,
+            device_id: "".to_owned(),
         })
     }
 }
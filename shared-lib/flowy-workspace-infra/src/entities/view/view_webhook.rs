@@ -0,0 +1,93 @@
+use crate::{
+    errors::ErrorCode,
+    parser::view::{ViewId, WebhookSecret, WebhookUrl},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum WebhookFormat {
+    Markdown = 0,
+    Html     = 1,
+}
+
+impl std::default::Default for WebhookFormat {
+    fn default() -> Self { WebhookFormat::Markdown }
+}
+
+// The doc of the view is rendered in the format and posted to the url after it stops changing for a
+// while and after the view is published. The secret is sent as the bearer token, so the receiver can
+// tell the requests from the server. The url is empty if the view has no webhook.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ViewWebhook {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub url: String,
+
+    #[pb(index = 3)]
+    pub format: WebhookFormat,
+
+    #[pb(index = 4)]
+    pub has_secret: bool,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+
+    // Zero until the doc is delivered for the first time
+    #[pb(index = 6)]
+    pub last_delivery_time: i64,
+
+    // The http status of the last delivery, zero if the url couldn't be reached
+    #[pb(index = 7)]
+    pub last_status: i32,
+}
+
+// Setting the webhook of the view replaces the one that it had
+#[derive(Default, ProtoBuf)]
+pub struct SetViewWebhookRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub url: String,
+
+    #[pb(index = 3)]
+    pub format: WebhookFormat,
+
+    #[pb(index = 4)]
+    pub secret: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct SetViewWebhookParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub url: String,
+
+    #[pb(index = 3)]
+    pub format: WebhookFormat,
+
+    #[pb(index = 4)]
+    pub secret: String,
+}
+
+impl TryInto<SetViewWebhookParams> for SetViewWebhookRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SetViewWebhookParams, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+        let url = WebhookUrl::parse(self.url)?.0;
+        let secret = WebhookSecret::parse(self.secret)?.0;
+
+        Ok(SetViewWebhookParams {
+            view_id,
+            url,
+            format: self.format,
+            secret,
+        })
+    }
+}
@@ -1,9 +1,29 @@
+pub use workspace_backup::*;
+pub use workspace_bot::*;
+pub use workspace_clone::*;
 pub use workspace_create::*;
+pub use workspace_filter::*;
+pub use workspace_member::*;
+pub use workspace_merge::*;
 pub use workspace_query::*;
+pub use workspace_search::*;
 pub use workspace_setting::*;
+pub use workspace_switch::*;
+pub use workspace_sync::*;
 pub use workspace_update::*;
+pub use workspace_usage::*;
 
+mod workspace_backup;
+mod workspace_bot;
+mod workspace_clone;
 mod workspace_create;
+mod workspace_filter;
+mod workspace_member;
+mod workspace_merge;
 mod workspace_query;
+mod workspace_search;
 mod workspace_setting;
+mod workspace_switch;
+mod workspace_sync;
 mod workspace_update;
+mod workspace_usage;
@@ -0,0 +1,28 @@
+use crate::entities::workspace::Workspace;
+use flowy_derive::ProtoBuf;
+
+// The backup only keeps the manifest of the attachments, so each attachment is created again under the
+// restored doc and its chunks must be uploaded again with the attachment_id. The backup_id is the id of
+// the attachment in the backup.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RestoredAttachment {
+    #[pb(index = 1)]
+    pub backup_id: String,
+
+    #[pb(index = 2)]
+    pub attachment_id: String,
+
+    #[pb(index = 3)]
+    pub doc_id: String,
+}
+
+// The workspace that is restored from the backup with fresh ids, the links between its docs are rewritten
+// to the new ids.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceRestore {
+    #[pb(index = 1)]
+    pub workspace: Workspace,
+
+    #[pb(index = 2)]
+    pub attachments: Vec<RestoredAttachment>,
+}
@@ -0,0 +1,108 @@
+use crate::{
+    errors::*,
+    parser::workspace::{BotName, WorkspaceId},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+// The read bot can only fetch the apps, views and documents, the write bot can update them as well.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum BotScope {
+    Read  = 0,
+    Write = 1,
+}
+
+impl std::default::Default for BotScope {
+    fn default() -> Self { BotScope::Read }
+}
+
+impl std::convert::From<i32> for BotScope {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => BotScope::Read,
+            1 => BotScope::Write,
+            _ => {
+                log::error!("Invalid bot scope: {}", val);
+                BotScope::Read
+            },
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct CreateBotRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub scope: BotScope,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct CreateBotParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub scope: BotScope,
+}
+
+impl TryInto<CreateBotParams> for CreateBotRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateBotParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?;
+        let name = BotName::parse(self.name)?;
+        Ok(CreateBotParams {
+            workspace_id: workspace_id.0,
+            name: name.0,
+            scope: self.scope,
+        })
+    }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct Bot {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub workspace_id: String,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub scope: BotScope,
+
+    #[pb(index = 5)]
+    pub create_time: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedBot {
+    #[pb(index = 1)]
+    pub items: Vec<Bot>,
+}
+
+// The token is only returned when the bot is created, it can't be read again.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct BotToken {
+    #[pb(index = 1)]
+    pub bot: Bot,
+
+    #[pb(index = 2)]
+    pub token: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct BotIdentifier {
+    #[pb(index = 1)]
+    pub bot_id: String,
+}
@@ -0,0 +1,56 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum WorkspaceCloneState {
+    Running   = 0,
+    Completed = 1,
+    Failed    = 2,
+}
+
+impl std::default::Default for WorkspaceCloneState {
+    fn default() -> Self { WorkspaceCloneState::Running }
+}
+
+impl std::convert::From<i32> for WorkspaceCloneState {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => WorkspaceCloneState::Running,
+            1 => WorkspaceCloneState::Completed,
+            2 => WorkspaceCloneState::Failed,
+            _ => {
+                log::error!("Invalid workspace clone state: {}", val);
+                WorkspaceCloneState::Failed
+            },
+        }
+    }
+}
+
+// The clone runs in the background, it's returned when the clone starts and then read with the clone_id
+// until it's completed. The workspace_id is the id of the copy, it's empty until the clone is completed.
+// The total is 0 until the apps and the views of the workspace are counted.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceCloneProgress {
+    #[pb(index = 1)]
+    pub clone_id: String,
+
+    #[pb(index = 2)]
+    pub source_workspace_id: String,
+
+    #[pb(index = 3)]
+    pub workspace_id: String,
+
+    #[pb(index = 4)]
+    pub total: i64,
+
+    #[pb(index = 5)]
+    pub finished: i64,
+
+    #[pb(index = 6)]
+    pub state: WorkspaceCloneState,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceCloneIdentifier {
+    #[pb(index = 1)]
+    pub clone_id: String,
+}
@@ -1,5 +1,5 @@
 use crate::{
-    entities::app::RepeatedApp,
+    entities::{app::RepeatedApp, workspace::WorkspaceRole},
     errors::*,
     impl_def_and_def_mut,
     parser::workspace::{WorkspaceDesc, WorkspaceName},
@@ -58,6 +58,13 @@ pub struct Workspace {
 
     #[pb(index = 6)]
     pub create_time: i64,
+
+    #[pb(index = 7)]
+    pub archived: bool,
+
+    // The role of the user who reads the workspace, the shared workspaces are listed with the owned ones
+    #[pb(index = 8)]
+    pub role: WorkspaceRole,
 }
 
 impl Workspace {
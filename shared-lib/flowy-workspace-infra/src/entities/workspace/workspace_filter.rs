@@ -0,0 +1,150 @@
+use crate::errors::ErrorCode;
+use flowy_derive::ProtoBuf;
+
+pub const MAX_LIST_LIMIT: i64 = 200;
+pub const LIST_SORT_FIELDS: [&str; 2] = ["create_time", "name"];
+
+// Reads a page of the apps of a workspace or of the views of an app, ordered by the create time. The
+// name filter matches the items whose name contains it, case insensitively. The limit of zero reads
+// all the items after the offset.
+//
+// The next page is read after the id of the last item of the page, which doesn't skip or repeat the
+// items when the items before it are added or deleted. It can't be combined with the offset.
+#[derive(Default, ProtoBuf, Clone, Debug, PartialEq)]
+pub struct ListFilter {
+    #[pb(index = 1)]
+    pub limit: i64,
+
+    #[pb(index = 2)]
+    pub offset: i64,
+
+    #[pb(index = 3, one_of)]
+    pub name: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub after: Option<String>,
+
+    // One of the LIST_SORT_FIELDS, the items are ordered by the rank if it's None
+    #[pb(index = 5, one_of)]
+    pub sort_by: Option<String>,
+}
+
+impl ListFilter {
+    pub fn new(limit: i64, offset: i64) -> Self {
+        Self {
+            limit,
+            offset,
+            name: None,
+            after: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn check(self) -> Result<ListFilter, ErrorCode> {
+        if self.limit < 0 || self.limit > MAX_LIST_LIMIT || self.offset < 0 {
+            return Err(ErrorCode::ListFilterInvalid);
+        }
+
+        if self.after.is_some() && self.offset > 0 {
+            return Err(ErrorCode::ListFilterInvalid);
+        }
+
+        if let Some(sort_by) = &self.sort_by {
+            if !LIST_SORT_FIELDS.contains(&sort_by.as_str()) {
+                return Err(ErrorCode::ListFilterInvalid);
+            }
+        }
+
+        let name = self.name.map(|name| name.trim().to_owned()).filter(|name| !name.is_empty());
+        Ok(ListFilter { name, ..self })
+    }
+
+    // The items must be ordered by the rank already. The key of the item is its id and name.
+    pub fn apply<T, F>(&self, mut items: Vec<T>, key_of: F) -> Vec<T>
+    where
+        F: Fn(&T) -> (&str, &str),
+    {
+        if self.sort_by.as_deref() == Some("name") {
+            items.sort_by_key(|item| key_of(item).1.to_lowercase());
+        }
+
+        if let Some(after) = &self.after {
+            items = match items.iter().position(|item| key_of(item).0 == after.as_str()) {
+                None => vec![],
+                Some(index) => items.split_off(index + 1),
+            };
+        }
+
+        let name = self.name.as_ref().map(|name| name.to_lowercase());
+        let iter = items
+            .into_iter()
+            .filter(|item| match &name {
+                None => true,
+                Some(name) => key_of(item).1.to_lowercase().contains(name),
+            })
+            .skip(self.offset as usize);
+
+        match self.limit {
+            0 => iter.collect(),
+            limit => iter.take(limit as usize).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListFilter;
+
+    #[test]
+    fn list_filter_apply() {
+        let filter = ListFilter {
+            name: Some("doc".to_owned()),
+            ..ListFilter::new(2, 1)
+        };
+        let items = vec!["Doc 1", "Grid", "doc 2", "DOC 3", "doc 4"];
+        assert_eq!(filter.apply(items, |item| (*item, *item)), vec!["doc 2", "DOC 3"]);
+    }
+
+    #[test]
+    fn list_filter_apply_after() {
+        let filter = ListFilter {
+            after: Some("b".to_owned()),
+            sort_by: Some("name".to_owned()),
+            ..ListFilter::new(2, 0)
+        };
+        let items = vec![("a", "C"), ("b", "a"), ("c", "b"), ("d", "D")];
+        assert_eq!(filter.apply(items.clone(), |item| *item), vec![("c", "b"), ("a", "C")]);
+
+        // The page is empty if the item is deleted
+        let filter = ListFilter {
+            after: Some("e".to_owned()),
+            ..ListFilter::new(2, 0)
+        };
+        assert!(filter.apply(items, |item| *item).is_empty());
+    }
+
+    #[test]
+    fn list_filter_check() {
+        assert!(ListFilter::new(-1, 0).check().is_err());
+        assert!(ListFilter::new(0, -1).check().is_err());
+        assert!(ListFilter::new(201, 0).check().is_err());
+        assert!(ListFilter {
+            after: Some("id".to_owned()),
+            ..ListFilter::new(10, 1)
+        }
+        .check()
+        .is_err());
+        assert!(ListFilter {
+            sort_by: Some("rank".to_owned()),
+            ..ListFilter::new(10, 0)
+        }
+        .check()
+        .is_err());
+
+        let filter = ListFilter {
+            name: Some("  ".to_owned()),
+            ..ListFilter::new(10, 0)
+        };
+        assert_eq!(filter.check().unwrap().name, None);
+    }
+}
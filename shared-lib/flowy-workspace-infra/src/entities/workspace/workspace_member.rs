@@ -0,0 +1,102 @@
+use crate::{
+    errors::*,
+    parser::workspace::{MemberEmail, WorkspaceId},
+};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+// The owner is the user who created the workspace. The editors can change the apps of the workspace,
+// the viewers can only read them.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum WorkspaceRole {
+    Owner  = 0,
+    Editor = 1,
+    Viewer = 2,
+}
+
+impl std::default::Default for WorkspaceRole {
+    fn default() -> Self { WorkspaceRole::Viewer }
+}
+
+impl std::convert::From<i32> for WorkspaceRole {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => WorkspaceRole::Owner,
+            1 => WorkspaceRole::Editor,
+            2 => WorkspaceRole::Viewer,
+            _ => {
+                log::error!("Invalid workspace role: {}", val);
+                WorkspaceRole::Viewer
+            },
+        }
+    }
+}
+
+// Invites the user who signed up with the email to the workspace, the role of the user is updated if
+// the user is already a member.
+#[derive(ProtoBuf, Default)]
+pub struct ShareWorkspaceRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct ShareWorkspaceParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub email: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+}
+
+impl TryInto<ShareWorkspaceParams> for ShareWorkspaceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ShareWorkspaceParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?;
+        let email = MemberEmail::parse(self.email)?;
+        if self.role == WorkspaceRole::Owner {
+            return Err(ErrorCode::MemberRoleInvalid);
+        }
+
+        Ok(ShareWorkspaceParams {
+            workspace_id: workspace_id.0,
+            email: email.0,
+            role: self.role,
+        })
+    }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceMember {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub email: String,
+
+    #[pb(index = 4)]
+    pub name: String,
+
+    #[pb(index = 5)]
+    pub role: WorkspaceRole,
+}
+
+// The owner comes first, then the members in the order they were invited
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedWorkspaceMember {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceMember>,
+}
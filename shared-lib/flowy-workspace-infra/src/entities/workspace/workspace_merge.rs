@@ -0,0 +1,74 @@
+use crate::errors::ErrorCode;
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+// Merges the data of another account that signed in on this device into the current user, e.g. the
+// guest data into the account that was registered later. The source account is kept on the device.
+#[derive(Default, ProtoBuf)]
+pub struct MergeAccountRequest {
+    #[pb(index = 1)]
+    pub source_user_id: String,
+}
+
+#[derive(Default, Debug)]
+pub struct MergeAccountParams {
+    pub source_user_id: String,
+}
+
+impl TryInto<MergeAccountParams> for MergeAccountRequest {
+    type Error = ErrorCode;
+    fn try_into(self) -> Result<MergeAccountParams, Self::Error> {
+        if self.source_user_id.trim().is_empty() {
+            return Err(ErrorCode::MergeSourceInvalid);
+        }
+
+        Ok(MergeAccountParams {
+            source_user_id: self.source_user_id,
+        })
+    }
+}
+
+// The workspace of the source account. Its apps are merged into the workspace of the current user that
+// has the same name, the target_id is empty if the workspace is created.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceMergeItem {
+    #[pb(index = 1)]
+    pub source_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub target_id: String,
+
+    #[pb(index = 4)]
+    pub app_count: i64,
+
+    #[pb(index = 5)]
+    pub view_count: i64,
+}
+
+// The preview reports what the merge would do without changing anything, the merge returns the same
+// report with is_applied set. The skipped ones are the apps and the views in the trash and the
+// workspaces that others shared with the source account.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct AccountMergeReport {
+    #[pb(index = 1)]
+    pub source_user_id: String,
+
+    #[pb(index = 2)]
+    pub workspaces: Vec<WorkspaceMergeItem>,
+
+    #[pb(index = 3)]
+    pub favorite_count: i64,
+
+    // The names of the settings that are copied, the ones that the current user has set are kept
+    #[pb(index = 4)]
+    pub settings: Vec<String>,
+
+    #[pb(index = 5)]
+    pub skipped_count: i64,
+
+    #[pb(index = 6)]
+    pub is_applied: bool,
+}
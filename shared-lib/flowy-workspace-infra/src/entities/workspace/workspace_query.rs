@@ -1,4 +1,4 @@
-use crate::{errors::*, parser::workspace::WorkspaceId};
+use crate::{entities::workspace::ListFilter, errors::*, parser::workspace::WorkspaceId};
 use flowy_derive::ProtoBuf;
 use std::convert::TryInto;
 
@@ -7,10 +7,19 @@ pub struct QueryWorkspaceRequest {
     // return all workspace if workspace_id is None
     #[pb(index = 1, one_of)]
     pub workspace_id: Option<String>,
+
+    // Limits the apps of each workspace
+    #[pb(index = 2, one_of)]
+    pub app_filter: Option<ListFilter>,
 }
 
 impl QueryWorkspaceRequest {
-    pub fn new(workspace_id: Option<String>) -> Self { Self { workspace_id } }
+    pub fn new(workspace_id: Option<String>) -> Self {
+        Self {
+            workspace_id,
+            app_filter: None,
+        }
+    }
 }
 
 // Read all workspaces if the workspace_id is None
@@ -18,10 +27,18 @@ impl QueryWorkspaceRequest {
 pub struct WorkspaceIdentifier {
     #[pb(index = 1, one_of)]
     pub workspace_id: Option<String>,
+
+    #[pb(index = 2, one_of)]
+    pub app_filter: Option<ListFilter>,
 }
 
 impl WorkspaceIdentifier {
-    pub fn new(workspace_id: Option<String>) -> Self { Self { workspace_id } }
+    pub fn new(workspace_id: Option<String>) -> Self {
+        Self {
+            workspace_id,
+            app_filter: None,
+        }
+    }
 }
 
 impl TryInto<WorkspaceIdentifier> for QueryWorkspaceRequest {
@@ -33,6 +50,14 @@ impl TryInto<WorkspaceIdentifier> for QueryWorkspaceRequest {
             Some(workspace_id) => Some(WorkspaceId::parse(workspace_id)?.0),
         };
 
-        Ok(WorkspaceIdentifier { workspace_id })
+        let app_filter = match self.app_filter {
+            None => None,
+            Some(filter) => Some(filter.check()?),
+        };
+
+        Ok(WorkspaceIdentifier {
+            workspace_id,
+            app_filter,
+        })
     }
 }
@@ -0,0 +1,104 @@
+use crate::{errors::ErrorCode, impl_def_and_def_mut, parser::workspace::WorkspaceId};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+pub const DEFAULT_SEARCH_LIMIT: i64 = 20;
+pub const MAX_SEARCH_LIMIT: i64 = 100;
+pub const MAX_SEARCH_MATCHES: usize = 10;
+
+// Searches the view names and the doc contents of the workspace. The limit of zero returns the first
+// DEFAULT_SEARCH_LIMIT matches.
+#[derive(Default, ProtoBuf)]
+pub struct SearchWorkspaceRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub query: String,
+
+    #[pb(index = 3)]
+    pub limit: i64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct SearchWorkspaceParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub query: String,
+
+    #[pb(index = 3)]
+    pub limit: i64,
+}
+
+impl TryInto<SearchWorkspaceParams> for SearchWorkspaceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SearchWorkspaceParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?.0;
+        let query = self.query.trim().to_owned();
+        if query.is_empty() || self.limit < 0 || self.limit > MAX_SEARCH_LIMIT {
+            return Err(ErrorCode::SearchQueryInvalid);
+        }
+
+        let limit = match self.limit {
+            0 => DEFAULT_SEARCH_LIMIT,
+            limit => limit,
+        };
+
+        Ok(SearchWorkspaceParams {
+            workspace_id,
+            query,
+            limit,
+        })
+    }
+}
+
+// The snippet is the part of the view name or the doc text around the matched words, which are
+// wrapped in <b></b>. The matches are the first MAX_SEARCH_MATCHES words of the doc that match the query,
+// they are empty if only the view name matches.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SearchResult {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub view_name: String,
+
+    #[pb(index = 3)]
+    pub snippet: String,
+
+    #[pb(index = 4)]
+    pub matches: Vec<SearchMatch>,
+}
+
+// The start and the end are the offsets of the matched word in utf16 code units of the doc, the same as
+// the index of the delta. The context is the text of the line around the word, the word is at the
+// highlight_start..highlight_end of the context.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SearchMatch {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub end: i64,
+
+    #[pb(index = 3)]
+    pub context: String,
+
+    #[pb(index = 4)]
+    pub highlight_start: i64,
+
+    #[pb(index = 5)]
+    pub highlight_end: i64,
+}
+
+// The items are ordered by the rank, the best match comes first
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedSearchResult {
+    #[pb(index = 1)]
+    pub items: Vec<SearchResult>,
+}
+
+impl_def_and_def_mut!(RepeatedSearchResult, SearchResult);
@@ -8,4 +8,8 @@ pub struct CurrentWorkspaceSetting {
 
     #[pb(index = 2, one_of)]
     pub latest_view: Option<View>,
+
+    // The views of the workspace that are changed by others since they were last opened
+    #[pb(index = 3)]
+    pub unread_view_ids: Vec<String>,
 }
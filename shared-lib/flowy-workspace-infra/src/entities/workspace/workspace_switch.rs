@@ -0,0 +1,30 @@
+use crate::entities::workspace::WorkspaceRole;
+use flowy_derive::ProtoBuf;
+
+// The workspace in the workspace switcher without its apps, either owned by the user or shared with the
+// user. The last_open_time is zero if the workspace was never opened on this device.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceItem {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub role: WorkspaceRole,
+
+    #[pb(index = 4)]
+    pub is_current: bool,
+
+    #[pb(index = 5)]
+    pub last_open_time: i64,
+}
+
+// The workspace that was opened last comes first, the ones that were never opened follow in the order
+// they were created.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedWorkspaceItem {
+    #[pb(index = 1)]
+    pub items: Vec<WorkspaceItem>,
+}
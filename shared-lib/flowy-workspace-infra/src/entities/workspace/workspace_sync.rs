@@ -0,0 +1,57 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// How the rename that was made offline is merged on reconnect if others renamed the app or the view
+// after it was renamed locally.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum RenameMergePolicy {
+    // The rename that was made last is kept, the other name is sent with the RenameConflicted notification
+    LatestWins   = 0,
+    // The local name is kept and the name of others is appended to it, e.g. "Roadmap (Plan)"
+    AppendSuffix = 1,
+}
+
+impl std::default::Default for RenameMergePolicy {
+    fn default() -> Self { RenameMergePolicy::LatestWins }
+}
+
+impl std::convert::From<i32> for RenameMergePolicy {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => RenameMergePolicy::LatestWins,
+            1 => RenameMergePolicy::AppendSuffix,
+            _ => {
+                log::error!("Invalid rename merge policy: {}", val);
+                RenameMergePolicy::LatestWins
+            },
+        }
+    }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug, PartialEq)]
+pub struct RenameMergePolicySetting {
+    #[pb(index = 1)]
+    pub policy: RenameMergePolicy,
+}
+
+// Sent with the RenameConflicted notification after the local rename and the rename of others are merged
+#[derive(Default, ProtoBuf, Clone, Debug, PartialEq)]
+pub struct RenameConflict {
+    #[pb(index = 1)]
+    pub object_id: String,
+
+    #[pb(index = 2)]
+    pub local_name: String,
+
+    #[pb(index = 3)]
+    pub remote_name: String,
+
+    #[pb(index = 4)]
+    pub resolved_name: String,
+
+    #[pb(index = 5)]
+    pub policy: RenameMergePolicy,
+
+    // The device that made the rename of others, it's empty if it's unknown
+    #[pb(index = 6)]
+    pub remote_device_id: String,
+}
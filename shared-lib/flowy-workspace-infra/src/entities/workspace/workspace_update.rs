@@ -46,3 +46,33 @@ impl TryInto<UpdateWorkspaceParams> for UpdateWorkspaceRequest {
         })
     }
 }
+
+#[derive(ProtoBuf, Default)]
+pub struct ArchiveWorkspaceRequest {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub archived: bool,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct ArchiveWorkspaceParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub archived: bool,
+}
+
+impl TryInto<ArchiveWorkspaceParams> for ArchiveWorkspaceRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ArchiveWorkspaceParams, Self::Error> {
+        let workspace_id = WorkspaceId::parse(self.workspace_id)?;
+        Ok(ArchiveWorkspaceParams {
+            workspace_id: workspace_id.0,
+            archived: self.archived,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use flowy_derive::ProtoBuf;
+
+// The API calls of one day (yyyy-mm-dd in UTC). The bot_id is empty for the calls that were sent with
+// the token of the user, otherwise each bot has its own usage.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct ApiUsage {
+    #[pb(index = 1)]
+    pub date: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub bot_id: String,
+
+    #[pb(index = 4)]
+    pub call_count: i64,
+
+    #[pb(index = 5)]
+    pub request_bytes: i64,
+
+    #[pb(index = 6)]
+    pub response_bytes: i64,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedApiUsage {
+    #[pb(index = 1)]
+    pub items: Vec<ApiUsage>,
+}
+
+// Reads the usage of the workspace owner and the bots of the workspace within the last days
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ReadApiUsageParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub days: i64,
+}
@@ -22,6 +22,12 @@ pub enum ErrorCode {
     #[display(fmt = "Workspace description too long")]
     WorkspaceNameTooLong = 4,
 
+    #[display(fmt = "Workspace is archived")]
+    WorkspaceArchived    = 5,
+
+    #[display(fmt = "Bot name can not be empty or longer than 256")]
+    BotNameInvalid       = 6,
+
     #[display(fmt = "App id can not be empty or whitespace")]
     AppIdInvalid         = 10,
 
@@ -46,9 +52,69 @@ pub enum ErrorCode {
     #[display(fmt = "View name too long")]
     ViewNameTooLong      = 25,
 
+    #[display(fmt = "View revision id can not be negative")]
+    ViewRevIdInvalid     = 26,
+
+    #[display(fmt = "The expire time of the share link can not be negative")]
+    ShareLinkExpireTimeInvalid = 27,
+
+    #[display(fmt = "The passcode of the share link can not be longer than 64")]
+    SharePasscodeTooLong = 28,
+
+    #[display(fmt = "The limit or offset of the list filter is invalid")]
+    ListFilterInvalid    = 30,
+
+    #[display(fmt = "The index of the move can not be negative")]
+    MoveIndexInvalid     = 31,
+
+    #[display(fmt = "The rank is invalid")]
+    RankInvalid          = 32,
+
+    #[display(fmt = "The view can't be moved under itself, its children or another workspace")]
+    MoveTargetInvalid    = 33,
+
+    #[display(fmt = "The search query can not be empty and the limit can not be larger than 100")]
+    SearchQueryInvalid   = 40,
+
+    #[display(fmt = "The path of the export can not be empty")]
+    ExportPathInvalid    = 50,
+
+    #[display(fmt = "The path of the attachment doesn't refer to a file")]
+    AttachmentPathInvalid = 51,
+
+    #[display(fmt = "The attachment can not be larger than 20MB")]
+    AttachmentTooLarge   = 52,
+
+    #[display(fmt = "The path of the import doesn't refer to a folder")]
+    ImportPathInvalid    = 53,
+
+    #[display(fmt = "The url of the webhook should start with http:// or https://")]
+    WebhookUrlInvalid    = 54,
+
+    #[display(fmt = "The secret of the webhook is too long")]
+    WebhookSecretTooLong = 55,
+
+    #[display(fmt = "The account to merge must be another account that signed in on this device")]
+    MergeSourceInvalid   = 56,
+
+    #[display(fmt = "The email of the member is invalid")]
+    MemberEmailInvalid   = 60,
+
+    #[display(fmt = "The member can only be an editor or a viewer")]
+    MemberRoleInvalid    = 61,
+
     #[display(fmt = "User unauthorized")]
     UserUnauthorized     = 100,
 
+    #[display(fmt = "The user doesn't have the permission")]
+    PermissionDenied     = 101,
+
+    #[display(fmt = "Too many requests, try again later")]
+    TooManyRequests      = 102,
+
+    #[display(fmt = "The request is too large")]
+    PayloadTooLarge      = 103,
+
     #[display(fmt = "Workspace websocket error")]
     WsConnectError       = 200,
 
@@ -7,4 +7,5 @@ mod macros;
 
 // #[cfg(feature = "backend")]
 pub mod protobuf;
+pub mod rank;
 pub mod user_default;
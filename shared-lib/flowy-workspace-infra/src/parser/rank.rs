@@ -0,0 +1,18 @@
+use crate::{errors::ErrorCode, rank::is_valid_rank};
+
+#[derive(Debug)]
+pub struct ItemRank(pub String);
+
+impl ItemRank {
+    pub fn parse(s: String) -> Result<ItemRank, ErrorCode> {
+        if !is_valid_rank(&s) {
+            return Err(ErrorCode::RankInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for ItemRank {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -3,9 +3,13 @@ mod view_desc;
 mod view_id;
 mod view_name;
 mod view_thumbnail;
+mod webhook_secret;
+mod webhook_url;
 
 pub use delta_data::*;
 pub use view_desc::*;
 pub use view_id::*;
 pub use view_name::*;
 pub use view_thumbnail::*;
+pub use webhook_secret::*;
+pub use webhook_url::*;
@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+
+pub const MAX_WEBHOOK_SECRET_LEN: usize = 256;
+
+#[derive(Debug)]
+pub struct WebhookSecret(pub String);
+
+impl WebhookSecret {
+    pub fn parse(s: String) -> Result<WebhookSecret, ErrorCode> {
+        if s.len() > MAX_WEBHOOK_SECRET_LEN {
+            return Err(ErrorCode::WebhookSecretTooLong);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for WebhookSecret {
+    fn as_ref(&self) -> &str { &self.0 }
+}
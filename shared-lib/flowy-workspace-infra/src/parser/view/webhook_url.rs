@@ -0,0 +1,25 @@
+use crate::errors::ErrorCode;
+
+pub const MAX_WEBHOOK_URL_LEN: usize = 2048;
+
+#[derive(Debug)]
+pub struct WebhookUrl(pub String);
+
+impl WebhookUrl {
+    pub fn parse(s: String) -> Result<WebhookUrl, ErrorCode> {
+        let s = s.trim().to_owned();
+        if !s.starts_with("http://") && !s.starts_with("https://") {
+            return Err(ErrorCode::WebhookUrlInvalid);
+        }
+
+        if s.len() > MAX_WEBHOOK_URL_LEN || s.contains(char::is_whitespace) {
+            return Err(ErrorCode::WebhookUrlInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for WebhookUrl {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -0,0 +1,19 @@
+use crate::errors::ErrorCode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct BotName(pub String);
+
+impl BotName {
+    pub fn parse(s: String) -> Result<BotName, ErrorCode> {
+        if s.trim().is_empty() || s.graphemes(true).count() > 256 {
+            return Err(ErrorCode::BotNameInvalid);
+        }
+
+        Ok(Self(s))
+    }
+}
+
+impl AsRef<str> for BotName {
+    fn as_ref(&self) -> &str { &self.0 }
+}
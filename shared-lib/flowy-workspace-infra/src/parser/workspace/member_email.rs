@@ -0,0 +1,19 @@
+use crate::errors::ErrorCode;
+
+#[derive(Debug)]
+pub struct MemberEmail(pub String);
+
+impl MemberEmail {
+    // It's only checked roughly, the member is found by the email that the user signed up with
+    pub fn parse(s: String) -> Result<MemberEmail, ErrorCode> {
+        let email = s.trim();
+        match email.split_once('@') {
+            Some((name, domain)) if !name.is_empty() && !domain.is_empty() => Ok(Self(email.to_owned())),
+            _ => Err(ErrorCode::MemberEmailInvalid),
+        }
+    }
+}
+
+impl AsRef<str> for MemberEmail {
+    fn as_ref(&self) -> &str { &self.0 }
+}
@@ -1,7 +1,11 @@
+mod bot_name;
+mod member_email;
 mod workspace_desc;
 mod workspace_id;
 mod workspace_name;
 
+pub use bot_name::*;
+pub use member_email::*;
 pub use workspace_desc::*;
 pub use workspace_id::*;
 pub use workspace_name::*;
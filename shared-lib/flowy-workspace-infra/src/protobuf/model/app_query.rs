@@ -27,6 +27,8 @@
 pub struct QueryAppRequest {
     // message fields
     pub app_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // message oneof groups
+    pub one_of_view_filter: ::std::option::Option<QueryAppRequest_oneof_one_of_view_filter>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -38,6 +40,11 @@ impl<'a> ::std::default::Default for &'a QueryAppRequest {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum QueryAppRequest_oneof_one_of_view_filter {
+    view_filter(super::workspace_filter::ListFilter),
+}
+
 impl QueryAppRequest {
     pub fn new() -> QueryAppRequest {
         ::std::default::Default::default()
@@ -67,10 +74,64 @@ impl QueryAppRequest {
     pub fn take_app_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
         ::std::mem::replace(&mut self.app_ids, ::protobuf::RepeatedField::new())
     }
+
+    // .ListFilter view_filter = 2;
+
+
+    pub fn get_view_filter(&self) -> &super::workspace_filter::ListFilter {
+        match self.one_of_view_filter {
+            ::std::option::Option::Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(ref v)) => v,
+            _ => <super::workspace_filter::ListFilter as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_view_filter(&mut self) {
+        self.one_of_view_filter = ::std::option::Option::None;
+    }
+
+    pub fn has_view_filter(&self) -> bool {
+        match self.one_of_view_filter {
+            ::std::option::Option::Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_filter(&mut self, v: super::workspace_filter::ListFilter) {
+        self.one_of_view_filter = ::std::option::Option::Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_view_filter(&mut self) -> &mut super::workspace_filter::ListFilter {
+        if let ::std::option::Option::Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(_)) = self.one_of_view_filter {
+        } else {
+            self.one_of_view_filter = ::std::option::Option::Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(super::workspace_filter::ListFilter::new()));
+        }
+        match self.one_of_view_filter {
+            ::std::option::Option::Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_view_filter(&mut self) -> super::workspace_filter::ListFilter {
+        if self.has_view_filter() {
+            match self.one_of_view_filter.take() {
+                ::std::option::Option::Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::workspace_filter::ListFilter::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for QueryAppRequest {
     fn is_initialized(&self) -> bool {
+        if let Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(ref v)) = self.one_of_view_filter {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -81,6 +142,12 @@ impl ::protobuf::Message for QueryAppRequest {
                 1 => {
                     ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.app_ids)?;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_view_filter = ::std::option::Option::Some(QueryAppRequest_oneof_one_of_view_filter::view_filter(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -96,6 +163,14 @@ impl ::protobuf::Message for QueryAppRequest {
         for value in &self.app_ids {
             my_size += ::protobuf::rt::string_size(1, &value);
         };
+        if let ::std::option::Option::Some(ref v) = self.one_of_view_filter {
+            match v {
+                &QueryAppRequest_oneof_one_of_view_filter::view_filter(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -105,6 +180,15 @@ impl ::protobuf::Message for QueryAppRequest {
         for v in &self.app_ids {
             os.write_string(1, &v)?;
         };
+        if let ::std::option::Option::Some(ref v) = self.one_of_view_filter {
+            match v {
+                &QueryAppRequest_oneof_one_of_view_filter::view_filter(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -148,6 +232,11 @@ impl ::protobuf::Message for QueryAppRequest {
                 |m: &QueryAppRequest| { &m.app_ids },
                 |m: &mut QueryAppRequest| { &mut m.app_ids },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::workspace_filter::ListFilter>(
+                "view_filter",
+                QueryAppRequest::has_view_filter,
+                QueryAppRequest::get_view_filter,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryAppRequest>(
                 "QueryAppRequest",
                 fields,
@@ -165,6 +254,7 @@ impl ::protobuf::Message for QueryAppRequest {
 impl ::protobuf::Clear for QueryAppRequest {
     fn clear(&mut self) {
         self.app_ids.clear();
+        self.one_of_view_filter = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -185,6 +275,8 @@ impl ::protobuf::reflect::ProtobufValue for QueryAppRequest {
 pub struct AppIdentifier {
     // message fields
     pub app_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_view_filter: ::std::option::Option<AppIdentifier_oneof_one_of_view_filter>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -196,6 +288,11 @@ impl<'a> ::std::default::Default for &'a AppIdentifier {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum AppIdentifier_oneof_one_of_view_filter {
+    view_filter(super::workspace_filter::ListFilter),
+}
+
 impl AppIdentifier {
     pub fn new() -> AppIdentifier {
         ::std::default::Default::default()
@@ -226,10 +323,64 @@ impl AppIdentifier {
     pub fn take_app_id(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.app_id, ::std::string::String::new())
     }
+
+    // .ListFilter view_filter = 2;
+
+
+    pub fn get_view_filter(&self) -> &super::workspace_filter::ListFilter {
+        match self.one_of_view_filter {
+            ::std::option::Option::Some(AppIdentifier_oneof_one_of_view_filter::view_filter(ref v)) => v,
+            _ => <super::workspace_filter::ListFilter as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_view_filter(&mut self) {
+        self.one_of_view_filter = ::std::option::Option::None;
+    }
+
+    pub fn has_view_filter(&self) -> bool {
+        match self.one_of_view_filter {
+            ::std::option::Option::Some(AppIdentifier_oneof_one_of_view_filter::view_filter(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_filter(&mut self, v: super::workspace_filter::ListFilter) {
+        self.one_of_view_filter = ::std::option::Option::Some(AppIdentifier_oneof_one_of_view_filter::view_filter(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_view_filter(&mut self) -> &mut super::workspace_filter::ListFilter {
+        if let ::std::option::Option::Some(AppIdentifier_oneof_one_of_view_filter::view_filter(_)) = self.one_of_view_filter {
+        } else {
+            self.one_of_view_filter = ::std::option::Option::Some(AppIdentifier_oneof_one_of_view_filter::view_filter(super::workspace_filter::ListFilter::new()));
+        }
+        match self.one_of_view_filter {
+            ::std::option::Option::Some(AppIdentifier_oneof_one_of_view_filter::view_filter(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_view_filter(&mut self) -> super::workspace_filter::ListFilter {
+        if self.has_view_filter() {
+            match self.one_of_view_filter.take() {
+                ::std::option::Option::Some(AppIdentifier_oneof_one_of_view_filter::view_filter(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::workspace_filter::ListFilter::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for AppIdentifier {
     fn is_initialized(&self) -> bool {
+        if let Some(AppIdentifier_oneof_one_of_view_filter::view_filter(ref v)) = self.one_of_view_filter {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -240,6 +391,12 @@ impl ::protobuf::Message for AppIdentifier {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.app_id)?;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_view_filter = ::std::option::Option::Some(AppIdentifier_oneof_one_of_view_filter::view_filter(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -255,6 +412,14 @@ impl ::protobuf::Message for AppIdentifier {
         if !self.app_id.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.app_id);
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_view_filter {
+            match v {
+                &AppIdentifier_oneof_one_of_view_filter::view_filter(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -264,6 +429,15 @@ impl ::protobuf::Message for AppIdentifier {
         if !self.app_id.is_empty() {
             os.write_string(1, &self.app_id)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_view_filter {
+            match v {
+                &AppIdentifier_oneof_one_of_view_filter::view_filter(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -307,6 +481,11 @@ impl ::protobuf::Message for AppIdentifier {
                 |m: &AppIdentifier| { &m.app_id },
                 |m: &mut AppIdentifier| { &mut m.app_id },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::workspace_filter::ListFilter>(
+                "view_filter",
+                AppIdentifier::has_view_filter,
+                AppIdentifier::get_view_filter,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<AppIdentifier>(
                 "AppIdentifier",
                 fields,
@@ -324,6 +503,7 @@ impl ::protobuf::Message for AppIdentifier {
 impl ::protobuf::Clear for AppIdentifier {
     fn clear(&mut self) {
         self.app_id.clear();
+        self.one_of_view_filter = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -341,18 +521,29 @@ impl ::protobuf::reflect::ProtobufValue for AppIdentifier {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0fapp_query.proto\"*\n\x0fQueryAppRequest\x12\x17\n\x07app_ids\x18\
-    \x01\x20\x03(\tR\x06appIds\"&\n\rAppIdentifier\x12\x15\n\x06app_id\x18\
-    \x01\x20\x01(\tR\x05appIdJ\xbe\x01\n\x06\x12\x04\0\0\x07\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\n\n\x03\x04\
-    \0\x01\x12\x03\x02\x08\x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x20\n\
-    \x0c\n\x05\x04\0\x02\0\x04\x12\x03\x03\x04\x0c\n\x0c\n\x05\x04\0\x02\0\
-    \x05\x12\x03\x03\r\x13\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x14\x1b\n\
-    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x1e\x1f\n\n\n\x02\x04\x01\x12\x04\
-    \x05\0\x07\x01\n\n\n\x03\x04\x01\x01\x12\x03\x05\x08\x15\n\x0b\n\x04\x04\
-    \x01\x02\0\x12\x03\x06\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x06\
-    \x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x06\x0b\x11\n\x0c\n\x05\x04\
-    \x01\x02\0\x03\x12\x03\x06\x14\x15b\x06proto3\
+    \n\x0fapp_query.proto\x1a\x16workspace_filter.proto\"p\n\x0fQueryAppRequ\
+    est\x12\x17\n\x07app_ids\x18\x01\x20\x03(\tR\x06appIds\x12.\n\x0bview_fi\
+    lter\x18\x02\x20\x01(\x0b2\x0b.ListFilterH\0R\nviewFilterB\x14\n\x12one_\
+    of_view_filter\"l\n\rAppIdentifier\x12\x15\n\x06app_id\x18\x01\x20\x01(\
+    \tR\x05appId\x12.\n\x0bview_filter\x18\x02\x20\x01(\x0b2\x0b.ListFilterH\
+    \0R\nviewFilterB\x14\n\x12one_of_view_filterJ\xed\x02\n\x06\x12\x04\0\0\
+    \n\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x20\
+    \n\n\n\x02\x04\0\x12\x04\x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\
+    \x08\x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x20\n\x0c\n\x05\x04\0\
+    \x02\0\x04\x12\x03\x04\x04\x0c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\r\
+    \x13\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x14\x1b\n\x0c\n\x05\x04\0\
+    \x02\0\x03\x12\x03\x04\x1e\x1f\n\x0b\n\x04\x04\0\x08\0\x12\x03\x05\x04<\
+    \n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x05\n\x1c\n\x0b\n\x04\x04\0\x02\x01\
+    \x12\x03\x05\x1f:\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x05\x1f)\n\x0c\n\
+    \x05\x04\0\x02\x01\x01\x12\x03\x05*5\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
+    \x03\x0589\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\x07\x08\x15\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x16\n\x0c\
+    \n\x05\x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x08\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x14\x15\n\
+    \x0b\n\x04\x04\x01\x08\0\x12\x03\t\x04<\n\x0c\n\x05\x04\x01\x08\0\x01\
+    \x12\x03\t\n\x1c\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x1f:\n\x0c\n\x05\
+    \x04\x01\x02\x01\x06\x12\x03\t\x1f)\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\
+    \x03\t*5\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t89b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -495,6 +495,7 @@ impl ::protobuf::reflect::ProtobufValue for UpdateAppRequest {
 pub struct UpdateAppParams {
     // message fields
     pub app_id: ::std::string::String,
+    pub device_id: ::std::string::String,
     // message oneof groups
     pub one_of_name: ::std::option::Option<UpdateAppParams_oneof_one_of_name>,
     pub one_of_desc: ::std::option::Option<UpdateAppParams_oneof_one_of_desc>,
@@ -733,6 +734,32 @@ impl UpdateAppParams {
     pub fn set_is_trash(&mut self, v: bool) {
         self.one_of_is_trash = ::std::option::Option::Some(UpdateAppParams_oneof_one_of_is_trash::is_trash(v))
     }
+
+    // string device_id = 6;
+
+
+    pub fn get_device_id(&self) -> &str {
+        &self.device_id
+    }
+    pub fn clear_device_id(&mut self) {
+        self.device_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_id(&mut self, v: ::std::string::String) {
+        self.device_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_id(&mut self) -> &mut ::std::string::String {
+        &mut self.device_id
+    }
+
+    // Take field
+    pub fn take_device_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for UpdateAppParams {
@@ -776,6 +803,9 @@ impl ::protobuf::Message for UpdateAppParams {
                     }
                     self.one_of_is_trash = ::std::option::Option::Some(UpdateAppParams_oneof_one_of_is_trash::is_trash(is.read_bool()?));
                 },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -791,6 +821,9 @@ impl ::protobuf::Message for UpdateAppParams {
         if !self.app_id.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.app_id);
         }
+        if !self.device_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.device_id);
+        }
         if let ::std::option::Option::Some(ref v) = self.one_of_name {
             match v {
                 &UpdateAppParams_oneof_one_of_name::name(ref v) => {
@@ -829,6 +862,9 @@ impl ::protobuf::Message for UpdateAppParams {
         if !self.app_id.is_empty() {
             os.write_string(1, &self.app_id)?;
         }
+        if !self.device_id.is_empty() {
+            os.write_string(6, &self.device_id)?;
+        }
         if let ::std::option::Option::Some(ref v) = self.one_of_name {
             match v {
                 &UpdateAppParams_oneof_one_of_name::name(ref v) => {
@@ -922,6 +958,11 @@ impl ::protobuf::Message for UpdateAppParams {
                 UpdateAppParams::has_is_trash,
                 UpdateAppParams::get_is_trash,
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "device_id",
+                |m: &UpdateAppParams| { &m.device_id },
+                |m: &mut UpdateAppParams| { &mut m.device_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateAppParams>(
                 "UpdateAppParams",
                 fields,
@@ -943,6 +984,7 @@ impl ::protobuf::Clear for UpdateAppParams {
         self.one_of_desc = ::std::option::Option::None;
         self.one_of_color_style = ::std::option::Option::None;
         self.one_of_is_trash = ::std::option::Option::None;
+        self.device_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -966,53 +1008,57 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x01R\x04desc\x12.\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.ColorStyle\
     H\x02R\ncolorStyle\x12\x1b\n\x08is_trash\x18\x05\x20\x01(\x08H\x03R\x07i\
     sTrashB\r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x14\n\x12one_of_color_st\
-    yleB\x11\n\x0fone_of_is_trash\"\xe8\x01\n\x0fUpdateAppParams\x12\x15\n\
+    yleB\x11\n\x0fone_of_is_trash\"\x85\x02\n\x0fUpdateAppParams\x12\x15\n\
     \x06app_id\x18\x01\x20\x01(\tR\x05appId\x12\x14\n\x04name\x18\x02\x20\
     \x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04desc\
     \x12.\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.ColorStyleH\x02R\ncolor\
-    Style\x12\x1b\n\x08is_trash\x18\x05\x20\x01(\x08H\x03R\x07isTrashB\r\n\
-    \x0bone_of_nameB\r\n\x0bone_of_descB\x14\n\x12one_of_color_styleB\x11\n\
-    \x0fone_of_is_trashJ\xcb\x06\n\x06\x12\x04\0\0\x10\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1a\n\n\n\x02\x04\0\x12\
-    \x04\x03\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x18\n\x0b\n\x04\x04\
-    \0\x02\0\x12\x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\
-    \n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\
-    \0\x03\x12\x03\x04\x14\x15\n\x0b\n\x04\x04\0\x08\0\x12\x03\x05\x04*\n\
-    \x0c\n\x05\x04\0\x08\0\x01\x12\x03\x05\n\x15\n\x0b\n\x04\x04\0\x02\x01\
-    \x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x18\x1e\n\
-    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x1f#\n\x0c\n\x05\x04\0\x02\x01\
-    \x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x06\x04*\n\x0c\n\
-    \x05\x04\0\x08\x01\x01\x12\x03\x06\n\x15\n\x0b\n\x04\x04\0\x02\x02\x12\
-    \x03\x06\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x06\x18\x1e\n\x0c\n\
-    \x05\x04\0\x02\x02\x01\x12\x03\x06\x1f#\n\x0c\n\x05\x04\0\x02\x02\x03\
-    \x12\x03\x06&'\n\x0b\n\x04\x04\0\x08\x02\x12\x03\x07\x04<\n\x0c\n\x05\
-    \x04\0\x08\x02\x01\x12\x03\x07\n\x1c\n\x0b\n\x04\x04\0\x02\x03\x12\x03\
-    \x07\x1f:\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03\x07\x1f)\n\x0c\n\x05\x04\
-    \0\x02\x03\x01\x12\x03\x07*5\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0789\
-    \n\x0b\n\x04\x04\0\x08\x03\x12\x03\x08\x040\n\x0c\n\x05\x04\0\x08\x03\
-    \x01\x12\x03\x08\n\x19\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x08\x1c.\n\x0c\
-    \n\x05\x04\0\x02\x04\x05\x12\x03\x08\x1c\x20\n\x0c\n\x05\x04\0\x02\x04\
-    \x01\x12\x03\x08!)\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x08,-\n\n\n\x02\
-    \x04\x01\x12\x04\n\0\x10\x01\n\n\n\x03\x04\x01\x01\x12\x03\n\x08\x17\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x04\x16\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b\x0b\x11\
-    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b\x14\x15\n\x0b\n\x04\x04\x01\
-    \x08\0\x12\x03\x0c\x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\x0c\n\x15\
-    \n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0c\x18(\n\x0c\n\x05\x04\x01\x02\
-    \x01\x05\x12\x03\x0c\x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0c\
-    \x1f#\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0c&'\n\x0b\n\x04\x04\x01\
-    \x08\x01\x12\x03\r\x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\r\n\x15\
-    \n\x0b\n\x04\x04\x01\x02\x02\x12\x03\r\x18(\n\x0c\n\x05\x04\x01\x02\x02\
-    \x05\x12\x03\r\x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\r\x1f#\n\
-    \x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\r&'\n\x0b\n\x04\x04\x01\x08\x02\
-    \x12\x03\x0e\x04<\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\x0e\n\x1c\n\
-    \x0b\n\x04\x04\x01\x02\x03\x12\x03\x0e\x1f:\n\x0c\n\x05\x04\x01\x02\x03\
-    \x06\x12\x03\x0e\x1f)\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0e*5\n\
-    \x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0e89\n\x0b\n\x04\x04\x01\x08\x03\
-    \x12\x03\x0f\x040\n\x0c\n\x05\x04\x01\x08\x03\x01\x12\x03\x0f\n\x19\n\
-    \x0b\n\x04\x04\x01\x02\x04\x12\x03\x0f\x1c.\n\x0c\n\x05\x04\x01\x02\x04\
-    \x05\x12\x03\x0f\x1c\x20\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x0f!)\n\
-    \x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\x0f,-b\x06proto3\
+    Style\x12\x1b\n\x08is_trash\x18\x05\x20\x01(\x08H\x03R\x07isTrash\x12\
+    \x1b\n\tdevice_id\x18\x06\x20\x01(\tR\x08deviceIdB\r\n\x0bone_of_nameB\r\
+    \n\x0bone_of_descB\x14\n\x12one_of_color_styleB\x11\n\x0fone_of_is_trash\
+    J\x82\x07\n\x06\x12\x04\0\0\x11\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\
+    \n\x02\x03\0\x12\x03\x01\0\x1a\n\n\n\x02\x04\0\x12\x04\x03\0\t\x01\n\n\n\
+    \x03\x04\0\x01\x12\x03\x03\x08\x18\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\
+    \x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\
+    \x14\x15\n\x0b\n\x04\x04\0\x08\0\x12\x03\x05\x04*\n\x0c\n\x05\x04\0\x08\
+    \0\x01\x12\x03\x05\n\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x18(\n\
+    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x18\x1e\n\x0c\n\x05\x04\0\x02\
+    \x01\x01\x12\x03\x05\x1f#\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05&'\n\
+    \x0b\n\x04\x04\0\x08\x01\x12\x03\x06\x04*\n\x0c\n\x05\x04\0\x08\x01\x01\
+    \x12\x03\x06\n\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x06\x18(\n\x0c\n\
+    \x05\x04\0\x02\x02\x05\x12\x03\x06\x18\x1e\n\x0c\n\x05\x04\0\x02\x02\x01\
+    \x12\x03\x06\x1f#\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x06&'\n\x0b\n\
+    \x04\x04\0\x08\x02\x12\x03\x07\x04<\n\x0c\n\x05\x04\0\x08\x02\x01\x12\
+    \x03\x07\n\x1c\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x07\x1f:\n\x0c\n\x05\
+    \x04\0\x02\x03\x06\x12\x03\x07\x1f)\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
+    \x03\x07*5\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0789\n\x0b\n\x04\x04\0\
+    \x08\x03\x12\x03\x08\x040\n\x0c\n\x05\x04\0\x08\x03\x01\x12\x03\x08\n\
+    \x19\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x08\x1c.\n\x0c\n\x05\x04\0\x02\
+    \x04\x05\x12\x03\x08\x1c\x20\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x08!)\
+    \n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x08,-\n\n\n\x02\x04\x01\x12\x04\n\
+    \0\x11\x01\n\n\n\x03\x04\x01\x01\x12\x03\n\x08\x17\n\x0b\n\x04\x04\x01\
+    \x02\0\x12\x03\x0b\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x0b\x04\
+    \n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b\x0b\x11\n\x0c\n\x05\x04\x01\
+    \x02\0\x03\x12\x03\x0b\x14\x15\n\x0b\n\x04\x04\x01\x08\0\x12\x03\x0c\x04\
+    *\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\x0c\n\x15\n\x0b\n\x04\x04\x01\
+    \x02\x01\x12\x03\x0c\x18(\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x0c\
+    \x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0c\x1f#\n\x0c\n\x05\
+    \x04\x01\x02\x01\x03\x12\x03\x0c&'\n\x0b\n\x04\x04\x01\x08\x01\x12\x03\r\
+    \x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\r\n\x15\n\x0b\n\x04\x04\
+    \x01\x02\x02\x12\x03\r\x18(\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\r\
+    \x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\r\x1f#\n\x0c\n\x05\x04\
+    \x01\x02\x02\x03\x12\x03\r&'\n\x0b\n\x04\x04\x01\x08\x02\x12\x03\x0e\x04\
+    <\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\x0e\n\x1c\n\x0b\n\x04\x04\x01\
+    \x02\x03\x12\x03\x0e\x1f:\n\x0c\n\x05\x04\x01\x02\x03\x06\x12\x03\x0e\
+    \x1f)\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0e*5\n\x0c\n\x05\x04\x01\
+    \x02\x03\x03\x12\x03\x0e89\n\x0b\n\x04\x04\x01\x08\x03\x12\x03\x0f\x040\
+    \n\x0c\n\x05\x04\x01\x08\x03\x01\x12\x03\x0f\n\x19\n\x0b\n\x04\x04\x01\
+    \x02\x04\x12\x03\x0f\x1c.\n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03\x0f\
+    \x1c\x20\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x0f!)\n\x0c\n\x05\x04\
+    \x01\x02\x04\x03\x12\x03\x0f,-\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x10\
+    \x04\x19\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x10\x04\n\n\x0c\n\x05\
+    \x04\x01\x02\x05\x01\x12\x03\x10\x0b\x14\n\x0c\n\x05\x04\x01\x02\x05\x03\
+    \x12\x03\x10\x17\x18b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -30,6 +30,8 @@ pub enum ErrorCode {
     AppColorStyleInvalid = 2,
     WorkspaceDescTooLong = 3,
     WorkspaceNameTooLong = 4,
+    WorkspaceArchived = 5,
+    BotNameInvalid = 6,
     AppIdInvalid = 10,
     AppNameInvalid = 11,
     ViewNameInvalid = 20,
@@ -38,7 +40,27 @@ pub enum ErrorCode {
     ViewDescTooLong = 23,
     ViewDataInvalid = 24,
     ViewNameTooLong = 25,
+    ViewRevIdInvalid = 26,
+    ShareLinkExpireTimeInvalid = 27,
+    SharePasscodeTooLong = 28,
+    ListFilterInvalid = 30,
+    MoveIndexInvalid = 31,
+    RankInvalid = 32,
+    MoveTargetInvalid = 33,
+    SearchQueryInvalid = 40,
+    ExportPathInvalid = 50,
+    AttachmentPathInvalid = 51,
+    AttachmentTooLarge = 52,
+    ImportPathInvalid = 53,
+    WebhookUrlInvalid = 54,
+    WebhookSecretTooLong = 55,
+    MergeSourceInvalid = 56,
+    MemberEmailInvalid = 60,
+    MemberRoleInvalid = 61,
     UserUnauthorized = 100,
+    PermissionDenied = 101,
+    TooManyRequests = 102,
+    PayloadTooLarge = 103,
     WsConnectError = 200,
     InternalError = 1000,
     RecordNotFound = 1001,
@@ -56,6 +78,8 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             2 => ::std::option::Option::Some(ErrorCode::AppColorStyleInvalid),
             3 => ::std::option::Option::Some(ErrorCode::WorkspaceDescTooLong),
             4 => ::std::option::Option::Some(ErrorCode::WorkspaceNameTooLong),
+            5 => ::std::option::Option::Some(ErrorCode::WorkspaceArchived),
+            6 => ::std::option::Option::Some(ErrorCode::BotNameInvalid),
             10 => ::std::option::Option::Some(ErrorCode::AppIdInvalid),
             11 => ::std::option::Option::Some(ErrorCode::AppNameInvalid),
             20 => ::std::option::Option::Some(ErrorCode::ViewNameInvalid),
@@ -64,7 +88,27 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             23 => ::std::option::Option::Some(ErrorCode::ViewDescTooLong),
             24 => ::std::option::Option::Some(ErrorCode::ViewDataInvalid),
             25 => ::std::option::Option::Some(ErrorCode::ViewNameTooLong),
+            26 => ::std::option::Option::Some(ErrorCode::ViewRevIdInvalid),
+            27 => ::std::option::Option::Some(ErrorCode::ShareLinkExpireTimeInvalid),
+            28 => ::std::option::Option::Some(ErrorCode::SharePasscodeTooLong),
+            30 => ::std::option::Option::Some(ErrorCode::ListFilterInvalid),
+            31 => ::std::option::Option::Some(ErrorCode::MoveIndexInvalid),
+            32 => ::std::option::Option::Some(ErrorCode::RankInvalid),
+            33 => ::std::option::Option::Some(ErrorCode::MoveTargetInvalid),
+            40 => ::std::option::Option::Some(ErrorCode::SearchQueryInvalid),
+            50 => ::std::option::Option::Some(ErrorCode::ExportPathInvalid),
+            51 => ::std::option::Option::Some(ErrorCode::AttachmentPathInvalid),
+            52 => ::std::option::Option::Some(ErrorCode::AttachmentTooLarge),
+            53 => ::std::option::Option::Some(ErrorCode::ImportPathInvalid),
+            54 => ::std::option::Option::Some(ErrorCode::WebhookUrlInvalid),
+            55 => ::std::option::Option::Some(ErrorCode::WebhookSecretTooLong),
+            56 => ::std::option::Option::Some(ErrorCode::MergeSourceInvalid),
+            60 => ::std::option::Option::Some(ErrorCode::MemberEmailInvalid),
+            61 => ::std::option::Option::Some(ErrorCode::MemberRoleInvalid),
             100 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
+            101 => ::std::option::Option::Some(ErrorCode::PermissionDenied),
+            102 => ::std::option::Option::Some(ErrorCode::TooManyRequests),
+            103 => ::std::option::Option::Some(ErrorCode::PayloadTooLarge),
             200 => ::std::option::Option::Some(ErrorCode::WsConnectError),
             1000 => ::std::option::Option::Some(ErrorCode::InternalError),
             1001 => ::std::option::Option::Some(ErrorCode::RecordNotFound),
@@ -79,6 +123,8 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::AppColorStyleInvalid,
             ErrorCode::WorkspaceDescTooLong,
             ErrorCode::WorkspaceNameTooLong,
+            ErrorCode::WorkspaceArchived,
+            ErrorCode::BotNameInvalid,
             ErrorCode::AppIdInvalid,
             ErrorCode::AppNameInvalid,
             ErrorCode::ViewNameInvalid,
@@ -87,7 +133,27 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::ViewDescTooLong,
             ErrorCode::ViewDataInvalid,
             ErrorCode::ViewNameTooLong,
+            ErrorCode::ViewRevIdInvalid,
+            ErrorCode::ShareLinkExpireTimeInvalid,
+            ErrorCode::SharePasscodeTooLong,
+            ErrorCode::ListFilterInvalid,
+            ErrorCode::MoveIndexInvalid,
+            ErrorCode::RankInvalid,
+            ErrorCode::MoveTargetInvalid,
+            ErrorCode::SearchQueryInvalid,
+            ErrorCode::ExportPathInvalid,
+            ErrorCode::AttachmentPathInvalid,
+            ErrorCode::AttachmentTooLarge,
+            ErrorCode::ImportPathInvalid,
+            ErrorCode::WebhookUrlInvalid,
+            ErrorCode::WebhookSecretTooLong,
+            ErrorCode::MergeSourceInvalid,
+            ErrorCode::MemberEmailInvalid,
+            ErrorCode::MemberRoleInvalid,
             ErrorCode::UserUnauthorized,
+            ErrorCode::PermissionDenied,
+            ErrorCode::TooManyRequests,
+            ErrorCode::PayloadTooLarge,
             ErrorCode::WsConnectError,
             ErrorCode::InternalError,
             ErrorCode::RecordNotFound,
@@ -119,53 +185,108 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cerrors.proto*\x86\x03\n\tErrorCode\x12\x18\n\x14WorkspaceNameInval\
+    \n\x0cerrors.proto*\x87\x07\n\tErrorCode\x12\x18\n\x14WorkspaceNameInval\
     id\x10\0\x12\x16\n\x12WorkspaceIdInvalid\x10\x01\x12\x18\n\x14AppColorSt\
     yleInvalid\x10\x02\x12\x18\n\x14WorkspaceDescTooLong\x10\x03\x12\x18\n\
-    \x14WorkspaceNameTooLong\x10\x04\x12\x10\n\x0cAppIdInvalid\x10\n\x12\x12\
-    \n\x0eAppNameInvalid\x10\x0b\x12\x13\n\x0fViewNameInvalid\x10\x14\x12\
-    \x18\n\x14ViewThumbnailInvalid\x10\x15\x12\x11\n\rViewIdInvalid\x10\x16\
-    \x12\x13\n\x0fViewDescTooLong\x10\x17\x12\x13\n\x0fViewDataInvalid\x10\
-    \x18\x12\x13\n\x0fViewNameTooLong\x10\x19\x12\x14\n\x10UserUnauthorized\
-    \x10d\x12\x13\n\x0eWsConnectError\x10\xc8\x01\x12\x12\n\rInternalError\
-    \x10\xe8\x07\x12\x13\n\x0eRecordNotFound\x10\xe9\x07J\xe3\x05\n\x06\x12\
-    \x04\0\0\x14\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\
-    \x02\0\x14\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x0e\n\x0b\n\x04\x05\0\
-    \x02\0\x12\x03\x03\x04\x1d\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\
-    \x18\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x1b\x1c\n\x0b\n\x04\x05\0\
-    \x02\x01\x12\x03\x04\x04\x1b\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
-    \x04\x16\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x19\x1a\n\x0b\n\x04\
-    \x05\0\x02\x02\x12\x03\x05\x04\x1d\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
-    \x05\x04\x18\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x1b\x1c\n\x0b\n\
-    \x04\x05\0\x02\x03\x12\x03\x06\x04\x1d\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
-    \x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x1b\x1c\n\x0b\
-    \n\x04\x05\0\x02\x04\x12\x03\x07\x04\x1d\n\x0c\n\x05\x05\0\x02\x04\x01\
-    \x12\x03\x07\x04\x18\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1c\n\
-    \x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x16\n\x0c\n\x05\x05\0\x02\x05\
-    \x01\x12\x03\x08\x04\x10\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x13\
-    \x15\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x18\n\x0c\n\x05\x05\0\x02\
-    \x06\x01\x12\x03\t\x04\x12\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x15\
-    \x17\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x19\n\x0c\n\x05\x05\0\x02\
-    \x07\x01\x12\x03\n\x04\x13\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x16\
-    \x18\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x1e\n\x0c\n\x05\x05\0\x02\
-    \x08\x01\x12\x03\x0b\x04\x18\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\
-    \x1b\x1d\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\x17\n\x0c\n\x05\x05\0\
-    \x02\t\x01\x12\x03\x0c\x04\x11\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\
-    \x14\x16\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x19\n\x0c\n\x05\x05\0\x02\
-    \n\x01\x12\x03\r\x04\x13\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x16\x18\n\
-    \x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x19\n\x0c\n\x05\x05\0\x02\x0b\
-    \x01\x12\x03\x0e\x04\x13\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x16\
-    \x18\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\x19\n\x0c\n\x05\x05\0\x02\
-    \x0c\x01\x12\x03\x0f\x04\x13\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\
-    \x16\x18\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x1b\n\x0c\n\x05\x05\0\
-    \x02\r\x01\x12\x03\x10\x04\x14\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\
-    \x17\x1a\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x19\n\x0c\n\x05\x05\0\
-    \x02\x0e\x01\x12\x03\x11\x04\x12\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\
-    \x11\x15\x18\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x19\n\x0c\n\x05\
-    \x05\0\x02\x0f\x01\x12\x03\x12\x04\x11\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\
-    \x03\x12\x14\x18\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04\x1a\n\x0c\n\
-    \x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x12\n\x0c\n\x05\x05\0\x02\x10\x02\
-    \x12\x03\x13\x15\x19b\x06proto3\
+    \x14WorkspaceNameTooLong\x10\x04\x12\x15\n\x11WorkspaceArchived\x10\x05\
+    \x12\x12\n\x0eBotNameInvalid\x10\x06\x12\x10\n\x0cAppIdInvalid\x10\n\x12\
+    \x12\n\x0eAppNameInvalid\x10\x0b\x12\x13\n\x0fViewNameInvalid\x10\x14\
+    \x12\x18\n\x14ViewThumbnailInvalid\x10\x15\x12\x11\n\rViewIdInvalid\x10\
+    \x16\x12\x13\n\x0fViewDescTooLong\x10\x17\x12\x13\n\x0fViewDataInvalid\
+    \x10\x18\x12\x13\n\x0fViewNameTooLong\x10\x19\x12\x14\n\x10ViewRevIdInva\
+    lid\x10\x1a\x12\x1e\n\x1aShareLinkExpireTimeInvalid\x10\x1b\x12\x18\n\
+    \x14SharePasscodeTooLong\x10\x1c\x12\x15\n\x11ListFilterInvalid\x10\x1e\
+    \x12\x14\n\x10MoveIndexInvalid\x10\x1f\x12\x0f\n\x0bRankInvalid\x10\x20\
+    \x12\x15\n\x11MoveTargetInvalid\x10!\x12\x16\n\x12SearchQueryInvalid\x10\
+    (\x12\x15\n\x11ExportPathInvalid\x102\x12\x19\n\x15AttachmentPathInvalid\
+    \x103\x12\x16\n\x12AttachmentTooLarge\x104\x12\x15\n\x11ImportPathInvali\
+    d\x105\x12\x15\n\x11WebhookUrlInvalid\x106\x12\x18\n\x14WebhookSecretToo\
+    Long\x107\x12\x16\n\x12MergeSourceInvalid\x108\x12\x16\n\x12MemberEmailI\
+    nvalid\x10<\x12\x15\n\x11MemberRoleInvalid\x10=\x12\x14\n\x10UserUnautho\
+    rized\x10d\x12\x14\n\x10PermissionDenied\x10e\x12\x13\n\x0fTooManyReques\
+    ts\x10f\x12\x13\n\x0fPayloadTooLarge\x10g\x12\x13\n\x0eWsConnectError\
+    \x10\xc8\x01\x12\x12\n\rInternalError\x10\xe8\x07\x12\x13\n\x0eRecordNot\
+    Found\x10\xe9\x07J\xe9\x0c\n\x06\x12\x04\0\0*\x01\n\x08\n\x01\x0c\x12\
+    \x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0*\x01\n\n\n\x03\x05\0\x01\x12\
+    \x03\x02\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x1d\n\x0c\n\x05\
+    \x05\0\x02\0\x01\x12\x03\x03\x04\x18\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\
+    \x03\x1b\x1c\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x1b\n\x0c\n\x05\
+    \x05\0\x02\x01\x01\x12\x03\x04\x04\x16\n\x0c\n\x05\x05\0\x02\x01\x02\x12\
+    \x03\x04\x19\x1a\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\x1d\n\x0c\n\
+    \x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x18\n\x0c\n\x05\x05\0\x02\x02\x02\
+    \x12\x03\x05\x1b\x1c\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\x04\x1d\n\x0c\
+    \n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\
+    \x02\x12\x03\x06\x1b\x1c\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x07\x04\x1d\n\
+    \x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x18\n\x0c\n\x05\x05\0\x02\
+    \x04\x02\x12\x03\x07\x1b\x1c\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\
+    \x1a\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x15\n\x0c\n\x05\x05\0\
+    \x02\x05\x02\x12\x03\x08\x18\x19\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\
+    \x17\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\x12\n\x0c\n\x05\x05\0\
+    \x02\x06\x02\x12\x03\t\x15\x16\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\
+    \x16\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\x10\n\x0c\n\x05\x05\0\
+    \x02\x07\x02\x12\x03\n\x13\x15\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\
+    \x18\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x12\n\x0c\n\x05\x05\0\
+    \x02\x08\x02\x12\x03\x0b\x15\x17\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\
+    \x19\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\x13\n\x0c\n\x05\x05\0\
+    \x02\t\x02\x12\x03\x0c\x16\x18\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x1e\
+    \n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x18\n\x0c\n\x05\x05\0\x02\n\
+    \x02\x12\x03\r\x1b\x1d\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x17\n\
+    \x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x11\n\x0c\n\x05\x05\0\x02\
+    \x0b\x02\x12\x03\x0e\x14\x16\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\
+    \x19\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x13\n\x0c\n\x05\x05\0\
+    \x02\x0c\x02\x12\x03\x0f\x16\x18\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\
+    \x19\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x13\n\x0c\n\x05\x05\0\
+    \x02\r\x02\x12\x03\x10\x16\x18\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\
+    \x19\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x13\n\x0c\n\x05\x05\0\
+    \x02\x0e\x02\x12\x03\x11\x16\x18\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\
+    \x04\x1a\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x14\n\x0c\n\x05\
+    \x05\0\x02\x0f\x02\x12\x03\x12\x17\x19\n\x0b\n\x04\x05\0\x02\x10\x12\x03\
+    \x13\x04$\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x1e\n\x0c\n\x05\
+    \x05\0\x02\x10\x02\x12\x03\x13!#\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x14\
+    \x04\x1e\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\x18\n\x0c\n\x05\
+    \x05\0\x02\x11\x02\x12\x03\x14\x1b\x1d\n\x0b\n\x04\x05\0\x02\x12\x12\x03\
+    \x15\x04\x1b\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\x15\n\x0c\n\
+    \x05\x05\0\x02\x12\x02\x12\x03\x15\x18\x1a\n\x0b\n\x04\x05\0\x02\x13\x12\
+    \x03\x16\x04\x1a\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\x14\n\x0c\
+    \n\x05\x05\0\x02\x13\x02\x12\x03\x16\x17\x19\n\x0b\n\x04\x05\0\x02\x14\
+    \x12\x03\x17\x04\x15\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\x04\x0f\n\
+    \x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x17\x12\x14\n\x0b\n\x04\x05\0\x02\
+    \x15\x12\x03\x18\x04\x1b\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\x18\x04\
+    \x15\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03\x18\x18\x1a\n\x0b\n\x04\x05\0\
+    \x02\x16\x12\x03\x19\x04\x1c\n\x0c\n\x05\x05\0\x02\x16\x01\x12\x03\x19\
+    \x04\x16\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\x19\x19\x1b\n\x0b\n\x04\
+    \x05\0\x02\x17\x12\x03\x1a\x04\x1b\n\x0c\n\x05\x05\0\x02\x17\x01\x12\x03\
+    \x1a\x04\x15\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03\x1a\x18\x1a\n\x0b\n\
+    \x04\x05\0\x02\x18\x12\x03\x1b\x04\x1f\n\x0c\n\x05\x05\0\x02\x18\x01\x12\
+    \x03\x1b\x04\x19\n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03\x1b\x1c\x1e\n\x0b\
+    \n\x04\x05\0\x02\x19\x12\x03\x1c\x04\x1c\n\x0c\n\x05\x05\0\x02\x19\x01\
+    \x12\x03\x1c\x04\x16\n\x0c\n\x05\x05\0\x02\x19\x02\x12\x03\x1c\x19\x1b\n\
+    \x0b\n\x04\x05\0\x02\x1a\x12\x03\x1d\x04\x1b\n\x0c\n\x05\x05\0\x02\x1a\
+    \x01\x12\x03\x1d\x04\x15\n\x0c\n\x05\x05\0\x02\x1a\x02\x12\x03\x1d\x18\
+    \x1a\n\x0b\n\x04\x05\0\x02\x1b\x12\x03\x1e\x04\x1b\n\x0c\n\x05\x05\0\x02\
+    \x1b\x01\x12\x03\x1e\x04\x15\n\x0c\n\x05\x05\0\x02\x1b\x02\x12\x03\x1e\
+    \x18\x1a\n\x0b\n\x04\x05\0\x02\x1c\x12\x03\x1f\x04\x1e\n\x0c\n\x05\x05\0\
+    \x02\x1c\x01\x12\x03\x1f\x04\x18\n\x0c\n\x05\x05\0\x02\x1c\x02\x12\x03\
+    \x1f\x1b\x1d\n\x0b\n\x04\x05\0\x02\x1d\x12\x03\x20\x04\x1c\n\x0c\n\x05\
+    \x05\0\x02\x1d\x01\x12\x03\x20\x04\x16\n\x0c\n\x05\x05\0\x02\x1d\x02\x12\
+    \x03\x20\x19\x1b\n\x0b\n\x04\x05\0\x02\x1e\x12\x03!\x04\x1c\n\x0c\n\x05\
+    \x05\0\x02\x1e\x01\x12\x03!\x04\x16\n\x0c\n\x05\x05\0\x02\x1e\x02\x12\
+    \x03!\x19\x1b\n\x0b\n\x04\x05\0\x02\x1f\x12\x03\"\x04\x1b\n\x0c\n\x05\
+    \x05\0\x02\x1f\x01\x12\x03\"\x04\x15\n\x0c\n\x05\x05\0\x02\x1f\x02\x12\
+    \x03\"\x18\x1a\n\x0b\n\x04\x05\0\x02\x20\x12\x03#\x04\x1b\n\x0c\n\x05\
+    \x05\0\x02\x20\x01\x12\x03#\x04\x14\n\x0c\n\x05\x05\0\x02\x20\x02\x12\
+    \x03#\x17\x1a\n\x0b\n\x04\x05\0\x02!\x12\x03$\x04\x1b\n\x0c\n\x05\x05\0\
+    \x02!\x01\x12\x03$\x04\x14\n\x0c\n\x05\x05\0\x02!\x02\x12\x03$\x17\x1a\n\
+    \x0b\n\x04\x05\0\x02\"\x12\x03%\x04\x1a\n\x0c\n\x05\x05\0\x02\"\x01\x12\
+    \x03%\x04\x13\n\x0c\n\x05\x05\0\x02\"\x02\x12\x03%\x16\x19\n\x0b\n\x04\
+    \x05\0\x02#\x12\x03&\x04\x1a\n\x0c\n\x05\x05\0\x02#\x01\x12\x03&\x04\x13\
+    \n\x0c\n\x05\x05\0\x02#\x02\x12\x03&\x16\x19\n\x0b\n\x04\x05\0\x02$\x12\
+    \x03'\x04\x19\n\x0c\n\x05\x05\0\x02$\x01\x12\x03'\x04\x12\n\x0c\n\x05\
+    \x05\0\x02$\x02\x12\x03'\x15\x18\n\x0b\n\x04\x05\0\x02%\x12\x03(\x04\x19\
+    \n\x0c\n\x05\x05\0\x02%\x01\x12\x03(\x04\x11\n\x0c\n\x05\x05\0\x02%\x02\
+    \x12\x03(\x14\x18\n\x0b\n\x04\x05\0\x02&\x12\x03)\x04\x1a\n\x0c\n\x05\
+    \x05\0\x02&\x01\x12\x03)\x04\x12\n\x0c\n\x05\x05\0\x02&\x02\x12\x03)\x15\
+    \x19b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -38,3 +38,69 @@ pub use trash_create::*;
 
 mod export; 
 pub use export::*; 
+
+mod workspace_bot; 
+pub use workspace_bot::*; 
+
+mod view_publish; 
+pub use view_publish::*; 
+
+mod workspace_usage; 
+pub use workspace_usage::*; 
+
+mod workspace_filter; 
+pub use workspace_filter::*; 
+
+mod workspace_search; 
+pub use workspace_search::*; 
+
+mod import; 
+pub use import::*; 
+
+mod view_unread; 
+pub use view_unread::*; 
+
+mod view_share; 
+pub use view_share::*; 
+
+mod workspace_sync; 
+pub use workspace_sync::*; 
+
+mod app_export; 
+pub use app_export::*; 
+
+mod workspace_member; 
+pub use workspace_member::*; 
+
+mod attachment; 
+pub use attachment::*; 
+
+mod app_move; 
+pub use app_move::*; 
+
+mod view_move; 
+pub use view_move::*; 
+
+mod view_favorite; 
+pub use view_favorite::*; 
+
+mod workspace_clone; 
+pub use workspace_clone::*; 
+
+mod view_recent; 
+pub use view_recent::*; 
+
+mod folder_import; 
+pub use folder_import::*; 
+
+mod workspace_backup; 
+pub use workspace_backup::*; 
+
+mod view_webhook; 
+pub use view_webhook::*; 
+
+mod workspace_switch; 
+pub use workspace_switch::*; 
+
+mod workspace_merge; 
+pub use workspace_merge::*; 
@@ -887,6 +887,7 @@ pub enum TrashType {
     Unknown = 0,
     View = 1,
     App = 2,
+    Workspace = 3,
 }
 
 impl ::protobuf::ProtobufEnum for TrashType {
@@ -899,6 +900,7 @@ impl ::protobuf::ProtobufEnum for TrashType {
             0 => ::std::option::Option::Some(TrashType::Unknown),
             1 => ::std::option::Option::Some(TrashType::View),
             2 => ::std::option::Option::Some(TrashType::App),
+            3 => ::std::option::Option::Some(TrashType::Workspace),
             _ => ::std::option::Option::None
         }
     }
@@ -908,6 +910,7 @@ impl ::protobuf::ProtobufEnum for TrashType {
             TrashType::Unknown,
             TrashType::View,
             TrashType::App,
+            TrashType::Workspace,
         ];
         values
     }
@@ -945,49 +948,52 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     me\x18\x03\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_time\x18\
     \x04\x20\x01(\x03R\ncreateTime\x12\x1a\n\x02ty\x18\x05\x20\x01(\x0e2\n.T\
     rashTypeR\x02ty\"-\n\rRepeatedTrash\x12\x1c\n\x05items\x18\x01\x20\x03(\
-    \x0b2\x06.TrashR\x05items*+\n\tTrashType\x12\x0b\n\x07Unknown\x10\0\x12\
-    \x08\n\x04View\x10\x01\x12\x07\n\x03App\x10\x02J\xc7\x06\n\x06\x12\x04\0\
-    \0\x18\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\
-    \x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x18\n\x0b\n\x04\x04\0\x02\0\
-    \x12\x03\x03\x04'\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x03\x04\x0c\n\x0c\
-    \n\x05\x04\0\x02\0\x06\x12\x03\x03\r\x1c\n\x0c\n\x05\x04\0\x02\0\x01\x12\
-    \x03\x03\x1d\"\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03%&\n\x0b\n\x04\x04\
-    \0\x02\x01\x12\x03\x04\x04\x18\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\
-    \x04\x08\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\t\x13\n\x0c\n\x05\x04\
-    \0\x02\x01\x03\x12\x03\x04\x16\x17\n\n\n\x02\x04\x01\x12\x04\x06\0\t\x01\
-    \n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x17\n\x0b\n\x04\x04\x01\x02\0\x12\
-    \x03\x07\x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x07\x04\n\n\x0c\n\
-    \x05\x04\x01\x02\0\x01\x12\x03\x07\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03\x07\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x08\x04\x15\n\
-    \x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x08\x04\r\n\x0c\n\x05\x04\x01\x02\
-    \x01\x01\x12\x03\x08\x0e\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x08\
-    \x13\x14\n\n\n\x02\x04\x02\x12\x04\n\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\n\x08\r\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0b\x04\x12\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03\x0b\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0b\x10\x11\n\x0b\n\
-    \x04\x04\x02\x02\x01\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\
-    \x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0c\x0b\x0f\n\
-    \x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0c\x12\x13\n\x0b\n\x04\x04\x02\
-    \x02\x02\x12\x03\r\x04\x1c\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\r\x04\
-    \t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\r\n\x17\n\x0c\n\x05\x04\x02\
-    \x02\x02\x03\x12\x03\r\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x0e\
-    \x04\x1a\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x0e\x04\t\n\x0c\n\x05\
-    \x04\x02\x02\x03\x01\x12\x03\x0e\n\x15\n\x0c\n\x05\x04\x02\x02\x03\x03\
-    \x12\x03\x0e\x18\x19\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x0f\x04\x15\n\
-    \x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x0f\x04\r\n\x0c\n\x05\x04\x02\x02\
-    \x04\x01\x12\x03\x0f\x0e\x10\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x0f\
-    \x13\x14\n\n\n\x02\x04\x03\x12\x04\x11\0\x13\x01\n\n\n\x03\x04\x03\x01\
-    \x12\x03\x11\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\x1d\n\x0c\
-    \n\x05\x04\x03\x02\0\x04\x12\x03\x12\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\
-    \x06\x12\x03\x12\r\x12\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x12\x13\x18\
-    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\x1b\x1c\n\n\n\x02\x05\0\x12\
-    \x04\x14\0\x18\x01\n\n\n\x03\x05\0\x01\x12\x03\x14\x05\x0e\n\x0b\n\x04\
-    \x05\0\x02\0\x12\x03\x15\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x15\
-    \x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x15\x0e\x0f\n\x0b\n\x04\x05\
-    \0\x02\x01\x12\x03\x16\x04\r\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x16\
-    \x04\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x16\x0b\x0c\n\x0b\n\x04\
-    \x05\0\x02\x02\x12\x03\x17\x04\x0c\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
-    \x17\x04\x07\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x17\n\x0bb\x06proto3\
+    \x0b2\x06.TrashR\x05items*:\n\tTrashType\x12\x0b\n\x07Unknown\x10\0\x12\
+    \x08\n\x04View\x10\x01\x12\x07\n\x03App\x10\x02\x12\r\n\tWorkspace\x10\
+    \x03J\xf0\x06\n\x06\x12\x04\0\0\x19\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
+    \n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\
+    \x08\x18\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04'\n\x0c\n\x05\x04\0\x02\
+    \0\x04\x12\x03\x03\x04\x0c\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\r\x1c\
+    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x1d\"\n\x0c\n\x05\x04\0\x02\0\
+    \x03\x12\x03\x03%&\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x18\n\x0c\n\
+    \x05\x04\0\x02\x01\x05\x12\x03\x04\x04\x08\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\x04\t\x13\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x16\x17\n\n\
+    \n\x02\x04\x01\x12\x04\x06\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\
+    \x17\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x04\x12\n\x0c\n\x05\x04\x01\
+    \x02\0\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x07\
+    \x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\x10\x11\n\x0b\n\x04\x04\
+    \x01\x02\x01\x12\x03\x08\x04\x15\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\
+    \x08\x04\r\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x08\x0e\x10\n\x0c\n\
+    \x05\x04\x01\x02\x01\x03\x12\x03\x08\x13\x14\n\n\n\x02\x04\x02\x12\x04\n\
+    \0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\n\x08\r\n\x0b\n\x04\x04\x02\x02\
+    \0\x12\x03\x0b\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0b\x04\n\n\
+    \x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0b\x0b\r\n\x0c\n\x05\x04\x02\x02\0\
+    \x03\x12\x03\x0b\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0c\x04\x14\
+    \n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x02\
+    \x02\x01\x01\x12\x03\x0c\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\
+    \x0c\x12\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\r\x04\x1c\n\x0c\n\x05\
+    \x04\x02\x02\x02\x05\x12\x03\r\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\
+    \x03\r\n\x17\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\r\x1a\x1b\n\x0b\n\
+    \x04\x04\x02\x02\x03\x12\x03\x0e\x04\x1a\n\x0c\n\x05\x04\x02\x02\x03\x05\
+    \x12\x03\x0e\x04\t\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x0e\n\x15\n\
+    \x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x0e\x18\x19\n\x0b\n\x04\x04\x02\
+    \x02\x04\x12\x03\x0f\x04\x15\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x0f\
+    \x04\r\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x0f\x0e\x10\n\x0c\n\x05\
+    \x04\x02\x02\x04\x03\x12\x03\x0f\x13\x14\n\n\n\x02\x04\x03\x12\x04\x11\0\
+    \x13\x01\n\n\n\x03\x04\x03\x01\x12\x03\x11\x08\x15\n\x0b\n\x04\x04\x03\
+    \x02\0\x12\x03\x12\x04\x1d\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x12\x04\
+    \x0c\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x12\r\x12\n\x0c\n\x05\x04\x03\
+    \x02\0\x01\x12\x03\x12\x13\x18\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\
+    \x1b\x1c\n\n\n\x02\x05\0\x12\x04\x14\0\x19\x01\n\n\n\x03\x05\0\x01\x12\
+    \x03\x14\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x15\x04\x10\n\x0c\n\x05\
+    \x05\0\x02\0\x01\x12\x03\x15\x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\
+    \x15\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x16\x04\r\n\x0c\n\x05\x05\
+    \0\x02\x01\x01\x12\x03\x16\x04\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
+    \x16\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x17\x04\x0c\n\x0c\n\x05\
+    \x05\0\x02\x02\x01\x12\x03\x17\x04\x07\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
+    \x03\x17\n\x0b\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x18\x04\x12\n\x0c\n\x05\
+    \x05\0\x02\x03\x01\x12\x03\x18\x04\r\n\x0c\n\x05\x05\0\x02\x03\x02\x12\
+    \x03\x18\x10\x11b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
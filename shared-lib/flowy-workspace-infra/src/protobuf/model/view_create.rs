@@ -749,6 +749,8 @@ pub struct View {
     pub belongings: ::protobuf::SingularPtrField<RepeatedView>,
     pub modified_time: i64,
     pub create_time: i64,
+    pub rank: ::std::string::String,
+    pub modified_device_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -961,6 +963,58 @@ impl View {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // string rank = 10;
+
+
+    pub fn get_rank(&self) -> &str {
+        &self.rank
+    }
+    pub fn clear_rank(&mut self) {
+        self.rank.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rank(&mut self, v: ::std::string::String) {
+        self.rank = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rank(&mut self) -> &mut ::std::string::String {
+        &mut self.rank
+    }
+
+    // Take field
+    pub fn take_rank(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.rank, ::std::string::String::new())
+    }
+
+    // string modified_device_id = 11;
+
+
+    pub fn get_modified_device_id(&self) -> &str {
+        &self.modified_device_id
+    }
+    pub fn clear_modified_device_id(&mut self) {
+        self.modified_device_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_modified_device_id(&mut self, v: ::std::string::String) {
+        self.modified_device_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_modified_device_id(&mut self) -> &mut ::std::string::String {
+        &mut self.modified_device_id
+    }
+
+    // Take field
+    pub fn take_modified_device_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.modified_device_id, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for View {
@@ -1016,6 +1070,12 @@ impl ::protobuf::Message for View {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.rank)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.modified_device_id)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1056,6 +1116,12 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(9, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.rank.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.rank);
+        }
+        if !self.modified_device_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.modified_device_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1091,6 +1157,12 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             os.write_int64(9, self.create_time)?;
         }
+        if !self.rank.is_empty() {
+            os.write_string(10, &self.rank)?;
+        }
+        if !self.modified_device_id.is_empty() {
+            os.write_string(11, &self.modified_device_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1174,6 +1246,16 @@ impl ::protobuf::Message for View {
                 |m: &View| { &m.create_time },
                 |m: &mut View| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "rank",
+                |m: &View| { &m.rank },
+                |m: &mut View| { &mut m.rank },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "modified_device_id",
+                |m: &View| { &m.modified_device_id },
+                |m: &mut View| { &mut m.modified_device_id },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<View>(
                 "View",
                 fields,
@@ -1199,6 +1281,8 @@ impl ::protobuf::Clear for View {
         self.belongings.clear();
         self.modified_time = 0;
         self.create_time = 0;
+        self.rank.clear();
+        self.modified_device_id.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1441,87 +1525,94 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \nbelongToId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04\
     desc\x18\x03\x20\x01(\tR\x04desc\x12\x1c\n\tthumbnail\x18\x04\x20\x01(\t\
     R\tthumbnail\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08view\
-    Type\x12\x12\n\x04data\x18\x06\x20\x01(\tR\x04data\"\x97\x02\n\x04View\
+    Type\x12\x12\n\x04data\x18\x06\x20\x01(\tR\x04data\"\xd9\x02\n\x04View\
     \x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x20\n\x0cbelong_to_id\x18\
     \x02\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04na\
     me\x12\x12\n\x04desc\x18\x04\x20\x01(\tR\x04desc\x12&\n\tview_type\x18\
     \x05\x20\x01(\x0e2\t.ViewTypeR\x08viewType\x12\x18\n\x07version\x18\x06\
     \x20\x01(\x03R\x07version\x12-\n\nbelongings\x18\x07\x20\x01(\x0b2\r.Rep\
     eatedViewR\nbelongings\x12#\n\rmodified_time\x18\x08\x20\x01(\x03R\x0cmo\
-    difiedTime\x12\x1f\n\x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTime\"+\n\
-    \x0cRepeatedView\x12\x1b\n\x05items\x18\x01\x20\x03(\x0b2\x05.ViewR\x05i\
-    tems*\x1e\n\x08ViewType\x12\t\n\x05Blank\x10\0\x12\x07\n\x03Doc\x10\x01J\
-    \x88\x0b\n\x06\x12\x04\0\0\"\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\
-    \x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\
-    \x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\
-    \x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\
-    \n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\
-    \x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\
-    \x0b\n\x04\x04\0\x08\0\x12\x03\x06\x044\n\x0c\n\x05\x04\0\x08\0\x01\x12\
-    \x03\x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x1d2\n\x0c\n\x05\
-    \x04\0\x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
-    \x03\x06$-\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0601\n\x0b\n\x04\x04\0\
-    \x02\x04\x12\x03\x07\x04\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x07\
-    \x04\x0c\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\r\x16\n\x0c\n\x05\x04\
-    \0\x02\x04\x03\x12\x03\x07\x19\x1a\n\n\n\x02\x04\x01\x12\x04\t\0\x10\x01\
-    \n\n\n\x03\x04\x01\x01\x12\x03\t\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\
-    \x03\n\x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\n\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\n\x1a\x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x04\x14\n\x0c\n\
-    \x05\x04\x01\x02\x01\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x0b\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\x12\
-    \x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \x0c\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0c\x12\x13\n\x0b\n\
-    \x04\x04\x01\x02\x03\x12\x03\r\x04\x19\n\x0c\n\x05\x04\x01\x02\x03\x05\
-    \x12\x03\r\x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\r\x0b\x14\n\x0c\
-    \n\x05\x04\x01\x02\x03\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x01\x02\x04\
-    \x12\x03\x0e\x04\x1b\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x0e\x04\x0c\
-    \n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x0e\r\x16\n\x0c\n\x05\x04\x01\
-    \x02\x04\x03\x12\x03\x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x0f\
-    \x04\x14\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\x05\x01\x12\x03\x0f\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x05\x03\
-    \x12\x03\x0f\x12\x13\n\n\n\x02\x04\x02\x12\x04\x11\0\x1b\x01\n\n\n\x03\
-    \x04\x02\x01\x12\x03\x11\x08\x0c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x12\
-    \x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\0\x01\x12\x03\x12\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\
-    \x12\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x13\x04\x1c\n\x0c\n\x05\
-    \x04\x02\x02\x01\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\
-    \x12\x03\x13\x0b\x17\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x13\x1a\x1b\
-    \n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x14\x04\x14\n\x0c\n\x05\x04\x02\x02\
-    \x02\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x14\
-    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x14\x12\x13\n\x0b\n\x04\
-    \x04\x02\x02\x03\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\
-    \x03\x15\x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x15\x0b\x0f\n\x0c\
-    \n\x05\x04\x02\x02\x03\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\x02\x02\
-    \x04\x12\x03\x16\x04\x1b\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x16\x04\
-    \x0c\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x16\r\x16\n\x0c\n\x05\x04\
-    \x02\x02\x04\x03\x12\x03\x16\x19\x1a\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\
-    \x17\x04\x16\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x17\x04\t\n\x0c\n\
-    \x05\x04\x02\x02\x05\x01\x12\x03\x17\n\x11\n\x0c\n\x05\x04\x02\x02\x05\
-    \x03\x12\x03\x17\x14\x15\n\x0b\n\x04\x04\x02\x02\x06\x12\x03\x18\x04\x20\
-    \n\x0c\n\x05\x04\x02\x02\x06\x06\x12\x03\x18\x04\x10\n\x0c\n\x05\x04\x02\
-    \x02\x06\x01\x12\x03\x18\x11\x1b\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\
-    \x18\x1e\x1f\n\x0b\n\x04\x04\x02\x02\x07\x12\x03\x19\x04\x1c\n\x0c\n\x05\
-    \x04\x02\x02\x07\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x02\x02\x07\x01\
-    \x12\x03\x19\n\x17\n\x0c\n\x05\x04\x02\x02\x07\x03\x12\x03\x19\x1a\x1b\n\
-    \x0b\n\x04\x04\x02\x02\x08\x12\x03\x1a\x04\x1a\n\x0c\n\x05\x04\x02\x02\
-    \x08\x05\x12\x03\x1a\x04\t\n\x0c\n\x05\x04\x02\x02\x08\x01\x12\x03\x1a\n\
-    \x15\n\x0c\n\x05\x04\x02\x02\x08\x03\x12\x03\x1a\x18\x19\n\n\n\x02\x04\
-    \x03\x12\x04\x1c\0\x1e\x01\n\n\n\x03\x04\x03\x01\x12\x03\x1c\x08\x14\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x03\x1d\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\
-    \x04\x12\x03\x1d\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x1d\r\x11\
-    \n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x1d\x12\x17\n\x0c\n\x05\x04\x03\
-    \x02\0\x03\x12\x03\x1d\x1a\x1b\n\n\n\x02\x05\0\x12\x04\x1f\0\"\x01\n\n\n\
-    \x03\x05\0\x01\x12\x03\x1f\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x20\x04\
-    \x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x20\x04\t\n\x0c\n\x05\x05\0\x02\
-    \0\x02\x12\x03\x20\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03!\x04\x0c\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03!\x04\x07\n\x0c\n\x05\x05\0\x02\x01\
-    \x02\x12\x03!\n\x0bb\x06proto3\
+    difiedTime\x12\x1f\n\x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTime\x12\
+    \x12\n\x04rank\x18\n\x20\x01(\tR\x04rank\x12,\n\x12modified_device_id\
+    \x18\x0b\x20\x01(\tR\x10modifiedDeviceId\"+\n\x0cRepeatedView\x12\x1b\n\
+    \x05items\x18\x01\x20\x03(\x0b2\x05.ViewR\x05items*\x1e\n\x08ViewType\
+    \x12\t\n\x05Blank\x10\0\x12\x07\n\x03Doc\x10\x01J\xf6\x0b\n\x06\x12\x04\
+    \0\0$\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\
+    \x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\x0b\n\x04\x04\0\x02\0\
+    \x12\x03\x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\
+    \n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\n\x05\x04\0\x02\0\x03\
+    \x12\x03\x03\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x14\n\x0c\
+    \n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x12\x13\n\
+    \x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\x02\
+    \x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x0f\
+    \n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\x0b\n\x04\x04\0\x08\
+    \0\x12\x03\x06\x044\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x06\n\x1a\n\x0b\
+    \n\x04\x04\0\x02\x03\x12\x03\x06\x1d2\n\x0c\n\x05\x04\0\x02\x03\x05\x12\
+    \x03\x06\x1d#\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06$-\n\x0c\n\x05\
+    \x04\0\x02\x03\x03\x12\x03\x0601\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\
+    \x04\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x07\x04\x0c\n\x0c\n\x05\
+    \x04\0\x02\x04\x01\x12\x03\x07\r\x16\n\x0c\n\x05\x04\0\x02\x04\x03\x12\
+    \x03\x07\x19\x1a\n\n\n\x02\x04\x01\x12\x04\t\0\x10\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\t\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\n\x04\x1c\n\x0c\
+    \n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\n\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\n\x1a\x1b\n\x0b\
+    \n\x04\x04\x01\x02\x01\x12\x03\x0b\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\
+    \x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0b\x0b\
+    \x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\x12\x13\n\x0b\n\x04\x04\
+    \x01\x02\x02\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\
+    \x0c\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0c\x0b\x0f\n\x0c\n\
+    \x05\x04\x01\x02\x02\x03\x12\x03\x0c\x12\x13\n\x0b\n\x04\x04\x01\x02\x03\
+    \x12\x03\r\x04\x19\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\r\x04\n\n\x0c\
+    \n\x05\x04\x01\x02\x03\x01\x12\x03\r\x0b\x14\n\x0c\n\x05\x04\x01\x02\x03\
+    \x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x0e\x04\x1b\n\
+    \x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x0e\x04\x0c\n\x0c\n\x05\x04\x01\
+    \x02\x04\x01\x12\x03\x0e\r\x16\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\
+    \x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x0f\x04\x14\n\x0c\n\x05\
+    \x04\x01\x02\x05\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x01\x02\x05\x01\
+    \x12\x03\x0f\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x0f\x12\x13\
+    \n\n\n\x02\x04\x02\x12\x04\x11\0\x1d\x01\n\n\n\x03\x04\x02\x01\x12\x03\
+    \x11\x08\x0c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x12\x04\x12\n\x0c\n\x05\
+    \x04\x02\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
+    \x03\x12\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x12\x10\x11\n\x0b\n\
+    \x04\x04\x02\x02\x01\x12\x03\x13\x04\x1c\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x13\x0b\x17\n\
+    \x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x13\x1a\x1b\n\x0b\n\x04\x04\x02\
+    \x02\x02\x12\x03\x14\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x14\
+    \x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x14\x0b\x0f\n\x0c\n\x05\
+    \x04\x02\x02\x02\x03\x12\x03\x14\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\
+    \x03\x15\x04\x14\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x15\x04\n\n\x0c\
+    \n\x05\x04\x02\x02\x03\x01\x12\x03\x15\x0b\x0f\n\x0c\n\x05\x04\x02\x02\
+    \x03\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x16\x04\
+    \x1b\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x16\x04\x0c\n\x0c\n\x05\x04\
+    \x02\x02\x04\x01\x12\x03\x16\r\x16\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\
+    \x03\x16\x19\x1a\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x17\x04\x16\n\x0c\n\
+    \x05\x04\x02\x02\x05\x05\x12\x03\x17\x04\t\n\x0c\n\x05\x04\x02\x02\x05\
+    \x01\x12\x03\x17\n\x11\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x17\x14\
+    \x15\n\x0b\n\x04\x04\x02\x02\x06\x12\x03\x18\x04\x20\n\x0c\n\x05\x04\x02\
+    \x02\x06\x06\x12\x03\x18\x04\x10\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x03\
+    \x18\x11\x1b\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\x18\x1e\x1f\n\x0b\n\
+    \x04\x04\x02\x02\x07\x12\x03\x19\x04\x1c\n\x0c\n\x05\x04\x02\x02\x07\x05\
+    \x12\x03\x19\x04\t\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03\x19\n\x17\n\
+    \x0c\n\x05\x04\x02\x02\x07\x03\x12\x03\x19\x1a\x1b\n\x0b\n\x04\x04\x02\
+    \x02\x08\x12\x03\x1a\x04\x1a\n\x0c\n\x05\x04\x02\x02\x08\x05\x12\x03\x1a\
+    \x04\t\n\x0c\n\x05\x04\x02\x02\x08\x01\x12\x03\x1a\n\x15\n\x0c\n\x05\x04\
+    \x02\x02\x08\x03\x12\x03\x1a\x18\x19\n\x0b\n\x04\x04\x02\x02\t\x12\x03\
+    \x1b\x04\x15\n\x0c\n\x05\x04\x02\x02\t\x05\x12\x03\x1b\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\t\x01\x12\x03\x1b\x0b\x0f\n\x0c\n\x05\x04\x02\x02\t\x03\x12\
+    \x03\x1b\x12\x14\n\x0b\n\x04\x04\x02\x02\n\x12\x03\x1c\x04#\n\x0c\n\x05\
+    \x04\x02\x02\n\x05\x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x02\x02\n\x01\x12\
+    \x03\x1c\x0b\x1d\n\x0c\n\x05\x04\x02\x02\n\x03\x12\x03\x1c\x20\"\n\n\n\
+    \x02\x04\x03\x12\x04\x1e\0\x20\x01\n\n\n\x03\x04\x03\x01\x12\x03\x1e\x08\
+    \x14\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x1f\x04\x1c\n\x0c\n\x05\x04\x03\
+    \x02\0\x04\x12\x03\x1f\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x1f\
+    \r\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x1f\x12\x17\n\x0c\n\x05\x04\
+    \x03\x02\0\x03\x12\x03\x1f\x1a\x1b\n\n\n\x02\x05\0\x12\x04!\0$\x01\n\n\n\
+    \x03\x05\0\x01\x12\x03!\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\"\x04\x0e\
+    \n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\"\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\
+    \x12\x03\"\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03#\x04\x0c\n\x0c\n\x05\
+    \x05\0\x02\x01\x01\x12\x03#\x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\
+    \x03#\n\x0bb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
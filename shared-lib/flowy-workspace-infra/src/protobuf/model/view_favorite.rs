@@ -0,0 +1,633 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_favorite.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewFavoriteState {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub is_favorite: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewFavoriteState {
+    fn default() -> &'a ViewFavoriteState {
+        <ViewFavoriteState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ViewFavoriteState {
+    pub fn new() -> ViewFavoriteState {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // bool is_favorite = 2;
+
+
+    pub fn get_is_favorite(&self) -> bool {
+        self.is_favorite
+    }
+    pub fn clear_is_favorite(&mut self) {
+        self.is_favorite = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_favorite(&mut self, v: bool) {
+        self.is_favorite = v;
+    }
+}
+
+impl ::protobuf::Message for ViewFavoriteState {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_favorite = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.is_favorite != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.is_favorite != false {
+            os.write_bool(2, self.is_favorite)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewFavoriteState {
+        ViewFavoriteState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &ViewFavoriteState| { &m.view_id },
+                |m: &mut ViewFavoriteState| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_favorite",
+                |m: &ViewFavoriteState| { &m.is_favorite },
+                |m: &mut ViewFavoriteState| { &mut m.is_favorite },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewFavoriteState>(
+                "ViewFavoriteState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewFavoriteState {
+        static instance: ::protobuf::rt::LazyV2<ViewFavoriteState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewFavoriteState::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewFavoriteState {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.is_favorite = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewFavoriteState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewFavoriteState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Favorite {
+    // message fields
+    pub view: ::protobuf::SingularPtrField<super::view_create::View>,
+    pub favorite_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Favorite {
+    fn default() -> &'a Favorite {
+        <Favorite as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Favorite {
+    pub fn new() -> Favorite {
+        ::std::default::Default::default()
+    }
+
+    // .View view = 1;
+
+
+    pub fn get_view(&self) -> &super::view_create::View {
+        self.view.as_ref().unwrap_or_else(|| <super::view_create::View as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_view(&mut self) {
+        self.view.clear();
+    }
+
+    pub fn has_view(&self) -> bool {
+        self.view.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: super::view_create::View) {
+        self.view = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view(&mut self) -> &mut super::view_create::View {
+        if self.view.is_none() {
+            self.view.set_default();
+        }
+        self.view.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_view(&mut self) -> super::view_create::View {
+        self.view.take().unwrap_or_else(|| super::view_create::View::new())
+    }
+
+    // int64 favorite_time = 2;
+
+
+    pub fn get_favorite_time(&self) -> i64 {
+        self.favorite_time
+    }
+    pub fn clear_favorite_time(&mut self) {
+        self.favorite_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_favorite_time(&mut self, v: i64) {
+        self.favorite_time = v;
+    }
+}
+
+impl ::protobuf::Message for Favorite {
+    fn is_initialized(&self) -> bool {
+        for v in &self.view {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.view)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.favorite_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.view.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.favorite_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.favorite_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.view.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.favorite_time != 0 {
+            os.write_int64(2, self.favorite_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Favorite {
+        Favorite::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::View>>(
+                "view",
+                |m: &Favorite| { &m.view },
+                |m: &mut Favorite| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "favorite_time",
+                |m: &Favorite| { &m.favorite_time },
+                |m: &mut Favorite| { &mut m.favorite_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Favorite>(
+                "Favorite",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Favorite {
+        static instance: ::protobuf::rt::LazyV2<Favorite> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Favorite::new)
+    }
+}
+
+impl ::protobuf::Clear for Favorite {
+    fn clear(&mut self) {
+        self.view.clear();
+        self.favorite_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Favorite {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Favorite {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedFavorite {
+    // message fields
+    pub items: ::protobuf::RepeatedField<Favorite>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedFavorite {
+    fn default() -> &'a RepeatedFavorite {
+        <RepeatedFavorite as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedFavorite {
+    pub fn new() -> RepeatedFavorite {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Favorite items = 1;
+
+
+    pub fn get_items(&self) -> &[Favorite] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<Favorite>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<Favorite> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<Favorite> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedFavorite {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedFavorite {
+        RepeatedFavorite::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Favorite>>(
+                "items",
+                |m: &RepeatedFavorite| { &m.items },
+                |m: &mut RepeatedFavorite| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedFavorite>(
+                "RepeatedFavorite",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedFavorite {
+        static instance: ::protobuf::rt::LazyV2<RepeatedFavorite> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedFavorite::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedFavorite {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedFavorite {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedFavorite {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x13view_favorite.proto\x1a\x11view_create.proto\"M\n\x11ViewFavoriteS\
+    tate\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x1f\n\x0bis_\
+    favorite\x18\x02\x20\x01(\x08R\nisFavorite\"J\n\x08Favorite\x12\x19\n\
+    \x04view\x18\x01\x20\x01(\x0b2\x05.ViewR\x04view\x12#\n\rfavorite_time\
+    \x18\x02\x20\x01(\x03R\x0cfavoriteTime\"3\n\x10RepeatedFavorite\x12\x1f\
+    \n\x05items\x18\x01\x20\x03(\x0b2\t.FavoriteR\x05itemsJ\x86\x03\n\x06\
+    \x12\x04\0\0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\
+    \x03\x01\0\x1b\n\n\n\x02\x04\0\x12\x04\x03\0\x06\x01\n\n\n\x03\x04\0\x01\
+    \x12\x03\x03\x08\x19\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x17\n\x0c\n\
+    \x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\
+    \x03\x04\x0b\x12\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x15\x16\n\x0b\n\
+    \x04\x04\0\x02\x01\x12\x03\x05\x04\x19\n\x0c\n\x05\x04\0\x02\x01\x05\x12\
+    \x03\x05\x04\x08\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\t\x14\n\x0c\n\
+    \x05\x04\0\x02\x01\x03\x12\x03\x05\x17\x18\n\n\n\x02\x04\x01\x12\x04\x07\
+    \0\n\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x10\n\x0b\n\x04\x04\x01\
+    \x02\0\x12\x03\x08\x04\x12\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x08\x04\
+    \x08\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\t\r\n\x0c\n\x05\x04\x01\
+    \x02\0\x03\x12\x03\x08\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\
+    \x1c\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\t\x04\t\n\x0c\n\x05\x04\x01\
+    \x02\x01\x01\x12\x03\t\n\x17\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\
+    \x1a\x1b\n\n\n\x02\x04\x02\x12\x04\x0b\0\r\x01\n\n\n\x03\x04\x02\x01\x12\
+    \x03\x0b\x08\x18\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0c\x04\x20\n\x0c\n\
+    \x05\x04\x02\x02\0\x04\x12\x03\x0c\x04\x0c\n\x0c\n\x05\x04\x02\x02\0\x06\
+    \x12\x03\x0c\r\x15\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x16\x1b\n\
+    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0c\x1e\x1fb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
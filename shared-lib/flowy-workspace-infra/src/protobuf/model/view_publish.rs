@@ -0,0 +1,1145 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_publish.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PublishedView {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub slug: ::std::string::String,
+    pub publish_time: i64,
+    pub rev_id: i64,
+    pub is_pinned: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PublishedView {
+    fn default() -> &'a PublishedView {
+        <PublishedView as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PublishedView {
+    pub fn new() -> PublishedView {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string slug = 2;
+
+
+    pub fn get_slug(&self) -> &str {
+        &self.slug
+    }
+    pub fn clear_slug(&mut self) {
+        self.slug.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_slug(&mut self, v: ::std::string::String) {
+        self.slug = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_slug(&mut self) -> &mut ::std::string::String {
+        &mut self.slug
+    }
+
+    // Take field
+    pub fn take_slug(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.slug, ::std::string::String::new())
+    }
+
+    // int64 publish_time = 3;
+
+
+    pub fn get_publish_time(&self) -> i64 {
+        self.publish_time
+    }
+    pub fn clear_publish_time(&mut self) {
+        self.publish_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_publish_time(&mut self, v: i64) {
+        self.publish_time = v;
+    }
+
+    // int64 rev_id = 4;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+
+    // bool is_pinned = 5;
+
+
+    pub fn get_is_pinned(&self) -> bool {
+        self.is_pinned
+    }
+    pub fn clear_is_pinned(&mut self) {
+        self.is_pinned = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_pinned(&mut self, v: bool) {
+        self.is_pinned = v;
+    }
+}
+
+impl ::protobuf::Message for PublishedView {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.slug)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.publish_time = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_pinned = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.slug.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.slug);
+        }
+        if self.publish_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.publish_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_pinned != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.slug.is_empty() {
+            os.write_string(2, &self.slug)?;
+        }
+        if self.publish_time != 0 {
+            os.write_int64(3, self.publish_time)?;
+        }
+        if self.rev_id != 0 {
+            os.write_int64(4, self.rev_id)?;
+        }
+        if self.is_pinned != false {
+            os.write_bool(5, self.is_pinned)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PublishedView {
+        PublishedView::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &PublishedView| { &m.view_id },
+                |m: &mut PublishedView| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "slug",
+                |m: &PublishedView| { &m.slug },
+                |m: &mut PublishedView| { &mut m.slug },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "publish_time",
+                |m: &PublishedView| { &m.publish_time },
+                |m: &mut PublishedView| { &mut m.publish_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &PublishedView| { &m.rev_id },
+                |m: &mut PublishedView| { &mut m.rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_pinned",
+                |m: &PublishedView| { &m.is_pinned },
+                |m: &mut PublishedView| { &mut m.is_pinned },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PublishedView>(
+                "PublishedView",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PublishedView {
+        static instance: ::protobuf::rt::LazyV2<PublishedView> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PublishedView::new)
+    }
+}
+
+impl ::protobuf::Clear for PublishedView {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.slug.clear();
+        self.publish_time = 0;
+        self.rev_id = 0;
+        self.is_pinned = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PublishedView {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PublishedView {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewAccessLog {
+    // message fields
+    pub date: ::std::string::String,
+    pub read_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewAccessLog {
+    fn default() -> &'a ViewAccessLog {
+        <ViewAccessLog as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ViewAccessLog {
+    pub fn new() -> ViewAccessLog {
+        ::std::default::Default::default()
+    }
+
+    // string date = 1;
+
+
+    pub fn get_date(&self) -> &str {
+        &self.date
+    }
+    pub fn clear_date(&mut self) {
+        self.date.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date(&mut self, v: ::std::string::String) {
+        self.date = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_date(&mut self) -> &mut ::std::string::String {
+        &mut self.date
+    }
+
+    // Take field
+    pub fn take_date(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.date, ::std::string::String::new())
+    }
+
+    // int64 read_count = 2;
+
+
+    pub fn get_read_count(&self) -> i64 {
+        self.read_count
+    }
+    pub fn clear_read_count(&mut self) {
+        self.read_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_count(&mut self, v: i64) {
+        self.read_count = v;
+    }
+}
+
+impl ::protobuf::Message for ViewAccessLog {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.date)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.read_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.date.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.date);
+        }
+        if self.read_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.read_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.date.is_empty() {
+            os.write_string(1, &self.date)?;
+        }
+        if self.read_count != 0 {
+            os.write_int64(2, self.read_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewAccessLog {
+        ViewAccessLog::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "date",
+                |m: &ViewAccessLog| { &m.date },
+                |m: &mut ViewAccessLog| { &mut m.date },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "read_count",
+                |m: &ViewAccessLog| { &m.read_count },
+                |m: &mut ViewAccessLog| { &mut m.read_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewAccessLog>(
+                "ViewAccessLog",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewAccessLog {
+        static instance: ::protobuf::rt::LazyV2<ViewAccessLog> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewAccessLog::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewAccessLog {
+    fn clear(&mut self) {
+        self.date.clear();
+        self.read_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewAccessLog {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewAccessLog {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedViewAccessLog {
+    // message fields
+    pub items: ::protobuf::RepeatedField<ViewAccessLog>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedViewAccessLog {
+    fn default() -> &'a RepeatedViewAccessLog {
+        <RepeatedViewAccessLog as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedViewAccessLog {
+    pub fn new() -> RepeatedViewAccessLog {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ViewAccessLog items = 1;
+
+
+    pub fn get_items(&self) -> &[ViewAccessLog] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ViewAccessLog>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ViewAccessLog> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ViewAccessLog> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedViewAccessLog {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedViewAccessLog {
+        RepeatedViewAccessLog::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ViewAccessLog>>(
+                "items",
+                |m: &RepeatedViewAccessLog| { &m.items },
+                |m: &mut RepeatedViewAccessLog| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedViewAccessLog>(
+                "RepeatedViewAccessLog",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedViewAccessLog {
+        static instance: ::protobuf::rt::LazyV2<RepeatedViewAccessLog> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedViewAccessLog::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedViewAccessLog {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedViewAccessLog {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedViewAccessLog {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PinPublishedViewRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub rev_id: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PinPublishedViewRequest {
+    fn default() -> &'a PinPublishedViewRequest {
+        <PinPublishedViewRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PinPublishedViewRequest {
+    pub fn new() -> PinPublishedViewRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // int64 rev_id = 2;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+}
+
+impl ::protobuf::Message for PinPublishedViewRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.rev_id != 0 {
+            os.write_int64(2, self.rev_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PinPublishedViewRequest {
+        PinPublishedViewRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &PinPublishedViewRequest| { &m.view_id },
+                |m: &mut PinPublishedViewRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &PinPublishedViewRequest| { &m.rev_id },
+                |m: &mut PinPublishedViewRequest| { &mut m.rev_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PinPublishedViewRequest>(
+                "PinPublishedViewRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PinPublishedViewRequest {
+        static instance: ::protobuf::rt::LazyV2<PinPublishedViewRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PinPublishedViewRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for PinPublishedViewRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.rev_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PinPublishedViewRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PinPublishedViewRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PinPublishedViewParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub rev_id: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PinPublishedViewParams {
+    fn default() -> &'a PinPublishedViewParams {
+        <PinPublishedViewParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PinPublishedViewParams {
+    pub fn new() -> PinPublishedViewParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // int64 rev_id = 2;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+}
+
+impl ::protobuf::Message for PinPublishedViewParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.rev_id != 0 {
+            os.write_int64(2, self.rev_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PinPublishedViewParams {
+        PinPublishedViewParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &PinPublishedViewParams| { &m.view_id },
+                |m: &mut PinPublishedViewParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &PinPublishedViewParams| { &m.rev_id },
+                |m: &mut PinPublishedViewParams| { &mut m.rev_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PinPublishedViewParams>(
+                "PinPublishedViewParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PinPublishedViewParams {
+        static instance: ::protobuf::rt::LazyV2<PinPublishedViewParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PinPublishedViewParams::new)
+    }
+}
+
+impl ::protobuf::Clear for PinPublishedViewParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.rev_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PinPublishedViewParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PinPublishedViewParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12view_publish.proto\"\x93\x01\n\rPublishedView\x12\x17\n\x07view_id\
+    \x18\x01\x20\x01(\tR\x06viewId\x12\x12\n\x04slug\x18\x02\x20\x01(\tR\x04\
+    slug\x12!\n\x0cpublish_time\x18\x03\x20\x01(\x03R\x0bpublishTime\x12\x15\
+    \n\x06rev_id\x18\x04\x20\x01(\x03R\x05revId\x12\x1b\n\tis_pinned\x18\x05\
+    \x20\x01(\x08R\x08isPinned\"B\n\rViewAccessLog\x12\x12\n\x04date\x18\x01\
+    \x20\x01(\tR\x04date\x12\x1d\n\nread_count\x18\x02\x20\x01(\x03R\treadCo\
+    unt\"=\n\x15RepeatedViewAccessLog\x12$\n\x05items\x18\x01\x20\x03(\x0b2\
+    \x0e.ViewAccessLogR\x05items\"I\n\x17PinPublishedViewRequest\x12\x17\n\
+    \x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x15\n\x06rev_id\x18\x02\
+    \x20\x01(\x03R\x05revId\"H\n\x16PinPublishedViewParams\x12\x17\n\x07view\
+    _id\x18\x01\x20\x01(\tR\x06viewId\x12\x15\n\x06rev_id\x18\x02\x20\x01(\
+    \x03R\x05revIdJ\xac\x06\n\x06\x12\x04\0\0\x17\x01\n\x08\n\x01\x0c\x12\
+    \x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x08\x01\n\n\n\x03\x04\0\x01\
+    \x12\x03\x02\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x17\n\x0c\n\
+    \x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\
+    \x03\x03\x0b\x12\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x15\x16\n\x0b\n\
+    \x04\x04\0\x02\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\
+    \x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0f\n\x0c\n\
+    \x05\x04\0\x02\x01\x03\x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\
+    \x03\x05\x04\x1b\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\n\x0c\n\
+    \x05\x04\0\x02\x02\x01\x12\x03\x05\n\x16\n\x0c\n\x05\x04\0\x02\x02\x03\
+    \x12\x03\x05\x19\x1a\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x15\n\x0c\
+    \n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\0\x02\x03\x01\
+    \x12\x03\x06\n\x10\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x13\x14\n\
+    \x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x17\n\x0c\n\x05\x04\0\x02\x04\
+    \x05\x12\x03\x07\x04\x08\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\t\x12\
+    \n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x15\x16\n\n\n\x02\x04\x01\x12\
+    \x04\t\0\x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\t\x08\x15\n\x0b\n\x04\x04\
+    \x01\x02\0\x12\x03\n\x04\x14\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\
+    \n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\n\x0b\x0f\n\x0c\n\x05\x04\x01\
+    \x02\0\x03\x12\x03\n\x12\x13\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x04\
+    \x19\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x0b\x04\t\n\x0c\n\x05\x04\
+    \x01\x02\x01\x01\x12\x03\x0b\n\x14\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\
+    \x03\x0b\x17\x18\n\n\n\x02\x04\x02\x12\x04\r\0\x0f\x01\n\n\n\x03\x04\x02\
+    \x01\x12\x03\r\x08\x1d\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0e\x04%\n\x0c\
+    \n\x05\x04\x02\x02\0\x04\x12\x03\x0e\x04\x0c\n\x0c\n\x05\x04\x02\x02\0\
+    \x06\x12\x03\x0e\r\x1a\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0e\x1b\x20\
+    \n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0e#$\n\n\n\x02\x04\x03\x12\x04\
+    \x10\0\x13\x01\n\n\n\x03\x04\x03\x01\x12\x03\x10\x08\x1f\n\x0b\n\x04\x04\
+    \x03\x02\0\x12\x03\x11\x04\x17\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x11\
+    \x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x11\x0b\x12\n\x0c\n\x05\x04\
+    \x03\x02\0\x03\x12\x03\x11\x15\x16\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\
+    \x12\x04\x15\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x12\x04\t\n\x0c\n\
+    \x05\x04\x03\x02\x01\x01\x12\x03\x12\n\x10\n\x0c\n\x05\x04\x03\x02\x01\
+    \x03\x12\x03\x12\x13\x14\n\n\n\x02\x04\x04\x12\x04\x14\0\x17\x01\n\n\n\
+    \x03\x04\x04\x01\x12\x03\x14\x08\x1e\n\x0b\n\x04\x04\x04\x02\0\x12\x03\
+    \x15\x04\x17\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x15\x04\n\n\x0c\n\x05\
+    \x04\x04\x02\0\x01\x12\x03\x15\x0b\x12\n\x0c\n\x05\x04\x04\x02\0\x03\x12\
+    \x03\x15\x15\x16\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x16\x04\x15\n\x0c\n\
+    \x05\x04\x04\x02\x01\x05\x12\x03\x16\x04\t\n\x0c\n\x05\x04\x04\x02\x01\
+    \x01\x12\x03\x16\n\x10\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x16\x13\
+    \x14b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
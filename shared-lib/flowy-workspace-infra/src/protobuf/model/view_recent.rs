@@ -0,0 +1,1130 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_recent.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryRecentViewsRequest {
+    // message fields
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryRecentViewsRequest {
+    fn default() -> &'a QueryRecentViewsRequest {
+        <QueryRecentViewsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryRecentViewsRequest {
+    pub fn new() -> QueryRecentViewsRequest {
+        ::std::default::Default::default()
+    }
+
+    // int64 limit = 1;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for QueryRecentViewsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.limit != 0 {
+            os.write_int64(1, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryRecentViewsRequest {
+        QueryRecentViewsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &QueryRecentViewsRequest| { &m.limit },
+                |m: &mut QueryRecentViewsRequest| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryRecentViewsRequest>(
+                "QueryRecentViewsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryRecentViewsRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryRecentViewsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryRecentViewsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryRecentViewsRequest {
+    fn clear(&mut self) {
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryRecentViewsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryRecentViewsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryRecentViewsParams {
+    // message fields
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryRecentViewsParams {
+    fn default() -> &'a QueryRecentViewsParams {
+        <QueryRecentViewsParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryRecentViewsParams {
+    pub fn new() -> QueryRecentViewsParams {
+        ::std::default::Default::default()
+    }
+
+    // int64 limit = 1;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for QueryRecentViewsParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.limit != 0 {
+            os.write_int64(1, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryRecentViewsParams {
+        QueryRecentViewsParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &QueryRecentViewsParams| { &m.limit },
+                |m: &mut QueryRecentViewsParams| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryRecentViewsParams>(
+                "QueryRecentViewsParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryRecentViewsParams {
+        static instance: ::protobuf::rt::LazyV2<QueryRecentViewsParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryRecentViewsParams::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryRecentViewsParams {
+    fn clear(&mut self) {
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryRecentViewsParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryRecentViewsParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RecentViewRecord {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub open_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RecentViewRecord {
+    fn default() -> &'a RecentViewRecord {
+        <RecentViewRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RecentViewRecord {
+    pub fn new() -> RecentViewRecord {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // int64 open_time = 2;
+
+
+    pub fn get_open_time(&self) -> i64 {
+        self.open_time
+    }
+    pub fn clear_open_time(&mut self) {
+        self.open_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_open_time(&mut self, v: i64) {
+        self.open_time = v;
+    }
+}
+
+impl ::protobuf::Message for RecentViewRecord {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.open_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.open_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.open_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.open_time != 0 {
+            os.write_int64(2, self.open_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RecentViewRecord {
+        RecentViewRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &RecentViewRecord| { &m.view_id },
+                |m: &mut RecentViewRecord| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "open_time",
+                |m: &RecentViewRecord| { &m.open_time },
+                |m: &mut RecentViewRecord| { &mut m.open_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RecentViewRecord>(
+                "RecentViewRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RecentViewRecord {
+        static instance: ::protobuf::rt::LazyV2<RecentViewRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RecentViewRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for RecentViewRecord {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.open_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RecentViewRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecentViewRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RecentView {
+    // message fields
+    pub view: ::protobuf::SingularPtrField<super::view_create::View>,
+    pub app_id: ::std::string::String,
+    pub app_name: ::std::string::String,
+    pub workspace_id: ::std::string::String,
+    pub workspace_name: ::std::string::String,
+    pub open_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RecentView {
+    fn default() -> &'a RecentView {
+        <RecentView as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RecentView {
+    pub fn new() -> RecentView {
+        ::std::default::Default::default()
+    }
+
+    // .View view = 1;
+
+
+    pub fn get_view(&self) -> &super::view_create::View {
+        self.view.as_ref().unwrap_or_else(|| <super::view_create::View as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_view(&mut self) {
+        self.view.clear();
+    }
+
+    pub fn has_view(&self) -> bool {
+        self.view.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: super::view_create::View) {
+        self.view = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view(&mut self) -> &mut super::view_create::View {
+        if self.view.is_none() {
+            self.view.set_default();
+        }
+        self.view.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_view(&mut self) -> super::view_create::View {
+        self.view.take().unwrap_or_else(|| super::view_create::View::new())
+    }
+
+    // string app_id = 2;
+
+
+    pub fn get_app_id(&self) -> &str {
+        &self.app_id
+    }
+    pub fn clear_app_id(&mut self) {
+        self.app_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_id(&mut self, v: ::std::string::String) {
+        self.app_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_app_id(&mut self) -> &mut ::std::string::String {
+        &mut self.app_id
+    }
+
+    // Take field
+    pub fn take_app_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.app_id, ::std::string::String::new())
+    }
+
+    // string app_name = 3;
+
+
+    pub fn get_app_name(&self) -> &str {
+        &self.app_name
+    }
+    pub fn clear_app_name(&mut self) {
+        self.app_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_name(&mut self, v: ::std::string::String) {
+        self.app_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_app_name(&mut self) -> &mut ::std::string::String {
+        &mut self.app_name
+    }
+
+    // Take field
+    pub fn take_app_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.app_name, ::std::string::String::new())
+    }
+
+    // string workspace_id = 4;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string workspace_name = 5;
+
+
+    pub fn get_workspace_name(&self) -> &str {
+        &self.workspace_name
+    }
+    pub fn clear_workspace_name(&mut self) {
+        self.workspace_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_name(&mut self, v: ::std::string::String) {
+        self.workspace_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_name(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_name
+    }
+
+    // Take field
+    pub fn take_workspace_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_name, ::std::string::String::new())
+    }
+
+    // int64 open_time = 6;
+
+
+    pub fn get_open_time(&self) -> i64 {
+        self.open_time
+    }
+    pub fn clear_open_time(&mut self) {
+        self.open_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_open_time(&mut self, v: i64) {
+        self.open_time = v;
+    }
+}
+
+impl ::protobuf::Message for RecentView {
+    fn is_initialized(&self) -> bool {
+        for v in &self.view {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.view)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.app_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.app_name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_name)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.open_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.view.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.app_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.app_id);
+        }
+        if !self.app_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.app_name);
+        }
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.workspace_id);
+        }
+        if !self.workspace_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.workspace_name);
+        }
+        if self.open_time != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.open_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.view.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.app_id.is_empty() {
+            os.write_string(2, &self.app_id)?;
+        }
+        if !self.app_name.is_empty() {
+            os.write_string(3, &self.app_name)?;
+        }
+        if !self.workspace_id.is_empty() {
+            os.write_string(4, &self.workspace_id)?;
+        }
+        if !self.workspace_name.is_empty() {
+            os.write_string(5, &self.workspace_name)?;
+        }
+        if self.open_time != 0 {
+            os.write_int64(6, self.open_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RecentView {
+        RecentView::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::View>>(
+                "view",
+                |m: &RecentView| { &m.view },
+                |m: &mut RecentView| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "app_id",
+                |m: &RecentView| { &m.app_id },
+                |m: &mut RecentView| { &mut m.app_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "app_name",
+                |m: &RecentView| { &m.app_name },
+                |m: &mut RecentView| { &mut m.app_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &RecentView| { &m.workspace_id },
+                |m: &mut RecentView| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_name",
+                |m: &RecentView| { &m.workspace_name },
+                |m: &mut RecentView| { &mut m.workspace_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "open_time",
+                |m: &RecentView| { &m.open_time },
+                |m: &mut RecentView| { &mut m.open_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RecentView>(
+                "RecentView",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RecentView {
+        static instance: ::protobuf::rt::LazyV2<RecentView> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RecentView::new)
+    }
+}
+
+impl ::protobuf::Clear for RecentView {
+    fn clear(&mut self) {
+        self.view.clear();
+        self.app_id.clear();
+        self.app_name.clear();
+        self.workspace_id.clear();
+        self.workspace_name.clear();
+        self.open_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RecentView {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecentView {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedRecentView {
+    // message fields
+    pub items: ::protobuf::RepeatedField<RecentView>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedRecentView {
+    fn default() -> &'a RepeatedRecentView {
+        <RepeatedRecentView as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedRecentView {
+    pub fn new() -> RepeatedRecentView {
+        ::std::default::Default::default()
+    }
+
+    // repeated .RecentView items = 1;
+
+
+    pub fn get_items(&self) -> &[RecentView] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<RecentView>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<RecentView> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<RecentView> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedRecentView {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedRecentView {
+        RepeatedRecentView::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RecentView>>(
+                "items",
+                |m: &RepeatedRecentView| { &m.items },
+                |m: &mut RepeatedRecentView| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedRecentView>(
+                "RepeatedRecentView",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedRecentView {
+        static instance: ::protobuf::rt::LazyV2<RepeatedRecentView> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedRecentView::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedRecentView {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedRecentView {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedRecentView {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x11view_recent.proto\x1a\x11view_create.proto\"/\n\x17QueryRecentView\
+    sRequest\x12\x14\n\x05limit\x18\x01\x20\x01(\x03R\x05limit\".\n\x16Query\
+    RecentViewsParams\x12\x14\n\x05limit\x18\x01\x20\x01(\x03R\x05limit\"H\n\
+    \x10RecentViewRecord\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\
+    \x12\x1b\n\topen_time\x18\x02\x20\x01(\x03R\x08openTime\"\xc0\x01\n\nRec\
+    entView\x12\x19\n\x04view\x18\x01\x20\x01(\x0b2\x05.ViewR\x04view\x12\
+    \x15\n\x06app_id\x18\x02\x20\x01(\tR\x05appId\x12\x19\n\x08app_name\x18\
+    \x03\x20\x01(\tR\x07appName\x12!\n\x0cworkspace_id\x18\x04\x20\x01(\tR\
+    \x0bworkspaceId\x12%\n\x0eworkspace_name\x18\x05\x20\x01(\tR\rworkspaceN\
+    ame\x12\x1b\n\topen_time\x18\x06\x20\x01(\x03R\x08openTime\"7\n\x12Repea\
+    tedRecentView\x12!\n\x05items\x18\x01\x20\x03(\x0b2\x0b.RecentViewR\x05i\
+    temsJ\x80\x06\n\x06\x12\x04\0\0\x17\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
+    \n\t\n\x02\x03\0\x12\x03\x01\0\x1b\n\n\n\x02\x04\0\x12\x04\x03\0\x05\x01\
+    \n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x1f\n\x0b\n\x04\x04\0\x02\0\x12\x03\
+    \x04\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\t\n\x0c\n\x05\
+    \x04\0\x02\0\x01\x12\x03\x04\n\x0f\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\
+    \x04\x12\x13\n\n\n\x02\x04\x01\x12\x04\x06\0\x08\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\x06\x08\x1e\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x04\x14\n\
+    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\x01\x02\0\
+    \x01\x12\x03\x07\n\x0f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\x12\x13\
+    \n\n\n\x02\x04\x02\x12\x04\t\0\x0c\x01\n\n\n\x03\x04\x02\x01\x12\x03\t\
+    \x08\x18\n\x0b\n\x04\x04\x02\x02\0\x12\x03\n\x04\x17\n\x0c\n\x05\x04\x02\
+    \x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\n\x0b\
+    \x12\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\n\x15\x16\n\x0b\n\x04\x04\x02\
+    \x02\x01\x12\x03\x0b\x04\x18\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0b\
+    \x04\t\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0b\n\x13\n\x0c\n\x05\x04\
+    \x02\x02\x01\x03\x12\x03\x0b\x16\x17\n\n\n\x02\x04\x03\x12\x04\r\0\x14\
+    \x01\n\n\n\x03\x04\x03\x01\x12\x03\r\x08\x12\n\x0b\n\x04\x04\x03\x02\0\
+    \x12\x03\x0e\x04\x12\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x0e\x04\x08\n\
+    \x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x0e\t\r\n\x0c\n\x05\x04\x03\x02\0\
+    \x03\x12\x03\x0e\x10\x11\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x0f\x04\x16\
+    \n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x03\
+    \x02\x01\x01\x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\
+    \x0f\x14\x15\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x10\x04\x18\n\x0c\n\x05\
+    \x04\x03\x02\x02\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\
+    \x12\x03\x10\x0b\x13\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x10\x16\x17\
+    \n\x0b\n\x04\x04\x03\x02\x03\x12\x03\x11\x04\x1c\n\x0c\n\x05\x04\x03\x02\
+    \x03\x05\x12\x03\x11\x04\n\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x11\
+    \x0b\x17\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x11\x1a\x1b\n\x0b\n\x04\
+    \x04\x03\x02\x04\x12\x03\x12\x04\x1e\n\x0c\n\x05\x04\x03\x02\x04\x05\x12\
+    \x03\x12\x04\n\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03\x12\x0b\x19\n\x0c\
+    \n\x05\x04\x03\x02\x04\x03\x12\x03\x12\x1c\x1d\n\x0b\n\x04\x04\x03\x02\
+    \x05\x12\x03\x13\x04\x18\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\x03\x13\x04\
+    \t\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x03\x13\n\x13\n\x0c\n\x05\x04\x03\
+    \x02\x05\x03\x12\x03\x13\x16\x17\n\n\n\x02\x04\x04\x12\x04\x15\0\x17\x01\
+    \n\n\n\x03\x04\x04\x01\x12\x03\x15\x08\x1a\n\x0b\n\x04\x04\x04\x02\0\x12\
+    \x03\x16\x04\"\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x16\x04\x0c\n\x0c\n\
+    \x05\x04\x04\x02\0\x06\x12\x03\x16\r\x17\n\x0c\n\x05\x04\x04\x02\0\x01\
+    \x12\x03\x16\x18\x1d\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x16\x20!b\x06\
+    proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,1243 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_share.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShareLink {
+    // message fields
+    pub link_id: ::std::string::String,
+    pub view_id: ::std::string::String,
+    pub create_time: i64,
+    pub expire_time: i64,
+    pub has_passcode: bool,
+    pub slug: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShareLink {
+    fn default() -> &'a ShareLink {
+        <ShareLink as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShareLink {
+    pub fn new() -> ShareLink {
+        ::std::default::Default::default()
+    }
+
+    // string link_id = 1;
+
+
+    pub fn get_link_id(&self) -> &str {
+        &self.link_id
+    }
+    pub fn clear_link_id(&mut self) {
+        self.link_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_link_id(&mut self, v: ::std::string::String) {
+        self.link_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_link_id(&mut self) -> &mut ::std::string::String {
+        &mut self.link_id
+    }
+
+    // Take field
+    pub fn take_link_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.link_id, ::std::string::String::new())
+    }
+
+    // string view_id = 2;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // int64 create_time = 3;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+
+    // int64 expire_time = 4;
+
+
+    pub fn get_expire_time(&self) -> i64 {
+        self.expire_time
+    }
+    pub fn clear_expire_time(&mut self) {
+        self.expire_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expire_time(&mut self, v: i64) {
+        self.expire_time = v;
+    }
+
+    // bool has_passcode = 5;
+
+
+    pub fn get_has_passcode(&self) -> bool {
+        self.has_passcode
+    }
+    pub fn clear_has_passcode(&mut self) {
+        self.has_passcode = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_passcode(&mut self, v: bool) {
+        self.has_passcode = v;
+    }
+
+    // string slug = 6;
+
+
+    pub fn get_slug(&self) -> &str {
+        &self.slug
+    }
+    pub fn clear_slug(&mut self) {
+        self.slug.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_slug(&mut self, v: ::std::string::String) {
+        self.slug = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_slug(&mut self) -> &mut ::std::string::String {
+        &mut self.slug
+    }
+
+    // Take field
+    pub fn take_slug(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.slug, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ShareLink {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.link_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.expire_time = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_passcode = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.slug)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.link_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.link_id);
+        }
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.view_id);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.expire_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.expire_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.has_passcode != false {
+            my_size += 2;
+        }
+        if !self.slug.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.slug);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.link_id.is_empty() {
+            os.write_string(1, &self.link_id)?;
+        }
+        if !self.view_id.is_empty() {
+            os.write_string(2, &self.view_id)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(3, self.create_time)?;
+        }
+        if self.expire_time != 0 {
+            os.write_int64(4, self.expire_time)?;
+        }
+        if self.has_passcode != false {
+            os.write_bool(5, self.has_passcode)?;
+        }
+        if !self.slug.is_empty() {
+            os.write_string(6, &self.slug)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShareLink {
+        ShareLink::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "link_id",
+                |m: &ShareLink| { &m.link_id },
+                |m: &mut ShareLink| { &mut m.link_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &ShareLink| { &m.view_id },
+                |m: &mut ShareLink| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &ShareLink| { &m.create_time },
+                |m: &mut ShareLink| { &mut m.create_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "expire_time",
+                |m: &ShareLink| { &m.expire_time },
+                |m: &mut ShareLink| { &mut m.expire_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_passcode",
+                |m: &ShareLink| { &m.has_passcode },
+                |m: &mut ShareLink| { &mut m.has_passcode },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "slug",
+                |m: &ShareLink| { &m.slug },
+                |m: &mut ShareLink| { &mut m.slug },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShareLink>(
+                "ShareLink",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShareLink {
+        static instance: ::protobuf::rt::LazyV2<ShareLink> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShareLink::new)
+    }
+}
+
+impl ::protobuf::Clear for ShareLink {
+    fn clear(&mut self) {
+        self.link_id.clear();
+        self.view_id.clear();
+        self.create_time = 0;
+        self.expire_time = 0;
+        self.has_passcode = false;
+        self.slug.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShareLink {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShareLink {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedShareLink {
+    // message fields
+    pub items: ::protobuf::RepeatedField<ShareLink>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedShareLink {
+    fn default() -> &'a RepeatedShareLink {
+        <RepeatedShareLink as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedShareLink {
+    pub fn new() -> RepeatedShareLink {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ShareLink items = 1;
+
+
+    pub fn get_items(&self) -> &[ShareLink] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ShareLink>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ShareLink> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ShareLink> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedShareLink {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedShareLink {
+        RepeatedShareLink::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShareLink>>(
+                "items",
+                |m: &RepeatedShareLink| { &m.items },
+                |m: &mut RepeatedShareLink| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedShareLink>(
+                "RepeatedShareLink",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedShareLink {
+        static instance: ::protobuf::rt::LazyV2<RepeatedShareLink> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedShareLink::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedShareLink {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedShareLink {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedShareLink {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateShareLinkRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub expire_time: i64,
+    pub passcode: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateShareLinkRequest {
+    fn default() -> &'a CreateShareLinkRequest {
+        <CreateShareLinkRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateShareLinkRequest {
+    pub fn new() -> CreateShareLinkRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // int64 expire_time = 2;
+
+
+    pub fn get_expire_time(&self) -> i64 {
+        self.expire_time
+    }
+    pub fn clear_expire_time(&mut self) {
+        self.expire_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expire_time(&mut self, v: i64) {
+        self.expire_time = v;
+    }
+
+    // string passcode = 3;
+
+
+    pub fn get_passcode(&self) -> &str {
+        &self.passcode
+    }
+    pub fn clear_passcode(&mut self) {
+        self.passcode.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_passcode(&mut self, v: ::std::string::String) {
+        self.passcode = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_passcode(&mut self) -> &mut ::std::string::String {
+        &mut self.passcode
+    }
+
+    // Take field
+    pub fn take_passcode(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.passcode, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CreateShareLinkRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.expire_time = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.passcode)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.expire_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.expire_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.passcode.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.passcode);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.expire_time != 0 {
+            os.write_int64(2, self.expire_time)?;
+        }
+        if !self.passcode.is_empty() {
+            os.write_string(3, &self.passcode)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateShareLinkRequest {
+        CreateShareLinkRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &CreateShareLinkRequest| { &m.view_id },
+                |m: &mut CreateShareLinkRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "expire_time",
+                |m: &CreateShareLinkRequest| { &m.expire_time },
+                |m: &mut CreateShareLinkRequest| { &mut m.expire_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "passcode",
+                |m: &CreateShareLinkRequest| { &m.passcode },
+                |m: &mut CreateShareLinkRequest| { &mut m.passcode },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateShareLinkRequest>(
+                "CreateShareLinkRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateShareLinkRequest {
+        static instance: ::protobuf::rt::LazyV2<CreateShareLinkRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateShareLinkRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateShareLinkRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.expire_time = 0;
+        self.passcode.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateShareLinkRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateShareLinkRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateShareLinkParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub expire_time: i64,
+    pub passcode: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateShareLinkParams {
+    fn default() -> &'a CreateShareLinkParams {
+        <CreateShareLinkParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateShareLinkParams {
+    pub fn new() -> CreateShareLinkParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // int64 expire_time = 2;
+
+
+    pub fn get_expire_time(&self) -> i64 {
+        self.expire_time
+    }
+    pub fn clear_expire_time(&mut self) {
+        self.expire_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expire_time(&mut self, v: i64) {
+        self.expire_time = v;
+    }
+
+    // string passcode = 3;
+
+
+    pub fn get_passcode(&self) -> &str {
+        &self.passcode
+    }
+    pub fn clear_passcode(&mut self) {
+        self.passcode.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_passcode(&mut self, v: ::std::string::String) {
+        self.passcode = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_passcode(&mut self) -> &mut ::std::string::String {
+        &mut self.passcode
+    }
+
+    // Take field
+    pub fn take_passcode(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.passcode, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CreateShareLinkParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.expire_time = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.passcode)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.expire_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.expire_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.passcode.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.passcode);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.expire_time != 0 {
+            os.write_int64(2, self.expire_time)?;
+        }
+        if !self.passcode.is_empty() {
+            os.write_string(3, &self.passcode)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateShareLinkParams {
+        CreateShareLinkParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &CreateShareLinkParams| { &m.view_id },
+                |m: &mut CreateShareLinkParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "expire_time",
+                |m: &CreateShareLinkParams| { &m.expire_time },
+                |m: &mut CreateShareLinkParams| { &mut m.expire_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "passcode",
+                |m: &CreateShareLinkParams| { &m.passcode },
+                |m: &mut CreateShareLinkParams| { &mut m.passcode },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateShareLinkParams>(
+                "CreateShareLinkParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateShareLinkParams {
+        static instance: ::protobuf::rt::LazyV2<CreateShareLinkParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateShareLinkParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateShareLinkParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.expire_time = 0;
+        self.passcode.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateShareLinkParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateShareLinkParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShareLinkIdentifier {
+    // message fields
+    pub link_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShareLinkIdentifier {
+    fn default() -> &'a ShareLinkIdentifier {
+        <ShareLinkIdentifier as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShareLinkIdentifier {
+    pub fn new() -> ShareLinkIdentifier {
+        ::std::default::Default::default()
+    }
+
+    // string link_id = 1;
+
+
+    pub fn get_link_id(&self) -> &str {
+        &self.link_id
+    }
+    pub fn clear_link_id(&mut self) {
+        self.link_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_link_id(&mut self, v: ::std::string::String) {
+        self.link_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_link_id(&mut self) -> &mut ::std::string::String {
+        &mut self.link_id
+    }
+
+    // Take field
+    pub fn take_link_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.link_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ShareLinkIdentifier {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.link_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.link_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.link_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.link_id.is_empty() {
+            os.write_string(1, &self.link_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShareLinkIdentifier {
+        ShareLinkIdentifier::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "link_id",
+                |m: &ShareLinkIdentifier| { &m.link_id },
+                |m: &mut ShareLinkIdentifier| { &mut m.link_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShareLinkIdentifier>(
+                "ShareLinkIdentifier",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShareLinkIdentifier {
+        static instance: ::protobuf::rt::LazyV2<ShareLinkIdentifier> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShareLinkIdentifier::new)
+    }
+}
+
+impl ::protobuf::Clear for ShareLinkIdentifier {
+    fn clear(&mut self) {
+        self.link_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShareLinkIdentifier {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShareLinkIdentifier {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10view_share.proto\"\xb6\x01\n\tShareLink\x12\x17\n\x07link_id\x18\
+    \x01\x20\x01(\tR\x06linkId\x12\x17\n\x07view_id\x18\x02\x20\x01(\tR\x06v\
+    iewId\x12\x1f\n\x0bcreate_time\x18\x03\x20\x01(\x03R\ncreateTime\x12\x1f\
+    \n\x0bexpire_time\x18\x04\x20\x01(\x03R\nexpireTime\x12!\n\x0chas_passco\
+    de\x18\x05\x20\x01(\x08R\x0bhasPasscode\x12\x12\n\x04slug\x18\x06\x20\
+    \x01(\tR\x04slug\"5\n\x11RepeatedShareLink\x12\x20\n\x05items\x18\x01\
+    \x20\x03(\x0b2\n.ShareLinkR\x05items\"n\n\x16CreateShareLinkRequest\x12\
+    \x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x1f\n\x0bexpire_time\
+    \x18\x02\x20\x01(\x03R\nexpireTime\x12\x1a\n\x08passcode\x18\x03\x20\x01\
+    (\tR\x08passcode\"m\n\x15CreateShareLinkParams\x12\x17\n\x07view_id\x18\
+    \x01\x20\x01(\tR\x06viewId\x12\x1f\n\x0bexpire_time\x18\x02\x20\x01(\x03\
+    R\nexpireTime\x12\x1a\n\x08passcode\x18\x03\x20\x01(\tR\x08passcode\".\n\
+    \x13ShareLinkIdentifier\x12\x17\n\x07link_id\x18\x01\x20\x01(\tR\x06link\
+    IdJ\x9a\x07\n\x06\x12\x04\0\0\x19\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\
+    \n\n\x02\x04\0\x12\x04\x02\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\
+    \x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\
+    \x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x12\n\
+    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x02\x01\
+    \x12\x03\x04\x04\x17\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\
+    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x12\n\x0c\n\x05\x04\0\x02\
+    \x01\x03\x12\x03\x04\x15\x16\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\
+    \x1a\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\
+    \x02\x02\x01\x12\x03\x05\n\x15\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\
+    \x18\x19\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x1a\n\x0c\n\x05\x04\0\
+    \x02\x03\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\
+    \n\x15\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x18\x19\n\x0b\n\x04\x04\
+    \0\x02\x04\x12\x03\x07\x04\x1a\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x07\
+    \x04\x08\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\t\x15\n\x0c\n\x05\x04\
+    \0\x02\x04\x03\x12\x03\x07\x18\x19\n\x0b\n\x04\x04\0\x02\x05\x12\x03\x08\
+    \x04\x14\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\
+    \0\x02\x05\x01\x12\x03\x08\x0b\x0f\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\
+    \x08\x12\x13\n\n\n\x02\x04\x01\x12\x04\n\0\x0c\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\n\x08\x19\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x04!\n\x0c\n\
+    \x05\x04\x01\x02\0\x04\x12\x03\x0b\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\
+    \x12\x03\x0b\r\x16\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b\x17\x1c\n\
+    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b\x1f\x20\n\n\n\x02\x04\x02\x12\
+    \x04\r\0\x11\x01\n\n\n\x03\x04\x02\x01\x12\x03\r\x08\x1e\n\x0b\n\x04\x04\
+    \x02\x02\0\x12\x03\x0e\x04\x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\
+    \x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0e\x0b\x12\n\x0c\n\x05\x04\
+    \x02\x02\0\x03\x12\x03\x0e\x15\x16\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\
+    \x0f\x04\x1a\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0f\x04\t\n\x0c\n\
+    \x05\x04\x02\x02\x01\x01\x12\x03\x0f\n\x15\n\x0c\n\x05\x04\x02\x02\x01\
+    \x03\x12\x03\x0f\x18\x19\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x10\x04\x18\
+    \n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x02\
+    \x02\x02\x01\x12\x03\x10\x0b\x13\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\
+    \x10\x16\x17\n\n\n\x02\x04\x03\x12\x04\x12\0\x16\x01\n\n\n\x03\x04\x03\
+    \x01\x12\x03\x12\x08\x1d\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x13\x04\x17\n\
+    \x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x03\x02\0\
+    \x01\x12\x03\x13\x0b\x12\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x13\x15\
+    \x16\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x14\x04\x1a\n\x0c\n\x05\x04\x03\
+    \x02\x01\x05\x12\x03\x14\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\
+    \x14\n\x15\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x14\x18\x19\n\x0b\n\
+    \x04\x04\x03\x02\x02\x12\x03\x15\x04\x18\n\x0c\n\x05\x04\x03\x02\x02\x05\
+    \x12\x03\x15\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x15\x0b\x13\n\
+    \x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x15\x16\x17\n\n\n\x02\x04\x04\x12\
+    \x04\x17\0\x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\x17\x08\x1b\n\x0b\n\x04\
+    \x04\x04\x02\0\x12\x03\x18\x04\x17\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\
+    \x18\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x18\x0b\x12\n\x0c\n\x05\
+    \x04\x04\x02\0\x03\x12\x03\x18\x15\x16b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
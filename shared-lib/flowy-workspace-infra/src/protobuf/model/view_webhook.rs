@@ -0,0 +1,1075 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_webhook.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewWebhook {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub url: ::std::string::String,
+    pub format: WebhookFormat,
+    pub has_secret: bool,
+    pub create_time: i64,
+    pub last_delivery_time: i64,
+    pub last_status: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewWebhook {
+    fn default() -> &'a ViewWebhook {
+        <ViewWebhook as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ViewWebhook {
+    pub fn new() -> ViewWebhook {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string url = 2;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+
+    // .WebhookFormat format = 3;
+
+
+    pub fn get_format(&self) -> WebhookFormat {
+        self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format = WebhookFormat::Markdown;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: WebhookFormat) {
+        self.format = v;
+    }
+
+    // bool has_secret = 4;
+
+
+    pub fn get_has_secret(&self) -> bool {
+        self.has_secret
+    }
+    pub fn clear_has_secret(&mut self) {
+        self.has_secret = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_secret(&mut self, v: bool) {
+        self.has_secret = v;
+    }
+
+    // int64 create_time = 5;
+
+
+    pub fn get_create_time(&self) -> i64 {
+        self.create_time
+    }
+    pub fn clear_create_time(&mut self) {
+        self.create_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_time(&mut self, v: i64) {
+        self.create_time = v;
+    }
+
+    // int64 last_delivery_time = 6;
+
+
+    pub fn get_last_delivery_time(&self) -> i64 {
+        self.last_delivery_time
+    }
+    pub fn clear_last_delivery_time(&mut self) {
+        self.last_delivery_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_delivery_time(&mut self, v: i64) {
+        self.last_delivery_time = v;
+    }
+
+    // int32 last_status = 7;
+
+
+    pub fn get_last_status(&self) -> i32 {
+        self.last_status
+    }
+    pub fn clear_last_status(&mut self) {
+        self.last_status = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_status(&mut self, v: i32) {
+        self.last_status = v;
+    }
+}
+
+impl ::protobuf::Message for ViewWebhook {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.format, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_secret = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.create_time = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_delivery_time = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.last_status = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.url);
+        }
+        if self.format != WebhookFormat::Markdown {
+            my_size += ::protobuf::rt::enum_size(3, self.format);
+        }
+        if self.has_secret != false {
+            my_size += 2;
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.create_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_delivery_time != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.last_delivery_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_status != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.last_status, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.url.is_empty() {
+            os.write_string(2, &self.url)?;
+        }
+        if self.format != WebhookFormat::Markdown {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.format))?;
+        }
+        if self.has_secret != false {
+            os.write_bool(4, self.has_secret)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(5, self.create_time)?;
+        }
+        if self.last_delivery_time != 0 {
+            os.write_int64(6, self.last_delivery_time)?;
+        }
+        if self.last_status != 0 {
+            os.write_int32(7, self.last_status)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewWebhook {
+        ViewWebhook::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &ViewWebhook| { &m.view_id },
+                |m: &mut ViewWebhook| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &ViewWebhook| { &m.url },
+                |m: &mut ViewWebhook| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WebhookFormat>>(
+                "format",
+                |m: &ViewWebhook| { &m.format },
+                |m: &mut ViewWebhook| { &mut m.format },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_secret",
+                |m: &ViewWebhook| { &m.has_secret },
+                |m: &mut ViewWebhook| { &mut m.has_secret },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "create_time",
+                |m: &ViewWebhook| { &m.create_time },
+                |m: &mut ViewWebhook| { &mut m.create_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_delivery_time",
+                |m: &ViewWebhook| { &m.last_delivery_time },
+                |m: &mut ViewWebhook| { &mut m.last_delivery_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "last_status",
+                |m: &ViewWebhook| { &m.last_status },
+                |m: &mut ViewWebhook| { &mut m.last_status },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewWebhook>(
+                "ViewWebhook",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewWebhook {
+        static instance: ::protobuf::rt::LazyV2<ViewWebhook> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewWebhook::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewWebhook {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.url.clear();
+        self.format = WebhookFormat::Markdown;
+        self.has_secret = false;
+        self.create_time = 0;
+        self.last_delivery_time = 0;
+        self.last_status = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewWebhook {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewWebhook {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetViewWebhookRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub url: ::std::string::String,
+    pub format: WebhookFormat,
+    pub secret: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetViewWebhookRequest {
+    fn default() -> &'a SetViewWebhookRequest {
+        <SetViewWebhookRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetViewWebhookRequest {
+    pub fn new() -> SetViewWebhookRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string url = 2;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+
+    // .WebhookFormat format = 3;
+
+
+    pub fn get_format(&self) -> WebhookFormat {
+        self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format = WebhookFormat::Markdown;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: WebhookFormat) {
+        self.format = v;
+    }
+
+    // string secret = 4;
+
+
+    pub fn get_secret(&self) -> &str {
+        &self.secret
+    }
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.secret
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SetViewWebhookRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.format, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.url);
+        }
+        if self.format != WebhookFormat::Markdown {
+            my_size += ::protobuf::rt::enum_size(3, self.format);
+        }
+        if !self.secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.url.is_empty() {
+            os.write_string(2, &self.url)?;
+        }
+        if self.format != WebhookFormat::Markdown {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.format))?;
+        }
+        if !self.secret.is_empty() {
+            os.write_string(4, &self.secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetViewWebhookRequest {
+        SetViewWebhookRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &SetViewWebhookRequest| { &m.view_id },
+                |m: &mut SetViewWebhookRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &SetViewWebhookRequest| { &m.url },
+                |m: &mut SetViewWebhookRequest| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WebhookFormat>>(
+                "format",
+                |m: &SetViewWebhookRequest| { &m.format },
+                |m: &mut SetViewWebhookRequest| { &mut m.format },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secret",
+                |m: &SetViewWebhookRequest| { &m.secret },
+                |m: &mut SetViewWebhookRequest| { &mut m.secret },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetViewWebhookRequest>(
+                "SetViewWebhookRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetViewWebhookRequest {
+        static instance: ::protobuf::rt::LazyV2<SetViewWebhookRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetViewWebhookRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetViewWebhookRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.url.clear();
+        self.format = WebhookFormat::Markdown;
+        self.secret.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetViewWebhookRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetViewWebhookRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetViewWebhookParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub url: ::std::string::String,
+    pub format: WebhookFormat,
+    pub secret: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetViewWebhookParams {
+    fn default() -> &'a SetViewWebhookParams {
+        <SetViewWebhookParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetViewWebhookParams {
+    pub fn new() -> SetViewWebhookParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string url = 2;
+
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.url, ::std::string::String::new())
+    }
+
+    // .WebhookFormat format = 3;
+
+
+    pub fn get_format(&self) -> WebhookFormat {
+        self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format = WebhookFormat::Markdown;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: WebhookFormat) {
+        self.format = v;
+    }
+
+    // string secret = 4;
+
+
+    pub fn get_secret(&self) -> &str {
+        &self.secret
+    }
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.secret
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SetViewWebhookParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.url)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.format, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.url);
+        }
+        if self.format != WebhookFormat::Markdown {
+            my_size += ::protobuf::rt::enum_size(3, self.format);
+        }
+        if !self.secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.url.is_empty() {
+            os.write_string(2, &self.url)?;
+        }
+        if self.format != WebhookFormat::Markdown {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.format))?;
+        }
+        if !self.secret.is_empty() {
+            os.write_string(4, &self.secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetViewWebhookParams {
+        SetViewWebhookParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &SetViewWebhookParams| { &m.view_id },
+                |m: &mut SetViewWebhookParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "url",
+                |m: &SetViewWebhookParams| { &m.url },
+                |m: &mut SetViewWebhookParams| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WebhookFormat>>(
+                "format",
+                |m: &SetViewWebhookParams| { &m.format },
+                |m: &mut SetViewWebhookParams| { &mut m.format },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secret",
+                |m: &SetViewWebhookParams| { &m.secret },
+                |m: &mut SetViewWebhookParams| { &mut m.secret },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetViewWebhookParams>(
+                "SetViewWebhookParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetViewWebhookParams {
+        static instance: ::protobuf::rt::LazyV2<SetViewWebhookParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetViewWebhookParams::new)
+    }
+}
+
+impl ::protobuf::Clear for SetViewWebhookParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.url.clear();
+        self.format = WebhookFormat::Markdown;
+        self.secret.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetViewWebhookParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetViewWebhookParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum WebhookFormat {
+    Markdown = 0,
+    Html = 1,
+}
+
+impl ::protobuf::ProtobufEnum for WebhookFormat {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WebhookFormat> {
+        match value {
+            0 => ::std::option::Option::Some(WebhookFormat::Markdown),
+            1 => ::std::option::Option::Some(WebhookFormat::Html),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [WebhookFormat] = &[
+            WebhookFormat::Markdown,
+            WebhookFormat::Html,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<WebhookFormat>("WebhookFormat", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for WebhookFormat {
+}
+
+impl ::std::default::Default for WebhookFormat {
+    fn default() -> Self {
+        WebhookFormat::Markdown
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WebhookFormat {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12view_webhook.proto\"\xef\x01\n\x0bViewWebhook\x12\x17\n\x07view_id\
+    \x18\x01\x20\x01(\tR\x06viewId\x12\x10\n\x03url\x18\x02\x20\x01(\tR\x03u\
+    rl\x12&\n\x06format\x18\x03\x20\x01(\x0e2\x0e.WebhookFormatR\x06format\
+    \x12\x1d\n\nhas_secret\x18\x04\x20\x01(\x08R\thasSecret\x12\x1f\n\x0bcre\
+    ate_time\x18\x05\x20\x01(\x03R\ncreateTime\x12,\n\x12last_delivery_time\
+    \x18\x06\x20\x01(\x03R\x10lastDeliveryTime\x12\x1f\n\x0blast_status\x18\
+    \x07\x20\x01(\x05R\nlastStatus\"\x82\x01\n\x15SetViewWebhookRequest\x12\
+    \x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x10\n\x03url\x18\x02\
+    \x20\x01(\tR\x03url\x12&\n\x06format\x18\x03\x20\x01(\x0e2\x0e.WebhookFo\
+    rmatR\x06format\x12\x16\n\x06secret\x18\x04\x20\x01(\tR\x06secret\"\x81\
+    \x01\n\x14SetViewWebhookParams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\
+    \x06viewId\x12\x10\n\x03url\x18\x02\x20\x01(\tR\x03url\x12&\n\x06format\
+    \x18\x03\x20\x01(\x0e2\x0e.WebhookFormatR\x06format\x12\x16\n\x06secret\
+    \x18\x04\x20\x01(\tR\x06secret*'\n\rWebhookFormat\x12\x0c\n\x08Markdown\
+    \x10\0\x12\x08\n\x04Html\x10\x01J\xfd\x07\n\x06\x12\x04\0\0\x1a\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\
+    \x03\x04\0\x01\x12\x03\x02\x08\x13\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
+    \x04\x17\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\x03\x0b\x12\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\
+    \x15\x16\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x13\n\x0c\n\x05\x04\0\
+    \x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\
+    \x0b\x0e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x11\x12\n\x0b\n\x04\
+    \x04\0\x02\x02\x12\x03\x05\x04\x1d\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03\
+    \x05\x04\x11\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x12\x18\n\x0c\n\
+    \x05\x04\0\x02\x02\x03\x12\x03\x05\x1b\x1c\n\x0b\n\x04\x04\0\x02\x03\x12\
+    \x03\x06\x04\x18\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\x08\n\x0c\
+    \n\x05\x04\0\x02\x03\x01\x12\x03\x06\t\x13\n\x0c\n\x05\x04\0\x02\x03\x03\
+    \x12\x03\x06\x16\x17\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x1a\n\x0c\
+    \n\x05\x04\0\x02\x04\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\0\x02\x04\x01\
+    \x12\x03\x07\n\x15\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x18\x19\n\
+    \x0b\n\x04\x04\0\x02\x05\x12\x03\x08\x04!\n\x0c\n\x05\x04\0\x02\x05\x05\
+    \x12\x03\x08\x04\t\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\n\x1c\n\x0c\
+    \n\x05\x04\0\x02\x05\x03\x12\x03\x08\x1f\x20\n\x0b\n\x04\x04\0\x02\x06\
+    \x12\x03\t\x04\x1a\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\t\x04\t\n\x0c\n\
+    \x05\x04\0\x02\x06\x01\x12\x03\t\n\x15\n\x0c\n\x05\x04\0\x02\x06\x03\x12\
+    \x03\t\x18\x19\n\n\n\x02\x04\x01\x12\x04\x0b\0\x10\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\x0b\x08\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0c\x04\x17\n\
+    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x01\x12\x03\x0c\x0b\x12\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0c\x15\
+    \x16\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\r\x04\x13\n\x0c\n\x05\x04\x01\
+    \x02\x01\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\r\
+    \x0b\x0e\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\r\x11\x12\n\x0b\n\x04\
+    \x04\x01\x02\x02\x12\x03\x0e\x04\x1d\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\
+    \x03\x0e\x04\x11\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0e\x12\x18\n\
+    \x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0e\x1b\x1c\n\x0b\n\x04\x04\x01\
+    \x02\x03\x12\x03\x0f\x04\x16\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x0f\
+    \x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0f\x0b\x11\n\x0c\n\x05\
+    \x04\x01\x02\x03\x03\x12\x03\x0f\x14\x15\n\n\n\x02\x04\x02\x12\x04\x11\0\
+    \x16\x01\n\n\n\x03\x04\x02\x01\x12\x03\x11\x08\x1c\n\x0b\n\x04\x04\x02\
+    \x02\0\x12\x03\x12\x04\x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x12\x04\
+    \n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x12\x0b\x12\n\x0c\n\x05\x04\x02\
+    \x02\0\x03\x12\x03\x12\x15\x16\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x13\
+    \x04\x13\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x13\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\x01\x01\x12\x03\x13\x0b\x0e\n\x0c\n\x05\x04\x02\x02\x01\x03\
+    \x12\x03\x13\x11\x12\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x14\x04\x1d\n\
+    \x0c\n\x05\x04\x02\x02\x02\x06\x12\x03\x14\x04\x11\n\x0c\n\x05\x04\x02\
+    \x02\x02\x01\x12\x03\x14\x12\x18\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\
+    \x14\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x15\x04\x16\n\x0c\n\x05\
+    \x04\x02\x02\x03\x05\x12\x03\x15\x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\
+    \x12\x03\x15\x0b\x11\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x15\x14\x15\
+    \n\n\n\x02\x05\0\x12\x04\x17\0\x1a\x01\n\n\n\x03\x05\0\x01\x12\x03\x17\
+    \x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x18\x04\x11\n\x0c\n\x05\x05\0\
+    \x02\0\x01\x12\x03\x18\x04\x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x18\
+    \x0f\x10\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x19\x04\r\n\x0c\n\x05\x05\0\
+    \x02\x01\x01\x12\x03\x19\x04\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
+    \x19\x0b\x0cb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
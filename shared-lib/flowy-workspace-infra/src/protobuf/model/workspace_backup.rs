@@ -0,0 +1,529 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_backup.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RestoredAttachment {
+    // message fields
+    pub backup_id: ::std::string::String,
+    pub attachment_id: ::std::string::String,
+    pub doc_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RestoredAttachment {
+    fn default() -> &'a RestoredAttachment {
+        <RestoredAttachment as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RestoredAttachment {
+    pub fn new() -> RestoredAttachment {
+        ::std::default::Default::default()
+    }
+
+    // string backup_id = 1;
+
+
+    pub fn get_backup_id(&self) -> &str {
+        &self.backup_id
+    }
+    pub fn clear_backup_id(&mut self) {
+        self.backup_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_backup_id(&mut self, v: ::std::string::String) {
+        self.backup_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_backup_id(&mut self) -> &mut ::std::string::String {
+        &mut self.backup_id
+    }
+
+    // Take field
+    pub fn take_backup_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.backup_id, ::std::string::String::new())
+    }
+
+    // string attachment_id = 2;
+
+
+    pub fn get_attachment_id(&self) -> &str {
+        &self.attachment_id
+    }
+    pub fn clear_attachment_id(&mut self) {
+        self.attachment_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachment_id(&mut self, v: ::std::string::String) {
+        self.attachment_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_attachment_id(&mut self) -> &mut ::std::string::String {
+        &mut self.attachment_id
+    }
+
+    // Take field
+    pub fn take_attachment_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.attachment_id, ::std::string::String::new())
+    }
+
+    // string doc_id = 3;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RestoredAttachment {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.backup_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.attachment_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.backup_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.backup_id);
+        }
+        if !self.attachment_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.attachment_id);
+        }
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.doc_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.backup_id.is_empty() {
+            os.write_string(1, &self.backup_id)?;
+        }
+        if !self.attachment_id.is_empty() {
+            os.write_string(2, &self.attachment_id)?;
+        }
+        if !self.doc_id.is_empty() {
+            os.write_string(3, &self.doc_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RestoredAttachment {
+        RestoredAttachment::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "backup_id",
+                |m: &RestoredAttachment| { &m.backup_id },
+                |m: &mut RestoredAttachment| { &mut m.backup_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "attachment_id",
+                |m: &RestoredAttachment| { &m.attachment_id },
+                |m: &mut RestoredAttachment| { &mut m.attachment_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &RestoredAttachment| { &m.doc_id },
+                |m: &mut RestoredAttachment| { &mut m.doc_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RestoredAttachment>(
+                "RestoredAttachment",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RestoredAttachment {
+        static instance: ::protobuf::rt::LazyV2<RestoredAttachment> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RestoredAttachment::new)
+    }
+}
+
+impl ::protobuf::Clear for RestoredAttachment {
+    fn clear(&mut self) {
+        self.backup_id.clear();
+        self.attachment_id.clear();
+        self.doc_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RestoredAttachment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RestoredAttachment {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceRestore {
+    // message fields
+    pub workspace: ::protobuf::SingularPtrField<super::workspace_create::Workspace>,
+    pub attachments: ::protobuf::RepeatedField<RestoredAttachment>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceRestore {
+    fn default() -> &'a WorkspaceRestore {
+        <WorkspaceRestore as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceRestore {
+    pub fn new() -> WorkspaceRestore {
+        ::std::default::Default::default()
+    }
+
+    // .Workspace workspace = 1;
+
+
+    pub fn get_workspace(&self) -> &super::workspace_create::Workspace {
+        self.workspace.as_ref().unwrap_or_else(|| <super::workspace_create::Workspace as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_workspace(&mut self) {
+        self.workspace.clear();
+    }
+
+    pub fn has_workspace(&self) -> bool {
+        self.workspace.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace(&mut self, v: super::workspace_create::Workspace) {
+        self.workspace = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace(&mut self) -> &mut super::workspace_create::Workspace {
+        if self.workspace.is_none() {
+            self.workspace.set_default();
+        }
+        self.workspace.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_workspace(&mut self) -> super::workspace_create::Workspace {
+        self.workspace.take().unwrap_or_else(|| super::workspace_create::Workspace::new())
+    }
+
+    // repeated .RestoredAttachment attachments = 2;
+
+
+    pub fn get_attachments(&self) -> &[RestoredAttachment] {
+        &self.attachments
+    }
+    pub fn clear_attachments(&mut self) {
+        self.attachments.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachments(&mut self, v: ::protobuf::RepeatedField<RestoredAttachment>) {
+        self.attachments = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_attachments(&mut self) -> &mut ::protobuf::RepeatedField<RestoredAttachment> {
+        &mut self.attachments
+    }
+
+    // Take field
+    pub fn take_attachments(&mut self) -> ::protobuf::RepeatedField<RestoredAttachment> {
+        ::std::mem::replace(&mut self.attachments, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for WorkspaceRestore {
+    fn is_initialized(&self) -> bool {
+        for v in &self.workspace {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.attachments {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.workspace)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.attachments)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.workspace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.attachments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.workspace.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.attachments {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceRestore {
+        WorkspaceRestore::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::workspace_create::Workspace>>(
+                "workspace",
+                |m: &WorkspaceRestore| { &m.workspace },
+                |m: &mut WorkspaceRestore| { &mut m.workspace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RestoredAttachment>>(
+                "attachments",
+                |m: &WorkspaceRestore| { &m.attachments },
+                |m: &mut WorkspaceRestore| { &mut m.attachments },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceRestore>(
+                "WorkspaceRestore",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceRestore {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceRestore> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceRestore::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceRestore {
+    fn clear(&mut self) {
+        self.workspace.clear();
+        self.attachments.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceRestore {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceRestore {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16workspace_backup.proto\x1a\x16workspace_create.proto\"m\n\x12Resto\
+    redAttachment\x12\x1b\n\tbackup_id\x18\x01\x20\x01(\tR\x08backupId\x12#\
+    \n\rattachment_id\x18\x02\x20\x01(\tR\x0cattachmentId\x12\x15\n\x06doc_i\
+    d\x18\x03\x20\x01(\tR\x05docId\"s\n\x10WorkspaceRestore\x12(\n\tworkspac\
+    e\x18\x01\x20\x01(\x0b2\n.WorkspaceR\tworkspace\x125\n\x0battachments\
+    \x18\x02\x20\x03(\x0b2\x13.RestoredAttachmentR\x0battachmentsJ\xee\x02\n\
+    \x06\x12\x04\0\0\x0b\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\
+    \x12\x03\x01\0\x20\n\n\n\x02\x04\0\x12\x04\x03\0\x07\x01\n\n\n\x03\x04\0\
+    \x01\x12\x03\x03\x08\x1a\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x19\n\
+    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x04\x0b\x14\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x17\x18\n\
+    \x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x1d\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x0b\x18\
+    \n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x1b\x1c\n\x0b\n\x04\x04\0\x02\
+    \x02\x12\x03\x06\x04\x16\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x06\x04\n\
+    \n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x0b\x11\n\x0c\n\x05\x04\0\x02\
+    \x02\x03\x12\x03\x06\x14\x15\n\n\n\x02\x04\x01\x12\x04\x08\0\x0b\x01\n\n\
+    \n\x03\x04\x01\x01\x12\x03\x08\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\
+    \t\x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\t\x04\r\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03\t\x0e\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\
+    \x1a\x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\n\x040\n\x0c\n\x05\x04\x01\
+    \x02\x01\x04\x12\x03\n\x04\x0c\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\n\
+    \r\x1f\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\n\x20+\n\x0c\n\x05\x04\
+    \x01\x02\x01\x03\x12\x03\n./b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
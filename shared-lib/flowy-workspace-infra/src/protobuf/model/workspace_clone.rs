@@ -0,0 +1,633 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_clone.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceCloneProgress {
+    // message fields
+    pub clone_id: ::std::string::String,
+    pub source_workspace_id: ::std::string::String,
+    pub workspace_id: ::std::string::String,
+    pub total: i64,
+    pub finished: i64,
+    pub state: WorkspaceCloneState,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceCloneProgress {
+    fn default() -> &'a WorkspaceCloneProgress {
+        <WorkspaceCloneProgress as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceCloneProgress {
+    pub fn new() -> WorkspaceCloneProgress {
+        ::std::default::Default::default()
+    }
+
+    // string clone_id = 1;
+
+
+    pub fn get_clone_id(&self) -> &str {
+        &self.clone_id
+    }
+    pub fn clear_clone_id(&mut self) {
+        self.clone_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clone_id(&mut self, v: ::std::string::String) {
+        self.clone_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_clone_id(&mut self) -> &mut ::std::string::String {
+        &mut self.clone_id
+    }
+
+    // Take field
+    pub fn take_clone_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.clone_id, ::std::string::String::new())
+    }
+
+    // string source_workspace_id = 2;
+
+
+    pub fn get_source_workspace_id(&self) -> &str {
+        &self.source_workspace_id
+    }
+    pub fn clear_source_workspace_id(&mut self) {
+        self.source_workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_workspace_id(&mut self, v: ::std::string::String) {
+        self.source_workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.source_workspace_id
+    }
+
+    // Take field
+    pub fn take_source_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source_workspace_id, ::std::string::String::new())
+    }
+
+    // string workspace_id = 3;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // int64 total = 4;
+
+
+    pub fn get_total(&self) -> i64 {
+        self.total
+    }
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: i64) {
+        self.total = v;
+    }
+
+    // int64 finished = 5;
+
+
+    pub fn get_finished(&self) -> i64 {
+        self.finished
+    }
+    pub fn clear_finished(&mut self) {
+        self.finished = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_finished(&mut self, v: i64) {
+        self.finished = v;
+    }
+
+    // .WorkspaceCloneState state = 6;
+
+
+    pub fn get_state(&self) -> WorkspaceCloneState {
+        self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state = WorkspaceCloneState::Running;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: WorkspaceCloneState) {
+        self.state = v;
+    }
+}
+
+impl ::protobuf::Message for WorkspaceCloneProgress {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.clone_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source_workspace_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.finished = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.state, 6, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.clone_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.clone_id);
+        }
+        if !self.source_workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.source_workspace_id);
+        }
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.workspace_id);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.finished != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.finished, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.state != WorkspaceCloneState::Running {
+            my_size += ::protobuf::rt::enum_size(6, self.state);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.clone_id.is_empty() {
+            os.write_string(1, &self.clone_id)?;
+        }
+        if !self.source_workspace_id.is_empty() {
+            os.write_string(2, &self.source_workspace_id)?;
+        }
+        if !self.workspace_id.is_empty() {
+            os.write_string(3, &self.workspace_id)?;
+        }
+        if self.total != 0 {
+            os.write_int64(4, self.total)?;
+        }
+        if self.finished != 0 {
+            os.write_int64(5, self.finished)?;
+        }
+        if self.state != WorkspaceCloneState::Running {
+            os.write_enum(6, ::protobuf::ProtobufEnum::value(&self.state))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceCloneProgress {
+        WorkspaceCloneProgress::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "clone_id",
+                |m: &WorkspaceCloneProgress| { &m.clone_id },
+                |m: &mut WorkspaceCloneProgress| { &mut m.clone_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_workspace_id",
+                |m: &WorkspaceCloneProgress| { &m.source_workspace_id },
+                |m: &mut WorkspaceCloneProgress| { &mut m.source_workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceCloneProgress| { &m.workspace_id },
+                |m: &mut WorkspaceCloneProgress| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "total",
+                |m: &WorkspaceCloneProgress| { &m.total },
+                |m: &mut WorkspaceCloneProgress| { &mut m.total },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "finished",
+                |m: &WorkspaceCloneProgress| { &m.finished },
+                |m: &mut WorkspaceCloneProgress| { &mut m.finished },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkspaceCloneState>>(
+                "state",
+                |m: &WorkspaceCloneProgress| { &m.state },
+                |m: &mut WorkspaceCloneProgress| { &mut m.state },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceCloneProgress>(
+                "WorkspaceCloneProgress",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceCloneProgress {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceCloneProgress> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceCloneProgress::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceCloneProgress {
+    fn clear(&mut self) {
+        self.clone_id.clear();
+        self.source_workspace_id.clear();
+        self.workspace_id.clear();
+        self.total = 0;
+        self.finished = 0;
+        self.state = WorkspaceCloneState::Running;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceCloneProgress {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceCloneProgress {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceCloneIdentifier {
+    // message fields
+    pub clone_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceCloneIdentifier {
+    fn default() -> &'a WorkspaceCloneIdentifier {
+        <WorkspaceCloneIdentifier as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceCloneIdentifier {
+    pub fn new() -> WorkspaceCloneIdentifier {
+        ::std::default::Default::default()
+    }
+
+    // string clone_id = 1;
+
+
+    pub fn get_clone_id(&self) -> &str {
+        &self.clone_id
+    }
+    pub fn clear_clone_id(&mut self) {
+        self.clone_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clone_id(&mut self, v: ::std::string::String) {
+        self.clone_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_clone_id(&mut self) -> &mut ::std::string::String {
+        &mut self.clone_id
+    }
+
+    // Take field
+    pub fn take_clone_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.clone_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for WorkspaceCloneIdentifier {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.clone_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.clone_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.clone_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.clone_id.is_empty() {
+            os.write_string(1, &self.clone_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceCloneIdentifier {
+        WorkspaceCloneIdentifier::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "clone_id",
+                |m: &WorkspaceCloneIdentifier| { &m.clone_id },
+                |m: &mut WorkspaceCloneIdentifier| { &mut m.clone_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceCloneIdentifier>(
+                "WorkspaceCloneIdentifier",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceCloneIdentifier {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceCloneIdentifier> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceCloneIdentifier::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceCloneIdentifier {
+    fn clear(&mut self) {
+        self.clone_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceCloneIdentifier {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceCloneIdentifier {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum WorkspaceCloneState {
+    Running = 0,
+    Completed = 1,
+    Failed = 2,
+}
+
+impl ::protobuf::ProtobufEnum for WorkspaceCloneState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WorkspaceCloneState> {
+        match value {
+            0 => ::std::option::Option::Some(WorkspaceCloneState::Running),
+            1 => ::std::option::Option::Some(WorkspaceCloneState::Completed),
+            2 => ::std::option::Option::Some(WorkspaceCloneState::Failed),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [WorkspaceCloneState] = &[
+            WorkspaceCloneState::Running,
+            WorkspaceCloneState::Completed,
+            WorkspaceCloneState::Failed,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<WorkspaceCloneState>("WorkspaceCloneState", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for WorkspaceCloneState {
+}
+
+impl ::std::default::Default for WorkspaceCloneState {
+    fn default() -> Self {
+        WorkspaceCloneState::Running
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceCloneState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x15workspace_clone.proto\"\xe4\x01\n\x16WorkspaceCloneProgress\x12\
+    \x19\n\x08clone_id\x18\x01\x20\x01(\tR\x07cloneId\x12.\n\x13source_works\
+    pace_id\x18\x02\x20\x01(\tR\x11sourceWorkspaceId\x12!\n\x0cworkspace_id\
+    \x18\x03\x20\x01(\tR\x0bworkspaceId\x12\x14\n\x05total\x18\x04\x20\x01(\
+    \x03R\x05total\x12\x1a\n\x08finished\x18\x05\x20\x01(\x03R\x08finished\
+    \x12*\n\x05state\x18\x06\x20\x01(\x0e2\x14.WorkspaceCloneStateR\x05state\
+    \"5\n\x18WorkspaceCloneIdentifier\x12\x19\n\x08clone_id\x18\x01\x20\x01(\
+    \tR\x07cloneId*=\n\x13WorkspaceCloneState\x12\x0b\n\x07Running\x10\0\x12\
+    \r\n\tCompleted\x10\x01\x12\n\n\x06Failed\x10\x02J\xd6\x04\n\x06\x12\x04\
+    \0\0\x11\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\
+    \0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x1e\n\x0b\n\x04\x04\0\x02\0\
+    \x12\x03\x03\x04\x18\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\
+    \n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x13\n\x0c\n\x05\x04\0\x02\0\x03\
+    \x12\x03\x03\x16\x17\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04#\n\x0c\n\
+    \x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\x04\x0b\x1e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04!\"\n\x0b\
+    \n\x04\x04\0\x02\x02\x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\x02\x05\
+    \x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x17\n\
+    \x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x1a\x1b\n\x0b\n\x04\x04\0\x02\
+    \x03\x12\x03\x06\x04\x14\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\t\
+    \n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\n\x0f\n\x0c\n\x05\x04\0\x02\
+    \x03\x03\x12\x03\x06\x12\x13\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\
+    \x17\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\0\
+    \x02\x04\x01\x12\x03\x07\n\x12\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\
+    \x15\x16\n\x0b\n\x04\x04\0\x02\x05\x12\x03\x08\x04\"\n\x0c\n\x05\x04\0\
+    \x02\x05\x06\x12\x03\x08\x04\x17\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\
+    \x08\x18\x1d\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x08\x20!\n\n\n\x02\
+    \x04\x01\x12\x04\n\0\x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\n\x08\x20\n\
+    \x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x04\x18\n\x0c\n\x05\x04\x01\x02\0\
+    \x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b\x0b\x13\
+    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b\x16\x17\n\n\n\x02\x05\0\x12\
+    \x04\r\0\x11\x01\n\n\n\x03\x05\0\x01\x12\x03\r\x05\x18\n\x0b\n\x04\x05\0\
+    \x02\0\x12\x03\x0e\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x0e\x04\
+    \x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x0e\x0e\x0f\n\x0b\n\x04\x05\0\
+    \x02\x01\x12\x03\x0f\x04\x12\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x0f\
+    \x04\r\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x0f\x10\x11\n\x0b\n\x04\x05\
+    \0\x02\x02\x12\x03\x10\x04\x0f\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x10\
+    \x04\n\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x10\r\x0eb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -434,6 +434,8 @@ pub struct Workspace {
     pub apps: ::protobuf::SingularPtrField<super::app_create::RepeatedApp>,
     pub modified_time: i64,
     pub create_time: i64,
+    pub archived: bool,
+    pub role: super::workspace_member::WorkspaceRole,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -590,6 +592,36 @@ impl Workspace {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // bool archived = 7;
+
+
+    pub fn get_archived(&self) -> bool {
+        self.archived
+    }
+    pub fn clear_archived(&mut self) {
+        self.archived = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_archived(&mut self, v: bool) {
+        self.archived = v;
+    }
+
+    // .WorkspaceRole role = 8;
+
+
+    pub fn get_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.role = v;
+    }
 }
 
 impl ::protobuf::Message for Workspace {
@@ -632,6 +664,16 @@ impl ::protobuf::Message for Workspace {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.archived = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 8, &mut self.unknown_fields)?
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -663,6 +705,12 @@ impl ::protobuf::Message for Workspace {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(6, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.archived != false {
+            my_size += 2;
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            my_size += ::protobuf::rt::enum_size(8, self.role);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -689,6 +737,12 @@ impl ::protobuf::Message for Workspace {
         if self.create_time != 0 {
             os.write_int64(6, self.create_time)?;
         }
+        if self.archived != false {
+            os.write_bool(7, self.archived)?;
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -757,6 +811,16 @@ impl ::protobuf::Message for Workspace {
                 |m: &Workspace| { &m.create_time },
                 |m: &mut Workspace| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "archived",
+                |m: &Workspace| { &m.archived },
+                |m: &mut Workspace| { &mut m.archived },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "role",
+                |m: &Workspace| { &m.role },
+                |m: &mut Workspace| { &mut m.role },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Workspace>(
                 "Workspace",
                 fields,
@@ -779,6 +843,8 @@ impl ::protobuf::Clear for Workspace {
         self.apps.clear();
         self.modified_time = 0;
         self.create_time = 0;
+        self.archived = false;
+        self.role = super::workspace_member::WorkspaceRole::Owner;
         self.unknown_fields.clear();
     }
 }
@@ -962,54 +1028,62 @@ impl ::protobuf::reflect::ProtobufValue for RepeatedWorkspace {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x16workspace_create.proto\x1a\x10app_create.proto\"@\n\x16CreateWorks\
-    paceRequest\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x12\n\x04d\
-    esc\x18\x02\x20\x01(\tR\x04desc\"?\n\x15CreateWorkspaceParams\x12\x12\n\
-    \x04name\x18\x01\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x02\x20\x01(\
-    \tR\x04desc\"\xab\x01\n\tWorkspace\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\
-    \x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\
-    \x18\x03\x20\x01(\tR\x04desc\x12\x20\n\x04apps\x18\x04\x20\x01(\x0b2\x0c\
-    .RepeatedAppR\x04apps\x12#\n\rmodified_time\x18\x05\x20\x01(\x03R\x0cmod\
-    ifiedTime\x12\x1f\n\x0bcreate_time\x18\x06\x20\x01(\x03R\ncreateTime\"5\
-    \n\x11RepeatedWorkspace\x12\x20\n\x05items\x18\x01\x20\x03(\x0b2\n.Works\
-    paceR\x05itemsJ\xe8\x05\n\x06\x12\x04\0\0\x15\x01\n\x08\n\x01\x0c\x12\
-    \x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1a\n\n\n\x02\x04\0\x12\x04\
-    \x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x1e\n\x0b\n\x04\x04\0\
-    \x02\0\x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\
-    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x14\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x12\
-    \x13\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\n\n\x03\x04\x01\x01\x12\x03\
-    \x07\x08\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x14\n\x0c\n\x05\
-    \x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\x08\x0b\x0f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x12\x13\n\x0b\
-    \n\x04\x04\x01\x02\x01\x12\x03\t\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\x05\
-    \x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x0b\x0f\n\x0c\
-    \n\x05\x04\x01\x02\x01\x03\x12\x03\t\x12\x13\n\n\n\x02\x04\x02\x12\x04\
-    \x0b\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x11\n\x0b\n\x04\x04\
-    \x02\x02\0\x12\x03\x0c\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0c\
-    \x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\r\n\x0c\n\x05\x04\
-    \x02\x02\0\x03\x12\x03\x0c\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\r\
-    \x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03\r\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
-    \x03\r\x12\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0e\x04\x14\n\x0c\n\
-    \x05\x04\x02\x02\x02\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x02\
-    \x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0e\x12\
-    \x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x0f\x04\x19\n\x0c\n\x05\x04\x02\
-    \x02\x03\x06\x12\x03\x0f\x04\x0f\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\
-    \x0f\x10\x14\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x0f\x17\x18\n\x0b\n\
-    \x04\x04\x02\x02\x04\x12\x03\x10\x04\x1c\n\x0c\n\x05\x04\x02\x02\x04\x05\
-    \x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x10\n\x17\n\
-    \x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x10\x1a\x1b\n\x0b\n\x04\x04\x02\
-    \x02\x05\x12\x03\x11\x04\x1a\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x11\
-    \x04\t\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03\x11\n\x15\n\x0c\n\x05\x04\
-    \x02\x02\x05\x03\x12\x03\x11\x18\x19\n\n\n\x02\x04\x03\x12\x04\x13\0\x15\
-    \x01\n\n\n\x03\x04\x03\x01\x12\x03\x13\x08\x19\n\x0b\n\x04\x04\x03\x02\0\
-    \x12\x03\x14\x04!\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x14\x04\x0c\n\
-    \x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x14\r\x16\n\x0c\n\x05\x04\x03\x02\0\
-    \x01\x12\x03\x14\x17\x1c\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x1f\
-    \x20b\x06proto3\
+    \n\x16workspace_create.proto\x1a\x10app_create.proto\x1a\x16workspace_me\
+    mber.proto\"@\n\x16CreateWorkspaceRequest\x12\x12\n\x04name\x18\x01\x20\
+    \x01(\tR\x04name\x12\x12\n\x04desc\x18\x02\x20\x01(\tR\x04desc\"?\n\x15C\
+    reateWorkspaceParams\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\
+    \x12\n\x04desc\x18\x02\x20\x01(\tR\x04desc\"\xeb\x01\n\tWorkspace\x12\
+    \x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\x04name\x18\x02\x20\x01\
+    (\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12\x20\n\x04\
+    apps\x18\x04\x20\x01(\x0b2\x0c.RepeatedAppR\x04apps\x12#\n\rmodified_tim\
+    e\x18\x05\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_time\x18\x06\
+    \x20\x01(\x03R\ncreateTime\x12\x1a\n\x08archived\x18\x07\x20\x01(\x08R\
+    \x08archived\x12\"\n\x04role\x18\x08\x20\x01(\x0e2\x0e.WorkspaceRoleR\
+    \x04role\"5\n\x11RepeatedWorkspace\x12\x20\n\x05items\x18\x01\x20\x03(\
+    \x0b2\n.WorkspaceR\x05itemsJ\xe1\x06\n\x06\x12\x04\0\0\x18\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1a\n\t\n\x02\x03\
+    \x01\x12\x03\x02\0\x20\n\n\n\x02\x04\0\x12\x04\x04\0\x07\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x04\x08\x1e\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x04\
+    \x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\
+    \0\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x12\
+    \x13\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x06\x04\x14\n\x0c\n\x05\x04\0\x02\
+    \x01\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x06\x0b\
+    \x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x06\x12\x13\n\n\n\x02\x04\x01\
+    \x12\x04\x08\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\x03\x08\x08\x1d\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03\t\x04\x14\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
+    \x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\t\x0b\x0f\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\t\x12\x13\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\
+    \n\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\n\x04\n\n\x0c\n\x05\
+    \x04\x01\x02\x01\x01\x12\x03\n\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\
+    \x12\x03\n\x12\x13\n\n\n\x02\x04\x02\x12\x04\x0c\0\x15\x01\n\n\n\x03\x04\
+    \x02\x01\x12\x03\x0c\x08\x11\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x12\
+    \n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\
+    \x01\x12\x03\r\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x10\x11\n\
+    \x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x04\x02\x02\
+    \x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\
+    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x12\x13\n\x0b\n\x04\
+    \x04\x02\x02\x02\x12\x03\x0f\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\
+    \x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x0b\x0f\n\x0c\
+    \n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x12\x13\n\x0b\n\x04\x04\x02\x02\
+    \x03\x12\x03\x10\x04\x19\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03\x10\x04\
+    \x0f\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x10\x10\x14\n\x0c\n\x05\x04\
+    \x02\x02\x03\x03\x12\x03\x10\x17\x18\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\
+    \x11\x04\x1c\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03\x11\x04\t\n\x0c\n\
+    \x05\x04\x02\x02\x04\x01\x12\x03\x11\n\x17\n\x0c\n\x05\x04\x02\x02\x04\
+    \x03\x12\x03\x11\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x12\x04\x1a\
+    \n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x12\x04\t\n\x0c\n\x05\x04\x02\
+    \x02\x05\x01\x12\x03\x12\n\x15\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\
+    \x12\x18\x19\n\x0b\n\x04\x04\x02\x02\x06\x12\x03\x13\x04\x16\n\x0c\n\x05\
+    \x04\x02\x02\x06\x05\x12\x03\x13\x04\x08\n\x0c\n\x05\x04\x02\x02\x06\x01\
+    \x12\x03\x13\t\x11\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\x13\x14\x15\n\
+    \x0b\n\x04\x04\x02\x02\x07\x12\x03\x14\x04\x1b\n\x0c\n\x05\x04\x02\x02\
+    \x07\x06\x12\x03\x14\x04\x11\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03\x14\
+    \x12\x16\n\x0c\n\x05\x04\x02\x02\x07\x03\x12\x03\x14\x19\x1a\n\n\n\x02\
+    \x04\x03\x12\x04\x16\0\x18\x01\n\n\n\x03\x04\x03\x01\x12\x03\x16\x08\x19\
+    \n\x0b\n\x04\x04\x03\x02\0\x12\x03\x17\x04!\n\x0c\n\x05\x04\x03\x02\0\
+    \x04\x12\x03\x17\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x17\r\x16\
+    \n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x17\x17\x1c\n\x0c\n\x05\x04\x03\
+    \x02\0\x03\x12\x03\x17\x1f\x20b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,495 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_filter.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListFilter {
+    // message fields
+    pub limit: i64,
+    pub offset: i64,
+    // message oneof groups
+    pub one_of_name: ::std::option::Option<ListFilter_oneof_one_of_name>,
+    pub one_of_after: ::std::option::Option<ListFilter_oneof_one_of_after>,
+    pub one_of_sort_by: ::std::option::Option<ListFilter_oneof_one_of_sort_by>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListFilter {
+    fn default() -> &'a ListFilter {
+        <ListFilter as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ListFilter_oneof_one_of_name {
+    name(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ListFilter_oneof_one_of_after {
+    after(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum ListFilter_oneof_one_of_sort_by {
+    sort_by(::std::string::String),
+}
+
+impl ListFilter {
+    pub fn new() -> ListFilter {
+        ::std::default::Default::default()
+    }
+
+    // int64 limit = 1;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+
+    // int64 offset = 2;
+
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        match self.one_of_name {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_name::name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_name(&mut self) {
+        self.one_of_name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        match self.one_of_name {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_name::name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.one_of_name = ::std::option::Option::Some(ListFilter_oneof_one_of_name::name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ListFilter_oneof_one_of_name::name(_)) = self.one_of_name {
+        } else {
+            self.one_of_name = ::std::option::Option::Some(ListFilter_oneof_one_of_name::name(::std::string::String::new()));
+        }
+        match self.one_of_name {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_name::name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        if self.has_name() {
+            match self.one_of_name.take() {
+                ::std::option::Option::Some(ListFilter_oneof_one_of_name::name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string after = 4;
+
+
+    pub fn get_after(&self) -> &str {
+        match self.one_of_after {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_after::after(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_after(&mut self) {
+        self.one_of_after = ::std::option::Option::None;
+    }
+
+    pub fn has_after(&self) -> bool {
+        match self.one_of_after {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_after::after(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_after(&mut self, v: ::std::string::String) {
+        self.one_of_after = ::std::option::Option::Some(ListFilter_oneof_one_of_after::after(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_after(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ListFilter_oneof_one_of_after::after(_)) = self.one_of_after {
+        } else {
+            self.one_of_after = ::std::option::Option::Some(ListFilter_oneof_one_of_after::after(::std::string::String::new()));
+        }
+        match self.one_of_after {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_after::after(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_after(&mut self) -> ::std::string::String {
+        if self.has_after() {
+            match self.one_of_after.take() {
+                ::std::option::Option::Some(ListFilter_oneof_one_of_after::after(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string sort_by = 5;
+
+
+    pub fn get_sort_by(&self) -> &str {
+        match self.one_of_sort_by {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_sort_by::sort_by(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_sort_by(&mut self) {
+        self.one_of_sort_by = ::std::option::Option::None;
+    }
+
+    pub fn has_sort_by(&self) -> bool {
+        match self.one_of_sort_by {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_sort_by::sort_by(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sort_by(&mut self, v: ::std::string::String) {
+        self.one_of_sort_by = ::std::option::Option::Some(ListFilter_oneof_one_of_sort_by::sort_by(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sort_by(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(ListFilter_oneof_one_of_sort_by::sort_by(_)) = self.one_of_sort_by {
+        } else {
+            self.one_of_sort_by = ::std::option::Option::Some(ListFilter_oneof_one_of_sort_by::sort_by(::std::string::String::new()));
+        }
+        match self.one_of_sort_by {
+            ::std::option::Option::Some(ListFilter_oneof_one_of_sort_by::sort_by(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sort_by(&mut self) -> ::std::string::String {
+        if self.has_sort_by() {
+            match self.one_of_sort_by.take() {
+                ::std::option::Option::Some(ListFilter_oneof_one_of_sort_by::sort_by(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for ListFilter {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(ListFilter_oneof_one_of_name::name(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_after = ::std::option::Option::Some(ListFilter_oneof_one_of_after::after(is.read_string()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_sort_by = ::std::option::Option::Some(ListFilter_oneof_one_of_sort_by::sort_by(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &ListFilter_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_after {
+            match v {
+                &ListFilter_oneof_one_of_after::after(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_sort_by {
+            match v {
+                &ListFilter_oneof_one_of_sort_by::sort_by(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.limit != 0 {
+            os.write_int64(1, self.limit)?;
+        }
+        if self.offset != 0 {
+            os.write_int64(2, self.offset)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &ListFilter_oneof_one_of_name::name(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_after {
+            match v {
+                &ListFilter_oneof_one_of_after::after(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_sort_by {
+            match v {
+                &ListFilter_oneof_one_of_sort_by::sort_by(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListFilter {
+        ListFilter::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &ListFilter| { &m.limit },
+                |m: &mut ListFilter| { &mut m.limit },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "offset",
+                |m: &ListFilter| { &m.offset },
+                |m: &mut ListFilter| { &mut m.offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                ListFilter::has_name,
+                ListFilter::get_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "after",
+                ListFilter::has_after,
+                ListFilter::get_after,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "sort_by",
+                ListFilter::has_sort_by,
+                ListFilter::get_sort_by,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ListFilter>(
+                "ListFilter",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ListFilter {
+        static instance: ::protobuf::rt::LazyV2<ListFilter> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ListFilter::new)
+    }
+}
+
+impl ::protobuf::Clear for ListFilter {
+    fn clear(&mut self) {
+        self.limit = 0;
+        self.offset = 0;
+        self.one_of_name = ::std::option::Option::None;
+        self.one_of_after = ::std::option::Option::None;
+        self.one_of_sort_by = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListFilter {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListFilter {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16workspace_filter.proto\"\xb4\x01\n\nListFilter\x12\x14\n\x05limit\
+    \x18\x01\x20\x01(\x03R\x05limit\x12\x16\n\x06offset\x18\x02\x20\x01(\x03\
+    R\x06offset\x12\x14\n\x04name\x18\x03\x20\x01(\tH\0R\x04name\x12\x16\n\
+    \x05after\x18\x04\x20\x01(\tH\x01R\x05after\x12\x19\n\x07sort_by\x18\x05\
+    \x20\x01(\tH\x02R\x06sortByB\r\n\x0bone_of_nameB\x0e\n\x0cone_of_afterB\
+    \x10\n\x0eone_of_sort_byJ\x8e\x03\n\x06\x12\x04\0\0\x08\x01\n\x08\n\x01\
+    \x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x08\x01\n\n\n\x03\x04\
+    \0\x01\x12\x03\x02\x08\x12\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x14\n\
+    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x03\n\x0f\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x12\x13\n\x0b\
+    \n\x04\x04\0\x02\x01\x12\x03\x04\x04\x15\n\x0c\n\x05\x04\0\x02\x01\x05\
+    \x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\n\x10\n\x0c\
+    \n\x05\x04\0\x02\x01\x03\x12\x03\x04\x13\x14\n\x0b\n\x04\x04\0\x08\0\x12\
+    \x03\x05\x04*\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x05\n\x15\n\x0b\n\x04\
+    \x04\0\x02\x02\x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\
+    \x05\x18\x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x1f#\n\x0c\n\x05\
+    \x04\0\x02\x02\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x06\
+    \x04,\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x06\n\x16\n\x0b\n\x04\x04\0\
+    \x02\x03\x12\x03\x06\x19*\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x19\
+    \x1f\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\x20%\n\x0c\n\x05\x04\0\
+    \x02\x03\x03\x12\x03\x06()\n\x0b\n\x04\x04\0\x08\x02\x12\x03\x07\x040\n\
+    \x0c\n\x05\x04\0\x08\x02\x01\x12\x03\x07\n\x18\n\x0b\n\x04\x04\0\x02\x04\
+    \x12\x03\x07\x1b.\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x07\x1b!\n\x0c\n\
+    \x05\x04\0\x02\x04\x01\x12\x03\x07\")\n\x0c\n\x05\x04\0\x02\x04\x03\x12\
+    \x03\x07,-b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,916 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_merge.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MergeAccountRequest {
+    // message fields
+    pub source_user_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeAccountRequest {
+    fn default() -> &'a MergeAccountRequest {
+        <MergeAccountRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeAccountRequest {
+    pub fn new() -> MergeAccountRequest {
+        ::std::default::Default::default()
+    }
+
+    // string source_user_id = 1;
+
+
+    pub fn get_source_user_id(&self) -> &str {
+        &self.source_user_id
+    }
+    pub fn clear_source_user_id(&mut self) {
+        self.source_user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_user_id(&mut self, v: ::std::string::String) {
+        self.source_user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.source_user_id
+    }
+
+    // Take field
+    pub fn take_source_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source_user_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for MergeAccountRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source_user_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.source_user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.source_user_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.source_user_id.is_empty() {
+            os.write_string(1, &self.source_user_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeAccountRequest {
+        MergeAccountRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_user_id",
+                |m: &MergeAccountRequest| { &m.source_user_id },
+                |m: &mut MergeAccountRequest| { &mut m.source_user_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MergeAccountRequest>(
+                "MergeAccountRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MergeAccountRequest {
+        static instance: ::protobuf::rt::LazyV2<MergeAccountRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MergeAccountRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MergeAccountRequest {
+    fn clear(&mut self) {
+        self.source_user_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeAccountRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeAccountRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceMergeItem {
+    // message fields
+    pub source_id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub target_id: ::std::string::String,
+    pub app_count: i64,
+    pub view_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceMergeItem {
+    fn default() -> &'a WorkspaceMergeItem {
+        <WorkspaceMergeItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceMergeItem {
+    pub fn new() -> WorkspaceMergeItem {
+        ::std::default::Default::default()
+    }
+
+    // string source_id = 1;
+
+
+    pub fn get_source_id(&self) -> &str {
+        &self.source_id
+    }
+    pub fn clear_source_id(&mut self) {
+        self.source_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_id(&mut self, v: ::std::string::String) {
+        self.source_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_id(&mut self) -> &mut ::std::string::String {
+        &mut self.source_id
+    }
+
+    // Take field
+    pub fn take_source_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source_id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string target_id = 3;
+
+
+    pub fn get_target_id(&self) -> &str {
+        &self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: ::std::string::String) {
+        self.target_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_id(&mut self) -> &mut ::std::string::String {
+        &mut self.target_id
+    }
+
+    // Take field
+    pub fn take_target_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_id, ::std::string::String::new())
+    }
+
+    // int64 app_count = 4;
+
+
+    pub fn get_app_count(&self) -> i64 {
+        self.app_count
+    }
+    pub fn clear_app_count(&mut self) {
+        self.app_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_count(&mut self, v: i64) {
+        self.app_count = v;
+    }
+
+    // int64 view_count = 5;
+
+
+    pub fn get_view_count(&self) -> i64 {
+        self.view_count
+    }
+    pub fn clear_view_count(&mut self) {
+        self.view_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_count(&mut self, v: i64) {
+        self.view_count = v;
+    }
+}
+
+impl ::protobuf::Message for WorkspaceMergeItem {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.app_count = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.view_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.source_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.source_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.target_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.target_id);
+        }
+        if self.app_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.app_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.view_count != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.view_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.source_id.is_empty() {
+            os.write_string(1, &self.source_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.target_id.is_empty() {
+            os.write_string(3, &self.target_id)?;
+        }
+        if self.app_count != 0 {
+            os.write_int64(4, self.app_count)?;
+        }
+        if self.view_count != 0 {
+            os.write_int64(5, self.view_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceMergeItem {
+        WorkspaceMergeItem::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_id",
+                |m: &WorkspaceMergeItem| { &m.source_id },
+                |m: &mut WorkspaceMergeItem| { &mut m.source_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &WorkspaceMergeItem| { &m.name },
+                |m: &mut WorkspaceMergeItem| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_id",
+                |m: &WorkspaceMergeItem| { &m.target_id },
+                |m: &mut WorkspaceMergeItem| { &mut m.target_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "app_count",
+                |m: &WorkspaceMergeItem| { &m.app_count },
+                |m: &mut WorkspaceMergeItem| { &mut m.app_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "view_count",
+                |m: &WorkspaceMergeItem| { &m.view_count },
+                |m: &mut WorkspaceMergeItem| { &mut m.view_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceMergeItem>(
+                "WorkspaceMergeItem",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceMergeItem {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceMergeItem> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceMergeItem::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceMergeItem {
+    fn clear(&mut self) {
+        self.source_id.clear();
+        self.name.clear();
+        self.target_id.clear();
+        self.app_count = 0;
+        self.view_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceMergeItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceMergeItem {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccountMergeReport {
+    // message fields
+    pub source_user_id: ::std::string::String,
+    pub workspaces: ::protobuf::RepeatedField<WorkspaceMergeItem>,
+    pub favorite_count: i64,
+    pub settings: ::protobuf::RepeatedField<::std::string::String>,
+    pub skipped_count: i64,
+    pub is_applied: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AccountMergeReport {
+    fn default() -> &'a AccountMergeReport {
+        <AccountMergeReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AccountMergeReport {
+    pub fn new() -> AccountMergeReport {
+        ::std::default::Default::default()
+    }
+
+    // string source_user_id = 1;
+
+
+    pub fn get_source_user_id(&self) -> &str {
+        &self.source_user_id
+    }
+    pub fn clear_source_user_id(&mut self) {
+        self.source_user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_user_id(&mut self, v: ::std::string::String) {
+        self.source_user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.source_user_id
+    }
+
+    // Take field
+    pub fn take_source_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source_user_id, ::std::string::String::new())
+    }
+
+    // repeated .WorkspaceMergeItem workspaces = 2;
+
+
+    pub fn get_workspaces(&self) -> &[WorkspaceMergeItem] {
+        &self.workspaces
+    }
+    pub fn clear_workspaces(&mut self) {
+        self.workspaces.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspaces(&mut self, v: ::protobuf::RepeatedField<WorkspaceMergeItem>) {
+        self.workspaces = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_workspaces(&mut self) -> &mut ::protobuf::RepeatedField<WorkspaceMergeItem> {
+        &mut self.workspaces
+    }
+
+    // Take field
+    pub fn take_workspaces(&mut self) -> ::protobuf::RepeatedField<WorkspaceMergeItem> {
+        ::std::mem::replace(&mut self.workspaces, ::protobuf::RepeatedField::new())
+    }
+
+    // int64 favorite_count = 3;
+
+
+    pub fn get_favorite_count(&self) -> i64 {
+        self.favorite_count
+    }
+    pub fn clear_favorite_count(&mut self) {
+        self.favorite_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_favorite_count(&mut self, v: i64) {
+        self.favorite_count = v;
+    }
+
+    // repeated string settings = 4;
+
+
+    pub fn get_settings(&self) -> &[::std::string::String] {
+        &self.settings
+    }
+    pub fn clear_settings(&mut self) {
+        self.settings.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_settings(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.settings = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_settings(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.settings
+    }
+
+    // Take field
+    pub fn take_settings(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.settings, ::protobuf::RepeatedField::new())
+    }
+
+    // int64 skipped_count = 5;
+
+
+    pub fn get_skipped_count(&self) -> i64 {
+        self.skipped_count
+    }
+    pub fn clear_skipped_count(&mut self) {
+        self.skipped_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_skipped_count(&mut self, v: i64) {
+        self.skipped_count = v;
+    }
+
+    // bool is_applied = 6;
+
+
+    pub fn get_is_applied(&self) -> bool {
+        self.is_applied
+    }
+    pub fn clear_is_applied(&mut self) {
+        self.is_applied = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_applied(&mut self, v: bool) {
+        self.is_applied = v;
+    }
+}
+
+impl ::protobuf::Message for AccountMergeReport {
+    fn is_initialized(&self) -> bool {
+        for v in &self.workspaces {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source_user_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.workspaces)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.favorite_count = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.settings)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.skipped_count = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_applied = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.source_user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.source_user_id);
+        }
+        for value in &self.workspaces {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.favorite_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.favorite_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.settings {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if self.skipped_count != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.skipped_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_applied != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.source_user_id.is_empty() {
+            os.write_string(1, &self.source_user_id)?;
+        }
+        for v in &self.workspaces {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.favorite_count != 0 {
+            os.write_int64(3, self.favorite_count)?;
+        }
+        for v in &self.settings {
+            os.write_string(4, &v)?;
+        };
+        if self.skipped_count != 0 {
+            os.write_int64(5, self.skipped_count)?;
+        }
+        if self.is_applied != false {
+            os.write_bool(6, self.is_applied)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AccountMergeReport {
+        AccountMergeReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_user_id",
+                |m: &AccountMergeReport| { &m.source_user_id },
+                |m: &mut AccountMergeReport| { &mut m.source_user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkspaceMergeItem>>(
+                "workspaces",
+                |m: &AccountMergeReport| { &m.workspaces },
+                |m: &mut AccountMergeReport| { &mut m.workspaces },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "favorite_count",
+                |m: &AccountMergeReport| { &m.favorite_count },
+                |m: &mut AccountMergeReport| { &mut m.favorite_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "settings",
+                |m: &AccountMergeReport| { &m.settings },
+                |m: &mut AccountMergeReport| { &mut m.settings },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "skipped_count",
+                |m: &AccountMergeReport| { &m.skipped_count },
+                |m: &mut AccountMergeReport| { &mut m.skipped_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_applied",
+                |m: &AccountMergeReport| { &m.is_applied },
+                |m: &mut AccountMergeReport| { &mut m.is_applied },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AccountMergeReport>(
+                "AccountMergeReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AccountMergeReport {
+        static instance: ::protobuf::rt::LazyV2<AccountMergeReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AccountMergeReport::new)
+    }
+}
+
+impl ::protobuf::Clear for AccountMergeReport {
+    fn clear(&mut self) {
+        self.source_user_id.clear();
+        self.workspaces.clear();
+        self.favorite_count = 0;
+        self.settings.clear();
+        self.skipped_count = 0;
+        self.is_applied = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccountMergeReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccountMergeReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x15workspace_merge.proto\";\n\x13MergeAccountRequest\x12$\n\x0esource\
+    _user_id\x18\x01\x20\x01(\tR\x0csourceUserId\"\x9e\x01\n\x12WorkspaceMer\
+    geItem\x12\x1b\n\tsource_id\x18\x01\x20\x01(\tR\x08sourceId\x12\x12\n\
+    \x04name\x18\x02\x20\x01(\tR\x04name\x12\x1b\n\ttarget_id\x18\x03\x20\
+    \x01(\tR\x08targetId\x12\x1b\n\tapp_count\x18\x04\x20\x01(\x03R\x08appCo\
+    unt\x12\x1d\n\nview_count\x18\x05\x20\x01(\x03R\tviewCount\"\xf6\x01\n\
+    \x12AccountMergeReport\x12$\n\x0esource_user_id\x18\x01\x20\x01(\tR\x0cs\
+    ourceUserId\x123\n\nworkspaces\x18\x02\x20\x03(\x0b2\x13.WorkspaceMergeI\
+    temR\nworkspaces\x12%\n\x0efavorite_count\x18\x03\x20\x01(\x03R\rfavorit\
+    eCount\x12\x1a\n\x08settings\x18\x04\x20\x03(\tR\x08settings\x12#\n\rski\
+    pped_count\x18\x05\x20\x01(\x03R\x0cskippedCount\x12\x1d\n\nis_applied\
+    \x18\x06\x20\x01(\x08R\tisAppliedJ\x8a\x06\n\x06\x12\x04\0\0\x13\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\n\
+    \n\x03\x04\0\x01\x12\x03\x02\x08\x1b\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
+    \x04\x1e\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\x03\x0b\x19\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\
+    \x1c\x1d\n\n\n\x02\x04\x01\x12\x04\x05\0\x0b\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\x05\x08\x1a\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\x04\x19\n\x0c\
+    \n\x05\x04\x01\x02\0\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x06\x0b\x14\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x06\x17\x18\n\
+    \x0b\n\x04\x04\x01\x02\x01\x12\x03\x07\x04\x14\n\x0c\n\x05\x04\x01\x02\
+    \x01\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x07\
+    \x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x07\x12\x13\n\x0b\n\x04\
+    \x04\x01\x02\x02\x12\x03\x08\x04\x19\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\
+    \x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x08\x0b\x14\n\x0c\
+    \n\x05\x04\x01\x02\x02\x03\x12\x03\x08\x17\x18\n\x0b\n\x04\x04\x01\x02\
+    \x03\x12\x03\t\x04\x18\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\t\x04\t\n\
+    \x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\t\n\x13\n\x0c\n\x05\x04\x01\x02\
+    \x03\x03\x12\x03\t\x16\x17\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\n\x04\x19\
+    \n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x01\x02\
+    \x04\x01\x12\x03\n\n\x14\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\n\x17\
+    \x18\n\n\n\x02\x04\x02\x12\x04\x0c\0\x13\x01\n\n\n\x03\x04\x02\x01\x12\
+    \x03\x0c\x08\x1a\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x1e\n\x0c\n\x05\
+    \x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
+    \r\x0b\x19\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x1c\x1d\n\x0b\n\x04\
+    \x04\x02\x02\x01\x12\x03\x0e\x04/\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\
+    \x03\x0e\x04\x0c\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x0e\r\x1f\n\x0c\
+    \n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\x20*\n\x0c\n\x05\x04\x02\x02\x01\
+    \x03\x12\x03\x0e-.\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x04\x1d\n\x0c\
+    \n\x05\x04\x02\x02\x02\x05\x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x02\x02\x02\
+    \x01\x12\x03\x0f\n\x18\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x1b\
+    \x1c\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x10\x04!\n\x0c\n\x05\x04\x02\
+    \x02\x03\x04\x12\x03\x10\x04\x0c\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\
+    \x10\r\x13\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x10\x14\x1c\n\x0c\n\
+    \x05\x04\x02\x02\x03\x03\x12\x03\x10\x1f\x20\n\x0b\n\x04\x04\x02\x02\x04\
+    \x12\x03\x11\x04\x1c\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03\x11\x04\t\n\
+    \x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x11\n\x17\n\x0c\n\x05\x04\x02\x02\
+    \x04\x03\x12\x03\x11\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x12\x04\
+    \x18\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x12\x04\x08\n\x0c\n\x05\x04\
+    \x02\x02\x05\x01\x12\x03\x12\t\x13\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\
+    \x03\x12\x16\x17b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
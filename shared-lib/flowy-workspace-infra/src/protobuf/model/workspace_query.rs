@@ -27,6 +27,7 @@
 pub struct QueryWorkspaceRequest {
     // message oneof groups
     pub one_of_workspace_id: ::std::option::Option<QueryWorkspaceRequest_oneof_one_of_workspace_id>,
+    pub one_of_app_filter: ::std::option::Option<QueryWorkspaceRequest_oneof_one_of_app_filter>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -43,6 +44,11 @@ pub enum QueryWorkspaceRequest_oneof_one_of_workspace_id {
     workspace_id(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum QueryWorkspaceRequest_oneof_one_of_app_filter {
+    app_filter(super::workspace_filter::ListFilter),
+}
+
 impl QueryWorkspaceRequest {
     pub fn new() -> QueryWorkspaceRequest {
         ::std::default::Default::default()
@@ -96,10 +102,64 @@ impl QueryWorkspaceRequest {
             ::std::string::String::new()
         }
     }
+
+    // .ListFilter app_filter = 2;
+
+
+    pub fn get_app_filter(&self) -> &super::workspace_filter::ListFilter {
+        match self.one_of_app_filter {
+            ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(ref v)) => v,
+            _ => <super::workspace_filter::ListFilter as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_app_filter(&mut self) {
+        self.one_of_app_filter = ::std::option::Option::None;
+    }
+
+    pub fn has_app_filter(&self) -> bool {
+        match self.one_of_app_filter {
+            ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_filter(&mut self, v: super::workspace_filter::ListFilter) {
+        self.one_of_app_filter = ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_app_filter(&mut self) -> &mut super::workspace_filter::ListFilter {
+        if let ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(_)) = self.one_of_app_filter {
+        } else {
+            self.one_of_app_filter = ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(super::workspace_filter::ListFilter::new()));
+        }
+        match self.one_of_app_filter {
+            ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_app_filter(&mut self) -> super::workspace_filter::ListFilter {
+        if self.has_app_filter() {
+            match self.one_of_app_filter.take() {
+                ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::workspace_filter::ListFilter::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for QueryWorkspaceRequest {
     fn is_initialized(&self) -> bool {
+        if let Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(ref v)) = self.one_of_app_filter {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -113,6 +173,12 @@ impl ::protobuf::Message for QueryWorkspaceRequest {
                     }
                     self.one_of_workspace_id = ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_workspace_id::workspace_id(is.read_string()?));
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_app_filter = ::std::option::Option::Some(QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -132,6 +198,14 @@ impl ::protobuf::Message for QueryWorkspaceRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_app_filter {
+            match v {
+                &QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -145,6 +219,15 @@ impl ::protobuf::Message for QueryWorkspaceRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_app_filter {
+            match v {
+                &QueryWorkspaceRequest_oneof_one_of_app_filter::app_filter(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -188,6 +271,11 @@ impl ::protobuf::Message for QueryWorkspaceRequest {
                 QueryWorkspaceRequest::has_workspace_id,
                 QueryWorkspaceRequest::get_workspace_id,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::workspace_filter::ListFilter>(
+                "app_filter",
+                QueryWorkspaceRequest::has_app_filter,
+                QueryWorkspaceRequest::get_app_filter,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryWorkspaceRequest>(
                 "QueryWorkspaceRequest",
                 fields,
@@ -205,6 +293,7 @@ impl ::protobuf::Message for QueryWorkspaceRequest {
 impl ::protobuf::Clear for QueryWorkspaceRequest {
     fn clear(&mut self) {
         self.one_of_workspace_id = ::std::option::Option::None;
+        self.one_of_app_filter = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -225,6 +314,7 @@ impl ::protobuf::reflect::ProtobufValue for QueryWorkspaceRequest {
 pub struct WorkspaceIdentifier {
     // message oneof groups
     pub one_of_workspace_id: ::std::option::Option<WorkspaceIdentifier_oneof_one_of_workspace_id>,
+    pub one_of_app_filter: ::std::option::Option<WorkspaceIdentifier_oneof_one_of_app_filter>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -241,6 +331,11 @@ pub enum WorkspaceIdentifier_oneof_one_of_workspace_id {
     workspace_id(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum WorkspaceIdentifier_oneof_one_of_app_filter {
+    app_filter(super::workspace_filter::ListFilter),
+}
+
 impl WorkspaceIdentifier {
     pub fn new() -> WorkspaceIdentifier {
         ::std::default::Default::default()
@@ -294,10 +389,64 @@ impl WorkspaceIdentifier {
             ::std::string::String::new()
         }
     }
+
+    // .ListFilter app_filter = 2;
+
+
+    pub fn get_app_filter(&self) -> &super::workspace_filter::ListFilter {
+        match self.one_of_app_filter {
+            ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(ref v)) => v,
+            _ => <super::workspace_filter::ListFilter as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_app_filter(&mut self) {
+        self.one_of_app_filter = ::std::option::Option::None;
+    }
+
+    pub fn has_app_filter(&self) -> bool {
+        match self.one_of_app_filter {
+            ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_filter(&mut self, v: super::workspace_filter::ListFilter) {
+        self.one_of_app_filter = ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_app_filter(&mut self) -> &mut super::workspace_filter::ListFilter {
+        if let ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(_)) = self.one_of_app_filter {
+        } else {
+            self.one_of_app_filter = ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(super::workspace_filter::ListFilter::new()));
+        }
+        match self.one_of_app_filter {
+            ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_app_filter(&mut self) -> super::workspace_filter::ListFilter {
+        if self.has_app_filter() {
+            match self.one_of_app_filter.take() {
+                ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::workspace_filter::ListFilter::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for WorkspaceIdentifier {
     fn is_initialized(&self) -> bool {
+        if let Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(ref v)) = self.one_of_app_filter {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -311,6 +460,12 @@ impl ::protobuf::Message for WorkspaceIdentifier {
                     }
                     self.one_of_workspace_id = ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_workspace_id::workspace_id(is.read_string()?));
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_app_filter = ::std::option::Option::Some(WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -330,6 +485,14 @@ impl ::protobuf::Message for WorkspaceIdentifier {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_app_filter {
+            match v {
+                &WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -343,6 +506,15 @@ impl ::protobuf::Message for WorkspaceIdentifier {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_app_filter {
+            match v {
+                &WorkspaceIdentifier_oneof_one_of_app_filter::app_filter(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -386,6 +558,11 @@ impl ::protobuf::Message for WorkspaceIdentifier {
                 WorkspaceIdentifier::has_workspace_id,
                 WorkspaceIdentifier::get_workspace_id,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::workspace_filter::ListFilter>(
+                "app_filter",
+                WorkspaceIdentifier::has_app_filter,
+                WorkspaceIdentifier::get_app_filter,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceIdentifier>(
                 "WorkspaceIdentifier",
                 fields,
@@ -403,6 +580,7 @@ impl ::protobuf::Message for WorkspaceIdentifier {
 impl ::protobuf::Clear for WorkspaceIdentifier {
     fn clear(&mut self) {
         self.one_of_workspace_id = ::std::option::Option::None;
+        self.one_of_app_filter = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -420,21 +598,33 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceIdentifier {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x15workspace_query.proto\"S\n\x15QueryWorkspaceRequest\x12#\n\x0cwork\
-    space_id\x18\x01\x20\x01(\tH\0R\x0bworkspaceIdB\x15\n\x13one_of_workspac\
-    e_id\"Q\n\x13WorkspaceIdentifier\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\
-    \tH\0R\x0bworkspaceIdB\x15\n\x13one_of_workspace_idJ\xe6\x01\n\x06\x12\
-    \x04\0\0\x07\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\
-    \x02\0\x04\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x1d\n\x0b\n\x04\x04\0\
-    \x08\0\x12\x03\x03\x04:\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x03\n\x1d\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x208\n\x0c\n\x05\x04\0\x02\0\x05\x12\
-    \x03\x03\x20&\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03'3\n\x0c\n\x05\x04\
-    \0\x02\0\x03\x12\x03\x0367\n\n\n\x02\x04\x01\x12\x04\x05\0\x07\x01\n\n\n\
-    \x03\x04\x01\x01\x12\x03\x05\x08\x1b\n\x0b\n\x04\x04\x01\x08\0\x12\x03\
-    \x06\x04:\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\x06\n\x1d\n\x0b\n\x04\
-    \x04\x01\x02\0\x12\x03\x06\x208\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\
-    \x06\x20&\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x06'3\n\x0c\n\x05\x04\
-    \x01\x02\0\x03\x12\x03\x0667b\x06proto3\
+    \n\x15workspace_query.proto\x1a\x16workspace_filter.proto\"\x96\x01\n\
+    \x15QueryWorkspaceRequest\x12#\n\x0cworkspace_id\x18\x01\x20\x01(\tH\0R\
+    \x0bworkspaceId\x12,\n\napp_filter\x18\x02\x20\x01(\x0b2\x0b.ListFilterH\
+    \x01R\tappFilterB\x15\n\x13one_of_workspace_idB\x13\n\x11one_of_app_filt\
+    er\"\x94\x01\n\x13WorkspaceIdentifier\x12#\n\x0cworkspace_id\x18\x01\x20\
+    \x01(\tH\0R\x0bworkspaceId\x12,\n\napp_filter\x18\x02\x20\x01(\x0b2\x0b.\
+    ListFilterH\x01R\tappFilterB\x15\n\x13one_of_workspace_idB\x13\n\x11one_\
+    of_app_filterJ\x95\x03\n\x06\x12\x04\0\0\n\x01\n\x08\n\x01\x0c\x12\x03\0\
+    \0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x20\n\n\n\x02\x04\0\x12\x04\x03\0\
+    \x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x1d\n\x0b\n\x04\x04\0\x08\0\
+    \x12\x03\x04\x04:\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x04\n\x1d\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x04\x208\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x04\x20&\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04'3\n\x0c\n\x05\x04\0\
+    \x02\0\x03\x12\x03\x0467\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x05\x04:\n\
+    \x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x05\n\x1b\n\x0b\n\x04\x04\0\x02\x01\
+    \x12\x03\x05\x1e8\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x05\x1e(\n\x0c\n\
+    \x05\x04\0\x02\x01\x01\x12\x03\x05)3\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
+    \x03\x0567\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\x07\x08\x1b\n\x0b\n\x04\x04\x01\x08\0\x12\x03\x08\x04:\n\x0c\n\
+    \x05\x04\x01\x08\0\x01\x12\x03\x08\n\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\
+    \x03\x08\x208\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\x20&\n\x0c\n\x05\
+    \x04\x01\x02\0\x01\x12\x03\x08'3\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\
+    \x0867\n\x0b\n\x04\x04\x01\x08\x01\x12\x03\t\x04:\n\x0c\n\x05\x04\x01\
+    \x08\x01\x01\x12\x03\t\n\x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x1e8\
+    \n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\t\x1e(\n\x0c\n\x05\x04\x01\x02\
+    \x01\x01\x12\x03\t)3\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t67b\x06pro\
+    to3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,1335 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_search.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SearchWorkspaceRequest {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub query: ::std::string::String,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SearchWorkspaceRequest {
+    fn default() -> &'a SearchWorkspaceRequest {
+        <SearchWorkspaceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchWorkspaceRequest {
+    pub fn new() -> SearchWorkspaceRequest {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string query = 2;
+
+
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query(&mut self, v: ::std::string::String) {
+        self.query = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query(&mut self) -> &mut ::std::string::String {
+        &mut self.query
+    }
+
+    // Take field
+    pub fn take_query(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.query, ::std::string::String::new())
+    }
+
+    // int64 limit = 3;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for SearchWorkspaceRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.query)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.query.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.query);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.query.is_empty() {
+            os.write_string(2, &self.query)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SearchWorkspaceRequest {
+        SearchWorkspaceRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &SearchWorkspaceRequest| { &m.workspace_id },
+                |m: &mut SearchWorkspaceRequest| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "query",
+                |m: &SearchWorkspaceRequest| { &m.query },
+                |m: &mut SearchWorkspaceRequest| { &mut m.query },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &SearchWorkspaceRequest| { &m.limit },
+                |m: &mut SearchWorkspaceRequest| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SearchWorkspaceRequest>(
+                "SearchWorkspaceRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SearchWorkspaceRequest {
+        static instance: ::protobuf::rt::LazyV2<SearchWorkspaceRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SearchWorkspaceRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SearchWorkspaceRequest {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.query.clear();
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SearchWorkspaceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchWorkspaceRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SearchWorkspaceParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub query: ::std::string::String,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SearchWorkspaceParams {
+    fn default() -> &'a SearchWorkspaceParams {
+        <SearchWorkspaceParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchWorkspaceParams {
+    pub fn new() -> SearchWorkspaceParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string query = 2;
+
+
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query(&mut self, v: ::std::string::String) {
+        self.query = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query(&mut self) -> &mut ::std::string::String {
+        &mut self.query
+    }
+
+    // Take field
+    pub fn take_query(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.query, ::std::string::String::new())
+    }
+
+    // int64 limit = 3;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for SearchWorkspaceParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.query)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.query.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.query);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.query.is_empty() {
+            os.write_string(2, &self.query)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SearchWorkspaceParams {
+        SearchWorkspaceParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &SearchWorkspaceParams| { &m.workspace_id },
+                |m: &mut SearchWorkspaceParams| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "query",
+                |m: &SearchWorkspaceParams| { &m.query },
+                |m: &mut SearchWorkspaceParams| { &mut m.query },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &SearchWorkspaceParams| { &m.limit },
+                |m: &mut SearchWorkspaceParams| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SearchWorkspaceParams>(
+                "SearchWorkspaceParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SearchWorkspaceParams {
+        static instance: ::protobuf::rt::LazyV2<SearchWorkspaceParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SearchWorkspaceParams::new)
+    }
+}
+
+impl ::protobuf::Clear for SearchWorkspaceParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.query.clear();
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SearchWorkspaceParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchWorkspaceParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SearchResult {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub view_name: ::std::string::String,
+    pub snippet: ::std::string::String,
+    pub matches: ::protobuf::RepeatedField<SearchMatch>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SearchResult {
+    fn default() -> &'a SearchResult {
+        <SearchResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchResult {
+    pub fn new() -> SearchResult {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string view_name = 2;
+
+
+    pub fn get_view_name(&self) -> &str {
+        &self.view_name
+    }
+    pub fn clear_view_name(&mut self) {
+        self.view_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_name(&mut self, v: ::std::string::String) {
+        self.view_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_name(&mut self) -> &mut ::std::string::String {
+        &mut self.view_name
+    }
+
+    // Take field
+    pub fn take_view_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_name, ::std::string::String::new())
+    }
+
+    // string snippet = 3;
+
+
+    pub fn get_snippet(&self) -> &str {
+        &self.snippet
+    }
+    pub fn clear_snippet(&mut self) {
+        self.snippet.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippet(&mut self, v: ::std::string::String) {
+        self.snippet = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_snippet(&mut self) -> &mut ::std::string::String {
+        &mut self.snippet
+    }
+
+    // Take field
+    pub fn take_snippet(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.snippet, ::std::string::String::new())
+    }
+
+    // repeated .SearchMatch matches = 4;
+
+
+    pub fn get_matches(&self) -> &[SearchMatch] {
+        &self.matches
+    }
+    pub fn clear_matches(&mut self) {
+        self.matches.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matches(&mut self, v: ::protobuf::RepeatedField<SearchMatch>) {
+        self.matches = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_matches(&mut self) -> &mut ::protobuf::RepeatedField<SearchMatch> {
+        &mut self.matches
+    }
+
+    // Take field
+    pub fn take_matches(&mut self) -> ::protobuf::RepeatedField<SearchMatch> {
+        ::std::mem::replace(&mut self.matches, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SearchResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.matches {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.snippet)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.matches)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.view_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.view_name);
+        }
+        if !self.snippet.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.snippet);
+        }
+        for value in &self.matches {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.view_name.is_empty() {
+            os.write_string(2, &self.view_name)?;
+        }
+        if !self.snippet.is_empty() {
+            os.write_string(3, &self.snippet)?;
+        }
+        for v in &self.matches {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SearchResult {
+        SearchResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &SearchResult| { &m.view_id },
+                |m: &mut SearchResult| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_name",
+                |m: &SearchResult| { &m.view_name },
+                |m: &mut SearchResult| { &mut m.view_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "snippet",
+                |m: &SearchResult| { &m.snippet },
+                |m: &mut SearchResult| { &mut m.snippet },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SearchMatch>>(
+                "matches",
+                |m: &SearchResult| { &m.matches },
+                |m: &mut SearchResult| { &mut m.matches },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SearchResult>(
+                "SearchResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SearchResult {
+        static instance: ::protobuf::rt::LazyV2<SearchResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SearchResult::new)
+    }
+}
+
+impl ::protobuf::Clear for SearchResult {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.view_name.clear();
+        self.snippet.clear();
+        self.matches.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SearchResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SearchMatch {
+    // message fields
+    pub start: i64,
+    pub end: i64,
+    pub context: ::std::string::String,
+    pub highlight_start: i64,
+    pub highlight_end: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SearchMatch {
+    fn default() -> &'a SearchMatch {
+        <SearchMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchMatch {
+    pub fn new() -> SearchMatch {
+        ::std::default::Default::default()
+    }
+
+    // int64 start = 1;
+
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+    pub fn clear_start(&mut self) {
+        self.start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: i64) {
+        self.start = v;
+    }
+
+    // int64 end = 2;
+
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: i64) {
+        self.end = v;
+    }
+
+    // string context = 3;
+
+
+    pub fn get_context(&self) -> &str {
+        &self.context
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: ::std::string::String) {
+        self.context = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut ::std::string::String {
+        &mut self.context
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.context, ::std::string::String::new())
+    }
+
+    // int64 highlight_start = 4;
+
+
+    pub fn get_highlight_start(&self) -> i64 {
+        self.highlight_start
+    }
+    pub fn clear_highlight_start(&mut self) {
+        self.highlight_start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_highlight_start(&mut self, v: i64) {
+        self.highlight_start = v;
+    }
+
+    // int64 highlight_end = 5;
+
+
+    pub fn get_highlight_end(&self) -> i64 {
+        self.highlight_end
+    }
+    pub fn clear_highlight_end(&mut self) {
+        self.highlight_end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_highlight_end(&mut self, v: i64) {
+        self.highlight_end = v;
+    }
+}
+
+impl ::protobuf::Message for SearchMatch {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.start = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.end = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.context)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.highlight_start = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.highlight_end = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.context.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.context);
+        }
+        if self.highlight_start != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.highlight_start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.highlight_end != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.highlight_end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start != 0 {
+            os.write_int64(1, self.start)?;
+        }
+        if self.end != 0 {
+            os.write_int64(2, self.end)?;
+        }
+        if !self.context.is_empty() {
+            os.write_string(3, &self.context)?;
+        }
+        if self.highlight_start != 0 {
+            os.write_int64(4, self.highlight_start)?;
+        }
+        if self.highlight_end != 0 {
+            os.write_int64(5, self.highlight_end)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SearchMatch {
+        SearchMatch::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "start",
+                |m: &SearchMatch| { &m.start },
+                |m: &mut SearchMatch| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "end",
+                |m: &SearchMatch| { &m.end },
+                |m: &mut SearchMatch| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "context",
+                |m: &SearchMatch| { &m.context },
+                |m: &mut SearchMatch| { &mut m.context },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "highlight_start",
+                |m: &SearchMatch| { &m.highlight_start },
+                |m: &mut SearchMatch| { &mut m.highlight_start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "highlight_end",
+                |m: &SearchMatch| { &m.highlight_end },
+                |m: &mut SearchMatch| { &mut m.highlight_end },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SearchMatch>(
+                "SearchMatch",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SearchMatch {
+        static instance: ::protobuf::rt::LazyV2<SearchMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SearchMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for SearchMatch {
+    fn clear(&mut self) {
+        self.start = 0;
+        self.end = 0;
+        self.context.clear();
+        self.highlight_start = 0;
+        self.highlight_end = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SearchMatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedSearchResult {
+    // message fields
+    pub items: ::protobuf::RepeatedField<SearchResult>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedSearchResult {
+    fn default() -> &'a RepeatedSearchResult {
+        <RepeatedSearchResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedSearchResult {
+    pub fn new() -> RepeatedSearchResult {
+        ::std::default::Default::default()
+    }
+
+    // repeated .SearchResult items = 1;
+
+
+    pub fn get_items(&self) -> &[SearchResult] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<SearchResult>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<SearchResult> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<SearchResult> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedSearchResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedSearchResult {
+        RepeatedSearchResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SearchResult>>(
+                "items",
+                |m: &RepeatedSearchResult| { &m.items },
+                |m: &mut RepeatedSearchResult| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedSearchResult>(
+                "RepeatedSearchResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedSearchResult {
+        static instance: ::protobuf::rt::LazyV2<RepeatedSearchResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedSearchResult::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedSearchResult {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedSearchResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedSearchResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16workspace_search.proto\"g\n\x16SearchWorkspaceRequest\x12!\n\x0cwo\
+    rkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\x14\n\x05query\x18\x02\
+    \x20\x01(\tR\x05query\x12\x14\n\x05limit\x18\x03\x20\x01(\x03R\x05limit\
+    \"f\n\x15SearchWorkspaceParams\x12!\n\x0cworkspace_id\x18\x01\x20\x01(\t\
+    R\x0bworkspaceId\x12\x14\n\x05query\x18\x02\x20\x01(\tR\x05query\x12\x14\
+    \n\x05limit\x18\x03\x20\x01(\x03R\x05limit\"\x86\x01\n\x0cSearchResult\
+    \x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x1b\n\tview_name\
+    \x18\x02\x20\x01(\tR\x08viewName\x12\x18\n\x07snippet\x18\x03\x20\x01(\t\
+    R\x07snippet\x12&\n\x07matches\x18\x04\x20\x03(\x0b2\x0c.SearchMatchR\
+    \x07matches\"\x9d\x01\n\x0bSearchMatch\x12\x14\n\x05start\x18\x01\x20\
+    \x01(\x03R\x05start\x12\x10\n\x03end\x18\x02\x20\x01(\x03R\x03end\x12\
+    \x18\n\x07context\x18\x03\x20\x01(\tR\x07context\x12'\n\x0fhighlight_sta\
+    rt\x18\x04\x20\x01(\x03R\x0ehighlightStart\x12#\n\rhighlight_end\x18\x05\
+    \x20\x01(\x03R\x0chighlightEnd\";\n\x14RepeatedSearchResult\x12#\n\x05it\
+    ems\x18\x01\x20\x03(\x0b2\r.SearchResultR\x05itemsJ\x96\x08\n\x06\x12\
+    \x04\0\0\x1b\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\
+    \x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x1e\n\x0b\n\x04\x04\0\
+    \x02\0\x12\x03\x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\
+    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\n\x05\x04\0\x02\0\
+    \x03\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x15\n\
+    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\
+    \x01\x12\x03\x04\x0b\x10\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x13\
+    \x14\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\
+    \x02\x05\x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\n\
+    \x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\n\n\x02\x04\x01\
+    \x12\x04\x07\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x1d\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03\x08\x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
+    \x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x0b\x17\n\x0c\n\
+    \x05\x04\x01\x02\0\x03\x12\x03\x08\x1a\x1b\n\x0b\n\x04\x04\x01\x02\x01\
+    \x12\x03\t\x04\x15\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\t\x04\n\n\x0c\
+    \n\x05\x04\x01\x02\x01\x01\x12\x03\t\x0b\x10\n\x0c\n\x05\x04\x01\x02\x01\
+    \x03\x12\x03\t\x13\x14\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\n\x04\x14\n\
+    \x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x01\x02\
+    \x02\x01\x12\x03\n\n\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\n\x12\
+    \x13\n\n\n\x02\x04\x02\x12\x04\x0c\0\x11\x01\n\n\n\x03\x04\x02\x01\x12\
+    \x03\x0c\x08\x14\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x17\n\x0c\n\x05\
+    \x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
+    \r\x0b\x12\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x15\x16\n\x0b\n\x04\
+    \x04\x02\x02\x01\x12\x03\x0e\x04\x19\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\
+    \x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\x0b\x14\n\x0c\
+    \n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x17\x18\n\x0b\n\x04\x04\x02\x02\
+    \x02\x12\x03\x0f\x04\x17\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0f\x04\
+    \n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x0b\x12\n\x0c\n\x05\x04\
+    \x02\x02\x02\x03\x12\x03\x0f\x15\x16\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\
+    \x10\x04%\n\x0c\n\x05\x04\x02\x02\x03\x04\x12\x03\x10\x04\x0c\n\x0c\n\
+    \x05\x04\x02\x02\x03\x06\x12\x03\x10\r\x18\n\x0c\n\x05\x04\x02\x02\x03\
+    \x01\x12\x03\x10\x19\x20\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x10#$\n\
+    \n\n\x02\x04\x03\x12\x04\x12\0\x18\x01\n\n\n\x03\x04\x03\x01\x12\x03\x12\
+    \x08\x13\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x13\x04\x14\n\x0c\n\x05\x04\
+    \x03\x02\0\x05\x12\x03\x13\x04\t\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\
+    \x13\n\x0f\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x13\x12\x13\n\x0b\n\x04\
+    \x04\x03\x02\x01\x12\x03\x14\x04\x12\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\
+    \x03\x14\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x14\n\r\n\x0c\n\
+    \x05\x04\x03\x02\x01\x03\x12\x03\x14\x10\x11\n\x0b\n\x04\x04\x03\x02\x02\
+    \x12\x03\x15\x04\x17\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x15\x04\n\n\
+    \x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x15\x0b\x12\n\x0c\n\x05\x04\x03\
+    \x02\x02\x03\x12\x03\x15\x15\x16\n\x0b\n\x04\x04\x03\x02\x03\x12\x03\x16\
+    \x04\x1e\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03\x16\x04\t\n\x0c\n\x05\
+    \x04\x03\x02\x03\x01\x12\x03\x16\n\x19\n\x0c\n\x05\x04\x03\x02\x03\x03\
+    \x12\x03\x16\x1c\x1d\n\x0b\n\x04\x04\x03\x02\x04\x12\x03\x17\x04\x1c\n\
+    \x0c\n\x05\x04\x03\x02\x04\x05\x12\x03\x17\x04\t\n\x0c\n\x05\x04\x03\x02\
+    \x04\x01\x12\x03\x17\n\x17\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x17\
+    \x1a\x1b\n\n\n\x02\x04\x04\x12\x04\x19\0\x1b\x01\n\n\n\x03\x04\x04\x01\
+    \x12\x03\x19\x08\x1c\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1a\x04$\n\x0c\n\
+    \x05\x04\x04\x02\0\x04\x12\x03\x1a\x04\x0c\n\x0c\n\x05\x04\x04\x02\0\x06\
+    \x12\x03\x1a\r\x19\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1a\x1a\x1f\n\
+    \x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1a\"#b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -27,6 +27,7 @@
 pub struct CurrentWorkspaceSetting {
     // message fields
     pub workspace: ::protobuf::SingularPtrField<super::workspace_create::Workspace>,
+    pub unread_view_ids: ::protobuf::RepeatedField<::std::string::String>,
     // message oneof groups
     pub one_of_latest_view: ::std::option::Option<CurrentWorkspaceSetting_oneof_one_of_latest_view>,
     // special fields
@@ -131,6 +132,31 @@ impl CurrentWorkspaceSetting {
             super::view_create::View::new()
         }
     }
+
+    // repeated string unread_view_ids = 3;
+
+
+    pub fn get_unread_view_ids(&self) -> &[::std::string::String] {
+        &self.unread_view_ids
+    }
+    pub fn clear_unread_view_ids(&mut self) {
+        self.unread_view_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unread_view_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.unread_view_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_unread_view_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.unread_view_ids
+    }
+
+    // Take field
+    pub fn take_unread_view_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.unread_view_ids, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for CurrentWorkspaceSetting {
@@ -161,6 +187,9 @@ impl ::protobuf::Message for CurrentWorkspaceSetting {
                     }
                     self.one_of_latest_view = ::std::option::Option::Some(CurrentWorkspaceSetting_oneof_one_of_latest_view::latest_view(is.read_message()?));
                 },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.unread_view_ids)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -177,6 +206,9 @@ impl ::protobuf::Message for CurrentWorkspaceSetting {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        for value in &self.unread_view_ids {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
         if let ::std::option::Option::Some(ref v) = self.one_of_latest_view {
             match v {
                 &CurrentWorkspaceSetting_oneof_one_of_latest_view::latest_view(ref v) => {
@@ -196,6 +228,9 @@ impl ::protobuf::Message for CurrentWorkspaceSetting {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        for v in &self.unread_view_ids {
+            os.write_string(3, &v)?;
+        };
         if let ::std::option::Option::Some(ref v) = self.one_of_latest_view {
             match v {
                 &CurrentWorkspaceSetting_oneof_one_of_latest_view::latest_view(ref v) => {
@@ -253,6 +288,11 @@ impl ::protobuf::Message for CurrentWorkspaceSetting {
                 CurrentWorkspaceSetting::has_latest_view,
                 CurrentWorkspaceSetting::get_latest_view,
             ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "unread_view_ids",
+                |m: &CurrentWorkspaceSetting| { &m.unread_view_ids },
+                |m: &mut CurrentWorkspaceSetting| { &mut m.unread_view_ids },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CurrentWorkspaceSetting>(
                 "CurrentWorkspaceSetting",
                 fields,
@@ -271,6 +311,7 @@ impl ::protobuf::Clear for CurrentWorkspaceSetting {
     fn clear(&mut self) {
         self.workspace.clear();
         self.one_of_latest_view = ::std::option::Option::None;
+        self.unread_view_ids.clear();
         self.unknown_fields.clear();
     }
 }
@@ -289,19 +330,23 @@ impl ::protobuf::reflect::ProtobufValue for CurrentWorkspaceSetting {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x17workspace_setting.proto\x1a\x11view_create.proto\x1a\x16workspace_\
-    create.proto\"\x83\x01\n\x17CurrentWorkspaceSetting\x12(\n\tworkspace\
+    create.proto\"\xab\x01\n\x17CurrentWorkspaceSetting\x12(\n\tworkspace\
     \x18\x01\x20\x01(\x0b2\n.WorkspaceR\tworkspace\x12(\n\x0blatest_view\x18\
-    \x02\x20\x01(\x0b2\x05.ViewH\0R\nlatestViewB\x14\n\x12one_of_latest_view\
-    J\xc9\x01\n\x06\x12\x04\0\0\x07\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\
-    \n\x02\x03\0\x12\x03\x01\0\x1b\n\t\n\x02\x03\x01\x12\x03\x02\0\x20\n\n\n\
-    \x02\x04\0\x12\x04\x04\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x1f\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x06\
+    \x02\x20\x01(\x0b2\x05.ViewH\0R\nlatestView\x12&\n\x0funread_view_ids\
+    \x18\x03\x20\x03(\tR\runreadViewIdsB\x14\n\x12one_of_latest_viewJ\x8e\
+    \x02\n\x06\x12\x04\0\0\x08\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\
+    \x03\0\x12\x03\x01\0\x1b\n\t\n\x02\x03\x01\x12\x03\x02\0\x20\n\n\n\x02\
+    \x04\0\x12\x04\x04\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x1f\n\
+    \x0b\n\x04\x04\0\x02\0\x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x06\
     \x12\x03\x05\x04\r\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x0e\x17\n\x0c\
     \n\x05\x04\0\x02\0\x03\x12\x03\x05\x1a\x1b\n\x0b\n\x04\x04\0\x08\0\x12\
     \x03\x06\x046\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x06\n\x1c\n\x0b\n\x04\
     \x04\0\x02\x01\x12\x03\x06\x1f4\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\
     \x06\x1f#\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x06$/\n\x0c\n\x05\x04\0\
-    \x02\x01\x03\x12\x03\x0623b\x06proto3\
+    \x02\x01\x03\x12\x03\x0623\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x07\x04(\n\
+    \x0c\n\x05\x04\0\x02\x02\x04\x12\x03\x07\x04\x0c\n\x0c\n\x05\x04\0\x02\
+    \x02\x05\x12\x03\x07\r\x13\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x07\x14\
+    #\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x07&'b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
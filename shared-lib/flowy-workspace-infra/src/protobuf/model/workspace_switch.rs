@@ -0,0 +1,534 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_switch.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceItem {
+    // message fields
+    pub id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub role: super::workspace_member::WorkspaceRole,
+    pub is_current: bool,
+    pub last_open_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceItem {
+    fn default() -> &'a WorkspaceItem {
+        <WorkspaceItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceItem {
+    pub fn new() -> WorkspaceItem {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .WorkspaceRole role = 3;
+
+
+    pub fn get_role(&self) -> super::workspace_member::WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::workspace_member::WorkspaceRole) {
+        self.role = v;
+    }
+
+    // bool is_current = 4;
+
+
+    pub fn get_is_current(&self) -> bool {
+        self.is_current
+    }
+    pub fn clear_is_current(&mut self) {
+        self.is_current = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_current(&mut self, v: bool) {
+        self.is_current = v;
+    }
+
+    // int64 last_open_time = 5;
+
+
+    pub fn get_last_open_time(&self) -> i64 {
+        self.last_open_time
+    }
+    pub fn clear_last_open_time(&mut self) {
+        self.last_open_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_open_time(&mut self, v: i64) {
+        self.last_open_time = v;
+    }
+}
+
+impl ::protobuf::Message for WorkspaceItem {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_current = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_open_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            my_size += ::protobuf::rt::enum_size(3, self.role);
+        }
+        if self.is_current != false {
+            my_size += 2;
+        }
+        if self.last_open_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.last_open_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.role != super::workspace_member::WorkspaceRole::Owner {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        if self.is_current != false {
+            os.write_bool(4, self.is_current)?;
+        }
+        if self.last_open_time != 0 {
+            os.write_int64(5, self.last_open_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceItem {
+        WorkspaceItem::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &WorkspaceItem| { &m.id },
+                |m: &mut WorkspaceItem| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &WorkspaceItem| { &m.name },
+                |m: &mut WorkspaceItem| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::workspace_member::WorkspaceRole>>(
+                "role",
+                |m: &WorkspaceItem| { &m.role },
+                |m: &mut WorkspaceItem| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_current",
+                |m: &WorkspaceItem| { &m.is_current },
+                |m: &mut WorkspaceItem| { &mut m.is_current },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_open_time",
+                |m: &WorkspaceItem| { &m.last_open_time },
+                |m: &mut WorkspaceItem| { &mut m.last_open_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceItem>(
+                "WorkspaceItem",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceItem {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceItem> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceItem::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceItem {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.name.clear();
+        self.role = super::workspace_member::WorkspaceRole::Owner;
+        self.is_current = false;
+        self.last_open_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceItem {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedWorkspaceItem {
+    // message fields
+    pub items: ::protobuf::RepeatedField<WorkspaceItem>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedWorkspaceItem {
+    fn default() -> &'a RepeatedWorkspaceItem {
+        <RepeatedWorkspaceItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedWorkspaceItem {
+    pub fn new() -> RepeatedWorkspaceItem {
+        ::std::default::Default::default()
+    }
+
+    // repeated .WorkspaceItem items = 1;
+
+
+    pub fn get_items(&self) -> &[WorkspaceItem] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<WorkspaceItem>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<WorkspaceItem> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<WorkspaceItem> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedWorkspaceItem {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedWorkspaceItem {
+        RepeatedWorkspaceItem::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkspaceItem>>(
+                "items",
+                |m: &RepeatedWorkspaceItem| { &m.items },
+                |m: &mut RepeatedWorkspaceItem| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedWorkspaceItem>(
+                "RepeatedWorkspaceItem",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedWorkspaceItem {
+        static instance: ::protobuf::rt::LazyV2<RepeatedWorkspaceItem> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedWorkspaceItem::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedWorkspaceItem {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedWorkspaceItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedWorkspaceItem {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16workspace_switch.proto\x1a\x16workspace_member.proto\"\x9c\x01\n\r\
+    WorkspaceItem\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\x04nam\
+    e\x18\x02\x20\x01(\tR\x04name\x12\"\n\x04role\x18\x03\x20\x01(\x0e2\x0e.\
+    WorkspaceRoleR\x04role\x12\x1d\n\nis_current\x18\x04\x20\x01(\x08R\tisCu\
+    rrent\x12$\n\x0elast_open_time\x18\x05\x20\x01(\x03R\x0clastOpenTime\"=\
+    \n\x15RepeatedWorkspaceItem\x12$\n\x05items\x18\x01\x20\x03(\x0b2\x0e.Wo\
+    rkspaceItemR\x05itemsJ\xa5\x03\n\x06\x12\x04\0\0\x0c\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x20\n\n\n\x02\x04\0\x12\
+    \x04\x03\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x15\n\x0b\n\x04\x04\
+    \0\x02\0\x12\x03\x04\x04\x12\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\
+    \n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\r\n\x0c\n\x05\x04\0\x02\0\
+    \x03\x12\x03\x04\x10\x11\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x14\n\
+    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x01\
+    \x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x12\
+    \x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x06\x04\x1b\n\x0c\n\x05\x04\0\x02\
+    \x02\x06\x12\x03\x06\x04\x11\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\
+    \x12\x16\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x06\x19\x1a\n\x0b\n\x04\
+    \x04\0\x02\x03\x12\x03\x07\x04\x18\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\
+    \x07\x04\x08\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x07\t\x13\n\x0c\n\x05\
+    \x04\0\x02\x03\x03\x12\x03\x07\x16\x17\n\x0b\n\x04\x04\0\x02\x04\x12\x03\
+    \x08\x04\x1d\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x08\x04\t\n\x0c\n\x05\
+    \x04\0\x02\x04\x01\x12\x03\x08\n\x18\n\x0c\n\x05\x04\0\x02\x04\x03\x12\
+    \x03\x08\x1b\x1c\n\n\n\x02\x04\x01\x12\x04\n\0\x0c\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\n\x08\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x04%\n\x0c\
+    \n\x05\x04\x01\x02\0\x04\x12\x03\x0b\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\
+    \x06\x12\x03\x0b\r\x1a\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b\x1b\x20\
+    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b#$b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
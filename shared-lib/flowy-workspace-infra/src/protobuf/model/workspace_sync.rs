@@ -0,0 +1,632 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_sync.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RenameMergePolicySetting {
+    // message fields
+    pub policy: RenameMergePolicy,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RenameMergePolicySetting {
+    fn default() -> &'a RenameMergePolicySetting {
+        <RenameMergePolicySetting as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RenameMergePolicySetting {
+    pub fn new() -> RenameMergePolicySetting {
+        ::std::default::Default::default()
+    }
+
+    // .RenameMergePolicy policy = 1;
+
+
+    pub fn get_policy(&self) -> RenameMergePolicy {
+        self.policy
+    }
+    pub fn clear_policy(&mut self) {
+        self.policy = RenameMergePolicy::LatestWins;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policy(&mut self, v: RenameMergePolicy) {
+        self.policy = v;
+    }
+}
+
+impl ::protobuf::Message for RenameMergePolicySetting {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.policy, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.policy != RenameMergePolicy::LatestWins {
+            my_size += ::protobuf::rt::enum_size(1, self.policy);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.policy != RenameMergePolicy::LatestWins {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.policy))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RenameMergePolicySetting {
+        RenameMergePolicySetting::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RenameMergePolicy>>(
+                "policy",
+                |m: &RenameMergePolicySetting| { &m.policy },
+                |m: &mut RenameMergePolicySetting| { &mut m.policy },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RenameMergePolicySetting>(
+                "RenameMergePolicySetting",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RenameMergePolicySetting {
+        static instance: ::protobuf::rt::LazyV2<RenameMergePolicySetting> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RenameMergePolicySetting::new)
+    }
+}
+
+impl ::protobuf::Clear for RenameMergePolicySetting {
+    fn clear(&mut self) {
+        self.policy = RenameMergePolicy::LatestWins;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RenameMergePolicySetting {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RenameMergePolicySetting {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RenameConflict {
+    // message fields
+    pub object_id: ::std::string::String,
+    pub local_name: ::std::string::String,
+    pub remote_name: ::std::string::String,
+    pub resolved_name: ::std::string::String,
+    pub policy: RenameMergePolicy,
+    pub remote_device_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RenameConflict {
+    fn default() -> &'a RenameConflict {
+        <RenameConflict as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RenameConflict {
+    pub fn new() -> RenameConflict {
+        ::std::default::Default::default()
+    }
+
+    // string object_id = 1;
+
+
+    pub fn get_object_id(&self) -> &str {
+        &self.object_id
+    }
+    pub fn clear_object_id(&mut self) {
+        self.object_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object_id(&mut self, v: ::std::string::String) {
+        self.object_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_object_id(&mut self) -> &mut ::std::string::String {
+        &mut self.object_id
+    }
+
+    // Take field
+    pub fn take_object_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.object_id, ::std::string::String::new())
+    }
+
+    // string local_name = 2;
+
+
+    pub fn get_local_name(&self) -> &str {
+        &self.local_name
+    }
+    pub fn clear_local_name(&mut self) {
+        self.local_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_local_name(&mut self, v: ::std::string::String) {
+        self.local_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_local_name(&mut self) -> &mut ::std::string::String {
+        &mut self.local_name
+    }
+
+    // Take field
+    pub fn take_local_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.local_name, ::std::string::String::new())
+    }
+
+    // string remote_name = 3;
+
+
+    pub fn get_remote_name(&self) -> &str {
+        &self.remote_name
+    }
+    pub fn clear_remote_name(&mut self) {
+        self.remote_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_name(&mut self, v: ::std::string::String) {
+        self.remote_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remote_name(&mut self) -> &mut ::std::string::String {
+        &mut self.remote_name
+    }
+
+    // Take field
+    pub fn take_remote_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.remote_name, ::std::string::String::new())
+    }
+
+    // string resolved_name = 4;
+
+
+    pub fn get_resolved_name(&self) -> &str {
+        &self.resolved_name
+    }
+    pub fn clear_resolved_name(&mut self) {
+        self.resolved_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolved_name(&mut self, v: ::std::string::String) {
+        self.resolved_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resolved_name(&mut self) -> &mut ::std::string::String {
+        &mut self.resolved_name
+    }
+
+    // Take field
+    pub fn take_resolved_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resolved_name, ::std::string::String::new())
+    }
+
+    // .RenameMergePolicy policy = 5;
+
+
+    pub fn get_policy(&self) -> RenameMergePolicy {
+        self.policy
+    }
+    pub fn clear_policy(&mut self) {
+        self.policy = RenameMergePolicy::LatestWins;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policy(&mut self, v: RenameMergePolicy) {
+        self.policy = v;
+    }
+
+    // string remote_device_id = 6;
+
+
+    pub fn get_remote_device_id(&self) -> &str {
+        &self.remote_device_id
+    }
+    pub fn clear_remote_device_id(&mut self) {
+        self.remote_device_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_device_id(&mut self, v: ::std::string::String) {
+        self.remote_device_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remote_device_id(&mut self) -> &mut ::std::string::String {
+        &mut self.remote_device_id
+    }
+
+    // Take field
+    pub fn take_remote_device_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.remote_device_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RenameConflict {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.object_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.local_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.remote_name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resolved_name)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.policy, 5, &mut self.unknown_fields)?
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.remote_device_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.object_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.object_id);
+        }
+        if !self.local_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.local_name);
+        }
+        if !self.remote_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.remote_name);
+        }
+        if !self.resolved_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.resolved_name);
+        }
+        if self.policy != RenameMergePolicy::LatestWins {
+            my_size += ::protobuf::rt::enum_size(5, self.policy);
+        }
+        if !self.remote_device_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.remote_device_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.object_id.is_empty() {
+            os.write_string(1, &self.object_id)?;
+        }
+        if !self.local_name.is_empty() {
+            os.write_string(2, &self.local_name)?;
+        }
+        if !self.remote_name.is_empty() {
+            os.write_string(3, &self.remote_name)?;
+        }
+        if !self.resolved_name.is_empty() {
+            os.write_string(4, &self.resolved_name)?;
+        }
+        if self.policy != RenameMergePolicy::LatestWins {
+            os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.policy))?;
+        }
+        if !self.remote_device_id.is_empty() {
+            os.write_string(6, &self.remote_device_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RenameConflict {
+        RenameConflict::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "object_id",
+                |m: &RenameConflict| { &m.object_id },
+                |m: &mut RenameConflict| { &mut m.object_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "local_name",
+                |m: &RenameConflict| { &m.local_name },
+                |m: &mut RenameConflict| { &mut m.local_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "remote_name",
+                |m: &RenameConflict| { &m.remote_name },
+                |m: &mut RenameConflict| { &mut m.remote_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resolved_name",
+                |m: &RenameConflict| { &m.resolved_name },
+                |m: &mut RenameConflict| { &mut m.resolved_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RenameMergePolicy>>(
+                "policy",
+                |m: &RenameConflict| { &m.policy },
+                |m: &mut RenameConflict| { &mut m.policy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "remote_device_id",
+                |m: &RenameConflict| { &m.remote_device_id },
+                |m: &mut RenameConflict| { &mut m.remote_device_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RenameConflict>(
+                "RenameConflict",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RenameConflict {
+        static instance: ::protobuf::rt::LazyV2<RenameConflict> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RenameConflict::new)
+    }
+}
+
+impl ::protobuf::Clear for RenameConflict {
+    fn clear(&mut self) {
+        self.object_id.clear();
+        self.local_name.clear();
+        self.remote_name.clear();
+        self.resolved_name.clear();
+        self.policy = RenameMergePolicy::LatestWins;
+        self.remote_device_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RenameConflict {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RenameConflict {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum RenameMergePolicy {
+    LatestWins = 0,
+    AppendSuffix = 1,
+}
+
+impl ::protobuf::ProtobufEnum for RenameMergePolicy {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<RenameMergePolicy> {
+        match value {
+            0 => ::std::option::Option::Some(RenameMergePolicy::LatestWins),
+            1 => ::std::option::Option::Some(RenameMergePolicy::AppendSuffix),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [RenameMergePolicy] = &[
+            RenameMergePolicy::LatestWins,
+            RenameMergePolicy::AppendSuffix,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<RenameMergePolicy>("RenameMergePolicy", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for RenameMergePolicy {
+}
+
+impl ::std::default::Default for RenameMergePolicy {
+    fn default() -> Self {
+        RenameMergePolicy::LatestWins
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RenameMergePolicy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14workspace_sync.proto\"F\n\x18RenameMergePolicySetting\x12*\n\x06po\
+    licy\x18\x01\x20\x01(\x0e2\x12.RenameMergePolicyR\x06policy\"\xe8\x01\n\
+    \x0eRenameConflict\x12\x1b\n\tobject_id\x18\x01\x20\x01(\tR\x08objectId\
+    \x12\x1d\n\nlocal_name\x18\x02\x20\x01(\tR\tlocalName\x12\x1f\n\x0bremot\
+    e_name\x18\x03\x20\x01(\tR\nremoteName\x12#\n\rresolved_name\x18\x04\x20\
+    \x01(\tR\x0cresolvedName\x12*\n\x06policy\x18\x05\x20\x01(\x0e2\x12.Rena\
+    meMergePolicyR\x06policy\x12(\n\x10remote_device_id\x18\x06\x20\x01(\tR\
+    \x0eremoteDeviceId*5\n\x11RenameMergePolicy\x12\x0e\n\nLatestWins\x10\0\
+    \x12\x10\n\x0cAppendSuffix\x10\x01J\xad\x04\n\x06\x12\x04\0\0\x10\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\n\
+    \n\x03\x04\0\x01\x12\x03\x02\x08\x20\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
+    \x04!\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\x03\x16\x1c\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\
+    \x1f\x20\n\n\n\x02\x04\x01\x12\x04\x05\0\x0c\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\x05\x08\x16\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\x04\x19\n\x0c\
+    \n\x05\x04\x01\x02\0\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x06\x0b\x14\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x06\x17\x18\n\
+    \x0b\n\x04\x04\x01\x02\x01\x12\x03\x07\x04\x1a\n\x0c\n\x05\x04\x01\x02\
+    \x01\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x07\
+    \x0b\x15\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x07\x18\x19\n\x0b\n\x04\
+    \x04\x01\x02\x02\x12\x03\x08\x04\x1b\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\
+    \x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x08\x0b\x16\n\x0c\
+    \n\x05\x04\x01\x02\x02\x03\x12\x03\x08\x19\x1a\n\x0b\n\x04\x04\x01\x02\
+    \x03\x12\x03\t\x04\x1d\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\t\x04\n\n\
+    \x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\t\x0b\x18\n\x0c\n\x05\x04\x01\x02\
+    \x03\x03\x12\x03\t\x1b\x1c\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\n\x04!\n\
+    \x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\n\x04\x15\n\x0c\n\x05\x04\x01\x02\
+    \x04\x01\x12\x03\n\x16\x1c\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\n\x1f\
+    \x20\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x0b\x04\x20\n\x0c\n\x05\x04\x01\
+    \x02\x05\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03\
+    \x0b\x0b\x1b\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x0b\x1e\x1f\n\n\n\
+    \x02\x05\0\x12\x04\r\0\x10\x01\n\n\n\x03\x05\0\x01\x12\x03\r\x05\x16\n\
+    \x0b\n\x04\x05\0\x02\0\x12\x03\x0e\x04\x13\n\x0c\n\x05\x05\0\x02\0\x01\
+    \x12\x03\x0e\x04\x0e\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x0e\x11\x12\n\
+    \x0b\n\x04\x05\0\x02\x01\x12\x03\x0f\x04\x15\n\x0c\n\x05\x05\0\x02\x01\
+    \x01\x12\x03\x0f\x04\x10\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x0f\x13\
+    \x14b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
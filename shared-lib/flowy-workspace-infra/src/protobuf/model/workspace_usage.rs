@@ -0,0 +1,785 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_usage.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ApiUsage {
+    // message fields
+    pub date: ::std::string::String,
+    pub user_id: ::std::string::String,
+    pub bot_id: ::std::string::String,
+    pub call_count: i64,
+    pub request_bytes: i64,
+    pub response_bytes: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ApiUsage {
+    fn default() -> &'a ApiUsage {
+        <ApiUsage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ApiUsage {
+    pub fn new() -> ApiUsage {
+        ::std::default::Default::default()
+    }
+
+    // string date = 1;
+
+
+    pub fn get_date(&self) -> &str {
+        &self.date
+    }
+    pub fn clear_date(&mut self) {
+        self.date.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date(&mut self, v: ::std::string::String) {
+        self.date = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_date(&mut self) -> &mut ::std::string::String {
+        &mut self.date
+    }
+
+    // Take field
+    pub fn take_date(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.date, ::std::string::String::new())
+    }
+
+    // string user_id = 2;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // string bot_id = 3;
+
+
+    pub fn get_bot_id(&self) -> &str {
+        &self.bot_id
+    }
+    pub fn clear_bot_id(&mut self) {
+        self.bot_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bot_id(&mut self, v: ::std::string::String) {
+        self.bot_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bot_id(&mut self) -> &mut ::std::string::String {
+        &mut self.bot_id
+    }
+
+    // Take field
+    pub fn take_bot_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.bot_id, ::std::string::String::new())
+    }
+
+    // int64 call_count = 4;
+
+
+    pub fn get_call_count(&self) -> i64 {
+        self.call_count
+    }
+    pub fn clear_call_count(&mut self) {
+        self.call_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_call_count(&mut self, v: i64) {
+        self.call_count = v;
+    }
+
+    // int64 request_bytes = 5;
+
+
+    pub fn get_request_bytes(&self) -> i64 {
+        self.request_bytes
+    }
+    pub fn clear_request_bytes(&mut self) {
+        self.request_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_request_bytes(&mut self, v: i64) {
+        self.request_bytes = v;
+    }
+
+    // int64 response_bytes = 6;
+
+
+    pub fn get_response_bytes(&self) -> i64 {
+        self.response_bytes
+    }
+    pub fn clear_response_bytes(&mut self) {
+        self.response_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_response_bytes(&mut self, v: i64) {
+        self.response_bytes = v;
+    }
+}
+
+impl ::protobuf::Message for ApiUsage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.date)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.bot_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.call_count = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.request_bytes = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.response_bytes = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.date.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.date);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        if !self.bot_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.bot_id);
+        }
+        if self.call_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.call_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.request_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.request_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.response_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.response_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.date.is_empty() {
+            os.write_string(1, &self.date)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        if !self.bot_id.is_empty() {
+            os.write_string(3, &self.bot_id)?;
+        }
+        if self.call_count != 0 {
+            os.write_int64(4, self.call_count)?;
+        }
+        if self.request_bytes != 0 {
+            os.write_int64(5, self.request_bytes)?;
+        }
+        if self.response_bytes != 0 {
+            os.write_int64(6, self.response_bytes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ApiUsage {
+        ApiUsage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "date",
+                |m: &ApiUsage| { &m.date },
+                |m: &mut ApiUsage| { &mut m.date },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &ApiUsage| { &m.user_id },
+                |m: &mut ApiUsage| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "bot_id",
+                |m: &ApiUsage| { &m.bot_id },
+                |m: &mut ApiUsage| { &mut m.bot_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "call_count",
+                |m: &ApiUsage| { &m.call_count },
+                |m: &mut ApiUsage| { &mut m.call_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "request_bytes",
+                |m: &ApiUsage| { &m.request_bytes },
+                |m: &mut ApiUsage| { &mut m.request_bytes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "response_bytes",
+                |m: &ApiUsage| { &m.response_bytes },
+                |m: &mut ApiUsage| { &mut m.response_bytes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ApiUsage>(
+                "ApiUsage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ApiUsage {
+        static instance: ::protobuf::rt::LazyV2<ApiUsage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ApiUsage::new)
+    }
+}
+
+impl ::protobuf::Clear for ApiUsage {
+    fn clear(&mut self) {
+        self.date.clear();
+        self.user_id.clear();
+        self.bot_id.clear();
+        self.call_count = 0;
+        self.request_bytes = 0;
+        self.response_bytes = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ApiUsage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ApiUsage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedApiUsage {
+    // message fields
+    pub items: ::protobuf::RepeatedField<ApiUsage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedApiUsage {
+    fn default() -> &'a RepeatedApiUsage {
+        <RepeatedApiUsage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedApiUsage {
+    pub fn new() -> RepeatedApiUsage {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ApiUsage items = 1;
+
+
+    pub fn get_items(&self) -> &[ApiUsage] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ApiUsage>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ApiUsage> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ApiUsage> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedApiUsage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedApiUsage {
+        RepeatedApiUsage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ApiUsage>>(
+                "items",
+                |m: &RepeatedApiUsage| { &m.items },
+                |m: &mut RepeatedApiUsage| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedApiUsage>(
+                "RepeatedApiUsage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedApiUsage {
+        static instance: ::protobuf::rt::LazyV2<RepeatedApiUsage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedApiUsage::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedApiUsage {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedApiUsage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedApiUsage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReadApiUsageParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub days: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReadApiUsageParams {
+    fn default() -> &'a ReadApiUsageParams {
+        <ReadApiUsageParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadApiUsageParams {
+    pub fn new() -> ReadApiUsageParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // int64 days = 2;
+
+
+    pub fn get_days(&self) -> i64 {
+        self.days
+    }
+    pub fn clear_days(&mut self) {
+        self.days = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_days(&mut self, v: i64) {
+        self.days = v;
+    }
+}
+
+impl ::protobuf::Message for ReadApiUsageParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.days = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if self.days != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.days, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if self.days != 0 {
+            os.write_int64(2, self.days)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReadApiUsageParams {
+        ReadApiUsageParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &ReadApiUsageParams| { &m.workspace_id },
+                |m: &mut ReadApiUsageParams| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "days",
+                |m: &ReadApiUsageParams| { &m.days },
+                |m: &mut ReadApiUsageParams| { &mut m.days },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReadApiUsageParams>(
+                "ReadApiUsageParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReadApiUsageParams {
+        static instance: ::protobuf::rt::LazyV2<ReadApiUsageParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReadApiUsageParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ReadApiUsageParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.days = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReadApiUsageParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadApiUsageParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x15workspace_usage.proto\"\xb9\x01\n\x08ApiUsage\x12\x12\n\x04date\
+    \x18\x01\x20\x01(\tR\x04date\x12\x17\n\x07user_id\x18\x02\x20\x01(\tR\
+    \x06userId\x12\x15\n\x06bot_id\x18\x03\x20\x01(\tR\x05botId\x12\x1d\n\nc\
+    all_count\x18\x04\x20\x01(\x03R\tcallCount\x12#\n\rrequest_bytes\x18\x05\
+    \x20\x01(\x03R\x0crequestBytes\x12%\n\x0eresponse_bytes\x18\x06\x20\x01(\
+    \x03R\rresponseBytes\"3\n\x10RepeatedApiUsage\x12\x1f\n\x05items\x18\x01\
+    \x20\x03(\x0b2\t.ApiUsageR\x05items\"K\n\x12ReadApiUsageParams\x12!\n\
+    \x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\x12\n\x04days\
+    \x18\x02\x20\x01(\x03R\x04daysJ\xd7\x04\n\x06\x12\x04\0\0\x10\x01\n\x08\
+    \n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\t\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\
+    \x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\
+    \0\x01\x12\x03\x03\x0b\x0f\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x12\
+    \x13\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x17\n\x0c\n\x05\x04\0\x02\
+    \x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\
+    \x12\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x15\x16\n\x0b\n\x04\x04\0\
+    \x02\x02\x12\x03\x05\x04\x16\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\
+    \x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\
+    \0\x02\x02\x03\x12\x03\x05\x14\x15\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\
+    \x04\x19\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\
+    \0\x02\x03\x01\x12\x03\x06\n\x14\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\
+    \x06\x17\x18\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x1c\n\x0c\n\x05\
+    \x04\0\x02\x04\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\0\x02\x04\x01\x12\
+    \x03\x07\n\x17\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x1a\x1b\n\x0b\n\
+    \x04\x04\0\x02\x05\x12\x03\x08\x04\x1d\n\x0c\n\x05\x04\0\x02\x05\x05\x12\
+    \x03\x08\x04\t\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08\n\x18\n\x0c\n\
+    \x05\x04\0\x02\x05\x03\x12\x03\x08\x1b\x1c\n\n\n\x02\x04\x01\x12\x04\n\0\
+    \x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\n\x08\x18\n\x0b\n\x04\x04\x01\x02\
+    \0\x12\x03\x0b\x04\x20\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x0b\x04\x0c\
+    \n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x0b\r\x15\n\x0c\n\x05\x04\x01\x02\
+    \0\x01\x12\x03\x0b\x16\x1b\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b\x1e\
+    \x1f\n\n\n\x02\x04\x02\x12\x04\r\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\
+    \r\x08\x1a\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0e\x04\x1c\n\x0c\n\x05\x04\
+    \x02\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
+    \x0e\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0e\x1a\x1b\n\x0b\n\
+    \x04\x04\x02\x02\x01\x12\x03\x0f\x04\x13\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0f\n\x0e\n\
+    \x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f\x11\x12b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,177 @@
+// The apps and views are sorted by their ranks, the rank is a fraction in base 62 that is written without
+// the leading "0.", e.g. "V" is 31/62. There is always a rank between two ranks, so moving an item only
+// changes the rank of the item itself.
+const RANK_DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+// The rank can't end with the smallest digit, otherwise there may be no rank between it and the rank
+// that it is the prefix of, e.g. "V" and "V0".
+pub fn is_valid_rank(rank: &str) -> bool {
+    !rank.is_empty() && !rank.ends_with('0') && rank.bytes().all(|b| RANK_DIGITS.contains(&b))
+}
+
+// Returns the rank between the prev and the next. The empty prev is before all the ranks and the next is
+// None if the item is the last one, the next is ignored if it isn't after the prev.
+pub fn rank_between(prev: &str, next: Option<&str>) -> String {
+    let next = next.filter(|next| *next > prev).map(to_digits);
+    to_rank(&midpoint(&to_digits(prev), next.as_deref()))
+}
+
+// Returns the rank that puts the item at the index of its siblings, the ranks of the siblings are sorted
+// and don't contain the rank of the item itself. Returns None if the siblings around the index have the
+// same rank, e.g. they were created before the items were ranked, then all of them need to be ranked
+// again.
+pub fn rank_at(ranks: &[&str], index: usize) -> Option<String> {
+    let index = index.min(ranks.len());
+    let prev = match index {
+        0 => "",
+        _ => ranks[index - 1],
+    };
+
+    match ranks.get(index) {
+        Some(next) if *next <= prev => None,
+        next => Some(rank_between(prev, next.copied())),
+    }
+}
+
+// Returns the ids and the ranks that change after moving the item to the index of its siblings, the
+// siblings are sorted and don't contain the item. Only the rank of the item changes unless the siblings
+// around the index have the same rank, then the item and all its siblings are ranked again.
+pub fn ranks_after_move(item_id: &str, siblings: &[(&str, &str)], index: usize) -> Vec<(String, String)> {
+    let ranks = siblings.iter().map(|(_, rank)| *rank).collect::<Vec<&str>>();
+    if let Some(rank) = rank_at(&ranks, index) {
+        return vec![(item_id.to_owned(), rank)];
+    }
+
+    let mut ids = siblings.iter().map(|(id, _)| *id).collect::<Vec<&str>>();
+    ids.insert(index.min(ids.len()), item_id);
+    ids.iter()
+        .map(|id| id.to_string())
+        .zip(ranks_in_order(ids.len()))
+        .collect()
+}
+
+// The ranks of the items in their order, they are spread evenly so that they are as short as possible
+pub fn ranks_in_order(count: usize) -> Vec<String> {
+    let base = RANK_DIGITS.len() as u128;
+    let mut width = 1;
+    while base.pow(width) <= count as u128 {
+        width += 1;
+    }
+
+    let step = base.pow(width) / (count as u128 + 1);
+    (1..=count as u128)
+        .map(|index| {
+            let mut value = index * step;
+            let mut digits = vec![0; width as usize];
+            for digit in digits.iter_mut().rev() {
+                *digit = (value % base) as usize;
+                value /= base;
+            }
+            while digits.last() == Some(&0) {
+                digits.pop();
+            }
+            to_rank(&digits)
+        })
+        .collect()
+}
+
+fn to_digits(rank: &str) -> Vec<usize> {
+    rank.bytes()
+        .map(|b| RANK_DIGITS.iter().position(|digit| *digit == b).unwrap_or(0))
+        .collect()
+}
+
+fn to_rank(digits: &[usize]) -> String { digits.iter().map(|digit| RANK_DIGITS[*digit] as char).collect() }
+
+// The prev is before the next, the missing digits of the prev are zeros and the missing next is one.
+fn midpoint(prev: &[usize], next: Option<&[usize]>) -> Vec<usize> {
+    if let Some(next) = next {
+        let common_len = next
+            .iter()
+            .enumerate()
+            .take_while(|(index, digit)| prev.get(*index).copied().unwrap_or(0) == **digit)
+            .count();
+        if common_len > 0 {
+            let mut digits = next[..common_len].to_vec();
+            digits.extend(midpoint(&prev[common_len.min(prev.len())..], Some(&next[common_len..])));
+            return digits;
+        }
+    }
+
+    let prev_digit = prev.first().copied().unwrap_or(0);
+    let next_digit = next
+        .and_then(|next| next.first().copied())
+        .unwrap_or(RANK_DIGITS.len());
+    if next_digit > prev_digit + 1 {
+        return vec![(prev_digit + next_digit + 1) / 2];
+    }
+
+    match next {
+        Some(next) if next.len() > 1 => vec![next[0]],
+        _ => {
+            let mut digits = vec![prev_digit];
+            digits.extend(midpoint(prev.get(1..).unwrap_or(&[]), None));
+            digits
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid_rank, rank_at, rank_between, ranks_after_move, ranks_in_order};
+
+    #[test]
+    fn rank_between_neighbours() {
+        assert_eq!(rank_between("", None), "V");
+        assert_eq!(rank_between("V", None), "l");
+        assert_eq!(rank_between("", Some("V")), "G");
+        assert_eq!(rank_between("V", Some("W")), "VV");
+        assert_eq!(rank_between("Vz", Some("W")), "VzV");
+        assert_eq!(rank_between("z", None), "zV");
+    }
+
+    #[test]
+    fn rank_between_keeps_order() {
+        let mut ranks = vec!["V".to_owned(), "l".to_owned()];
+        for _ in 0..50 {
+            let first = rank_between("", Some(ranks[0].as_str()));
+            ranks.insert(0, first);
+            let middle = rank_between(&ranks[1], Some(ranks[2].as_str()));
+            ranks.insert(2, middle);
+        }
+
+        assert!(ranks.iter().all(|rank| is_valid_rank(rank)));
+        assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn rank_at_index() {
+        let ranks = vec!["G", "V", "k"];
+        assert_eq!(rank_at(&ranks, 0).unwrap(), "8");
+        assert_eq!(rank_at(&ranks, 1).unwrap(), "O");
+        assert_eq!(rank_at(&ranks, 3).unwrap(), "s");
+        assert_eq!(rank_at(&ranks, 10).unwrap(), "s");
+        assert_eq!(rank_at(&["", ""], 1), None);
+    }
+
+    #[test]
+    fn rank_items_in_order() {
+        assert_eq!(ranks_in_order(1), vec!["V"]);
+        assert_eq!(ranks_in_order(61).len(), 61);
+        let ranks = ranks_in_order(200);
+        assert!(ranks.iter().all(|rank| is_valid_rank(rank) && rank.len() <= 2));
+        assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn move_item_to_index() {
+        let siblings = vec![("a", "G"), ("b", "V")];
+        assert_eq!(ranks_after_move("c", &siblings, 1), vec![("c".to_owned(), "O".to_owned())]);
+
+        let siblings = vec![("a", ""), ("b", "")];
+        let ranks = ranks_after_move("c", &siblings, 1);
+        let ids = ranks.iter().map(|(id, _)| id.as_str()).collect::<Vec<&str>>();
+        assert_eq!(ids, vec!["a", "c", "b"]);
+        assert!(ranks.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    }
+}
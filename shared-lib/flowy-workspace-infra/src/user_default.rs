@@ -1,7 +1,10 @@
-use crate::entities::{
-    app::{App, RepeatedApp},
-    view::{RepeatedView, View, ViewType},
-    workspace::Workspace,
+use crate::{
+    entities::{
+        app::{App, RepeatedApp},
+        view::{RepeatedView, View, ViewType},
+        workspace::{Workspace, WorkspaceRole},
+    },
+    rank::rank_between,
 };
 use chrono::Utc;
 
@@ -21,6 +24,8 @@ pub fn create_default_workspace(time: chrono::DateTime<Utc>) -> Workspace {
         apps,
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        archived: false,
+        role: WorkspaceRole::Owner,
     };
 
     workspace
@@ -44,6 +49,8 @@ fn create_default_app(workspace_id: String, time: chrono::DateTime<Utc>) -> App
         version: 0,
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        rank: rank_between("", None),
+        modified_device_id: "".to_owned(),
     }
 }
 
@@ -63,5 +70,7 @@ fn create_default_view(app_id: String, time: chrono::DateTime<Utc>) -> View {
         belongings: Default::default(),
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        rank: rank_between("", None),
+        modified_device_id: "".to_owned(),
     }
 }
@@ -25,6 +25,8 @@ impl Attribute {
     inline_attribute!(Size, usize);
     inline_attribute!(Background, String);
     inline_attribute!(InlineCode, bool);
+    // The value is the id of the attachment that the text is replaced with, e.g. an image
+    inline_attribute!(Attachment, &str);
 
     // block
     block_attribute!(Header, usize);
@@ -110,6 +112,8 @@ pub enum AttributeKey {
     Height,
     #[serde(rename = "header")]
     Header,
+    #[serde(rename = "attachment")]
+    Attachment,
 }
 
 // pub trait AttributeValueData<'a>: Serialize + Deserialize<'a> {}
@@ -185,6 +189,7 @@ lazy_static! {
         AttributeKey::Size,
         AttributeKey::Background,
         AttributeKey::InlineCode,
+        AttributeKey::Attachment,
     ]);
     static ref INGORE_KEYS: HashSet<AttributeKey> =
         HashSet::from_iter(vec![AttributeKey::Width, AttributeKey::Height,]);
@@ -72,7 +72,8 @@ where
             | AttributeKey::Color
             | AttributeKey::Background
             | AttributeKey::Align
-            | AttributeKey::List => {
+            | AttributeKey::List
+            | AttributeKey::Attachment => {
                 map_serializer.serialize_entry(&key, v)?;
             },
         }
@@ -3,6 +3,7 @@ mod delta;
 mod flowy_str;
 mod interval;
 mod operation;
+mod undo;
 
 use crate::errors::OTError;
 pub use attributes::*;
@@ -10,6 +11,7 @@ pub use delta::*;
 pub use flowy_str::*;
 pub use interval::*;
 pub use operation::*;
+pub use undo::*;
 
 pub trait OperationTransformable {
     /// Merges the operation with `other` into one operation while preserving
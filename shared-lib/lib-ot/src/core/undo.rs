@@ -0,0 +1,140 @@
+use crate::{
+    core::{Delta, OperationTransformable},
+    errors::OTError,
+};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+pub const DEFAULT_UNDO_DEPTH: usize = 20;
+pub const DEFAULT_UNDO_MERGE_INTERVAL: Duration = Duration::from_millis(400);
+
+// Records the inverted deltas of the changes, so they can be undone in reverse order. The changes that
+// are recorded within the merge_interval of the first change of the group are undone together, e.g. the
+// keystrokes of a word. The changes that are recorded with the same group id are undone together however
+// long they take, e.g. the chunks of a large paste. The oldest group is dropped when there are more than
+// max_depth groups.
+#[derive(Debug, Clone)]
+pub struct UndoManager {
+    undos: VecDeque<Delta>,
+    redos: Vec<Delta>,
+    max_depth: usize,
+    merge_interval: Duration,
+    group_start: Option<Instant>,
+    group_id: Option<String>,
+}
+
+impl std::default::Default for UndoManager {
+    fn default() -> Self { UndoManager::new(DEFAULT_UNDO_DEPTH, DEFAULT_UNDO_MERGE_INTERVAL) }
+}
+
+impl UndoManager {
+    pub fn new(max_depth: usize, merge_interval: Duration) -> Self {
+        UndoManager {
+            undos: VecDeque::new(),
+            redos: Vec::new(),
+            max_depth,
+            merge_interval,
+            group_start: None,
+            group_id: None,
+        }
+    }
+
+    pub fn can_undo(&self) -> bool { !self.undos.is_empty() }
+
+    pub fn can_redo(&self) -> bool { !self.redos.is_empty() }
+
+    pub fn max_depth(&self) -> usize { self.max_depth }
+
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+        while self.undos.len() > max_depth {
+            self.undos.pop_front();
+        }
+        if self.redos.len() > max_depth {
+            self.redos.drain(..self.redos.len() - max_depth);
+        }
+    }
+
+    pub fn set_merge_interval(&mut self, merge_interval: Duration) { self.merge_interval = merge_interval; }
+
+    // The delta is the change that is going to be composed into the document, the document is the one
+    // before the change. Recording a change clears the redo stack.
+    pub fn record(&mut self, delta: &Delta, document: &Delta) -> Result<(), OTError> {
+        self.record_at(delta, document, Instant::now())
+    }
+
+    pub fn record_at(&mut self, delta: &Delta, document: &Delta, now: Instant) -> Result<(), OTError> {
+        let undo = delta.invert(document);
+        if undo.is_empty() || self.max_depth == 0 {
+            return Ok(());
+        }
+
+        let is_merged = match (self.group_start, &self.group_id) {
+            (Some(group_start), None) => now.duration_since(group_start) < self.merge_interval,
+            _ => false,
+        };
+        if !is_merged {
+            self.group_start = Some(now);
+            self.group_id = None;
+        }
+        self.push_undo(undo, is_merged)
+    }
+
+    // The change is merged into the last group if it was recorded with the same group id
+    pub fn record_in_group(&mut self, delta: &Delta, document: &Delta, group_id: &str) -> Result<(), OTError> {
+        let undo = delta.invert(document);
+        if undo.is_empty() || self.max_depth == 0 {
+            return Ok(());
+        }
+
+        let is_merged = self.group_id.as_deref() == Some(group_id);
+        self.group_start = None;
+        self.group_id = Some(group_id.to_owned());
+        self.push_undo(undo, is_merged)
+    }
+
+    fn push_undo(&mut self, mut undo: Delta, is_merged: bool) -> Result<(), OTError> {
+        if is_merged {
+            if let Some(last_undo) = self.undos.pop_back() {
+                undo = undo.compose(&last_undo)?;
+            }
+        }
+
+        self.redos.clear();
+        self.undos.push_back(undo);
+        if self.undos.len() > self.max_depth {
+            self.undos.pop_front();
+        }
+        Ok(())
+    }
+
+    // The next change starts a new group even if it's recorded within the merge_interval
+    pub fn cutoff(&mut self) {
+        self.group_start = None;
+        self.group_id = None;
+    }
+
+    // Returns the delta that reverts the last group of changes, it should be composed into the document.
+    // The document is the current one, it's used to calculate the delta that redoes the changes.
+    pub fn undo(&mut self, document: &Delta) -> Option<Delta> {
+        let undo = self.undos.pop_back()?;
+        self.redos.push(undo.invert(document));
+        self.cutoff();
+        Some(undo)
+    }
+
+    pub fn redo(&mut self, document: &Delta) -> Option<Delta> {
+        let redo = self.redos.pop()?;
+        self.undos.push_back(redo.invert(document));
+        self.cutoff();
+        Some(redo)
+    }
+
+    pub fn clear(&mut self) {
+        self.undos.clear();
+        self.redos.clear();
+        self.cutoff();
+    }
+}
@@ -2,7 +2,9 @@ pub mod connect;
 pub mod errors;
 mod msg;
 pub mod protobuf;
+mod reconnect;
 mod ws;
 
 pub use msg::*;
+pub use reconnect::*;
 pub use ws::*;
@@ -11,6 +11,10 @@ pub struct WsMessage {
 
     #[pb(index = 2)]
     pub data: Vec<u8>,
+
+    // The id of the event that sends the message, empty if it's not sent by an event
+    #[pb(index = 3)]
+    pub request_id: String,
 }
 
 #[derive(ProtoBuf_Enum, Debug, Clone, Eq, PartialEq, Hash)]
@@ -0,0 +1,70 @@
+use lib_infra::retry::{jitter, ExponentialBackoff};
+use std::time::Duration;
+
+// How the lost connection is made again. The delay before each attempt doubles from the base_delay up to
+// the max_delay, and half of it is random, so the clients that lost the connection at the same time, e.g.
+// when the server restarts, don't reconnect at the same time.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    // The connection keeps being retried if it's None
+    pub max_retries: Option<usize>,
+}
+
+impl std::default::Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+            max_retries: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    // The delays before the attempts after the first one
+    pub fn delays(&self) -> Box<dyn Iterator<Item = Duration> + Send> {
+        let base_millis = self.base_delay.as_millis().max(2) as u64;
+        let max_delay = self.max_delay;
+        let delays = ExponentialBackoff::from_millis(2)
+            .factor(base_millis / 2)
+            .max_delay(max_delay)
+            .map(|delay| delay / 2 + jitter(delay / 2));
+        match self.max_retries {
+            None => Box::new(delays),
+            Some(max_retries) => Box::new(delays.take(max_retries)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconnect_delays_test() {
+        let policy = ReconnectPolicy {
+            base_delay: Duration::from_millis(1000),
+            max_delay: Duration::from_secs(5),
+            max_retries: Some(5),
+        };
+        let delays = policy.delays().collect::<Vec<Duration>>();
+        assert_eq!(delays.len(), 5);
+
+        // The delay is between the half of the backoff and the backoff: 1s, 2s, 4s, 5s, 5s
+        let backoffs = vec![1000, 2000, 4000, 5000, 5000];
+        for (delay, backoff) in delays.into_iter().zip(backoffs) {
+            assert!(delay >= Duration::from_millis(backoff / 2));
+            assert!(delay <= Duration::from_millis(backoff));
+        }
+    }
+
+    #[test]
+    fn reconnect_without_max_retries_test() {
+        let policy = ReconnectPolicy::default();
+        let delays = policy.delays().take(100).collect::<Vec<Duration>>();
+        assert_eq!(delays.len(), 100);
+        assert!(delays.iter().all(|delay| *delay <= policy.max_delay));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     connect::{WsConnectionFuture, WsStream},
     errors::WsError,
+    ReconnectPolicy,
     WsMessage,
     WsModule,
 };
@@ -9,7 +10,7 @@ use bytes::Bytes;
 use dashmap::DashMap;
 use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender};
 use futures_core::{ready, Stream};
-use lib_infra::retry::{Action, FixedInterval, Retry};
+use lib_infra::{metrics, retry::Action};
 use parking_lot::RwLock;
 use pin_project::pin_project;
 use std::{
@@ -17,7 +18,10 @@ use std::{
     fmt::Formatter,
     future::Future,
     pin::Pin,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
     time::Duration,
 };
@@ -30,6 +34,10 @@ use tokio_tungstenite::tungstenite::{
 pub type MsgReceiver = UnboundedReceiver<Message>;
 pub type MsgSender = UnboundedSender<Message>;
 type Handlers = DashMap<WsModule, Arc<dyn WsMessageHandler>>;
+type Delays = Box<dyn Iterator<Item = Duration> + Send>;
+
+// The first connection is retried this many times before it's reported as Disconnected
+const START_CONNECT_RETRIES: usize = 3;
 
 pub trait WsMessageHandler: Sync + Send + 'static {
     fn source(&self) -> WsModule;
@@ -40,7 +48,12 @@ pub trait WsMessageHandler: Sync + Send + 'static {
 pub enum WsState {
     Init,
     Connected(Arc<WsSender>),
+    // The connection was lost or couldn't be made, the owner of the controller reconnects with retry
     Disconnected(WsError),
+    // Sent before each attempt of the retry after the first one, with the number of the attempt
+    Reconnecting(usize),
+    // The retry gave up after the max retries of the ReconnectPolicy
+    Offline(WsError),
 }
 
 impl std::fmt::Display for WsState {
@@ -49,6 +62,8 @@ impl std::fmt::Display for WsState {
             WsState::Init => f.write_str("Init"),
             WsState::Connected(_) => f.write_str("Connected"),
             WsState::Disconnected(_) => f.write_str("Disconnected"),
+            WsState::Reconnecting(attempt) => f.write_str(&format!("Reconnecting({})", attempt)),
+            WsState::Offline(_) => f.write_str("Offline"),
         }
     }
 }
@@ -62,6 +77,8 @@ pub struct WsController {
     state_notify: Arc<broadcast::Sender<WsState>>,
     sender: Arc<RwLock<Option<Arc<WsSender>>>>,
     addr: Arc<RwLock<Option<String>>>,
+    policy: RwLock<ReconnectPolicy>,
+    is_connecting: Arc<AtomicBool>,
 }
 
 impl WsController {
@@ -72,6 +89,8 @@ impl WsController {
             sender: Arc::new(RwLock::new(None)),
             state_notify: Arc::new(state_notify),
             addr: Arc::new(RwLock::new(None)),
+            policy: RwLock::new(ReconnectPolicy::default()),
+            is_connecting: Arc::new(AtomicBool::new(false)),
         };
         controller
     }
@@ -85,32 +104,36 @@ impl WsController {
         Ok(())
     }
 
-    pub async fn start_connect(&self, addr: String) -> Result<(), ServerError> {
-        *self.addr.write() = Some(addr.clone());
+    pub fn set_reconnect_policy(&self, policy: ReconnectPolicy) { *self.policy.write() = policy; }
 
-        let strategy = FixedInterval::from_millis(5000).take(3);
-        self.connect(addr, strategy).await
+    pub async fn start_connect(&self, addr: String) -> Result<(), ServerError> {
+        let delays = self.policy.read().delays().take(START_CONNECT_RETRIES);
+        self.connect(addr, Box::new(delays), WsState::Disconnected).await
     }
 
-    async fn connect<T, I>(&self, addr: String, strategy: T) -> Result<(), ServerError>
-    where
-        T: IntoIterator<IntoIter = I, Item = Duration>,
-        I: Iterator<Item = Duration> + Send + 'static,
-    {
+    // The state that's sent if the connection can't be made after all the delays is created by on_failure
+    async fn connect(
+        &self,
+        addr: String,
+        delays: Delays,
+        on_failure: fn(WsError) -> WsState,
+    ) -> Result<(), ServerError> {
         let (ret, rx) = oneshot::channel::<Result<(), ServerError>>();
         *self.addr.write() = Some(addr.clone());
+        self.is_connecting.store(true, Ordering::SeqCst);
 
         let action = WsConnectAction {
             addr,
             handlers: self.handlers.clone(),
         };
-
-        let retry = Retry::spawn(strategy, action);
         let sender_holder = self.sender.clone();
         let state_notify = self.state_notify.clone();
+        let is_connecting = self.is_connecting.clone();
 
         tokio::spawn(async move {
-            match retry.await {
+            let result = connect_with_delays(action, delays, &state_notify).await;
+            is_connecting.store(false, Ordering::SeqCst);
+            match result {
                 Ok(result) => {
                     let WsConnectResult {
                         stream,
@@ -122,11 +145,10 @@ impl WsController {
 
                     let _ = state_notify.send(WsState::Connected(sender));
                     let _ = ret.send(Ok(()));
-                    spawn_stream_and_handlers(stream, handlers_fut, state_notify).await;
+                    spawn_stream_and_handlers(stream, handlers_fut, state_notify, sender_holder).await;
                 },
                 Err(e) => {
-                    //
-                    let _ = state_notify.send(WsState::Disconnected(e.clone()));
+                    let _ = state_notify.send(on_failure(e.clone()));
                     let _ = ret.send(Err(ServerError::internal().context(e)));
                 },
             }
@@ -135,6 +157,8 @@ impl WsController {
         rx.await?
     }
 
+    // Reconnects with the delays of the ReconnectPolicy. It's skipped if the connection is being made, e.g.
+    // the Disconnected state was sent twice.
     pub async fn retry(&self) -> Result<(), ServerError> {
         let addr = self
             .addr
@@ -142,8 +166,18 @@ impl WsController {
             .as_ref()
             .expect("must call start_connect first")
             .clone();
-        let strategy = FixedInterval::from_millis(5000);
-        self.connect(addr, strategy).await
+        if self.is_connecting.load(Ordering::SeqCst) {
+            log::debug!("The websocket is connecting, the retry is skipped");
+            return Ok(());
+        }
+        let delays = self.policy.read().delays();
+        self.connect(addr, delays, WsState::Offline).await
+    }
+
+    // Reconnects with the new address, e.g. the token in the address was refreshed
+    pub async fn retry_with_addr(&self, addr: String) -> Result<(), ServerError> {
+        *self.addr.write() = Some(addr);
+        self.retry().await
     }
 
     pub fn state_subscribe(&self) -> broadcast::Receiver<WsState> { self.state_notify.subscribe() }
@@ -156,23 +190,54 @@ impl WsController {
     }
 }
 
+// The first attempt is made right away, the others after the delays
+async fn connect_with_delays(
+    mut action: WsConnectAction,
+    mut delays: Delays,
+    state_notify: &broadcast::Sender<WsState>,
+) -> Result<WsConnectResult, WsError> {
+    let mut attempt = 0;
+    loop {
+        match action.run().await {
+            Ok(result) => return Ok(result),
+            Err(e) => match delays.next() {
+                None => return Err(e),
+                Some(delay) => {
+                    attempt += 1;
+                    log::debug!("Connect websocket failed: {:?}, retry in {:?}", e, delay);
+                    let _ = state_notify.send(WsState::Reconnecting(attempt));
+                    tokio::time::sleep(delay).await;
+                },
+            },
+        }
+    }
+}
+
+// Runs until the connection is lost, the sender of the lost connection is removed so nothing is sent
+// through it until the connection is made again.
 async fn spawn_stream_and_handlers(
     stream: WsStream,
     handlers: WsHandlerFuture,
     state_notify: Arc<broadcast::Sender<WsState>>,
+    sender_holder: Arc<RwLock<Option<Arc<WsSender>>>>,
 ) {
-    tokio::select! {
+    let error = tokio::select! {
         result = stream => {
             match result {
-                Ok(_) => {},
+                Ok(_) => WsError::internal().context("The websocket connection was closed"),
                 Err(e) => {
                     log::error!("websocket error: {:?}", e);
-                    let _ = state_notify.send(WsState::Disconnected(e)).unwrap();
+                    e
                 }
             }
         },
-        result = handlers => tracing::debug!("handlers completed {:?}", result),
+        result = handlers => {
+            tracing::debug!("handlers completed {:?}", result);
+            WsError::internal().context("The websocket handlers completed")
+        },
     };
+    let _ = sender_holder.write().take();
+    let _ = state_notify.send(WsState::Disconnected(error));
 }
 
 #[pin_project]
@@ -195,10 +260,14 @@ impl WsHandlerFuture {
 
     fn handle_binary_message(&self, bytes: Vec<u8>) {
         let bytes = Bytes::from(bytes);
+        let size = bytes.len() as u64;
         match WsMessage::try_from(bytes) {
-            Ok(message) => match self.handlers.get(&message.module) {
-                None => log::error!("Can't find any handler for message: {:?}", message),
-                Some(handler) => handler.receive_message(message.clone()),
+            Ok(message) => {
+                record_message_metrics("ws_received_messages_total", "ws_received_bytes_total", &message.module, size);
+                match self.handlers.get(&message.module) {
+                    None => log::error!("Can't find any handler for message: {:?}", message),
+                    Some(handler) => handler.receive_message(message.clone()),
+                }
             },
             Err(e) => {
                 log::error!("Deserialize binary ws message failed: {:?}", e);
@@ -221,6 +290,17 @@ impl Future for WsHandlerFuture {
     }
 }
 
+// Counts the messages and their bytes per module
+fn record_message_metrics(messages_name: &'static str, bytes_name: &'static str, module: &WsModule, size: u64) {
+    if !metrics::is_metrics_enabled() {
+        return;
+    }
+    let module = module.to_string();
+    let labels = [("module", module.as_str())];
+    metrics::increment_counter(messages_name, &labels, 1);
+    metrics::increment_counter(bytes_name, &labels, size);
+}
+
 #[derive(Debug, Clone)]
 pub struct WsSender {
     ws_tx: MsgSender,
@@ -229,6 +309,7 @@ pub struct WsSender {
 impl WsSender {
     pub fn send_msg<T: Into<WsMessage>>(&self, msg: T) -> Result<(), WsError> {
         let msg = msg.into();
+        record_message_metrics("ws_sent_messages_total", "ws_sent_bytes_total", &msg.module, msg.data.len() as u64);
         let _ = self
             .ws_tx
             .unbounded_send(msg.into())
@@ -240,6 +321,7 @@ impl WsSender {
         let msg = WsMessage {
             module: source.clone(),
             data: text.as_bytes().to_vec(),
+            request_id: "".to_owned(),
         };
         self.send_msg(msg)
     }
@@ -248,6 +330,7 @@ impl WsSender {
         let msg = WsMessage {
             module: source.clone(),
             data: bytes,
+            request_id: "".to_owned(),
         };
         self.send_msg(msg)
     }